@@ -244,11 +244,19 @@ class TypeExpr:
 class Decl:
     name: str
     ty: TypeExpr
+    # The `len` attribute, e.g. 'pPropertyCount' or 'null-terminated'
+    len: ty.Optional[str] = field(default=None)
+    # The `externsync` attribute: 'true', or expressions naming the
+    # members which must be synchronized, e.g. 'pAllocateInfo->commandPool'
+    externsync: ty.Optional[str] = field(default=None)
 
 
 @dataclass
 class AggregateMember(Decl):
-    values: ty.List[str]
+    values: ty.List[str] = field(default_factory=list)
+    # Whether a pointer may be null or ignored, going by the `optional`
+    # and `noautovalidity` attributes
+    optional: bool = field(default=False)
 
 
 @dataclass
@@ -257,11 +265,26 @@ class Arg:
     ty: TypeExpr
 
 
+@dataclass
+class Provider:
+    # The name the command is exposed under, which may be an alias
+    command: Name
+    # Features and extensions which must all be enabled, e.g.
+    # ['VK_KHR_swapchain', 'VK_VERSION_1_1']
+    requires: ty.List[str]
+
+
 @dataclass
 class Func:
     name: Name
     ret: ty.Optional[TypeExpr]
     args: ty.List[ty.Union[Arg, Decl]]
+    successcodes: ty.List[str] = field(default_factory=list)
+    # Other names the command is exposed under, e.g. by extensions
+    # that were later promoted to core
+    aliases: ty.List[Name] = field(default_factory=list)
+    # Features and extensions which provide the command
+    providers: ty.List[Provider] = field(default_factory=list)
 
 
 @dataclass
@@ -365,6 +388,9 @@ class Aggregate:
     name: Name
     members: ty.List[AggregateMember]
     ty: str
+    # The `structextends` attribute: structures whose `pNext` chain
+    # this one may appear in
+    extends: ty.List[Name] = field(default_factory=list)
 
 
 @datatype(category='func_pointer')
@@ -451,6 +477,55 @@ def resolve_aliases(entry):
             raise ValueError(f'No member {name} of {enum.name}')
 
 
+def resolve_command_aliases(commands, aliases):
+    commands = {command.name: command for command in commands}
+    targets = {alias.name: alias.target for alias in aliases}
+    for alias in aliases:
+        target = alias.target
+        while target in targets:
+            target = targets[target]
+        commands[target].aliases.append(alias.name)
+
+
+def resolve_command_providers(commands, providers):
+    commands = {
+        name: command
+        for command in commands
+        for name in [command.name, *command.aliases]
+    }
+    for provider in providers:
+        command = commands.get(provider.command)
+        if command and provider not in command.providers:
+            command.providers.append(provider)
+
+
+def parse_depends(expr: str) -> ty.List[ty.List[str]]:
+    """Converts a `depends` expression, e.g. 'A+(B,C)', into a list of
+    alternatives, each of which lists names that must all be enabled.
+    """
+    tokens = TokenStream(re.findall(r'[\w:]+|[+,()]', expr))
+
+    def parse_or():
+        alternatives = parse_and()
+        while tokens.accept(','):
+            alternatives += parse_and()
+        return alternatives
+
+    def parse_and():
+        alternatives = [[]]
+        while True:
+            if tokens.accept('('):
+                factor = parse_or()
+                tokens.expect(')')
+            else:
+                factor = [[tokens.pop()]]
+            alternatives = [a + b for a in alternatives for b in factor]
+            if not tokens.accept('+'):
+                return alternatives
+
+    return parse_or()
+
+
 def fill_handle_levels(types):
     handles = {
         ty.name.base: ty
@@ -476,6 +551,8 @@ class Registry:
         self.externs = copy.deepcopy(EXTERNS)
         self.types = copy.deepcopy(ALIASES)
         self.commands = []
+        self.command_aliases = []
+        self.command_providers = []
         self.extensions = []
 
     def parse_registry(self, root):
@@ -506,6 +583,8 @@ class Registry:
             resolve_aliases(enum)
         self.enums = [elem['enum'] for elem in self.enums.values()]
 
+        resolve_command_aliases(self.commands, self.command_aliases)
+        resolve_command_providers(self.commands, self.command_providers)
         fill_handle_levels(self.types)
 
     def parse_enums(self, elem):
@@ -643,10 +722,17 @@ class Registry:
             members.append(AggregateMember(
                 name=decl.name,
                 ty=decl.ty,
+                # `altlen` is a C expression where `len` is LaTeX
+                len=child.get('altlen', child.get('len')),
                 values=values,
+                optional=child.get('optional', '').startswith('true')
+                or child.get('noautovalidity') == 'true',
             ))
         category = elem.attrib['category']
-        self.types.append(Aggregate(name, members, category))
+        extends = elem.get('structextends')
+        extends = [Name.from_ident(n) for n in extends.split(',')] \
+            if extends else []
+        self.types.append(Aggregate(name, members, category, extends))
 
     def parse_opaque_type(self, elem):
         name = elem.attrib['name']
@@ -659,25 +745,41 @@ class Registry:
         try:
             target = Name.from_ident(elem.attrib['alias'])
             name = Name.from_ident(elem.attrib['name'])
-            return Alias(name, target)
+            self.command_aliases.append(Alias(name, target))
+            return
         except KeyError:
             pass
 
         tokens = c_tokens(elem_txt(elem.find('proto')))
         ret = parse_c_basic_type(tokens)
         name = Name.from_ident(tokens.pop())
-        args = [parse_c_decl(elem_txt(p)) for p in elem.findall('param')]
-
-        command = Command(name=name, ret=ret, args=args)
+        args = []
+        for param in elem.findall('param'):
+            decl = parse_c_decl(elem_txt(param))
+            decl.len = param.get('len')
+            decl.externsync = param.get('externsync')
+            args.append(decl)
+        successcodes = elem.get('successcodes')
+        successcodes = successcodes.split(',') if successcodes else []
+
+        command = Command(
+            name=name,
+            ret=ret,
+            args=args,
+            successcodes=successcodes,
+        )
         self.commands.append(command)
 
     def parse_feature(self, elem):
         for child in elem.findall('./require/enum'):
             self.parse_enum_ext(0, child)
+        if 'vulkan' in elem.get('api', 'vulkan').split(','):
+            self.parse_requires(elem)
 
     def parse_extensions(self, elem):
         for ext in elem:
-            self.parse_extension(ext)
+            if self.parse_extension(ext):
+                self.parse_requires(ext)
             base_extnumber = ext.attrib['number']
             for child in ext.findall('./require/enum'):
                 self.parse_enum_ext(base_extnumber, child)
@@ -687,6 +789,28 @@ class Registry:
             name = elem.attrib['name']
             level = elem.attrib['type']
             self.extensions.append(Extension(name, level))
+            return True
+        return False
+
+    def parse_requires(self, elem):
+        """Records which commands a feature or extension provides."""
+        for require in elem.findall('./require'):
+            if 'vulkan' not in require.get('api', 'vulkan').split(','):
+                continue
+            if 'depends' in require.attrib:
+                conditions = parse_depends(require.attrib['depends'])
+            else:
+                # Older registries use these instead of `depends`
+                conditions = [[
+                    require.attrib[key]
+                    for key in ('feature', 'extension')
+                    if key in require.attrib
+                ]]
+            for command in require.findall('./command'):
+                name = Name.from_ident(command.attrib['name'])
+                for condition in conditions:
+                    requires = [elem.attrib['name'], *condition]
+                    self.command_providers.append(Provider(name, requires))
 
     def parse_enum_ext(self, base_extnumber, elem):
         try:
@@ -12056,6 +12056,5717 @@ mod aggregates {
     }
 }
 
+impl_tagged! {
+    ApplicationInfo = APPLICATION_INFO,
+    DeviceQueueCreateInfo = DEVICE_QUEUE_CREATE_INFO,
+    DeviceCreateInfo = DEVICE_CREATE_INFO,
+    InstanceCreateInfo = INSTANCE_CREATE_INFO,
+    MemoryAllocateInfo = MEMORY_ALLOCATE_INFO,
+    MappedMemoryRange = MAPPED_MEMORY_RANGE,
+    WriteDescriptorSet = WRITE_DESCRIPTOR_SET,
+    CopyDescriptorSet = COPY_DESCRIPTOR_SET,
+    BufferCreateInfo = BUFFER_CREATE_INFO,
+    BufferViewCreateInfo = BUFFER_VIEW_CREATE_INFO,
+    MemoryBarrier = MEMORY_BARRIER,
+    BufferMemoryBarrier = BUFFER_MEMORY_BARRIER,
+    ImageMemoryBarrier = IMAGE_MEMORY_BARRIER,
+    ImageCreateInfo = IMAGE_CREATE_INFO,
+    ImageViewCreateInfo = IMAGE_VIEW_CREATE_INFO,
+    BindSparseInfo = BIND_SPARSE_INFO,
+    ShaderModuleCreateInfo = SHADER_MODULE_CREATE_INFO,
+    DescriptorSetLayoutCreateInfo = DESCRIPTOR_SET_LAYOUT_CREATE_INFO,
+    DescriptorPoolCreateInfo = DESCRIPTOR_POOL_CREATE_INFO,
+    DescriptorSetAllocateInfo = DESCRIPTOR_SET_ALLOCATE_INFO,
+    PipelineShaderStageCreateInfo = PIPELINE_SHADER_STAGE_CREATE_INFO,
+    ComputePipelineCreateInfo = COMPUTE_PIPELINE_CREATE_INFO,
+    PipelineVertexInputStateCreateInfo = PIPELINE_VERTEX_INPUT_STATE_CREATE_INFO,
+    PipelineInputAssemblyStateCreateInfo = PIPELINE_INPUT_ASSEMBLY_STATE_CREATE_INFO,
+    PipelineTessellationStateCreateInfo = PIPELINE_TESSELLATION_STATE_CREATE_INFO,
+    PipelineViewportStateCreateInfo = PIPELINE_VIEWPORT_STATE_CREATE_INFO,
+    PipelineRasterizationStateCreateInfo = PIPELINE_RASTERIZATION_STATE_CREATE_INFO,
+    PipelineMultisampleStateCreateInfo = PIPELINE_MULTISAMPLE_STATE_CREATE_INFO,
+    PipelineColorBlendStateCreateInfo = PIPELINE_COLOR_BLEND_STATE_CREATE_INFO,
+    PipelineDynamicStateCreateInfo = PIPELINE_DYNAMIC_STATE_CREATE_INFO,
+    PipelineDepthStencilStateCreateInfo = PIPELINE_DEPTH_STENCIL_STATE_CREATE_INFO,
+    GraphicsPipelineCreateInfo = GRAPHICS_PIPELINE_CREATE_INFO,
+    PipelineCacheCreateInfo = PIPELINE_CACHE_CREATE_INFO,
+    PipelineLayoutCreateInfo = PIPELINE_LAYOUT_CREATE_INFO,
+    SamplerCreateInfo = SAMPLER_CREATE_INFO,
+    CommandPoolCreateInfo = COMMAND_POOL_CREATE_INFO,
+    CommandBufferAllocateInfo = COMMAND_BUFFER_ALLOCATE_INFO,
+    CommandBufferInheritanceInfo = COMMAND_BUFFER_INHERITANCE_INFO,
+    CommandBufferBeginInfo = COMMAND_BUFFER_BEGIN_INFO,
+    RenderPassBeginInfo = RENDER_PASS_BEGIN_INFO,
+    RenderPassCreateInfo = RENDER_PASS_CREATE_INFO,
+    EventCreateInfo = EVENT_CREATE_INFO,
+    FenceCreateInfo = FENCE_CREATE_INFO,
+    SemaphoreCreateInfo = SEMAPHORE_CREATE_INFO,
+    QueryPoolCreateInfo = QUERY_POOL_CREATE_INFO,
+    FramebufferCreateInfo = FRAMEBUFFER_CREATE_INFO,
+    SubmitInfo = SUBMIT_INFO,
+    DisplayModeCreateInfoKHR = DISPLAY_MODE_CREATE_INFO_KHR,
+    DisplaySurfaceCreateInfoKHR = DISPLAY_SURFACE_CREATE_INFO_KHR,
+    DisplayPresentInfoKHR = DISPLAY_PRESENT_INFO_KHR,
+    AndroidSurfaceCreateInfoKHR = ANDROID_SURFACE_CREATE_INFO_KHR,
+    ViSurfaceCreateInfoNN = VI_SURFACE_CREATE_INFO_NN,
+    WaylandSurfaceCreateInfoKHR = WAYLAND_SURFACE_CREATE_INFO_KHR,
+    Win32SurfaceCreateInfoKHR = WIN32_SURFACE_CREATE_INFO_KHR,
+    XlibSurfaceCreateInfoKHR = XLIB_SURFACE_CREATE_INFO_KHR,
+    XcbSurfaceCreateInfoKHR = XCB_SURFACE_CREATE_INFO_KHR,
+    DirectFBSurfaceCreateInfoEXT = DIRECTFB_SURFACE_CREATE_INFO_EXT,
+    ImagePipeSurfaceCreateInfoFUCHSIA = IMAGEPIPE_SURFACE_CREATE_INFO_FUCHSIA,
+    StreamDescriptorSurfaceCreateInfoGGP = STREAM_DESCRIPTOR_SURFACE_CREATE_INFO_GGP,
+    ScreenSurfaceCreateInfoQNX = SCREEN_SURFACE_CREATE_INFO_QNX,
+    SwapchainCreateInfoKHR = SWAPCHAIN_CREATE_INFO_KHR,
+    PresentInfoKHR = PRESENT_INFO_KHR,
+    DebugReportCallbackCreateInfoEXT = DEBUG_REPORT_CALLBACK_CREATE_INFO_EXT,
+    ValidationFlagsEXT = VALIDATION_FLAGS_EXT,
+    ValidationFeaturesEXT = VALIDATION_FEATURES_EXT,
+    PipelineRasterizationStateRasterizationOrderAMD = PIPELINE_RASTERIZATION_STATE_RASTERIZATION_ORDER_AMD,
+    DebugMarkerObjectNameInfoEXT = DEBUG_MARKER_OBJECT_NAME_INFO_EXT,
+    DebugMarkerObjectTagInfoEXT = DEBUG_MARKER_OBJECT_TAG_INFO_EXT,
+    DebugMarkerMarkerInfoEXT = DEBUG_MARKER_MARKER_INFO_EXT,
+    DedicatedAllocationImageCreateInfoNV = DEDICATED_ALLOCATION_IMAGE_CREATE_INFO_NV,
+    DedicatedAllocationBufferCreateInfoNV = DEDICATED_ALLOCATION_BUFFER_CREATE_INFO_NV,
+    DedicatedAllocationMemoryAllocateInfoNV = DEDICATED_ALLOCATION_MEMORY_ALLOCATE_INFO_NV,
+    ExternalMemoryImageCreateInfoNV = EXTERNAL_MEMORY_IMAGE_CREATE_INFO_NV,
+    ExportMemoryAllocateInfoNV = EXPORT_MEMORY_ALLOCATE_INFO_NV,
+    ImportMemoryWin32HandleInfoNV = IMPORT_MEMORY_WIN32_HANDLE_INFO_NV,
+    ExportMemoryWin32HandleInfoNV = EXPORT_MEMORY_WIN32_HANDLE_INFO_NV,
+    Win32KeyedMutexAcquireReleaseInfoNV = WIN32_KEYED_MUTEX_ACQUIRE_RELEASE_INFO_NV,
+    PhysicalDeviceDeviceGeneratedCommandsFeaturesNV = PHYSICAL_DEVICE_DEVICE_GENERATED_COMMANDS_FEATURES_NV,
+    DevicePrivateDataCreateInfo = DEVICE_PRIVATE_DATA_CREATE_INFO,
+    PrivateDataSlotCreateInfo = PRIVATE_DATA_SLOT_CREATE_INFO,
+    PhysicalDevicePrivateDataFeatures = PHYSICAL_DEVICE_PRIVATE_DATA_FEATURES,
+    PhysicalDeviceDeviceGeneratedCommandsPropertiesNV = PHYSICAL_DEVICE_DEVICE_GENERATED_COMMANDS_PROPERTIES_NV,
+    PhysicalDeviceMultiDrawPropertiesEXT = PHYSICAL_DEVICE_MULTI_DRAW_PROPERTIES_EXT,
+    GraphicsShaderGroupCreateInfoNV = GRAPHICS_SHADER_GROUP_CREATE_INFO_NV,
+    GraphicsPipelineShaderGroupsCreateInfoNV = GRAPHICS_PIPELINE_SHADER_GROUPS_CREATE_INFO_NV,
+    IndirectCommandsLayoutTokenNV = INDIRECT_COMMANDS_LAYOUT_TOKEN_NV,
+    IndirectCommandsLayoutCreateInfoNV = INDIRECT_COMMANDS_LAYOUT_CREATE_INFO_NV,
+    GeneratedCommandsInfoNV = GENERATED_COMMANDS_INFO_NV,
+    GeneratedCommandsMemoryRequirementsInfoNV = GENERATED_COMMANDS_MEMORY_REQUIREMENTS_INFO_NV,
+    PhysicalDeviceFeatures2 = PHYSICAL_DEVICE_FEATURES_2,
+    PhysicalDeviceProperties2 = PHYSICAL_DEVICE_PROPERTIES_2,
+    FormatProperties2 = FORMAT_PROPERTIES_2,
+    ImageFormatProperties2 = IMAGE_FORMAT_PROPERTIES_2,
+    PhysicalDeviceImageFormatInfo2 = PHYSICAL_DEVICE_IMAGE_FORMAT_INFO_2,
+    QueueFamilyProperties2 = QUEUE_FAMILY_PROPERTIES_2,
+    PhysicalDeviceMemoryProperties2 = PHYSICAL_DEVICE_MEMORY_PROPERTIES_2,
+    SparseImageFormatProperties2 = SPARSE_IMAGE_FORMAT_PROPERTIES_2,
+    PhysicalDeviceSparseImageFormatInfo2 = PHYSICAL_DEVICE_SPARSE_IMAGE_FORMAT_INFO_2,
+    PhysicalDevicePushDescriptorPropertiesKHR = PHYSICAL_DEVICE_PUSH_DESCRIPTOR_PROPERTIES_KHR,
+    PhysicalDeviceDriverProperties = PHYSICAL_DEVICE_DRIVER_PROPERTIES,
+    PresentRegionsKHR = PRESENT_REGIONS_KHR,
+    PhysicalDeviceVariablePointersFeatures = PHYSICAL_DEVICE_VARIABLE_POINTERS_FEATURES,
+    PhysicalDeviceExternalImageFormatInfo = PHYSICAL_DEVICE_EXTERNAL_IMAGE_FORMAT_INFO,
+    ExternalImageFormatProperties = EXTERNAL_IMAGE_FORMAT_PROPERTIES,
+    PhysicalDeviceExternalBufferInfo = PHYSICAL_DEVICE_EXTERNAL_BUFFER_INFO,
+    ExternalBufferProperties = EXTERNAL_BUFFER_PROPERTIES,
+    PhysicalDeviceIDProperties = PHYSICAL_DEVICE_ID_PROPERTIES,
+    ExternalMemoryImageCreateInfo = EXTERNAL_MEMORY_IMAGE_CREATE_INFO,
+    ExternalMemoryBufferCreateInfo = EXTERNAL_MEMORY_BUFFER_CREATE_INFO,
+    ExportMemoryAllocateInfo = EXPORT_MEMORY_ALLOCATE_INFO,
+    ImportMemoryWin32HandleInfoKHR = IMPORT_MEMORY_WIN32_HANDLE_INFO_KHR,
+    ExportMemoryWin32HandleInfoKHR = EXPORT_MEMORY_WIN32_HANDLE_INFO_KHR,
+    ImportMemoryZirconHandleInfoFUCHSIA = IMPORT_MEMORY_ZIRCON_HANDLE_INFO_FUCHSIA,
+    MemoryZirconHandlePropertiesFUCHSIA = MEMORY_ZIRCON_HANDLE_PROPERTIES_FUCHSIA,
+    MemoryGetZirconHandleInfoFUCHSIA = MEMORY_GET_ZIRCON_HANDLE_INFO_FUCHSIA,
+    MemoryWin32HandlePropertiesKHR = MEMORY_WIN32_HANDLE_PROPERTIES_KHR,
+    MemoryGetWin32HandleInfoKHR = MEMORY_GET_WIN32_HANDLE_INFO_KHR,
+    ImportMemoryFdInfoKHR = IMPORT_MEMORY_FD_INFO_KHR,
+    MemoryFdPropertiesKHR = MEMORY_FD_PROPERTIES_KHR,
+    MemoryGetFdInfoKHR = MEMORY_GET_FD_INFO_KHR,
+    Win32KeyedMutexAcquireReleaseInfoKHR = WIN32_KEYED_MUTEX_ACQUIRE_RELEASE_INFO_KHR,
+    PhysicalDeviceExternalSemaphoreInfo = PHYSICAL_DEVICE_EXTERNAL_SEMAPHORE_INFO,
+    ExternalSemaphoreProperties = EXTERNAL_SEMAPHORE_PROPERTIES,
+    ExportSemaphoreCreateInfo = EXPORT_SEMAPHORE_CREATE_INFO,
+    ImportSemaphoreWin32HandleInfoKHR = IMPORT_SEMAPHORE_WIN32_HANDLE_INFO_KHR,
+    ExportSemaphoreWin32HandleInfoKHR = EXPORT_SEMAPHORE_WIN32_HANDLE_INFO_KHR,
+    D3D12FenceSubmitInfoKHR = D3D12_FENCE_SUBMIT_INFO_KHR,
+    SemaphoreGetWin32HandleInfoKHR = SEMAPHORE_GET_WIN32_HANDLE_INFO_KHR,
+    ImportSemaphoreFdInfoKHR = IMPORT_SEMAPHORE_FD_INFO_KHR,
+    SemaphoreGetFdInfoKHR = SEMAPHORE_GET_FD_INFO_KHR,
+    ImportSemaphoreZirconHandleInfoFUCHSIA = IMPORT_SEMAPHORE_ZIRCON_HANDLE_INFO_FUCHSIA,
+    SemaphoreGetZirconHandleInfoFUCHSIA = SEMAPHORE_GET_ZIRCON_HANDLE_INFO_FUCHSIA,
+    PhysicalDeviceExternalFenceInfo = PHYSICAL_DEVICE_EXTERNAL_FENCE_INFO,
+    ExternalFenceProperties = EXTERNAL_FENCE_PROPERTIES,
+    ExportFenceCreateInfo = EXPORT_FENCE_CREATE_INFO,
+    ImportFenceWin32HandleInfoKHR = IMPORT_FENCE_WIN32_HANDLE_INFO_KHR,
+    ExportFenceWin32HandleInfoKHR = EXPORT_FENCE_WIN32_HANDLE_INFO_KHR,
+    FenceGetWin32HandleInfoKHR = FENCE_GET_WIN32_HANDLE_INFO_KHR,
+    ImportFenceFdInfoKHR = IMPORT_FENCE_FD_INFO_KHR,
+    FenceGetFdInfoKHR = FENCE_GET_FD_INFO_KHR,
+    PhysicalDeviceMultiviewFeatures = PHYSICAL_DEVICE_MULTIVIEW_FEATURES,
+    PhysicalDeviceMultiviewProperties = PHYSICAL_DEVICE_MULTIVIEW_PROPERTIES,
+    RenderPassMultiviewCreateInfo = RENDER_PASS_MULTIVIEW_CREATE_INFO,
+    SurfaceCapabilities2EXT = SURFACE_CAPABILITIES_2_EXT,
+    DisplayPowerInfoEXT = DISPLAY_POWER_INFO_EXT,
+    DeviceEventInfoEXT = DEVICE_EVENT_INFO_EXT,
+    DisplayEventInfoEXT = DISPLAY_EVENT_INFO_EXT,
+    SwapchainCounterCreateInfoEXT = SWAPCHAIN_COUNTER_CREATE_INFO_EXT,
+    PhysicalDeviceGroupProperties = PHYSICAL_DEVICE_GROUP_PROPERTIES,
+    MemoryAllocateFlagsInfo = MEMORY_ALLOCATE_FLAGS_INFO,
+    BindBufferMemoryInfo = BIND_BUFFER_MEMORY_INFO,
+    BindBufferMemoryDeviceGroupInfo = BIND_BUFFER_MEMORY_DEVICE_GROUP_INFO,
+    BindImageMemoryInfo = BIND_IMAGE_MEMORY_INFO,
+    BindImageMemoryDeviceGroupInfo = BIND_IMAGE_MEMORY_DEVICE_GROUP_INFO,
+    DeviceGroupRenderPassBeginInfo = DEVICE_GROUP_RENDER_PASS_BEGIN_INFO,
+    DeviceGroupCommandBufferBeginInfo = DEVICE_GROUP_COMMAND_BUFFER_BEGIN_INFO,
+    DeviceGroupSubmitInfo = DEVICE_GROUP_SUBMIT_INFO,
+    DeviceGroupBindSparseInfo = DEVICE_GROUP_BIND_SPARSE_INFO,
+    DeviceGroupPresentCapabilitiesKHR = DEVICE_GROUP_PRESENT_CAPABILITIES_KHR,
+    ImageSwapchainCreateInfoKHR = IMAGE_SWAPCHAIN_CREATE_INFO_KHR,
+    BindImageMemorySwapchainInfoKHR = BIND_IMAGE_MEMORY_SWAPCHAIN_INFO_KHR,
+    AcquireNextImageInfoKHR = ACQUIRE_NEXT_IMAGE_INFO_KHR,
+    DeviceGroupPresentInfoKHR = DEVICE_GROUP_PRESENT_INFO_KHR,
+    DeviceGroupDeviceCreateInfo = DEVICE_GROUP_DEVICE_CREATE_INFO,
+    DeviceGroupSwapchainCreateInfoKHR = DEVICE_GROUP_SWAPCHAIN_CREATE_INFO_KHR,
+    DescriptorUpdateTemplateCreateInfo = DESCRIPTOR_UPDATE_TEMPLATE_CREATE_INFO,
+    PhysicalDevicePresentIdFeaturesKHR = PHYSICAL_DEVICE_PRESENT_ID_FEATURES_KHR,
+    PresentIdKHR = PRESENT_ID_KHR,
+    PhysicalDevicePresentWaitFeaturesKHR = PHYSICAL_DEVICE_PRESENT_WAIT_FEATURES_KHR,
+    HdrMetadataEXT = HDR_METADATA_EXT,
+    DisplayNativeHdrSurfaceCapabilitiesAMD = DISPLAY_NATIVE_HDR_SURFACE_CAPABILITIES_AMD,
+    SwapchainDisplayNativeHdrCreateInfoAMD = SWAPCHAIN_DISPLAY_NATIVE_HDR_CREATE_INFO_AMD,
+    PresentTimesInfoGOOGLE = PRESENT_TIMES_INFO_GOOGLE,
+    IOSSurfaceCreateInfoMVK = IOS_SURFACE_CREATE_INFO_MVK,
+    MacOSSurfaceCreateInfoMVK = MACOS_SURFACE_CREATE_INFO_MVK,
+    MetalSurfaceCreateInfoEXT = METAL_SURFACE_CREATE_INFO_EXT,
+    PipelineViewportWScalingStateCreateInfoNV = PIPELINE_VIEWPORT_W_SCALING_STATE_CREATE_INFO_NV,
+    PipelineViewportSwizzleStateCreateInfoNV = PIPELINE_VIEWPORT_SWIZZLE_STATE_CREATE_INFO_NV,
+    PhysicalDeviceDiscardRectanglePropertiesEXT = PHYSICAL_DEVICE_DISCARD_RECTANGLE_PROPERTIES_EXT,
+    PipelineDiscardRectangleStateCreateInfoEXT = PIPELINE_DISCARD_RECTANGLE_STATE_CREATE_INFO_EXT,
+    PhysicalDeviceMultiviewPerViewAttributesPropertiesNVX = PHYSICAL_DEVICE_MULTIVIEW_PER_VIEW_ATTRIBUTES_PROPERTIES_NVX,
+    RenderPassInputAttachmentAspectCreateInfo = RENDER_PASS_INPUT_ATTACHMENT_ASPECT_CREATE_INFO,
+    PhysicalDeviceSurfaceInfo2KHR = PHYSICAL_DEVICE_SURFACE_INFO_2_KHR,
+    SurfaceCapabilities2KHR = SURFACE_CAPABILITIES_2_KHR,
+    SurfaceFormat2KHR = SURFACE_FORMAT_2_KHR,
+    DisplayProperties2KHR = DISPLAY_PROPERTIES_2_KHR,
+    DisplayPlaneProperties2KHR = DISPLAY_PLANE_PROPERTIES_2_KHR,
+    DisplayModeProperties2KHR = DISPLAY_MODE_PROPERTIES_2_KHR,
+    DisplayPlaneInfo2KHR = DISPLAY_PLANE_INFO_2_KHR,
+    DisplayPlaneCapabilities2KHR = DISPLAY_PLANE_CAPABILITIES_2_KHR,
+    SharedPresentSurfaceCapabilitiesKHR = SHARED_PRESENT_SURFACE_CAPABILITIES_KHR,
+    PhysicalDevice16BitStorageFeatures = PHYSICAL_DEVICE_16BIT_STORAGE_FEATURES,
+    PhysicalDeviceSubgroupProperties = PHYSICAL_DEVICE_SUBGROUP_PROPERTIES,
+    PhysicalDeviceShaderSubgroupExtendedTypesFeatures = PHYSICAL_DEVICE_SHADER_SUBGROUP_EXTENDED_TYPES_FEATURES,
+    BufferMemoryRequirementsInfo2 = BUFFER_MEMORY_REQUIREMENTS_INFO_2,
+    DeviceBufferMemoryRequirements = DEVICE_BUFFER_MEMORY_REQUIREMENTS,
+    ImageMemoryRequirementsInfo2 = IMAGE_MEMORY_REQUIREMENTS_INFO_2,
+    ImageSparseMemoryRequirementsInfo2 = IMAGE_SPARSE_MEMORY_REQUIREMENTS_INFO_2,
+    DeviceImageMemoryRequirements = DEVICE_IMAGE_MEMORY_REQUIREMENTS,
+    MemoryRequirements2 = MEMORY_REQUIREMENTS_2,
+    SparseImageMemoryRequirements2 = SPARSE_IMAGE_MEMORY_REQUIREMENTS_2,
+    PhysicalDevicePointClippingProperties = PHYSICAL_DEVICE_POINT_CLIPPING_PROPERTIES,
+    MemoryDedicatedRequirements = MEMORY_DEDICATED_REQUIREMENTS,
+    MemoryDedicatedAllocateInfo = MEMORY_DEDICATED_ALLOCATE_INFO,
+    ImageViewUsageCreateInfo = IMAGE_VIEW_USAGE_CREATE_INFO,
+    PipelineTessellationDomainOriginStateCreateInfo = PIPELINE_TESSELLATION_DOMAIN_ORIGIN_STATE_CREATE_INFO,
+    SamplerYcbcrConversionInfo = SAMPLER_YCBCR_CONVERSION_INFO,
+    SamplerYcbcrConversionCreateInfo = SAMPLER_YCBCR_CONVERSION_CREATE_INFO,
+    BindImagePlaneMemoryInfo = BIND_IMAGE_PLANE_MEMORY_INFO,
+    ImagePlaneMemoryRequirementsInfo = IMAGE_PLANE_MEMORY_REQUIREMENTS_INFO,
+    PhysicalDeviceSamplerYcbcrConversionFeatures = PHYSICAL_DEVICE_SAMPLER_YCBCR_CONVERSION_FEATURES,
+    SamplerYcbcrConversionImageFormatProperties = SAMPLER_YCBCR_CONVERSION_IMAGE_FORMAT_PROPERTIES,
+    TextureLODGatherFormatPropertiesAMD = TEXTURE_LOD_GATHER_FORMAT_PROPERTIES_AMD,
+    ConditionalRenderingBeginInfoEXT = CONDITIONAL_RENDERING_BEGIN_INFO_EXT,
+    ProtectedSubmitInfo = PROTECTED_SUBMIT_INFO,
+    PhysicalDeviceProtectedMemoryFeatures = PHYSICAL_DEVICE_PROTECTED_MEMORY_FEATURES,
+    PhysicalDeviceProtectedMemoryProperties = PHYSICAL_DEVICE_PROTECTED_MEMORY_PROPERTIES,
+    DeviceQueueInfo2 = DEVICE_QUEUE_INFO_2,
+    PipelineCoverageToColorStateCreateInfoNV = PIPELINE_COVERAGE_TO_COLOR_STATE_CREATE_INFO_NV,
+    PhysicalDeviceSamplerFilterMinmaxProperties = PHYSICAL_DEVICE_SAMPLER_FILTER_MINMAX_PROPERTIES,
+    SampleLocationsInfoEXT = SAMPLE_LOCATIONS_INFO_EXT,
+    RenderPassSampleLocationsBeginInfoEXT = RENDER_PASS_SAMPLE_LOCATIONS_BEGIN_INFO_EXT,
+    PipelineSampleLocationsStateCreateInfoEXT = PIPELINE_SAMPLE_LOCATIONS_STATE_CREATE_INFO_EXT,
+    PhysicalDeviceSampleLocationsPropertiesEXT = PHYSICAL_DEVICE_SAMPLE_LOCATIONS_PROPERTIES_EXT,
+    MultisamplePropertiesEXT = MULTISAMPLE_PROPERTIES_EXT,
+    SamplerReductionModeCreateInfo = SAMPLER_REDUCTION_MODE_CREATE_INFO,
+    PhysicalDeviceBlendOperationAdvancedFeaturesEXT = PHYSICAL_DEVICE_BLEND_OPERATION_ADVANCED_FEATURES_EXT,
+    PhysicalDeviceMultiDrawFeaturesEXT = PHYSICAL_DEVICE_MULTI_DRAW_FEATURES_EXT,
+    PhysicalDeviceBlendOperationAdvancedPropertiesEXT = PHYSICAL_DEVICE_BLEND_OPERATION_ADVANCED_PROPERTIES_EXT,
+    PipelineColorBlendAdvancedStateCreateInfoEXT = PIPELINE_COLOR_BLEND_ADVANCED_STATE_CREATE_INFO_EXT,
+    PhysicalDeviceInlineUniformBlockFeatures = PHYSICAL_DEVICE_INLINE_UNIFORM_BLOCK_FEATURES,
+    PhysicalDeviceInlineUniformBlockProperties = PHYSICAL_DEVICE_INLINE_UNIFORM_BLOCK_PROPERTIES,
+    WriteDescriptorSetInlineUniformBlock = WRITE_DESCRIPTOR_SET_INLINE_UNIFORM_BLOCK,
+    DescriptorPoolInlineUniformBlockCreateInfo = DESCRIPTOR_POOL_INLINE_UNIFORM_BLOCK_CREATE_INFO,
+    PipelineCoverageModulationStateCreateInfoNV = PIPELINE_COVERAGE_MODULATION_STATE_CREATE_INFO_NV,
+    ImageFormatListCreateInfo = IMAGE_FORMAT_LIST_CREATE_INFO,
+    ValidationCacheCreateInfoEXT = VALIDATION_CACHE_CREATE_INFO_EXT,
+    ShaderModuleValidationCacheCreateInfoEXT = SHADER_MODULE_VALIDATION_CACHE_CREATE_INFO_EXT,
+    PhysicalDeviceMaintenance3Properties = PHYSICAL_DEVICE_MAINTENANCE_3_PROPERTIES,
+    PhysicalDeviceMaintenance4Features = PHYSICAL_DEVICE_MAINTENANCE_4_FEATURES,
+    PhysicalDeviceMaintenance4Properties = PHYSICAL_DEVICE_MAINTENANCE_4_PROPERTIES,
+    DescriptorSetLayoutSupport = DESCRIPTOR_SET_LAYOUT_SUPPORT,
+    PhysicalDeviceShaderDrawParametersFeatures = PHYSICAL_DEVICE_SHADER_DRAW_PARAMETERS_FEATURES,
+    PhysicalDeviceShaderFloat16Int8Features = PHYSICAL_DEVICE_SHADER_FLOAT16_INT8_FEATURES,
+    PhysicalDeviceFloatControlsProperties = PHYSICAL_DEVICE_FLOAT_CONTROLS_PROPERTIES,
+    PhysicalDeviceHostQueryResetFeatures = PHYSICAL_DEVICE_HOST_QUERY_RESET_FEATURES,
+    NativeBufferANDROID = NATIVE_BUFFER_ANDROID,
+    SwapchainImageCreateInfoANDROID = SWAPCHAIN_IMAGE_CREATE_INFO_ANDROID,
+    PhysicalDevicePresentationPropertiesANDROID = PHYSICAL_DEVICE_PRESENTATION_PROPERTIES_ANDROID,
+    DeviceQueueGlobalPriorityCreateInfoKHR = DEVICE_QUEUE_GLOBAL_PRIORITY_CREATE_INFO_KHR,
+    PhysicalDeviceGlobalPriorityQueryFeaturesKHR = PHYSICAL_DEVICE_GLOBAL_PRIORITY_QUERY_FEATURES_KHR,
+    QueueFamilyGlobalPriorityPropertiesKHR = QUEUE_FAMILY_GLOBAL_PRIORITY_PROPERTIES_KHR,
+    DebugUtilsObjectNameInfoEXT = DEBUG_UTILS_OBJECT_NAME_INFO_EXT,
+    DebugUtilsObjectTagInfoEXT = DEBUG_UTILS_OBJECT_TAG_INFO_EXT,
+    DebugUtilsLabelEXT = DEBUG_UTILS_LABEL_EXT,
+    DebugUtilsMessengerCreateInfoEXT = DEBUG_UTILS_MESSENGER_CREATE_INFO_EXT,
+    DebugUtilsMessengerCallbackDataEXT = DEBUG_UTILS_MESSENGER_CALLBACK_DATA_EXT,
+    PhysicalDeviceDeviceMemoryReportFeaturesEXT = PHYSICAL_DEVICE_DEVICE_MEMORY_REPORT_FEATURES_EXT,
+    DeviceDeviceMemoryReportCreateInfoEXT = DEVICE_DEVICE_MEMORY_REPORT_CREATE_INFO_EXT,
+    DeviceMemoryReportCallbackDataEXT = DEVICE_MEMORY_REPORT_CALLBACK_DATA_EXT,
+    ImportMemoryHostPointerInfoEXT = IMPORT_MEMORY_HOST_POINTER_INFO_EXT,
+    MemoryHostPointerPropertiesEXT = MEMORY_HOST_POINTER_PROPERTIES_EXT,
+    PhysicalDeviceExternalMemoryHostPropertiesEXT = PHYSICAL_DEVICE_EXTERNAL_MEMORY_HOST_PROPERTIES_EXT,
+    PhysicalDeviceConservativeRasterizationPropertiesEXT = PHYSICAL_DEVICE_CONSERVATIVE_RASTERIZATION_PROPERTIES_EXT,
+    CalibratedTimestampInfoEXT = CALIBRATED_TIMESTAMP_INFO_EXT,
+    PhysicalDeviceShaderCorePropertiesAMD = PHYSICAL_DEVICE_SHADER_CORE_PROPERTIES_AMD,
+    PhysicalDeviceShaderCoreProperties2AMD = PHYSICAL_DEVICE_SHADER_CORE_PROPERTIES_2_AMD,
+    PipelineRasterizationConservativeStateCreateInfoEXT = PIPELINE_RASTERIZATION_CONSERVATIVE_STATE_CREATE_INFO_EXT,
+    PhysicalDeviceDescriptorIndexingFeatures = PHYSICAL_DEVICE_DESCRIPTOR_INDEXING_FEATURES,
+    PhysicalDeviceDescriptorIndexingProperties = PHYSICAL_DEVICE_DESCRIPTOR_INDEXING_PROPERTIES,
+    DescriptorSetLayoutBindingFlagsCreateInfo = DESCRIPTOR_SET_LAYOUT_BINDING_FLAGS_CREATE_INFO,
+    DescriptorSetVariableDescriptorCountAllocateInfo = DESCRIPTOR_SET_VARIABLE_DESCRIPTOR_COUNT_ALLOCATE_INFO,
+    DescriptorSetVariableDescriptorCountLayoutSupport = DESCRIPTOR_SET_VARIABLE_DESCRIPTOR_COUNT_LAYOUT_SUPPORT,
+    AttachmentDescription2 = ATTACHMENT_DESCRIPTION_2,
+    AttachmentReference2 = ATTACHMENT_REFERENCE_2,
+    SubpassDescription2 = SUBPASS_DESCRIPTION_2,
+    SubpassDependency2 = SUBPASS_DEPENDENCY_2,
+    RenderPassCreateInfo2 = RENDER_PASS_CREATE_INFO_2,
+    SubpassBeginInfo = SUBPASS_BEGIN_INFO,
+    SubpassEndInfo = SUBPASS_END_INFO,
+    PhysicalDeviceTimelineSemaphoreFeatures = PHYSICAL_DEVICE_TIMELINE_SEMAPHORE_FEATURES,
+    PhysicalDeviceTimelineSemaphoreProperties = PHYSICAL_DEVICE_TIMELINE_SEMAPHORE_PROPERTIES,
+    SemaphoreTypeCreateInfo = SEMAPHORE_TYPE_CREATE_INFO,
+    TimelineSemaphoreSubmitInfo = TIMELINE_SEMAPHORE_SUBMIT_INFO,
+    SemaphoreWaitInfo = SEMAPHORE_WAIT_INFO,
+    SemaphoreSignalInfo = SEMAPHORE_SIGNAL_INFO,
+    PipelineVertexInputDivisorStateCreateInfoEXT = PIPELINE_VERTEX_INPUT_DIVISOR_STATE_CREATE_INFO_EXT,
+    PhysicalDeviceVertexAttributeDivisorPropertiesEXT = PHYSICAL_DEVICE_VERTEX_ATTRIBUTE_DIVISOR_PROPERTIES_EXT,
+    PhysicalDevicePCIBusInfoPropertiesEXT = PHYSICAL_DEVICE_PCI_BUS_INFO_PROPERTIES_EXT,
+    ImportAndroidHardwareBufferInfoANDROID = IMPORT_ANDROID_HARDWARE_BUFFER_INFO_ANDROID,
+    AndroidHardwareBufferUsageANDROID = ANDROID_HARDWARE_BUFFER_USAGE_ANDROID,
+    AndroidHardwareBufferPropertiesANDROID = ANDROID_HARDWARE_BUFFER_PROPERTIES_ANDROID,
+    MemoryGetAndroidHardwareBufferInfoANDROID = MEMORY_GET_ANDROID_HARDWARE_BUFFER_INFO_ANDROID,
+    AndroidHardwareBufferFormatPropertiesANDROID = ANDROID_HARDWARE_BUFFER_FORMAT_PROPERTIES_ANDROID,
+    CommandBufferInheritanceConditionalRenderingInfoEXT = COMMAND_BUFFER_INHERITANCE_CONDITIONAL_RENDERING_INFO_EXT,
+    ExternalFormatANDROID = EXTERNAL_FORMAT_ANDROID,
+    PhysicalDevice8BitStorageFeatures = PHYSICAL_DEVICE_8BIT_STORAGE_FEATURES,
+    PhysicalDeviceConditionalRenderingFeaturesEXT = PHYSICAL_DEVICE_CONDITIONAL_RENDERING_FEATURES_EXT,
+    PhysicalDeviceVulkanMemoryModelFeatures = PHYSICAL_DEVICE_VULKAN_MEMORY_MODEL_FEATURES,
+    PhysicalDeviceShaderAtomicInt64Features = PHYSICAL_DEVICE_SHADER_ATOMIC_INT64_FEATURES,
+    PhysicalDeviceShaderAtomicFloatFeaturesEXT = PHYSICAL_DEVICE_SHADER_ATOMIC_FLOAT_FEATURES_EXT,
+    PhysicalDeviceShaderAtomicFloat2FeaturesEXT = PHYSICAL_DEVICE_SHADER_ATOMIC_FLOAT_2_FEATURES_EXT,
+    PhysicalDeviceVertexAttributeDivisorFeaturesEXT = PHYSICAL_DEVICE_VERTEX_ATTRIBUTE_DIVISOR_FEATURES_EXT,
+    QueueFamilyCheckpointPropertiesNV = QUEUE_FAMILY_CHECKPOINT_PROPERTIES_NV,
+    CheckpointDataNV = CHECKPOINT_DATA_NV,
+    PhysicalDeviceDepthStencilResolveProperties = PHYSICAL_DEVICE_DEPTH_STENCIL_RESOLVE_PROPERTIES,
+    SubpassDescriptionDepthStencilResolve = SUBPASS_DESCRIPTION_DEPTH_STENCIL_RESOLVE,
+    ImageViewASTCDecodeModeEXT = IMAGE_VIEW_ASTC_DECODE_MODE_EXT,
+    PhysicalDeviceASTCDecodeFeaturesEXT = PHYSICAL_DEVICE_ASTC_DECODE_FEATURES_EXT,
+    PhysicalDeviceTransformFeedbackFeaturesEXT = PHYSICAL_DEVICE_TRANSFORM_FEEDBACK_FEATURES_EXT,
+    PhysicalDeviceTransformFeedbackPropertiesEXT = PHYSICAL_DEVICE_TRANSFORM_FEEDBACK_PROPERTIES_EXT,
+    PipelineRasterizationStateStreamCreateInfoEXT = PIPELINE_RASTERIZATION_STATE_STREAM_CREATE_INFO_EXT,
+    PhysicalDeviceRepresentativeFragmentTestFeaturesNV = PHYSICAL_DEVICE_REPRESENTATIVE_FRAGMENT_TEST_FEATURES_NV,
+    PipelineRepresentativeFragmentTestStateCreateInfoNV = PIPELINE_REPRESENTATIVE_FRAGMENT_TEST_STATE_CREATE_INFO_NV,
+    PhysicalDeviceExclusiveScissorFeaturesNV = PHYSICAL_DEVICE_EXCLUSIVE_SCISSOR_FEATURES_NV,
+    PipelineViewportExclusiveScissorStateCreateInfoNV = PIPELINE_VIEWPORT_EXCLUSIVE_SCISSOR_STATE_CREATE_INFO_NV,
+    PhysicalDeviceCornerSampledImageFeaturesNV = PHYSICAL_DEVICE_CORNER_SAMPLED_IMAGE_FEATURES_NV,
+    PhysicalDeviceComputeShaderDerivativesFeaturesNV = PHYSICAL_DEVICE_COMPUTE_SHADER_DERIVATIVES_FEATURES_NV,
+    PhysicalDeviceShaderImageFootprintFeaturesNV = PHYSICAL_DEVICE_SHADER_IMAGE_FOOTPRINT_FEATURES_NV,
+    PhysicalDeviceDedicatedAllocationImageAliasingFeaturesNV = PHYSICAL_DEVICE_DEDICATED_ALLOCATION_IMAGE_ALIASING_FEATURES_NV,
+    PipelineViewportShadingRateImageStateCreateInfoNV = PIPELINE_VIEWPORT_SHADING_RATE_IMAGE_STATE_CREATE_INFO_NV,
+    PhysicalDeviceShadingRateImageFeaturesNV = PHYSICAL_DEVICE_SHADING_RATE_IMAGE_FEATURES_NV,
+    PhysicalDeviceShadingRateImagePropertiesNV = PHYSICAL_DEVICE_SHADING_RATE_IMAGE_PROPERTIES_NV,
+    PhysicalDeviceInvocationMaskFeaturesHUAWEI = PHYSICAL_DEVICE_INVOCATION_MASK_FEATURES_HUAWEI,
+    PipelineViewportCoarseSampleOrderStateCreateInfoNV = PIPELINE_VIEWPORT_COARSE_SAMPLE_ORDER_STATE_CREATE_INFO_NV,
+    PhysicalDeviceMeshShaderFeaturesNV = PHYSICAL_DEVICE_MESH_SHADER_FEATURES_NV,
+    PhysicalDeviceMeshShaderPropertiesNV = PHYSICAL_DEVICE_MESH_SHADER_PROPERTIES_NV,
+    PhysicalDeviceMeshShaderFeaturesEXT = PHYSICAL_DEVICE_MESH_SHADER_FEATURES_EXT,
+    PhysicalDeviceMeshShaderPropertiesEXT = PHYSICAL_DEVICE_MESH_SHADER_PROPERTIES_EXT,
+    RayTracingShaderGroupCreateInfoNV = RAY_TRACING_SHADER_GROUP_CREATE_INFO_NV,
+    RayTracingShaderGroupCreateInfoKHR = RAY_TRACING_SHADER_GROUP_CREATE_INFO_KHR,
+    RayTracingPipelineCreateInfoNV = RAY_TRACING_PIPELINE_CREATE_INFO_NV,
+    RayTracingPipelineCreateInfoKHR = RAY_TRACING_PIPELINE_CREATE_INFO_KHR,
+    GeometryTrianglesNV = GEOMETRY_TRIANGLES_NV,
+    GeometryAABBNV = GEOMETRY_AABB_NV,
+    GeometryNV = GEOMETRY_NV,
+    AccelerationStructureInfoNV = ACCELERATION_STRUCTURE_INFO_NV,
+    AccelerationStructureCreateInfoNV = ACCELERATION_STRUCTURE_CREATE_INFO_NV,
+    BindAccelerationStructureMemoryInfoNV = BIND_ACCELERATION_STRUCTURE_MEMORY_INFO_NV,
+    WriteDescriptorSetAccelerationStructureKHR = WRITE_DESCRIPTOR_SET_ACCELERATION_STRUCTURE_KHR,
+    WriteDescriptorSetAccelerationStructureNV = WRITE_DESCRIPTOR_SET_ACCELERATION_STRUCTURE_NV,
+    AccelerationStructureMemoryRequirementsInfoNV = ACCELERATION_STRUCTURE_MEMORY_REQUIREMENTS_INFO_NV,
+    PhysicalDeviceAccelerationStructureFeaturesKHR = PHYSICAL_DEVICE_ACCELERATION_STRUCTURE_FEATURES_KHR,
+    PhysicalDeviceRayTracingPipelineFeaturesKHR = PHYSICAL_DEVICE_RAY_TRACING_PIPELINE_FEATURES_KHR,
+    PhysicalDeviceRayQueryFeaturesKHR = PHYSICAL_DEVICE_RAY_QUERY_FEATURES_KHR,
+    PhysicalDeviceAccelerationStructurePropertiesKHR = PHYSICAL_DEVICE_ACCELERATION_STRUCTURE_PROPERTIES_KHR,
+    PhysicalDeviceRayTracingPipelinePropertiesKHR = PHYSICAL_DEVICE_RAY_TRACING_PIPELINE_PROPERTIES_KHR,
+    PhysicalDeviceRayTracingPropertiesNV = PHYSICAL_DEVICE_RAY_TRACING_PROPERTIES_NV,
+    PhysicalDeviceRayTracingMaintenance1FeaturesKHR = PHYSICAL_DEVICE_RAY_TRACING_MAINTENANCE_1_FEATURES_KHR,
+    DrmFormatModifierPropertiesListEXT = DRM_FORMAT_MODIFIER_PROPERTIES_LIST_EXT,
+    PhysicalDeviceImageDrmFormatModifierInfoEXT = PHYSICAL_DEVICE_IMAGE_DRM_FORMAT_MODIFIER_INFO_EXT,
+    ImageDrmFormatModifierListCreateInfoEXT = IMAGE_DRM_FORMAT_MODIFIER_LIST_CREATE_INFO_EXT,
+    ImageDrmFormatModifierExplicitCreateInfoEXT = IMAGE_DRM_FORMAT_MODIFIER_EXPLICIT_CREATE_INFO_EXT,
+    ImageDrmFormatModifierPropertiesEXT = IMAGE_DRM_FORMAT_MODIFIER_PROPERTIES_EXT,
+    ImageStencilUsageCreateInfo = IMAGE_STENCIL_USAGE_CREATE_INFO,
+    DeviceMemoryOverallocationCreateInfoAMD = DEVICE_MEMORY_OVERALLOCATION_CREATE_INFO_AMD,
+    PhysicalDeviceFragmentDensityMapFeaturesEXT = PHYSICAL_DEVICE_FRAGMENT_DENSITY_MAP_FEATURES_EXT,
+    PhysicalDeviceFragmentDensityMap2FeaturesEXT = PHYSICAL_DEVICE_FRAGMENT_DENSITY_MAP_2_FEATURES_EXT,
+    PhysicalDeviceFragmentDensityMapOffsetFeaturesQCOM = PHYSICAL_DEVICE_FRAGMENT_DENSITY_MAP_OFFSET_FEATURES_QCOM,
+    PhysicalDeviceFragmentDensityMapPropertiesEXT = PHYSICAL_DEVICE_FRAGMENT_DENSITY_MAP_PROPERTIES_EXT,
+    PhysicalDeviceFragmentDensityMap2PropertiesEXT = PHYSICAL_DEVICE_FRAGMENT_DENSITY_MAP_2_PROPERTIES_EXT,
+    PhysicalDeviceFragmentDensityMapOffsetPropertiesQCOM = PHYSICAL_DEVICE_FRAGMENT_DENSITY_MAP_OFFSET_PROPERTIES_QCOM,
+    RenderPassFragmentDensityMapCreateInfoEXT = RENDER_PASS_FRAGMENT_DENSITY_MAP_CREATE_INFO_EXT,
+    SubpassFragmentDensityMapOffsetEndInfoQCOM = SUBPASS_FRAGMENT_DENSITY_MAP_OFFSET_END_INFO_QCOM,
+    PhysicalDeviceScalarBlockLayoutFeatures = PHYSICAL_DEVICE_SCALAR_BLOCK_LAYOUT_FEATURES,
+    SurfaceProtectedCapabilitiesKHR = SURFACE_PROTECTED_CAPABILITIES_KHR,
+    PhysicalDeviceUniformBufferStandardLayoutFeatures = PHYSICAL_DEVICE_UNIFORM_BUFFER_STANDARD_LAYOUT_FEATURES,
+    PhysicalDeviceDepthClipEnableFeaturesEXT = PHYSICAL_DEVICE_DEPTH_CLIP_ENABLE_FEATURES_EXT,
+    PipelineRasterizationDepthClipStateCreateInfoEXT = PIPELINE_RASTERIZATION_DEPTH_CLIP_STATE_CREATE_INFO_EXT,
+    PhysicalDeviceMemoryBudgetPropertiesEXT = PHYSICAL_DEVICE_MEMORY_BUDGET_PROPERTIES_EXT,
+    PhysicalDeviceMemoryPriorityFeaturesEXT = PHYSICAL_DEVICE_MEMORY_PRIORITY_FEATURES_EXT,
+    MemoryPriorityAllocateInfoEXT = MEMORY_PRIORITY_ALLOCATE_INFO_EXT,
+    PhysicalDevicePageableDeviceLocalMemoryFeaturesEXT = PHYSICAL_DEVICE_PAGEABLE_DEVICE_LOCAL_MEMORY_FEATURES_EXT,
+    PhysicalDeviceBufferDeviceAddressFeatures = PHYSICAL_DEVICE_BUFFER_DEVICE_ADDRESS_FEATURES,
+    PhysicalDeviceBufferDeviceAddressFeaturesEXT = PHYSICAL_DEVICE_BUFFER_DEVICE_ADDRESS_FEATURES_EXT,
+    BufferDeviceAddressInfo = BUFFER_DEVICE_ADDRESS_INFO,
+    BufferOpaqueCaptureAddressCreateInfo = BUFFER_OPAQUE_CAPTURE_ADDRESS_CREATE_INFO,
+    BufferDeviceAddressCreateInfoEXT = BUFFER_DEVICE_ADDRESS_CREATE_INFO_EXT,
+    PhysicalDeviceImageViewImageFormatInfoEXT = PHYSICAL_DEVICE_IMAGE_VIEW_IMAGE_FORMAT_INFO_EXT,
+    FilterCubicImageViewImageFormatPropertiesEXT = FILTER_CUBIC_IMAGE_VIEW_IMAGE_FORMAT_PROPERTIES_EXT,
+    PhysicalDeviceImagelessFramebufferFeatures = PHYSICAL_DEVICE_IMAGELESS_FRAMEBUFFER_FEATURES,
+    FramebufferAttachmentsCreateInfo = FRAMEBUFFER_ATTACHMENTS_CREATE_INFO,
+    FramebufferAttachmentImageInfo = FRAMEBUFFER_ATTACHMENT_IMAGE_INFO,
+    RenderPassAttachmentBeginInfo = RENDER_PASS_ATTACHMENT_BEGIN_INFO,
+    PhysicalDeviceTextureCompressionASTCHDRFeatures = PHYSICAL_DEVICE_TEXTURE_COMPRESSION_ASTC_HDR_FEATURES,
+    PhysicalDeviceCooperativeMatrixFeaturesNV = PHYSICAL_DEVICE_COOPERATIVE_MATRIX_FEATURES_NV,
+    PhysicalDeviceCooperativeMatrixPropertiesNV = PHYSICAL_DEVICE_COOPERATIVE_MATRIX_PROPERTIES_NV,
+    CooperativeMatrixPropertiesNV = COOPERATIVE_MATRIX_PROPERTIES_NV,
+    PhysicalDeviceYcbcrImageArraysFeaturesEXT = PHYSICAL_DEVICE_YCBCR_IMAGE_ARRAYS_FEATURES_EXT,
+    ImageViewHandleInfoNVX = IMAGE_VIEW_HANDLE_INFO_NVX,
+    ImageViewAddressPropertiesNVX = IMAGE_VIEW_ADDRESS_PROPERTIES_NVX,
+    PresentFrameTokenGGP = PRESENT_FRAME_TOKEN_GGP,
+    PipelineCreationFeedbackCreateInfo = PIPELINE_CREATION_FEEDBACK_CREATE_INFO,
+    SurfaceFullScreenExclusiveInfoEXT = SURFACE_FULL_SCREEN_EXCLUSIVE_INFO_EXT,
+    SurfaceFullScreenExclusiveWin32InfoEXT = SURFACE_FULL_SCREEN_EXCLUSIVE_WIN32_INFO_EXT,
+    SurfaceCapabilitiesFullScreenExclusiveEXT = SURFACE_CAPABILITIES_FULL_SCREEN_EXCLUSIVE_EXT,
+    PhysicalDevicePresentBarrierFeaturesNV = PHYSICAL_DEVICE_PRESENT_BARRIER_FEATURES_NV,
+    SurfaceCapabilitiesPresentBarrierNV = SURFACE_CAPABILITIES_PRESENT_BARRIER_NV,
+    SwapchainPresentBarrierCreateInfoNV = SWAPCHAIN_PRESENT_BARRIER_CREATE_INFO_NV,
+    PhysicalDevicePerformanceQueryFeaturesKHR = PHYSICAL_DEVICE_PERFORMANCE_QUERY_FEATURES_KHR,
+    PhysicalDevicePerformanceQueryPropertiesKHR = PHYSICAL_DEVICE_PERFORMANCE_QUERY_PROPERTIES_KHR,
+    PerformanceCounterKHR = PERFORMANCE_COUNTER_KHR,
+    PerformanceCounterDescriptionKHR = PERFORMANCE_COUNTER_DESCRIPTION_KHR,
+    QueryPoolPerformanceCreateInfoKHR = QUERY_POOL_PERFORMANCE_CREATE_INFO_KHR,
+    AcquireProfilingLockInfoKHR = ACQUIRE_PROFILING_LOCK_INFO_KHR,
+    PerformanceQuerySubmitInfoKHR = PERFORMANCE_QUERY_SUBMIT_INFO_KHR,
+    HeadlessSurfaceCreateInfoEXT = HEADLESS_SURFACE_CREATE_INFO_EXT,
+    PhysicalDeviceCoverageReductionModeFeaturesNV = PHYSICAL_DEVICE_COVERAGE_REDUCTION_MODE_FEATURES_NV,
+    PipelineCoverageReductionStateCreateInfoNV = PIPELINE_COVERAGE_REDUCTION_STATE_CREATE_INFO_NV,
+    FramebufferMixedSamplesCombinationNV = FRAMEBUFFER_MIXED_SAMPLES_COMBINATION_NV,
+    PhysicalDeviceShaderIntegerFunctions2FeaturesINTEL = PHYSICAL_DEVICE_SHADER_INTEGER_FUNCTIONS_2_FEATURES_INTEL,
+    InitializePerformanceApiInfoINTEL = INITIALIZE_PERFORMANCE_API_INFO_INTEL,
+    QueryPoolPerformanceQueryCreateInfoINTEL = QUERY_POOL_PERFORMANCE_QUERY_CREATE_INFO_INTEL,
+    PerformanceMarkerInfoINTEL = PERFORMANCE_MARKER_INFO_INTEL,
+    PerformanceStreamMarkerInfoINTEL = PERFORMANCE_STREAM_MARKER_INFO_INTEL,
+    PerformanceOverrideInfoINTEL = PERFORMANCE_OVERRIDE_INFO_INTEL,
+    PerformanceConfigurationAcquireInfoINTEL = PERFORMANCE_CONFIGURATION_ACQUIRE_INFO_INTEL,
+    PhysicalDeviceShaderClockFeaturesKHR = PHYSICAL_DEVICE_SHADER_CLOCK_FEATURES_KHR,
+    PhysicalDeviceIndexTypeUint8FeaturesEXT = PHYSICAL_DEVICE_INDEX_TYPE_UINT8_FEATURES_EXT,
+    PhysicalDeviceShaderSMBuiltinsPropertiesNV = PHYSICAL_DEVICE_SHADER_SM_BUILTINS_PROPERTIES_NV,
+    PhysicalDeviceShaderSMBuiltinsFeaturesNV = PHYSICAL_DEVICE_SHADER_SM_BUILTINS_FEATURES_NV,
+    PhysicalDeviceFragmentShaderInterlockFeaturesEXT = PHYSICAL_DEVICE_FRAGMENT_SHADER_INTERLOCK_FEATURES_EXT,
+    PhysicalDeviceSeparateDepthStencilLayoutsFeatures = PHYSICAL_DEVICE_SEPARATE_DEPTH_STENCIL_LAYOUTS_FEATURES,
+    AttachmentReferenceStencilLayout = ATTACHMENT_REFERENCE_STENCIL_LAYOUT,
+    PhysicalDevicePrimitiveTopologyListRestartFeaturesEXT = PHYSICAL_DEVICE_PRIMITIVE_TOPOLOGY_LIST_RESTART_FEATURES_EXT,
+    AttachmentDescriptionStencilLayout = ATTACHMENT_DESCRIPTION_STENCIL_LAYOUT,
+    PhysicalDevicePipelineExecutablePropertiesFeaturesKHR = PHYSICAL_DEVICE_PIPELINE_EXECUTABLE_PROPERTIES_FEATURES_KHR,
+    PipelineInfoKHR = PIPELINE_INFO_KHR,
+    PipelineExecutablePropertiesKHR = PIPELINE_EXECUTABLE_PROPERTIES_KHR,
+    PipelineExecutableInfoKHR = PIPELINE_EXECUTABLE_INFO_KHR,
+    PipelineExecutableStatisticKHR = PIPELINE_EXECUTABLE_STATISTIC_KHR,
+    PipelineExecutableInternalRepresentationKHR = PIPELINE_EXECUTABLE_INTERNAL_REPRESENTATION_KHR,
+    PhysicalDeviceShaderDemoteToHelperInvocationFeatures = PHYSICAL_DEVICE_SHADER_DEMOTE_TO_HELPER_INVOCATION_FEATURES,
+    PhysicalDeviceTexelBufferAlignmentFeaturesEXT = PHYSICAL_DEVICE_TEXEL_BUFFER_ALIGNMENT_FEATURES_EXT,
+    PhysicalDeviceTexelBufferAlignmentProperties = PHYSICAL_DEVICE_TEXEL_BUFFER_ALIGNMENT_PROPERTIES,
+    PhysicalDeviceSubgroupSizeControlFeatures = PHYSICAL_DEVICE_SUBGROUP_SIZE_CONTROL_FEATURES,
+    PhysicalDeviceSubgroupSizeControlProperties = PHYSICAL_DEVICE_SUBGROUP_SIZE_CONTROL_PROPERTIES,
+    PipelineShaderStageRequiredSubgroupSizeCreateInfo = PIPELINE_SHADER_STAGE_REQUIRED_SUBGROUP_SIZE_CREATE_INFO,
+    SubpassShadingPipelineCreateInfoHUAWEI = SUBPASS_SHADING_PIPELINE_CREATE_INFO_HUAWEI,
+    PhysicalDeviceSubpassShadingPropertiesHUAWEI = PHYSICAL_DEVICE_SUBPASS_SHADING_PROPERTIES_HUAWEI,
+    MemoryOpaqueCaptureAddressAllocateInfo = MEMORY_OPAQUE_CAPTURE_ADDRESS_ALLOCATE_INFO,
+    DeviceMemoryOpaqueCaptureAddressInfo = DEVICE_MEMORY_OPAQUE_CAPTURE_ADDRESS_INFO,
+    PhysicalDeviceLineRasterizationFeaturesEXT = PHYSICAL_DEVICE_LINE_RASTERIZATION_FEATURES_EXT,
+    PhysicalDeviceLineRasterizationPropertiesEXT = PHYSICAL_DEVICE_LINE_RASTERIZATION_PROPERTIES_EXT,
+    PipelineRasterizationLineStateCreateInfoEXT = PIPELINE_RASTERIZATION_LINE_STATE_CREATE_INFO_EXT,
+    PhysicalDevicePipelineCreationCacheControlFeatures = PHYSICAL_DEVICE_PIPELINE_CREATION_CACHE_CONTROL_FEATURES,
+    PhysicalDeviceVulkan11Features = PHYSICAL_DEVICE_VULKAN_1_1_FEATURES,
+    PhysicalDeviceVulkan11Properties = PHYSICAL_DEVICE_VULKAN_1_1_PROPERTIES,
+    PhysicalDeviceVulkan12Features = PHYSICAL_DEVICE_VULKAN_1_2_FEATURES,
+    PhysicalDeviceVulkan12Properties = PHYSICAL_DEVICE_VULKAN_1_2_PROPERTIES,
+    PhysicalDeviceVulkan13Features = PHYSICAL_DEVICE_VULKAN_1_3_FEATURES,
+    PhysicalDeviceVulkan13Properties = PHYSICAL_DEVICE_VULKAN_1_3_PROPERTIES,
+    PipelineCompilerControlCreateInfoAMD = PIPELINE_COMPILER_CONTROL_CREATE_INFO_AMD,
+    PhysicalDeviceCoherentMemoryFeaturesAMD = PHYSICAL_DEVICE_COHERENT_MEMORY_FEATURES_AMD,
+    PhysicalDeviceToolProperties = PHYSICAL_DEVICE_TOOL_PROPERTIES,
+    SamplerCustomBorderColorCreateInfoEXT = SAMPLER_CUSTOM_BORDER_COLOR_CREATE_INFO_EXT,
+    PhysicalDeviceCustomBorderColorPropertiesEXT = PHYSICAL_DEVICE_CUSTOM_BORDER_COLOR_PROPERTIES_EXT,
+    PhysicalDeviceCustomBorderColorFeaturesEXT = PHYSICAL_DEVICE_CUSTOM_BORDER_COLOR_FEATURES_EXT,
+    SamplerBorderColorComponentMappingCreateInfoEXT = SAMPLER_BORDER_COLOR_COMPONENT_MAPPING_CREATE_INFO_EXT,
+    PhysicalDeviceBorderColorSwizzleFeaturesEXT = PHYSICAL_DEVICE_BORDER_COLOR_SWIZZLE_FEATURES_EXT,
+    AccelerationStructureGeometryTrianglesDataKHR = ACCELERATION_STRUCTURE_GEOMETRY_TRIANGLES_DATA_KHR,
+    AccelerationStructureGeometryAabbsDataKHR = ACCELERATION_STRUCTURE_GEOMETRY_AABBS_DATA_KHR,
+    AccelerationStructureGeometryInstancesDataKHR = ACCELERATION_STRUCTURE_GEOMETRY_INSTANCES_DATA_KHR,
+    AccelerationStructureGeometryKHR = ACCELERATION_STRUCTURE_GEOMETRY_KHR,
+    AccelerationStructureBuildGeometryInfoKHR = ACCELERATION_STRUCTURE_BUILD_GEOMETRY_INFO_KHR,
+    AccelerationStructureCreateInfoKHR = ACCELERATION_STRUCTURE_CREATE_INFO_KHR,
+    AccelerationStructureDeviceAddressInfoKHR = ACCELERATION_STRUCTURE_DEVICE_ADDRESS_INFO_KHR,
+    AccelerationStructureVersionInfoKHR = ACCELERATION_STRUCTURE_VERSION_INFO_KHR,
+    CopyAccelerationStructureInfoKHR = COPY_ACCELERATION_STRUCTURE_INFO_KHR,
+    CopyAccelerationStructureToMemoryInfoKHR = COPY_ACCELERATION_STRUCTURE_TO_MEMORY_INFO_KHR,
+    CopyMemoryToAccelerationStructureInfoKHR = COPY_MEMORY_TO_ACCELERATION_STRUCTURE_INFO_KHR,
+    RayTracingPipelineInterfaceCreateInfoKHR = RAY_TRACING_PIPELINE_INTERFACE_CREATE_INFO_KHR,
+    PipelineLibraryCreateInfoKHR = PIPELINE_LIBRARY_CREATE_INFO_KHR,
+    PhysicalDeviceExtendedDynamicStateFeaturesEXT = PHYSICAL_DEVICE_EXTENDED_DYNAMIC_STATE_FEATURES_EXT,
+    PhysicalDeviceExtendedDynamicState2FeaturesEXT = PHYSICAL_DEVICE_EXTENDED_DYNAMIC_STATE_2_FEATURES_EXT,
+    PhysicalDeviceExtendedDynamicState3FeaturesEXT = PHYSICAL_DEVICE_EXTENDED_DYNAMIC_STATE_3_FEATURES_EXT,
+    PhysicalDeviceExtendedDynamicState3PropertiesEXT = PHYSICAL_DEVICE_EXTENDED_DYNAMIC_STATE_3_PROPERTIES_EXT,
+    RenderPassTransformBeginInfoQCOM = RENDER_PASS_TRANSFORM_BEGIN_INFO_QCOM,
+    CopyCommandTransformInfoQCOM = COPY_COMMAND_TRANSFORM_INFO_QCOM,
+    CommandBufferInheritanceRenderPassTransformInfoQCOM = COMMAND_BUFFER_INHERITANCE_RENDER_PASS_TRANSFORM_INFO_QCOM,
+    PhysicalDeviceDiagnosticsConfigFeaturesNV = PHYSICAL_DEVICE_DIAGNOSTICS_CONFIG_FEATURES_NV,
+    DeviceDiagnosticsConfigCreateInfoNV = DEVICE_DIAGNOSTICS_CONFIG_CREATE_INFO_NV,
+    PhysicalDeviceZeroInitializeWorkgroupMemoryFeatures = PHYSICAL_DEVICE_ZERO_INITIALIZE_WORKGROUP_MEMORY_FEATURES,
+    PhysicalDeviceShaderSubgroupUniformControlFlowFeaturesKHR = PHYSICAL_DEVICE_SHADER_SUBGROUP_UNIFORM_CONTROL_FLOW_FEATURES_KHR,
+    PhysicalDeviceRobustness2FeaturesEXT = PHYSICAL_DEVICE_ROBUSTNESS_2_FEATURES_EXT,
+    PhysicalDeviceRobustness2PropertiesEXT = PHYSICAL_DEVICE_ROBUSTNESS_2_PROPERTIES_EXT,
+    PhysicalDeviceImageRobustnessFeatures = PHYSICAL_DEVICE_IMAGE_ROBUSTNESS_FEATURES,
+    PhysicalDeviceWorkgroupMemoryExplicitLayoutFeaturesKHR = PHYSICAL_DEVICE_WORKGROUP_MEMORY_EXPLICIT_LAYOUT_FEATURES_KHR,
+    PhysicalDevicePortabilitySubsetFeaturesKHR = PHYSICAL_DEVICE_PORTABILITY_SUBSET_FEATURES_KHR,
+    PhysicalDevicePortabilitySubsetPropertiesKHR = PHYSICAL_DEVICE_PORTABILITY_SUBSET_PROPERTIES_KHR,
+    PhysicalDevice4444FormatsFeaturesEXT = PHYSICAL_DEVICE_4444_FORMATS_FEATURES_EXT,
+    PhysicalDeviceSubpassShadingFeaturesHUAWEI = PHYSICAL_DEVICE_SUBPASS_SHADING_FEATURES_HUAWEI,
+    BufferCopy2 = BUFFER_COPY_2,
+    ImageCopy2 = IMAGE_COPY_2,
+    ImageBlit2 = IMAGE_BLIT_2,
+    BufferImageCopy2 = BUFFER_IMAGE_COPY_2,
+    ImageResolve2 = IMAGE_RESOLVE_2,
+    CopyBufferInfo2 = COPY_BUFFER_INFO_2,
+    CopyImageInfo2 = COPY_IMAGE_INFO_2,
+    BlitImageInfo2 = BLIT_IMAGE_INFO_2,
+    CopyBufferToImageInfo2 = COPY_BUFFER_TO_IMAGE_INFO_2,
+    CopyImageToBufferInfo2 = COPY_IMAGE_TO_BUFFER_INFO_2,
+    ResolveImageInfo2 = RESOLVE_IMAGE_INFO_2,
+    PhysicalDeviceShaderImageAtomicInt64FeaturesEXT = PHYSICAL_DEVICE_SHADER_IMAGE_ATOMIC_INT64_FEATURES_EXT,
+    FragmentShadingRateAttachmentInfoKHR = FRAGMENT_SHADING_RATE_ATTACHMENT_INFO_KHR,
+    PipelineFragmentShadingRateStateCreateInfoKHR = PIPELINE_FRAGMENT_SHADING_RATE_STATE_CREATE_INFO_KHR,
+    PhysicalDeviceFragmentShadingRateFeaturesKHR = PHYSICAL_DEVICE_FRAGMENT_SHADING_RATE_FEATURES_KHR,
+    PhysicalDeviceFragmentShadingRatePropertiesKHR = PHYSICAL_DEVICE_FRAGMENT_SHADING_RATE_PROPERTIES_KHR,
+    PhysicalDeviceFragmentShadingRateKHR = PHYSICAL_DEVICE_FRAGMENT_SHADING_RATE_KHR,
+    PhysicalDeviceShaderTerminateInvocationFeatures = PHYSICAL_DEVICE_SHADER_TERMINATE_INVOCATION_FEATURES,
+    PhysicalDeviceFragmentShadingRateEnumsFeaturesNV = PHYSICAL_DEVICE_FRAGMENT_SHADING_RATE_ENUMS_FEATURES_NV,
+    PhysicalDeviceFragmentShadingRateEnumsPropertiesNV = PHYSICAL_DEVICE_FRAGMENT_SHADING_RATE_ENUMS_PROPERTIES_NV,
+    PipelineFragmentShadingRateEnumStateCreateInfoNV = PIPELINE_FRAGMENT_SHADING_RATE_ENUM_STATE_CREATE_INFO_NV,
+    AccelerationStructureBuildSizesInfoKHR = ACCELERATION_STRUCTURE_BUILD_SIZES_INFO_KHR,
+    PhysicalDeviceImage2DViewOf3DFeaturesEXT = PHYSICAL_DEVICE_IMAGE_2D_VIEW_OF_3D_FEATURES_EXT,
+    PhysicalDeviceMutableDescriptorTypeFeaturesEXT = PHYSICAL_DEVICE_MUTABLE_DESCRIPTOR_TYPE_FEATURES_EXT,
+    MutableDescriptorTypeCreateInfoEXT = MUTABLE_DESCRIPTOR_TYPE_CREATE_INFO_EXT,
+    PhysicalDeviceDepthClipControlFeaturesEXT = PHYSICAL_DEVICE_DEPTH_CLIP_CONTROL_FEATURES_EXT,
+    PipelineViewportDepthClipControlCreateInfoEXT = PIPELINE_VIEWPORT_DEPTH_CLIP_CONTROL_CREATE_INFO_EXT,
+    PhysicalDeviceVertexInputDynamicStateFeaturesEXT = PHYSICAL_DEVICE_VERTEX_INPUT_DYNAMIC_STATE_FEATURES_EXT,
+    PhysicalDeviceExternalMemoryRDMAFeaturesNV = PHYSICAL_DEVICE_EXTERNAL_MEMORY_RDMA_FEATURES_NV,
+    VertexInputBindingDescription2EXT = VERTEX_INPUT_BINDING_DESCRIPTION_2_EXT,
+    VertexInputAttributeDescription2EXT = VERTEX_INPUT_ATTRIBUTE_DESCRIPTION_2_EXT,
+    PhysicalDeviceColorWriteEnableFeaturesEXT = PHYSICAL_DEVICE_COLOR_WRITE_ENABLE_FEATURES_EXT,
+    PipelineColorWriteCreateInfoEXT = PIPELINE_COLOR_WRITE_CREATE_INFO_EXT,
+    MemoryBarrier2 = MEMORY_BARRIER_2,
+    ImageMemoryBarrier2 = IMAGE_MEMORY_BARRIER_2,
+    BufferMemoryBarrier2 = BUFFER_MEMORY_BARRIER_2,
+    DependencyInfo = DEPENDENCY_INFO,
+    SemaphoreSubmitInfo = SEMAPHORE_SUBMIT_INFO,
+    CommandBufferSubmitInfo = COMMAND_BUFFER_SUBMIT_INFO,
+    SubmitInfo2 = SUBMIT_INFO_2,
+    QueueFamilyCheckpointProperties2NV = QUEUE_FAMILY_CHECKPOINT_PROPERTIES_2_NV,
+    CheckpointData2NV = CHECKPOINT_DATA_2_NV,
+    PhysicalDeviceSynchronization2Features = PHYSICAL_DEVICE_SYNCHRONIZATION_2_FEATURES,
+    PhysicalDevicePrimitivesGeneratedQueryFeaturesEXT = PHYSICAL_DEVICE_PRIMITIVES_GENERATED_QUERY_FEATURES_EXT,
+    PhysicalDeviceLegacyDitheringFeaturesEXT = PHYSICAL_DEVICE_LEGACY_DITHERING_FEATURES_EXT,
+    PhysicalDeviceMultisampledRenderToSingleSampledFeaturesEXT = PHYSICAL_DEVICE_MULTISAMPLED_RENDER_TO_SINGLE_SAMPLED_FEATURES_EXT,
+    SubpassResolvePerformanceQueryEXT = SUBPASS_RESOLVE_PERFORMANCE_QUERY_EXT,
+    MultisampledRenderToSingleSampledInfoEXT = MULTISAMPLED_RENDER_TO_SINGLE_SAMPLED_INFO_EXT,
+    PhysicalDevicePipelineProtectedAccessFeaturesEXT = PHYSICAL_DEVICE_PIPELINE_PROTECTED_ACCESS_FEATURES_EXT,
+    QueueFamilyVideoPropertiesKHR = QUEUE_FAMILY_VIDEO_PROPERTIES_KHR,
+    QueueFamilyQueryResultStatusPropertiesKHR = QUEUE_FAMILY_QUERY_RESULT_STATUS_PROPERTIES_KHR,
+    VideoProfileListInfoKHR = VIDEO_PROFILE_LIST_INFO_KHR,
+    PhysicalDeviceVideoFormatInfoKHR = PHYSICAL_DEVICE_VIDEO_FORMAT_INFO_KHR,
+    VideoFormatPropertiesKHR = VIDEO_FORMAT_PROPERTIES_KHR,
+    VideoProfileInfoKHR = VIDEO_PROFILE_INFO_KHR,
+    VideoCapabilitiesKHR = VIDEO_CAPABILITIES_KHR,
+    VideoSessionMemoryRequirementsKHR = VIDEO_SESSION_MEMORY_REQUIREMENTS_KHR,
+    BindVideoSessionMemoryInfoKHR = BIND_VIDEO_SESSION_MEMORY_INFO_KHR,
+    VideoPictureResourceInfoKHR = VIDEO_PICTURE_RESOURCE_INFO_KHR,
+    VideoReferenceSlotInfoKHR = VIDEO_REFERENCE_SLOT_INFO_KHR,
+    VideoDecodeCapabilitiesKHR = VIDEO_DECODE_CAPABILITIES_KHR,
+    VideoDecodeUsageInfoKHR = VIDEO_DECODE_USAGE_INFO_KHR,
+    VideoDecodeInfoKHR = VIDEO_DECODE_INFO_KHR,
+    VideoSessionCreateInfoKHR = VIDEO_SESSION_CREATE_INFO_KHR,
+    VideoSessionParametersCreateInfoKHR = VIDEO_SESSION_PARAMETERS_CREATE_INFO_KHR,
+    VideoSessionParametersUpdateInfoKHR = VIDEO_SESSION_PARAMETERS_UPDATE_INFO_KHR,
+    VideoBeginCodingInfoKHR = VIDEO_BEGIN_CODING_INFO_KHR,
+    VideoEndCodingInfoKHR = VIDEO_END_CODING_INFO_KHR,
+    VideoCodingControlInfoKHR = VIDEO_CODING_CONTROL_INFO_KHR,
+    VideoEncodeUsageInfoKHR = VIDEO_ENCODE_USAGE_INFO_KHR,
+    VideoEncodeInfoKHR = VIDEO_ENCODE_INFO_KHR,
+    VideoEncodeRateControlInfoKHR = VIDEO_ENCODE_RATE_CONTROL_INFO_KHR,
+    VideoEncodeRateControlLayerInfoKHR = VIDEO_ENCODE_RATE_CONTROL_LAYER_INFO_KHR,
+    VideoEncodeCapabilitiesKHR = VIDEO_ENCODE_CAPABILITIES_KHR,
+    VideoEncodeH264CapabilitiesEXT = VIDEO_ENCODE_H264_CAPABILITIES_EXT,
+    VideoEncodeH264EmitPictureParametersInfoEXT = VIDEO_ENCODE_H264_EMIT_PICTURE_PARAMETERS_INFO_EXT,
+    VideoEncodeH264RateControlInfoEXT = VIDEO_ENCODE_H264_RATE_CONTROL_INFO_EXT,
+    VideoEncodeH264RateControlLayerInfoEXT = VIDEO_ENCODE_H264_RATE_CONTROL_LAYER_INFO_EXT,
+    VideoEncodeH265CapabilitiesEXT = VIDEO_ENCODE_H265_CAPABILITIES_EXT,
+    VideoEncodeH265EmitPictureParametersInfoEXT = VIDEO_ENCODE_H265_EMIT_PICTURE_PARAMETERS_INFO_EXT,
+    VideoEncodeH265RateControlInfoEXT = VIDEO_ENCODE_H265_RATE_CONTROL_INFO_EXT,
+    VideoEncodeH265RateControlLayerInfoEXT = VIDEO_ENCODE_H265_RATE_CONTROL_LAYER_INFO_EXT,
+    PhysicalDeviceInheritedViewportScissorFeaturesNV = PHYSICAL_DEVICE_INHERITED_VIEWPORT_SCISSOR_FEATURES_NV,
+    CommandBufferInheritanceViewportScissorInfoNV = COMMAND_BUFFER_INHERITANCE_VIEWPORT_SCISSOR_INFO_NV,
+    PhysicalDeviceYcbcr2Plane444FormatsFeaturesEXT = PHYSICAL_DEVICE_YCBCR_2_PLANE_444_FORMATS_FEATURES_EXT,
+    PhysicalDeviceProvokingVertexFeaturesEXT = PHYSICAL_DEVICE_PROVOKING_VERTEX_FEATURES_EXT,
+    PhysicalDeviceProvokingVertexPropertiesEXT = PHYSICAL_DEVICE_PROVOKING_VERTEX_PROPERTIES_EXT,
+    PipelineRasterizationProvokingVertexStateCreateInfoEXT = PIPELINE_RASTERIZATION_PROVOKING_VERTEX_STATE_CREATE_INFO_EXT,
+    CuModuleCreateInfoNVX = CU_MODULE_CREATE_INFO_NVX,
+    CuFunctionCreateInfoNVX = CU_FUNCTION_CREATE_INFO_NVX,
+    CuLaunchInfoNVX = CU_LAUNCH_INFO_NVX,
+    PhysicalDeviceShaderIntegerDotProductFeatures = PHYSICAL_DEVICE_SHADER_INTEGER_DOT_PRODUCT_FEATURES,
+    PhysicalDeviceShaderIntegerDotProductProperties = PHYSICAL_DEVICE_SHADER_INTEGER_DOT_PRODUCT_PROPERTIES,
+    PhysicalDeviceDrmPropertiesEXT = PHYSICAL_DEVICE_DRM_PROPERTIES_EXT,
+    PhysicalDeviceFragmentShaderBarycentricFeaturesKHR = PHYSICAL_DEVICE_FRAGMENT_SHADER_BARYCENTRIC_FEATURES_KHR,
+    PhysicalDeviceFragmentShaderBarycentricPropertiesKHR = PHYSICAL_DEVICE_FRAGMENT_SHADER_BARYCENTRIC_PROPERTIES_KHR,
+    PhysicalDeviceRayTracingMotionBlurFeaturesNV = PHYSICAL_DEVICE_RAY_TRACING_MOTION_BLUR_FEATURES_NV,
+    AccelerationStructureGeometryMotionTrianglesDataNV = ACCELERATION_STRUCTURE_GEOMETRY_MOTION_TRIANGLES_DATA_NV,
+    AccelerationStructureMotionInfoNV = ACCELERATION_STRUCTURE_MOTION_INFO_NV,
+    MemoryGetRemoteAddressInfoNV = MEMORY_GET_REMOTE_ADDRESS_INFO_NV,
+    ImportMemoryBufferCollectionFUCHSIA = IMPORT_MEMORY_BUFFER_COLLECTION_FUCHSIA,
+    BufferCollectionImageCreateInfoFUCHSIA = BUFFER_COLLECTION_IMAGE_CREATE_INFO_FUCHSIA,
+    BufferCollectionBufferCreateInfoFUCHSIA = BUFFER_COLLECTION_BUFFER_CREATE_INFO_FUCHSIA,
+    BufferCollectionCreateInfoFUCHSIA = BUFFER_COLLECTION_CREATE_INFO_FUCHSIA,
+    BufferCollectionPropertiesFUCHSIA = BUFFER_COLLECTION_PROPERTIES_FUCHSIA,
+    BufferConstraintsInfoFUCHSIA = BUFFER_CONSTRAINTS_INFO_FUCHSIA,
+    SysmemColorSpaceFUCHSIA = SYSMEM_COLOR_SPACE_FUCHSIA,
+    ImageFormatConstraintsInfoFUCHSIA = IMAGE_FORMAT_CONSTRAINTS_INFO_FUCHSIA,
+    ImageConstraintsInfoFUCHSIA = IMAGE_CONSTRAINTS_INFO_FUCHSIA,
+    BufferCollectionConstraintsInfoFUCHSIA = BUFFER_COLLECTION_CONSTRAINTS_INFO_FUCHSIA,
+    PhysicalDeviceRGBA10X6FormatsFeaturesEXT = PHYSICAL_DEVICE_RGBA10X6_FORMATS_FEATURES_EXT,
+    FormatProperties3 = FORMAT_PROPERTIES_3,
+    DrmFormatModifierPropertiesList2EXT = DRM_FORMAT_MODIFIER_PROPERTIES_LIST_2_EXT,
+    AndroidHardwareBufferFormatProperties2ANDROID = ANDROID_HARDWARE_BUFFER_FORMAT_PROPERTIES_2_ANDROID,
+    PipelineRenderingCreateInfo = PIPELINE_RENDERING_CREATE_INFO,
+    RenderingInfo = RENDERING_INFO,
+    RenderingAttachmentInfo = RENDERING_ATTACHMENT_INFO,
+    RenderingFragmentShadingRateAttachmentInfoKHR = RENDERING_FRAGMENT_SHADING_RATE_ATTACHMENT_INFO_KHR,
+    RenderingFragmentDensityMapAttachmentInfoEXT = RENDERING_FRAGMENT_DENSITY_MAP_ATTACHMENT_INFO_EXT,
+    PhysicalDeviceDynamicRenderingFeatures = PHYSICAL_DEVICE_DYNAMIC_RENDERING_FEATURES,
+    CommandBufferInheritanceRenderingInfo = COMMAND_BUFFER_INHERITANCE_RENDERING_INFO,
+    AttachmentSampleCountInfoAMD = ATTACHMENT_SAMPLE_COUNT_INFO_AMD,
+    MultiviewPerViewAttributesInfoNVX = MULTIVIEW_PER_VIEW_ATTRIBUTES_INFO_NVX,
+    PhysicalDeviceImageViewMinLodFeaturesEXT = PHYSICAL_DEVICE_IMAGE_VIEW_MIN_LOD_FEATURES_EXT,
+    ImageViewMinLodCreateInfoEXT = IMAGE_VIEW_MIN_LOD_CREATE_INFO_EXT,
+    PhysicalDeviceRasterizationOrderAttachmentAccessFeaturesEXT = PHYSICAL_DEVICE_RASTERIZATION_ORDER_ATTACHMENT_ACCESS_FEATURES_EXT,
+    PhysicalDeviceLinearColorAttachmentFeaturesNV = PHYSICAL_DEVICE_LINEAR_COLOR_ATTACHMENT_FEATURES_NV,
+    PhysicalDeviceGraphicsPipelineLibraryFeaturesEXT = PHYSICAL_DEVICE_GRAPHICS_PIPELINE_LIBRARY_FEATURES_EXT,
+    PhysicalDeviceGraphicsPipelineLibraryPropertiesEXT = PHYSICAL_DEVICE_GRAPHICS_PIPELINE_LIBRARY_PROPERTIES_EXT,
+    GraphicsPipelineLibraryCreateInfoEXT = GRAPHICS_PIPELINE_LIBRARY_CREATE_INFO_EXT,
+    PhysicalDeviceDescriptorSetHostMappingFeaturesVALVE = PHYSICAL_DEVICE_DESCRIPTOR_SET_HOST_MAPPING_FEATURES_VALVE,
+    DescriptorSetBindingReferenceVALVE = DESCRIPTOR_SET_BINDING_REFERENCE_VALVE,
+    DescriptorSetLayoutHostMappingInfoVALVE = DESCRIPTOR_SET_LAYOUT_HOST_MAPPING_INFO_VALVE,
+    PhysicalDeviceShaderModuleIdentifierFeaturesEXT = PHYSICAL_DEVICE_SHADER_MODULE_IDENTIFIER_FEATURES_EXT,
+    PhysicalDeviceShaderModuleIdentifierPropertiesEXT = PHYSICAL_DEVICE_SHADER_MODULE_IDENTIFIER_PROPERTIES_EXT,
+    PipelineShaderStageModuleIdentifierCreateInfoEXT = PIPELINE_SHADER_STAGE_MODULE_IDENTIFIER_CREATE_INFO_EXT,
+    ShaderModuleIdentifierEXT = SHADER_MODULE_IDENTIFIER_EXT,
+    ImageCompressionControlEXT = IMAGE_COMPRESSION_CONTROL_EXT,
+    PhysicalDeviceImageCompressionControlFeaturesEXT = PHYSICAL_DEVICE_IMAGE_COMPRESSION_CONTROL_FEATURES_EXT,
+    ImageCompressionPropertiesEXT = IMAGE_COMPRESSION_PROPERTIES_EXT,
+    PhysicalDeviceImageCompressionControlSwapchainFeaturesEXT = PHYSICAL_DEVICE_IMAGE_COMPRESSION_CONTROL_SWAPCHAIN_FEATURES_EXT,
+    ImageSubresource2EXT = IMAGE_SUBRESOURCE_2_EXT,
+    SubresourceLayout2EXT = SUBRESOURCE_LAYOUT_2_EXT,
+    RenderPassCreationControlEXT = RENDER_PASS_CREATION_CONTROL_EXT,
+    RenderPassCreationFeedbackCreateInfoEXT = RENDER_PASS_CREATION_FEEDBACK_CREATE_INFO_EXT,
+    RenderPassSubpassFeedbackCreateInfoEXT = RENDER_PASS_SUBPASS_FEEDBACK_CREATE_INFO_EXT,
+    PhysicalDeviceSubpassMergeFeedbackFeaturesEXT = PHYSICAL_DEVICE_SUBPASS_MERGE_FEEDBACK_FEATURES_EXT,
+    MicromapBuildInfoEXT = MICROMAP_BUILD_INFO_EXT,
+    MicromapCreateInfoEXT = MICROMAP_CREATE_INFO_EXT,
+    MicromapVersionInfoEXT = MICROMAP_VERSION_INFO_EXT,
+    CopyMicromapInfoEXT = COPY_MICROMAP_INFO_EXT,
+    CopyMicromapToMemoryInfoEXT = COPY_MICROMAP_TO_MEMORY_INFO_EXT,
+    CopyMemoryToMicromapInfoEXT = COPY_MEMORY_TO_MICROMAP_INFO_EXT,
+    MicromapBuildSizesInfoEXT = MICROMAP_BUILD_SIZES_INFO_EXT,
+    PhysicalDeviceOpacityMicromapFeaturesEXT = PHYSICAL_DEVICE_OPACITY_MICROMAP_FEATURES_EXT,
+    PhysicalDeviceOpacityMicromapPropertiesEXT = PHYSICAL_DEVICE_OPACITY_MICROMAP_PROPERTIES_EXT,
+    AccelerationStructureTrianglesOpacityMicromapEXT = ACCELERATION_STRUCTURE_TRIANGLES_OPACITY_MICROMAP_EXT,
+    PipelinePropertiesIdentifierEXT = PIPELINE_PROPERTIES_IDENTIFIER_EXT,
+    PhysicalDevicePipelinePropertiesFeaturesEXT = PHYSICAL_DEVICE_PIPELINE_PROPERTIES_FEATURES_EXT,
+    PhysicalDeviceShaderEarlyAndLateFragmentTestsFeaturesAMD = PHYSICAL_DEVICE_SHADER_EARLY_AND_LATE_FRAGMENT_TESTS_FEATURES_AMD,
+    ExportMetalObjectCreateInfoEXT = EXPORT_METAL_OBJECT_CREATE_INFO_EXT,
+    ExportMetalObjectsInfoEXT = EXPORT_METAL_OBJECTS_INFO_EXT,
+    ExportMetalDeviceInfoEXT = EXPORT_METAL_DEVICE_INFO_EXT,
+    ExportMetalCommandQueueInfoEXT = EXPORT_METAL_COMMAND_QUEUE_INFO_EXT,
+    ExportMetalBufferInfoEXT = EXPORT_METAL_BUFFER_INFO_EXT,
+    ImportMetalBufferInfoEXT = IMPORT_METAL_BUFFER_INFO_EXT,
+    ExportMetalTextureInfoEXT = EXPORT_METAL_TEXTURE_INFO_EXT,
+    ImportMetalTextureInfoEXT = IMPORT_METAL_TEXTURE_INFO_EXT,
+    ExportMetalIOSurfaceInfoEXT = EXPORT_METAL_IO_SURFACE_INFO_EXT,
+    ImportMetalIOSurfaceInfoEXT = IMPORT_METAL_IO_SURFACE_INFO_EXT,
+    ExportMetalSharedEventInfoEXT = EXPORT_METAL_SHARED_EVENT_INFO_EXT,
+    ImportMetalSharedEventInfoEXT = IMPORT_METAL_SHARED_EVENT_INFO_EXT,
+    PhysicalDeviceNonSeamlessCubeMapFeaturesEXT = PHYSICAL_DEVICE_NON_SEAMLESS_CUBE_MAP_FEATURES_EXT,
+    PhysicalDevicePipelineRobustnessFeaturesEXT = PHYSICAL_DEVICE_PIPELINE_ROBUSTNESS_FEATURES_EXT,
+    PipelineRobustnessCreateInfoEXT = PIPELINE_ROBUSTNESS_CREATE_INFO_EXT,
+    PhysicalDevicePipelineRobustnessPropertiesEXT = PHYSICAL_DEVICE_PIPELINE_ROBUSTNESS_PROPERTIES_EXT,
+    ImageViewSampleWeightCreateInfoQCOM = IMAGE_VIEW_SAMPLE_WEIGHT_CREATE_INFO_QCOM,
+    PhysicalDeviceImageProcessingFeaturesQCOM = PHYSICAL_DEVICE_IMAGE_PROCESSING_FEATURES_QCOM,
+    PhysicalDeviceImageProcessingPropertiesQCOM = PHYSICAL_DEVICE_IMAGE_PROCESSING_PROPERTIES_QCOM,
+    PhysicalDeviceTilePropertiesFeaturesQCOM = PHYSICAL_DEVICE_TILE_PROPERTIES_FEATURES_QCOM,
+    TilePropertiesQCOM = TILE_PROPERTIES_QCOM,
+    PhysicalDeviceAmigoProfilingFeaturesSEC = PHYSICAL_DEVICE_AMIGO_PROFILING_FEATURES_SEC,
+    AmigoProfilingSubmitInfoSEC = AMIGO_PROFILING_SUBMIT_INFO_SEC,
+    PhysicalDeviceAttachmentFeedbackLoopLayoutFeaturesEXT = PHYSICAL_DEVICE_ATTACHMENT_FEEDBACK_LOOP_LAYOUT_FEATURES_EXT,
+    PhysicalDeviceDepthClampZeroOneFeaturesEXT = PHYSICAL_DEVICE_DEPTH_CLAMP_ZERO_ONE_FEATURES_EXT,
+    PhysicalDeviceAddressBindingReportFeaturesEXT = PHYSICAL_DEVICE_ADDRESS_BINDING_REPORT_FEATURES_EXT,
+    DeviceAddressBindingCallbackDataEXT = DEVICE_ADDRESS_BINDING_CALLBACK_DATA_EXT,
+    PhysicalDeviceOpticalFlowFeaturesNV = PHYSICAL_DEVICE_OPTICAL_FLOW_FEATURES_NV,
+    PhysicalDeviceOpticalFlowPropertiesNV = PHYSICAL_DEVICE_OPTICAL_FLOW_PROPERTIES_NV,
+    OpticalFlowImageFormatInfoNV = OPTICAL_FLOW_IMAGE_FORMAT_INFO_NV,
+    OpticalFlowImageFormatPropertiesNV = OPTICAL_FLOW_IMAGE_FORMAT_PROPERTIES_NV,
+    OpticalFlowSessionCreateInfoNV = OPTICAL_FLOW_SESSION_CREATE_INFO_NV,
+    OpticalFlowSessionCreatePrivateDataInfoNV = OPTICAL_FLOW_SESSION_CREATE_PRIVATE_DATA_INFO_NV,
+    OpticalFlowExecuteInfoNV = OPTICAL_FLOW_EXECUTE_INFO_NV,
+    PhysicalDeviceFaultFeaturesEXT = PHYSICAL_DEVICE_FAULT_FEATURES_EXT,
+    DeviceFaultCountsEXT = DEVICE_FAULT_COUNTS_EXT,
+    DeviceFaultInfoEXT = DEVICE_FAULT_INFO_EXT,
+}
+
+impl_extends! {
+    DeviceQueueCreateInfo {
+        DeviceQueueGlobalPriorityCreateInfoKHR,
+    }
+    DeviceCreateInfo {
+        PhysicalDeviceDeviceGeneratedCommandsFeaturesNV,
+        DevicePrivateDataCreateInfo,
+        PhysicalDevicePrivateDataFeatures,
+        PhysicalDeviceVariablePointersFeatures,
+        PhysicalDeviceMultiviewFeatures,
+        DeviceGroupDeviceCreateInfo,
+        PhysicalDevicePresentIdFeaturesKHR,
+        PhysicalDevicePresentWaitFeaturesKHR,
+        PhysicalDevice16BitStorageFeatures,
+        PhysicalDeviceShaderSubgroupExtendedTypesFeatures,
+        PhysicalDeviceSamplerYcbcrConversionFeatures,
+        PhysicalDeviceProtectedMemoryFeatures,
+        PhysicalDeviceBlendOperationAdvancedFeaturesEXT,
+        PhysicalDeviceMultiDrawFeaturesEXT,
+        PhysicalDeviceInlineUniformBlockFeatures,
+        PhysicalDeviceMaintenance4Features,
+        PhysicalDeviceShaderDrawParametersFeatures,
+        PhysicalDeviceShaderFloat16Int8Features,
+        PhysicalDeviceHostQueryResetFeatures,
+        PhysicalDeviceGlobalPriorityQueryFeaturesKHR,
+        PhysicalDeviceDeviceMemoryReportFeaturesEXT,
+        DeviceDeviceMemoryReportCreateInfoEXT,
+        PhysicalDeviceDescriptorIndexingFeatures,
+        PhysicalDeviceTimelineSemaphoreFeatures,
+        PhysicalDevice8BitStorageFeatures,
+        PhysicalDeviceConditionalRenderingFeaturesEXT,
+        PhysicalDeviceVulkanMemoryModelFeatures,
+        PhysicalDeviceShaderAtomicInt64Features,
+        PhysicalDeviceShaderAtomicFloatFeaturesEXT,
+        PhysicalDeviceShaderAtomicFloat2FeaturesEXT,
+        PhysicalDeviceVertexAttributeDivisorFeaturesEXT,
+        PhysicalDeviceASTCDecodeFeaturesEXT,
+        PhysicalDeviceTransformFeedbackFeaturesEXT,
+        PhysicalDeviceRepresentativeFragmentTestFeaturesNV,
+        PhysicalDeviceExclusiveScissorFeaturesNV,
+        PhysicalDeviceCornerSampledImageFeaturesNV,
+        PhysicalDeviceComputeShaderDerivativesFeaturesNV,
+        PhysicalDeviceShaderImageFootprintFeaturesNV,
+        PhysicalDeviceDedicatedAllocationImageAliasingFeaturesNV,
+        PhysicalDeviceShadingRateImageFeaturesNV,
+        PhysicalDeviceInvocationMaskFeaturesHUAWEI,
+        PhysicalDeviceMeshShaderFeaturesNV,
+        PhysicalDeviceMeshShaderFeaturesEXT,
+        PhysicalDeviceAccelerationStructureFeaturesKHR,
+        PhysicalDeviceRayTracingPipelineFeaturesKHR,
+        PhysicalDeviceRayQueryFeaturesKHR,
+        PhysicalDeviceRayTracingMaintenance1FeaturesKHR,
+        DeviceMemoryOverallocationCreateInfoAMD,
+        PhysicalDeviceFragmentDensityMapFeaturesEXT,
+        PhysicalDeviceFragmentDensityMap2FeaturesEXT,
+        PhysicalDeviceFragmentDensityMapOffsetFeaturesQCOM,
+        PhysicalDeviceScalarBlockLayoutFeatures,
+        PhysicalDeviceUniformBufferStandardLayoutFeatures,
+        PhysicalDeviceDepthClipEnableFeaturesEXT,
+        PhysicalDeviceMemoryPriorityFeaturesEXT,
+        PhysicalDevicePageableDeviceLocalMemoryFeaturesEXT,
+        PhysicalDeviceBufferDeviceAddressFeatures,
+        PhysicalDeviceBufferDeviceAddressFeaturesEXT,
+        PhysicalDeviceImagelessFramebufferFeatures,
+        PhysicalDeviceTextureCompressionASTCHDRFeatures,
+        PhysicalDeviceCooperativeMatrixFeaturesNV,
+        PhysicalDeviceYcbcrImageArraysFeaturesEXT,
+        PhysicalDevicePresentBarrierFeaturesNV,
+        PhysicalDevicePerformanceQueryFeaturesKHR,
+        PhysicalDeviceCoverageReductionModeFeaturesNV,
+        PhysicalDeviceShaderIntegerFunctions2FeaturesINTEL,
+        PhysicalDeviceShaderClockFeaturesKHR,
+        PhysicalDeviceIndexTypeUint8FeaturesEXT,
+        PhysicalDeviceShaderSMBuiltinsFeaturesNV,
+        PhysicalDeviceFragmentShaderInterlockFeaturesEXT,
+        PhysicalDeviceSeparateDepthStencilLayoutsFeatures,
+        PhysicalDevicePrimitiveTopologyListRestartFeaturesEXT,
+        PhysicalDevicePipelineExecutablePropertiesFeaturesKHR,
+        PhysicalDeviceShaderDemoteToHelperInvocationFeatures,
+        PhysicalDeviceTexelBufferAlignmentFeaturesEXT,
+        PhysicalDeviceSubgroupSizeControlFeatures,
+        PhysicalDeviceLineRasterizationFeaturesEXT,
+        PhysicalDevicePipelineCreationCacheControlFeatures,
+        PhysicalDeviceVulkan11Features,
+        PhysicalDeviceVulkan12Features,
+        PhysicalDeviceVulkan13Features,
+        PhysicalDeviceCoherentMemoryFeaturesAMD,
+        PhysicalDeviceCustomBorderColorFeaturesEXT,
+        PhysicalDeviceBorderColorSwizzleFeaturesEXT,
+        PhysicalDeviceExtendedDynamicStateFeaturesEXT,
+        PhysicalDeviceExtendedDynamicState2FeaturesEXT,
+        PhysicalDeviceExtendedDynamicState3FeaturesEXT,
+        PhysicalDeviceDiagnosticsConfigFeaturesNV,
+        DeviceDiagnosticsConfigCreateInfoNV,
+        PhysicalDeviceZeroInitializeWorkgroupMemoryFeatures,
+        PhysicalDeviceShaderSubgroupUniformControlFlowFeaturesKHR,
+        PhysicalDeviceRobustness2FeaturesEXT,
+        PhysicalDeviceImageRobustnessFeatures,
+        PhysicalDeviceWorkgroupMemoryExplicitLayoutFeaturesKHR,
+        PhysicalDevicePortabilitySubsetFeaturesKHR,
+        PhysicalDevice4444FormatsFeaturesEXT,
+        PhysicalDeviceSubpassShadingFeaturesHUAWEI,
+        PhysicalDeviceShaderImageAtomicInt64FeaturesEXT,
+        PhysicalDeviceFragmentShadingRateFeaturesKHR,
+        PhysicalDeviceShaderTerminateInvocationFeatures,
+        PhysicalDeviceFragmentShadingRateEnumsFeaturesNV,
+        PhysicalDeviceImage2DViewOf3DFeaturesEXT,
+        PhysicalDeviceMutableDescriptorTypeFeaturesEXT,
+        PhysicalDeviceDepthClipControlFeaturesEXT,
+        PhysicalDeviceVertexInputDynamicStateFeaturesEXT,
+        PhysicalDeviceExternalMemoryRDMAFeaturesNV,
+        PhysicalDeviceColorWriteEnableFeaturesEXT,
+        PhysicalDeviceSynchronization2Features,
+        PhysicalDevicePrimitivesGeneratedQueryFeaturesEXT,
+        PhysicalDeviceLegacyDitheringFeaturesEXT,
+        PhysicalDeviceMultisampledRenderToSingleSampledFeaturesEXT,
+        PhysicalDevicePipelineProtectedAccessFeaturesEXT,
+        PhysicalDeviceInheritedViewportScissorFeaturesNV,
+        PhysicalDeviceYcbcr2Plane444FormatsFeaturesEXT,
+        PhysicalDeviceProvokingVertexFeaturesEXT,
+        PhysicalDeviceShaderIntegerDotProductFeatures,
+        PhysicalDeviceFragmentShaderBarycentricFeaturesKHR,
+        PhysicalDeviceRayTracingMotionBlurFeaturesNV,
+        PhysicalDeviceRGBA10X6FormatsFeaturesEXT,
+        PhysicalDeviceDynamicRenderingFeatures,
+        PhysicalDeviceImageViewMinLodFeaturesEXT,
+        PhysicalDeviceRasterizationOrderAttachmentAccessFeaturesEXT,
+        PhysicalDeviceLinearColorAttachmentFeaturesNV,
+        PhysicalDeviceGraphicsPipelineLibraryFeaturesEXT,
+        PhysicalDeviceDescriptorSetHostMappingFeaturesVALVE,
+        PhysicalDeviceShaderModuleIdentifierFeaturesEXT,
+        PhysicalDeviceImageCompressionControlFeaturesEXT,
+        PhysicalDeviceImageCompressionControlSwapchainFeaturesEXT,
+        PhysicalDeviceSubpassMergeFeedbackFeaturesEXT,
+        PhysicalDeviceOpacityMicromapFeaturesEXT,
+        PhysicalDevicePipelinePropertiesFeaturesEXT,
+        PhysicalDeviceShaderEarlyAndLateFragmentTestsFeaturesAMD,
+        PhysicalDeviceNonSeamlessCubeMapFeaturesEXT,
+        PhysicalDevicePipelineRobustnessFeaturesEXT,
+        PhysicalDeviceImageProcessingFeaturesQCOM,
+        PhysicalDeviceTilePropertiesFeaturesQCOM,
+        PhysicalDeviceAmigoProfilingFeaturesSEC,
+        PhysicalDeviceAttachmentFeedbackLoopLayoutFeaturesEXT,
+        PhysicalDeviceDepthClampZeroOneFeaturesEXT,
+        PhysicalDeviceAddressBindingReportFeaturesEXT,
+        PhysicalDeviceOpticalFlowFeaturesNV,
+        PhysicalDeviceFaultFeaturesEXT,
+    }
+    InstanceCreateInfo {
+        DebugReportCallbackCreateInfoEXT,
+        ValidationFlagsEXT,
+        ValidationFeaturesEXT,
+        DebugUtilsMessengerCreateInfoEXT,
+        ExportMetalObjectCreateInfoEXT,
+    }
+    MemoryAllocateInfo {
+        DedicatedAllocationMemoryAllocateInfoNV,
+        ExportMemoryAllocateInfoNV,
+        ImportMemoryWin32HandleInfoNV,
+        ExportMemoryWin32HandleInfoNV,
+        ExportMemoryAllocateInfo,
+        ImportMemoryWin32HandleInfoKHR,
+        ExportMemoryWin32HandleInfoKHR,
+        ImportMemoryZirconHandleInfoFUCHSIA,
+        ImportMemoryFdInfoKHR,
+        MemoryAllocateFlagsInfo,
+        MemoryDedicatedAllocateInfo,
+        ImportMemoryHostPointerInfoEXT,
+        ImportAndroidHardwareBufferInfoANDROID,
+        MemoryPriorityAllocateInfoEXT,
+        MemoryOpaqueCaptureAddressAllocateInfo,
+        ImportMemoryBufferCollectionFUCHSIA,
+        ExportMetalObjectCreateInfoEXT,
+        ImportMetalBufferInfoEXT,
+    }
+    WriteDescriptorSet {
+        WriteDescriptorSetInlineUniformBlock,
+        WriteDescriptorSetAccelerationStructureKHR,
+        WriteDescriptorSetAccelerationStructureNV,
+    }
+    BufferCreateInfo {
+        DedicatedAllocationBufferCreateInfoNV,
+        ExternalMemoryBufferCreateInfo,
+        BufferOpaqueCaptureAddressCreateInfo,
+        BufferDeviceAddressCreateInfoEXT,
+        VideoProfileListInfoKHR,
+        BufferCollectionBufferCreateInfoFUCHSIA,
+    }
+    BufferViewCreateInfo {
+        ExportMetalObjectCreateInfoEXT,
+    }
+    ImageMemoryBarrier {
+        SampleLocationsInfoEXT,
+    }
+    ImageCreateInfo {
+        DedicatedAllocationImageCreateInfoNV,
+        ExternalMemoryImageCreateInfoNV,
+        ExternalMemoryImageCreateInfo,
+        ImageSwapchainCreateInfoKHR,
+        ImageFormatListCreateInfo,
+        NativeBufferANDROID,
+        SwapchainImageCreateInfoANDROID,
+        ExternalFormatANDROID,
+        ImageDrmFormatModifierListCreateInfoEXT,
+        ImageDrmFormatModifierExplicitCreateInfoEXT,
+        ImageStencilUsageCreateInfo,
+        VideoProfileListInfoKHR,
+        BufferCollectionImageCreateInfoFUCHSIA,
+        ImageCompressionControlEXT,
+        ExportMetalObjectCreateInfoEXT,
+        ImportMetalTextureInfoEXT,
+        ImportMetalIOSurfaceInfoEXT,
+        OpticalFlowImageFormatInfoNV,
+    }
+    ImageViewCreateInfo {
+        ImageViewUsageCreateInfo,
+        SamplerYcbcrConversionInfo,
+        ImageViewASTCDecodeModeEXT,
+        ImageViewMinLodCreateInfoEXT,
+        ExportMetalObjectCreateInfoEXT,
+        ImageViewSampleWeightCreateInfoQCOM,
+    }
+    BindSparseInfo {
+        DeviceGroupBindSparseInfo,
+        TimelineSemaphoreSubmitInfo,
+    }
+    ShaderModuleCreateInfo {
+        ShaderModuleValidationCacheCreateInfoEXT,
+    }
+    DescriptorSetLayoutCreateInfo {
+        DescriptorSetLayoutBindingFlagsCreateInfo,
+        MutableDescriptorTypeCreateInfoEXT,
+    }
+    DescriptorPoolCreateInfo {
+        DescriptorPoolInlineUniformBlockCreateInfo,
+        MutableDescriptorTypeCreateInfoEXT,
+    }
+    DescriptorSetAllocateInfo {
+        DescriptorSetVariableDescriptorCountAllocateInfo,
+    }
+    PipelineShaderStageCreateInfo {
+        DebugUtilsObjectNameInfoEXT,
+        PipelineShaderStageRequiredSubgroupSizeCreateInfo,
+        PipelineShaderStageModuleIdentifierCreateInfoEXT,
+        PipelineRobustnessCreateInfoEXT,
+    }
+    ComputePipelineCreateInfo {
+        PipelineCreationFeedbackCreateInfo,
+        SubpassShadingPipelineCreateInfoHUAWEI,
+        PipelineCompilerControlCreateInfoAMD,
+        PipelineRobustnessCreateInfoEXT,
+    }
+    PipelineVertexInputStateCreateInfo {
+        PipelineVertexInputDivisorStateCreateInfoEXT,
+    }
+    PipelineTessellationStateCreateInfo {
+        PipelineTessellationDomainOriginStateCreateInfo,
+    }
+    PipelineViewportStateCreateInfo {
+        PipelineViewportWScalingStateCreateInfoNV,
+        PipelineViewportSwizzleStateCreateInfoNV,
+        PipelineViewportExclusiveScissorStateCreateInfoNV,
+        PipelineViewportShadingRateImageStateCreateInfoNV,
+        PipelineViewportCoarseSampleOrderStateCreateInfoNV,
+        PipelineViewportDepthClipControlCreateInfoEXT,
+    }
+    PipelineRasterizationStateCreateInfo {
+        PipelineRasterizationStateRasterizationOrderAMD,
+        PipelineRasterizationConservativeStateCreateInfoEXT,
+        PipelineRasterizationStateStreamCreateInfoEXT,
+        PipelineRasterizationDepthClipStateCreateInfoEXT,
+        PipelineRasterizationLineStateCreateInfoEXT,
+        PipelineRasterizationProvokingVertexStateCreateInfoEXT,
+    }
+    PipelineMultisampleStateCreateInfo {
+        PipelineCoverageToColorStateCreateInfoNV,
+        PipelineSampleLocationsStateCreateInfoEXT,
+        PipelineCoverageModulationStateCreateInfoNV,
+        PipelineCoverageReductionStateCreateInfoNV,
+    }
+    PipelineColorBlendStateCreateInfo {
+        PipelineColorBlendAdvancedStateCreateInfoEXT,
+        PipelineColorWriteCreateInfoEXT,
+    }
+    GraphicsPipelineCreateInfo {
+        GraphicsPipelineShaderGroupsCreateInfoNV,
+        PipelineDiscardRectangleStateCreateInfoEXT,
+        PipelineRepresentativeFragmentTestStateCreateInfoNV,
+        PipelineCreationFeedbackCreateInfo,
+        PipelineCompilerControlCreateInfoAMD,
+        PipelineLibraryCreateInfoKHR,
+        PipelineFragmentShadingRateStateCreateInfoKHR,
+        PipelineFragmentShadingRateEnumStateCreateInfoNV,
+        PipelineRenderingCreateInfo,
+        AttachmentSampleCountInfoAMD,
+        MultiviewPerViewAttributesInfoNVX,
+        GraphicsPipelineLibraryCreateInfoEXT,
+        PipelineRobustnessCreateInfoEXT,
+    }
+    SamplerCreateInfo {
+        SamplerYcbcrConversionInfo,
+        SamplerReductionModeCreateInfo,
+        SamplerCustomBorderColorCreateInfoEXT,
+        SamplerBorderColorComponentMappingCreateInfoEXT,
+    }
+    CommandBufferInheritanceInfo {
+        CommandBufferInheritanceConditionalRenderingInfoEXT,
+        CommandBufferInheritanceRenderPassTransformInfoQCOM,
+        CommandBufferInheritanceViewportScissorInfoNV,
+        CommandBufferInheritanceRenderingInfo,
+        AttachmentSampleCountInfoAMD,
+        MultiviewPerViewAttributesInfoNVX,
+    }
+    CommandBufferBeginInfo {
+        DeviceGroupCommandBufferBeginInfo,
+    }
+    RenderPassBeginInfo {
+        DeviceGroupRenderPassBeginInfo,
+        RenderPassSampleLocationsBeginInfoEXT,
+        RenderPassAttachmentBeginInfo,
+        RenderPassTransformBeginInfoQCOM,
+    }
+    RenderPassCreateInfo {
+        RenderPassMultiviewCreateInfo,
+        RenderPassInputAttachmentAspectCreateInfo,
+        RenderPassFragmentDensityMapCreateInfoEXT,
+    }
+    EventCreateInfo {
+        ExportMetalObjectCreateInfoEXT,
+        ImportMetalSharedEventInfoEXT,
+    }
+    FenceCreateInfo {
+        ExportFenceCreateInfo,
+        ExportFenceWin32HandleInfoKHR,
+    }
+    SemaphoreCreateInfo {
+        ExportSemaphoreCreateInfo,
+        ExportSemaphoreWin32HandleInfoKHR,
+        SemaphoreTypeCreateInfo,
+        ExportMetalObjectCreateInfoEXT,
+        ImportMetalSharedEventInfoEXT,
+    }
+    QueryPoolCreateInfo {
+        QueryPoolPerformanceCreateInfoKHR,
+        QueryPoolPerformanceQueryCreateInfoINTEL,
+        VideoProfileInfoKHR,
+        VideoDecodeUsageInfoKHR,
+        VideoEncodeUsageInfoKHR,
+    }
+    FramebufferCreateInfo {
+        FramebufferAttachmentsCreateInfo,
+    }
+    SubmitInfo {
+        Win32KeyedMutexAcquireReleaseInfoNV,
+        Win32KeyedMutexAcquireReleaseInfoKHR,
+        D3D12FenceSubmitInfoKHR,
+        DeviceGroupSubmitInfo,
+        ProtectedSubmitInfo,
+        TimelineSemaphoreSubmitInfo,
+        PerformanceQuerySubmitInfoKHR,
+        AmigoProfilingSubmitInfoSEC,
+    }
+    SwapchainCreateInfoKHR {
+        SwapchainCounterCreateInfoEXT,
+        DeviceGroupSwapchainCreateInfoKHR,
+        SwapchainDisplayNativeHdrCreateInfoAMD,
+        ImageFormatListCreateInfo,
+        SurfaceFullScreenExclusiveInfoEXT,
+        SurfaceFullScreenExclusiveWin32InfoEXT,
+        SwapchainPresentBarrierCreateInfoNV,
+        ImageCompressionControlEXT,
+    }
+    PresentInfoKHR {
+        DisplayPresentInfoKHR,
+        PresentRegionsKHR,
+        DeviceGroupPresentInfoKHR,
+        PresentIdKHR,
+        PresentTimesInfoGOOGLE,
+        PresentFrameTokenGGP,
+    }
+    PhysicalDeviceFeatures2 {
+        PhysicalDeviceDeviceGeneratedCommandsFeaturesNV,
+        PhysicalDevicePrivateDataFeatures,
+        PhysicalDeviceVariablePointersFeatures,
+        PhysicalDeviceMultiviewFeatures,
+        PhysicalDevicePresentIdFeaturesKHR,
+        PhysicalDevicePresentWaitFeaturesKHR,
+        PhysicalDevice16BitStorageFeatures,
+        PhysicalDeviceShaderSubgroupExtendedTypesFeatures,
+        PhysicalDeviceSamplerYcbcrConversionFeatures,
+        PhysicalDeviceProtectedMemoryFeatures,
+        PhysicalDeviceBlendOperationAdvancedFeaturesEXT,
+        PhysicalDeviceMultiDrawFeaturesEXT,
+        PhysicalDeviceInlineUniformBlockFeatures,
+        PhysicalDeviceMaintenance4Features,
+        PhysicalDeviceShaderDrawParametersFeatures,
+        PhysicalDeviceShaderFloat16Int8Features,
+        PhysicalDeviceHostQueryResetFeatures,
+        PhysicalDeviceGlobalPriorityQueryFeaturesKHR,
+        PhysicalDeviceDeviceMemoryReportFeaturesEXT,
+        PhysicalDeviceDescriptorIndexingFeatures,
+        PhysicalDeviceTimelineSemaphoreFeatures,
+        PhysicalDevice8BitStorageFeatures,
+        PhysicalDeviceConditionalRenderingFeaturesEXT,
+        PhysicalDeviceVulkanMemoryModelFeatures,
+        PhysicalDeviceShaderAtomicInt64Features,
+        PhysicalDeviceShaderAtomicFloatFeaturesEXT,
+        PhysicalDeviceShaderAtomicFloat2FeaturesEXT,
+        PhysicalDeviceVertexAttributeDivisorFeaturesEXT,
+        PhysicalDeviceASTCDecodeFeaturesEXT,
+        PhysicalDeviceTransformFeedbackFeaturesEXT,
+        PhysicalDeviceRepresentativeFragmentTestFeaturesNV,
+        PhysicalDeviceExclusiveScissorFeaturesNV,
+        PhysicalDeviceCornerSampledImageFeaturesNV,
+        PhysicalDeviceComputeShaderDerivativesFeaturesNV,
+        PhysicalDeviceShaderImageFootprintFeaturesNV,
+        PhysicalDeviceDedicatedAllocationImageAliasingFeaturesNV,
+        PhysicalDeviceShadingRateImageFeaturesNV,
+        PhysicalDeviceInvocationMaskFeaturesHUAWEI,
+        PhysicalDeviceMeshShaderFeaturesNV,
+        PhysicalDeviceMeshShaderFeaturesEXT,
+        PhysicalDeviceAccelerationStructureFeaturesKHR,
+        PhysicalDeviceRayTracingPipelineFeaturesKHR,
+        PhysicalDeviceRayQueryFeaturesKHR,
+        PhysicalDeviceRayTracingMaintenance1FeaturesKHR,
+        PhysicalDeviceFragmentDensityMapFeaturesEXT,
+        PhysicalDeviceFragmentDensityMap2FeaturesEXT,
+        PhysicalDeviceFragmentDensityMapOffsetFeaturesQCOM,
+        PhysicalDeviceScalarBlockLayoutFeatures,
+        PhysicalDeviceUniformBufferStandardLayoutFeatures,
+        PhysicalDeviceDepthClipEnableFeaturesEXT,
+        PhysicalDeviceMemoryPriorityFeaturesEXT,
+        PhysicalDevicePageableDeviceLocalMemoryFeaturesEXT,
+        PhysicalDeviceBufferDeviceAddressFeatures,
+        PhysicalDeviceBufferDeviceAddressFeaturesEXT,
+        PhysicalDeviceImagelessFramebufferFeatures,
+        PhysicalDeviceTextureCompressionASTCHDRFeatures,
+        PhysicalDeviceCooperativeMatrixFeaturesNV,
+        PhysicalDeviceYcbcrImageArraysFeaturesEXT,
+        PhysicalDevicePresentBarrierFeaturesNV,
+        PhysicalDevicePerformanceQueryFeaturesKHR,
+        PhysicalDeviceCoverageReductionModeFeaturesNV,
+        PhysicalDeviceShaderIntegerFunctions2FeaturesINTEL,
+        PhysicalDeviceShaderClockFeaturesKHR,
+        PhysicalDeviceIndexTypeUint8FeaturesEXT,
+        PhysicalDeviceShaderSMBuiltinsFeaturesNV,
+        PhysicalDeviceFragmentShaderInterlockFeaturesEXT,
+        PhysicalDeviceSeparateDepthStencilLayoutsFeatures,
+        PhysicalDevicePrimitiveTopologyListRestartFeaturesEXT,
+        PhysicalDevicePipelineExecutablePropertiesFeaturesKHR,
+        PhysicalDeviceShaderDemoteToHelperInvocationFeatures,
+        PhysicalDeviceTexelBufferAlignmentFeaturesEXT,
+        PhysicalDeviceSubgroupSizeControlFeatures,
+        PhysicalDeviceLineRasterizationFeaturesEXT,
+        PhysicalDevicePipelineCreationCacheControlFeatures,
+        PhysicalDeviceVulkan11Features,
+        PhysicalDeviceVulkan12Features,
+        PhysicalDeviceVulkan13Features,
+        PhysicalDeviceCoherentMemoryFeaturesAMD,
+        PhysicalDeviceCustomBorderColorFeaturesEXT,
+        PhysicalDeviceBorderColorSwizzleFeaturesEXT,
+        PhysicalDeviceExtendedDynamicStateFeaturesEXT,
+        PhysicalDeviceExtendedDynamicState2FeaturesEXT,
+        PhysicalDeviceExtendedDynamicState3FeaturesEXT,
+        PhysicalDeviceDiagnosticsConfigFeaturesNV,
+        PhysicalDeviceZeroInitializeWorkgroupMemoryFeatures,
+        PhysicalDeviceShaderSubgroupUniformControlFlowFeaturesKHR,
+        PhysicalDeviceRobustness2FeaturesEXT,
+        PhysicalDeviceImageRobustnessFeatures,
+        PhysicalDeviceWorkgroupMemoryExplicitLayoutFeaturesKHR,
+        PhysicalDevicePortabilitySubsetFeaturesKHR,
+        PhysicalDevice4444FormatsFeaturesEXT,
+        PhysicalDeviceSubpassShadingFeaturesHUAWEI,
+        PhysicalDeviceShaderImageAtomicInt64FeaturesEXT,
+        PhysicalDeviceFragmentShadingRateFeaturesKHR,
+        PhysicalDeviceShaderTerminateInvocationFeatures,
+        PhysicalDeviceFragmentShadingRateEnumsFeaturesNV,
+        PhysicalDeviceImage2DViewOf3DFeaturesEXT,
+        PhysicalDeviceMutableDescriptorTypeFeaturesEXT,
+        PhysicalDeviceDepthClipControlFeaturesEXT,
+        PhysicalDeviceVertexInputDynamicStateFeaturesEXT,
+        PhysicalDeviceExternalMemoryRDMAFeaturesNV,
+        PhysicalDeviceColorWriteEnableFeaturesEXT,
+        PhysicalDeviceSynchronization2Features,
+        PhysicalDevicePrimitivesGeneratedQueryFeaturesEXT,
+        PhysicalDeviceLegacyDitheringFeaturesEXT,
+        PhysicalDeviceMultisampledRenderToSingleSampledFeaturesEXT,
+        PhysicalDevicePipelineProtectedAccessFeaturesEXT,
+        PhysicalDeviceInheritedViewportScissorFeaturesNV,
+        PhysicalDeviceYcbcr2Plane444FormatsFeaturesEXT,
+        PhysicalDeviceProvokingVertexFeaturesEXT,
+        PhysicalDeviceShaderIntegerDotProductFeatures,
+        PhysicalDeviceFragmentShaderBarycentricFeaturesKHR,
+        PhysicalDeviceRayTracingMotionBlurFeaturesNV,
+        PhysicalDeviceRGBA10X6FormatsFeaturesEXT,
+        PhysicalDeviceDynamicRenderingFeatures,
+        PhysicalDeviceImageViewMinLodFeaturesEXT,
+        PhysicalDeviceRasterizationOrderAttachmentAccessFeaturesEXT,
+        PhysicalDeviceLinearColorAttachmentFeaturesNV,
+        PhysicalDeviceGraphicsPipelineLibraryFeaturesEXT,
+        PhysicalDeviceDescriptorSetHostMappingFeaturesVALVE,
+        PhysicalDeviceShaderModuleIdentifierFeaturesEXT,
+        PhysicalDeviceImageCompressionControlFeaturesEXT,
+        PhysicalDeviceImageCompressionControlSwapchainFeaturesEXT,
+        PhysicalDeviceSubpassMergeFeedbackFeaturesEXT,
+        PhysicalDeviceOpacityMicromapFeaturesEXT,
+        PhysicalDevicePipelinePropertiesFeaturesEXT,
+        PhysicalDeviceShaderEarlyAndLateFragmentTestsFeaturesAMD,
+        PhysicalDeviceNonSeamlessCubeMapFeaturesEXT,
+        PhysicalDevicePipelineRobustnessFeaturesEXT,
+        PhysicalDeviceImageProcessingFeaturesQCOM,
+        PhysicalDeviceTilePropertiesFeaturesQCOM,
+        PhysicalDeviceAmigoProfilingFeaturesSEC,
+        PhysicalDeviceAttachmentFeedbackLoopLayoutFeaturesEXT,
+        PhysicalDeviceDepthClampZeroOneFeaturesEXT,
+        PhysicalDeviceAddressBindingReportFeaturesEXT,
+        PhysicalDeviceOpticalFlowFeaturesNV,
+        PhysicalDeviceFaultFeaturesEXT,
+    }
+    PhysicalDeviceProperties2 {
+        PhysicalDeviceDeviceGeneratedCommandsPropertiesNV,
+        PhysicalDeviceMultiDrawPropertiesEXT,
+        PhysicalDevicePushDescriptorPropertiesKHR,
+        PhysicalDeviceDriverProperties,
+        PhysicalDeviceIDProperties,
+        PhysicalDeviceMultiviewProperties,
+        PhysicalDeviceDiscardRectanglePropertiesEXT,
+        PhysicalDeviceMultiviewPerViewAttributesPropertiesNVX,
+        PhysicalDeviceSubgroupProperties,
+        PhysicalDevicePointClippingProperties,
+        PhysicalDeviceProtectedMemoryProperties,
+        PhysicalDeviceSamplerFilterMinmaxProperties,
+        PhysicalDeviceSampleLocationsPropertiesEXT,
+        PhysicalDeviceBlendOperationAdvancedPropertiesEXT,
+        PhysicalDeviceInlineUniformBlockProperties,
+        PhysicalDeviceMaintenance3Properties,
+        PhysicalDeviceMaintenance4Properties,
+        PhysicalDeviceFloatControlsProperties,
+        PhysicalDevicePresentationPropertiesANDROID,
+        PhysicalDeviceExternalMemoryHostPropertiesEXT,
+        PhysicalDeviceConservativeRasterizationPropertiesEXT,
+        PhysicalDeviceShaderCorePropertiesAMD,
+        PhysicalDeviceShaderCoreProperties2AMD,
+        PhysicalDeviceDescriptorIndexingProperties,
+        PhysicalDeviceTimelineSemaphoreProperties,
+        PhysicalDeviceVertexAttributeDivisorPropertiesEXT,
+        PhysicalDevicePCIBusInfoPropertiesEXT,
+        PhysicalDeviceDepthStencilResolveProperties,
+        PhysicalDeviceTransformFeedbackPropertiesEXT,
+        PhysicalDeviceShadingRateImagePropertiesNV,
+        PhysicalDeviceMeshShaderPropertiesNV,
+        PhysicalDeviceMeshShaderPropertiesEXT,
+        PhysicalDeviceAccelerationStructurePropertiesKHR,
+        PhysicalDeviceRayTracingPipelinePropertiesKHR,
+        PhysicalDeviceRayTracingPropertiesNV,
+        PhysicalDeviceFragmentDensityMapPropertiesEXT,
+        PhysicalDeviceFragmentDensityMap2PropertiesEXT,
+        PhysicalDeviceFragmentDensityMapOffsetPropertiesQCOM,
+        PhysicalDeviceCooperativeMatrixPropertiesNV,
+        PhysicalDevicePerformanceQueryPropertiesKHR,
+        PhysicalDeviceShaderSMBuiltinsPropertiesNV,
+        PhysicalDeviceTexelBufferAlignmentProperties,
+        PhysicalDeviceSubgroupSizeControlProperties,
+        PhysicalDeviceSubpassShadingPropertiesHUAWEI,
+        PhysicalDeviceLineRasterizationPropertiesEXT,
+        PhysicalDeviceVulkan11Properties,
+        PhysicalDeviceVulkan12Properties,
+        PhysicalDeviceVulkan13Properties,
+        PhysicalDeviceCustomBorderColorPropertiesEXT,
+        PhysicalDeviceExtendedDynamicState3PropertiesEXT,
+        PhysicalDeviceRobustness2PropertiesEXT,
+        PhysicalDevicePortabilitySubsetPropertiesKHR,
+        PhysicalDeviceFragmentShadingRatePropertiesKHR,
+        PhysicalDeviceFragmentShadingRateEnumsPropertiesNV,
+        PhysicalDeviceProvokingVertexPropertiesEXT,
+        PhysicalDeviceShaderIntegerDotProductProperties,
+        PhysicalDeviceDrmPropertiesEXT,
+        PhysicalDeviceFragmentShaderBarycentricPropertiesKHR,
+        PhysicalDeviceGraphicsPipelineLibraryPropertiesEXT,
+        PhysicalDeviceShaderModuleIdentifierPropertiesEXT,
+        PhysicalDeviceOpacityMicromapPropertiesEXT,
+        PhysicalDevicePipelineRobustnessPropertiesEXT,
+        PhysicalDeviceImageProcessingPropertiesQCOM,
+        PhysicalDeviceOpticalFlowPropertiesNV,
+    }
+    FormatProperties2 {
+        DrmFormatModifierPropertiesListEXT,
+        SubpassResolvePerformanceQueryEXT,
+        FormatProperties3,
+        DrmFormatModifierPropertiesList2EXT,
+    }
+    ImageFormatProperties2 {
+        ExternalImageFormatProperties,
+        SamplerYcbcrConversionImageFormatProperties,
+        TextureLODGatherFormatPropertiesAMD,
+        AndroidHardwareBufferUsageANDROID,
+        FilterCubicImageViewImageFormatPropertiesEXT,
+        ImageCompressionPropertiesEXT,
+    }
+    PhysicalDeviceImageFormatInfo2 {
+        PhysicalDeviceExternalImageFormatInfo,
+        ImageFormatListCreateInfo,
+        PhysicalDeviceImageDrmFormatModifierInfoEXT,
+        ImageStencilUsageCreateInfo,
+        PhysicalDeviceImageViewImageFormatInfoEXT,
+        VideoProfileListInfoKHR,
+        ImageCompressionControlEXT,
+        OpticalFlowImageFormatInfoNV,
+    }
+    QueueFamilyProperties2 {
+        QueueFamilyGlobalPriorityPropertiesKHR,
+        QueueFamilyCheckpointPropertiesNV,
+        QueueFamilyCheckpointProperties2NV,
+        QueueFamilyVideoPropertiesKHR,
+        QueueFamilyQueryResultStatusPropertiesKHR,
+    }
+    PhysicalDeviceMemoryProperties2 {
+        PhysicalDeviceMemoryBudgetPropertiesEXT,
+    }
+    PhysicalDeviceExternalSemaphoreInfo {
+        SemaphoreTypeCreateInfo,
+    }
+    BindBufferMemoryInfo {
+        BindBufferMemoryDeviceGroupInfo,
+    }
+    BindImageMemoryInfo {
+        BindImageMemoryDeviceGroupInfo,
+        BindImageMemorySwapchainInfoKHR,
+        BindImagePlaneMemoryInfo,
+    }
+    PhysicalDeviceSurfaceInfo2KHR {
+        SurfaceFullScreenExclusiveInfoEXT,
+        SurfaceFullScreenExclusiveWin32InfoEXT,
+    }
+    SurfaceCapabilities2KHR {
+        DisplayNativeHdrSurfaceCapabilitiesAMD,
+        SharedPresentSurfaceCapabilitiesKHR,
+        SurfaceProtectedCapabilitiesKHR,
+        SurfaceCapabilitiesFullScreenExclusiveEXT,
+        SurfaceCapabilitiesPresentBarrierNV,
+    }
+    SurfaceFormat2KHR {
+        ImageCompressionPropertiesEXT,
+    }
+    ImageMemoryRequirementsInfo2 {
+        ImagePlaneMemoryRequirementsInfo,
+    }
+    MemoryRequirements2 {
+        MemoryDedicatedRequirements,
+    }
+    SamplerYcbcrConversionCreateInfo {
+        ExternalFormatANDROID,
+    }
+    DescriptorSetLayoutSupport {
+        DescriptorSetVariableDescriptorCountLayoutSupport,
+    }
+    DebugUtilsMessengerCallbackDataEXT {
+        DeviceAddressBindingCallbackDataEXT,
+    }
+    AttachmentDescription2 {
+        AttachmentDescriptionStencilLayout,
+    }
+    AttachmentReference2 {
+        AttachmentReferenceStencilLayout,
+    }
+    SubpassDescription2 {
+        SubpassDescriptionDepthStencilResolve,
+        FragmentShadingRateAttachmentInfoKHR,
+        MultisampledRenderToSingleSampledInfoEXT,
+        RenderPassCreationControlEXT,
+        RenderPassSubpassFeedbackCreateInfoEXT,
+    }
+    SubpassDependency2 {
+        MemoryBarrier2,
+    }
+    RenderPassCreateInfo2 {
+        RenderPassFragmentDensityMapCreateInfoEXT,
+        RenderPassCreationControlEXT,
+        RenderPassCreationFeedbackCreateInfoEXT,
+    }
+    SubpassEndInfo {
+        SubpassFragmentDensityMapOffsetEndInfoQCOM,
+    }
+    AndroidHardwareBufferPropertiesANDROID {
+        AndroidHardwareBufferFormatPropertiesANDROID,
+        AndroidHardwareBufferFormatProperties2ANDROID,
+    }
+    RayTracingPipelineCreateInfoNV {
+        PipelineCreationFeedbackCreateInfo,
+    }
+    RayTracingPipelineCreateInfoKHR {
+        PipelineCreationFeedbackCreateInfo,
+        PipelineRobustnessCreateInfoEXT,
+    }
+    AccelerationStructureGeometryTrianglesDataKHR {
+        AccelerationStructureGeometryMotionTrianglesDataNV,
+        AccelerationStructureTrianglesOpacityMicromapEXT,
+    }
+    AccelerationStructureCreateInfoKHR {
+        AccelerationStructureMotionInfoNV,
+    }
+    ImageBlit2 {
+        CopyCommandTransformInfoQCOM,
+    }
+    BufferImageCopy2 {
+        CopyCommandTransformInfoQCOM,
+    }
+    ImageMemoryBarrier2 {
+        SampleLocationsInfoEXT,
+    }
+    SubmitInfo2 {
+        Win32KeyedMutexAcquireReleaseInfoNV,
+        Win32KeyedMutexAcquireReleaseInfoKHR,
+        PerformanceQuerySubmitInfoKHR,
+    }
+    PhysicalDeviceVideoFormatInfoKHR {
+        VideoProfileListInfoKHR,
+    }
+    VideoProfileInfoKHR {
+        VideoDecodeUsageInfoKHR,
+        VideoEncodeUsageInfoKHR,
+    }
+    VideoCapabilitiesKHR {
+        VideoDecodeCapabilitiesKHR,
+        VideoEncodeCapabilitiesKHR,
+        VideoEncodeH264CapabilitiesEXT,
+        VideoEncodeH265CapabilitiesEXT,
+    }
+    VideoCodingControlInfoKHR {
+        VideoEncodeRateControlInfoKHR,
+        VideoEncodeRateControlLayerInfoKHR,
+        VideoEncodeH264RateControlInfoEXT,
+        VideoEncodeH265RateControlInfoEXT,
+    }
+    VideoEncodeInfoKHR {
+        VideoEncodeH264EmitPictureParametersInfoEXT,
+        VideoEncodeH265EmitPictureParametersInfoEXT,
+    }
+    VideoEncodeRateControlLayerInfoKHR {
+        VideoEncodeH264RateControlLayerInfoEXT,
+        VideoEncodeH265RateControlLayerInfoEXT,
+    }
+    RenderingInfo {
+        DeviceGroupRenderPassBeginInfo,
+        MultisampledRenderToSingleSampledInfoEXT,
+        RenderingFragmentShadingRateAttachmentInfoKHR,
+        RenderingFragmentDensityMapAttachmentInfoEXT,
+        MultiviewPerViewAttributesInfoNVX,
+    }
+    SubresourceLayout2EXT {
+        ImageCompressionPropertiesEXT,
+    }
+    ExportMetalObjectsInfoEXT {
+        ExportMetalDeviceInfoEXT,
+        ExportMetalCommandQueueInfoEXT,
+        ExportMetalBufferInfoEXT,
+        ExportMetalTextureInfoEXT,
+        ExportMetalIOSurfaceInfoEXT,
+        ExportMetalSharedEventInfoEXT,
+    }
+    OpticalFlowSessionCreateInfoNV {
+        OpticalFlowSessionCreatePrivateDataInfoNV,
+    }
+}
+
+impl_builders! {
+    ApplicationInfo => ApplicationInfoBuilder {
+        [str application_name = p_application_name]
+        [value application_version: u32]
+        [str engine_name = p_engine_name]
+        [value engine_version: u32]
+        [value api_version: u32]
+    }
+    AllocationCallbacks => AllocationCallbacksBuilder {
+        [value p_user_data: *mut c_void]
+        [value pfn_allocation: crate::pfn::AllocationFunction]
+        [value pfn_reallocation: crate::pfn::ReallocationFunction]
+        [value pfn_free: crate::pfn::FreeFunction]
+        [value pfn_internal_allocation: crate::pfn::InternalAllocationNotification]
+        [value pfn_internal_free: crate::pfn::InternalFreeNotification]
+    }
+    DeviceQueueCreateInfo => DeviceQueueCreateInfoBuilder {
+        [next]
+        [value flags: crate::data::DeviceQueueCreateFlags]
+        [value queue_family_index: u32]
+        [slice queue_priorities = p_queue_priorities, queue_count: c_float]
+    }
+    DeviceCreateInfo => DeviceCreateInfoBuilder {
+        [next]
+        [value flags: crate::data::DeviceCreateFlags]
+        [slice queue_create_infos = p_queue_create_infos, queue_create_info_count: crate::data::DeviceQueueCreateInfo]
+        [slice enabled_layer_names = pp_enabled_layer_names, enabled_layer_count: *const c_char]
+        [slice enabled_extension_names = pp_enabled_extension_names, enabled_extension_count: *const c_char]
+        [ptr enabled_features = p_enabled_features: crate::data::PhysicalDeviceFeatures]
+    }
+    InstanceCreateInfo => InstanceCreateInfoBuilder {
+        [next]
+        [value flags: crate::data::InstanceCreateFlags]
+        [ptr application_info = p_application_info: crate::data::ApplicationInfo]
+        [slice enabled_layer_names = pp_enabled_layer_names, enabled_layer_count: *const c_char]
+        [slice enabled_extension_names = pp_enabled_extension_names, enabled_extension_count: *const c_char]
+    }
+    MemoryAllocateInfo => MemoryAllocateInfoBuilder {
+        [next]
+        [value allocation_size: crate::data::DeviceSize]
+        [value memory_type_index: u32]
+    }
+    MappedMemoryRange => MappedMemoryRangeBuilder {
+        [value memory: crate::data::DeviceMemory]
+        [value offset: crate::data::DeviceSize]
+        [value size: crate::data::DeviceSize]
+    }
+    WriteDescriptorSet => WriteDescriptorSetBuilder (shared) {
+        [next]
+        [value dst_set: crate::data::DescriptorSet]
+        [value dst_binding: u32]
+        [value dst_array_element: u32]
+        [value descriptor_count: u32]
+        [value descriptor_type: crate::data::DescriptorType]
+        [slice image_info = p_image_info, descriptor_count shared(0x1, 0x6): crate::data::DescriptorImageInfo]
+        [slice buffer_info = p_buffer_info, descriptor_count shared(0x2, 0x5): crate::data::DescriptorBufferInfo]
+        [slice texel_buffer_view = p_texel_buffer_view, descriptor_count shared(0x4, 0x3): crate::data::BufferView]
+    }
+    CopyDescriptorSet => CopyDescriptorSetBuilder {
+        [value src_set: crate::data::DescriptorSet]
+        [value src_binding: u32]
+        [value src_array_element: u32]
+        [value dst_set: crate::data::DescriptorSet]
+        [value dst_binding: u32]
+        [value dst_array_element: u32]
+        [value descriptor_count: u32]
+    }
+    BufferCreateInfo => BufferCreateInfoBuilder {
+        [next]
+        [value flags: crate::data::BufferCreateFlags]
+        [value size: crate::data::DeviceSize]
+        [value usage: crate::data::BufferUsageFlags]
+        [value sharing_mode: crate::data::SharingMode]
+        [slice queue_family_indices = p_queue_family_indices, queue_family_index_count: u32]
+    }
+    BufferViewCreateInfo => BufferViewCreateInfoBuilder {
+        [next]
+        [value flags: crate::data::BufferViewCreateFlags]
+        [value buffer: crate::data::Buffer]
+        [value format: crate::data::Format]
+        [value offset: crate::data::DeviceSize]
+        [value range: crate::data::DeviceSize]
+    }
+    MemoryBarrier => MemoryBarrierBuilder {
+        [value src_access_mask: crate::data::AccessFlags]
+        [value dst_access_mask: crate::data::AccessFlags]
+    }
+    BufferMemoryBarrier => BufferMemoryBarrierBuilder {
+        [value src_access_mask: crate::data::AccessFlags]
+        [value dst_access_mask: crate::data::AccessFlags]
+        [value src_queue_family_index: u32]
+        [value dst_queue_family_index: u32]
+        [value buffer: crate::data::Buffer]
+        [value offset: crate::data::DeviceSize]
+        [value size: crate::data::DeviceSize]
+    }
+    ImageMemoryBarrier => ImageMemoryBarrierBuilder {
+        [next]
+        [value src_access_mask: crate::data::AccessFlags]
+        [value dst_access_mask: crate::data::AccessFlags]
+        [value old_layout: crate::data::ImageLayout]
+        [value new_layout: crate::data::ImageLayout]
+        [value src_queue_family_index: u32]
+        [value dst_queue_family_index: u32]
+        [value image: crate::data::Image]
+        [value subresource_range: crate::data::ImageSubresourceRange]
+    }
+    ImageCreateInfo => ImageCreateInfoBuilder {
+        [next]
+        [value flags: crate::data::ImageCreateFlags]
+        [value image_type: crate::data::ImageType]
+        [value format: crate::data::Format]
+        [value extent: crate::data::Extent3D]
+        [value mip_levels: u32]
+        [value array_layers: u32]
+        [value samples: crate::data::SampleCountFlagBits]
+        [value tiling: crate::data::ImageTiling]
+        [value usage: crate::data::ImageUsageFlags]
+        [value sharing_mode: crate::data::SharingMode]
+        [slice queue_family_indices = p_queue_family_indices, queue_family_index_count: u32]
+        [value initial_layout: crate::data::ImageLayout]
+    }
+    ImageViewCreateInfo => ImageViewCreateInfoBuilder {
+        [next]
+        [value flags: crate::data::ImageViewCreateFlags]
+        [value image: crate::data::Image]
+        [value view_type: crate::data::ImageViewType]
+        [value format: crate::data::Format]
+        [value components: crate::data::ComponentMapping]
+        [value subresource_range: crate::data::ImageSubresourceRange]
+    }
+    SparseBufferMemoryBindInfo => SparseBufferMemoryBindInfoBuilder {
+        [value buffer: crate::data::Buffer]
+        [slice binds = p_binds, bind_count: crate::data::SparseMemoryBind]
+    }
+    SparseImageOpaqueMemoryBindInfo => SparseImageOpaqueMemoryBindInfoBuilder {
+        [value image: crate::data::Image]
+        [slice binds = p_binds, bind_count: crate::data::SparseMemoryBind]
+    }
+    SparseImageMemoryBindInfo => SparseImageMemoryBindInfoBuilder {
+        [value image: crate::data::Image]
+        [slice binds = p_binds, bind_count: crate::data::SparseImageMemoryBind]
+    }
+    BindSparseInfo => BindSparseInfoBuilder {
+        [next]
+        [slice wait_semaphores = p_wait_semaphores, wait_semaphore_count: crate::data::Semaphore]
+        [slice buffer_binds = p_buffer_binds, buffer_bind_count: crate::data::SparseBufferMemoryBindInfo]
+        [slice image_opaque_binds = p_image_opaque_binds, image_opaque_bind_count: crate::data::SparseImageOpaqueMemoryBindInfo]
+        [slice image_binds = p_image_binds, image_bind_count: crate::data::SparseImageMemoryBindInfo]
+        [slice signal_semaphores = p_signal_semaphores, signal_semaphore_count: crate::data::Semaphore]
+    }
+    ShaderModuleCreateInfo => ShaderModuleCreateInfoBuilder {
+        [next]
+        [value flags: crate::data::ShaderModuleCreateFlags]
+        [slice code = p_code, code_size * 4: u32]
+    }
+    DescriptorSetLayoutBinding => DescriptorSetLayoutBindingBuilder {
+        [value binding: u32]
+        [value descriptor_type: crate::data::DescriptorType]
+        [value descriptor_count: u32]
+        [value stage_flags: crate::data::ShaderStageFlags]
+        [slice immutable_samplers = p_immutable_samplers, descriptor_count: crate::data::Sampler]
+    }
+    DescriptorSetLayoutCreateInfo => DescriptorSetLayoutCreateInfoBuilder {
+        [next]
+        [value flags: crate::data::DescriptorSetLayoutCreateFlags]
+        [slice bindings = p_bindings, binding_count: crate::data::DescriptorSetLayoutBinding]
+    }
+    DescriptorPoolCreateInfo => DescriptorPoolCreateInfoBuilder {
+        [next]
+        [value flags: crate::data::DescriptorPoolCreateFlags]
+        [value max_sets: u32]
+        [slice pool_sizes = p_pool_sizes, pool_size_count: crate::data::DescriptorPoolSize]
+    }
+    DescriptorSetAllocateInfo => DescriptorSetAllocateInfoBuilder {
+        [next]
+        [value descriptor_pool: crate::data::DescriptorPool]
+        [slice set_layouts = p_set_layouts, descriptor_set_count: crate::data::DescriptorSetLayout]
+    }
+    SpecializationInfo => SpecializationInfoBuilder {
+        [slice map_entries = p_map_entries, map_entry_count: crate::data::SpecializationMapEntry]
+        [slice data = p_data, data_size: u8]
+    }
+    PipelineShaderStageCreateInfo => PipelineShaderStageCreateInfoBuilder {
+        [next]
+        [value flags: crate::data::PipelineShaderStageCreateFlags]
+        [value stage: crate::data::ShaderStageFlagBits]
+        [value module: crate::data::ShaderModule]
+        [str name = p_name]
+        [ptr specialization_info = p_specialization_info: crate::data::SpecializationInfo]
+    }
+    ComputePipelineCreateInfo => ComputePipelineCreateInfoBuilder {
+        [next]
+        [value flags: crate::data::PipelineCreateFlags]
+        [value stage: crate::data::PipelineShaderStageCreateInfo]
+        [value layout: crate::data::PipelineLayout]
+        [value base_pipeline_handle: crate::data::Pipeline]
+        [value base_pipeline_index: i32]
+    }
+    PipelineVertexInputStateCreateInfo => PipelineVertexInputStateCreateInfoBuilder {
+        [next]
+        [value flags: crate::data::PipelineVertexInputStateCreateFlags]
+        [slice vertex_binding_descriptions = p_vertex_binding_descriptions, vertex_binding_description_count: crate::data::VertexInputBindingDescription]
+        [slice vertex_attribute_descriptions = p_vertex_attribute_descriptions, vertex_attribute_description_count: crate::data::VertexInputAttributeDescription]
+    }
+    PipelineInputAssemblyStateCreateInfo => PipelineInputAssemblyStateCreateInfoBuilder {
+        [value flags: crate::data::PipelineInputAssemblyStateCreateFlags]
+        [value topology: crate::data::PrimitiveTopology]
+        [value primitive_restart_enable: crate::data::Bool32]
+    }
+    PipelineTessellationStateCreateInfo => PipelineTessellationStateCreateInfoBuilder {
+        [next]
+        [value flags: crate::data::PipelineTessellationStateCreateFlags]
+        [value patch_control_points: u32]
+    }
+    PipelineViewportStateCreateInfo => PipelineViewportStateCreateInfoBuilder {
+        [next]
+        [value flags: crate::data::PipelineViewportStateCreateFlags]
+        [value viewport_count: u32]
+        [slice viewports = p_viewports, viewport_count: crate::data::Viewport]
+        [value scissor_count: u32]
+        [slice scissors = p_scissors, scissor_count: crate::data::Rect2D]
+    }
+    PipelineRasterizationStateCreateInfo => PipelineRasterizationStateCreateInfoBuilder {
+        [next]
+        [value flags: crate::data::PipelineRasterizationStateCreateFlags]
+        [value depth_clamp_enable: crate::data::Bool32]
+        [value rasterizer_discard_enable: crate::data::Bool32]
+        [value polygon_mode: crate::data::PolygonMode]
+        [value cull_mode: crate::data::CullModeFlags]
+        [value front_face: crate::data::FrontFace]
+        [value depth_bias_enable: crate::data::Bool32]
+        [value depth_bias_constant_factor: c_float]
+        [value depth_bias_clamp: c_float]
+        [value depth_bias_slope_factor: c_float]
+        [value line_width: c_float]
+    }
+    PipelineMultisampleStateCreateInfo => PipelineMultisampleStateCreateInfoBuilder {
+        [next]
+        [value flags: crate::data::PipelineMultisampleStateCreateFlags]
+        [value rasterization_samples: crate::data::SampleCountFlagBits]
+        [value sample_shading_enable: crate::data::Bool32]
+        [value min_sample_shading: c_float]
+        [value p_sample_mask: *const crate::data::SampleMask]
+        [value alpha_to_coverage_enable: crate::data::Bool32]
+        [value alpha_to_one_enable: crate::data::Bool32]
+    }
+    PipelineColorBlendStateCreateInfo => PipelineColorBlendStateCreateInfoBuilder {
+        [next]
+        [value flags: crate::data::PipelineColorBlendStateCreateFlags]
+        [value logic_op_enable: crate::data::Bool32]
+        [value logic_op: crate::data::LogicOp]
+        [slice attachments = p_attachments, attachment_count: crate::data::PipelineColorBlendAttachmentState]
+        [value blend_constants: [c_float; 4]]
+    }
+    PipelineDynamicStateCreateInfo => PipelineDynamicStateCreateInfoBuilder {
+        [value flags: crate::data::PipelineDynamicStateCreateFlags]
+        [slice dynamic_states = p_dynamic_states, dynamic_state_count: crate::data::DynamicState]
+    }
+    PipelineDepthStencilStateCreateInfo => PipelineDepthStencilStateCreateInfoBuilder {
+        [value flags: crate::data::PipelineDepthStencilStateCreateFlags]
+        [value depth_test_enable: crate::data::Bool32]
+        [value depth_write_enable: crate::data::Bool32]
+        [value depth_compare_op: crate::data::CompareOp]
+        [value depth_bounds_test_enable: crate::data::Bool32]
+        [value stencil_test_enable: crate::data::Bool32]
+        [value front: crate::data::StencilOpState]
+        [value back: crate::data::StencilOpState]
+        [value min_depth_bounds: c_float]
+        [value max_depth_bounds: c_float]
+    }
+    GraphicsPipelineCreateInfo => GraphicsPipelineCreateInfoBuilder {
+        [next]
+        [value flags: crate::data::PipelineCreateFlags]
+        [slice stages = p_stages, stage_count: crate::data::PipelineShaderStageCreateInfo]
+        [ptr vertex_input_state = p_vertex_input_state: crate::data::PipelineVertexInputStateCreateInfo]
+        [ptr input_assembly_state = p_input_assembly_state: crate::data::PipelineInputAssemblyStateCreateInfo]
+        [ptr tessellation_state = p_tessellation_state: crate::data::PipelineTessellationStateCreateInfo]
+        [ptr viewport_state = p_viewport_state: crate::data::PipelineViewportStateCreateInfo]
+        [ptr rasterization_state = p_rasterization_state: crate::data::PipelineRasterizationStateCreateInfo]
+        [ptr multisample_state = p_multisample_state: crate::data::PipelineMultisampleStateCreateInfo]
+        [ptr depth_stencil_state = p_depth_stencil_state: crate::data::PipelineDepthStencilStateCreateInfo]
+        [ptr color_blend_state = p_color_blend_state: crate::data::PipelineColorBlendStateCreateInfo]
+        [ptr dynamic_state = p_dynamic_state: crate::data::PipelineDynamicStateCreateInfo]
+        [value layout: crate::data::PipelineLayout]
+        [value render_pass: crate::data::RenderPass]
+        [value subpass: u32]
+        [value base_pipeline_handle: crate::data::Pipeline]
+        [value base_pipeline_index: i32]
+    }
+    PipelineCacheCreateInfo => PipelineCacheCreateInfoBuilder {
+        [value flags: crate::data::PipelineCacheCreateFlags]
+        [slice initial_data = p_initial_data, initial_data_size: u8]
+    }
+    PipelineLayoutCreateInfo => PipelineLayoutCreateInfoBuilder {
+        [value flags: crate::data::PipelineLayoutCreateFlags]
+        [slice set_layouts = p_set_layouts, set_layout_count: crate::data::DescriptorSetLayout]
+        [slice push_constant_ranges = p_push_constant_ranges, push_constant_range_count: crate::data::PushConstantRange]
+    }
+    SamplerCreateInfo => SamplerCreateInfoBuilder {
+        [next]
+        [value flags: crate::data::SamplerCreateFlags]
+        [value mag_filter: crate::data::Filter]
+        [value min_filter: crate::data::Filter]
+        [value mipmap_mode: crate::data::SamplerMipmapMode]
+        [value address_mode_u: crate::data::SamplerAddressMode]
+        [value address_mode_v: crate::data::SamplerAddressMode]
+        [value address_mode_w: crate::data::SamplerAddressMode]
+        [value mip_lod_bias: c_float]
+        [value anisotropy_enable: crate::data::Bool32]
+        [value max_anisotropy: c_float]
+        [value compare_enable: crate::data::Bool32]
+        [value compare_op: crate::data::CompareOp]
+        [value min_lod: c_float]
+        [value max_lod: c_float]
+        [value border_color: crate::data::BorderColor]
+        [value unnormalized_coordinates: crate::data::Bool32]
+    }
+    CommandPoolCreateInfo => CommandPoolCreateInfoBuilder {
+        [value flags: crate::data::CommandPoolCreateFlags]
+        [value queue_family_index: u32]
+    }
+    CommandBufferAllocateInfo => CommandBufferAllocateInfoBuilder {
+        [value command_pool: crate::data::CommandPool]
+        [value level: crate::data::CommandBufferLevel]
+        [value command_buffer_count: u32]
+    }
+    CommandBufferInheritanceInfo => CommandBufferInheritanceInfoBuilder {
+        [next]
+        [value render_pass: crate::data::RenderPass]
+        [value subpass: u32]
+        [value framebuffer: crate::data::Framebuffer]
+        [value occlusion_query_enable: crate::data::Bool32]
+        [value query_flags: crate::data::QueryControlFlags]
+        [value pipeline_statistics: crate::data::QueryPipelineStatisticFlags]
+    }
+    CommandBufferBeginInfo => CommandBufferBeginInfoBuilder {
+        [next]
+        [value flags: crate::data::CommandBufferUsageFlags]
+        [ptr inheritance_info = p_inheritance_info: crate::data::CommandBufferInheritanceInfo]
+    }
+    RenderPassBeginInfo => RenderPassBeginInfoBuilder {
+        [next]
+        [value render_pass: crate::data::RenderPass]
+        [value framebuffer: crate::data::Framebuffer]
+        [value render_area: crate::data::Rect2D]
+        [slice clear_values = p_clear_values, clear_value_count: crate::data::ClearValue]
+    }
+    SubpassDescription => SubpassDescriptionBuilder (shared) {
+        [value flags: crate::data::SubpassDescriptionFlags]
+        [value pipeline_bind_point: crate::data::PipelineBindPoint]
+        [slice input_attachments = p_input_attachments, input_attachment_count: crate::data::AttachmentReference]
+        [value color_attachment_count: u32]
+        [slice color_attachments = p_color_attachments, color_attachment_count shared(0x1, 0x2): crate::data::AttachmentReference]
+        [slice resolve_attachments = p_resolve_attachments, color_attachment_count shared(0x2, 0x1): crate::data::AttachmentReference]
+        [ptr depth_stencil_attachment = p_depth_stencil_attachment: crate::data::AttachmentReference]
+        [slice preserve_attachments = p_preserve_attachments, preserve_attachment_count: u32]
+    }
+    RenderPassCreateInfo => RenderPassCreateInfoBuilder {
+        [next]
+        [value flags: crate::data::RenderPassCreateFlags]
+        [slice attachments = p_attachments, attachment_count: crate::data::AttachmentDescription]
+        [slice subpasses = p_subpasses, subpass_count: crate::data::SubpassDescription]
+        [slice dependencies = p_dependencies, dependency_count: crate::data::SubpassDependency]
+    }
+    EventCreateInfo => EventCreateInfoBuilder {
+        [next]
+        [value flags: crate::data::EventCreateFlags]
+    }
+    FenceCreateInfo => FenceCreateInfoBuilder {
+        [next]
+        [value flags: crate::data::FenceCreateFlags]
+    }
+    SemaphoreCreateInfo => SemaphoreCreateInfoBuilder {
+        [next]
+        [value flags: crate::data::SemaphoreCreateFlags]
+    }
+    QueryPoolCreateInfo => QueryPoolCreateInfoBuilder {
+        [next]
+        [value flags: crate::data::QueryPoolCreateFlags]
+        [value query_type: crate::data::QueryType]
+        [value query_count: u32]
+        [value pipeline_statistics: crate::data::QueryPipelineStatisticFlags]
+    }
+    FramebufferCreateInfo => FramebufferCreateInfoBuilder {
+        [next]
+        [value flags: crate::data::FramebufferCreateFlags]
+        [value render_pass: crate::data::RenderPass]
+        [slice attachments = p_attachments, attachment_count: crate::data::ImageView]
+        [value width: u32]
+        [value height: u32]
+        [value layers: u32]
+    }
+    SubmitInfo => SubmitInfoBuilder (shared) {
+        [next]
+        [slice wait_semaphores = p_wait_semaphores, wait_semaphore_count shared(0x1, 0x2): crate::data::Semaphore]
+        [slice wait_dst_stage_mask = p_wait_dst_stage_mask, wait_semaphore_count shared(0x2, 0x1): crate::data::PipelineStageFlags]
+        [slice command_buffers = p_command_buffers, command_buffer_count: crate::data::CommandBuffer]
+        [slice signal_semaphores = p_signal_semaphores, signal_semaphore_count: crate::data::Semaphore]
+    }
+    DisplayPropertiesKHR => DisplayPropertiesKHRBuilder {
+        [value display: crate::data::DisplayKHR]
+        [str display_name = display_name]
+        [value physical_dimensions: crate::data::Extent2D]
+        [value physical_resolution: crate::data::Extent2D]
+        [value supported_transforms: crate::data::SurfaceTransformFlagsKHR]
+        [value plane_reorder_possible: crate::data::Bool32]
+        [value persistent_content: crate::data::Bool32]
+    }
+    DisplayModeCreateInfoKHR => DisplayModeCreateInfoKHRBuilder {
+        [value flags: crate::data::DisplayModeCreateFlagsKHR]
+        [value parameters: crate::data::DisplayModeParametersKHR]
+    }
+    DisplaySurfaceCreateInfoKHR => DisplaySurfaceCreateInfoKHRBuilder {
+        [value flags: crate::data::DisplaySurfaceCreateFlagsKHR]
+        [value display_mode: crate::data::DisplayModeKHR]
+        [value plane_index: u32]
+        [value plane_stack_index: u32]
+        [value transform: crate::data::SurfaceTransformFlagBitsKHR]
+        [value global_alpha: c_float]
+        [value alpha_mode: crate::data::DisplayPlaneAlphaFlagBitsKHR]
+        [value image_extent: crate::data::Extent2D]
+    }
+    DisplayPresentInfoKHR => DisplayPresentInfoKHRBuilder {
+        [value src_rect: crate::data::Rect2D]
+        [value dst_rect: crate::data::Rect2D]
+        [value persistent: crate::data::Bool32]
+    }
+    AndroidSurfaceCreateInfoKHR => AndroidSurfaceCreateInfoKHRBuilder {
+        [value flags: crate::data::AndroidSurfaceCreateFlagsKHR]
+        [value window: *mut ANativeWindow]
+    }
+    ViSurfaceCreateInfoNN => ViSurfaceCreateInfoNNBuilder {
+        [value flags: crate::data::ViSurfaceCreateFlagsNN]
+        [value window: *mut c_void]
+    }
+    WaylandSurfaceCreateInfoKHR => WaylandSurfaceCreateInfoKHRBuilder {
+        [value flags: crate::data::WaylandSurfaceCreateFlagsKHR]
+        [value display: *mut wl_display]
+        [value surface: *mut wl_surface]
+    }
+    Win32SurfaceCreateInfoKHR => Win32SurfaceCreateInfoKHRBuilder {
+        [value flags: crate::data::Win32SurfaceCreateFlagsKHR]
+        [value hinstance: HINSTANCE]
+        [value hwnd: HWND]
+    }
+    XlibSurfaceCreateInfoKHR => XlibSurfaceCreateInfoKHRBuilder {
+        [value flags: crate::data::XlibSurfaceCreateFlagsKHR]
+        [value dpy: *mut Display]
+        [value window: Window]
+    }
+    XcbSurfaceCreateInfoKHR => XcbSurfaceCreateInfoKHRBuilder {
+        [value flags: crate::data::XcbSurfaceCreateFlagsKHR]
+        [value connection: *mut xcb_connection_t]
+        [value window: xcb_window_t]
+    }
+    DirectFBSurfaceCreateInfoEXT => DirectFBSurfaceCreateInfoEXTBuilder {
+        [value flags: crate::data::DirectFBSurfaceCreateFlagsEXT]
+        [value dfb: *mut IDirectFB]
+        [value surface: *mut IDirectFBSurface]
+    }
+    ImagePipeSurfaceCreateInfoFUCHSIA => ImagePipeSurfaceCreateInfoFUCHSIABuilder {
+        [value flags: crate::data::ImagePipeSurfaceCreateFlagsFUCHSIA]
+        [value image_pipe_handle: zx_handle_t]
+    }
+    StreamDescriptorSurfaceCreateInfoGGP => StreamDescriptorSurfaceCreateInfoGGPBuilder {
+        [value flags: crate::data::StreamDescriptorSurfaceCreateFlagsGGP]
+        [value stream_descriptor: GgpStreamDescriptor]
+    }
+    ScreenSurfaceCreateInfoQNX => ScreenSurfaceCreateInfoQNXBuilder {
+        [value flags: crate::data::ScreenSurfaceCreateFlagsQNX]
+        [value context: *mut _screen_context]
+        [value window: *mut _screen_window]
+    }
+    SwapchainCreateInfoKHR => SwapchainCreateInfoKHRBuilder {
+        [next]
+        [value flags: crate::data::SwapchainCreateFlagsKHR]
+        [value surface: crate::data::SurfaceKHR]
+        [value min_image_count: u32]
+        [value image_format: crate::data::Format]
+        [value image_color_space: crate::data::ColorSpaceKHR]
+        [value image_extent: crate::data::Extent2D]
+        [value image_array_layers: u32]
+        [value image_usage: crate::data::ImageUsageFlags]
+        [value image_sharing_mode: crate::data::SharingMode]
+        [slice queue_family_indices = p_queue_family_indices, queue_family_index_count: u32]
+        [value pre_transform: crate::data::SurfaceTransformFlagBitsKHR]
+        [value composite_alpha: crate::data::CompositeAlphaFlagBitsKHR]
+        [value present_mode: crate::data::PresentModeKHR]
+        [value clipped: crate::data::Bool32]
+        [value old_swapchain: crate::data::SwapchainKHR]
+    }
+    PresentInfoKHR => PresentInfoKHRBuilder (shared) {
+        [next]
+        [slice wait_semaphores = p_wait_semaphores, wait_semaphore_count: crate::data::Semaphore]
+        [value swapchain_count: u32]
+        [slice swapchains = p_swapchains, swapchain_count shared(0x1, 0x6): crate::data::SwapchainKHR]
+        [slice image_indices = p_image_indices, swapchain_count shared(0x2, 0x5): u32]
+        [slice_mut results = p_results, swapchain_count shared(0x4, 0x3): crate::data::Result]
+    }
+    DebugReportCallbackCreateInfoEXT => DebugReportCallbackCreateInfoEXTBuilder {
+        [value flags: crate::data::DebugReportFlagsEXT]
+        [value pfn_callback: crate::pfn::DebugReportCallbackEXT]
+        [value p_user_data: *mut c_void]
+    }
+    ValidationFlagsEXT => ValidationFlagsEXTBuilder {
+        [slice disabled_validation_checks = p_disabled_validation_checks, disabled_validation_check_count: crate::data::ValidationCheckEXT]
+    }
+    ValidationFeaturesEXT => ValidationFeaturesEXTBuilder {
+        [slice enabled_validation_features = p_enabled_validation_features, enabled_validation_feature_count: crate::data::ValidationFeatureEnableEXT]
+        [slice disabled_validation_features = p_disabled_validation_features, disabled_validation_feature_count: crate::data::ValidationFeatureDisableEXT]
+    }
+    PipelineRasterizationStateRasterizationOrderAMD => PipelineRasterizationStateRasterizationOrderAMDBuilder {
+        [value rasterization_order: crate::data::RasterizationOrderAMD]
+    }
+    DebugMarkerObjectNameInfoEXT => DebugMarkerObjectNameInfoEXTBuilder {
+        [value object_type: crate::data::DebugReportObjectTypeEXT]
+        [value object: u64]
+        [str object_name = p_object_name]
+    }
+    DebugMarkerObjectTagInfoEXT => DebugMarkerObjectTagInfoEXTBuilder {
+        [value object_type: crate::data::DebugReportObjectTypeEXT]
+        [value object: u64]
+        [value tag_name: u64]
+        [slice tag = p_tag, tag_size: u8]
+    }
+    DebugMarkerMarkerInfoEXT => DebugMarkerMarkerInfoEXTBuilder {
+        [str marker_name = p_marker_name]
+        [value color: [c_float; 4]]
+    }
+    DedicatedAllocationImageCreateInfoNV => DedicatedAllocationImageCreateInfoNVBuilder {
+        [value dedicated_allocation: crate::data::Bool32]
+    }
+    DedicatedAllocationBufferCreateInfoNV => DedicatedAllocationBufferCreateInfoNVBuilder {
+        [value dedicated_allocation: crate::data::Bool32]
+    }
+    DedicatedAllocationMemoryAllocateInfoNV => DedicatedAllocationMemoryAllocateInfoNVBuilder {
+        [value image: crate::data::Image]
+        [value buffer: crate::data::Buffer]
+    }
+    ExternalMemoryImageCreateInfoNV => ExternalMemoryImageCreateInfoNVBuilder {
+        [value handle_types: crate::data::ExternalMemoryHandleTypeFlagsNV]
+    }
+    ExportMemoryAllocateInfoNV => ExportMemoryAllocateInfoNVBuilder {
+        [value handle_types: crate::data::ExternalMemoryHandleTypeFlagsNV]
+    }
+    ImportMemoryWin32HandleInfoNV => ImportMemoryWin32HandleInfoNVBuilder {
+        [value handle_type: crate::data::ExternalMemoryHandleTypeFlagsNV]
+        [value handle: HANDLE]
+    }
+    ExportMemoryWin32HandleInfoNV => ExportMemoryWin32HandleInfoNVBuilder {
+        [value p_attributes: *const SECURITY_ATTRIBUTES]
+        [value dw_access: DWORD]
+    }
+    Win32KeyedMutexAcquireReleaseInfoNV => Win32KeyedMutexAcquireReleaseInfoNVBuilder (shared) {
+        [slice acquire_syncs = p_acquire_syncs, acquire_count shared(0x1, 0x6): crate::data::DeviceMemory]
+        [slice acquire_keys = p_acquire_keys, acquire_count shared(0x2, 0x5): u64]
+        [slice acquire_timeout_milliseconds = p_acquire_timeout_milliseconds, acquire_count shared(0x4, 0x3): u32]
+        [slice release_syncs = p_release_syncs, release_count shared(0x8, 0x10): crate::data::DeviceMemory]
+        [slice release_keys = p_release_keys, release_count shared(0x10, 0x8): u64]
+    }
+    PhysicalDeviceDeviceGeneratedCommandsFeaturesNV => PhysicalDeviceDeviceGeneratedCommandsFeaturesNVBuilder {
+        [value device_generated_commands: crate::data::Bool32]
+    }
+    DevicePrivateDataCreateInfo => DevicePrivateDataCreateInfoBuilder {
+        [value private_data_slot_request_count: u32]
+    }
+    PrivateDataSlotCreateInfo => PrivateDataSlotCreateInfoBuilder {
+        [value flags: crate::data::PrivateDataSlotCreateFlags]
+    }
+    PhysicalDevicePrivateDataFeatures => PhysicalDevicePrivateDataFeaturesBuilder {
+        [value private_data: crate::data::Bool32]
+    }
+    PhysicalDeviceDeviceGeneratedCommandsPropertiesNV => PhysicalDeviceDeviceGeneratedCommandsPropertiesNVBuilder {
+        [value max_graphics_shader_group_count: u32]
+        [value max_indirect_sequence_count: u32]
+        [value max_indirect_commands_token_count: u32]
+        [value max_indirect_commands_stream_count: u32]
+        [value max_indirect_commands_token_offset: u32]
+        [value max_indirect_commands_stream_stride: u32]
+        [value min_sequences_count_buffer_offset_alignment: u32]
+        [value min_sequences_index_buffer_offset_alignment: u32]
+        [value min_indirect_commands_buffer_offset_alignment: u32]
+    }
+    PhysicalDeviceMultiDrawPropertiesEXT => PhysicalDeviceMultiDrawPropertiesEXTBuilder {
+        [value max_multi_draw_count: u32]
+    }
+    GraphicsShaderGroupCreateInfoNV => GraphicsShaderGroupCreateInfoNVBuilder {
+        [slice stages = p_stages, stage_count: crate::data::PipelineShaderStageCreateInfo]
+        [ptr vertex_input_state = p_vertex_input_state: crate::data::PipelineVertexInputStateCreateInfo]
+        [ptr tessellation_state = p_tessellation_state: crate::data::PipelineTessellationStateCreateInfo]
+    }
+    GraphicsPipelineShaderGroupsCreateInfoNV => GraphicsPipelineShaderGroupsCreateInfoNVBuilder {
+        [slice groups = p_groups, group_count: crate::data::GraphicsShaderGroupCreateInfoNV]
+        [slice pipelines = p_pipelines, pipeline_count: crate::data::Pipeline]
+    }
+    IndirectCommandsLayoutTokenNV => IndirectCommandsLayoutTokenNVBuilder (shared) {
+        [value token_type: crate::data::IndirectCommandsTokenTypeNV]
+        [value stream: u32]
+        [value offset: u32]
+        [value vertex_binding_unit: u32]
+        [value vertex_dynamic_stride: crate::data::Bool32]
+        [value pushconstant_pipeline_layout: crate::data::PipelineLayout]
+        [value pushconstant_shader_stage_flags: crate::data::ShaderStageFlags]
+        [value pushconstant_offset: u32]
+        [value pushconstant_size: u32]
+        [value indirect_state_flags: crate::data::IndirectStateFlagsNV]
+        [slice index_types = p_index_types, index_type_count shared(0x1, 0x2): crate::data::IndexType]
+        [slice index_type_values = p_index_type_values, index_type_count shared(0x2, 0x1): u32]
+    }
+    IndirectCommandsLayoutCreateInfoNV => IndirectCommandsLayoutCreateInfoNVBuilder {
+        [value flags: crate::data::IndirectCommandsLayoutUsageFlagsNV]
+        [value pipeline_bind_point: crate::data::PipelineBindPoint]
+        [slice tokens = p_tokens, token_count: crate::data::IndirectCommandsLayoutTokenNV]
+        [slice stream_strides = p_stream_strides, stream_count: u32]
+    }
+    GeneratedCommandsInfoNV => GeneratedCommandsInfoNVBuilder {
+        [value pipeline_bind_point: crate::data::PipelineBindPoint]
+        [value pipeline: crate::data::Pipeline]
+        [value indirect_commands_layout: crate::data::IndirectCommandsLayoutNV]
+        [slice streams = p_streams, stream_count: crate::data::IndirectCommandsStreamNV]
+        [value sequences_count: u32]
+        [value preprocess_buffer: crate::data::Buffer]
+        [value preprocess_offset: crate::data::DeviceSize]
+        [value preprocess_size: crate::data::DeviceSize]
+        [value sequences_count_buffer: crate::data::Buffer]
+        [value sequences_count_offset: crate::data::DeviceSize]
+        [value sequences_index_buffer: crate::data::Buffer]
+        [value sequences_index_offset: crate::data::DeviceSize]
+    }
+    GeneratedCommandsMemoryRequirementsInfoNV => GeneratedCommandsMemoryRequirementsInfoNVBuilder {
+        [value pipeline_bind_point: crate::data::PipelineBindPoint]
+        [value pipeline: crate::data::Pipeline]
+        [value indirect_commands_layout: crate::data::IndirectCommandsLayoutNV]
+        [value max_sequences_count: u32]
+    }
+    PhysicalDeviceFeatures2 => PhysicalDeviceFeatures2Builder {
+        [next]
+        [value features: crate::data::PhysicalDeviceFeatures]
+    }
+    PhysicalDeviceProperties2 => PhysicalDeviceProperties2Builder {
+        [next]
+        [value properties: crate::data::PhysicalDeviceProperties]
+    }
+    FormatProperties2 => FormatProperties2Builder {
+        [next]
+        [value format_properties: crate::data::FormatProperties]
+    }
+    ImageFormatProperties2 => ImageFormatProperties2Builder {
+        [next]
+        [value image_format_properties: crate::data::ImageFormatProperties]
+    }
+    PhysicalDeviceImageFormatInfo2 => PhysicalDeviceImageFormatInfo2Builder {
+        [next]
+        [value format: crate::data::Format]
+        [value ty: crate::data::ImageType]
+        [value tiling: crate::data::ImageTiling]
+        [value usage: crate::data::ImageUsageFlags]
+        [value flags: crate::data::ImageCreateFlags]
+    }
+    QueueFamilyProperties2 => QueueFamilyProperties2Builder {
+        [next]
+        [value queue_family_properties: crate::data::QueueFamilyProperties]
+    }
+    PhysicalDeviceMemoryProperties2 => PhysicalDeviceMemoryProperties2Builder {
+        [next]
+        [value memory_properties: crate::data::PhysicalDeviceMemoryProperties]
+    }
+    SparseImageFormatProperties2 => SparseImageFormatProperties2Builder {
+        [value properties: crate::data::SparseImageFormatProperties]
+    }
+    PhysicalDeviceSparseImageFormatInfo2 => PhysicalDeviceSparseImageFormatInfo2Builder {
+        [value format: crate::data::Format]
+        [value ty: crate::data::ImageType]
+        [value samples: crate::data::SampleCountFlagBits]
+        [value usage: crate::data::ImageUsageFlags]
+        [value tiling: crate::data::ImageTiling]
+    }
+    PhysicalDevicePushDescriptorPropertiesKHR => PhysicalDevicePushDescriptorPropertiesKHRBuilder {
+        [value max_push_descriptors: u32]
+    }
+    PhysicalDeviceDriverProperties => PhysicalDeviceDriverPropertiesBuilder {
+        [value driver_id: crate::data::DriverId]
+        [value driver_name: [c_char; 256]]
+        [value driver_info: [c_char; 256]]
+        [value conformance_version: crate::data::ConformanceVersion]
+    }
+    PresentRegionsKHR => PresentRegionsKHRBuilder {
+        [slice regions = p_regions, swapchain_count: crate::data::PresentRegionKHR]
+    }
+    PresentRegionKHR => PresentRegionKHRBuilder {
+        [slice rectangles = p_rectangles, rectangle_count: crate::data::RectLayerKHR]
+    }
+    PhysicalDeviceVariablePointersFeatures => PhysicalDeviceVariablePointersFeaturesBuilder {
+        [value variable_pointers_storage_buffer: crate::data::Bool32]
+        [value variable_pointers: crate::data::Bool32]
+    }
+    PhysicalDeviceExternalImageFormatInfo => PhysicalDeviceExternalImageFormatInfoBuilder {
+        [value handle_type: crate::data::ExternalMemoryHandleTypeFlagBits]
+    }
+    ExternalImageFormatProperties => ExternalImageFormatPropertiesBuilder {
+        [value external_memory_properties: crate::data::ExternalMemoryProperties]
+    }
+    PhysicalDeviceExternalBufferInfo => PhysicalDeviceExternalBufferInfoBuilder {
+        [value flags: crate::data::BufferCreateFlags]
+        [value usage: crate::data::BufferUsageFlags]
+        [value handle_type: crate::data::ExternalMemoryHandleTypeFlagBits]
+    }
+    ExternalBufferProperties => ExternalBufferPropertiesBuilder {
+        [value external_memory_properties: crate::data::ExternalMemoryProperties]
+    }
+    PhysicalDeviceIDProperties => PhysicalDeviceIDPropertiesBuilder {
+        [value device_uuid: [u8; 16]]
+        [value driver_uuid: [u8; 16]]
+        [value device_luid: [u8; 8]]
+        [value device_node_mask: u32]
+        [value device_luid_valid: crate::data::Bool32]
+    }
+    ExternalMemoryImageCreateInfo => ExternalMemoryImageCreateInfoBuilder {
+        [value handle_types: crate::data::ExternalMemoryHandleTypeFlags]
+    }
+    ExternalMemoryBufferCreateInfo => ExternalMemoryBufferCreateInfoBuilder {
+        [value handle_types: crate::data::ExternalMemoryHandleTypeFlags]
+    }
+    ExportMemoryAllocateInfo => ExportMemoryAllocateInfoBuilder {
+        [value handle_types: crate::data::ExternalMemoryHandleTypeFlags]
+    }
+    ImportMemoryWin32HandleInfoKHR => ImportMemoryWin32HandleInfoKHRBuilder {
+        [value handle_type: crate::data::ExternalMemoryHandleTypeFlagBits]
+        [value handle: HANDLE]
+        [value name: LPCWSTR]
+    }
+    ExportMemoryWin32HandleInfoKHR => ExportMemoryWin32HandleInfoKHRBuilder {
+        [value p_attributes: *const SECURITY_ATTRIBUTES]
+        [value dw_access: DWORD]
+        [value name: LPCWSTR]
+    }
+    ImportMemoryZirconHandleInfoFUCHSIA => ImportMemoryZirconHandleInfoFUCHSIABuilder {
+        [value handle_type: crate::data::ExternalMemoryHandleTypeFlagBits]
+        [value handle: zx_handle_t]
+    }
+    MemoryZirconHandlePropertiesFUCHSIA => MemoryZirconHandlePropertiesFUCHSIABuilder {
+        [value memory_type_bits: u32]
+    }
+    MemoryGetZirconHandleInfoFUCHSIA => MemoryGetZirconHandleInfoFUCHSIABuilder {
+        [value memory: crate::data::DeviceMemory]
+        [value handle_type: crate::data::ExternalMemoryHandleTypeFlagBits]
+    }
+    MemoryWin32HandlePropertiesKHR => MemoryWin32HandlePropertiesKHRBuilder {
+        [value memory_type_bits: u32]
+    }
+    MemoryGetWin32HandleInfoKHR => MemoryGetWin32HandleInfoKHRBuilder {
+        [value memory: crate::data::DeviceMemory]
+        [value handle_type: crate::data::ExternalMemoryHandleTypeFlagBits]
+    }
+    ImportMemoryFdInfoKHR => ImportMemoryFdInfoKHRBuilder {
+        [value handle_type: crate::data::ExternalMemoryHandleTypeFlagBits]
+        [value fd: c_int]
+    }
+    MemoryFdPropertiesKHR => MemoryFdPropertiesKHRBuilder {
+        [value memory_type_bits: u32]
+    }
+    MemoryGetFdInfoKHR => MemoryGetFdInfoKHRBuilder {
+        [value memory: crate::data::DeviceMemory]
+        [value handle_type: crate::data::ExternalMemoryHandleTypeFlagBits]
+    }
+    Win32KeyedMutexAcquireReleaseInfoKHR => Win32KeyedMutexAcquireReleaseInfoKHRBuilder (shared) {
+        [slice acquire_syncs = p_acquire_syncs, acquire_count shared(0x1, 0x6): crate::data::DeviceMemory]
+        [slice acquire_keys = p_acquire_keys, acquire_count shared(0x2, 0x5): u64]
+        [slice acquire_timeouts = p_acquire_timeouts, acquire_count shared(0x4, 0x3): u32]
+        [slice release_syncs = p_release_syncs, release_count shared(0x8, 0x10): crate::data::DeviceMemory]
+        [slice release_keys = p_release_keys, release_count shared(0x10, 0x8): u64]
+    }
+    PhysicalDeviceExternalSemaphoreInfo => PhysicalDeviceExternalSemaphoreInfoBuilder {
+        [next]
+        [value handle_type: crate::data::ExternalSemaphoreHandleTypeFlagBits]
+    }
+    ExternalSemaphoreProperties => ExternalSemaphorePropertiesBuilder {
+        [value export_from_imported_handle_types: crate::data::ExternalSemaphoreHandleTypeFlags]
+        [value compatible_handle_types: crate::data::ExternalSemaphoreHandleTypeFlags]
+        [value external_semaphore_features: crate::data::ExternalSemaphoreFeatureFlags]
+    }
+    ExportSemaphoreCreateInfo => ExportSemaphoreCreateInfoBuilder {
+        [value handle_types: crate::data::ExternalSemaphoreHandleTypeFlags]
+    }
+    ImportSemaphoreWin32HandleInfoKHR => ImportSemaphoreWin32HandleInfoKHRBuilder {
+        [value semaphore: crate::data::Semaphore]
+        [value flags: crate::data::SemaphoreImportFlags]
+        [value handle_type: crate::data::ExternalSemaphoreHandleTypeFlagBits]
+        [value handle: HANDLE]
+        [value name: LPCWSTR]
+    }
+    ExportSemaphoreWin32HandleInfoKHR => ExportSemaphoreWin32HandleInfoKHRBuilder {
+        [value p_attributes: *const SECURITY_ATTRIBUTES]
+        [value dw_access: DWORD]
+        [value name: LPCWSTR]
+    }
+    D3D12FenceSubmitInfoKHR => D3D12FenceSubmitInfoKHRBuilder {
+        [slice wait_semaphore_values = p_wait_semaphore_values, wait_semaphore_values_count: u64]
+        [slice signal_semaphore_values = p_signal_semaphore_values, signal_semaphore_values_count: u64]
+    }
+    SemaphoreGetWin32HandleInfoKHR => SemaphoreGetWin32HandleInfoKHRBuilder {
+        [value semaphore: crate::data::Semaphore]
+        [value handle_type: crate::data::ExternalSemaphoreHandleTypeFlagBits]
+    }
+    ImportSemaphoreFdInfoKHR => ImportSemaphoreFdInfoKHRBuilder {
+        [value semaphore: crate::data::Semaphore]
+        [value flags: crate::data::SemaphoreImportFlags]
+        [value handle_type: crate::data::ExternalSemaphoreHandleTypeFlagBits]
+        [value fd: c_int]
+    }
+    SemaphoreGetFdInfoKHR => SemaphoreGetFdInfoKHRBuilder {
+        [value semaphore: crate::data::Semaphore]
+        [value handle_type: crate::data::ExternalSemaphoreHandleTypeFlagBits]
+    }
+    ImportSemaphoreZirconHandleInfoFUCHSIA => ImportSemaphoreZirconHandleInfoFUCHSIABuilder {
+        [value semaphore: crate::data::Semaphore]
+        [value flags: crate::data::SemaphoreImportFlags]
+        [value handle_type: crate::data::ExternalSemaphoreHandleTypeFlagBits]
+        [value zircon_handle: zx_handle_t]
+    }
+    SemaphoreGetZirconHandleInfoFUCHSIA => SemaphoreGetZirconHandleInfoFUCHSIABuilder {
+        [value semaphore: crate::data::Semaphore]
+        [value handle_type: crate::data::ExternalSemaphoreHandleTypeFlagBits]
+    }
+    PhysicalDeviceExternalFenceInfo => PhysicalDeviceExternalFenceInfoBuilder {
+        [value handle_type: crate::data::ExternalFenceHandleTypeFlagBits]
+    }
+    ExternalFenceProperties => ExternalFencePropertiesBuilder {
+        [value export_from_imported_handle_types: crate::data::ExternalFenceHandleTypeFlags]
+        [value compatible_handle_types: crate::data::ExternalFenceHandleTypeFlags]
+        [value external_fence_features: crate::data::ExternalFenceFeatureFlags]
+    }
+    ExportFenceCreateInfo => ExportFenceCreateInfoBuilder {
+        [value handle_types: crate::data::ExternalFenceHandleTypeFlags]
+    }
+    ImportFenceWin32HandleInfoKHR => ImportFenceWin32HandleInfoKHRBuilder {
+        [value fence: crate::data::Fence]
+        [value flags: crate::data::FenceImportFlags]
+        [value handle_type: crate::data::ExternalFenceHandleTypeFlagBits]
+        [value handle: HANDLE]
+        [value name: LPCWSTR]
+    }
+    ExportFenceWin32HandleInfoKHR => ExportFenceWin32HandleInfoKHRBuilder {
+        [value p_attributes: *const SECURITY_ATTRIBUTES]
+        [value dw_access: DWORD]
+        [value name: LPCWSTR]
+    }
+    FenceGetWin32HandleInfoKHR => FenceGetWin32HandleInfoKHRBuilder {
+        [value fence: crate::data::Fence]
+        [value handle_type: crate::data::ExternalFenceHandleTypeFlagBits]
+    }
+    ImportFenceFdInfoKHR => ImportFenceFdInfoKHRBuilder {
+        [value fence: crate::data::Fence]
+        [value flags: crate::data::FenceImportFlags]
+        [value handle_type: crate::data::ExternalFenceHandleTypeFlagBits]
+        [value fd: c_int]
+    }
+    FenceGetFdInfoKHR => FenceGetFdInfoKHRBuilder {
+        [value fence: crate::data::Fence]
+        [value handle_type: crate::data::ExternalFenceHandleTypeFlagBits]
+    }
+    PhysicalDeviceMultiviewFeatures => PhysicalDeviceMultiviewFeaturesBuilder {
+        [value multiview: crate::data::Bool32]
+        [value multiview_geometry_shader: crate::data::Bool32]
+        [value multiview_tessellation_shader: crate::data::Bool32]
+    }
+    PhysicalDeviceMultiviewProperties => PhysicalDeviceMultiviewPropertiesBuilder {
+        [value max_multiview_view_count: u32]
+        [value max_multiview_instance_index: u32]
+    }
+    RenderPassMultiviewCreateInfo => RenderPassMultiviewCreateInfoBuilder {
+        [slice view_masks = p_view_masks, subpass_count: u32]
+        [slice view_offsets = p_view_offsets, dependency_count: i32]
+        [slice correlation_masks = p_correlation_masks, correlation_mask_count: u32]
+    }
+    SurfaceCapabilities2EXT => SurfaceCapabilities2EXTBuilder {
+        [value min_image_count: u32]
+        [value max_image_count: u32]
+        [value current_extent: crate::data::Extent2D]
+        [value min_image_extent: crate::data::Extent2D]
+        [value max_image_extent: crate::data::Extent2D]
+        [value max_image_array_layers: u32]
+        [value supported_transforms: crate::data::SurfaceTransformFlagsKHR]
+        [value current_transform: crate::data::SurfaceTransformFlagBitsKHR]
+        [value supported_composite_alpha: crate::data::CompositeAlphaFlagsKHR]
+        [value supported_usage_flags: crate::data::ImageUsageFlags]
+        [value supported_surface_counters: crate::data::SurfaceCounterFlagsEXT]
+    }
+    DisplayPowerInfoEXT => DisplayPowerInfoEXTBuilder {
+        [value power_state: crate::data::DisplayPowerStateEXT]
+    }
+    DeviceEventInfoEXT => DeviceEventInfoEXTBuilder {
+        [value device_event: crate::data::DeviceEventTypeEXT]
+    }
+    DisplayEventInfoEXT => DisplayEventInfoEXTBuilder {
+        [value display_event: crate::data::DisplayEventTypeEXT]
+    }
+    SwapchainCounterCreateInfoEXT => SwapchainCounterCreateInfoEXTBuilder {
+        [value surface_counters: crate::data::SurfaceCounterFlagsEXT]
+    }
+    PhysicalDeviceGroupProperties => PhysicalDeviceGroupPropertiesBuilder {
+        [value physical_device_count: u32]
+        [value physical_devices: [crate::data::PhysicalDevice; 32]]
+        [value subset_allocation: crate::data::Bool32]
+    }
+    MemoryAllocateFlagsInfo => MemoryAllocateFlagsInfoBuilder {
+        [value flags: crate::data::MemoryAllocateFlags]
+        [value device_mask: u32]
+    }
+    BindBufferMemoryInfo => BindBufferMemoryInfoBuilder {
+        [next]
+        [value buffer: crate::data::Buffer]
+        [value memory: crate::data::DeviceMemory]
+        [value memory_offset: crate::data::DeviceSize]
+    }
+    BindBufferMemoryDeviceGroupInfo => BindBufferMemoryDeviceGroupInfoBuilder {
+        [slice device_indices = p_device_indices, device_index_count: u32]
+    }
+    BindImageMemoryInfo => BindImageMemoryInfoBuilder {
+        [next]
+        [value image: crate::data::Image]
+        [value memory: crate::data::DeviceMemory]
+        [value memory_offset: crate::data::DeviceSize]
+    }
+    BindImageMemoryDeviceGroupInfo => BindImageMemoryDeviceGroupInfoBuilder {
+        [slice device_indices = p_device_indices, device_index_count: u32]
+        [slice split_instance_bind_regions = p_split_instance_bind_regions, split_instance_bind_region_count: crate::data::Rect2D]
+    }
+    DeviceGroupRenderPassBeginInfo => DeviceGroupRenderPassBeginInfoBuilder {
+        [value device_mask: u32]
+        [slice device_render_areas = p_device_render_areas, device_render_area_count: crate::data::Rect2D]
+    }
+    DeviceGroupCommandBufferBeginInfo => DeviceGroupCommandBufferBeginInfoBuilder {
+        [value device_mask: u32]
+    }
+    DeviceGroupSubmitInfo => DeviceGroupSubmitInfoBuilder {
+        [slice wait_semaphore_device_indices = p_wait_semaphore_device_indices, wait_semaphore_count: u32]
+        [slice command_buffer_device_masks = p_command_buffer_device_masks, command_buffer_count: u32]
+        [slice signal_semaphore_device_indices = p_signal_semaphore_device_indices, signal_semaphore_count: u32]
+    }
+    DeviceGroupBindSparseInfo => DeviceGroupBindSparseInfoBuilder {
+        [value resource_device_index: u32]
+        [value memory_device_index: u32]
+    }
+    DeviceGroupPresentCapabilitiesKHR => DeviceGroupPresentCapabilitiesKHRBuilder {
+        [value present_mask: [u32; 32]]
+        [value modes: crate::data::DeviceGroupPresentModeFlagsKHR]
+    }
+    ImageSwapchainCreateInfoKHR => ImageSwapchainCreateInfoKHRBuilder {
+        [value swapchain: crate::data::SwapchainKHR]
+    }
+    BindImageMemorySwapchainInfoKHR => BindImageMemorySwapchainInfoKHRBuilder {
+        [value swapchain: crate::data::SwapchainKHR]
+        [value image_index: u32]
+    }
+    AcquireNextImageInfoKHR => AcquireNextImageInfoKHRBuilder {
+        [value swapchain: crate::data::SwapchainKHR]
+        [value timeout: u64]
+        [value semaphore: crate::data::Semaphore]
+        [value fence: crate::data::Fence]
+        [value device_mask: u32]
+    }
+    DeviceGroupPresentInfoKHR => DeviceGroupPresentInfoKHRBuilder {
+        [slice device_masks = p_device_masks, swapchain_count: u32]
+        [value mode: crate::data::DeviceGroupPresentModeFlagBitsKHR]
+    }
+    DeviceGroupDeviceCreateInfo => DeviceGroupDeviceCreateInfoBuilder {
+        [slice physical_devices = p_physical_devices, physical_device_count: crate::data::PhysicalDevice]
+    }
+    DeviceGroupSwapchainCreateInfoKHR => DeviceGroupSwapchainCreateInfoKHRBuilder {
+        [value modes: crate::data::DeviceGroupPresentModeFlagsKHR]
+    }
+    DescriptorUpdateTemplateCreateInfo => DescriptorUpdateTemplateCreateInfoBuilder {
+        [value flags: crate::data::DescriptorUpdateTemplateCreateFlags]
+        [slice descriptor_update_entries = p_descriptor_update_entries, descriptor_update_entry_count: crate::data::DescriptorUpdateTemplateEntry]
+        [value template_type: crate::data::DescriptorUpdateTemplateType]
+        [value descriptor_set_layout: crate::data::DescriptorSetLayout]
+        [value pipeline_bind_point: crate::data::PipelineBindPoint]
+        [value pipeline_layout: crate::data::PipelineLayout]
+        [value set: u32]
+    }
+    PhysicalDevicePresentIdFeaturesKHR => PhysicalDevicePresentIdFeaturesKHRBuilder {
+        [value present_id: crate::data::Bool32]
+    }
+    PresentIdKHR => PresentIdKHRBuilder {
+        [slice present_ids = p_present_ids, swapchain_count: u64]
+    }
+    PhysicalDevicePresentWaitFeaturesKHR => PhysicalDevicePresentWaitFeaturesKHRBuilder {
+        [value present_wait: crate::data::Bool32]
+    }
+    HdrMetadataEXT => HdrMetadataEXTBuilder {
+        [value display_primary_red: crate::data::XYColorEXT]
+        [value display_primary_green: crate::data::XYColorEXT]
+        [value display_primary_blue: crate::data::XYColorEXT]
+        [value white_point: crate::data::XYColorEXT]
+        [value max_luminance: c_float]
+        [value min_luminance: c_float]
+        [value max_content_light_level: c_float]
+        [value max_frame_average_light_level: c_float]
+    }
+    DisplayNativeHdrSurfaceCapabilitiesAMD => DisplayNativeHdrSurfaceCapabilitiesAMDBuilder {
+        [value local_dimming_support: crate::data::Bool32]
+    }
+    SwapchainDisplayNativeHdrCreateInfoAMD => SwapchainDisplayNativeHdrCreateInfoAMDBuilder {
+        [value local_dimming_enable: crate::data::Bool32]
+    }
+    PresentTimesInfoGOOGLE => PresentTimesInfoGOOGLEBuilder {
+        [slice times = p_times, swapchain_count: crate::data::PresentTimeGOOGLE]
+    }
+    IOSSurfaceCreateInfoMVK => IOSSurfaceCreateInfoMVKBuilder {
+        [value flags: crate::data::IOSSurfaceCreateFlagsMVK]
+        [value p_view: *const c_void]
+    }
+    MacOSSurfaceCreateInfoMVK => MacOSSurfaceCreateInfoMVKBuilder {
+        [value flags: crate::data::MacOSSurfaceCreateFlagsMVK]
+        [value p_view: *const c_void]
+    }
+    MetalSurfaceCreateInfoEXT => MetalSurfaceCreateInfoEXTBuilder {
+        [value flags: crate::data::MetalSurfaceCreateFlagsEXT]
+        [value p_layer: *const CAMetalLayer]
+    }
+    PipelineViewportWScalingStateCreateInfoNV => PipelineViewportWScalingStateCreateInfoNVBuilder {
+        [value viewport_w_scaling_enable: crate::data::Bool32]
+        [slice viewport_w_scalings = p_viewport_w_scalings, viewport_count: crate::data::ViewportWScalingNV]
+    }
+    PipelineViewportSwizzleStateCreateInfoNV => PipelineViewportSwizzleStateCreateInfoNVBuilder {
+        [value flags: crate::data::PipelineViewportSwizzleStateCreateFlagsNV]
+        [slice viewport_swizzles = p_viewport_swizzles, viewport_count: crate::data::ViewportSwizzleNV]
+    }
+    PhysicalDeviceDiscardRectanglePropertiesEXT => PhysicalDeviceDiscardRectanglePropertiesEXTBuilder {
+        [value max_discard_rectangles: u32]
+    }
+    PipelineDiscardRectangleStateCreateInfoEXT => PipelineDiscardRectangleStateCreateInfoEXTBuilder {
+        [value flags: crate::data::PipelineDiscardRectangleStateCreateFlagsEXT]
+        [value discard_rectangle_mode: crate::data::DiscardRectangleModeEXT]
+        [slice discard_rectangles = p_discard_rectangles, discard_rectangle_count: crate::data::Rect2D]
+    }
+    PhysicalDeviceMultiviewPerViewAttributesPropertiesNVX => PhysicalDeviceMultiviewPerViewAttributesPropertiesNVXBuilder {
+        [value per_view_position_all_components: crate::data::Bool32]
+    }
+    RenderPassInputAttachmentAspectCreateInfo => RenderPassInputAttachmentAspectCreateInfoBuilder {
+        [slice aspect_references = p_aspect_references, aspect_reference_count: crate::data::InputAttachmentAspectReference]
+    }
+    PhysicalDeviceSurfaceInfo2KHR => PhysicalDeviceSurfaceInfo2KHRBuilder {
+        [next]
+        [value surface: crate::data::SurfaceKHR]
+    }
+    SurfaceCapabilities2KHR => SurfaceCapabilities2KHRBuilder {
+        [next]
+        [value surface_capabilities: crate::data::SurfaceCapabilitiesKHR]
+    }
+    SurfaceFormat2KHR => SurfaceFormat2KHRBuilder {
+        [next]
+        [value surface_format: crate::data::SurfaceFormatKHR]
+    }
+    DisplayProperties2KHR => DisplayProperties2KHRBuilder {
+        [value display_properties: crate::data::DisplayPropertiesKHR]
+    }
+    DisplayPlaneProperties2KHR => DisplayPlaneProperties2KHRBuilder {
+        [value display_plane_properties: crate::data::DisplayPlanePropertiesKHR]
+    }
+    DisplayModeProperties2KHR => DisplayModeProperties2KHRBuilder {
+        [value display_mode_properties: crate::data::DisplayModePropertiesKHR]
+    }
+    DisplayPlaneInfo2KHR => DisplayPlaneInfo2KHRBuilder {
+        [value mode: crate::data::DisplayModeKHR]
+        [value plane_index: u32]
+    }
+    DisplayPlaneCapabilities2KHR => DisplayPlaneCapabilities2KHRBuilder {
+        [value capabilities: crate::data::DisplayPlaneCapabilitiesKHR]
+    }
+    SharedPresentSurfaceCapabilitiesKHR => SharedPresentSurfaceCapabilitiesKHRBuilder {
+        [value shared_present_supported_usage_flags: crate::data::ImageUsageFlags]
+    }
+    PhysicalDevice16BitStorageFeatures => PhysicalDevice16BitStorageFeaturesBuilder {
+        [value storage_buffer_16_bit_access: crate::data::Bool32]
+        [value uniform_and_storage_buffer_16_bit_access: crate::data::Bool32]
+        [value storage_push_constant_16: crate::data::Bool32]
+        [value storage_input_output_16: crate::data::Bool32]
+    }
+    PhysicalDeviceSubgroupProperties => PhysicalDeviceSubgroupPropertiesBuilder {
+        [value subgroup_size: u32]
+        [value supported_stages: crate::data::ShaderStageFlags]
+        [value supported_operations: crate::data::SubgroupFeatureFlags]
+        [value quad_operations_in_all_stages: crate::data::Bool32]
+    }
+    PhysicalDeviceShaderSubgroupExtendedTypesFeatures => PhysicalDeviceShaderSubgroupExtendedTypesFeaturesBuilder {
+        [value shader_subgroup_extended_types: crate::data::Bool32]
+    }
+    BufferMemoryRequirementsInfo2 => BufferMemoryRequirementsInfo2Builder {
+        [value buffer: crate::data::Buffer]
+    }
+    DeviceBufferMemoryRequirements => DeviceBufferMemoryRequirementsBuilder {
+        [ptr create_info = p_create_info: crate::data::BufferCreateInfo]
+    }
+    ImageMemoryRequirementsInfo2 => ImageMemoryRequirementsInfo2Builder {
+        [next]
+        [value image: crate::data::Image]
+    }
+    ImageSparseMemoryRequirementsInfo2 => ImageSparseMemoryRequirementsInfo2Builder {
+        [value image: crate::data::Image]
+    }
+    DeviceImageMemoryRequirements => DeviceImageMemoryRequirementsBuilder {
+        [ptr create_info = p_create_info: crate::data::ImageCreateInfo]
+        [value plane_aspect: crate::data::ImageAspectFlagBits]
+    }
+    MemoryRequirements2 => MemoryRequirements2Builder {
+        [next]
+        [value memory_requirements: crate::data::MemoryRequirements]
+    }
+    SparseImageMemoryRequirements2 => SparseImageMemoryRequirements2Builder {
+        [value memory_requirements: crate::data::SparseImageMemoryRequirements]
+    }
+    PhysicalDevicePointClippingProperties => PhysicalDevicePointClippingPropertiesBuilder {
+        [value point_clipping_behavior: crate::data::PointClippingBehavior]
+    }
+    MemoryDedicatedRequirements => MemoryDedicatedRequirementsBuilder {
+        [value prefers_dedicated_allocation: crate::data::Bool32]
+        [value requires_dedicated_allocation: crate::data::Bool32]
+    }
+    MemoryDedicatedAllocateInfo => MemoryDedicatedAllocateInfoBuilder {
+        [value image: crate::data::Image]
+        [value buffer: crate::data::Buffer]
+    }
+    ImageViewUsageCreateInfo => ImageViewUsageCreateInfoBuilder {
+        [value usage: crate::data::ImageUsageFlags]
+    }
+    PipelineTessellationDomainOriginStateCreateInfo => PipelineTessellationDomainOriginStateCreateInfoBuilder {
+        [value domain_origin: crate::data::TessellationDomainOrigin]
+    }
+    SamplerYcbcrConversionInfo => SamplerYcbcrConversionInfoBuilder {
+        [value conversion: crate::data::SamplerYcbcrConversion]
+    }
+    SamplerYcbcrConversionCreateInfo => SamplerYcbcrConversionCreateInfoBuilder {
+        [next]
+        [value format: crate::data::Format]
+        [value ycbcr_model: crate::data::SamplerYcbcrModelConversion]
+        [value ycbcr_range: crate::data::SamplerYcbcrRange]
+        [value components: crate::data::ComponentMapping]
+        [value x_chroma_offset: crate::data::ChromaLocation]
+        [value y_chroma_offset: crate::data::ChromaLocation]
+        [value chroma_filter: crate::data::Filter]
+        [value force_explicit_reconstruction: crate::data::Bool32]
+    }
+    BindImagePlaneMemoryInfo => BindImagePlaneMemoryInfoBuilder {
+        [value plane_aspect: crate::data::ImageAspectFlagBits]
+    }
+    ImagePlaneMemoryRequirementsInfo => ImagePlaneMemoryRequirementsInfoBuilder {
+        [value plane_aspect: crate::data::ImageAspectFlagBits]
+    }
+    PhysicalDeviceSamplerYcbcrConversionFeatures => PhysicalDeviceSamplerYcbcrConversionFeaturesBuilder {
+        [value sampler_ycbcr_conversion: crate::data::Bool32]
+    }
+    SamplerYcbcrConversionImageFormatProperties => SamplerYcbcrConversionImageFormatPropertiesBuilder {
+        [value combined_image_sampler_descriptor_count: u32]
+    }
+    TextureLODGatherFormatPropertiesAMD => TextureLODGatherFormatPropertiesAMDBuilder {
+        [value supports_texture_gather_lod_bias_amd: crate::data::Bool32]
+    }
+    ConditionalRenderingBeginInfoEXT => ConditionalRenderingBeginInfoEXTBuilder {
+        [value buffer: crate::data::Buffer]
+        [value offset: crate::data::DeviceSize]
+        [value flags: crate::data::ConditionalRenderingFlagsEXT]
+    }
+    ProtectedSubmitInfo => ProtectedSubmitInfoBuilder {
+        [value protected_submit: crate::data::Bool32]
+    }
+    PhysicalDeviceProtectedMemoryFeatures => PhysicalDeviceProtectedMemoryFeaturesBuilder {
+        [value protected_memory: crate::data::Bool32]
+    }
+    PhysicalDeviceProtectedMemoryProperties => PhysicalDeviceProtectedMemoryPropertiesBuilder {
+        [value protected_no_fault: crate::data::Bool32]
+    }
+    DeviceQueueInfo2 => DeviceQueueInfo2Builder {
+        [value flags: crate::data::DeviceQueueCreateFlags]
+        [value queue_family_index: u32]
+        [value queue_index: u32]
+    }
+    PipelineCoverageToColorStateCreateInfoNV => PipelineCoverageToColorStateCreateInfoNVBuilder {
+        [value flags: crate::data::PipelineCoverageToColorStateCreateFlagsNV]
+        [value coverage_to_color_enable: crate::data::Bool32]
+        [value coverage_to_color_location: u32]
+    }
+    PhysicalDeviceSamplerFilterMinmaxProperties => PhysicalDeviceSamplerFilterMinmaxPropertiesBuilder {
+        [value filter_minmax_single_component_formats: crate::data::Bool32]
+        [value filter_minmax_image_component_mapping: crate::data::Bool32]
+    }
+    SampleLocationsInfoEXT => SampleLocationsInfoEXTBuilder {
+        [value sample_locations_per_pixel: crate::data::SampleCountFlagBits]
+        [value sample_location_grid_size: crate::data::Extent2D]
+        [slice sample_locations = p_sample_locations, sample_locations_count: crate::data::SampleLocationEXT]
+    }
+    RenderPassSampleLocationsBeginInfoEXT => RenderPassSampleLocationsBeginInfoEXTBuilder {
+        [slice attachment_initial_sample_locations = p_attachment_initial_sample_locations, attachment_initial_sample_locations_count: crate::data::AttachmentSampleLocationsEXT]
+        [slice post_subpass_sample_locations = p_post_subpass_sample_locations, post_subpass_sample_locations_count: crate::data::SubpassSampleLocationsEXT]
+    }
+    PipelineSampleLocationsStateCreateInfoEXT => PipelineSampleLocationsStateCreateInfoEXTBuilder {
+        [value sample_locations_enable: crate::data::Bool32]
+        [value sample_locations_info: crate::data::SampleLocationsInfoEXT]
+    }
+    PhysicalDeviceSampleLocationsPropertiesEXT => PhysicalDeviceSampleLocationsPropertiesEXTBuilder {
+        [value sample_location_sample_counts: crate::data::SampleCountFlags]
+        [value max_sample_location_grid_size: crate::data::Extent2D]
+        [value sample_location_coordinate_range: [c_float; 2]]
+        [value sample_location_sub_pixel_bits: u32]
+        [value variable_sample_locations: crate::data::Bool32]
+    }
+    MultisamplePropertiesEXT => MultisamplePropertiesEXTBuilder {
+        [value max_sample_location_grid_size: crate::data::Extent2D]
+    }
+    SamplerReductionModeCreateInfo => SamplerReductionModeCreateInfoBuilder {
+        [value reduction_mode: crate::data::SamplerReductionMode]
+    }
+    PhysicalDeviceBlendOperationAdvancedFeaturesEXT => PhysicalDeviceBlendOperationAdvancedFeaturesEXTBuilder {
+        [value advanced_blend_coherent_operations: crate::data::Bool32]
+    }
+    PhysicalDeviceMultiDrawFeaturesEXT => PhysicalDeviceMultiDrawFeaturesEXTBuilder {
+        [value multi_draw: crate::data::Bool32]
+    }
+    PhysicalDeviceBlendOperationAdvancedPropertiesEXT => PhysicalDeviceBlendOperationAdvancedPropertiesEXTBuilder {
+        [value advanced_blend_max_color_attachments: u32]
+        [value advanced_blend_independent_blend: crate::data::Bool32]
+        [value advanced_blend_non_premultiplied_src_color: crate::data::Bool32]
+        [value advanced_blend_non_premultiplied_dst_color: crate::data::Bool32]
+        [value advanced_blend_correlated_overlap: crate::data::Bool32]
+        [value advanced_blend_all_operations: crate::data::Bool32]
+    }
+    PipelineColorBlendAdvancedStateCreateInfoEXT => PipelineColorBlendAdvancedStateCreateInfoEXTBuilder {
+        [value src_premultiplied: crate::data::Bool32]
+        [value dst_premultiplied: crate::data::Bool32]
+        [value blend_overlap: crate::data::BlendOverlapEXT]
+    }
+    PhysicalDeviceInlineUniformBlockFeatures => PhysicalDeviceInlineUniformBlockFeaturesBuilder {
+        [value inline_uniform_block: crate::data::Bool32]
+        [value descriptor_binding_inline_uniform_block_update_after_bind: crate::data::Bool32]
+    }
+    PhysicalDeviceInlineUniformBlockProperties => PhysicalDeviceInlineUniformBlockPropertiesBuilder {
+        [value max_inline_uniform_block_size: u32]
+        [value max_per_stage_descriptor_inline_uniform_blocks: u32]
+        [value max_per_stage_descriptor_update_after_bind_inline_uniform_blocks: u32]
+        [value max_descriptor_set_inline_uniform_blocks: u32]
+        [value max_descriptor_set_update_after_bind_inline_uniform_blocks: u32]
+    }
+    WriteDescriptorSetInlineUniformBlock => WriteDescriptorSetInlineUniformBlockBuilder {
+        [slice data = p_data, data_size: u8]
+    }
+    DescriptorPoolInlineUniformBlockCreateInfo => DescriptorPoolInlineUniformBlockCreateInfoBuilder {
+        [value max_inline_uniform_block_bindings: u32]
+    }
+    PipelineCoverageModulationStateCreateInfoNV => PipelineCoverageModulationStateCreateInfoNVBuilder {
+        [value flags: crate::data::PipelineCoverageModulationStateCreateFlagsNV]
+        [value coverage_modulation_mode: crate::data::CoverageModulationModeNV]
+        [value coverage_modulation_table_enable: crate::data::Bool32]
+        [slice coverage_modulation_table = p_coverage_modulation_table, coverage_modulation_table_count: c_float]
+    }
+    ImageFormatListCreateInfo => ImageFormatListCreateInfoBuilder {
+        [slice view_formats = p_view_formats, view_format_count: crate::data::Format]
+    }
+    ValidationCacheCreateInfoEXT => ValidationCacheCreateInfoEXTBuilder {
+        [value flags: crate::data::ValidationCacheCreateFlagsEXT]
+        [slice initial_data = p_initial_data, initial_data_size: u8]
+    }
+    ShaderModuleValidationCacheCreateInfoEXT => ShaderModuleValidationCacheCreateInfoEXTBuilder {
+        [value validation_cache: crate::data::ValidationCacheEXT]
+    }
+    PhysicalDeviceMaintenance3Properties => PhysicalDeviceMaintenance3PropertiesBuilder {
+        [value max_per_set_descriptors: u32]
+        [value max_memory_allocation_size: crate::data::DeviceSize]
+    }
+    PhysicalDeviceMaintenance4Features => PhysicalDeviceMaintenance4FeaturesBuilder {
+        [value maintenance_4: crate::data::Bool32]
+    }
+    PhysicalDeviceMaintenance4Properties => PhysicalDeviceMaintenance4PropertiesBuilder {
+        [value max_buffer_size: crate::data::DeviceSize]
+    }
+    DescriptorSetLayoutSupport => DescriptorSetLayoutSupportBuilder {
+        [next]
+        [value supported: crate::data::Bool32]
+    }
+    PhysicalDeviceShaderDrawParametersFeatures => PhysicalDeviceShaderDrawParametersFeaturesBuilder {
+        [value shader_draw_parameters: crate::data::Bool32]
+    }
+    PhysicalDeviceShaderFloat16Int8Features => PhysicalDeviceShaderFloat16Int8FeaturesBuilder {
+        [value shader_float_16: crate::data::Bool32]
+        [value shader_int_8: crate::data::Bool32]
+    }
+    PhysicalDeviceFloatControlsProperties => PhysicalDeviceFloatControlsPropertiesBuilder {
+        [value denorm_behavior_independence: crate::data::ShaderFloatControlsIndependence]
+        [value rounding_mode_independence: crate::data::ShaderFloatControlsIndependence]
+        [value shader_signed_zero_inf_nan_preserve_float_16: crate::data::Bool32]
+        [value shader_signed_zero_inf_nan_preserve_float_32: crate::data::Bool32]
+        [value shader_signed_zero_inf_nan_preserve_float_64: crate::data::Bool32]
+        [value shader_denorm_preserve_float_16: crate::data::Bool32]
+        [value shader_denorm_preserve_float_32: crate::data::Bool32]
+        [value shader_denorm_preserve_float_64: crate::data::Bool32]
+        [value shader_denorm_flush_to_zero_float_16: crate::data::Bool32]
+        [value shader_denorm_flush_to_zero_float_32: crate::data::Bool32]
+        [value shader_denorm_flush_to_zero_float_64: crate::data::Bool32]
+        [value shader_rounding_mode_rte_float_16: crate::data::Bool32]
+        [value shader_rounding_mode_rte_float_32: crate::data::Bool32]
+        [value shader_rounding_mode_rte_float_64: crate::data::Bool32]
+        [value shader_rounding_mode_rtz_float_16: crate::data::Bool32]
+        [value shader_rounding_mode_rtz_float_32: crate::data::Bool32]
+        [value shader_rounding_mode_rtz_float_64: crate::data::Bool32]
+    }
+    PhysicalDeviceHostQueryResetFeatures => PhysicalDeviceHostQueryResetFeaturesBuilder {
+        [value host_query_reset: crate::data::Bool32]
+    }
+    NativeBufferANDROID => NativeBufferANDROIDBuilder {
+        [value handle: *const c_void]
+        [value stride: c_int]
+        [value format: c_int]
+        [value usage: c_int]
+        [value usage_2: crate::data::NativeBufferUsage2ANDROID]
+    }
+    SwapchainImageCreateInfoANDROID => SwapchainImageCreateInfoANDROIDBuilder {
+        [value usage: crate::data::SwapchainImageUsageFlagsANDROID]
+    }
+    PhysicalDevicePresentationPropertiesANDROID => PhysicalDevicePresentationPropertiesANDROIDBuilder {
+        [value shared_image: crate::data::Bool32]
+    }
+    DeviceQueueGlobalPriorityCreateInfoKHR => DeviceQueueGlobalPriorityCreateInfoKHRBuilder {
+        [value global_priority: crate::data::QueueGlobalPriorityKHR]
+    }
+    PhysicalDeviceGlobalPriorityQueryFeaturesKHR => PhysicalDeviceGlobalPriorityQueryFeaturesKHRBuilder {
+        [value global_priority_query: crate::data::Bool32]
+    }
+    QueueFamilyGlobalPriorityPropertiesKHR => QueueFamilyGlobalPriorityPropertiesKHRBuilder {
+        [value priority_count: u32]
+        [value priorities: [crate::data::QueueGlobalPriorityKHR; 16]]
+    }
+    DebugUtilsObjectNameInfoEXT => DebugUtilsObjectNameInfoEXTBuilder {
+        [value object_type: crate::data::ObjectType]
+        [value object_handle: u64]
+        [str object_name = p_object_name]
+    }
+    DebugUtilsObjectTagInfoEXT => DebugUtilsObjectTagInfoEXTBuilder {
+        [value object_type: crate::data::ObjectType]
+        [value object_handle: u64]
+        [value tag_name: u64]
+        [slice tag = p_tag, tag_size: u8]
+    }
+    DebugUtilsLabelEXT => DebugUtilsLabelEXTBuilder {
+        [str label_name = p_label_name]
+        [value color: [c_float; 4]]
+    }
+    DebugUtilsMessengerCreateInfoEXT => DebugUtilsMessengerCreateInfoEXTBuilder {
+        [value flags: crate::data::DebugUtilsMessengerCreateFlagsEXT]
+        [value message_severity: crate::data::DebugUtilsMessageSeverityFlagsEXT]
+        [value message_type: crate::data::DebugUtilsMessageTypeFlagsEXT]
+        [value pfn_user_callback: crate::pfn::DebugUtilsMessengerCallbackEXT]
+        [value p_user_data: *mut c_void]
+    }
+    DebugUtilsMessengerCallbackDataEXT => DebugUtilsMessengerCallbackDataEXTBuilder {
+        [next]
+        [value flags: crate::data::DebugUtilsMessengerCallbackDataFlagsEXT]
+        [str message_id_name = p_message_id_name]
+        [value message_id_number: i32]
+        [str message = p_message]
+        [slice queue_labels = p_queue_labels, queue_label_count: crate::data::DebugUtilsLabelEXT]
+        [slice cmd_buf_labels = p_cmd_buf_labels, cmd_buf_label_count: crate::data::DebugUtilsLabelEXT]
+        [slice objects = p_objects, object_count: crate::data::DebugUtilsObjectNameInfoEXT]
+    }
+    PhysicalDeviceDeviceMemoryReportFeaturesEXT => PhysicalDeviceDeviceMemoryReportFeaturesEXTBuilder {
+        [value device_memory_report: crate::data::Bool32]
+    }
+    DeviceDeviceMemoryReportCreateInfoEXT => DeviceDeviceMemoryReportCreateInfoEXTBuilder {
+        [value flags: crate::data::DeviceMemoryReportFlagsEXT]
+        [value pfn_user_callback: crate::pfn::DeviceMemoryReportCallbackEXT]
+        [value p_user_data: *mut c_void]
+    }
+    DeviceMemoryReportCallbackDataEXT => DeviceMemoryReportCallbackDataEXTBuilder {
+        [value flags: crate::data::DeviceMemoryReportFlagsEXT]
+        [value ty: crate::data::DeviceMemoryReportEventTypeEXT]
+        [value memory_object_id: u64]
+        [value size: crate::data::DeviceSize]
+        [value object_type: crate::data::ObjectType]
+        [value object_handle: u64]
+        [value heap_index: u32]
+    }
+    ImportMemoryHostPointerInfoEXT => ImportMemoryHostPointerInfoEXTBuilder {
+        [value handle_type: crate::data::ExternalMemoryHandleTypeFlagBits]
+        [value p_host_pointer: *mut c_void]
+    }
+    MemoryHostPointerPropertiesEXT => MemoryHostPointerPropertiesEXTBuilder {
+        [value memory_type_bits: u32]
+    }
+    PhysicalDeviceExternalMemoryHostPropertiesEXT => PhysicalDeviceExternalMemoryHostPropertiesEXTBuilder {
+        [value min_imported_host_pointer_alignment: crate::data::DeviceSize]
+    }
+    PhysicalDeviceConservativeRasterizationPropertiesEXT => PhysicalDeviceConservativeRasterizationPropertiesEXTBuilder {
+        [value primitive_overestimation_size: c_float]
+        [value max_extra_primitive_overestimation_size: c_float]
+        [value extra_primitive_overestimation_size_granularity: c_float]
+        [value primitive_underestimation: crate::data::Bool32]
+        [value conservative_point_and_line_rasterization: crate::data::Bool32]
+        [value degenerate_triangles_rasterized: crate::data::Bool32]
+        [value degenerate_lines_rasterized: crate::data::Bool32]
+        [value fully_covered_fragment_shader_input_variable: crate::data::Bool32]
+        [value conservative_rasterization_post_depth_coverage: crate::data::Bool32]
+    }
+    CalibratedTimestampInfoEXT => CalibratedTimestampInfoEXTBuilder {
+        [value time_domain: crate::data::TimeDomainEXT]
+    }
+    PhysicalDeviceShaderCorePropertiesAMD => PhysicalDeviceShaderCorePropertiesAMDBuilder {
+        [value shader_engine_count: u32]
+        [value shader_arrays_per_engine_count: u32]
+        [value compute_units_per_shader_array: u32]
+        [value simd_per_compute_unit: u32]
+        [value wavefronts_per_simd: u32]
+        [value wavefront_size: u32]
+        [value sgprs_per_simd: u32]
+        [value min_sgpr_allocation: u32]
+        [value max_sgpr_allocation: u32]
+        [value sgpr_allocation_granularity: u32]
+        [value vgprs_per_simd: u32]
+        [value min_vgpr_allocation: u32]
+        [value max_vgpr_allocation: u32]
+        [value vgpr_allocation_granularity: u32]
+    }
+    PhysicalDeviceShaderCoreProperties2AMD => PhysicalDeviceShaderCoreProperties2AMDBuilder {
+        [value shader_core_features: crate::data::ShaderCorePropertiesFlagsAMD]
+        [value active_compute_unit_count: u32]
+    }
+    PipelineRasterizationConservativeStateCreateInfoEXT => PipelineRasterizationConservativeStateCreateInfoEXTBuilder {
+        [value flags: crate::data::PipelineRasterizationConservativeStateCreateFlagsEXT]
+        [value conservative_rasterization_mode: crate::data::ConservativeRasterizationModeEXT]
+        [value extra_primitive_overestimation_size: c_float]
+    }
+    PhysicalDeviceDescriptorIndexingFeatures => PhysicalDeviceDescriptorIndexingFeaturesBuilder {
+        [value shader_input_attachment_array_dynamic_indexing: crate::data::Bool32]
+        [value shader_uniform_texel_buffer_array_dynamic_indexing: crate::data::Bool32]
+        [value shader_storage_texel_buffer_array_dynamic_indexing: crate::data::Bool32]
+        [value shader_uniform_buffer_array_non_uniform_indexing: crate::data::Bool32]
+        [value shader_sampled_image_array_non_uniform_indexing: crate::data::Bool32]
+        [value shader_storage_buffer_array_non_uniform_indexing: crate::data::Bool32]
+        [value shader_storage_image_array_non_uniform_indexing: crate::data::Bool32]
+        [value shader_input_attachment_array_non_uniform_indexing: crate::data::Bool32]
+        [value shader_uniform_texel_buffer_array_non_uniform_indexing: crate::data::Bool32]
+        [value shader_storage_texel_buffer_array_non_uniform_indexing: crate::data::Bool32]
+        [value descriptor_binding_uniform_buffer_update_after_bind: crate::data::Bool32]
+        [value descriptor_binding_sampled_image_update_after_bind: crate::data::Bool32]
+        [value descriptor_binding_storage_image_update_after_bind: crate::data::Bool32]
+        [value descriptor_binding_storage_buffer_update_after_bind: crate::data::Bool32]
+        [value descriptor_binding_uniform_texel_buffer_update_after_bind: crate::data::Bool32]
+        [value descriptor_binding_storage_texel_buffer_update_after_bind: crate::data::Bool32]
+        [value descriptor_binding_update_unused_while_pending: crate::data::Bool32]
+        [value descriptor_binding_partially_bound: crate::data::Bool32]
+        [value descriptor_binding_variable_descriptor_count: crate::data::Bool32]
+        [value runtime_descriptor_array: crate::data::Bool32]
+    }
+    PhysicalDeviceDescriptorIndexingProperties => PhysicalDeviceDescriptorIndexingPropertiesBuilder {
+        [value max_update_after_bind_descriptors_in_all_pools: u32]
+        [value shader_uniform_buffer_array_non_uniform_indexing_native: crate::data::Bool32]
+        [value shader_sampled_image_array_non_uniform_indexing_native: crate::data::Bool32]
+        [value shader_storage_buffer_array_non_uniform_indexing_native: crate::data::Bool32]
+        [value shader_storage_image_array_non_uniform_indexing_native: crate::data::Bool32]
+        [value shader_input_attachment_array_non_uniform_indexing_native: crate::data::Bool32]
+        [value robust_buffer_access_update_after_bind: crate::data::Bool32]
+        [value quad_divergent_implicit_lod: crate::data::Bool32]
+        [value max_per_stage_descriptor_update_after_bind_samplers: u32]
+        [value max_per_stage_descriptor_update_after_bind_uniform_buffers: u32]
+        [value max_per_stage_descriptor_update_after_bind_storage_buffers: u32]
+        [value max_per_stage_descriptor_update_after_bind_sampled_images: u32]
+        [value max_per_stage_descriptor_update_after_bind_storage_images: u32]
+        [value max_per_stage_descriptor_update_after_bind_input_attachments: u32]
+        [value max_per_stage_update_after_bind_resources: u32]
+        [value max_descriptor_set_update_after_bind_samplers: u32]
+        [value max_descriptor_set_update_after_bind_uniform_buffers: u32]
+        [value max_descriptor_set_update_after_bind_uniform_buffers_dynamic: u32]
+        [value max_descriptor_set_update_after_bind_storage_buffers: u32]
+        [value max_descriptor_set_update_after_bind_storage_buffers_dynamic: u32]
+        [value max_descriptor_set_update_after_bind_sampled_images: u32]
+        [value max_descriptor_set_update_after_bind_storage_images: u32]
+        [value max_descriptor_set_update_after_bind_input_attachments: u32]
+    }
+    DescriptorSetLayoutBindingFlagsCreateInfo => DescriptorSetLayoutBindingFlagsCreateInfoBuilder {
+        [slice binding_flags = p_binding_flags, binding_count: crate::data::DescriptorBindingFlags]
+    }
+    DescriptorSetVariableDescriptorCountAllocateInfo => DescriptorSetVariableDescriptorCountAllocateInfoBuilder {
+        [slice descriptor_counts = p_descriptor_counts, descriptor_set_count: u32]
+    }
+    DescriptorSetVariableDescriptorCountLayoutSupport => DescriptorSetVariableDescriptorCountLayoutSupportBuilder {
+        [value max_variable_descriptor_count: u32]
+    }
+    AttachmentDescription2 => AttachmentDescription2Builder {
+        [next]
+        [value flags: crate::data::AttachmentDescriptionFlags]
+        [value format: crate::data::Format]
+        [value samples: crate::data::SampleCountFlagBits]
+        [value load_op: crate::data::AttachmentLoadOp]
+        [value store_op: crate::data::AttachmentStoreOp]
+        [value stencil_load_op: crate::data::AttachmentLoadOp]
+        [value stencil_store_op: crate::data::AttachmentStoreOp]
+        [value initial_layout: crate::data::ImageLayout]
+        [value final_layout: crate::data::ImageLayout]
+    }
+    AttachmentReference2 => AttachmentReference2Builder {
+        [next]
+        [value attachment: u32]
+        [value layout: crate::data::ImageLayout]
+        [value aspect_mask: crate::data::ImageAspectFlags]
+    }
+    SubpassDescription2 => SubpassDescription2Builder (shared) {
+        [next]
+        [value flags: crate::data::SubpassDescriptionFlags]
+        [value pipeline_bind_point: crate::data::PipelineBindPoint]
+        [value view_mask: u32]
+        [slice input_attachments = p_input_attachments, input_attachment_count: crate::data::AttachmentReference2]
+        [value color_attachment_count: u32]
+        [slice color_attachments = p_color_attachments, color_attachment_count shared(0x1, 0x2): crate::data::AttachmentReference2]
+        [slice resolve_attachments = p_resolve_attachments, color_attachment_count shared(0x2, 0x1): crate::data::AttachmentReference2]
+        [ptr depth_stencil_attachment = p_depth_stencil_attachment: crate::data::AttachmentReference2]
+        [slice preserve_attachments = p_preserve_attachments, preserve_attachment_count: u32]
+    }
+    SubpassDependency2 => SubpassDependency2Builder {
+        [next]
+        [value src_subpass: u32]
+        [value dst_subpass: u32]
+        [value src_stage_mask: crate::data::PipelineStageFlags]
+        [value dst_stage_mask: crate::data::PipelineStageFlags]
+        [value src_access_mask: crate::data::AccessFlags]
+        [value dst_access_mask: crate::data::AccessFlags]
+        [value dependency_flags: crate::data::DependencyFlags]
+        [value view_offset: i32]
+    }
+    RenderPassCreateInfo2 => RenderPassCreateInfo2Builder {
+        [next]
+        [value flags: crate::data::RenderPassCreateFlags]
+        [slice attachments = p_attachments, attachment_count: crate::data::AttachmentDescription2]
+        [slice subpasses = p_subpasses, subpass_count: crate::data::SubpassDescription2]
+        [slice dependencies = p_dependencies, dependency_count: crate::data::SubpassDependency2]
+        [slice correlated_view_masks = p_correlated_view_masks, correlated_view_mask_count: u32]
+    }
+    SubpassBeginInfo => SubpassBeginInfoBuilder {
+        [value contents: crate::data::SubpassContents]
+    }
+    SubpassEndInfo => SubpassEndInfoBuilder {
+        [next]
+    }
+    PhysicalDeviceTimelineSemaphoreFeatures => PhysicalDeviceTimelineSemaphoreFeaturesBuilder {
+        [value timeline_semaphore: crate::data::Bool32]
+    }
+    PhysicalDeviceTimelineSemaphoreProperties => PhysicalDeviceTimelineSemaphorePropertiesBuilder {
+        [value max_timeline_semaphore_value_difference: u64]
+    }
+    SemaphoreTypeCreateInfo => SemaphoreTypeCreateInfoBuilder {
+        [value semaphore_type: crate::data::SemaphoreType]
+        [value initial_value: u64]
+    }
+    TimelineSemaphoreSubmitInfo => TimelineSemaphoreSubmitInfoBuilder {
+        [slice wait_semaphore_values = p_wait_semaphore_values, wait_semaphore_value_count: u64]
+        [slice signal_semaphore_values = p_signal_semaphore_values, signal_semaphore_value_count: u64]
+    }
+    SemaphoreWaitInfo => SemaphoreWaitInfoBuilder (shared) {
+        [value flags: crate::data::SemaphoreWaitFlags]
+        [slice semaphores = p_semaphores, semaphore_count shared(0x1, 0x2): crate::data::Semaphore]
+        [slice values = p_values, semaphore_count shared(0x2, 0x1): u64]
+    }
+    SemaphoreSignalInfo => SemaphoreSignalInfoBuilder {
+        [value semaphore: crate::data::Semaphore]
+        [value value: u64]
+    }
+    PipelineVertexInputDivisorStateCreateInfoEXT => PipelineVertexInputDivisorStateCreateInfoEXTBuilder {
+        [slice vertex_binding_divisors = p_vertex_binding_divisors, vertex_binding_divisor_count: crate::data::VertexInputBindingDivisorDescriptionEXT]
+    }
+    PhysicalDeviceVertexAttributeDivisorPropertiesEXT => PhysicalDeviceVertexAttributeDivisorPropertiesEXTBuilder {
+        [value max_vertex_attrib_divisor: u32]
+    }
+    PhysicalDevicePCIBusInfoPropertiesEXT => PhysicalDevicePCIBusInfoPropertiesEXTBuilder {
+        [value pci_domain: u32]
+        [value pci_bus: u32]
+        [value pci_device: u32]
+        [value pci_function: u32]
+    }
+    ImportAndroidHardwareBufferInfoANDROID => ImportAndroidHardwareBufferInfoANDROIDBuilder {
+        [value buffer: *mut AHardwareBuffer]
+    }
+    AndroidHardwareBufferUsageANDROID => AndroidHardwareBufferUsageANDROIDBuilder {
+        [value android_hardware_buffer_usage: u64]
+    }
+    AndroidHardwareBufferPropertiesANDROID => AndroidHardwareBufferPropertiesANDROIDBuilder {
+        [next]
+        [value allocation_size: crate::data::DeviceSize]
+        [value memory_type_bits: u32]
+    }
+    MemoryGetAndroidHardwareBufferInfoANDROID => MemoryGetAndroidHardwareBufferInfoANDROIDBuilder {
+        [value memory: crate::data::DeviceMemory]
+    }
+    AndroidHardwareBufferFormatPropertiesANDROID => AndroidHardwareBufferFormatPropertiesANDROIDBuilder {
+        [value format: crate::data::Format]
+        [value external_format: u64]
+        [value format_features: crate::data::FormatFeatureFlags]
+        [value sampler_ycbcr_conversion_components: crate::data::ComponentMapping]
+        [value suggested_ycbcr_model: crate::data::SamplerYcbcrModelConversion]
+        [value suggested_ycbcr_range: crate::data::SamplerYcbcrRange]
+        [value suggested_x_chroma_offset: crate::data::ChromaLocation]
+        [value suggested_y_chroma_offset: crate::data::ChromaLocation]
+    }
+    CommandBufferInheritanceConditionalRenderingInfoEXT => CommandBufferInheritanceConditionalRenderingInfoEXTBuilder {
+        [value conditional_rendering_enable: crate::data::Bool32]
+    }
+    ExternalFormatANDROID => ExternalFormatANDROIDBuilder {
+        [value external_format: u64]
+    }
+    PhysicalDevice8BitStorageFeatures => PhysicalDevice8BitStorageFeaturesBuilder {
+        [value storage_buffer_8_bit_access: crate::data::Bool32]
+        [value uniform_and_storage_buffer_8_bit_access: crate::data::Bool32]
+        [value storage_push_constant_8: crate::data::Bool32]
+    }
+    PhysicalDeviceConditionalRenderingFeaturesEXT => PhysicalDeviceConditionalRenderingFeaturesEXTBuilder {
+        [value conditional_rendering: crate::data::Bool32]
+        [value inherited_conditional_rendering: crate::data::Bool32]
+    }
+    PhysicalDeviceVulkanMemoryModelFeatures => PhysicalDeviceVulkanMemoryModelFeaturesBuilder {
+        [value vulkan_memory_model: crate::data::Bool32]
+        [value vulkan_memory_model_device_scope: crate::data::Bool32]
+        [value vulkan_memory_model_availability_visibility_chains: crate::data::Bool32]
+    }
+    PhysicalDeviceShaderAtomicInt64Features => PhysicalDeviceShaderAtomicInt64FeaturesBuilder {
+        [value shader_buffer_int_64_atomics: crate::data::Bool32]
+        [value shader_shared_int_64_atomics: crate::data::Bool32]
+    }
+    PhysicalDeviceShaderAtomicFloatFeaturesEXT => PhysicalDeviceShaderAtomicFloatFeaturesEXTBuilder {
+        [value shader_buffer_float_32_atomics: crate::data::Bool32]
+        [value shader_buffer_float_32_atomic_add: crate::data::Bool32]
+        [value shader_buffer_float_64_atomics: crate::data::Bool32]
+        [value shader_buffer_float_64_atomic_add: crate::data::Bool32]
+        [value shader_shared_float_32_atomics: crate::data::Bool32]
+        [value shader_shared_float_32_atomic_add: crate::data::Bool32]
+        [value shader_shared_float_64_atomics: crate::data::Bool32]
+        [value shader_shared_float_64_atomic_add: crate::data::Bool32]
+        [value shader_image_float_32_atomics: crate::data::Bool32]
+        [value shader_image_float_32_atomic_add: crate::data::Bool32]
+        [value sparse_image_float_32_atomics: crate::data::Bool32]
+        [value sparse_image_float_32_atomic_add: crate::data::Bool32]
+    }
+    PhysicalDeviceShaderAtomicFloat2FeaturesEXT => PhysicalDeviceShaderAtomicFloat2FeaturesEXTBuilder {
+        [value shader_buffer_float_16_atomics: crate::data::Bool32]
+        [value shader_buffer_float_16_atomic_add: crate::data::Bool32]
+        [value shader_buffer_float_16_atomic_min_max: crate::data::Bool32]
+        [value shader_buffer_float_32_atomic_min_max: crate::data::Bool32]
+        [value shader_buffer_float_64_atomic_min_max: crate::data::Bool32]
+        [value shader_shared_float_16_atomics: crate::data::Bool32]
+        [value shader_shared_float_16_atomic_add: crate::data::Bool32]
+        [value shader_shared_float_16_atomic_min_max: crate::data::Bool32]
+        [value shader_shared_float_32_atomic_min_max: crate::data::Bool32]
+        [value shader_shared_float_64_atomic_min_max: crate::data::Bool32]
+        [value shader_image_float_32_atomic_min_max: crate::data::Bool32]
+        [value sparse_image_float_32_atomic_min_max: crate::data::Bool32]
+    }
+    PhysicalDeviceVertexAttributeDivisorFeaturesEXT => PhysicalDeviceVertexAttributeDivisorFeaturesEXTBuilder {
+        [value vertex_attribute_instance_rate_divisor: crate::data::Bool32]
+        [value vertex_attribute_instance_rate_zero_divisor: crate::data::Bool32]
+    }
+    QueueFamilyCheckpointPropertiesNV => QueueFamilyCheckpointPropertiesNVBuilder {
+        [value checkpoint_execution_stage_mask: crate::data::PipelineStageFlags]
+    }
+    CheckpointDataNV => CheckpointDataNVBuilder {
+        [value stage: crate::data::PipelineStageFlagBits]
+        [value p_checkpoint_marker: *mut c_void]
+    }
+    PhysicalDeviceDepthStencilResolveProperties => PhysicalDeviceDepthStencilResolvePropertiesBuilder {
+        [value supported_depth_resolve_modes: crate::data::ResolveModeFlags]
+        [value supported_stencil_resolve_modes: crate::data::ResolveModeFlags]
+        [value independent_resolve_none: crate::data::Bool32]
+        [value independent_resolve: crate::data::Bool32]
+    }
+    SubpassDescriptionDepthStencilResolve => SubpassDescriptionDepthStencilResolveBuilder {
+        [value depth_resolve_mode: crate::data::ResolveModeFlagBits]
+        [value stencil_resolve_mode: crate::data::ResolveModeFlagBits]
+        [ptr depth_stencil_resolve_attachment = p_depth_stencil_resolve_attachment: crate::data::AttachmentReference2]
+    }
+    ImageViewASTCDecodeModeEXT => ImageViewASTCDecodeModeEXTBuilder {
+        [value decode_mode: crate::data::Format]
+    }
+    PhysicalDeviceASTCDecodeFeaturesEXT => PhysicalDeviceASTCDecodeFeaturesEXTBuilder {
+        [value decode_mode_shared_exponent: crate::data::Bool32]
+    }
+    PhysicalDeviceTransformFeedbackFeaturesEXT => PhysicalDeviceTransformFeedbackFeaturesEXTBuilder {
+        [value transform_feedback: crate::data::Bool32]
+        [value geometry_streams: crate::data::Bool32]
+    }
+    PhysicalDeviceTransformFeedbackPropertiesEXT => PhysicalDeviceTransformFeedbackPropertiesEXTBuilder {
+        [value max_transform_feedback_streams: u32]
+        [value max_transform_feedback_buffers: u32]
+        [value max_transform_feedback_buffer_size: crate::data::DeviceSize]
+        [value max_transform_feedback_stream_data_size: u32]
+        [value max_transform_feedback_buffer_data_size: u32]
+        [value max_transform_feedback_buffer_data_stride: u32]
+        [value transform_feedback_queries: crate::data::Bool32]
+        [value transform_feedback_streams_lines_triangles: crate::data::Bool32]
+        [value transform_feedback_rasterization_stream_select: crate::data::Bool32]
+        [value transform_feedback_draw: crate::data::Bool32]
+    }
+    PipelineRasterizationStateStreamCreateInfoEXT => PipelineRasterizationStateStreamCreateInfoEXTBuilder {
+        [value flags: crate::data::PipelineRasterizationStateStreamCreateFlagsEXT]
+        [value rasterization_stream: u32]
+    }
+    PhysicalDeviceRepresentativeFragmentTestFeaturesNV => PhysicalDeviceRepresentativeFragmentTestFeaturesNVBuilder {
+        [value representative_fragment_test: crate::data::Bool32]
+    }
+    PipelineRepresentativeFragmentTestStateCreateInfoNV => PipelineRepresentativeFragmentTestStateCreateInfoNVBuilder {
+        [value representative_fragment_test_enable: crate::data::Bool32]
+    }
+    PhysicalDeviceExclusiveScissorFeaturesNV => PhysicalDeviceExclusiveScissorFeaturesNVBuilder {
+        [value exclusive_scissor: crate::data::Bool32]
+    }
+    PipelineViewportExclusiveScissorStateCreateInfoNV => PipelineViewportExclusiveScissorStateCreateInfoNVBuilder {
+        [slice exclusive_scissors = p_exclusive_scissors, exclusive_scissor_count: crate::data::Rect2D]
+    }
+    PhysicalDeviceCornerSampledImageFeaturesNV => PhysicalDeviceCornerSampledImageFeaturesNVBuilder {
+        [value corner_sampled_image: crate::data::Bool32]
+    }
+    PhysicalDeviceComputeShaderDerivativesFeaturesNV => PhysicalDeviceComputeShaderDerivativesFeaturesNVBuilder {
+        [value compute_derivative_group_quads: crate::data::Bool32]
+        [value compute_derivative_group_linear: crate::data::Bool32]
+    }
+    PhysicalDeviceShaderImageFootprintFeaturesNV => PhysicalDeviceShaderImageFootprintFeaturesNVBuilder {
+        [value image_footprint: crate::data::Bool32]
+    }
+    PhysicalDeviceDedicatedAllocationImageAliasingFeaturesNV => PhysicalDeviceDedicatedAllocationImageAliasingFeaturesNVBuilder {
+        [value dedicated_allocation_image_aliasing: crate::data::Bool32]
+    }
+    ShadingRatePaletteNV => ShadingRatePaletteNVBuilder {
+        [slice shading_rate_palette_entries = p_shading_rate_palette_entries, shading_rate_palette_entry_count: crate::data::ShadingRatePaletteEntryNV]
+    }
+    PipelineViewportShadingRateImageStateCreateInfoNV => PipelineViewportShadingRateImageStateCreateInfoNVBuilder {
+        [value shading_rate_image_enable: crate::data::Bool32]
+        [slice shading_rate_palettes = p_shading_rate_palettes, viewport_count: crate::data::ShadingRatePaletteNV]
+    }
+    PhysicalDeviceShadingRateImageFeaturesNV => PhysicalDeviceShadingRateImageFeaturesNVBuilder {
+        [value shading_rate_image: crate::data::Bool32]
+        [value shading_rate_coarse_sample_order: crate::data::Bool32]
+    }
+    PhysicalDeviceShadingRateImagePropertiesNV => PhysicalDeviceShadingRateImagePropertiesNVBuilder {
+        [value shading_rate_texel_size: crate::data::Extent2D]
+        [value shading_rate_palette_size: u32]
+        [value shading_rate_max_coarse_samples: u32]
+    }
+    PhysicalDeviceInvocationMaskFeaturesHUAWEI => PhysicalDeviceInvocationMaskFeaturesHUAWEIBuilder {
+        [value invocation_mask: crate::data::Bool32]
+    }
+    CoarseSampleOrderCustomNV => CoarseSampleOrderCustomNVBuilder {
+        [value shading_rate: crate::data::ShadingRatePaletteEntryNV]
+        [value sample_count: u32]
+        [slice sample_locations = p_sample_locations, sample_location_count: crate::data::CoarseSampleLocationNV]
+    }
+    PipelineViewportCoarseSampleOrderStateCreateInfoNV => PipelineViewportCoarseSampleOrderStateCreateInfoNVBuilder {
+        [value sample_order_type: crate::data::CoarseSampleOrderTypeNV]
+        [slice custom_sample_orders = p_custom_sample_orders, custom_sample_order_count: crate::data::CoarseSampleOrderCustomNV]
+    }
+    PhysicalDeviceMeshShaderFeaturesNV => PhysicalDeviceMeshShaderFeaturesNVBuilder {
+        [value task_shader: crate::data::Bool32]
+        [value mesh_shader: crate::data::Bool32]
+    }
+    PhysicalDeviceMeshShaderPropertiesNV => PhysicalDeviceMeshShaderPropertiesNVBuilder {
+        [value max_draw_mesh_tasks_count: u32]
+        [value max_task_work_group_invocations: u32]
+        [value max_task_work_group_size: [u32; 3]]
+        [value max_task_total_memory_size: u32]
+        [value max_task_output_count: u32]
+        [value max_mesh_work_group_invocations: u32]
+        [value max_mesh_work_group_size: [u32; 3]]
+        [value max_mesh_total_memory_size: u32]
+        [value max_mesh_output_vertices: u32]
+        [value max_mesh_output_primitives: u32]
+        [value max_mesh_multiview_view_count: u32]
+        [value mesh_output_per_vertex_granularity: u32]
+        [value mesh_output_per_primitive_granularity: u32]
+    }
+    PhysicalDeviceMeshShaderFeaturesEXT => PhysicalDeviceMeshShaderFeaturesEXTBuilder {
+        [value task_shader: crate::data::Bool32]
+        [value mesh_shader: crate::data::Bool32]
+        [value multiview_mesh_shader: crate::data::Bool32]
+        [value primitive_fragment_shading_rate_mesh_shader: crate::data::Bool32]
+        [value mesh_shader_queries: crate::data::Bool32]
+    }
+    PhysicalDeviceMeshShaderPropertiesEXT => PhysicalDeviceMeshShaderPropertiesEXTBuilder {
+        [value max_task_work_group_total_count: u32]
+        [value max_task_work_group_count: [u32; 3]]
+        [value max_task_work_group_invocations: u32]
+        [value max_task_work_group_size: [u32; 3]]
+        [value max_task_payload_size: u32]
+        [value max_task_shared_memory_size: u32]
+        [value max_task_payload_and_shared_memory_size: u32]
+        [value max_mesh_work_group_total_count: u32]
+        [value max_mesh_work_group_count: [u32; 3]]
+        [value max_mesh_work_group_invocations: u32]
+        [value max_mesh_work_group_size: [u32; 3]]
+        [value max_mesh_shared_memory_size: u32]
+        [value max_mesh_payload_and_shared_memory_size: u32]
+        [value max_mesh_output_memory_size: u32]
+        [value max_mesh_payload_and_output_memory_size: u32]
+        [value max_mesh_output_components: u32]
+        [value max_mesh_output_vertices: u32]
+        [value max_mesh_output_primitives: u32]
+        [value max_mesh_output_layers: u32]
+        [value max_mesh_multiview_view_count: u32]
+        [value mesh_output_per_vertex_granularity: u32]
+        [value mesh_output_per_primitive_granularity: u32]
+        [value max_preferred_task_work_group_invocations: u32]
+        [value max_preferred_mesh_work_group_invocations: u32]
+        [value prefers_local_invocation_vertex_output: crate::data::Bool32]
+        [value prefers_local_invocation_primitive_output: crate::data::Bool32]
+        [value prefers_compact_vertex_output: crate::data::Bool32]
+        [value prefers_compact_primitive_output: crate::data::Bool32]
+    }
+    RayTracingShaderGroupCreateInfoNV => RayTracingShaderGroupCreateInfoNVBuilder {
+        [value ty: crate::data::RayTracingShaderGroupTypeKHR]
+        [value general_shader: u32]
+        [value closest_hit_shader: u32]
+        [value any_hit_shader: u32]
+        [value intersection_shader: u32]
+    }
+    RayTracingShaderGroupCreateInfoKHR => RayTracingShaderGroupCreateInfoKHRBuilder {
+        [value ty: crate::data::RayTracingShaderGroupTypeKHR]
+        [value general_shader: u32]
+        [value closest_hit_shader: u32]
+        [value any_hit_shader: u32]
+        [value intersection_shader: u32]
+        [value p_shader_group_capture_replay_handle: *const c_void]
+    }
+    RayTracingPipelineCreateInfoNV => RayTracingPipelineCreateInfoNVBuilder {
+        [next]
+        [value flags: crate::data::PipelineCreateFlags]
+        [slice stages = p_stages, stage_count: crate::data::PipelineShaderStageCreateInfo]
+        [slice groups = p_groups, group_count: crate::data::RayTracingShaderGroupCreateInfoNV]
+        [value max_recursion_depth: u32]
+        [value layout: crate::data::PipelineLayout]
+        [value base_pipeline_handle: crate::data::Pipeline]
+        [value base_pipeline_index: i32]
+    }
+    RayTracingPipelineCreateInfoKHR => RayTracingPipelineCreateInfoKHRBuilder {
+        [next]
+        [value flags: crate::data::PipelineCreateFlags]
+        [slice stages = p_stages, stage_count: crate::data::PipelineShaderStageCreateInfo]
+        [slice groups = p_groups, group_count: crate::data::RayTracingShaderGroupCreateInfoKHR]
+        [value max_pipeline_ray_recursion_depth: u32]
+        [ptr library_info = p_library_info: crate::data::PipelineLibraryCreateInfoKHR]
+        [ptr library_interface = p_library_interface: crate::data::RayTracingPipelineInterfaceCreateInfoKHR]
+        [ptr dynamic_state = p_dynamic_state: crate::data::PipelineDynamicStateCreateInfo]
+        [value layout: crate::data::PipelineLayout]
+        [value base_pipeline_handle: crate::data::Pipeline]
+        [value base_pipeline_index: i32]
+    }
+    GeometryTrianglesNV => GeometryTrianglesNVBuilder {
+        [value vertex_data: crate::data::Buffer]
+        [value vertex_offset: crate::data::DeviceSize]
+        [value vertex_count: u32]
+        [value vertex_stride: crate::data::DeviceSize]
+        [value vertex_format: crate::data::Format]
+        [value index_data: crate::data::Buffer]
+        [value index_offset: crate::data::DeviceSize]
+        [value index_count: u32]
+        [value index_type: crate::data::IndexType]
+        [value transform_data: crate::data::Buffer]
+        [value transform_offset: crate::data::DeviceSize]
+    }
+    GeometryAABBNV => GeometryAABBNVBuilder {
+        [value aabb_data: crate::data::Buffer]
+        [value num_aab_bs: u32]
+        [value stride: u32]
+        [value offset: crate::data::DeviceSize]
+    }
+    GeometryNV => GeometryNVBuilder {
+        [value geometry_type: crate::data::GeometryTypeKHR]
+        [value geometry: crate::data::GeometryDataNV]
+        [value flags: crate::data::GeometryFlagsKHR]
+    }
+    AccelerationStructureInfoNV => AccelerationStructureInfoNVBuilder {
+        [value ty: crate::data::AccelerationStructureTypeNV]
+        [value flags: crate::data::BuildAccelerationStructureFlagsNV]
+        [value instance_count: u32]
+        [slice geometries = p_geometries, geometry_count: crate::data::GeometryNV]
+    }
+    AccelerationStructureCreateInfoNV => AccelerationStructureCreateInfoNVBuilder {
+        [value compacted_size: crate::data::DeviceSize]
+        [value info: crate::data::AccelerationStructureInfoNV]
+    }
+    BindAccelerationStructureMemoryInfoNV => BindAccelerationStructureMemoryInfoNVBuilder {
+        [value acceleration_structure: crate::data::AccelerationStructureNV]
+        [value memory: crate::data::DeviceMemory]
+        [value memory_offset: crate::data::DeviceSize]
+        [slice device_indices = p_device_indices, device_index_count: u32]
+    }
+    WriteDescriptorSetAccelerationStructureKHR => WriteDescriptorSetAccelerationStructureKHRBuilder {
+        [slice acceleration_structures = p_acceleration_structures, acceleration_structure_count: crate::data::AccelerationStructureKHR]
+    }
+    WriteDescriptorSetAccelerationStructureNV => WriteDescriptorSetAccelerationStructureNVBuilder {
+        [slice acceleration_structures = p_acceleration_structures, acceleration_structure_count: crate::data::AccelerationStructureNV]
+    }
+    AccelerationStructureMemoryRequirementsInfoNV => AccelerationStructureMemoryRequirementsInfoNVBuilder {
+        [value ty: crate::data::AccelerationStructureMemoryRequirementsTypeNV]
+        [value acceleration_structure: crate::data::AccelerationStructureNV]
+    }
+    PhysicalDeviceAccelerationStructureFeaturesKHR => PhysicalDeviceAccelerationStructureFeaturesKHRBuilder {
+        [value acceleration_structure: crate::data::Bool32]
+        [value acceleration_structure_capture_replay: crate::data::Bool32]
+        [value acceleration_structure_indirect_build: crate::data::Bool32]
+        [value acceleration_structure_host_commands: crate::data::Bool32]
+        [value descriptor_binding_acceleration_structure_update_after_bind: crate::data::Bool32]
+    }
+    PhysicalDeviceRayTracingPipelineFeaturesKHR => PhysicalDeviceRayTracingPipelineFeaturesKHRBuilder {
+        [value ray_tracing_pipeline: crate::data::Bool32]
+        [value ray_tracing_pipeline_shader_group_handle_capture_replay: crate::data::Bool32]
+        [value ray_tracing_pipeline_shader_group_handle_capture_replay_mixed: crate::data::Bool32]
+        [value ray_tracing_pipeline_trace_rays_indirect: crate::data::Bool32]
+        [value ray_traversal_primitive_culling: crate::data::Bool32]
+    }
+    PhysicalDeviceRayQueryFeaturesKHR => PhysicalDeviceRayQueryFeaturesKHRBuilder {
+        [value ray_query: crate::data::Bool32]
+    }
+    PhysicalDeviceAccelerationStructurePropertiesKHR => PhysicalDeviceAccelerationStructurePropertiesKHRBuilder {
+        [value max_geometry_count: u64]
+        [value max_instance_count: u64]
+        [value max_primitive_count: u64]
+        [value max_per_stage_descriptor_acceleration_structures: u32]
+        [value max_per_stage_descriptor_update_after_bind_acceleration_structures: u32]
+        [value max_descriptor_set_acceleration_structures: u32]
+        [value max_descriptor_set_update_after_bind_acceleration_structures: u32]
+        [value min_acceleration_structure_scratch_offset_alignment: u32]
+    }
+    PhysicalDeviceRayTracingPipelinePropertiesKHR => PhysicalDeviceRayTracingPipelinePropertiesKHRBuilder {
+        [value shader_group_handle_size: u32]
+        [value max_ray_recursion_depth: u32]
+        [value max_shader_group_stride: u32]
+        [value shader_group_base_alignment: u32]
+        [value shader_group_handle_capture_replay_size: u32]
+        [value max_ray_dispatch_invocation_count: u32]
+        [value shader_group_handle_alignment: u32]
+        [value max_ray_hit_attribute_size: u32]
+    }
+    PhysicalDeviceRayTracingPropertiesNV => PhysicalDeviceRayTracingPropertiesNVBuilder {
+        [value shader_group_handle_size: u32]
+        [value max_recursion_depth: u32]
+        [value max_shader_group_stride: u32]
+        [value shader_group_base_alignment: u32]
+        [value max_geometry_count: u64]
+        [value max_instance_count: u64]
+        [value max_triangle_count: u64]
+        [value max_descriptor_set_acceleration_structures: u32]
+    }
+    PhysicalDeviceRayTracingMaintenance1FeaturesKHR => PhysicalDeviceRayTracingMaintenance1FeaturesKHRBuilder {
+        [value ray_tracing_maintenance_1: crate::data::Bool32]
+        [value ray_tracing_pipeline_trace_rays_indirect_2: crate::data::Bool32]
+    }
+    DrmFormatModifierPropertiesListEXT => DrmFormatModifierPropertiesListEXTBuilder {
+        [slice_mut drm_format_modifier_properties = p_drm_format_modifier_properties, drm_format_modifier_count: crate::data::DrmFormatModifierPropertiesEXT]
+    }
+    PhysicalDeviceImageDrmFormatModifierInfoEXT => PhysicalDeviceImageDrmFormatModifierInfoEXTBuilder {
+        [value drm_format_modifier: u64]
+        [value sharing_mode: crate::data::SharingMode]
+        [slice queue_family_indices = p_queue_family_indices, queue_family_index_count: u32]
+    }
+    ImageDrmFormatModifierListCreateInfoEXT => ImageDrmFormatModifierListCreateInfoEXTBuilder {
+        [slice drm_format_modifiers = p_drm_format_modifiers, drm_format_modifier_count: u64]
+    }
+    ImageDrmFormatModifierExplicitCreateInfoEXT => ImageDrmFormatModifierExplicitCreateInfoEXTBuilder {
+        [value drm_format_modifier: u64]
+        [slice plane_layouts = p_plane_layouts, drm_format_modifier_plane_count: crate::data::SubresourceLayout]
+    }
+    ImageDrmFormatModifierPropertiesEXT => ImageDrmFormatModifierPropertiesEXTBuilder {
+        [value drm_format_modifier: u64]
+    }
+    ImageStencilUsageCreateInfo => ImageStencilUsageCreateInfoBuilder {
+        [value stencil_usage: crate::data::ImageUsageFlags]
+    }
+    DeviceMemoryOverallocationCreateInfoAMD => DeviceMemoryOverallocationCreateInfoAMDBuilder {
+        [value overallocation_behavior: crate::data::MemoryOverallocationBehaviorAMD]
+    }
+    PhysicalDeviceFragmentDensityMapFeaturesEXT => PhysicalDeviceFragmentDensityMapFeaturesEXTBuilder {
+        [value fragment_density_map: crate::data::Bool32]
+        [value fragment_density_map_dynamic: crate::data::Bool32]
+        [value fragment_density_map_non_subsampled_images: crate::data::Bool32]
+    }
+    PhysicalDeviceFragmentDensityMap2FeaturesEXT => PhysicalDeviceFragmentDensityMap2FeaturesEXTBuilder {
+        [value fragment_density_map_deferred: crate::data::Bool32]
+    }
+    PhysicalDeviceFragmentDensityMapOffsetFeaturesQCOM => PhysicalDeviceFragmentDensityMapOffsetFeaturesQCOMBuilder {
+        [value fragment_density_map_offset: crate::data::Bool32]
+    }
+    PhysicalDeviceFragmentDensityMapPropertiesEXT => PhysicalDeviceFragmentDensityMapPropertiesEXTBuilder {
+        [value min_fragment_density_texel_size: crate::data::Extent2D]
+        [value max_fragment_density_texel_size: crate::data::Extent2D]
+        [value fragment_density_invocations: crate::data::Bool32]
+    }
+    PhysicalDeviceFragmentDensityMap2PropertiesEXT => PhysicalDeviceFragmentDensityMap2PropertiesEXTBuilder {
+        [value subsampled_loads: crate::data::Bool32]
+        [value subsampled_coarse_reconstruction_early_access: crate::data::Bool32]
+        [value max_subsampled_array_layers: u32]
+        [value max_descriptor_set_subsampled_samplers: u32]
+    }
+    PhysicalDeviceFragmentDensityMapOffsetPropertiesQCOM => PhysicalDeviceFragmentDensityMapOffsetPropertiesQCOMBuilder {
+        [value fragment_density_offset_granularity: crate::data::Extent2D]
+    }
+    RenderPassFragmentDensityMapCreateInfoEXT => RenderPassFragmentDensityMapCreateInfoEXTBuilder {
+        [value fragment_density_map_attachment: crate::data::AttachmentReference]
+    }
+    SubpassFragmentDensityMapOffsetEndInfoQCOM => SubpassFragmentDensityMapOffsetEndInfoQCOMBuilder {
+        [slice fragment_density_offsets = p_fragment_density_offsets, fragment_density_offset_count: crate::data::Offset2D]
+    }
+    PhysicalDeviceScalarBlockLayoutFeatures => PhysicalDeviceScalarBlockLayoutFeaturesBuilder {
+        [value scalar_block_layout: crate::data::Bool32]
+    }
+    SurfaceProtectedCapabilitiesKHR => SurfaceProtectedCapabilitiesKHRBuilder {
+        [value supports_protected: crate::data::Bool32]
+    }
+    PhysicalDeviceUniformBufferStandardLayoutFeatures => PhysicalDeviceUniformBufferStandardLayoutFeaturesBuilder {
+        [value uniform_buffer_standard_layout: crate::data::Bool32]
+    }
+    PhysicalDeviceDepthClipEnableFeaturesEXT => PhysicalDeviceDepthClipEnableFeaturesEXTBuilder {
+        [value depth_clip_enable: crate::data::Bool32]
+    }
+    PipelineRasterizationDepthClipStateCreateInfoEXT => PipelineRasterizationDepthClipStateCreateInfoEXTBuilder {
+        [value flags: crate::data::PipelineRasterizationDepthClipStateCreateFlagsEXT]
+        [value depth_clip_enable: crate::data::Bool32]
+    }
+    PhysicalDeviceMemoryBudgetPropertiesEXT => PhysicalDeviceMemoryBudgetPropertiesEXTBuilder {
+        [value heap_budget: [crate::data::DeviceSize; 16]]
+        [value heap_usage: [crate::data::DeviceSize; 16]]
+    }
+    PhysicalDeviceMemoryPriorityFeaturesEXT => PhysicalDeviceMemoryPriorityFeaturesEXTBuilder {
+        [value memory_priority: crate::data::Bool32]
+    }
+    MemoryPriorityAllocateInfoEXT => MemoryPriorityAllocateInfoEXTBuilder {
+        [value priority: c_float]
+    }
+    PhysicalDevicePageableDeviceLocalMemoryFeaturesEXT => PhysicalDevicePageableDeviceLocalMemoryFeaturesEXTBuilder {
+        [value pageable_device_local_memory: crate::data::Bool32]
+    }
+    PhysicalDeviceBufferDeviceAddressFeatures => PhysicalDeviceBufferDeviceAddressFeaturesBuilder {
+        [value buffer_device_address: crate::data::Bool32]
+        [value buffer_device_address_capture_replay: crate::data::Bool32]
+        [value buffer_device_address_multi_device: crate::data::Bool32]
+    }
+    PhysicalDeviceBufferDeviceAddressFeaturesEXT => PhysicalDeviceBufferDeviceAddressFeaturesEXTBuilder {
+        [value buffer_device_address: crate::data::Bool32]
+        [value buffer_device_address_capture_replay: crate::data::Bool32]
+        [value buffer_device_address_multi_device: crate::data::Bool32]
+    }
+    BufferDeviceAddressInfo => BufferDeviceAddressInfoBuilder {
+        [value buffer: crate::data::Buffer]
+    }
+    BufferOpaqueCaptureAddressCreateInfo => BufferOpaqueCaptureAddressCreateInfoBuilder {
+        [value opaque_capture_address: u64]
+    }
+    BufferDeviceAddressCreateInfoEXT => BufferDeviceAddressCreateInfoEXTBuilder {
+        [value device_address: crate::data::DeviceAddress]
+    }
+    PhysicalDeviceImageViewImageFormatInfoEXT => PhysicalDeviceImageViewImageFormatInfoEXTBuilder {
+        [value image_view_type: crate::data::ImageViewType]
+    }
+    FilterCubicImageViewImageFormatPropertiesEXT => FilterCubicImageViewImageFormatPropertiesEXTBuilder {
+        [value filter_cubic: crate::data::Bool32]
+        [value filter_cubic_minmax: crate::data::Bool32]
+    }
+    PhysicalDeviceImagelessFramebufferFeatures => PhysicalDeviceImagelessFramebufferFeaturesBuilder {
+        [value imageless_framebuffer: crate::data::Bool32]
+    }
+    FramebufferAttachmentsCreateInfo => FramebufferAttachmentsCreateInfoBuilder {
+        [slice attachment_image_infos = p_attachment_image_infos, attachment_image_info_count: crate::data::FramebufferAttachmentImageInfo]
+    }
+    FramebufferAttachmentImageInfo => FramebufferAttachmentImageInfoBuilder {
+        [value flags: crate::data::ImageCreateFlags]
+        [value usage: crate::data::ImageUsageFlags]
+        [value width: u32]
+        [value height: u32]
+        [value layer_count: u32]
+        [slice view_formats = p_view_formats, view_format_count: crate::data::Format]
+    }
+    RenderPassAttachmentBeginInfo => RenderPassAttachmentBeginInfoBuilder {
+        [slice attachments = p_attachments, attachment_count: crate::data::ImageView]
+    }
+    PhysicalDeviceTextureCompressionASTCHDRFeatures => PhysicalDeviceTextureCompressionASTCHDRFeaturesBuilder {
+        [value texture_compression_astc_hdr: crate::data::Bool32]
+    }
+    PhysicalDeviceCooperativeMatrixFeaturesNV => PhysicalDeviceCooperativeMatrixFeaturesNVBuilder {
+        [value cooperative_matrix: crate::data::Bool32]
+        [value cooperative_matrix_robust_buffer_access: crate::data::Bool32]
+    }
+    PhysicalDeviceCooperativeMatrixPropertiesNV => PhysicalDeviceCooperativeMatrixPropertiesNVBuilder {
+        [value cooperative_matrix_supported_stages: crate::data::ShaderStageFlags]
+    }
+    CooperativeMatrixPropertiesNV => CooperativeMatrixPropertiesNVBuilder {
+        [value m_size: u32]
+        [value n_size: u32]
+        [value k_size: u32]
+        [value a_type: crate::data::ComponentTypeNV]
+        [value b_type: crate::data::ComponentTypeNV]
+        [value c_type: crate::data::ComponentTypeNV]
+        [value d_type: crate::data::ComponentTypeNV]
+        [value scope: crate::data::ScopeNV]
+    }
+    PhysicalDeviceYcbcrImageArraysFeaturesEXT => PhysicalDeviceYcbcrImageArraysFeaturesEXTBuilder {
+        [value ycbcr_image_arrays: crate::data::Bool32]
+    }
+    ImageViewHandleInfoNVX => ImageViewHandleInfoNVXBuilder {
+        [value image_view: crate::data::ImageView]
+        [value descriptor_type: crate::data::DescriptorType]
+        [value sampler: crate::data::Sampler]
+    }
+    ImageViewAddressPropertiesNVX => ImageViewAddressPropertiesNVXBuilder {
+        [value device_address: crate::data::DeviceAddress]
+        [value size: crate::data::DeviceSize]
+    }
+    PresentFrameTokenGGP => PresentFrameTokenGGPBuilder {
+        [value frame_token: GgpFrameToken]
+    }
+    PipelineCreationFeedbackCreateInfo => PipelineCreationFeedbackCreateInfoBuilder {
+        [ptr_mut pipeline_creation_feedback = p_pipeline_creation_feedback: crate::data::PipelineCreationFeedback]
+        [slice_mut pipeline_stage_creation_feedbacks = p_pipeline_stage_creation_feedbacks, pipeline_stage_creation_feedback_count: crate::data::PipelineCreationFeedback]
+    }
+    SurfaceFullScreenExclusiveInfoEXT => SurfaceFullScreenExclusiveInfoEXTBuilder {
+        [value full_screen_exclusive: crate::data::FullScreenExclusiveEXT]
+    }
+    SurfaceFullScreenExclusiveWin32InfoEXT => SurfaceFullScreenExclusiveWin32InfoEXTBuilder {
+        [value hmonitor: HMONITOR]
+    }
+    SurfaceCapabilitiesFullScreenExclusiveEXT => SurfaceCapabilitiesFullScreenExclusiveEXTBuilder {
+        [value full_screen_exclusive_supported: crate::data::Bool32]
+    }
+    PhysicalDevicePresentBarrierFeaturesNV => PhysicalDevicePresentBarrierFeaturesNVBuilder {
+        [value present_barrier: crate::data::Bool32]
+    }
+    SurfaceCapabilitiesPresentBarrierNV => SurfaceCapabilitiesPresentBarrierNVBuilder {
+        [value present_barrier_supported: crate::data::Bool32]
+    }
+    SwapchainPresentBarrierCreateInfoNV => SwapchainPresentBarrierCreateInfoNVBuilder {
+        [value present_barrier_enable: crate::data::Bool32]
+    }
+    PhysicalDevicePerformanceQueryFeaturesKHR => PhysicalDevicePerformanceQueryFeaturesKHRBuilder {
+        [value performance_counter_query_pools: crate::data::Bool32]
+        [value performance_counter_multiple_query_pools: crate::data::Bool32]
+    }
+    PhysicalDevicePerformanceQueryPropertiesKHR => PhysicalDevicePerformanceQueryPropertiesKHRBuilder {
+        [value allow_command_buffer_query_copies: crate::data::Bool32]
+    }
+    PerformanceCounterKHR => PerformanceCounterKHRBuilder {
+        [value unit: crate::data::PerformanceCounterUnitKHR]
+        [value scope: crate::data::PerformanceCounterScopeKHR]
+        [value storage: crate::data::PerformanceCounterStorageKHR]
+        [value uuid: [u8; 16]]
+    }
+    PerformanceCounterDescriptionKHR => PerformanceCounterDescriptionKHRBuilder {
+        [value flags: crate::data::PerformanceCounterDescriptionFlagsKHR]
+        [value name: [c_char; 256]]
+        [value category: [c_char; 256]]
+        [value description: [c_char; 256]]
+    }
+    QueryPoolPerformanceCreateInfoKHR => QueryPoolPerformanceCreateInfoKHRBuilder {
+        [value queue_family_index: u32]
+        [slice counter_indices = p_counter_indices, counter_index_count: u32]
+    }
+    AcquireProfilingLockInfoKHR => AcquireProfilingLockInfoKHRBuilder {
+        [value flags: crate::data::AcquireProfilingLockFlagsKHR]
+        [value timeout: u64]
+    }
+    PerformanceQuerySubmitInfoKHR => PerformanceQuerySubmitInfoKHRBuilder {
+        [value counter_pass_index: u32]
+    }
+    HeadlessSurfaceCreateInfoEXT => HeadlessSurfaceCreateInfoEXTBuilder {
+        [value flags: crate::data::HeadlessSurfaceCreateFlagsEXT]
+    }
+    PhysicalDeviceCoverageReductionModeFeaturesNV => PhysicalDeviceCoverageReductionModeFeaturesNVBuilder {
+        [value coverage_reduction_mode: crate::data::Bool32]
+    }
+    PipelineCoverageReductionStateCreateInfoNV => PipelineCoverageReductionStateCreateInfoNVBuilder {
+        [value flags: crate::data::PipelineCoverageReductionStateCreateFlagsNV]
+        [value coverage_reduction_mode: crate::data::CoverageReductionModeNV]
+    }
+    FramebufferMixedSamplesCombinationNV => FramebufferMixedSamplesCombinationNVBuilder {
+        [value coverage_reduction_mode: crate::data::CoverageReductionModeNV]
+        [value rasterization_samples: crate::data::SampleCountFlagBits]
+        [value depth_stencil_samples: crate::data::SampleCountFlags]
+        [value color_samples: crate::data::SampleCountFlags]
+    }
+    PhysicalDeviceShaderIntegerFunctions2FeaturesINTEL => PhysicalDeviceShaderIntegerFunctions2FeaturesINTELBuilder {
+        [value shader_integer_functions_2: crate::data::Bool32]
+    }
+    InitializePerformanceApiInfoINTEL => InitializePerformanceApiInfoINTELBuilder {
+        [value p_user_data: *mut c_void]
+    }
+    QueryPoolPerformanceQueryCreateInfoINTEL => QueryPoolPerformanceQueryCreateInfoINTELBuilder {
+        [value performance_counters_sampling: crate::data::QueryPoolSamplingModeINTEL]
+    }
+    PerformanceMarkerInfoINTEL => PerformanceMarkerInfoINTELBuilder {
+        [value marker: u64]
+    }
+    PerformanceStreamMarkerInfoINTEL => PerformanceStreamMarkerInfoINTELBuilder {
+        [value marker: u32]
+    }
+    PerformanceOverrideInfoINTEL => PerformanceOverrideInfoINTELBuilder {
+        [value ty: crate::data::PerformanceOverrideTypeINTEL]
+        [value enable: crate::data::Bool32]
+        [value parameter: u64]
+    }
+    PerformanceConfigurationAcquireInfoINTEL => PerformanceConfigurationAcquireInfoINTELBuilder {
+        [value ty: crate::data::PerformanceConfigurationTypeINTEL]
+    }
+    PhysicalDeviceShaderClockFeaturesKHR => PhysicalDeviceShaderClockFeaturesKHRBuilder {
+        [value shader_subgroup_clock: crate::data::Bool32]
+        [value shader_device_clock: crate::data::Bool32]
+    }
+    PhysicalDeviceIndexTypeUint8FeaturesEXT => PhysicalDeviceIndexTypeUint8FeaturesEXTBuilder {
+        [value index_type_uint_8: crate::data::Bool32]
+    }
+    PhysicalDeviceShaderSMBuiltinsPropertiesNV => PhysicalDeviceShaderSMBuiltinsPropertiesNVBuilder {
+        [value shader_sm_count: u32]
+        [value shader_warps_per_sm: u32]
+    }
+    PhysicalDeviceShaderSMBuiltinsFeaturesNV => PhysicalDeviceShaderSMBuiltinsFeaturesNVBuilder {
+        [value shader_sm_builtins: crate::data::Bool32]
+    }
+    PhysicalDeviceFragmentShaderInterlockFeaturesEXT => PhysicalDeviceFragmentShaderInterlockFeaturesEXTBuilder {
+        [value fragment_shader_sample_interlock: crate::data::Bool32]
+        [value fragment_shader_pixel_interlock: crate::data::Bool32]
+        [value fragment_shader_shading_rate_interlock: crate::data::Bool32]
+    }
+    PhysicalDeviceSeparateDepthStencilLayoutsFeatures => PhysicalDeviceSeparateDepthStencilLayoutsFeaturesBuilder {
+        [value separate_depth_stencil_layouts: crate::data::Bool32]
+    }
+    AttachmentReferenceStencilLayout => AttachmentReferenceStencilLayoutBuilder {
+        [value stencil_layout: crate::data::ImageLayout]
+    }
+    PhysicalDevicePrimitiveTopologyListRestartFeaturesEXT => PhysicalDevicePrimitiveTopologyListRestartFeaturesEXTBuilder {
+        [value primitive_topology_list_restart: crate::data::Bool32]
+        [value primitive_topology_patch_list_restart: crate::data::Bool32]
+    }
+    AttachmentDescriptionStencilLayout => AttachmentDescriptionStencilLayoutBuilder {
+        [value stencil_initial_layout: crate::data::ImageLayout]
+        [value stencil_final_layout: crate::data::ImageLayout]
+    }
+    PhysicalDevicePipelineExecutablePropertiesFeaturesKHR => PhysicalDevicePipelineExecutablePropertiesFeaturesKHRBuilder {
+        [value pipeline_executable_info: crate::data::Bool32]
+    }
+    PipelineInfoKHR => PipelineInfoKHRBuilder {
+        [value pipeline: crate::data::Pipeline]
+    }
+    PipelineExecutablePropertiesKHR => PipelineExecutablePropertiesKHRBuilder {
+        [value stages: crate::data::ShaderStageFlags]
+        [value name: [c_char; 256]]
+        [value description: [c_char; 256]]
+        [value subgroup_size: u32]
+    }
+    PipelineExecutableInfoKHR => PipelineExecutableInfoKHRBuilder {
+        [value pipeline: crate::data::Pipeline]
+        [value executable_index: u32]
+    }
+    PipelineExecutableStatisticKHR => PipelineExecutableStatisticKHRBuilder {
+        [value name: [c_char; 256]]
+        [value description: [c_char; 256]]
+        [value format: crate::data::PipelineExecutableStatisticFormatKHR]
+        [value value: crate::data::PipelineExecutableStatisticValueKHR]
+    }
+    PipelineExecutableInternalRepresentationKHR => PipelineExecutableInternalRepresentationKHRBuilder {
+        [value name: [c_char; 256]]
+        [value description: [c_char; 256]]
+        [value is_text: crate::data::Bool32]
+        [slice_mut data = p_data, data_size: u8]
+    }
+    PhysicalDeviceShaderDemoteToHelperInvocationFeatures => PhysicalDeviceShaderDemoteToHelperInvocationFeaturesBuilder {
+        [value shader_demote_to_helper_invocation: crate::data::Bool32]
+    }
+    PhysicalDeviceTexelBufferAlignmentFeaturesEXT => PhysicalDeviceTexelBufferAlignmentFeaturesEXTBuilder {
+        [value texel_buffer_alignment: crate::data::Bool32]
+    }
+    PhysicalDeviceTexelBufferAlignmentProperties => PhysicalDeviceTexelBufferAlignmentPropertiesBuilder {
+        [value storage_texel_buffer_offset_alignment_bytes: crate::data::DeviceSize]
+        [value storage_texel_buffer_offset_single_texel_alignment: crate::data::Bool32]
+        [value uniform_texel_buffer_offset_alignment_bytes: crate::data::DeviceSize]
+        [value uniform_texel_buffer_offset_single_texel_alignment: crate::data::Bool32]
+    }
+    PhysicalDeviceSubgroupSizeControlFeatures => PhysicalDeviceSubgroupSizeControlFeaturesBuilder {
+        [value subgroup_size_control: crate::data::Bool32]
+        [value compute_full_subgroups: crate::data::Bool32]
+    }
+    PhysicalDeviceSubgroupSizeControlProperties => PhysicalDeviceSubgroupSizeControlPropertiesBuilder {
+        [value min_subgroup_size: u32]
+        [value max_subgroup_size: u32]
+        [value max_compute_workgroup_subgroups: u32]
+        [value required_subgroup_size_stages: crate::data::ShaderStageFlags]
+    }
+    PipelineShaderStageRequiredSubgroupSizeCreateInfo => PipelineShaderStageRequiredSubgroupSizeCreateInfoBuilder {
+        [value required_subgroup_size: u32]
+    }
+    SubpassShadingPipelineCreateInfoHUAWEI => SubpassShadingPipelineCreateInfoHUAWEIBuilder {
+        [value render_pass: crate::data::RenderPass]
+        [value subpass: u32]
+    }
+    PhysicalDeviceSubpassShadingPropertiesHUAWEI => PhysicalDeviceSubpassShadingPropertiesHUAWEIBuilder {
+        [value max_subpass_shading_workgroup_size_aspect_ratio: u32]
+    }
+    MemoryOpaqueCaptureAddressAllocateInfo => MemoryOpaqueCaptureAddressAllocateInfoBuilder {
+        [value opaque_capture_address: u64]
+    }
+    DeviceMemoryOpaqueCaptureAddressInfo => DeviceMemoryOpaqueCaptureAddressInfoBuilder {
+        [value memory: crate::data::DeviceMemory]
+    }
+    PhysicalDeviceLineRasterizationFeaturesEXT => PhysicalDeviceLineRasterizationFeaturesEXTBuilder {
+        [value rectangular_lines: crate::data::Bool32]
+        [value bresenham_lines: crate::data::Bool32]
+        [value smooth_lines: crate::data::Bool32]
+        [value stippled_rectangular_lines: crate::data::Bool32]
+        [value stippled_bresenham_lines: crate::data::Bool32]
+        [value stippled_smooth_lines: crate::data::Bool32]
+    }
+    PhysicalDeviceLineRasterizationPropertiesEXT => PhysicalDeviceLineRasterizationPropertiesEXTBuilder {
+        [value line_sub_pixel_precision_bits: u32]
+    }
+    PipelineRasterizationLineStateCreateInfoEXT => PipelineRasterizationLineStateCreateInfoEXTBuilder {
+        [value line_rasterization_mode: crate::data::LineRasterizationModeEXT]
+        [value stippled_line_enable: crate::data::Bool32]
+        [value line_stipple_factor: u32]
+        [value line_stipple_pattern: u16]
+    }
+    PhysicalDevicePipelineCreationCacheControlFeatures => PhysicalDevicePipelineCreationCacheControlFeaturesBuilder {
+        [value pipeline_creation_cache_control: crate::data::Bool32]
+    }
+    PhysicalDeviceVulkan11Features => PhysicalDeviceVulkan11FeaturesBuilder {
+        [value storage_buffer_16_bit_access: crate::data::Bool32]
+        [value uniform_and_storage_buffer_16_bit_access: crate::data::Bool32]
+        [value storage_push_constant_16: crate::data::Bool32]
+        [value storage_input_output_16: crate::data::Bool32]
+        [value multiview: crate::data::Bool32]
+        [value multiview_geometry_shader: crate::data::Bool32]
+        [value multiview_tessellation_shader: crate::data::Bool32]
+        [value variable_pointers_storage_buffer: crate::data::Bool32]
+        [value variable_pointers: crate::data::Bool32]
+        [value protected_memory: crate::data::Bool32]
+        [value sampler_ycbcr_conversion: crate::data::Bool32]
+        [value shader_draw_parameters: crate::data::Bool32]
+    }
+    PhysicalDeviceVulkan11Properties => PhysicalDeviceVulkan11PropertiesBuilder {
+        [value device_uuid: [u8; 16]]
+        [value driver_uuid: [u8; 16]]
+        [value device_luid: [u8; 8]]
+        [value device_node_mask: u32]
+        [value device_luid_valid: crate::data::Bool32]
+        [value subgroup_size: u32]
+        [value subgroup_supported_stages: crate::data::ShaderStageFlags]
+        [value subgroup_supported_operations: crate::data::SubgroupFeatureFlags]
+        [value subgroup_quad_operations_in_all_stages: crate::data::Bool32]
+        [value point_clipping_behavior: crate::data::PointClippingBehavior]
+        [value max_multiview_view_count: u32]
+        [value max_multiview_instance_index: u32]
+        [value protected_no_fault: crate::data::Bool32]
+        [value max_per_set_descriptors: u32]
+        [value max_memory_allocation_size: crate::data::DeviceSize]
+    }
+    PhysicalDeviceVulkan12Features => PhysicalDeviceVulkan12FeaturesBuilder {
+        [value sampler_mirror_clamp_to_edge: crate::data::Bool32]
+        [value draw_indirect_count: crate::data::Bool32]
+        [value storage_buffer_8_bit_access: crate::data::Bool32]
+        [value uniform_and_storage_buffer_8_bit_access: crate::data::Bool32]
+        [value storage_push_constant_8: crate::data::Bool32]
+        [value shader_buffer_int_64_atomics: crate::data::Bool32]
+        [value shader_shared_int_64_atomics: crate::data::Bool32]
+        [value shader_float_16: crate::data::Bool32]
+        [value shader_int_8: crate::data::Bool32]
+        [value descriptor_indexing: crate::data::Bool32]
+        [value shader_input_attachment_array_dynamic_indexing: crate::data::Bool32]
+        [value shader_uniform_texel_buffer_array_dynamic_indexing: crate::data::Bool32]
+        [value shader_storage_texel_buffer_array_dynamic_indexing: crate::data::Bool32]
+        [value shader_uniform_buffer_array_non_uniform_indexing: crate::data::Bool32]
+        [value shader_sampled_image_array_non_uniform_indexing: crate::data::Bool32]
+        [value shader_storage_buffer_array_non_uniform_indexing: crate::data::Bool32]
+        [value shader_storage_image_array_non_uniform_indexing: crate::data::Bool32]
+        [value shader_input_attachment_array_non_uniform_indexing: crate::data::Bool32]
+        [value shader_uniform_texel_buffer_array_non_uniform_indexing: crate::data::Bool32]
+        [value shader_storage_texel_buffer_array_non_uniform_indexing: crate::data::Bool32]
+        [value descriptor_binding_uniform_buffer_update_after_bind: crate::data::Bool32]
+        [value descriptor_binding_sampled_image_update_after_bind: crate::data::Bool32]
+        [value descriptor_binding_storage_image_update_after_bind: crate::data::Bool32]
+        [value descriptor_binding_storage_buffer_update_after_bind: crate::data::Bool32]
+        [value descriptor_binding_uniform_texel_buffer_update_after_bind: crate::data::Bool32]
+        [value descriptor_binding_storage_texel_buffer_update_after_bind: crate::data::Bool32]
+        [value descriptor_binding_update_unused_while_pending: crate::data::Bool32]
+        [value descriptor_binding_partially_bound: crate::data::Bool32]
+        [value descriptor_binding_variable_descriptor_count: crate::data::Bool32]
+        [value runtime_descriptor_array: crate::data::Bool32]
+        [value sampler_filter_minmax: crate::data::Bool32]
+        [value scalar_block_layout: crate::data::Bool32]
+        [value imageless_framebuffer: crate::data::Bool32]
+        [value uniform_buffer_standard_layout: crate::data::Bool32]
+        [value shader_subgroup_extended_types: crate::data::Bool32]
+        [value separate_depth_stencil_layouts: crate::data::Bool32]
+        [value host_query_reset: crate::data::Bool32]
+        [value timeline_semaphore: crate::data::Bool32]
+        [value buffer_device_address: crate::data::Bool32]
+        [value buffer_device_address_capture_replay: crate::data::Bool32]
+        [value buffer_device_address_multi_device: crate::data::Bool32]
+        [value vulkan_memory_model: crate::data::Bool32]
+        [value vulkan_memory_model_device_scope: crate::data::Bool32]
+        [value vulkan_memory_model_availability_visibility_chains: crate::data::Bool32]
+        [value shader_output_viewport_index: crate::data::Bool32]
+        [value shader_output_layer: crate::data::Bool32]
+        [value subgroup_broadcast_dynamic_id: crate::data::Bool32]
+    }
+    PhysicalDeviceVulkan12Properties => PhysicalDeviceVulkan12PropertiesBuilder {
+        [value driver_id: crate::data::DriverId]
+        [value driver_name: [c_char; 256]]
+        [value driver_info: [c_char; 256]]
+        [value conformance_version: crate::data::ConformanceVersion]
+        [value denorm_behavior_independence: crate::data::ShaderFloatControlsIndependence]
+        [value rounding_mode_independence: crate::data::ShaderFloatControlsIndependence]
+        [value shader_signed_zero_inf_nan_preserve_float_16: crate::data::Bool32]
+        [value shader_signed_zero_inf_nan_preserve_float_32: crate::data::Bool32]
+        [value shader_signed_zero_inf_nan_preserve_float_64: crate::data::Bool32]
+        [value shader_denorm_preserve_float_16: crate::data::Bool32]
+        [value shader_denorm_preserve_float_32: crate::data::Bool32]
+        [value shader_denorm_preserve_float_64: crate::data::Bool32]
+        [value shader_denorm_flush_to_zero_float_16: crate::data::Bool32]
+        [value shader_denorm_flush_to_zero_float_32: crate::data::Bool32]
+        [value shader_denorm_flush_to_zero_float_64: crate::data::Bool32]
+        [value shader_rounding_mode_rte_float_16: crate::data::Bool32]
+        [value shader_rounding_mode_rte_float_32: crate::data::Bool32]
+        [value shader_rounding_mode_rte_float_64: crate::data::Bool32]
+        [value shader_rounding_mode_rtz_float_16: crate::data::Bool32]
+        [value shader_rounding_mode_rtz_float_32: crate::data::Bool32]
+        [value shader_rounding_mode_rtz_float_64: crate::data::Bool32]
+        [value max_update_after_bind_descriptors_in_all_pools: u32]
+        [value shader_uniform_buffer_array_non_uniform_indexing_native: crate::data::Bool32]
+        [value shader_sampled_image_array_non_uniform_indexing_native: crate::data::Bool32]
+        [value shader_storage_buffer_array_non_uniform_indexing_native: crate::data::Bool32]
+        [value shader_storage_image_array_non_uniform_indexing_native: crate::data::Bool32]
+        [value shader_input_attachment_array_non_uniform_indexing_native: crate::data::Bool32]
+        [value robust_buffer_access_update_after_bind: crate::data::Bool32]
+        [value quad_divergent_implicit_lod: crate::data::Bool32]
+        [value max_per_stage_descriptor_update_after_bind_samplers: u32]
+        [value max_per_stage_descriptor_update_after_bind_uniform_buffers: u32]
+        [value max_per_stage_descriptor_update_after_bind_storage_buffers: u32]
+        [value max_per_stage_descriptor_update_after_bind_sampled_images: u32]
+        [value max_per_stage_descriptor_update_after_bind_storage_images: u32]
+        [value max_per_stage_descriptor_update_after_bind_input_attachments: u32]
+        [value max_per_stage_update_after_bind_resources: u32]
+        [value max_descriptor_set_update_after_bind_samplers: u32]
+        [value max_descriptor_set_update_after_bind_uniform_buffers: u32]
+        [value max_descriptor_set_update_after_bind_uniform_buffers_dynamic: u32]
+        [value max_descriptor_set_update_after_bind_storage_buffers: u32]
+        [value max_descriptor_set_update_after_bind_storage_buffers_dynamic: u32]
+        [value max_descriptor_set_update_after_bind_sampled_images: u32]
+        [value max_descriptor_set_update_after_bind_storage_images: u32]
+        [value max_descriptor_set_update_after_bind_input_attachments: u32]
+        [value supported_depth_resolve_modes: crate::data::ResolveModeFlags]
+        [value supported_stencil_resolve_modes: crate::data::ResolveModeFlags]
+        [value independent_resolve_none: crate::data::Bool32]
+        [value independent_resolve: crate::data::Bool32]
+        [value filter_minmax_single_component_formats: crate::data::Bool32]
+        [value filter_minmax_image_component_mapping: crate::data::Bool32]
+        [value max_timeline_semaphore_value_difference: u64]
+        [value framebuffer_integer_color_sample_counts: crate::data::SampleCountFlags]
+    }
+    PhysicalDeviceVulkan13Features => PhysicalDeviceVulkan13FeaturesBuilder {
+        [value robust_image_access: crate::data::Bool32]
+        [value inline_uniform_block: crate::data::Bool32]
+        [value descriptor_binding_inline_uniform_block_update_after_bind: crate::data::Bool32]
+        [value pipeline_creation_cache_control: crate::data::Bool32]
+        [value private_data: crate::data::Bool32]
+        [value shader_demote_to_helper_invocation: crate::data::Bool32]
+        [value shader_terminate_invocation: crate::data::Bool32]
+        [value subgroup_size_control: crate::data::Bool32]
+        [value compute_full_subgroups: crate::data::Bool32]
+        [value synchronization_2: crate::data::Bool32]
+        [value texture_compression_astc_hdr: crate::data::Bool32]
+        [value shader_zero_initialize_workgroup_memory: crate::data::Bool32]
+        [value dynamic_rendering: crate::data::Bool32]
+        [value shader_integer_dot_product: crate::data::Bool32]
+        [value maintenance_4: crate::data::Bool32]
+    }
+    PhysicalDeviceVulkan13Properties => PhysicalDeviceVulkan13PropertiesBuilder {
+        [value min_subgroup_size: u32]
+        [value max_subgroup_size: u32]
+        [value max_compute_workgroup_subgroups: u32]
+        [value required_subgroup_size_stages: crate::data::ShaderStageFlags]
+        [value max_inline_uniform_block_size: u32]
+        [value max_per_stage_descriptor_inline_uniform_blocks: u32]
+        [value max_per_stage_descriptor_update_after_bind_inline_uniform_blocks: u32]
+        [value max_descriptor_set_inline_uniform_blocks: u32]
+        [value max_descriptor_set_update_after_bind_inline_uniform_blocks: u32]
+        [value max_inline_uniform_total_size: u32]
+        [value integer_dot_product_8_bit_unsigned_accelerated: crate::data::Bool32]
+        [value integer_dot_product_8_bit_signed_accelerated: crate::data::Bool32]
+        [value integer_dot_product_8_bit_mixed_signedness_accelerated: crate::data::Bool32]
+        [value integer_dot_product_4x_8_bit_packed_unsigned_accelerated: crate::data::Bool32]
+        [value integer_dot_product_4x_8_bit_packed_signed_accelerated: crate::data::Bool32]
+        [value integer_dot_product_4x_8_bit_packed_mixed_signedness_accelerated: crate::data::Bool32]
+        [value integer_dot_product_16_bit_unsigned_accelerated: crate::data::Bool32]
+        [value integer_dot_product_16_bit_signed_accelerated: crate::data::Bool32]
+        [value integer_dot_product_16_bit_mixed_signedness_accelerated: crate::data::Bool32]
+        [value integer_dot_product_32_bit_unsigned_accelerated: crate::data::Bool32]
+        [value integer_dot_product_32_bit_signed_accelerated: crate::data::Bool32]
+        [value integer_dot_product_32_bit_mixed_signedness_accelerated: crate::data::Bool32]
+        [value integer_dot_product_64_bit_unsigned_accelerated: crate::data::Bool32]
+        [value integer_dot_product_64_bit_signed_accelerated: crate::data::Bool32]
+        [value integer_dot_product_64_bit_mixed_signedness_accelerated: crate::data::Bool32]
+        [value integer_dot_product_accumulating_saturating_8_bit_unsigned_accelerated: crate::data::Bool32]
+        [value integer_dot_product_accumulating_saturating_8_bit_signed_accelerated: crate::data::Bool32]
+        [value integer_dot_product_accumulating_saturating_8_bit_mixed_signedness_accelerated: crate::data::Bool32]
+        [value integer_dot_product_accumulating_saturating_4x_8_bit_packed_unsigned_accelerated: crate::data::Bool32]
+        [value integer_dot_product_accumulating_saturating_4x_8_bit_packed_signed_accelerated: crate::data::Bool32]
+        [value integer_dot_product_accumulating_saturating_4x_8_bit_packed_mixed_signedness_accelerated: crate::data::Bool32]
+        [value integer_dot_product_accumulating_saturating_16_bit_unsigned_accelerated: crate::data::Bool32]
+        [value integer_dot_product_accumulating_saturating_16_bit_signed_accelerated: crate::data::Bool32]
+        [value integer_dot_product_accumulating_saturating_16_bit_mixed_signedness_accelerated: crate::data::Bool32]
+        [value integer_dot_product_accumulating_saturating_32_bit_unsigned_accelerated: crate::data::Bool32]
+        [value integer_dot_product_accumulating_saturating_32_bit_signed_accelerated: crate::data::Bool32]
+        [value integer_dot_product_accumulating_saturating_32_bit_mixed_signedness_accelerated: crate::data::Bool32]
+        [value integer_dot_product_accumulating_saturating_64_bit_unsigned_accelerated: crate::data::Bool32]
+        [value integer_dot_product_accumulating_saturating_64_bit_signed_accelerated: crate::data::Bool32]
+        [value integer_dot_product_accumulating_saturating_64_bit_mixed_signedness_accelerated: crate::data::Bool32]
+        [value storage_texel_buffer_offset_alignment_bytes: crate::data::DeviceSize]
+        [value storage_texel_buffer_offset_single_texel_alignment: crate::data::Bool32]
+        [value uniform_texel_buffer_offset_alignment_bytes: crate::data::DeviceSize]
+        [value uniform_texel_buffer_offset_single_texel_alignment: crate::data::Bool32]
+        [value max_buffer_size: crate::data::DeviceSize]
+    }
+    PipelineCompilerControlCreateInfoAMD => PipelineCompilerControlCreateInfoAMDBuilder {
+        [value compiler_control_flags: crate::data::PipelineCompilerControlFlagsAMD]
+    }
+    PhysicalDeviceCoherentMemoryFeaturesAMD => PhysicalDeviceCoherentMemoryFeaturesAMDBuilder {
+        [value device_coherent_memory: crate::data::Bool32]
+    }
+    PhysicalDeviceToolProperties => PhysicalDeviceToolPropertiesBuilder {
+        [value name: [c_char; 256]]
+        [value version: [c_char; 256]]
+        [value purposes: crate::data::ToolPurposeFlags]
+        [value description: [c_char; 256]]
+        [value layer: [c_char; 256]]
+    }
+    SamplerCustomBorderColorCreateInfoEXT => SamplerCustomBorderColorCreateInfoEXTBuilder {
+        [value custom_border_color: crate::data::ClearColorValue]
+        [value format: crate::data::Format]
+    }
+    PhysicalDeviceCustomBorderColorPropertiesEXT => PhysicalDeviceCustomBorderColorPropertiesEXTBuilder {
+        [value max_custom_border_color_samplers: u32]
+    }
+    PhysicalDeviceCustomBorderColorFeaturesEXT => PhysicalDeviceCustomBorderColorFeaturesEXTBuilder {
+        [value custom_border_colors: crate::data::Bool32]
+        [value custom_border_color_without_format: crate::data::Bool32]
+    }
+    SamplerBorderColorComponentMappingCreateInfoEXT => SamplerBorderColorComponentMappingCreateInfoEXTBuilder {
+        [value components: crate::data::ComponentMapping]
+        [value srgb: crate::data::Bool32]
+    }
+    PhysicalDeviceBorderColorSwizzleFeaturesEXT => PhysicalDeviceBorderColorSwizzleFeaturesEXTBuilder {
+        [value border_color_swizzle: crate::data::Bool32]
+        [value border_color_swizzle_from_image: crate::data::Bool32]
+    }
+    AccelerationStructureGeometryTrianglesDataKHR => AccelerationStructureGeometryTrianglesDataKHRBuilder {
+        [next]
+        [value vertex_format: crate::data::Format]
+        [value vertex_data: crate::data::DeviceOrHostAddressConstKHR]
+        [value vertex_stride: crate::data::DeviceSize]
+        [value max_vertex: u32]
+        [value index_type: crate::data::IndexType]
+        [value index_data: crate::data::DeviceOrHostAddressConstKHR]
+        [value transform_data: crate::data::DeviceOrHostAddressConstKHR]
+    }
+    AccelerationStructureGeometryAabbsDataKHR => AccelerationStructureGeometryAabbsDataKHRBuilder {
+        [value data: crate::data::DeviceOrHostAddressConstKHR]
+        [value stride: crate::data::DeviceSize]
+    }
+    AccelerationStructureGeometryInstancesDataKHR => AccelerationStructureGeometryInstancesDataKHRBuilder {
+        [value array_of_pointers: crate::data::Bool32]
+        [value data: crate::data::DeviceOrHostAddressConstKHR]
+    }
+    AccelerationStructureGeometryKHR => AccelerationStructureGeometryKHRBuilder {
+        [value geometry_type: crate::data::GeometryTypeKHR]
+        [value geometry: crate::data::AccelerationStructureGeometryDataKHR]
+        [value flags: crate::data::GeometryFlagsKHR]
+    }
+    AccelerationStructureBuildGeometryInfoKHR => AccelerationStructureBuildGeometryInfoKHRBuilder (shared) {
+        [value ty: crate::data::AccelerationStructureTypeKHR]
+        [value flags: crate::data::BuildAccelerationStructureFlagsKHR]
+        [value mode: crate::data::BuildAccelerationStructureModeKHR]
+        [value src_acceleration_structure: crate::data::AccelerationStructureKHR]
+        [value dst_acceleration_structure: crate::data::AccelerationStructureKHR]
+        [slice geometries = p_geometries, geometry_count shared(0x1, 0x2): crate::data::AccelerationStructureGeometryKHR]
+        [slice pp_geometries = pp_geometries, geometry_count shared(0x2, 0x1): *const crate::data::AccelerationStructureGeometryKHR]
+        [value scratch_data: crate::data::DeviceOrHostAddressKHR]
+    }
+    AccelerationStructureCreateInfoKHR => AccelerationStructureCreateInfoKHRBuilder {
+        [next]
+        [value create_flags: crate::data::AccelerationStructureCreateFlagsKHR]
+        [value buffer: crate::data::Buffer]
+        [value offset: crate::data::DeviceSize]
+        [value size: crate::data::DeviceSize]
+        [value ty: crate::data::AccelerationStructureTypeKHR]
+        [value device_address: crate::data::DeviceAddress]
+    }
+    AccelerationStructureDeviceAddressInfoKHR => AccelerationStructureDeviceAddressInfoKHRBuilder {
+        [value acceleration_structure: crate::data::AccelerationStructureKHR]
+    }
+    AccelerationStructureVersionInfoKHR => AccelerationStructureVersionInfoKHRBuilder {
+        [value p_version_data: *const u8]
+    }
+    CopyAccelerationStructureInfoKHR => CopyAccelerationStructureInfoKHRBuilder {
+        [value src: crate::data::AccelerationStructureKHR]
+        [value dst: crate::data::AccelerationStructureKHR]
+        [value mode: crate::data::CopyAccelerationStructureModeKHR]
+    }
+    CopyAccelerationStructureToMemoryInfoKHR => CopyAccelerationStructureToMemoryInfoKHRBuilder {
+        [value src: crate::data::AccelerationStructureKHR]
+        [value dst: crate::data::DeviceOrHostAddressKHR]
+        [value mode: crate::data::CopyAccelerationStructureModeKHR]
+    }
+    CopyMemoryToAccelerationStructureInfoKHR => CopyMemoryToAccelerationStructureInfoKHRBuilder {
+        [value src: crate::data::DeviceOrHostAddressConstKHR]
+        [value dst: crate::data::AccelerationStructureKHR]
+        [value mode: crate::data::CopyAccelerationStructureModeKHR]
+    }
+    RayTracingPipelineInterfaceCreateInfoKHR => RayTracingPipelineInterfaceCreateInfoKHRBuilder {
+        [value max_pipeline_ray_payload_size: u32]
+        [value max_pipeline_ray_hit_attribute_size: u32]
+    }
+    PipelineLibraryCreateInfoKHR => PipelineLibraryCreateInfoKHRBuilder {
+        [slice libraries = p_libraries, library_count: crate::data::Pipeline]
+    }
+    PhysicalDeviceExtendedDynamicStateFeaturesEXT => PhysicalDeviceExtendedDynamicStateFeaturesEXTBuilder {
+        [value extended_dynamic_state: crate::data::Bool32]
+    }
+    PhysicalDeviceExtendedDynamicState2FeaturesEXT => PhysicalDeviceExtendedDynamicState2FeaturesEXTBuilder {
+        [value extended_dynamic_state_2: crate::data::Bool32]
+        [value extended_dynamic_state_2_logic_op: crate::data::Bool32]
+        [value extended_dynamic_state_2_patch_control_points: crate::data::Bool32]
+    }
+    PhysicalDeviceExtendedDynamicState3FeaturesEXT => PhysicalDeviceExtendedDynamicState3FeaturesEXTBuilder {
+        [value extended_dynamic_state_3_tessellation_domain_origin: crate::data::Bool32]
+        [value extended_dynamic_state_3_depth_clamp_enable: crate::data::Bool32]
+        [value extended_dynamic_state_3_polygon_mode: crate::data::Bool32]
+        [value extended_dynamic_state_3_rasterization_samples: crate::data::Bool32]
+        [value extended_dynamic_state_3_sample_mask: crate::data::Bool32]
+        [value extended_dynamic_state_3_alpha_to_coverage_enable: crate::data::Bool32]
+        [value extended_dynamic_state_3_alpha_to_one_enable: crate::data::Bool32]
+        [value extended_dynamic_state_3_logic_op_enable: crate::data::Bool32]
+        [value extended_dynamic_state_3_color_blend_enable: crate::data::Bool32]
+        [value extended_dynamic_state_3_color_blend_equation: crate::data::Bool32]
+        [value extended_dynamic_state_3_color_write_mask: crate::data::Bool32]
+        [value extended_dynamic_state_3_rasterization_stream: crate::data::Bool32]
+        [value extended_dynamic_state_3_conservative_rasterization_mode: crate::data::Bool32]
+        [value extended_dynamic_state_3_extra_primitive_overestimation_size: crate::data::Bool32]
+        [value extended_dynamic_state_3_depth_clip_enable: crate::data::Bool32]
+        [value extended_dynamic_state_3_sample_locations_enable: crate::data::Bool32]
+        [value extended_dynamic_state_3_color_blend_advanced: crate::data::Bool32]
+        [value extended_dynamic_state_3_provoking_vertex_mode: crate::data::Bool32]
+        [value extended_dynamic_state_3_line_rasterization_mode: crate::data::Bool32]
+        [value extended_dynamic_state_3_line_stipple_enable: crate::data::Bool32]
+        [value extended_dynamic_state_3_depth_clip_negative_one_to_one: crate::data::Bool32]
+        [value extended_dynamic_state_3_viewport_w_scaling_enable: crate::data::Bool32]
+        [value extended_dynamic_state_3_viewport_swizzle: crate::data::Bool32]
+        [value extended_dynamic_state_3_coverage_to_color_enable: crate::data::Bool32]
+        [value extended_dynamic_state_3_coverage_to_color_location: crate::data::Bool32]
+        [value extended_dynamic_state_3_coverage_modulation_mode: crate::data::Bool32]
+        [value extended_dynamic_state_3_coverage_modulation_table_enable: crate::data::Bool32]
+        [value extended_dynamic_state_3_coverage_modulation_table: crate::data::Bool32]
+        [value extended_dynamic_state_3_coverage_reduction_mode: crate::data::Bool32]
+        [value extended_dynamic_state_3_representative_fragment_test_enable: crate::data::Bool32]
+        [value extended_dynamic_state_3_shading_rate_image_enable: crate::data::Bool32]
+    }
+    PhysicalDeviceExtendedDynamicState3PropertiesEXT => PhysicalDeviceExtendedDynamicState3PropertiesEXTBuilder {
+        [value dynamic_primitive_topology_unrestricted: crate::data::Bool32]
+    }
+    RenderPassTransformBeginInfoQCOM => RenderPassTransformBeginInfoQCOMBuilder {
+        [value transform: crate::data::SurfaceTransformFlagBitsKHR]
+    }
+    CopyCommandTransformInfoQCOM => CopyCommandTransformInfoQCOMBuilder {
+        [value transform: crate::data::SurfaceTransformFlagBitsKHR]
+    }
+    CommandBufferInheritanceRenderPassTransformInfoQCOM => CommandBufferInheritanceRenderPassTransformInfoQCOMBuilder {
+        [value transform: crate::data::SurfaceTransformFlagBitsKHR]
+        [value render_area: crate::data::Rect2D]
+    }
+    PhysicalDeviceDiagnosticsConfigFeaturesNV => PhysicalDeviceDiagnosticsConfigFeaturesNVBuilder {
+        [value diagnostics_config: crate::data::Bool32]
+    }
+    DeviceDiagnosticsConfigCreateInfoNV => DeviceDiagnosticsConfigCreateInfoNVBuilder {
+        [value flags: crate::data::DeviceDiagnosticsConfigFlagsNV]
+    }
+    PhysicalDeviceZeroInitializeWorkgroupMemoryFeatures => PhysicalDeviceZeroInitializeWorkgroupMemoryFeaturesBuilder {
+        [value shader_zero_initialize_workgroup_memory: crate::data::Bool32]
+    }
+    PhysicalDeviceShaderSubgroupUniformControlFlowFeaturesKHR => PhysicalDeviceShaderSubgroupUniformControlFlowFeaturesKHRBuilder {
+        [value shader_subgroup_uniform_control_flow: crate::data::Bool32]
+    }
+    PhysicalDeviceRobustness2FeaturesEXT => PhysicalDeviceRobustness2FeaturesEXTBuilder {
+        [value robust_buffer_access_2: crate::data::Bool32]
+        [value robust_image_access_2: crate::data::Bool32]
+        [value null_descriptor: crate::data::Bool32]
+    }
+    PhysicalDeviceRobustness2PropertiesEXT => PhysicalDeviceRobustness2PropertiesEXTBuilder {
+        [value robust_storage_buffer_access_size_alignment: crate::data::DeviceSize]
+        [value robust_uniform_buffer_access_size_alignment: crate::data::DeviceSize]
+    }
+    PhysicalDeviceImageRobustnessFeatures => PhysicalDeviceImageRobustnessFeaturesBuilder {
+        [value robust_image_access: crate::data::Bool32]
+    }
+    PhysicalDeviceWorkgroupMemoryExplicitLayoutFeaturesKHR => PhysicalDeviceWorkgroupMemoryExplicitLayoutFeaturesKHRBuilder {
+        [value workgroup_memory_explicit_layout: crate::data::Bool32]
+        [value workgroup_memory_explicit_layout_scalar_block_layout: crate::data::Bool32]
+        [value workgroup_memory_explicit_layout_8_bit_access: crate::data::Bool32]
+        [value workgroup_memory_explicit_layout_16_bit_access: crate::data::Bool32]
+    }
+    PhysicalDevicePortabilitySubsetFeaturesKHR => PhysicalDevicePortabilitySubsetFeaturesKHRBuilder {
+        [value constant_alpha_color_blend_factors: crate::data::Bool32]
+        [value events: crate::data::Bool32]
+        [value image_view_format_reinterpretation: crate::data::Bool32]
+        [value image_view_format_swizzle: crate::data::Bool32]
+        [value image_view_2d_on_3d_image: crate::data::Bool32]
+        [value multisample_array_image: crate::data::Bool32]
+        [value mutable_comparison_samplers: crate::data::Bool32]
+        [value point_polygons: crate::data::Bool32]
+        [value sampler_mip_lod_bias: crate::data::Bool32]
+        [value separate_stencil_mask_ref: crate::data::Bool32]
+        [value shader_sample_rate_interpolation_functions: crate::data::Bool32]
+        [value tessellation_isolines: crate::data::Bool32]
+        [value tessellation_point_mode: crate::data::Bool32]
+        [value triangle_fans: crate::data::Bool32]
+        [value vertex_attribute_access_beyond_stride: crate::data::Bool32]
+    }
+    PhysicalDevicePortabilitySubsetPropertiesKHR => PhysicalDevicePortabilitySubsetPropertiesKHRBuilder {
+        [value min_vertex_input_binding_stride_alignment: u32]
+    }
+    PhysicalDevice4444FormatsFeaturesEXT => PhysicalDevice4444FormatsFeaturesEXTBuilder {
+        [value format_a_4r_4g_4b_4: crate::data::Bool32]
+        [value format_a_4b_4g_4r_4: crate::data::Bool32]
+    }
+    PhysicalDeviceSubpassShadingFeaturesHUAWEI => PhysicalDeviceSubpassShadingFeaturesHUAWEIBuilder {
+        [value subpass_shading: crate::data::Bool32]
+    }
+    BufferCopy2 => BufferCopy2Builder {
+        [value src_offset: crate::data::DeviceSize]
+        [value dst_offset: crate::data::DeviceSize]
+        [value size: crate::data::DeviceSize]
+    }
+    ImageCopy2 => ImageCopy2Builder {
+        [value src_subresource: crate::data::ImageSubresourceLayers]
+        [value src_offset: crate::data::Offset3D]
+        [value dst_subresource: crate::data::ImageSubresourceLayers]
+        [value dst_offset: crate::data::Offset3D]
+        [value extent: crate::data::Extent3D]
+    }
+    ImageBlit2 => ImageBlit2Builder {
+        [next]
+        [value src_subresource: crate::data::ImageSubresourceLayers]
+        [value src_offsets: [crate::data::Offset3D; 2]]
+        [value dst_subresource: crate::data::ImageSubresourceLayers]
+        [value dst_offsets: [crate::data::Offset3D; 2]]
+    }
+    BufferImageCopy2 => BufferImageCopy2Builder {
+        [next]
+        [value buffer_offset: crate::data::DeviceSize]
+        [value buffer_row_length: u32]
+        [value buffer_image_height: u32]
+        [value image_subresource: crate::data::ImageSubresourceLayers]
+        [value image_offset: crate::data::Offset3D]
+        [value image_extent: crate::data::Extent3D]
+    }
+    ImageResolve2 => ImageResolve2Builder {
+        [value src_subresource: crate::data::ImageSubresourceLayers]
+        [value src_offset: crate::data::Offset3D]
+        [value dst_subresource: crate::data::ImageSubresourceLayers]
+        [value dst_offset: crate::data::Offset3D]
+        [value extent: crate::data::Extent3D]
+    }
+    CopyBufferInfo2 => CopyBufferInfo2Builder {
+        [value src_buffer: crate::data::Buffer]
+        [value dst_buffer: crate::data::Buffer]
+        [slice regions = p_regions, region_count: crate::data::BufferCopy2]
+    }
+    CopyImageInfo2 => CopyImageInfo2Builder {
+        [value src_image: crate::data::Image]
+        [value src_image_layout: crate::data::ImageLayout]
+        [value dst_image: crate::data::Image]
+        [value dst_image_layout: crate::data::ImageLayout]
+        [slice regions = p_regions, region_count: crate::data::ImageCopy2]
+    }
+    BlitImageInfo2 => BlitImageInfo2Builder {
+        [value src_image: crate::data::Image]
+        [value src_image_layout: crate::data::ImageLayout]
+        [value dst_image: crate::data::Image]
+        [value dst_image_layout: crate::data::ImageLayout]
+        [slice regions = p_regions, region_count: crate::data::ImageBlit2]
+        [value filter: crate::data::Filter]
+    }
+    CopyBufferToImageInfo2 => CopyBufferToImageInfo2Builder {
+        [value src_buffer: crate::data::Buffer]
+        [value dst_image: crate::data::Image]
+        [value dst_image_layout: crate::data::ImageLayout]
+        [slice regions = p_regions, region_count: crate::data::BufferImageCopy2]
+    }
+    CopyImageToBufferInfo2 => CopyImageToBufferInfo2Builder {
+        [value src_image: crate::data::Image]
+        [value src_image_layout: crate::data::ImageLayout]
+        [value dst_buffer: crate::data::Buffer]
+        [slice regions = p_regions, region_count: crate::data::BufferImageCopy2]
+    }
+    ResolveImageInfo2 => ResolveImageInfo2Builder {
+        [value src_image: crate::data::Image]
+        [value src_image_layout: crate::data::ImageLayout]
+        [value dst_image: crate::data::Image]
+        [value dst_image_layout: crate::data::ImageLayout]
+        [slice regions = p_regions, region_count: crate::data::ImageResolve2]
+    }
+    PhysicalDeviceShaderImageAtomicInt64FeaturesEXT => PhysicalDeviceShaderImageAtomicInt64FeaturesEXTBuilder {
+        [value shader_image_int_64_atomics: crate::data::Bool32]
+        [value sparse_image_int_64_atomics: crate::data::Bool32]
+    }
+    FragmentShadingRateAttachmentInfoKHR => FragmentShadingRateAttachmentInfoKHRBuilder {
+        [ptr fragment_shading_rate_attachment = p_fragment_shading_rate_attachment: crate::data::AttachmentReference2]
+        [value shading_rate_attachment_texel_size: crate::data::Extent2D]
+    }
+    PipelineFragmentShadingRateStateCreateInfoKHR => PipelineFragmentShadingRateStateCreateInfoKHRBuilder {
+        [value fragment_size: crate::data::Extent2D]
+        [value combiner_ops: [crate::data::FragmentShadingRateCombinerOpKHR; 2]]
+    }
+    PhysicalDeviceFragmentShadingRateFeaturesKHR => PhysicalDeviceFragmentShadingRateFeaturesKHRBuilder {
+        [value pipeline_fragment_shading_rate: crate::data::Bool32]
+        [value primitive_fragment_shading_rate: crate::data::Bool32]
+        [value attachment_fragment_shading_rate: crate::data::Bool32]
+    }
+    PhysicalDeviceFragmentShadingRatePropertiesKHR => PhysicalDeviceFragmentShadingRatePropertiesKHRBuilder {
+        [value min_fragment_shading_rate_attachment_texel_size: crate::data::Extent2D]
+        [value max_fragment_shading_rate_attachment_texel_size: crate::data::Extent2D]
+        [value max_fragment_shading_rate_attachment_texel_size_aspect_ratio: u32]
+        [value primitive_fragment_shading_rate_with_multiple_viewports: crate::data::Bool32]
+        [value layered_shading_rate_attachments: crate::data::Bool32]
+        [value fragment_shading_rate_non_trivial_combiner_ops: crate::data::Bool32]
+        [value max_fragment_size: crate::data::Extent2D]
+        [value max_fragment_size_aspect_ratio: u32]
+        [value max_fragment_shading_rate_coverage_samples: u32]
+        [value max_fragment_shading_rate_rasterization_samples: crate::data::SampleCountFlagBits]
+        [value fragment_shading_rate_with_shader_depth_stencil_writes: crate::data::Bool32]
+        [value fragment_shading_rate_with_sample_mask: crate::data::Bool32]
+        [value fragment_shading_rate_with_shader_sample_mask: crate::data::Bool32]
+        [value fragment_shading_rate_with_conservative_rasterization: crate::data::Bool32]
+        [value fragment_shading_rate_with_fragment_shader_interlock: crate::data::Bool32]
+        [value fragment_shading_rate_with_custom_sample_locations: crate::data::Bool32]
+        [value fragment_shading_rate_strict_multiply_combiner: crate::data::Bool32]
+    }
+    PhysicalDeviceFragmentShadingRateKHR => PhysicalDeviceFragmentShadingRateKHRBuilder {
+        [value sample_counts: crate::data::SampleCountFlags]
+        [value fragment_size: crate::data::Extent2D]
+    }
+    PhysicalDeviceShaderTerminateInvocationFeatures => PhysicalDeviceShaderTerminateInvocationFeaturesBuilder {
+        [value shader_terminate_invocation: crate::data::Bool32]
+    }
+    PhysicalDeviceFragmentShadingRateEnumsFeaturesNV => PhysicalDeviceFragmentShadingRateEnumsFeaturesNVBuilder {
+        [value fragment_shading_rate_enums: crate::data::Bool32]
+        [value supersample_fragment_shading_rates: crate::data::Bool32]
+        [value no_invocation_fragment_shading_rates: crate::data::Bool32]
+    }
+    PhysicalDeviceFragmentShadingRateEnumsPropertiesNV => PhysicalDeviceFragmentShadingRateEnumsPropertiesNVBuilder {
+        [value max_fragment_shading_rate_invocation_count: crate::data::SampleCountFlagBits]
+    }
+    PipelineFragmentShadingRateEnumStateCreateInfoNV => PipelineFragmentShadingRateEnumStateCreateInfoNVBuilder {
+        [value shading_rate_type: crate::data::FragmentShadingRateTypeNV]
+        [value shading_rate: crate::data::FragmentShadingRateNV]
+        [value combiner_ops: [crate::data::FragmentShadingRateCombinerOpKHR; 2]]
+    }
+    AccelerationStructureBuildSizesInfoKHR => AccelerationStructureBuildSizesInfoKHRBuilder {
+        [value acceleration_structure_size: crate::data::DeviceSize]
+        [value update_scratch_size: crate::data::DeviceSize]
+        [value build_scratch_size: crate::data::DeviceSize]
+    }
+    PhysicalDeviceImage2DViewOf3DFeaturesEXT => PhysicalDeviceImage2DViewOf3DFeaturesEXTBuilder {
+        [value image_2d_view_of_3d: crate::data::Bool32]
+        [value sampler_2d_view_of_3d: crate::data::Bool32]
+    }
+    PhysicalDeviceMutableDescriptorTypeFeaturesEXT => PhysicalDeviceMutableDescriptorTypeFeaturesEXTBuilder {
+        [value mutable_descriptor_type: crate::data::Bool32]
+    }
+    MutableDescriptorTypeListEXT => MutableDescriptorTypeListEXTBuilder {
+        [slice descriptor_types = p_descriptor_types, descriptor_type_count: crate::data::DescriptorType]
+    }
+    MutableDescriptorTypeCreateInfoEXT => MutableDescriptorTypeCreateInfoEXTBuilder {
+        [slice mutable_descriptor_type_lists = p_mutable_descriptor_type_lists, mutable_descriptor_type_list_count: crate::data::MutableDescriptorTypeListEXT]
+    }
+    PhysicalDeviceDepthClipControlFeaturesEXT => PhysicalDeviceDepthClipControlFeaturesEXTBuilder {
+        [value depth_clip_control: crate::data::Bool32]
+    }
+    PipelineViewportDepthClipControlCreateInfoEXT => PipelineViewportDepthClipControlCreateInfoEXTBuilder {
+        [value negative_one_to_one: crate::data::Bool32]
+    }
+    PhysicalDeviceVertexInputDynamicStateFeaturesEXT => PhysicalDeviceVertexInputDynamicStateFeaturesEXTBuilder {
+        [value vertex_input_dynamic_state: crate::data::Bool32]
+    }
+    PhysicalDeviceExternalMemoryRDMAFeaturesNV => PhysicalDeviceExternalMemoryRDMAFeaturesNVBuilder {
+        [value external_memory_rdma: crate::data::Bool32]
+    }
+    VertexInputBindingDescription2EXT => VertexInputBindingDescription2EXTBuilder {
+        [value binding: u32]
+        [value stride: u32]
+        [value input_rate: crate::data::VertexInputRate]
+        [value divisor: u32]
+    }
+    VertexInputAttributeDescription2EXT => VertexInputAttributeDescription2EXTBuilder {
+        [value location: u32]
+        [value binding: u32]
+        [value format: crate::data::Format]
+        [value offset: u32]
+    }
+    PhysicalDeviceColorWriteEnableFeaturesEXT => PhysicalDeviceColorWriteEnableFeaturesEXTBuilder {
+        [value color_write_enable: crate::data::Bool32]
+    }
+    PipelineColorWriteCreateInfoEXT => PipelineColorWriteCreateInfoEXTBuilder {
+        [slice color_write_enables = p_color_write_enables, attachment_count: crate::data::Bool32]
+    }
+    MemoryBarrier2 => MemoryBarrier2Builder {
+        [value src_stage_mask: crate::data::PipelineStageFlags2]
+        [value src_access_mask: crate::data::AccessFlags2]
+        [value dst_stage_mask: crate::data::PipelineStageFlags2]
+        [value dst_access_mask: crate::data::AccessFlags2]
+    }
+    ImageMemoryBarrier2 => ImageMemoryBarrier2Builder {
+        [next]
+        [value src_stage_mask: crate::data::PipelineStageFlags2]
+        [value src_access_mask: crate::data::AccessFlags2]
+        [value dst_stage_mask: crate::data::PipelineStageFlags2]
+        [value dst_access_mask: crate::data::AccessFlags2]
+        [value old_layout: crate::data::ImageLayout]
+        [value new_layout: crate::data::ImageLayout]
+        [value src_queue_family_index: u32]
+        [value dst_queue_family_index: u32]
+        [value image: crate::data::Image]
+        [value subresource_range: crate::data::ImageSubresourceRange]
+    }
+    BufferMemoryBarrier2 => BufferMemoryBarrier2Builder {
+        [value src_stage_mask: crate::data::PipelineStageFlags2]
+        [value src_access_mask: crate::data::AccessFlags2]
+        [value dst_stage_mask: crate::data::PipelineStageFlags2]
+        [value dst_access_mask: crate::data::AccessFlags2]
+        [value src_queue_family_index: u32]
+        [value dst_queue_family_index: u32]
+        [value buffer: crate::data::Buffer]
+        [value offset: crate::data::DeviceSize]
+        [value size: crate::data::DeviceSize]
+    }
+    DependencyInfo => DependencyInfoBuilder {
+        [value dependency_flags: crate::data::DependencyFlags]
+        [slice memory_barriers = p_memory_barriers, memory_barrier_count: crate::data::MemoryBarrier2]
+        [slice buffer_memory_barriers = p_buffer_memory_barriers, buffer_memory_barrier_count: crate::data::BufferMemoryBarrier2]
+        [slice image_memory_barriers = p_image_memory_barriers, image_memory_barrier_count: crate::data::ImageMemoryBarrier2]
+    }
+    SemaphoreSubmitInfo => SemaphoreSubmitInfoBuilder {
+        [value semaphore: crate::data::Semaphore]
+        [value value: u64]
+        [value stage_mask: crate::data::PipelineStageFlags2]
+        [value device_index: u32]
+    }
+    CommandBufferSubmitInfo => CommandBufferSubmitInfoBuilder {
+        [value command_buffer: crate::data::CommandBuffer]
+        [value device_mask: u32]
+    }
+    SubmitInfo2 => SubmitInfo2Builder {
+        [next]
+        [value flags: crate::data::SubmitFlags]
+        [slice wait_semaphore_infos = p_wait_semaphore_infos, wait_semaphore_info_count: crate::data::SemaphoreSubmitInfo]
+        [slice command_buffer_infos = p_command_buffer_infos, command_buffer_info_count: crate::data::CommandBufferSubmitInfo]
+        [slice signal_semaphore_infos = p_signal_semaphore_infos, signal_semaphore_info_count: crate::data::SemaphoreSubmitInfo]
+    }
+    QueueFamilyCheckpointProperties2NV => QueueFamilyCheckpointProperties2NVBuilder {
+        [value checkpoint_execution_stage_mask: crate::data::PipelineStageFlags2]
+    }
+    CheckpointData2NV => CheckpointData2NVBuilder {
+        [value stage: crate::data::PipelineStageFlags2]
+        [value p_checkpoint_marker: *mut c_void]
+    }
+    PhysicalDeviceSynchronization2Features => PhysicalDeviceSynchronization2FeaturesBuilder {
+        [value synchronization_2: crate::data::Bool32]
+    }
+    PhysicalDevicePrimitivesGeneratedQueryFeaturesEXT => PhysicalDevicePrimitivesGeneratedQueryFeaturesEXTBuilder {
+        [value primitives_generated_query: crate::data::Bool32]
+        [value primitives_generated_query_with_rasterizer_discard: crate::data::Bool32]
+        [value primitives_generated_query_with_non_zero_streams: crate::data::Bool32]
+    }
+    PhysicalDeviceLegacyDitheringFeaturesEXT => PhysicalDeviceLegacyDitheringFeaturesEXTBuilder {
+        [value legacy_dithering: crate::data::Bool32]
+    }
+    PhysicalDeviceMultisampledRenderToSingleSampledFeaturesEXT => PhysicalDeviceMultisampledRenderToSingleSampledFeaturesEXTBuilder {
+        [value multisampled_render_to_single_sampled: crate::data::Bool32]
+    }
+    SubpassResolvePerformanceQueryEXT => SubpassResolvePerformanceQueryEXTBuilder {
+        [value optimal: crate::data::Bool32]
+    }
+    MultisampledRenderToSingleSampledInfoEXT => MultisampledRenderToSingleSampledInfoEXTBuilder {
+        [value multisampled_render_to_single_sampled_enable: crate::data::Bool32]
+        [value rasterization_samples: crate::data::SampleCountFlagBits]
+    }
+    PhysicalDevicePipelineProtectedAccessFeaturesEXT => PhysicalDevicePipelineProtectedAccessFeaturesEXTBuilder {
+        [value pipeline_protected_access: crate::data::Bool32]
+    }
+    QueueFamilyVideoPropertiesKHR => QueueFamilyVideoPropertiesKHRBuilder {
+        [value video_codec_operations: crate::data::VideoCodecOperationFlagsKHR]
+    }
+    QueueFamilyQueryResultStatusPropertiesKHR => QueueFamilyQueryResultStatusPropertiesKHRBuilder {
+        [value query_result_status_support: crate::data::Bool32]
+    }
+    VideoProfileListInfoKHR => VideoProfileListInfoKHRBuilder {
+        [slice profiles = p_profiles, profile_count: crate::data::VideoProfileInfoKHR]
+    }
+    PhysicalDeviceVideoFormatInfoKHR => PhysicalDeviceVideoFormatInfoKHRBuilder {
+        [next]
+        [value image_usage: crate::data::ImageUsageFlags]
+    }
+    VideoFormatPropertiesKHR => VideoFormatPropertiesKHRBuilder {
+        [value format: crate::data::Format]
+        [value component_mapping: crate::data::ComponentMapping]
+        [value image_create_flags: crate::data::ImageCreateFlags]
+        [value image_type: crate::data::ImageType]
+        [value image_tiling: crate::data::ImageTiling]
+        [value image_usage_flags: crate::data::ImageUsageFlags]
+    }
+    VideoProfileInfoKHR => VideoProfileInfoKHRBuilder {
+        [next]
+        [value video_codec_operation: crate::data::VideoCodecOperationFlagBitsKHR]
+        [value chroma_subsampling: crate::data::VideoChromaSubsamplingFlagsKHR]
+        [value luma_bit_depth: crate::data::VideoComponentBitDepthFlagsKHR]
+        [value chroma_bit_depth: crate::data::VideoComponentBitDepthFlagsKHR]
+    }
+    VideoCapabilitiesKHR => VideoCapabilitiesKHRBuilder {
+        [next]
+        [value flags: crate::data::VideoCapabilityFlagsKHR]
+        [value min_bitstream_buffer_offset_alignment: crate::data::DeviceSize]
+        [value min_bitstream_buffer_size_alignment: crate::data::DeviceSize]
+        [value picture_access_granularity: crate::data::Extent2D]
+        [value min_coded_extent: crate::data::Extent2D]
+        [value max_coded_extent: crate::data::Extent2D]
+        [value max_dpb_slots: u32]
+        [value max_active_reference_pictures: u32]
+        [value std_header_version: crate::data::ExtensionProperties]
+    }
+    VideoSessionMemoryRequirementsKHR => VideoSessionMemoryRequirementsKHRBuilder {
+        [value memory_bind_index: u32]
+        [value memory_requirements: crate::data::MemoryRequirements]
+    }
+    BindVideoSessionMemoryInfoKHR => BindVideoSessionMemoryInfoKHRBuilder {
+        [value memory_bind_index: u32]
+        [value memory: crate::data::DeviceMemory]
+        [value memory_offset: crate::data::DeviceSize]
+        [value memory_size: crate::data::DeviceSize]
+    }
+    VideoPictureResourceInfoKHR => VideoPictureResourceInfoKHRBuilder {
+        [value coded_offset: crate::data::Offset2D]
+        [value coded_extent: crate::data::Extent2D]
+        [value base_array_layer: u32]
+        [value image_view_binding: crate::data::ImageView]
+    }
+    VideoReferenceSlotInfoKHR => VideoReferenceSlotInfoKHRBuilder {
+        [value slot_index: i32]
+        [ptr picture_resource = p_picture_resource: crate::data::VideoPictureResourceInfoKHR]
+    }
+    VideoDecodeCapabilitiesKHR => VideoDecodeCapabilitiesKHRBuilder {
+        [value flags: crate::data::VideoDecodeCapabilityFlagsKHR]
+    }
+    VideoDecodeUsageInfoKHR => VideoDecodeUsageInfoKHRBuilder {
+        [value video_usage_hints: crate::data::VideoDecodeUsageFlagsKHR]
+    }
+    VideoDecodeInfoKHR => VideoDecodeInfoKHRBuilder {
+        [value flags: crate::data::VideoDecodeFlagsKHR]
+        [value src_buffer: crate::data::Buffer]
+        [value src_buffer_offset: crate::data::DeviceSize]
+        [value src_buffer_range: crate::data::DeviceSize]
+        [value dst_picture_resource: crate::data::VideoPictureResourceInfoKHR]
+        [ptr setup_reference_slot = p_setup_reference_slot: crate::data::VideoReferenceSlotInfoKHR]
+        [slice reference_slots = p_reference_slots, reference_slot_count: crate::data::VideoReferenceSlotInfoKHR]
+    }
+    VideoSessionCreateInfoKHR => VideoSessionCreateInfoKHRBuilder {
+        [value queue_family_index: u32]
+        [value flags: crate::data::VideoSessionCreateFlagsKHR]
+        [ptr video_profile = p_video_profile: crate::data::VideoProfileInfoKHR]
+        [value picture_format: crate::data::Format]
+        [value max_coded_extent: crate::data::Extent2D]
+        [value reference_picture_format: crate::data::Format]
+        [value max_dpb_slots: u32]
+        [value max_active_reference_pictures: u32]
+        [ptr std_header_version = p_std_header_version: crate::data::ExtensionProperties]
+    }
+    VideoSessionParametersCreateInfoKHR => VideoSessionParametersCreateInfoKHRBuilder {
+        [value flags: crate::data::VideoSessionParametersCreateFlagsKHR]
+        [value video_session_parameters_template: crate::data::VideoSessionParametersKHR]
+        [value video_session: crate::data::VideoSessionKHR]
+    }
+    VideoSessionParametersUpdateInfoKHR => VideoSessionParametersUpdateInfoKHRBuilder {
+        [value update_sequence_count: u32]
+    }
+    VideoBeginCodingInfoKHR => VideoBeginCodingInfoKHRBuilder {
+        [value flags: crate::data::VideoBeginCodingFlagsKHR]
+        [value video_session: crate::data::VideoSessionKHR]
+        [value video_session_parameters: crate::data::VideoSessionParametersKHR]
+        [slice reference_slots = p_reference_slots, reference_slot_count: crate::data::VideoReferenceSlotInfoKHR]
+    }
+    VideoEndCodingInfoKHR => VideoEndCodingInfoKHRBuilder {
+        [value flags: crate::data::VideoEndCodingFlagsKHR]
+    }
+    VideoCodingControlInfoKHR => VideoCodingControlInfoKHRBuilder {
+        [next]
+        [value flags: crate::data::VideoCodingControlFlagsKHR]
+    }
+    VideoEncodeUsageInfoKHR => VideoEncodeUsageInfoKHRBuilder {
+        [value video_usage_hints: crate::data::VideoEncodeUsageFlagsKHR]
+        [value video_content_hints: crate::data::VideoEncodeContentFlagsKHR]
+        [value tuning_mode: crate::data::VideoEncodeTuningModeKHR]
+    }
+    VideoEncodeInfoKHR => VideoEncodeInfoKHRBuilder {
+        [next]
+        [value flags: crate::data::VideoEncodeFlagsKHR]
+        [value quality_level: u32]
+        [value dst_bitstream_buffer: crate::data::Buffer]
+        [value dst_bitstream_buffer_offset: crate::data::DeviceSize]
+        [value dst_bitstream_buffer_max_range: crate::data::DeviceSize]
+        [value src_picture_resource: crate::data::VideoPictureResourceInfoKHR]
+        [ptr setup_reference_slot = p_setup_reference_slot: crate::data::VideoReferenceSlotInfoKHR]
+        [slice reference_slots = p_reference_slots, reference_slot_count: crate::data::VideoReferenceSlotInfoKHR]
+        [value preceding_externally_encoded_bytes: u32]
+    }
+    VideoEncodeRateControlInfoKHR => VideoEncodeRateControlInfoKHRBuilder {
+        [value flags: crate::data::VideoEncodeRateControlFlagsKHR]
+        [value rate_control_mode: crate::data::VideoEncodeRateControlModeFlagBitsKHR]
+        [value layer_count: u8]
+        [ptr layer_configs = p_layer_configs: crate::data::VideoEncodeRateControlLayerInfoKHR]
+    }
+    VideoEncodeRateControlLayerInfoKHR => VideoEncodeRateControlLayerInfoKHRBuilder {
+        [next]
+        [value average_bitrate: u32]
+        [value max_bitrate: u32]
+        [value frame_rate_numerator: u32]
+        [value frame_rate_denominator: u32]
+        [value virtual_buffer_size_in_ms: u32]
+        [value initial_virtual_buffer_size_in_ms: u32]
+    }
+    VideoEncodeCapabilitiesKHR => VideoEncodeCapabilitiesKHRBuilder {
+        [value flags: crate::data::VideoEncodeCapabilityFlagsKHR]
+        [value rate_control_modes: crate::data::VideoEncodeRateControlModeFlagsKHR]
+        [value rate_control_layer_count: u8]
+        [value quality_level_count: u8]
+        [value input_image_data_fill_alignment: crate::data::Extent2D]
+    }
+    VideoEncodeH264CapabilitiesEXT => VideoEncodeH264CapabilitiesEXTBuilder {
+        [value flags: crate::data::VideoEncodeH264CapabilityFlagsEXT]
+        [value input_mode_flags: crate::data::VideoEncodeH264InputModeFlagsEXT]
+        [value output_mode_flags: crate::data::VideoEncodeH264OutputModeFlagsEXT]
+        [value max_p_picture_l_0_reference_count: u8]
+        [value max_b_picture_l_0_reference_count: u8]
+        [value max_l_1_reference_count: u8]
+        [value motion_vectors_over_pic_boundaries_flag: crate::data::Bool32]
+        [value max_bytes_per_pic_denom: u32]
+        [value max_bits_per_mb_denom: u32]
+        [value log_2_max_mv_length_horizontal: u32]
+        [value log_2_max_mv_length_vertical: u32]
+    }
+    VideoEncodeH264EmitPictureParametersInfoEXT => VideoEncodeH264EmitPictureParametersInfoEXTBuilder {
+        [value sps_id: u8]
+        [value emit_sps_enable: crate::data::Bool32]
+        [slice pps_id_entries = pps_id_entries, pps_id_entry_count: u8]
+    }
+    VideoEncodeH264RateControlInfoEXT => VideoEncodeH264RateControlInfoEXTBuilder {
+        [value gop_frame_count: u32]
+        [value idr_period: u32]
+        [value consecutive_b_frame_count: u32]
+        [value rate_control_structure: crate::data::VideoEncodeH264RateControlStructureEXT]
+        [value temporal_layer_count: u8]
+    }
+    VideoEncodeH264RateControlLayerInfoEXT => VideoEncodeH264RateControlLayerInfoEXTBuilder {
+        [value temporal_layer_id: u8]
+        [value use_initial_rc_qp: crate::data::Bool32]
+        [value initial_rc_qp: crate::data::VideoEncodeH264QpEXT]
+        [value use_min_qp: crate::data::Bool32]
+        [value min_qp: crate::data::VideoEncodeH264QpEXT]
+        [value use_max_qp: crate::data::Bool32]
+        [value max_qp: crate::data::VideoEncodeH264QpEXT]
+        [value use_max_frame_size: crate::data::Bool32]
+        [value max_frame_size: crate::data::VideoEncodeH264FrameSizeEXT]
+    }
+    VideoEncodeH265CapabilitiesEXT => VideoEncodeH265CapabilitiesEXTBuilder {
+        [value flags: crate::data::VideoEncodeH265CapabilityFlagsEXT]
+        [value input_mode_flags: crate::data::VideoEncodeH265InputModeFlagsEXT]
+        [value output_mode_flags: crate::data::VideoEncodeH265OutputModeFlagsEXT]
+        [value ctb_sizes: crate::data::VideoEncodeH265CtbSizeFlagsEXT]
+        [value transform_block_sizes: crate::data::VideoEncodeH265TransformBlockSizeFlagsEXT]
+        [value max_p_picture_l_0_reference_count: u8]
+        [value max_b_picture_l_0_reference_count: u8]
+        [value max_l_1_reference_count: u8]
+        [value max_sub_layers_count: u8]
+        [value min_log_2_min_luma_coding_block_size_minus_3: u8]
+        [value max_log_2_min_luma_coding_block_size_minus_3: u8]
+        [value min_log_2_min_luma_transform_block_size_minus_2: u8]
+        [value max_log_2_min_luma_transform_block_size_minus_2: u8]
+        [value min_max_transform_hierarchy_depth_inter: u8]
+        [value max_max_transform_hierarchy_depth_inter: u8]
+        [value min_max_transform_hierarchy_depth_intra: u8]
+        [value max_max_transform_hierarchy_depth_intra: u8]
+        [value max_diff_cu_qp_delta_depth: u8]
+        [value min_max_num_merge_cand: u8]
+        [value max_max_num_merge_cand: u8]
+    }
+    VideoEncodeH265EmitPictureParametersInfoEXT => VideoEncodeH265EmitPictureParametersInfoEXTBuilder {
+        [value vps_id: u8]
+        [value sps_id: u8]
+        [value emit_vps_enable: crate::data::Bool32]
+        [value emit_sps_enable: crate::data::Bool32]
+        [slice pps_id_entries = pps_id_entries, pps_id_entry_count: u8]
+    }
+    VideoEncodeH265RateControlInfoEXT => VideoEncodeH265RateControlInfoEXTBuilder {
+        [value gop_frame_count: u32]
+        [value idr_period: u32]
+        [value consecutive_b_frame_count: u32]
+        [value rate_control_structure: crate::data::VideoEncodeH265RateControlStructureEXT]
+        [value sub_layer_count: u8]
+    }
+    VideoEncodeH265RateControlLayerInfoEXT => VideoEncodeH265RateControlLayerInfoEXTBuilder {
+        [value temporal_id: u8]
+        [value use_initial_rc_qp: crate::data::Bool32]
+        [value initial_rc_qp: crate::data::VideoEncodeH265QpEXT]
+        [value use_min_qp: crate::data::Bool32]
+        [value min_qp: crate::data::VideoEncodeH265QpEXT]
+        [value use_max_qp: crate::data::Bool32]
+        [value max_qp: crate::data::VideoEncodeH265QpEXT]
+        [value use_max_frame_size: crate::data::Bool32]
+        [value max_frame_size: crate::data::VideoEncodeH265FrameSizeEXT]
+    }
+    PhysicalDeviceInheritedViewportScissorFeaturesNV => PhysicalDeviceInheritedViewportScissorFeaturesNVBuilder {
+        [value inherited_viewport_scissor_2d: crate::data::Bool32]
+    }
+    CommandBufferInheritanceViewportScissorInfoNV => CommandBufferInheritanceViewportScissorInfoNVBuilder {
+        [value viewport_scissor_2d: crate::data::Bool32]
+        [slice viewport_depths = p_viewport_depths, viewport_depth_count: crate::data::Viewport]
+    }
+    PhysicalDeviceYcbcr2Plane444FormatsFeaturesEXT => PhysicalDeviceYcbcr2Plane444FormatsFeaturesEXTBuilder {
+        [value ycbcr_2plane_444_formats: crate::data::Bool32]
+    }
+    PhysicalDeviceProvokingVertexFeaturesEXT => PhysicalDeviceProvokingVertexFeaturesEXTBuilder {
+        [value provoking_vertex_last: crate::data::Bool32]
+        [value transform_feedback_preserves_provoking_vertex: crate::data::Bool32]
+    }
+    PhysicalDeviceProvokingVertexPropertiesEXT => PhysicalDeviceProvokingVertexPropertiesEXTBuilder {
+        [value provoking_vertex_mode_per_pipeline: crate::data::Bool32]
+        [value transform_feedback_preserves_triangle_fan_provoking_vertex: crate::data::Bool32]
+    }
+    PipelineRasterizationProvokingVertexStateCreateInfoEXT => PipelineRasterizationProvokingVertexStateCreateInfoEXTBuilder {
+        [value provoking_vertex_mode: crate::data::ProvokingVertexModeEXT]
+    }
+    CuModuleCreateInfoNVX => CuModuleCreateInfoNVXBuilder {
+        [slice data = p_data, data_size: u8]
+    }
+    CuFunctionCreateInfoNVX => CuFunctionCreateInfoNVXBuilder {
+        [value module: crate::data::CuModuleNVX]
+        [str name = p_name]
+    }
+    CuLaunchInfoNVX => CuLaunchInfoNVXBuilder {
+        [value function: crate::data::CuFunctionNVX]
+        [value grid_dim_x: u32]
+        [value grid_dim_y: u32]
+        [value grid_dim_z: u32]
+        [value block_dim_x: u32]
+        [value block_dim_y: u32]
+        [value block_dim_z: u32]
+        [value shared_mem_bytes: u32]
+        [slice params = p_params, param_count: *const c_void]
+        [slice extras = p_extras, extra_count: *const c_void]
+    }
+    PhysicalDeviceShaderIntegerDotProductFeatures => PhysicalDeviceShaderIntegerDotProductFeaturesBuilder {
+        [value shader_integer_dot_product: crate::data::Bool32]
+    }
+    PhysicalDeviceShaderIntegerDotProductProperties => PhysicalDeviceShaderIntegerDotProductPropertiesBuilder {
+        [value integer_dot_product_8_bit_unsigned_accelerated: crate::data::Bool32]
+        [value integer_dot_product_8_bit_signed_accelerated: crate::data::Bool32]
+        [value integer_dot_product_8_bit_mixed_signedness_accelerated: crate::data::Bool32]
+        [value integer_dot_product_4x_8_bit_packed_unsigned_accelerated: crate::data::Bool32]
+        [value integer_dot_product_4x_8_bit_packed_signed_accelerated: crate::data::Bool32]
+        [value integer_dot_product_4x_8_bit_packed_mixed_signedness_accelerated: crate::data::Bool32]
+        [value integer_dot_product_16_bit_unsigned_accelerated: crate::data::Bool32]
+        [value integer_dot_product_16_bit_signed_accelerated: crate::data::Bool32]
+        [value integer_dot_product_16_bit_mixed_signedness_accelerated: crate::data::Bool32]
+        [value integer_dot_product_32_bit_unsigned_accelerated: crate::data::Bool32]
+        [value integer_dot_product_32_bit_signed_accelerated: crate::data::Bool32]
+        [value integer_dot_product_32_bit_mixed_signedness_accelerated: crate::data::Bool32]
+        [value integer_dot_product_64_bit_unsigned_accelerated: crate::data::Bool32]
+        [value integer_dot_product_64_bit_signed_accelerated: crate::data::Bool32]
+        [value integer_dot_product_64_bit_mixed_signedness_accelerated: crate::data::Bool32]
+        [value integer_dot_product_accumulating_saturating_8_bit_unsigned_accelerated: crate::data::Bool32]
+        [value integer_dot_product_accumulating_saturating_8_bit_signed_accelerated: crate::data::Bool32]
+        [value integer_dot_product_accumulating_saturating_8_bit_mixed_signedness_accelerated: crate::data::Bool32]
+        [value integer_dot_product_accumulating_saturating_4x_8_bit_packed_unsigned_accelerated: crate::data::Bool32]
+        [value integer_dot_product_accumulating_saturating_4x_8_bit_packed_signed_accelerated: crate::data::Bool32]
+        [value integer_dot_product_accumulating_saturating_4x_8_bit_packed_mixed_signedness_accelerated: crate::data::Bool32]
+        [value integer_dot_product_accumulating_saturating_16_bit_unsigned_accelerated: crate::data::Bool32]
+        [value integer_dot_product_accumulating_saturating_16_bit_signed_accelerated: crate::data::Bool32]
+        [value integer_dot_product_accumulating_saturating_16_bit_mixed_signedness_accelerated: crate::data::Bool32]
+        [value integer_dot_product_accumulating_saturating_32_bit_unsigned_accelerated: crate::data::Bool32]
+        [value integer_dot_product_accumulating_saturating_32_bit_signed_accelerated: crate::data::Bool32]
+        [value integer_dot_product_accumulating_saturating_32_bit_mixed_signedness_accelerated: crate::data::Bool32]
+        [value integer_dot_product_accumulating_saturating_64_bit_unsigned_accelerated: crate::data::Bool32]
+        [value integer_dot_product_accumulating_saturating_64_bit_signed_accelerated: crate::data::Bool32]
+        [value integer_dot_product_accumulating_saturating_64_bit_mixed_signedness_accelerated: crate::data::Bool32]
+    }
+    PhysicalDeviceDrmPropertiesEXT => PhysicalDeviceDrmPropertiesEXTBuilder {
+        [value has_primary: crate::data::Bool32]
+        [value has_render: crate::data::Bool32]
+        [value primary_major: i64]
+        [value primary_minor: i64]
+        [value render_major: i64]
+        [value render_minor: i64]
+    }
+    PhysicalDeviceFragmentShaderBarycentricFeaturesKHR => PhysicalDeviceFragmentShaderBarycentricFeaturesKHRBuilder {
+        [value fragment_shader_barycentric: crate::data::Bool32]
+    }
+    PhysicalDeviceFragmentShaderBarycentricPropertiesKHR => PhysicalDeviceFragmentShaderBarycentricPropertiesKHRBuilder {
+        [value tri_strip_vertex_order_independent_of_provoking_vertex: crate::data::Bool32]
+    }
+    PhysicalDeviceRayTracingMotionBlurFeaturesNV => PhysicalDeviceRayTracingMotionBlurFeaturesNVBuilder {
+        [value ray_tracing_motion_blur: crate::data::Bool32]
+        [value ray_tracing_motion_blur_pipeline_trace_rays_indirect: crate::data::Bool32]
+    }
+    AccelerationStructureGeometryMotionTrianglesDataNV => AccelerationStructureGeometryMotionTrianglesDataNVBuilder {
+        [value vertex_data: crate::data::DeviceOrHostAddressConstKHR]
+    }
+    AccelerationStructureMotionInfoNV => AccelerationStructureMotionInfoNVBuilder {
+        [value max_instances: u32]
+        [value flags: crate::data::AccelerationStructureMotionInfoFlagsNV]
+    }
+    MemoryGetRemoteAddressInfoNV => MemoryGetRemoteAddressInfoNVBuilder {
+        [value memory: crate::data::DeviceMemory]
+        [value handle_type: crate::data::ExternalMemoryHandleTypeFlagBits]
+    }
+    ImportMemoryBufferCollectionFUCHSIA => ImportMemoryBufferCollectionFUCHSIABuilder {
+        [value collection: crate::data::BufferCollectionFUCHSIA]
+        [value index: u32]
+    }
+    BufferCollectionImageCreateInfoFUCHSIA => BufferCollectionImageCreateInfoFUCHSIABuilder {
+        [value collection: crate::data::BufferCollectionFUCHSIA]
+        [value index: u32]
+    }
+    BufferCollectionBufferCreateInfoFUCHSIA => BufferCollectionBufferCreateInfoFUCHSIABuilder {
+        [value collection: crate::data::BufferCollectionFUCHSIA]
+        [value index: u32]
+    }
+    BufferCollectionCreateInfoFUCHSIA => BufferCollectionCreateInfoFUCHSIABuilder {
+        [value collection_token: zx_handle_t]
+    }
+    BufferCollectionPropertiesFUCHSIA => BufferCollectionPropertiesFUCHSIABuilder {
+        [value memory_type_bits: u32]
+        [value buffer_count: u32]
+        [value create_info_index: u32]
+        [value sysmem_pixel_format: u64]
+        [value format_features: crate::data::FormatFeatureFlags]
+        [value sysmem_color_space_index: crate::data::SysmemColorSpaceFUCHSIA]
+        [value sampler_ycbcr_conversion_components: crate::data::ComponentMapping]
+        [value suggested_ycbcr_model: crate::data::SamplerYcbcrModelConversion]
+        [value suggested_ycbcr_range: crate::data::SamplerYcbcrRange]
+        [value suggested_x_chroma_offset: crate::data::ChromaLocation]
+        [value suggested_y_chroma_offset: crate::data::ChromaLocation]
+    }
+    BufferConstraintsInfoFUCHSIA => BufferConstraintsInfoFUCHSIABuilder {
+        [value create_info: crate::data::BufferCreateInfo]
+        [value required_format_features: crate::data::FormatFeatureFlags]
+        [value buffer_collection_constraints: crate::data::BufferCollectionConstraintsInfoFUCHSIA]
+    }
+    SysmemColorSpaceFUCHSIA => SysmemColorSpaceFUCHSIABuilder {
+        [value color_space: u32]
+    }
+    ImageFormatConstraintsInfoFUCHSIA => ImageFormatConstraintsInfoFUCHSIABuilder {
+        [value image_create_info: crate::data::ImageCreateInfo]
+        [value required_format_features: crate::data::FormatFeatureFlags]
+        [value flags: crate::data::ImageFormatConstraintsFlagsFUCHSIA]
+        [value sysmem_pixel_format: u64]
+        [slice color_spaces = p_color_spaces, color_space_count: crate::data::SysmemColorSpaceFUCHSIA]
+    }
+    ImageConstraintsInfoFUCHSIA => ImageConstraintsInfoFUCHSIABuilder {
+        [slice format_constraints = p_format_constraints, format_constraints_count: crate::data::ImageFormatConstraintsInfoFUCHSIA]
+        [value buffer_collection_constraints: crate::data::BufferCollectionConstraintsInfoFUCHSIA]
+        [value flags: crate::data::ImageConstraintsInfoFlagsFUCHSIA]
+    }
+    BufferCollectionConstraintsInfoFUCHSIA => BufferCollectionConstraintsInfoFUCHSIABuilder {
+        [value min_buffer_count: u32]
+        [value max_buffer_count: u32]
+        [value min_buffer_count_for_camping: u32]
+        [value min_buffer_count_for_dedicated_slack: u32]
+        [value min_buffer_count_for_shared_slack: u32]
+    }
+    PhysicalDeviceRGBA10X6FormatsFeaturesEXT => PhysicalDeviceRGBA10X6FormatsFeaturesEXTBuilder {
+        [value format_rgba_10x_6_without_y_cb_cr_sampler: crate::data::Bool32]
+    }
+    FormatProperties3 => FormatProperties3Builder {
+        [value linear_tiling_features: crate::data::FormatFeatureFlags2]
+        [value optimal_tiling_features: crate::data::FormatFeatureFlags2]
+        [value buffer_features: crate::data::FormatFeatureFlags2]
+    }
+    DrmFormatModifierPropertiesList2EXT => DrmFormatModifierPropertiesList2EXTBuilder {
+        [slice_mut drm_format_modifier_properties = p_drm_format_modifier_properties, drm_format_modifier_count: crate::data::DrmFormatModifierProperties2EXT]
+    }
+    AndroidHardwareBufferFormatProperties2ANDROID => AndroidHardwareBufferFormatProperties2ANDROIDBuilder {
+        [value format: crate::data::Format]
+        [value external_format: u64]
+        [value format_features: crate::data::FormatFeatureFlags2]
+        [value sampler_ycbcr_conversion_components: crate::data::ComponentMapping]
+        [value suggested_ycbcr_model: crate::data::SamplerYcbcrModelConversion]
+        [value suggested_ycbcr_range: crate::data::SamplerYcbcrRange]
+        [value suggested_x_chroma_offset: crate::data::ChromaLocation]
+        [value suggested_y_chroma_offset: crate::data::ChromaLocation]
+    }
+    PipelineRenderingCreateInfo => PipelineRenderingCreateInfoBuilder {
+        [value view_mask: u32]
+        [slice color_attachment_formats = p_color_attachment_formats, color_attachment_count: crate::data::Format]
+        [value depth_attachment_format: crate::data::Format]
+        [value stencil_attachment_format: crate::data::Format]
+    }
+    RenderingInfo => RenderingInfoBuilder {
+        [next]
+        [value flags: crate::data::RenderingFlags]
+        [value render_area: crate::data::Rect2D]
+        [value layer_count: u32]
+        [value view_mask: u32]
+        [slice color_attachments = p_color_attachments, color_attachment_count: crate::data::RenderingAttachmentInfo]
+        [ptr depth_attachment = p_depth_attachment: crate::data::RenderingAttachmentInfo]
+        [ptr stencil_attachment = p_stencil_attachment: crate::data::RenderingAttachmentInfo]
+    }
+    RenderingAttachmentInfo => RenderingAttachmentInfoBuilder {
+        [value image_view: crate::data::ImageView]
+        [value image_layout: crate::data::ImageLayout]
+        [value resolve_mode: crate::data::ResolveModeFlagBits]
+        [value resolve_image_view: crate::data::ImageView]
+        [value resolve_image_layout: crate::data::ImageLayout]
+        [value load_op: crate::data::AttachmentLoadOp]
+        [value store_op: crate::data::AttachmentStoreOp]
+        [value clear_value: crate::data::ClearValue]
+    }
+    RenderingFragmentShadingRateAttachmentInfoKHR => RenderingFragmentShadingRateAttachmentInfoKHRBuilder {
+        [value image_view: crate::data::ImageView]
+        [value image_layout: crate::data::ImageLayout]
+        [value shading_rate_attachment_texel_size: crate::data::Extent2D]
+    }
+    RenderingFragmentDensityMapAttachmentInfoEXT => RenderingFragmentDensityMapAttachmentInfoEXTBuilder {
+        [value image_view: crate::data::ImageView]
+        [value image_layout: crate::data::ImageLayout]
+    }
+    PhysicalDeviceDynamicRenderingFeatures => PhysicalDeviceDynamicRenderingFeaturesBuilder {
+        [value dynamic_rendering: crate::data::Bool32]
+    }
+    CommandBufferInheritanceRenderingInfo => CommandBufferInheritanceRenderingInfoBuilder {
+        [value flags: crate::data::RenderingFlags]
+        [value view_mask: u32]
+        [slice color_attachment_formats = p_color_attachment_formats, color_attachment_count: crate::data::Format]
+        [value depth_attachment_format: crate::data::Format]
+        [value stencil_attachment_format: crate::data::Format]
+        [value rasterization_samples: crate::data::SampleCountFlagBits]
+    }
+    AttachmentSampleCountInfoAMD => AttachmentSampleCountInfoAMDBuilder {
+        [slice color_attachment_samples = p_color_attachment_samples, color_attachment_count: crate::data::SampleCountFlagBits]
+        [value depth_stencil_attachment_samples: crate::data::SampleCountFlagBits]
+    }
+    MultiviewPerViewAttributesInfoNVX => MultiviewPerViewAttributesInfoNVXBuilder {
+        [value per_view_attributes: crate::data::Bool32]
+        [value per_view_attributes_position_x_only: crate::data::Bool32]
+    }
+    PhysicalDeviceImageViewMinLodFeaturesEXT => PhysicalDeviceImageViewMinLodFeaturesEXTBuilder {
+        [value min_lod: crate::data::Bool32]
+    }
+    ImageViewMinLodCreateInfoEXT => ImageViewMinLodCreateInfoEXTBuilder {
+        [value min_lod: c_float]
+    }
+    PhysicalDeviceRasterizationOrderAttachmentAccessFeaturesEXT => PhysicalDeviceRasterizationOrderAttachmentAccessFeaturesEXTBuilder {
+        [value rasterization_order_color_attachment_access: crate::data::Bool32]
+        [value rasterization_order_depth_attachment_access: crate::data::Bool32]
+        [value rasterization_order_stencil_attachment_access: crate::data::Bool32]
+    }
+    PhysicalDeviceLinearColorAttachmentFeaturesNV => PhysicalDeviceLinearColorAttachmentFeaturesNVBuilder {
+        [value linear_color_attachment: crate::data::Bool32]
+    }
+    PhysicalDeviceGraphicsPipelineLibraryFeaturesEXT => PhysicalDeviceGraphicsPipelineLibraryFeaturesEXTBuilder {
+        [value graphics_pipeline_library: crate::data::Bool32]
+    }
+    PhysicalDeviceGraphicsPipelineLibraryPropertiesEXT => PhysicalDeviceGraphicsPipelineLibraryPropertiesEXTBuilder {
+        [value graphics_pipeline_library_fast_linking: crate::data::Bool32]
+        [value graphics_pipeline_library_independent_interpolation_decoration: crate::data::Bool32]
+    }
+    GraphicsPipelineLibraryCreateInfoEXT => GraphicsPipelineLibraryCreateInfoEXTBuilder {
+        [value flags: crate::data::GraphicsPipelineLibraryFlagsEXT]
+    }
+    PhysicalDeviceDescriptorSetHostMappingFeaturesVALVE => PhysicalDeviceDescriptorSetHostMappingFeaturesVALVEBuilder {
+        [value descriptor_set_host_mapping: crate::data::Bool32]
+    }
+    DescriptorSetBindingReferenceVALVE => DescriptorSetBindingReferenceVALVEBuilder {
+        [value descriptor_set_layout: crate::data::DescriptorSetLayout]
+        [value binding: u32]
+    }
+    DescriptorSetLayoutHostMappingInfoVALVE => DescriptorSetLayoutHostMappingInfoVALVEBuilder {
+        [value descriptor_offset: usize]
+        [value descriptor_size: u32]
+    }
+    PhysicalDeviceShaderModuleIdentifierFeaturesEXT => PhysicalDeviceShaderModuleIdentifierFeaturesEXTBuilder {
+        [value shader_module_identifier: crate::data::Bool32]
+    }
+    PhysicalDeviceShaderModuleIdentifierPropertiesEXT => PhysicalDeviceShaderModuleIdentifierPropertiesEXTBuilder {
+        [value shader_module_identifier_algorithm_uuid: [u8; 16]]
+    }
+    PipelineShaderStageModuleIdentifierCreateInfoEXT => PipelineShaderStageModuleIdentifierCreateInfoEXTBuilder {
+        [slice identifier = p_identifier, identifier_size: u8]
+    }
+    ShaderModuleIdentifierEXT => ShaderModuleIdentifierEXTBuilder {
+        [value identifier_size: u32]
+        [value identifier: [u8; 32]]
+    }
+    ImageCompressionControlEXT => ImageCompressionControlEXTBuilder {
+        [value flags: crate::data::ImageCompressionFlagsEXT]
+        [slice_mut fixed_rate_flags = p_fixed_rate_flags, compression_control_plane_count: crate::data::ImageCompressionFixedRateFlagsEXT]
+    }
+    PhysicalDeviceImageCompressionControlFeaturesEXT => PhysicalDeviceImageCompressionControlFeaturesEXTBuilder {
+        [value image_compression_control: crate::data::Bool32]
+    }
+    ImageCompressionPropertiesEXT => ImageCompressionPropertiesEXTBuilder {
+        [value image_compression_flags: crate::data::ImageCompressionFlagsEXT]
+        [value image_compression_fixed_rate_flags: crate::data::ImageCompressionFixedRateFlagsEXT]
+    }
+    PhysicalDeviceImageCompressionControlSwapchainFeaturesEXT => PhysicalDeviceImageCompressionControlSwapchainFeaturesEXTBuilder {
+        [value image_compression_control_swapchain: crate::data::Bool32]
+    }
+    ImageSubresource2EXT => ImageSubresource2EXTBuilder {
+        [value image_subresource: crate::data::ImageSubresource]
+    }
+    SubresourceLayout2EXT => SubresourceLayout2EXTBuilder {
+        [next]
+        [value subresource_layout: crate::data::SubresourceLayout]
+    }
+    RenderPassCreationControlEXT => RenderPassCreationControlEXTBuilder {
+        [value disallow_merging: crate::data::Bool32]
+    }
+    RenderPassCreationFeedbackCreateInfoEXT => RenderPassCreationFeedbackCreateInfoEXTBuilder {
+        [ptr_mut render_pass_feedback = p_render_pass_feedback: crate::data::RenderPassCreationFeedbackInfoEXT]
+    }
+    RenderPassSubpassFeedbackCreateInfoEXT => RenderPassSubpassFeedbackCreateInfoEXTBuilder {
+        [ptr_mut subpass_feedback = p_subpass_feedback: crate::data::RenderPassSubpassFeedbackInfoEXT]
+    }
+    PhysicalDeviceSubpassMergeFeedbackFeaturesEXT => PhysicalDeviceSubpassMergeFeedbackFeaturesEXTBuilder {
+        [value subpass_merge_feedback: crate::data::Bool32]
+    }
+    MicromapBuildInfoEXT => MicromapBuildInfoEXTBuilder (shared) {
+        [value ty: crate::data::MicromapTypeEXT]
+        [value flags: crate::data::BuildMicromapFlagsEXT]
+        [value mode: crate::data::BuildMicromapModeEXT]
+        [value dst_micromap: crate::data::MicromapEXT]
+        [slice usage_counts = p_usage_counts, usage_counts_count shared(0x1, 0x2): crate::data::MicromapUsageEXT]
+        [slice pp_usage_counts = pp_usage_counts, usage_counts_count shared(0x2, 0x1): *const crate::data::MicromapUsageEXT]
+        [value data: crate::data::DeviceOrHostAddressConstKHR]
+        [value scratch_data: crate::data::DeviceOrHostAddressKHR]
+        [value triangle_array: crate::data::DeviceOrHostAddressConstKHR]
+        [value triangle_array_stride: crate::data::DeviceSize]
+    }
+    MicromapCreateInfoEXT => MicromapCreateInfoEXTBuilder {
+        [value create_flags: crate::data::MicromapCreateFlagsEXT]
+        [value buffer: crate::data::Buffer]
+        [value offset: crate::data::DeviceSize]
+        [value size: crate::data::DeviceSize]
+        [value ty: crate::data::MicromapTypeEXT]
+        [value device_address: crate::data::DeviceAddress]
+    }
+    MicromapVersionInfoEXT => MicromapVersionInfoEXTBuilder {
+        [value p_version_data: *const u8]
+    }
+    CopyMicromapInfoEXT => CopyMicromapInfoEXTBuilder {
+        [value src: crate::data::MicromapEXT]
+        [value dst: crate::data::MicromapEXT]
+        [value mode: crate::data::CopyMicromapModeEXT]
+    }
+    CopyMicromapToMemoryInfoEXT => CopyMicromapToMemoryInfoEXTBuilder {
+        [value src: crate::data::MicromapEXT]
+        [value dst: crate::data::DeviceOrHostAddressKHR]
+        [value mode: crate::data::CopyMicromapModeEXT]
+    }
+    CopyMemoryToMicromapInfoEXT => CopyMemoryToMicromapInfoEXTBuilder {
+        [value src: crate::data::DeviceOrHostAddressConstKHR]
+        [value dst: crate::data::MicromapEXT]
+        [value mode: crate::data::CopyMicromapModeEXT]
+    }
+    MicromapBuildSizesInfoEXT => MicromapBuildSizesInfoEXTBuilder {
+        [value micromap_size: crate::data::DeviceSize]
+        [value build_scratch_size: crate::data::DeviceSize]
+        [value discardable: crate::data::Bool32]
+    }
+    PhysicalDeviceOpacityMicromapFeaturesEXT => PhysicalDeviceOpacityMicromapFeaturesEXTBuilder {
+        [value micromap: crate::data::Bool32]
+        [value micromap_capture_replay: crate::data::Bool32]
+        [value micromap_host_commands: crate::data::Bool32]
+    }
+    PhysicalDeviceOpacityMicromapPropertiesEXT => PhysicalDeviceOpacityMicromapPropertiesEXTBuilder {
+        [value max_opacity_2_state_subdivision_level: u32]
+        [value max_opacity_4_state_subdivision_level: u32]
+    }
+    AccelerationStructureTrianglesOpacityMicromapEXT => AccelerationStructureTrianglesOpacityMicromapEXTBuilder (shared) {
+        [value index_type: crate::data::IndexType]
+        [value index_buffer: crate::data::DeviceOrHostAddressConstKHR]
+        [value index_stride: crate::data::DeviceSize]
+        [value base_triangle: u32]
+        [slice usage_counts = p_usage_counts, usage_counts_count shared(0x1, 0x2): crate::data::MicromapUsageEXT]
+        [slice pp_usage_counts = pp_usage_counts, usage_counts_count shared(0x2, 0x1): *const crate::data::MicromapUsageEXT]
+        [value micromap: crate::data::MicromapEXT]
+    }
+    PipelinePropertiesIdentifierEXT => PipelinePropertiesIdentifierEXTBuilder {
+        [value pipeline_identifier: [u8; 16]]
+    }
+    PhysicalDevicePipelinePropertiesFeaturesEXT => PhysicalDevicePipelinePropertiesFeaturesEXTBuilder {
+        [value pipeline_properties_identifier: crate::data::Bool32]
+    }
+    PhysicalDeviceShaderEarlyAndLateFragmentTestsFeaturesAMD => PhysicalDeviceShaderEarlyAndLateFragmentTestsFeaturesAMDBuilder {
+        [value shader_early_and_late_fragment_tests: crate::data::Bool32]
+    }
+    ExportMetalObjectCreateInfoEXT => ExportMetalObjectCreateInfoEXTBuilder {
+        [value export_object_type: crate::data::ExportMetalObjectTypeFlagBitsEXT]
+    }
+    ExportMetalObjectsInfoEXT => ExportMetalObjectsInfoEXTBuilder {
+        [next]
+    }
+    ExportMetalDeviceInfoEXT => ExportMetalDeviceInfoEXTBuilder {
+        [value mtl_device: MTLDevice_id]
+    }
+    ExportMetalCommandQueueInfoEXT => ExportMetalCommandQueueInfoEXTBuilder {
+        [value queue: crate::data::Queue]
+        [value mtl_command_queue: MTLCommandQueue_id]
+    }
+    ExportMetalBufferInfoEXT => ExportMetalBufferInfoEXTBuilder {
+        [value memory: crate::data::DeviceMemory]
+        [value mtl_buffer: MTLBuffer_id]
+    }
+    ImportMetalBufferInfoEXT => ImportMetalBufferInfoEXTBuilder {
+        [value mtl_buffer: MTLBuffer_id]
+    }
+    ExportMetalTextureInfoEXT => ExportMetalTextureInfoEXTBuilder {
+        [value image: crate::data::Image]
+        [value image_view: crate::data::ImageView]
+        [value buffer_view: crate::data::BufferView]
+        [value plane: crate::data::ImageAspectFlagBits]
+        [value mtl_texture: MTLTexture_id]
+    }
+    ImportMetalTextureInfoEXT => ImportMetalTextureInfoEXTBuilder {
+        [value plane: crate::data::ImageAspectFlagBits]
+        [value mtl_texture: MTLTexture_id]
+    }
+    ExportMetalIOSurfaceInfoEXT => ExportMetalIOSurfaceInfoEXTBuilder {
+        [value image: crate::data::Image]
+        [value io_surface: IOSurfaceRef]
+    }
+    ImportMetalIOSurfaceInfoEXT => ImportMetalIOSurfaceInfoEXTBuilder {
+        [value io_surface: IOSurfaceRef]
+    }
+    ExportMetalSharedEventInfoEXT => ExportMetalSharedEventInfoEXTBuilder {
+        [value semaphore: crate::data::Semaphore]
+        [value event: crate::data::Event]
+        [value mtl_shared_event: MTLSharedEvent_id]
+    }
+    ImportMetalSharedEventInfoEXT => ImportMetalSharedEventInfoEXTBuilder {
+        [value mtl_shared_event: MTLSharedEvent_id]
+    }
+    PhysicalDeviceNonSeamlessCubeMapFeaturesEXT => PhysicalDeviceNonSeamlessCubeMapFeaturesEXTBuilder {
+        [value non_seamless_cube_map: crate::data::Bool32]
+    }
+    PhysicalDevicePipelineRobustnessFeaturesEXT => PhysicalDevicePipelineRobustnessFeaturesEXTBuilder {
+        [value pipeline_robustness: crate::data::Bool32]
+    }
+    PipelineRobustnessCreateInfoEXT => PipelineRobustnessCreateInfoEXTBuilder {
+        [value storage_buffers: crate::data::PipelineRobustnessBufferBehaviorEXT]
+        [value uniform_buffers: crate::data::PipelineRobustnessBufferBehaviorEXT]
+        [value vertex_inputs: crate::data::PipelineRobustnessBufferBehaviorEXT]
+        [value images: crate::data::PipelineRobustnessImageBehaviorEXT]
+    }
+    PhysicalDevicePipelineRobustnessPropertiesEXT => PhysicalDevicePipelineRobustnessPropertiesEXTBuilder {
+        [value default_robustness_storage_buffers: crate::data::PipelineRobustnessBufferBehaviorEXT]
+        [value default_robustness_uniform_buffers: crate::data::PipelineRobustnessBufferBehaviorEXT]
+        [value default_robustness_vertex_inputs: crate::data::PipelineRobustnessBufferBehaviorEXT]
+        [value default_robustness_images: crate::data::PipelineRobustnessImageBehaviorEXT]
+    }
+    ImageViewSampleWeightCreateInfoQCOM => ImageViewSampleWeightCreateInfoQCOMBuilder {
+        [value filter_center: crate::data::Offset2D]
+        [value filter_size: crate::data::Extent2D]
+        [value num_phases: u32]
+    }
+    PhysicalDeviceImageProcessingFeaturesQCOM => PhysicalDeviceImageProcessingFeaturesQCOMBuilder {
+        [value texture_sample_weighted: crate::data::Bool32]
+        [value texture_box_filter: crate::data::Bool32]
+        [value texture_block_match: crate::data::Bool32]
+    }
+    PhysicalDeviceImageProcessingPropertiesQCOM => PhysicalDeviceImageProcessingPropertiesQCOMBuilder {
+        [value max_weight_filter_phases: u32]
+        [value max_weight_filter_dimension: crate::data::Extent2D]
+        [value max_block_match_region: crate::data::Extent2D]
+        [value max_box_filter_block_size: crate::data::Extent2D]
+    }
+    PhysicalDeviceTilePropertiesFeaturesQCOM => PhysicalDeviceTilePropertiesFeaturesQCOMBuilder {
+        [value tile_properties: crate::data::Bool32]
+    }
+    TilePropertiesQCOM => TilePropertiesQCOMBuilder {
+        [value tile_size: crate::data::Extent3D]
+        [value apron_size: crate::data::Extent2D]
+        [value origin: crate::data::Offset2D]
+    }
+    PhysicalDeviceAmigoProfilingFeaturesSEC => PhysicalDeviceAmigoProfilingFeaturesSECBuilder {
+        [value amigo_profiling: crate::data::Bool32]
+    }
+    AmigoProfilingSubmitInfoSEC => AmigoProfilingSubmitInfoSECBuilder {
+        [value first_draw_timestamp: u64]
+        [value swap_buffer_timestamp: u64]
+    }
+    PhysicalDeviceAttachmentFeedbackLoopLayoutFeaturesEXT => PhysicalDeviceAttachmentFeedbackLoopLayoutFeaturesEXTBuilder {
+        [value attachment_feedback_loop_layout: crate::data::Bool32]
+    }
+    PhysicalDeviceDepthClampZeroOneFeaturesEXT => PhysicalDeviceDepthClampZeroOneFeaturesEXTBuilder {
+        [value depth_clamp_zero_one: crate::data::Bool32]
+    }
+    PhysicalDeviceAddressBindingReportFeaturesEXT => PhysicalDeviceAddressBindingReportFeaturesEXTBuilder {
+        [value report_address_binding: crate::data::Bool32]
+    }
+    DeviceAddressBindingCallbackDataEXT => DeviceAddressBindingCallbackDataEXTBuilder {
+        [value flags: crate::data::DeviceAddressBindingFlagsEXT]
+        [value base_address: crate::data::DeviceAddress]
+        [value size: crate::data::DeviceSize]
+        [value binding_type: crate::data::DeviceAddressBindingTypeEXT]
+    }
+    PhysicalDeviceOpticalFlowFeaturesNV => PhysicalDeviceOpticalFlowFeaturesNVBuilder {
+        [value optical_flow: crate::data::Bool32]
+    }
+    PhysicalDeviceOpticalFlowPropertiesNV => PhysicalDeviceOpticalFlowPropertiesNVBuilder {
+        [value supported_output_grid_sizes: crate::data::OpticalFlowGridSizeFlagsNV]
+        [value supported_hint_grid_sizes: crate::data::OpticalFlowGridSizeFlagsNV]
+        [value hint_supported: crate::data::Bool32]
+        [value cost_supported: crate::data::Bool32]
+        [value bidirectional_flow_supported: crate::data::Bool32]
+        [value global_flow_supported: crate::data::Bool32]
+        [value min_width: u32]
+        [value min_height: u32]
+        [value max_width: u32]
+        [value max_height: u32]
+        [value max_num_regions_of_interest: u32]
+    }
+    OpticalFlowImageFormatInfoNV => OpticalFlowImageFormatInfoNVBuilder {
+        [value usage: crate::data::OpticalFlowUsageFlagsNV]
+    }
+    OpticalFlowImageFormatPropertiesNV => OpticalFlowImageFormatPropertiesNVBuilder {
+        [value format: crate::data::Format]
+    }
+    OpticalFlowSessionCreateInfoNV => OpticalFlowSessionCreateInfoNVBuilder {
+        [next]
+        [value width: u32]
+        [value height: u32]
+        [value image_format: crate::data::Format]
+        [value flow_vector_format: crate::data::Format]
+        [value cost_format: crate::data::Format]
+        [value output_grid_size: crate::data::OpticalFlowGridSizeFlagsNV]
+        [value hint_grid_size: crate::data::OpticalFlowGridSizeFlagsNV]
+        [value performance_level: crate::data::OpticalFlowPerformanceLevelNV]
+        [value flags: crate::data::OpticalFlowSessionCreateFlagsNV]
+    }
+    OpticalFlowSessionCreatePrivateDataInfoNV => OpticalFlowSessionCreatePrivateDataInfoNVBuilder {
+        [value id: u32]
+        [value size: u32]
+        [value p_private_data: *const c_void]
+    }
+    OpticalFlowExecuteInfoNV => OpticalFlowExecuteInfoNVBuilder {
+        [value flags: crate::data::OpticalFlowExecuteFlagsNV]
+        [slice regions = p_regions, region_count: crate::data::Rect2D]
+    }
+    PhysicalDeviceFaultFeaturesEXT => PhysicalDeviceFaultFeaturesEXTBuilder {
+        [value device_fault: crate::data::Bool32]
+        [value device_fault_vendor_binary: crate::data::Bool32]
+    }
+    DeviceFaultCountsEXT => DeviceFaultCountsEXTBuilder {
+        [value address_info_count: u32]
+        [value vendor_info_count: u32]
+        [value vendor_binary_size: crate::data::DeviceSize]
+    }
+    DeviceFaultInfoEXT => DeviceFaultInfoEXTBuilder {
+        [value description: [c_char; 256]]
+        [ptr_mut address_infos = p_address_infos: crate::data::DeviceFaultAddressInfoEXT]
+        [ptr_mut vendor_infos = p_vendor_infos: crate::data::DeviceFaultVendorInfoEXT]
+        [value p_vendor_binary_data: *mut c_void]
+    }
+}
+
+impl_accessors! {
+    ApplicationInfo {
+        [str application_name = p_application_name]
+        [str engine_name = p_engine_name]
+    }
+    DeviceQueueCreateInfo {
+        [slice queue_priorities = p_queue_priorities, queue_count: c_float]
+    }
+    DeviceCreateInfo {
+        [slice queue_create_infos = p_queue_create_infos, queue_create_info_count: crate::data::DeviceQueueCreateInfo]
+        [strs enabled_layer_names = pp_enabled_layer_names, enabled_layer_count: *const c_char]
+        [strs enabled_extension_names = pp_enabled_extension_names, enabled_extension_count: *const c_char]
+    }
+    InstanceCreateInfo {
+        [strs enabled_layer_names = pp_enabled_layer_names, enabled_layer_count: *const c_char]
+        [strs enabled_extension_names = pp_enabled_extension_names, enabled_extension_count: *const c_char]
+    }
+    WriteDescriptorSet {
+        [slice image_info = p_image_info, descriptor_count: crate::data::DescriptorImageInfo]
+        [slice buffer_info = p_buffer_info, descriptor_count: crate::data::DescriptorBufferInfo]
+        [slice texel_buffer_view = p_texel_buffer_view, descriptor_count: crate::data::BufferView]
+    }
+    BufferCreateInfo {
+        [slice queue_family_indices = p_queue_family_indices, queue_family_index_count: u32]
+    }
+    ImageCreateInfo {
+        [slice queue_family_indices = p_queue_family_indices, queue_family_index_count: u32]
+    }
+    SparseBufferMemoryBindInfo {
+        [slice binds = p_binds, bind_count: crate::data::SparseMemoryBind]
+    }
+    SparseImageOpaqueMemoryBindInfo {
+        [slice binds = p_binds, bind_count: crate::data::SparseMemoryBind]
+    }
+    SparseImageMemoryBindInfo {
+        [slice binds = p_binds, bind_count: crate::data::SparseImageMemoryBind]
+    }
+    BindSparseInfo {
+        [slice wait_semaphores = p_wait_semaphores, wait_semaphore_count: crate::data::Semaphore]
+        [slice buffer_binds = p_buffer_binds, buffer_bind_count: crate::data::SparseBufferMemoryBindInfo]
+        [slice image_opaque_binds = p_image_opaque_binds, image_opaque_bind_count: crate::data::SparseImageOpaqueMemoryBindInfo]
+        [slice image_binds = p_image_binds, image_bind_count: crate::data::SparseImageMemoryBindInfo]
+        [slice signal_semaphores = p_signal_semaphores, signal_semaphore_count: crate::data::Semaphore]
+    }
+    ShaderModuleCreateInfo {
+        [slice code = p_code, code_size * 4: u32]
+    }
+    DescriptorSetLayoutBinding {
+        [slice immutable_samplers = p_immutable_samplers, descriptor_count: crate::data::Sampler]
+    }
+    DescriptorSetLayoutCreateInfo {
+        [slice bindings = p_bindings, binding_count: crate::data::DescriptorSetLayoutBinding]
+    }
+    DescriptorPoolCreateInfo {
+        [slice pool_sizes = p_pool_sizes, pool_size_count: crate::data::DescriptorPoolSize]
+    }
+    DescriptorSetAllocateInfo {
+        [slice set_layouts = p_set_layouts, descriptor_set_count: crate::data::DescriptorSetLayout]
+    }
+    SpecializationInfo {
+        [slice map_entries = p_map_entries, map_entry_count: crate::data::SpecializationMapEntry]
+        [slice data = p_data, data_size: u8]
+    }
+    PipelineShaderStageCreateInfo {
+        [str name = p_name]
+    }
+    PipelineVertexInputStateCreateInfo {
+        [slice vertex_binding_descriptions = p_vertex_binding_descriptions, vertex_binding_description_count: crate::data::VertexInputBindingDescription]
+        [slice vertex_attribute_descriptions = p_vertex_attribute_descriptions, vertex_attribute_description_count: crate::data::VertexInputAttributeDescription]
+    }
+    PipelineViewportStateCreateInfo {
+        [slice viewports = p_viewports, viewport_count: crate::data::Viewport]
+        [slice scissors = p_scissors, scissor_count: crate::data::Rect2D]
+    }
+    PipelineColorBlendStateCreateInfo {
+        [slice attachments = p_attachments, attachment_count: crate::data::PipelineColorBlendAttachmentState]
+    }
+    PipelineDynamicStateCreateInfo {
+        [slice dynamic_states = p_dynamic_states, dynamic_state_count: crate::data::DynamicState]
+    }
+    GraphicsPipelineCreateInfo {
+        [slice stages = p_stages, stage_count: crate::data::PipelineShaderStageCreateInfo]
+    }
+    PipelineCacheCreateInfo {
+        [slice initial_data = p_initial_data, initial_data_size: u8]
+    }
+    PipelineLayoutCreateInfo {
+        [slice set_layouts = p_set_layouts, set_layout_count: crate::data::DescriptorSetLayout]
+        [slice push_constant_ranges = p_push_constant_ranges, push_constant_range_count: crate::data::PushConstantRange]
+    }
+    RenderPassBeginInfo {
+        [slice clear_values = p_clear_values, clear_value_count: crate::data::ClearValue]
+    }
+    SubpassDescription {
+        [slice input_attachments = p_input_attachments, input_attachment_count: crate::data::AttachmentReference]
+        [slice color_attachments = p_color_attachments, color_attachment_count: crate::data::AttachmentReference]
+        [slice resolve_attachments = p_resolve_attachments, color_attachment_count: crate::data::AttachmentReference]
+        [slice preserve_attachments = p_preserve_attachments, preserve_attachment_count: u32]
+    }
+    RenderPassCreateInfo {
+        [slice attachments = p_attachments, attachment_count: crate::data::AttachmentDescription]
+        [slice subpasses = p_subpasses, subpass_count: crate::data::SubpassDescription]
+        [slice dependencies = p_dependencies, dependency_count: crate::data::SubpassDependency]
+    }
+    FramebufferCreateInfo {
+        [slice attachments = p_attachments, attachment_count: crate::data::ImageView]
+    }
+    SubmitInfo {
+        [slice wait_semaphores = p_wait_semaphores, wait_semaphore_count: crate::data::Semaphore]
+        [slice wait_dst_stage_mask = p_wait_dst_stage_mask, wait_semaphore_count: crate::data::PipelineStageFlags]
+        [slice command_buffers = p_command_buffers, command_buffer_count: crate::data::CommandBuffer]
+        [slice signal_semaphores = p_signal_semaphores, signal_semaphore_count: crate::data::Semaphore]
+    }
+    DisplayPropertiesKHR {
+        [str display_name = display_name]
+    }
+    SwapchainCreateInfoKHR {
+        [slice queue_family_indices = p_queue_family_indices, queue_family_index_count: u32]
+    }
+    PresentInfoKHR {
+        [slice wait_semaphores = p_wait_semaphores, wait_semaphore_count: crate::data::Semaphore]
+        [slice swapchains = p_swapchains, swapchain_count: crate::data::SwapchainKHR]
+        [slice image_indices = p_image_indices, swapchain_count: u32]
+        [slice results = p_results, swapchain_count: crate::data::Result]
+    }
+    ValidationFlagsEXT {
+        [slice disabled_validation_checks = p_disabled_validation_checks, disabled_validation_check_count: crate::data::ValidationCheckEXT]
+    }
+    ValidationFeaturesEXT {
+        [slice enabled_validation_features = p_enabled_validation_features, enabled_validation_feature_count: crate::data::ValidationFeatureEnableEXT]
+        [slice disabled_validation_features = p_disabled_validation_features, disabled_validation_feature_count: crate::data::ValidationFeatureDisableEXT]
+    }
+    DebugMarkerObjectNameInfoEXT {
+        [str object_name = p_object_name]
+    }
+    DebugMarkerObjectTagInfoEXT {
+        [slice tag = p_tag, tag_size: u8]
+    }
+    DebugMarkerMarkerInfoEXT {
+        [str marker_name = p_marker_name]
+    }
+    Win32KeyedMutexAcquireReleaseInfoNV {
+        [slice acquire_syncs = p_acquire_syncs, acquire_count: crate::data::DeviceMemory]
+        [slice acquire_keys = p_acquire_keys, acquire_count: u64]
+        [slice acquire_timeout_milliseconds = p_acquire_timeout_milliseconds, acquire_count: u32]
+        [slice release_syncs = p_release_syncs, release_count: crate::data::DeviceMemory]
+        [slice release_keys = p_release_keys, release_count: u64]
+    }
+    GraphicsShaderGroupCreateInfoNV {
+        [slice stages = p_stages, stage_count: crate::data::PipelineShaderStageCreateInfo]
+    }
+    GraphicsPipelineShaderGroupsCreateInfoNV {
+        [slice groups = p_groups, group_count: crate::data::GraphicsShaderGroupCreateInfoNV]
+        [slice pipelines = p_pipelines, pipeline_count: crate::data::Pipeline]
+    }
+    IndirectCommandsLayoutTokenNV {
+        [slice index_types = p_index_types, index_type_count: crate::data::IndexType]
+        [slice index_type_values = p_index_type_values, index_type_count: u32]
+    }
+    IndirectCommandsLayoutCreateInfoNV {
+        [slice tokens = p_tokens, token_count: crate::data::IndirectCommandsLayoutTokenNV]
+        [slice stream_strides = p_stream_strides, stream_count: u32]
+    }
+    GeneratedCommandsInfoNV {
+        [slice streams = p_streams, stream_count: crate::data::IndirectCommandsStreamNV]
+    }
+    PresentRegionsKHR {
+        [slice regions = p_regions, swapchain_count: crate::data::PresentRegionKHR]
+    }
+    PresentRegionKHR {
+        [slice rectangles = p_rectangles, rectangle_count: crate::data::RectLayerKHR]
+    }
+    Win32KeyedMutexAcquireReleaseInfoKHR {
+        [slice acquire_syncs = p_acquire_syncs, acquire_count: crate::data::DeviceMemory]
+        [slice acquire_keys = p_acquire_keys, acquire_count: u64]
+        [slice acquire_timeouts = p_acquire_timeouts, acquire_count: u32]
+        [slice release_syncs = p_release_syncs, release_count: crate::data::DeviceMemory]
+        [slice release_keys = p_release_keys, release_count: u64]
+    }
+    D3D12FenceSubmitInfoKHR {
+        [slice wait_semaphore_values = p_wait_semaphore_values, wait_semaphore_values_count: u64]
+        [slice signal_semaphore_values = p_signal_semaphore_values, signal_semaphore_values_count: u64]
+    }
+    RenderPassMultiviewCreateInfo {
+        [slice view_masks = p_view_masks, subpass_count: u32]
+        [slice view_offsets = p_view_offsets, dependency_count: i32]
+        [slice correlation_masks = p_correlation_masks, correlation_mask_count: u32]
+    }
+    BindBufferMemoryDeviceGroupInfo {
+        [slice device_indices = p_device_indices, device_index_count: u32]
+    }
+    BindImageMemoryDeviceGroupInfo {
+        [slice device_indices = p_device_indices, device_index_count: u32]
+        [slice split_instance_bind_regions = p_split_instance_bind_regions, split_instance_bind_region_count: crate::data::Rect2D]
+    }
+    DeviceGroupRenderPassBeginInfo {
+        [slice device_render_areas = p_device_render_areas, device_render_area_count: crate::data::Rect2D]
+    }
+    DeviceGroupSubmitInfo {
+        [slice wait_semaphore_device_indices = p_wait_semaphore_device_indices, wait_semaphore_count: u32]
+        [slice command_buffer_device_masks = p_command_buffer_device_masks, command_buffer_count: u32]
+        [slice signal_semaphore_device_indices = p_signal_semaphore_device_indices, signal_semaphore_count: u32]
+    }
+    DeviceGroupPresentInfoKHR {
+        [slice device_masks = p_device_masks, swapchain_count: u32]
+    }
+    DeviceGroupDeviceCreateInfo {
+        [slice physical_devices = p_physical_devices, physical_device_count: crate::data::PhysicalDevice]
+    }
+    DescriptorUpdateTemplateCreateInfo {
+        [slice descriptor_update_entries = p_descriptor_update_entries, descriptor_update_entry_count: crate::data::DescriptorUpdateTemplateEntry]
+    }
+    PresentIdKHR {
+        [slice present_ids = p_present_ids, swapchain_count: u64]
+    }
+    PresentTimesInfoGOOGLE {
+        [slice times = p_times, swapchain_count: crate::data::PresentTimeGOOGLE]
+    }
+    PipelineViewportWScalingStateCreateInfoNV {
+        [slice viewport_w_scalings = p_viewport_w_scalings, viewport_count: crate::data::ViewportWScalingNV]
+    }
+    PipelineViewportSwizzleStateCreateInfoNV {
+        [slice viewport_swizzles = p_viewport_swizzles, viewport_count: crate::data::ViewportSwizzleNV]
+    }
+    PipelineDiscardRectangleStateCreateInfoEXT {
+        [slice discard_rectangles = p_discard_rectangles, discard_rectangle_count: crate::data::Rect2D]
+    }
+    RenderPassInputAttachmentAspectCreateInfo {
+        [slice aspect_references = p_aspect_references, aspect_reference_count: crate::data::InputAttachmentAspectReference]
+    }
+    SampleLocationsInfoEXT {
+        [slice sample_locations = p_sample_locations, sample_locations_count: crate::data::SampleLocationEXT]
+    }
+    RenderPassSampleLocationsBeginInfoEXT {
+        [slice attachment_initial_sample_locations = p_attachment_initial_sample_locations, attachment_initial_sample_locations_count: crate::data::AttachmentSampleLocationsEXT]
+        [slice post_subpass_sample_locations = p_post_subpass_sample_locations, post_subpass_sample_locations_count: crate::data::SubpassSampleLocationsEXT]
+    }
+    WriteDescriptorSetInlineUniformBlock {
+        [slice data = p_data, data_size: u8]
+    }
+    PipelineCoverageModulationStateCreateInfoNV {
+        [slice coverage_modulation_table = p_coverage_modulation_table, coverage_modulation_table_count: c_float]
+    }
+    ImageFormatListCreateInfo {
+        [slice view_formats = p_view_formats, view_format_count: crate::data::Format]
+    }
+    ValidationCacheCreateInfoEXT {
+        [slice initial_data = p_initial_data, initial_data_size: u8]
+    }
+    DebugUtilsObjectNameInfoEXT {
+        [str object_name = p_object_name]
+    }
+    DebugUtilsObjectTagInfoEXT {
+        [slice tag = p_tag, tag_size: u8]
+    }
+    DebugUtilsLabelEXT {
+        [str label_name = p_label_name]
+    }
+    DebugUtilsMessengerCallbackDataEXT {
+        [str message_id_name = p_message_id_name]
+        [str message = p_message]
+        [slice queue_labels = p_queue_labels, queue_label_count: crate::data::DebugUtilsLabelEXT]
+        [slice cmd_buf_labels = p_cmd_buf_labels, cmd_buf_label_count: crate::data::DebugUtilsLabelEXT]
+        [slice objects = p_objects, object_count: crate::data::DebugUtilsObjectNameInfoEXT]
+    }
+    DescriptorSetLayoutBindingFlagsCreateInfo {
+        [slice binding_flags = p_binding_flags, binding_count: crate::data::DescriptorBindingFlags]
+    }
+    DescriptorSetVariableDescriptorCountAllocateInfo {
+        [slice descriptor_counts = p_descriptor_counts, descriptor_set_count: u32]
+    }
+    SubpassDescription2 {
+        [slice input_attachments = p_input_attachments, input_attachment_count: crate::data::AttachmentReference2]
+        [slice color_attachments = p_color_attachments, color_attachment_count: crate::data::AttachmentReference2]
+        [slice resolve_attachments = p_resolve_attachments, color_attachment_count: crate::data::AttachmentReference2]
+        [slice preserve_attachments = p_preserve_attachments, preserve_attachment_count: u32]
+    }
+    RenderPassCreateInfo2 {
+        [slice attachments = p_attachments, attachment_count: crate::data::AttachmentDescription2]
+        [slice subpasses = p_subpasses, subpass_count: crate::data::SubpassDescription2]
+        [slice dependencies = p_dependencies, dependency_count: crate::data::SubpassDependency2]
+        [slice correlated_view_masks = p_correlated_view_masks, correlated_view_mask_count: u32]
+    }
+    TimelineSemaphoreSubmitInfo {
+        [slice wait_semaphore_values = p_wait_semaphore_values, wait_semaphore_value_count: u64]
+        [slice signal_semaphore_values = p_signal_semaphore_values, signal_semaphore_value_count: u64]
+    }
+    SemaphoreWaitInfo {
+        [slice semaphores = p_semaphores, semaphore_count: crate::data::Semaphore]
+        [slice values = p_values, semaphore_count: u64]
+    }
+    PipelineVertexInputDivisorStateCreateInfoEXT {
+        [slice vertex_binding_divisors = p_vertex_binding_divisors, vertex_binding_divisor_count: crate::data::VertexInputBindingDivisorDescriptionEXT]
+    }
+    PipelineViewportExclusiveScissorStateCreateInfoNV {
+        [slice exclusive_scissors = p_exclusive_scissors, exclusive_scissor_count: crate::data::Rect2D]
+    }
+    ShadingRatePaletteNV {
+        [slice shading_rate_palette_entries = p_shading_rate_palette_entries, shading_rate_palette_entry_count: crate::data::ShadingRatePaletteEntryNV]
+    }
+    PipelineViewportShadingRateImageStateCreateInfoNV {
+        [slice shading_rate_palettes = p_shading_rate_palettes, viewport_count: crate::data::ShadingRatePaletteNV]
+    }
+    CoarseSampleOrderCustomNV {
+        [slice sample_locations = p_sample_locations, sample_location_count: crate::data::CoarseSampleLocationNV]
+    }
+    PipelineViewportCoarseSampleOrderStateCreateInfoNV {
+        [slice custom_sample_orders = p_custom_sample_orders, custom_sample_order_count: crate::data::CoarseSampleOrderCustomNV]
+    }
+    RayTracingPipelineCreateInfoNV {
+        [slice stages = p_stages, stage_count: crate::data::PipelineShaderStageCreateInfo]
+        [slice groups = p_groups, group_count: crate::data::RayTracingShaderGroupCreateInfoNV]
+    }
+    RayTracingPipelineCreateInfoKHR {
+        [slice stages = p_stages, stage_count: crate::data::PipelineShaderStageCreateInfo]
+        [slice groups = p_groups, group_count: crate::data::RayTracingShaderGroupCreateInfoKHR]
+    }
+    AccelerationStructureInfoNV {
+        [slice geometries = p_geometries, geometry_count: crate::data::GeometryNV]
+    }
+    BindAccelerationStructureMemoryInfoNV {
+        [slice device_indices = p_device_indices, device_index_count: u32]
+    }
+    WriteDescriptorSetAccelerationStructureKHR {
+        [slice acceleration_structures = p_acceleration_structures, acceleration_structure_count: crate::data::AccelerationStructureKHR]
+    }
+    WriteDescriptorSetAccelerationStructureNV {
+        [slice acceleration_structures = p_acceleration_structures, acceleration_structure_count: crate::data::AccelerationStructureNV]
+    }
+    DrmFormatModifierPropertiesListEXT {
+        [slice drm_format_modifier_properties = p_drm_format_modifier_properties, drm_format_modifier_count: crate::data::DrmFormatModifierPropertiesEXT]
+    }
+    PhysicalDeviceImageDrmFormatModifierInfoEXT {
+        [slice queue_family_indices = p_queue_family_indices, queue_family_index_count: u32]
+    }
+    ImageDrmFormatModifierListCreateInfoEXT {
+        [slice drm_format_modifiers = p_drm_format_modifiers, drm_format_modifier_count: u64]
+    }
+    ImageDrmFormatModifierExplicitCreateInfoEXT {
+        [slice plane_layouts = p_plane_layouts, drm_format_modifier_plane_count: crate::data::SubresourceLayout]
+    }
+    SubpassFragmentDensityMapOffsetEndInfoQCOM {
+        [slice fragment_density_offsets = p_fragment_density_offsets, fragment_density_offset_count: crate::data::Offset2D]
+    }
+    FramebufferAttachmentsCreateInfo {
+        [slice attachment_image_infos = p_attachment_image_infos, attachment_image_info_count: crate::data::FramebufferAttachmentImageInfo]
+    }
+    FramebufferAttachmentImageInfo {
+        [slice view_formats = p_view_formats, view_format_count: crate::data::Format]
+    }
+    RenderPassAttachmentBeginInfo {
+        [slice attachments = p_attachments, attachment_count: crate::data::ImageView]
+    }
+    PipelineCreationFeedbackCreateInfo {
+        [slice pipeline_stage_creation_feedbacks = p_pipeline_stage_creation_feedbacks, pipeline_stage_creation_feedback_count: crate::data::PipelineCreationFeedback]
+    }
+    QueryPoolPerformanceCreateInfoKHR {
+        [slice counter_indices = p_counter_indices, counter_index_count: u32]
+    }
+    PipelineExecutableInternalRepresentationKHR {
+        [slice data = p_data, data_size: u8]
+    }
+    AccelerationStructureBuildGeometryInfoKHR {
+        [slice geometries = p_geometries, geometry_count: crate::data::AccelerationStructureGeometryKHR]
+        [slice pp_geometries = pp_geometries, geometry_count: *const crate::data::AccelerationStructureGeometryKHR]
+    }
+    PipelineLibraryCreateInfoKHR {
+        [slice libraries = p_libraries, library_count: crate::data::Pipeline]
+    }
+    CopyBufferInfo2 {
+        [slice regions = p_regions, region_count: crate::data::BufferCopy2]
+    }
+    CopyImageInfo2 {
+        [slice regions = p_regions, region_count: crate::data::ImageCopy2]
+    }
+    BlitImageInfo2 {
+        [slice regions = p_regions, region_count: crate::data::ImageBlit2]
+    }
+    CopyBufferToImageInfo2 {
+        [slice regions = p_regions, region_count: crate::data::BufferImageCopy2]
+    }
+    CopyImageToBufferInfo2 {
+        [slice regions = p_regions, region_count: crate::data::BufferImageCopy2]
+    }
+    ResolveImageInfo2 {
+        [slice regions = p_regions, region_count: crate::data::ImageResolve2]
+    }
+    MutableDescriptorTypeListEXT {
+        [slice descriptor_types = p_descriptor_types, descriptor_type_count: crate::data::DescriptorType]
+    }
+    MutableDescriptorTypeCreateInfoEXT {
+        [slice mutable_descriptor_type_lists = p_mutable_descriptor_type_lists, mutable_descriptor_type_list_count: crate::data::MutableDescriptorTypeListEXT]
+    }
+    PipelineColorWriteCreateInfoEXT {
+        [slice color_write_enables = p_color_write_enables, attachment_count: crate::data::Bool32]
+    }
+    DependencyInfo {
+        [slice memory_barriers = p_memory_barriers, memory_barrier_count: crate::data::MemoryBarrier2]
+        [slice buffer_memory_barriers = p_buffer_memory_barriers, buffer_memory_barrier_count: crate::data::BufferMemoryBarrier2]
+        [slice image_memory_barriers = p_image_memory_barriers, image_memory_barrier_count: crate::data::ImageMemoryBarrier2]
+    }
+    SubmitInfo2 {
+        [slice wait_semaphore_infos = p_wait_semaphore_infos, wait_semaphore_info_count: crate::data::SemaphoreSubmitInfo]
+        [slice command_buffer_infos = p_command_buffer_infos, command_buffer_info_count: crate::data::CommandBufferSubmitInfo]
+        [slice signal_semaphore_infos = p_signal_semaphore_infos, signal_semaphore_info_count: crate::data::SemaphoreSubmitInfo]
+    }
+    VideoProfileListInfoKHR {
+        [slice profiles = p_profiles, profile_count: crate::data::VideoProfileInfoKHR]
+    }
+    VideoDecodeInfoKHR {
+        [slice reference_slots = p_reference_slots, reference_slot_count: crate::data::VideoReferenceSlotInfoKHR]
+    }
+    VideoBeginCodingInfoKHR {
+        [slice reference_slots = p_reference_slots, reference_slot_count: crate::data::VideoReferenceSlotInfoKHR]
+    }
+    VideoEncodeInfoKHR {
+        [slice reference_slots = p_reference_slots, reference_slot_count: crate::data::VideoReferenceSlotInfoKHR]
+    }
+    VideoEncodeH264EmitPictureParametersInfoEXT {
+        [slice pps_id_entries = pps_id_entries, pps_id_entry_count: u8]
+    }
+    VideoEncodeH265EmitPictureParametersInfoEXT {
+        [slice pps_id_entries = pps_id_entries, pps_id_entry_count: u8]
+    }
+    CommandBufferInheritanceViewportScissorInfoNV {
+        [slice viewport_depths = p_viewport_depths, viewport_depth_count: crate::data::Viewport]
+    }
+    CuModuleCreateInfoNVX {
+        [slice data = p_data, data_size: u8]
+    }
+    CuFunctionCreateInfoNVX {
+        [str name = p_name]
+    }
+    CuLaunchInfoNVX {
+        [slice params = p_params, param_count: *const c_void]
+        [slice extras = p_extras, extra_count: *const c_void]
+    }
+    ImageFormatConstraintsInfoFUCHSIA {
+        [slice color_spaces = p_color_spaces, color_space_count: crate::data::SysmemColorSpaceFUCHSIA]
+    }
+    ImageConstraintsInfoFUCHSIA {
+        [slice format_constraints = p_format_constraints, format_constraints_count: crate::data::ImageFormatConstraintsInfoFUCHSIA]
+    }
+    DrmFormatModifierPropertiesList2EXT {
+        [slice drm_format_modifier_properties = p_drm_format_modifier_properties, drm_format_modifier_count: crate::data::DrmFormatModifierProperties2EXT]
+    }
+    PipelineRenderingCreateInfo {
+        [slice color_attachment_formats = p_color_attachment_formats, color_attachment_count: crate::data::Format]
+    }
+    RenderingInfo {
+        [slice color_attachments = p_color_attachments, color_attachment_count: crate::data::RenderingAttachmentInfo]
+    }
+    CommandBufferInheritanceRenderingInfo {
+        [slice color_attachment_formats = p_color_attachment_formats, color_attachment_count: crate::data::Format]
+    }
+    AttachmentSampleCountInfoAMD {
+        [slice color_attachment_samples = p_color_attachment_samples, color_attachment_count: crate::data::SampleCountFlagBits]
+    }
+    PipelineShaderStageModuleIdentifierCreateInfoEXT {
+        [slice identifier = p_identifier, identifier_size: u8]
+    }
+    ImageCompressionControlEXT {
+        [slice fixed_rate_flags = p_fixed_rate_flags, compression_control_plane_count: crate::data::ImageCompressionFixedRateFlagsEXT]
+    }
+    MicromapBuildInfoEXT {
+        [slice usage_counts = p_usage_counts, usage_counts_count: crate::data::MicromapUsageEXT]
+        [slice pp_usage_counts = pp_usage_counts, usage_counts_count: *const crate::data::MicromapUsageEXT]
+    }
+    AccelerationStructureTrianglesOpacityMicromapEXT {
+        [slice usage_counts = p_usage_counts, usage_counts_count: crate::data::MicromapUsageEXT]
+        [slice pp_usage_counts = pp_usage_counts, usage_counts_count: *const crate::data::MicromapUsageEXT]
+    }
+    OpticalFlowExecuteInfoNV {
+        [slice regions = p_regions, region_count: crate::data::Rect2D]
+    }
+}
+
 impl_commands! {
     CreateInstance(
         p_create_info: *const crate::data::InstanceCreateInfo,
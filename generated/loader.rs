@@ -1,5808 +1,20384 @@
-impl_table! {
-    name: InstanceTable,
-    get_proc_addr: GetInstanceProcAddr,
-    handle: {
-        name: instance,
-        type: Instance,
+impl_commands! {
+    DestroyInstance,
+    EnumeratePhysicalDevices -> Result,
+    GetInstanceProcAddr,
+    GetPhysicalDeviceProperties,
+    GetPhysicalDeviceQueueFamilyProperties,
+    GetPhysicalDeviceMemoryProperties,
+    GetPhysicalDeviceFeatures,
+    GetPhysicalDeviceFormatProperties,
+    GetPhysicalDeviceImageFormatProperties -> Result,
+    CreateDevice -> Result,
+    EnumerateDeviceLayerProperties -> Result,
+    EnumerateDeviceExtensionProperties -> Result,
+    GetPhysicalDeviceSparseImageFormatProperties,
+    CreateAndroidSurfaceKHR -> Result,
+    GetPhysicalDeviceDisplayPropertiesKHR -> Result,
+    GetPhysicalDeviceDisplayPlanePropertiesKHR -> Result,
+    GetDisplayPlaneSupportedDisplaysKHR -> Result,
+    GetDisplayModePropertiesKHR -> Result,
+    CreateDisplayModeKHR -> Result,
+    GetDisplayPlaneCapabilitiesKHR -> Result,
+    CreateDisplayPlaneSurfaceKHR -> Result,
+    DestroySurfaceKHR,
+    GetPhysicalDeviceSurfaceSupportKHR -> Result,
+    GetPhysicalDeviceSurfaceCapabilitiesKHR -> Result,
+    GetPhysicalDeviceSurfaceFormatsKHR -> Result,
+    GetPhysicalDeviceSurfacePresentModesKHR -> Result,
+    CreateViSurfaceNN -> Result,
+    CreateWaylandSurfaceKHR -> Result,
+    GetPhysicalDeviceWaylandPresentationSupportKHR,
+    CreateWin32SurfaceKHR -> Result,
+    GetPhysicalDeviceWin32PresentationSupportKHR,
+    CreateXlibSurfaceKHR -> Result,
+    GetPhysicalDeviceXlibPresentationSupportKHR,
+    CreateXcbSurfaceKHR -> Result,
+    GetPhysicalDeviceXcbPresentationSupportKHR,
+    CreateDirectFBSurfaceEXT -> Result,
+    GetPhysicalDeviceDirectFBPresentationSupportEXT,
+    CreateImagePipeSurfaceFUCHSIA -> Result,
+    CreateStreamDescriptorSurfaceGGP -> Result,
+    CreateScreenSurfaceQNX -> Result,
+    GetPhysicalDeviceScreenPresentationSupportQNX,
+    CreateDebugReportCallbackEXT -> Result,
+    DestroyDebugReportCallbackEXT,
+    DebugReportMessageEXT,
+    GetPhysicalDeviceExternalImageFormatPropertiesNV -> Result,
+    GetPhysicalDeviceFeatures2,
+    GetPhysicalDeviceProperties2,
+    GetPhysicalDeviceFormatProperties2,
+    GetPhysicalDeviceImageFormatProperties2 -> Result,
+    GetPhysicalDeviceQueueFamilyProperties2,
+    GetPhysicalDeviceMemoryProperties2,
+    GetPhysicalDeviceSparseImageFormatProperties2,
+    GetPhysicalDeviceExternalBufferProperties,
+    GetPhysicalDeviceExternalSemaphoreProperties,
+    GetPhysicalDeviceExternalFenceProperties,
+    ReleaseDisplayEXT -> Result,
+    AcquireXlibDisplayEXT -> Result,
+    GetRandROutputDisplayEXT -> Result,
+    AcquireWinrtDisplayNV -> Result,
+    GetWinrtDisplayNV -> Result,
+    GetPhysicalDeviceSurfaceCapabilities2EXT -> Result,
+    EnumeratePhysicalDeviceGroups -> Result,
+    GetPhysicalDevicePresentRectanglesKHR -> Result,
+    CreateIOSSurfaceMVK -> Result,
+    CreateMacOSSurfaceMVK -> Result,
+    CreateMetalSurfaceEXT -> Result,
+    GetPhysicalDeviceMultisamplePropertiesEXT,
+    GetPhysicalDeviceSurfaceCapabilities2KHR -> Result,
+    GetPhysicalDeviceSurfaceFormats2KHR -> Result,
+    GetPhysicalDeviceDisplayProperties2KHR -> Result,
+    GetPhysicalDeviceDisplayPlaneProperties2KHR -> Result,
+    GetDisplayModeProperties2KHR -> Result,
+    GetDisplayPlaneCapabilities2KHR -> Result,
+    GetPhysicalDeviceCalibrateableTimeDomainsEXT -> Result,
+    CreateDebugUtilsMessengerEXT -> Result,
+    DestroyDebugUtilsMessengerEXT,
+    SubmitDebugUtilsMessageEXT,
+    GetPhysicalDeviceCooperativeMatrixPropertiesNV -> Result,
+    GetPhysicalDeviceSurfacePresentModes2EXT -> Result,
+    EnumeratePhysicalDeviceQueueFamilyPerformanceQueryCountersKHR -> Result,
+    GetPhysicalDeviceQueueFamilyPerformanceQueryPassesKHR,
+    CreateHeadlessSurfaceEXT -> Result,
+    GetPhysicalDeviceSupportedFramebufferMixedSamplesCombinationsNV -> Result,
+    GetPhysicalDeviceToolProperties -> Result,
+    GetPhysicalDeviceFragmentShadingRatesKHR -> Result,
+    GetPhysicalDeviceVideoCapabilitiesKHR -> Result,
+    GetPhysicalDeviceVideoFormatPropertiesKHR -> Result,
+    AcquireDrmDisplayEXT -> Result,
+    GetDrmDisplayEXT -> Result,
+    GetPhysicalDeviceOpticalFlowImageFormatsNV -> Result,
+    GetDeviceProcAddr,
+    DestroyDevice,
+    GetDeviceQueue,
+    QueueSubmit -> Result,
+    QueueWaitIdle -> Result,
+    DeviceWaitIdle -> Result,
+    AllocateMemory -> Result,
+    FreeMemory,
+    MapMemory -> Result,
+    UnmapMemory,
+    FlushMappedMemoryRanges -> Result,
+    InvalidateMappedMemoryRanges -> Result,
+    GetDeviceMemoryCommitment,
+    GetBufferMemoryRequirements,
+    BindBufferMemory -> Result,
+    GetImageMemoryRequirements,
+    BindImageMemory -> Result,
+    GetImageSparseMemoryRequirements,
+    QueueBindSparse -> Result,
+    CreateFence -> Result,
+    DestroyFence,
+    ResetFences -> Result,
+    GetFenceStatus -> Result,
+    WaitForFences -> Result,
+    CreateSemaphore -> Result,
+    DestroySemaphore,
+    CreateEvent -> Result,
+    DestroyEvent,
+    GetEventStatus -> Result,
+    SetEvent -> Result,
+    ResetEvent -> Result,
+    CreateQueryPool -> Result,
+    DestroyQueryPool,
+    GetQueryPoolResults -> Result,
+    ResetQueryPool,
+    CreateBuffer -> Result,
+    DestroyBuffer,
+    CreateBufferView -> Result,
+    DestroyBufferView,
+    CreateImage -> Result,
+    DestroyImage,
+    GetImageSubresourceLayout,
+    CreateImageView -> Result,
+    DestroyImageView,
+    CreateShaderModule -> Result,
+    DestroyShaderModule,
+    CreatePipelineCache -> Result,
+    DestroyPipelineCache,
+    GetPipelineCacheData -> Result,
+    MergePipelineCaches -> Result,
+    CreateGraphicsPipelines -> Result,
+    CreateComputePipelines -> Result,
+    GetDeviceSubpassShadingMaxWorkgroupSizeHUAWEI -> Result,
+    DestroyPipeline,
+    CreatePipelineLayout -> Result,
+    DestroyPipelineLayout,
+    CreateSampler -> Result,
+    DestroySampler,
+    CreateDescriptorSetLayout -> Result,
+    DestroyDescriptorSetLayout,
+    CreateDescriptorPool -> Result,
+    DestroyDescriptorPool,
+    ResetDescriptorPool -> Result,
+    AllocateDescriptorSets -> Result,
+    FreeDescriptorSets -> Result,
+    UpdateDescriptorSets,
+    CreateFramebuffer -> Result,
+    DestroyFramebuffer,
+    CreateRenderPass -> Result,
+    DestroyRenderPass,
+    GetRenderAreaGranularity,
+    CreateCommandPool -> Result,
+    DestroyCommandPool,
+    ResetCommandPool -> Result,
+    AllocateCommandBuffers -> Result,
+    FreeCommandBuffers,
+    BeginCommandBuffer -> Result,
+    EndCommandBuffer -> Result,
+    ResetCommandBuffer -> Result,
+    CmdBindPipeline,
+    CmdSetViewport,
+    CmdSetScissor,
+    CmdSetLineWidth,
+    CmdSetDepthBias,
+    CmdSetBlendConstants,
+    CmdSetDepthBounds,
+    CmdSetStencilCompareMask,
+    CmdSetStencilWriteMask,
+    CmdSetStencilReference,
+    CmdBindDescriptorSets,
+    CmdBindIndexBuffer,
+    CmdBindVertexBuffers,
+    CmdDraw,
+    CmdDrawIndexed,
+    CmdDrawMultiEXT,
+    CmdDrawMultiIndexedEXT,
+    CmdDrawIndirect,
+    CmdDrawIndexedIndirect,
+    CmdDispatch,
+    CmdDispatchIndirect,
+    CmdSubpassShadingHUAWEI,
+    CmdCopyBuffer,
+    CmdCopyImage,
+    CmdBlitImage,
+    CmdCopyBufferToImage,
+    CmdCopyImageToBuffer,
+    CmdUpdateBuffer,
+    CmdFillBuffer,
+    CmdClearColorImage,
+    CmdClearDepthStencilImage,
+    CmdClearAttachments,
+    CmdResolveImage,
+    CmdSetEvent,
+    CmdResetEvent,
+    CmdWaitEvents,
+    CmdPipelineBarrier,
+    CmdBeginQuery,
+    CmdEndQuery,
+    CmdBeginConditionalRenderingEXT,
+    CmdEndConditionalRenderingEXT,
+    CmdResetQueryPool,
+    CmdWriteTimestamp,
+    CmdCopyQueryPoolResults,
+    CmdPushConstants,
+    CmdBeginRenderPass,
+    CmdNextSubpass,
+    CmdEndRenderPass,
+    CmdExecuteCommands,
+    CreateSharedSwapchainsKHR -> Result,
+    CreateSwapchainKHR -> Result,
+    DestroySwapchainKHR,
+    GetSwapchainImagesKHR -> Result,
+    AcquireNextImageKHR -> Result,
+    QueuePresentKHR -> Result,
+    DebugMarkerSetObjectNameEXT -> Result,
+    DebugMarkerSetObjectTagEXT -> Result,
+    CmdDebugMarkerBeginEXT,
+    CmdDebugMarkerEndEXT,
+    CmdDebugMarkerInsertEXT,
+    GetMemoryWin32HandleNV -> Result,
+    CmdExecuteGeneratedCommandsNV,
+    CmdPreprocessGeneratedCommandsNV,
+    CmdBindPipelineShaderGroupNV,
+    GetGeneratedCommandsMemoryRequirementsNV,
+    CreateIndirectCommandsLayoutNV -> Result,
+    DestroyIndirectCommandsLayoutNV,
+    CmdPushDescriptorSetKHR,
+    TrimCommandPool,
+    GetMemoryWin32HandleKHR -> Result,
+    GetMemoryWin32HandlePropertiesKHR -> Result,
+    GetMemoryFdKHR -> Result,
+    GetMemoryFdPropertiesKHR -> Result,
+    GetMemoryZirconHandleFUCHSIA -> Result,
+    GetMemoryZirconHandlePropertiesFUCHSIA -> Result,
+    GetMemoryRemoteAddressNV -> Result,
+    GetSemaphoreWin32HandleKHR -> Result,
+    ImportSemaphoreWin32HandleKHR -> Result,
+    GetSemaphoreFdKHR -> Result,
+    ImportSemaphoreFdKHR -> Result,
+    GetSemaphoreZirconHandleFUCHSIA -> Result,
+    ImportSemaphoreZirconHandleFUCHSIA -> Result,
+    GetFenceWin32HandleKHR -> Result,
+    ImportFenceWin32HandleKHR -> Result,
+    GetFenceFdKHR -> Result,
+    ImportFenceFdKHR -> Result,
+    DisplayPowerControlEXT -> Result,
+    RegisterDeviceEventEXT -> Result,
+    RegisterDisplayEventEXT -> Result,
+    GetSwapchainCounterEXT -> Result,
+    GetDeviceGroupPeerMemoryFeatures,
+    BindBufferMemory2 -> Result,
+    BindImageMemory2 -> Result,
+    CmdSetDeviceMask,
+    GetDeviceGroupPresentCapabilitiesKHR -> Result,
+    GetDeviceGroupSurfacePresentModesKHR -> Result,
+    AcquireNextImage2KHR -> Result,
+    CmdDispatchBase,
+    CreateDescriptorUpdateTemplate -> Result,
+    DestroyDescriptorUpdateTemplate,
+    UpdateDescriptorSetWithTemplate,
+    CmdPushDescriptorSetWithTemplateKHR,
+    SetHdrMetadataEXT,
+    GetSwapchainStatusKHR -> Result,
+    GetRefreshCycleDurationGOOGLE -> Result,
+    GetPastPresentationTimingGOOGLE -> Result,
+    CmdSetViewportWScalingNV,
+    CmdSetDiscardRectangleEXT,
+    CmdSetSampleLocationsEXT,
+    GetBufferMemoryRequirements2,
+    GetImageMemoryRequirements2,
+    GetImageSparseMemoryRequirements2,
+    GetDeviceBufferMemoryRequirements,
+    GetDeviceImageMemoryRequirements,
+    GetDeviceImageSparseMemoryRequirements,
+    CreateSamplerYcbcrConversion -> Result,
+    DestroySamplerYcbcrConversion,
+    GetDeviceQueue2,
+    CreateValidationCacheEXT -> Result,
+    DestroyValidationCacheEXT,
+    GetValidationCacheDataEXT -> Result,
+    MergeValidationCachesEXT -> Result,
+    GetDescriptorSetLayoutSupport,
+    GetSwapchainGrallocUsageANDROID -> Result,
+    GetSwapchainGrallocUsage2ANDROID -> Result,
+    AcquireImageANDROID -> Result,
+    QueueSignalReleaseImageANDROID -> Result,
+    GetShaderInfoAMD -> Result,
+    SetLocalDimmingAMD,
+    GetCalibratedTimestampsEXT -> Result,
+    SetDebugUtilsObjectNameEXT -> Result,
+    SetDebugUtilsObjectTagEXT -> Result,
+    QueueBeginDebugUtilsLabelEXT,
+    QueueEndDebugUtilsLabelEXT,
+    QueueInsertDebugUtilsLabelEXT,
+    CmdBeginDebugUtilsLabelEXT,
+    CmdEndDebugUtilsLabelEXT,
+    CmdInsertDebugUtilsLabelEXT,
+    GetMemoryHostPointerPropertiesEXT -> Result,
+    CmdWriteBufferMarkerAMD,
+    CreateRenderPass2 -> Result,
+    CmdBeginRenderPass2,
+    CmdNextSubpass2,
+    CmdEndRenderPass2,
+    GetSemaphoreCounterValue -> Result,
+    WaitSemaphores -> Result,
+    SignalSemaphore -> Result,
+    GetAndroidHardwareBufferPropertiesANDROID -> Result,
+    GetMemoryAndroidHardwareBufferANDROID -> Result,
+    CmdDrawIndirectCount,
+    CmdDrawIndexedIndirectCount,
+    CmdSetCheckpointNV,
+    GetQueueCheckpointDataNV,
+    CmdBindTransformFeedbackBuffersEXT,
+    CmdBeginTransformFeedbackEXT,
+    CmdEndTransformFeedbackEXT,
+    CmdBeginQueryIndexedEXT,
+    CmdEndQueryIndexedEXT,
+    CmdDrawIndirectByteCountEXT,
+    CmdSetExclusiveScissorNV,
+    CmdBindShadingRateImageNV,
+    CmdSetViewportShadingRatePaletteNV,
+    CmdSetCoarseSampleOrderNV,
+    CmdDrawMeshTasksNV,
+    CmdDrawMeshTasksIndirectNV,
+    CmdDrawMeshTasksIndirectCountNV,
+    CmdDrawMeshTasksEXT,
+    CmdDrawMeshTasksIndirectEXT,
+    CmdDrawMeshTasksIndirectCountEXT,
+    CompileDeferredNV -> Result,
+    CreateAccelerationStructureNV -> Result,
+    CmdBindInvocationMaskHUAWEI,
+    DestroyAccelerationStructureKHR,
+    DestroyAccelerationStructureNV,
+    GetAccelerationStructureMemoryRequirementsNV,
+    BindAccelerationStructureMemoryNV -> Result,
+    CmdCopyAccelerationStructureNV,
+    CmdCopyAccelerationStructureKHR,
+    CopyAccelerationStructureKHR -> Result,
+    CmdCopyAccelerationStructureToMemoryKHR,
+    CopyAccelerationStructureToMemoryKHR -> Result,
+    CmdCopyMemoryToAccelerationStructureKHR,
+    CopyMemoryToAccelerationStructureKHR -> Result,
+    CmdWriteAccelerationStructuresPropertiesKHR,
+    CmdWriteAccelerationStructuresPropertiesNV,
+    CmdBuildAccelerationStructureNV,
+    WriteAccelerationStructuresPropertiesKHR -> Result,
+    CmdTraceRaysKHR,
+    CmdTraceRaysNV,
+    GetRayTracingShaderGroupHandlesKHR -> Result,
+    GetRayTracingCaptureReplayShaderGroupHandlesKHR -> Result,
+    GetAccelerationStructureHandleNV -> Result,
+    CreateRayTracingPipelinesNV -> Result,
+    CreateRayTracingPipelinesKHR -> Result,
+    CmdTraceRaysIndirectKHR,
+    CmdTraceRaysIndirect2KHR,
+    GetDeviceAccelerationStructureCompatibilityKHR,
+    GetRayTracingShaderGroupStackSizeKHR,
+    CmdSetRayTracingPipelineStackSizeKHR,
+    GetImageViewHandleNVX,
+    GetImageViewAddressNVX -> Result,
+    GetDeviceGroupSurfacePresentModes2EXT -> Result,
+    AcquireFullScreenExclusiveModeEXT -> Result,
+    ReleaseFullScreenExclusiveModeEXT -> Result,
+    AcquireProfilingLockKHR -> Result,
+    ReleaseProfilingLockKHR,
+    GetImageDrmFormatModifierPropertiesEXT -> Result,
+    GetBufferOpaqueCaptureAddress,
+    GetBufferDeviceAddress,
+    InitializePerformanceApiINTEL -> Result,
+    UninitializePerformanceApiINTEL,
+    CmdSetPerformanceMarkerINTEL -> Result,
+    CmdSetPerformanceStreamMarkerINTEL -> Result,
+    CmdSetPerformanceOverrideINTEL -> Result,
+    AcquirePerformanceConfigurationINTEL -> Result,
+    ReleasePerformanceConfigurationINTEL -> Result,
+    QueueSetPerformanceConfigurationINTEL -> Result,
+    GetPerformanceParameterINTEL -> Result,
+    GetDeviceMemoryOpaqueCaptureAddress,
+    GetPipelineExecutablePropertiesKHR -> Result,
+    GetPipelineExecutableStatisticsKHR -> Result,
+    GetPipelineExecutableInternalRepresentationsKHR -> Result,
+    CmdSetLineStippleEXT,
+    CreateAccelerationStructureKHR -> Result,
+    CmdBuildAccelerationStructuresKHR,
+    CmdBuildAccelerationStructuresIndirectKHR,
+    BuildAccelerationStructuresKHR -> Result,
+    GetAccelerationStructureDeviceAddressKHR,
+    CreateDeferredOperationKHR -> Result,
+    DestroyDeferredOperationKHR,
+    GetDeferredOperationMaxConcurrencyKHR,
+    GetDeferredOperationResultKHR -> Result,
+    DeferredOperationJoinKHR -> Result,
+    CmdSetCullMode,
+    CmdSetFrontFace,
+    CmdSetPrimitiveTopology,
+    CmdSetViewportWithCount,
+    CmdSetScissorWithCount,
+    CmdBindVertexBuffers2,
+    CmdSetDepthTestEnable,
+    CmdSetDepthWriteEnable,
+    CmdSetDepthCompareOp,
+    CmdSetDepthBoundsTestEnable,
+    CmdSetStencilTestEnable,
+    CmdSetStencilOp,
+    CmdSetPatchControlPointsEXT,
+    CmdSetRasterizerDiscardEnable,
+    CmdSetDepthBiasEnable,
+    CmdSetLogicOpEXT,
+    CmdSetPrimitiveRestartEnable,
+    CreatePrivateDataSlot -> Result,
+    CmdSetTessellationDomainOriginEXT,
+    CmdSetDepthClampEnableEXT,
+    CmdSetPolygonModeEXT,
+    CmdSetRasterizationSamplesEXT,
+    CmdSetSampleMaskEXT,
+    CmdSetAlphaToCoverageEnableEXT,
+    CmdSetAlphaToOneEnableEXT,
+    CmdSetLogicOpEnableEXT,
+    CmdSetColorBlendEnableEXT,
+    CmdSetColorBlendEquationEXT,
+    CmdSetColorWriteMaskEXT,
+    CmdSetRasterizationStreamEXT,
+    CmdSetConservativeRasterizationModeEXT,
+    CmdSetExtraPrimitiveOverestimationSizeEXT,
+    CmdSetDepthClipEnableEXT,
+    CmdSetSampleLocationsEnableEXT,
+    CmdSetColorBlendAdvancedEXT,
+    CmdSetProvokingVertexModeEXT,
+    CmdSetLineRasterizationModeEXT,
+    CmdSetLineStippleEnableEXT,
+    CmdSetDepthClipNegativeOneToOneEXT,
+    CmdSetViewportWScalingEnableNV,
+    CmdSetViewportSwizzleNV,
+    CmdSetCoverageToColorEnableNV,
+    CmdSetCoverageToColorLocationNV,
+    CmdSetCoverageModulationModeNV,
+    CmdSetCoverageModulationTableEnableNV,
+    CmdSetCoverageModulationTableNV,
+    CmdSetShadingRateImageEnableNV,
+    CmdSetCoverageReductionModeNV,
+    CmdSetRepresentativeFragmentTestEnableNV,
+    DestroyPrivateDataSlot,
+    SetPrivateData -> Result,
+    GetPrivateData,
+    CmdCopyBuffer2,
+    CmdCopyImage2,
+    CmdBlitImage2,
+    CmdCopyBufferToImage2,
+    CmdCopyImageToBuffer2,
+    CmdResolveImage2,
+    CmdSetFragmentShadingRateKHR,
+    CmdSetFragmentShadingRateEnumNV,
+    GetAccelerationStructureBuildSizesKHR,
+    CmdSetVertexInputEXT,
+    CmdSetColorWriteEnableEXT,
+    CmdSetEvent2,
+    CmdResetEvent2,
+    CmdWaitEvents2,
+    CmdPipelineBarrier2,
+    QueueSubmit2 -> Result,
+    CmdWriteTimestamp2,
+    CmdWriteBufferMarker2AMD,
+    GetQueueCheckpointData2NV,
+    CreateVideoSessionKHR -> Result,
+    DestroyVideoSessionKHR,
+    CreateVideoSessionParametersKHR -> Result,
+    UpdateVideoSessionParametersKHR -> Result,
+    DestroyVideoSessionParametersKHR,
+    GetVideoSessionMemoryRequirementsKHR -> Result,
+    BindVideoSessionMemoryKHR -> Result,
+    CmdDecodeVideoKHR,
+    CmdBeginVideoCodingKHR,
+    CmdControlVideoCodingKHR,
+    CmdEndVideoCodingKHR,
+    CmdEncodeVideoKHR,
+    CreateCuModuleNVX -> Result,
+    CreateCuFunctionNVX -> Result,
+    DestroyCuModuleNVX,
+    DestroyCuFunctionNVX,
+    CmdCuLaunchKernelNVX,
+    SetDeviceMemoryPriorityEXT,
+    WaitForPresentKHR -> Result,
+    CreateBufferCollectionFUCHSIA -> Result,
+    SetBufferCollectionBufferConstraintsFUCHSIA -> Result,
+    SetBufferCollectionImageConstraintsFUCHSIA -> Result,
+    DestroyBufferCollectionFUCHSIA,
+    GetBufferCollectionPropertiesFUCHSIA -> Result,
+    CmdBeginRendering,
+    CmdEndRendering,
+    GetDescriptorSetLayoutHostMappingInfoVALVE,
+    GetDescriptorSetHostMappingVALVE,
+    CreateMicromapEXT -> Result,
+    CmdBuildMicromapsEXT,
+    BuildMicromapsEXT -> Result,
+    DestroyMicromapEXT,
+    CmdCopyMicromapEXT,
+    CopyMicromapEXT -> Result,
+    CmdCopyMicromapToMemoryEXT,
+    CopyMicromapToMemoryEXT -> Result,
+    CmdCopyMemoryToMicromapEXT,
+    CopyMemoryToMicromapEXT -> Result,
+    CmdWriteMicromapsPropertiesEXT,
+    WriteMicromapsPropertiesEXT -> Result,
+    GetDeviceMicromapCompatibilityEXT,
+    GetMicromapBuildSizesEXT,
+    GetShaderModuleIdentifierEXT,
+    GetShaderModuleCreateInfoIdentifierEXT,
+    GetImageSubresourceLayout2EXT,
+    GetPipelinePropertiesEXT -> Result,
+    ExportMetalObjectsEXT,
+    GetFramebufferTilePropertiesQCOM -> Result,
+    GetDynamicRenderingTilePropertiesQCOM -> Result,
+    CreateOpticalFlowSessionNV -> Result,
+    DestroyOpticalFlowSessionNV,
+    BindOpticalFlowSessionImageNV -> Result,
+    CmdOpticalFlowExecuteNV,
+    GetDeviceFaultInfoEXT -> Result,
+}
+
+
+impl_externsync! {
+    DestroyInstance: "instance";
+    DestroySurfaceKHR: "surface";
+    DestroyDebugReportCallbackEXT: "callback";
+    DestroyDebugUtilsMessengerEXT: "messenger";
+    DestroyDevice: "device";
+    QueueSubmit: "queue", "fence";
+    QueueWaitIdle: "queue";
+    FreeMemory: "memory";
+    MapMemory: "memory";
+    UnmapMemory: "memory";
+    BindBufferMemory: "buffer";
+    BindImageMemory: "image";
+    QueueBindSparse: "queue", "fence";
+    DestroyFence: "fence";
+    ResetFences: "p_fences";
+    DestroySemaphore: "semaphore";
+    DestroyEvent: "event";
+    SetEvent: "event";
+    ResetEvent: "event";
+    DestroyQueryPool: "query_pool";
+    DestroyBuffer: "buffer";
+    DestroyBufferView: "buffer_view";
+    DestroyImage: "image";
+    DestroyImageView: "image_view";
+    DestroyShaderModule: "shader_module";
+    DestroyPipelineCache: "pipeline_cache";
+    MergePipelineCaches: "dst_cache";
+    DestroyPipeline: "pipeline";
+    DestroyPipelineLayout: "pipeline_layout";
+    DestroySampler: "sampler";
+    DestroyDescriptorSetLayout: "descriptor_set_layout";
+    DestroyDescriptorPool: "descriptor_pool";
+    ResetDescriptorPool: "descriptor_pool";
+    AllocateDescriptorSets: "p_allocate_info.descriptor_pool";
+    FreeDescriptorSets: "descriptor_pool", "p_descriptor_sets";
+    UpdateDescriptorSets: "p_descriptor_writes.dst_set", "p_descriptor_copies.dst_set";
+    DestroyFramebuffer: "framebuffer";
+    DestroyRenderPass: "render_pass";
+    DestroyCommandPool: "command_pool";
+    ResetCommandPool: "command_pool";
+    AllocateCommandBuffers: "p_allocate_info.command_pool";
+    FreeCommandBuffers: "command_pool", "p_command_buffers";
+    BeginCommandBuffer: "command_buffer";
+    EndCommandBuffer: "command_buffer";
+    ResetCommandBuffer: "command_buffer";
+    CmdBindPipeline: "command_buffer";
+    CmdSetViewport: "command_buffer";
+    CmdSetScissor: "command_buffer";
+    CmdSetLineWidth: "command_buffer";
+    CmdSetDepthBias: "command_buffer";
+    CmdSetBlendConstants: "command_buffer";
+    CmdSetDepthBounds: "command_buffer";
+    CmdSetStencilCompareMask: "command_buffer";
+    CmdSetStencilWriteMask: "command_buffer";
+    CmdSetStencilReference: "command_buffer";
+    CmdBindDescriptorSets: "command_buffer";
+    CmdBindIndexBuffer: "command_buffer";
+    CmdBindVertexBuffers: "command_buffer";
+    CmdDraw: "command_buffer";
+    CmdDrawIndexed: "command_buffer";
+    CmdDrawMultiEXT: "command_buffer";
+    CmdDrawMultiIndexedEXT: "command_buffer";
+    CmdDrawIndirect: "command_buffer";
+    CmdDrawIndexedIndirect: "command_buffer";
+    CmdDispatch: "command_buffer";
+    CmdDispatchIndirect: "command_buffer";
+    CmdSubpassShadingHUAWEI: "command_buffer";
+    CmdCopyBuffer: "command_buffer";
+    CmdCopyImage: "command_buffer";
+    CmdBlitImage: "command_buffer";
+    CmdCopyBufferToImage: "command_buffer";
+    CmdCopyImageToBuffer: "command_buffer";
+    CmdUpdateBuffer: "command_buffer";
+    CmdFillBuffer: "command_buffer";
+    CmdClearColorImage: "command_buffer";
+    CmdClearDepthStencilImage: "command_buffer";
+    CmdClearAttachments: "command_buffer";
+    CmdResolveImage: "command_buffer";
+    CmdSetEvent: "command_buffer";
+    CmdResetEvent: "command_buffer";
+    CmdWaitEvents: "command_buffer";
+    CmdPipelineBarrier: "command_buffer";
+    CmdBeginQuery: "command_buffer";
+    CmdEndQuery: "command_buffer";
+    CmdBeginConditionalRenderingEXT: "command_buffer";
+    CmdEndConditionalRenderingEXT: "command_buffer";
+    CmdResetQueryPool: "command_buffer";
+    CmdWriteTimestamp: "command_buffer";
+    CmdCopyQueryPoolResults: "command_buffer";
+    CmdPushConstants: "command_buffer";
+    CmdBeginRenderPass: "command_buffer";
+    CmdNextSubpass: "command_buffer";
+    CmdEndRenderPass: "command_buffer";
+    CmdExecuteCommands: "command_buffer";
+    CreateSwapchainKHR: "p_create_info.surface", "p_create_info.old_swapchain";
+    DestroySwapchainKHR: "swapchain";
+    AcquireNextImageKHR: "swapchain", "semaphore", "fence";
+    QueuePresentKHR: "queue";
+    CmdDebugMarkerBeginEXT: "command_buffer";
+    CmdDebugMarkerEndEXT: "command_buffer";
+    CmdDebugMarkerInsertEXT: "command_buffer";
+    CmdExecuteGeneratedCommandsNV: "command_buffer";
+    CmdPreprocessGeneratedCommandsNV: "command_buffer";
+    CmdBindPipelineShaderGroupNV: "command_buffer";
+    DestroyIndirectCommandsLayoutNV: "indirect_commands_layout";
+    CmdPushDescriptorSetKHR: "command_buffer";
+    TrimCommandPool: "command_pool";
+    CmdSetDeviceMask: "command_buffer";
+    CmdDispatchBase: "command_buffer";
+    DestroyDescriptorUpdateTemplate: "descriptor_update_template";
+    UpdateDescriptorSetWithTemplate: "descriptor_set";
+    CmdPushDescriptorSetWithTemplateKHR: "command_buffer";
+    CmdSetViewportWScalingNV: "command_buffer";
+    CmdSetDiscardRectangleEXT: "command_buffer";
+    CmdSetSampleLocationsEXT: "command_buffer";
+    DestroySamplerYcbcrConversion: "ycbcr_conversion";
+    DestroyValidationCacheEXT: "validation_cache";
+    CmdBeginDebugUtilsLabelEXT: "command_buffer";
+    CmdEndDebugUtilsLabelEXT: "command_buffer";
+    CmdInsertDebugUtilsLabelEXT: "command_buffer";
+    CmdWriteBufferMarkerAMD: "command_buffer";
+    CmdBeginRenderPass2: "command_buffer";
+    CmdNextSubpass2: "command_buffer";
+    CmdEndRenderPass2: "command_buffer";
+    CmdDrawIndirectCount: "command_buffer";
+    CmdDrawIndexedIndirectCount: "command_buffer";
+    CmdSetCheckpointNV: "command_buffer";
+    CmdBindTransformFeedbackBuffersEXT: "command_buffer";
+    CmdBeginTransformFeedbackEXT: "command_buffer";
+    CmdEndTransformFeedbackEXT: "command_buffer";
+    CmdBeginQueryIndexedEXT: "command_buffer";
+    CmdEndQueryIndexedEXT: "command_buffer";
+    CmdDrawIndirectByteCountEXT: "command_buffer";
+    CmdSetExclusiveScissorNV: "command_buffer";
+    CmdBindShadingRateImageNV: "command_buffer";
+    CmdSetViewportShadingRatePaletteNV: "command_buffer";
+    CmdSetCoarseSampleOrderNV: "command_buffer";
+    CmdDrawMeshTasksNV: "command_buffer";
+    CmdDrawMeshTasksIndirectNV: "command_buffer";
+    CmdDrawMeshTasksIndirectCountNV: "command_buffer";
+    CmdDrawMeshTasksEXT: "command_buffer";
+    CmdDrawMeshTasksIndirectEXT: "command_buffer";
+    CmdDrawMeshTasksIndirectCountEXT: "command_buffer";
+    CmdBindInvocationMaskHUAWEI: "command_buffer";
+    DestroyAccelerationStructureKHR: "acceleration_structure";
+    DestroyAccelerationStructureNV: "acceleration_structure";
+    CmdCopyAccelerationStructureNV: "command_buffer";
+    CmdCopyAccelerationStructureKHR: "command_buffer";
+    CmdCopyAccelerationStructureToMemoryKHR: "command_buffer";
+    CmdCopyMemoryToAccelerationStructureKHR: "command_buffer";
+    CmdWriteAccelerationStructuresPropertiesKHR: "command_buffer";
+    CmdWriteAccelerationStructuresPropertiesNV: "command_buffer";
+    CmdBuildAccelerationStructureNV: "command_buffer";
+    CmdTraceRaysKHR: "command_buffer";
+    CmdTraceRaysNV: "command_buffer";
+    CmdTraceRaysIndirectKHR: "command_buffer";
+    CmdTraceRaysIndirect2KHR: "command_buffer";
+    CmdSetRayTracingPipelineStackSizeKHR: "command_buffer";
+    CmdSetPerformanceMarkerINTEL: "command_buffer";
+    CmdSetPerformanceStreamMarkerINTEL: "command_buffer";
+    CmdSetPerformanceOverrideINTEL: "command_buffer";
+    CmdSetLineStippleEXT: "command_buffer";
+    CmdBuildAccelerationStructuresKHR: "command_buffer";
+    CmdBuildAccelerationStructuresIndirectKHR: "command_buffer";
+    DestroyDeferredOperationKHR: "operation";
+    CmdSetCullMode: "command_buffer";
+    CmdSetFrontFace: "command_buffer";
+    CmdSetPrimitiveTopology: "command_buffer";
+    CmdSetViewportWithCount: "command_buffer";
+    CmdSetScissorWithCount: "command_buffer";
+    CmdBindVertexBuffers2: "command_buffer";
+    CmdSetDepthTestEnable: "command_buffer";
+    CmdSetDepthWriteEnable: "command_buffer";
+    CmdSetDepthCompareOp: "command_buffer";
+    CmdSetDepthBoundsTestEnable: "command_buffer";
+    CmdSetStencilTestEnable: "command_buffer";
+    CmdSetStencilOp: "command_buffer";
+    CmdSetPatchControlPointsEXT: "command_buffer";
+    CmdSetRasterizerDiscardEnable: "command_buffer";
+    CmdSetDepthBiasEnable: "command_buffer";
+    CmdSetLogicOpEXT: "command_buffer";
+    CmdSetPrimitiveRestartEnable: "command_buffer";
+    CmdSetTessellationDomainOriginEXT: "command_buffer";
+    CmdSetDepthClampEnableEXT: "command_buffer";
+    CmdSetPolygonModeEXT: "command_buffer";
+    CmdSetRasterizationSamplesEXT: "command_buffer";
+    CmdSetSampleMaskEXT: "command_buffer";
+    CmdSetAlphaToCoverageEnableEXT: "command_buffer";
+    CmdSetAlphaToOneEnableEXT: "command_buffer";
+    CmdSetLogicOpEnableEXT: "command_buffer";
+    CmdSetColorBlendEnableEXT: "command_buffer";
+    CmdSetColorBlendEquationEXT: "command_buffer";
+    CmdSetColorWriteMaskEXT: "command_buffer";
+    CmdSetRasterizationStreamEXT: "command_buffer";
+    CmdSetConservativeRasterizationModeEXT: "command_buffer";
+    CmdSetExtraPrimitiveOverestimationSizeEXT: "command_buffer";
+    CmdSetDepthClipEnableEXT: "command_buffer";
+    CmdSetSampleLocationsEnableEXT: "command_buffer";
+    CmdSetColorBlendAdvancedEXT: "command_buffer";
+    CmdSetProvokingVertexModeEXT: "command_buffer";
+    CmdSetLineRasterizationModeEXT: "command_buffer";
+    CmdSetLineStippleEnableEXT: "command_buffer";
+    CmdSetDepthClipNegativeOneToOneEXT: "command_buffer";
+    CmdSetViewportWScalingEnableNV: "command_buffer";
+    CmdSetViewportSwizzleNV: "command_buffer";
+    CmdSetCoverageToColorEnableNV: "command_buffer";
+    CmdSetCoverageToColorLocationNV: "command_buffer";
+    CmdSetCoverageModulationModeNV: "command_buffer";
+    CmdSetCoverageModulationTableEnableNV: "command_buffer";
+    CmdSetCoverageModulationTableNV: "command_buffer";
+    CmdSetShadingRateImageEnableNV: "command_buffer";
+    CmdSetCoverageReductionModeNV: "command_buffer";
+    CmdSetRepresentativeFragmentTestEnableNV: "command_buffer";
+    DestroyPrivateDataSlot: "private_data_slot";
+    CmdCopyBuffer2: "command_buffer";
+    CmdCopyImage2: "command_buffer";
+    CmdBlitImage2: "command_buffer";
+    CmdCopyBufferToImage2: "command_buffer";
+    CmdCopyImageToBuffer2: "command_buffer";
+    CmdResolveImage2: "command_buffer";
+    CmdSetFragmentShadingRateKHR: "command_buffer";
+    CmdSetFragmentShadingRateEnumNV: "command_buffer";
+    CmdSetVertexInputEXT: "command_buffer";
+    CmdSetColorWriteEnableEXT: "command_buffer";
+    CmdSetEvent2: "command_buffer";
+    CmdResetEvent2: "command_buffer";
+    CmdWaitEvents2: "command_buffer";
+    CmdPipelineBarrier2: "command_buffer";
+    QueueSubmit2: "queue", "fence";
+    CmdWriteTimestamp2: "command_buffer";
+    CmdWriteBufferMarker2AMD: "command_buffer";
+    DestroyVideoSessionKHR: "video_session";
+    DestroyVideoSessionParametersKHR: "video_session_parameters";
+    CmdDecodeVideoKHR: "command_buffer";
+    CmdBeginVideoCodingKHR: "command_buffer";
+    CmdControlVideoCodingKHR: "command_buffer";
+    CmdEndVideoCodingKHR: "command_buffer";
+    CmdEncodeVideoKHR: "command_buffer";
+    DestroyCuModuleNVX: "module";
+    DestroyCuFunctionNVX: "function";
+    CmdCuLaunchKernelNVX: "command_buffer";
+    DestroyBufferCollectionFUCHSIA: "collection";
+    CmdBeginRendering: "command_buffer";
+    CmdEndRendering: "command_buffer";
+    CmdBuildMicromapsEXT: "command_buffer";
+    DestroyMicromapEXT: "micromap";
+    CmdCopyMicromapEXT: "command_buffer";
+    CmdCopyMicromapToMemoryEXT: "command_buffer";
+    CmdCopyMemoryToMicromapEXT: "command_buffer";
+    CmdWriteMicromapsPropertiesEXT: "command_buffer";
+    DestroyOpticalFlowSessionNV: "session";
+    CmdOpticalFlowExecuteNV: "command_buffer";
+}
+
+
+impl_handle_types! {
+    Instance = INSTANCE,
+    PhysicalDevice = PHYSICAL_DEVICE: Instance,
+    Device = DEVICE: PhysicalDevice,
+    Queue = QUEUE: Device,
+    CommandBuffer = COMMAND_BUFFER: CommandPool,
+    DeviceMemory = DEVICE_MEMORY: Device,
+    CommandPool = COMMAND_POOL: Device,
+    Buffer = BUFFER: Device,
+    BufferView = BUFFER_VIEW: Device,
+    Image = IMAGE: Device,
+    ImageView = IMAGE_VIEW: Device,
+    ShaderModule = SHADER_MODULE: Device,
+    Pipeline = PIPELINE: Device,
+    PipelineLayout = PIPELINE_LAYOUT: Device,
+    Sampler = SAMPLER: Device,
+    DescriptorSet = DESCRIPTOR_SET: DescriptorPool,
+    DescriptorSetLayout = DESCRIPTOR_SET_LAYOUT: Device,
+    DescriptorPool = DESCRIPTOR_POOL: Device,
+    Fence = FENCE: Device,
+    Semaphore = SEMAPHORE: Device,
+    Event = EVENT: Device,
+    QueryPool = QUERY_POOL: Device,
+    Framebuffer = FRAMEBUFFER: Device,
+    RenderPass = RENDER_PASS: Device,
+    PipelineCache = PIPELINE_CACHE: Device,
+    IndirectCommandsLayoutNV = INDIRECT_COMMANDS_LAYOUT_NV: Device,
+    DescriptorUpdateTemplate = DESCRIPTOR_UPDATE_TEMPLATE: Device,
+    SamplerYcbcrConversion = SAMPLER_YCBCR_CONVERSION: Device,
+    ValidationCacheEXT = VALIDATION_CACHE_EXT: Device,
+    AccelerationStructureKHR = ACCELERATION_STRUCTURE_KHR: Device,
+    AccelerationStructureNV = ACCELERATION_STRUCTURE_NV: Device,
+    PerformanceConfigurationINTEL = PERFORMANCE_CONFIGURATION_INTEL: Device,
+    BufferCollectionFUCHSIA = BUFFER_COLLECTION_FUCHSIA: Device,
+    DeferredOperationKHR = DEFERRED_OPERATION_KHR: Device,
+    PrivateDataSlot = PRIVATE_DATA_SLOT: Device,
+    CuModuleNVX = CU_MODULE_NVX: Device,
+    CuFunctionNVX = CU_FUNCTION_NVX: Device,
+    OpticalFlowSessionNV = OPTICAL_FLOW_SESSION_NV: Device,
+    MicromapEXT = MICROMAP_EXT: Device,
+    DisplayKHR = DISPLAY_KHR: PhysicalDevice,
+    DisplayModeKHR = DISPLAY_MODE_KHR: DisplayKHR,
+    SurfaceKHR = SURFACE_KHR: Instance,
+    SwapchainKHR = SWAPCHAIN_KHR: SurfaceKHR,
+    DebugReportCallbackEXT = DEBUG_REPORT_CALLBACK_EXT: Instance,
+    DebugUtilsMessengerEXT = DEBUG_UTILS_MESSENGER_EXT: Instance,
+    VideoSessionKHR = VIDEO_SESSION_KHR: Device,
+    VideoSessionParametersKHR = VIDEO_SESSION_PARAMETERS_KHR: VideoSessionKHR,
+}
+
+
+impl_reflect! {
+    this;
+    BaseOutStructure {
+        p_next: next,
     },
-    members: [
+    BaseInStructure {
+        p_next: next,
+    },
+    ApplicationInfo = APPLICATION_INFO {
+        p_next: next,
+        p_application_name: str,
+        p_engine_name: str,
+    },
+    AllocationCallbacks {
+        p_user_data: opaque,
+    },
+    DeviceQueueCreateInfo = DEVICE_QUEUE_CREATE_INFO {
+        p_next: next,
+        p_queue_priorities: ptr[this.queue_count],
+    },
+    DeviceCreateInfo = DEVICE_CREATE_INFO {
+        p_next: next,
+        p_queue_create_infos: structs[this.queue_create_info_count],
+        pp_enabled_layer_names: strs[this.enabled_layer_count],
+        pp_enabled_extension_names: strs[this.enabled_extension_count],
+        p_enabled_features: ptr,
+    },
+    InstanceCreateInfo = INSTANCE_CREATE_INFO {
+        p_next: next,
+        p_application_info: structs,
+        pp_enabled_layer_names: strs[this.enabled_layer_count],
+        pp_enabled_extension_names: strs[this.enabled_extension_count],
+    },
+    MemoryAllocateInfo = MEMORY_ALLOCATE_INFO {
+        p_next: next,
+    },
+    MappedMemoryRange = MAPPED_MEMORY_RANGE {
+        p_next: next,
+        memory: handle,
+    },
+    DescriptorBufferInfo {
+        buffer: handle,
+    },
+    DescriptorImageInfo {
+        sampler: handle,
+        image_view: handle,
+    },
+    WriteDescriptorSet = WRITE_DESCRIPTOR_SET {
+        p_next: next,
+        dst_set: handle,
+        p_image_info: structs[this.descriptor_count],
+        p_buffer_info: structs[this.descriptor_count],
+        p_texel_buffer_view: handles[this.descriptor_count],
+    },
+    CopyDescriptorSet = COPY_DESCRIPTOR_SET {
+        p_next: next,
+        src_set: handle,
+        dst_set: handle,
+    },
+    BufferCreateInfo = BUFFER_CREATE_INFO {
+        p_next: next,
+        p_queue_family_indices: ptr[this.queue_family_index_count],
+    },
+    BufferViewCreateInfo = BUFFER_VIEW_CREATE_INFO {
+        p_next: next,
+        buffer: handle,
+    },
+    MemoryBarrier = MEMORY_BARRIER {
+        p_next: next,
+    },
+    BufferMemoryBarrier = BUFFER_MEMORY_BARRIER {
+        p_next: next,
+        buffer: handle,
+    },
+    ImageMemoryBarrier = IMAGE_MEMORY_BARRIER {
+        p_next: next,
+        image: handle,
+    },
+    ImageCreateInfo = IMAGE_CREATE_INFO {
+        p_next: next,
+        p_queue_family_indices: ptr[this.queue_family_index_count],
+    },
+    ImageViewCreateInfo = IMAGE_VIEW_CREATE_INFO {
+        p_next: next,
+        image: handle,
+    },
+    SparseMemoryBind {
+        memory: handle,
+    },
+    SparseImageMemoryBind {
+        memory: handle,
+    },
+    SparseBufferMemoryBindInfo {
+        buffer: handle,
+        p_binds: structs[this.bind_count],
+    },
+    SparseImageOpaqueMemoryBindInfo {
+        image: handle,
+        p_binds: structs[this.bind_count],
+    },
+    SparseImageMemoryBindInfo {
+        image: handle,
+        p_binds: structs[this.bind_count],
+    },
+    BindSparseInfo = BIND_SPARSE_INFO {
+        p_next: next,
+        p_wait_semaphores: handles[this.wait_semaphore_count],
+        p_buffer_binds: structs[this.buffer_bind_count],
+        p_image_opaque_binds: structs[this.image_opaque_bind_count],
+        p_image_binds: structs[this.image_bind_count],
+        p_signal_semaphores: handles[this.signal_semaphore_count],
+    },
+    ShaderModuleCreateInfo = SHADER_MODULE_CREATE_INFO {
+        p_next: next,
+        p_code: ptr[this.code_size / 4],
+    },
+    DescriptorSetLayoutBinding {
+        p_immutable_samplers: handles[this.descriptor_count],
+    },
+    DescriptorSetLayoutCreateInfo = DESCRIPTOR_SET_LAYOUT_CREATE_INFO {
+        p_next: next,
+        p_bindings: structs[this.binding_count],
+    },
+    DescriptorPoolCreateInfo = DESCRIPTOR_POOL_CREATE_INFO {
+        p_next: next,
+        p_pool_sizes: ptr[this.pool_size_count],
+    },
+    DescriptorSetAllocateInfo = DESCRIPTOR_SET_ALLOCATE_INFO {
+        p_next: next,
+        descriptor_pool: handle,
+        p_set_layouts: handles[this.descriptor_set_count],
+    },
+    SpecializationInfo {
+        p_map_entries: ptr[this.map_entry_count],
+        p_data: bytes[this.data_size],
+    },
+    PipelineShaderStageCreateInfo = PIPELINE_SHADER_STAGE_CREATE_INFO {
+        p_next: next,
+        module: handle,
+        p_name: str,
+        p_specialization_info: structs,
+    },
+    ComputePipelineCreateInfo = COMPUTE_PIPELINE_CREATE_INFO {
+        p_next: next,
+        stage: struct,
+        layout: handle,
+        base_pipeline_handle: handle,
+    },
+    PipelineVertexInputStateCreateInfo = PIPELINE_VERTEX_INPUT_STATE_CREATE_INFO {
+        p_next: next,
+        p_vertex_binding_descriptions: ptr[this.vertex_binding_description_count],
+        p_vertex_attribute_descriptions: ptr[this.vertex_attribute_description_count],
+    },
+    PipelineInputAssemblyStateCreateInfo = PIPELINE_INPUT_ASSEMBLY_STATE_CREATE_INFO {
+        p_next: next,
+    },
+    PipelineTessellationStateCreateInfo = PIPELINE_TESSELLATION_STATE_CREATE_INFO {
+        p_next: next,
+    },
+    PipelineViewportStateCreateInfo = PIPELINE_VIEWPORT_STATE_CREATE_INFO {
+        p_next: next,
+        p_viewports: ptr[this.viewport_count],
+        p_scissors: ptr[this.scissor_count],
+    },
+    PipelineRasterizationStateCreateInfo = PIPELINE_RASTERIZATION_STATE_CREATE_INFO {
+        p_next: next,
+    },
+    PipelineMultisampleStateCreateInfo = PIPELINE_MULTISAMPLE_STATE_CREATE_INFO {
+        p_next: next,
+        p_sample_mask: ptr[(this.rasterization_samples.0 + 31) / 32],
+    },
+    PipelineColorBlendStateCreateInfo = PIPELINE_COLOR_BLEND_STATE_CREATE_INFO {
+        p_next: next,
+        p_attachments: ptr[this.attachment_count],
+    },
+    PipelineDynamicStateCreateInfo = PIPELINE_DYNAMIC_STATE_CREATE_INFO {
+        p_next: next,
+        p_dynamic_states: ptr[this.dynamic_state_count],
+    },
+    PipelineDepthStencilStateCreateInfo = PIPELINE_DEPTH_STENCIL_STATE_CREATE_INFO {
+        p_next: next,
+    },
+    GraphicsPipelineCreateInfo = GRAPHICS_PIPELINE_CREATE_INFO {
+        p_next: next,
+        p_stages: structs[this.stage_count],
+        p_vertex_input_state: structs,
+        p_input_assembly_state: structs,
+        p_tessellation_state: structs,
+        p_viewport_state: structs,
+        p_rasterization_state: structs,
+        p_multisample_state: structs,
+        p_depth_stencil_state: structs,
+        p_color_blend_state: structs,
+        p_dynamic_state: structs,
+        layout: handle,
+        render_pass: handle,
+        base_pipeline_handle: handle,
+    },
+    PipelineCacheCreateInfo = PIPELINE_CACHE_CREATE_INFO {
+        p_next: next,
+        p_initial_data: bytes[this.initial_data_size],
+    },
+    PipelineLayoutCreateInfo = PIPELINE_LAYOUT_CREATE_INFO {
+        p_next: next,
+        p_set_layouts: handles[this.set_layout_count],
+        p_push_constant_ranges: ptr[this.push_constant_range_count],
+    },
+    SamplerCreateInfo = SAMPLER_CREATE_INFO {
+        p_next: next,
+    },
+    CommandPoolCreateInfo = COMMAND_POOL_CREATE_INFO {
+        p_next: next,
+    },
+    CommandBufferAllocateInfo = COMMAND_BUFFER_ALLOCATE_INFO {
+        p_next: next,
+        command_pool: handle,
+    },
+    CommandBufferInheritanceInfo = COMMAND_BUFFER_INHERITANCE_INFO {
+        p_next: next,
+        render_pass: handle,
+        framebuffer: handle,
+    },
+    CommandBufferBeginInfo = COMMAND_BUFFER_BEGIN_INFO {
+        p_next: next,
+        p_inheritance_info: structs,
+    },
+    RenderPassBeginInfo = RENDER_PASS_BEGIN_INFO {
+        p_next: next,
+        render_pass: handle,
+        framebuffer: handle,
+        p_clear_values: ptr[this.clear_value_count],
+    },
+    SubpassDescription {
+        p_input_attachments: ptr[this.input_attachment_count],
+        p_color_attachments: ptr[this.color_attachment_count],
+        p_resolve_attachments: ptr[this.color_attachment_count],
+        p_depth_stencil_attachment: ptr,
+        p_preserve_attachments: ptr[this.preserve_attachment_count],
+    },
+    RenderPassCreateInfo = RENDER_PASS_CREATE_INFO {
+        p_next: next,
+        p_attachments: ptr[this.attachment_count],
+        p_subpasses: structs[this.subpass_count],
+        p_dependencies: ptr[this.dependency_count],
+    },
+    EventCreateInfo = EVENT_CREATE_INFO {
+        p_next: next,
+    },
+    FenceCreateInfo = FENCE_CREATE_INFO {
+        p_next: next,
+    },
+    SemaphoreCreateInfo = SEMAPHORE_CREATE_INFO {
+        p_next: next,
+    },
+    QueryPoolCreateInfo = QUERY_POOL_CREATE_INFO {
+        p_next: next,
+    },
+    FramebufferCreateInfo = FRAMEBUFFER_CREATE_INFO {
+        p_next: next,
+        render_pass: handle,
+        p_attachments: handles[this.attachment_count],
+    },
+    SubmitInfo = SUBMIT_INFO {
+        p_next: next,
+        p_wait_semaphores: handles[this.wait_semaphore_count],
+        p_wait_dst_stage_mask: ptr[this.wait_semaphore_count],
+        p_command_buffers: handles[this.command_buffer_count],
+        p_signal_semaphores: handles[this.signal_semaphore_count],
+    },
+    DisplayPropertiesKHR {
+        display: handle,
+        display_name: str,
+    },
+    DisplayPlanePropertiesKHR {
+        current_display: handle,
+    },
+    DisplayModePropertiesKHR {
+        display_mode: handle,
+    },
+    DisplayModeCreateInfoKHR = DISPLAY_MODE_CREATE_INFO_KHR {
+        p_next: next,
+    },
+    DisplaySurfaceCreateInfoKHR = DISPLAY_SURFACE_CREATE_INFO_KHR {
+        p_next: next,
+        display_mode: handle,
+    },
+    DisplayPresentInfoKHR = DISPLAY_PRESENT_INFO_KHR {
+        p_next: next,
+    },
+    AndroidSurfaceCreateInfoKHR = ANDROID_SURFACE_CREATE_INFO_KHR {
+        p_next: next,
+        window: opaque,
+    },
+    ViSurfaceCreateInfoNN = VI_SURFACE_CREATE_INFO_NN {
+        p_next: next,
+        window: opaque,
+    },
+    WaylandSurfaceCreateInfoKHR = WAYLAND_SURFACE_CREATE_INFO_KHR {
+        p_next: next,
+        display: opaque,
+        surface: opaque,
+    },
+    Win32SurfaceCreateInfoKHR = WIN32_SURFACE_CREATE_INFO_KHR {
+        p_next: next,
+    },
+    XlibSurfaceCreateInfoKHR = XLIB_SURFACE_CREATE_INFO_KHR {
+        p_next: next,
+        dpy: ptr,
+    },
+    XcbSurfaceCreateInfoKHR = XCB_SURFACE_CREATE_INFO_KHR {
+        p_next: next,
+        connection: ptr,
+    },
+    DirectFBSurfaceCreateInfoEXT = DIRECTFB_SURFACE_CREATE_INFO_EXT {
+        p_next: next,
+        dfb: opaque,
+        surface: opaque,
+    },
+    ImagePipeSurfaceCreateInfoFUCHSIA = IMAGEPIPE_SURFACE_CREATE_INFO_FUCHSIA {
+        p_next: next,
+    },
+    StreamDescriptorSurfaceCreateInfoGGP = STREAM_DESCRIPTOR_SURFACE_CREATE_INFO_GGP {
+        p_next: next,
+    },
+    ScreenSurfaceCreateInfoQNX = SCREEN_SURFACE_CREATE_INFO_QNX {
+        p_next: next,
+        context: opaque,
+        window: opaque,
+    },
+    SwapchainCreateInfoKHR = SWAPCHAIN_CREATE_INFO_KHR {
+        p_next: next,
+        surface: handle,
+        p_queue_family_indices: ptr[this.queue_family_index_count],
+        old_swapchain: handle,
+    },
+    PresentInfoKHR = PRESENT_INFO_KHR {
+        p_next: next,
+        p_wait_semaphores: handles[this.wait_semaphore_count],
+        p_swapchains: handles[this.swapchain_count],
+        p_image_indices: ptr[this.swapchain_count],
+        p_results: ptr[this.swapchain_count],
+    },
+    DebugReportCallbackCreateInfoEXT = DEBUG_REPORT_CALLBACK_CREATE_INFO_EXT {
+        p_next: next,
+        p_user_data: opaque,
+    },
+    ValidationFlagsEXT = VALIDATION_FLAGS_EXT {
+        p_next: next,
+        p_disabled_validation_checks: ptr[this.disabled_validation_check_count],
+    },
+    ValidationFeaturesEXT = VALIDATION_FEATURES_EXT {
+        p_next: next,
+        p_enabled_validation_features: ptr[this.enabled_validation_feature_count],
+        p_disabled_validation_features: ptr[this.disabled_validation_feature_count],
+    },
+    PipelineRasterizationStateRasterizationOrderAMD = PIPELINE_RASTERIZATION_STATE_RASTERIZATION_ORDER_AMD {
+        p_next: next,
+    },
+    DebugMarkerObjectNameInfoEXT = DEBUG_MARKER_OBJECT_NAME_INFO_EXT {
+        p_next: next,
+        p_object_name: str,
+    },
+    DebugMarkerObjectTagInfoEXT = DEBUG_MARKER_OBJECT_TAG_INFO_EXT {
+        p_next: next,
+        p_tag: bytes[this.tag_size],
+    },
+    DebugMarkerMarkerInfoEXT = DEBUG_MARKER_MARKER_INFO_EXT {
+        p_next: next,
+        p_marker_name: str,
+    },
+    DedicatedAllocationImageCreateInfoNV = DEDICATED_ALLOCATION_IMAGE_CREATE_INFO_NV {
+        p_next: next,
+    },
+    DedicatedAllocationBufferCreateInfoNV = DEDICATED_ALLOCATION_BUFFER_CREATE_INFO_NV {
+        p_next: next,
+    },
+    DedicatedAllocationMemoryAllocateInfoNV = DEDICATED_ALLOCATION_MEMORY_ALLOCATE_INFO_NV {
+        p_next: next,
+        image: handle,
+        buffer: handle,
+    },
+    ExternalMemoryImageCreateInfoNV = EXTERNAL_MEMORY_IMAGE_CREATE_INFO_NV {
+        p_next: next,
+    },
+    ExportMemoryAllocateInfoNV = EXPORT_MEMORY_ALLOCATE_INFO_NV {
+        p_next: next,
+    },
+    ImportMemoryWin32HandleInfoNV = IMPORT_MEMORY_WIN32_HANDLE_INFO_NV {
+        p_next: next,
+    },
+    ExportMemoryWin32HandleInfoNV = EXPORT_MEMORY_WIN32_HANDLE_INFO_NV {
+        p_next: next,
+        p_attributes: ptr,
+    },
+    Win32KeyedMutexAcquireReleaseInfoNV = WIN32_KEYED_MUTEX_ACQUIRE_RELEASE_INFO_NV {
+        p_next: next,
+        p_acquire_syncs: handles[this.acquire_count],
+        p_acquire_keys: ptr[this.acquire_count],
+        p_acquire_timeout_milliseconds: ptr[this.acquire_count],
+        p_release_syncs: handles[this.release_count],
+        p_release_keys: ptr[this.release_count],
+    },
+    PhysicalDeviceDeviceGeneratedCommandsFeaturesNV = PHYSICAL_DEVICE_DEVICE_GENERATED_COMMANDS_FEATURES_NV {
+        p_next: next,
+    },
+    DevicePrivateDataCreateInfo = DEVICE_PRIVATE_DATA_CREATE_INFO {
+        p_next: next,
+    },
+    PrivateDataSlotCreateInfo = PRIVATE_DATA_SLOT_CREATE_INFO {
+        p_next: next,
+    },
+    PhysicalDevicePrivateDataFeatures = PHYSICAL_DEVICE_PRIVATE_DATA_FEATURES {
+        p_next: next,
+    },
+    PhysicalDeviceDeviceGeneratedCommandsPropertiesNV = PHYSICAL_DEVICE_DEVICE_GENERATED_COMMANDS_PROPERTIES_NV {
+        p_next: next,
+    },
+    PhysicalDeviceMultiDrawPropertiesEXT = PHYSICAL_DEVICE_MULTI_DRAW_PROPERTIES_EXT {
+        p_next: next,
+    },
+    GraphicsShaderGroupCreateInfoNV = GRAPHICS_SHADER_GROUP_CREATE_INFO_NV {
+        p_next: next,
+        p_stages: structs[this.stage_count],
+        p_vertex_input_state: structs,
+        p_tessellation_state: structs,
+    },
+    GraphicsPipelineShaderGroupsCreateInfoNV = GRAPHICS_PIPELINE_SHADER_GROUPS_CREATE_INFO_NV {
+        p_next: next,
+        p_groups: structs[this.group_count],
+        p_pipelines: handles[this.pipeline_count],
+    },
+    IndirectCommandsStreamNV {
+        buffer: handle,
+    },
+    IndirectCommandsLayoutTokenNV = INDIRECT_COMMANDS_LAYOUT_TOKEN_NV {
+        p_next: next,
+        pushconstant_pipeline_layout: handle,
+        p_index_types: ptr[this.index_type_count],
+        p_index_type_values: ptr[this.index_type_count],
+    },
+    IndirectCommandsLayoutCreateInfoNV = INDIRECT_COMMANDS_LAYOUT_CREATE_INFO_NV {
+        p_next: next,
+        p_tokens: structs[this.token_count],
+        p_stream_strides: ptr[this.stream_count],
+    },
+    GeneratedCommandsInfoNV = GENERATED_COMMANDS_INFO_NV {
+        p_next: next,
+        pipeline: handle,
+        indirect_commands_layout: handle,
+        p_streams: structs[this.stream_count],
+        preprocess_buffer: handle,
+        sequences_count_buffer: handle,
+        sequences_index_buffer: handle,
+    },
+    GeneratedCommandsMemoryRequirementsInfoNV = GENERATED_COMMANDS_MEMORY_REQUIREMENTS_INFO_NV {
+        p_next: next,
+        pipeline: handle,
+        indirect_commands_layout: handle,
+    },
+    PhysicalDeviceFeatures2 = PHYSICAL_DEVICE_FEATURES_2 {
+        p_next: next,
+    },
+    PhysicalDeviceProperties2 = PHYSICAL_DEVICE_PROPERTIES_2 {
+        p_next: next,
+    },
+    FormatProperties2 = FORMAT_PROPERTIES_2 {
+        p_next: next,
+    },
+    ImageFormatProperties2 = IMAGE_FORMAT_PROPERTIES_2 {
+        p_next: next,
+    },
+    PhysicalDeviceImageFormatInfo2 = PHYSICAL_DEVICE_IMAGE_FORMAT_INFO_2 {
+        p_next: next,
+    },
+    QueueFamilyProperties2 = QUEUE_FAMILY_PROPERTIES_2 {
+        p_next: next,
+    },
+    PhysicalDeviceMemoryProperties2 = PHYSICAL_DEVICE_MEMORY_PROPERTIES_2 {
+        p_next: next,
+    },
+    SparseImageFormatProperties2 = SPARSE_IMAGE_FORMAT_PROPERTIES_2 {
+        p_next: next,
+    },
+    PhysicalDeviceSparseImageFormatInfo2 = PHYSICAL_DEVICE_SPARSE_IMAGE_FORMAT_INFO_2 {
+        p_next: next,
+    },
+    PhysicalDevicePushDescriptorPropertiesKHR = PHYSICAL_DEVICE_PUSH_DESCRIPTOR_PROPERTIES_KHR {
+        p_next: next,
+    },
+    PhysicalDeviceDriverProperties = PHYSICAL_DEVICE_DRIVER_PROPERTIES {
+        p_next: next,
+    },
+    PresentRegionsKHR = PRESENT_REGIONS_KHR {
+        p_next: next,
+        p_regions: structs[this.swapchain_count],
+    },
+    PresentRegionKHR {
+        p_rectangles: ptr[this.rectangle_count],
+    },
+    PhysicalDeviceVariablePointersFeatures = PHYSICAL_DEVICE_VARIABLE_POINTERS_FEATURES {
+        p_next: next,
+    },
+    PhysicalDeviceExternalImageFormatInfo = PHYSICAL_DEVICE_EXTERNAL_IMAGE_FORMAT_INFO {
+        p_next: next,
+    },
+    ExternalImageFormatProperties = EXTERNAL_IMAGE_FORMAT_PROPERTIES {
+        p_next: next,
+    },
+    PhysicalDeviceExternalBufferInfo = PHYSICAL_DEVICE_EXTERNAL_BUFFER_INFO {
+        p_next: next,
+    },
+    ExternalBufferProperties = EXTERNAL_BUFFER_PROPERTIES {
+        p_next: next,
+    },
+    PhysicalDeviceIDProperties = PHYSICAL_DEVICE_ID_PROPERTIES {
+        p_next: next,
+    },
+    ExternalMemoryImageCreateInfo = EXTERNAL_MEMORY_IMAGE_CREATE_INFO {
+        p_next: next,
+    },
+    ExternalMemoryBufferCreateInfo = EXTERNAL_MEMORY_BUFFER_CREATE_INFO {
+        p_next: next,
+    },
+    ExportMemoryAllocateInfo = EXPORT_MEMORY_ALLOCATE_INFO {
+        p_next: next,
+    },
+    ImportMemoryWin32HandleInfoKHR = IMPORT_MEMORY_WIN32_HANDLE_INFO_KHR {
+        p_next: next,
+    },
+    ExportMemoryWin32HandleInfoKHR = EXPORT_MEMORY_WIN32_HANDLE_INFO_KHR {
+        p_next: next,
+        p_attributes: ptr,
+    },
+    ImportMemoryZirconHandleInfoFUCHSIA = IMPORT_MEMORY_ZIRCON_HANDLE_INFO_FUCHSIA {
+        p_next: next,
+    },
+    MemoryZirconHandlePropertiesFUCHSIA = MEMORY_ZIRCON_HANDLE_PROPERTIES_FUCHSIA {
+        p_next: next,
+    },
+    MemoryGetZirconHandleInfoFUCHSIA = MEMORY_GET_ZIRCON_HANDLE_INFO_FUCHSIA {
+        p_next: next,
+        memory: handle,
+    },
+    MemoryWin32HandlePropertiesKHR = MEMORY_WIN32_HANDLE_PROPERTIES_KHR {
+        p_next: next,
+    },
+    MemoryGetWin32HandleInfoKHR = MEMORY_GET_WIN32_HANDLE_INFO_KHR {
+        p_next: next,
+        memory: handle,
+    },
+    ImportMemoryFdInfoKHR = IMPORT_MEMORY_FD_INFO_KHR {
+        p_next: next,
+    },
+    MemoryFdPropertiesKHR = MEMORY_FD_PROPERTIES_KHR {
+        p_next: next,
+    },
+    MemoryGetFdInfoKHR = MEMORY_GET_FD_INFO_KHR {
+        p_next: next,
+        memory: handle,
+    },
+    Win32KeyedMutexAcquireReleaseInfoKHR = WIN32_KEYED_MUTEX_ACQUIRE_RELEASE_INFO_KHR {
+        p_next: next,
+        p_acquire_syncs: handles[this.acquire_count],
+        p_acquire_keys: ptr[this.acquire_count],
+        p_acquire_timeouts: ptr[this.acquire_count],
+        p_release_syncs: handles[this.release_count],
+        p_release_keys: ptr[this.release_count],
+    },
+    PhysicalDeviceExternalSemaphoreInfo = PHYSICAL_DEVICE_EXTERNAL_SEMAPHORE_INFO {
+        p_next: next,
+    },
+    ExternalSemaphoreProperties = EXTERNAL_SEMAPHORE_PROPERTIES {
+        p_next: next,
+    },
+    ExportSemaphoreCreateInfo = EXPORT_SEMAPHORE_CREATE_INFO {
+        p_next: next,
+    },
+    ImportSemaphoreWin32HandleInfoKHR = IMPORT_SEMAPHORE_WIN32_HANDLE_INFO_KHR {
+        p_next: next,
+        semaphore: handle,
+    },
+    ExportSemaphoreWin32HandleInfoKHR = EXPORT_SEMAPHORE_WIN32_HANDLE_INFO_KHR {
+        p_next: next,
+        p_attributes: ptr,
+    },
+    D3D12FenceSubmitInfoKHR = D3D12_FENCE_SUBMIT_INFO_KHR {
+        p_next: next,
+        p_wait_semaphore_values: ptr[this.wait_semaphore_values_count],
+        p_signal_semaphore_values: ptr[this.signal_semaphore_values_count],
+    },
+    SemaphoreGetWin32HandleInfoKHR = SEMAPHORE_GET_WIN32_HANDLE_INFO_KHR {
+        p_next: next,
+        semaphore: handle,
+    },
+    ImportSemaphoreFdInfoKHR = IMPORT_SEMAPHORE_FD_INFO_KHR {
+        p_next: next,
+        semaphore: handle,
+    },
+    SemaphoreGetFdInfoKHR = SEMAPHORE_GET_FD_INFO_KHR {
+        p_next: next,
+        semaphore: handle,
+    },
+    ImportSemaphoreZirconHandleInfoFUCHSIA = IMPORT_SEMAPHORE_ZIRCON_HANDLE_INFO_FUCHSIA {
+        p_next: next,
+        semaphore: handle,
+    },
+    SemaphoreGetZirconHandleInfoFUCHSIA = SEMAPHORE_GET_ZIRCON_HANDLE_INFO_FUCHSIA {
+        p_next: next,
+        semaphore: handle,
+    },
+    PhysicalDeviceExternalFenceInfo = PHYSICAL_DEVICE_EXTERNAL_FENCE_INFO {
+        p_next: next,
+    },
+    ExternalFenceProperties = EXTERNAL_FENCE_PROPERTIES {
+        p_next: next,
+    },
+    ExportFenceCreateInfo = EXPORT_FENCE_CREATE_INFO {
+        p_next: next,
+    },
+    ImportFenceWin32HandleInfoKHR = IMPORT_FENCE_WIN32_HANDLE_INFO_KHR {
+        p_next: next,
+        fence: handle,
+    },
+    ExportFenceWin32HandleInfoKHR = EXPORT_FENCE_WIN32_HANDLE_INFO_KHR {
+        p_next: next,
+        p_attributes: ptr,
+    },
+    FenceGetWin32HandleInfoKHR = FENCE_GET_WIN32_HANDLE_INFO_KHR {
+        p_next: next,
+        fence: handle,
+    },
+    ImportFenceFdInfoKHR = IMPORT_FENCE_FD_INFO_KHR {
+        p_next: next,
+        fence: handle,
+    },
+    FenceGetFdInfoKHR = FENCE_GET_FD_INFO_KHR {
+        p_next: next,
+        fence: handle,
+    },
+    PhysicalDeviceMultiviewFeatures = PHYSICAL_DEVICE_MULTIVIEW_FEATURES {
+        p_next: next,
+    },
+    PhysicalDeviceMultiviewProperties = PHYSICAL_DEVICE_MULTIVIEW_PROPERTIES {
+        p_next: next,
+    },
+    RenderPassMultiviewCreateInfo = RENDER_PASS_MULTIVIEW_CREATE_INFO {
+        p_next: next,
+        p_view_masks: ptr[this.subpass_count],
+        p_view_offsets: ptr[this.dependency_count],
+        p_correlation_masks: ptr[this.correlation_mask_count],
+    },
+    SurfaceCapabilities2EXT = SURFACE_CAPABILITIES_2_EXT {
+        p_next: next,
+    },
+    DisplayPowerInfoEXT = DISPLAY_POWER_INFO_EXT {
+        p_next: next,
+    },
+    DeviceEventInfoEXT = DEVICE_EVENT_INFO_EXT {
+        p_next: next,
+    },
+    DisplayEventInfoEXT = DISPLAY_EVENT_INFO_EXT {
+        p_next: next,
+    },
+    SwapchainCounterCreateInfoEXT = SWAPCHAIN_COUNTER_CREATE_INFO_EXT {
+        p_next: next,
+    },
+    PhysicalDeviceGroupProperties = PHYSICAL_DEVICE_GROUP_PROPERTIES {
+        p_next: next,
+    },
+    MemoryAllocateFlagsInfo = MEMORY_ALLOCATE_FLAGS_INFO {
+        p_next: next,
+    },
+    BindBufferMemoryInfo = BIND_BUFFER_MEMORY_INFO {
+        p_next: next,
+        buffer: handle,
+        memory: handle,
+    },
+    BindBufferMemoryDeviceGroupInfo = BIND_BUFFER_MEMORY_DEVICE_GROUP_INFO {
+        p_next: next,
+        p_device_indices: ptr[this.device_index_count],
+    },
+    BindImageMemoryInfo = BIND_IMAGE_MEMORY_INFO {
+        p_next: next,
+        image: handle,
+        memory: handle,
+    },
+    BindImageMemoryDeviceGroupInfo = BIND_IMAGE_MEMORY_DEVICE_GROUP_INFO {
+        p_next: next,
+        p_device_indices: ptr[this.device_index_count],
+        p_split_instance_bind_regions: ptr[this.split_instance_bind_region_count],
+    },
+    DeviceGroupRenderPassBeginInfo = DEVICE_GROUP_RENDER_PASS_BEGIN_INFO {
+        p_next: next,
+        p_device_render_areas: ptr[this.device_render_area_count],
+    },
+    DeviceGroupCommandBufferBeginInfo = DEVICE_GROUP_COMMAND_BUFFER_BEGIN_INFO {
+        p_next: next,
+    },
+    DeviceGroupSubmitInfo = DEVICE_GROUP_SUBMIT_INFO {
+        p_next: next,
+        p_wait_semaphore_device_indices: ptr[this.wait_semaphore_count],
+        p_command_buffer_device_masks: ptr[this.command_buffer_count],
+        p_signal_semaphore_device_indices: ptr[this.signal_semaphore_count],
+    },
+    DeviceGroupBindSparseInfo = DEVICE_GROUP_BIND_SPARSE_INFO {
+        p_next: next,
+    },
+    DeviceGroupPresentCapabilitiesKHR = DEVICE_GROUP_PRESENT_CAPABILITIES_KHR {
+        p_next: next,
+    },
+    ImageSwapchainCreateInfoKHR = IMAGE_SWAPCHAIN_CREATE_INFO_KHR {
+        p_next: next,
+        swapchain: handle,
+    },
+    BindImageMemorySwapchainInfoKHR = BIND_IMAGE_MEMORY_SWAPCHAIN_INFO_KHR {
+        p_next: next,
+        swapchain: handle,
+    },
+    AcquireNextImageInfoKHR = ACQUIRE_NEXT_IMAGE_INFO_KHR {
+        p_next: next,
+        swapchain: handle,
+        semaphore: handle,
+        fence: handle,
+    },
+    DeviceGroupPresentInfoKHR = DEVICE_GROUP_PRESENT_INFO_KHR {
+        p_next: next,
+        p_device_masks: ptr[this.swapchain_count],
+    },
+    DeviceGroupDeviceCreateInfo = DEVICE_GROUP_DEVICE_CREATE_INFO {
+        p_next: next,
+        p_physical_devices: handles[this.physical_device_count],
+    },
+    DeviceGroupSwapchainCreateInfoKHR = DEVICE_GROUP_SWAPCHAIN_CREATE_INFO_KHR {
+        p_next: next,
+    },
+    DescriptorUpdateTemplateCreateInfo = DESCRIPTOR_UPDATE_TEMPLATE_CREATE_INFO {
+        p_next: next,
+        p_descriptor_update_entries: ptr[this.descriptor_update_entry_count],
+        descriptor_set_layout: handle,
+        pipeline_layout: handle,
+    },
+    PhysicalDevicePresentIdFeaturesKHR = PHYSICAL_DEVICE_PRESENT_ID_FEATURES_KHR {
+        p_next: next,
+    },
+    PresentIdKHR = PRESENT_ID_KHR {
+        p_next: next,
+        p_present_ids: ptr[this.swapchain_count],
+    },
+    PhysicalDevicePresentWaitFeaturesKHR = PHYSICAL_DEVICE_PRESENT_WAIT_FEATURES_KHR {
+        p_next: next,
+    },
+    HdrMetadataEXT = HDR_METADATA_EXT {
+        p_next: next,
+    },
+    DisplayNativeHdrSurfaceCapabilitiesAMD = DISPLAY_NATIVE_HDR_SURFACE_CAPABILITIES_AMD {
+        p_next: next,
+    },
+    SwapchainDisplayNativeHdrCreateInfoAMD = SWAPCHAIN_DISPLAY_NATIVE_HDR_CREATE_INFO_AMD {
+        p_next: next,
+    },
+    PresentTimesInfoGOOGLE = PRESENT_TIMES_INFO_GOOGLE {
+        p_next: next,
+        p_times: ptr[this.swapchain_count],
+    },
+    IOSSurfaceCreateInfoMVK = IOS_SURFACE_CREATE_INFO_MVK {
+        p_next: next,
+        p_view: opaque,
+    },
+    MacOSSurfaceCreateInfoMVK = MACOS_SURFACE_CREATE_INFO_MVK {
+        p_next: next,
+        p_view: opaque,
+    },
+    MetalSurfaceCreateInfoEXT = METAL_SURFACE_CREATE_INFO_EXT {
+        p_next: next,
+        p_layer: opaque,
+    },
+    PipelineViewportWScalingStateCreateInfoNV = PIPELINE_VIEWPORT_W_SCALING_STATE_CREATE_INFO_NV {
+        p_next: next,
+        p_viewport_w_scalings: ptr[this.viewport_count],
+    },
+    PipelineViewportSwizzleStateCreateInfoNV = PIPELINE_VIEWPORT_SWIZZLE_STATE_CREATE_INFO_NV {
+        p_next: next,
+        p_viewport_swizzles: ptr[this.viewport_count],
+    },
+    PhysicalDeviceDiscardRectanglePropertiesEXT = PHYSICAL_DEVICE_DISCARD_RECTANGLE_PROPERTIES_EXT {
+        p_next: next,
+    },
+    PipelineDiscardRectangleStateCreateInfoEXT = PIPELINE_DISCARD_RECTANGLE_STATE_CREATE_INFO_EXT {
+        p_next: next,
+        p_discard_rectangles: ptr[this.discard_rectangle_count],
+    },
+    PhysicalDeviceMultiviewPerViewAttributesPropertiesNVX = PHYSICAL_DEVICE_MULTIVIEW_PER_VIEW_ATTRIBUTES_PROPERTIES_NVX {
+        p_next: next,
+    },
+    RenderPassInputAttachmentAspectCreateInfo = RENDER_PASS_INPUT_ATTACHMENT_ASPECT_CREATE_INFO {
+        p_next: next,
+        p_aspect_references: ptr[this.aspect_reference_count],
+    },
+    PhysicalDeviceSurfaceInfo2KHR = PHYSICAL_DEVICE_SURFACE_INFO_2_KHR {
+        p_next: next,
+        surface: handle,
+    },
+    SurfaceCapabilities2KHR = SURFACE_CAPABILITIES_2_KHR {
+        p_next: next,
+    },
+    SurfaceFormat2KHR = SURFACE_FORMAT_2_KHR {
+        p_next: next,
+    },
+    DisplayProperties2KHR = DISPLAY_PROPERTIES_2_KHR {
+        p_next: next,
+        display_properties: struct,
+    },
+    DisplayPlaneProperties2KHR = DISPLAY_PLANE_PROPERTIES_2_KHR {
+        p_next: next,
+        display_plane_properties: struct,
+    },
+    DisplayModeProperties2KHR = DISPLAY_MODE_PROPERTIES_2_KHR {
+        p_next: next,
+        display_mode_properties: struct,
+    },
+    DisplayPlaneInfo2KHR = DISPLAY_PLANE_INFO_2_KHR {
+        p_next: next,
+        mode: handle,
+    },
+    DisplayPlaneCapabilities2KHR = DISPLAY_PLANE_CAPABILITIES_2_KHR {
+        p_next: next,
+    },
+    SharedPresentSurfaceCapabilitiesKHR = SHARED_PRESENT_SURFACE_CAPABILITIES_KHR {
+        p_next: next,
+    },
+    PhysicalDevice16BitStorageFeatures = PHYSICAL_DEVICE_16BIT_STORAGE_FEATURES {
+        p_next: next,
+    },
+    PhysicalDeviceSubgroupProperties = PHYSICAL_DEVICE_SUBGROUP_PROPERTIES {
+        p_next: next,
+    },
+    PhysicalDeviceShaderSubgroupExtendedTypesFeatures = PHYSICAL_DEVICE_SHADER_SUBGROUP_EXTENDED_TYPES_FEATURES {
+        p_next: next,
+    },
+    BufferMemoryRequirementsInfo2 = BUFFER_MEMORY_REQUIREMENTS_INFO_2 {
+        p_next: next,
+        buffer: handle,
+    },
+    DeviceBufferMemoryRequirements = DEVICE_BUFFER_MEMORY_REQUIREMENTS {
+        p_next: next,
+        p_create_info: structs,
+    },
+    ImageMemoryRequirementsInfo2 = IMAGE_MEMORY_REQUIREMENTS_INFO_2 {
+        p_next: next,
+        image: handle,
+    },
+    ImageSparseMemoryRequirementsInfo2 = IMAGE_SPARSE_MEMORY_REQUIREMENTS_INFO_2 {
+        p_next: next,
+        image: handle,
+    },
+    DeviceImageMemoryRequirements = DEVICE_IMAGE_MEMORY_REQUIREMENTS {
+        p_next: next,
+        p_create_info: structs,
+    },
+    MemoryRequirements2 = MEMORY_REQUIREMENTS_2 {
+        p_next: next,
+    },
+    SparseImageMemoryRequirements2 = SPARSE_IMAGE_MEMORY_REQUIREMENTS_2 {
+        p_next: next,
+    },
+    PhysicalDevicePointClippingProperties = PHYSICAL_DEVICE_POINT_CLIPPING_PROPERTIES {
+        p_next: next,
+    },
+    MemoryDedicatedRequirements = MEMORY_DEDICATED_REQUIREMENTS {
+        p_next: next,
+    },
+    MemoryDedicatedAllocateInfo = MEMORY_DEDICATED_ALLOCATE_INFO {
+        p_next: next,
+        image: handle,
+        buffer: handle,
+    },
+    ImageViewUsageCreateInfo = IMAGE_VIEW_USAGE_CREATE_INFO {
+        p_next: next,
+    },
+    PipelineTessellationDomainOriginStateCreateInfo = PIPELINE_TESSELLATION_DOMAIN_ORIGIN_STATE_CREATE_INFO {
+        p_next: next,
+    },
+    SamplerYcbcrConversionInfo = SAMPLER_YCBCR_CONVERSION_INFO {
+        p_next: next,
+        conversion: handle,
+    },
+    SamplerYcbcrConversionCreateInfo = SAMPLER_YCBCR_CONVERSION_CREATE_INFO {
+        p_next: next,
+    },
+    BindImagePlaneMemoryInfo = BIND_IMAGE_PLANE_MEMORY_INFO {
+        p_next: next,
+    },
+    ImagePlaneMemoryRequirementsInfo = IMAGE_PLANE_MEMORY_REQUIREMENTS_INFO {
+        p_next: next,
+    },
+    PhysicalDeviceSamplerYcbcrConversionFeatures = PHYSICAL_DEVICE_SAMPLER_YCBCR_CONVERSION_FEATURES {
+        p_next: next,
+    },
+    SamplerYcbcrConversionImageFormatProperties = SAMPLER_YCBCR_CONVERSION_IMAGE_FORMAT_PROPERTIES {
+        p_next: next,
+    },
+    TextureLODGatherFormatPropertiesAMD = TEXTURE_LOD_GATHER_FORMAT_PROPERTIES_AMD {
+        p_next: next,
+    },
+    ConditionalRenderingBeginInfoEXT = CONDITIONAL_RENDERING_BEGIN_INFO_EXT {
+        p_next: next,
+        buffer: handle,
+    },
+    ProtectedSubmitInfo = PROTECTED_SUBMIT_INFO {
+        p_next: next,
+    },
+    PhysicalDeviceProtectedMemoryFeatures = PHYSICAL_DEVICE_PROTECTED_MEMORY_FEATURES {
+        p_next: next,
+    },
+    PhysicalDeviceProtectedMemoryProperties = PHYSICAL_DEVICE_PROTECTED_MEMORY_PROPERTIES {
+        p_next: next,
+    },
+    DeviceQueueInfo2 = DEVICE_QUEUE_INFO_2 {
+        p_next: next,
+    },
+    PipelineCoverageToColorStateCreateInfoNV = PIPELINE_COVERAGE_TO_COLOR_STATE_CREATE_INFO_NV {
+        p_next: next,
+    },
+    PhysicalDeviceSamplerFilterMinmaxProperties = PHYSICAL_DEVICE_SAMPLER_FILTER_MINMAX_PROPERTIES {
+        p_next: next,
+    },
+    SampleLocationsInfoEXT = SAMPLE_LOCATIONS_INFO_EXT {
+        p_next: next,
+        p_sample_locations: ptr[this.sample_locations_count],
+    },
+    AttachmentSampleLocationsEXT {
+        sample_locations_info: struct,
+    },
+    SubpassSampleLocationsEXT {
+        sample_locations_info: struct,
+    },
+    RenderPassSampleLocationsBeginInfoEXT = RENDER_PASS_SAMPLE_LOCATIONS_BEGIN_INFO_EXT {
+        p_next: next,
+        p_attachment_initial_sample_locations: structs[this.attachment_initial_sample_locations_count],
+        p_post_subpass_sample_locations: structs[this.post_subpass_sample_locations_count],
+    },
+    PipelineSampleLocationsStateCreateInfoEXT = PIPELINE_SAMPLE_LOCATIONS_STATE_CREATE_INFO_EXT {
+        p_next: next,
+        sample_locations_info: struct,
+    },
+    PhysicalDeviceSampleLocationsPropertiesEXT = PHYSICAL_DEVICE_SAMPLE_LOCATIONS_PROPERTIES_EXT {
+        p_next: next,
+    },
+    MultisamplePropertiesEXT = MULTISAMPLE_PROPERTIES_EXT {
+        p_next: next,
+    },
+    SamplerReductionModeCreateInfo = SAMPLER_REDUCTION_MODE_CREATE_INFO {
+        p_next: next,
+    },
+    PhysicalDeviceBlendOperationAdvancedFeaturesEXT = PHYSICAL_DEVICE_BLEND_OPERATION_ADVANCED_FEATURES_EXT {
+        p_next: next,
+    },
+    PhysicalDeviceMultiDrawFeaturesEXT = PHYSICAL_DEVICE_MULTI_DRAW_FEATURES_EXT {
+        p_next: next,
+    },
+    PhysicalDeviceBlendOperationAdvancedPropertiesEXT = PHYSICAL_DEVICE_BLEND_OPERATION_ADVANCED_PROPERTIES_EXT {
+        p_next: next,
+    },
+    PipelineColorBlendAdvancedStateCreateInfoEXT = PIPELINE_COLOR_BLEND_ADVANCED_STATE_CREATE_INFO_EXT {
+        p_next: next,
+    },
+    PhysicalDeviceInlineUniformBlockFeatures = PHYSICAL_DEVICE_INLINE_UNIFORM_BLOCK_FEATURES {
+        p_next: next,
+    },
+    PhysicalDeviceInlineUniformBlockProperties = PHYSICAL_DEVICE_INLINE_UNIFORM_BLOCK_PROPERTIES {
+        p_next: next,
+    },
+    WriteDescriptorSetInlineUniformBlock = WRITE_DESCRIPTOR_SET_INLINE_UNIFORM_BLOCK {
+        p_next: next,
+        p_data: bytes[this.data_size],
+    },
+    DescriptorPoolInlineUniformBlockCreateInfo = DESCRIPTOR_POOL_INLINE_UNIFORM_BLOCK_CREATE_INFO {
+        p_next: next,
+    },
+    PipelineCoverageModulationStateCreateInfoNV = PIPELINE_COVERAGE_MODULATION_STATE_CREATE_INFO_NV {
+        p_next: next,
+        p_coverage_modulation_table: ptr[this.coverage_modulation_table_count],
+    },
+    ImageFormatListCreateInfo = IMAGE_FORMAT_LIST_CREATE_INFO {
+        p_next: next,
+        p_view_formats: ptr[this.view_format_count],
+    },
+    ValidationCacheCreateInfoEXT = VALIDATION_CACHE_CREATE_INFO_EXT {
+        p_next: next,
+        p_initial_data: bytes[this.initial_data_size],
+    },
+    ShaderModuleValidationCacheCreateInfoEXT = SHADER_MODULE_VALIDATION_CACHE_CREATE_INFO_EXT {
+        p_next: next,
+        validation_cache: handle,
+    },
+    PhysicalDeviceMaintenance3Properties = PHYSICAL_DEVICE_MAINTENANCE_3_PROPERTIES {
+        p_next: next,
+    },
+    PhysicalDeviceMaintenance4Features = PHYSICAL_DEVICE_MAINTENANCE_4_FEATURES {
+        p_next: next,
+    },
+    PhysicalDeviceMaintenance4Properties = PHYSICAL_DEVICE_MAINTENANCE_4_PROPERTIES {
+        p_next: next,
+    },
+    DescriptorSetLayoutSupport = DESCRIPTOR_SET_LAYOUT_SUPPORT {
+        p_next: next,
+    },
+    PhysicalDeviceShaderDrawParametersFeatures = PHYSICAL_DEVICE_SHADER_DRAW_PARAMETERS_FEATURES {
+        p_next: next,
+    },
+    PhysicalDeviceShaderFloat16Int8Features = PHYSICAL_DEVICE_SHADER_FLOAT16_INT8_FEATURES {
+        p_next: next,
+    },
+    PhysicalDeviceFloatControlsProperties = PHYSICAL_DEVICE_FLOAT_CONTROLS_PROPERTIES {
+        p_next: next,
+    },
+    PhysicalDeviceHostQueryResetFeatures = PHYSICAL_DEVICE_HOST_QUERY_RESET_FEATURES {
+        p_next: next,
+    },
+    NativeBufferANDROID = NATIVE_BUFFER_ANDROID {
+        p_next: next,
+        handle: opaque,
+    },
+    SwapchainImageCreateInfoANDROID = SWAPCHAIN_IMAGE_CREATE_INFO_ANDROID {
+        p_next: next,
+    },
+    PhysicalDevicePresentationPropertiesANDROID = PHYSICAL_DEVICE_PRESENTATION_PROPERTIES_ANDROID {
+        p_next: next,
+    },
+    DeviceQueueGlobalPriorityCreateInfoKHR = DEVICE_QUEUE_GLOBAL_PRIORITY_CREATE_INFO_KHR {
+        p_next: next,
+    },
+    PhysicalDeviceGlobalPriorityQueryFeaturesKHR = PHYSICAL_DEVICE_GLOBAL_PRIORITY_QUERY_FEATURES_KHR {
+        p_next: next,
+    },
+    QueueFamilyGlobalPriorityPropertiesKHR = QUEUE_FAMILY_GLOBAL_PRIORITY_PROPERTIES_KHR {
+        p_next: next,
+    },
+    DebugUtilsObjectNameInfoEXT = DEBUG_UTILS_OBJECT_NAME_INFO_EXT {
+        p_next: next,
+        p_object_name: str,
+    },
+    DebugUtilsObjectTagInfoEXT = DEBUG_UTILS_OBJECT_TAG_INFO_EXT {
+        p_next: next,
+        p_tag: bytes[this.tag_size],
+    },
+    DebugUtilsLabelEXT = DEBUG_UTILS_LABEL_EXT {
+        p_next: next,
+        p_label_name: str,
+    },
+    DebugUtilsMessengerCreateInfoEXT = DEBUG_UTILS_MESSENGER_CREATE_INFO_EXT {
+        p_next: next,
+        p_user_data: opaque,
+    },
+    DebugUtilsMessengerCallbackDataEXT = DEBUG_UTILS_MESSENGER_CALLBACK_DATA_EXT {
+        p_next: next,
+        p_message_id_name: str,
+        p_message: str,
+        p_queue_labels: structs[this.queue_label_count],
+        p_cmd_buf_labels: structs[this.cmd_buf_label_count],
+        p_objects: structs[this.object_count],
+    },
+    PhysicalDeviceDeviceMemoryReportFeaturesEXT = PHYSICAL_DEVICE_DEVICE_MEMORY_REPORT_FEATURES_EXT {
+        p_next: next,
+    },
+    DeviceDeviceMemoryReportCreateInfoEXT = DEVICE_DEVICE_MEMORY_REPORT_CREATE_INFO_EXT {
+        p_next: next,
+        p_user_data: opaque,
+    },
+    DeviceMemoryReportCallbackDataEXT = DEVICE_MEMORY_REPORT_CALLBACK_DATA_EXT {
+        p_next: next,
+    },
+    ImportMemoryHostPointerInfoEXT = IMPORT_MEMORY_HOST_POINTER_INFO_EXT {
+        p_next: next,
+        p_host_pointer: opaque,
+    },
+    MemoryHostPointerPropertiesEXT = MEMORY_HOST_POINTER_PROPERTIES_EXT {
+        p_next: next,
+    },
+    PhysicalDeviceExternalMemoryHostPropertiesEXT = PHYSICAL_DEVICE_EXTERNAL_MEMORY_HOST_PROPERTIES_EXT {
+        p_next: next,
+    },
+    PhysicalDeviceConservativeRasterizationPropertiesEXT = PHYSICAL_DEVICE_CONSERVATIVE_RASTERIZATION_PROPERTIES_EXT {
+        p_next: next,
+    },
+    CalibratedTimestampInfoEXT = CALIBRATED_TIMESTAMP_INFO_EXT {
+        p_next: next,
+    },
+    PhysicalDeviceShaderCorePropertiesAMD = PHYSICAL_DEVICE_SHADER_CORE_PROPERTIES_AMD {
+        p_next: next,
+    },
+    PhysicalDeviceShaderCoreProperties2AMD = PHYSICAL_DEVICE_SHADER_CORE_PROPERTIES_2_AMD {
+        p_next: next,
+    },
+    PipelineRasterizationConservativeStateCreateInfoEXT = PIPELINE_RASTERIZATION_CONSERVATIVE_STATE_CREATE_INFO_EXT {
+        p_next: next,
+    },
+    PhysicalDeviceDescriptorIndexingFeatures = PHYSICAL_DEVICE_DESCRIPTOR_INDEXING_FEATURES {
+        p_next: next,
+    },
+    PhysicalDeviceDescriptorIndexingProperties = PHYSICAL_DEVICE_DESCRIPTOR_INDEXING_PROPERTIES {
+        p_next: next,
+    },
+    DescriptorSetLayoutBindingFlagsCreateInfo = DESCRIPTOR_SET_LAYOUT_BINDING_FLAGS_CREATE_INFO {
+        p_next: next,
+        p_binding_flags: ptr[this.binding_count],
+    },
+    DescriptorSetVariableDescriptorCountAllocateInfo = DESCRIPTOR_SET_VARIABLE_DESCRIPTOR_COUNT_ALLOCATE_INFO {
+        p_next: next,
+        p_descriptor_counts: ptr[this.descriptor_set_count],
+    },
+    DescriptorSetVariableDescriptorCountLayoutSupport = DESCRIPTOR_SET_VARIABLE_DESCRIPTOR_COUNT_LAYOUT_SUPPORT {
+        p_next: next,
+    },
+    AttachmentDescription2 = ATTACHMENT_DESCRIPTION_2 {
+        p_next: next,
+    },
+    AttachmentReference2 = ATTACHMENT_REFERENCE_2 {
+        p_next: next,
+    },
+    SubpassDescription2 = SUBPASS_DESCRIPTION_2 {
+        p_next: next,
+        p_input_attachments: structs[this.input_attachment_count],
+        p_color_attachments: structs[this.color_attachment_count],
+        p_resolve_attachments: structs[this.color_attachment_count],
+        p_depth_stencil_attachment: structs,
+        p_preserve_attachments: ptr[this.preserve_attachment_count],
+    },
+    SubpassDependency2 = SUBPASS_DEPENDENCY_2 {
+        p_next: next,
+    },
+    RenderPassCreateInfo2 = RENDER_PASS_CREATE_INFO_2 {
+        p_next: next,
+        p_attachments: structs[this.attachment_count],
+        p_subpasses: structs[this.subpass_count],
+        p_dependencies: structs[this.dependency_count],
+        p_correlated_view_masks: ptr[this.correlated_view_mask_count],
+    },
+    SubpassBeginInfo = SUBPASS_BEGIN_INFO {
+        p_next: next,
+    },
+    SubpassEndInfo = SUBPASS_END_INFO {
+        p_next: next,
+    },
+    PhysicalDeviceTimelineSemaphoreFeatures = PHYSICAL_DEVICE_TIMELINE_SEMAPHORE_FEATURES {
+        p_next: next,
+    },
+    PhysicalDeviceTimelineSemaphoreProperties = PHYSICAL_DEVICE_TIMELINE_SEMAPHORE_PROPERTIES {
+        p_next: next,
+    },
+    SemaphoreTypeCreateInfo = SEMAPHORE_TYPE_CREATE_INFO {
+        p_next: next,
+    },
+    TimelineSemaphoreSubmitInfo = TIMELINE_SEMAPHORE_SUBMIT_INFO {
+        p_next: next,
+        p_wait_semaphore_values: ptr[this.wait_semaphore_value_count],
+        p_signal_semaphore_values: ptr[this.signal_semaphore_value_count],
+    },
+    SemaphoreWaitInfo = SEMAPHORE_WAIT_INFO {
+        p_next: next,
+        p_semaphores: handles[this.semaphore_count],
+        p_values: ptr[this.semaphore_count],
+    },
+    SemaphoreSignalInfo = SEMAPHORE_SIGNAL_INFO {
+        p_next: next,
+        semaphore: handle,
+    },
+    PipelineVertexInputDivisorStateCreateInfoEXT = PIPELINE_VERTEX_INPUT_DIVISOR_STATE_CREATE_INFO_EXT {
+        p_next: next,
+        p_vertex_binding_divisors: ptr[this.vertex_binding_divisor_count],
+    },
+    PhysicalDeviceVertexAttributeDivisorPropertiesEXT = PHYSICAL_DEVICE_VERTEX_ATTRIBUTE_DIVISOR_PROPERTIES_EXT {
+        p_next: next,
+    },
+    PhysicalDevicePCIBusInfoPropertiesEXT = PHYSICAL_DEVICE_PCI_BUS_INFO_PROPERTIES_EXT {
+        p_next: next,
+    },
+    ImportAndroidHardwareBufferInfoANDROID = IMPORT_ANDROID_HARDWARE_BUFFER_INFO_ANDROID {
+        p_next: next,
+        buffer: opaque,
+    },
+    AndroidHardwareBufferUsageANDROID = ANDROID_HARDWARE_BUFFER_USAGE_ANDROID {
+        p_next: next,
+    },
+    AndroidHardwareBufferPropertiesANDROID = ANDROID_HARDWARE_BUFFER_PROPERTIES_ANDROID {
+        p_next: next,
+    },
+    MemoryGetAndroidHardwareBufferInfoANDROID = MEMORY_GET_ANDROID_HARDWARE_BUFFER_INFO_ANDROID {
+        p_next: next,
+        memory: handle,
+    },
+    AndroidHardwareBufferFormatPropertiesANDROID = ANDROID_HARDWARE_BUFFER_FORMAT_PROPERTIES_ANDROID {
+        p_next: next,
+    },
+    CommandBufferInheritanceConditionalRenderingInfoEXT = COMMAND_BUFFER_INHERITANCE_CONDITIONAL_RENDERING_INFO_EXT {
+        p_next: next,
+    },
+    ExternalFormatANDROID = EXTERNAL_FORMAT_ANDROID {
+        p_next: next,
+    },
+    PhysicalDevice8BitStorageFeatures = PHYSICAL_DEVICE_8BIT_STORAGE_FEATURES {
+        p_next: next,
+    },
+    PhysicalDeviceConditionalRenderingFeaturesEXT = PHYSICAL_DEVICE_CONDITIONAL_RENDERING_FEATURES_EXT {
+        p_next: next,
+    },
+    PhysicalDeviceVulkanMemoryModelFeatures = PHYSICAL_DEVICE_VULKAN_MEMORY_MODEL_FEATURES {
+        p_next: next,
+    },
+    PhysicalDeviceShaderAtomicInt64Features = PHYSICAL_DEVICE_SHADER_ATOMIC_INT64_FEATURES {
+        p_next: next,
+    },
+    PhysicalDeviceShaderAtomicFloatFeaturesEXT = PHYSICAL_DEVICE_SHADER_ATOMIC_FLOAT_FEATURES_EXT {
+        p_next: next,
+    },
+    PhysicalDeviceShaderAtomicFloat2FeaturesEXT = PHYSICAL_DEVICE_SHADER_ATOMIC_FLOAT_2_FEATURES_EXT {
+        p_next: next,
+    },
+    PhysicalDeviceVertexAttributeDivisorFeaturesEXT = PHYSICAL_DEVICE_VERTEX_ATTRIBUTE_DIVISOR_FEATURES_EXT {
+        p_next: next,
+    },
+    QueueFamilyCheckpointPropertiesNV = QUEUE_FAMILY_CHECKPOINT_PROPERTIES_NV {
+        p_next: next,
+    },
+    CheckpointDataNV = CHECKPOINT_DATA_NV {
+        p_next: next,
+        p_checkpoint_marker: opaque,
+    },
+    PhysicalDeviceDepthStencilResolveProperties = PHYSICAL_DEVICE_DEPTH_STENCIL_RESOLVE_PROPERTIES {
+        p_next: next,
+    },
+    SubpassDescriptionDepthStencilResolve = SUBPASS_DESCRIPTION_DEPTH_STENCIL_RESOLVE {
+        p_next: next,
+        p_depth_stencil_resolve_attachment: structs,
+    },
+    ImageViewASTCDecodeModeEXT = IMAGE_VIEW_ASTC_DECODE_MODE_EXT {
+        p_next: next,
+    },
+    PhysicalDeviceASTCDecodeFeaturesEXT = PHYSICAL_DEVICE_ASTC_DECODE_FEATURES_EXT {
+        p_next: next,
+    },
+    PhysicalDeviceTransformFeedbackFeaturesEXT = PHYSICAL_DEVICE_TRANSFORM_FEEDBACK_FEATURES_EXT {
+        p_next: next,
+    },
+    PhysicalDeviceTransformFeedbackPropertiesEXT = PHYSICAL_DEVICE_TRANSFORM_FEEDBACK_PROPERTIES_EXT {
+        p_next: next,
+    },
+    PipelineRasterizationStateStreamCreateInfoEXT = PIPELINE_RASTERIZATION_STATE_STREAM_CREATE_INFO_EXT {
+        p_next: next,
+    },
+    PhysicalDeviceRepresentativeFragmentTestFeaturesNV = PHYSICAL_DEVICE_REPRESENTATIVE_FRAGMENT_TEST_FEATURES_NV {
+        p_next: next,
+    },
+    PipelineRepresentativeFragmentTestStateCreateInfoNV = PIPELINE_REPRESENTATIVE_FRAGMENT_TEST_STATE_CREATE_INFO_NV {
+        p_next: next,
+    },
+    PhysicalDeviceExclusiveScissorFeaturesNV = PHYSICAL_DEVICE_EXCLUSIVE_SCISSOR_FEATURES_NV {
+        p_next: next,
+    },
+    PipelineViewportExclusiveScissorStateCreateInfoNV = PIPELINE_VIEWPORT_EXCLUSIVE_SCISSOR_STATE_CREATE_INFO_NV {
+        p_next: next,
+        p_exclusive_scissors: ptr[this.exclusive_scissor_count],
+    },
+    PhysicalDeviceCornerSampledImageFeaturesNV = PHYSICAL_DEVICE_CORNER_SAMPLED_IMAGE_FEATURES_NV {
+        p_next: next,
+    },
+    PhysicalDeviceComputeShaderDerivativesFeaturesNV = PHYSICAL_DEVICE_COMPUTE_SHADER_DERIVATIVES_FEATURES_NV {
+        p_next: next,
+    },
+    PhysicalDeviceShaderImageFootprintFeaturesNV = PHYSICAL_DEVICE_SHADER_IMAGE_FOOTPRINT_FEATURES_NV {
+        p_next: next,
+    },
+    PhysicalDeviceDedicatedAllocationImageAliasingFeaturesNV = PHYSICAL_DEVICE_DEDICATED_ALLOCATION_IMAGE_ALIASING_FEATURES_NV {
+        p_next: next,
+    },
+    ShadingRatePaletteNV {
+        p_shading_rate_palette_entries: ptr[this.shading_rate_palette_entry_count],
+    },
+    PipelineViewportShadingRateImageStateCreateInfoNV = PIPELINE_VIEWPORT_SHADING_RATE_IMAGE_STATE_CREATE_INFO_NV {
+        p_next: next,
+        p_shading_rate_palettes: structs[this.viewport_count],
+    },
+    PhysicalDeviceShadingRateImageFeaturesNV = PHYSICAL_DEVICE_SHADING_RATE_IMAGE_FEATURES_NV {
+        p_next: next,
+    },
+    PhysicalDeviceShadingRateImagePropertiesNV = PHYSICAL_DEVICE_SHADING_RATE_IMAGE_PROPERTIES_NV {
+        p_next: next,
+    },
+    PhysicalDeviceInvocationMaskFeaturesHUAWEI = PHYSICAL_DEVICE_INVOCATION_MASK_FEATURES_HUAWEI {
+        p_next: next,
+    },
+    CoarseSampleOrderCustomNV {
+        p_sample_locations: ptr[this.sample_location_count],
+    },
+    PipelineViewportCoarseSampleOrderStateCreateInfoNV = PIPELINE_VIEWPORT_COARSE_SAMPLE_ORDER_STATE_CREATE_INFO_NV {
+        p_next: next,
+        p_custom_sample_orders: structs[this.custom_sample_order_count],
+    },
+    PhysicalDeviceMeshShaderFeaturesNV = PHYSICAL_DEVICE_MESH_SHADER_FEATURES_NV {
+        p_next: next,
+    },
+    PhysicalDeviceMeshShaderPropertiesNV = PHYSICAL_DEVICE_MESH_SHADER_PROPERTIES_NV {
+        p_next: next,
+    },
+    PhysicalDeviceMeshShaderFeaturesEXT = PHYSICAL_DEVICE_MESH_SHADER_FEATURES_EXT {
+        p_next: next,
+    },
+    PhysicalDeviceMeshShaderPropertiesEXT = PHYSICAL_DEVICE_MESH_SHADER_PROPERTIES_EXT {
+        p_next: next,
+    },
+    RayTracingShaderGroupCreateInfoNV = RAY_TRACING_SHADER_GROUP_CREATE_INFO_NV {
+        p_next: next,
+    },
+    RayTracingShaderGroupCreateInfoKHR = RAY_TRACING_SHADER_GROUP_CREATE_INFO_KHR {
+        p_next: next,
+        p_shader_group_capture_replay_handle: opaque,
+    },
+    RayTracingPipelineCreateInfoNV = RAY_TRACING_PIPELINE_CREATE_INFO_NV {
+        p_next: next,
+        p_stages: structs[this.stage_count],
+        p_groups: structs[this.group_count],
+        layout: handle,
+        base_pipeline_handle: handle,
+    },
+    RayTracingPipelineCreateInfoKHR = RAY_TRACING_PIPELINE_CREATE_INFO_KHR {
+        p_next: next,
+        p_stages: structs[this.stage_count],
+        p_groups: structs[this.group_count],
+        p_library_info: structs,
+        p_library_interface: structs,
+        p_dynamic_state: structs,
+        layout: handle,
+        base_pipeline_handle: handle,
+    },
+    GeometryTrianglesNV = GEOMETRY_TRIANGLES_NV {
+        p_next: next,
+        vertex_data: handle,
+        index_data: handle,
+        transform_data: handle,
+    },
+    GeometryAABBNV = GEOMETRY_AABB_NV {
+        p_next: next,
+        aabb_data: handle,
+    },
+    GeometryDataNV {
+        triangles: struct,
+        aabbs: struct,
+    },
+    GeometryNV = GEOMETRY_NV {
+        p_next: next,
+        geometry: struct,
+    },
+    AccelerationStructureInfoNV = ACCELERATION_STRUCTURE_INFO_NV {
+        p_next: next,
+        p_geometries: structs[this.geometry_count],
+    },
+    AccelerationStructureCreateInfoNV = ACCELERATION_STRUCTURE_CREATE_INFO_NV {
+        p_next: next,
+        info: struct,
+    },
+    BindAccelerationStructureMemoryInfoNV = BIND_ACCELERATION_STRUCTURE_MEMORY_INFO_NV {
+        p_next: next,
+        acceleration_structure: handle,
+        memory: handle,
+        p_device_indices: ptr[this.device_index_count],
+    },
+    WriteDescriptorSetAccelerationStructureKHR = WRITE_DESCRIPTOR_SET_ACCELERATION_STRUCTURE_KHR {
+        p_next: next,
+        p_acceleration_structures: handles[this.acceleration_structure_count],
+    },
+    WriteDescriptorSetAccelerationStructureNV = WRITE_DESCRIPTOR_SET_ACCELERATION_STRUCTURE_NV {
+        p_next: next,
+        p_acceleration_structures: handles[this.acceleration_structure_count],
+    },
+    AccelerationStructureMemoryRequirementsInfoNV = ACCELERATION_STRUCTURE_MEMORY_REQUIREMENTS_INFO_NV {
+        p_next: next,
+        acceleration_structure: handle,
+    },
+    PhysicalDeviceAccelerationStructureFeaturesKHR = PHYSICAL_DEVICE_ACCELERATION_STRUCTURE_FEATURES_KHR {
+        p_next: next,
+    },
+    PhysicalDeviceRayTracingPipelineFeaturesKHR = PHYSICAL_DEVICE_RAY_TRACING_PIPELINE_FEATURES_KHR {
+        p_next: next,
+    },
+    PhysicalDeviceRayQueryFeaturesKHR = PHYSICAL_DEVICE_RAY_QUERY_FEATURES_KHR {
+        p_next: next,
+    },
+    PhysicalDeviceAccelerationStructurePropertiesKHR = PHYSICAL_DEVICE_ACCELERATION_STRUCTURE_PROPERTIES_KHR {
+        p_next: next,
+    },
+    PhysicalDeviceRayTracingPipelinePropertiesKHR = PHYSICAL_DEVICE_RAY_TRACING_PIPELINE_PROPERTIES_KHR {
+        p_next: next,
+    },
+    PhysicalDeviceRayTracingPropertiesNV = PHYSICAL_DEVICE_RAY_TRACING_PROPERTIES_NV {
+        p_next: next,
+    },
+    PhysicalDeviceRayTracingMaintenance1FeaturesKHR = PHYSICAL_DEVICE_RAY_TRACING_MAINTENANCE_1_FEATURES_KHR {
+        p_next: next,
+    },
+    DrmFormatModifierPropertiesListEXT = DRM_FORMAT_MODIFIER_PROPERTIES_LIST_EXT {
+        p_next: next,
+        p_drm_format_modifier_properties: ptr[this.drm_format_modifier_count],
+    },
+    PhysicalDeviceImageDrmFormatModifierInfoEXT = PHYSICAL_DEVICE_IMAGE_DRM_FORMAT_MODIFIER_INFO_EXT {
+        p_next: next,
+        p_queue_family_indices: ptr[this.queue_family_index_count],
+    },
+    ImageDrmFormatModifierListCreateInfoEXT = IMAGE_DRM_FORMAT_MODIFIER_LIST_CREATE_INFO_EXT {
+        p_next: next,
+        p_drm_format_modifiers: ptr[this.drm_format_modifier_count],
+    },
+    ImageDrmFormatModifierExplicitCreateInfoEXT = IMAGE_DRM_FORMAT_MODIFIER_EXPLICIT_CREATE_INFO_EXT {
+        p_next: next,
+        p_plane_layouts: ptr[this.drm_format_modifier_plane_count],
+    },
+    ImageDrmFormatModifierPropertiesEXT = IMAGE_DRM_FORMAT_MODIFIER_PROPERTIES_EXT {
+        p_next: next,
+    },
+    ImageStencilUsageCreateInfo = IMAGE_STENCIL_USAGE_CREATE_INFO {
+        p_next: next,
+    },
+    DeviceMemoryOverallocationCreateInfoAMD = DEVICE_MEMORY_OVERALLOCATION_CREATE_INFO_AMD {
+        p_next: next,
+    },
+    PhysicalDeviceFragmentDensityMapFeaturesEXT = PHYSICAL_DEVICE_FRAGMENT_DENSITY_MAP_FEATURES_EXT {
+        p_next: next,
+    },
+    PhysicalDeviceFragmentDensityMap2FeaturesEXT = PHYSICAL_DEVICE_FRAGMENT_DENSITY_MAP_2_FEATURES_EXT {
+        p_next: next,
+    },
+    PhysicalDeviceFragmentDensityMapOffsetFeaturesQCOM = PHYSICAL_DEVICE_FRAGMENT_DENSITY_MAP_OFFSET_FEATURES_QCOM {
+        p_next: next,
+    },
+    PhysicalDeviceFragmentDensityMapPropertiesEXT = PHYSICAL_DEVICE_FRAGMENT_DENSITY_MAP_PROPERTIES_EXT {
+        p_next: next,
+    },
+    PhysicalDeviceFragmentDensityMap2PropertiesEXT = PHYSICAL_DEVICE_FRAGMENT_DENSITY_MAP_2_PROPERTIES_EXT {
+        p_next: next,
+    },
+    PhysicalDeviceFragmentDensityMapOffsetPropertiesQCOM = PHYSICAL_DEVICE_FRAGMENT_DENSITY_MAP_OFFSET_PROPERTIES_QCOM {
+        p_next: next,
+    },
+    RenderPassFragmentDensityMapCreateInfoEXT = RENDER_PASS_FRAGMENT_DENSITY_MAP_CREATE_INFO_EXT {
+        p_next: next,
+    },
+    SubpassFragmentDensityMapOffsetEndInfoQCOM = SUBPASS_FRAGMENT_DENSITY_MAP_OFFSET_END_INFO_QCOM {
+        p_next: next,
+        p_fragment_density_offsets: ptr[this.fragment_density_offset_count],
+    },
+    PhysicalDeviceScalarBlockLayoutFeatures = PHYSICAL_DEVICE_SCALAR_BLOCK_LAYOUT_FEATURES {
+        p_next: next,
+    },
+    SurfaceProtectedCapabilitiesKHR = SURFACE_PROTECTED_CAPABILITIES_KHR {
+        p_next: next,
+    },
+    PhysicalDeviceUniformBufferStandardLayoutFeatures = PHYSICAL_DEVICE_UNIFORM_BUFFER_STANDARD_LAYOUT_FEATURES {
+        p_next: next,
+    },
+    PhysicalDeviceDepthClipEnableFeaturesEXT = PHYSICAL_DEVICE_DEPTH_CLIP_ENABLE_FEATURES_EXT {
+        p_next: next,
+    },
+    PipelineRasterizationDepthClipStateCreateInfoEXT = PIPELINE_RASTERIZATION_DEPTH_CLIP_STATE_CREATE_INFO_EXT {
+        p_next: next,
+    },
+    PhysicalDeviceMemoryBudgetPropertiesEXT = PHYSICAL_DEVICE_MEMORY_BUDGET_PROPERTIES_EXT {
+        p_next: next,
+    },
+    PhysicalDeviceMemoryPriorityFeaturesEXT = PHYSICAL_DEVICE_MEMORY_PRIORITY_FEATURES_EXT {
+        p_next: next,
+    },
+    MemoryPriorityAllocateInfoEXT = MEMORY_PRIORITY_ALLOCATE_INFO_EXT {
+        p_next: next,
+    },
+    PhysicalDevicePageableDeviceLocalMemoryFeaturesEXT = PHYSICAL_DEVICE_PAGEABLE_DEVICE_LOCAL_MEMORY_FEATURES_EXT {
+        p_next: next,
+    },
+    PhysicalDeviceBufferDeviceAddressFeatures = PHYSICAL_DEVICE_BUFFER_DEVICE_ADDRESS_FEATURES {
+        p_next: next,
+    },
+    PhysicalDeviceBufferDeviceAddressFeaturesEXT = PHYSICAL_DEVICE_BUFFER_DEVICE_ADDRESS_FEATURES_EXT {
+        p_next: next,
+    },
+    BufferDeviceAddressInfo = BUFFER_DEVICE_ADDRESS_INFO {
+        p_next: next,
+        buffer: handle,
+    },
+    BufferOpaqueCaptureAddressCreateInfo = BUFFER_OPAQUE_CAPTURE_ADDRESS_CREATE_INFO {
+        p_next: next,
+    },
+    BufferDeviceAddressCreateInfoEXT = BUFFER_DEVICE_ADDRESS_CREATE_INFO_EXT {
+        p_next: next,
+    },
+    PhysicalDeviceImageViewImageFormatInfoEXT = PHYSICAL_DEVICE_IMAGE_VIEW_IMAGE_FORMAT_INFO_EXT {
+        p_next: next,
+    },
+    FilterCubicImageViewImageFormatPropertiesEXT = FILTER_CUBIC_IMAGE_VIEW_IMAGE_FORMAT_PROPERTIES_EXT {
+        p_next: next,
+    },
+    PhysicalDeviceImagelessFramebufferFeatures = PHYSICAL_DEVICE_IMAGELESS_FRAMEBUFFER_FEATURES {
+        p_next: next,
+    },
+    FramebufferAttachmentsCreateInfo = FRAMEBUFFER_ATTACHMENTS_CREATE_INFO {
+        p_next: next,
+        p_attachment_image_infos: structs[this.attachment_image_info_count],
+    },
+    FramebufferAttachmentImageInfo = FRAMEBUFFER_ATTACHMENT_IMAGE_INFO {
+        p_next: next,
+        p_view_formats: ptr[this.view_format_count],
+    },
+    RenderPassAttachmentBeginInfo = RENDER_PASS_ATTACHMENT_BEGIN_INFO {
+        p_next: next,
+        p_attachments: handles[this.attachment_count],
+    },
+    PhysicalDeviceTextureCompressionASTCHDRFeatures = PHYSICAL_DEVICE_TEXTURE_COMPRESSION_ASTC_HDR_FEATURES {
+        p_next: next,
+    },
+    PhysicalDeviceCooperativeMatrixFeaturesNV = PHYSICAL_DEVICE_COOPERATIVE_MATRIX_FEATURES_NV {
+        p_next: next,
+    },
+    PhysicalDeviceCooperativeMatrixPropertiesNV = PHYSICAL_DEVICE_COOPERATIVE_MATRIX_PROPERTIES_NV {
+        p_next: next,
+    },
+    CooperativeMatrixPropertiesNV = COOPERATIVE_MATRIX_PROPERTIES_NV {
+        p_next: next,
+    },
+    PhysicalDeviceYcbcrImageArraysFeaturesEXT = PHYSICAL_DEVICE_YCBCR_IMAGE_ARRAYS_FEATURES_EXT {
+        p_next: next,
+    },
+    ImageViewHandleInfoNVX = IMAGE_VIEW_HANDLE_INFO_NVX {
+        p_next: next,
+        image_view: handle,
+        sampler: handle,
+    },
+    ImageViewAddressPropertiesNVX = IMAGE_VIEW_ADDRESS_PROPERTIES_NVX {
+        p_next: next,
+    },
+    PresentFrameTokenGGP = PRESENT_FRAME_TOKEN_GGP {
+        p_next: next,
+    },
+    PipelineCreationFeedbackCreateInfo = PIPELINE_CREATION_FEEDBACK_CREATE_INFO {
+        p_next: next,
+        p_pipeline_creation_feedback: ptr,
+        p_pipeline_stage_creation_feedbacks: ptr[this.pipeline_stage_creation_feedback_count],
+    },
+    SurfaceFullScreenExclusiveInfoEXT = SURFACE_FULL_SCREEN_EXCLUSIVE_INFO_EXT {
+        p_next: next,
+    },
+    SurfaceFullScreenExclusiveWin32InfoEXT = SURFACE_FULL_SCREEN_EXCLUSIVE_WIN32_INFO_EXT {
+        p_next: next,
+    },
+    SurfaceCapabilitiesFullScreenExclusiveEXT = SURFACE_CAPABILITIES_FULL_SCREEN_EXCLUSIVE_EXT {
+        p_next: next,
+    },
+    PhysicalDevicePresentBarrierFeaturesNV = PHYSICAL_DEVICE_PRESENT_BARRIER_FEATURES_NV {
+        p_next: next,
+    },
+    SurfaceCapabilitiesPresentBarrierNV = SURFACE_CAPABILITIES_PRESENT_BARRIER_NV {
+        p_next: next,
+    },
+    SwapchainPresentBarrierCreateInfoNV = SWAPCHAIN_PRESENT_BARRIER_CREATE_INFO_NV {
+        p_next: next,
+    },
+    PhysicalDevicePerformanceQueryFeaturesKHR = PHYSICAL_DEVICE_PERFORMANCE_QUERY_FEATURES_KHR {
+        p_next: next,
+    },
+    PhysicalDevicePerformanceQueryPropertiesKHR = PHYSICAL_DEVICE_PERFORMANCE_QUERY_PROPERTIES_KHR {
+        p_next: next,
+    },
+    PerformanceCounterKHR = PERFORMANCE_COUNTER_KHR {
+        p_next: next,
+    },
+    PerformanceCounterDescriptionKHR = PERFORMANCE_COUNTER_DESCRIPTION_KHR {
+        p_next: next,
+    },
+    QueryPoolPerformanceCreateInfoKHR = QUERY_POOL_PERFORMANCE_CREATE_INFO_KHR {
+        p_next: next,
+        p_counter_indices: ptr[this.counter_index_count],
+    },
+    AcquireProfilingLockInfoKHR = ACQUIRE_PROFILING_LOCK_INFO_KHR {
+        p_next: next,
+    },
+    PerformanceQuerySubmitInfoKHR = PERFORMANCE_QUERY_SUBMIT_INFO_KHR {
+        p_next: next,
+    },
+    HeadlessSurfaceCreateInfoEXT = HEADLESS_SURFACE_CREATE_INFO_EXT {
+        p_next: next,
+    },
+    PhysicalDeviceCoverageReductionModeFeaturesNV = PHYSICAL_DEVICE_COVERAGE_REDUCTION_MODE_FEATURES_NV {
+        p_next: next,
+    },
+    PipelineCoverageReductionStateCreateInfoNV = PIPELINE_COVERAGE_REDUCTION_STATE_CREATE_INFO_NV {
+        p_next: next,
+    },
+    FramebufferMixedSamplesCombinationNV = FRAMEBUFFER_MIXED_SAMPLES_COMBINATION_NV {
+        p_next: next,
+    },
+    PhysicalDeviceShaderIntegerFunctions2FeaturesINTEL = PHYSICAL_DEVICE_SHADER_INTEGER_FUNCTIONS_2_FEATURES_INTEL {
+        p_next: next,
+    },
+    InitializePerformanceApiInfoINTEL = INITIALIZE_PERFORMANCE_API_INFO_INTEL {
+        p_next: next,
+        p_user_data: opaque,
+    },
+    QueryPoolPerformanceQueryCreateInfoINTEL = QUERY_POOL_PERFORMANCE_QUERY_CREATE_INFO_INTEL {
+        p_next: next,
+    },
+    PerformanceMarkerInfoINTEL = PERFORMANCE_MARKER_INFO_INTEL {
+        p_next: next,
+    },
+    PerformanceStreamMarkerInfoINTEL = PERFORMANCE_STREAM_MARKER_INFO_INTEL {
+        p_next: next,
+    },
+    PerformanceOverrideInfoINTEL = PERFORMANCE_OVERRIDE_INFO_INTEL {
+        p_next: next,
+    },
+    PerformanceConfigurationAcquireInfoINTEL = PERFORMANCE_CONFIGURATION_ACQUIRE_INFO_INTEL {
+        p_next: next,
+    },
+    PhysicalDeviceShaderClockFeaturesKHR = PHYSICAL_DEVICE_SHADER_CLOCK_FEATURES_KHR {
+        p_next: next,
+    },
+    PhysicalDeviceIndexTypeUint8FeaturesEXT = PHYSICAL_DEVICE_INDEX_TYPE_UINT8_FEATURES_EXT {
+        p_next: next,
+    },
+    PhysicalDeviceShaderSMBuiltinsPropertiesNV = PHYSICAL_DEVICE_SHADER_SM_BUILTINS_PROPERTIES_NV {
+        p_next: next,
+    },
+    PhysicalDeviceShaderSMBuiltinsFeaturesNV = PHYSICAL_DEVICE_SHADER_SM_BUILTINS_FEATURES_NV {
+        p_next: next,
+    },
+    PhysicalDeviceFragmentShaderInterlockFeaturesEXT = PHYSICAL_DEVICE_FRAGMENT_SHADER_INTERLOCK_FEATURES_EXT {
+        p_next: next,
+    },
+    PhysicalDeviceSeparateDepthStencilLayoutsFeatures = PHYSICAL_DEVICE_SEPARATE_DEPTH_STENCIL_LAYOUTS_FEATURES {
+        p_next: next,
+    },
+    AttachmentReferenceStencilLayout = ATTACHMENT_REFERENCE_STENCIL_LAYOUT {
+        p_next: next,
+    },
+    PhysicalDevicePrimitiveTopologyListRestartFeaturesEXT = PHYSICAL_DEVICE_PRIMITIVE_TOPOLOGY_LIST_RESTART_FEATURES_EXT {
+        p_next: next,
+    },
+    AttachmentDescriptionStencilLayout = ATTACHMENT_DESCRIPTION_STENCIL_LAYOUT {
+        p_next: next,
+    },
+    PhysicalDevicePipelineExecutablePropertiesFeaturesKHR = PHYSICAL_DEVICE_PIPELINE_EXECUTABLE_PROPERTIES_FEATURES_KHR {
+        p_next: next,
+    },
+    PipelineInfoKHR = PIPELINE_INFO_KHR {
+        p_next: next,
+        pipeline: handle,
+    },
+    PipelineExecutablePropertiesKHR = PIPELINE_EXECUTABLE_PROPERTIES_KHR {
+        p_next: next,
+    },
+    PipelineExecutableInfoKHR = PIPELINE_EXECUTABLE_INFO_KHR {
+        p_next: next,
+        pipeline: handle,
+    },
+    PipelineExecutableStatisticKHR = PIPELINE_EXECUTABLE_STATISTIC_KHR {
+        p_next: next,
+    },
+    PipelineExecutableInternalRepresentationKHR = PIPELINE_EXECUTABLE_INTERNAL_REPRESENTATION_KHR {
+        p_next: next,
+        p_data: bytes[this.data_size],
+    },
+    PhysicalDeviceShaderDemoteToHelperInvocationFeatures = PHYSICAL_DEVICE_SHADER_DEMOTE_TO_HELPER_INVOCATION_FEATURES {
+        p_next: next,
+    },
+    PhysicalDeviceTexelBufferAlignmentFeaturesEXT = PHYSICAL_DEVICE_TEXEL_BUFFER_ALIGNMENT_FEATURES_EXT {
+        p_next: next,
+    },
+    PhysicalDeviceTexelBufferAlignmentProperties = PHYSICAL_DEVICE_TEXEL_BUFFER_ALIGNMENT_PROPERTIES {
+        p_next: next,
+    },
+    PhysicalDeviceSubgroupSizeControlFeatures = PHYSICAL_DEVICE_SUBGROUP_SIZE_CONTROL_FEATURES {
+        p_next: next,
+    },
+    PhysicalDeviceSubgroupSizeControlProperties = PHYSICAL_DEVICE_SUBGROUP_SIZE_CONTROL_PROPERTIES {
+        p_next: next,
+    },
+    PipelineShaderStageRequiredSubgroupSizeCreateInfo = PIPELINE_SHADER_STAGE_REQUIRED_SUBGROUP_SIZE_CREATE_INFO {
+        p_next: next,
+    },
+    SubpassShadingPipelineCreateInfoHUAWEI = SUBPASS_SHADING_PIPELINE_CREATE_INFO_HUAWEI {
+        p_next: next,
+        render_pass: handle,
+    },
+    PhysicalDeviceSubpassShadingPropertiesHUAWEI = PHYSICAL_DEVICE_SUBPASS_SHADING_PROPERTIES_HUAWEI {
+        p_next: next,
+    },
+    MemoryOpaqueCaptureAddressAllocateInfo = MEMORY_OPAQUE_CAPTURE_ADDRESS_ALLOCATE_INFO {
+        p_next: next,
+    },
+    DeviceMemoryOpaqueCaptureAddressInfo = DEVICE_MEMORY_OPAQUE_CAPTURE_ADDRESS_INFO {
+        p_next: next,
+        memory: handle,
+    },
+    PhysicalDeviceLineRasterizationFeaturesEXT = PHYSICAL_DEVICE_LINE_RASTERIZATION_FEATURES_EXT {
+        p_next: next,
+    },
+    PhysicalDeviceLineRasterizationPropertiesEXT = PHYSICAL_DEVICE_LINE_RASTERIZATION_PROPERTIES_EXT {
+        p_next: next,
+    },
+    PipelineRasterizationLineStateCreateInfoEXT = PIPELINE_RASTERIZATION_LINE_STATE_CREATE_INFO_EXT {
+        p_next: next,
+    },
+    PhysicalDevicePipelineCreationCacheControlFeatures = PHYSICAL_DEVICE_PIPELINE_CREATION_CACHE_CONTROL_FEATURES {
+        p_next: next,
+    },
+    PhysicalDeviceVulkan11Features = PHYSICAL_DEVICE_VULKAN_1_1_FEATURES {
+        p_next: next,
+    },
+    PhysicalDeviceVulkan11Properties = PHYSICAL_DEVICE_VULKAN_1_1_PROPERTIES {
+        p_next: next,
+    },
+    PhysicalDeviceVulkan12Features = PHYSICAL_DEVICE_VULKAN_1_2_FEATURES {
+        p_next: next,
+    },
+    PhysicalDeviceVulkan12Properties = PHYSICAL_DEVICE_VULKAN_1_2_PROPERTIES {
+        p_next: next,
+    },
+    PhysicalDeviceVulkan13Features = PHYSICAL_DEVICE_VULKAN_1_3_FEATURES {
+        p_next: next,
+    },
+    PhysicalDeviceVulkan13Properties = PHYSICAL_DEVICE_VULKAN_1_3_PROPERTIES {
+        p_next: next,
+    },
+    PipelineCompilerControlCreateInfoAMD = PIPELINE_COMPILER_CONTROL_CREATE_INFO_AMD {
+        p_next: next,
+    },
+    PhysicalDeviceCoherentMemoryFeaturesAMD = PHYSICAL_DEVICE_COHERENT_MEMORY_FEATURES_AMD {
+        p_next: next,
+    },
+    PhysicalDeviceToolProperties = PHYSICAL_DEVICE_TOOL_PROPERTIES {
+        p_next: next,
+    },
+    SamplerCustomBorderColorCreateInfoEXT = SAMPLER_CUSTOM_BORDER_COLOR_CREATE_INFO_EXT {
+        p_next: next,
+    },
+    PhysicalDeviceCustomBorderColorPropertiesEXT = PHYSICAL_DEVICE_CUSTOM_BORDER_COLOR_PROPERTIES_EXT {
+        p_next: next,
+    },
+    PhysicalDeviceCustomBorderColorFeaturesEXT = PHYSICAL_DEVICE_CUSTOM_BORDER_COLOR_FEATURES_EXT {
+        p_next: next,
+    },
+    SamplerBorderColorComponentMappingCreateInfoEXT = SAMPLER_BORDER_COLOR_COMPONENT_MAPPING_CREATE_INFO_EXT {
+        p_next: next,
+    },
+    PhysicalDeviceBorderColorSwizzleFeaturesEXT = PHYSICAL_DEVICE_BORDER_COLOR_SWIZZLE_FEATURES_EXT {
+        p_next: next,
+    },
+    AccelerationStructureGeometryTrianglesDataKHR = ACCELERATION_STRUCTURE_GEOMETRY_TRIANGLES_DATA_KHR {
+        p_next: next,
+    },
+    AccelerationStructureGeometryAabbsDataKHR = ACCELERATION_STRUCTURE_GEOMETRY_AABBS_DATA_KHR {
+        p_next: next,
+    },
+    AccelerationStructureGeometryInstancesDataKHR = ACCELERATION_STRUCTURE_GEOMETRY_INSTANCES_DATA_KHR {
+        p_next: next,
+    },
+    AccelerationStructureGeometryKHR = ACCELERATION_STRUCTURE_GEOMETRY_KHR {
+        p_next: next,
+    },
+    AccelerationStructureBuildGeometryInfoKHR = ACCELERATION_STRUCTURE_BUILD_GEOMETRY_INFO_KHR {
+        p_next: next,
+        src_acceleration_structure: handle,
+        dst_acceleration_structure: handle,
+        p_geometries: structs[this.geometry_count],
+        pp_geometries: opaque,
+    },
+    AccelerationStructureCreateInfoKHR = ACCELERATION_STRUCTURE_CREATE_INFO_KHR {
+        p_next: next,
+        buffer: handle,
+    },
+    AccelerationStructureDeviceAddressInfoKHR = ACCELERATION_STRUCTURE_DEVICE_ADDRESS_INFO_KHR {
+        p_next: next,
+        acceleration_structure: handle,
+    },
+    AccelerationStructureVersionInfoKHR = ACCELERATION_STRUCTURE_VERSION_INFO_KHR {
+        p_next: next,
+        p_version_data: opaque,
+    },
+    CopyAccelerationStructureInfoKHR = COPY_ACCELERATION_STRUCTURE_INFO_KHR {
+        p_next: next,
+        src: handle,
+        dst: handle,
+    },
+    CopyAccelerationStructureToMemoryInfoKHR = COPY_ACCELERATION_STRUCTURE_TO_MEMORY_INFO_KHR {
+        p_next: next,
+        src: handle,
+    },
+    CopyMemoryToAccelerationStructureInfoKHR = COPY_MEMORY_TO_ACCELERATION_STRUCTURE_INFO_KHR {
+        p_next: next,
+        dst: handle,
+    },
+    RayTracingPipelineInterfaceCreateInfoKHR = RAY_TRACING_PIPELINE_INTERFACE_CREATE_INFO_KHR {
+        p_next: next,
+    },
+    PipelineLibraryCreateInfoKHR = PIPELINE_LIBRARY_CREATE_INFO_KHR {
+        p_next: next,
+        p_libraries: handles[this.library_count],
+    },
+    PhysicalDeviceExtendedDynamicStateFeaturesEXT = PHYSICAL_DEVICE_EXTENDED_DYNAMIC_STATE_FEATURES_EXT {
+        p_next: next,
+    },
+    PhysicalDeviceExtendedDynamicState2FeaturesEXT = PHYSICAL_DEVICE_EXTENDED_DYNAMIC_STATE_2_FEATURES_EXT {
+        p_next: next,
+    },
+    PhysicalDeviceExtendedDynamicState3FeaturesEXT = PHYSICAL_DEVICE_EXTENDED_DYNAMIC_STATE_3_FEATURES_EXT {
+        p_next: next,
+    },
+    PhysicalDeviceExtendedDynamicState3PropertiesEXT = PHYSICAL_DEVICE_EXTENDED_DYNAMIC_STATE_3_PROPERTIES_EXT {
+        p_next: next,
+    },
+    RenderPassTransformBeginInfoQCOM = RENDER_PASS_TRANSFORM_BEGIN_INFO_QCOM {
+        p_next: next,
+    },
+    CopyCommandTransformInfoQCOM = COPY_COMMAND_TRANSFORM_INFO_QCOM {
+        p_next: next,
+    },
+    CommandBufferInheritanceRenderPassTransformInfoQCOM = COMMAND_BUFFER_INHERITANCE_RENDER_PASS_TRANSFORM_INFO_QCOM {
+        p_next: next,
+    },
+    PhysicalDeviceDiagnosticsConfigFeaturesNV = PHYSICAL_DEVICE_DIAGNOSTICS_CONFIG_FEATURES_NV {
+        p_next: next,
+    },
+    DeviceDiagnosticsConfigCreateInfoNV = DEVICE_DIAGNOSTICS_CONFIG_CREATE_INFO_NV {
+        p_next: next,
+    },
+    PhysicalDeviceZeroInitializeWorkgroupMemoryFeatures = PHYSICAL_DEVICE_ZERO_INITIALIZE_WORKGROUP_MEMORY_FEATURES {
+        p_next: next,
+    },
+    PhysicalDeviceShaderSubgroupUniformControlFlowFeaturesKHR = PHYSICAL_DEVICE_SHADER_SUBGROUP_UNIFORM_CONTROL_FLOW_FEATURES_KHR {
+        p_next: next,
+    },
+    PhysicalDeviceRobustness2FeaturesEXT = PHYSICAL_DEVICE_ROBUSTNESS_2_FEATURES_EXT {
+        p_next: next,
+    },
+    PhysicalDeviceRobustness2PropertiesEXT = PHYSICAL_DEVICE_ROBUSTNESS_2_PROPERTIES_EXT {
+        p_next: next,
+    },
+    PhysicalDeviceImageRobustnessFeatures = PHYSICAL_DEVICE_IMAGE_ROBUSTNESS_FEATURES {
+        p_next: next,
+    },
+    PhysicalDeviceWorkgroupMemoryExplicitLayoutFeaturesKHR = PHYSICAL_DEVICE_WORKGROUP_MEMORY_EXPLICIT_LAYOUT_FEATURES_KHR {
+        p_next: next,
+    },
+    PhysicalDevicePortabilitySubsetFeaturesKHR = PHYSICAL_DEVICE_PORTABILITY_SUBSET_FEATURES_KHR {
+        p_next: next,
+    },
+    PhysicalDevicePortabilitySubsetPropertiesKHR = PHYSICAL_DEVICE_PORTABILITY_SUBSET_PROPERTIES_KHR {
+        p_next: next,
+    },
+    PhysicalDevice4444FormatsFeaturesEXT = PHYSICAL_DEVICE_4444_FORMATS_FEATURES_EXT {
+        p_next: next,
+    },
+    PhysicalDeviceSubpassShadingFeaturesHUAWEI = PHYSICAL_DEVICE_SUBPASS_SHADING_FEATURES_HUAWEI {
+        p_next: next,
+    },
+    BufferCopy2 = BUFFER_COPY_2 {
+        p_next: next,
+    },
+    ImageCopy2 = IMAGE_COPY_2 {
+        p_next: next,
+    },
+    ImageBlit2 = IMAGE_BLIT_2 {
+        p_next: next,
+    },
+    BufferImageCopy2 = BUFFER_IMAGE_COPY_2 {
+        p_next: next,
+    },
+    ImageResolve2 = IMAGE_RESOLVE_2 {
+        p_next: next,
+    },
+    CopyBufferInfo2 = COPY_BUFFER_INFO_2 {
+        p_next: next,
+        src_buffer: handle,
+        dst_buffer: handle,
+        p_regions: structs[this.region_count],
+    },
+    CopyImageInfo2 = COPY_IMAGE_INFO_2 {
+        p_next: next,
+        src_image: handle,
+        dst_image: handle,
+        p_regions: structs[this.region_count],
+    },
+    BlitImageInfo2 = BLIT_IMAGE_INFO_2 {
+        p_next: next,
+        src_image: handle,
+        dst_image: handle,
+        p_regions: structs[this.region_count],
+    },
+    CopyBufferToImageInfo2 = COPY_BUFFER_TO_IMAGE_INFO_2 {
+        p_next: next,
+        src_buffer: handle,
+        dst_image: handle,
+        p_regions: structs[this.region_count],
+    },
+    CopyImageToBufferInfo2 = COPY_IMAGE_TO_BUFFER_INFO_2 {
+        p_next: next,
+        src_image: handle,
+        dst_buffer: handle,
+        p_regions: structs[this.region_count],
+    },
+    ResolveImageInfo2 = RESOLVE_IMAGE_INFO_2 {
+        p_next: next,
+        src_image: handle,
+        dst_image: handle,
+        p_regions: structs[this.region_count],
+    },
+    PhysicalDeviceShaderImageAtomicInt64FeaturesEXT = PHYSICAL_DEVICE_SHADER_IMAGE_ATOMIC_INT64_FEATURES_EXT {
+        p_next: next,
+    },
+    FragmentShadingRateAttachmentInfoKHR = FRAGMENT_SHADING_RATE_ATTACHMENT_INFO_KHR {
+        p_next: next,
+        p_fragment_shading_rate_attachment: structs,
+    },
+    PipelineFragmentShadingRateStateCreateInfoKHR = PIPELINE_FRAGMENT_SHADING_RATE_STATE_CREATE_INFO_KHR {
+        p_next: next,
+    },
+    PhysicalDeviceFragmentShadingRateFeaturesKHR = PHYSICAL_DEVICE_FRAGMENT_SHADING_RATE_FEATURES_KHR {
+        p_next: next,
+    },
+    PhysicalDeviceFragmentShadingRatePropertiesKHR = PHYSICAL_DEVICE_FRAGMENT_SHADING_RATE_PROPERTIES_KHR {
+        p_next: next,
+    },
+    PhysicalDeviceFragmentShadingRateKHR = PHYSICAL_DEVICE_FRAGMENT_SHADING_RATE_KHR {
+        p_next: next,
+    },
+    PhysicalDeviceShaderTerminateInvocationFeatures = PHYSICAL_DEVICE_SHADER_TERMINATE_INVOCATION_FEATURES {
+        p_next: next,
+    },
+    PhysicalDeviceFragmentShadingRateEnumsFeaturesNV = PHYSICAL_DEVICE_FRAGMENT_SHADING_RATE_ENUMS_FEATURES_NV {
+        p_next: next,
+    },
+    PhysicalDeviceFragmentShadingRateEnumsPropertiesNV = PHYSICAL_DEVICE_FRAGMENT_SHADING_RATE_ENUMS_PROPERTIES_NV {
+        p_next: next,
+    },
+    PipelineFragmentShadingRateEnumStateCreateInfoNV = PIPELINE_FRAGMENT_SHADING_RATE_ENUM_STATE_CREATE_INFO_NV {
+        p_next: next,
+    },
+    AccelerationStructureBuildSizesInfoKHR = ACCELERATION_STRUCTURE_BUILD_SIZES_INFO_KHR {
+        p_next: next,
+    },
+    PhysicalDeviceImage2DViewOf3DFeaturesEXT = PHYSICAL_DEVICE_IMAGE_2D_VIEW_OF_3D_FEATURES_EXT {
+        p_next: next,
+    },
+    PhysicalDeviceMutableDescriptorTypeFeaturesEXT = PHYSICAL_DEVICE_MUTABLE_DESCRIPTOR_TYPE_FEATURES_EXT {
+        p_next: next,
+    },
+    MutableDescriptorTypeListEXT {
+        p_descriptor_types: ptr[this.descriptor_type_count],
+    },
+    MutableDescriptorTypeCreateInfoEXT = MUTABLE_DESCRIPTOR_TYPE_CREATE_INFO_EXT {
+        p_next: next,
+        p_mutable_descriptor_type_lists: structs[this.mutable_descriptor_type_list_count],
+    },
+    PhysicalDeviceDepthClipControlFeaturesEXT = PHYSICAL_DEVICE_DEPTH_CLIP_CONTROL_FEATURES_EXT {
+        p_next: next,
+    },
+    PipelineViewportDepthClipControlCreateInfoEXT = PIPELINE_VIEWPORT_DEPTH_CLIP_CONTROL_CREATE_INFO_EXT {
+        p_next: next,
+    },
+    PhysicalDeviceVertexInputDynamicStateFeaturesEXT = PHYSICAL_DEVICE_VERTEX_INPUT_DYNAMIC_STATE_FEATURES_EXT {
+        p_next: next,
+    },
+    PhysicalDeviceExternalMemoryRDMAFeaturesNV = PHYSICAL_DEVICE_EXTERNAL_MEMORY_RDMA_FEATURES_NV {
+        p_next: next,
+    },
+    VertexInputBindingDescription2EXT = VERTEX_INPUT_BINDING_DESCRIPTION_2_EXT {
+        p_next: next,
+    },
+    VertexInputAttributeDescription2EXT = VERTEX_INPUT_ATTRIBUTE_DESCRIPTION_2_EXT {
+        p_next: next,
+    },
+    PhysicalDeviceColorWriteEnableFeaturesEXT = PHYSICAL_DEVICE_COLOR_WRITE_ENABLE_FEATURES_EXT {
+        p_next: next,
+    },
+    PipelineColorWriteCreateInfoEXT = PIPELINE_COLOR_WRITE_CREATE_INFO_EXT {
+        p_next: next,
+        p_color_write_enables: ptr[this.attachment_count],
+    },
+    MemoryBarrier2 = MEMORY_BARRIER_2 {
+        p_next: next,
+    },
+    ImageMemoryBarrier2 = IMAGE_MEMORY_BARRIER_2 {
+        p_next: next,
+        image: handle,
+    },
+    BufferMemoryBarrier2 = BUFFER_MEMORY_BARRIER_2 {
+        p_next: next,
+        buffer: handle,
+    },
+    DependencyInfo = DEPENDENCY_INFO {
+        p_next: next,
+        p_memory_barriers: structs[this.memory_barrier_count],
+        p_buffer_memory_barriers: structs[this.buffer_memory_barrier_count],
+        p_image_memory_barriers: structs[this.image_memory_barrier_count],
+    },
+    SemaphoreSubmitInfo = SEMAPHORE_SUBMIT_INFO {
+        p_next: next,
+        semaphore: handle,
+    },
+    CommandBufferSubmitInfo = COMMAND_BUFFER_SUBMIT_INFO {
+        p_next: next,
+        command_buffer: handle,
+    },
+    SubmitInfo2 = SUBMIT_INFO_2 {
+        p_next: next,
+        p_wait_semaphore_infos: structs[this.wait_semaphore_info_count],
+        p_command_buffer_infos: structs[this.command_buffer_info_count],
+        p_signal_semaphore_infos: structs[this.signal_semaphore_info_count],
+    },
+    QueueFamilyCheckpointProperties2NV = QUEUE_FAMILY_CHECKPOINT_PROPERTIES_2_NV {
+        p_next: next,
+    },
+    CheckpointData2NV = CHECKPOINT_DATA_2_NV {
+        p_next: next,
+        p_checkpoint_marker: opaque,
+    },
+    PhysicalDeviceSynchronization2Features = PHYSICAL_DEVICE_SYNCHRONIZATION_2_FEATURES {
+        p_next: next,
+    },
+    PhysicalDevicePrimitivesGeneratedQueryFeaturesEXT = PHYSICAL_DEVICE_PRIMITIVES_GENERATED_QUERY_FEATURES_EXT {
+        p_next: next,
+    },
+    PhysicalDeviceLegacyDitheringFeaturesEXT = PHYSICAL_DEVICE_LEGACY_DITHERING_FEATURES_EXT {
+        p_next: next,
+    },
+    PhysicalDeviceMultisampledRenderToSingleSampledFeaturesEXT = PHYSICAL_DEVICE_MULTISAMPLED_RENDER_TO_SINGLE_SAMPLED_FEATURES_EXT {
+        p_next: next,
+    },
+    SubpassResolvePerformanceQueryEXT = SUBPASS_RESOLVE_PERFORMANCE_QUERY_EXT {
+        p_next: next,
+    },
+    MultisampledRenderToSingleSampledInfoEXT = MULTISAMPLED_RENDER_TO_SINGLE_SAMPLED_INFO_EXT {
+        p_next: next,
+    },
+    PhysicalDevicePipelineProtectedAccessFeaturesEXT = PHYSICAL_DEVICE_PIPELINE_PROTECTED_ACCESS_FEATURES_EXT {
+        p_next: next,
+    },
+    QueueFamilyVideoPropertiesKHR = QUEUE_FAMILY_VIDEO_PROPERTIES_KHR {
+        p_next: next,
+    },
+    QueueFamilyQueryResultStatusPropertiesKHR = QUEUE_FAMILY_QUERY_RESULT_STATUS_PROPERTIES_KHR {
+        p_next: next,
+    },
+    VideoProfileListInfoKHR = VIDEO_PROFILE_LIST_INFO_KHR {
+        p_next: next,
+        p_profiles: structs[this.profile_count],
+    },
+    PhysicalDeviceVideoFormatInfoKHR = PHYSICAL_DEVICE_VIDEO_FORMAT_INFO_KHR {
+        p_next: next,
+    },
+    VideoFormatPropertiesKHR = VIDEO_FORMAT_PROPERTIES_KHR {
+        p_next: next,
+    },
+    VideoProfileInfoKHR = VIDEO_PROFILE_INFO_KHR {
+        p_next: next,
+    },
+    VideoCapabilitiesKHR = VIDEO_CAPABILITIES_KHR {
+        p_next: next,
+    },
+    VideoSessionMemoryRequirementsKHR = VIDEO_SESSION_MEMORY_REQUIREMENTS_KHR {
+        p_next: next,
+    },
+    BindVideoSessionMemoryInfoKHR = BIND_VIDEO_SESSION_MEMORY_INFO_KHR {
+        p_next: next,
+        memory: handle,
+    },
+    VideoPictureResourceInfoKHR = VIDEO_PICTURE_RESOURCE_INFO_KHR {
+        p_next: next,
+        image_view_binding: handle,
+    },
+    VideoReferenceSlotInfoKHR = VIDEO_REFERENCE_SLOT_INFO_KHR {
+        p_next: next,
+        p_picture_resource: structs,
+    },
+    VideoDecodeCapabilitiesKHR = VIDEO_DECODE_CAPABILITIES_KHR {
+        p_next: next,
+    },
+    VideoDecodeUsageInfoKHR = VIDEO_DECODE_USAGE_INFO_KHR {
+        p_next: next,
+    },
+    VideoDecodeInfoKHR = VIDEO_DECODE_INFO_KHR {
+        p_next: next,
+        src_buffer: handle,
+        dst_picture_resource: struct,
+        p_setup_reference_slot: structs,
+        p_reference_slots: structs[this.reference_slot_count],
+    },
+    VideoSessionCreateInfoKHR = VIDEO_SESSION_CREATE_INFO_KHR {
+        p_next: next,
+        p_video_profile: structs,
+        p_std_header_version: ptr,
+    },
+    VideoSessionParametersCreateInfoKHR = VIDEO_SESSION_PARAMETERS_CREATE_INFO_KHR {
+        p_next: next,
+        video_session_parameters_template: handle,
+        video_session: handle,
+    },
+    VideoSessionParametersUpdateInfoKHR = VIDEO_SESSION_PARAMETERS_UPDATE_INFO_KHR {
+        p_next: next,
+    },
+    VideoBeginCodingInfoKHR = VIDEO_BEGIN_CODING_INFO_KHR {
+        p_next: next,
+        video_session: handle,
+        video_session_parameters: handle,
+        p_reference_slots: structs[this.reference_slot_count],
+    },
+    VideoEndCodingInfoKHR = VIDEO_END_CODING_INFO_KHR {
+        p_next: next,
+    },
+    VideoCodingControlInfoKHR = VIDEO_CODING_CONTROL_INFO_KHR {
+        p_next: next,
+    },
+    VideoEncodeUsageInfoKHR = VIDEO_ENCODE_USAGE_INFO_KHR {
+        p_next: next,
+    },
+    VideoEncodeInfoKHR = VIDEO_ENCODE_INFO_KHR {
+        p_next: next,
+        dst_bitstream_buffer: handle,
+        src_picture_resource: struct,
+        p_setup_reference_slot: structs,
+        p_reference_slots: structs[this.reference_slot_count],
+    },
+    VideoEncodeRateControlInfoKHR = VIDEO_ENCODE_RATE_CONTROL_INFO_KHR {
+        p_next: next,
+        p_layer_configs: structs,
+    },
+    VideoEncodeRateControlLayerInfoKHR = VIDEO_ENCODE_RATE_CONTROL_LAYER_INFO_KHR {
+        p_next: next,
+    },
+    VideoEncodeCapabilitiesKHR = VIDEO_ENCODE_CAPABILITIES_KHR {
+        p_next: next,
+    },
+    VideoEncodeH264CapabilitiesEXT = VIDEO_ENCODE_H264_CAPABILITIES_EXT {
+        p_next: next,
+    },
+    VideoEncodeH264EmitPictureParametersInfoEXT = VIDEO_ENCODE_H264_EMIT_PICTURE_PARAMETERS_INFO_EXT {
+        p_next: next,
+        pps_id_entries: ptr[this.pps_id_entry_count],
+    },
+    VideoEncodeH264RateControlInfoEXT = VIDEO_ENCODE_H264_RATE_CONTROL_INFO_EXT {
+        p_next: next,
+    },
+    VideoEncodeH264RateControlLayerInfoEXT = VIDEO_ENCODE_H264_RATE_CONTROL_LAYER_INFO_EXT {
+        p_next: next,
+    },
+    VideoEncodeH265CapabilitiesEXT = VIDEO_ENCODE_H265_CAPABILITIES_EXT {
+        p_next: next,
+    },
+    VideoEncodeH265EmitPictureParametersInfoEXT = VIDEO_ENCODE_H265_EMIT_PICTURE_PARAMETERS_INFO_EXT {
+        p_next: next,
+        pps_id_entries: ptr[this.pps_id_entry_count],
+    },
+    VideoEncodeH265RateControlInfoEXT = VIDEO_ENCODE_H265_RATE_CONTROL_INFO_EXT {
+        p_next: next,
+    },
+    VideoEncodeH265RateControlLayerInfoEXT = VIDEO_ENCODE_H265_RATE_CONTROL_LAYER_INFO_EXT {
+        p_next: next,
+    },
+    PhysicalDeviceInheritedViewportScissorFeaturesNV = PHYSICAL_DEVICE_INHERITED_VIEWPORT_SCISSOR_FEATURES_NV {
+        p_next: next,
+    },
+    CommandBufferInheritanceViewportScissorInfoNV = COMMAND_BUFFER_INHERITANCE_VIEWPORT_SCISSOR_INFO_NV {
+        p_next: next,
+        p_viewport_depths: ptr[this.viewport_depth_count],
+    },
+    PhysicalDeviceYcbcr2Plane444FormatsFeaturesEXT = PHYSICAL_DEVICE_YCBCR_2_PLANE_444_FORMATS_FEATURES_EXT {
+        p_next: next,
+    },
+    PhysicalDeviceProvokingVertexFeaturesEXT = PHYSICAL_DEVICE_PROVOKING_VERTEX_FEATURES_EXT {
+        p_next: next,
+    },
+    PhysicalDeviceProvokingVertexPropertiesEXT = PHYSICAL_DEVICE_PROVOKING_VERTEX_PROPERTIES_EXT {
+        p_next: next,
+    },
+    PipelineRasterizationProvokingVertexStateCreateInfoEXT = PIPELINE_RASTERIZATION_PROVOKING_VERTEX_STATE_CREATE_INFO_EXT {
+        p_next: next,
+    },
+    CuModuleCreateInfoNVX = CU_MODULE_CREATE_INFO_NVX {
+        p_next: next,
+        p_data: bytes[this.data_size],
+    },
+    CuFunctionCreateInfoNVX = CU_FUNCTION_CREATE_INFO_NVX {
+        p_next: next,
+        module: handle,
+        p_name: str,
+    },
+    CuLaunchInfoNVX = CU_LAUNCH_INFO_NVX {
+        p_next: next,
+        function: handle,
+        p_params: opaque,
+        p_extras: opaque,
+    },
+    PhysicalDeviceShaderIntegerDotProductFeatures = PHYSICAL_DEVICE_SHADER_INTEGER_DOT_PRODUCT_FEATURES {
+        p_next: next,
+    },
+    PhysicalDeviceShaderIntegerDotProductProperties = PHYSICAL_DEVICE_SHADER_INTEGER_DOT_PRODUCT_PROPERTIES {
+        p_next: next,
+    },
+    PhysicalDeviceDrmPropertiesEXT = PHYSICAL_DEVICE_DRM_PROPERTIES_EXT {
+        p_next: next,
+    },
+    PhysicalDeviceFragmentShaderBarycentricFeaturesKHR = PHYSICAL_DEVICE_FRAGMENT_SHADER_BARYCENTRIC_FEATURES_KHR {
+        p_next: next,
+    },
+    PhysicalDeviceFragmentShaderBarycentricPropertiesKHR = PHYSICAL_DEVICE_FRAGMENT_SHADER_BARYCENTRIC_PROPERTIES_KHR {
+        p_next: next,
+    },
+    PhysicalDeviceRayTracingMotionBlurFeaturesNV = PHYSICAL_DEVICE_RAY_TRACING_MOTION_BLUR_FEATURES_NV {
+        p_next: next,
+    },
+    AccelerationStructureGeometryMotionTrianglesDataNV = ACCELERATION_STRUCTURE_GEOMETRY_MOTION_TRIANGLES_DATA_NV {
+        p_next: next,
+    },
+    AccelerationStructureMotionInfoNV = ACCELERATION_STRUCTURE_MOTION_INFO_NV {
+        p_next: next,
+    },
+    MemoryGetRemoteAddressInfoNV = MEMORY_GET_REMOTE_ADDRESS_INFO_NV {
+        p_next: next,
+        memory: handle,
+    },
+    ImportMemoryBufferCollectionFUCHSIA = IMPORT_MEMORY_BUFFER_COLLECTION_FUCHSIA {
+        p_next: next,
+        collection: handle,
+    },
+    BufferCollectionImageCreateInfoFUCHSIA = BUFFER_COLLECTION_IMAGE_CREATE_INFO_FUCHSIA {
+        p_next: next,
+        collection: handle,
+    },
+    BufferCollectionBufferCreateInfoFUCHSIA = BUFFER_COLLECTION_BUFFER_CREATE_INFO_FUCHSIA {
+        p_next: next,
+        collection: handle,
+    },
+    BufferCollectionCreateInfoFUCHSIA = BUFFER_COLLECTION_CREATE_INFO_FUCHSIA {
+        p_next: next,
+    },
+    BufferCollectionPropertiesFUCHSIA = BUFFER_COLLECTION_PROPERTIES_FUCHSIA {
+        p_next: next,
+        sysmem_color_space_index: struct,
+    },
+    BufferConstraintsInfoFUCHSIA = BUFFER_CONSTRAINTS_INFO_FUCHSIA {
+        p_next: next,
+        create_info: struct,
+        buffer_collection_constraints: struct,
+    },
+    SysmemColorSpaceFUCHSIA = SYSMEM_COLOR_SPACE_FUCHSIA {
+        p_next: next,
+    },
+    ImageFormatConstraintsInfoFUCHSIA = IMAGE_FORMAT_CONSTRAINTS_INFO_FUCHSIA {
+        p_next: next,
+        image_create_info: struct,
+        p_color_spaces: structs[this.color_space_count],
+    },
+    ImageConstraintsInfoFUCHSIA = IMAGE_CONSTRAINTS_INFO_FUCHSIA {
+        p_next: next,
+        p_format_constraints: structs[this.format_constraints_count],
+        buffer_collection_constraints: struct,
+    },
+    BufferCollectionConstraintsInfoFUCHSIA = BUFFER_COLLECTION_CONSTRAINTS_INFO_FUCHSIA {
+        p_next: next,
+    },
+    PhysicalDeviceRGBA10X6FormatsFeaturesEXT = PHYSICAL_DEVICE_RGBA10X6_FORMATS_FEATURES_EXT {
+        p_next: next,
+    },
+    FormatProperties3 = FORMAT_PROPERTIES_3 {
+        p_next: next,
+    },
+    DrmFormatModifierPropertiesList2EXT = DRM_FORMAT_MODIFIER_PROPERTIES_LIST_2_EXT {
+        p_next: next,
+        p_drm_format_modifier_properties: ptr[this.drm_format_modifier_count],
+    },
+    AndroidHardwareBufferFormatProperties2ANDROID = ANDROID_HARDWARE_BUFFER_FORMAT_PROPERTIES_2_ANDROID {
+        p_next: next,
+    },
+    PipelineRenderingCreateInfo = PIPELINE_RENDERING_CREATE_INFO {
+        p_next: next,
+        p_color_attachment_formats: ptr[this.color_attachment_count],
+    },
+    RenderingInfo = RENDERING_INFO {
+        p_next: next,
+        p_color_attachments: structs[this.color_attachment_count],
+        p_depth_attachment: structs,
+        p_stencil_attachment: structs,
+    },
+    RenderingAttachmentInfo = RENDERING_ATTACHMENT_INFO {
+        p_next: next,
+        image_view: handle,
+        resolve_image_view: handle,
+    },
+    RenderingFragmentShadingRateAttachmentInfoKHR = RENDERING_FRAGMENT_SHADING_RATE_ATTACHMENT_INFO_KHR {
+        p_next: next,
+        image_view: handle,
+    },
+    RenderingFragmentDensityMapAttachmentInfoEXT = RENDERING_FRAGMENT_DENSITY_MAP_ATTACHMENT_INFO_EXT {
+        p_next: next,
+        image_view: handle,
+    },
+    PhysicalDeviceDynamicRenderingFeatures = PHYSICAL_DEVICE_DYNAMIC_RENDERING_FEATURES {
+        p_next: next,
+    },
+    CommandBufferInheritanceRenderingInfo = COMMAND_BUFFER_INHERITANCE_RENDERING_INFO {
+        p_next: next,
+        p_color_attachment_formats: ptr[this.color_attachment_count],
+    },
+    AttachmentSampleCountInfoAMD = ATTACHMENT_SAMPLE_COUNT_INFO_AMD {
+        p_next: next,
+        p_color_attachment_samples: ptr[this.color_attachment_count],
+    },
+    MultiviewPerViewAttributesInfoNVX = MULTIVIEW_PER_VIEW_ATTRIBUTES_INFO_NVX {
+        p_next: next,
+    },
+    PhysicalDeviceImageViewMinLodFeaturesEXT = PHYSICAL_DEVICE_IMAGE_VIEW_MIN_LOD_FEATURES_EXT {
+        p_next: next,
+    },
+    ImageViewMinLodCreateInfoEXT = IMAGE_VIEW_MIN_LOD_CREATE_INFO_EXT {
+        p_next: next,
+    },
+    PhysicalDeviceRasterizationOrderAttachmentAccessFeaturesEXT = PHYSICAL_DEVICE_RASTERIZATION_ORDER_ATTACHMENT_ACCESS_FEATURES_EXT {
+        p_next: next,
+    },
+    PhysicalDeviceLinearColorAttachmentFeaturesNV = PHYSICAL_DEVICE_LINEAR_COLOR_ATTACHMENT_FEATURES_NV {
+        p_next: next,
+    },
+    PhysicalDeviceGraphicsPipelineLibraryFeaturesEXT = PHYSICAL_DEVICE_GRAPHICS_PIPELINE_LIBRARY_FEATURES_EXT {
+        p_next: next,
+    },
+    PhysicalDeviceGraphicsPipelineLibraryPropertiesEXT = PHYSICAL_DEVICE_GRAPHICS_PIPELINE_LIBRARY_PROPERTIES_EXT {
+        p_next: next,
+    },
+    GraphicsPipelineLibraryCreateInfoEXT = GRAPHICS_PIPELINE_LIBRARY_CREATE_INFO_EXT {
+        p_next: next,
+    },
+    PhysicalDeviceDescriptorSetHostMappingFeaturesVALVE = PHYSICAL_DEVICE_DESCRIPTOR_SET_HOST_MAPPING_FEATURES_VALVE {
+        p_next: next,
+    },
+    DescriptorSetBindingReferenceVALVE = DESCRIPTOR_SET_BINDING_REFERENCE_VALVE {
+        p_next: next,
+        descriptor_set_layout: handle,
+    },
+    DescriptorSetLayoutHostMappingInfoVALVE = DESCRIPTOR_SET_LAYOUT_HOST_MAPPING_INFO_VALVE {
+        p_next: next,
+    },
+    PhysicalDeviceShaderModuleIdentifierFeaturesEXT = PHYSICAL_DEVICE_SHADER_MODULE_IDENTIFIER_FEATURES_EXT {
+        p_next: next,
+    },
+    PhysicalDeviceShaderModuleIdentifierPropertiesEXT = PHYSICAL_DEVICE_SHADER_MODULE_IDENTIFIER_PROPERTIES_EXT {
+        p_next: next,
+    },
+    PipelineShaderStageModuleIdentifierCreateInfoEXT = PIPELINE_SHADER_STAGE_MODULE_IDENTIFIER_CREATE_INFO_EXT {
+        p_next: next,
+        p_identifier: ptr[this.identifier_size],
+    },
+    ShaderModuleIdentifierEXT = SHADER_MODULE_IDENTIFIER_EXT {
+        p_next: next,
+    },
+    ImageCompressionControlEXT = IMAGE_COMPRESSION_CONTROL_EXT {
+        p_next: next,
+        p_fixed_rate_flags: ptr[this.compression_control_plane_count],
+    },
+    PhysicalDeviceImageCompressionControlFeaturesEXT = PHYSICAL_DEVICE_IMAGE_COMPRESSION_CONTROL_FEATURES_EXT {
+        p_next: next,
+    },
+    ImageCompressionPropertiesEXT = IMAGE_COMPRESSION_PROPERTIES_EXT {
+        p_next: next,
+    },
+    PhysicalDeviceImageCompressionControlSwapchainFeaturesEXT = PHYSICAL_DEVICE_IMAGE_COMPRESSION_CONTROL_SWAPCHAIN_FEATURES_EXT {
+        p_next: next,
+    },
+    ImageSubresource2EXT = IMAGE_SUBRESOURCE_2_EXT {
+        p_next: next,
+    },
+    SubresourceLayout2EXT = SUBRESOURCE_LAYOUT_2_EXT {
+        p_next: next,
+    },
+    RenderPassCreationControlEXT = RENDER_PASS_CREATION_CONTROL_EXT {
+        p_next: next,
+    },
+    RenderPassCreationFeedbackCreateInfoEXT = RENDER_PASS_CREATION_FEEDBACK_CREATE_INFO_EXT {
+        p_next: next,
+        p_render_pass_feedback: ptr,
+    },
+    RenderPassSubpassFeedbackCreateInfoEXT = RENDER_PASS_SUBPASS_FEEDBACK_CREATE_INFO_EXT {
+        p_next: next,
+        p_subpass_feedback: ptr,
+    },
+    PhysicalDeviceSubpassMergeFeedbackFeaturesEXT = PHYSICAL_DEVICE_SUBPASS_MERGE_FEEDBACK_FEATURES_EXT {
+        p_next: next,
+    },
+    MicromapBuildInfoEXT = MICROMAP_BUILD_INFO_EXT {
+        p_next: next,
+        dst_micromap: handle,
+        p_usage_counts: ptr[this.usage_counts_count],
+        pp_usage_counts: opaque,
+    },
+    MicromapCreateInfoEXT = MICROMAP_CREATE_INFO_EXT {
+        p_next: next,
+        buffer: handle,
+    },
+    MicromapVersionInfoEXT = MICROMAP_VERSION_INFO_EXT {
+        p_next: next,
+        p_version_data: opaque,
+    },
+    CopyMicromapInfoEXT = COPY_MICROMAP_INFO_EXT {
+        p_next: next,
+        src: handle,
+        dst: handle,
+    },
+    CopyMicromapToMemoryInfoEXT = COPY_MICROMAP_TO_MEMORY_INFO_EXT {
+        p_next: next,
+        src: handle,
+    },
+    CopyMemoryToMicromapInfoEXT = COPY_MEMORY_TO_MICROMAP_INFO_EXT {
+        p_next: next,
+        dst: handle,
+    },
+    MicromapBuildSizesInfoEXT = MICROMAP_BUILD_SIZES_INFO_EXT {
+        p_next: next,
+    },
+    PhysicalDeviceOpacityMicromapFeaturesEXT = PHYSICAL_DEVICE_OPACITY_MICROMAP_FEATURES_EXT {
+        p_next: next,
+    },
+    PhysicalDeviceOpacityMicromapPropertiesEXT = PHYSICAL_DEVICE_OPACITY_MICROMAP_PROPERTIES_EXT {
+        p_next: next,
+    },
+    AccelerationStructureTrianglesOpacityMicromapEXT = ACCELERATION_STRUCTURE_TRIANGLES_OPACITY_MICROMAP_EXT {
+        p_next: next,
+        p_usage_counts: ptr[this.usage_counts_count],
+        pp_usage_counts: opaque,
+        micromap: handle,
+    },
+    PipelinePropertiesIdentifierEXT = PIPELINE_PROPERTIES_IDENTIFIER_EXT {
+        p_next: next,
+    },
+    PhysicalDevicePipelinePropertiesFeaturesEXT = PHYSICAL_DEVICE_PIPELINE_PROPERTIES_FEATURES_EXT {
+        p_next: next,
+    },
+    PhysicalDeviceShaderEarlyAndLateFragmentTestsFeaturesAMD = PHYSICAL_DEVICE_SHADER_EARLY_AND_LATE_FRAGMENT_TESTS_FEATURES_AMD {
+        p_next: next,
+    },
+    ExportMetalObjectCreateInfoEXT = EXPORT_METAL_OBJECT_CREATE_INFO_EXT {
+        p_next: next,
+    },
+    ExportMetalObjectsInfoEXT = EXPORT_METAL_OBJECTS_INFO_EXT {
+        p_next: next,
+    },
+    ExportMetalDeviceInfoEXT = EXPORT_METAL_DEVICE_INFO_EXT {
+        p_next: next,
+    },
+    ExportMetalCommandQueueInfoEXT = EXPORT_METAL_COMMAND_QUEUE_INFO_EXT {
+        p_next: next,
+        queue: handle,
+    },
+    ExportMetalBufferInfoEXT = EXPORT_METAL_BUFFER_INFO_EXT {
+        p_next: next,
+        memory: handle,
+    },
+    ImportMetalBufferInfoEXT = IMPORT_METAL_BUFFER_INFO_EXT {
+        p_next: next,
+    },
+    ExportMetalTextureInfoEXT = EXPORT_METAL_TEXTURE_INFO_EXT {
+        p_next: next,
+        image: handle,
+        image_view: handle,
+        buffer_view: handle,
+    },
+    ImportMetalTextureInfoEXT = IMPORT_METAL_TEXTURE_INFO_EXT {
+        p_next: next,
+    },
+    ExportMetalIOSurfaceInfoEXT = EXPORT_METAL_IO_SURFACE_INFO_EXT {
+        p_next: next,
+        image: handle,
+    },
+    ImportMetalIOSurfaceInfoEXT = IMPORT_METAL_IO_SURFACE_INFO_EXT {
+        p_next: next,
+    },
+    ExportMetalSharedEventInfoEXT = EXPORT_METAL_SHARED_EVENT_INFO_EXT {
+        p_next: next,
+        semaphore: handle,
+        event: handle,
+    },
+    ImportMetalSharedEventInfoEXT = IMPORT_METAL_SHARED_EVENT_INFO_EXT {
+        p_next: next,
+    },
+    PhysicalDeviceNonSeamlessCubeMapFeaturesEXT = PHYSICAL_DEVICE_NON_SEAMLESS_CUBE_MAP_FEATURES_EXT {
+        p_next: next,
+    },
+    PhysicalDevicePipelineRobustnessFeaturesEXT = PHYSICAL_DEVICE_PIPELINE_ROBUSTNESS_FEATURES_EXT {
+        p_next: next,
+    },
+    PipelineRobustnessCreateInfoEXT = PIPELINE_ROBUSTNESS_CREATE_INFO_EXT {
+        p_next: next,
+    },
+    PhysicalDevicePipelineRobustnessPropertiesEXT = PHYSICAL_DEVICE_PIPELINE_ROBUSTNESS_PROPERTIES_EXT {
+        p_next: next,
+    },
+    ImageViewSampleWeightCreateInfoQCOM = IMAGE_VIEW_SAMPLE_WEIGHT_CREATE_INFO_QCOM {
+        p_next: next,
+    },
+    PhysicalDeviceImageProcessingFeaturesQCOM = PHYSICAL_DEVICE_IMAGE_PROCESSING_FEATURES_QCOM {
+        p_next: next,
+    },
+    PhysicalDeviceImageProcessingPropertiesQCOM = PHYSICAL_DEVICE_IMAGE_PROCESSING_PROPERTIES_QCOM {
+        p_next: next,
+    },
+    PhysicalDeviceTilePropertiesFeaturesQCOM = PHYSICAL_DEVICE_TILE_PROPERTIES_FEATURES_QCOM {
+        p_next: next,
+    },
+    TilePropertiesQCOM = TILE_PROPERTIES_QCOM {
+        p_next: next,
+    },
+    PhysicalDeviceAmigoProfilingFeaturesSEC = PHYSICAL_DEVICE_AMIGO_PROFILING_FEATURES_SEC {
+        p_next: next,
+    },
+    AmigoProfilingSubmitInfoSEC = AMIGO_PROFILING_SUBMIT_INFO_SEC {
+        p_next: next,
+    },
+    PhysicalDeviceAttachmentFeedbackLoopLayoutFeaturesEXT = PHYSICAL_DEVICE_ATTACHMENT_FEEDBACK_LOOP_LAYOUT_FEATURES_EXT {
+        p_next: next,
+    },
+    PhysicalDeviceDepthClampZeroOneFeaturesEXT = PHYSICAL_DEVICE_DEPTH_CLAMP_ZERO_ONE_FEATURES_EXT {
+        p_next: next,
+    },
+    PhysicalDeviceAddressBindingReportFeaturesEXT = PHYSICAL_DEVICE_ADDRESS_BINDING_REPORT_FEATURES_EXT {
+        p_next: next,
+    },
+    DeviceAddressBindingCallbackDataEXT = DEVICE_ADDRESS_BINDING_CALLBACK_DATA_EXT {
+        p_next: next,
+    },
+    PhysicalDeviceOpticalFlowFeaturesNV = PHYSICAL_DEVICE_OPTICAL_FLOW_FEATURES_NV {
+        p_next: next,
+    },
+    PhysicalDeviceOpticalFlowPropertiesNV = PHYSICAL_DEVICE_OPTICAL_FLOW_PROPERTIES_NV {
+        p_next: next,
+    },
+    OpticalFlowImageFormatInfoNV = OPTICAL_FLOW_IMAGE_FORMAT_INFO_NV {
+        p_next: next,
+    },
+    OpticalFlowImageFormatPropertiesNV = OPTICAL_FLOW_IMAGE_FORMAT_PROPERTIES_NV {
+        p_next: next,
+    },
+    OpticalFlowSessionCreateInfoNV = OPTICAL_FLOW_SESSION_CREATE_INFO_NV {
+        p_next: next,
+    },
+    OpticalFlowSessionCreatePrivateDataInfoNV = OPTICAL_FLOW_SESSION_CREATE_PRIVATE_DATA_INFO_NV {
+        p_next: next,
+        p_private_data: opaque,
+    },
+    OpticalFlowExecuteInfoNV = OPTICAL_FLOW_EXECUTE_INFO_NV {
+        p_next: next,
+        p_regions: ptr[this.region_count],
+    },
+    PhysicalDeviceFaultFeaturesEXT = PHYSICAL_DEVICE_FAULT_FEATURES_EXT {
+        p_next: next,
+    },
+    DeviceFaultCountsEXT = DEVICE_FAULT_COUNTS_EXT {
+        p_next: next,
+    },
+    DeviceFaultInfoEXT = DEVICE_FAULT_INFO_EXT {
+        p_next: next,
+        p_address_infos: ptr,
+        p_vendor_infos: ptr,
+        p_vendor_binary_data: opaque,
+    },
+}
+
+
+impl_linked! {
+    "linked" {
+        CreateInstance(
+            p_create_info: *const InstanceCreateInfo,
+            p_allocator: *const AllocationCallbacks,
+            p_instance: *mut Instance,
+        ) -> Result;
+        DestroyInstance(
+            instance: Instance,
+            p_allocator: *const AllocationCallbacks,
+        );
+        EnumeratePhysicalDevices(
+            instance: Instance,
+            p_physical_device_count: *mut u32,
+            p_physical_devices: *mut PhysicalDevice,
+        ) -> Result;
+        GetDeviceProcAddr(
+            device: Device,
+            p_name: *const c_char,
+        ) -> crate::pfn::VoidFunction;
+        GetInstanceProcAddr(
+            instance: Instance,
+            p_name: *const c_char,
+        ) -> crate::pfn::VoidFunction;
+        GetPhysicalDeviceProperties(
+            physical_device: PhysicalDevice,
+            p_properties: *mut PhysicalDeviceProperties,
+        );
+        GetPhysicalDeviceQueueFamilyProperties(
+            physical_device: PhysicalDevice,
+            p_queue_family_property_count: *mut u32,
+            p_queue_family_properties: *mut QueueFamilyProperties,
+        );
+        GetPhysicalDeviceMemoryProperties(
+            physical_device: PhysicalDevice,
+            p_memory_properties: *mut PhysicalDeviceMemoryProperties,
+        );
+        GetPhysicalDeviceFeatures(
+            physical_device: PhysicalDevice,
+            p_features: *mut PhysicalDeviceFeatures,
+        );
+        GetPhysicalDeviceFormatProperties(
+            physical_device: PhysicalDevice,
+            format: Format,
+            p_format_properties: *mut FormatProperties,
+        );
+        GetPhysicalDeviceImageFormatProperties(
+            physical_device: PhysicalDevice,
+            format: Format,
+            ty: ImageType,
+            tiling: ImageTiling,
+            usage: ImageUsageFlags,
+            flags: ImageCreateFlags,
+            p_image_format_properties: *mut ImageFormatProperties,
+        ) -> Result;
+        CreateDevice(
+            physical_device: PhysicalDevice,
+            p_create_info: *const DeviceCreateInfo,
+            p_allocator: *const AllocationCallbacks,
+            p_device: *mut Device,
+        ) -> Result;
+        DestroyDevice(
+            device: Device,
+            p_allocator: *const AllocationCallbacks,
+        );
+        EnumerateInstanceLayerProperties(
+            p_property_count: *mut u32,
+            p_properties: *mut LayerProperties,
+        ) -> Result;
+        EnumerateInstanceExtensionProperties(
+            p_layer_name: *const c_char,
+            p_property_count: *mut u32,
+            p_properties: *mut ExtensionProperties,
+        ) -> Result;
+        EnumerateDeviceLayerProperties(
+            physical_device: PhysicalDevice,
+            p_property_count: *mut u32,
+            p_properties: *mut LayerProperties,
+        ) -> Result;
+        EnumerateDeviceExtensionProperties(
+            physical_device: PhysicalDevice,
+            p_layer_name: *const c_char,
+            p_property_count: *mut u32,
+            p_properties: *mut ExtensionProperties,
+        ) -> Result;
+        GetDeviceQueue(
+            device: Device,
+            queue_family_index: u32,
+            queue_index: u32,
+            p_queue: *mut Queue,
+        );
+        QueueSubmit(
+            queue: Queue,
+            submit_count: u32,
+            p_submits: *const SubmitInfo,
+            fence: Fence,
+        ) -> Result;
+        QueueWaitIdle(
+            queue: Queue,
+        ) -> Result;
+        DeviceWaitIdle(
+            device: Device,
+        ) -> Result;
+        AllocateMemory(
+            device: Device,
+            p_allocate_info: *const MemoryAllocateInfo,
+            p_allocator: *const AllocationCallbacks,
+            p_memory: *mut DeviceMemory,
+        ) -> Result;
+        FreeMemory(
+            device: Device,
+            memory: DeviceMemory,
+            p_allocator: *const AllocationCallbacks,
+        );
+        MapMemory(
+            device: Device,
+            memory: DeviceMemory,
+            offset: DeviceSize,
+            size: DeviceSize,
+            flags: MemoryMapFlags,
+            pp_data: *mut *mut c_void,
+        ) -> Result;
+        UnmapMemory(
+            device: Device,
+            memory: DeviceMemory,
+        );
+        FlushMappedMemoryRanges(
+            device: Device,
+            memory_range_count: u32,
+            p_memory_ranges: *const MappedMemoryRange,
+        ) -> Result;
+        InvalidateMappedMemoryRanges(
+            device: Device,
+            memory_range_count: u32,
+            p_memory_ranges: *const MappedMemoryRange,
+        ) -> Result;
+        GetDeviceMemoryCommitment(
+            device: Device,
+            memory: DeviceMemory,
+            p_committed_memory_in_bytes: *mut DeviceSize,
+        );
+        GetBufferMemoryRequirements(
+            device: Device,
+            buffer: Buffer,
+            p_memory_requirements: *mut MemoryRequirements,
+        );
+        BindBufferMemory(
+            device: Device,
+            buffer: Buffer,
+            memory: DeviceMemory,
+            memory_offset: DeviceSize,
+        ) -> Result;
+        GetImageMemoryRequirements(
+            device: Device,
+            image: Image,
+            p_memory_requirements: *mut MemoryRequirements,
+        );
+        BindImageMemory(
+            device: Device,
+            image: Image,
+            memory: DeviceMemory,
+            memory_offset: DeviceSize,
+        ) -> Result;
+        GetImageSparseMemoryRequirements(
+            device: Device,
+            image: Image,
+            p_sparse_memory_requirement_count: *mut u32,
+            p_sparse_memory_requirements: *mut SparseImageMemoryRequirements,
+        );
+        GetPhysicalDeviceSparseImageFormatProperties(
+            physical_device: PhysicalDevice,
+            format: Format,
+            ty: ImageType,
+            samples: SampleCountFlagBits,
+            usage: ImageUsageFlags,
+            tiling: ImageTiling,
+            p_property_count: *mut u32,
+            p_properties: *mut SparseImageFormatProperties,
+        );
+        QueueBindSparse(
+            queue: Queue,
+            bind_info_count: u32,
+            p_bind_info: *const BindSparseInfo,
+            fence: Fence,
+        ) -> Result;
+        CreateFence(
+            device: Device,
+            p_create_info: *const FenceCreateInfo,
+            p_allocator: *const AllocationCallbacks,
+            p_fence: *mut Fence,
+        ) -> Result;
+        DestroyFence(
+            device: Device,
+            fence: Fence,
+            p_allocator: *const AllocationCallbacks,
+        );
+        ResetFences(
+            device: Device,
+            fence_count: u32,
+            p_fences: *const Fence,
+        ) -> Result;
+        GetFenceStatus(
+            device: Device,
+            fence: Fence,
+        ) -> Result;
+        WaitForFences(
+            device: Device,
+            fence_count: u32,
+            p_fences: *const Fence,
+            wait_all: Bool32,
+            timeout: u64,
+        ) -> Result;
+        CreateSemaphore(
+            device: Device,
+            p_create_info: *const SemaphoreCreateInfo,
+            p_allocator: *const AllocationCallbacks,
+            p_semaphore: *mut Semaphore,
+        ) -> Result;
+        DestroySemaphore(
+            device: Device,
+            semaphore: Semaphore,
+            p_allocator: *const AllocationCallbacks,
+        );
+        CreateEvent(
+            device: Device,
+            p_create_info: *const EventCreateInfo,
+            p_allocator: *const AllocationCallbacks,
+            p_event: *mut Event,
+        ) -> Result;
+        DestroyEvent(
+            device: Device,
+            event: Event,
+            p_allocator: *const AllocationCallbacks,
+        );
+        GetEventStatus(
+            device: Device,
+            event: Event,
+        ) -> Result;
+        SetEvent(
+            device: Device,
+            event: Event,
+        ) -> Result;
+        ResetEvent(
+            device: Device,
+            event: Event,
+        ) -> Result;
+        CreateQueryPool(
+            device: Device,
+            p_create_info: *const QueryPoolCreateInfo,
+            p_allocator: *const AllocationCallbacks,
+            p_query_pool: *mut QueryPool,
+        ) -> Result;
+        DestroyQueryPool(
+            device: Device,
+            query_pool: QueryPool,
+            p_allocator: *const AllocationCallbacks,
+        );
+        GetQueryPoolResults(
+            device: Device,
+            query_pool: QueryPool,
+            first_query: u32,
+            query_count: u32,
+            data_size: usize,
+            p_data: *mut c_void,
+            stride: DeviceSize,
+            flags: QueryResultFlags,
+        ) -> Result;
+        CreateBuffer(
+            device: Device,
+            p_create_info: *const BufferCreateInfo,
+            p_allocator: *const AllocationCallbacks,
+            p_buffer: *mut Buffer,
+        ) -> Result;
+        DestroyBuffer(
+            device: Device,
+            buffer: Buffer,
+            p_allocator: *const AllocationCallbacks,
+        );
+        CreateBufferView(
+            device: Device,
+            p_create_info: *const BufferViewCreateInfo,
+            p_allocator: *const AllocationCallbacks,
+            p_view: *mut BufferView,
+        ) -> Result;
+        DestroyBufferView(
+            device: Device,
+            buffer_view: BufferView,
+            p_allocator: *const AllocationCallbacks,
+        );
+        CreateImage(
+            device: Device,
+            p_create_info: *const ImageCreateInfo,
+            p_allocator: *const AllocationCallbacks,
+            p_image: *mut Image,
+        ) -> Result;
+        DestroyImage(
+            device: Device,
+            image: Image,
+            p_allocator: *const AllocationCallbacks,
+        );
+        GetImageSubresourceLayout(
+            device: Device,
+            image: Image,
+            p_subresource: *const ImageSubresource,
+            p_layout: *mut SubresourceLayout,
+        );
+        CreateImageView(
+            device: Device,
+            p_create_info: *const ImageViewCreateInfo,
+            p_allocator: *const AllocationCallbacks,
+            p_view: *mut ImageView,
+        ) -> Result;
+        DestroyImageView(
+            device: Device,
+            image_view: ImageView,
+            p_allocator: *const AllocationCallbacks,
+        );
+        CreateShaderModule(
+            device: Device,
+            p_create_info: *const ShaderModuleCreateInfo,
+            p_allocator: *const AllocationCallbacks,
+            p_shader_module: *mut ShaderModule,
+        ) -> Result;
+        DestroyShaderModule(
+            device: Device,
+            shader_module: ShaderModule,
+            p_allocator: *const AllocationCallbacks,
+        );
+        CreatePipelineCache(
+            device: Device,
+            p_create_info: *const PipelineCacheCreateInfo,
+            p_allocator: *const AllocationCallbacks,
+            p_pipeline_cache: *mut PipelineCache,
+        ) -> Result;
+        DestroyPipelineCache(
+            device: Device,
+            pipeline_cache: PipelineCache,
+            p_allocator: *const AllocationCallbacks,
+        );
+        GetPipelineCacheData(
+            device: Device,
+            pipeline_cache: PipelineCache,
+            p_data_size: *mut usize,
+            p_data: *mut c_void,
+        ) -> Result;
+        MergePipelineCaches(
+            device: Device,
+            dst_cache: PipelineCache,
+            src_cache_count: u32,
+            p_src_caches: *const PipelineCache,
+        ) -> Result;
+        CreateGraphicsPipelines(
+            device: Device,
+            pipeline_cache: PipelineCache,
+            create_info_count: u32,
+            p_create_infos: *const GraphicsPipelineCreateInfo,
+            p_allocator: *const AllocationCallbacks,
+            p_pipelines: *mut Pipeline,
+        ) -> Result;
+        CreateComputePipelines(
+            device: Device,
+            pipeline_cache: PipelineCache,
+            create_info_count: u32,
+            p_create_infos: *const ComputePipelineCreateInfo,
+            p_allocator: *const AllocationCallbacks,
+            p_pipelines: *mut Pipeline,
+        ) -> Result;
+        DestroyPipeline(
+            device: Device,
+            pipeline: Pipeline,
+            p_allocator: *const AllocationCallbacks,
+        );
+        CreatePipelineLayout(
+            device: Device,
+            p_create_info: *const PipelineLayoutCreateInfo,
+            p_allocator: *const AllocationCallbacks,
+            p_pipeline_layout: *mut PipelineLayout,
+        ) -> Result;
+        DestroyPipelineLayout(
+            device: Device,
+            pipeline_layout: PipelineLayout,
+            p_allocator: *const AllocationCallbacks,
+        );
+        CreateSampler(
+            device: Device,
+            p_create_info: *const SamplerCreateInfo,
+            p_allocator: *const AllocationCallbacks,
+            p_sampler: *mut Sampler,
+        ) -> Result;
+        DestroySampler(
+            device: Device,
+            sampler: Sampler,
+            p_allocator: *const AllocationCallbacks,
+        );
+        CreateDescriptorSetLayout(
+            device: Device,
+            p_create_info: *const DescriptorSetLayoutCreateInfo,
+            p_allocator: *const AllocationCallbacks,
+            p_set_layout: *mut DescriptorSetLayout,
+        ) -> Result;
+        DestroyDescriptorSetLayout(
+            device: Device,
+            descriptor_set_layout: DescriptorSetLayout,
+            p_allocator: *const AllocationCallbacks,
+        );
+        CreateDescriptorPool(
+            device: Device,
+            p_create_info: *const DescriptorPoolCreateInfo,
+            p_allocator: *const AllocationCallbacks,
+            p_descriptor_pool: *mut DescriptorPool,
+        ) -> Result;
+        DestroyDescriptorPool(
+            device: Device,
+            descriptor_pool: DescriptorPool,
+            p_allocator: *const AllocationCallbacks,
+        );
+        ResetDescriptorPool(
+            device: Device,
+            descriptor_pool: DescriptorPool,
+            flags: DescriptorPoolResetFlags,
+        ) -> Result;
+        AllocateDescriptorSets(
+            device: Device,
+            p_allocate_info: *const DescriptorSetAllocateInfo,
+            p_descriptor_sets: *mut DescriptorSet,
+        ) -> Result;
+        FreeDescriptorSets(
+            device: Device,
+            descriptor_pool: DescriptorPool,
+            descriptor_set_count: u32,
+            p_descriptor_sets: *const DescriptorSet,
+        ) -> Result;
+        UpdateDescriptorSets(
+            device: Device,
+            descriptor_write_count: u32,
+            p_descriptor_writes: *const WriteDescriptorSet,
+            descriptor_copy_count: u32,
+            p_descriptor_copies: *const CopyDescriptorSet,
+        );
+        CreateFramebuffer(
+            device: Device,
+            p_create_info: *const FramebufferCreateInfo,
+            p_allocator: *const AllocationCallbacks,
+            p_framebuffer: *mut Framebuffer,
+        ) -> Result;
+        DestroyFramebuffer(
+            device: Device,
+            framebuffer: Framebuffer,
+            p_allocator: *const AllocationCallbacks,
+        );
+        CreateRenderPass(
+            device: Device,
+            p_create_info: *const RenderPassCreateInfo,
+            p_allocator: *const AllocationCallbacks,
+            p_render_pass: *mut RenderPass,
+        ) -> Result;
+        DestroyRenderPass(
+            device: Device,
+            render_pass: RenderPass,
+            p_allocator: *const AllocationCallbacks,
+        );
+        GetRenderAreaGranularity(
+            device: Device,
+            render_pass: RenderPass,
+            p_granularity: *mut Extent2D,
+        );
+        CreateCommandPool(
+            device: Device,
+            p_create_info: *const CommandPoolCreateInfo,
+            p_allocator: *const AllocationCallbacks,
+            p_command_pool: *mut CommandPool,
+        ) -> Result;
+        DestroyCommandPool(
+            device: Device,
+            command_pool: CommandPool,
+            p_allocator: *const AllocationCallbacks,
+        );
+        ResetCommandPool(
+            device: Device,
+            command_pool: CommandPool,
+            flags: CommandPoolResetFlags,
+        ) -> Result;
+        AllocateCommandBuffers(
+            device: Device,
+            p_allocate_info: *const CommandBufferAllocateInfo,
+            p_command_buffers: *mut CommandBuffer,
+        ) -> Result;
+        FreeCommandBuffers(
+            device: Device,
+            command_pool: CommandPool,
+            command_buffer_count: u32,
+            p_command_buffers: *const CommandBuffer,
+        );
+        BeginCommandBuffer(
+            command_buffer: CommandBuffer,
+            p_begin_info: *const CommandBufferBeginInfo,
+        ) -> Result;
+        EndCommandBuffer(
+            command_buffer: CommandBuffer,
+        ) -> Result;
+        ResetCommandBuffer(
+            command_buffer: CommandBuffer,
+            flags: CommandBufferResetFlags,
+        ) -> Result;
+        CmdBindPipeline(
+            command_buffer: CommandBuffer,
+            pipeline_bind_point: PipelineBindPoint,
+            pipeline: Pipeline,
+        );
+        CmdSetViewport(
+            command_buffer: CommandBuffer,
+            first_viewport: u32,
+            viewport_count: u32,
+            p_viewports: *const Viewport,
+        );
+        CmdSetScissor(
+            command_buffer: CommandBuffer,
+            first_scissor: u32,
+            scissor_count: u32,
+            p_scissors: *const Rect2D,
+        );
+        CmdSetLineWidth(
+            command_buffer: CommandBuffer,
+            line_width: c_float,
+        );
+        CmdSetDepthBias(
+            command_buffer: CommandBuffer,
+            depth_bias_constant_factor: c_float,
+            depth_bias_clamp: c_float,
+            depth_bias_slope_factor: c_float,
+        );
+        CmdSetDepthBounds(
+            command_buffer: CommandBuffer,
+            min_depth_bounds: c_float,
+            max_depth_bounds: c_float,
+        );
+        CmdSetStencilCompareMask(
+            command_buffer: CommandBuffer,
+            face_mask: StencilFaceFlags,
+            compare_mask: u32,
+        );
+        CmdSetStencilWriteMask(
+            command_buffer: CommandBuffer,
+            face_mask: StencilFaceFlags,
+            write_mask: u32,
+        );
+        CmdSetStencilReference(
+            command_buffer: CommandBuffer,
+            face_mask: StencilFaceFlags,
+            reference: u32,
+        );
+        CmdBindDescriptorSets(
+            command_buffer: CommandBuffer,
+            pipeline_bind_point: PipelineBindPoint,
+            layout: PipelineLayout,
+            first_set: u32,
+            descriptor_set_count: u32,
+            p_descriptor_sets: *const DescriptorSet,
+            dynamic_offset_count: u32,
+            p_dynamic_offsets: *const u32,
+        );
+        CmdBindIndexBuffer(
+            command_buffer: CommandBuffer,
+            buffer: Buffer,
+            offset: DeviceSize,
+            index_type: IndexType,
+        );
+        CmdBindVertexBuffers(
+            command_buffer: CommandBuffer,
+            first_binding: u32,
+            binding_count: u32,
+            p_buffers: *const Buffer,
+            p_offsets: *const DeviceSize,
+        );
+        CmdDraw(
+            command_buffer: CommandBuffer,
+            vertex_count: u32,
+            instance_count: u32,
+            first_vertex: u32,
+            first_instance: u32,
+        );
+        CmdDrawIndexed(
+            command_buffer: CommandBuffer,
+            index_count: u32,
+            instance_count: u32,
+            first_index: u32,
+            vertex_offset: i32,
+            first_instance: u32,
+        );
+        CmdDrawIndirect(
+            command_buffer: CommandBuffer,
+            buffer: Buffer,
+            offset: DeviceSize,
+            draw_count: u32,
+            stride: u32,
+        );
+        CmdDrawIndexedIndirect(
+            command_buffer: CommandBuffer,
+            buffer: Buffer,
+            offset: DeviceSize,
+            draw_count: u32,
+            stride: u32,
+        );
+        CmdDispatch(
+            command_buffer: CommandBuffer,
+            group_count_x: u32,
+            group_count_y: u32,
+            group_count_z: u32,
+        );
+        CmdDispatchIndirect(
+            command_buffer: CommandBuffer,
+            buffer: Buffer,
+            offset: DeviceSize,
+        );
+        CmdCopyBuffer(
+            command_buffer: CommandBuffer,
+            src_buffer: Buffer,
+            dst_buffer: Buffer,
+            region_count: u32,
+            p_regions: *const BufferCopy,
+        );
+        CmdCopyImage(
+            command_buffer: CommandBuffer,
+            src_image: Image,
+            src_image_layout: ImageLayout,
+            dst_image: Image,
+            dst_image_layout: ImageLayout,
+            region_count: u32,
+            p_regions: *const ImageCopy,
+        );
+        CmdBlitImage(
+            command_buffer: CommandBuffer,
+            src_image: Image,
+            src_image_layout: ImageLayout,
+            dst_image: Image,
+            dst_image_layout: ImageLayout,
+            region_count: u32,
+            p_regions: *const ImageBlit,
+            filter: Filter,
+        );
+        CmdCopyBufferToImage(
+            command_buffer: CommandBuffer,
+            src_buffer: Buffer,
+            dst_image: Image,
+            dst_image_layout: ImageLayout,
+            region_count: u32,
+            p_regions: *const BufferImageCopy,
+        );
+        CmdCopyImageToBuffer(
+            command_buffer: CommandBuffer,
+            src_image: Image,
+            src_image_layout: ImageLayout,
+            dst_buffer: Buffer,
+            region_count: u32,
+            p_regions: *const BufferImageCopy,
+        );
+        CmdUpdateBuffer(
+            command_buffer: CommandBuffer,
+            dst_buffer: Buffer,
+            dst_offset: DeviceSize,
+            data_size: DeviceSize,
+            p_data: *const c_void,
+        );
+        CmdFillBuffer(
+            command_buffer: CommandBuffer,
+            dst_buffer: Buffer,
+            dst_offset: DeviceSize,
+            size: DeviceSize,
+            data: u32,
+        );
+        CmdClearColorImage(
+            command_buffer: CommandBuffer,
+            image: Image,
+            image_layout: ImageLayout,
+            p_color: *const ClearColorValue,
+            range_count: u32,
+            p_ranges: *const ImageSubresourceRange,
+        );
+        CmdClearDepthStencilImage(
+            command_buffer: CommandBuffer,
+            image: Image,
+            image_layout: ImageLayout,
+            p_depth_stencil: *const ClearDepthStencilValue,
+            range_count: u32,
+            p_ranges: *const ImageSubresourceRange,
+        );
+        CmdClearAttachments(
+            command_buffer: CommandBuffer,
+            attachment_count: u32,
+            p_attachments: *const ClearAttachment,
+            rect_count: u32,
+            p_rects: *const ClearRect,
+        );
+        CmdResolveImage(
+            command_buffer: CommandBuffer,
+            src_image: Image,
+            src_image_layout: ImageLayout,
+            dst_image: Image,
+            dst_image_layout: ImageLayout,
+            region_count: u32,
+            p_regions: *const ImageResolve,
+        );
+        CmdSetEvent(
+            command_buffer: CommandBuffer,
+            event: Event,
+            stage_mask: PipelineStageFlags,
+        );
+        CmdResetEvent(
+            command_buffer: CommandBuffer,
+            event: Event,
+            stage_mask: PipelineStageFlags,
+        );
+        CmdWaitEvents(
+            command_buffer: CommandBuffer,
+            event_count: u32,
+            p_events: *const Event,
+            src_stage_mask: PipelineStageFlags,
+            dst_stage_mask: PipelineStageFlags,
+            memory_barrier_count: u32,
+            p_memory_barriers: *const MemoryBarrier,
+            buffer_memory_barrier_count: u32,
+            p_buffer_memory_barriers: *const BufferMemoryBarrier,
+            image_memory_barrier_count: u32,
+            p_image_memory_barriers: *const ImageMemoryBarrier,
+        );
+        CmdPipelineBarrier(
+            command_buffer: CommandBuffer,
+            src_stage_mask: PipelineStageFlags,
+            dst_stage_mask: PipelineStageFlags,
+            dependency_flags: DependencyFlags,
+            memory_barrier_count: u32,
+            p_memory_barriers: *const MemoryBarrier,
+            buffer_memory_barrier_count: u32,
+            p_buffer_memory_barriers: *const BufferMemoryBarrier,
+            image_memory_barrier_count: u32,
+            p_image_memory_barriers: *const ImageMemoryBarrier,
+        );
+        CmdBeginQuery(
+            command_buffer: CommandBuffer,
+            query_pool: QueryPool,
+            query: u32,
+            flags: QueryControlFlags,
+        );
+        CmdEndQuery(
+            command_buffer: CommandBuffer,
+            query_pool: QueryPool,
+            query: u32,
+        );
+        CmdResetQueryPool(
+            command_buffer: CommandBuffer,
+            query_pool: QueryPool,
+            first_query: u32,
+            query_count: u32,
+        );
+        CmdWriteTimestamp(
+            command_buffer: CommandBuffer,
+            pipeline_stage: PipelineStageFlagBits,
+            query_pool: QueryPool,
+            query: u32,
+        );
+        CmdCopyQueryPoolResults(
+            command_buffer: CommandBuffer,
+            query_pool: QueryPool,
+            first_query: u32,
+            query_count: u32,
+            dst_buffer: Buffer,
+            dst_offset: DeviceSize,
+            stride: DeviceSize,
+            flags: QueryResultFlags,
+        );
+        CmdPushConstants(
+            command_buffer: CommandBuffer,
+            layout: PipelineLayout,
+            stage_flags: ShaderStageFlags,
+            offset: u32,
+            size: u32,
+            p_values: *const c_void,
+        );
+        CmdBeginRenderPass(
+            command_buffer: CommandBuffer,
+            p_render_pass_begin: *const RenderPassBeginInfo,
+            contents: SubpassContents,
+        );
+        CmdNextSubpass(
+            command_buffer: CommandBuffer,
+            contents: SubpassContents,
+        );
+        CmdEndRenderPass(
+            command_buffer: CommandBuffer,
+        );
+        CmdExecuteCommands(
+            command_buffer: CommandBuffer,
+            command_buffer_count: u32,
+            p_command_buffers: *const CommandBuffer,
+        );
+    }
+    "linked-1-1" {
+        EnumerateInstanceVersion(
+            p_api_version: *mut u32,
+        ) -> Result;
+        GetPhysicalDeviceFeatures2(
+            physical_device: PhysicalDevice,
+            p_features: *mut PhysicalDeviceFeatures2,
+        );
+        GetPhysicalDeviceProperties2(
+            physical_device: PhysicalDevice,
+            p_properties: *mut PhysicalDeviceProperties2,
+        );
+        GetPhysicalDeviceFormatProperties2(
+            physical_device: PhysicalDevice,
+            format: Format,
+            p_format_properties: *mut FormatProperties2,
+        );
+        GetPhysicalDeviceImageFormatProperties2(
+            physical_device: PhysicalDevice,
+            p_image_format_info: *const PhysicalDeviceImageFormatInfo2,
+            p_image_format_properties: *mut ImageFormatProperties2,
+        ) -> Result;
+        GetPhysicalDeviceQueueFamilyProperties2(
+            physical_device: PhysicalDevice,
+            p_queue_family_property_count: *mut u32,
+            p_queue_family_properties: *mut QueueFamilyProperties2,
+        );
+        GetPhysicalDeviceMemoryProperties2(
+            physical_device: PhysicalDevice,
+            p_memory_properties: *mut PhysicalDeviceMemoryProperties2,
+        );
+        GetPhysicalDeviceSparseImageFormatProperties2(
+            physical_device: PhysicalDevice,
+            p_format_info: *const PhysicalDeviceSparseImageFormatInfo2,
+            p_property_count: *mut u32,
+            p_properties: *mut SparseImageFormatProperties2,
+        );
+        TrimCommandPool(
+            device: Device,
+            command_pool: CommandPool,
+            flags: CommandPoolTrimFlags,
+        );
+        GetPhysicalDeviceExternalBufferProperties(
+            physical_device: PhysicalDevice,
+            p_external_buffer_info: *const PhysicalDeviceExternalBufferInfo,
+            p_external_buffer_properties: *mut ExternalBufferProperties,
+        );
+        GetPhysicalDeviceExternalSemaphoreProperties(
+            physical_device: PhysicalDevice,
+            p_external_semaphore_info: *const PhysicalDeviceExternalSemaphoreInfo,
+            p_external_semaphore_properties: *mut ExternalSemaphoreProperties,
+        );
+        GetPhysicalDeviceExternalFenceProperties(
+            physical_device: PhysicalDevice,
+            p_external_fence_info: *const PhysicalDeviceExternalFenceInfo,
+            p_external_fence_properties: *mut ExternalFenceProperties,
+        );
+        EnumeratePhysicalDeviceGroups(
+            instance: Instance,
+            p_physical_device_group_count: *mut u32,
+            p_physical_device_group_properties: *mut PhysicalDeviceGroupProperties,
+        ) -> Result;
+        GetDeviceGroupPeerMemoryFeatures(
+            device: Device,
+            heap_index: u32,
+            local_device_index: u32,
+            remote_device_index: u32,
+            p_peer_memory_features: *mut PeerMemoryFeatureFlags,
+        );
+        BindBufferMemory2(
+            device: Device,
+            bind_info_count: u32,
+            p_bind_infos: *const BindBufferMemoryInfo,
+        ) -> Result;
+        BindImageMemory2(
+            device: Device,
+            bind_info_count: u32,
+            p_bind_infos: *const BindImageMemoryInfo,
+        ) -> Result;
+        CmdSetDeviceMask(
+            command_buffer: CommandBuffer,
+            device_mask: u32,
+        );
+        CmdDispatchBase(
+            command_buffer: CommandBuffer,
+            base_group_x: u32,
+            base_group_y: u32,
+            base_group_z: u32,
+            group_count_x: u32,
+            group_count_y: u32,
+            group_count_z: u32,
+        );
+        CreateDescriptorUpdateTemplate(
+            device: Device,
+            p_create_info: *const DescriptorUpdateTemplateCreateInfo,
+            p_allocator: *const AllocationCallbacks,
+            p_descriptor_update_template: *mut DescriptorUpdateTemplate,
+        ) -> Result;
+        DestroyDescriptorUpdateTemplate(
+            device: Device,
+            descriptor_update_template: DescriptorUpdateTemplate,
+            p_allocator: *const AllocationCallbacks,
+        );
+        UpdateDescriptorSetWithTemplate(
+            device: Device,
+            descriptor_set: DescriptorSet,
+            descriptor_update_template: DescriptorUpdateTemplate,
+            p_data: *const c_void,
+        );
+        GetBufferMemoryRequirements2(
+            device: Device,
+            p_info: *const BufferMemoryRequirementsInfo2,
+            p_memory_requirements: *mut MemoryRequirements2,
+        );
+        GetImageMemoryRequirements2(
+            device: Device,
+            p_info: *const ImageMemoryRequirementsInfo2,
+            p_memory_requirements: *mut MemoryRequirements2,
+        );
+        GetImageSparseMemoryRequirements2(
+            device: Device,
+            p_info: *const ImageSparseMemoryRequirementsInfo2,
+            p_sparse_memory_requirement_count: *mut u32,
+            p_sparse_memory_requirements: *mut SparseImageMemoryRequirements2,
+        );
+        CreateSamplerYcbcrConversion(
+            device: Device,
+            p_create_info: *const SamplerYcbcrConversionCreateInfo,
+            p_allocator: *const AllocationCallbacks,
+            p_ycbcr_conversion: *mut SamplerYcbcrConversion,
+        ) -> Result;
+        DestroySamplerYcbcrConversion(
+            device: Device,
+            ycbcr_conversion: SamplerYcbcrConversion,
+            p_allocator: *const AllocationCallbacks,
+        );
+        GetDeviceQueue2(
+            device: Device,
+            p_queue_info: *const DeviceQueueInfo2,
+            p_queue: *mut Queue,
+        );
+        GetDescriptorSetLayoutSupport(
+            device: Device,
+            p_create_info: *const DescriptorSetLayoutCreateInfo,
+            p_support: *mut DescriptorSetLayoutSupport,
+        );
+    }
+    "linked-1-2" {
+        ResetQueryPool(
+            device: Device,
+            query_pool: QueryPool,
+            first_query: u32,
+            query_count: u32,
+        );
+        CreateRenderPass2(
+            device: Device,
+            p_create_info: *const RenderPassCreateInfo2,
+            p_allocator: *const AllocationCallbacks,
+            p_render_pass: *mut RenderPass,
+        ) -> Result;
+        CmdBeginRenderPass2(
+            command_buffer: CommandBuffer,
+            p_render_pass_begin: *const RenderPassBeginInfo,
+            p_subpass_begin_info: *const SubpassBeginInfo,
+        );
+        CmdNextSubpass2(
+            command_buffer: CommandBuffer,
+            p_subpass_begin_info: *const SubpassBeginInfo,
+            p_subpass_end_info: *const SubpassEndInfo,
+        );
+        CmdEndRenderPass2(
+            command_buffer: CommandBuffer,
+            p_subpass_end_info: *const SubpassEndInfo,
+        );
+        GetSemaphoreCounterValue(
+            device: Device,
+            semaphore: Semaphore,
+            p_value: *mut u64,
+        ) -> Result;
+        WaitSemaphores(
+            device: Device,
+            p_wait_info: *const SemaphoreWaitInfo,
+            timeout: u64,
+        ) -> Result;
+        SignalSemaphore(
+            device: Device,
+            p_signal_info: *const SemaphoreSignalInfo,
+        ) -> Result;
+        CmdDrawIndirectCount(
+            command_buffer: CommandBuffer,
+            buffer: Buffer,
+            offset: DeviceSize,
+            count_buffer: Buffer,
+            count_buffer_offset: DeviceSize,
+            max_draw_count: u32,
+            stride: u32,
+        );
+        CmdDrawIndexedIndirectCount(
+            command_buffer: CommandBuffer,
+            buffer: Buffer,
+            offset: DeviceSize,
+            count_buffer: Buffer,
+            count_buffer_offset: DeviceSize,
+            max_draw_count: u32,
+            stride: u32,
+        );
+        GetBufferOpaqueCaptureAddress(
+            device: Device,
+            p_info: *const BufferDeviceAddressInfo,
+        ) -> u64;
+        GetBufferDeviceAddress(
+            device: Device,
+            p_info: *const BufferDeviceAddressInfo,
+        ) -> DeviceAddress;
+        GetDeviceMemoryOpaqueCaptureAddress(
+            device: Device,
+            p_info: *const DeviceMemoryOpaqueCaptureAddressInfo,
+        ) -> u64;
+    }
+    "linked-1-3" {
+        GetDeviceBufferMemoryRequirements(
+            device: Device,
+            p_info: *const DeviceBufferMemoryRequirements,
+            p_memory_requirements: *mut MemoryRequirements2,
+        );
+        GetDeviceImageMemoryRequirements(
+            device: Device,
+            p_info: *const DeviceImageMemoryRequirements,
+            p_memory_requirements: *mut MemoryRequirements2,
+        );
+        GetDeviceImageSparseMemoryRequirements(
+            device: Device,
+            p_info: *const DeviceImageMemoryRequirements,
+            p_sparse_memory_requirement_count: *mut u32,
+            p_sparse_memory_requirements: *mut SparseImageMemoryRequirements2,
+        );
+        GetPhysicalDeviceToolProperties(
+            physical_device: PhysicalDevice,
+            p_tool_count: *mut u32,
+            p_tool_properties: *mut PhysicalDeviceToolProperties,
+        ) -> Result;
+        CmdSetCullMode(
+            command_buffer: CommandBuffer,
+            cull_mode: CullModeFlags,
+        );
+        CmdSetFrontFace(
+            command_buffer: CommandBuffer,
+            front_face: FrontFace,
+        );
+        CmdSetPrimitiveTopology(
+            command_buffer: CommandBuffer,
+            primitive_topology: PrimitiveTopology,
+        );
+        CmdSetViewportWithCount(
+            command_buffer: CommandBuffer,
+            viewport_count: u32,
+            p_viewports: *const Viewport,
+        );
+        CmdSetScissorWithCount(
+            command_buffer: CommandBuffer,
+            scissor_count: u32,
+            p_scissors: *const Rect2D,
+        );
+        CmdBindVertexBuffers2(
+            command_buffer: CommandBuffer,
+            first_binding: u32,
+            binding_count: u32,
+            p_buffers: *const Buffer,
+            p_offsets: *const DeviceSize,
+            p_sizes: *const DeviceSize,
+            p_strides: *const DeviceSize,
+        );
+        CmdSetDepthTestEnable(
+            command_buffer: CommandBuffer,
+            depth_test_enable: Bool32,
+        );
+        CmdSetDepthWriteEnable(
+            command_buffer: CommandBuffer,
+            depth_write_enable: Bool32,
+        );
+        CmdSetDepthCompareOp(
+            command_buffer: CommandBuffer,
+            depth_compare_op: CompareOp,
+        );
+        CmdSetDepthBoundsTestEnable(
+            command_buffer: CommandBuffer,
+            depth_bounds_test_enable: Bool32,
+        );
+        CmdSetStencilTestEnable(
+            command_buffer: CommandBuffer,
+            stencil_test_enable: Bool32,
+        );
+        CmdSetStencilOp(
+            command_buffer: CommandBuffer,
+            face_mask: StencilFaceFlags,
+            fail_op: StencilOp,
+            pass_op: StencilOp,
+            depth_fail_op: StencilOp,
+            compare_op: CompareOp,
+        );
+        CmdSetRasterizerDiscardEnable(
+            command_buffer: CommandBuffer,
+            rasterizer_discard_enable: Bool32,
+        );
+        CmdSetDepthBiasEnable(
+            command_buffer: CommandBuffer,
+            depth_bias_enable: Bool32,
+        );
+        CmdSetPrimitiveRestartEnable(
+            command_buffer: CommandBuffer,
+            primitive_restart_enable: Bool32,
+        );
+        CreatePrivateDataSlot(
+            device: Device,
+            p_create_info: *const PrivateDataSlotCreateInfo,
+            p_allocator: *const AllocationCallbacks,
+            p_private_data_slot: *mut PrivateDataSlot,
+        ) -> Result;
+        DestroyPrivateDataSlot(
+            device: Device,
+            private_data_slot: PrivateDataSlot,
+            p_allocator: *const AllocationCallbacks,
+        );
+        SetPrivateData(
+            device: Device,
+            object_type: ObjectType,
+            object_handle: u64,
+            private_data_slot: PrivateDataSlot,
+            data: u64,
+        ) -> Result;
+        GetPrivateData(
+            device: Device,
+            object_type: ObjectType,
+            object_handle: u64,
+            private_data_slot: PrivateDataSlot,
+            p_data: *mut u64,
+        );
+        CmdCopyBuffer2(
+            command_buffer: CommandBuffer,
+            p_copy_buffer_info: *const CopyBufferInfo2,
+        );
+        CmdCopyImage2(
+            command_buffer: CommandBuffer,
+            p_copy_image_info: *const CopyImageInfo2,
+        );
+        CmdBlitImage2(
+            command_buffer: CommandBuffer,
+            p_blit_image_info: *const BlitImageInfo2,
+        );
+        CmdCopyBufferToImage2(
+            command_buffer: CommandBuffer,
+            p_copy_buffer_to_image_info: *const CopyBufferToImageInfo2,
+        );
+        CmdCopyImageToBuffer2(
+            command_buffer: CommandBuffer,
+            p_copy_image_to_buffer_info: *const CopyImageToBufferInfo2,
+        );
+        CmdResolveImage2(
+            command_buffer: CommandBuffer,
+            p_resolve_image_info: *const ResolveImageInfo2,
+        );
+        CmdSetEvent2(
+            command_buffer: CommandBuffer,
+            event: Event,
+            p_dependency_info: *const DependencyInfo,
+        );
+        CmdResetEvent2(
+            command_buffer: CommandBuffer,
+            event: Event,
+            stage_mask: PipelineStageFlags2,
+        );
+        CmdWaitEvents2(
+            command_buffer: CommandBuffer,
+            event_count: u32,
+            p_events: *const Event,
+            p_dependency_infos: *const DependencyInfo,
+        );
+        CmdPipelineBarrier2(
+            command_buffer: CommandBuffer,
+            p_dependency_info: *const DependencyInfo,
+        );
+        QueueSubmit2(
+            queue: Queue,
+            submit_count: u32,
+            p_submits: *const SubmitInfo2,
+            fence: Fence,
+        ) -> Result;
+        CmdWriteTimestamp2(
+            command_buffer: CommandBuffer,
+            stage: PipelineStageFlags2,
+            query_pool: QueryPool,
+            query: u32,
+        );
+        CmdBeginRendering(
+            command_buffer: CommandBuffer,
+            p_rendering_info: *const RenderingInfo,
+        );
+        CmdEndRendering(
+            command_buffer: CommandBuffer,
+        );
+    }
+}
+
+impl_table! {
+    name: InstanceTable,
+    lazy_name: LazyInstanceTable,
+    get_proc_addr: GetInstanceProcAddr,
+    handle: {
+        name: instance,
+        type: Instance,
+    },
+    members: [
+        {
+            name: pfn_destroy_instance,
+            method_name: destroy_instance,
+            try_method_name: try_destroy_instance,
+            unchecked_method_name: destroy_instance_unchecked,
+            ptr: DestroyInstance,
+            signature: (
+                p_allocator: *const AllocationCallbacks,
+            ),
+            takes_handle: true,
+            provided_by: [
+                [VK_VERSION_1_0] => DestroyInstance,
+            ],
+            hooks: [
+                p_allocator: structs,
+            ],
+        },
+        {
+            name: pfn_enumerate_physical_devices,
+            method_name: enumerate_physical_devices,
+            try_method_name: try_enumerate_physical_devices,
+            unchecked_method_name: enumerate_physical_devices_unchecked,
+            ptr: EnumeratePhysicalDevices,
+            signature: (
+                p_physical_device_count: *mut u32,
+                p_physical_devices: *mut PhysicalDevice,
+            ) -> Result,
+            takes_handle: true,
+            provided_by: [
+                [VK_VERSION_1_0] => EnumeratePhysicalDevices,
+            ],
+            hooks: [
+                p_physical_device_count: ptr,
+                p_physical_devices: handles[*p_physical_device_count],
+            ],
+            vec: {
+                method_name: enumerate_physical_devices_vec,
+                args: (
+                ),
+                element: PhysicalDevice,
+                result: success,
+            },
+        },
+        {
+            name: pfn_get_instance_proc_addr,
+            method_name: get_instance_proc_addr,
+            try_method_name: try_get_instance_proc_addr,
+            unchecked_method_name: get_instance_proc_addr_unchecked,
+            ptr: GetInstanceProcAddr,
+            signature: (
+                p_name: *const c_char,
+            ) -> crate::pfn::VoidFunction,
+            takes_handle: true,
+            provided_by: [
+                [VK_VERSION_1_0] => GetInstanceProcAddr,
+            ],
+            hooks: [
+                p_name: str,
+            ],
+        },
+        {
+            name: pfn_get_physical_device_properties,
+            method_name: get_physical_device_properties,
+            try_method_name: try_get_physical_device_properties,
+            unchecked_method_name: get_physical_device_properties_unchecked,
+            ptr: GetPhysicalDeviceProperties,
+            signature: (
+                physical_device: PhysicalDevice,
+                p_properties: *mut PhysicalDeviceProperties,
+            ),
+            takes_handle: false,
+            provided_by: [
+                [VK_VERSION_1_0] => GetPhysicalDeviceProperties,
+            ],
+            hooks: [
+                physical_device: handle,
+                p_properties: ptr,
+            ],
+            checked: {
+                method_name: get_physical_device_properties_checked,
+                args: (
+                    physical_device: PhysicalDevice,
+                ),
+                output: p_properties: PhysicalDeviceProperties,
+                result: void,
+            },
+        },
+        {
+            name: pfn_get_physical_device_queue_family_properties,
+            method_name: get_physical_device_queue_family_properties,
+            try_method_name: try_get_physical_device_queue_family_properties,
+            unchecked_method_name: get_physical_device_queue_family_properties_unchecked,
+            ptr: GetPhysicalDeviceQueueFamilyProperties,
+            signature: (
+                physical_device: PhysicalDevice,
+                p_queue_family_property_count: *mut u32,
+                p_queue_family_properties: *mut QueueFamilyProperties,
+            ),
+            takes_handle: false,
+            provided_by: [
+                [VK_VERSION_1_0] => GetPhysicalDeviceQueueFamilyProperties,
+            ],
+            hooks: [
+                physical_device: handle,
+                p_queue_family_property_count: ptr,
+                p_queue_family_properties: ptr[*p_queue_family_property_count],
+            ],
+            vec: {
+                method_name: get_physical_device_queue_family_properties_vec,
+                args: (
+                    physical_device: PhysicalDevice,
+                ),
+                element: QueueFamilyProperties,
+                result: void,
+            },
+        },
+        {
+            name: pfn_get_physical_device_memory_properties,
+            method_name: get_physical_device_memory_properties,
+            try_method_name: try_get_physical_device_memory_properties,
+            unchecked_method_name: get_physical_device_memory_properties_unchecked,
+            ptr: GetPhysicalDeviceMemoryProperties,
+            signature: (
+                physical_device: PhysicalDevice,
+                p_memory_properties: *mut PhysicalDeviceMemoryProperties,
+            ),
+            takes_handle: false,
+            provided_by: [
+                [VK_VERSION_1_0] => GetPhysicalDeviceMemoryProperties,
+            ],
+            hooks: [
+                physical_device: handle,
+                p_memory_properties: ptr,
+            ],
+            checked: {
+                method_name: get_physical_device_memory_properties_checked,
+                args: (
+                    physical_device: PhysicalDevice,
+                ),
+                output: p_memory_properties: PhysicalDeviceMemoryProperties,
+                result: void,
+            },
+        },
+        {
+            name: pfn_get_physical_device_features,
+            method_name: get_physical_device_features,
+            try_method_name: try_get_physical_device_features,
+            unchecked_method_name: get_physical_device_features_unchecked,
+            ptr: GetPhysicalDeviceFeatures,
+            signature: (
+                physical_device: PhysicalDevice,
+                p_features: *mut PhysicalDeviceFeatures,
+            ),
+            takes_handle: false,
+            provided_by: [
+                [VK_VERSION_1_0] => GetPhysicalDeviceFeatures,
+            ],
+            hooks: [
+                physical_device: handle,
+                p_features: ptr,
+            ],
+            checked: {
+                method_name: get_physical_device_features_checked,
+                args: (
+                    physical_device: PhysicalDevice,
+                ),
+                output: p_features: PhysicalDeviceFeatures,
+                result: void,
+            },
+        },
+        {
+            name: pfn_get_physical_device_format_properties,
+            method_name: get_physical_device_format_properties,
+            try_method_name: try_get_physical_device_format_properties,
+            unchecked_method_name: get_physical_device_format_properties_unchecked,
+            ptr: GetPhysicalDeviceFormatProperties,
+            signature: (
+                physical_device: PhysicalDevice,
+                format: Format,
+                p_format_properties: *mut FormatProperties,
+            ),
+            takes_handle: false,
+            provided_by: [
+                [VK_VERSION_1_0] => GetPhysicalDeviceFormatProperties,
+            ],
+            hooks: [
+                physical_device: handle,
+                format: value,
+                p_format_properties: ptr,
+            ],
+            checked: {
+                method_name: get_physical_device_format_properties_checked,
+                args: (
+                    physical_device: PhysicalDevice,
+                    format: Format,
+                ),
+                output: p_format_properties: FormatProperties,
+                result: void,
+            },
+        },
+        {
+            name: pfn_get_physical_device_image_format_properties,
+            method_name: get_physical_device_image_format_properties,
+            try_method_name: try_get_physical_device_image_format_properties,
+            unchecked_method_name: get_physical_device_image_format_properties_unchecked,
+            ptr: GetPhysicalDeviceImageFormatProperties,
+            signature: (
+                physical_device: PhysicalDevice,
+                format: Format,
+                ty: ImageType,
+                tiling: ImageTiling,
+                usage: ImageUsageFlags,
+                flags: ImageCreateFlags,
+                p_image_format_properties: *mut ImageFormatProperties,
+            ) -> Result,
+            takes_handle: false,
+            provided_by: [
+                [VK_VERSION_1_0] => GetPhysicalDeviceImageFormatProperties,
+            ],
+            hooks: [
+                physical_device: handle,
+                format: value,
+                ty: value,
+                tiling: value,
+                usage: value,
+                flags: value,
+                p_image_format_properties: ptr,
+            ],
+            checked: {
+                method_name: get_physical_device_image_format_properties_checked,
+                args: (
+                    physical_device: PhysicalDevice,
+                    format: Format,
+                    ty: ImageType,
+                    tiling: ImageTiling,
+                    usage: ImageUsageFlags,
+                    flags: ImageCreateFlags,
+                ),
+                output: p_image_format_properties: ImageFormatProperties,
+                result: success,
+            },
+        },
+        {
+            name: pfn_create_device,
+            method_name: create_device,
+            try_method_name: try_create_device,
+            unchecked_method_name: create_device_unchecked,
+            ptr: CreateDevice,
+            signature: (
+                physical_device: PhysicalDevice,
+                p_create_info: *const DeviceCreateInfo,
+                p_allocator: *const AllocationCallbacks,
+                p_device: *mut Device,
+            ) -> Result,
+            takes_handle: false,
+            provided_by: [
+                [VK_VERSION_1_0] => CreateDevice,
+            ],
+            hooks: [
+                physical_device: handle,
+                p_create_info: structs,
+                p_allocator: structs,
+                p_device: handles,
+            ],
+            checked: {
+                method_name: create_device_checked,
+                args: (
+                    physical_device: PhysicalDevice,
+                    p_create_info: *const DeviceCreateInfo,
+                    p_allocator: Option<&AllocationCallbacks>,
+                ),
+                output: p_device: Device,
+                result: success,
+            },
+        },
+        {
+            name: pfn_enumerate_device_layer_properties,
+            method_name: enumerate_device_layer_properties,
+            try_method_name: try_enumerate_device_layer_properties,
+            unchecked_method_name: enumerate_device_layer_properties_unchecked,
+            ptr: EnumerateDeviceLayerProperties,
+            signature: (
+                physical_device: PhysicalDevice,
+                p_property_count: *mut u32,
+                p_properties: *mut LayerProperties,
+            ) -> Result,
+            takes_handle: false,
+            provided_by: [
+                [VK_VERSION_1_0] => EnumerateDeviceLayerProperties,
+            ],
+            hooks: [
+                physical_device: handle,
+                p_property_count: ptr,
+                p_properties: ptr[*p_property_count],
+            ],
+            vec: {
+                method_name: enumerate_device_layer_properties_vec,
+                args: (
+                    physical_device: PhysicalDevice,
+                ),
+                element: LayerProperties,
+                result: success,
+            },
+        },
+        {
+            name: pfn_enumerate_device_extension_properties,
+            method_name: enumerate_device_extension_properties,
+            try_method_name: try_enumerate_device_extension_properties,
+            unchecked_method_name: enumerate_device_extension_properties_unchecked,
+            ptr: EnumerateDeviceExtensionProperties,
+            signature: (
+                physical_device: PhysicalDevice,
+                p_layer_name: *const c_char,
+                p_property_count: *mut u32,
+                p_properties: *mut ExtensionProperties,
+            ) -> Result,
+            takes_handle: false,
+            provided_by: [
+                [VK_VERSION_1_0] => EnumerateDeviceExtensionProperties,
+            ],
+            hooks: [
+                physical_device: handle,
+                p_layer_name: str,
+                p_property_count: ptr,
+                p_properties: ptr[*p_property_count],
+            ],
+            vec: {
+                method_name: enumerate_device_extension_properties_vec,
+                args: (
+                    physical_device: PhysicalDevice,
+                    p_layer_name: *const c_char,
+                ),
+                element: ExtensionProperties,
+                result: success,
+            },
+        },
+        {
+            name: pfn_get_physical_device_sparse_image_format_properties,
+            method_name: get_physical_device_sparse_image_format_properties,
+            try_method_name: try_get_physical_device_sparse_image_format_properties,
+            unchecked_method_name: get_physical_device_sparse_image_format_properties_unchecked,
+            ptr: GetPhysicalDeviceSparseImageFormatProperties,
+            signature: (
+                physical_device: PhysicalDevice,
+                format: Format,
+                ty: ImageType,
+                samples: SampleCountFlagBits,
+                usage: ImageUsageFlags,
+                tiling: ImageTiling,
+                p_property_count: *mut u32,
+                p_properties: *mut SparseImageFormatProperties,
+            ),
+            takes_handle: false,
+            provided_by: [
+                [VK_VERSION_1_0] => GetPhysicalDeviceSparseImageFormatProperties,
+            ],
+            hooks: [
+                physical_device: handle,
+                format: value,
+                ty: value,
+                samples: value,
+                usage: value,
+                tiling: value,
+                p_property_count: ptr,
+                p_properties: ptr[*p_property_count],
+            ],
+            vec: {
+                method_name: get_physical_device_sparse_image_format_properties_vec,
+                args: (
+                    physical_device: PhysicalDevice,
+                    format: Format,
+                    ty: ImageType,
+                    samples: SampleCountFlagBits,
+                    usage: ImageUsageFlags,
+                    tiling: ImageTiling,
+                ),
+                element: SparseImageFormatProperties,
+                result: void,
+            },
+        },
+        {
+            name: pfn_create_android_surface_khr,
+            method_name: create_android_surface_khr,
+            try_method_name: try_create_android_surface_khr,
+            unchecked_method_name: create_android_surface_khr_unchecked,
+            ptr: CreateAndroidSurfaceKHR,
+            signature: (
+                p_create_info: *const AndroidSurfaceCreateInfoKHR,
+                p_allocator: *const AllocationCallbacks,
+                p_surface: *mut SurfaceKHR,
+            ) -> Result,
+            takes_handle: true,
+            provided_by: [
+                [VK_KHR_android_surface] => CreateAndroidSurfaceKHR,
+            ],
+            hooks: [
+                p_create_info: structs,
+                p_allocator: structs,
+                p_surface: handles,
+            ],
+            checked: {
+                method_name: create_android_surface_khr_checked,
+                args: (
+                    p_create_info: *const AndroidSurfaceCreateInfoKHR,
+                    p_allocator: Option<&AllocationCallbacks>,
+                ),
+                output: p_surface: SurfaceKHR,
+                result: success,
+            },
+        },
+        {
+            name: pfn_get_physical_device_display_properties_khr,
+            method_name: get_physical_device_display_properties_khr,
+            try_method_name: try_get_physical_device_display_properties_khr,
+            unchecked_method_name: get_physical_device_display_properties_khr_unchecked,
+            ptr: GetPhysicalDeviceDisplayPropertiesKHR,
+            signature: (
+                physical_device: PhysicalDevice,
+                p_property_count: *mut u32,
+                p_properties: *mut DisplayPropertiesKHR,
+            ) -> Result,
+            takes_handle: false,
+            provided_by: [
+                [VK_KHR_display] => GetPhysicalDeviceDisplayPropertiesKHR,
+            ],
+            hooks: [
+                physical_device: handle,
+                p_property_count: ptr,
+                p_properties: structs[*p_property_count],
+            ],
+            vec: {
+                method_name: get_physical_device_display_properties_khr_vec,
+                args: (
+                    physical_device: PhysicalDevice,
+                ),
+                element: DisplayPropertiesKHR,
+                result: success,
+            },
+        },
+        {
+            name: pfn_get_physical_device_display_plane_properties_khr,
+            method_name: get_physical_device_display_plane_properties_khr,
+            try_method_name: try_get_physical_device_display_plane_properties_khr,
+            unchecked_method_name: get_physical_device_display_plane_properties_khr_unchecked,
+            ptr: GetPhysicalDeviceDisplayPlanePropertiesKHR,
+            signature: (
+                physical_device: PhysicalDevice,
+                p_property_count: *mut u32,
+                p_properties: *mut DisplayPlanePropertiesKHR,
+            ) -> Result,
+            takes_handle: false,
+            provided_by: [
+                [VK_KHR_display] => GetPhysicalDeviceDisplayPlanePropertiesKHR,
+            ],
+            hooks: [
+                physical_device: handle,
+                p_property_count: ptr,
+                p_properties: structs[*p_property_count],
+            ],
+            vec: {
+                method_name: get_physical_device_display_plane_properties_khr_vec,
+                args: (
+                    physical_device: PhysicalDevice,
+                ),
+                element: DisplayPlanePropertiesKHR,
+                result: success,
+            },
+        },
+        {
+            name: pfn_get_display_plane_supported_displays_khr,
+            method_name: get_display_plane_supported_displays_khr,
+            try_method_name: try_get_display_plane_supported_displays_khr,
+            unchecked_method_name: get_display_plane_supported_displays_khr_unchecked,
+            ptr: GetDisplayPlaneSupportedDisplaysKHR,
+            signature: (
+                physical_device: PhysicalDevice,
+                plane_index: u32,
+                p_display_count: *mut u32,
+                p_displays: *mut DisplayKHR,
+            ) -> Result,
+            takes_handle: false,
+            provided_by: [
+                [VK_KHR_display] => GetDisplayPlaneSupportedDisplaysKHR,
+            ],
+            hooks: [
+                physical_device: handle,
+                plane_index: value,
+                p_display_count: ptr,
+                p_displays: handles[*p_display_count],
+            ],
+            vec: {
+                method_name: get_display_plane_supported_displays_khr_vec,
+                args: (
+                    physical_device: PhysicalDevice,
+                    plane_index: u32,
+                ),
+                element: DisplayKHR,
+                result: success,
+            },
+        },
+        {
+            name: pfn_get_display_mode_properties_khr,
+            method_name: get_display_mode_properties_khr,
+            try_method_name: try_get_display_mode_properties_khr,
+            unchecked_method_name: get_display_mode_properties_khr_unchecked,
+            ptr: GetDisplayModePropertiesKHR,
+            signature: (
+                physical_device: PhysicalDevice,
+                display: DisplayKHR,
+                p_property_count: *mut u32,
+                p_properties: *mut DisplayModePropertiesKHR,
+            ) -> Result,
+            takes_handle: false,
+            provided_by: [
+                [VK_KHR_display] => GetDisplayModePropertiesKHR,
+            ],
+            hooks: [
+                physical_device: handle,
+                display: handle,
+                p_property_count: ptr,
+                p_properties: structs[*p_property_count],
+            ],
+            vec: {
+                method_name: get_display_mode_properties_khr_vec,
+                args: (
+                    physical_device: PhysicalDevice,
+                    display: DisplayKHR,
+                ),
+                element: DisplayModePropertiesKHR,
+                result: success,
+            },
+        },
+        {
+            name: pfn_create_display_mode_khr,
+            method_name: create_display_mode_khr,
+            try_method_name: try_create_display_mode_khr,
+            unchecked_method_name: create_display_mode_khr_unchecked,
+            ptr: CreateDisplayModeKHR,
+            signature: (
+                physical_device: PhysicalDevice,
+                display: DisplayKHR,
+                p_create_info: *const DisplayModeCreateInfoKHR,
+                p_allocator: *const AllocationCallbacks,
+                p_mode: *mut DisplayModeKHR,
+            ) -> Result,
+            takes_handle: false,
+            provided_by: [
+                [VK_KHR_display] => CreateDisplayModeKHR,
+            ],
+            hooks: [
+                physical_device: handle,
+                display: handle,
+                p_create_info: structs,
+                p_allocator: structs,
+                p_mode: handles,
+            ],
+            checked: {
+                method_name: create_display_mode_khr_checked,
+                args: (
+                    physical_device: PhysicalDevice,
+                    display: DisplayKHR,
+                    p_create_info: *const DisplayModeCreateInfoKHR,
+                    p_allocator: Option<&AllocationCallbacks>,
+                ),
+                output: p_mode: DisplayModeKHR,
+                result: success,
+            },
+        },
+        {
+            name: pfn_get_display_plane_capabilities_khr,
+            method_name: get_display_plane_capabilities_khr,
+            try_method_name: try_get_display_plane_capabilities_khr,
+            unchecked_method_name: get_display_plane_capabilities_khr_unchecked,
+            ptr: GetDisplayPlaneCapabilitiesKHR,
+            signature: (
+                physical_device: PhysicalDevice,
+                mode: DisplayModeKHR,
+                plane_index: u32,
+                p_capabilities: *mut DisplayPlaneCapabilitiesKHR,
+            ) -> Result,
+            takes_handle: false,
+            provided_by: [
+                [VK_KHR_display] => GetDisplayPlaneCapabilitiesKHR,
+            ],
+            hooks: [
+                physical_device: handle,
+                mode: handle,
+                plane_index: value,
+                p_capabilities: ptr,
+            ],
+            checked: {
+                method_name: get_display_plane_capabilities_khr_checked,
+                args: (
+                    physical_device: PhysicalDevice,
+                    mode: DisplayModeKHR,
+                    plane_index: u32,
+                ),
+                output: p_capabilities: DisplayPlaneCapabilitiesKHR,
+                result: success,
+            },
+        },
+        {
+            name: pfn_create_display_plane_surface_khr,
+            method_name: create_display_plane_surface_khr,
+            try_method_name: try_create_display_plane_surface_khr,
+            unchecked_method_name: create_display_plane_surface_khr_unchecked,
+            ptr: CreateDisplayPlaneSurfaceKHR,
+            signature: (
+                p_create_info: *const DisplaySurfaceCreateInfoKHR,
+                p_allocator: *const AllocationCallbacks,
+                p_surface: *mut SurfaceKHR,
+            ) -> Result,
+            takes_handle: true,
+            provided_by: [
+                [VK_KHR_display] => CreateDisplayPlaneSurfaceKHR,
+            ],
+            hooks: [
+                p_create_info: structs,
+                p_allocator: structs,
+                p_surface: handles,
+            ],
+            checked: {
+                method_name: create_display_plane_surface_khr_checked,
+                args: (
+                    p_create_info: *const DisplaySurfaceCreateInfoKHR,
+                    p_allocator: Option<&AllocationCallbacks>,
+                ),
+                output: p_surface: SurfaceKHR,
+                result: success,
+            },
+        },
+        {
+            name: pfn_destroy_surface_khr,
+            method_name: destroy_surface_khr,
+            try_method_name: try_destroy_surface_khr,
+            unchecked_method_name: destroy_surface_khr_unchecked,
+            ptr: DestroySurfaceKHR,
+            signature: (
+                surface: SurfaceKHR,
+                p_allocator: *const AllocationCallbacks,
+            ),
+            takes_handle: true,
+            provided_by: [
+                [VK_KHR_surface] => DestroySurfaceKHR,
+            ],
+            hooks: [
+                surface: handle,
+                p_allocator: structs,
+            ],
+        },
+        {
+            name: pfn_get_physical_device_surface_support_khr,
+            method_name: get_physical_device_surface_support_khr,
+            try_method_name: try_get_physical_device_surface_support_khr,
+            unchecked_method_name: get_physical_device_surface_support_khr_unchecked,
+            ptr: GetPhysicalDeviceSurfaceSupportKHR,
+            signature: (
+                physical_device: PhysicalDevice,
+                queue_family_index: u32,
+                surface: SurfaceKHR,
+                p_supported: *mut Bool32,
+            ) -> Result,
+            takes_handle: false,
+            provided_by: [
+                [VK_KHR_surface] => GetPhysicalDeviceSurfaceSupportKHR,
+            ],
+            hooks: [
+                physical_device: handle,
+                queue_family_index: value,
+                surface: handle,
+                p_supported: ptr,
+            ],
+            checked: {
+                method_name: get_physical_device_surface_support_khr_checked,
+                args: (
+                    physical_device: PhysicalDevice,
+                    queue_family_index: u32,
+                    surface: SurfaceKHR,
+                ),
+                output: p_supported: Bool32,
+                result: success,
+            },
+        },
+        {
+            name: pfn_get_physical_device_surface_capabilities_khr,
+            method_name: get_physical_device_surface_capabilities_khr,
+            try_method_name: try_get_physical_device_surface_capabilities_khr,
+            unchecked_method_name: get_physical_device_surface_capabilities_khr_unchecked,
+            ptr: GetPhysicalDeviceSurfaceCapabilitiesKHR,
+            signature: (
+                physical_device: PhysicalDevice,
+                surface: SurfaceKHR,
+                p_surface_capabilities: *mut SurfaceCapabilitiesKHR,
+            ) -> Result,
+            takes_handle: false,
+            provided_by: [
+                [VK_KHR_surface] => GetPhysicalDeviceSurfaceCapabilitiesKHR,
+            ],
+            hooks: [
+                physical_device: handle,
+                surface: handle,
+                p_surface_capabilities: ptr,
+            ],
+            checked: {
+                method_name: get_physical_device_surface_capabilities_khr_checked,
+                args: (
+                    physical_device: PhysicalDevice,
+                    surface: SurfaceKHR,
+                ),
+                output: p_surface_capabilities: SurfaceCapabilitiesKHR,
+                result: success,
+            },
+        },
+        {
+            name: pfn_get_physical_device_surface_formats_khr,
+            method_name: get_physical_device_surface_formats_khr,
+            try_method_name: try_get_physical_device_surface_formats_khr,
+            unchecked_method_name: get_physical_device_surface_formats_khr_unchecked,
+            ptr: GetPhysicalDeviceSurfaceFormatsKHR,
+            signature: (
+                physical_device: PhysicalDevice,
+                surface: SurfaceKHR,
+                p_surface_format_count: *mut u32,
+                p_surface_formats: *mut SurfaceFormatKHR,
+            ) -> Result,
+            takes_handle: false,
+            provided_by: [
+                [VK_KHR_surface] => GetPhysicalDeviceSurfaceFormatsKHR,
+            ],
+            hooks: [
+                physical_device: handle,
+                surface: handle,
+                p_surface_format_count: ptr,
+                p_surface_formats: ptr[*p_surface_format_count],
+            ],
+            vec: {
+                method_name: get_physical_device_surface_formats_khr_vec,
+                args: (
+                    physical_device: PhysicalDevice,
+                    surface: SurfaceKHR,
+                ),
+                element: SurfaceFormatKHR,
+                result: success,
+            },
+        },
+        {
+            name: pfn_get_physical_device_surface_present_modes_khr,
+            method_name: get_physical_device_surface_present_modes_khr,
+            try_method_name: try_get_physical_device_surface_present_modes_khr,
+            unchecked_method_name: get_physical_device_surface_present_modes_khr_unchecked,
+            ptr: GetPhysicalDeviceSurfacePresentModesKHR,
+            signature: (
+                physical_device: PhysicalDevice,
+                surface: SurfaceKHR,
+                p_present_mode_count: *mut u32,
+                p_present_modes: *mut PresentModeKHR,
+            ) -> Result,
+            takes_handle: false,
+            provided_by: [
+                [VK_KHR_surface] => GetPhysicalDeviceSurfacePresentModesKHR,
+            ],
+            hooks: [
+                physical_device: handle,
+                surface: handle,
+                p_present_mode_count: ptr,
+                p_present_modes: ptr[*p_present_mode_count],
+            ],
+            vec: {
+                method_name: get_physical_device_surface_present_modes_khr_vec,
+                args: (
+                    physical_device: PhysicalDevice,
+                    surface: SurfaceKHR,
+                ),
+                element: PresentModeKHR,
+                result: success,
+            },
+        },
+        {
+            name: pfn_create_vi_surface_nn,
+            method_name: create_vi_surface_nn,
+            try_method_name: try_create_vi_surface_nn,
+            unchecked_method_name: create_vi_surface_nn_unchecked,
+            ptr: CreateViSurfaceNN,
+            signature: (
+                p_create_info: *const ViSurfaceCreateInfoNN,
+                p_allocator: *const AllocationCallbacks,
+                p_surface: *mut SurfaceKHR,
+            ) -> Result,
+            takes_handle: true,
+            provided_by: [
+                [VK_NN_vi_surface] => CreateViSurfaceNN,
+            ],
+            hooks: [
+                p_create_info: structs,
+                p_allocator: structs,
+                p_surface: handles,
+            ],
+            checked: {
+                method_name: create_vi_surface_nn_checked,
+                args: (
+                    p_create_info: *const ViSurfaceCreateInfoNN,
+                    p_allocator: Option<&AllocationCallbacks>,
+                ),
+                output: p_surface: SurfaceKHR,
+                result: success,
+            },
+        },
+        {
+            name: pfn_create_wayland_surface_khr,
+            method_name: create_wayland_surface_khr,
+            try_method_name: try_create_wayland_surface_khr,
+            unchecked_method_name: create_wayland_surface_khr_unchecked,
+            ptr: CreateWaylandSurfaceKHR,
+            signature: (
+                p_create_info: *const WaylandSurfaceCreateInfoKHR,
+                p_allocator: *const AllocationCallbacks,
+                p_surface: *mut SurfaceKHR,
+            ) -> Result,
+            takes_handle: true,
+            provided_by: [
+                [VK_KHR_wayland_surface] => CreateWaylandSurfaceKHR,
+            ],
+            hooks: [
+                p_create_info: structs,
+                p_allocator: structs,
+                p_surface: handles,
+            ],
+            checked: {
+                method_name: create_wayland_surface_khr_checked,
+                args: (
+                    p_create_info: *const WaylandSurfaceCreateInfoKHR,
+                    p_allocator: Option<&AllocationCallbacks>,
+                ),
+                output: p_surface: SurfaceKHR,
+                result: success,
+            },
+        },
+        {
+            name: pfn_get_physical_device_wayland_presentation_support_khr,
+            method_name: get_physical_device_wayland_presentation_support_khr,
+            try_method_name: try_get_physical_device_wayland_presentation_support_khr,
+            unchecked_method_name: get_physical_device_wayland_presentation_support_khr_unchecked,
+            ptr: GetPhysicalDeviceWaylandPresentationSupportKHR,
+            signature: (
+                physical_device: PhysicalDevice,
+                queue_family_index: u32,
+                display: *mut wl_display,
+            ) -> Bool32,
+            takes_handle: false,
+            provided_by: [
+                [VK_KHR_wayland_surface] => GetPhysicalDeviceWaylandPresentationSupportKHR,
+            ],
+            hooks: [
+                physical_device: handle,
+                queue_family_index: value,
+                display: opaque,
+            ],
+        },
+        {
+            name: pfn_create_win_32_surface_khr,
+            method_name: create_win_32_surface_khr,
+            try_method_name: try_create_win_32_surface_khr,
+            unchecked_method_name: create_win_32_surface_khr_unchecked,
+            ptr: CreateWin32SurfaceKHR,
+            signature: (
+                p_create_info: *const Win32SurfaceCreateInfoKHR,
+                p_allocator: *const AllocationCallbacks,
+                p_surface: *mut SurfaceKHR,
+            ) -> Result,
+            takes_handle: true,
+            provided_by: [
+                [VK_KHR_win32_surface] => CreateWin32SurfaceKHR,
+            ],
+            hooks: [
+                p_create_info: structs,
+                p_allocator: structs,
+                p_surface: handles,
+            ],
+            checked: {
+                method_name: create_win_32_surface_khr_checked,
+                args: (
+                    p_create_info: *const Win32SurfaceCreateInfoKHR,
+                    p_allocator: Option<&AllocationCallbacks>,
+                ),
+                output: p_surface: SurfaceKHR,
+                result: success,
+            },
+        },
+        {
+            name: pfn_get_physical_device_win_32_presentation_support_khr,
+            method_name: get_physical_device_win_32_presentation_support_khr,
+            try_method_name: try_get_physical_device_win_32_presentation_support_khr,
+            unchecked_method_name: get_physical_device_win_32_presentation_support_khr_unchecked,
+            ptr: GetPhysicalDeviceWin32PresentationSupportKHR,
+            signature: (
+                physical_device: PhysicalDevice,
+                queue_family_index: u32,
+            ) -> Bool32,
+            takes_handle: false,
+            provided_by: [
+                [VK_KHR_win32_surface] => GetPhysicalDeviceWin32PresentationSupportKHR,
+            ],
+            hooks: [
+                physical_device: handle,
+                queue_family_index: value,
+            ],
+        },
+        {
+            name: pfn_create_xlib_surface_khr,
+            method_name: create_xlib_surface_khr,
+            try_method_name: try_create_xlib_surface_khr,
+            unchecked_method_name: create_xlib_surface_khr_unchecked,
+            ptr: CreateXlibSurfaceKHR,
+            signature: (
+                p_create_info: *const XlibSurfaceCreateInfoKHR,
+                p_allocator: *const AllocationCallbacks,
+                p_surface: *mut SurfaceKHR,
+            ) -> Result,
+            takes_handle: true,
+            provided_by: [
+                [VK_KHR_xlib_surface] => CreateXlibSurfaceKHR,
+            ],
+            hooks: [
+                p_create_info: structs,
+                p_allocator: structs,
+                p_surface: handles,
+            ],
+            checked: {
+                method_name: create_xlib_surface_khr_checked,
+                args: (
+                    p_create_info: *const XlibSurfaceCreateInfoKHR,
+                    p_allocator: Option<&AllocationCallbacks>,
+                ),
+                output: p_surface: SurfaceKHR,
+                result: success,
+            },
+        },
+        {
+            name: pfn_get_physical_device_xlib_presentation_support_khr,
+            method_name: get_physical_device_xlib_presentation_support_khr,
+            try_method_name: try_get_physical_device_xlib_presentation_support_khr,
+            unchecked_method_name: get_physical_device_xlib_presentation_support_khr_unchecked,
+            ptr: GetPhysicalDeviceXlibPresentationSupportKHR,
+            signature: (
+                physical_device: PhysicalDevice,
+                queue_family_index: u32,
+                dpy: *mut Display,
+                visual_id: VisualID,
+            ) -> Bool32,
+            takes_handle: false,
+            provided_by: [
+                [VK_KHR_xlib_surface] => GetPhysicalDeviceXlibPresentationSupportKHR,
+            ],
+            hooks: [
+                physical_device: handle,
+                queue_family_index: value,
+                dpy: ptr,
+                visual_id: value,
+            ],
+        },
+        {
+            name: pfn_create_xcb_surface_khr,
+            method_name: create_xcb_surface_khr,
+            try_method_name: try_create_xcb_surface_khr,
+            unchecked_method_name: create_xcb_surface_khr_unchecked,
+            ptr: CreateXcbSurfaceKHR,
+            signature: (
+                p_create_info: *const XcbSurfaceCreateInfoKHR,
+                p_allocator: *const AllocationCallbacks,
+                p_surface: *mut SurfaceKHR,
+            ) -> Result,
+            takes_handle: true,
+            provided_by: [
+                [VK_KHR_xcb_surface] => CreateXcbSurfaceKHR,
+            ],
+            hooks: [
+                p_create_info: structs,
+                p_allocator: structs,
+                p_surface: handles,
+            ],
+            checked: {
+                method_name: create_xcb_surface_khr_checked,
+                args: (
+                    p_create_info: *const XcbSurfaceCreateInfoKHR,
+                    p_allocator: Option<&AllocationCallbacks>,
+                ),
+                output: p_surface: SurfaceKHR,
+                result: success,
+            },
+        },
+        {
+            name: pfn_get_physical_device_xcb_presentation_support_khr,
+            method_name: get_physical_device_xcb_presentation_support_khr,
+            try_method_name: try_get_physical_device_xcb_presentation_support_khr,
+            unchecked_method_name: get_physical_device_xcb_presentation_support_khr_unchecked,
+            ptr: GetPhysicalDeviceXcbPresentationSupportKHR,
+            signature: (
+                physical_device: PhysicalDevice,
+                queue_family_index: u32,
+                connection: *mut xcb_connection_t,
+                visual_id: xcb_visualid_t,
+            ) -> Bool32,
+            takes_handle: false,
+            provided_by: [
+                [VK_KHR_xcb_surface] => GetPhysicalDeviceXcbPresentationSupportKHR,
+            ],
+            hooks: [
+                physical_device: handle,
+                queue_family_index: value,
+                connection: ptr,
+                visual_id: value,
+            ],
+        },
+        {
+            name: pfn_create_direct_fb_surface_ext,
+            method_name: create_direct_fb_surface_ext,
+            try_method_name: try_create_direct_fb_surface_ext,
+            unchecked_method_name: create_direct_fb_surface_ext_unchecked,
+            ptr: CreateDirectFBSurfaceEXT,
+            signature: (
+                p_create_info: *const DirectFBSurfaceCreateInfoEXT,
+                p_allocator: *const AllocationCallbacks,
+                p_surface: *mut SurfaceKHR,
+            ) -> Result,
+            takes_handle: true,
+            provided_by: [
+                [VK_EXT_directfb_surface] => CreateDirectFBSurfaceEXT,
+            ],
+            hooks: [
+                p_create_info: structs,
+                p_allocator: structs,
+                p_surface: handles,
+            ],
+            checked: {
+                method_name: create_direct_fb_surface_ext_checked,
+                args: (
+                    p_create_info: *const DirectFBSurfaceCreateInfoEXT,
+                    p_allocator: Option<&AllocationCallbacks>,
+                ),
+                output: p_surface: SurfaceKHR,
+                result: success,
+            },
+        },
+        {
+            name: pfn_get_physical_device_direct_fb_presentation_support_ext,
+            method_name: get_physical_device_direct_fb_presentation_support_ext,
+            try_method_name: try_get_physical_device_direct_fb_presentation_support_ext,
+            unchecked_method_name: get_physical_device_direct_fb_presentation_support_ext_unchecked,
+            ptr: GetPhysicalDeviceDirectFBPresentationSupportEXT,
+            signature: (
+                physical_device: PhysicalDevice,
+                queue_family_index: u32,
+                dfb: *mut IDirectFB,
+            ) -> Bool32,
+            takes_handle: false,
+            provided_by: [
+                [VK_EXT_directfb_surface] => GetPhysicalDeviceDirectFBPresentationSupportEXT,
+            ],
+            hooks: [
+                physical_device: handle,
+                queue_family_index: value,
+                dfb: opaque,
+            ],
+        },
+        {
+            name: pfn_create_image_pipe_surface_fuchsia,
+            method_name: create_image_pipe_surface_fuchsia,
+            try_method_name: try_create_image_pipe_surface_fuchsia,
+            unchecked_method_name: create_image_pipe_surface_fuchsia_unchecked,
+            ptr: CreateImagePipeSurfaceFUCHSIA,
+            signature: (
+                p_create_info: *const ImagePipeSurfaceCreateInfoFUCHSIA,
+                p_allocator: *const AllocationCallbacks,
+                p_surface: *mut SurfaceKHR,
+            ) -> Result,
+            takes_handle: true,
+            provided_by: [
+                [VK_FUCHSIA_imagepipe_surface] => CreateImagePipeSurfaceFUCHSIA,
+            ],
+            hooks: [
+                p_create_info: structs,
+                p_allocator: structs,
+                p_surface: handles,
+            ],
+            checked: {
+                method_name: create_image_pipe_surface_fuchsia_checked,
+                args: (
+                    p_create_info: *const ImagePipeSurfaceCreateInfoFUCHSIA,
+                    p_allocator: Option<&AllocationCallbacks>,
+                ),
+                output: p_surface: SurfaceKHR,
+                result: success,
+            },
+        },
+        {
+            name: pfn_create_stream_descriptor_surface_ggp,
+            method_name: create_stream_descriptor_surface_ggp,
+            try_method_name: try_create_stream_descriptor_surface_ggp,
+            unchecked_method_name: create_stream_descriptor_surface_ggp_unchecked,
+            ptr: CreateStreamDescriptorSurfaceGGP,
+            signature: (
+                p_create_info: *const StreamDescriptorSurfaceCreateInfoGGP,
+                p_allocator: *const AllocationCallbacks,
+                p_surface: *mut SurfaceKHR,
+            ) -> Result,
+            takes_handle: true,
+            provided_by: [
+                [VK_GGP_stream_descriptor_surface] => CreateStreamDescriptorSurfaceGGP,
+            ],
+            hooks: [
+                p_create_info: structs,
+                p_allocator: structs,
+                p_surface: handles,
+            ],
+            checked: {
+                method_name: create_stream_descriptor_surface_ggp_checked,
+                args: (
+                    p_create_info: *const StreamDescriptorSurfaceCreateInfoGGP,
+                    p_allocator: Option<&AllocationCallbacks>,
+                ),
+                output: p_surface: SurfaceKHR,
+                result: success,
+            },
+        },
+        {
+            name: pfn_create_screen_surface_qnx,
+            method_name: create_screen_surface_qnx,
+            try_method_name: try_create_screen_surface_qnx,
+            unchecked_method_name: create_screen_surface_qnx_unchecked,
+            ptr: CreateScreenSurfaceQNX,
+            signature: (
+                p_create_info: *const ScreenSurfaceCreateInfoQNX,
+                p_allocator: *const AllocationCallbacks,
+                p_surface: *mut SurfaceKHR,
+            ) -> Result,
+            takes_handle: true,
+            provided_by: [
+                [VK_QNX_screen_surface] => CreateScreenSurfaceQNX,
+            ],
+            hooks: [
+                p_create_info: structs,
+                p_allocator: structs,
+                p_surface: handles,
+            ],
+            checked: {
+                method_name: create_screen_surface_qnx_checked,
+                args: (
+                    p_create_info: *const ScreenSurfaceCreateInfoQNX,
+                    p_allocator: Option<&AllocationCallbacks>,
+                ),
+                output: p_surface: SurfaceKHR,
+                result: success,
+            },
+        },
+        {
+            name: pfn_get_physical_device_screen_presentation_support_qnx,
+            method_name: get_physical_device_screen_presentation_support_qnx,
+            try_method_name: try_get_physical_device_screen_presentation_support_qnx,
+            unchecked_method_name: get_physical_device_screen_presentation_support_qnx_unchecked,
+            ptr: GetPhysicalDeviceScreenPresentationSupportQNX,
+            signature: (
+                physical_device: PhysicalDevice,
+                queue_family_index: u32,
+                window: *mut _screen_window,
+            ) -> Bool32,
+            takes_handle: false,
+            provided_by: [
+                [VK_QNX_screen_surface] => GetPhysicalDeviceScreenPresentationSupportQNX,
+            ],
+            hooks: [
+                physical_device: handle,
+                queue_family_index: value,
+                window: opaque,
+            ],
+        },
+        {
+            name: pfn_create_debug_report_callback_ext,
+            method_name: create_debug_report_callback_ext,
+            try_method_name: try_create_debug_report_callback_ext,
+            unchecked_method_name: create_debug_report_callback_ext_unchecked,
+            ptr: CreateDebugReportCallbackEXT,
+            signature: (
+                p_create_info: *const DebugReportCallbackCreateInfoEXT,
+                p_allocator: *const AllocationCallbacks,
+                p_callback: *mut DebugReportCallbackEXT,
+            ) -> Result,
+            takes_handle: true,
+            provided_by: [
+                [VK_EXT_debug_report] => CreateDebugReportCallbackEXT,
+            ],
+            hooks: [
+                p_create_info: structs,
+                p_allocator: structs,
+                p_callback: handles,
+            ],
+            checked: {
+                method_name: create_debug_report_callback_ext_checked,
+                args: (
+                    p_create_info: *const DebugReportCallbackCreateInfoEXT,
+                    p_allocator: Option<&AllocationCallbacks>,
+                ),
+                output: p_callback: DebugReportCallbackEXT,
+                result: success,
+            },
+        },
+        {
+            name: pfn_destroy_debug_report_callback_ext,
+            method_name: destroy_debug_report_callback_ext,
+            try_method_name: try_destroy_debug_report_callback_ext,
+            unchecked_method_name: destroy_debug_report_callback_ext_unchecked,
+            ptr: DestroyDebugReportCallbackEXT,
+            signature: (
+                callback: DebugReportCallbackEXT,
+                p_allocator: *const AllocationCallbacks,
+            ),
+            takes_handle: true,
+            provided_by: [
+                [VK_EXT_debug_report] => DestroyDebugReportCallbackEXT,
+            ],
+            hooks: [
+                callback: handle,
+                p_allocator: structs,
+            ],
+        },
+        {
+            name: pfn_debug_report_message_ext,
+            method_name: debug_report_message_ext,
+            try_method_name: try_debug_report_message_ext,
+            unchecked_method_name: debug_report_message_ext_unchecked,
+            ptr: DebugReportMessageEXT,
+            signature: (
+                flags: DebugReportFlagsEXT,
+                object_type: DebugReportObjectTypeEXT,
+                object: u64,
+                location: usize,
+                message_code: i32,
+                p_layer_prefix: *const c_char,
+                p_message: *const c_char,
+            ),
+            takes_handle: true,
+            provided_by: [
+                [VK_EXT_debug_report] => DebugReportMessageEXT,
+            ],
+            hooks: [
+                flags: value,
+                object_type: value,
+                object: value,
+                location: value,
+                message_code: value,
+                p_layer_prefix: str,
+                p_message: str,
+            ],
+        },
+        {
+            name: pfn_get_physical_device_external_image_format_properties_nv,
+            method_name: get_physical_device_external_image_format_properties_nv,
+            try_method_name: try_get_physical_device_external_image_format_properties_nv,
+            unchecked_method_name: get_physical_device_external_image_format_properties_nv_unchecked,
+            ptr: GetPhysicalDeviceExternalImageFormatPropertiesNV,
+            signature: (
+                physical_device: PhysicalDevice,
+                format: Format,
+                ty: ImageType,
+                tiling: ImageTiling,
+                usage: ImageUsageFlags,
+                flags: ImageCreateFlags,
+                external_handle_type: ExternalMemoryHandleTypeFlagsNV,
+                p_external_image_format_properties: *mut ExternalImageFormatPropertiesNV,
+            ) -> Result,
+            takes_handle: false,
+            provided_by: [
+                [VK_NV_external_memory_capabilities] => GetPhysicalDeviceExternalImageFormatPropertiesNV,
+            ],
+            hooks: [
+                physical_device: handle,
+                format: value,
+                ty: value,
+                tiling: value,
+                usage: value,
+                flags: value,
+                external_handle_type: value,
+                p_external_image_format_properties: ptr,
+            ],
+            checked: {
+                method_name: get_physical_device_external_image_format_properties_nv_checked,
+                args: (
+                    physical_device: PhysicalDevice,
+                    format: Format,
+                    ty: ImageType,
+                    tiling: ImageTiling,
+                    usage: ImageUsageFlags,
+                    flags: ImageCreateFlags,
+                    external_handle_type: ExternalMemoryHandleTypeFlagsNV,
+                ),
+                output: p_external_image_format_properties: ExternalImageFormatPropertiesNV,
+                result: success,
+            },
+        },
+        {
+            name: pfn_get_physical_device_features_2,
+            method_name: get_physical_device_features_2,
+            try_method_name: try_get_physical_device_features_2,
+            unchecked_method_name: get_physical_device_features_2_unchecked,
+            ptr: GetPhysicalDeviceFeatures2,
+            aliases: [GetPhysicalDeviceFeatures2KHR],
+            signature: (
+                physical_device: PhysicalDevice,
+                p_features: *mut PhysicalDeviceFeatures2,
+            ),
+            takes_handle: false,
+            provided_by: [
+                [VK_VERSION_1_1] => GetPhysicalDeviceFeatures2,
+                [VK_KHR_get_physical_device_properties2] => GetPhysicalDeviceFeatures2KHR,
+            ],
+            hooks: [
+                physical_device: handle,
+                p_features: structs,
+            ],
+            checked: {
+                method_name: get_physical_device_features_2_checked,
+                args: (
+                    physical_device: PhysicalDevice,
+                ),
+                output: p_features: PhysicalDeviceFeatures2,
+                result: void,
+            },
+        },
+        {
+            name: pfn_get_physical_device_properties_2,
+            method_name: get_physical_device_properties_2,
+            try_method_name: try_get_physical_device_properties_2,
+            unchecked_method_name: get_physical_device_properties_2_unchecked,
+            ptr: GetPhysicalDeviceProperties2,
+            aliases: [GetPhysicalDeviceProperties2KHR],
+            signature: (
+                physical_device: PhysicalDevice,
+                p_properties: *mut PhysicalDeviceProperties2,
+            ),
+            takes_handle: false,
+            provided_by: [
+                [VK_VERSION_1_1] => GetPhysicalDeviceProperties2,
+                [VK_KHR_get_physical_device_properties2] => GetPhysicalDeviceProperties2KHR,
+            ],
+            hooks: [
+                physical_device: handle,
+                p_properties: structs,
+            ],
+            checked: {
+                method_name: get_physical_device_properties_2_checked,
+                args: (
+                    physical_device: PhysicalDevice,
+                ),
+                output: p_properties: PhysicalDeviceProperties2,
+                result: void,
+            },
+        },
+        {
+            name: pfn_get_physical_device_format_properties_2,
+            method_name: get_physical_device_format_properties_2,
+            try_method_name: try_get_physical_device_format_properties_2,
+            unchecked_method_name: get_physical_device_format_properties_2_unchecked,
+            ptr: GetPhysicalDeviceFormatProperties2,
+            aliases: [GetPhysicalDeviceFormatProperties2KHR],
+            signature: (
+                physical_device: PhysicalDevice,
+                format: Format,
+                p_format_properties: *mut FormatProperties2,
+            ),
+            takes_handle: false,
+            provided_by: [
+                [VK_VERSION_1_1] => GetPhysicalDeviceFormatProperties2,
+                [VK_KHR_get_physical_device_properties2] => GetPhysicalDeviceFormatProperties2KHR,
+            ],
+            hooks: [
+                physical_device: handle,
+                format: value,
+                p_format_properties: structs,
+            ],
+            checked: {
+                method_name: get_physical_device_format_properties_2_checked,
+                args: (
+                    physical_device: PhysicalDevice,
+                    format: Format,
+                ),
+                output: p_format_properties: FormatProperties2,
+                result: void,
+            },
+        },
+        {
+            name: pfn_get_physical_device_image_format_properties_2,
+            method_name: get_physical_device_image_format_properties_2,
+            try_method_name: try_get_physical_device_image_format_properties_2,
+            unchecked_method_name: get_physical_device_image_format_properties_2_unchecked,
+            ptr: GetPhysicalDeviceImageFormatProperties2,
+            aliases: [GetPhysicalDeviceImageFormatProperties2KHR],
+            signature: (
+                physical_device: PhysicalDevice,
+                p_image_format_info: *const PhysicalDeviceImageFormatInfo2,
+                p_image_format_properties: *mut ImageFormatProperties2,
+            ) -> Result,
+            takes_handle: false,
+            provided_by: [
+                [VK_VERSION_1_1] => GetPhysicalDeviceImageFormatProperties2,
+                [VK_KHR_get_physical_device_properties2] => GetPhysicalDeviceImageFormatProperties2KHR,
+            ],
+            hooks: [
+                physical_device: handle,
+                p_image_format_info: structs,
+                p_image_format_properties: structs,
+            ],
+            checked: {
+                method_name: get_physical_device_image_format_properties_2_checked,
+                args: (
+                    physical_device: PhysicalDevice,
+                    p_image_format_info: *const PhysicalDeviceImageFormatInfo2,
+                ),
+                output: p_image_format_properties: ImageFormatProperties2,
+                result: success,
+            },
+        },
+        {
+            name: pfn_get_physical_device_queue_family_properties_2,
+            method_name: get_physical_device_queue_family_properties_2,
+            try_method_name: try_get_physical_device_queue_family_properties_2,
+            unchecked_method_name: get_physical_device_queue_family_properties_2_unchecked,
+            ptr: GetPhysicalDeviceQueueFamilyProperties2,
+            aliases: [GetPhysicalDeviceQueueFamilyProperties2KHR],
+            signature: (
+                physical_device: PhysicalDevice,
+                p_queue_family_property_count: *mut u32,
+                p_queue_family_properties: *mut QueueFamilyProperties2,
+            ),
+            takes_handle: false,
+            provided_by: [
+                [VK_VERSION_1_1] => GetPhysicalDeviceQueueFamilyProperties2,
+                [VK_KHR_get_physical_device_properties2] => GetPhysicalDeviceQueueFamilyProperties2KHR,
+            ],
+            hooks: [
+                physical_device: handle,
+                p_queue_family_property_count: ptr,
+                p_queue_family_properties: structs[*p_queue_family_property_count],
+            ],
+            vec: {
+                method_name: get_physical_device_queue_family_properties_2_vec,
+                args: (
+                    physical_device: PhysicalDevice,
+                ),
+                element: QueueFamilyProperties2,
+                result: void,
+            },
+        },
+        {
+            name: pfn_get_physical_device_memory_properties_2,
+            method_name: get_physical_device_memory_properties_2,
+            try_method_name: try_get_physical_device_memory_properties_2,
+            unchecked_method_name: get_physical_device_memory_properties_2_unchecked,
+            ptr: GetPhysicalDeviceMemoryProperties2,
+            aliases: [GetPhysicalDeviceMemoryProperties2KHR],
+            signature: (
+                physical_device: PhysicalDevice,
+                p_memory_properties: *mut PhysicalDeviceMemoryProperties2,
+            ),
+            takes_handle: false,
+            provided_by: [
+                [VK_VERSION_1_1] => GetPhysicalDeviceMemoryProperties2,
+                [VK_KHR_get_physical_device_properties2] => GetPhysicalDeviceMemoryProperties2KHR,
+            ],
+            hooks: [
+                physical_device: handle,
+                p_memory_properties: structs,
+            ],
+            checked: {
+                method_name: get_physical_device_memory_properties_2_checked,
+                args: (
+                    physical_device: PhysicalDevice,
+                ),
+                output: p_memory_properties: PhysicalDeviceMemoryProperties2,
+                result: void,
+            },
+        },
+        {
+            name: pfn_get_physical_device_sparse_image_format_properties_2,
+            method_name: get_physical_device_sparse_image_format_properties_2,
+            try_method_name: try_get_physical_device_sparse_image_format_properties_2,
+            unchecked_method_name: get_physical_device_sparse_image_format_properties_2_unchecked,
+            ptr: GetPhysicalDeviceSparseImageFormatProperties2,
+            aliases: [GetPhysicalDeviceSparseImageFormatProperties2KHR],
+            signature: (
+                physical_device: PhysicalDevice,
+                p_format_info: *const PhysicalDeviceSparseImageFormatInfo2,
+                p_property_count: *mut u32,
+                p_properties: *mut SparseImageFormatProperties2,
+            ),
+            takes_handle: false,
+            provided_by: [
+                [VK_VERSION_1_1] => GetPhysicalDeviceSparseImageFormatProperties2,
+                [VK_KHR_get_physical_device_properties2] => GetPhysicalDeviceSparseImageFormatProperties2KHR,
+            ],
+            hooks: [
+                physical_device: handle,
+                p_format_info: structs,
+                p_property_count: ptr,
+                p_properties: structs[*p_property_count],
+            ],
+            vec: {
+                method_name: get_physical_device_sparse_image_format_properties_2_vec,
+                args: (
+                    physical_device: PhysicalDevice,
+                    p_format_info: *const PhysicalDeviceSparseImageFormatInfo2,
+                ),
+                element: SparseImageFormatProperties2,
+                result: void,
+            },
+        },
+        {
+            name: pfn_get_physical_device_external_buffer_properties,
+            method_name: get_physical_device_external_buffer_properties,
+            try_method_name: try_get_physical_device_external_buffer_properties,
+            unchecked_method_name: get_physical_device_external_buffer_properties_unchecked,
+            ptr: GetPhysicalDeviceExternalBufferProperties,
+            aliases: [GetPhysicalDeviceExternalBufferPropertiesKHR],
+            signature: (
+                physical_device: PhysicalDevice,
+                p_external_buffer_info: *const PhysicalDeviceExternalBufferInfo,
+                p_external_buffer_properties: *mut ExternalBufferProperties,
+            ),
+            takes_handle: false,
+            provided_by: [
+                [VK_VERSION_1_1] => GetPhysicalDeviceExternalBufferProperties,
+                [VK_KHR_external_memory_capabilities] => GetPhysicalDeviceExternalBufferPropertiesKHR,
+            ],
+            hooks: [
+                physical_device: handle,
+                p_external_buffer_info: structs,
+                p_external_buffer_properties: structs,
+            ],
+            checked: {
+                method_name: get_physical_device_external_buffer_properties_checked,
+                args: (
+                    physical_device: PhysicalDevice,
+                    p_external_buffer_info: *const PhysicalDeviceExternalBufferInfo,
+                ),
+                output: p_external_buffer_properties: ExternalBufferProperties,
+                result: void,
+            },
+        },
+        {
+            name: pfn_get_physical_device_external_semaphore_properties,
+            method_name: get_physical_device_external_semaphore_properties,
+            try_method_name: try_get_physical_device_external_semaphore_properties,
+            unchecked_method_name: get_physical_device_external_semaphore_properties_unchecked,
+            ptr: GetPhysicalDeviceExternalSemaphoreProperties,
+            aliases: [GetPhysicalDeviceExternalSemaphorePropertiesKHR],
+            signature: (
+                physical_device: PhysicalDevice,
+                p_external_semaphore_info: *const PhysicalDeviceExternalSemaphoreInfo,
+                p_external_semaphore_properties: *mut ExternalSemaphoreProperties,
+            ),
+            takes_handle: false,
+            provided_by: [
+                [VK_VERSION_1_1] => GetPhysicalDeviceExternalSemaphoreProperties,
+                [VK_KHR_external_semaphore_capabilities] => GetPhysicalDeviceExternalSemaphorePropertiesKHR,
+            ],
+            hooks: [
+                physical_device: handle,
+                p_external_semaphore_info: structs,
+                p_external_semaphore_properties: structs,
+            ],
+            checked: {
+                method_name: get_physical_device_external_semaphore_properties_checked,
+                args: (
+                    physical_device: PhysicalDevice,
+                    p_external_semaphore_info: *const PhysicalDeviceExternalSemaphoreInfo,
+                ),
+                output: p_external_semaphore_properties: ExternalSemaphoreProperties,
+                result: void,
+            },
+        },
+        {
+            name: pfn_get_physical_device_external_fence_properties,
+            method_name: get_physical_device_external_fence_properties,
+            try_method_name: try_get_physical_device_external_fence_properties,
+            unchecked_method_name: get_physical_device_external_fence_properties_unchecked,
+            ptr: GetPhysicalDeviceExternalFenceProperties,
+            aliases: [GetPhysicalDeviceExternalFencePropertiesKHR],
+            signature: (
+                physical_device: PhysicalDevice,
+                p_external_fence_info: *const PhysicalDeviceExternalFenceInfo,
+                p_external_fence_properties: *mut ExternalFenceProperties,
+            ),
+            takes_handle: false,
+            provided_by: [
+                [VK_VERSION_1_1] => GetPhysicalDeviceExternalFenceProperties,
+                [VK_KHR_external_fence_capabilities] => GetPhysicalDeviceExternalFencePropertiesKHR,
+            ],
+            hooks: [
+                physical_device: handle,
+                p_external_fence_info: structs,
+                p_external_fence_properties: structs,
+            ],
+            checked: {
+                method_name: get_physical_device_external_fence_properties_checked,
+                args: (
+                    physical_device: PhysicalDevice,
+                    p_external_fence_info: *const PhysicalDeviceExternalFenceInfo,
+                ),
+                output: p_external_fence_properties: ExternalFenceProperties,
+                result: void,
+            },
+        },
+        {
+            name: pfn_release_display_ext,
+            method_name: release_display_ext,
+            try_method_name: try_release_display_ext,
+            unchecked_method_name: release_display_ext_unchecked,
+            ptr: ReleaseDisplayEXT,
+            signature: (
+                physical_device: PhysicalDevice,
+                display: DisplayKHR,
+            ) -> Result,
+            takes_handle: false,
+            provided_by: [
+                [VK_EXT_direct_mode_display] => ReleaseDisplayEXT,
+            ],
+            hooks: [
+                physical_device: handle,
+                display: handle,
+            ],
+        },
+        {
+            name: pfn_acquire_xlib_display_ext,
+            method_name: acquire_xlib_display_ext,
+            try_method_name: try_acquire_xlib_display_ext,
+            unchecked_method_name: acquire_xlib_display_ext_unchecked,
+            ptr: AcquireXlibDisplayEXT,
+            signature: (
+                physical_device: PhysicalDevice,
+                dpy: *mut Display,
+                display: DisplayKHR,
+            ) -> Result,
+            takes_handle: false,
+            provided_by: [
+                [VK_EXT_acquire_xlib_display] => AcquireXlibDisplayEXT,
+            ],
+            hooks: [
+                physical_device: handle,
+                dpy: ptr,
+                display: handle,
+            ],
+        },
+        {
+            name: pfn_get_rand_r_output_display_ext,
+            method_name: get_rand_r_output_display_ext,
+            try_method_name: try_get_rand_r_output_display_ext,
+            unchecked_method_name: get_rand_r_output_display_ext_unchecked,
+            ptr: GetRandROutputDisplayEXT,
+            signature: (
+                physical_device: PhysicalDevice,
+                dpy: *mut Display,
+                rr_output: RROutput,
+                p_display: *mut DisplayKHR,
+            ) -> Result,
+            takes_handle: false,
+            provided_by: [
+                [VK_EXT_acquire_xlib_display] => GetRandROutputDisplayEXT,
+            ],
+            hooks: [
+                physical_device: handle,
+                dpy: ptr,
+                rr_output: value,
+                p_display: handles,
+            ],
+        },
+        {
+            name: pfn_acquire_winrt_display_nv,
+            method_name: acquire_winrt_display_nv,
+            try_method_name: try_acquire_winrt_display_nv,
+            unchecked_method_name: acquire_winrt_display_nv_unchecked,
+            ptr: AcquireWinrtDisplayNV,
+            signature: (
+                physical_device: PhysicalDevice,
+                display: DisplayKHR,
+            ) -> Result,
+            takes_handle: false,
+            provided_by: [
+                [VK_NV_acquire_winrt_display] => AcquireWinrtDisplayNV,
+            ],
+            hooks: [
+                physical_device: handle,
+                display: handle,
+            ],
+        },
+        {
+            name: pfn_get_winrt_display_nv,
+            method_name: get_winrt_display_nv,
+            try_method_name: try_get_winrt_display_nv,
+            unchecked_method_name: get_winrt_display_nv_unchecked,
+            ptr: GetWinrtDisplayNV,
+            signature: (
+                physical_device: PhysicalDevice,
+                device_relative_id: u32,
+                p_display: *mut DisplayKHR,
+            ) -> Result,
+            takes_handle: false,
+            provided_by: [
+                [VK_NV_acquire_winrt_display] => GetWinrtDisplayNV,
+            ],
+            hooks: [
+                physical_device: handle,
+                device_relative_id: value,
+                p_display: handles,
+            ],
+            checked: {
+                method_name: get_winrt_display_nv_checked,
+                args: (
+                    physical_device: PhysicalDevice,
+                    device_relative_id: u32,
+                ),
+                output: p_display: DisplayKHR,
+                result: success,
+            },
+        },
+        {
+            name: pfn_get_physical_device_surface_capabilities_2_ext,
+            method_name: get_physical_device_surface_capabilities_2_ext,
+            try_method_name: try_get_physical_device_surface_capabilities_2_ext,
+            unchecked_method_name: get_physical_device_surface_capabilities_2_ext_unchecked,
+            ptr: GetPhysicalDeviceSurfaceCapabilities2EXT,
+            signature: (
+                physical_device: PhysicalDevice,
+                surface: SurfaceKHR,
+                p_surface_capabilities: *mut SurfaceCapabilities2EXT,
+            ) -> Result,
+            takes_handle: false,
+            provided_by: [
+                [VK_EXT_display_surface_counter] => GetPhysicalDeviceSurfaceCapabilities2EXT,
+            ],
+            hooks: [
+                physical_device: handle,
+                surface: handle,
+                p_surface_capabilities: structs,
+            ],
+            checked: {
+                method_name: get_physical_device_surface_capabilities_2_ext_checked,
+                args: (
+                    physical_device: PhysicalDevice,
+                    surface: SurfaceKHR,
+                ),
+                output: p_surface_capabilities: SurfaceCapabilities2EXT,
+                result: success,
+            },
+        },
+        {
+            name: pfn_enumerate_physical_device_groups,
+            method_name: enumerate_physical_device_groups,
+            try_method_name: try_enumerate_physical_device_groups,
+            unchecked_method_name: enumerate_physical_device_groups_unchecked,
+            ptr: EnumeratePhysicalDeviceGroups,
+            aliases: [EnumeratePhysicalDeviceGroupsKHR],
+            signature: (
+                p_physical_device_group_count: *mut u32,
+                p_physical_device_group_properties: *mut PhysicalDeviceGroupProperties,
+            ) -> Result,
+            takes_handle: true,
+            provided_by: [
+                [VK_VERSION_1_1] => EnumeratePhysicalDeviceGroups,
+                [VK_KHR_device_group_creation] => EnumeratePhysicalDeviceGroupsKHR,
+            ],
+            hooks: [
+                p_physical_device_group_count: ptr,
+                p_physical_device_group_properties: structs[*p_physical_device_group_count],
+            ],
+            vec: {
+                method_name: enumerate_physical_device_groups_vec,
+                args: (
+                ),
+                element: PhysicalDeviceGroupProperties,
+                result: success,
+            },
+        },
+        {
+            name: pfn_get_physical_device_present_rectangles_khr,
+            method_name: get_physical_device_present_rectangles_khr,
+            try_method_name: try_get_physical_device_present_rectangles_khr,
+            unchecked_method_name: get_physical_device_present_rectangles_khr_unchecked,
+            ptr: GetPhysicalDevicePresentRectanglesKHR,
+            signature: (
+                physical_device: PhysicalDevice,
+                surface: SurfaceKHR,
+                p_rect_count: *mut u32,
+                p_rects: *mut Rect2D,
+            ) -> Result,
+            takes_handle: false,
+            provided_by: [
+                [VK_VERSION_1_1] => GetPhysicalDevicePresentRectanglesKHR,
+                [VK_KHR_surface] => GetPhysicalDevicePresentRectanglesKHR,
+            ],
+            hooks: [
+                physical_device: handle,
+                surface: handle,
+                p_rect_count: ptr,
+                p_rects: ptr[*p_rect_count],
+            ],
+            vec: {
+                method_name: get_physical_device_present_rectangles_khr_vec,
+                args: (
+                    physical_device: PhysicalDevice,
+                    surface: SurfaceKHR,
+                ),
+                element: Rect2D,
+                result: success,
+            },
+        },
+        {
+            name: pfn_create_ios_surface_mvk,
+            method_name: create_ios_surface_mvk,
+            try_method_name: try_create_ios_surface_mvk,
+            unchecked_method_name: create_ios_surface_mvk_unchecked,
+            ptr: CreateIOSSurfaceMVK,
+            signature: (
+                p_create_info: *const IOSSurfaceCreateInfoMVK,
+                p_allocator: *const AllocationCallbacks,
+                p_surface: *mut SurfaceKHR,
+            ) -> Result,
+            takes_handle: true,
+            provided_by: [
+                [VK_MVK_ios_surface] => CreateIOSSurfaceMVK,
+            ],
+            hooks: [
+                p_create_info: structs,
+                p_allocator: structs,
+                p_surface: handles,
+            ],
+            checked: {
+                method_name: create_ios_surface_mvk_checked,
+                args: (
+                    p_create_info: *const IOSSurfaceCreateInfoMVK,
+                    p_allocator: Option<&AllocationCallbacks>,
+                ),
+                output: p_surface: SurfaceKHR,
+                result: success,
+            },
+        },
+        {
+            name: pfn_create_mac_os_surface_mvk,
+            method_name: create_mac_os_surface_mvk,
+            try_method_name: try_create_mac_os_surface_mvk,
+            unchecked_method_name: create_mac_os_surface_mvk_unchecked,
+            ptr: CreateMacOSSurfaceMVK,
+            signature: (
+                p_create_info: *const MacOSSurfaceCreateInfoMVK,
+                p_allocator: *const AllocationCallbacks,
+                p_surface: *mut SurfaceKHR,
+            ) -> Result,
+            takes_handle: true,
+            provided_by: [
+                [VK_MVK_macos_surface] => CreateMacOSSurfaceMVK,
+            ],
+            hooks: [
+                p_create_info: structs,
+                p_allocator: structs,
+                p_surface: handles,
+            ],
+            checked: {
+                method_name: create_mac_os_surface_mvk_checked,
+                args: (
+                    p_create_info: *const MacOSSurfaceCreateInfoMVK,
+                    p_allocator: Option<&AllocationCallbacks>,
+                ),
+                output: p_surface: SurfaceKHR,
+                result: success,
+            },
+        },
+        {
+            name: pfn_create_metal_surface_ext,
+            method_name: create_metal_surface_ext,
+            try_method_name: try_create_metal_surface_ext,
+            unchecked_method_name: create_metal_surface_ext_unchecked,
+            ptr: CreateMetalSurfaceEXT,
+            signature: (
+                p_create_info: *const MetalSurfaceCreateInfoEXT,
+                p_allocator: *const AllocationCallbacks,
+                p_surface: *mut SurfaceKHR,
+            ) -> Result,
+            takes_handle: true,
+            provided_by: [
+                [VK_EXT_metal_surface] => CreateMetalSurfaceEXT,
+            ],
+            hooks: [
+                p_create_info: structs,
+                p_allocator: structs,
+                p_surface: handles,
+            ],
+            checked: {
+                method_name: create_metal_surface_ext_checked,
+                args: (
+                    p_create_info: *const MetalSurfaceCreateInfoEXT,
+                    p_allocator: Option<&AllocationCallbacks>,
+                ),
+                output: p_surface: SurfaceKHR,
+                result: success,
+            },
+        },
+        {
+            name: pfn_get_physical_device_multisample_properties_ext,
+            method_name: get_physical_device_multisample_properties_ext,
+            try_method_name: try_get_physical_device_multisample_properties_ext,
+            unchecked_method_name: get_physical_device_multisample_properties_ext_unchecked,
+            ptr: GetPhysicalDeviceMultisamplePropertiesEXT,
+            signature: (
+                physical_device: PhysicalDevice,
+                samples: SampleCountFlagBits,
+                p_multisample_properties: *mut MultisamplePropertiesEXT,
+            ),
+            takes_handle: false,
+            provided_by: [
+                [] => GetPhysicalDeviceMultisamplePropertiesEXT,
+            ],
+            hooks: [
+                physical_device: handle,
+                samples: value,
+                p_multisample_properties: structs,
+            ],
+            checked: {
+                method_name: get_physical_device_multisample_properties_ext_checked,
+                args: (
+                    physical_device: PhysicalDevice,
+                    samples: SampleCountFlagBits,
+                ),
+                output: p_multisample_properties: MultisamplePropertiesEXT,
+                result: void,
+            },
+        },
+        {
+            name: pfn_get_physical_device_surface_capabilities_2_khr,
+            method_name: get_physical_device_surface_capabilities_2_khr,
+            try_method_name: try_get_physical_device_surface_capabilities_2_khr,
+            unchecked_method_name: get_physical_device_surface_capabilities_2_khr_unchecked,
+            ptr: GetPhysicalDeviceSurfaceCapabilities2KHR,
+            signature: (
+                physical_device: PhysicalDevice,
+                p_surface_info: *const PhysicalDeviceSurfaceInfo2KHR,
+                p_surface_capabilities: *mut SurfaceCapabilities2KHR,
+            ) -> Result,
+            takes_handle: false,
+            provided_by: [
+                [VK_KHR_get_surface_capabilities2] => GetPhysicalDeviceSurfaceCapabilities2KHR,
+            ],
+            hooks: [
+                physical_device: handle,
+                p_surface_info: structs,
+                p_surface_capabilities: structs,
+            ],
+            checked: {
+                method_name: get_physical_device_surface_capabilities_2_khr_checked,
+                args: (
+                    physical_device: PhysicalDevice,
+                    p_surface_info: *const PhysicalDeviceSurfaceInfo2KHR,
+                ),
+                output: p_surface_capabilities: SurfaceCapabilities2KHR,
+                result: success,
+            },
+        },
+        {
+            name: pfn_get_physical_device_surface_formats_2_khr,
+            method_name: get_physical_device_surface_formats_2_khr,
+            try_method_name: try_get_physical_device_surface_formats_2_khr,
+            unchecked_method_name: get_physical_device_surface_formats_2_khr_unchecked,
+            ptr: GetPhysicalDeviceSurfaceFormats2KHR,
+            signature: (
+                physical_device: PhysicalDevice,
+                p_surface_info: *const PhysicalDeviceSurfaceInfo2KHR,
+                p_surface_format_count: *mut u32,
+                p_surface_formats: *mut SurfaceFormat2KHR,
+            ) -> Result,
+            takes_handle: false,
+            provided_by: [
+                [VK_KHR_get_surface_capabilities2] => GetPhysicalDeviceSurfaceFormats2KHR,
+            ],
+            hooks: [
+                physical_device: handle,
+                p_surface_info: structs,
+                p_surface_format_count: ptr,
+                p_surface_formats: structs[*p_surface_format_count],
+            ],
+            vec: {
+                method_name: get_physical_device_surface_formats_2_khr_vec,
+                args: (
+                    physical_device: PhysicalDevice,
+                    p_surface_info: *const PhysicalDeviceSurfaceInfo2KHR,
+                ),
+                element: SurfaceFormat2KHR,
+                result: success,
+            },
+        },
+        {
+            name: pfn_get_physical_device_display_properties_2_khr,
+            method_name: get_physical_device_display_properties_2_khr,
+            try_method_name: try_get_physical_device_display_properties_2_khr,
+            unchecked_method_name: get_physical_device_display_properties_2_khr_unchecked,
+            ptr: GetPhysicalDeviceDisplayProperties2KHR,
+            signature: (
+                physical_device: PhysicalDevice,
+                p_property_count: *mut u32,
+                p_properties: *mut DisplayProperties2KHR,
+            ) -> Result,
+            takes_handle: false,
+            provided_by: [
+                [VK_KHR_get_display_properties2] => GetPhysicalDeviceDisplayProperties2KHR,
+            ],
+            hooks: [
+                physical_device: handle,
+                p_property_count: ptr,
+                p_properties: structs[*p_property_count],
+            ],
+            vec: {
+                method_name: get_physical_device_display_properties_2_khr_vec,
+                args: (
+                    physical_device: PhysicalDevice,
+                ),
+                element: DisplayProperties2KHR,
+                result: success,
+            },
+        },
+        {
+            name: pfn_get_physical_device_display_plane_properties_2_khr,
+            method_name: get_physical_device_display_plane_properties_2_khr,
+            try_method_name: try_get_physical_device_display_plane_properties_2_khr,
+            unchecked_method_name: get_physical_device_display_plane_properties_2_khr_unchecked,
+            ptr: GetPhysicalDeviceDisplayPlaneProperties2KHR,
+            signature: (
+                physical_device: PhysicalDevice,
+                p_property_count: *mut u32,
+                p_properties: *mut DisplayPlaneProperties2KHR,
+            ) -> Result,
+            takes_handle: false,
+            provided_by: [
+                [VK_KHR_get_display_properties2] => GetPhysicalDeviceDisplayPlaneProperties2KHR,
+            ],
+            hooks: [
+                physical_device: handle,
+                p_property_count: ptr,
+                p_properties: structs[*p_property_count],
+            ],
+            vec: {
+                method_name: get_physical_device_display_plane_properties_2_khr_vec,
+                args: (
+                    physical_device: PhysicalDevice,
+                ),
+                element: DisplayPlaneProperties2KHR,
+                result: success,
+            },
+        },
+        {
+            name: pfn_get_display_mode_properties_2_khr,
+            method_name: get_display_mode_properties_2_khr,
+            try_method_name: try_get_display_mode_properties_2_khr,
+            unchecked_method_name: get_display_mode_properties_2_khr_unchecked,
+            ptr: GetDisplayModeProperties2KHR,
+            signature: (
+                physical_device: PhysicalDevice,
+                display: DisplayKHR,
+                p_property_count: *mut u32,
+                p_properties: *mut DisplayModeProperties2KHR,
+            ) -> Result,
+            takes_handle: false,
+            provided_by: [
+                [VK_KHR_get_display_properties2] => GetDisplayModeProperties2KHR,
+            ],
+            hooks: [
+                physical_device: handle,
+                display: handle,
+                p_property_count: ptr,
+                p_properties: structs[*p_property_count],
+            ],
+            vec: {
+                method_name: get_display_mode_properties_2_khr_vec,
+                args: (
+                    physical_device: PhysicalDevice,
+                    display: DisplayKHR,
+                ),
+                element: DisplayModeProperties2KHR,
+                result: success,
+            },
+        },
+        {
+            name: pfn_get_display_plane_capabilities_2_khr,
+            method_name: get_display_plane_capabilities_2_khr,
+            try_method_name: try_get_display_plane_capabilities_2_khr,
+            unchecked_method_name: get_display_plane_capabilities_2_khr_unchecked,
+            ptr: GetDisplayPlaneCapabilities2KHR,
+            signature: (
+                physical_device: PhysicalDevice,
+                p_display_plane_info: *const DisplayPlaneInfo2KHR,
+                p_capabilities: *mut DisplayPlaneCapabilities2KHR,
+            ) -> Result,
+            takes_handle: false,
+            provided_by: [
+                [VK_KHR_get_display_properties2] => GetDisplayPlaneCapabilities2KHR,
+            ],
+            hooks: [
+                physical_device: handle,
+                p_display_plane_info: structs,
+                p_capabilities: structs,
+            ],
+            checked: {
+                method_name: get_display_plane_capabilities_2_khr_checked,
+                args: (
+                    physical_device: PhysicalDevice,
+                    p_display_plane_info: *const DisplayPlaneInfo2KHR,
+                ),
+                output: p_capabilities: DisplayPlaneCapabilities2KHR,
+                result: success,
+            },
+        },
+        {
+            name: pfn_get_physical_device_calibrateable_time_domains_ext,
+            method_name: get_physical_device_calibrateable_time_domains_ext,
+            try_method_name: try_get_physical_device_calibrateable_time_domains_ext,
+            unchecked_method_name: get_physical_device_calibrateable_time_domains_ext_unchecked,
+            ptr: GetPhysicalDeviceCalibrateableTimeDomainsEXT,
+            signature: (
+                physical_device: PhysicalDevice,
+                p_time_domain_count: *mut u32,
+                p_time_domains: *mut TimeDomainEXT,
+            ) -> Result,
+            takes_handle: false,
+            provided_by: [
+                [] => GetPhysicalDeviceCalibrateableTimeDomainsEXT,
+            ],
+            hooks: [
+                physical_device: handle,
+                p_time_domain_count: ptr,
+                p_time_domains: ptr[*p_time_domain_count],
+            ],
+            vec: {
+                method_name: get_physical_device_calibrateable_time_domains_ext_vec,
+                args: (
+                    physical_device: PhysicalDevice,
+                ),
+                element: TimeDomainEXT,
+                result: success,
+            },
+        },
+        {
+            name: pfn_create_debug_utils_messenger_ext,
+            method_name: create_debug_utils_messenger_ext,
+            try_method_name: try_create_debug_utils_messenger_ext,
+            unchecked_method_name: create_debug_utils_messenger_ext_unchecked,
+            ptr: CreateDebugUtilsMessengerEXT,
+            signature: (
+                p_create_info: *const DebugUtilsMessengerCreateInfoEXT,
+                p_allocator: *const AllocationCallbacks,
+                p_messenger: *mut DebugUtilsMessengerEXT,
+            ) -> Result,
+            takes_handle: true,
+            provided_by: [
+                [VK_EXT_debug_utils] => CreateDebugUtilsMessengerEXT,
+            ],
+            hooks: [
+                p_create_info: structs,
+                p_allocator: structs,
+                p_messenger: handles,
+            ],
+            checked: {
+                method_name: create_debug_utils_messenger_ext_checked,
+                args: (
+                    p_create_info: *const DebugUtilsMessengerCreateInfoEXT,
+                    p_allocator: Option<&AllocationCallbacks>,
+                ),
+                output: p_messenger: DebugUtilsMessengerEXT,
+                result: success,
+            },
+        },
+        {
+            name: pfn_destroy_debug_utils_messenger_ext,
+            method_name: destroy_debug_utils_messenger_ext,
+            try_method_name: try_destroy_debug_utils_messenger_ext,
+            unchecked_method_name: destroy_debug_utils_messenger_ext_unchecked,
+            ptr: DestroyDebugUtilsMessengerEXT,
+            signature: (
+                messenger: DebugUtilsMessengerEXT,
+                p_allocator: *const AllocationCallbacks,
+            ),
+            takes_handle: true,
+            provided_by: [
+                [VK_EXT_debug_utils] => DestroyDebugUtilsMessengerEXT,
+            ],
+            hooks: [
+                messenger: handle,
+                p_allocator: structs,
+            ],
+        },
+        {
+            name: pfn_submit_debug_utils_message_ext,
+            method_name: submit_debug_utils_message_ext,
+            try_method_name: try_submit_debug_utils_message_ext,
+            unchecked_method_name: submit_debug_utils_message_ext_unchecked,
+            ptr: SubmitDebugUtilsMessageEXT,
+            signature: (
+                message_severity: DebugUtilsMessageSeverityFlagBitsEXT,
+                message_types: DebugUtilsMessageTypeFlagsEXT,
+                p_callback_data: *const DebugUtilsMessengerCallbackDataEXT,
+            ),
+            takes_handle: true,
+            provided_by: [
+                [VK_EXT_debug_utils] => SubmitDebugUtilsMessageEXT,
+            ],
+            hooks: [
+                message_severity: value,
+                message_types: value,
+                p_callback_data: structs,
+            ],
+        },
+        {
+            name: pfn_get_physical_device_cooperative_matrix_properties_nv,
+            method_name: get_physical_device_cooperative_matrix_properties_nv,
+            try_method_name: try_get_physical_device_cooperative_matrix_properties_nv,
+            unchecked_method_name: get_physical_device_cooperative_matrix_properties_nv_unchecked,
+            ptr: GetPhysicalDeviceCooperativeMatrixPropertiesNV,
+            signature: (
+                physical_device: PhysicalDevice,
+                p_property_count: *mut u32,
+                p_properties: *mut CooperativeMatrixPropertiesNV,
+            ) -> Result,
+            takes_handle: false,
+            provided_by: [
+                [] => GetPhysicalDeviceCooperativeMatrixPropertiesNV,
+            ],
+            hooks: [
+                physical_device: handle,
+                p_property_count: ptr,
+                p_properties: structs[*p_property_count],
+            ],
+            vec: {
+                method_name: get_physical_device_cooperative_matrix_properties_nv_vec,
+                args: (
+                    physical_device: PhysicalDevice,
+                ),
+                element: CooperativeMatrixPropertiesNV,
+                result: success,
+            },
+        },
+        {
+            name: pfn_get_physical_device_surface_present_modes_2_ext,
+            method_name: get_physical_device_surface_present_modes_2_ext,
+            try_method_name: try_get_physical_device_surface_present_modes_2_ext,
+            unchecked_method_name: get_physical_device_surface_present_modes_2_ext_unchecked,
+            ptr: GetPhysicalDeviceSurfacePresentModes2EXT,
+            signature: (
+                physical_device: PhysicalDevice,
+                p_surface_info: *const PhysicalDeviceSurfaceInfo2KHR,
+                p_present_mode_count: *mut u32,
+                p_present_modes: *mut PresentModeKHR,
+            ) -> Result,
+            takes_handle: false,
+            provided_by: [
+                [] => GetPhysicalDeviceSurfacePresentModes2EXT,
+            ],
+            hooks: [
+                physical_device: handle,
+                p_surface_info: structs,
+                p_present_mode_count: ptr,
+                p_present_modes: ptr[*p_present_mode_count],
+            ],
+            vec: {
+                method_name: get_physical_device_surface_present_modes_2_ext_vec,
+                args: (
+                    physical_device: PhysicalDevice,
+                    p_surface_info: *const PhysicalDeviceSurfaceInfo2KHR,
+                ),
+                element: PresentModeKHR,
+                result: success,
+            },
+        },
+        {
+            name: pfn_enumerate_physical_device_queue_family_performance_query_counters_khr,
+            method_name: enumerate_physical_device_queue_family_performance_query_counters_khr,
+            try_method_name: try_enumerate_physical_device_queue_family_performance_query_counters_khr,
+            unchecked_method_name: enumerate_physical_device_queue_family_performance_query_counters_khr_unchecked,
+            ptr: EnumeratePhysicalDeviceQueueFamilyPerformanceQueryCountersKHR,
+            signature: (
+                physical_device: PhysicalDevice,
+                queue_family_index: u32,
+                p_counter_count: *mut u32,
+                p_counters: *mut PerformanceCounterKHR,
+                p_counter_descriptions: *mut PerformanceCounterDescriptionKHR,
+            ) -> Result,
+            takes_handle: false,
+            provided_by: [
+                [] => EnumeratePhysicalDeviceQueueFamilyPerformanceQueryCountersKHR,
+            ],
+            hooks: [
+                physical_device: handle,
+                queue_family_index: value,
+                p_counter_count: ptr,
+                p_counters: structs[*p_counter_count],
+                p_counter_descriptions: structs[*p_counter_count],
+            ],
+        },
+        {
+            name: pfn_get_physical_device_queue_family_performance_query_passes_khr,
+            method_name: get_physical_device_queue_family_performance_query_passes_khr,
+            try_method_name: try_get_physical_device_queue_family_performance_query_passes_khr,
+            unchecked_method_name: get_physical_device_queue_family_performance_query_passes_khr_unchecked,
+            ptr: GetPhysicalDeviceQueueFamilyPerformanceQueryPassesKHR,
+            signature: (
+                physical_device: PhysicalDevice,
+                p_performance_query_create_info: *const QueryPoolPerformanceCreateInfoKHR,
+                p_num_passes: *mut u32,
+            ),
+            takes_handle: false,
+            provided_by: [
+                [] => GetPhysicalDeviceQueueFamilyPerformanceQueryPassesKHR,
+            ],
+            hooks: [
+                physical_device: handle,
+                p_performance_query_create_info: structs,
+                p_num_passes: ptr,
+            ],
+            checked: {
+                method_name: get_physical_device_queue_family_performance_query_passes_khr_checked,
+                args: (
+                    physical_device: PhysicalDevice,
+                    p_performance_query_create_info: *const QueryPoolPerformanceCreateInfoKHR,
+                ),
+                output: p_num_passes: u32,
+                result: void,
+            },
+        },
+        {
+            name: pfn_create_headless_surface_ext,
+            method_name: create_headless_surface_ext,
+            try_method_name: try_create_headless_surface_ext,
+            unchecked_method_name: create_headless_surface_ext_unchecked,
+            ptr: CreateHeadlessSurfaceEXT,
+            signature: (
+                p_create_info: *const HeadlessSurfaceCreateInfoEXT,
+                p_allocator: *const AllocationCallbacks,
+                p_surface: *mut SurfaceKHR,
+            ) -> Result,
+            takes_handle: true,
+            provided_by: [
+                [VK_EXT_headless_surface] => CreateHeadlessSurfaceEXT,
+            ],
+            hooks: [
+                p_create_info: structs,
+                p_allocator: structs,
+                p_surface: handles,
+            ],
+            checked: {
+                method_name: create_headless_surface_ext_checked,
+                args: (
+                    p_create_info: *const HeadlessSurfaceCreateInfoEXT,
+                    p_allocator: Option<&AllocationCallbacks>,
+                ),
+                output: p_surface: SurfaceKHR,
+                result: success,
+            },
+        },
+        {
+            name: pfn_get_physical_device_supported_framebuffer_mixed_samples_combinations_nv,
+            method_name: get_physical_device_supported_framebuffer_mixed_samples_combinations_nv,
+            try_method_name: try_get_physical_device_supported_framebuffer_mixed_samples_combinations_nv,
+            unchecked_method_name: get_physical_device_supported_framebuffer_mixed_samples_combinations_nv_unchecked,
+            ptr: GetPhysicalDeviceSupportedFramebufferMixedSamplesCombinationsNV,
+            signature: (
+                physical_device: PhysicalDevice,
+                p_combination_count: *mut u32,
+                p_combinations: *mut FramebufferMixedSamplesCombinationNV,
+            ) -> Result,
+            takes_handle: false,
+            provided_by: [
+                [] => GetPhysicalDeviceSupportedFramebufferMixedSamplesCombinationsNV,
+            ],
+            hooks: [
+                physical_device: handle,
+                p_combination_count: ptr,
+                p_combinations: structs[*p_combination_count],
+            ],
+            vec: {
+                method_name: get_physical_device_supported_framebuffer_mixed_samples_combinations_nv_vec,
+                args: (
+                    physical_device: PhysicalDevice,
+                ),
+                element: FramebufferMixedSamplesCombinationNV,
+                result: success,
+            },
+        },
+        {
+            name: pfn_get_physical_device_tool_properties,
+            method_name: get_physical_device_tool_properties,
+            try_method_name: try_get_physical_device_tool_properties,
+            unchecked_method_name: get_physical_device_tool_properties_unchecked,
+            ptr: GetPhysicalDeviceToolProperties,
+            aliases: [GetPhysicalDeviceToolPropertiesEXT],
+            signature: (
+                physical_device: PhysicalDevice,
+                p_tool_count: *mut u32,
+                p_tool_properties: *mut PhysicalDeviceToolProperties,
+            ) -> Result,
+            takes_handle: false,
+            provided_by: [
+                [VK_VERSION_1_3] => GetPhysicalDeviceToolProperties,
+                [] => GetPhysicalDeviceToolPropertiesEXT,
+            ],
+            hooks: [
+                physical_device: handle,
+                p_tool_count: ptr,
+                p_tool_properties: structs[*p_tool_count],
+            ],
+            vec: {
+                method_name: get_physical_device_tool_properties_vec,
+                args: (
+                    physical_device: PhysicalDevice,
+                ),
+                element: PhysicalDeviceToolProperties,
+                result: success,
+            },
+        },
+        {
+            name: pfn_get_physical_device_fragment_shading_rates_khr,
+            method_name: get_physical_device_fragment_shading_rates_khr,
+            try_method_name: try_get_physical_device_fragment_shading_rates_khr,
+            unchecked_method_name: get_physical_device_fragment_shading_rates_khr_unchecked,
+            ptr: GetPhysicalDeviceFragmentShadingRatesKHR,
+            signature: (
+                physical_device: PhysicalDevice,
+                p_fragment_shading_rate_count: *mut u32,
+                p_fragment_shading_rates: *mut PhysicalDeviceFragmentShadingRateKHR,
+            ) -> Result,
+            takes_handle: false,
+            provided_by: [
+                [] => GetPhysicalDeviceFragmentShadingRatesKHR,
+            ],
+            hooks: [
+                physical_device: handle,
+                p_fragment_shading_rate_count: ptr,
+                p_fragment_shading_rates: structs[*p_fragment_shading_rate_count],
+            ],
+            vec: {
+                method_name: get_physical_device_fragment_shading_rates_khr_vec,
+                args: (
+                    physical_device: PhysicalDevice,
+                ),
+                element: PhysicalDeviceFragmentShadingRateKHR,
+                result: success,
+            },
+        },
+        {
+            name: pfn_get_physical_device_video_capabilities_khr,
+            method_name: get_physical_device_video_capabilities_khr,
+            try_method_name: try_get_physical_device_video_capabilities_khr,
+            unchecked_method_name: get_physical_device_video_capabilities_khr_unchecked,
+            ptr: GetPhysicalDeviceVideoCapabilitiesKHR,
+            signature: (
+                physical_device: PhysicalDevice,
+                p_video_profile: *const VideoProfileInfoKHR,
+                p_capabilities: *mut VideoCapabilitiesKHR,
+            ) -> Result,
+            takes_handle: false,
+            provided_by: [
+                [] => GetPhysicalDeviceVideoCapabilitiesKHR,
+            ],
+            hooks: [
+                physical_device: handle,
+                p_video_profile: structs,
+                p_capabilities: structs,
+            ],
+            checked: {
+                method_name: get_physical_device_video_capabilities_khr_checked,
+                args: (
+                    physical_device: PhysicalDevice,
+                    p_video_profile: *const VideoProfileInfoKHR,
+                ),
+                output: p_capabilities: VideoCapabilitiesKHR,
+                result: success,
+            },
+        },
+        {
+            name: pfn_get_physical_device_video_format_properties_khr,
+            method_name: get_physical_device_video_format_properties_khr,
+            try_method_name: try_get_physical_device_video_format_properties_khr,
+            unchecked_method_name: get_physical_device_video_format_properties_khr_unchecked,
+            ptr: GetPhysicalDeviceVideoFormatPropertiesKHR,
+            signature: (
+                physical_device: PhysicalDevice,
+                p_video_format_info: *const PhysicalDeviceVideoFormatInfoKHR,
+                p_video_format_property_count: *mut u32,
+                p_video_format_properties: *mut VideoFormatPropertiesKHR,
+            ) -> Result,
+            takes_handle: false,
+            provided_by: [
+                [] => GetPhysicalDeviceVideoFormatPropertiesKHR,
+            ],
+            hooks: [
+                physical_device: handle,
+                p_video_format_info: structs,
+                p_video_format_property_count: ptr,
+                p_video_format_properties: structs[*p_video_format_property_count],
+            ],
+            vec: {
+                method_name: get_physical_device_video_format_properties_khr_vec,
+                args: (
+                    physical_device: PhysicalDevice,
+                    p_video_format_info: *const PhysicalDeviceVideoFormatInfoKHR,
+                ),
+                element: VideoFormatPropertiesKHR,
+                result: success,
+            },
+        },
+        {
+            name: pfn_acquire_drm_display_ext,
+            method_name: acquire_drm_display_ext,
+            try_method_name: try_acquire_drm_display_ext,
+            unchecked_method_name: acquire_drm_display_ext_unchecked,
+            ptr: AcquireDrmDisplayEXT,
+            signature: (
+                physical_device: PhysicalDevice,
+                drm_fd: i32,
+                display: DisplayKHR,
+            ) -> Result,
+            takes_handle: false,
+            provided_by: [
+                [VK_EXT_acquire_drm_display] => AcquireDrmDisplayEXT,
+            ],
+            hooks: [
+                physical_device: handle,
+                drm_fd: value,
+                display: handle,
+            ],
+        },
+        {
+            name: pfn_get_drm_display_ext,
+            method_name: get_drm_display_ext,
+            try_method_name: try_get_drm_display_ext,
+            unchecked_method_name: get_drm_display_ext_unchecked,
+            ptr: GetDrmDisplayEXT,
+            signature: (
+                physical_device: PhysicalDevice,
+                drm_fd: i32,
+                connector_id: u32,
+                display: *mut DisplayKHR,
+            ) -> Result,
+            takes_handle: false,
+            provided_by: [
+                [VK_EXT_acquire_drm_display] => GetDrmDisplayEXT,
+            ],
+            hooks: [
+                physical_device: handle,
+                drm_fd: value,
+                connector_id: value,
+                display: handles,
+            ],
+            checked: {
+                method_name: get_drm_display_ext_checked,
+                args: (
+                    physical_device: PhysicalDevice,
+                    drm_fd: i32,
+                    connector_id: u32,
+                ),
+                output: display: DisplayKHR,
+                result: success,
+            },
+        },
+        {
+            name: pfn_get_physical_device_optical_flow_image_formats_nv,
+            method_name: get_physical_device_optical_flow_image_formats_nv,
+            try_method_name: try_get_physical_device_optical_flow_image_formats_nv,
+            unchecked_method_name: get_physical_device_optical_flow_image_formats_nv_unchecked,
+            ptr: GetPhysicalDeviceOpticalFlowImageFormatsNV,
+            signature: (
+                physical_device: PhysicalDevice,
+                p_optical_flow_image_format_info: *const OpticalFlowImageFormatInfoNV,
+                p_format_count: *mut u32,
+                p_image_format_properties: *mut OpticalFlowImageFormatPropertiesNV,
+            ) -> Result,
+            takes_handle: false,
+            provided_by: [
+                [] => GetPhysicalDeviceOpticalFlowImageFormatsNV,
+            ],
+            hooks: [
+                physical_device: handle,
+                p_optical_flow_image_format_info: structs,
+                p_format_count: ptr,
+                p_image_format_properties: structs[*p_format_count],
+            ],
+            vec: {
+                method_name: get_physical_device_optical_flow_image_formats_nv_vec,
+                args: (
+                    physical_device: PhysicalDevice,
+                    p_optical_flow_image_format_info: *const OpticalFlowImageFormatInfoNV,
+                ),
+                element: OpticalFlowImageFormatPropertiesNV,
+                result: success,
+            },
+        },
+    ],
+}
+
+impl_view! {
+    name: PhysicalDeviceView,
+    table: InstanceTable,
+    handle: {
+        name: physical_device,
+        type: PhysicalDevice,
+    },
+    members: [
+        {
+            method_name: get_physical_device_properties,
+            try_method_name: try_get_physical_device_properties,
+            unchecked_method_name: get_physical_device_properties_unchecked,
+            signature: (
+                p_properties: *mut PhysicalDeviceProperties,
+            ),
+            checked: {
+                method_name: get_physical_device_properties_checked,
+                args: (
+                ),
+                output: p_properties: PhysicalDeviceProperties,
+                result: void,
+            },
+        },
+        {
+            method_name: get_physical_device_queue_family_properties,
+            try_method_name: try_get_physical_device_queue_family_properties,
+            unchecked_method_name: get_physical_device_queue_family_properties_unchecked,
+            signature: (
+                p_queue_family_property_count: *mut u32,
+                p_queue_family_properties: *mut QueueFamilyProperties,
+            ),
+            vec: {
+                method_name: get_physical_device_queue_family_properties_vec,
+                args: (
+                ),
+                element: QueueFamilyProperties,
+                result: void,
+            },
+        },
+        {
+            method_name: get_physical_device_memory_properties,
+            try_method_name: try_get_physical_device_memory_properties,
+            unchecked_method_name: get_physical_device_memory_properties_unchecked,
+            signature: (
+                p_memory_properties: *mut PhysicalDeviceMemoryProperties,
+            ),
+            checked: {
+                method_name: get_physical_device_memory_properties_checked,
+                args: (
+                ),
+                output: p_memory_properties: PhysicalDeviceMemoryProperties,
+                result: void,
+            },
+        },
+        {
+            method_name: get_physical_device_features,
+            try_method_name: try_get_physical_device_features,
+            unchecked_method_name: get_physical_device_features_unchecked,
+            signature: (
+                p_features: *mut PhysicalDeviceFeatures,
+            ),
+            checked: {
+                method_name: get_physical_device_features_checked,
+                args: (
+                ),
+                output: p_features: PhysicalDeviceFeatures,
+                result: void,
+            },
+        },
+        {
+            method_name: get_physical_device_format_properties,
+            try_method_name: try_get_physical_device_format_properties,
+            unchecked_method_name: get_physical_device_format_properties_unchecked,
+            signature: (
+                format: Format,
+                p_format_properties: *mut FormatProperties,
+            ),
+            checked: {
+                method_name: get_physical_device_format_properties_checked,
+                args: (
+                    format: Format,
+                ),
+                output: p_format_properties: FormatProperties,
+                result: void,
+            },
+        },
+        {
+            method_name: get_physical_device_image_format_properties,
+            try_method_name: try_get_physical_device_image_format_properties,
+            unchecked_method_name: get_physical_device_image_format_properties_unchecked,
+            signature: (
+                format: Format,
+                ty: ImageType,
+                tiling: ImageTiling,
+                usage: ImageUsageFlags,
+                flags: ImageCreateFlags,
+                p_image_format_properties: *mut ImageFormatProperties,
+            ) -> Result,
+            checked: {
+                method_name: get_physical_device_image_format_properties_checked,
+                args: (
+                    format: Format,
+                    ty: ImageType,
+                    tiling: ImageTiling,
+                    usage: ImageUsageFlags,
+                    flags: ImageCreateFlags,
+                ),
+                output: p_image_format_properties: ImageFormatProperties,
+                result: success,
+            },
+        },
+        {
+            method_name: create_device,
+            try_method_name: try_create_device,
+            unchecked_method_name: create_device_unchecked,
+            signature: (
+                p_create_info: *const DeviceCreateInfo,
+                p_allocator: *const AllocationCallbacks,
+                p_device: *mut Device,
+            ) -> Result,
+            checked: {
+                method_name: create_device_checked,
+                args: (
+                    p_create_info: *const DeviceCreateInfo,
+                    p_allocator: Option<&AllocationCallbacks>,
+                ),
+                output: p_device: Device,
+                result: success,
+            },
+        },
+        {
+            method_name: enumerate_device_layer_properties,
+            try_method_name: try_enumerate_device_layer_properties,
+            unchecked_method_name: enumerate_device_layer_properties_unchecked,
+            signature: (
+                p_property_count: *mut u32,
+                p_properties: *mut LayerProperties,
+            ) -> Result,
+            vec: {
+                method_name: enumerate_device_layer_properties_vec,
+                args: (
+                ),
+                element: LayerProperties,
+                result: success,
+            },
+        },
+        {
+            method_name: enumerate_device_extension_properties,
+            try_method_name: try_enumerate_device_extension_properties,
+            unchecked_method_name: enumerate_device_extension_properties_unchecked,
+            signature: (
+                p_layer_name: *const c_char,
+                p_property_count: *mut u32,
+                p_properties: *mut ExtensionProperties,
+            ) -> Result,
+            vec: {
+                method_name: enumerate_device_extension_properties_vec,
+                args: (
+                    p_layer_name: *const c_char,
+                ),
+                element: ExtensionProperties,
+                result: success,
+            },
+        },
+        {
+            method_name: get_physical_device_sparse_image_format_properties,
+            try_method_name: try_get_physical_device_sparse_image_format_properties,
+            unchecked_method_name: get_physical_device_sparse_image_format_properties_unchecked,
+            signature: (
+                format: Format,
+                ty: ImageType,
+                samples: SampleCountFlagBits,
+                usage: ImageUsageFlags,
+                tiling: ImageTiling,
+                p_property_count: *mut u32,
+                p_properties: *mut SparseImageFormatProperties,
+            ),
+            vec: {
+                method_name: get_physical_device_sparse_image_format_properties_vec,
+                args: (
+                    format: Format,
+                    ty: ImageType,
+                    samples: SampleCountFlagBits,
+                    usage: ImageUsageFlags,
+                    tiling: ImageTiling,
+                ),
+                element: SparseImageFormatProperties,
+                result: void,
+            },
+        },
+        {
+            method_name: get_physical_device_display_properties_khr,
+            try_method_name: try_get_physical_device_display_properties_khr,
+            unchecked_method_name: get_physical_device_display_properties_khr_unchecked,
+            signature: (
+                p_property_count: *mut u32,
+                p_properties: *mut DisplayPropertiesKHR,
+            ) -> Result,
+            vec: {
+                method_name: get_physical_device_display_properties_khr_vec,
+                args: (
+                ),
+                element: DisplayPropertiesKHR,
+                result: success,
+            },
+        },
+        {
+            method_name: get_physical_device_display_plane_properties_khr,
+            try_method_name: try_get_physical_device_display_plane_properties_khr,
+            unchecked_method_name: get_physical_device_display_plane_properties_khr_unchecked,
+            signature: (
+                p_property_count: *mut u32,
+                p_properties: *mut DisplayPlanePropertiesKHR,
+            ) -> Result,
+            vec: {
+                method_name: get_physical_device_display_plane_properties_khr_vec,
+                args: (
+                ),
+                element: DisplayPlanePropertiesKHR,
+                result: success,
+            },
+        },
+        {
+            method_name: get_display_plane_supported_displays_khr,
+            try_method_name: try_get_display_plane_supported_displays_khr,
+            unchecked_method_name: get_display_plane_supported_displays_khr_unchecked,
+            signature: (
+                plane_index: u32,
+                p_display_count: *mut u32,
+                p_displays: *mut DisplayKHR,
+            ) -> Result,
+            vec: {
+                method_name: get_display_plane_supported_displays_khr_vec,
+                args: (
+                    plane_index: u32,
+                ),
+                element: DisplayKHR,
+                result: success,
+            },
+        },
+        {
+            method_name: get_display_mode_properties_khr,
+            try_method_name: try_get_display_mode_properties_khr,
+            unchecked_method_name: get_display_mode_properties_khr_unchecked,
+            signature: (
+                display: DisplayKHR,
+                p_property_count: *mut u32,
+                p_properties: *mut DisplayModePropertiesKHR,
+            ) -> Result,
+            vec: {
+                method_name: get_display_mode_properties_khr_vec,
+                args: (
+                    display: DisplayKHR,
+                ),
+                element: DisplayModePropertiesKHR,
+                result: success,
+            },
+        },
+        {
+            method_name: create_display_mode_khr,
+            try_method_name: try_create_display_mode_khr,
+            unchecked_method_name: create_display_mode_khr_unchecked,
+            signature: (
+                display: DisplayKHR,
+                p_create_info: *const DisplayModeCreateInfoKHR,
+                p_allocator: *const AllocationCallbacks,
+                p_mode: *mut DisplayModeKHR,
+            ) -> Result,
+            checked: {
+                method_name: create_display_mode_khr_checked,
+                args: (
+                    display: DisplayKHR,
+                    p_create_info: *const DisplayModeCreateInfoKHR,
+                    p_allocator: Option<&AllocationCallbacks>,
+                ),
+                output: p_mode: DisplayModeKHR,
+                result: success,
+            },
+        },
+        {
+            method_name: get_display_plane_capabilities_khr,
+            try_method_name: try_get_display_plane_capabilities_khr,
+            unchecked_method_name: get_display_plane_capabilities_khr_unchecked,
+            signature: (
+                mode: DisplayModeKHR,
+                plane_index: u32,
+                p_capabilities: *mut DisplayPlaneCapabilitiesKHR,
+            ) -> Result,
+            checked: {
+                method_name: get_display_plane_capabilities_khr_checked,
+                args: (
+                    mode: DisplayModeKHR,
+                    plane_index: u32,
+                ),
+                output: p_capabilities: DisplayPlaneCapabilitiesKHR,
+                result: success,
+            },
+        },
+        {
+            method_name: get_physical_device_surface_support_khr,
+            try_method_name: try_get_physical_device_surface_support_khr,
+            unchecked_method_name: get_physical_device_surface_support_khr_unchecked,
+            signature: (
+                queue_family_index: u32,
+                surface: SurfaceKHR,
+                p_supported: *mut Bool32,
+            ) -> Result,
+            checked: {
+                method_name: get_physical_device_surface_support_khr_checked,
+                args: (
+                    queue_family_index: u32,
+                    surface: SurfaceKHR,
+                ),
+                output: p_supported: Bool32,
+                result: success,
+            },
+        },
+        {
+            method_name: get_physical_device_surface_capabilities_khr,
+            try_method_name: try_get_physical_device_surface_capabilities_khr,
+            unchecked_method_name: get_physical_device_surface_capabilities_khr_unchecked,
+            signature: (
+                surface: SurfaceKHR,
+                p_surface_capabilities: *mut SurfaceCapabilitiesKHR,
+            ) -> Result,
+            checked: {
+                method_name: get_physical_device_surface_capabilities_khr_checked,
+                args: (
+                    surface: SurfaceKHR,
+                ),
+                output: p_surface_capabilities: SurfaceCapabilitiesKHR,
+                result: success,
+            },
+        },
+        {
+            method_name: get_physical_device_surface_formats_khr,
+            try_method_name: try_get_physical_device_surface_formats_khr,
+            unchecked_method_name: get_physical_device_surface_formats_khr_unchecked,
+            signature: (
+                surface: SurfaceKHR,
+                p_surface_format_count: *mut u32,
+                p_surface_formats: *mut SurfaceFormatKHR,
+            ) -> Result,
+            vec: {
+                method_name: get_physical_device_surface_formats_khr_vec,
+                args: (
+                    surface: SurfaceKHR,
+                ),
+                element: SurfaceFormatKHR,
+                result: success,
+            },
+        },
+        {
+            method_name: get_physical_device_surface_present_modes_khr,
+            try_method_name: try_get_physical_device_surface_present_modes_khr,
+            unchecked_method_name: get_physical_device_surface_present_modes_khr_unchecked,
+            signature: (
+                surface: SurfaceKHR,
+                p_present_mode_count: *mut u32,
+                p_present_modes: *mut PresentModeKHR,
+            ) -> Result,
+            vec: {
+                method_name: get_physical_device_surface_present_modes_khr_vec,
+                args: (
+                    surface: SurfaceKHR,
+                ),
+                element: PresentModeKHR,
+                result: success,
+            },
+        },
+        {
+            method_name: get_physical_device_wayland_presentation_support_khr,
+            try_method_name: try_get_physical_device_wayland_presentation_support_khr,
+            unchecked_method_name: get_physical_device_wayland_presentation_support_khr_unchecked,
+            signature: (
+                queue_family_index: u32,
+                display: *mut wl_display,
+            ) -> Bool32,
+        },
+        {
+            method_name: get_physical_device_win_32_presentation_support_khr,
+            try_method_name: try_get_physical_device_win_32_presentation_support_khr,
+            unchecked_method_name: get_physical_device_win_32_presentation_support_khr_unchecked,
+            signature: (
+                queue_family_index: u32,
+            ) -> Bool32,
+        },
+        {
+            method_name: get_physical_device_xlib_presentation_support_khr,
+            try_method_name: try_get_physical_device_xlib_presentation_support_khr,
+            unchecked_method_name: get_physical_device_xlib_presentation_support_khr_unchecked,
+            signature: (
+                queue_family_index: u32,
+                dpy: *mut Display,
+                visual_id: VisualID,
+            ) -> Bool32,
+        },
+        {
+            method_name: get_physical_device_xcb_presentation_support_khr,
+            try_method_name: try_get_physical_device_xcb_presentation_support_khr,
+            unchecked_method_name: get_physical_device_xcb_presentation_support_khr_unchecked,
+            signature: (
+                queue_family_index: u32,
+                connection: *mut xcb_connection_t,
+                visual_id: xcb_visualid_t,
+            ) -> Bool32,
+        },
+        {
+            method_name: get_physical_device_direct_fb_presentation_support_ext,
+            try_method_name: try_get_physical_device_direct_fb_presentation_support_ext,
+            unchecked_method_name: get_physical_device_direct_fb_presentation_support_ext_unchecked,
+            signature: (
+                queue_family_index: u32,
+                dfb: *mut IDirectFB,
+            ) -> Bool32,
+        },
+        {
+            method_name: get_physical_device_screen_presentation_support_qnx,
+            try_method_name: try_get_physical_device_screen_presentation_support_qnx,
+            unchecked_method_name: get_physical_device_screen_presentation_support_qnx_unchecked,
+            signature: (
+                queue_family_index: u32,
+                window: *mut _screen_window,
+            ) -> Bool32,
+        },
+        {
+            method_name: get_physical_device_external_image_format_properties_nv,
+            try_method_name: try_get_physical_device_external_image_format_properties_nv,
+            unchecked_method_name: get_physical_device_external_image_format_properties_nv_unchecked,
+            signature: (
+                format: Format,
+                ty: ImageType,
+                tiling: ImageTiling,
+                usage: ImageUsageFlags,
+                flags: ImageCreateFlags,
+                external_handle_type: ExternalMemoryHandleTypeFlagsNV,
+                p_external_image_format_properties: *mut ExternalImageFormatPropertiesNV,
+            ) -> Result,
+            checked: {
+                method_name: get_physical_device_external_image_format_properties_nv_checked,
+                args: (
+                    format: Format,
+                    ty: ImageType,
+                    tiling: ImageTiling,
+                    usage: ImageUsageFlags,
+                    flags: ImageCreateFlags,
+                    external_handle_type: ExternalMemoryHandleTypeFlagsNV,
+                ),
+                output: p_external_image_format_properties: ExternalImageFormatPropertiesNV,
+                result: success,
+            },
+        },
+        {
+            method_name: get_physical_device_features_2,
+            try_method_name: try_get_physical_device_features_2,
+            unchecked_method_name: get_physical_device_features_2_unchecked,
+            signature: (
+                p_features: *mut PhysicalDeviceFeatures2,
+            ),
+            checked: {
+                method_name: get_physical_device_features_2_checked,
+                args: (
+                ),
+                output: p_features: PhysicalDeviceFeatures2,
+                result: void,
+            },
+        },
+        {
+            method_name: get_physical_device_properties_2,
+            try_method_name: try_get_physical_device_properties_2,
+            unchecked_method_name: get_physical_device_properties_2_unchecked,
+            signature: (
+                p_properties: *mut PhysicalDeviceProperties2,
+            ),
+            checked: {
+                method_name: get_physical_device_properties_2_checked,
+                args: (
+                ),
+                output: p_properties: PhysicalDeviceProperties2,
+                result: void,
+            },
+        },
+        {
+            method_name: get_physical_device_format_properties_2,
+            try_method_name: try_get_physical_device_format_properties_2,
+            unchecked_method_name: get_physical_device_format_properties_2_unchecked,
+            signature: (
+                format: Format,
+                p_format_properties: *mut FormatProperties2,
+            ),
+            checked: {
+                method_name: get_physical_device_format_properties_2_checked,
+                args: (
+                    format: Format,
+                ),
+                output: p_format_properties: FormatProperties2,
+                result: void,
+            },
+        },
+        {
+            method_name: get_physical_device_image_format_properties_2,
+            try_method_name: try_get_physical_device_image_format_properties_2,
+            unchecked_method_name: get_physical_device_image_format_properties_2_unchecked,
+            signature: (
+                p_image_format_info: *const PhysicalDeviceImageFormatInfo2,
+                p_image_format_properties: *mut ImageFormatProperties2,
+            ) -> Result,
+            checked: {
+                method_name: get_physical_device_image_format_properties_2_checked,
+                args: (
+                    p_image_format_info: *const PhysicalDeviceImageFormatInfo2,
+                ),
+                output: p_image_format_properties: ImageFormatProperties2,
+                result: success,
+            },
+        },
+        {
+            method_name: get_physical_device_queue_family_properties_2,
+            try_method_name: try_get_physical_device_queue_family_properties_2,
+            unchecked_method_name: get_physical_device_queue_family_properties_2_unchecked,
+            signature: (
+                p_queue_family_property_count: *mut u32,
+                p_queue_family_properties: *mut QueueFamilyProperties2,
+            ),
+            vec: {
+                method_name: get_physical_device_queue_family_properties_2_vec,
+                args: (
+                ),
+                element: QueueFamilyProperties2,
+                result: void,
+            },
+        },
+        {
+            method_name: get_physical_device_memory_properties_2,
+            try_method_name: try_get_physical_device_memory_properties_2,
+            unchecked_method_name: get_physical_device_memory_properties_2_unchecked,
+            signature: (
+                p_memory_properties: *mut PhysicalDeviceMemoryProperties2,
+            ),
+            checked: {
+                method_name: get_physical_device_memory_properties_2_checked,
+                args: (
+                ),
+                output: p_memory_properties: PhysicalDeviceMemoryProperties2,
+                result: void,
+            },
+        },
+        {
+            method_name: get_physical_device_sparse_image_format_properties_2,
+            try_method_name: try_get_physical_device_sparse_image_format_properties_2,
+            unchecked_method_name: get_physical_device_sparse_image_format_properties_2_unchecked,
+            signature: (
+                p_format_info: *const PhysicalDeviceSparseImageFormatInfo2,
+                p_property_count: *mut u32,
+                p_properties: *mut SparseImageFormatProperties2,
+            ),
+            vec: {
+                method_name: get_physical_device_sparse_image_format_properties_2_vec,
+                args: (
+                    p_format_info: *const PhysicalDeviceSparseImageFormatInfo2,
+                ),
+                element: SparseImageFormatProperties2,
+                result: void,
+            },
+        },
+        {
+            method_name: get_physical_device_external_buffer_properties,
+            try_method_name: try_get_physical_device_external_buffer_properties,
+            unchecked_method_name: get_physical_device_external_buffer_properties_unchecked,
+            signature: (
+                p_external_buffer_info: *const PhysicalDeviceExternalBufferInfo,
+                p_external_buffer_properties: *mut ExternalBufferProperties,
+            ),
+            checked: {
+                method_name: get_physical_device_external_buffer_properties_checked,
+                args: (
+                    p_external_buffer_info: *const PhysicalDeviceExternalBufferInfo,
+                ),
+                output: p_external_buffer_properties: ExternalBufferProperties,
+                result: void,
+            },
+        },
+        {
+            method_name: get_physical_device_external_semaphore_properties,
+            try_method_name: try_get_physical_device_external_semaphore_properties,
+            unchecked_method_name: get_physical_device_external_semaphore_properties_unchecked,
+            signature: (
+                p_external_semaphore_info: *const PhysicalDeviceExternalSemaphoreInfo,
+                p_external_semaphore_properties: *mut ExternalSemaphoreProperties,
+            ),
+            checked: {
+                method_name: get_physical_device_external_semaphore_properties_checked,
+                args: (
+                    p_external_semaphore_info: *const PhysicalDeviceExternalSemaphoreInfo,
+                ),
+                output: p_external_semaphore_properties: ExternalSemaphoreProperties,
+                result: void,
+            },
+        },
+        {
+            method_name: get_physical_device_external_fence_properties,
+            try_method_name: try_get_physical_device_external_fence_properties,
+            unchecked_method_name: get_physical_device_external_fence_properties_unchecked,
+            signature: (
+                p_external_fence_info: *const PhysicalDeviceExternalFenceInfo,
+                p_external_fence_properties: *mut ExternalFenceProperties,
+            ),
+            checked: {
+                method_name: get_physical_device_external_fence_properties_checked,
+                args: (
+                    p_external_fence_info: *const PhysicalDeviceExternalFenceInfo,
+                ),
+                output: p_external_fence_properties: ExternalFenceProperties,
+                result: void,
+            },
+        },
+        {
+            method_name: release_display_ext,
+            try_method_name: try_release_display_ext,
+            unchecked_method_name: release_display_ext_unchecked,
+            signature: (
+                display: DisplayKHR,
+            ) -> Result,
+        },
+        {
+            method_name: acquire_xlib_display_ext,
+            try_method_name: try_acquire_xlib_display_ext,
+            unchecked_method_name: acquire_xlib_display_ext_unchecked,
+            signature: (
+                dpy: *mut Display,
+                display: DisplayKHR,
+            ) -> Result,
+        },
+        {
+            method_name: get_rand_r_output_display_ext,
+            try_method_name: try_get_rand_r_output_display_ext,
+            unchecked_method_name: get_rand_r_output_display_ext_unchecked,
+            signature: (
+                dpy: *mut Display,
+                rr_output: RROutput,
+                p_display: *mut DisplayKHR,
+            ) -> Result,
+        },
+        {
+            method_name: acquire_winrt_display_nv,
+            try_method_name: try_acquire_winrt_display_nv,
+            unchecked_method_name: acquire_winrt_display_nv_unchecked,
+            signature: (
+                display: DisplayKHR,
+            ) -> Result,
+        },
+        {
+            method_name: get_winrt_display_nv,
+            try_method_name: try_get_winrt_display_nv,
+            unchecked_method_name: get_winrt_display_nv_unchecked,
+            signature: (
+                device_relative_id: u32,
+                p_display: *mut DisplayKHR,
+            ) -> Result,
+            checked: {
+                method_name: get_winrt_display_nv_checked,
+                args: (
+                    device_relative_id: u32,
+                ),
+                output: p_display: DisplayKHR,
+                result: success,
+            },
+        },
+        {
+            method_name: get_physical_device_surface_capabilities_2_ext,
+            try_method_name: try_get_physical_device_surface_capabilities_2_ext,
+            unchecked_method_name: get_physical_device_surface_capabilities_2_ext_unchecked,
+            signature: (
+                surface: SurfaceKHR,
+                p_surface_capabilities: *mut SurfaceCapabilities2EXT,
+            ) -> Result,
+            checked: {
+                method_name: get_physical_device_surface_capabilities_2_ext_checked,
+                args: (
+                    surface: SurfaceKHR,
+                ),
+                output: p_surface_capabilities: SurfaceCapabilities2EXT,
+                result: success,
+            },
+        },
+        {
+            method_name: get_physical_device_present_rectangles_khr,
+            try_method_name: try_get_physical_device_present_rectangles_khr,
+            unchecked_method_name: get_physical_device_present_rectangles_khr_unchecked,
+            signature: (
+                surface: SurfaceKHR,
+                p_rect_count: *mut u32,
+                p_rects: *mut Rect2D,
+            ) -> Result,
+            vec: {
+                method_name: get_physical_device_present_rectangles_khr_vec,
+                args: (
+                    surface: SurfaceKHR,
+                ),
+                element: Rect2D,
+                result: success,
+            },
+        },
+        {
+            method_name: get_physical_device_multisample_properties_ext,
+            try_method_name: try_get_physical_device_multisample_properties_ext,
+            unchecked_method_name: get_physical_device_multisample_properties_ext_unchecked,
+            signature: (
+                samples: SampleCountFlagBits,
+                p_multisample_properties: *mut MultisamplePropertiesEXT,
+            ),
+            checked: {
+                method_name: get_physical_device_multisample_properties_ext_checked,
+                args: (
+                    samples: SampleCountFlagBits,
+                ),
+                output: p_multisample_properties: MultisamplePropertiesEXT,
+                result: void,
+            },
+        },
+        {
+            method_name: get_physical_device_surface_capabilities_2_khr,
+            try_method_name: try_get_physical_device_surface_capabilities_2_khr,
+            unchecked_method_name: get_physical_device_surface_capabilities_2_khr_unchecked,
+            signature: (
+                p_surface_info: *const PhysicalDeviceSurfaceInfo2KHR,
+                p_surface_capabilities: *mut SurfaceCapabilities2KHR,
+            ) -> Result,
+            checked: {
+                method_name: get_physical_device_surface_capabilities_2_khr_checked,
+                args: (
+                    p_surface_info: *const PhysicalDeviceSurfaceInfo2KHR,
+                ),
+                output: p_surface_capabilities: SurfaceCapabilities2KHR,
+                result: success,
+            },
+        },
+        {
+            method_name: get_physical_device_surface_formats_2_khr,
+            try_method_name: try_get_physical_device_surface_formats_2_khr,
+            unchecked_method_name: get_physical_device_surface_formats_2_khr_unchecked,
+            signature: (
+                p_surface_info: *const PhysicalDeviceSurfaceInfo2KHR,
+                p_surface_format_count: *mut u32,
+                p_surface_formats: *mut SurfaceFormat2KHR,
+            ) -> Result,
+            vec: {
+                method_name: get_physical_device_surface_formats_2_khr_vec,
+                args: (
+                    p_surface_info: *const PhysicalDeviceSurfaceInfo2KHR,
+                ),
+                element: SurfaceFormat2KHR,
+                result: success,
+            },
+        },
+        {
+            method_name: get_physical_device_display_properties_2_khr,
+            try_method_name: try_get_physical_device_display_properties_2_khr,
+            unchecked_method_name: get_physical_device_display_properties_2_khr_unchecked,
+            signature: (
+                p_property_count: *mut u32,
+                p_properties: *mut DisplayProperties2KHR,
+            ) -> Result,
+            vec: {
+                method_name: get_physical_device_display_properties_2_khr_vec,
+                args: (
+                ),
+                element: DisplayProperties2KHR,
+                result: success,
+            },
+        },
+        {
+            method_name: get_physical_device_display_plane_properties_2_khr,
+            try_method_name: try_get_physical_device_display_plane_properties_2_khr,
+            unchecked_method_name: get_physical_device_display_plane_properties_2_khr_unchecked,
+            signature: (
+                p_property_count: *mut u32,
+                p_properties: *mut DisplayPlaneProperties2KHR,
+            ) -> Result,
+            vec: {
+                method_name: get_physical_device_display_plane_properties_2_khr_vec,
+                args: (
+                ),
+                element: DisplayPlaneProperties2KHR,
+                result: success,
+            },
+        },
+        {
+            method_name: get_display_mode_properties_2_khr,
+            try_method_name: try_get_display_mode_properties_2_khr,
+            unchecked_method_name: get_display_mode_properties_2_khr_unchecked,
+            signature: (
+                display: DisplayKHR,
+                p_property_count: *mut u32,
+                p_properties: *mut DisplayModeProperties2KHR,
+            ) -> Result,
+            vec: {
+                method_name: get_display_mode_properties_2_khr_vec,
+                args: (
+                    display: DisplayKHR,
+                ),
+                element: DisplayModeProperties2KHR,
+                result: success,
+            },
+        },
+        {
+            method_name: get_display_plane_capabilities_2_khr,
+            try_method_name: try_get_display_plane_capabilities_2_khr,
+            unchecked_method_name: get_display_plane_capabilities_2_khr_unchecked,
+            signature: (
+                p_display_plane_info: *const DisplayPlaneInfo2KHR,
+                p_capabilities: *mut DisplayPlaneCapabilities2KHR,
+            ) -> Result,
+            checked: {
+                method_name: get_display_plane_capabilities_2_khr_checked,
+                args: (
+                    p_display_plane_info: *const DisplayPlaneInfo2KHR,
+                ),
+                output: p_capabilities: DisplayPlaneCapabilities2KHR,
+                result: success,
+            },
+        },
+        {
+            method_name: get_physical_device_calibrateable_time_domains_ext,
+            try_method_name: try_get_physical_device_calibrateable_time_domains_ext,
+            unchecked_method_name: get_physical_device_calibrateable_time_domains_ext_unchecked,
+            signature: (
+                p_time_domain_count: *mut u32,
+                p_time_domains: *mut TimeDomainEXT,
+            ) -> Result,
+            vec: {
+                method_name: get_physical_device_calibrateable_time_domains_ext_vec,
+                args: (
+                ),
+                element: TimeDomainEXT,
+                result: success,
+            },
+        },
+        {
+            method_name: get_physical_device_cooperative_matrix_properties_nv,
+            try_method_name: try_get_physical_device_cooperative_matrix_properties_nv,
+            unchecked_method_name: get_physical_device_cooperative_matrix_properties_nv_unchecked,
+            signature: (
+                p_property_count: *mut u32,
+                p_properties: *mut CooperativeMatrixPropertiesNV,
+            ) -> Result,
+            vec: {
+                method_name: get_physical_device_cooperative_matrix_properties_nv_vec,
+                args: (
+                ),
+                element: CooperativeMatrixPropertiesNV,
+                result: success,
+            },
+        },
+        {
+            method_name: get_physical_device_surface_present_modes_2_ext,
+            try_method_name: try_get_physical_device_surface_present_modes_2_ext,
+            unchecked_method_name: get_physical_device_surface_present_modes_2_ext_unchecked,
+            signature: (
+                p_surface_info: *const PhysicalDeviceSurfaceInfo2KHR,
+                p_present_mode_count: *mut u32,
+                p_present_modes: *mut PresentModeKHR,
+            ) -> Result,
+            vec: {
+                method_name: get_physical_device_surface_present_modes_2_ext_vec,
+                args: (
+                    p_surface_info: *const PhysicalDeviceSurfaceInfo2KHR,
+                ),
+                element: PresentModeKHR,
+                result: success,
+            },
+        },
+        {
+            method_name: enumerate_physical_device_queue_family_performance_query_counters_khr,
+            try_method_name: try_enumerate_physical_device_queue_family_performance_query_counters_khr,
+            unchecked_method_name: enumerate_physical_device_queue_family_performance_query_counters_khr_unchecked,
+            signature: (
+                queue_family_index: u32,
+                p_counter_count: *mut u32,
+                p_counters: *mut PerformanceCounterKHR,
+                p_counter_descriptions: *mut PerformanceCounterDescriptionKHR,
+            ) -> Result,
+        },
+        {
+            method_name: get_physical_device_queue_family_performance_query_passes_khr,
+            try_method_name: try_get_physical_device_queue_family_performance_query_passes_khr,
+            unchecked_method_name: get_physical_device_queue_family_performance_query_passes_khr_unchecked,
+            signature: (
+                p_performance_query_create_info: *const QueryPoolPerformanceCreateInfoKHR,
+                p_num_passes: *mut u32,
+            ),
+            checked: {
+                method_name: get_physical_device_queue_family_performance_query_passes_khr_checked,
+                args: (
+                    p_performance_query_create_info: *const QueryPoolPerformanceCreateInfoKHR,
+                ),
+                output: p_num_passes: u32,
+                result: void,
+            },
+        },
+        {
+            method_name: get_physical_device_supported_framebuffer_mixed_samples_combinations_nv,
+            try_method_name: try_get_physical_device_supported_framebuffer_mixed_samples_combinations_nv,
+            unchecked_method_name: get_physical_device_supported_framebuffer_mixed_samples_combinations_nv_unchecked,
+            signature: (
+                p_combination_count: *mut u32,
+                p_combinations: *mut FramebufferMixedSamplesCombinationNV,
+            ) -> Result,
+            vec: {
+                method_name: get_physical_device_supported_framebuffer_mixed_samples_combinations_nv_vec,
+                args: (
+                ),
+                element: FramebufferMixedSamplesCombinationNV,
+                result: success,
+            },
+        },
+        {
+            method_name: get_physical_device_tool_properties,
+            try_method_name: try_get_physical_device_tool_properties,
+            unchecked_method_name: get_physical_device_tool_properties_unchecked,
+            signature: (
+                p_tool_count: *mut u32,
+                p_tool_properties: *mut PhysicalDeviceToolProperties,
+            ) -> Result,
+            vec: {
+                method_name: get_physical_device_tool_properties_vec,
+                args: (
+                ),
+                element: PhysicalDeviceToolProperties,
+                result: success,
+            },
+        },
+        {
+            method_name: get_physical_device_fragment_shading_rates_khr,
+            try_method_name: try_get_physical_device_fragment_shading_rates_khr,
+            unchecked_method_name: get_physical_device_fragment_shading_rates_khr_unchecked,
+            signature: (
+                p_fragment_shading_rate_count: *mut u32,
+                p_fragment_shading_rates: *mut PhysicalDeviceFragmentShadingRateKHR,
+            ) -> Result,
+            vec: {
+                method_name: get_physical_device_fragment_shading_rates_khr_vec,
+                args: (
+                ),
+                element: PhysicalDeviceFragmentShadingRateKHR,
+                result: success,
+            },
+        },
+        {
+            method_name: get_physical_device_video_capabilities_khr,
+            try_method_name: try_get_physical_device_video_capabilities_khr,
+            unchecked_method_name: get_physical_device_video_capabilities_khr_unchecked,
+            signature: (
+                p_video_profile: *const VideoProfileInfoKHR,
+                p_capabilities: *mut VideoCapabilitiesKHR,
+            ) -> Result,
+            checked: {
+                method_name: get_physical_device_video_capabilities_khr_checked,
+                args: (
+                    p_video_profile: *const VideoProfileInfoKHR,
+                ),
+                output: p_capabilities: VideoCapabilitiesKHR,
+                result: success,
+            },
+        },
+        {
+            method_name: get_physical_device_video_format_properties_khr,
+            try_method_name: try_get_physical_device_video_format_properties_khr,
+            unchecked_method_name: get_physical_device_video_format_properties_khr_unchecked,
+            signature: (
+                p_video_format_info: *const PhysicalDeviceVideoFormatInfoKHR,
+                p_video_format_property_count: *mut u32,
+                p_video_format_properties: *mut VideoFormatPropertiesKHR,
+            ) -> Result,
+            vec: {
+                method_name: get_physical_device_video_format_properties_khr_vec,
+                args: (
+                    p_video_format_info: *const PhysicalDeviceVideoFormatInfoKHR,
+                ),
+                element: VideoFormatPropertiesKHR,
+                result: success,
+            },
+        },
+        {
+            method_name: acquire_drm_display_ext,
+            try_method_name: try_acquire_drm_display_ext,
+            unchecked_method_name: acquire_drm_display_ext_unchecked,
+            signature: (
+                drm_fd: i32,
+                display: DisplayKHR,
+            ) -> Result,
+        },
+        {
+            method_name: get_drm_display_ext,
+            try_method_name: try_get_drm_display_ext,
+            unchecked_method_name: get_drm_display_ext_unchecked,
+            signature: (
+                drm_fd: i32,
+                connector_id: u32,
+                display: *mut DisplayKHR,
+            ) -> Result,
+            checked: {
+                method_name: get_drm_display_ext_checked,
+                args: (
+                    drm_fd: i32,
+                    connector_id: u32,
+                ),
+                output: display: DisplayKHR,
+                result: success,
+            },
+        },
+        {
+            method_name: get_physical_device_optical_flow_image_formats_nv,
+            try_method_name: try_get_physical_device_optical_flow_image_formats_nv,
+            unchecked_method_name: get_physical_device_optical_flow_image_formats_nv_unchecked,
+            signature: (
+                p_optical_flow_image_format_info: *const OpticalFlowImageFormatInfoNV,
+                p_format_count: *mut u32,
+                p_image_format_properties: *mut OpticalFlowImageFormatPropertiesNV,
+            ) -> Result,
+            vec: {
+                method_name: get_physical_device_optical_flow_image_formats_nv_vec,
+                args: (
+                    p_optical_flow_image_format_info: *const OpticalFlowImageFormatInfoNV,
+                ),
+                element: OpticalFlowImageFormatPropertiesNV,
+                result: success,
+            },
+        },
+    ],
+}
+
+impl_table! {
+    name: DeviceTable,
+    lazy_name: LazyDeviceTable,
+    get_proc_addr: GetDeviceProcAddr,
+    handle: {
+        name: device,
+        type: Device,
+    },
+    members: [
+        {
+            name: pfn_get_device_proc_addr,
+            method_name: get_device_proc_addr,
+            try_method_name: try_get_device_proc_addr,
+            unchecked_method_name: get_device_proc_addr_unchecked,
+            ptr: GetDeviceProcAddr,
+            signature: (
+                p_name: *const c_char,
+            ) -> crate::pfn::VoidFunction,
+            takes_handle: true,
+            provided_by: [
+                [VK_VERSION_1_0] => GetDeviceProcAddr,
+            ],
+            hooks: [
+                p_name: str,
+            ],
+        },
+        {
+            name: pfn_destroy_device,
+            method_name: destroy_device,
+            try_method_name: try_destroy_device,
+            unchecked_method_name: destroy_device_unchecked,
+            ptr: DestroyDevice,
+            signature: (
+                p_allocator: *const AllocationCallbacks,
+            ),
+            takes_handle: true,
+            provided_by: [
+                [VK_VERSION_1_0] => DestroyDevice,
+            ],
+            hooks: [
+                p_allocator: structs,
+            ],
+        },
+        {
+            name: pfn_get_device_queue,
+            method_name: get_device_queue,
+            try_method_name: try_get_device_queue,
+            unchecked_method_name: get_device_queue_unchecked,
+            ptr: GetDeviceQueue,
+            signature: (
+                queue_family_index: u32,
+                queue_index: u32,
+                p_queue: *mut Queue,
+            ),
+            takes_handle: true,
+            provided_by: [
+                [VK_VERSION_1_0] => GetDeviceQueue,
+            ],
+            hooks: [
+                queue_family_index: value,
+                queue_index: value,
+                p_queue: handles,
+            ],
+            checked: {
+                method_name: get_device_queue_checked,
+                args: (
+                    queue_family_index: u32,
+                    queue_index: u32,
+                ),
+                output: p_queue: Queue,
+                result: void,
+            },
+        },
+        {
+            name: pfn_queue_submit,
+            method_name: queue_submit,
+            try_method_name: try_queue_submit,
+            unchecked_method_name: queue_submit_unchecked,
+            ptr: QueueSubmit,
+            signature: (
+                queue: Queue,
+                submit_count: u32,
+                p_submits: *const SubmitInfo,
+                fence: Fence,
+            ) -> Result,
+            takes_handle: false,
+            provided_by: [
+                [VK_VERSION_1_0] => QueueSubmit,
+            ],
+            hooks: [
+                queue: handle,
+                submit_count: value,
+                p_submits: structs[submit_count],
+                fence: handle,
+            ],
+        },
+        {
+            name: pfn_queue_wait_idle,
+            method_name: queue_wait_idle,
+            try_method_name: try_queue_wait_idle,
+            unchecked_method_name: queue_wait_idle_unchecked,
+            ptr: QueueWaitIdle,
+            signature: (
+                queue: Queue,
+            ) -> Result,
+            takes_handle: false,
+            provided_by: [
+                [VK_VERSION_1_0] => QueueWaitIdle,
+            ],
+            hooks: [
+                queue: handle,
+            ],
+        },
+        {
+            name: pfn_device_wait_idle,
+            method_name: device_wait_idle,
+            try_method_name: try_device_wait_idle,
+            unchecked_method_name: device_wait_idle_unchecked,
+            ptr: DeviceWaitIdle,
+            signature: (
+            ) -> Result,
+            takes_handle: true,
+            provided_by: [
+                [VK_VERSION_1_0] => DeviceWaitIdle,
+            ],
+            hooks: [],
+        },
+        {
+            name: pfn_allocate_memory,
+            method_name: allocate_memory,
+            try_method_name: try_allocate_memory,
+            unchecked_method_name: allocate_memory_unchecked,
+            ptr: AllocateMemory,
+            signature: (
+                p_allocate_info: *const MemoryAllocateInfo,
+                p_allocator: *const AllocationCallbacks,
+                p_memory: *mut DeviceMemory,
+            ) -> Result,
+            takes_handle: true,
+            provided_by: [
+                [VK_VERSION_1_0] => AllocateMemory,
+            ],
+            hooks: [
+                p_allocate_info: structs,
+                p_allocator: structs,
+                p_memory: handles,
+            ],
+            checked: {
+                method_name: allocate_memory_checked,
+                args: (
+                    p_allocate_info: *const MemoryAllocateInfo,
+                    p_allocator: Option<&AllocationCallbacks>,
+                ),
+                output: p_memory: DeviceMemory,
+                result: success,
+            },
+        },
+        {
+            name: pfn_free_memory,
+            method_name: free_memory,
+            try_method_name: try_free_memory,
+            unchecked_method_name: free_memory_unchecked,
+            ptr: FreeMemory,
+            signature: (
+                memory: DeviceMemory,
+                p_allocator: *const AllocationCallbacks,
+            ),
+            takes_handle: true,
+            provided_by: [
+                [VK_VERSION_1_0] => FreeMemory,
+            ],
+            hooks: [
+                memory: handle,
+                p_allocator: structs,
+            ],
+        },
+        {
+            name: pfn_map_memory,
+            method_name: map_memory,
+            try_method_name: try_map_memory,
+            unchecked_method_name: map_memory_unchecked,
+            ptr: MapMemory,
+            signature: (
+                memory: DeviceMemory,
+                offset: DeviceSize,
+                size: DeviceSize,
+                flags: MemoryMapFlags,
+                pp_data: *mut *mut c_void,
+            ) -> Result,
+            takes_handle: true,
+            provided_by: [
+                [VK_VERSION_1_0] => MapMemory,
+            ],
+            hooks: [
+                memory: handle,
+                offset: value,
+                size: value,
+                flags: value,
+                pp_data: ptr,
+            ],
+        },
+        {
+            name: pfn_unmap_memory,
+            method_name: unmap_memory,
+            try_method_name: try_unmap_memory,
+            unchecked_method_name: unmap_memory_unchecked,
+            ptr: UnmapMemory,
+            signature: (
+                memory: DeviceMemory,
+            ),
+            takes_handle: true,
+            provided_by: [
+                [VK_VERSION_1_0] => UnmapMemory,
+            ],
+            hooks: [
+                memory: handle,
+            ],
+        },
+        {
+            name: pfn_flush_mapped_memory_ranges,
+            method_name: flush_mapped_memory_ranges,
+            try_method_name: try_flush_mapped_memory_ranges,
+            unchecked_method_name: flush_mapped_memory_ranges_unchecked,
+            ptr: FlushMappedMemoryRanges,
+            signature: (
+                memory_range_count: u32,
+                p_memory_ranges: *const MappedMemoryRange,
+            ) -> Result,
+            takes_handle: true,
+            provided_by: [
+                [VK_VERSION_1_0] => FlushMappedMemoryRanges,
+            ],
+            hooks: [
+                memory_range_count: value,
+                p_memory_ranges: structs[memory_range_count],
+            ],
+        },
+        {
+            name: pfn_invalidate_mapped_memory_ranges,
+            method_name: invalidate_mapped_memory_ranges,
+            try_method_name: try_invalidate_mapped_memory_ranges,
+            unchecked_method_name: invalidate_mapped_memory_ranges_unchecked,
+            ptr: InvalidateMappedMemoryRanges,
+            signature: (
+                memory_range_count: u32,
+                p_memory_ranges: *const MappedMemoryRange,
+            ) -> Result,
+            takes_handle: true,
+            provided_by: [
+                [VK_VERSION_1_0] => InvalidateMappedMemoryRanges,
+            ],
+            hooks: [
+                memory_range_count: value,
+                p_memory_ranges: structs[memory_range_count],
+            ],
+        },
+        {
+            name: pfn_get_device_memory_commitment,
+            method_name: get_device_memory_commitment,
+            try_method_name: try_get_device_memory_commitment,
+            unchecked_method_name: get_device_memory_commitment_unchecked,
+            ptr: GetDeviceMemoryCommitment,
+            signature: (
+                memory: DeviceMemory,
+                p_committed_memory_in_bytes: *mut DeviceSize,
+            ),
+            takes_handle: true,
+            provided_by: [
+                [VK_VERSION_1_0] => GetDeviceMemoryCommitment,
+            ],
+            hooks: [
+                memory: handle,
+                p_committed_memory_in_bytes: ptr,
+            ],
+            checked: {
+                method_name: get_device_memory_commitment_checked,
+                args: (
+                    memory: DeviceMemory,
+                ),
+                output: p_committed_memory_in_bytes: DeviceSize,
+                result: void,
+            },
+        },
+        {
+            name: pfn_get_buffer_memory_requirements,
+            method_name: get_buffer_memory_requirements,
+            try_method_name: try_get_buffer_memory_requirements,
+            unchecked_method_name: get_buffer_memory_requirements_unchecked,
+            ptr: GetBufferMemoryRequirements,
+            signature: (
+                buffer: Buffer,
+                p_memory_requirements: *mut MemoryRequirements,
+            ),
+            takes_handle: true,
+            provided_by: [
+                [VK_VERSION_1_0] => GetBufferMemoryRequirements,
+            ],
+            hooks: [
+                buffer: handle,
+                p_memory_requirements: ptr,
+            ],
+            checked: {
+                method_name: get_buffer_memory_requirements_checked,
+                args: (
+                    buffer: Buffer,
+                ),
+                output: p_memory_requirements: MemoryRequirements,
+                result: void,
+            },
+        },
+        {
+            name: pfn_bind_buffer_memory,
+            method_name: bind_buffer_memory,
+            try_method_name: try_bind_buffer_memory,
+            unchecked_method_name: bind_buffer_memory_unchecked,
+            ptr: BindBufferMemory,
+            signature: (
+                buffer: Buffer,
+                memory: DeviceMemory,
+                memory_offset: DeviceSize,
+            ) -> Result,
+            takes_handle: true,
+            provided_by: [
+                [VK_VERSION_1_0] => BindBufferMemory,
+            ],
+            hooks: [
+                buffer: handle,
+                memory: handle,
+                memory_offset: value,
+            ],
+        },
+        {
+            name: pfn_get_image_memory_requirements,
+            method_name: get_image_memory_requirements,
+            try_method_name: try_get_image_memory_requirements,
+            unchecked_method_name: get_image_memory_requirements_unchecked,
+            ptr: GetImageMemoryRequirements,
+            signature: (
+                image: Image,
+                p_memory_requirements: *mut MemoryRequirements,
+            ),
+            takes_handle: true,
+            provided_by: [
+                [VK_VERSION_1_0] => GetImageMemoryRequirements,
+            ],
+            hooks: [
+                image: handle,
+                p_memory_requirements: ptr,
+            ],
+            checked: {
+                method_name: get_image_memory_requirements_checked,
+                args: (
+                    image: Image,
+                ),
+                output: p_memory_requirements: MemoryRequirements,
+                result: void,
+            },
+        },
+        {
+            name: pfn_bind_image_memory,
+            method_name: bind_image_memory,
+            try_method_name: try_bind_image_memory,
+            unchecked_method_name: bind_image_memory_unchecked,
+            ptr: BindImageMemory,
+            signature: (
+                image: Image,
+                memory: DeviceMemory,
+                memory_offset: DeviceSize,
+            ) -> Result,
+            takes_handle: true,
+            provided_by: [
+                [VK_VERSION_1_0] => BindImageMemory,
+            ],
+            hooks: [
+                image: handle,
+                memory: handle,
+                memory_offset: value,
+            ],
+        },
+        {
+            name: pfn_get_image_sparse_memory_requirements,
+            method_name: get_image_sparse_memory_requirements,
+            try_method_name: try_get_image_sparse_memory_requirements,
+            unchecked_method_name: get_image_sparse_memory_requirements_unchecked,
+            ptr: GetImageSparseMemoryRequirements,
+            signature: (
+                image: Image,
+                p_sparse_memory_requirement_count: *mut u32,
+                p_sparse_memory_requirements: *mut SparseImageMemoryRequirements,
+            ),
+            takes_handle: true,
+            provided_by: [
+                [VK_VERSION_1_0] => GetImageSparseMemoryRequirements,
+            ],
+            hooks: [
+                image: handle,
+                p_sparse_memory_requirement_count: ptr,
+                p_sparse_memory_requirements: ptr[*p_sparse_memory_requirement_count],
+            ],
+            vec: {
+                method_name: get_image_sparse_memory_requirements_vec,
+                args: (
+                    image: Image,
+                ),
+                element: SparseImageMemoryRequirements,
+                result: void,
+            },
+        },
+        {
+            name: pfn_queue_bind_sparse,
+            method_name: queue_bind_sparse,
+            try_method_name: try_queue_bind_sparse,
+            unchecked_method_name: queue_bind_sparse_unchecked,
+            ptr: QueueBindSparse,
+            signature: (
+                queue: Queue,
+                bind_info_count: u32,
+                p_bind_info: *const BindSparseInfo,
+                fence: Fence,
+            ) -> Result,
+            takes_handle: false,
+            provided_by: [
+                [VK_VERSION_1_0] => QueueBindSparse,
+            ],
+            hooks: [
+                queue: handle,
+                bind_info_count: value,
+                p_bind_info: structs[bind_info_count],
+                fence: handle,
+            ],
+        },
+        {
+            name: pfn_create_fence,
+            method_name: create_fence,
+            try_method_name: try_create_fence,
+            unchecked_method_name: create_fence_unchecked,
+            ptr: CreateFence,
+            signature: (
+                p_create_info: *const FenceCreateInfo,
+                p_allocator: *const AllocationCallbacks,
+                p_fence: *mut Fence,
+            ) -> Result,
+            takes_handle: true,
+            provided_by: [
+                [VK_VERSION_1_0] => CreateFence,
+            ],
+            hooks: [
+                p_create_info: structs,
+                p_allocator: structs,
+                p_fence: handles,
+            ],
+            checked: {
+                method_name: create_fence_checked,
+                args: (
+                    p_create_info: *const FenceCreateInfo,
+                    p_allocator: Option<&AllocationCallbacks>,
+                ),
+                output: p_fence: Fence,
+                result: success,
+            },
+        },
+        {
+            name: pfn_destroy_fence,
+            method_name: destroy_fence,
+            try_method_name: try_destroy_fence,
+            unchecked_method_name: destroy_fence_unchecked,
+            ptr: DestroyFence,
+            signature: (
+                fence: Fence,
+                p_allocator: *const AllocationCallbacks,
+            ),
+            takes_handle: true,
+            provided_by: [
+                [VK_VERSION_1_0] => DestroyFence,
+            ],
+            hooks: [
+                fence: handle,
+                p_allocator: structs,
+            ],
+        },
+        {
+            name: pfn_reset_fences,
+            method_name: reset_fences,
+            try_method_name: try_reset_fences,
+            unchecked_method_name: reset_fences_unchecked,
+            ptr: ResetFences,
+            signature: (
+                fence_count: u32,
+                p_fences: *const Fence,
+            ) -> Result,
+            takes_handle: true,
+            provided_by: [
+                [VK_VERSION_1_0] => ResetFences,
+            ],
+            hooks: [
+                fence_count: value,
+                p_fences: handles[fence_count],
+            ],
+        },
+        {
+            name: pfn_get_fence_status,
+            method_name: get_fence_status,
+            try_method_name: try_get_fence_status,
+            unchecked_method_name: get_fence_status_unchecked,
+            ptr: GetFenceStatus,
+            signature: (
+                fence: Fence,
+            ) -> Result,
+            takes_handle: true,
+            provided_by: [
+                [VK_VERSION_1_0] => GetFenceStatus,
+            ],
+            hooks: [
+                fence: handle,
+            ],
+        },
+        {
+            name: pfn_wait_for_fences,
+            method_name: wait_for_fences,
+            try_method_name: try_wait_for_fences,
+            unchecked_method_name: wait_for_fences_unchecked,
+            ptr: WaitForFences,
+            signature: (
+                fence_count: u32,
+                p_fences: *const Fence,
+                wait_all: Bool32,
+                timeout: u64,
+            ) -> Result,
+            takes_handle: true,
+            provided_by: [
+                [VK_VERSION_1_0] => WaitForFences,
+            ],
+            hooks: [
+                fence_count: value,
+                p_fences: handles[fence_count],
+                wait_all: value,
+                timeout: value,
+            ],
+        },
+        {
+            name: pfn_create_semaphore,
+            method_name: create_semaphore,
+            try_method_name: try_create_semaphore,
+            unchecked_method_name: create_semaphore_unchecked,
+            ptr: CreateSemaphore,
+            signature: (
+                p_create_info: *const SemaphoreCreateInfo,
+                p_allocator: *const AllocationCallbacks,
+                p_semaphore: *mut Semaphore,
+            ) -> Result,
+            takes_handle: true,
+            provided_by: [
+                [VK_VERSION_1_0] => CreateSemaphore,
+            ],
+            hooks: [
+                p_create_info: structs,
+                p_allocator: structs,
+                p_semaphore: handles,
+            ],
+            checked: {
+                method_name: create_semaphore_checked,
+                args: (
+                    p_create_info: *const SemaphoreCreateInfo,
+                    p_allocator: Option<&AllocationCallbacks>,
+                ),
+                output: p_semaphore: Semaphore,
+                result: success,
+            },
+        },
+        {
+            name: pfn_destroy_semaphore,
+            method_name: destroy_semaphore,
+            try_method_name: try_destroy_semaphore,
+            unchecked_method_name: destroy_semaphore_unchecked,
+            ptr: DestroySemaphore,
+            signature: (
+                semaphore: Semaphore,
+                p_allocator: *const AllocationCallbacks,
+            ),
+            takes_handle: true,
+            provided_by: [
+                [VK_VERSION_1_0] => DestroySemaphore,
+            ],
+            hooks: [
+                semaphore: handle,
+                p_allocator: structs,
+            ],
+        },
+        {
+            name: pfn_create_event,
+            method_name: create_event,
+            try_method_name: try_create_event,
+            unchecked_method_name: create_event_unchecked,
+            ptr: CreateEvent,
+            signature: (
+                p_create_info: *const EventCreateInfo,
+                p_allocator: *const AllocationCallbacks,
+                p_event: *mut Event,
+            ) -> Result,
+            takes_handle: true,
+            provided_by: [
+                [VK_VERSION_1_0] => CreateEvent,
+            ],
+            hooks: [
+                p_create_info: structs,
+                p_allocator: structs,
+                p_event: handles,
+            ],
+            checked: {
+                method_name: create_event_checked,
+                args: (
+                    p_create_info: *const EventCreateInfo,
+                    p_allocator: Option<&AllocationCallbacks>,
+                ),
+                output: p_event: Event,
+                result: success,
+            },
+        },
+        {
+            name: pfn_destroy_event,
+            method_name: destroy_event,
+            try_method_name: try_destroy_event,
+            unchecked_method_name: destroy_event_unchecked,
+            ptr: DestroyEvent,
+            signature: (
+                event: Event,
+                p_allocator: *const AllocationCallbacks,
+            ),
+            takes_handle: true,
+            provided_by: [
+                [VK_VERSION_1_0] => DestroyEvent,
+            ],
+            hooks: [
+                event: handle,
+                p_allocator: structs,
+            ],
+        },
+        {
+            name: pfn_get_event_status,
+            method_name: get_event_status,
+            try_method_name: try_get_event_status,
+            unchecked_method_name: get_event_status_unchecked,
+            ptr: GetEventStatus,
+            signature: (
+                event: Event,
+            ) -> Result,
+            takes_handle: true,
+            provided_by: [
+                [VK_VERSION_1_0] => GetEventStatus,
+            ],
+            hooks: [
+                event: handle,
+            ],
+        },
+        {
+            name: pfn_set_event,
+            method_name: set_event,
+            try_method_name: try_set_event,
+            unchecked_method_name: set_event_unchecked,
+            ptr: SetEvent,
+            signature: (
+                event: Event,
+            ) -> Result,
+            takes_handle: true,
+            provided_by: [
+                [VK_VERSION_1_0] => SetEvent,
+            ],
+            hooks: [
+                event: handle,
+            ],
+        },
+        {
+            name: pfn_reset_event,
+            method_name: reset_event,
+            try_method_name: try_reset_event,
+            unchecked_method_name: reset_event_unchecked,
+            ptr: ResetEvent,
+            signature: (
+                event: Event,
+            ) -> Result,
+            takes_handle: true,
+            provided_by: [
+                [VK_VERSION_1_0] => ResetEvent,
+            ],
+            hooks: [
+                event: handle,
+            ],
+        },
+        {
+            name: pfn_create_query_pool,
+            method_name: create_query_pool,
+            try_method_name: try_create_query_pool,
+            unchecked_method_name: create_query_pool_unchecked,
+            ptr: CreateQueryPool,
+            signature: (
+                p_create_info: *const QueryPoolCreateInfo,
+                p_allocator: *const AllocationCallbacks,
+                p_query_pool: *mut QueryPool,
+            ) -> Result,
+            takes_handle: true,
+            provided_by: [
+                [VK_VERSION_1_0] => CreateQueryPool,
+            ],
+            hooks: [
+                p_create_info: structs,
+                p_allocator: structs,
+                p_query_pool: handles,
+            ],
+            checked: {
+                method_name: create_query_pool_checked,
+                args: (
+                    p_create_info: *const QueryPoolCreateInfo,
+                    p_allocator: Option<&AllocationCallbacks>,
+                ),
+                output: p_query_pool: QueryPool,
+                result: success,
+            },
+        },
+        {
+            name: pfn_destroy_query_pool,
+            method_name: destroy_query_pool,
+            try_method_name: try_destroy_query_pool,
+            unchecked_method_name: destroy_query_pool_unchecked,
+            ptr: DestroyQueryPool,
+            signature: (
+                query_pool: QueryPool,
+                p_allocator: *const AllocationCallbacks,
+            ),
+            takes_handle: true,
+            provided_by: [
+                [VK_VERSION_1_0] => DestroyQueryPool,
+            ],
+            hooks: [
+                query_pool: handle,
+                p_allocator: structs,
+            ],
+        },
+        {
+            name: pfn_get_query_pool_results,
+            method_name: get_query_pool_results,
+            try_method_name: try_get_query_pool_results,
+            unchecked_method_name: get_query_pool_results_unchecked,
+            ptr: GetQueryPoolResults,
+            signature: (
+                query_pool: QueryPool,
+                first_query: u32,
+                query_count: u32,
+                data_size: usize,
+                p_data: *mut c_void,
+                stride: DeviceSize,
+                flags: QueryResultFlags,
+            ) -> Result,
+            takes_handle: true,
+            provided_by: [
+                [VK_VERSION_1_0] => GetQueryPoolResults,
+            ],
+            hooks: [
+                query_pool: handle,
+                first_query: value,
+                query_count: value,
+                data_size: value,
+                p_data: bytes[data_size],
+                stride: value,
+                flags: value,
+            ],
+        },
+        {
+            name: pfn_reset_query_pool,
+            method_name: reset_query_pool,
+            try_method_name: try_reset_query_pool,
+            unchecked_method_name: reset_query_pool_unchecked,
+            ptr: ResetQueryPool,
+            aliases: [ResetQueryPoolEXT],
+            signature: (
+                query_pool: QueryPool,
+                first_query: u32,
+                query_count: u32,
+            ),
+            takes_handle: true,
+            provided_by: [
+                [VK_VERSION_1_2] => ResetQueryPool,
+                [VK_EXT_host_query_reset] => ResetQueryPoolEXT,
+            ],
+            hooks: [
+                query_pool: handle,
+                first_query: value,
+                query_count: value,
+            ],
+        },
+        {
+            name: pfn_create_buffer,
+            method_name: create_buffer,
+            try_method_name: try_create_buffer,
+            unchecked_method_name: create_buffer_unchecked,
+            ptr: CreateBuffer,
+            signature: (
+                p_create_info: *const BufferCreateInfo,
+                p_allocator: *const AllocationCallbacks,
+                p_buffer: *mut Buffer,
+            ) -> Result,
+            takes_handle: true,
+            provided_by: [
+                [VK_VERSION_1_0] => CreateBuffer,
+            ],
+            hooks: [
+                p_create_info: structs,
+                p_allocator: structs,
+                p_buffer: handles,
+            ],
+            checked: {
+                method_name: create_buffer_checked,
+                args: (
+                    p_create_info: *const BufferCreateInfo,
+                    p_allocator: Option<&AllocationCallbacks>,
+                ),
+                output: p_buffer: Buffer,
+                result: success,
+            },
+        },
+        {
+            name: pfn_destroy_buffer,
+            method_name: destroy_buffer,
+            try_method_name: try_destroy_buffer,
+            unchecked_method_name: destroy_buffer_unchecked,
+            ptr: DestroyBuffer,
+            signature: (
+                buffer: Buffer,
+                p_allocator: *const AllocationCallbacks,
+            ),
+            takes_handle: true,
+            provided_by: [
+                [VK_VERSION_1_0] => DestroyBuffer,
+            ],
+            hooks: [
+                buffer: handle,
+                p_allocator: structs,
+            ],
+        },
+        {
+            name: pfn_create_buffer_view,
+            method_name: create_buffer_view,
+            try_method_name: try_create_buffer_view,
+            unchecked_method_name: create_buffer_view_unchecked,
+            ptr: CreateBufferView,
+            signature: (
+                p_create_info: *const BufferViewCreateInfo,
+                p_allocator: *const AllocationCallbacks,
+                p_view: *mut BufferView,
+            ) -> Result,
+            takes_handle: true,
+            provided_by: [
+                [VK_VERSION_1_0] => CreateBufferView,
+            ],
+            hooks: [
+                p_create_info: structs,
+                p_allocator: structs,
+                p_view: handles,
+            ],
+            checked: {
+                method_name: create_buffer_view_checked,
+                args: (
+                    p_create_info: *const BufferViewCreateInfo,
+                    p_allocator: Option<&AllocationCallbacks>,
+                ),
+                output: p_view: BufferView,
+                result: success,
+            },
+        },
+        {
+            name: pfn_destroy_buffer_view,
+            method_name: destroy_buffer_view,
+            try_method_name: try_destroy_buffer_view,
+            unchecked_method_name: destroy_buffer_view_unchecked,
+            ptr: DestroyBufferView,
+            signature: (
+                buffer_view: BufferView,
+                p_allocator: *const AllocationCallbacks,
+            ),
+            takes_handle: true,
+            provided_by: [
+                [VK_VERSION_1_0] => DestroyBufferView,
+            ],
+            hooks: [
+                buffer_view: handle,
+                p_allocator: structs,
+            ],
+        },
+        {
+            name: pfn_create_image,
+            method_name: create_image,
+            try_method_name: try_create_image,
+            unchecked_method_name: create_image_unchecked,
+            ptr: CreateImage,
+            signature: (
+                p_create_info: *const ImageCreateInfo,
+                p_allocator: *const AllocationCallbacks,
+                p_image: *mut Image,
+            ) -> Result,
+            takes_handle: true,
+            provided_by: [
+                [VK_VERSION_1_0] => CreateImage,
+            ],
+            hooks: [
+                p_create_info: structs,
+                p_allocator: structs,
+                p_image: handles,
+            ],
+            checked: {
+                method_name: create_image_checked,
+                args: (
+                    p_create_info: *const ImageCreateInfo,
+                    p_allocator: Option<&AllocationCallbacks>,
+                ),
+                output: p_image: Image,
+                result: success,
+            },
+        },
+        {
+            name: pfn_destroy_image,
+            method_name: destroy_image,
+            try_method_name: try_destroy_image,
+            unchecked_method_name: destroy_image_unchecked,
+            ptr: DestroyImage,
+            signature: (
+                image: Image,
+                p_allocator: *const AllocationCallbacks,
+            ),
+            takes_handle: true,
+            provided_by: [
+                [VK_VERSION_1_0] => DestroyImage,
+            ],
+            hooks: [
+                image: handle,
+                p_allocator: structs,
+            ],
+        },
+        {
+            name: pfn_get_image_subresource_layout,
+            method_name: get_image_subresource_layout,
+            try_method_name: try_get_image_subresource_layout,
+            unchecked_method_name: get_image_subresource_layout_unchecked,
+            ptr: GetImageSubresourceLayout,
+            signature: (
+                image: Image,
+                p_subresource: *const ImageSubresource,
+                p_layout: *mut SubresourceLayout,
+            ),
+            takes_handle: true,
+            provided_by: [
+                [VK_VERSION_1_0] => GetImageSubresourceLayout,
+            ],
+            hooks: [
+                image: handle,
+                p_subresource: ptr,
+                p_layout: ptr,
+            ],
+            checked: {
+                method_name: get_image_subresource_layout_checked,
+                args: (
+                    image: Image,
+                    p_subresource: *const ImageSubresource,
+                ),
+                output: p_layout: SubresourceLayout,
+                result: void,
+            },
+        },
+        {
+            name: pfn_create_image_view,
+            method_name: create_image_view,
+            try_method_name: try_create_image_view,
+            unchecked_method_name: create_image_view_unchecked,
+            ptr: CreateImageView,
+            signature: (
+                p_create_info: *const ImageViewCreateInfo,
+                p_allocator: *const AllocationCallbacks,
+                p_view: *mut ImageView,
+            ) -> Result,
+            takes_handle: true,
+            provided_by: [
+                [VK_VERSION_1_0] => CreateImageView,
+            ],
+            hooks: [
+                p_create_info: structs,
+                p_allocator: structs,
+                p_view: handles,
+            ],
+            checked: {
+                method_name: create_image_view_checked,
+                args: (
+                    p_create_info: *const ImageViewCreateInfo,
+                    p_allocator: Option<&AllocationCallbacks>,
+                ),
+                output: p_view: ImageView,
+                result: success,
+            },
+        },
+        {
+            name: pfn_destroy_image_view,
+            method_name: destroy_image_view,
+            try_method_name: try_destroy_image_view,
+            unchecked_method_name: destroy_image_view_unchecked,
+            ptr: DestroyImageView,
+            signature: (
+                image_view: ImageView,
+                p_allocator: *const AllocationCallbacks,
+            ),
+            takes_handle: true,
+            provided_by: [
+                [VK_VERSION_1_0] => DestroyImageView,
+            ],
+            hooks: [
+                image_view: handle,
+                p_allocator: structs,
+            ],
+        },
+        {
+            name: pfn_create_shader_module,
+            method_name: create_shader_module,
+            try_method_name: try_create_shader_module,
+            unchecked_method_name: create_shader_module_unchecked,
+            ptr: CreateShaderModule,
+            signature: (
+                p_create_info: *const ShaderModuleCreateInfo,
+                p_allocator: *const AllocationCallbacks,
+                p_shader_module: *mut ShaderModule,
+            ) -> Result,
+            takes_handle: true,
+            provided_by: [
+                [VK_VERSION_1_0] => CreateShaderModule,
+            ],
+            hooks: [
+                p_create_info: structs,
+                p_allocator: structs,
+                p_shader_module: handles,
+            ],
+            checked: {
+                method_name: create_shader_module_checked,
+                args: (
+                    p_create_info: *const ShaderModuleCreateInfo,
+                    p_allocator: Option<&AllocationCallbacks>,
+                ),
+                output: p_shader_module: ShaderModule,
+                result: success,
+            },
+        },
+        {
+            name: pfn_destroy_shader_module,
+            method_name: destroy_shader_module,
+            try_method_name: try_destroy_shader_module,
+            unchecked_method_name: destroy_shader_module_unchecked,
+            ptr: DestroyShaderModule,
+            signature: (
+                shader_module: ShaderModule,
+                p_allocator: *const AllocationCallbacks,
+            ),
+            takes_handle: true,
+            provided_by: [
+                [VK_VERSION_1_0] => DestroyShaderModule,
+            ],
+            hooks: [
+                shader_module: handle,
+                p_allocator: structs,
+            ],
+        },
+        {
+            name: pfn_create_pipeline_cache,
+            method_name: create_pipeline_cache,
+            try_method_name: try_create_pipeline_cache,
+            unchecked_method_name: create_pipeline_cache_unchecked,
+            ptr: CreatePipelineCache,
+            signature: (
+                p_create_info: *const PipelineCacheCreateInfo,
+                p_allocator: *const AllocationCallbacks,
+                p_pipeline_cache: *mut PipelineCache,
+            ) -> Result,
+            takes_handle: true,
+            provided_by: [
+                [VK_VERSION_1_0] => CreatePipelineCache,
+            ],
+            hooks: [
+                p_create_info: structs,
+                p_allocator: structs,
+                p_pipeline_cache: handles,
+            ],
+            checked: {
+                method_name: create_pipeline_cache_checked,
+                args: (
+                    p_create_info: *const PipelineCacheCreateInfo,
+                    p_allocator: Option<&AllocationCallbacks>,
+                ),
+                output: p_pipeline_cache: PipelineCache,
+                result: success,
+            },
+        },
+        {
+            name: pfn_destroy_pipeline_cache,
+            method_name: destroy_pipeline_cache,
+            try_method_name: try_destroy_pipeline_cache,
+            unchecked_method_name: destroy_pipeline_cache_unchecked,
+            ptr: DestroyPipelineCache,
+            signature: (
+                pipeline_cache: PipelineCache,
+                p_allocator: *const AllocationCallbacks,
+            ),
+            takes_handle: true,
+            provided_by: [
+                [VK_VERSION_1_0] => DestroyPipelineCache,
+            ],
+            hooks: [
+                pipeline_cache: handle,
+                p_allocator: structs,
+            ],
+        },
+        {
+            name: pfn_get_pipeline_cache_data,
+            method_name: get_pipeline_cache_data,
+            try_method_name: try_get_pipeline_cache_data,
+            unchecked_method_name: get_pipeline_cache_data_unchecked,
+            ptr: GetPipelineCacheData,
+            signature: (
+                pipeline_cache: PipelineCache,
+                p_data_size: *mut usize,
+                p_data: *mut c_void,
+            ) -> Result,
+            takes_handle: true,
+            provided_by: [
+                [VK_VERSION_1_0] => GetPipelineCacheData,
+            ],
+            hooks: [
+                pipeline_cache: handle,
+                p_data_size: ptr,
+                p_data: opaque,
+            ],
+        },
+        {
+            name: pfn_merge_pipeline_caches,
+            method_name: merge_pipeline_caches,
+            try_method_name: try_merge_pipeline_caches,
+            unchecked_method_name: merge_pipeline_caches_unchecked,
+            ptr: MergePipelineCaches,
+            signature: (
+                dst_cache: PipelineCache,
+                src_cache_count: u32,
+                p_src_caches: *const PipelineCache,
+            ) -> Result,
+            takes_handle: true,
+            provided_by: [
+                [VK_VERSION_1_0] => MergePipelineCaches,
+            ],
+            hooks: [
+                dst_cache: handle,
+                src_cache_count: value,
+                p_src_caches: handles[src_cache_count],
+            ],
+        },
+        {
+            name: pfn_create_graphics_pipelines,
+            method_name: create_graphics_pipelines,
+            try_method_name: try_create_graphics_pipelines,
+            unchecked_method_name: create_graphics_pipelines_unchecked,
+            ptr: CreateGraphicsPipelines,
+            signature: (
+                pipeline_cache: PipelineCache,
+                create_info_count: u32,
+                p_create_infos: *const GraphicsPipelineCreateInfo,
+                p_allocator: *const AllocationCallbacks,
+                p_pipelines: *mut Pipeline,
+            ) -> Result,
+            takes_handle: true,
+            provided_by: [
+                [VK_VERSION_1_0] => CreateGraphicsPipelines,
+            ],
+            hooks: [
+                pipeline_cache: handle,
+                create_info_count: value,
+                p_create_infos: structs[create_info_count],
+                p_allocator: structs,
+                p_pipelines: handles[create_info_count],
+            ],
+            checked: {
+                method_name: create_graphics_pipelines_checked,
+                args: (
+                    pipeline_cache: PipelineCache,
+                    create_info_count: u32,
+                    p_create_infos: *const GraphicsPipelineCreateInfo,
+                    p_allocator: Option<&AllocationCallbacks>,
+                ),
+                output: p_pipelines: Vec<Pipeline>,
+                len: create_info_count,
+                result: status,
+            },
+        },
+        {
+            name: pfn_create_compute_pipelines,
+            method_name: create_compute_pipelines,
+            try_method_name: try_create_compute_pipelines,
+            unchecked_method_name: create_compute_pipelines_unchecked,
+            ptr: CreateComputePipelines,
+            signature: (
+                pipeline_cache: PipelineCache,
+                create_info_count: u32,
+                p_create_infos: *const ComputePipelineCreateInfo,
+                p_allocator: *const AllocationCallbacks,
+                p_pipelines: *mut Pipeline,
+            ) -> Result,
+            takes_handle: true,
+            provided_by: [
+                [VK_VERSION_1_0] => CreateComputePipelines,
+            ],
+            hooks: [
+                pipeline_cache: handle,
+                create_info_count: value,
+                p_create_infos: structs[create_info_count],
+                p_allocator: structs,
+                p_pipelines: handles[create_info_count],
+            ],
+            checked: {
+                method_name: create_compute_pipelines_checked,
+                args: (
+                    pipeline_cache: PipelineCache,
+                    create_info_count: u32,
+                    p_create_infos: *const ComputePipelineCreateInfo,
+                    p_allocator: Option<&AllocationCallbacks>,
+                ),
+                output: p_pipelines: Vec<Pipeline>,
+                len: create_info_count,
+                result: status,
+            },
+        },
+        {
+            name: pfn_get_device_subpass_shading_max_workgroup_size_huawei,
+            method_name: get_device_subpass_shading_max_workgroup_size_huawei,
+            try_method_name: try_get_device_subpass_shading_max_workgroup_size_huawei,
+            unchecked_method_name: get_device_subpass_shading_max_workgroup_size_huawei_unchecked,
+            ptr: GetDeviceSubpassShadingMaxWorkgroupSizeHUAWEI,
+            signature: (
+                renderpass: RenderPass,
+                p_max_workgroup_size: *mut Extent2D,
+            ) -> Result,
+            takes_handle: true,
+            provided_by: [
+                [VK_HUAWEI_subpass_shading] => GetDeviceSubpassShadingMaxWorkgroupSizeHUAWEI,
+            ],
+            hooks: [
+                renderpass: handle,
+                p_max_workgroup_size: ptr,
+            ],
+            checked: {
+                method_name: get_device_subpass_shading_max_workgroup_size_huawei_checked,
+                args: (
+                    renderpass: RenderPass,
+                ),
+                output: p_max_workgroup_size: Extent2D,
+                result: status,
+            },
+        },
+        {
+            name: pfn_destroy_pipeline,
+            method_name: destroy_pipeline,
+            try_method_name: try_destroy_pipeline,
+            unchecked_method_name: destroy_pipeline_unchecked,
+            ptr: DestroyPipeline,
+            signature: (
+                pipeline: Pipeline,
+                p_allocator: *const AllocationCallbacks,
+            ),
+            takes_handle: true,
+            provided_by: [
+                [VK_VERSION_1_0] => DestroyPipeline,
+            ],
+            hooks: [
+                pipeline: handle,
+                p_allocator: structs,
+            ],
+        },
+        {
+            name: pfn_create_pipeline_layout,
+            method_name: create_pipeline_layout,
+            try_method_name: try_create_pipeline_layout,
+            unchecked_method_name: create_pipeline_layout_unchecked,
+            ptr: CreatePipelineLayout,
+            signature: (
+                p_create_info: *const PipelineLayoutCreateInfo,
+                p_allocator: *const AllocationCallbacks,
+                p_pipeline_layout: *mut PipelineLayout,
+            ) -> Result,
+            takes_handle: true,
+            provided_by: [
+                [VK_VERSION_1_0] => CreatePipelineLayout,
+            ],
+            hooks: [
+                p_create_info: structs,
+                p_allocator: structs,
+                p_pipeline_layout: handles,
+            ],
+            checked: {
+                method_name: create_pipeline_layout_checked,
+                args: (
+                    p_create_info: *const PipelineLayoutCreateInfo,
+                    p_allocator: Option<&AllocationCallbacks>,
+                ),
+                output: p_pipeline_layout: PipelineLayout,
+                result: success,
+            },
+        },
+        {
+            name: pfn_destroy_pipeline_layout,
+            method_name: destroy_pipeline_layout,
+            try_method_name: try_destroy_pipeline_layout,
+            unchecked_method_name: destroy_pipeline_layout_unchecked,
+            ptr: DestroyPipelineLayout,
+            signature: (
+                pipeline_layout: PipelineLayout,
+                p_allocator: *const AllocationCallbacks,
+            ),
+            takes_handle: true,
+            provided_by: [
+                [VK_VERSION_1_0] => DestroyPipelineLayout,
+            ],
+            hooks: [
+                pipeline_layout: handle,
+                p_allocator: structs,
+            ],
+        },
+        {
+            name: pfn_create_sampler,
+            method_name: create_sampler,
+            try_method_name: try_create_sampler,
+            unchecked_method_name: create_sampler_unchecked,
+            ptr: CreateSampler,
+            signature: (
+                p_create_info: *const SamplerCreateInfo,
+                p_allocator: *const AllocationCallbacks,
+                p_sampler: *mut Sampler,
+            ) -> Result,
+            takes_handle: true,
+            provided_by: [
+                [VK_VERSION_1_0] => CreateSampler,
+            ],
+            hooks: [
+                p_create_info: structs,
+                p_allocator: structs,
+                p_sampler: handles,
+            ],
+            checked: {
+                method_name: create_sampler_checked,
+                args: (
+                    p_create_info: *const SamplerCreateInfo,
+                    p_allocator: Option<&AllocationCallbacks>,
+                ),
+                output: p_sampler: Sampler,
+                result: success,
+            },
+        },
+        {
+            name: pfn_destroy_sampler,
+            method_name: destroy_sampler,
+            try_method_name: try_destroy_sampler,
+            unchecked_method_name: destroy_sampler_unchecked,
+            ptr: DestroySampler,
+            signature: (
+                sampler: Sampler,
+                p_allocator: *const AllocationCallbacks,
+            ),
+            takes_handle: true,
+            provided_by: [
+                [VK_VERSION_1_0] => DestroySampler,
+            ],
+            hooks: [
+                sampler: handle,
+                p_allocator: structs,
+            ],
+        },
+        {
+            name: pfn_create_descriptor_set_layout,
+            method_name: create_descriptor_set_layout,
+            try_method_name: try_create_descriptor_set_layout,
+            unchecked_method_name: create_descriptor_set_layout_unchecked,
+            ptr: CreateDescriptorSetLayout,
+            signature: (
+                p_create_info: *const DescriptorSetLayoutCreateInfo,
+                p_allocator: *const AllocationCallbacks,
+                p_set_layout: *mut DescriptorSetLayout,
+            ) -> Result,
+            takes_handle: true,
+            provided_by: [
+                [VK_VERSION_1_0] => CreateDescriptorSetLayout,
+            ],
+            hooks: [
+                p_create_info: structs,
+                p_allocator: structs,
+                p_set_layout: handles,
+            ],
+            checked: {
+                method_name: create_descriptor_set_layout_checked,
+                args: (
+                    p_create_info: *const DescriptorSetLayoutCreateInfo,
+                    p_allocator: Option<&AllocationCallbacks>,
+                ),
+                output: p_set_layout: DescriptorSetLayout,
+                result: success,
+            },
+        },
+        {
+            name: pfn_destroy_descriptor_set_layout,
+            method_name: destroy_descriptor_set_layout,
+            try_method_name: try_destroy_descriptor_set_layout,
+            unchecked_method_name: destroy_descriptor_set_layout_unchecked,
+            ptr: DestroyDescriptorSetLayout,
+            signature: (
+                descriptor_set_layout: DescriptorSetLayout,
+                p_allocator: *const AllocationCallbacks,
+            ),
+            takes_handle: true,
+            provided_by: [
+                [VK_VERSION_1_0] => DestroyDescriptorSetLayout,
+            ],
+            hooks: [
+                descriptor_set_layout: handle,
+                p_allocator: structs,
+            ],
+        },
+        {
+            name: pfn_create_descriptor_pool,
+            method_name: create_descriptor_pool,
+            try_method_name: try_create_descriptor_pool,
+            unchecked_method_name: create_descriptor_pool_unchecked,
+            ptr: CreateDescriptorPool,
+            signature: (
+                p_create_info: *const DescriptorPoolCreateInfo,
+                p_allocator: *const AllocationCallbacks,
+                p_descriptor_pool: *mut DescriptorPool,
+            ) -> Result,
+            takes_handle: true,
+            provided_by: [
+                [VK_VERSION_1_0] => CreateDescriptorPool,
+            ],
+            hooks: [
+                p_create_info: structs,
+                p_allocator: structs,
+                p_descriptor_pool: handles,
+            ],
+            checked: {
+                method_name: create_descriptor_pool_checked,
+                args: (
+                    p_create_info: *const DescriptorPoolCreateInfo,
+                    p_allocator: Option<&AllocationCallbacks>,
+                ),
+                output: p_descriptor_pool: DescriptorPool,
+                result: success,
+            },
+        },
+        {
+            name: pfn_destroy_descriptor_pool,
+            method_name: destroy_descriptor_pool,
+            try_method_name: try_destroy_descriptor_pool,
+            unchecked_method_name: destroy_descriptor_pool_unchecked,
+            ptr: DestroyDescriptorPool,
+            signature: (
+                descriptor_pool: DescriptorPool,
+                p_allocator: *const AllocationCallbacks,
+            ),
+            takes_handle: true,
+            provided_by: [
+                [VK_VERSION_1_0] => DestroyDescriptorPool,
+            ],
+            hooks: [
+                descriptor_pool: handle,
+                p_allocator: structs,
+            ],
+        },
+        {
+            name: pfn_reset_descriptor_pool,
+            method_name: reset_descriptor_pool,
+            try_method_name: try_reset_descriptor_pool,
+            unchecked_method_name: reset_descriptor_pool_unchecked,
+            ptr: ResetDescriptorPool,
+            signature: (
+                descriptor_pool: DescriptorPool,
+                flags: DescriptorPoolResetFlags,
+            ) -> Result,
+            takes_handle: true,
+            provided_by: [
+                [VK_VERSION_1_0] => ResetDescriptorPool,
+            ],
+            hooks: [
+                descriptor_pool: handle,
+                flags: value,
+            ],
+        },
+        {
+            name: pfn_allocate_descriptor_sets,
+            method_name: allocate_descriptor_sets,
+            try_method_name: try_allocate_descriptor_sets,
+            unchecked_method_name: allocate_descriptor_sets_unchecked,
+            ptr: AllocateDescriptorSets,
+            signature: (
+                p_allocate_info: *const DescriptorSetAllocateInfo,
+                p_descriptor_sets: *mut DescriptorSet,
+            ) -> Result,
+            takes_handle: true,
+            provided_by: [
+                [VK_VERSION_1_0] => AllocateDescriptorSets,
+            ],
+            hooks: [
+                p_allocate_info: structs,
+                p_descriptor_sets: handles[(*p_allocate_info).descriptor_set_count],
+            ],
+            checked: {
+                method_name: allocate_descriptor_sets_checked,
+                args: (
+                    p_allocate_info: *const DescriptorSetAllocateInfo,
+                ),
+                output: p_descriptor_sets: Vec<DescriptorSet>,
+                len: (*p_allocate_info).descriptor_set_count,
+                result: success,
+            },
+        },
+        {
+            name: pfn_free_descriptor_sets,
+            method_name: free_descriptor_sets,
+            try_method_name: try_free_descriptor_sets,
+            unchecked_method_name: free_descriptor_sets_unchecked,
+            ptr: FreeDescriptorSets,
+            signature: (
+                descriptor_pool: DescriptorPool,
+                descriptor_set_count: u32,
+                p_descriptor_sets: *const DescriptorSet,
+            ) -> Result,
+            takes_handle: true,
+            provided_by: [
+                [VK_VERSION_1_0] => FreeDescriptorSets,
+            ],
+            hooks: [
+                descriptor_pool: handle,
+                descriptor_set_count: value,
+                p_descriptor_sets: handles[descriptor_set_count],
+            ],
+        },
+        {
+            name: pfn_update_descriptor_sets,
+            method_name: update_descriptor_sets,
+            try_method_name: try_update_descriptor_sets,
+            unchecked_method_name: update_descriptor_sets_unchecked,
+            ptr: UpdateDescriptorSets,
+            signature: (
+                descriptor_write_count: u32,
+                p_descriptor_writes: *const WriteDescriptorSet,
+                descriptor_copy_count: u32,
+                p_descriptor_copies: *const CopyDescriptorSet,
+            ),
+            takes_handle: true,
+            provided_by: [
+                [VK_VERSION_1_0] => UpdateDescriptorSets,
+            ],
+            hooks: [
+                descriptor_write_count: value,
+                p_descriptor_writes: structs[descriptor_write_count],
+                descriptor_copy_count: value,
+                p_descriptor_copies: structs[descriptor_copy_count],
+            ],
+        },
+        {
+            name: pfn_create_framebuffer,
+            method_name: create_framebuffer,
+            try_method_name: try_create_framebuffer,
+            unchecked_method_name: create_framebuffer_unchecked,
+            ptr: CreateFramebuffer,
+            signature: (
+                p_create_info: *const FramebufferCreateInfo,
+                p_allocator: *const AllocationCallbacks,
+                p_framebuffer: *mut Framebuffer,
+            ) -> Result,
+            takes_handle: true,
+            provided_by: [
+                [VK_VERSION_1_0] => CreateFramebuffer,
+            ],
+            hooks: [
+                p_create_info: structs,
+                p_allocator: structs,
+                p_framebuffer: handles,
+            ],
+            checked: {
+                method_name: create_framebuffer_checked,
+                args: (
+                    p_create_info: *const FramebufferCreateInfo,
+                    p_allocator: Option<&AllocationCallbacks>,
+                ),
+                output: p_framebuffer: Framebuffer,
+                result: success,
+            },
+        },
+        {
+            name: pfn_destroy_framebuffer,
+            method_name: destroy_framebuffer,
+            try_method_name: try_destroy_framebuffer,
+            unchecked_method_name: destroy_framebuffer_unchecked,
+            ptr: DestroyFramebuffer,
+            signature: (
+                framebuffer: Framebuffer,
+                p_allocator: *const AllocationCallbacks,
+            ),
+            takes_handle: true,
+            provided_by: [
+                [VK_VERSION_1_0] => DestroyFramebuffer,
+            ],
+            hooks: [
+                framebuffer: handle,
+                p_allocator: structs,
+            ],
+        },
+        {
+            name: pfn_create_render_pass,
+            method_name: create_render_pass,
+            try_method_name: try_create_render_pass,
+            unchecked_method_name: create_render_pass_unchecked,
+            ptr: CreateRenderPass,
+            signature: (
+                p_create_info: *const RenderPassCreateInfo,
+                p_allocator: *const AllocationCallbacks,
+                p_render_pass: *mut RenderPass,
+            ) -> Result,
+            takes_handle: true,
+            provided_by: [
+                [VK_VERSION_1_0] => CreateRenderPass,
+            ],
+            hooks: [
+                p_create_info: structs,
+                p_allocator: structs,
+                p_render_pass: handles,
+            ],
+            checked: {
+                method_name: create_render_pass_checked,
+                args: (
+                    p_create_info: *const RenderPassCreateInfo,
+                    p_allocator: Option<&AllocationCallbacks>,
+                ),
+                output: p_render_pass: RenderPass,
+                result: success,
+            },
+        },
+        {
+            name: pfn_destroy_render_pass,
+            method_name: destroy_render_pass,
+            try_method_name: try_destroy_render_pass,
+            unchecked_method_name: destroy_render_pass_unchecked,
+            ptr: DestroyRenderPass,
+            signature: (
+                render_pass: RenderPass,
+                p_allocator: *const AllocationCallbacks,
+            ),
+            takes_handle: true,
+            provided_by: [
+                [VK_VERSION_1_0] => DestroyRenderPass,
+            ],
+            hooks: [
+                render_pass: handle,
+                p_allocator: structs,
+            ],
+        },
+        {
+            name: pfn_get_render_area_granularity,
+            method_name: get_render_area_granularity,
+            try_method_name: try_get_render_area_granularity,
+            unchecked_method_name: get_render_area_granularity_unchecked,
+            ptr: GetRenderAreaGranularity,
+            signature: (
+                render_pass: RenderPass,
+                p_granularity: *mut Extent2D,
+            ),
+            takes_handle: true,
+            provided_by: [
+                [VK_VERSION_1_0] => GetRenderAreaGranularity,
+            ],
+            hooks: [
+                render_pass: handle,
+                p_granularity: ptr,
+            ],
+            checked: {
+                method_name: get_render_area_granularity_checked,
+                args: (
+                    render_pass: RenderPass,
+                ),
+                output: p_granularity: Extent2D,
+                result: void,
+            },
+        },
+        {
+            name: pfn_create_command_pool,
+            method_name: create_command_pool,
+            try_method_name: try_create_command_pool,
+            unchecked_method_name: create_command_pool_unchecked,
+            ptr: CreateCommandPool,
+            signature: (
+                p_create_info: *const CommandPoolCreateInfo,
+                p_allocator: *const AllocationCallbacks,
+                p_command_pool: *mut CommandPool,
+            ) -> Result,
+            takes_handle: true,
+            provided_by: [
+                [VK_VERSION_1_0] => CreateCommandPool,
+            ],
+            hooks: [
+                p_create_info: structs,
+                p_allocator: structs,
+                p_command_pool: handles,
+            ],
+            checked: {
+                method_name: create_command_pool_checked,
+                args: (
+                    p_create_info: *const CommandPoolCreateInfo,
+                    p_allocator: Option<&AllocationCallbacks>,
+                ),
+                output: p_command_pool: CommandPool,
+                result: success,
+            },
+        },
+        {
+            name: pfn_destroy_command_pool,
+            method_name: destroy_command_pool,
+            try_method_name: try_destroy_command_pool,
+            unchecked_method_name: destroy_command_pool_unchecked,
+            ptr: DestroyCommandPool,
+            signature: (
+                command_pool: CommandPool,
+                p_allocator: *const AllocationCallbacks,
+            ),
+            takes_handle: true,
+            provided_by: [
+                [VK_VERSION_1_0] => DestroyCommandPool,
+            ],
+            hooks: [
+                command_pool: handle,
+                p_allocator: structs,
+            ],
+        },
+        {
+            name: pfn_reset_command_pool,
+            method_name: reset_command_pool,
+            try_method_name: try_reset_command_pool,
+            unchecked_method_name: reset_command_pool_unchecked,
+            ptr: ResetCommandPool,
+            signature: (
+                command_pool: CommandPool,
+                flags: CommandPoolResetFlags,
+            ) -> Result,
+            takes_handle: true,
+            provided_by: [
+                [VK_VERSION_1_0] => ResetCommandPool,
+            ],
+            hooks: [
+                command_pool: handle,
+                flags: value,
+            ],
+        },
+        {
+            name: pfn_allocate_command_buffers,
+            method_name: allocate_command_buffers,
+            try_method_name: try_allocate_command_buffers,
+            unchecked_method_name: allocate_command_buffers_unchecked,
+            ptr: AllocateCommandBuffers,
+            signature: (
+                p_allocate_info: *const CommandBufferAllocateInfo,
+                p_command_buffers: *mut CommandBuffer,
+            ) -> Result,
+            takes_handle: true,
+            provided_by: [
+                [VK_VERSION_1_0] => AllocateCommandBuffers,
+            ],
+            hooks: [
+                p_allocate_info: structs,
+                p_command_buffers: handles[(*p_allocate_info).command_buffer_count],
+            ],
+            checked: {
+                method_name: allocate_command_buffers_checked,
+                args: (
+                    p_allocate_info: *const CommandBufferAllocateInfo,
+                ),
+                output: p_command_buffers: Vec<CommandBuffer>,
+                len: (*p_allocate_info).command_buffer_count,
+                result: success,
+            },
+        },
+        {
+            name: pfn_free_command_buffers,
+            method_name: free_command_buffers,
+            try_method_name: try_free_command_buffers,
+            unchecked_method_name: free_command_buffers_unchecked,
+            ptr: FreeCommandBuffers,
+            signature: (
+                command_pool: CommandPool,
+                command_buffer_count: u32,
+                p_command_buffers: *const CommandBuffer,
+            ),
+            takes_handle: true,
+            provided_by: [
+                [VK_VERSION_1_0] => FreeCommandBuffers,
+            ],
+            hooks: [
+                command_pool: handle,
+                command_buffer_count: value,
+                p_command_buffers: handles[command_buffer_count],
+            ],
+        },
+        {
+            name: pfn_begin_command_buffer,
+            method_name: begin_command_buffer,
+            try_method_name: try_begin_command_buffer,
+            unchecked_method_name: begin_command_buffer_unchecked,
+            ptr: BeginCommandBuffer,
+            signature: (
+                command_buffer: CommandBuffer,
+                p_begin_info: *const CommandBufferBeginInfo,
+            ) -> Result,
+            takes_handle: false,
+            provided_by: [
+                [VK_VERSION_1_0] => BeginCommandBuffer,
+            ],
+            hooks: [
+                command_buffer: handle,
+                p_begin_info: structs,
+            ],
+        },
+        {
+            name: pfn_end_command_buffer,
+            method_name: end_command_buffer,
+            try_method_name: try_end_command_buffer,
+            unchecked_method_name: end_command_buffer_unchecked,
+            ptr: EndCommandBuffer,
+            signature: (
+                command_buffer: CommandBuffer,
+            ) -> Result,
+            takes_handle: false,
+            provided_by: [
+                [VK_VERSION_1_0] => EndCommandBuffer,
+            ],
+            hooks: [
+                command_buffer: handle,
+            ],
+        },
+        {
+            name: pfn_reset_command_buffer,
+            method_name: reset_command_buffer,
+            try_method_name: try_reset_command_buffer,
+            unchecked_method_name: reset_command_buffer_unchecked,
+            ptr: ResetCommandBuffer,
+            signature: (
+                command_buffer: CommandBuffer,
+                flags: CommandBufferResetFlags,
+            ) -> Result,
+            takes_handle: false,
+            provided_by: [
+                [VK_VERSION_1_0] => ResetCommandBuffer,
+            ],
+            hooks: [
+                command_buffer: handle,
+                flags: value,
+            ],
+        },
+        {
+            name: pfn_cmd_bind_pipeline,
+            method_name: cmd_bind_pipeline,
+            try_method_name: try_cmd_bind_pipeline,
+            unchecked_method_name: cmd_bind_pipeline_unchecked,
+            ptr: CmdBindPipeline,
+            signature: (
+                command_buffer: CommandBuffer,
+                pipeline_bind_point: PipelineBindPoint,
+                pipeline: Pipeline,
+            ),
+            takes_handle: false,
+            provided_by: [
+                [VK_VERSION_1_0] => CmdBindPipeline,
+            ],
+            hooks: [
+                command_buffer: handle,
+                pipeline_bind_point: value,
+                pipeline: handle,
+            ],
+        },
+        {
+            name: pfn_cmd_set_viewport,
+            method_name: cmd_set_viewport,
+            try_method_name: try_cmd_set_viewport,
+            unchecked_method_name: cmd_set_viewport_unchecked,
+            ptr: CmdSetViewport,
+            signature: (
+                command_buffer: CommandBuffer,
+                first_viewport: u32,
+                viewport_count: u32,
+                p_viewports: *const Viewport,
+            ),
+            takes_handle: false,
+            provided_by: [
+                [VK_VERSION_1_0] => CmdSetViewport,
+            ],
+            hooks: [
+                command_buffer: handle,
+                first_viewport: value,
+                viewport_count: value,
+                p_viewports: ptr[viewport_count],
+            ],
+        },
+        {
+            name: pfn_cmd_set_scissor,
+            method_name: cmd_set_scissor,
+            try_method_name: try_cmd_set_scissor,
+            unchecked_method_name: cmd_set_scissor_unchecked,
+            ptr: CmdSetScissor,
+            signature: (
+                command_buffer: CommandBuffer,
+                first_scissor: u32,
+                scissor_count: u32,
+                p_scissors: *const Rect2D,
+            ),
+            takes_handle: false,
+            provided_by: [
+                [VK_VERSION_1_0] => CmdSetScissor,
+            ],
+            hooks: [
+                command_buffer: handle,
+                first_scissor: value,
+                scissor_count: value,
+                p_scissors: ptr[scissor_count],
+            ],
+        },
+        {
+            name: pfn_cmd_set_line_width,
+            method_name: cmd_set_line_width,
+            try_method_name: try_cmd_set_line_width,
+            unchecked_method_name: cmd_set_line_width_unchecked,
+            ptr: CmdSetLineWidth,
+            signature: (
+                command_buffer: CommandBuffer,
+                line_width: c_float,
+            ),
+            takes_handle: false,
+            provided_by: [
+                [VK_VERSION_1_0] => CmdSetLineWidth,
+            ],
+            hooks: [
+                command_buffer: handle,
+                line_width: value,
+            ],
+        },
+        {
+            name: pfn_cmd_set_depth_bias,
+            method_name: cmd_set_depth_bias,
+            try_method_name: try_cmd_set_depth_bias,
+            unchecked_method_name: cmd_set_depth_bias_unchecked,
+            ptr: CmdSetDepthBias,
+            signature: (
+                command_buffer: CommandBuffer,
+                depth_bias_constant_factor: c_float,
+                depth_bias_clamp: c_float,
+                depth_bias_slope_factor: c_float,
+            ),
+            takes_handle: false,
+            provided_by: [
+                [VK_VERSION_1_0] => CmdSetDepthBias,
+            ],
+            hooks: [
+                command_buffer: handle,
+                depth_bias_constant_factor: value,
+                depth_bias_clamp: value,
+                depth_bias_slope_factor: value,
+            ],
+        },
+        {
+            name: pfn_cmd_set_blend_constants,
+            method_name: cmd_set_blend_constants,
+            try_method_name: try_cmd_set_blend_constants,
+            unchecked_method_name: cmd_set_blend_constants_unchecked,
+            ptr: CmdSetBlendConstants,
+            signature: (
+                command_buffer: CommandBuffer,
+                blend_constants: [c_float; 4],
+            ),
+            takes_handle: false,
+            provided_by: [
+                [VK_VERSION_1_0] => CmdSetBlendConstants,
+            ],
+            hooks: [
+                command_buffer: handle,
+                blend_constants: value,
+            ],
+        },
+        {
+            name: pfn_cmd_set_depth_bounds,
+            method_name: cmd_set_depth_bounds,
+            try_method_name: try_cmd_set_depth_bounds,
+            unchecked_method_name: cmd_set_depth_bounds_unchecked,
+            ptr: CmdSetDepthBounds,
+            signature: (
+                command_buffer: CommandBuffer,
+                min_depth_bounds: c_float,
+                max_depth_bounds: c_float,
+            ),
+            takes_handle: false,
+            provided_by: [
+                [VK_VERSION_1_0] => CmdSetDepthBounds,
+            ],
+            hooks: [
+                command_buffer: handle,
+                min_depth_bounds: value,
+                max_depth_bounds: value,
+            ],
+        },
+        {
+            name: pfn_cmd_set_stencil_compare_mask,
+            method_name: cmd_set_stencil_compare_mask,
+            try_method_name: try_cmd_set_stencil_compare_mask,
+            unchecked_method_name: cmd_set_stencil_compare_mask_unchecked,
+            ptr: CmdSetStencilCompareMask,
+            signature: (
+                command_buffer: CommandBuffer,
+                face_mask: StencilFaceFlags,
+                compare_mask: u32,
+            ),
+            takes_handle: false,
+            provided_by: [
+                [VK_VERSION_1_0] => CmdSetStencilCompareMask,
+            ],
+            hooks: [
+                command_buffer: handle,
+                face_mask: value,
+                compare_mask: value,
+            ],
+        },
+        {
+            name: pfn_cmd_set_stencil_write_mask,
+            method_name: cmd_set_stencil_write_mask,
+            try_method_name: try_cmd_set_stencil_write_mask,
+            unchecked_method_name: cmd_set_stencil_write_mask_unchecked,
+            ptr: CmdSetStencilWriteMask,
+            signature: (
+                command_buffer: CommandBuffer,
+                face_mask: StencilFaceFlags,
+                write_mask: u32,
+            ),
+            takes_handle: false,
+            provided_by: [
+                [VK_VERSION_1_0] => CmdSetStencilWriteMask,
+            ],
+            hooks: [
+                command_buffer: handle,
+                face_mask: value,
+                write_mask: value,
+            ],
+        },
+        {
+            name: pfn_cmd_set_stencil_reference,
+            method_name: cmd_set_stencil_reference,
+            try_method_name: try_cmd_set_stencil_reference,
+            unchecked_method_name: cmd_set_stencil_reference_unchecked,
+            ptr: CmdSetStencilReference,
+            signature: (
+                command_buffer: CommandBuffer,
+                face_mask: StencilFaceFlags,
+                reference: u32,
+            ),
+            takes_handle: false,
+            provided_by: [
+                [VK_VERSION_1_0] => CmdSetStencilReference,
+            ],
+            hooks: [
+                command_buffer: handle,
+                face_mask: value,
+                reference: value,
+            ],
+        },
+        {
+            name: pfn_cmd_bind_descriptor_sets,
+            method_name: cmd_bind_descriptor_sets,
+            try_method_name: try_cmd_bind_descriptor_sets,
+            unchecked_method_name: cmd_bind_descriptor_sets_unchecked,
+            ptr: CmdBindDescriptorSets,
+            signature: (
+                command_buffer: CommandBuffer,
+                pipeline_bind_point: PipelineBindPoint,
+                layout: PipelineLayout,
+                first_set: u32,
+                descriptor_set_count: u32,
+                p_descriptor_sets: *const DescriptorSet,
+                dynamic_offset_count: u32,
+                p_dynamic_offsets: *const u32,
+            ),
+            takes_handle: false,
+            provided_by: [
+                [VK_VERSION_1_0] => CmdBindDescriptorSets,
+            ],
+            hooks: [
+                command_buffer: handle,
+                pipeline_bind_point: value,
+                layout: handle,
+                first_set: value,
+                descriptor_set_count: value,
+                p_descriptor_sets: handles[descriptor_set_count],
+                dynamic_offset_count: value,
+                p_dynamic_offsets: ptr[dynamic_offset_count],
+            ],
+        },
+        {
+            name: pfn_cmd_bind_index_buffer,
+            method_name: cmd_bind_index_buffer,
+            try_method_name: try_cmd_bind_index_buffer,
+            unchecked_method_name: cmd_bind_index_buffer_unchecked,
+            ptr: CmdBindIndexBuffer,
+            signature: (
+                command_buffer: CommandBuffer,
+                buffer: Buffer,
+                offset: DeviceSize,
+                index_type: IndexType,
+            ),
+            takes_handle: false,
+            provided_by: [
+                [VK_VERSION_1_0] => CmdBindIndexBuffer,
+            ],
+            hooks: [
+                command_buffer: handle,
+                buffer: handle,
+                offset: value,
+                index_type: value,
+            ],
+        },
+        {
+            name: pfn_cmd_bind_vertex_buffers,
+            method_name: cmd_bind_vertex_buffers,
+            try_method_name: try_cmd_bind_vertex_buffers,
+            unchecked_method_name: cmd_bind_vertex_buffers_unchecked,
+            ptr: CmdBindVertexBuffers,
+            signature: (
+                command_buffer: CommandBuffer,
+                first_binding: u32,
+                binding_count: u32,
+                p_buffers: *const Buffer,
+                p_offsets: *const DeviceSize,
+            ),
+            takes_handle: false,
+            provided_by: [
+                [VK_VERSION_1_0] => CmdBindVertexBuffers,
+            ],
+            hooks: [
+                command_buffer: handle,
+                first_binding: value,
+                binding_count: value,
+                p_buffers: handles[binding_count],
+                p_offsets: ptr[binding_count],
+            ],
+        },
+        {
+            name: pfn_cmd_draw,
+            method_name: cmd_draw,
+            try_method_name: try_cmd_draw,
+            unchecked_method_name: cmd_draw_unchecked,
+            ptr: CmdDraw,
+            signature: (
+                command_buffer: CommandBuffer,
+                vertex_count: u32,
+                instance_count: u32,
+                first_vertex: u32,
+                first_instance: u32,
+            ),
+            takes_handle: false,
+            provided_by: [
+                [VK_VERSION_1_0] => CmdDraw,
+            ],
+            hooks: [
+                command_buffer: handle,
+                vertex_count: value,
+                instance_count: value,
+                first_vertex: value,
+                first_instance: value,
+            ],
+        },
+        {
+            name: pfn_cmd_draw_indexed,
+            method_name: cmd_draw_indexed,
+            try_method_name: try_cmd_draw_indexed,
+            unchecked_method_name: cmd_draw_indexed_unchecked,
+            ptr: CmdDrawIndexed,
+            signature: (
+                command_buffer: CommandBuffer,
+                index_count: u32,
+                instance_count: u32,
+                first_index: u32,
+                vertex_offset: i32,
+                first_instance: u32,
+            ),
+            takes_handle: false,
+            provided_by: [
+                [VK_VERSION_1_0] => CmdDrawIndexed,
+            ],
+            hooks: [
+                command_buffer: handle,
+                index_count: value,
+                instance_count: value,
+                first_index: value,
+                vertex_offset: value,
+                first_instance: value,
+            ],
+        },
+        {
+            name: pfn_cmd_draw_multi_ext,
+            method_name: cmd_draw_multi_ext,
+            try_method_name: try_cmd_draw_multi_ext,
+            unchecked_method_name: cmd_draw_multi_ext_unchecked,
+            ptr: CmdDrawMultiEXT,
+            signature: (
+                command_buffer: CommandBuffer,
+                draw_count: u32,
+                p_vertex_info: *const MultiDrawInfoEXT,
+                instance_count: u32,
+                first_instance: u32,
+                stride: u32,
+            ),
+            takes_handle: false,
+            provided_by: [
+                [VK_EXT_multi_draw] => CmdDrawMultiEXT,
+            ],
+            hooks: [
+                command_buffer: handle,
+                draw_count: value,
+                p_vertex_info: ptr[draw_count],
+                instance_count: value,
+                first_instance: value,
+                stride: value,
+            ],
+        },
+        {
+            name: pfn_cmd_draw_multi_indexed_ext,
+            method_name: cmd_draw_multi_indexed_ext,
+            try_method_name: try_cmd_draw_multi_indexed_ext,
+            unchecked_method_name: cmd_draw_multi_indexed_ext_unchecked,
+            ptr: CmdDrawMultiIndexedEXT,
+            signature: (
+                command_buffer: CommandBuffer,
+                draw_count: u32,
+                p_index_info: *const MultiDrawIndexedInfoEXT,
+                instance_count: u32,
+                first_instance: u32,
+                stride: u32,
+                p_vertex_offset: *const i32,
+            ),
+            takes_handle: false,
+            provided_by: [
+                [VK_EXT_multi_draw] => CmdDrawMultiIndexedEXT,
+            ],
+            hooks: [
+                command_buffer: handle,
+                draw_count: value,
+                p_index_info: ptr[draw_count],
+                instance_count: value,
+                first_instance: value,
+                stride: value,
+                p_vertex_offset: ptr,
+            ],
+        },
+        {
+            name: pfn_cmd_draw_indirect,
+            method_name: cmd_draw_indirect,
+            try_method_name: try_cmd_draw_indirect,
+            unchecked_method_name: cmd_draw_indirect_unchecked,
+            ptr: CmdDrawIndirect,
+            signature: (
+                command_buffer: CommandBuffer,
+                buffer: Buffer,
+                offset: DeviceSize,
+                draw_count: u32,
+                stride: u32,
+            ),
+            takes_handle: false,
+            provided_by: [
+                [VK_VERSION_1_0] => CmdDrawIndirect,
+            ],
+            hooks: [
+                command_buffer: handle,
+                buffer: handle,
+                offset: value,
+                draw_count: value,
+                stride: value,
+            ],
+        },
+        {
+            name: pfn_cmd_draw_indexed_indirect,
+            method_name: cmd_draw_indexed_indirect,
+            try_method_name: try_cmd_draw_indexed_indirect,
+            unchecked_method_name: cmd_draw_indexed_indirect_unchecked,
+            ptr: CmdDrawIndexedIndirect,
+            signature: (
+                command_buffer: CommandBuffer,
+                buffer: Buffer,
+                offset: DeviceSize,
+                draw_count: u32,
+                stride: u32,
+            ),
+            takes_handle: false,
+            provided_by: [
+                [VK_VERSION_1_0] => CmdDrawIndexedIndirect,
+            ],
+            hooks: [
+                command_buffer: handle,
+                buffer: handle,
+                offset: value,
+                draw_count: value,
+                stride: value,
+            ],
+        },
+        {
+            name: pfn_cmd_dispatch,
+            method_name: cmd_dispatch,
+            try_method_name: try_cmd_dispatch,
+            unchecked_method_name: cmd_dispatch_unchecked,
+            ptr: CmdDispatch,
+            signature: (
+                command_buffer: CommandBuffer,
+                group_count_x: u32,
+                group_count_y: u32,
+                group_count_z: u32,
+            ),
+            takes_handle: false,
+            provided_by: [
+                [VK_VERSION_1_0] => CmdDispatch,
+            ],
+            hooks: [
+                command_buffer: handle,
+                group_count_x: value,
+                group_count_y: value,
+                group_count_z: value,
+            ],
+        },
+        {
+            name: pfn_cmd_dispatch_indirect,
+            method_name: cmd_dispatch_indirect,
+            try_method_name: try_cmd_dispatch_indirect,
+            unchecked_method_name: cmd_dispatch_indirect_unchecked,
+            ptr: CmdDispatchIndirect,
+            signature: (
+                command_buffer: CommandBuffer,
+                buffer: Buffer,
+                offset: DeviceSize,
+            ),
+            takes_handle: false,
+            provided_by: [
+                [VK_VERSION_1_0] => CmdDispatchIndirect,
+            ],
+            hooks: [
+                command_buffer: handle,
+                buffer: handle,
+                offset: value,
+            ],
+        },
         {
-            name: pfn_destroy_instance,
-            method_name: destroy_instance,
-            ptr: DestroyInstance,
+            name: pfn_cmd_subpass_shading_huawei,
+            method_name: cmd_subpass_shading_huawei,
+            try_method_name: try_cmd_subpass_shading_huawei,
+            unchecked_method_name: cmd_subpass_shading_huawei_unchecked,
+            ptr: CmdSubpassShadingHUAWEI,
             signature: (
-                p_allocator: *const AllocationCallbacks,
+                command_buffer: CommandBuffer,
             ),
-            takes_handle: true,
+            takes_handle: false,
+            provided_by: [
+                [VK_HUAWEI_subpass_shading] => CmdSubpassShadingHUAWEI,
+            ],
+            hooks: [
+                command_buffer: handle,
+            ],
         },
         {
-            name: pfn_enumerate_physical_devices,
-            method_name: enumerate_physical_devices,
-            ptr: EnumeratePhysicalDevices,
+            name: pfn_cmd_copy_buffer,
+            method_name: cmd_copy_buffer,
+            try_method_name: try_cmd_copy_buffer,
+            unchecked_method_name: cmd_copy_buffer_unchecked,
+            ptr: CmdCopyBuffer,
             signature: (
-                p_physical_device_count: *mut u32,
-                p_physical_devices: *mut PhysicalDevice,
-            ) -> Result,
-            takes_handle: true,
+                command_buffer: CommandBuffer,
+                src_buffer: Buffer,
+                dst_buffer: Buffer,
+                region_count: u32,
+                p_regions: *const BufferCopy,
+            ),
+            takes_handle: false,
+            provided_by: [
+                [VK_VERSION_1_0] => CmdCopyBuffer,
+            ],
+            hooks: [
+                command_buffer: handle,
+                src_buffer: handle,
+                dst_buffer: handle,
+                region_count: value,
+                p_regions: ptr[region_count],
+            ],
         },
         {
-            name: pfn_get_instance_proc_addr,
-            method_name: get_instance_proc_addr,
-            ptr: GetInstanceProcAddr,
+            name: pfn_cmd_copy_image,
+            method_name: cmd_copy_image,
+            try_method_name: try_cmd_copy_image,
+            unchecked_method_name: cmd_copy_image_unchecked,
+            ptr: CmdCopyImage,
             signature: (
-                p_name: *const c_char,
-            ) -> crate::pfn::VoidFunction,
-            takes_handle: true,
+                command_buffer: CommandBuffer,
+                src_image: Image,
+                src_image_layout: ImageLayout,
+                dst_image: Image,
+                dst_image_layout: ImageLayout,
+                region_count: u32,
+                p_regions: *const ImageCopy,
+            ),
+            takes_handle: false,
+            provided_by: [
+                [VK_VERSION_1_0] => CmdCopyImage,
+            ],
+            hooks: [
+                command_buffer: handle,
+                src_image: handle,
+                src_image_layout: value,
+                dst_image: handle,
+                dst_image_layout: value,
+                region_count: value,
+                p_regions: ptr[region_count],
+            ],
         },
         {
-            name: pfn_get_physical_device_properties,
-            method_name: get_physical_device_properties,
-            ptr: GetPhysicalDeviceProperties,
+            name: pfn_cmd_blit_image,
+            method_name: cmd_blit_image,
+            try_method_name: try_cmd_blit_image,
+            unchecked_method_name: cmd_blit_image_unchecked,
+            ptr: CmdBlitImage,
             signature: (
-                physical_device: PhysicalDevice,
-                p_properties: *mut PhysicalDeviceProperties,
+                command_buffer: CommandBuffer,
+                src_image: Image,
+                src_image_layout: ImageLayout,
+                dst_image: Image,
+                dst_image_layout: ImageLayout,
+                region_count: u32,
+                p_regions: *const ImageBlit,
+                filter: Filter,
             ),
             takes_handle: false,
+            provided_by: [
+                [VK_VERSION_1_0] => CmdBlitImage,
+            ],
+            hooks: [
+                command_buffer: handle,
+                src_image: handle,
+                src_image_layout: value,
+                dst_image: handle,
+                dst_image_layout: value,
+                region_count: value,
+                p_regions: ptr[region_count],
+                filter: value,
+            ],
         },
         {
-            name: pfn_get_physical_device_queue_family_properties,
-            method_name: get_physical_device_queue_family_properties,
-            ptr: GetPhysicalDeviceQueueFamilyProperties,
+            name: pfn_cmd_copy_buffer_to_image,
+            method_name: cmd_copy_buffer_to_image,
+            try_method_name: try_cmd_copy_buffer_to_image,
+            unchecked_method_name: cmd_copy_buffer_to_image_unchecked,
+            ptr: CmdCopyBufferToImage,
             signature: (
-                physical_device: PhysicalDevice,
-                p_queue_family_property_count: *mut u32,
-                p_queue_family_properties: *mut QueueFamilyProperties,
+                command_buffer: CommandBuffer,
+                src_buffer: Buffer,
+                dst_image: Image,
+                dst_image_layout: ImageLayout,
+                region_count: u32,
+                p_regions: *const BufferImageCopy,
             ),
             takes_handle: false,
+            provided_by: [
+                [VK_VERSION_1_0] => CmdCopyBufferToImage,
+            ],
+            hooks: [
+                command_buffer: handle,
+                src_buffer: handle,
+                dst_image: handle,
+                dst_image_layout: value,
+                region_count: value,
+                p_regions: ptr[region_count],
+            ],
         },
         {
-            name: pfn_get_physical_device_memory_properties,
-            method_name: get_physical_device_memory_properties,
-            ptr: GetPhysicalDeviceMemoryProperties,
+            name: pfn_cmd_copy_image_to_buffer,
+            method_name: cmd_copy_image_to_buffer,
+            try_method_name: try_cmd_copy_image_to_buffer,
+            unchecked_method_name: cmd_copy_image_to_buffer_unchecked,
+            ptr: CmdCopyImageToBuffer,
             signature: (
-                physical_device: PhysicalDevice,
-                p_memory_properties: *mut PhysicalDeviceMemoryProperties,
+                command_buffer: CommandBuffer,
+                src_image: Image,
+                src_image_layout: ImageLayout,
+                dst_buffer: Buffer,
+                region_count: u32,
+                p_regions: *const BufferImageCopy,
             ),
             takes_handle: false,
+            provided_by: [
+                [VK_VERSION_1_0] => CmdCopyImageToBuffer,
+            ],
+            hooks: [
+                command_buffer: handle,
+                src_image: handle,
+                src_image_layout: value,
+                dst_buffer: handle,
+                region_count: value,
+                p_regions: ptr[region_count],
+            ],
         },
         {
-            name: pfn_get_physical_device_features,
-            method_name: get_physical_device_features,
-            ptr: GetPhysicalDeviceFeatures,
+            name: pfn_cmd_update_buffer,
+            method_name: cmd_update_buffer,
+            try_method_name: try_cmd_update_buffer,
+            unchecked_method_name: cmd_update_buffer_unchecked,
+            ptr: CmdUpdateBuffer,
             signature: (
-                physical_device: PhysicalDevice,
-                p_features: *mut PhysicalDeviceFeatures,
+                command_buffer: CommandBuffer,
+                dst_buffer: Buffer,
+                dst_offset: DeviceSize,
+                data_size: DeviceSize,
+                p_data: *const c_void,
             ),
             takes_handle: false,
+            provided_by: [
+                [VK_VERSION_1_0] => CmdUpdateBuffer,
+            ],
+            hooks: [
+                command_buffer: handle,
+                dst_buffer: handle,
+                dst_offset: value,
+                data_size: value,
+                p_data: bytes[data_size],
+            ],
         },
         {
-            name: pfn_get_physical_device_format_properties,
-            method_name: get_physical_device_format_properties,
-            ptr: GetPhysicalDeviceFormatProperties,
+            name: pfn_cmd_fill_buffer,
+            method_name: cmd_fill_buffer,
+            try_method_name: try_cmd_fill_buffer,
+            unchecked_method_name: cmd_fill_buffer_unchecked,
+            ptr: CmdFillBuffer,
             signature: (
-                physical_device: PhysicalDevice,
-                format: Format,
-                p_format_properties: *mut FormatProperties,
+                command_buffer: CommandBuffer,
+                dst_buffer: Buffer,
+                dst_offset: DeviceSize,
+                size: DeviceSize,
+                data: u32,
             ),
             takes_handle: false,
+            provided_by: [
+                [VK_VERSION_1_0] => CmdFillBuffer,
+            ],
+            hooks: [
+                command_buffer: handle,
+                dst_buffer: handle,
+                dst_offset: value,
+                size: value,
+                data: value,
+            ],
         },
         {
-            name: pfn_get_physical_device_image_format_properties,
-            method_name: get_physical_device_image_format_properties,
-            ptr: GetPhysicalDeviceImageFormatProperties,
+            name: pfn_cmd_clear_color_image,
+            method_name: cmd_clear_color_image,
+            try_method_name: try_cmd_clear_color_image,
+            unchecked_method_name: cmd_clear_color_image_unchecked,
+            ptr: CmdClearColorImage,
             signature: (
-                physical_device: PhysicalDevice,
-                format: Format,
-                ty: ImageType,
-                tiling: ImageTiling,
-                usage: ImageUsageFlags,
-                flags: ImageCreateFlags,
-                p_image_format_properties: *mut ImageFormatProperties,
-            ) -> Result,
+                command_buffer: CommandBuffer,
+                image: Image,
+                image_layout: ImageLayout,
+                p_color: *const ClearColorValue,
+                range_count: u32,
+                p_ranges: *const ImageSubresourceRange,
+            ),
             takes_handle: false,
+            provided_by: [
+                [VK_VERSION_1_0] => CmdClearColorImage,
+            ],
+            hooks: [
+                command_buffer: handle,
+                image: handle,
+                image_layout: value,
+                p_color: ptr,
+                range_count: value,
+                p_ranges: ptr[range_count],
+            ],
         },
         {
-            name: pfn_create_device,
-            method_name: create_device,
-            ptr: CreateDevice,
+            name: pfn_cmd_clear_depth_stencil_image,
+            method_name: cmd_clear_depth_stencil_image,
+            try_method_name: try_cmd_clear_depth_stencil_image,
+            unchecked_method_name: cmd_clear_depth_stencil_image_unchecked,
+            ptr: CmdClearDepthStencilImage,
             signature: (
-                physical_device: PhysicalDevice,
-                p_create_info: *const DeviceCreateInfo,
-                p_allocator: *const AllocationCallbacks,
-                p_device: *mut Device,
-            ) -> Result,
+                command_buffer: CommandBuffer,
+                image: Image,
+                image_layout: ImageLayout,
+                p_depth_stencil: *const ClearDepthStencilValue,
+                range_count: u32,
+                p_ranges: *const ImageSubresourceRange,
+            ),
             takes_handle: false,
+            provided_by: [
+                [VK_VERSION_1_0] => CmdClearDepthStencilImage,
+            ],
+            hooks: [
+                command_buffer: handle,
+                image: handle,
+                image_layout: value,
+                p_depth_stencil: ptr,
+                range_count: value,
+                p_ranges: ptr[range_count],
+            ],
         },
         {
-            name: pfn_enumerate_device_layer_properties,
-            method_name: enumerate_device_layer_properties,
-            ptr: EnumerateDeviceLayerProperties,
+            name: pfn_cmd_clear_attachments,
+            method_name: cmd_clear_attachments,
+            try_method_name: try_cmd_clear_attachments,
+            unchecked_method_name: cmd_clear_attachments_unchecked,
+            ptr: CmdClearAttachments,
             signature: (
-                physical_device: PhysicalDevice,
-                p_property_count: *mut u32,
-                p_properties: *mut LayerProperties,
-            ) -> Result,
+                command_buffer: CommandBuffer,
+                attachment_count: u32,
+                p_attachments: *const ClearAttachment,
+                rect_count: u32,
+                p_rects: *const ClearRect,
+            ),
             takes_handle: false,
+            provided_by: [
+                [VK_VERSION_1_0] => CmdClearAttachments,
+            ],
+            hooks: [
+                command_buffer: handle,
+                attachment_count: value,
+                p_attachments: ptr[attachment_count],
+                rect_count: value,
+                p_rects: ptr[rect_count],
+            ],
         },
         {
-            name: pfn_enumerate_device_extension_properties,
-            method_name: enumerate_device_extension_properties,
-            ptr: EnumerateDeviceExtensionProperties,
+            name: pfn_cmd_resolve_image,
+            method_name: cmd_resolve_image,
+            try_method_name: try_cmd_resolve_image,
+            unchecked_method_name: cmd_resolve_image_unchecked,
+            ptr: CmdResolveImage,
             signature: (
-                physical_device: PhysicalDevice,
-                p_layer_name: *const c_char,
-                p_property_count: *mut u32,
-                p_properties: *mut ExtensionProperties,
-            ) -> Result,
+                command_buffer: CommandBuffer,
+                src_image: Image,
+                src_image_layout: ImageLayout,
+                dst_image: Image,
+                dst_image_layout: ImageLayout,
+                region_count: u32,
+                p_regions: *const ImageResolve,
+            ),
+            takes_handle: false,
+            provided_by: [
+                [VK_VERSION_1_0] => CmdResolveImage,
+            ],
+            hooks: [
+                command_buffer: handle,
+                src_image: handle,
+                src_image_layout: value,
+                dst_image: handle,
+                dst_image_layout: value,
+                region_count: value,
+                p_regions: ptr[region_count],
+            ],
+        },
+        {
+            name: pfn_cmd_set_event,
+            method_name: cmd_set_event,
+            try_method_name: try_cmd_set_event,
+            unchecked_method_name: cmd_set_event_unchecked,
+            ptr: CmdSetEvent,
+            signature: (
+                command_buffer: CommandBuffer,
+                event: Event,
+                stage_mask: PipelineStageFlags,
+            ),
+            takes_handle: false,
+            provided_by: [
+                [VK_VERSION_1_0] => CmdSetEvent,
+            ],
+            hooks: [
+                command_buffer: handle,
+                event: handle,
+                stage_mask: value,
+            ],
+        },
+        {
+            name: pfn_cmd_reset_event,
+            method_name: cmd_reset_event,
+            try_method_name: try_cmd_reset_event,
+            unchecked_method_name: cmd_reset_event_unchecked,
+            ptr: CmdResetEvent,
+            signature: (
+                command_buffer: CommandBuffer,
+                event: Event,
+                stage_mask: PipelineStageFlags,
+            ),
+            takes_handle: false,
+            provided_by: [
+                [VK_VERSION_1_0] => CmdResetEvent,
+            ],
+            hooks: [
+                command_buffer: handle,
+                event: handle,
+                stage_mask: value,
+            ],
+        },
+        {
+            name: pfn_cmd_wait_events,
+            method_name: cmd_wait_events,
+            try_method_name: try_cmd_wait_events,
+            unchecked_method_name: cmd_wait_events_unchecked,
+            ptr: CmdWaitEvents,
+            signature: (
+                command_buffer: CommandBuffer,
+                event_count: u32,
+                p_events: *const Event,
+                src_stage_mask: PipelineStageFlags,
+                dst_stage_mask: PipelineStageFlags,
+                memory_barrier_count: u32,
+                p_memory_barriers: *const MemoryBarrier,
+                buffer_memory_barrier_count: u32,
+                p_buffer_memory_barriers: *const BufferMemoryBarrier,
+                image_memory_barrier_count: u32,
+                p_image_memory_barriers: *const ImageMemoryBarrier,
+            ),
+            takes_handle: false,
+            provided_by: [
+                [VK_VERSION_1_0] => CmdWaitEvents,
+            ],
+            hooks: [
+                command_buffer: handle,
+                event_count: value,
+                p_events: handles[event_count],
+                src_stage_mask: value,
+                dst_stage_mask: value,
+                memory_barrier_count: value,
+                p_memory_barriers: structs[memory_barrier_count],
+                buffer_memory_barrier_count: value,
+                p_buffer_memory_barriers: structs[buffer_memory_barrier_count],
+                image_memory_barrier_count: value,
+                p_image_memory_barriers: structs[image_memory_barrier_count],
+            ],
+        },
+        {
+            name: pfn_cmd_pipeline_barrier,
+            method_name: cmd_pipeline_barrier,
+            try_method_name: try_cmd_pipeline_barrier,
+            unchecked_method_name: cmd_pipeline_barrier_unchecked,
+            ptr: CmdPipelineBarrier,
+            signature: (
+                command_buffer: CommandBuffer,
+                src_stage_mask: PipelineStageFlags,
+                dst_stage_mask: PipelineStageFlags,
+                dependency_flags: DependencyFlags,
+                memory_barrier_count: u32,
+                p_memory_barriers: *const MemoryBarrier,
+                buffer_memory_barrier_count: u32,
+                p_buffer_memory_barriers: *const BufferMemoryBarrier,
+                image_memory_barrier_count: u32,
+                p_image_memory_barriers: *const ImageMemoryBarrier,
+            ),
             takes_handle: false,
+            provided_by: [
+                [VK_VERSION_1_0] => CmdPipelineBarrier,
+            ],
+            hooks: [
+                command_buffer: handle,
+                src_stage_mask: value,
+                dst_stage_mask: value,
+                dependency_flags: value,
+                memory_barrier_count: value,
+                p_memory_barriers: structs[memory_barrier_count],
+                buffer_memory_barrier_count: value,
+                p_buffer_memory_barriers: structs[buffer_memory_barrier_count],
+                image_memory_barrier_count: value,
+                p_image_memory_barriers: structs[image_memory_barrier_count],
+            ],
         },
         {
-            name: pfn_get_physical_device_sparse_image_format_properties,
-            method_name: get_physical_device_sparse_image_format_properties,
-            ptr: GetPhysicalDeviceSparseImageFormatProperties,
+            name: pfn_cmd_begin_query,
+            method_name: cmd_begin_query,
+            try_method_name: try_cmd_begin_query,
+            unchecked_method_name: cmd_begin_query_unchecked,
+            ptr: CmdBeginQuery,
             signature: (
-                physical_device: PhysicalDevice,
-                format: Format,
-                ty: ImageType,
-                samples: SampleCountFlagBits,
-                usage: ImageUsageFlags,
-                tiling: ImageTiling,
-                p_property_count: *mut u32,
-                p_properties: *mut SparseImageFormatProperties,
+                command_buffer: CommandBuffer,
+                query_pool: QueryPool,
+                query: u32,
+                flags: QueryControlFlags,
             ),
             takes_handle: false,
+            provided_by: [
+                [VK_VERSION_1_0] => CmdBeginQuery,
+            ],
+            hooks: [
+                command_buffer: handle,
+                query_pool: handle,
+                query: value,
+                flags: value,
+            ],
         },
         {
-            name: pfn_create_android_surface_khr,
-            method_name: create_android_surface_khr,
-            ptr: CreateAndroidSurfaceKHR,
-            signature: (
-                p_create_info: *const AndroidSurfaceCreateInfoKHR,
-                p_allocator: *const AllocationCallbacks,
-                p_surface: *mut SurfaceKHR,
-            ) -> Result,
-            takes_handle: true,
-        },
-        {
-            name: pfn_get_physical_device_display_properties_khr,
-            method_name: get_physical_device_display_properties_khr,
-            ptr: GetPhysicalDeviceDisplayPropertiesKHR,
+            name: pfn_cmd_end_query,
+            method_name: cmd_end_query,
+            try_method_name: try_cmd_end_query,
+            unchecked_method_name: cmd_end_query_unchecked,
+            ptr: CmdEndQuery,
             signature: (
-                physical_device: PhysicalDevice,
-                p_property_count: *mut u32,
-                p_properties: *mut DisplayPropertiesKHR,
-            ) -> Result,
+                command_buffer: CommandBuffer,
+                query_pool: QueryPool,
+                query: u32,
+            ),
             takes_handle: false,
+            provided_by: [
+                [VK_VERSION_1_0] => CmdEndQuery,
+            ],
+            hooks: [
+                command_buffer: handle,
+                query_pool: handle,
+                query: value,
+            ],
         },
         {
-            name: pfn_get_physical_device_display_plane_properties_khr,
-            method_name: get_physical_device_display_plane_properties_khr,
-            ptr: GetPhysicalDeviceDisplayPlanePropertiesKHR,
+            name: pfn_cmd_begin_conditional_rendering_ext,
+            method_name: cmd_begin_conditional_rendering_ext,
+            try_method_name: try_cmd_begin_conditional_rendering_ext,
+            unchecked_method_name: cmd_begin_conditional_rendering_ext_unchecked,
+            ptr: CmdBeginConditionalRenderingEXT,
             signature: (
-                physical_device: PhysicalDevice,
-                p_property_count: *mut u32,
-                p_properties: *mut DisplayPlanePropertiesKHR,
-            ) -> Result,
+                command_buffer: CommandBuffer,
+                p_conditional_rendering_begin: *const ConditionalRenderingBeginInfoEXT,
+            ),
             takes_handle: false,
+            provided_by: [
+                [VK_EXT_conditional_rendering] => CmdBeginConditionalRenderingEXT,
+            ],
+            hooks: [
+                command_buffer: handle,
+                p_conditional_rendering_begin: structs,
+            ],
         },
         {
-            name: pfn_get_display_plane_supported_displays_khr,
-            method_name: get_display_plane_supported_displays_khr,
-            ptr: GetDisplayPlaneSupportedDisplaysKHR,
+            name: pfn_cmd_end_conditional_rendering_ext,
+            method_name: cmd_end_conditional_rendering_ext,
+            try_method_name: try_cmd_end_conditional_rendering_ext,
+            unchecked_method_name: cmd_end_conditional_rendering_ext_unchecked,
+            ptr: CmdEndConditionalRenderingEXT,
             signature: (
-                physical_device: PhysicalDevice,
-                plane_index: u32,
-                p_display_count: *mut u32,
-                p_displays: *mut DisplayKHR,
-            ) -> Result,
+                command_buffer: CommandBuffer,
+            ),
             takes_handle: false,
+            provided_by: [
+                [VK_EXT_conditional_rendering] => CmdEndConditionalRenderingEXT,
+            ],
+            hooks: [
+                command_buffer: handle,
+            ],
         },
         {
-            name: pfn_get_display_mode_properties_khr,
-            method_name: get_display_mode_properties_khr,
-            ptr: GetDisplayModePropertiesKHR,
+            name: pfn_cmd_reset_query_pool,
+            method_name: cmd_reset_query_pool,
+            try_method_name: try_cmd_reset_query_pool,
+            unchecked_method_name: cmd_reset_query_pool_unchecked,
+            ptr: CmdResetQueryPool,
             signature: (
-                physical_device: PhysicalDevice,
-                display: DisplayKHR,
-                p_property_count: *mut u32,
-                p_properties: *mut DisplayModePropertiesKHR,
-            ) -> Result,
+                command_buffer: CommandBuffer,
+                query_pool: QueryPool,
+                first_query: u32,
+                query_count: u32,
+            ),
             takes_handle: false,
+            provided_by: [
+                [VK_VERSION_1_0] => CmdResetQueryPool,
+            ],
+            hooks: [
+                command_buffer: handle,
+                query_pool: handle,
+                first_query: value,
+                query_count: value,
+            ],
         },
         {
-            name: pfn_create_display_mode_khr,
-            method_name: create_display_mode_khr,
-            ptr: CreateDisplayModeKHR,
+            name: pfn_cmd_write_timestamp,
+            method_name: cmd_write_timestamp,
+            try_method_name: try_cmd_write_timestamp,
+            unchecked_method_name: cmd_write_timestamp_unchecked,
+            ptr: CmdWriteTimestamp,
             signature: (
-                physical_device: PhysicalDevice,
-                display: DisplayKHR,
-                p_create_info: *const DisplayModeCreateInfoKHR,
-                p_allocator: *const AllocationCallbacks,
-                p_mode: *mut DisplayModeKHR,
-            ) -> Result,
+                command_buffer: CommandBuffer,
+                pipeline_stage: PipelineStageFlagBits,
+                query_pool: QueryPool,
+                query: u32,
+            ),
             takes_handle: false,
+            provided_by: [
+                [VK_VERSION_1_0] => CmdWriteTimestamp,
+            ],
+            hooks: [
+                command_buffer: handle,
+                pipeline_stage: value,
+                query_pool: handle,
+                query: value,
+            ],
         },
         {
-            name: pfn_get_display_plane_capabilities_khr,
-            method_name: get_display_plane_capabilities_khr,
-            ptr: GetDisplayPlaneCapabilitiesKHR,
+            name: pfn_cmd_copy_query_pool_results,
+            method_name: cmd_copy_query_pool_results,
+            try_method_name: try_cmd_copy_query_pool_results,
+            unchecked_method_name: cmd_copy_query_pool_results_unchecked,
+            ptr: CmdCopyQueryPoolResults,
             signature: (
-                physical_device: PhysicalDevice,
-                mode: DisplayModeKHR,
-                plane_index: u32,
-                p_capabilities: *mut DisplayPlaneCapabilitiesKHR,
-            ) -> Result,
+                command_buffer: CommandBuffer,
+                query_pool: QueryPool,
+                first_query: u32,
+                query_count: u32,
+                dst_buffer: Buffer,
+                dst_offset: DeviceSize,
+                stride: DeviceSize,
+                flags: QueryResultFlags,
+            ),
             takes_handle: false,
+            provided_by: [
+                [VK_VERSION_1_0] => CmdCopyQueryPoolResults,
+            ],
+            hooks: [
+                command_buffer: handle,
+                query_pool: handle,
+                first_query: value,
+                query_count: value,
+                dst_buffer: handle,
+                dst_offset: value,
+                stride: value,
+                flags: value,
+            ],
         },
         {
-            name: pfn_create_display_plane_surface_khr,
-            method_name: create_display_plane_surface_khr,
-            ptr: CreateDisplayPlaneSurfaceKHR,
+            name: pfn_cmd_push_constants,
+            method_name: cmd_push_constants,
+            try_method_name: try_cmd_push_constants,
+            unchecked_method_name: cmd_push_constants_unchecked,
+            ptr: CmdPushConstants,
             signature: (
-                p_create_info: *const DisplaySurfaceCreateInfoKHR,
-                p_allocator: *const AllocationCallbacks,
-                p_surface: *mut SurfaceKHR,
-            ) -> Result,
-            takes_handle: true,
+                command_buffer: CommandBuffer,
+                layout: PipelineLayout,
+                stage_flags: ShaderStageFlags,
+                offset: u32,
+                size: u32,
+                p_values: *const c_void,
+            ),
+            takes_handle: false,
+            provided_by: [
+                [VK_VERSION_1_0] => CmdPushConstants,
+            ],
+            hooks: [
+                command_buffer: handle,
+                layout: handle,
+                stage_flags: value,
+                offset: value,
+                size: value,
+                p_values: bytes[size],
+            ],
         },
         {
-            name: pfn_destroy_surface_khr,
-            method_name: destroy_surface_khr,
-            ptr: DestroySurfaceKHR,
+            name: pfn_cmd_begin_render_pass,
+            method_name: cmd_begin_render_pass,
+            try_method_name: try_cmd_begin_render_pass,
+            unchecked_method_name: cmd_begin_render_pass_unchecked,
+            ptr: CmdBeginRenderPass,
             signature: (
-                surface: SurfaceKHR,
-                p_allocator: *const AllocationCallbacks,
+                command_buffer: CommandBuffer,
+                p_render_pass_begin: *const RenderPassBeginInfo,
+                contents: SubpassContents,
             ),
-            takes_handle: true,
+            takes_handle: false,
+            provided_by: [
+                [VK_VERSION_1_0] => CmdBeginRenderPass,
+            ],
+            hooks: [
+                command_buffer: handle,
+                p_render_pass_begin: structs,
+                contents: value,
+            ],
         },
         {
-            name: pfn_get_physical_device_surface_support_khr,
-            method_name: get_physical_device_surface_support_khr,
-            ptr: GetPhysicalDeviceSurfaceSupportKHR,
+            name: pfn_cmd_next_subpass,
+            method_name: cmd_next_subpass,
+            try_method_name: try_cmd_next_subpass,
+            unchecked_method_name: cmd_next_subpass_unchecked,
+            ptr: CmdNextSubpass,
             signature: (
-                physical_device: PhysicalDevice,
-                queue_family_index: u32,
-                surface: SurfaceKHR,
-                p_supported: *mut Bool32,
-            ) -> Result,
+                command_buffer: CommandBuffer,
+                contents: SubpassContents,
+            ),
             takes_handle: false,
+            provided_by: [
+                [VK_VERSION_1_0] => CmdNextSubpass,
+            ],
+            hooks: [
+                command_buffer: handle,
+                contents: value,
+            ],
         },
         {
-            name: pfn_get_physical_device_surface_capabilities_khr,
-            method_name: get_physical_device_surface_capabilities_khr,
-            ptr: GetPhysicalDeviceSurfaceCapabilitiesKHR,
+            name: pfn_cmd_end_render_pass,
+            method_name: cmd_end_render_pass,
+            try_method_name: try_cmd_end_render_pass,
+            unchecked_method_name: cmd_end_render_pass_unchecked,
+            ptr: CmdEndRenderPass,
             signature: (
-                physical_device: PhysicalDevice,
-                surface: SurfaceKHR,
-                p_surface_capabilities: *mut SurfaceCapabilitiesKHR,
-            ) -> Result,
+                command_buffer: CommandBuffer,
+            ),
             takes_handle: false,
+            provided_by: [
+                [VK_VERSION_1_0] => CmdEndRenderPass,
+            ],
+            hooks: [
+                command_buffer: handle,
+            ],
         },
         {
-            name: pfn_get_physical_device_surface_formats_khr,
-            method_name: get_physical_device_surface_formats_khr,
-            ptr: GetPhysicalDeviceSurfaceFormatsKHR,
+            name: pfn_cmd_execute_commands,
+            method_name: cmd_execute_commands,
+            try_method_name: try_cmd_execute_commands,
+            unchecked_method_name: cmd_execute_commands_unchecked,
+            ptr: CmdExecuteCommands,
             signature: (
-                physical_device: PhysicalDevice,
-                surface: SurfaceKHR,
-                p_surface_format_count: *mut u32,
-                p_surface_formats: *mut SurfaceFormatKHR,
-            ) -> Result,
+                command_buffer: CommandBuffer,
+                command_buffer_count: u32,
+                p_command_buffers: *const CommandBuffer,
+            ),
             takes_handle: false,
+            provided_by: [
+                [VK_VERSION_1_0] => CmdExecuteCommands,
+            ],
+            hooks: [
+                command_buffer: handle,
+                command_buffer_count: value,
+                p_command_buffers: handles[command_buffer_count],
+            ],
         },
         {
-            name: pfn_get_physical_device_surface_present_modes_khr,
-            method_name: get_physical_device_surface_present_modes_khr,
-            ptr: GetPhysicalDeviceSurfacePresentModesKHR,
+            name: pfn_create_shared_swapchains_khr,
+            method_name: create_shared_swapchains_khr,
+            try_method_name: try_create_shared_swapchains_khr,
+            unchecked_method_name: create_shared_swapchains_khr_unchecked,
+            ptr: CreateSharedSwapchainsKHR,
             signature: (
-                physical_device: PhysicalDevice,
-                surface: SurfaceKHR,
-                p_present_mode_count: *mut u32,
-                p_present_modes: *mut PresentModeKHR,
+                swapchain_count: u32,
+                p_create_infos: *const SwapchainCreateInfoKHR,
+                p_allocator: *const AllocationCallbacks,
+                p_swapchains: *mut SwapchainKHR,
             ) -> Result,
-            takes_handle: false,
+            takes_handle: true,
+            provided_by: [
+                [VK_KHR_display_swapchain] => CreateSharedSwapchainsKHR,
+            ],
+            hooks: [
+                swapchain_count: value,
+                p_create_infos: structs[swapchain_count],
+                p_allocator: structs,
+                p_swapchains: handles[swapchain_count],
+            ],
+            checked: {
+                method_name: create_shared_swapchains_khr_checked,
+                args: (
+                    swapchain_count: u32,
+                    p_create_infos: *const SwapchainCreateInfoKHR,
+                    p_allocator: Option<&AllocationCallbacks>,
+                ),
+                output: p_swapchains: Vec<SwapchainKHR>,
+                len: swapchain_count,
+                result: success,
+            },
         },
         {
-            name: pfn_create_vi_surface_nn,
-            method_name: create_vi_surface_nn,
-            ptr: CreateViSurfaceNN,
+            name: pfn_create_swapchain_khr,
+            method_name: create_swapchain_khr,
+            try_method_name: try_create_swapchain_khr,
+            unchecked_method_name: create_swapchain_khr_unchecked,
+            ptr: CreateSwapchainKHR,
             signature: (
-                p_create_info: *const ViSurfaceCreateInfoNN,
+                p_create_info: *const SwapchainCreateInfoKHR,
                 p_allocator: *const AllocationCallbacks,
-                p_surface: *mut SurfaceKHR,
+                p_swapchain: *mut SwapchainKHR,
             ) -> Result,
             takes_handle: true,
+            provided_by: [
+                [VK_KHR_swapchain] => CreateSwapchainKHR,
+            ],
+            hooks: [
+                p_create_info: structs,
+                p_allocator: structs,
+                p_swapchain: handles,
+            ],
+            checked: {
+                method_name: create_swapchain_khr_checked,
+                args: (
+                    p_create_info: *const SwapchainCreateInfoKHR,
+                    p_allocator: Option<&AllocationCallbacks>,
+                ),
+                output: p_swapchain: SwapchainKHR,
+                result: success,
+            },
         },
         {
-            name: pfn_create_wayland_surface_khr,
-            method_name: create_wayland_surface_khr,
-            ptr: CreateWaylandSurfaceKHR,
+            name: pfn_destroy_swapchain_khr,
+            method_name: destroy_swapchain_khr,
+            try_method_name: try_destroy_swapchain_khr,
+            unchecked_method_name: destroy_swapchain_khr_unchecked,
+            ptr: DestroySwapchainKHR,
             signature: (
-                p_create_info: *const WaylandSurfaceCreateInfoKHR,
+                swapchain: SwapchainKHR,
                 p_allocator: *const AllocationCallbacks,
-                p_surface: *mut SurfaceKHR,
-            ) -> Result,
+            ),
             takes_handle: true,
+            provided_by: [
+                [VK_KHR_swapchain] => DestroySwapchainKHR,
+            ],
+            hooks: [
+                swapchain: handle,
+                p_allocator: structs,
+            ],
         },
         {
-            name: pfn_get_physical_device_wayland_presentation_support_khr,
-            method_name: get_physical_device_wayland_presentation_support_khr,
-            ptr: GetPhysicalDeviceWaylandPresentationSupportKHR,
+            name: pfn_get_swapchain_images_khr,
+            method_name: get_swapchain_images_khr,
+            try_method_name: try_get_swapchain_images_khr,
+            unchecked_method_name: get_swapchain_images_khr_unchecked,
+            ptr: GetSwapchainImagesKHR,
             signature: (
-                physical_device: PhysicalDevice,
-                queue_family_index: u32,
-                display: *mut wl_display,
-            ) -> Bool32,
-            takes_handle: false,
+                swapchain: SwapchainKHR,
+                p_swapchain_image_count: *mut u32,
+                p_swapchain_images: *mut Image,
+            ) -> Result,
+            takes_handle: true,
+            provided_by: [
+                [VK_KHR_swapchain] => GetSwapchainImagesKHR,
+            ],
+            hooks: [
+                swapchain: handle,
+                p_swapchain_image_count: ptr,
+                p_swapchain_images: handles[*p_swapchain_image_count],
+            ],
+            vec: {
+                method_name: get_swapchain_images_khr_vec,
+                args: (
+                    swapchain: SwapchainKHR,
+                ),
+                element: Image,
+                result: success,
+            },
         },
         {
-            name: pfn_create_win_32_surface_khr,
-            method_name: create_win_32_surface_khr,
-            ptr: CreateWin32SurfaceKHR,
+            name: pfn_acquire_next_image_khr,
+            method_name: acquire_next_image_khr,
+            try_method_name: try_acquire_next_image_khr,
+            unchecked_method_name: acquire_next_image_khr_unchecked,
+            ptr: AcquireNextImageKHR,
             signature: (
-                p_create_info: *const Win32SurfaceCreateInfoKHR,
-                p_allocator: *const AllocationCallbacks,
-                p_surface: *mut SurfaceKHR,
+                swapchain: SwapchainKHR,
+                timeout: u64,
+                semaphore: Semaphore,
+                fence: Fence,
+                p_image_index: *mut u32,
             ) -> Result,
             takes_handle: true,
+            provided_by: [
+                [VK_KHR_swapchain] => AcquireNextImageKHR,
+            ],
+            hooks: [
+                swapchain: handle,
+                timeout: value,
+                semaphore: handle,
+                fence: handle,
+                p_image_index: ptr,
+            ],
+            checked: {
+                method_name: acquire_next_image_khr_checked,
+                args: (
+                    swapchain: SwapchainKHR,
+                    timeout: u64,
+                    semaphore: Semaphore,
+                    fence: Fence,
+                ),
+                output: p_image_index: u32,
+                result: status,
+            },
         },
         {
-            name: pfn_get_physical_device_win_32_presentation_support_khr,
-            method_name: get_physical_device_win_32_presentation_support_khr,
-            ptr: GetPhysicalDeviceWin32PresentationSupportKHR,
+            name: pfn_queue_present_khr,
+            method_name: queue_present_khr,
+            try_method_name: try_queue_present_khr,
+            unchecked_method_name: queue_present_khr_unchecked,
+            ptr: QueuePresentKHR,
             signature: (
-                physical_device: PhysicalDevice,
-                queue_family_index: u32,
-            ) -> Bool32,
+                queue: Queue,
+                p_present_info: *const PresentInfoKHR,
+            ) -> Result,
             takes_handle: false,
+            provided_by: [
+                [VK_KHR_swapchain] => QueuePresentKHR,
+            ],
+            hooks: [
+                queue: handle,
+                p_present_info: structs,
+            ],
         },
         {
-            name: pfn_create_xlib_surface_khr,
-            method_name: create_xlib_surface_khr,
-            ptr: CreateXlibSurfaceKHR,
+            name: pfn_debug_marker_set_object_name_ext,
+            method_name: debug_marker_set_object_name_ext,
+            try_method_name: try_debug_marker_set_object_name_ext,
+            unchecked_method_name: debug_marker_set_object_name_ext_unchecked,
+            ptr: DebugMarkerSetObjectNameEXT,
             signature: (
-                p_create_info: *const XlibSurfaceCreateInfoKHR,
-                p_allocator: *const AllocationCallbacks,
-                p_surface: *mut SurfaceKHR,
+                p_name_info: *const DebugMarkerObjectNameInfoEXT,
             ) -> Result,
             takes_handle: true,
+            provided_by: [
+                [VK_EXT_debug_marker] => DebugMarkerSetObjectNameEXT,
+            ],
+            hooks: [
+                p_name_info: structs,
+            ],
         },
         {
-            name: pfn_get_physical_device_xlib_presentation_support_khr,
-            method_name: get_physical_device_xlib_presentation_support_khr,
-            ptr: GetPhysicalDeviceXlibPresentationSupportKHR,
-            signature: (
-                physical_device: PhysicalDevice,
-                queue_family_index: u32,
-                dpy: *mut Display,
-                visual_id: VisualID,
-            ) -> Bool32,
-            takes_handle: false,
-        },
-        {
-            name: pfn_create_xcb_surface_khr,
-            method_name: create_xcb_surface_khr,
-            ptr: CreateXcbSurfaceKHR,
+            name: pfn_debug_marker_set_object_tag_ext,
+            method_name: debug_marker_set_object_tag_ext,
+            try_method_name: try_debug_marker_set_object_tag_ext,
+            unchecked_method_name: debug_marker_set_object_tag_ext_unchecked,
+            ptr: DebugMarkerSetObjectTagEXT,
             signature: (
-                p_create_info: *const XcbSurfaceCreateInfoKHR,
-                p_allocator: *const AllocationCallbacks,
-                p_surface: *mut SurfaceKHR,
+                p_tag_info: *const DebugMarkerObjectTagInfoEXT,
             ) -> Result,
             takes_handle: true,
+            provided_by: [
+                [VK_EXT_debug_marker] => DebugMarkerSetObjectTagEXT,
+            ],
+            hooks: [
+                p_tag_info: structs,
+            ],
         },
         {
-            name: pfn_get_physical_device_xcb_presentation_support_khr,
-            method_name: get_physical_device_xcb_presentation_support_khr,
-            ptr: GetPhysicalDeviceXcbPresentationSupportKHR,
+            name: pfn_cmd_debug_marker_begin_ext,
+            method_name: cmd_debug_marker_begin_ext,
+            try_method_name: try_cmd_debug_marker_begin_ext,
+            unchecked_method_name: cmd_debug_marker_begin_ext_unchecked,
+            ptr: CmdDebugMarkerBeginEXT,
             signature: (
-                physical_device: PhysicalDevice,
-                queue_family_index: u32,
-                connection: *mut xcb_connection_t,
-                visual_id: xcb_visualid_t,
-            ) -> Bool32,
+                command_buffer: CommandBuffer,
+                p_marker_info: *const DebugMarkerMarkerInfoEXT,
+            ),
             takes_handle: false,
+            provided_by: [
+                [VK_EXT_debug_marker] => CmdDebugMarkerBeginEXT,
+            ],
+            hooks: [
+                command_buffer: handle,
+                p_marker_info: structs,
+            ],
         },
         {
-            name: pfn_create_direct_fb_surface_ext,
-            method_name: create_direct_fb_surface_ext,
-            ptr: CreateDirectFBSurfaceEXT,
+            name: pfn_cmd_debug_marker_end_ext,
+            method_name: cmd_debug_marker_end_ext,
+            try_method_name: try_cmd_debug_marker_end_ext,
+            unchecked_method_name: cmd_debug_marker_end_ext_unchecked,
+            ptr: CmdDebugMarkerEndEXT,
             signature: (
-                p_create_info: *const DirectFBSurfaceCreateInfoEXT,
-                p_allocator: *const AllocationCallbacks,
-                p_surface: *mut SurfaceKHR,
-            ) -> Result,
-            takes_handle: true,
+                command_buffer: CommandBuffer,
+            ),
+            takes_handle: false,
+            provided_by: [
+                [VK_EXT_debug_marker] => CmdDebugMarkerEndEXT,
+            ],
+            hooks: [
+                command_buffer: handle,
+            ],
         },
         {
-            name: pfn_get_physical_device_direct_fb_presentation_support_ext,
-            method_name: get_physical_device_direct_fb_presentation_support_ext,
-            ptr: GetPhysicalDeviceDirectFBPresentationSupportEXT,
+            name: pfn_cmd_debug_marker_insert_ext,
+            method_name: cmd_debug_marker_insert_ext,
+            try_method_name: try_cmd_debug_marker_insert_ext,
+            unchecked_method_name: cmd_debug_marker_insert_ext_unchecked,
+            ptr: CmdDebugMarkerInsertEXT,
             signature: (
-                physical_device: PhysicalDevice,
-                queue_family_index: u32,
-                dfb: *mut IDirectFB,
-            ) -> Bool32,
+                command_buffer: CommandBuffer,
+                p_marker_info: *const DebugMarkerMarkerInfoEXT,
+            ),
             takes_handle: false,
+            provided_by: [
+                [VK_EXT_debug_marker] => CmdDebugMarkerInsertEXT,
+            ],
+            hooks: [
+                command_buffer: handle,
+                p_marker_info: structs,
+            ],
         },
         {
-            name: pfn_create_image_pipe_surface_fuchsia,
-            method_name: create_image_pipe_surface_fuchsia,
-            ptr: CreateImagePipeSurfaceFUCHSIA,
+            name: pfn_get_memory_win_32_handle_nv,
+            method_name: get_memory_win_32_handle_nv,
+            try_method_name: try_get_memory_win_32_handle_nv,
+            unchecked_method_name: get_memory_win_32_handle_nv_unchecked,
+            ptr: GetMemoryWin32HandleNV,
             signature: (
-                p_create_info: *const ImagePipeSurfaceCreateInfoFUCHSIA,
-                p_allocator: *const AllocationCallbacks,
-                p_surface: *mut SurfaceKHR,
+                memory: DeviceMemory,
+                handle_type: ExternalMemoryHandleTypeFlagsNV,
+                p_handle: *mut HANDLE,
             ) -> Result,
             takes_handle: true,
+            provided_by: [
+                [VK_NV_external_memory_win32] => GetMemoryWin32HandleNV,
+            ],
+            hooks: [
+                memory: handle,
+                handle_type: value,
+                p_handle: ptr,
+            ],
+            checked: {
+                method_name: get_memory_win_32_handle_nv_checked,
+                args: (
+                    memory: DeviceMemory,
+                    handle_type: ExternalMemoryHandleTypeFlagsNV,
+                ),
+                output: p_handle: HANDLE,
+                result: success,
+            },
         },
         {
-            name: pfn_create_stream_descriptor_surface_ggp,
-            method_name: create_stream_descriptor_surface_ggp,
-            ptr: CreateStreamDescriptorSurfaceGGP,
+            name: pfn_cmd_execute_generated_commands_nv,
+            method_name: cmd_execute_generated_commands_nv,
+            try_method_name: try_cmd_execute_generated_commands_nv,
+            unchecked_method_name: cmd_execute_generated_commands_nv_unchecked,
+            ptr: CmdExecuteGeneratedCommandsNV,
             signature: (
-                p_create_info: *const StreamDescriptorSurfaceCreateInfoGGP,
-                p_allocator: *const AllocationCallbacks,
-                p_surface: *mut SurfaceKHR,
-            ) -> Result,
-            takes_handle: true,
+                command_buffer: CommandBuffer,
+                is_preprocessed: Bool32,
+                p_generated_commands_info: *const GeneratedCommandsInfoNV,
+            ),
+            takes_handle: false,
+            provided_by: [
+                [VK_NV_device_generated_commands] => CmdExecuteGeneratedCommandsNV,
+            ],
+            hooks: [
+                command_buffer: handle,
+                is_preprocessed: value,
+                p_generated_commands_info: structs,
+            ],
         },
         {
-            name: pfn_create_screen_surface_qnx,
-            method_name: create_screen_surface_qnx,
-            ptr: CreateScreenSurfaceQNX,
+            name: pfn_cmd_preprocess_generated_commands_nv,
+            method_name: cmd_preprocess_generated_commands_nv,
+            try_method_name: try_cmd_preprocess_generated_commands_nv,
+            unchecked_method_name: cmd_preprocess_generated_commands_nv_unchecked,
+            ptr: CmdPreprocessGeneratedCommandsNV,
             signature: (
-                p_create_info: *const ScreenSurfaceCreateInfoQNX,
-                p_allocator: *const AllocationCallbacks,
-                p_surface: *mut SurfaceKHR,
-            ) -> Result,
-            takes_handle: true,
+                command_buffer: CommandBuffer,
+                p_generated_commands_info: *const GeneratedCommandsInfoNV,
+            ),
+            takes_handle: false,
+            provided_by: [
+                [VK_NV_device_generated_commands] => CmdPreprocessGeneratedCommandsNV,
+            ],
+            hooks: [
+                command_buffer: handle,
+                p_generated_commands_info: structs,
+            ],
         },
         {
-            name: pfn_get_physical_device_screen_presentation_support_qnx,
-            method_name: get_physical_device_screen_presentation_support_qnx,
-            ptr: GetPhysicalDeviceScreenPresentationSupportQNX,
+            name: pfn_cmd_bind_pipeline_shader_group_nv,
+            method_name: cmd_bind_pipeline_shader_group_nv,
+            try_method_name: try_cmd_bind_pipeline_shader_group_nv,
+            unchecked_method_name: cmd_bind_pipeline_shader_group_nv_unchecked,
+            ptr: CmdBindPipelineShaderGroupNV,
             signature: (
-                physical_device: PhysicalDevice,
-                queue_family_index: u32,
-                window: *mut _screen_window,
-            ) -> Bool32,
+                command_buffer: CommandBuffer,
+                pipeline_bind_point: PipelineBindPoint,
+                pipeline: Pipeline,
+                group_index: u32,
+            ),
             takes_handle: false,
+            provided_by: [
+                [VK_NV_device_generated_commands] => CmdBindPipelineShaderGroupNV,
+            ],
+            hooks: [
+                command_buffer: handle,
+                pipeline_bind_point: value,
+                pipeline: handle,
+                group_index: value,
+            ],
         },
         {
-            name: pfn_create_debug_report_callback_ext,
-            method_name: create_debug_report_callback_ext,
-            ptr: CreateDebugReportCallbackEXT,
+            name: pfn_get_generated_commands_memory_requirements_nv,
+            method_name: get_generated_commands_memory_requirements_nv,
+            try_method_name: try_get_generated_commands_memory_requirements_nv,
+            unchecked_method_name: get_generated_commands_memory_requirements_nv_unchecked,
+            ptr: GetGeneratedCommandsMemoryRequirementsNV,
             signature: (
-                p_create_info: *const DebugReportCallbackCreateInfoEXT,
-                p_allocator: *const AllocationCallbacks,
-                p_callback: *mut DebugReportCallbackEXT,
-            ) -> Result,
+                p_info: *const GeneratedCommandsMemoryRequirementsInfoNV,
+                p_memory_requirements: *mut MemoryRequirements2,
+            ),
             takes_handle: true,
+            provided_by: [
+                [VK_NV_device_generated_commands] => GetGeneratedCommandsMemoryRequirementsNV,
+            ],
+            hooks: [
+                p_info: structs,
+                p_memory_requirements: structs,
+            ],
+            checked: {
+                method_name: get_generated_commands_memory_requirements_nv_checked,
+                args: (
+                    p_info: *const GeneratedCommandsMemoryRequirementsInfoNV,
+                ),
+                output: p_memory_requirements: MemoryRequirements2,
+                result: void,
+            },
         },
         {
-            name: pfn_destroy_debug_report_callback_ext,
-            method_name: destroy_debug_report_callback_ext,
-            ptr: DestroyDebugReportCallbackEXT,
+            name: pfn_create_indirect_commands_layout_nv,
+            method_name: create_indirect_commands_layout_nv,
+            try_method_name: try_create_indirect_commands_layout_nv,
+            unchecked_method_name: create_indirect_commands_layout_nv_unchecked,
+            ptr: CreateIndirectCommandsLayoutNV,
             signature: (
-                callback: DebugReportCallbackEXT,
+                p_create_info: *const IndirectCommandsLayoutCreateInfoNV,
                 p_allocator: *const AllocationCallbacks,
-            ),
+                p_indirect_commands_layout: *mut IndirectCommandsLayoutNV,
+            ) -> Result,
             takes_handle: true,
+            provided_by: [
+                [VK_NV_device_generated_commands] => CreateIndirectCommandsLayoutNV,
+            ],
+            hooks: [
+                p_create_info: structs,
+                p_allocator: structs,
+                p_indirect_commands_layout: handles,
+            ],
+            checked: {
+                method_name: create_indirect_commands_layout_nv_checked,
+                args: (
+                    p_create_info: *const IndirectCommandsLayoutCreateInfoNV,
+                    p_allocator: Option<&AllocationCallbacks>,
+                ),
+                output: p_indirect_commands_layout: IndirectCommandsLayoutNV,
+                result: success,
+            },
         },
         {
-            name: pfn_debug_report_message_ext,
-            method_name: debug_report_message_ext,
-            ptr: DebugReportMessageEXT,
+            name: pfn_destroy_indirect_commands_layout_nv,
+            method_name: destroy_indirect_commands_layout_nv,
+            try_method_name: try_destroy_indirect_commands_layout_nv,
+            unchecked_method_name: destroy_indirect_commands_layout_nv_unchecked,
+            ptr: DestroyIndirectCommandsLayoutNV,
             signature: (
-                flags: DebugReportFlagsEXT,
-                object_type: DebugReportObjectTypeEXT,
-                object: u64,
-                location: usize,
-                message_code: i32,
-                p_layer_prefix: *const c_char,
-                p_message: *const c_char,
+                indirect_commands_layout: IndirectCommandsLayoutNV,
+                p_allocator: *const AllocationCallbacks,
             ),
             takes_handle: true,
+            provided_by: [
+                [VK_NV_device_generated_commands] => DestroyIndirectCommandsLayoutNV,
+            ],
+            hooks: [
+                indirect_commands_layout: handle,
+                p_allocator: structs,
+            ],
         },
         {
-            name: pfn_get_physical_device_external_image_format_properties_nv,
-            method_name: get_physical_device_external_image_format_properties_nv,
-            ptr: GetPhysicalDeviceExternalImageFormatPropertiesNV,
+            name: pfn_cmd_push_descriptor_set_khr,
+            method_name: cmd_push_descriptor_set_khr,
+            try_method_name: try_cmd_push_descriptor_set_khr,
+            unchecked_method_name: cmd_push_descriptor_set_khr_unchecked,
+            ptr: CmdPushDescriptorSetKHR,
             signature: (
-                physical_device: PhysicalDevice,
-                format: Format,
-                ty: ImageType,
-                tiling: ImageTiling,
-                usage: ImageUsageFlags,
-                flags: ImageCreateFlags,
-                external_handle_type: ExternalMemoryHandleTypeFlagsNV,
-                p_external_image_format_properties: *mut ExternalImageFormatPropertiesNV,
-            ) -> Result,
+                command_buffer: CommandBuffer,
+                pipeline_bind_point: PipelineBindPoint,
+                layout: PipelineLayout,
+                set: u32,
+                descriptor_write_count: u32,
+                p_descriptor_writes: *const WriteDescriptorSet,
+            ),
             takes_handle: false,
+            provided_by: [
+                [VK_KHR_push_descriptor] => CmdPushDescriptorSetKHR,
+            ],
+            hooks: [
+                command_buffer: handle,
+                pipeline_bind_point: value,
+                layout: handle,
+                set: value,
+                descriptor_write_count: value,
+                p_descriptor_writes: structs[descriptor_write_count],
+            ],
         },
         {
-            name: pfn_get_physical_device_features_2,
-            method_name: get_physical_device_features_2,
-            ptr: GetPhysicalDeviceFeatures2,
+            name: pfn_trim_command_pool,
+            method_name: trim_command_pool,
+            try_method_name: try_trim_command_pool,
+            unchecked_method_name: trim_command_pool_unchecked,
+            ptr: TrimCommandPool,
+            aliases: [TrimCommandPoolKHR],
             signature: (
-                physical_device: PhysicalDevice,
-                p_features: *mut PhysicalDeviceFeatures2,
+                command_pool: CommandPool,
+                flags: CommandPoolTrimFlags,
             ),
-            takes_handle: false,
+            takes_handle: true,
+            provided_by: [
+                [VK_VERSION_1_1] => TrimCommandPool,
+                [VK_KHR_maintenance1] => TrimCommandPoolKHR,
+            ],
+            hooks: [
+                command_pool: handle,
+                flags: value,
+            ],
         },
         {
-            name: pfn_get_physical_device_properties_2,
-            method_name: get_physical_device_properties_2,
-            ptr: GetPhysicalDeviceProperties2,
+            name: pfn_get_memory_win_32_handle_khr,
+            method_name: get_memory_win_32_handle_khr,
+            try_method_name: try_get_memory_win_32_handle_khr,
+            unchecked_method_name: get_memory_win_32_handle_khr_unchecked,
+            ptr: GetMemoryWin32HandleKHR,
             signature: (
-                physical_device: PhysicalDevice,
-                p_properties: *mut PhysicalDeviceProperties2,
-            ),
-            takes_handle: false,
+                p_get_win_32_handle_info: *const MemoryGetWin32HandleInfoKHR,
+                p_handle: *mut HANDLE,
+            ) -> Result,
+            takes_handle: true,
+            provided_by: [
+                [VK_KHR_external_memory_win32] => GetMemoryWin32HandleKHR,
+            ],
+            hooks: [
+                p_get_win_32_handle_info: structs,
+                p_handle: ptr,
+            ],
+            checked: {
+                method_name: get_memory_win_32_handle_khr_checked,
+                args: (
+                    p_get_win_32_handle_info: *const MemoryGetWin32HandleInfoKHR,
+                ),
+                output: p_handle: HANDLE,
+                result: success,
+            },
         },
         {
-            name: pfn_get_physical_device_format_properties_2,
-            method_name: get_physical_device_format_properties_2,
-            ptr: GetPhysicalDeviceFormatProperties2,
+            name: pfn_get_memory_win_32_handle_properties_khr,
+            method_name: get_memory_win_32_handle_properties_khr,
+            try_method_name: try_get_memory_win_32_handle_properties_khr,
+            unchecked_method_name: get_memory_win_32_handle_properties_khr_unchecked,
+            ptr: GetMemoryWin32HandlePropertiesKHR,
+            signature: (
+                handle_type: ExternalMemoryHandleTypeFlagBits,
+                handle: HANDLE,
+                p_memory_win_32_handle_properties: *mut MemoryWin32HandlePropertiesKHR,
+            ) -> Result,
+            takes_handle: true,
+            provided_by: [
+                [VK_KHR_external_memory_win32] => GetMemoryWin32HandlePropertiesKHR,
+            ],
+            hooks: [
+                handle_type: value,
+                handle: value,
+                p_memory_win_32_handle_properties: structs,
+            ],
+            checked: {
+                method_name: get_memory_win_32_handle_properties_khr_checked,
+                args: (
+                    handle_type: ExternalMemoryHandleTypeFlagBits,
+                    handle: HANDLE,
+                ),
+                output: p_memory_win_32_handle_properties: MemoryWin32HandlePropertiesKHR,
+                result: success,
+            },
+        },
+        {
+            name: pfn_get_memory_fd_khr,
+            method_name: get_memory_fd_khr,
+            try_method_name: try_get_memory_fd_khr,
+            unchecked_method_name: get_memory_fd_khr_unchecked,
+            ptr: GetMemoryFdKHR,
             signature: (
-                physical_device: PhysicalDevice,
-                format: Format,
-                p_format_properties: *mut FormatProperties2,
-            ),
-            takes_handle: false,
+                p_get_fd_info: *const MemoryGetFdInfoKHR,
+                p_fd: *mut c_int,
+            ) -> Result,
+            takes_handle: true,
+            provided_by: [
+                [VK_KHR_external_memory_fd] => GetMemoryFdKHR,
+            ],
+            hooks: [
+                p_get_fd_info: structs,
+                p_fd: ptr,
+            ],
+            checked: {
+                method_name: get_memory_fd_khr_checked,
+                args: (
+                    p_get_fd_info: *const MemoryGetFdInfoKHR,
+                ),
+                output: p_fd: c_int,
+                result: success,
+            },
         },
         {
-            name: pfn_get_physical_device_image_format_properties_2,
-            method_name: get_physical_device_image_format_properties_2,
-            ptr: GetPhysicalDeviceImageFormatProperties2,
+            name: pfn_get_memory_fd_properties_khr,
+            method_name: get_memory_fd_properties_khr,
+            try_method_name: try_get_memory_fd_properties_khr,
+            unchecked_method_name: get_memory_fd_properties_khr_unchecked,
+            ptr: GetMemoryFdPropertiesKHR,
             signature: (
-                physical_device: PhysicalDevice,
-                p_image_format_info: *const PhysicalDeviceImageFormatInfo2,
-                p_image_format_properties: *mut ImageFormatProperties2,
+                handle_type: ExternalMemoryHandleTypeFlagBits,
+                fd: c_int,
+                p_memory_fd_properties: *mut MemoryFdPropertiesKHR,
             ) -> Result,
-            takes_handle: false,
+            takes_handle: true,
+            provided_by: [
+                [VK_KHR_external_memory_fd] => GetMemoryFdPropertiesKHR,
+            ],
+            hooks: [
+                handle_type: value,
+                fd: value,
+                p_memory_fd_properties: structs,
+            ],
+            checked: {
+                method_name: get_memory_fd_properties_khr_checked,
+                args: (
+                    handle_type: ExternalMemoryHandleTypeFlagBits,
+                    fd: c_int,
+                ),
+                output: p_memory_fd_properties: MemoryFdPropertiesKHR,
+                result: success,
+            },
         },
         {
-            name: pfn_get_physical_device_queue_family_properties_2,
-            method_name: get_physical_device_queue_family_properties_2,
-            ptr: GetPhysicalDeviceQueueFamilyProperties2,
+            name: pfn_get_memory_zircon_handle_fuchsia,
+            method_name: get_memory_zircon_handle_fuchsia,
+            try_method_name: try_get_memory_zircon_handle_fuchsia,
+            unchecked_method_name: get_memory_zircon_handle_fuchsia_unchecked,
+            ptr: GetMemoryZirconHandleFUCHSIA,
             signature: (
-                physical_device: PhysicalDevice,
-                p_queue_family_property_count: *mut u32,
-                p_queue_family_properties: *mut QueueFamilyProperties2,
-            ),
-            takes_handle: false,
+                p_get_zircon_handle_info: *const MemoryGetZirconHandleInfoFUCHSIA,
+                p_zircon_handle: *mut zx_handle_t,
+            ) -> Result,
+            takes_handle: true,
+            provided_by: [
+                [VK_FUCHSIA_external_memory] => GetMemoryZirconHandleFUCHSIA,
+            ],
+            hooks: [
+                p_get_zircon_handle_info: structs,
+                p_zircon_handle: ptr,
+            ],
+            checked: {
+                method_name: get_memory_zircon_handle_fuchsia_checked,
+                args: (
+                    p_get_zircon_handle_info: *const MemoryGetZirconHandleInfoFUCHSIA,
+                ),
+                output: p_zircon_handle: zx_handle_t,
+                result: success,
+            },
         },
         {
-            name: pfn_get_physical_device_memory_properties_2,
-            method_name: get_physical_device_memory_properties_2,
-            ptr: GetPhysicalDeviceMemoryProperties2,
+            name: pfn_get_memory_zircon_handle_properties_fuchsia,
+            method_name: get_memory_zircon_handle_properties_fuchsia,
+            try_method_name: try_get_memory_zircon_handle_properties_fuchsia,
+            unchecked_method_name: get_memory_zircon_handle_properties_fuchsia_unchecked,
+            ptr: GetMemoryZirconHandlePropertiesFUCHSIA,
             signature: (
-                physical_device: PhysicalDevice,
-                p_memory_properties: *mut PhysicalDeviceMemoryProperties2,
-            ),
-            takes_handle: false,
+                handle_type: ExternalMemoryHandleTypeFlagBits,
+                zircon_handle: zx_handle_t,
+                p_memory_zircon_handle_properties: *mut MemoryZirconHandlePropertiesFUCHSIA,
+            ) -> Result,
+            takes_handle: true,
+            provided_by: [
+                [VK_FUCHSIA_external_memory] => GetMemoryZirconHandlePropertiesFUCHSIA,
+            ],
+            hooks: [
+                handle_type: value,
+                zircon_handle: value,
+                p_memory_zircon_handle_properties: structs,
+            ],
+            checked: {
+                method_name: get_memory_zircon_handle_properties_fuchsia_checked,
+                args: (
+                    handle_type: ExternalMemoryHandleTypeFlagBits,
+                    zircon_handle: zx_handle_t,
+                ),
+                output: p_memory_zircon_handle_properties: MemoryZirconHandlePropertiesFUCHSIA,
+                result: success,
+            },
         },
         {
-            name: pfn_get_physical_device_sparse_image_format_properties_2,
-            method_name: get_physical_device_sparse_image_format_properties_2,
-            ptr: GetPhysicalDeviceSparseImageFormatProperties2,
+            name: pfn_get_memory_remote_address_nv,
+            method_name: get_memory_remote_address_nv,
+            try_method_name: try_get_memory_remote_address_nv,
+            unchecked_method_name: get_memory_remote_address_nv_unchecked,
+            ptr: GetMemoryRemoteAddressNV,
             signature: (
-                physical_device: PhysicalDevice,
-                p_format_info: *const PhysicalDeviceSparseImageFormatInfo2,
-                p_property_count: *mut u32,
-                p_properties: *mut SparseImageFormatProperties2,
-            ),
-            takes_handle: false,
+                p_memory_get_remote_address_info: *const MemoryGetRemoteAddressInfoNV,
+                p_address: *mut RemoteAddressNV,
+            ) -> Result,
+            takes_handle: true,
+            provided_by: [
+                [VK_NV_external_memory_rdma] => GetMemoryRemoteAddressNV,
+            ],
+            hooks: [
+                p_memory_get_remote_address_info: structs,
+                p_address: ptr,
+            ],
         },
         {
-            name: pfn_get_physical_device_external_buffer_properties,
-            method_name: get_physical_device_external_buffer_properties,
-            ptr: GetPhysicalDeviceExternalBufferProperties,
+            name: pfn_get_semaphore_win_32_handle_khr,
+            method_name: get_semaphore_win_32_handle_khr,
+            try_method_name: try_get_semaphore_win_32_handle_khr,
+            unchecked_method_name: get_semaphore_win_32_handle_khr_unchecked,
+            ptr: GetSemaphoreWin32HandleKHR,
             signature: (
-                physical_device: PhysicalDevice,
-                p_external_buffer_info: *const PhysicalDeviceExternalBufferInfo,
-                p_external_buffer_properties: *mut ExternalBufferProperties,
-            ),
-            takes_handle: false,
+                p_get_win_32_handle_info: *const SemaphoreGetWin32HandleInfoKHR,
+                p_handle: *mut HANDLE,
+            ) -> Result,
+            takes_handle: true,
+            provided_by: [
+                [VK_KHR_external_semaphore_win32] => GetSemaphoreWin32HandleKHR,
+            ],
+            hooks: [
+                p_get_win_32_handle_info: structs,
+                p_handle: ptr,
+            ],
+            checked: {
+                method_name: get_semaphore_win_32_handle_khr_checked,
+                args: (
+                    p_get_win_32_handle_info: *const SemaphoreGetWin32HandleInfoKHR,
+                ),
+                output: p_handle: HANDLE,
+                result: success,
+            },
         },
         {
-            name: pfn_get_physical_device_external_semaphore_properties,
-            method_name: get_physical_device_external_semaphore_properties,
-            ptr: GetPhysicalDeviceExternalSemaphoreProperties,
+            name: pfn_import_semaphore_win_32_handle_khr,
+            method_name: import_semaphore_win_32_handle_khr,
+            try_method_name: try_import_semaphore_win_32_handle_khr,
+            unchecked_method_name: import_semaphore_win_32_handle_khr_unchecked,
+            ptr: ImportSemaphoreWin32HandleKHR,
             signature: (
-                physical_device: PhysicalDevice,
-                p_external_semaphore_info: *const PhysicalDeviceExternalSemaphoreInfo,
-                p_external_semaphore_properties: *mut ExternalSemaphoreProperties,
-            ),
-            takes_handle: false,
+                p_import_semaphore_win_32_handle_info: *const ImportSemaphoreWin32HandleInfoKHR,
+            ) -> Result,
+            takes_handle: true,
+            provided_by: [
+                [VK_KHR_external_semaphore_win32] => ImportSemaphoreWin32HandleKHR,
+            ],
+            hooks: [
+                p_import_semaphore_win_32_handle_info: structs,
+            ],
         },
         {
-            name: pfn_get_physical_device_external_fence_properties,
-            method_name: get_physical_device_external_fence_properties,
-            ptr: GetPhysicalDeviceExternalFenceProperties,
+            name: pfn_get_semaphore_fd_khr,
+            method_name: get_semaphore_fd_khr,
+            try_method_name: try_get_semaphore_fd_khr,
+            unchecked_method_name: get_semaphore_fd_khr_unchecked,
+            ptr: GetSemaphoreFdKHR,
             signature: (
-                physical_device: PhysicalDevice,
-                p_external_fence_info: *const PhysicalDeviceExternalFenceInfo,
-                p_external_fence_properties: *mut ExternalFenceProperties,
-            ),
-            takes_handle: false,
+                p_get_fd_info: *const SemaphoreGetFdInfoKHR,
+                p_fd: *mut c_int,
+            ) -> Result,
+            takes_handle: true,
+            provided_by: [
+                [VK_KHR_external_semaphore_fd] => GetSemaphoreFdKHR,
+            ],
+            hooks: [
+                p_get_fd_info: structs,
+                p_fd: ptr,
+            ],
+            checked: {
+                method_name: get_semaphore_fd_khr_checked,
+                args: (
+                    p_get_fd_info: *const SemaphoreGetFdInfoKHR,
+                ),
+                output: p_fd: c_int,
+                result: success,
+            },
         },
         {
-            name: pfn_release_display_ext,
-            method_name: release_display_ext,
-            ptr: ReleaseDisplayEXT,
+            name: pfn_import_semaphore_fd_khr,
+            method_name: import_semaphore_fd_khr,
+            try_method_name: try_import_semaphore_fd_khr,
+            unchecked_method_name: import_semaphore_fd_khr_unchecked,
+            ptr: ImportSemaphoreFdKHR,
             signature: (
-                physical_device: PhysicalDevice,
-                display: DisplayKHR,
+                p_import_semaphore_fd_info: *const ImportSemaphoreFdInfoKHR,
             ) -> Result,
-            takes_handle: false,
+            takes_handle: true,
+            provided_by: [
+                [VK_KHR_external_semaphore_fd] => ImportSemaphoreFdKHR,
+            ],
+            hooks: [
+                p_import_semaphore_fd_info: structs,
+            ],
         },
         {
-            name: pfn_acquire_xlib_display_ext,
-            method_name: acquire_xlib_display_ext,
-            ptr: AcquireXlibDisplayEXT,
+            name: pfn_get_semaphore_zircon_handle_fuchsia,
+            method_name: get_semaphore_zircon_handle_fuchsia,
+            try_method_name: try_get_semaphore_zircon_handle_fuchsia,
+            unchecked_method_name: get_semaphore_zircon_handle_fuchsia_unchecked,
+            ptr: GetSemaphoreZirconHandleFUCHSIA,
             signature: (
-                physical_device: PhysicalDevice,
-                dpy: *mut Display,
-                display: DisplayKHR,
+                p_get_zircon_handle_info: *const SemaphoreGetZirconHandleInfoFUCHSIA,
+                p_zircon_handle: *mut zx_handle_t,
             ) -> Result,
-            takes_handle: false,
+            takes_handle: true,
+            provided_by: [
+                [VK_FUCHSIA_external_semaphore] => GetSemaphoreZirconHandleFUCHSIA,
+            ],
+            hooks: [
+                p_get_zircon_handle_info: structs,
+                p_zircon_handle: ptr,
+            ],
+            checked: {
+                method_name: get_semaphore_zircon_handle_fuchsia_checked,
+                args: (
+                    p_get_zircon_handle_info: *const SemaphoreGetZirconHandleInfoFUCHSIA,
+                ),
+                output: p_zircon_handle: zx_handle_t,
+                result: success,
+            },
         },
         {
-            name: pfn_get_rand_r_output_display_ext,
-            method_name: get_rand_r_output_display_ext,
-            ptr: GetRandROutputDisplayEXT,
+            name: pfn_import_semaphore_zircon_handle_fuchsia,
+            method_name: import_semaphore_zircon_handle_fuchsia,
+            try_method_name: try_import_semaphore_zircon_handle_fuchsia,
+            unchecked_method_name: import_semaphore_zircon_handle_fuchsia_unchecked,
+            ptr: ImportSemaphoreZirconHandleFUCHSIA,
             signature: (
-                physical_device: PhysicalDevice,
-                dpy: *mut Display,
-                rr_output: RROutput,
-                p_display: *mut DisplayKHR,
+                p_import_semaphore_zircon_handle_info: *const ImportSemaphoreZirconHandleInfoFUCHSIA,
             ) -> Result,
-            takes_handle: false,
+            takes_handle: true,
+            provided_by: [
+                [VK_FUCHSIA_external_semaphore] => ImportSemaphoreZirconHandleFUCHSIA,
+            ],
+            hooks: [
+                p_import_semaphore_zircon_handle_info: structs,
+            ],
         },
         {
-            name: pfn_acquire_winrt_display_nv,
-            method_name: acquire_winrt_display_nv,
-            ptr: AcquireWinrtDisplayNV,
+            name: pfn_get_fence_win_32_handle_khr,
+            method_name: get_fence_win_32_handle_khr,
+            try_method_name: try_get_fence_win_32_handle_khr,
+            unchecked_method_name: get_fence_win_32_handle_khr_unchecked,
+            ptr: GetFenceWin32HandleKHR,
             signature: (
-                physical_device: PhysicalDevice,
-                display: DisplayKHR,
+                p_get_win_32_handle_info: *const FenceGetWin32HandleInfoKHR,
+                p_handle: *mut HANDLE,
             ) -> Result,
-            takes_handle: false,
+            takes_handle: true,
+            provided_by: [
+                [VK_KHR_external_fence_win32] => GetFenceWin32HandleKHR,
+            ],
+            hooks: [
+                p_get_win_32_handle_info: structs,
+                p_handle: ptr,
+            ],
+            checked: {
+                method_name: get_fence_win_32_handle_khr_checked,
+                args: (
+                    p_get_win_32_handle_info: *const FenceGetWin32HandleInfoKHR,
+                ),
+                output: p_handle: HANDLE,
+                result: success,
+            },
         },
         {
-            name: pfn_get_winrt_display_nv,
-            method_name: get_winrt_display_nv,
-            ptr: GetWinrtDisplayNV,
+            name: pfn_import_fence_win_32_handle_khr,
+            method_name: import_fence_win_32_handle_khr,
+            try_method_name: try_import_fence_win_32_handle_khr,
+            unchecked_method_name: import_fence_win_32_handle_khr_unchecked,
+            ptr: ImportFenceWin32HandleKHR,
             signature: (
-                physical_device: PhysicalDevice,
-                device_relative_id: u32,
-                p_display: *mut DisplayKHR,
+                p_import_fence_win_32_handle_info: *const ImportFenceWin32HandleInfoKHR,
             ) -> Result,
-            takes_handle: false,
+            takes_handle: true,
+            provided_by: [
+                [VK_KHR_external_fence_win32] => ImportFenceWin32HandleKHR,
+            ],
+            hooks: [
+                p_import_fence_win_32_handle_info: structs,
+            ],
         },
         {
-            name: pfn_get_physical_device_surface_capabilities_2_ext,
-            method_name: get_physical_device_surface_capabilities_2_ext,
-            ptr: GetPhysicalDeviceSurfaceCapabilities2EXT,
+            name: pfn_get_fence_fd_khr,
+            method_name: get_fence_fd_khr,
+            try_method_name: try_get_fence_fd_khr,
+            unchecked_method_name: get_fence_fd_khr_unchecked,
+            ptr: GetFenceFdKHR,
             signature: (
-                physical_device: PhysicalDevice,
-                surface: SurfaceKHR,
-                p_surface_capabilities: *mut SurfaceCapabilities2EXT,
+                p_get_fd_info: *const FenceGetFdInfoKHR,
+                p_fd: *mut c_int,
             ) -> Result,
-            takes_handle: false,
+            takes_handle: true,
+            provided_by: [
+                [VK_KHR_external_fence_fd] => GetFenceFdKHR,
+            ],
+            hooks: [
+                p_get_fd_info: structs,
+                p_fd: ptr,
+            ],
+            checked: {
+                method_name: get_fence_fd_khr_checked,
+                args: (
+                    p_get_fd_info: *const FenceGetFdInfoKHR,
+                ),
+                output: p_fd: c_int,
+                result: success,
+            },
         },
         {
-            name: pfn_enumerate_physical_device_groups,
-            method_name: enumerate_physical_device_groups,
-            ptr: EnumeratePhysicalDeviceGroups,
+            name: pfn_import_fence_fd_khr,
+            method_name: import_fence_fd_khr,
+            try_method_name: try_import_fence_fd_khr,
+            unchecked_method_name: import_fence_fd_khr_unchecked,
+            ptr: ImportFenceFdKHR,
             signature: (
-                p_physical_device_group_count: *mut u32,
-                p_physical_device_group_properties: *mut PhysicalDeviceGroupProperties,
+                p_import_fence_fd_info: *const ImportFenceFdInfoKHR,
             ) -> Result,
             takes_handle: true,
+            provided_by: [
+                [VK_KHR_external_fence_fd] => ImportFenceFdKHR,
+            ],
+            hooks: [
+                p_import_fence_fd_info: structs,
+            ],
         },
         {
-            name: pfn_get_physical_device_present_rectangles_khr,
-            method_name: get_physical_device_present_rectangles_khr,
-            ptr: GetPhysicalDevicePresentRectanglesKHR,
+            name: pfn_display_power_control_ext,
+            method_name: display_power_control_ext,
+            try_method_name: try_display_power_control_ext,
+            unchecked_method_name: display_power_control_ext_unchecked,
+            ptr: DisplayPowerControlEXT,
             signature: (
-                physical_device: PhysicalDevice,
-                surface: SurfaceKHR,
-                p_rect_count: *mut u32,
-                p_rects: *mut Rect2D,
+                display: DisplayKHR,
+                p_display_power_info: *const DisplayPowerInfoEXT,
             ) -> Result,
-            takes_handle: false,
+            takes_handle: true,
+            provided_by: [
+                [VK_EXT_display_control] => DisplayPowerControlEXT,
+            ],
+            hooks: [
+                display: handle,
+                p_display_power_info: structs,
+            ],
         },
         {
-            name: pfn_create_ios_surface_mvk,
-            method_name: create_ios_surface_mvk,
-            ptr: CreateIOSSurfaceMVK,
+            name: pfn_register_device_event_ext,
+            method_name: register_device_event_ext,
+            try_method_name: try_register_device_event_ext,
+            unchecked_method_name: register_device_event_ext_unchecked,
+            ptr: RegisterDeviceEventEXT,
             signature: (
-                p_create_info: *const IOSSurfaceCreateInfoMVK,
+                p_device_event_info: *const DeviceEventInfoEXT,
                 p_allocator: *const AllocationCallbacks,
-                p_surface: *mut SurfaceKHR,
+                p_fence: *mut Fence,
             ) -> Result,
             takes_handle: true,
+            provided_by: [
+                [VK_EXT_display_control] => RegisterDeviceEventEXT,
+            ],
+            hooks: [
+                p_device_event_info: structs,
+                p_allocator: structs,
+                p_fence: handles,
+            ],
         },
         {
-            name: pfn_create_mac_os_surface_mvk,
-            method_name: create_mac_os_surface_mvk,
-            ptr: CreateMacOSSurfaceMVK,
+            name: pfn_register_display_event_ext,
+            method_name: register_display_event_ext,
+            try_method_name: try_register_display_event_ext,
+            unchecked_method_name: register_display_event_ext_unchecked,
+            ptr: RegisterDisplayEventEXT,
             signature: (
-                p_create_info: *const MacOSSurfaceCreateInfoMVK,
+                display: DisplayKHR,
+                p_display_event_info: *const DisplayEventInfoEXT,
                 p_allocator: *const AllocationCallbacks,
-                p_surface: *mut SurfaceKHR,
+                p_fence: *mut Fence,
             ) -> Result,
             takes_handle: true,
+            provided_by: [
+                [VK_EXT_display_control] => RegisterDisplayEventEXT,
+            ],
+            hooks: [
+                display: handle,
+                p_display_event_info: structs,
+                p_allocator: structs,
+                p_fence: handles,
+            ],
         },
         {
-            name: pfn_create_metal_surface_ext,
-            method_name: create_metal_surface_ext,
-            ptr: CreateMetalSurfaceEXT,
+            name: pfn_get_swapchain_counter_ext,
+            method_name: get_swapchain_counter_ext,
+            try_method_name: try_get_swapchain_counter_ext,
+            unchecked_method_name: get_swapchain_counter_ext_unchecked,
+            ptr: GetSwapchainCounterEXT,
             signature: (
-                p_create_info: *const MetalSurfaceCreateInfoEXT,
-                p_allocator: *const AllocationCallbacks,
-                p_surface: *mut SurfaceKHR,
+                swapchain: SwapchainKHR,
+                counter: SurfaceCounterFlagBitsEXT,
+                p_counter_value: *mut u64,
             ) -> Result,
             takes_handle: true,
+            provided_by: [
+                [VK_EXT_display_control] => GetSwapchainCounterEXT,
+            ],
+            hooks: [
+                swapchain: handle,
+                counter: value,
+                p_counter_value: ptr,
+            ],
+            checked: {
+                method_name: get_swapchain_counter_ext_checked,
+                args: (
+                    swapchain: SwapchainKHR,
+                    counter: SurfaceCounterFlagBitsEXT,
+                ),
+                output: p_counter_value: u64,
+                result: success,
+            },
         },
         {
-            name: pfn_get_physical_device_multisample_properties_ext,
-            method_name: get_physical_device_multisample_properties_ext,
-            ptr: GetPhysicalDeviceMultisamplePropertiesEXT,
+            name: pfn_get_device_group_peer_memory_features,
+            method_name: get_device_group_peer_memory_features,
+            try_method_name: try_get_device_group_peer_memory_features,
+            unchecked_method_name: get_device_group_peer_memory_features_unchecked,
+            ptr: GetDeviceGroupPeerMemoryFeatures,
+            aliases: [GetDeviceGroupPeerMemoryFeaturesKHR],
             signature: (
-                physical_device: PhysicalDevice,
-                samples: SampleCountFlagBits,
-                p_multisample_properties: *mut MultisamplePropertiesEXT,
+                heap_index: u32,
+                local_device_index: u32,
+                remote_device_index: u32,
+                p_peer_memory_features: *mut PeerMemoryFeatureFlags,
             ),
-            takes_handle: false,
+            takes_handle: true,
+            provided_by: [
+                [VK_VERSION_1_1] => GetDeviceGroupPeerMemoryFeatures,
+                [VK_KHR_device_group] => GetDeviceGroupPeerMemoryFeaturesKHR,
+            ],
+            hooks: [
+                heap_index: value,
+                local_device_index: value,
+                remote_device_index: value,
+                p_peer_memory_features: ptr,
+            ],
+            checked: {
+                method_name: get_device_group_peer_memory_features_checked,
+                args: (
+                    heap_index: u32,
+                    local_device_index: u32,
+                    remote_device_index: u32,
+                ),
+                output: p_peer_memory_features: PeerMemoryFeatureFlags,
+                result: void,
+            },
         },
         {
-            name: pfn_get_physical_device_surface_capabilities_2_khr,
-            method_name: get_physical_device_surface_capabilities_2_khr,
-            ptr: GetPhysicalDeviceSurfaceCapabilities2KHR,
+            name: pfn_bind_buffer_memory_2,
+            method_name: bind_buffer_memory_2,
+            try_method_name: try_bind_buffer_memory_2,
+            unchecked_method_name: bind_buffer_memory_2_unchecked,
+            ptr: BindBufferMemory2,
+            aliases: [BindBufferMemory2KHR],
             signature: (
-                physical_device: PhysicalDevice,
-                p_surface_info: *const PhysicalDeviceSurfaceInfo2KHR,
-                p_surface_capabilities: *mut SurfaceCapabilities2KHR,
+                bind_info_count: u32,
+                p_bind_infos: *const BindBufferMemoryInfo,
             ) -> Result,
-            takes_handle: false,
+            takes_handle: true,
+            provided_by: [
+                [VK_VERSION_1_1] => BindBufferMemory2,
+                [VK_KHR_bind_memory2] => BindBufferMemory2KHR,
+            ],
+            hooks: [
+                bind_info_count: value,
+                p_bind_infos: structs[bind_info_count],
+            ],
         },
         {
-            name: pfn_get_physical_device_surface_formats_2_khr,
-            method_name: get_physical_device_surface_formats_2_khr,
-            ptr: GetPhysicalDeviceSurfaceFormats2KHR,
+            name: pfn_bind_image_memory_2,
+            method_name: bind_image_memory_2,
+            try_method_name: try_bind_image_memory_2,
+            unchecked_method_name: bind_image_memory_2_unchecked,
+            ptr: BindImageMemory2,
+            aliases: [BindImageMemory2KHR],
             signature: (
-                physical_device: PhysicalDevice,
-                p_surface_info: *const PhysicalDeviceSurfaceInfo2KHR,
-                p_surface_format_count: *mut u32,
-                p_surface_formats: *mut SurfaceFormat2KHR,
+                bind_info_count: u32,
+                p_bind_infos: *const BindImageMemoryInfo,
             ) -> Result,
-            takes_handle: false,
+            takes_handle: true,
+            provided_by: [
+                [VK_VERSION_1_1] => BindImageMemory2,
+                [VK_KHR_bind_memory2] => BindImageMemory2KHR,
+            ],
+            hooks: [
+                bind_info_count: value,
+                p_bind_infos: structs[bind_info_count],
+            ],
         },
         {
-            name: pfn_get_physical_device_display_properties_2_khr,
-            method_name: get_physical_device_display_properties_2_khr,
-            ptr: GetPhysicalDeviceDisplayProperties2KHR,
+            name: pfn_cmd_set_device_mask,
+            method_name: cmd_set_device_mask,
+            try_method_name: try_cmd_set_device_mask,
+            unchecked_method_name: cmd_set_device_mask_unchecked,
+            ptr: CmdSetDeviceMask,
+            aliases: [CmdSetDeviceMaskKHR],
             signature: (
-                physical_device: PhysicalDevice,
-                p_property_count: *mut u32,
-                p_properties: *mut DisplayProperties2KHR,
-            ) -> Result,
+                command_buffer: CommandBuffer,
+                device_mask: u32,
+            ),
             takes_handle: false,
+            provided_by: [
+                [VK_VERSION_1_1] => CmdSetDeviceMask,
+                [VK_KHR_device_group] => CmdSetDeviceMaskKHR,
+            ],
+            hooks: [
+                command_buffer: handle,
+                device_mask: value,
+            ],
         },
         {
-            name: pfn_get_physical_device_display_plane_properties_2_khr,
-            method_name: get_physical_device_display_plane_properties_2_khr,
-            ptr: GetPhysicalDeviceDisplayPlaneProperties2KHR,
+            name: pfn_get_device_group_present_capabilities_khr,
+            method_name: get_device_group_present_capabilities_khr,
+            try_method_name: try_get_device_group_present_capabilities_khr,
+            unchecked_method_name: get_device_group_present_capabilities_khr_unchecked,
+            ptr: GetDeviceGroupPresentCapabilitiesKHR,
             signature: (
-                physical_device: PhysicalDevice,
-                p_property_count: *mut u32,
-                p_properties: *mut DisplayPlaneProperties2KHR,
+                p_device_group_present_capabilities: *mut DeviceGroupPresentCapabilitiesKHR,
             ) -> Result,
-            takes_handle: false,
+            takes_handle: true,
+            provided_by: [
+                [VK_KHR_swapchain, VK_VERSION_1_1] => GetDeviceGroupPresentCapabilitiesKHR,
+                [VK_KHR_device_group] => GetDeviceGroupPresentCapabilitiesKHR,
+            ],
+            hooks: [
+                p_device_group_present_capabilities: structs,
+            ],
+            checked: {
+                method_name: get_device_group_present_capabilities_khr_checked,
+                args: (
+                ),
+                output: p_device_group_present_capabilities: DeviceGroupPresentCapabilitiesKHR,
+                result: success,
+            },
         },
         {
-            name: pfn_get_display_mode_properties_2_khr,
-            method_name: get_display_mode_properties_2_khr,
-            ptr: GetDisplayModeProperties2KHR,
+            name: pfn_get_device_group_surface_present_modes_khr,
+            method_name: get_device_group_surface_present_modes_khr,
+            try_method_name: try_get_device_group_surface_present_modes_khr,
+            unchecked_method_name: get_device_group_surface_present_modes_khr_unchecked,
+            ptr: GetDeviceGroupSurfacePresentModesKHR,
             signature: (
-                physical_device: PhysicalDevice,
-                display: DisplayKHR,
-                p_property_count: *mut u32,
-                p_properties: *mut DisplayModeProperties2KHR,
+                surface: SurfaceKHR,
+                p_modes: *mut DeviceGroupPresentModeFlagsKHR,
             ) -> Result,
-            takes_handle: false,
+            takes_handle: true,
+            provided_by: [
+                [VK_KHR_swapchain, VK_VERSION_1_1] => GetDeviceGroupSurfacePresentModesKHR,
+                [VK_KHR_device_group] => GetDeviceGroupSurfacePresentModesKHR,
+            ],
+            hooks: [
+                surface: handle,
+                p_modes: ptr,
+            ],
+            checked: {
+                method_name: get_device_group_surface_present_modes_khr_checked,
+                args: (
+                    surface: SurfaceKHR,
+                ),
+                output: p_modes: DeviceGroupPresentModeFlagsKHR,
+                result: success,
+            },
         },
         {
-            name: pfn_get_display_plane_capabilities_2_khr,
-            method_name: get_display_plane_capabilities_2_khr,
-            ptr: GetDisplayPlaneCapabilities2KHR,
+            name: pfn_acquire_next_image_2_khr,
+            method_name: acquire_next_image_2_khr,
+            try_method_name: try_acquire_next_image_2_khr,
+            unchecked_method_name: acquire_next_image_2_khr_unchecked,
+            ptr: AcquireNextImage2KHR,
             signature: (
-                physical_device: PhysicalDevice,
-                p_display_plane_info: *const DisplayPlaneInfo2KHR,
-                p_capabilities: *mut DisplayPlaneCapabilities2KHR,
+                p_acquire_info: *const AcquireNextImageInfoKHR,
+                p_image_index: *mut u32,
             ) -> Result,
-            takes_handle: false,
+            takes_handle: true,
+            provided_by: [
+                [VK_KHR_swapchain, VK_VERSION_1_1] => AcquireNextImage2KHR,
+                [VK_KHR_device_group, VK_KHR_swapchain] => AcquireNextImage2KHR,
+            ],
+            hooks: [
+                p_acquire_info: structs,
+                p_image_index: ptr,
+            ],
+            checked: {
+                method_name: acquire_next_image_2_khr_checked,
+                args: (
+                    p_acquire_info: *const AcquireNextImageInfoKHR,
+                ),
+                output: p_image_index: u32,
+                result: status,
+            },
         },
         {
-            name: pfn_get_physical_device_calibrateable_time_domains_ext,
-            method_name: get_physical_device_calibrateable_time_domains_ext,
-            ptr: GetPhysicalDeviceCalibrateableTimeDomainsEXT,
+            name: pfn_cmd_dispatch_base,
+            method_name: cmd_dispatch_base,
+            try_method_name: try_cmd_dispatch_base,
+            unchecked_method_name: cmd_dispatch_base_unchecked,
+            ptr: CmdDispatchBase,
+            aliases: [CmdDispatchBaseKHR],
             signature: (
-                physical_device: PhysicalDevice,
-                p_time_domain_count: *mut u32,
-                p_time_domains: *mut TimeDomainEXT,
-            ) -> Result,
+                command_buffer: CommandBuffer,
+                base_group_x: u32,
+                base_group_y: u32,
+                base_group_z: u32,
+                group_count_x: u32,
+                group_count_y: u32,
+                group_count_z: u32,
+            ),
             takes_handle: false,
+            provided_by: [
+                [VK_VERSION_1_1] => CmdDispatchBase,
+                [VK_KHR_device_group] => CmdDispatchBaseKHR,
+            ],
+            hooks: [
+                command_buffer: handle,
+                base_group_x: value,
+                base_group_y: value,
+                base_group_z: value,
+                group_count_x: value,
+                group_count_y: value,
+                group_count_z: value,
+            ],
         },
         {
-            name: pfn_create_debug_utils_messenger_ext,
-            method_name: create_debug_utils_messenger_ext,
-            ptr: CreateDebugUtilsMessengerEXT,
+            name: pfn_create_descriptor_update_template,
+            method_name: create_descriptor_update_template,
+            try_method_name: try_create_descriptor_update_template,
+            unchecked_method_name: create_descriptor_update_template_unchecked,
+            ptr: CreateDescriptorUpdateTemplate,
+            aliases: [CreateDescriptorUpdateTemplateKHR],
             signature: (
-                p_create_info: *const DebugUtilsMessengerCreateInfoEXT,
+                p_create_info: *const DescriptorUpdateTemplateCreateInfo,
                 p_allocator: *const AllocationCallbacks,
-                p_messenger: *mut DebugUtilsMessengerEXT,
+                p_descriptor_update_template: *mut DescriptorUpdateTemplate,
             ) -> Result,
             takes_handle: true,
+            provided_by: [
+                [VK_VERSION_1_1] => CreateDescriptorUpdateTemplate,
+                [VK_KHR_descriptor_update_template] => CreateDescriptorUpdateTemplateKHR,
+            ],
+            hooks: [
+                p_create_info: structs,
+                p_allocator: structs,
+                p_descriptor_update_template: handles,
+            ],
+            checked: {
+                method_name: create_descriptor_update_template_checked,
+                args: (
+                    p_create_info: *const DescriptorUpdateTemplateCreateInfo,
+                    p_allocator: Option<&AllocationCallbacks>,
+                ),
+                output: p_descriptor_update_template: DescriptorUpdateTemplate,
+                result: success,
+            },
         },
         {
-            name: pfn_destroy_debug_utils_messenger_ext,
-            method_name: destroy_debug_utils_messenger_ext,
-            ptr: DestroyDebugUtilsMessengerEXT,
+            name: pfn_destroy_descriptor_update_template,
+            method_name: destroy_descriptor_update_template,
+            try_method_name: try_destroy_descriptor_update_template,
+            unchecked_method_name: destroy_descriptor_update_template_unchecked,
+            ptr: DestroyDescriptorUpdateTemplate,
+            aliases: [DestroyDescriptorUpdateTemplateKHR],
             signature: (
-                messenger: DebugUtilsMessengerEXT,
+                descriptor_update_template: DescriptorUpdateTemplate,
                 p_allocator: *const AllocationCallbacks,
             ),
             takes_handle: true,
+            provided_by: [
+                [VK_VERSION_1_1] => DestroyDescriptorUpdateTemplate,
+                [VK_KHR_descriptor_update_template] => DestroyDescriptorUpdateTemplateKHR,
+            ],
+            hooks: [
+                descriptor_update_template: handle,
+                p_allocator: structs,
+            ],
         },
         {
-            name: pfn_submit_debug_utils_message_ext,
-            method_name: submit_debug_utils_message_ext,
-            ptr: SubmitDebugUtilsMessageEXT,
+            name: pfn_update_descriptor_set_with_template,
+            method_name: update_descriptor_set_with_template,
+            try_method_name: try_update_descriptor_set_with_template,
+            unchecked_method_name: update_descriptor_set_with_template_unchecked,
+            ptr: UpdateDescriptorSetWithTemplate,
+            aliases: [UpdateDescriptorSetWithTemplateKHR],
             signature: (
-                message_severity: DebugUtilsMessageSeverityFlagBitsEXT,
-                message_types: DebugUtilsMessageTypeFlagsEXT,
-                p_callback_data: *const DebugUtilsMessengerCallbackDataEXT,
+                descriptor_set: DescriptorSet,
+                descriptor_update_template: DescriptorUpdateTemplate,
+                p_data: *const c_void,
             ),
             takes_handle: true,
+            provided_by: [
+                [VK_VERSION_1_1] => UpdateDescriptorSetWithTemplate,
+                [VK_KHR_descriptor_update_template] => UpdateDescriptorSetWithTemplateKHR,
+            ],
+            hooks: [
+                descriptor_set: handle,
+                descriptor_update_template: handle,
+                p_data: opaque,
+            ],
         },
         {
-            name: pfn_get_physical_device_cooperative_matrix_properties_nv,
-            method_name: get_physical_device_cooperative_matrix_properties_nv,
-            ptr: GetPhysicalDeviceCooperativeMatrixPropertiesNV,
+            name: pfn_cmd_push_descriptor_set_with_template_khr,
+            method_name: cmd_push_descriptor_set_with_template_khr,
+            try_method_name: try_cmd_push_descriptor_set_with_template_khr,
+            unchecked_method_name: cmd_push_descriptor_set_with_template_khr_unchecked,
+            ptr: CmdPushDescriptorSetWithTemplateKHR,
             signature: (
-                physical_device: PhysicalDevice,
-                p_property_count: *mut u32,
-                p_properties: *mut CooperativeMatrixPropertiesNV,
-            ) -> Result,
+                command_buffer: CommandBuffer,
+                descriptor_update_template: DescriptorUpdateTemplate,
+                layout: PipelineLayout,
+                set: u32,
+                p_data: *const c_void,
+            ),
             takes_handle: false,
+            provided_by: [
+                [VK_KHR_push_descriptor, VK_VERSION_1_1] => CmdPushDescriptorSetWithTemplateKHR,
+                [VK_KHR_push_descriptor, VK_KHR_descriptor_update_template] => CmdPushDescriptorSetWithTemplateKHR,
+                [VK_KHR_descriptor_update_template, VK_KHR_push_descriptor] => CmdPushDescriptorSetWithTemplateKHR,
+            ],
+            hooks: [
+                command_buffer: handle,
+                descriptor_update_template: handle,
+                layout: handle,
+                set: value,
+                p_data: opaque,
+            ],
         },
         {
-            name: pfn_get_physical_device_surface_present_modes_2_ext,
-            method_name: get_physical_device_surface_present_modes_2_ext,
-            ptr: GetPhysicalDeviceSurfacePresentModes2EXT,
+            name: pfn_set_hdr_metadata_ext,
+            method_name: set_hdr_metadata_ext,
+            try_method_name: try_set_hdr_metadata_ext,
+            unchecked_method_name: set_hdr_metadata_ext_unchecked,
+            ptr: SetHdrMetadataEXT,
             signature: (
-                physical_device: PhysicalDevice,
-                p_surface_info: *const PhysicalDeviceSurfaceInfo2KHR,
-                p_present_mode_count: *mut u32,
-                p_present_modes: *mut PresentModeKHR,
+                swapchain_count: u32,
+                p_swapchains: *const SwapchainKHR,
+                p_metadata: *const HdrMetadataEXT,
+            ),
+            takes_handle: true,
+            provided_by: [
+                [VK_EXT_hdr_metadata] => SetHdrMetadataEXT,
+            ],
+            hooks: [
+                swapchain_count: value,
+                p_swapchains: handles[swapchain_count],
+                p_metadata: structs[swapchain_count],
+            ],
+        },
+        {
+            name: pfn_get_swapchain_status_khr,
+            method_name: get_swapchain_status_khr,
+            try_method_name: try_get_swapchain_status_khr,
+            unchecked_method_name: get_swapchain_status_khr_unchecked,
+            ptr: GetSwapchainStatusKHR,
+            signature: (
+                swapchain: SwapchainKHR,
             ) -> Result,
-            takes_handle: false,
+            takes_handle: true,
+            provided_by: [
+                [VK_KHR_shared_presentable_image] => GetSwapchainStatusKHR,
+            ],
+            hooks: [
+                swapchain: handle,
+            ],
         },
         {
-            name: pfn_enumerate_physical_device_queue_family_performance_query_counters_khr,
-            method_name: enumerate_physical_device_queue_family_performance_query_counters_khr,
-            ptr: EnumeratePhysicalDeviceQueueFamilyPerformanceQueryCountersKHR,
+            name: pfn_get_refresh_cycle_duration_google,
+            method_name: get_refresh_cycle_duration_google,
+            try_method_name: try_get_refresh_cycle_duration_google,
+            unchecked_method_name: get_refresh_cycle_duration_google_unchecked,
+            ptr: GetRefreshCycleDurationGOOGLE,
             signature: (
-                physical_device: PhysicalDevice,
-                queue_family_index: u32,
-                p_counter_count: *mut u32,
-                p_counters: *mut PerformanceCounterKHR,
-                p_counter_descriptions: *mut PerformanceCounterDescriptionKHR,
+                swapchain: SwapchainKHR,
+                p_display_timing_properties: *mut RefreshCycleDurationGOOGLE,
+            ) -> Result,
+            takes_handle: true,
+            provided_by: [
+                [VK_GOOGLE_display_timing] => GetRefreshCycleDurationGOOGLE,
+            ],
+            hooks: [
+                swapchain: handle,
+                p_display_timing_properties: ptr,
+            ],
+            checked: {
+                method_name: get_refresh_cycle_duration_google_checked,
+                args: (
+                    swapchain: SwapchainKHR,
+                ),
+                output: p_display_timing_properties: RefreshCycleDurationGOOGLE,
+                result: success,
+            },
+        },
+        {
+            name: pfn_get_past_presentation_timing_google,
+            method_name: get_past_presentation_timing_google,
+            try_method_name: try_get_past_presentation_timing_google,
+            unchecked_method_name: get_past_presentation_timing_google_unchecked,
+            ptr: GetPastPresentationTimingGOOGLE,
+            signature: (
+                swapchain: SwapchainKHR,
+                p_presentation_timing_count: *mut u32,
+                p_presentation_timings: *mut PastPresentationTimingGOOGLE,
             ) -> Result,
+            takes_handle: true,
+            provided_by: [
+                [VK_GOOGLE_display_timing] => GetPastPresentationTimingGOOGLE,
+            ],
+            hooks: [
+                swapchain: handle,
+                p_presentation_timing_count: ptr,
+                p_presentation_timings: ptr[*p_presentation_timing_count],
+            ],
+            vec: {
+                method_name: get_past_presentation_timing_google_vec,
+                args: (
+                    swapchain: SwapchainKHR,
+                ),
+                element: PastPresentationTimingGOOGLE,
+                result: success,
+            },
+        },
+        {
+            name: pfn_cmd_set_viewport_w_scaling_nv,
+            method_name: cmd_set_viewport_w_scaling_nv,
+            try_method_name: try_cmd_set_viewport_w_scaling_nv,
+            unchecked_method_name: cmd_set_viewport_w_scaling_nv_unchecked,
+            ptr: CmdSetViewportWScalingNV,
+            signature: (
+                command_buffer: CommandBuffer,
+                first_viewport: u32,
+                viewport_count: u32,
+                p_viewport_w_scalings: *const ViewportWScalingNV,
+            ),
             takes_handle: false,
+            provided_by: [
+                [VK_NV_clip_space_w_scaling] => CmdSetViewportWScalingNV,
+            ],
+            hooks: [
+                command_buffer: handle,
+                first_viewport: value,
+                viewport_count: value,
+                p_viewport_w_scalings: ptr[viewport_count],
+            ],
         },
         {
-            name: pfn_get_physical_device_queue_family_performance_query_passes_khr,
-            method_name: get_physical_device_queue_family_performance_query_passes_khr,
-            ptr: GetPhysicalDeviceQueueFamilyPerformanceQueryPassesKHR,
+            name: pfn_cmd_set_discard_rectangle_ext,
+            method_name: cmd_set_discard_rectangle_ext,
+            try_method_name: try_cmd_set_discard_rectangle_ext,
+            unchecked_method_name: cmd_set_discard_rectangle_ext_unchecked,
+            ptr: CmdSetDiscardRectangleEXT,
             signature: (
-                physical_device: PhysicalDevice,
-                p_performance_query_create_info: *const QueryPoolPerformanceCreateInfoKHR,
-                p_num_passes: *mut u32,
+                command_buffer: CommandBuffer,
+                first_discard_rectangle: u32,
+                discard_rectangle_count: u32,
+                p_discard_rectangles: *const Rect2D,
             ),
             takes_handle: false,
+            provided_by: [
+                [VK_EXT_discard_rectangles] => CmdSetDiscardRectangleEXT,
+            ],
+            hooks: [
+                command_buffer: handle,
+                first_discard_rectangle: value,
+                discard_rectangle_count: value,
+                p_discard_rectangles: ptr[discard_rectangle_count],
+            ],
         },
         {
-            name: pfn_create_headless_surface_ext,
-            method_name: create_headless_surface_ext,
-            ptr: CreateHeadlessSurfaceEXT,
+            name: pfn_cmd_set_sample_locations_ext,
+            method_name: cmd_set_sample_locations_ext,
+            try_method_name: try_cmd_set_sample_locations_ext,
+            unchecked_method_name: cmd_set_sample_locations_ext_unchecked,
+            ptr: CmdSetSampleLocationsEXT,
             signature: (
-                p_create_info: *const HeadlessSurfaceCreateInfoEXT,
-                p_allocator: *const AllocationCallbacks,
-                p_surface: *mut SurfaceKHR,
-            ) -> Result,
+                command_buffer: CommandBuffer,
+                p_sample_locations_info: *const SampleLocationsInfoEXT,
+            ),
+            takes_handle: false,
+            provided_by: [
+                [VK_EXT_sample_locations] => CmdSetSampleLocationsEXT,
+            ],
+            hooks: [
+                command_buffer: handle,
+                p_sample_locations_info: structs,
+            ],
+        },
+        {
+            name: pfn_get_buffer_memory_requirements_2,
+            method_name: get_buffer_memory_requirements_2,
+            try_method_name: try_get_buffer_memory_requirements_2,
+            unchecked_method_name: get_buffer_memory_requirements_2_unchecked,
+            ptr: GetBufferMemoryRequirements2,
+            aliases: [GetBufferMemoryRequirements2KHR],
+            signature: (
+                p_info: *const BufferMemoryRequirementsInfo2,
+                p_memory_requirements: *mut MemoryRequirements2,
+            ),
             takes_handle: true,
+            provided_by: [
+                [VK_VERSION_1_1] => GetBufferMemoryRequirements2,
+                [VK_KHR_get_memory_requirements2] => GetBufferMemoryRequirements2KHR,
+            ],
+            hooks: [
+                p_info: structs,
+                p_memory_requirements: structs,
+            ],
+            checked: {
+                method_name: get_buffer_memory_requirements_2_checked,
+                args: (
+                    p_info: *const BufferMemoryRequirementsInfo2,
+                ),
+                output: p_memory_requirements: MemoryRequirements2,
+                result: void,
+            },
         },
         {
-            name: pfn_get_physical_device_supported_framebuffer_mixed_samples_combinations_nv,
-            method_name: get_physical_device_supported_framebuffer_mixed_samples_combinations_nv,
-            ptr: GetPhysicalDeviceSupportedFramebufferMixedSamplesCombinationsNV,
+            name: pfn_get_image_memory_requirements_2,
+            method_name: get_image_memory_requirements_2,
+            try_method_name: try_get_image_memory_requirements_2,
+            unchecked_method_name: get_image_memory_requirements_2_unchecked,
+            ptr: GetImageMemoryRequirements2,
+            aliases: [GetImageMemoryRequirements2KHR],
             signature: (
-                physical_device: PhysicalDevice,
-                p_combination_count: *mut u32,
-                p_combinations: *mut FramebufferMixedSamplesCombinationNV,
-            ) -> Result,
-            takes_handle: false,
+                p_info: *const ImageMemoryRequirementsInfo2,
+                p_memory_requirements: *mut MemoryRequirements2,
+            ),
+            takes_handle: true,
+            provided_by: [
+                [VK_VERSION_1_1] => GetImageMemoryRequirements2,
+                [VK_KHR_get_memory_requirements2] => GetImageMemoryRequirements2KHR,
+            ],
+            hooks: [
+                p_info: structs,
+                p_memory_requirements: structs,
+            ],
+            checked: {
+                method_name: get_image_memory_requirements_2_checked,
+                args: (
+                    p_info: *const ImageMemoryRequirementsInfo2,
+                ),
+                output: p_memory_requirements: MemoryRequirements2,
+                result: void,
+            },
         },
         {
-            name: pfn_get_physical_device_tool_properties,
-            method_name: get_physical_device_tool_properties,
-            ptr: GetPhysicalDeviceToolProperties,
+            name: pfn_get_image_sparse_memory_requirements_2,
+            method_name: get_image_sparse_memory_requirements_2,
+            try_method_name: try_get_image_sparse_memory_requirements_2,
+            unchecked_method_name: get_image_sparse_memory_requirements_2_unchecked,
+            ptr: GetImageSparseMemoryRequirements2,
+            aliases: [GetImageSparseMemoryRequirements2KHR],
             signature: (
-                physical_device: PhysicalDevice,
-                p_tool_count: *mut u32,
-                p_tool_properties: *mut PhysicalDeviceToolProperties,
-            ) -> Result,
-            takes_handle: false,
+                p_info: *const ImageSparseMemoryRequirementsInfo2,
+                p_sparse_memory_requirement_count: *mut u32,
+                p_sparse_memory_requirements: *mut SparseImageMemoryRequirements2,
+            ),
+            takes_handle: true,
+            provided_by: [
+                [VK_VERSION_1_1] => GetImageSparseMemoryRequirements2,
+                [VK_KHR_get_memory_requirements2] => GetImageSparseMemoryRequirements2KHR,
+            ],
+            hooks: [
+                p_info: structs,
+                p_sparse_memory_requirement_count: ptr,
+                p_sparse_memory_requirements: structs[*p_sparse_memory_requirement_count],
+            ],
+            vec: {
+                method_name: get_image_sparse_memory_requirements_2_vec,
+                args: (
+                    p_info: *const ImageSparseMemoryRequirementsInfo2,
+                ),
+                element: SparseImageMemoryRequirements2,
+                result: void,
+            },
         },
         {
-            name: pfn_get_physical_device_fragment_shading_rates_khr,
-            method_name: get_physical_device_fragment_shading_rates_khr,
-            ptr: GetPhysicalDeviceFragmentShadingRatesKHR,
+            name: pfn_get_device_buffer_memory_requirements,
+            method_name: get_device_buffer_memory_requirements,
+            try_method_name: try_get_device_buffer_memory_requirements,
+            unchecked_method_name: get_device_buffer_memory_requirements_unchecked,
+            ptr: GetDeviceBufferMemoryRequirements,
+            aliases: [GetDeviceBufferMemoryRequirementsKHR],
             signature: (
-                physical_device: PhysicalDevice,
-                p_fragment_shading_rate_count: *mut u32,
-                p_fragment_shading_rates: *mut PhysicalDeviceFragmentShadingRateKHR,
-            ) -> Result,
-            takes_handle: false,
+                p_info: *const DeviceBufferMemoryRequirements,
+                p_memory_requirements: *mut MemoryRequirements2,
+            ),
+            takes_handle: true,
+            provided_by: [
+                [VK_VERSION_1_3] => GetDeviceBufferMemoryRequirements,
+                [VK_KHR_maintenance4] => GetDeviceBufferMemoryRequirementsKHR,
+            ],
+            hooks: [
+                p_info: structs,
+                p_memory_requirements: structs,
+            ],
+            checked: {
+                method_name: get_device_buffer_memory_requirements_checked,
+                args: (
+                    p_info: *const DeviceBufferMemoryRequirements,
+                ),
+                output: p_memory_requirements: MemoryRequirements2,
+                result: void,
+            },
         },
         {
-            name: pfn_get_physical_device_video_capabilities_khr,
-            method_name: get_physical_device_video_capabilities_khr,
-            ptr: GetPhysicalDeviceVideoCapabilitiesKHR,
+            name: pfn_get_device_image_memory_requirements,
+            method_name: get_device_image_memory_requirements,
+            try_method_name: try_get_device_image_memory_requirements,
+            unchecked_method_name: get_device_image_memory_requirements_unchecked,
+            ptr: GetDeviceImageMemoryRequirements,
+            aliases: [GetDeviceImageMemoryRequirementsKHR],
             signature: (
-                physical_device: PhysicalDevice,
-                p_video_profile: *const VideoProfileInfoKHR,
-                p_capabilities: *mut VideoCapabilitiesKHR,
-            ) -> Result,
-            takes_handle: false,
+                p_info: *const DeviceImageMemoryRequirements,
+                p_memory_requirements: *mut MemoryRequirements2,
+            ),
+            takes_handle: true,
+            provided_by: [
+                [VK_VERSION_1_3] => GetDeviceImageMemoryRequirements,
+                [VK_KHR_maintenance4] => GetDeviceImageMemoryRequirementsKHR,
+            ],
+            hooks: [
+                p_info: structs,
+                p_memory_requirements: structs,
+            ],
+            checked: {
+                method_name: get_device_image_memory_requirements_checked,
+                args: (
+                    p_info: *const DeviceImageMemoryRequirements,
+                ),
+                output: p_memory_requirements: MemoryRequirements2,
+                result: void,
+            },
         },
         {
-            name: pfn_get_physical_device_video_format_properties_khr,
-            method_name: get_physical_device_video_format_properties_khr,
-            ptr: GetPhysicalDeviceVideoFormatPropertiesKHR,
+            name: pfn_get_device_image_sparse_memory_requirements,
+            method_name: get_device_image_sparse_memory_requirements,
+            try_method_name: try_get_device_image_sparse_memory_requirements,
+            unchecked_method_name: get_device_image_sparse_memory_requirements_unchecked,
+            ptr: GetDeviceImageSparseMemoryRequirements,
+            aliases: [GetDeviceImageSparseMemoryRequirementsKHR],
             signature: (
-                physical_device: PhysicalDevice,
-                p_video_format_info: *const PhysicalDeviceVideoFormatInfoKHR,
-                p_video_format_property_count: *mut u32,
-                p_video_format_properties: *mut VideoFormatPropertiesKHR,
-            ) -> Result,
-            takes_handle: false,
+                p_info: *const DeviceImageMemoryRequirements,
+                p_sparse_memory_requirement_count: *mut u32,
+                p_sparse_memory_requirements: *mut SparseImageMemoryRequirements2,
+            ),
+            takes_handle: true,
+            provided_by: [
+                [VK_VERSION_1_3] => GetDeviceImageSparseMemoryRequirements,
+                [VK_KHR_maintenance4] => GetDeviceImageSparseMemoryRequirementsKHR,
+            ],
+            hooks: [
+                p_info: structs,
+                p_sparse_memory_requirement_count: ptr,
+                p_sparse_memory_requirements: structs[*p_sparse_memory_requirement_count],
+            ],
+            vec: {
+                method_name: get_device_image_sparse_memory_requirements_vec,
+                args: (
+                    p_info: *const DeviceImageMemoryRequirements,
+                ),
+                element: SparseImageMemoryRequirements2,
+                result: void,
+            },
         },
         {
-            name: pfn_acquire_drm_display_ext,
-            method_name: acquire_drm_display_ext,
-            ptr: AcquireDrmDisplayEXT,
+            name: pfn_create_sampler_ycbcr_conversion,
+            method_name: create_sampler_ycbcr_conversion,
+            try_method_name: try_create_sampler_ycbcr_conversion,
+            unchecked_method_name: create_sampler_ycbcr_conversion_unchecked,
+            ptr: CreateSamplerYcbcrConversion,
+            aliases: [CreateSamplerYcbcrConversionKHR],
             signature: (
-                physical_device: PhysicalDevice,
-                drm_fd: i32,
-                display: DisplayKHR,
+                p_create_info: *const SamplerYcbcrConversionCreateInfo,
+                p_allocator: *const AllocationCallbacks,
+                p_ycbcr_conversion: *mut SamplerYcbcrConversion,
             ) -> Result,
-            takes_handle: false,
+            takes_handle: true,
+            provided_by: [
+                [VK_VERSION_1_1] => CreateSamplerYcbcrConversion,
+                [VK_KHR_sampler_ycbcr_conversion] => CreateSamplerYcbcrConversionKHR,
+            ],
+            hooks: [
+                p_create_info: structs,
+                p_allocator: structs,
+                p_ycbcr_conversion: handles,
+            ],
+            checked: {
+                method_name: create_sampler_ycbcr_conversion_checked,
+                args: (
+                    p_create_info: *const SamplerYcbcrConversionCreateInfo,
+                    p_allocator: Option<&AllocationCallbacks>,
+                ),
+                output: p_ycbcr_conversion: SamplerYcbcrConversion,
+                result: success,
+            },
         },
         {
-            name: pfn_get_drm_display_ext,
-            method_name: get_drm_display_ext,
-            ptr: GetDrmDisplayEXT,
+            name: pfn_destroy_sampler_ycbcr_conversion,
+            method_name: destroy_sampler_ycbcr_conversion,
+            try_method_name: try_destroy_sampler_ycbcr_conversion,
+            unchecked_method_name: destroy_sampler_ycbcr_conversion_unchecked,
+            ptr: DestroySamplerYcbcrConversion,
+            aliases: [DestroySamplerYcbcrConversionKHR],
             signature: (
-                physical_device: PhysicalDevice,
-                drm_fd: i32,
-                connector_id: u32,
-                display: *mut DisplayKHR,
-            ) -> Result,
-            takes_handle: false,
+                ycbcr_conversion: SamplerYcbcrConversion,
+                p_allocator: *const AllocationCallbacks,
+            ),
+            takes_handle: true,
+            provided_by: [
+                [VK_VERSION_1_1] => DestroySamplerYcbcrConversion,
+                [VK_KHR_sampler_ycbcr_conversion] => DestroySamplerYcbcrConversionKHR,
+            ],
+            hooks: [
+                ycbcr_conversion: handle,
+                p_allocator: structs,
+            ],
         },
         {
-            name: pfn_get_physical_device_optical_flow_image_formats_nv,
-            method_name: get_physical_device_optical_flow_image_formats_nv,
-            ptr: GetPhysicalDeviceOpticalFlowImageFormatsNV,
+            name: pfn_get_device_queue_2,
+            method_name: get_device_queue_2,
+            try_method_name: try_get_device_queue_2,
+            unchecked_method_name: get_device_queue_2_unchecked,
+            ptr: GetDeviceQueue2,
             signature: (
-                physical_device: PhysicalDevice,
-                p_optical_flow_image_format_info: *const OpticalFlowImageFormatInfoNV,
-                p_format_count: *mut u32,
-                p_image_format_properties: *mut OpticalFlowImageFormatPropertiesNV,
-            ) -> Result,
-            takes_handle: false,
+                p_queue_info: *const DeviceQueueInfo2,
+                p_queue: *mut Queue,
+            ),
+            takes_handle: true,
+            provided_by: [
+                [VK_VERSION_1_1] => GetDeviceQueue2,
+            ],
+            hooks: [
+                p_queue_info: structs,
+                p_queue: handles,
+            ],
+            checked: {
+                method_name: get_device_queue_2_checked,
+                args: (
+                    p_queue_info: *const DeviceQueueInfo2,
+                ),
+                output: p_queue: Queue,
+                result: void,
+            },
         },
-    ],
-}
-
-impl_table! {
-    name: DeviceTable,
-    get_proc_addr: GetDeviceProcAddr,
-    handle: {
-        name: device,
-        type: Device,
-    },
-    members: [
         {
-            name: pfn_get_device_proc_addr,
-            method_name: get_device_proc_addr,
-            ptr: GetDeviceProcAddr,
+            name: pfn_create_validation_cache_ext,
+            method_name: create_validation_cache_ext,
+            try_method_name: try_create_validation_cache_ext,
+            unchecked_method_name: create_validation_cache_ext_unchecked,
+            ptr: CreateValidationCacheEXT,
             signature: (
-                p_name: *const c_char,
-            ) -> crate::pfn::VoidFunction,
+                p_create_info: *const ValidationCacheCreateInfoEXT,
+                p_allocator: *const AllocationCallbacks,
+                p_validation_cache: *mut ValidationCacheEXT,
+            ) -> Result,
             takes_handle: true,
+            provided_by: [
+                [VK_EXT_validation_cache] => CreateValidationCacheEXT,
+            ],
+            hooks: [
+                p_create_info: structs,
+                p_allocator: structs,
+                p_validation_cache: handles,
+            ],
+            checked: {
+                method_name: create_validation_cache_ext_checked,
+                args: (
+                    p_create_info: *const ValidationCacheCreateInfoEXT,
+                    p_allocator: Option<&AllocationCallbacks>,
+                ),
+                output: p_validation_cache: ValidationCacheEXT,
+                result: success,
+            },
         },
         {
-            name: pfn_destroy_device,
-            method_name: destroy_device,
-            ptr: DestroyDevice,
+            name: pfn_destroy_validation_cache_ext,
+            method_name: destroy_validation_cache_ext,
+            try_method_name: try_destroy_validation_cache_ext,
+            unchecked_method_name: destroy_validation_cache_ext_unchecked,
+            ptr: DestroyValidationCacheEXT,
             signature: (
+                validation_cache: ValidationCacheEXT,
                 p_allocator: *const AllocationCallbacks,
             ),
             takes_handle: true,
+            provided_by: [
+                [VK_EXT_validation_cache] => DestroyValidationCacheEXT,
+            ],
+            hooks: [
+                validation_cache: handle,
+                p_allocator: structs,
+            ],
         },
         {
-            name: pfn_get_device_queue,
-            method_name: get_device_queue,
-            ptr: GetDeviceQueue,
+            name: pfn_get_validation_cache_data_ext,
+            method_name: get_validation_cache_data_ext,
+            try_method_name: try_get_validation_cache_data_ext,
+            unchecked_method_name: get_validation_cache_data_ext_unchecked,
+            ptr: GetValidationCacheDataEXT,
             signature: (
-                queue_family_index: u32,
-                queue_index: u32,
-                p_queue: *mut Queue,
-            ),
+                validation_cache: ValidationCacheEXT,
+                p_data_size: *mut usize,
+                p_data: *mut c_void,
+            ) -> Result,
             takes_handle: true,
+            provided_by: [
+                [VK_EXT_validation_cache] => GetValidationCacheDataEXT,
+            ],
+            hooks: [
+                validation_cache: handle,
+                p_data_size: ptr,
+                p_data: opaque,
+            ],
         },
         {
-            name: pfn_queue_submit,
-            method_name: queue_submit,
-            ptr: QueueSubmit,
+            name: pfn_merge_validation_caches_ext,
+            method_name: merge_validation_caches_ext,
+            try_method_name: try_merge_validation_caches_ext,
+            unchecked_method_name: merge_validation_caches_ext_unchecked,
+            ptr: MergeValidationCachesEXT,
             signature: (
-                queue: Queue,
-                submit_count: u32,
-                p_submits: *const SubmitInfo,
-                fence: Fence,
+                dst_cache: ValidationCacheEXT,
+                src_cache_count: u32,
+                p_src_caches: *const ValidationCacheEXT,
             ) -> Result,
-            takes_handle: false,
+            takes_handle: true,
+            provided_by: [
+                [VK_EXT_validation_cache] => MergeValidationCachesEXT,
+            ],
+            hooks: [
+                dst_cache: handle,
+                src_cache_count: value,
+                p_src_caches: handles[src_cache_count],
+            ],
         },
         {
-            name: pfn_queue_wait_idle,
-            method_name: queue_wait_idle,
-            ptr: QueueWaitIdle,
+            name: pfn_get_descriptor_set_layout_support,
+            method_name: get_descriptor_set_layout_support,
+            try_method_name: try_get_descriptor_set_layout_support,
+            unchecked_method_name: get_descriptor_set_layout_support_unchecked,
+            ptr: GetDescriptorSetLayoutSupport,
+            aliases: [GetDescriptorSetLayoutSupportKHR],
             signature: (
-                queue: Queue,
-            ) -> Result,
-            takes_handle: false,
+                p_create_info: *const DescriptorSetLayoutCreateInfo,
+                p_support: *mut DescriptorSetLayoutSupport,
+            ),
+            takes_handle: true,
+            provided_by: [
+                [VK_VERSION_1_1] => GetDescriptorSetLayoutSupport,
+                [VK_KHR_maintenance3] => GetDescriptorSetLayoutSupportKHR,
+            ],
+            hooks: [
+                p_create_info: structs,
+                p_support: structs,
+            ],
+            checked: {
+                method_name: get_descriptor_set_layout_support_checked,
+                args: (
+                    p_create_info: *const DescriptorSetLayoutCreateInfo,
+                ),
+                output: p_support: DescriptorSetLayoutSupport,
+                result: void,
+            },
         },
         {
-            name: pfn_device_wait_idle,
-            method_name: device_wait_idle,
-            ptr: DeviceWaitIdle,
+            name: pfn_get_swapchain_gralloc_usage_android,
+            method_name: get_swapchain_gralloc_usage_android,
+            try_method_name: try_get_swapchain_gralloc_usage_android,
+            unchecked_method_name: get_swapchain_gralloc_usage_android_unchecked,
+            ptr: GetSwapchainGrallocUsageANDROID,
             signature: (
+                format: Format,
+                image_usage: ImageUsageFlags,
+                gralloc_usage: *mut c_int,
             ) -> Result,
             takes_handle: true,
+            provided_by: [],
+            hooks: [
+                format: value,
+                image_usage: value,
+                gralloc_usage: ptr,
+            ],
+            checked: {
+                method_name: get_swapchain_gralloc_usage_android_checked,
+                args: (
+                    format: Format,
+                    image_usage: ImageUsageFlags,
+                ),
+                output: gralloc_usage: c_int,
+                result: success,
+            },
         },
         {
-            name: pfn_allocate_memory,
-            method_name: allocate_memory,
-            ptr: AllocateMemory,
+            name: pfn_get_swapchain_gralloc_usage_2_android,
+            method_name: get_swapchain_gralloc_usage_2_android,
+            try_method_name: try_get_swapchain_gralloc_usage_2_android,
+            unchecked_method_name: get_swapchain_gralloc_usage_2_android_unchecked,
+            ptr: GetSwapchainGrallocUsage2ANDROID,
             signature: (
-                p_allocate_info: *const MemoryAllocateInfo,
-                p_allocator: *const AllocationCallbacks,
-                p_memory: *mut DeviceMemory,
+                format: Format,
+                image_usage: ImageUsageFlags,
+                swapchain_image_usage: SwapchainImageUsageFlagsANDROID,
+                gralloc_consumer_usage: *mut u64,
+                gralloc_producer_usage: *mut u64,
             ) -> Result,
             takes_handle: true,
+            provided_by: [],
+            hooks: [
+                format: value,
+                image_usage: value,
+                swapchain_image_usage: value,
+                gralloc_consumer_usage: ptr,
+                gralloc_producer_usage: ptr,
+            ],
         },
         {
-            name: pfn_free_memory,
-            method_name: free_memory,
-            ptr: FreeMemory,
+            name: pfn_acquire_image_android,
+            method_name: acquire_image_android,
+            try_method_name: try_acquire_image_android,
+            unchecked_method_name: acquire_image_android_unchecked,
+            ptr: AcquireImageANDROID,
             signature: (
-                memory: DeviceMemory,
-                p_allocator: *const AllocationCallbacks,
-            ),
+                image: Image,
+                native_fence_fd: c_int,
+                semaphore: Semaphore,
+                fence: Fence,
+            ) -> Result,
             takes_handle: true,
+            provided_by: [],
+            hooks: [
+                image: handle,
+                native_fence_fd: value,
+                semaphore: handle,
+                fence: handle,
+            ],
         },
         {
-            name: pfn_map_memory,
-            method_name: map_memory,
-            ptr: MapMemory,
+            name: pfn_queue_signal_release_image_android,
+            method_name: queue_signal_release_image_android,
+            try_method_name: try_queue_signal_release_image_android,
+            unchecked_method_name: queue_signal_release_image_android_unchecked,
+            ptr: QueueSignalReleaseImageANDROID,
             signature: (
-                memory: DeviceMemory,
-                offset: DeviceSize,
-                size: DeviceSize,
-                flags: MemoryMapFlags,
-                pp_data: *mut *mut c_void,
+                queue: Queue,
+                wait_semaphore_count: u32,
+                p_wait_semaphores: *const Semaphore,
+                image: Image,
+                p_native_fence_fd: *mut c_int,
+            ) -> Result,
+            takes_handle: false,
+            provided_by: [],
+            hooks: [
+                queue: handle,
+                wait_semaphore_count: value,
+                p_wait_semaphores: handles[wait_semaphore_count],
+                image: handle,
+                p_native_fence_fd: ptr,
+            ],
+        },
+        {
+            name: pfn_get_shader_info_amd,
+            method_name: get_shader_info_amd,
+            try_method_name: try_get_shader_info_amd,
+            unchecked_method_name: get_shader_info_amd_unchecked,
+            ptr: GetShaderInfoAMD,
+            signature: (
+                pipeline: Pipeline,
+                shader_stage: ShaderStageFlagBits,
+                info_type: ShaderInfoTypeAMD,
+                p_info_size: *mut usize,
+                p_info: *mut c_void,
             ) -> Result,
             takes_handle: true,
+            provided_by: [
+                [VK_AMD_shader_info] => GetShaderInfoAMD,
+            ],
+            hooks: [
+                pipeline: handle,
+                shader_stage: value,
+                info_type: value,
+                p_info_size: ptr,
+                p_info: opaque,
+            ],
         },
         {
-            name: pfn_unmap_memory,
-            method_name: unmap_memory,
-            ptr: UnmapMemory,
+            name: pfn_set_local_dimming_amd,
+            method_name: set_local_dimming_amd,
+            try_method_name: try_set_local_dimming_amd,
+            unchecked_method_name: set_local_dimming_amd_unchecked,
+            ptr: SetLocalDimmingAMD,
             signature: (
-                memory: DeviceMemory,
+                swap_chain: SwapchainKHR,
+                local_dimming_enable: Bool32,
             ),
             takes_handle: true,
+            provided_by: [
+                [VK_AMD_display_native_hdr] => SetLocalDimmingAMD,
+            ],
+            hooks: [
+                swap_chain: handle,
+                local_dimming_enable: value,
+            ],
         },
         {
-            name: pfn_flush_mapped_memory_ranges,
-            method_name: flush_mapped_memory_ranges,
-            ptr: FlushMappedMemoryRanges,
+            name: pfn_get_calibrated_timestamps_ext,
+            method_name: get_calibrated_timestamps_ext,
+            try_method_name: try_get_calibrated_timestamps_ext,
+            unchecked_method_name: get_calibrated_timestamps_ext_unchecked,
+            ptr: GetCalibratedTimestampsEXT,
             signature: (
-                memory_range_count: u32,
-                p_memory_ranges: *const MappedMemoryRange,
+                timestamp_count: u32,
+                p_timestamp_infos: *const CalibratedTimestampInfoEXT,
+                p_timestamps: *mut u64,
+                p_max_deviation: *mut u64,
             ) -> Result,
             takes_handle: true,
+            provided_by: [
+                [VK_EXT_calibrated_timestamps] => GetCalibratedTimestampsEXT,
+            ],
+            hooks: [
+                timestamp_count: value,
+                p_timestamp_infos: structs[timestamp_count],
+                p_timestamps: ptr[timestamp_count],
+                p_max_deviation: ptr,
+            ],
         },
         {
-            name: pfn_invalidate_mapped_memory_ranges,
-            method_name: invalidate_mapped_memory_ranges,
-            ptr: InvalidateMappedMemoryRanges,
+            name: pfn_set_debug_utils_object_name_ext,
+            method_name: set_debug_utils_object_name_ext,
+            try_method_name: try_set_debug_utils_object_name_ext,
+            unchecked_method_name: set_debug_utils_object_name_ext_unchecked,
+            ptr: SetDebugUtilsObjectNameEXT,
             signature: (
-                memory_range_count: u32,
-                p_memory_ranges: *const MappedMemoryRange,
+                p_name_info: *const DebugUtilsObjectNameInfoEXT,
             ) -> Result,
             takes_handle: true,
+            provided_by: [
+                [] => SetDebugUtilsObjectNameEXT,
+            ],
+            hooks: [
+                p_name_info: structs,
+            ],
         },
         {
-            name: pfn_get_device_memory_commitment,
-            method_name: get_device_memory_commitment,
-            ptr: GetDeviceMemoryCommitment,
+            name: pfn_set_debug_utils_object_tag_ext,
+            method_name: set_debug_utils_object_tag_ext,
+            try_method_name: try_set_debug_utils_object_tag_ext,
+            unchecked_method_name: set_debug_utils_object_tag_ext_unchecked,
+            ptr: SetDebugUtilsObjectTagEXT,
             signature: (
-                memory: DeviceMemory,
-                p_committed_memory_in_bytes: *mut DeviceSize,
-            ),
+                p_tag_info: *const DebugUtilsObjectTagInfoEXT,
+            ) -> Result,
             takes_handle: true,
+            provided_by: [
+                [] => SetDebugUtilsObjectTagEXT,
+            ],
+            hooks: [
+                p_tag_info: structs,
+            ],
         },
         {
-            name: pfn_get_buffer_memory_requirements,
-            method_name: get_buffer_memory_requirements,
-            ptr: GetBufferMemoryRequirements,
+            name: pfn_queue_begin_debug_utils_label_ext,
+            method_name: queue_begin_debug_utils_label_ext,
+            try_method_name: try_queue_begin_debug_utils_label_ext,
+            unchecked_method_name: queue_begin_debug_utils_label_ext_unchecked,
+            ptr: QueueBeginDebugUtilsLabelEXT,
             signature: (
-                buffer: Buffer,
-                p_memory_requirements: *mut MemoryRequirements,
+                queue: Queue,
+                p_label_info: *const DebugUtilsLabelEXT,
             ),
-            takes_handle: true,
+            takes_handle: false,
+            provided_by: [
+                [] => QueueBeginDebugUtilsLabelEXT,
+            ],
+            hooks: [
+                queue: handle,
+                p_label_info: structs,
+            ],
         },
         {
-            name: pfn_bind_buffer_memory,
-            method_name: bind_buffer_memory,
-            ptr: BindBufferMemory,
+            name: pfn_queue_end_debug_utils_label_ext,
+            method_name: queue_end_debug_utils_label_ext,
+            try_method_name: try_queue_end_debug_utils_label_ext,
+            unchecked_method_name: queue_end_debug_utils_label_ext_unchecked,
+            ptr: QueueEndDebugUtilsLabelEXT,
             signature: (
-                buffer: Buffer,
-                memory: DeviceMemory,
-                memory_offset: DeviceSize,
-            ) -> Result,
-            takes_handle: true,
+                queue: Queue,
+            ),
+            takes_handle: false,
+            provided_by: [
+                [] => QueueEndDebugUtilsLabelEXT,
+            ],
+            hooks: [
+                queue: handle,
+            ],
         },
         {
-            name: pfn_get_image_memory_requirements,
-            method_name: get_image_memory_requirements,
-            ptr: GetImageMemoryRequirements,
+            name: pfn_queue_insert_debug_utils_label_ext,
+            method_name: queue_insert_debug_utils_label_ext,
+            try_method_name: try_queue_insert_debug_utils_label_ext,
+            unchecked_method_name: queue_insert_debug_utils_label_ext_unchecked,
+            ptr: QueueInsertDebugUtilsLabelEXT,
             signature: (
-                image: Image,
-                p_memory_requirements: *mut MemoryRequirements,
+                queue: Queue,
+                p_label_info: *const DebugUtilsLabelEXT,
             ),
-            takes_handle: true,
+            takes_handle: false,
+            provided_by: [
+                [] => QueueInsertDebugUtilsLabelEXT,
+            ],
+            hooks: [
+                queue: handle,
+                p_label_info: structs,
+            ],
         },
         {
-            name: pfn_bind_image_memory,
-            method_name: bind_image_memory,
-            ptr: BindImageMemory,
+            name: pfn_cmd_begin_debug_utils_label_ext,
+            method_name: cmd_begin_debug_utils_label_ext,
+            try_method_name: try_cmd_begin_debug_utils_label_ext,
+            unchecked_method_name: cmd_begin_debug_utils_label_ext_unchecked,
+            ptr: CmdBeginDebugUtilsLabelEXT,
             signature: (
-                image: Image,
-                memory: DeviceMemory,
-                memory_offset: DeviceSize,
-            ) -> Result,
-            takes_handle: true,
+                command_buffer: CommandBuffer,
+                p_label_info: *const DebugUtilsLabelEXT,
+            ),
+            takes_handle: false,
+            provided_by: [
+                [] => CmdBeginDebugUtilsLabelEXT,
+            ],
+            hooks: [
+                command_buffer: handle,
+                p_label_info: structs,
+            ],
         },
         {
-            name: pfn_get_image_sparse_memory_requirements,
-            method_name: get_image_sparse_memory_requirements,
-            ptr: GetImageSparseMemoryRequirements,
+            name: pfn_cmd_end_debug_utils_label_ext,
+            method_name: cmd_end_debug_utils_label_ext,
+            try_method_name: try_cmd_end_debug_utils_label_ext,
+            unchecked_method_name: cmd_end_debug_utils_label_ext_unchecked,
+            ptr: CmdEndDebugUtilsLabelEXT,
             signature: (
-                image: Image,
-                p_sparse_memory_requirement_count: *mut u32,
-                p_sparse_memory_requirements: *mut SparseImageMemoryRequirements,
+                command_buffer: CommandBuffer,
             ),
-            takes_handle: true,
+            takes_handle: false,
+            provided_by: [
+                [] => CmdEndDebugUtilsLabelEXT,
+            ],
+            hooks: [
+                command_buffer: handle,
+            ],
         },
         {
-            name: pfn_queue_bind_sparse,
-            method_name: queue_bind_sparse,
-            ptr: QueueBindSparse,
+            name: pfn_cmd_insert_debug_utils_label_ext,
+            method_name: cmd_insert_debug_utils_label_ext,
+            try_method_name: try_cmd_insert_debug_utils_label_ext,
+            unchecked_method_name: cmd_insert_debug_utils_label_ext_unchecked,
+            ptr: CmdInsertDebugUtilsLabelEXT,
             signature: (
-                queue: Queue,
-                bind_info_count: u32,
-                p_bind_info: *const BindSparseInfo,
-                fence: Fence,
-            ) -> Result,
+                command_buffer: CommandBuffer,
+                p_label_info: *const DebugUtilsLabelEXT,
+            ),
             takes_handle: false,
+            provided_by: [
+                [] => CmdInsertDebugUtilsLabelEXT,
+            ],
+            hooks: [
+                command_buffer: handle,
+                p_label_info: structs,
+            ],
         },
         {
-            name: pfn_create_fence,
-            method_name: create_fence,
-            ptr: CreateFence,
+            name: pfn_get_memory_host_pointer_properties_ext,
+            method_name: get_memory_host_pointer_properties_ext,
+            try_method_name: try_get_memory_host_pointer_properties_ext,
+            unchecked_method_name: get_memory_host_pointer_properties_ext_unchecked,
+            ptr: GetMemoryHostPointerPropertiesEXT,
             signature: (
-                p_create_info: *const FenceCreateInfo,
-                p_allocator: *const AllocationCallbacks,
-                p_fence: *mut Fence,
+                handle_type: ExternalMemoryHandleTypeFlagBits,
+                p_host_pointer: *const c_void,
+                p_memory_host_pointer_properties: *mut MemoryHostPointerPropertiesEXT,
             ) -> Result,
             takes_handle: true,
+            provided_by: [
+                [VK_EXT_external_memory_host] => GetMemoryHostPointerPropertiesEXT,
+            ],
+            hooks: [
+                handle_type: value,
+                p_host_pointer: opaque,
+                p_memory_host_pointer_properties: structs,
+            ],
+            checked: {
+                method_name: get_memory_host_pointer_properties_ext_checked,
+                args: (
+                    handle_type: ExternalMemoryHandleTypeFlagBits,
+                    p_host_pointer: *const c_void,
+                ),
+                output: p_memory_host_pointer_properties: MemoryHostPointerPropertiesEXT,
+                result: success,
+            },
         },
         {
-            name: pfn_destroy_fence,
-            method_name: destroy_fence,
-            ptr: DestroyFence,
+            name: pfn_cmd_write_buffer_marker_amd,
+            method_name: cmd_write_buffer_marker_amd,
+            try_method_name: try_cmd_write_buffer_marker_amd,
+            unchecked_method_name: cmd_write_buffer_marker_amd_unchecked,
+            ptr: CmdWriteBufferMarkerAMD,
             signature: (
-                fence: Fence,
-                p_allocator: *const AllocationCallbacks,
+                command_buffer: CommandBuffer,
+                pipeline_stage: PipelineStageFlagBits,
+                dst_buffer: Buffer,
+                dst_offset: DeviceSize,
+                marker: u32,
             ),
-            takes_handle: true,
+            takes_handle: false,
+            provided_by: [
+                [VK_AMD_buffer_marker] => CmdWriteBufferMarkerAMD,
+            ],
+            hooks: [
+                command_buffer: handle,
+                pipeline_stage: value,
+                dst_buffer: handle,
+                dst_offset: value,
+                marker: value,
+            ],
         },
         {
-            name: pfn_reset_fences,
-            method_name: reset_fences,
-            ptr: ResetFences,
+            name: pfn_create_render_pass_2,
+            method_name: create_render_pass_2,
+            try_method_name: try_create_render_pass_2,
+            unchecked_method_name: create_render_pass_2_unchecked,
+            ptr: CreateRenderPass2,
+            aliases: [CreateRenderPass2KHR],
             signature: (
-                fence_count: u32,
-                p_fences: *const Fence,
+                p_create_info: *const RenderPassCreateInfo2,
+                p_allocator: *const AllocationCallbacks,
+                p_render_pass: *mut RenderPass,
             ) -> Result,
             takes_handle: true,
+            provided_by: [
+                [VK_VERSION_1_2] => CreateRenderPass2,
+                [VK_KHR_create_renderpass2] => CreateRenderPass2KHR,
+            ],
+            hooks: [
+                p_create_info: structs,
+                p_allocator: structs,
+                p_render_pass: handles,
+            ],
+            checked: {
+                method_name: create_render_pass_2_checked,
+                args: (
+                    p_create_info: *const RenderPassCreateInfo2,
+                    p_allocator: Option<&AllocationCallbacks>,
+                ),
+                output: p_render_pass: RenderPass,
+                result: success,
+            },
         },
         {
-            name: pfn_get_fence_status,
-            method_name: get_fence_status,
-            ptr: GetFenceStatus,
+            name: pfn_cmd_begin_render_pass_2,
+            method_name: cmd_begin_render_pass_2,
+            try_method_name: try_cmd_begin_render_pass_2,
+            unchecked_method_name: cmd_begin_render_pass_2_unchecked,
+            ptr: CmdBeginRenderPass2,
+            aliases: [CmdBeginRenderPass2KHR],
             signature: (
-                fence: Fence,
-            ) -> Result,
-            takes_handle: true,
+                command_buffer: CommandBuffer,
+                p_render_pass_begin: *const RenderPassBeginInfo,
+                p_subpass_begin_info: *const SubpassBeginInfo,
+            ),
+            takes_handle: false,
+            provided_by: [
+                [VK_VERSION_1_2] => CmdBeginRenderPass2,
+                [VK_KHR_create_renderpass2] => CmdBeginRenderPass2KHR,
+            ],
+            hooks: [
+                command_buffer: handle,
+                p_render_pass_begin: structs,
+                p_subpass_begin_info: structs,
+            ],
         },
         {
-            name: pfn_wait_for_fences,
-            method_name: wait_for_fences,
-            ptr: WaitForFences,
+            name: pfn_cmd_next_subpass_2,
+            method_name: cmd_next_subpass_2,
+            try_method_name: try_cmd_next_subpass_2,
+            unchecked_method_name: cmd_next_subpass_2_unchecked,
+            ptr: CmdNextSubpass2,
+            aliases: [CmdNextSubpass2KHR],
             signature: (
-                fence_count: u32,
-                p_fences: *const Fence,
-                wait_all: Bool32,
-                timeout: u64,
-            ) -> Result,
-            takes_handle: true,
+                command_buffer: CommandBuffer,
+                p_subpass_begin_info: *const SubpassBeginInfo,
+                p_subpass_end_info: *const SubpassEndInfo,
+            ),
+            takes_handle: false,
+            provided_by: [
+                [VK_VERSION_1_2] => CmdNextSubpass2,
+                [VK_KHR_create_renderpass2] => CmdNextSubpass2KHR,
+            ],
+            hooks: [
+                command_buffer: handle,
+                p_subpass_begin_info: structs,
+                p_subpass_end_info: structs,
+            ],
         },
         {
-            name: pfn_create_semaphore,
-            method_name: create_semaphore,
-            ptr: CreateSemaphore,
+            name: pfn_cmd_end_render_pass_2,
+            method_name: cmd_end_render_pass_2,
+            try_method_name: try_cmd_end_render_pass_2,
+            unchecked_method_name: cmd_end_render_pass_2_unchecked,
+            ptr: CmdEndRenderPass2,
+            aliases: [CmdEndRenderPass2KHR],
             signature: (
-                p_create_info: *const SemaphoreCreateInfo,
-                p_allocator: *const AllocationCallbacks,
-                p_semaphore: *mut Semaphore,
-            ) -> Result,
-            takes_handle: true,
+                command_buffer: CommandBuffer,
+                p_subpass_end_info: *const SubpassEndInfo,
+            ),
+            takes_handle: false,
+            provided_by: [
+                [VK_VERSION_1_2] => CmdEndRenderPass2,
+                [VK_KHR_create_renderpass2] => CmdEndRenderPass2KHR,
+            ],
+            hooks: [
+                command_buffer: handle,
+                p_subpass_end_info: structs,
+            ],
         },
         {
-            name: pfn_destroy_semaphore,
-            method_name: destroy_semaphore,
-            ptr: DestroySemaphore,
+            name: pfn_get_semaphore_counter_value,
+            method_name: get_semaphore_counter_value,
+            try_method_name: try_get_semaphore_counter_value,
+            unchecked_method_name: get_semaphore_counter_value_unchecked,
+            ptr: GetSemaphoreCounterValue,
+            aliases: [GetSemaphoreCounterValueKHR],
             signature: (
                 semaphore: Semaphore,
-                p_allocator: *const AllocationCallbacks,
-            ),
+                p_value: *mut u64,
+            ) -> Result,
             takes_handle: true,
+            provided_by: [
+                [VK_VERSION_1_2] => GetSemaphoreCounterValue,
+                [VK_KHR_timeline_semaphore] => GetSemaphoreCounterValueKHR,
+            ],
+            hooks: [
+                semaphore: handle,
+                p_value: ptr,
+            ],
+            checked: {
+                method_name: get_semaphore_counter_value_checked,
+                args: (
+                    semaphore: Semaphore,
+                ),
+                output: p_value: u64,
+                result: success,
+            },
         },
         {
-            name: pfn_create_event,
-            method_name: create_event,
-            ptr: CreateEvent,
+            name: pfn_wait_semaphores,
+            method_name: wait_semaphores,
+            try_method_name: try_wait_semaphores,
+            unchecked_method_name: wait_semaphores_unchecked,
+            ptr: WaitSemaphores,
+            aliases: [WaitSemaphoresKHR],
             signature: (
-                p_create_info: *const EventCreateInfo,
-                p_allocator: *const AllocationCallbacks,
-                p_event: *mut Event,
+                p_wait_info: *const SemaphoreWaitInfo,
+                timeout: u64,
             ) -> Result,
             takes_handle: true,
+            provided_by: [
+                [VK_VERSION_1_2] => WaitSemaphores,
+                [VK_KHR_timeline_semaphore] => WaitSemaphoresKHR,
+            ],
+            hooks: [
+                p_wait_info: structs,
+                timeout: value,
+            ],
         },
         {
-            name: pfn_destroy_event,
-            method_name: destroy_event,
-            ptr: DestroyEvent,
+            name: pfn_signal_semaphore,
+            method_name: signal_semaphore,
+            try_method_name: try_signal_semaphore,
+            unchecked_method_name: signal_semaphore_unchecked,
+            ptr: SignalSemaphore,
+            aliases: [SignalSemaphoreKHR],
             signature: (
-                event: Event,
-                p_allocator: *const AllocationCallbacks,
-            ),
+                p_signal_info: *const SemaphoreSignalInfo,
+            ) -> Result,
             takes_handle: true,
+            provided_by: [
+                [VK_VERSION_1_2] => SignalSemaphore,
+                [VK_KHR_timeline_semaphore] => SignalSemaphoreKHR,
+            ],
+            hooks: [
+                p_signal_info: structs,
+            ],
         },
         {
-            name: pfn_get_event_status,
-            method_name: get_event_status,
-            ptr: GetEventStatus,
+            name: pfn_get_android_hardware_buffer_properties_android,
+            method_name: get_android_hardware_buffer_properties_android,
+            try_method_name: try_get_android_hardware_buffer_properties_android,
+            unchecked_method_name: get_android_hardware_buffer_properties_android_unchecked,
+            ptr: GetAndroidHardwareBufferPropertiesANDROID,
             signature: (
-                event: Event,
+                buffer: *const AHardwareBuffer,
+                p_properties: *mut AndroidHardwareBufferPropertiesANDROID,
             ) -> Result,
             takes_handle: true,
+            provided_by: [
+                [VK_ANDROID_external_memory_android_hardware_buffer] => GetAndroidHardwareBufferPropertiesANDROID,
+            ],
+            hooks: [
+                buffer: opaque,
+                p_properties: structs,
+            ],
+            checked: {
+                method_name: get_android_hardware_buffer_properties_android_checked,
+                args: (
+                    buffer: *const AHardwareBuffer,
+                ),
+                output: p_properties: AndroidHardwareBufferPropertiesANDROID,
+                result: success,
+            },
         },
         {
-            name: pfn_set_event,
-            method_name: set_event,
-            ptr: SetEvent,
+            name: pfn_get_memory_android_hardware_buffer_android,
+            method_name: get_memory_android_hardware_buffer_android,
+            try_method_name: try_get_memory_android_hardware_buffer_android,
+            unchecked_method_name: get_memory_android_hardware_buffer_android_unchecked,
+            ptr: GetMemoryAndroidHardwareBufferANDROID,
             signature: (
-                event: Event,
+                p_info: *const MemoryGetAndroidHardwareBufferInfoANDROID,
+                p_buffer: *mut *mut AHardwareBuffer,
             ) -> Result,
             takes_handle: true,
+            provided_by: [
+                [VK_ANDROID_external_memory_android_hardware_buffer] => GetMemoryAndroidHardwareBufferANDROID,
+            ],
+            hooks: [
+                p_info: structs,
+                p_buffer: ptr,
+            ],
         },
         {
-            name: pfn_reset_event,
-            method_name: reset_event,
-            ptr: ResetEvent,
+            name: pfn_cmd_draw_indirect_count,
+            method_name: cmd_draw_indirect_count,
+            try_method_name: try_cmd_draw_indirect_count,
+            unchecked_method_name: cmd_draw_indirect_count_unchecked,
+            ptr: CmdDrawIndirectCount,
+            aliases: [CmdDrawIndirectCountKHR, CmdDrawIndirectCountAMD],
             signature: (
-                event: Event,
-            ) -> Result,
-            takes_handle: true,
+                command_buffer: CommandBuffer,
+                buffer: Buffer,
+                offset: DeviceSize,
+                count_buffer: Buffer,
+                count_buffer_offset: DeviceSize,
+                max_draw_count: u32,
+                stride: u32,
+            ),
+            takes_handle: false,
+            provided_by: [
+                [VK_VERSION_1_2] => CmdDrawIndirectCount,
+                [VK_AMD_draw_indirect_count] => CmdDrawIndirectCountAMD,
+                [VK_KHR_draw_indirect_count] => CmdDrawIndirectCountKHR,
+            ],
+            hooks: [
+                command_buffer: handle,
+                buffer: handle,
+                offset: value,
+                count_buffer: handle,
+                count_buffer_offset: value,
+                max_draw_count: value,
+                stride: value,
+            ],
         },
         {
-            name: pfn_create_query_pool,
-            method_name: create_query_pool,
-            ptr: CreateQueryPool,
+            name: pfn_cmd_draw_indexed_indirect_count,
+            method_name: cmd_draw_indexed_indirect_count,
+            try_method_name: try_cmd_draw_indexed_indirect_count,
+            unchecked_method_name: cmd_draw_indexed_indirect_count_unchecked,
+            ptr: CmdDrawIndexedIndirectCount,
+            aliases: [CmdDrawIndexedIndirectCountKHR, CmdDrawIndexedIndirectCountAMD],
             signature: (
-                p_create_info: *const QueryPoolCreateInfo,
-                p_allocator: *const AllocationCallbacks,
-                p_query_pool: *mut QueryPool,
-            ) -> Result,
-            takes_handle: true,
+                command_buffer: CommandBuffer,
+                buffer: Buffer,
+                offset: DeviceSize,
+                count_buffer: Buffer,
+                count_buffer_offset: DeviceSize,
+                max_draw_count: u32,
+                stride: u32,
+            ),
+            takes_handle: false,
+            provided_by: [
+                [VK_VERSION_1_2] => CmdDrawIndexedIndirectCount,
+                [VK_AMD_draw_indirect_count] => CmdDrawIndexedIndirectCountAMD,
+                [VK_KHR_draw_indirect_count] => CmdDrawIndexedIndirectCountKHR,
+            ],
+            hooks: [
+                command_buffer: handle,
+                buffer: handle,
+                offset: value,
+                count_buffer: handle,
+                count_buffer_offset: value,
+                max_draw_count: value,
+                stride: value,
+            ],
         },
         {
-            name: pfn_destroy_query_pool,
-            method_name: destroy_query_pool,
-            ptr: DestroyQueryPool,
+            name: pfn_cmd_set_checkpoint_nv,
+            method_name: cmd_set_checkpoint_nv,
+            try_method_name: try_cmd_set_checkpoint_nv,
+            unchecked_method_name: cmd_set_checkpoint_nv_unchecked,
+            ptr: CmdSetCheckpointNV,
             signature: (
-                query_pool: QueryPool,
-                p_allocator: *const AllocationCallbacks,
+                command_buffer: CommandBuffer,
+                p_checkpoint_marker: *const c_void,
             ),
-            takes_handle: true,
+            takes_handle: false,
+            provided_by: [
+                [VK_NV_device_diagnostic_checkpoints] => CmdSetCheckpointNV,
+            ],
+            hooks: [
+                command_buffer: handle,
+                p_checkpoint_marker: opaque,
+            ],
         },
         {
-            name: pfn_get_query_pool_results,
-            method_name: get_query_pool_results,
-            ptr: GetQueryPoolResults,
+            name: pfn_get_queue_checkpoint_data_nv,
+            method_name: get_queue_checkpoint_data_nv,
+            try_method_name: try_get_queue_checkpoint_data_nv,
+            unchecked_method_name: get_queue_checkpoint_data_nv_unchecked,
+            ptr: GetQueueCheckpointDataNV,
             signature: (
-                query_pool: QueryPool,
-                first_query: u32,
-                query_count: u32,
-                data_size: usize,
-                p_data: *mut c_void,
-                stride: DeviceSize,
-                flags: QueryResultFlags,
-            ) -> Result,
-            takes_handle: true,
+                queue: Queue,
+                p_checkpoint_data_count: *mut u32,
+                p_checkpoint_data: *mut CheckpointDataNV,
+            ),
+            takes_handle: false,
+            provided_by: [
+                [VK_NV_device_diagnostic_checkpoints] => GetQueueCheckpointDataNV,
+            ],
+            hooks: [
+                queue: handle,
+                p_checkpoint_data_count: ptr,
+                p_checkpoint_data: structs[*p_checkpoint_data_count],
+            ],
+            vec: {
+                method_name: get_queue_checkpoint_data_nv_vec,
+                args: (
+                    queue: Queue,
+                ),
+                element: CheckpointDataNV,
+                result: void,
+            },
         },
         {
-            name: pfn_reset_query_pool,
-            method_name: reset_query_pool,
-            ptr: ResetQueryPool,
+            name: pfn_cmd_bind_transform_feedback_buffers_ext,
+            method_name: cmd_bind_transform_feedback_buffers_ext,
+            try_method_name: try_cmd_bind_transform_feedback_buffers_ext,
+            unchecked_method_name: cmd_bind_transform_feedback_buffers_ext_unchecked,
+            ptr: CmdBindTransformFeedbackBuffersEXT,
             signature: (
-                query_pool: QueryPool,
-                first_query: u32,
-                query_count: u32,
+                command_buffer: CommandBuffer,
+                first_binding: u32,
+                binding_count: u32,
+                p_buffers: *const Buffer,
+                p_offsets: *const DeviceSize,
+                p_sizes: *const DeviceSize,
             ),
-            takes_handle: true,
+            takes_handle: false,
+            provided_by: [
+                [VK_EXT_transform_feedback] => CmdBindTransformFeedbackBuffersEXT,
+            ],
+            hooks: [
+                command_buffer: handle,
+                first_binding: value,
+                binding_count: value,
+                p_buffers: handles[binding_count],
+                p_offsets: ptr[binding_count],
+                p_sizes: ptr[binding_count],
+            ],
         },
         {
-            name: pfn_create_buffer,
-            method_name: create_buffer,
-            ptr: CreateBuffer,
+            name: pfn_cmd_begin_transform_feedback_ext,
+            method_name: cmd_begin_transform_feedback_ext,
+            try_method_name: try_cmd_begin_transform_feedback_ext,
+            unchecked_method_name: cmd_begin_transform_feedback_ext_unchecked,
+            ptr: CmdBeginTransformFeedbackEXT,
             signature: (
-                p_create_info: *const BufferCreateInfo,
-                p_allocator: *const AllocationCallbacks,
-                p_buffer: *mut Buffer,
-            ) -> Result,
-            takes_handle: true,
+                command_buffer: CommandBuffer,
+                first_counter_buffer: u32,
+                counter_buffer_count: u32,
+                p_counter_buffers: *const Buffer,
+                p_counter_buffer_offsets: *const DeviceSize,
+            ),
+            takes_handle: false,
+            provided_by: [
+                [VK_EXT_transform_feedback] => CmdBeginTransformFeedbackEXT,
+            ],
+            hooks: [
+                command_buffer: handle,
+                first_counter_buffer: value,
+                counter_buffer_count: value,
+                p_counter_buffers: handles[counter_buffer_count],
+                p_counter_buffer_offsets: ptr[counter_buffer_count],
+            ],
         },
         {
-            name: pfn_destroy_buffer,
-            method_name: destroy_buffer,
-            ptr: DestroyBuffer,
+            name: pfn_cmd_end_transform_feedback_ext,
+            method_name: cmd_end_transform_feedback_ext,
+            try_method_name: try_cmd_end_transform_feedback_ext,
+            unchecked_method_name: cmd_end_transform_feedback_ext_unchecked,
+            ptr: CmdEndTransformFeedbackEXT,
             signature: (
-                buffer: Buffer,
-                p_allocator: *const AllocationCallbacks,
+                command_buffer: CommandBuffer,
+                first_counter_buffer: u32,
+                counter_buffer_count: u32,
+                p_counter_buffers: *const Buffer,
+                p_counter_buffer_offsets: *const DeviceSize,
             ),
-            takes_handle: true,
+            takes_handle: false,
+            provided_by: [
+                [VK_EXT_transform_feedback] => CmdEndTransformFeedbackEXT,
+            ],
+            hooks: [
+                command_buffer: handle,
+                first_counter_buffer: value,
+                counter_buffer_count: value,
+                p_counter_buffers: handles[counter_buffer_count],
+                p_counter_buffer_offsets: ptr[counter_buffer_count],
+            ],
         },
         {
-            name: pfn_create_buffer_view,
-            method_name: create_buffer_view,
-            ptr: CreateBufferView,
+            name: pfn_cmd_begin_query_indexed_ext,
+            method_name: cmd_begin_query_indexed_ext,
+            try_method_name: try_cmd_begin_query_indexed_ext,
+            unchecked_method_name: cmd_begin_query_indexed_ext_unchecked,
+            ptr: CmdBeginQueryIndexedEXT,
             signature: (
-                p_create_info: *const BufferViewCreateInfo,
-                p_allocator: *const AllocationCallbacks,
-                p_view: *mut BufferView,
-            ) -> Result,
-            takes_handle: true,
+                command_buffer: CommandBuffer,
+                query_pool: QueryPool,
+                query: u32,
+                flags: QueryControlFlags,
+                index: u32,
+            ),
+            takes_handle: false,
+            provided_by: [
+                [VK_EXT_transform_feedback] => CmdBeginQueryIndexedEXT,
+            ],
+            hooks: [
+                command_buffer: handle,
+                query_pool: handle,
+                query: value,
+                flags: value,
+                index: value,
+            ],
         },
         {
-            name: pfn_destroy_buffer_view,
-            method_name: destroy_buffer_view,
-            ptr: DestroyBufferView,
+            name: pfn_cmd_end_query_indexed_ext,
+            method_name: cmd_end_query_indexed_ext,
+            try_method_name: try_cmd_end_query_indexed_ext,
+            unchecked_method_name: cmd_end_query_indexed_ext_unchecked,
+            ptr: CmdEndQueryIndexedEXT,
             signature: (
-                buffer_view: BufferView,
-                p_allocator: *const AllocationCallbacks,
+                command_buffer: CommandBuffer,
+                query_pool: QueryPool,
+                query: u32,
+                index: u32,
             ),
-            takes_handle: true,
+            takes_handle: false,
+            provided_by: [
+                [VK_EXT_transform_feedback] => CmdEndQueryIndexedEXT,
+            ],
+            hooks: [
+                command_buffer: handle,
+                query_pool: handle,
+                query: value,
+                index: value,
+            ],
         },
         {
-            name: pfn_create_image,
-            method_name: create_image,
-            ptr: CreateImage,
+            name: pfn_cmd_draw_indirect_byte_count_ext,
+            method_name: cmd_draw_indirect_byte_count_ext,
+            try_method_name: try_cmd_draw_indirect_byte_count_ext,
+            unchecked_method_name: cmd_draw_indirect_byte_count_ext_unchecked,
+            ptr: CmdDrawIndirectByteCountEXT,
             signature: (
-                p_create_info: *const ImageCreateInfo,
-                p_allocator: *const AllocationCallbacks,
-                p_image: *mut Image,
-            ) -> Result,
-            takes_handle: true,
+                command_buffer: CommandBuffer,
+                instance_count: u32,
+                first_instance: u32,
+                counter_buffer: Buffer,
+                counter_buffer_offset: DeviceSize,
+                counter_offset: u32,
+                vertex_stride: u32,
+            ),
+            takes_handle: false,
+            provided_by: [
+                [VK_EXT_transform_feedback] => CmdDrawIndirectByteCountEXT,
+            ],
+            hooks: [
+                command_buffer: handle,
+                instance_count: value,
+                first_instance: value,
+                counter_buffer: handle,
+                counter_buffer_offset: value,
+                counter_offset: value,
+                vertex_stride: value,
+            ],
         },
         {
-            name: pfn_destroy_image,
-            method_name: destroy_image,
-            ptr: DestroyImage,
+            name: pfn_cmd_set_exclusive_scissor_nv,
+            method_name: cmd_set_exclusive_scissor_nv,
+            try_method_name: try_cmd_set_exclusive_scissor_nv,
+            unchecked_method_name: cmd_set_exclusive_scissor_nv_unchecked,
+            ptr: CmdSetExclusiveScissorNV,
             signature: (
-                image: Image,
-                p_allocator: *const AllocationCallbacks,
+                command_buffer: CommandBuffer,
+                first_exclusive_scissor: u32,
+                exclusive_scissor_count: u32,
+                p_exclusive_scissors: *const Rect2D,
             ),
-            takes_handle: true,
+            takes_handle: false,
+            provided_by: [
+                [VK_NV_scissor_exclusive] => CmdSetExclusiveScissorNV,
+            ],
+            hooks: [
+                command_buffer: handle,
+                first_exclusive_scissor: value,
+                exclusive_scissor_count: value,
+                p_exclusive_scissors: ptr[exclusive_scissor_count],
+            ],
         },
         {
-            name: pfn_get_image_subresource_layout,
-            method_name: get_image_subresource_layout,
-            ptr: GetImageSubresourceLayout,
+            name: pfn_cmd_bind_shading_rate_image_nv,
+            method_name: cmd_bind_shading_rate_image_nv,
+            try_method_name: try_cmd_bind_shading_rate_image_nv,
+            unchecked_method_name: cmd_bind_shading_rate_image_nv_unchecked,
+            ptr: CmdBindShadingRateImageNV,
             signature: (
-                image: Image,
-                p_subresource: *const ImageSubresource,
-                p_layout: *mut SubresourceLayout,
+                command_buffer: CommandBuffer,
+                image_view: ImageView,
+                image_layout: ImageLayout,
             ),
-            takes_handle: true,
+            takes_handle: false,
+            provided_by: [
+                [VK_NV_shading_rate_image] => CmdBindShadingRateImageNV,
+            ],
+            hooks: [
+                command_buffer: handle,
+                image_view: handle,
+                image_layout: value,
+            ],
         },
         {
-            name: pfn_create_image_view,
-            method_name: create_image_view,
-            ptr: CreateImageView,
+            name: pfn_cmd_set_viewport_shading_rate_palette_nv,
+            method_name: cmd_set_viewport_shading_rate_palette_nv,
+            try_method_name: try_cmd_set_viewport_shading_rate_palette_nv,
+            unchecked_method_name: cmd_set_viewport_shading_rate_palette_nv_unchecked,
+            ptr: CmdSetViewportShadingRatePaletteNV,
             signature: (
-                p_create_info: *const ImageViewCreateInfo,
-                p_allocator: *const AllocationCallbacks,
-                p_view: *mut ImageView,
-            ) -> Result,
-            takes_handle: true,
+                command_buffer: CommandBuffer,
+                first_viewport: u32,
+                viewport_count: u32,
+                p_shading_rate_palettes: *const ShadingRatePaletteNV,
+            ),
+            takes_handle: false,
+            provided_by: [
+                [VK_NV_shading_rate_image] => CmdSetViewportShadingRatePaletteNV,
+            ],
+            hooks: [
+                command_buffer: handle,
+                first_viewport: value,
+                viewport_count: value,
+                p_shading_rate_palettes: structs[viewport_count],
+            ],
         },
         {
-            name: pfn_destroy_image_view,
-            method_name: destroy_image_view,
-            ptr: DestroyImageView,
+            name: pfn_cmd_set_coarse_sample_order_nv,
+            method_name: cmd_set_coarse_sample_order_nv,
+            try_method_name: try_cmd_set_coarse_sample_order_nv,
+            unchecked_method_name: cmd_set_coarse_sample_order_nv_unchecked,
+            ptr: CmdSetCoarseSampleOrderNV,
             signature: (
-                image_view: ImageView,
-                p_allocator: *const AllocationCallbacks,
+                command_buffer: CommandBuffer,
+                sample_order_type: CoarseSampleOrderTypeNV,
+                custom_sample_order_count: u32,
+                p_custom_sample_orders: *const CoarseSampleOrderCustomNV,
             ),
-            takes_handle: true,
+            takes_handle: false,
+            provided_by: [
+                [VK_NV_shading_rate_image] => CmdSetCoarseSampleOrderNV,
+            ],
+            hooks: [
+                command_buffer: handle,
+                sample_order_type: value,
+                custom_sample_order_count: value,
+                p_custom_sample_orders: structs[custom_sample_order_count],
+            ],
         },
         {
-            name: pfn_create_shader_module,
-            method_name: create_shader_module,
-            ptr: CreateShaderModule,
+            name: pfn_cmd_draw_mesh_tasks_nv,
+            method_name: cmd_draw_mesh_tasks_nv,
+            try_method_name: try_cmd_draw_mesh_tasks_nv,
+            unchecked_method_name: cmd_draw_mesh_tasks_nv_unchecked,
+            ptr: CmdDrawMeshTasksNV,
             signature: (
-                p_create_info: *const ShaderModuleCreateInfo,
-                p_allocator: *const AllocationCallbacks,
-                p_shader_module: *mut ShaderModule,
-            ) -> Result,
-            takes_handle: true,
+                command_buffer: CommandBuffer,
+                task_count: u32,
+                first_task: u32,
+            ),
+            takes_handle: false,
+            provided_by: [
+                [VK_NV_mesh_shader] => CmdDrawMeshTasksNV,
+            ],
+            hooks: [
+                command_buffer: handle,
+                task_count: value,
+                first_task: value,
+            ],
         },
         {
-            name: pfn_destroy_shader_module,
-            method_name: destroy_shader_module,
-            ptr: DestroyShaderModule,
+            name: pfn_cmd_draw_mesh_tasks_indirect_nv,
+            method_name: cmd_draw_mesh_tasks_indirect_nv,
+            try_method_name: try_cmd_draw_mesh_tasks_indirect_nv,
+            unchecked_method_name: cmd_draw_mesh_tasks_indirect_nv_unchecked,
+            ptr: CmdDrawMeshTasksIndirectNV,
             signature: (
-                shader_module: ShaderModule,
-                p_allocator: *const AllocationCallbacks,
+                command_buffer: CommandBuffer,
+                buffer: Buffer,
+                offset: DeviceSize,
+                draw_count: u32,
+                stride: u32,
             ),
-            takes_handle: true,
+            takes_handle: false,
+            provided_by: [
+                [VK_NV_mesh_shader] => CmdDrawMeshTasksIndirectNV,
+            ],
+            hooks: [
+                command_buffer: handle,
+                buffer: handle,
+                offset: value,
+                draw_count: value,
+                stride: value,
+            ],
         },
         {
-            name: pfn_create_pipeline_cache,
-            method_name: create_pipeline_cache,
-            ptr: CreatePipelineCache,
+            name: pfn_cmd_draw_mesh_tasks_indirect_count_nv,
+            method_name: cmd_draw_mesh_tasks_indirect_count_nv,
+            try_method_name: try_cmd_draw_mesh_tasks_indirect_count_nv,
+            unchecked_method_name: cmd_draw_mesh_tasks_indirect_count_nv_unchecked,
+            ptr: CmdDrawMeshTasksIndirectCountNV,
             signature: (
-                p_create_info: *const PipelineCacheCreateInfo,
-                p_allocator: *const AllocationCallbacks,
-                p_pipeline_cache: *mut PipelineCache,
-            ) -> Result,
-            takes_handle: true,
+                command_buffer: CommandBuffer,
+                buffer: Buffer,
+                offset: DeviceSize,
+                count_buffer: Buffer,
+                count_buffer_offset: DeviceSize,
+                max_draw_count: u32,
+                stride: u32,
+            ),
+            takes_handle: false,
+            provided_by: [
+                [VK_NV_mesh_shader] => CmdDrawMeshTasksIndirectCountNV,
+            ],
+            hooks: [
+                command_buffer: handle,
+                buffer: handle,
+                offset: value,
+                count_buffer: handle,
+                count_buffer_offset: value,
+                max_draw_count: value,
+                stride: value,
+            ],
         },
         {
-            name: pfn_destroy_pipeline_cache,
-            method_name: destroy_pipeline_cache,
-            ptr: DestroyPipelineCache,
+            name: pfn_cmd_draw_mesh_tasks_ext,
+            method_name: cmd_draw_mesh_tasks_ext,
+            try_method_name: try_cmd_draw_mesh_tasks_ext,
+            unchecked_method_name: cmd_draw_mesh_tasks_ext_unchecked,
+            ptr: CmdDrawMeshTasksEXT,
             signature: (
-                pipeline_cache: PipelineCache,
-                p_allocator: *const AllocationCallbacks,
+                command_buffer: CommandBuffer,
+                group_count_x: u32,
+                group_count_y: u32,
+                group_count_z: u32,
             ),
-            takes_handle: true,
+            takes_handle: false,
+            provided_by: [
+                [VK_EXT_mesh_shader] => CmdDrawMeshTasksEXT,
+            ],
+            hooks: [
+                command_buffer: handle,
+                group_count_x: value,
+                group_count_y: value,
+                group_count_z: value,
+            ],
         },
         {
-            name: pfn_get_pipeline_cache_data,
-            method_name: get_pipeline_cache_data,
-            ptr: GetPipelineCacheData,
+            name: pfn_cmd_draw_mesh_tasks_indirect_ext,
+            method_name: cmd_draw_mesh_tasks_indirect_ext,
+            try_method_name: try_cmd_draw_mesh_tasks_indirect_ext,
+            unchecked_method_name: cmd_draw_mesh_tasks_indirect_ext_unchecked,
+            ptr: CmdDrawMeshTasksIndirectEXT,
             signature: (
-                pipeline_cache: PipelineCache,
-                p_data_size: *mut usize,
-                p_data: *mut c_void,
-            ) -> Result,
-            takes_handle: true,
+                command_buffer: CommandBuffer,
+                buffer: Buffer,
+                offset: DeviceSize,
+                draw_count: u32,
+                stride: u32,
+            ),
+            takes_handle: false,
+            provided_by: [
+                [VK_EXT_mesh_shader] => CmdDrawMeshTasksIndirectEXT,
+            ],
+            hooks: [
+                command_buffer: handle,
+                buffer: handle,
+                offset: value,
+                draw_count: value,
+                stride: value,
+            ],
         },
         {
-            name: pfn_merge_pipeline_caches,
-            method_name: merge_pipeline_caches,
-            ptr: MergePipelineCaches,
+            name: pfn_cmd_draw_mesh_tasks_indirect_count_ext,
+            method_name: cmd_draw_mesh_tasks_indirect_count_ext,
+            try_method_name: try_cmd_draw_mesh_tasks_indirect_count_ext,
+            unchecked_method_name: cmd_draw_mesh_tasks_indirect_count_ext_unchecked,
+            ptr: CmdDrawMeshTasksIndirectCountEXT,
             signature: (
-                dst_cache: PipelineCache,
-                src_cache_count: u32,
-                p_src_caches: *const PipelineCache,
-            ) -> Result,
-            takes_handle: true,
+                command_buffer: CommandBuffer,
+                buffer: Buffer,
+                offset: DeviceSize,
+                count_buffer: Buffer,
+                count_buffer_offset: DeviceSize,
+                max_draw_count: u32,
+                stride: u32,
+            ),
+            takes_handle: false,
+            provided_by: [
+                [VK_EXT_mesh_shader] => CmdDrawMeshTasksIndirectCountEXT,
+            ],
+            hooks: [
+                command_buffer: handle,
+                buffer: handle,
+                offset: value,
+                count_buffer: handle,
+                count_buffer_offset: value,
+                max_draw_count: value,
+                stride: value,
+            ],
         },
         {
-            name: pfn_create_graphics_pipelines,
-            method_name: create_graphics_pipelines,
-            ptr: CreateGraphicsPipelines,
+            name: pfn_compile_deferred_nv,
+            method_name: compile_deferred_nv,
+            try_method_name: try_compile_deferred_nv,
+            unchecked_method_name: compile_deferred_nv_unchecked,
+            ptr: CompileDeferredNV,
             signature: (
-                pipeline_cache: PipelineCache,
-                create_info_count: u32,
-                p_create_infos: *const GraphicsPipelineCreateInfo,
-                p_allocator: *const AllocationCallbacks,
-                p_pipelines: *mut Pipeline,
+                pipeline: Pipeline,
+                shader: u32,
             ) -> Result,
             takes_handle: true,
+            provided_by: [
+                [VK_NV_ray_tracing] => CompileDeferredNV,
+            ],
+            hooks: [
+                pipeline: handle,
+                shader: value,
+            ],
         },
         {
-            name: pfn_create_compute_pipelines,
-            method_name: create_compute_pipelines,
-            ptr: CreateComputePipelines,
+            name: pfn_create_acceleration_structure_nv,
+            method_name: create_acceleration_structure_nv,
+            try_method_name: try_create_acceleration_structure_nv,
+            unchecked_method_name: create_acceleration_structure_nv_unchecked,
+            ptr: CreateAccelerationStructureNV,
             signature: (
-                pipeline_cache: PipelineCache,
-                create_info_count: u32,
-                p_create_infos: *const ComputePipelineCreateInfo,
+                p_create_info: *const AccelerationStructureCreateInfoNV,
                 p_allocator: *const AllocationCallbacks,
-                p_pipelines: *mut Pipeline,
+                p_acceleration_structure: *mut AccelerationStructureNV,
             ) -> Result,
             takes_handle: true,
+            provided_by: [
+                [VK_NV_ray_tracing] => CreateAccelerationStructureNV,
+            ],
+            hooks: [
+                p_create_info: structs,
+                p_allocator: structs,
+                p_acceleration_structure: handles,
+            ],
+            checked: {
+                method_name: create_acceleration_structure_nv_checked,
+                args: (
+                    p_create_info: *const AccelerationStructureCreateInfoNV,
+                    p_allocator: Option<&AllocationCallbacks>,
+                ),
+                output: p_acceleration_structure: AccelerationStructureNV,
+                result: success,
+            },
         },
         {
-            name: pfn_get_device_subpass_shading_max_workgroup_size_huawei,
-            method_name: get_device_subpass_shading_max_workgroup_size_huawei,
-            ptr: GetDeviceSubpassShadingMaxWorkgroupSizeHUAWEI,
+            name: pfn_cmd_bind_invocation_mask_huawei,
+            method_name: cmd_bind_invocation_mask_huawei,
+            try_method_name: try_cmd_bind_invocation_mask_huawei,
+            unchecked_method_name: cmd_bind_invocation_mask_huawei_unchecked,
+            ptr: CmdBindInvocationMaskHUAWEI,
             signature: (
-                renderpass: RenderPass,
-                p_max_workgroup_size: *mut Extent2D,
-            ) -> Result,
-            takes_handle: true,
+                command_buffer: CommandBuffer,
+                image_view: ImageView,
+                image_layout: ImageLayout,
+            ),
+            takes_handle: false,
+            provided_by: [
+                [VK_HUAWEI_invocation_mask] => CmdBindInvocationMaskHUAWEI,
+            ],
+            hooks: [
+                command_buffer: handle,
+                image_view: handle,
+                image_layout: value,
+            ],
         },
         {
-            name: pfn_destroy_pipeline,
-            method_name: destroy_pipeline,
-            ptr: DestroyPipeline,
+            name: pfn_destroy_acceleration_structure_khr,
+            method_name: destroy_acceleration_structure_khr,
+            try_method_name: try_destroy_acceleration_structure_khr,
+            unchecked_method_name: destroy_acceleration_structure_khr_unchecked,
+            ptr: DestroyAccelerationStructureKHR,
             signature: (
-                pipeline: Pipeline,
+                acceleration_structure: AccelerationStructureKHR,
                 p_allocator: *const AllocationCallbacks,
             ),
             takes_handle: true,
+            provided_by: [
+                [VK_KHR_acceleration_structure] => DestroyAccelerationStructureKHR,
+            ],
+            hooks: [
+                acceleration_structure: handle,
+                p_allocator: structs,
+            ],
         },
         {
-            name: pfn_create_pipeline_layout,
-            method_name: create_pipeline_layout,
-            ptr: CreatePipelineLayout,
+            name: pfn_destroy_acceleration_structure_nv,
+            method_name: destroy_acceleration_structure_nv,
+            try_method_name: try_destroy_acceleration_structure_nv,
+            unchecked_method_name: destroy_acceleration_structure_nv_unchecked,
+            ptr: DestroyAccelerationStructureNV,
             signature: (
-                p_create_info: *const PipelineLayoutCreateInfo,
+                acceleration_structure: AccelerationStructureNV,
                 p_allocator: *const AllocationCallbacks,
-                p_pipeline_layout: *mut PipelineLayout,
-            ) -> Result,
+            ),
             takes_handle: true,
+            provided_by: [
+                [VK_NV_ray_tracing] => DestroyAccelerationStructureNV,
+            ],
+            hooks: [
+                acceleration_structure: handle,
+                p_allocator: structs,
+            ],
         },
         {
-            name: pfn_destroy_pipeline_layout,
-            method_name: destroy_pipeline_layout,
-            ptr: DestroyPipelineLayout,
+            name: pfn_get_acceleration_structure_memory_requirements_nv,
+            method_name: get_acceleration_structure_memory_requirements_nv,
+            try_method_name: try_get_acceleration_structure_memory_requirements_nv,
+            unchecked_method_name: get_acceleration_structure_memory_requirements_nv_unchecked,
+            ptr: GetAccelerationStructureMemoryRequirementsNV,
             signature: (
-                pipeline_layout: PipelineLayout,
-                p_allocator: *const AllocationCallbacks,
+                p_info: *const AccelerationStructureMemoryRequirementsInfoNV,
+                p_memory_requirements: *mut MemoryRequirements2KHR,
             ),
             takes_handle: true,
+            provided_by: [
+                [VK_NV_ray_tracing] => GetAccelerationStructureMemoryRequirementsNV,
+            ],
+            hooks: [
+                p_info: structs,
+                p_memory_requirements: structs,
+            ],
+            checked: {
+                method_name: get_acceleration_structure_memory_requirements_nv_checked,
+                args: (
+                    p_info: *const AccelerationStructureMemoryRequirementsInfoNV,
+                ),
+                output: p_memory_requirements: MemoryRequirements2KHR,
+                result: void,
+            },
         },
         {
-            name: pfn_create_sampler,
-            method_name: create_sampler,
-            ptr: CreateSampler,
+            name: pfn_bind_acceleration_structure_memory_nv,
+            method_name: bind_acceleration_structure_memory_nv,
+            try_method_name: try_bind_acceleration_structure_memory_nv,
+            unchecked_method_name: bind_acceleration_structure_memory_nv_unchecked,
+            ptr: BindAccelerationStructureMemoryNV,
             signature: (
-                p_create_info: *const SamplerCreateInfo,
-                p_allocator: *const AllocationCallbacks,
-                p_sampler: *mut Sampler,
+                bind_info_count: u32,
+                p_bind_infos: *const BindAccelerationStructureMemoryInfoNV,
             ) -> Result,
             takes_handle: true,
+            provided_by: [
+                [VK_NV_ray_tracing] => BindAccelerationStructureMemoryNV,
+            ],
+            hooks: [
+                bind_info_count: value,
+                p_bind_infos: structs[bind_info_count],
+            ],
         },
         {
-            name: pfn_destroy_sampler,
-            method_name: destroy_sampler,
-            ptr: DestroySampler,
+            name: pfn_cmd_copy_acceleration_structure_nv,
+            method_name: cmd_copy_acceleration_structure_nv,
+            try_method_name: try_cmd_copy_acceleration_structure_nv,
+            unchecked_method_name: cmd_copy_acceleration_structure_nv_unchecked,
+            ptr: CmdCopyAccelerationStructureNV,
             signature: (
-                sampler: Sampler,
-                p_allocator: *const AllocationCallbacks,
+                command_buffer: CommandBuffer,
+                dst: AccelerationStructureNV,
+                src: AccelerationStructureNV,
+                mode: CopyAccelerationStructureModeKHR,
             ),
-            takes_handle: true,
-        },
-        {
-            name: pfn_create_descriptor_set_layout,
-            method_name: create_descriptor_set_layout,
-            ptr: CreateDescriptorSetLayout,
-            signature: (
-                p_create_info: *const DescriptorSetLayoutCreateInfo,
-                p_allocator: *const AllocationCallbacks,
-                p_set_layout: *mut DescriptorSetLayout,
-            ) -> Result,
-            takes_handle: true,
+            takes_handle: false,
+            provided_by: [
+                [VK_NV_ray_tracing] => CmdCopyAccelerationStructureNV,
+            ],
+            hooks: [
+                command_buffer: handle,
+                dst: handle,
+                src: handle,
+                mode: value,
+            ],
         },
         {
-            name: pfn_destroy_descriptor_set_layout,
-            method_name: destroy_descriptor_set_layout,
-            ptr: DestroyDescriptorSetLayout,
+            name: pfn_cmd_copy_acceleration_structure_khr,
+            method_name: cmd_copy_acceleration_structure_khr,
+            try_method_name: try_cmd_copy_acceleration_structure_khr,
+            unchecked_method_name: cmd_copy_acceleration_structure_khr_unchecked,
+            ptr: CmdCopyAccelerationStructureKHR,
             signature: (
-                descriptor_set_layout: DescriptorSetLayout,
-                p_allocator: *const AllocationCallbacks,
+                command_buffer: CommandBuffer,
+                p_info: *const CopyAccelerationStructureInfoKHR,
             ),
-            takes_handle: true,
+            takes_handle: false,
+            provided_by: [
+                [VK_KHR_acceleration_structure] => CmdCopyAccelerationStructureKHR,
+            ],
+            hooks: [
+                command_buffer: handle,
+                p_info: structs,
+            ],
         },
         {
-            name: pfn_create_descriptor_pool,
-            method_name: create_descriptor_pool,
-            ptr: CreateDescriptorPool,
+            name: pfn_copy_acceleration_structure_khr,
+            method_name: copy_acceleration_structure_khr,
+            try_method_name: try_copy_acceleration_structure_khr,
+            unchecked_method_name: copy_acceleration_structure_khr_unchecked,
+            ptr: CopyAccelerationStructureKHR,
             signature: (
-                p_create_info: *const DescriptorPoolCreateInfo,
-                p_allocator: *const AllocationCallbacks,
-                p_descriptor_pool: *mut DescriptorPool,
+                deferred_operation: DeferredOperationKHR,
+                p_info: *const CopyAccelerationStructureInfoKHR,
             ) -> Result,
             takes_handle: true,
+            provided_by: [
+                [VK_KHR_acceleration_structure] => CopyAccelerationStructureKHR,
+            ],
+            hooks: [
+                deferred_operation: handle,
+                p_info: structs,
+            ],
         },
         {
-            name: pfn_destroy_descriptor_pool,
-            method_name: destroy_descriptor_pool,
-            ptr: DestroyDescriptorPool,
+            name: pfn_cmd_copy_acceleration_structure_to_memory_khr,
+            method_name: cmd_copy_acceleration_structure_to_memory_khr,
+            try_method_name: try_cmd_copy_acceleration_structure_to_memory_khr,
+            unchecked_method_name: cmd_copy_acceleration_structure_to_memory_khr_unchecked,
+            ptr: CmdCopyAccelerationStructureToMemoryKHR,
             signature: (
-                descriptor_pool: DescriptorPool,
-                p_allocator: *const AllocationCallbacks,
+                command_buffer: CommandBuffer,
+                p_info: *const CopyAccelerationStructureToMemoryInfoKHR,
             ),
-            takes_handle: true,
+            takes_handle: false,
+            provided_by: [
+                [VK_KHR_acceleration_structure] => CmdCopyAccelerationStructureToMemoryKHR,
+            ],
+            hooks: [
+                command_buffer: handle,
+                p_info: structs,
+            ],
         },
         {
-            name: pfn_reset_descriptor_pool,
-            method_name: reset_descriptor_pool,
-            ptr: ResetDescriptorPool,
+            name: pfn_copy_acceleration_structure_to_memory_khr,
+            method_name: copy_acceleration_structure_to_memory_khr,
+            try_method_name: try_copy_acceleration_structure_to_memory_khr,
+            unchecked_method_name: copy_acceleration_structure_to_memory_khr_unchecked,
+            ptr: CopyAccelerationStructureToMemoryKHR,
             signature: (
-                descriptor_pool: DescriptorPool,
-                flags: DescriptorPoolResetFlags,
+                deferred_operation: DeferredOperationKHR,
+                p_info: *const CopyAccelerationStructureToMemoryInfoKHR,
             ) -> Result,
             takes_handle: true,
+            provided_by: [
+                [VK_KHR_acceleration_structure] => CopyAccelerationStructureToMemoryKHR,
+            ],
+            hooks: [
+                deferred_operation: handle,
+                p_info: structs,
+            ],
         },
         {
-            name: pfn_allocate_descriptor_sets,
-            method_name: allocate_descriptor_sets,
-            ptr: AllocateDescriptorSets,
+            name: pfn_cmd_copy_memory_to_acceleration_structure_khr,
+            method_name: cmd_copy_memory_to_acceleration_structure_khr,
+            try_method_name: try_cmd_copy_memory_to_acceleration_structure_khr,
+            unchecked_method_name: cmd_copy_memory_to_acceleration_structure_khr_unchecked,
+            ptr: CmdCopyMemoryToAccelerationStructureKHR,
             signature: (
-                p_allocate_info: *const DescriptorSetAllocateInfo,
-                p_descriptor_sets: *mut DescriptorSet,
-            ) -> Result,
-            takes_handle: true,
+                command_buffer: CommandBuffer,
+                p_info: *const CopyMemoryToAccelerationStructureInfoKHR,
+            ),
+            takes_handle: false,
+            provided_by: [
+                [VK_KHR_acceleration_structure] => CmdCopyMemoryToAccelerationStructureKHR,
+            ],
+            hooks: [
+                command_buffer: handle,
+                p_info: structs,
+            ],
         },
         {
-            name: pfn_free_descriptor_sets,
-            method_name: free_descriptor_sets,
-            ptr: FreeDescriptorSets,
+            name: pfn_copy_memory_to_acceleration_structure_khr,
+            method_name: copy_memory_to_acceleration_structure_khr,
+            try_method_name: try_copy_memory_to_acceleration_structure_khr,
+            unchecked_method_name: copy_memory_to_acceleration_structure_khr_unchecked,
+            ptr: CopyMemoryToAccelerationStructureKHR,
             signature: (
-                descriptor_pool: DescriptorPool,
-                descriptor_set_count: u32,
-                p_descriptor_sets: *const DescriptorSet,
+                deferred_operation: DeferredOperationKHR,
+                p_info: *const CopyMemoryToAccelerationStructureInfoKHR,
             ) -> Result,
             takes_handle: true,
+            provided_by: [
+                [VK_KHR_acceleration_structure] => CopyMemoryToAccelerationStructureKHR,
+            ],
+            hooks: [
+                deferred_operation: handle,
+                p_info: structs,
+            ],
         },
         {
-            name: pfn_update_descriptor_sets,
-            method_name: update_descriptor_sets,
-            ptr: UpdateDescriptorSets,
+            name: pfn_cmd_write_acceleration_structures_properties_khr,
+            method_name: cmd_write_acceleration_structures_properties_khr,
+            try_method_name: try_cmd_write_acceleration_structures_properties_khr,
+            unchecked_method_name: cmd_write_acceleration_structures_properties_khr_unchecked,
+            ptr: CmdWriteAccelerationStructuresPropertiesKHR,
             signature: (
-                descriptor_write_count: u32,
-                p_descriptor_writes: *const WriteDescriptorSet,
-                descriptor_copy_count: u32,
-                p_descriptor_copies: *const CopyDescriptorSet,
+                command_buffer: CommandBuffer,
+                acceleration_structure_count: u32,
+                p_acceleration_structures: *const AccelerationStructureKHR,
+                query_type: QueryType,
+                query_pool: QueryPool,
+                first_query: u32,
             ),
-            takes_handle: true,
+            takes_handle: false,
+            provided_by: [
+                [VK_KHR_acceleration_structure] => CmdWriteAccelerationStructuresPropertiesKHR,
+            ],
+            hooks: [
+                command_buffer: handle,
+                acceleration_structure_count: value,
+                p_acceleration_structures: handles[acceleration_structure_count],
+                query_type: value,
+                query_pool: handle,
+                first_query: value,
+            ],
         },
         {
-            name: pfn_create_framebuffer,
-            method_name: create_framebuffer,
-            ptr: CreateFramebuffer,
+            name: pfn_cmd_write_acceleration_structures_properties_nv,
+            method_name: cmd_write_acceleration_structures_properties_nv,
+            try_method_name: try_cmd_write_acceleration_structures_properties_nv,
+            unchecked_method_name: cmd_write_acceleration_structures_properties_nv_unchecked,
+            ptr: CmdWriteAccelerationStructuresPropertiesNV,
             signature: (
-                p_create_info: *const FramebufferCreateInfo,
-                p_allocator: *const AllocationCallbacks,
-                p_framebuffer: *mut Framebuffer,
-            ) -> Result,
-            takes_handle: true,
+                command_buffer: CommandBuffer,
+                acceleration_structure_count: u32,
+                p_acceleration_structures: *const AccelerationStructureNV,
+                query_type: QueryType,
+                query_pool: QueryPool,
+                first_query: u32,
+            ),
+            takes_handle: false,
+            provided_by: [
+                [VK_NV_ray_tracing] => CmdWriteAccelerationStructuresPropertiesNV,
+            ],
+            hooks: [
+                command_buffer: handle,
+                acceleration_structure_count: value,
+                p_acceleration_structures: handles[acceleration_structure_count],
+                query_type: value,
+                query_pool: handle,
+                first_query: value,
+            ],
         },
         {
-            name: pfn_destroy_framebuffer,
-            method_name: destroy_framebuffer,
-            ptr: DestroyFramebuffer,
+            name: pfn_cmd_build_acceleration_structure_nv,
+            method_name: cmd_build_acceleration_structure_nv,
+            try_method_name: try_cmd_build_acceleration_structure_nv,
+            unchecked_method_name: cmd_build_acceleration_structure_nv_unchecked,
+            ptr: CmdBuildAccelerationStructureNV,
             signature: (
-                framebuffer: Framebuffer,
-                p_allocator: *const AllocationCallbacks,
+                command_buffer: CommandBuffer,
+                p_info: *const AccelerationStructureInfoNV,
+                instance_data: Buffer,
+                instance_offset: DeviceSize,
+                update: Bool32,
+                dst: AccelerationStructureNV,
+                src: AccelerationStructureNV,
+                scratch: Buffer,
+                scratch_offset: DeviceSize,
             ),
-            takes_handle: true,
+            takes_handle: false,
+            provided_by: [
+                [VK_NV_ray_tracing] => CmdBuildAccelerationStructureNV,
+            ],
+            hooks: [
+                command_buffer: handle,
+                p_info: structs,
+                instance_data: handle,
+                instance_offset: value,
+                update: value,
+                dst: handle,
+                src: handle,
+                scratch: handle,
+                scratch_offset: value,
+            ],
         },
         {
-            name: pfn_create_render_pass,
-            method_name: create_render_pass,
-            ptr: CreateRenderPass,
+            name: pfn_write_acceleration_structures_properties_khr,
+            method_name: write_acceleration_structures_properties_khr,
+            try_method_name: try_write_acceleration_structures_properties_khr,
+            unchecked_method_name: write_acceleration_structures_properties_khr_unchecked,
+            ptr: WriteAccelerationStructuresPropertiesKHR,
             signature: (
-                p_create_info: *const RenderPassCreateInfo,
-                p_allocator: *const AllocationCallbacks,
-                p_render_pass: *mut RenderPass,
+                acceleration_structure_count: u32,
+                p_acceleration_structures: *const AccelerationStructureKHR,
+                query_type: QueryType,
+                data_size: usize,
+                p_data: *mut c_void,
+                stride: usize,
             ) -> Result,
             takes_handle: true,
+            provided_by: [
+                [VK_KHR_acceleration_structure] => WriteAccelerationStructuresPropertiesKHR,
+            ],
+            hooks: [
+                acceleration_structure_count: value,
+                p_acceleration_structures: handles[acceleration_structure_count],
+                query_type: value,
+                data_size: value,
+                p_data: bytes[data_size],
+                stride: value,
+            ],
         },
         {
-            name: pfn_destroy_render_pass,
-            method_name: destroy_render_pass,
-            ptr: DestroyRenderPass,
+            name: pfn_cmd_trace_rays_khr,
+            method_name: cmd_trace_rays_khr,
+            try_method_name: try_cmd_trace_rays_khr,
+            unchecked_method_name: cmd_trace_rays_khr_unchecked,
+            ptr: CmdTraceRaysKHR,
             signature: (
-                render_pass: RenderPass,
-                p_allocator: *const AllocationCallbacks,
+                command_buffer: CommandBuffer,
+                p_raygen_shader_binding_table: *const StridedDeviceAddressRegionKHR,
+                p_miss_shader_binding_table: *const StridedDeviceAddressRegionKHR,
+                p_hit_shader_binding_table: *const StridedDeviceAddressRegionKHR,
+                p_callable_shader_binding_table: *const StridedDeviceAddressRegionKHR,
+                width: u32,
+                height: u32,
+                depth: u32,
             ),
-            takes_handle: true,
+            takes_handle: false,
+            provided_by: [
+                [VK_KHR_ray_tracing_pipeline] => CmdTraceRaysKHR,
+            ],
+            hooks: [
+                command_buffer: handle,
+                p_raygen_shader_binding_table: ptr,
+                p_miss_shader_binding_table: ptr,
+                p_hit_shader_binding_table: ptr,
+                p_callable_shader_binding_table: ptr,
+                width: value,
+                height: value,
+                depth: value,
+            ],
         },
         {
-            name: pfn_get_render_area_granularity,
-            method_name: get_render_area_granularity,
-            ptr: GetRenderAreaGranularity,
+            name: pfn_cmd_trace_rays_nv,
+            method_name: cmd_trace_rays_nv,
+            try_method_name: try_cmd_trace_rays_nv,
+            unchecked_method_name: cmd_trace_rays_nv_unchecked,
+            ptr: CmdTraceRaysNV,
             signature: (
-                render_pass: RenderPass,
-                p_granularity: *mut Extent2D,
+                command_buffer: CommandBuffer,
+                raygen_shader_binding_table_buffer: Buffer,
+                raygen_shader_binding_offset: DeviceSize,
+                miss_shader_binding_table_buffer: Buffer,
+                miss_shader_binding_offset: DeviceSize,
+                miss_shader_binding_stride: DeviceSize,
+                hit_shader_binding_table_buffer: Buffer,
+                hit_shader_binding_offset: DeviceSize,
+                hit_shader_binding_stride: DeviceSize,
+                callable_shader_binding_table_buffer: Buffer,
+                callable_shader_binding_offset: DeviceSize,
+                callable_shader_binding_stride: DeviceSize,
+                width: u32,
+                height: u32,
+                depth: u32,
             ),
-            takes_handle: true,
+            takes_handle: false,
+            provided_by: [
+                [VK_NV_ray_tracing] => CmdTraceRaysNV,
+            ],
+            hooks: [
+                command_buffer: handle,
+                raygen_shader_binding_table_buffer: handle,
+                raygen_shader_binding_offset: value,
+                miss_shader_binding_table_buffer: handle,
+                miss_shader_binding_offset: value,
+                miss_shader_binding_stride: value,
+                hit_shader_binding_table_buffer: handle,
+                hit_shader_binding_offset: value,
+                hit_shader_binding_stride: value,
+                callable_shader_binding_table_buffer: handle,
+                callable_shader_binding_offset: value,
+                callable_shader_binding_stride: value,
+                width: value,
+                height: value,
+                depth: value,
+            ],
         },
         {
-            name: pfn_create_command_pool,
-            method_name: create_command_pool,
-            ptr: CreateCommandPool,
+            name: pfn_get_ray_tracing_shader_group_handles_khr,
+            method_name: get_ray_tracing_shader_group_handles_khr,
+            try_method_name: try_get_ray_tracing_shader_group_handles_khr,
+            unchecked_method_name: get_ray_tracing_shader_group_handles_khr_unchecked,
+            ptr: GetRayTracingShaderGroupHandlesKHR,
+            aliases: [GetRayTracingShaderGroupHandlesNV],
             signature: (
-                p_create_info: *const CommandPoolCreateInfo,
-                p_allocator: *const AllocationCallbacks,
-                p_command_pool: *mut CommandPool,
+                pipeline: Pipeline,
+                first_group: u32,
+                group_count: u32,
+                data_size: usize,
+                p_data: *mut c_void,
             ) -> Result,
             takes_handle: true,
+            provided_by: [
+                [VK_KHR_ray_tracing_pipeline] => GetRayTracingShaderGroupHandlesKHR,
+                [VK_NV_ray_tracing] => GetRayTracingShaderGroupHandlesNV,
+            ],
+            hooks: [
+                pipeline: handle,
+                first_group: value,
+                group_count: value,
+                data_size: value,
+                p_data: bytes[data_size],
+            ],
         },
         {
-            name: pfn_destroy_command_pool,
-            method_name: destroy_command_pool,
-            ptr: DestroyCommandPool,
-            signature: (
-                command_pool: CommandPool,
-                p_allocator: *const AllocationCallbacks,
-            ),
-            takes_handle: true,
-        },
-        {
-            name: pfn_reset_command_pool,
-            method_name: reset_command_pool,
-            ptr: ResetCommandPool,
+            name: pfn_get_ray_tracing_capture_replay_shader_group_handles_khr,
+            method_name: get_ray_tracing_capture_replay_shader_group_handles_khr,
+            try_method_name: try_get_ray_tracing_capture_replay_shader_group_handles_khr,
+            unchecked_method_name: get_ray_tracing_capture_replay_shader_group_handles_khr_unchecked,
+            ptr: GetRayTracingCaptureReplayShaderGroupHandlesKHR,
             signature: (
-                command_pool: CommandPool,
-                flags: CommandPoolResetFlags,
+                pipeline: Pipeline,
+                first_group: u32,
+                group_count: u32,
+                data_size: usize,
+                p_data: *mut c_void,
             ) -> Result,
             takes_handle: true,
+            provided_by: [
+                [VK_KHR_ray_tracing_pipeline] => GetRayTracingCaptureReplayShaderGroupHandlesKHR,
+            ],
+            hooks: [
+                pipeline: handle,
+                first_group: value,
+                group_count: value,
+                data_size: value,
+                p_data: bytes[data_size],
+            ],
         },
         {
-            name: pfn_allocate_command_buffers,
-            method_name: allocate_command_buffers,
-            ptr: AllocateCommandBuffers,
+            name: pfn_get_acceleration_structure_handle_nv,
+            method_name: get_acceleration_structure_handle_nv,
+            try_method_name: try_get_acceleration_structure_handle_nv,
+            unchecked_method_name: get_acceleration_structure_handle_nv_unchecked,
+            ptr: GetAccelerationStructureHandleNV,
             signature: (
-                p_allocate_info: *const CommandBufferAllocateInfo,
-                p_command_buffers: *mut CommandBuffer,
+                acceleration_structure: AccelerationStructureNV,
+                data_size: usize,
+                p_data: *mut c_void,
             ) -> Result,
             takes_handle: true,
+            provided_by: [
+                [VK_NV_ray_tracing] => GetAccelerationStructureHandleNV,
+            ],
+            hooks: [
+                acceleration_structure: handle,
+                data_size: value,
+                p_data: bytes[data_size],
+            ],
         },
         {
-            name: pfn_free_command_buffers,
-            method_name: free_command_buffers,
-            ptr: FreeCommandBuffers,
+            name: pfn_create_ray_tracing_pipelines_nv,
+            method_name: create_ray_tracing_pipelines_nv,
+            try_method_name: try_create_ray_tracing_pipelines_nv,
+            unchecked_method_name: create_ray_tracing_pipelines_nv_unchecked,
+            ptr: CreateRayTracingPipelinesNV,
             signature: (
-                command_pool: CommandPool,
-                command_buffer_count: u32,
-                p_command_buffers: *const CommandBuffer,
-            ),
+                pipeline_cache: PipelineCache,
+                create_info_count: u32,
+                p_create_infos: *const RayTracingPipelineCreateInfoNV,
+                p_allocator: *const AllocationCallbacks,
+                p_pipelines: *mut Pipeline,
+            ) -> Result,
             takes_handle: true,
+            provided_by: [
+                [VK_NV_ray_tracing] => CreateRayTracingPipelinesNV,
+            ],
+            hooks: [
+                pipeline_cache: handle,
+                create_info_count: value,
+                p_create_infos: structs[create_info_count],
+                p_allocator: structs,
+                p_pipelines: handles[create_info_count],
+            ],
+            checked: {
+                method_name: create_ray_tracing_pipelines_nv_checked,
+                args: (
+                    pipeline_cache: PipelineCache,
+                    create_info_count: u32,
+                    p_create_infos: *const RayTracingPipelineCreateInfoNV,
+                    p_allocator: Option<&AllocationCallbacks>,
+                ),
+                output: p_pipelines: Vec<Pipeline>,
+                len: create_info_count,
+                result: status,
+            },
         },
         {
-            name: pfn_begin_command_buffer,
-            method_name: begin_command_buffer,
-            ptr: BeginCommandBuffer,
+            name: pfn_create_ray_tracing_pipelines_khr,
+            method_name: create_ray_tracing_pipelines_khr,
+            try_method_name: try_create_ray_tracing_pipelines_khr,
+            unchecked_method_name: create_ray_tracing_pipelines_khr_unchecked,
+            ptr: CreateRayTracingPipelinesKHR,
             signature: (
-                command_buffer: CommandBuffer,
-                p_begin_info: *const CommandBufferBeginInfo,
+                deferred_operation: DeferredOperationKHR,
+                pipeline_cache: PipelineCache,
+                create_info_count: u32,
+                p_create_infos: *const RayTracingPipelineCreateInfoKHR,
+                p_allocator: *const AllocationCallbacks,
+                p_pipelines: *mut Pipeline,
             ) -> Result,
-            takes_handle: false,
+            takes_handle: true,
+            provided_by: [
+                [VK_KHR_ray_tracing_pipeline] => CreateRayTracingPipelinesKHR,
+            ],
+            hooks: [
+                deferred_operation: handle,
+                pipeline_cache: handle,
+                create_info_count: value,
+                p_create_infos: structs[create_info_count],
+                p_allocator: structs,
+                p_pipelines: handles[create_info_count],
+            ],
+            checked: {
+                method_name: create_ray_tracing_pipelines_khr_checked,
+                args: (
+                    deferred_operation: DeferredOperationKHR,
+                    pipeline_cache: PipelineCache,
+                    create_info_count: u32,
+                    p_create_infos: *const RayTracingPipelineCreateInfoKHR,
+                    p_allocator: Option<&AllocationCallbacks>,
+                ),
+                output: p_pipelines: Vec<Pipeline>,
+                len: create_info_count,
+                result: status,
+            },
         },
         {
-            name: pfn_end_command_buffer,
-            method_name: end_command_buffer,
-            ptr: EndCommandBuffer,
+            name: pfn_cmd_trace_rays_indirect_khr,
+            method_name: cmd_trace_rays_indirect_khr,
+            try_method_name: try_cmd_trace_rays_indirect_khr,
+            unchecked_method_name: cmd_trace_rays_indirect_khr_unchecked,
+            ptr: CmdTraceRaysIndirectKHR,
             signature: (
                 command_buffer: CommandBuffer,
-            ) -> Result,
+                p_raygen_shader_binding_table: *const StridedDeviceAddressRegionKHR,
+                p_miss_shader_binding_table: *const StridedDeviceAddressRegionKHR,
+                p_hit_shader_binding_table: *const StridedDeviceAddressRegionKHR,
+                p_callable_shader_binding_table: *const StridedDeviceAddressRegionKHR,
+                indirect_device_address: DeviceAddress,
+            ),
             takes_handle: false,
+            provided_by: [
+                [VK_KHR_ray_tracing_pipeline] => CmdTraceRaysIndirectKHR,
+            ],
+            hooks: [
+                command_buffer: handle,
+                p_raygen_shader_binding_table: ptr,
+                p_miss_shader_binding_table: ptr,
+                p_hit_shader_binding_table: ptr,
+                p_callable_shader_binding_table: ptr,
+                indirect_device_address: value,
+            ],
         },
         {
-            name: pfn_reset_command_buffer,
-            method_name: reset_command_buffer,
-            ptr: ResetCommandBuffer,
+            name: pfn_cmd_trace_rays_indirect_2_khr,
+            method_name: cmd_trace_rays_indirect_2_khr,
+            try_method_name: try_cmd_trace_rays_indirect_2_khr,
+            unchecked_method_name: cmd_trace_rays_indirect_2_khr_unchecked,
+            ptr: CmdTraceRaysIndirect2KHR,
             signature: (
                 command_buffer: CommandBuffer,
-                flags: CommandBufferResetFlags,
-            ) -> Result,
+                indirect_device_address: DeviceAddress,
+            ),
             takes_handle: false,
+            provided_by: [
+                [VK_KHR_ray_tracing_maintenance1, VK_KHR_ray_tracing_pipeline] => CmdTraceRaysIndirect2KHR,
+            ],
+            hooks: [
+                command_buffer: handle,
+                indirect_device_address: value,
+            ],
         },
         {
-            name: pfn_cmd_bind_pipeline,
-            method_name: cmd_bind_pipeline,
-            ptr: CmdBindPipeline,
+            name: pfn_get_device_acceleration_structure_compatibility_khr,
+            method_name: get_device_acceleration_structure_compatibility_khr,
+            try_method_name: try_get_device_acceleration_structure_compatibility_khr,
+            unchecked_method_name: get_device_acceleration_structure_compatibility_khr_unchecked,
+            ptr: GetDeviceAccelerationStructureCompatibilityKHR,
             signature: (
-                command_buffer: CommandBuffer,
-                pipeline_bind_point: PipelineBindPoint,
-                pipeline: Pipeline,
+                p_version_info: *const AccelerationStructureVersionInfoKHR,
+                p_compatibility: *mut AccelerationStructureCompatibilityKHR,
             ),
-            takes_handle: false,
+            takes_handle: true,
+            provided_by: [
+                [VK_KHR_acceleration_structure] => GetDeviceAccelerationStructureCompatibilityKHR,
+            ],
+            hooks: [
+                p_version_info: structs,
+                p_compatibility: ptr,
+            ],
+            checked: {
+                method_name: get_device_acceleration_structure_compatibility_khr_checked,
+                args: (
+                    p_version_info: *const AccelerationStructureVersionInfoKHR,
+                ),
+                output: p_compatibility: AccelerationStructureCompatibilityKHR,
+                result: void,
+            },
         },
         {
-            name: pfn_cmd_set_viewport,
-            method_name: cmd_set_viewport,
-            ptr: CmdSetViewport,
+            name: pfn_get_ray_tracing_shader_group_stack_size_khr,
+            method_name: get_ray_tracing_shader_group_stack_size_khr,
+            try_method_name: try_get_ray_tracing_shader_group_stack_size_khr,
+            unchecked_method_name: get_ray_tracing_shader_group_stack_size_khr_unchecked,
+            ptr: GetRayTracingShaderGroupStackSizeKHR,
             signature: (
-                command_buffer: CommandBuffer,
-                first_viewport: u32,
-                viewport_count: u32,
-                p_viewports: *const Viewport,
-            ),
-            takes_handle: false,
+                pipeline: Pipeline,
+                group: u32,
+                group_shader: ShaderGroupShaderKHR,
+            ) -> DeviceSize,
+            takes_handle: true,
+            provided_by: [
+                [VK_KHR_ray_tracing_pipeline] => GetRayTracingShaderGroupStackSizeKHR,
+            ],
+            hooks: [
+                pipeline: handle,
+                group: value,
+                group_shader: value,
+            ],
         },
         {
-            name: pfn_cmd_set_scissor,
-            method_name: cmd_set_scissor,
-            ptr: CmdSetScissor,
+            name: pfn_cmd_set_ray_tracing_pipeline_stack_size_khr,
+            method_name: cmd_set_ray_tracing_pipeline_stack_size_khr,
+            try_method_name: try_cmd_set_ray_tracing_pipeline_stack_size_khr,
+            unchecked_method_name: cmd_set_ray_tracing_pipeline_stack_size_khr_unchecked,
+            ptr: CmdSetRayTracingPipelineStackSizeKHR,
             signature: (
                 command_buffer: CommandBuffer,
-                first_scissor: u32,
-                scissor_count: u32,
-                p_scissors: *const Rect2D,
+                pipeline_stack_size: u32,
             ),
             takes_handle: false,
+            provided_by: [
+                [VK_KHR_ray_tracing_pipeline] => CmdSetRayTracingPipelineStackSizeKHR,
+            ],
+            hooks: [
+                command_buffer: handle,
+                pipeline_stack_size: value,
+            ],
         },
         {
-            name: pfn_cmd_set_line_width,
-            method_name: cmd_set_line_width,
-            ptr: CmdSetLineWidth,
+            name: pfn_get_image_view_handle_nvx,
+            method_name: get_image_view_handle_nvx,
+            try_method_name: try_get_image_view_handle_nvx,
+            unchecked_method_name: get_image_view_handle_nvx_unchecked,
+            ptr: GetImageViewHandleNVX,
             signature: (
-                command_buffer: CommandBuffer,
-                line_width: c_float,
-            ),
-            takes_handle: false,
+                p_info: *const ImageViewHandleInfoNVX,
+            ) -> u32,
+            takes_handle: true,
+            provided_by: [
+                [VK_NVX_image_view_handle] => GetImageViewHandleNVX,
+            ],
+            hooks: [
+                p_info: structs,
+            ],
         },
         {
-            name: pfn_cmd_set_depth_bias,
-            method_name: cmd_set_depth_bias,
-            ptr: CmdSetDepthBias,
+            name: pfn_get_image_view_address_nvx,
+            method_name: get_image_view_address_nvx,
+            try_method_name: try_get_image_view_address_nvx,
+            unchecked_method_name: get_image_view_address_nvx_unchecked,
+            ptr: GetImageViewAddressNVX,
             signature: (
-                command_buffer: CommandBuffer,
-                depth_bias_constant_factor: c_float,
-                depth_bias_clamp: c_float,
-                depth_bias_slope_factor: c_float,
-            ),
-            takes_handle: false,
+                image_view: ImageView,
+                p_properties: *mut ImageViewAddressPropertiesNVX,
+            ) -> Result,
+            takes_handle: true,
+            provided_by: [
+                [VK_NVX_image_view_handle] => GetImageViewAddressNVX,
+            ],
+            hooks: [
+                image_view: handle,
+                p_properties: structs,
+            ],
+            checked: {
+                method_name: get_image_view_address_nvx_checked,
+                args: (
+                    image_view: ImageView,
+                ),
+                output: p_properties: ImageViewAddressPropertiesNVX,
+                result: success,
+            },
         },
         {
-            name: pfn_cmd_set_blend_constants,
-            method_name: cmd_set_blend_constants,
-            ptr: CmdSetBlendConstants,
+            name: pfn_get_device_group_surface_present_modes_2_ext,
+            method_name: get_device_group_surface_present_modes_2_ext,
+            try_method_name: try_get_device_group_surface_present_modes_2_ext,
+            unchecked_method_name: get_device_group_surface_present_modes_2_ext_unchecked,
+            ptr: GetDeviceGroupSurfacePresentModes2EXT,
             signature: (
-                command_buffer: CommandBuffer,
-                blend_constants: [c_float; 4],
-            ),
-            takes_handle: false,
+                p_surface_info: *const PhysicalDeviceSurfaceInfo2KHR,
+                p_modes: *mut DeviceGroupPresentModeFlagsKHR,
+            ) -> Result,
+            takes_handle: true,
+            provided_by: [
+                [VK_EXT_full_screen_exclusive, VK_KHR_device_group] => GetDeviceGroupSurfacePresentModes2EXT,
+                [VK_EXT_full_screen_exclusive, VK_VERSION_1_1] => GetDeviceGroupSurfacePresentModes2EXT,
+            ],
+            hooks: [
+                p_surface_info: structs,
+                p_modes: ptr,
+            ],
+            checked: {
+                method_name: get_device_group_surface_present_modes_2_ext_checked,
+                args: (
+                    p_surface_info: *const PhysicalDeviceSurfaceInfo2KHR,
+                ),
+                output: p_modes: DeviceGroupPresentModeFlagsKHR,
+                result: success,
+            },
         },
         {
-            name: pfn_cmd_set_depth_bounds,
-            method_name: cmd_set_depth_bounds,
-            ptr: CmdSetDepthBounds,
+            name: pfn_acquire_full_screen_exclusive_mode_ext,
+            method_name: acquire_full_screen_exclusive_mode_ext,
+            try_method_name: try_acquire_full_screen_exclusive_mode_ext,
+            unchecked_method_name: acquire_full_screen_exclusive_mode_ext_unchecked,
+            ptr: AcquireFullScreenExclusiveModeEXT,
             signature: (
-                command_buffer: CommandBuffer,
-                min_depth_bounds: c_float,
-                max_depth_bounds: c_float,
-            ),
-            takes_handle: false,
+                swapchain: SwapchainKHR,
+            ) -> Result,
+            takes_handle: true,
+            provided_by: [
+                [VK_EXT_full_screen_exclusive] => AcquireFullScreenExclusiveModeEXT,
+            ],
+            hooks: [
+                swapchain: handle,
+            ],
         },
         {
-            name: pfn_cmd_set_stencil_compare_mask,
-            method_name: cmd_set_stencil_compare_mask,
-            ptr: CmdSetStencilCompareMask,
+            name: pfn_release_full_screen_exclusive_mode_ext,
+            method_name: release_full_screen_exclusive_mode_ext,
+            try_method_name: try_release_full_screen_exclusive_mode_ext,
+            unchecked_method_name: release_full_screen_exclusive_mode_ext_unchecked,
+            ptr: ReleaseFullScreenExclusiveModeEXT,
             signature: (
-                command_buffer: CommandBuffer,
-                face_mask: StencilFaceFlags,
-                compare_mask: u32,
-            ),
-            takes_handle: false,
+                swapchain: SwapchainKHR,
+            ) -> Result,
+            takes_handle: true,
+            provided_by: [
+                [VK_EXT_full_screen_exclusive] => ReleaseFullScreenExclusiveModeEXT,
+            ],
+            hooks: [
+                swapchain: handle,
+            ],
         },
         {
-            name: pfn_cmd_set_stencil_write_mask,
-            method_name: cmd_set_stencil_write_mask,
-            ptr: CmdSetStencilWriteMask,
+            name: pfn_acquire_profiling_lock_khr,
+            method_name: acquire_profiling_lock_khr,
+            try_method_name: try_acquire_profiling_lock_khr,
+            unchecked_method_name: acquire_profiling_lock_khr_unchecked,
+            ptr: AcquireProfilingLockKHR,
             signature: (
-                command_buffer: CommandBuffer,
-                face_mask: StencilFaceFlags,
-                write_mask: u32,
-            ),
-            takes_handle: false,
+                p_info: *const AcquireProfilingLockInfoKHR,
+            ) -> Result,
+            takes_handle: true,
+            provided_by: [
+                [VK_KHR_performance_query] => AcquireProfilingLockKHR,
+            ],
+            hooks: [
+                p_info: structs,
+            ],
         },
         {
-            name: pfn_cmd_set_stencil_reference,
-            method_name: cmd_set_stencil_reference,
-            ptr: CmdSetStencilReference,
+            name: pfn_release_profiling_lock_khr,
+            method_name: release_profiling_lock_khr,
+            try_method_name: try_release_profiling_lock_khr,
+            unchecked_method_name: release_profiling_lock_khr_unchecked,
+            ptr: ReleaseProfilingLockKHR,
             signature: (
-                command_buffer: CommandBuffer,
-                face_mask: StencilFaceFlags,
-                reference: u32,
             ),
-            takes_handle: false,
+            takes_handle: true,
+            provided_by: [
+                [VK_KHR_performance_query] => ReleaseProfilingLockKHR,
+            ],
+            hooks: [],
         },
         {
-            name: pfn_cmd_bind_descriptor_sets,
-            method_name: cmd_bind_descriptor_sets,
-            ptr: CmdBindDescriptorSets,
+            name: pfn_get_image_drm_format_modifier_properties_ext,
+            method_name: get_image_drm_format_modifier_properties_ext,
+            try_method_name: try_get_image_drm_format_modifier_properties_ext,
+            unchecked_method_name: get_image_drm_format_modifier_properties_ext_unchecked,
+            ptr: GetImageDrmFormatModifierPropertiesEXT,
             signature: (
-                command_buffer: CommandBuffer,
-                pipeline_bind_point: PipelineBindPoint,
-                layout: PipelineLayout,
-                first_set: u32,
-                descriptor_set_count: u32,
-                p_descriptor_sets: *const DescriptorSet,
-                dynamic_offset_count: u32,
-                p_dynamic_offsets: *const u32,
-            ),
-            takes_handle: false,
+                image: Image,
+                p_properties: *mut ImageDrmFormatModifierPropertiesEXT,
+            ) -> Result,
+            takes_handle: true,
+            provided_by: [
+                [VK_EXT_image_drm_format_modifier] => GetImageDrmFormatModifierPropertiesEXT,
+            ],
+            hooks: [
+                image: handle,
+                p_properties: structs,
+            ],
+            checked: {
+                method_name: get_image_drm_format_modifier_properties_ext_checked,
+                args: (
+                    image: Image,
+                ),
+                output: p_properties: ImageDrmFormatModifierPropertiesEXT,
+                result: success,
+            },
         },
         {
-            name: pfn_cmd_bind_index_buffer,
-            method_name: cmd_bind_index_buffer,
-            ptr: CmdBindIndexBuffer,
+            name: pfn_get_buffer_opaque_capture_address,
+            method_name: get_buffer_opaque_capture_address,
+            try_method_name: try_get_buffer_opaque_capture_address,
+            unchecked_method_name: get_buffer_opaque_capture_address_unchecked,
+            ptr: GetBufferOpaqueCaptureAddress,
+            aliases: [GetBufferOpaqueCaptureAddressKHR],
             signature: (
-                command_buffer: CommandBuffer,
-                buffer: Buffer,
-                offset: DeviceSize,
-                index_type: IndexType,
-            ),
-            takes_handle: false,
+                p_info: *const BufferDeviceAddressInfo,
+            ) -> u64,
+            takes_handle: true,
+            provided_by: [
+                [VK_VERSION_1_2] => GetBufferOpaqueCaptureAddress,
+                [VK_KHR_buffer_device_address] => GetBufferOpaqueCaptureAddressKHR,
+            ],
+            hooks: [
+                p_info: structs,
+            ],
         },
         {
-            name: pfn_cmd_bind_vertex_buffers,
-            method_name: cmd_bind_vertex_buffers,
-            ptr: CmdBindVertexBuffers,
+            name: pfn_get_buffer_device_address,
+            method_name: get_buffer_device_address,
+            try_method_name: try_get_buffer_device_address,
+            unchecked_method_name: get_buffer_device_address_unchecked,
+            ptr: GetBufferDeviceAddress,
+            aliases: [GetBufferDeviceAddressKHR, GetBufferDeviceAddressEXT],
             signature: (
-                command_buffer: CommandBuffer,
-                first_binding: u32,
-                binding_count: u32,
-                p_buffers: *const Buffer,
-                p_offsets: *const DeviceSize,
-            ),
-            takes_handle: false,
+                p_info: *const BufferDeviceAddressInfo,
+            ) -> DeviceAddress,
+            takes_handle: true,
+            provided_by: [
+                [VK_VERSION_1_2] => GetBufferDeviceAddress,
+                [VK_EXT_buffer_device_address] => GetBufferDeviceAddressEXT,
+                [VK_KHR_buffer_device_address] => GetBufferDeviceAddressKHR,
+            ],
+            hooks: [
+                p_info: structs,
+            ],
         },
         {
-            name: pfn_cmd_draw,
-            method_name: cmd_draw,
-            ptr: CmdDraw,
+            name: pfn_initialize_performance_api_intel,
+            method_name: initialize_performance_api_intel,
+            try_method_name: try_initialize_performance_api_intel,
+            unchecked_method_name: initialize_performance_api_intel_unchecked,
+            ptr: InitializePerformanceApiINTEL,
             signature: (
-                command_buffer: CommandBuffer,
-                vertex_count: u32,
-                instance_count: u32,
-                first_vertex: u32,
-                first_instance: u32,
-            ),
-            takes_handle: false,
+                p_initialize_info: *const InitializePerformanceApiInfoINTEL,
+            ) -> Result,
+            takes_handle: true,
+            provided_by: [
+                [VK_INTEL_performance_query] => InitializePerformanceApiINTEL,
+            ],
+            hooks: [
+                p_initialize_info: structs,
+            ],
         },
         {
-            name: pfn_cmd_draw_indexed,
-            method_name: cmd_draw_indexed,
-            ptr: CmdDrawIndexed,
+            name: pfn_uninitialize_performance_api_intel,
+            method_name: uninitialize_performance_api_intel,
+            try_method_name: try_uninitialize_performance_api_intel,
+            unchecked_method_name: uninitialize_performance_api_intel_unchecked,
+            ptr: UninitializePerformanceApiINTEL,
             signature: (
-                command_buffer: CommandBuffer,
-                index_count: u32,
-                instance_count: u32,
-                first_index: u32,
-                vertex_offset: i32,
-                first_instance: u32,
             ),
-            takes_handle: false,
+            takes_handle: true,
+            provided_by: [
+                [VK_INTEL_performance_query] => UninitializePerformanceApiINTEL,
+            ],
+            hooks: [],
         },
         {
-            name: pfn_cmd_draw_multi_ext,
-            method_name: cmd_draw_multi_ext,
-            ptr: CmdDrawMultiEXT,
+            name: pfn_cmd_set_performance_marker_intel,
+            method_name: cmd_set_performance_marker_intel,
+            try_method_name: try_cmd_set_performance_marker_intel,
+            unchecked_method_name: cmd_set_performance_marker_intel_unchecked,
+            ptr: CmdSetPerformanceMarkerINTEL,
             signature: (
                 command_buffer: CommandBuffer,
-                draw_count: u32,
-                p_vertex_info: *const MultiDrawInfoEXT,
-                instance_count: u32,
-                first_instance: u32,
-                stride: u32,
-            ),
+                p_marker_info: *const PerformanceMarkerInfoINTEL,
+            ) -> Result,
             takes_handle: false,
+            provided_by: [
+                [VK_INTEL_performance_query] => CmdSetPerformanceMarkerINTEL,
+            ],
+            hooks: [
+                command_buffer: handle,
+                p_marker_info: structs,
+            ],
         },
         {
-            name: pfn_cmd_draw_multi_indexed_ext,
-            method_name: cmd_draw_multi_indexed_ext,
-            ptr: CmdDrawMultiIndexedEXT,
+            name: pfn_cmd_set_performance_stream_marker_intel,
+            method_name: cmd_set_performance_stream_marker_intel,
+            try_method_name: try_cmd_set_performance_stream_marker_intel,
+            unchecked_method_name: cmd_set_performance_stream_marker_intel_unchecked,
+            ptr: CmdSetPerformanceStreamMarkerINTEL,
             signature: (
                 command_buffer: CommandBuffer,
-                draw_count: u32,
-                p_index_info: *const MultiDrawIndexedInfoEXT,
-                instance_count: u32,
-                first_instance: u32,
-                stride: u32,
-                p_vertex_offset: *const i32,
-            ),
+                p_marker_info: *const PerformanceStreamMarkerInfoINTEL,
+            ) -> Result,
             takes_handle: false,
+            provided_by: [
+                [VK_INTEL_performance_query] => CmdSetPerformanceStreamMarkerINTEL,
+            ],
+            hooks: [
+                command_buffer: handle,
+                p_marker_info: structs,
+            ],
         },
         {
-            name: pfn_cmd_draw_indirect,
-            method_name: cmd_draw_indirect,
-            ptr: CmdDrawIndirect,
+            name: pfn_cmd_set_performance_override_intel,
+            method_name: cmd_set_performance_override_intel,
+            try_method_name: try_cmd_set_performance_override_intel,
+            unchecked_method_name: cmd_set_performance_override_intel_unchecked,
+            ptr: CmdSetPerformanceOverrideINTEL,
             signature: (
                 command_buffer: CommandBuffer,
-                buffer: Buffer,
-                offset: DeviceSize,
-                draw_count: u32,
-                stride: u32,
-            ),
+                p_override_info: *const PerformanceOverrideInfoINTEL,
+            ) -> Result,
             takes_handle: false,
+            provided_by: [
+                [VK_INTEL_performance_query] => CmdSetPerformanceOverrideINTEL,
+            ],
+            hooks: [
+                command_buffer: handle,
+                p_override_info: structs,
+            ],
         },
         {
-            name: pfn_cmd_draw_indexed_indirect,
-            method_name: cmd_draw_indexed_indirect,
-            ptr: CmdDrawIndexedIndirect,
+            name: pfn_acquire_performance_configuration_intel,
+            method_name: acquire_performance_configuration_intel,
+            try_method_name: try_acquire_performance_configuration_intel,
+            unchecked_method_name: acquire_performance_configuration_intel_unchecked,
+            ptr: AcquirePerformanceConfigurationINTEL,
             signature: (
-                command_buffer: CommandBuffer,
-                buffer: Buffer,
-                offset: DeviceSize,
-                draw_count: u32,
-                stride: u32,
-            ),
-            takes_handle: false,
+                p_acquire_info: *const PerformanceConfigurationAcquireInfoINTEL,
+                p_configuration: *mut PerformanceConfigurationINTEL,
+            ) -> Result,
+            takes_handle: true,
+            provided_by: [
+                [VK_INTEL_performance_query] => AcquirePerformanceConfigurationINTEL,
+            ],
+            hooks: [
+                p_acquire_info: structs,
+                p_configuration: handles,
+            ],
+            checked: {
+                method_name: acquire_performance_configuration_intel_checked,
+                args: (
+                    p_acquire_info: *const PerformanceConfigurationAcquireInfoINTEL,
+                ),
+                output: p_configuration: PerformanceConfigurationINTEL,
+                result: success,
+            },
         },
         {
-            name: pfn_cmd_dispatch,
-            method_name: cmd_dispatch,
-            ptr: CmdDispatch,
+            name: pfn_release_performance_configuration_intel,
+            method_name: release_performance_configuration_intel,
+            try_method_name: try_release_performance_configuration_intel,
+            unchecked_method_name: release_performance_configuration_intel_unchecked,
+            ptr: ReleasePerformanceConfigurationINTEL,
             signature: (
-                command_buffer: CommandBuffer,
-                group_count_x: u32,
-                group_count_y: u32,
-                group_count_z: u32,
-            ),
-            takes_handle: false,
+                configuration: PerformanceConfigurationINTEL,
+            ) -> Result,
+            takes_handle: true,
+            provided_by: [
+                [VK_INTEL_performance_query] => ReleasePerformanceConfigurationINTEL,
+            ],
+            hooks: [
+                configuration: handle,
+            ],
         },
         {
-            name: pfn_cmd_dispatch_indirect,
-            method_name: cmd_dispatch_indirect,
-            ptr: CmdDispatchIndirect,
+            name: pfn_queue_set_performance_configuration_intel,
+            method_name: queue_set_performance_configuration_intel,
+            try_method_name: try_queue_set_performance_configuration_intel,
+            unchecked_method_name: queue_set_performance_configuration_intel_unchecked,
+            ptr: QueueSetPerformanceConfigurationINTEL,
             signature: (
-                command_buffer: CommandBuffer,
-                buffer: Buffer,
-                offset: DeviceSize,
-            ),
+                queue: Queue,
+                configuration: PerformanceConfigurationINTEL,
+            ) -> Result,
             takes_handle: false,
+            provided_by: [
+                [VK_INTEL_performance_query] => QueueSetPerformanceConfigurationINTEL,
+            ],
+            hooks: [
+                queue: handle,
+                configuration: handle,
+            ],
+        },
+        {
+            name: pfn_get_performance_parameter_intel,
+            method_name: get_performance_parameter_intel,
+            try_method_name: try_get_performance_parameter_intel,
+            unchecked_method_name: get_performance_parameter_intel_unchecked,
+            ptr: GetPerformanceParameterINTEL,
+            signature: (
+                parameter: PerformanceParameterTypeINTEL,
+                p_value: *mut PerformanceValueINTEL,
+            ) -> Result,
+            takes_handle: true,
+            provided_by: [
+                [VK_INTEL_performance_query] => GetPerformanceParameterINTEL,
+            ],
+            hooks: [
+                parameter: value,
+                p_value: ptr,
+            ],
+            checked: {
+                method_name: get_performance_parameter_intel_checked,
+                args: (
+                    parameter: PerformanceParameterTypeINTEL,
+                ),
+                output: p_value: PerformanceValueINTEL,
+                result: success,
+            },
         },
         {
-            name: pfn_cmd_subpass_shading_huawei,
-            method_name: cmd_subpass_shading_huawei,
-            ptr: CmdSubpassShadingHUAWEI,
+            name: pfn_get_device_memory_opaque_capture_address,
+            method_name: get_device_memory_opaque_capture_address,
+            try_method_name: try_get_device_memory_opaque_capture_address,
+            unchecked_method_name: get_device_memory_opaque_capture_address_unchecked,
+            ptr: GetDeviceMemoryOpaqueCaptureAddress,
+            aliases: [GetDeviceMemoryOpaqueCaptureAddressKHR],
             signature: (
-                command_buffer: CommandBuffer,
-            ),
-            takes_handle: false,
+                p_info: *const DeviceMemoryOpaqueCaptureAddressInfo,
+            ) -> u64,
+            takes_handle: true,
+            provided_by: [
+                [VK_VERSION_1_2] => GetDeviceMemoryOpaqueCaptureAddress,
+                [VK_KHR_buffer_device_address] => GetDeviceMemoryOpaqueCaptureAddressKHR,
+            ],
+            hooks: [
+                p_info: structs,
+            ],
         },
         {
-            name: pfn_cmd_copy_buffer,
-            method_name: cmd_copy_buffer,
-            ptr: CmdCopyBuffer,
+            name: pfn_get_pipeline_executable_properties_khr,
+            method_name: get_pipeline_executable_properties_khr,
+            try_method_name: try_get_pipeline_executable_properties_khr,
+            unchecked_method_name: get_pipeline_executable_properties_khr_unchecked,
+            ptr: GetPipelineExecutablePropertiesKHR,
             signature: (
-                command_buffer: CommandBuffer,
-                src_buffer: Buffer,
-                dst_buffer: Buffer,
-                region_count: u32,
-                p_regions: *const BufferCopy,
-            ),
-            takes_handle: false,
+                p_pipeline_info: *const PipelineInfoKHR,
+                p_executable_count: *mut u32,
+                p_properties: *mut PipelineExecutablePropertiesKHR,
+            ) -> Result,
+            takes_handle: true,
+            provided_by: [
+                [VK_KHR_pipeline_executable_properties] => GetPipelineExecutablePropertiesKHR,
+            ],
+            hooks: [
+                p_pipeline_info: structs,
+                p_executable_count: ptr,
+                p_properties: structs[*p_executable_count],
+            ],
+            vec: {
+                method_name: get_pipeline_executable_properties_khr_vec,
+                args: (
+                    p_pipeline_info: *const PipelineInfoKHR,
+                ),
+                element: PipelineExecutablePropertiesKHR,
+                result: success,
+            },
         },
         {
-            name: pfn_cmd_copy_image,
-            method_name: cmd_copy_image,
-            ptr: CmdCopyImage,
+            name: pfn_get_pipeline_executable_statistics_khr,
+            method_name: get_pipeline_executable_statistics_khr,
+            try_method_name: try_get_pipeline_executable_statistics_khr,
+            unchecked_method_name: get_pipeline_executable_statistics_khr_unchecked,
+            ptr: GetPipelineExecutableStatisticsKHR,
             signature: (
-                command_buffer: CommandBuffer,
-                src_image: Image,
-                src_image_layout: ImageLayout,
-                dst_image: Image,
-                dst_image_layout: ImageLayout,
-                region_count: u32,
-                p_regions: *const ImageCopy,
-            ),
-            takes_handle: false,
+                p_executable_info: *const PipelineExecutableInfoKHR,
+                p_statistic_count: *mut u32,
+                p_statistics: *mut PipelineExecutableStatisticKHR,
+            ) -> Result,
+            takes_handle: true,
+            provided_by: [
+                [VK_KHR_pipeline_executable_properties] => GetPipelineExecutableStatisticsKHR,
+            ],
+            hooks: [
+                p_executable_info: structs,
+                p_statistic_count: ptr,
+                p_statistics: structs[*p_statistic_count],
+            ],
+            vec: {
+                method_name: get_pipeline_executable_statistics_khr_vec,
+                args: (
+                    p_executable_info: *const PipelineExecutableInfoKHR,
+                ),
+                element: PipelineExecutableStatisticKHR,
+                result: success,
+            },
         },
         {
-            name: pfn_cmd_blit_image,
-            method_name: cmd_blit_image,
-            ptr: CmdBlitImage,
+            name: pfn_get_pipeline_executable_internal_representations_khr,
+            method_name: get_pipeline_executable_internal_representations_khr,
+            try_method_name: try_get_pipeline_executable_internal_representations_khr,
+            unchecked_method_name: get_pipeline_executable_internal_representations_khr_unchecked,
+            ptr: GetPipelineExecutableInternalRepresentationsKHR,
             signature: (
-                command_buffer: CommandBuffer,
-                src_image: Image,
-                src_image_layout: ImageLayout,
-                dst_image: Image,
-                dst_image_layout: ImageLayout,
-                region_count: u32,
-                p_regions: *const ImageBlit,
-                filter: Filter,
-            ),
-            takes_handle: false,
+                p_executable_info: *const PipelineExecutableInfoKHR,
+                p_internal_representation_count: *mut u32,
+                p_internal_representations: *mut PipelineExecutableInternalRepresentationKHR,
+            ) -> Result,
+            takes_handle: true,
+            provided_by: [
+                [VK_KHR_pipeline_executable_properties] => GetPipelineExecutableInternalRepresentationsKHR,
+            ],
+            hooks: [
+                p_executable_info: structs,
+                p_internal_representation_count: ptr,
+                p_internal_representations: structs[*p_internal_representation_count],
+            ],
+            vec: {
+                method_name: get_pipeline_executable_internal_representations_khr_vec,
+                args: (
+                    p_executable_info: *const PipelineExecutableInfoKHR,
+                ),
+                element: PipelineExecutableInternalRepresentationKHR,
+                result: success,
+            },
         },
         {
-            name: pfn_cmd_copy_buffer_to_image,
-            method_name: cmd_copy_buffer_to_image,
-            ptr: CmdCopyBufferToImage,
+            name: pfn_cmd_set_line_stipple_ext,
+            method_name: cmd_set_line_stipple_ext,
+            try_method_name: try_cmd_set_line_stipple_ext,
+            unchecked_method_name: cmd_set_line_stipple_ext_unchecked,
+            ptr: CmdSetLineStippleEXT,
             signature: (
                 command_buffer: CommandBuffer,
-                src_buffer: Buffer,
-                dst_image: Image,
-                dst_image_layout: ImageLayout,
-                region_count: u32,
-                p_regions: *const BufferImageCopy,
+                line_stipple_factor: u32,
+                line_stipple_pattern: u16,
             ),
             takes_handle: false,
+            provided_by: [
+                [VK_EXT_line_rasterization] => CmdSetLineStippleEXT,
+            ],
+            hooks: [
+                command_buffer: handle,
+                line_stipple_factor: value,
+                line_stipple_pattern: value,
+            ],
         },
         {
-            name: pfn_cmd_copy_image_to_buffer,
-            method_name: cmd_copy_image_to_buffer,
-            ptr: CmdCopyImageToBuffer,
+            name: pfn_create_acceleration_structure_khr,
+            method_name: create_acceleration_structure_khr,
+            try_method_name: try_create_acceleration_structure_khr,
+            unchecked_method_name: create_acceleration_structure_khr_unchecked,
+            ptr: CreateAccelerationStructureKHR,
             signature: (
-                command_buffer: CommandBuffer,
-                src_image: Image,
-                src_image_layout: ImageLayout,
-                dst_buffer: Buffer,
-                region_count: u32,
-                p_regions: *const BufferImageCopy,
-            ),
-            takes_handle: false,
+                p_create_info: *const AccelerationStructureCreateInfoKHR,
+                p_allocator: *const AllocationCallbacks,
+                p_acceleration_structure: *mut AccelerationStructureKHR,
+            ) -> Result,
+            takes_handle: true,
+            provided_by: [
+                [VK_KHR_acceleration_structure] => CreateAccelerationStructureKHR,
+            ],
+            hooks: [
+                p_create_info: structs,
+                p_allocator: structs,
+                p_acceleration_structure: handles,
+            ],
+            checked: {
+                method_name: create_acceleration_structure_khr_checked,
+                args: (
+                    p_create_info: *const AccelerationStructureCreateInfoKHR,
+                    p_allocator: Option<&AllocationCallbacks>,
+                ),
+                output: p_acceleration_structure: AccelerationStructureKHR,
+                result: success,
+            },
         },
         {
-            name: pfn_cmd_update_buffer,
-            method_name: cmd_update_buffer,
-            ptr: CmdUpdateBuffer,
+            name: pfn_cmd_build_acceleration_structures_khr,
+            method_name: cmd_build_acceleration_structures_khr,
+            try_method_name: try_cmd_build_acceleration_structures_khr,
+            unchecked_method_name: cmd_build_acceleration_structures_khr_unchecked,
+            ptr: CmdBuildAccelerationStructuresKHR,
             signature: (
                 command_buffer: CommandBuffer,
-                dst_buffer: Buffer,
-                dst_offset: DeviceSize,
-                data_size: DeviceSize,
-                p_data: *const c_void,
+                info_count: u32,
+                p_infos: *const AccelerationStructureBuildGeometryInfoKHR,
+                pp_build_range_infos: *const *const AccelerationStructureBuildRangeInfoKHR,
             ),
             takes_handle: false,
+            provided_by: [
+                [VK_KHR_acceleration_structure] => CmdBuildAccelerationStructuresKHR,
+            ],
+            hooks: [
+                command_buffer: handle,
+                info_count: value,
+                p_infos: structs[info_count],
+                pp_build_range_infos: opaque,
+            ],
         },
         {
-            name: pfn_cmd_fill_buffer,
-            method_name: cmd_fill_buffer,
-            ptr: CmdFillBuffer,
+            name: pfn_cmd_build_acceleration_structures_indirect_khr,
+            method_name: cmd_build_acceleration_structures_indirect_khr,
+            try_method_name: try_cmd_build_acceleration_structures_indirect_khr,
+            unchecked_method_name: cmd_build_acceleration_structures_indirect_khr_unchecked,
+            ptr: CmdBuildAccelerationStructuresIndirectKHR,
             signature: (
                 command_buffer: CommandBuffer,
-                dst_buffer: Buffer,
-                dst_offset: DeviceSize,
-                size: DeviceSize,
-                data: u32,
+                info_count: u32,
+                p_infos: *const AccelerationStructureBuildGeometryInfoKHR,
+                p_indirect_device_addresses: *const DeviceAddress,
+                p_indirect_strides: *const u32,
+                pp_max_primitive_counts: *const *const u32,
             ),
             takes_handle: false,
+            provided_by: [
+                [VK_KHR_acceleration_structure] => CmdBuildAccelerationStructuresIndirectKHR,
+            ],
+            hooks: [
+                command_buffer: handle,
+                info_count: value,
+                p_infos: structs[info_count],
+                p_indirect_device_addresses: ptr[info_count],
+                p_indirect_strides: ptr[info_count],
+                pp_max_primitive_counts: opaque,
+            ],
         },
         {
-            name: pfn_cmd_clear_color_image,
-            method_name: cmd_clear_color_image,
-            ptr: CmdClearColorImage,
+            name: pfn_build_acceleration_structures_khr,
+            method_name: build_acceleration_structures_khr,
+            try_method_name: try_build_acceleration_structures_khr,
+            unchecked_method_name: build_acceleration_structures_khr_unchecked,
+            ptr: BuildAccelerationStructuresKHR,
             signature: (
-                command_buffer: CommandBuffer,
-                image: Image,
-                image_layout: ImageLayout,
-                p_color: *const ClearColorValue,
-                range_count: u32,
-                p_ranges: *const ImageSubresourceRange,
-            ),
-            takes_handle: false,
+                deferred_operation: DeferredOperationKHR,
+                info_count: u32,
+                p_infos: *const AccelerationStructureBuildGeometryInfoKHR,
+                pp_build_range_infos: *const *const AccelerationStructureBuildRangeInfoKHR,
+            ) -> Result,
+            takes_handle: true,
+            provided_by: [
+                [VK_KHR_acceleration_structure] => BuildAccelerationStructuresKHR,
+            ],
+            hooks: [
+                deferred_operation: handle,
+                info_count: value,
+                p_infos: structs[info_count],
+                pp_build_range_infos: opaque,
+            ],
         },
         {
-            name: pfn_cmd_clear_depth_stencil_image,
-            method_name: cmd_clear_depth_stencil_image,
-            ptr: CmdClearDepthStencilImage,
+            name: pfn_get_acceleration_structure_device_address_khr,
+            method_name: get_acceleration_structure_device_address_khr,
+            try_method_name: try_get_acceleration_structure_device_address_khr,
+            unchecked_method_name: get_acceleration_structure_device_address_khr_unchecked,
+            ptr: GetAccelerationStructureDeviceAddressKHR,
             signature: (
-                command_buffer: CommandBuffer,
-                image: Image,
-                image_layout: ImageLayout,
-                p_depth_stencil: *const ClearDepthStencilValue,
-                range_count: u32,
-                p_ranges: *const ImageSubresourceRange,
-            ),
-            takes_handle: false,
+                p_info: *const AccelerationStructureDeviceAddressInfoKHR,
+            ) -> DeviceAddress,
+            takes_handle: true,
+            provided_by: [
+                [VK_KHR_acceleration_structure] => GetAccelerationStructureDeviceAddressKHR,
+            ],
+            hooks: [
+                p_info: structs,
+            ],
         },
         {
-            name: pfn_cmd_clear_attachments,
-            method_name: cmd_clear_attachments,
-            ptr: CmdClearAttachments,
+            name: pfn_create_deferred_operation_khr,
+            method_name: create_deferred_operation_khr,
+            try_method_name: try_create_deferred_operation_khr,
+            unchecked_method_name: create_deferred_operation_khr_unchecked,
+            ptr: CreateDeferredOperationKHR,
             signature: (
-                command_buffer: CommandBuffer,
-                attachment_count: u32,
-                p_attachments: *const ClearAttachment,
-                rect_count: u32,
-                p_rects: *const ClearRect,
-            ),
-            takes_handle: false,
+                p_allocator: *const AllocationCallbacks,
+                p_deferred_operation: *mut DeferredOperationKHR,
+            ) -> Result,
+            takes_handle: true,
+            provided_by: [
+                [VK_KHR_deferred_host_operations] => CreateDeferredOperationKHR,
+            ],
+            hooks: [
+                p_allocator: structs,
+                p_deferred_operation: handles,
+            ],
+            checked: {
+                method_name: create_deferred_operation_khr_checked,
+                args: (
+                    p_allocator: Option<&AllocationCallbacks>,
+                ),
+                output: p_deferred_operation: DeferredOperationKHR,
+                result: success,
+            },
         },
         {
-            name: pfn_cmd_resolve_image,
-            method_name: cmd_resolve_image,
-            ptr: CmdResolveImage,
+            name: pfn_destroy_deferred_operation_khr,
+            method_name: destroy_deferred_operation_khr,
+            try_method_name: try_destroy_deferred_operation_khr,
+            unchecked_method_name: destroy_deferred_operation_khr_unchecked,
+            ptr: DestroyDeferredOperationKHR,
             signature: (
-                command_buffer: CommandBuffer,
-                src_image: Image,
-                src_image_layout: ImageLayout,
-                dst_image: Image,
-                dst_image_layout: ImageLayout,
-                region_count: u32,
-                p_regions: *const ImageResolve,
+                operation: DeferredOperationKHR,
+                p_allocator: *const AllocationCallbacks,
             ),
-            takes_handle: false,
+            takes_handle: true,
+            provided_by: [
+                [VK_KHR_deferred_host_operations] => DestroyDeferredOperationKHR,
+            ],
+            hooks: [
+                operation: handle,
+                p_allocator: structs,
+            ],
         },
         {
-            name: pfn_cmd_set_event,
-            method_name: cmd_set_event,
-            ptr: CmdSetEvent,
+            name: pfn_get_deferred_operation_max_concurrency_khr,
+            method_name: get_deferred_operation_max_concurrency_khr,
+            try_method_name: try_get_deferred_operation_max_concurrency_khr,
+            unchecked_method_name: get_deferred_operation_max_concurrency_khr_unchecked,
+            ptr: GetDeferredOperationMaxConcurrencyKHR,
             signature: (
-                command_buffer: CommandBuffer,
-                event: Event,
-                stage_mask: PipelineStageFlags,
-            ),
-            takes_handle: false,
+                operation: DeferredOperationKHR,
+            ) -> u32,
+            takes_handle: true,
+            provided_by: [
+                [VK_KHR_deferred_host_operations] => GetDeferredOperationMaxConcurrencyKHR,
+            ],
+            hooks: [
+                operation: handle,
+            ],
         },
         {
-            name: pfn_cmd_reset_event,
-            method_name: cmd_reset_event,
-            ptr: CmdResetEvent,
+            name: pfn_get_deferred_operation_result_khr,
+            method_name: get_deferred_operation_result_khr,
+            try_method_name: try_get_deferred_operation_result_khr,
+            unchecked_method_name: get_deferred_operation_result_khr_unchecked,
+            ptr: GetDeferredOperationResultKHR,
             signature: (
-                command_buffer: CommandBuffer,
-                event: Event,
-                stage_mask: PipelineStageFlags,
-            ),
-            takes_handle: false,
+                operation: DeferredOperationKHR,
+            ) -> Result,
+            takes_handle: true,
+            provided_by: [
+                [VK_KHR_deferred_host_operations] => GetDeferredOperationResultKHR,
+            ],
+            hooks: [
+                operation: handle,
+            ],
         },
         {
-            name: pfn_cmd_wait_events,
-            method_name: cmd_wait_events,
-            ptr: CmdWaitEvents,
+            name: pfn_deferred_operation_join_khr,
+            method_name: deferred_operation_join_khr,
+            try_method_name: try_deferred_operation_join_khr,
+            unchecked_method_name: deferred_operation_join_khr_unchecked,
+            ptr: DeferredOperationJoinKHR,
             signature: (
-                command_buffer: CommandBuffer,
-                event_count: u32,
-                p_events: *const Event,
-                src_stage_mask: PipelineStageFlags,
-                dst_stage_mask: PipelineStageFlags,
-                memory_barrier_count: u32,
-                p_memory_barriers: *const MemoryBarrier,
-                buffer_memory_barrier_count: u32,
-                p_buffer_memory_barriers: *const BufferMemoryBarrier,
-                image_memory_barrier_count: u32,
-                p_image_memory_barriers: *const ImageMemoryBarrier,
-            ),
-            takes_handle: false,
+                operation: DeferredOperationKHR,
+            ) -> Result,
+            takes_handle: true,
+            provided_by: [
+                [VK_KHR_deferred_host_operations] => DeferredOperationJoinKHR,
+            ],
+            hooks: [
+                operation: handle,
+            ],
         },
         {
-            name: pfn_cmd_pipeline_barrier,
-            method_name: cmd_pipeline_barrier,
-            ptr: CmdPipelineBarrier,
+            name: pfn_cmd_set_cull_mode,
+            method_name: cmd_set_cull_mode,
+            try_method_name: try_cmd_set_cull_mode,
+            unchecked_method_name: cmd_set_cull_mode_unchecked,
+            ptr: CmdSetCullMode,
+            aliases: [CmdSetCullModeEXT],
             signature: (
                 command_buffer: CommandBuffer,
-                src_stage_mask: PipelineStageFlags,
-                dst_stage_mask: PipelineStageFlags,
-                dependency_flags: DependencyFlags,
-                memory_barrier_count: u32,
-                p_memory_barriers: *const MemoryBarrier,
-                buffer_memory_barrier_count: u32,
-                p_buffer_memory_barriers: *const BufferMemoryBarrier,
-                image_memory_barrier_count: u32,
-                p_image_memory_barriers: *const ImageMemoryBarrier,
+                cull_mode: CullModeFlags,
             ),
             takes_handle: false,
+            provided_by: [
+                [VK_VERSION_1_3] => CmdSetCullMode,
+                [VK_EXT_extended_dynamic_state] => CmdSetCullModeEXT,
+            ],
+            hooks: [
+                command_buffer: handle,
+                cull_mode: value,
+            ],
         },
         {
-            name: pfn_cmd_begin_query,
-            method_name: cmd_begin_query,
-            ptr: CmdBeginQuery,
+            name: pfn_cmd_set_front_face,
+            method_name: cmd_set_front_face,
+            try_method_name: try_cmd_set_front_face,
+            unchecked_method_name: cmd_set_front_face_unchecked,
+            ptr: CmdSetFrontFace,
+            aliases: [CmdSetFrontFaceEXT],
             signature: (
                 command_buffer: CommandBuffer,
-                query_pool: QueryPool,
-                query: u32,
-                flags: QueryControlFlags,
+                front_face: FrontFace,
             ),
             takes_handle: false,
+            provided_by: [
+                [VK_VERSION_1_3] => CmdSetFrontFace,
+                [VK_EXT_extended_dynamic_state] => CmdSetFrontFaceEXT,
+            ],
+            hooks: [
+                command_buffer: handle,
+                front_face: value,
+            ],
         },
         {
-            name: pfn_cmd_end_query,
-            method_name: cmd_end_query,
-            ptr: CmdEndQuery,
+            name: pfn_cmd_set_primitive_topology,
+            method_name: cmd_set_primitive_topology,
+            try_method_name: try_cmd_set_primitive_topology,
+            unchecked_method_name: cmd_set_primitive_topology_unchecked,
+            ptr: CmdSetPrimitiveTopology,
+            aliases: [CmdSetPrimitiveTopologyEXT],
             signature: (
                 command_buffer: CommandBuffer,
-                query_pool: QueryPool,
-                query: u32,
+                primitive_topology: PrimitiveTopology,
             ),
             takes_handle: false,
+            provided_by: [
+                [VK_VERSION_1_3] => CmdSetPrimitiveTopology,
+                [VK_EXT_extended_dynamic_state] => CmdSetPrimitiveTopologyEXT,
+            ],
+            hooks: [
+                command_buffer: handle,
+                primitive_topology: value,
+            ],
         },
         {
-            name: pfn_cmd_begin_conditional_rendering_ext,
-            method_name: cmd_begin_conditional_rendering_ext,
-            ptr: CmdBeginConditionalRenderingEXT,
+            name: pfn_cmd_set_viewport_with_count,
+            method_name: cmd_set_viewport_with_count,
+            try_method_name: try_cmd_set_viewport_with_count,
+            unchecked_method_name: cmd_set_viewport_with_count_unchecked,
+            ptr: CmdSetViewportWithCount,
+            aliases: [CmdSetViewportWithCountEXT],
             signature: (
                 command_buffer: CommandBuffer,
-                p_conditional_rendering_begin: *const ConditionalRenderingBeginInfoEXT,
+                viewport_count: u32,
+                p_viewports: *const Viewport,
             ),
             takes_handle: false,
+            provided_by: [
+                [VK_VERSION_1_3] => CmdSetViewportWithCount,
+                [VK_EXT_extended_dynamic_state] => CmdSetViewportWithCountEXT,
+            ],
+            hooks: [
+                command_buffer: handle,
+                viewport_count: value,
+                p_viewports: ptr[viewport_count],
+            ],
         },
         {
-            name: pfn_cmd_end_conditional_rendering_ext,
-            method_name: cmd_end_conditional_rendering_ext,
-            ptr: CmdEndConditionalRenderingEXT,
+            name: pfn_cmd_set_scissor_with_count,
+            method_name: cmd_set_scissor_with_count,
+            try_method_name: try_cmd_set_scissor_with_count,
+            unchecked_method_name: cmd_set_scissor_with_count_unchecked,
+            ptr: CmdSetScissorWithCount,
+            aliases: [CmdSetScissorWithCountEXT],
             signature: (
                 command_buffer: CommandBuffer,
+                scissor_count: u32,
+                p_scissors: *const Rect2D,
             ),
             takes_handle: false,
+            provided_by: [
+                [VK_VERSION_1_3] => CmdSetScissorWithCount,
+                [VK_EXT_extended_dynamic_state] => CmdSetScissorWithCountEXT,
+            ],
+            hooks: [
+                command_buffer: handle,
+                scissor_count: value,
+                p_scissors: ptr[scissor_count],
+            ],
         },
         {
-            name: pfn_cmd_reset_query_pool,
-            method_name: cmd_reset_query_pool,
-            ptr: CmdResetQueryPool,
+            name: pfn_cmd_bind_vertex_buffers_2,
+            method_name: cmd_bind_vertex_buffers_2,
+            try_method_name: try_cmd_bind_vertex_buffers_2,
+            unchecked_method_name: cmd_bind_vertex_buffers_2_unchecked,
+            ptr: CmdBindVertexBuffers2,
+            aliases: [CmdBindVertexBuffers2EXT],
             signature: (
                 command_buffer: CommandBuffer,
-                query_pool: QueryPool,
-                first_query: u32,
-                query_count: u32,
+                first_binding: u32,
+                binding_count: u32,
+                p_buffers: *const Buffer,
+                p_offsets: *const DeviceSize,
+                p_sizes: *const DeviceSize,
+                p_strides: *const DeviceSize,
             ),
             takes_handle: false,
+            provided_by: [
+                [VK_VERSION_1_3] => CmdBindVertexBuffers2,
+                [VK_EXT_extended_dynamic_state] => CmdBindVertexBuffers2EXT,
+            ],
+            hooks: [
+                command_buffer: handle,
+                first_binding: value,
+                binding_count: value,
+                p_buffers: handles[binding_count],
+                p_offsets: ptr[binding_count],
+                p_sizes: ptr[binding_count],
+                p_strides: ptr[binding_count],
+            ],
         },
         {
-            name: pfn_cmd_write_timestamp,
-            method_name: cmd_write_timestamp,
-            ptr: CmdWriteTimestamp,
+            name: pfn_cmd_set_depth_test_enable,
+            method_name: cmd_set_depth_test_enable,
+            try_method_name: try_cmd_set_depth_test_enable,
+            unchecked_method_name: cmd_set_depth_test_enable_unchecked,
+            ptr: CmdSetDepthTestEnable,
+            aliases: [CmdSetDepthTestEnableEXT],
             signature: (
                 command_buffer: CommandBuffer,
-                pipeline_stage: PipelineStageFlagBits,
-                query_pool: QueryPool,
-                query: u32,
+                depth_test_enable: Bool32,
             ),
             takes_handle: false,
+            provided_by: [
+                [VK_VERSION_1_3] => CmdSetDepthTestEnable,
+                [VK_EXT_extended_dynamic_state] => CmdSetDepthTestEnableEXT,
+            ],
+            hooks: [
+                command_buffer: handle,
+                depth_test_enable: value,
+            ],
         },
         {
-            name: pfn_cmd_copy_query_pool_results,
-            method_name: cmd_copy_query_pool_results,
-            ptr: CmdCopyQueryPoolResults,
+            name: pfn_cmd_set_depth_write_enable,
+            method_name: cmd_set_depth_write_enable,
+            try_method_name: try_cmd_set_depth_write_enable,
+            unchecked_method_name: cmd_set_depth_write_enable_unchecked,
+            ptr: CmdSetDepthWriteEnable,
+            aliases: [CmdSetDepthWriteEnableEXT],
             signature: (
                 command_buffer: CommandBuffer,
-                query_pool: QueryPool,
-                first_query: u32,
-                query_count: u32,
-                dst_buffer: Buffer,
-                dst_offset: DeviceSize,
-                stride: DeviceSize,
-                flags: QueryResultFlags,
+                depth_write_enable: Bool32,
             ),
             takes_handle: false,
+            provided_by: [
+                [VK_VERSION_1_3] => CmdSetDepthWriteEnable,
+                [VK_EXT_extended_dynamic_state] => CmdSetDepthWriteEnableEXT,
+            ],
+            hooks: [
+                command_buffer: handle,
+                depth_write_enable: value,
+            ],
         },
         {
-            name: pfn_cmd_push_constants,
-            method_name: cmd_push_constants,
-            ptr: CmdPushConstants,
+            name: pfn_cmd_set_depth_compare_op,
+            method_name: cmd_set_depth_compare_op,
+            try_method_name: try_cmd_set_depth_compare_op,
+            unchecked_method_name: cmd_set_depth_compare_op_unchecked,
+            ptr: CmdSetDepthCompareOp,
+            aliases: [CmdSetDepthCompareOpEXT],
             signature: (
                 command_buffer: CommandBuffer,
-                layout: PipelineLayout,
-                stage_flags: ShaderStageFlags,
-                offset: u32,
-                size: u32,
-                p_values: *const c_void,
+                depth_compare_op: CompareOp,
             ),
             takes_handle: false,
+            provided_by: [
+                [VK_VERSION_1_3] => CmdSetDepthCompareOp,
+                [VK_EXT_extended_dynamic_state] => CmdSetDepthCompareOpEXT,
+            ],
+            hooks: [
+                command_buffer: handle,
+                depth_compare_op: value,
+            ],
         },
         {
-            name: pfn_cmd_begin_render_pass,
-            method_name: cmd_begin_render_pass,
-            ptr: CmdBeginRenderPass,
+            name: pfn_cmd_set_depth_bounds_test_enable,
+            method_name: cmd_set_depth_bounds_test_enable,
+            try_method_name: try_cmd_set_depth_bounds_test_enable,
+            unchecked_method_name: cmd_set_depth_bounds_test_enable_unchecked,
+            ptr: CmdSetDepthBoundsTestEnable,
+            aliases: [CmdSetDepthBoundsTestEnableEXT],
             signature: (
                 command_buffer: CommandBuffer,
-                p_render_pass_begin: *const RenderPassBeginInfo,
-                contents: SubpassContents,
+                depth_bounds_test_enable: Bool32,
             ),
             takes_handle: false,
+            provided_by: [
+                [VK_VERSION_1_3] => CmdSetDepthBoundsTestEnable,
+                [VK_EXT_extended_dynamic_state] => CmdSetDepthBoundsTestEnableEXT,
+            ],
+            hooks: [
+                command_buffer: handle,
+                depth_bounds_test_enable: value,
+            ],
         },
         {
-            name: pfn_cmd_next_subpass,
-            method_name: cmd_next_subpass,
-            ptr: CmdNextSubpass,
+            name: pfn_cmd_set_stencil_test_enable,
+            method_name: cmd_set_stencil_test_enable,
+            try_method_name: try_cmd_set_stencil_test_enable,
+            unchecked_method_name: cmd_set_stencil_test_enable_unchecked,
+            ptr: CmdSetStencilTestEnable,
+            aliases: [CmdSetStencilTestEnableEXT],
             signature: (
                 command_buffer: CommandBuffer,
-                contents: SubpassContents,
+                stencil_test_enable: Bool32,
             ),
             takes_handle: false,
+            provided_by: [
+                [VK_VERSION_1_3] => CmdSetStencilTestEnable,
+                [VK_EXT_extended_dynamic_state] => CmdSetStencilTestEnableEXT,
+            ],
+            hooks: [
+                command_buffer: handle,
+                stencil_test_enable: value,
+            ],
         },
         {
-            name: pfn_cmd_end_render_pass,
-            method_name: cmd_end_render_pass,
-            ptr: CmdEndRenderPass,
+            name: pfn_cmd_set_stencil_op,
+            method_name: cmd_set_stencil_op,
+            try_method_name: try_cmd_set_stencil_op,
+            unchecked_method_name: cmd_set_stencil_op_unchecked,
+            ptr: CmdSetStencilOp,
+            aliases: [CmdSetStencilOpEXT],
             signature: (
                 command_buffer: CommandBuffer,
+                face_mask: StencilFaceFlags,
+                fail_op: StencilOp,
+                pass_op: StencilOp,
+                depth_fail_op: StencilOp,
+                compare_op: CompareOp,
             ),
             takes_handle: false,
+            provided_by: [
+                [VK_VERSION_1_3] => CmdSetStencilOp,
+                [VK_EXT_extended_dynamic_state] => CmdSetStencilOpEXT,
+            ],
+            hooks: [
+                command_buffer: handle,
+                face_mask: value,
+                fail_op: value,
+                pass_op: value,
+                depth_fail_op: value,
+                compare_op: value,
+            ],
         },
         {
-            name: pfn_cmd_execute_commands,
-            method_name: cmd_execute_commands,
-            ptr: CmdExecuteCommands,
+            name: pfn_cmd_set_patch_control_points_ext,
+            method_name: cmd_set_patch_control_points_ext,
+            try_method_name: try_cmd_set_patch_control_points_ext,
+            unchecked_method_name: cmd_set_patch_control_points_ext_unchecked,
+            ptr: CmdSetPatchControlPointsEXT,
             signature: (
                 command_buffer: CommandBuffer,
-                command_buffer_count: u32,
-                p_command_buffers: *const CommandBuffer,
+                patch_control_points: u32,
             ),
             takes_handle: false,
+            provided_by: [
+                [VK_EXT_extended_dynamic_state2] => CmdSetPatchControlPointsEXT,
+            ],
+            hooks: [
+                command_buffer: handle,
+                patch_control_points: value,
+            ],
         },
         {
-            name: pfn_create_shared_swapchains_khr,
-            method_name: create_shared_swapchains_khr,
-            ptr: CreateSharedSwapchainsKHR,
-            signature: (
-                swapchain_count: u32,
-                p_create_infos: *const SwapchainCreateInfoKHR,
-                p_allocator: *const AllocationCallbacks,
-                p_swapchains: *mut SwapchainKHR,
-            ) -> Result,
-            takes_handle: true,
-        },
-        {
-            name: pfn_create_swapchain_khr,
-            method_name: create_swapchain_khr,
-            ptr: CreateSwapchainKHR,
-            signature: (
-                p_create_info: *const SwapchainCreateInfoKHR,
-                p_allocator: *const AllocationCallbacks,
-                p_swapchain: *mut SwapchainKHR,
-            ) -> Result,
-            takes_handle: true,
-        },
-        {
-            name: pfn_destroy_swapchain_khr,
-            method_name: destroy_swapchain_khr,
-            ptr: DestroySwapchainKHR,
+            name: pfn_cmd_set_rasterizer_discard_enable,
+            method_name: cmd_set_rasterizer_discard_enable,
+            try_method_name: try_cmd_set_rasterizer_discard_enable,
+            unchecked_method_name: cmd_set_rasterizer_discard_enable_unchecked,
+            ptr: CmdSetRasterizerDiscardEnable,
+            aliases: [CmdSetRasterizerDiscardEnableEXT],
             signature: (
-                swapchain: SwapchainKHR,
-                p_allocator: *const AllocationCallbacks,
+                command_buffer: CommandBuffer,
+                rasterizer_discard_enable: Bool32,
             ),
-            takes_handle: true,
+            takes_handle: false,
+            provided_by: [
+                [VK_VERSION_1_3] => CmdSetRasterizerDiscardEnable,
+                [VK_EXT_extended_dynamic_state2] => CmdSetRasterizerDiscardEnableEXT,
+            ],
+            hooks: [
+                command_buffer: handle,
+                rasterizer_discard_enable: value,
+            ],
         },
         {
-            name: pfn_get_swapchain_images_khr,
-            method_name: get_swapchain_images_khr,
-            ptr: GetSwapchainImagesKHR,
+            name: pfn_cmd_set_depth_bias_enable,
+            method_name: cmd_set_depth_bias_enable,
+            try_method_name: try_cmd_set_depth_bias_enable,
+            unchecked_method_name: cmd_set_depth_bias_enable_unchecked,
+            ptr: CmdSetDepthBiasEnable,
+            aliases: [CmdSetDepthBiasEnableEXT],
             signature: (
-                swapchain: SwapchainKHR,
-                p_swapchain_image_count: *mut u32,
-                p_swapchain_images: *mut Image,
-            ) -> Result,
-            takes_handle: true,
+                command_buffer: CommandBuffer,
+                depth_bias_enable: Bool32,
+            ),
+            takes_handle: false,
+            provided_by: [
+                [VK_VERSION_1_3] => CmdSetDepthBiasEnable,
+                [VK_EXT_extended_dynamic_state2] => CmdSetDepthBiasEnableEXT,
+            ],
+            hooks: [
+                command_buffer: handle,
+                depth_bias_enable: value,
+            ],
         },
         {
-            name: pfn_acquire_next_image_khr,
-            method_name: acquire_next_image_khr,
-            ptr: AcquireNextImageKHR,
+            name: pfn_cmd_set_logic_op_ext,
+            method_name: cmd_set_logic_op_ext,
+            try_method_name: try_cmd_set_logic_op_ext,
+            unchecked_method_name: cmd_set_logic_op_ext_unchecked,
+            ptr: CmdSetLogicOpEXT,
             signature: (
-                swapchain: SwapchainKHR,
-                timeout: u64,
-                semaphore: Semaphore,
-                fence: Fence,
-                p_image_index: *mut u32,
-            ) -> Result,
-            takes_handle: true,
+                command_buffer: CommandBuffer,
+                logic_op: LogicOp,
+            ),
+            takes_handle: false,
+            provided_by: [
+                [VK_EXT_extended_dynamic_state2] => CmdSetLogicOpEXT,
+            ],
+            hooks: [
+                command_buffer: handle,
+                logic_op: value,
+            ],
         },
         {
-            name: pfn_queue_present_khr,
-            method_name: queue_present_khr,
-            ptr: QueuePresentKHR,
+            name: pfn_cmd_set_primitive_restart_enable,
+            method_name: cmd_set_primitive_restart_enable,
+            try_method_name: try_cmd_set_primitive_restart_enable,
+            unchecked_method_name: cmd_set_primitive_restart_enable_unchecked,
+            ptr: CmdSetPrimitiveRestartEnable,
+            aliases: [CmdSetPrimitiveRestartEnableEXT],
             signature: (
-                queue: Queue,
-                p_present_info: *const PresentInfoKHR,
-            ) -> Result,
+                command_buffer: CommandBuffer,
+                primitive_restart_enable: Bool32,
+            ),
             takes_handle: false,
+            provided_by: [
+                [VK_VERSION_1_3] => CmdSetPrimitiveRestartEnable,
+                [VK_EXT_extended_dynamic_state2] => CmdSetPrimitiveRestartEnableEXT,
+            ],
+            hooks: [
+                command_buffer: handle,
+                primitive_restart_enable: value,
+            ],
         },
         {
-            name: pfn_debug_marker_set_object_name_ext,
-            method_name: debug_marker_set_object_name_ext,
-            ptr: DebugMarkerSetObjectNameEXT,
+            name: pfn_create_private_data_slot,
+            method_name: create_private_data_slot,
+            try_method_name: try_create_private_data_slot,
+            unchecked_method_name: create_private_data_slot_unchecked,
+            ptr: CreatePrivateDataSlot,
+            aliases: [CreatePrivateDataSlotEXT],
             signature: (
-                p_name_info: *const DebugMarkerObjectNameInfoEXT,
+                p_create_info: *const PrivateDataSlotCreateInfo,
+                p_allocator: *const AllocationCallbacks,
+                p_private_data_slot: *mut PrivateDataSlot,
             ) -> Result,
             takes_handle: true,
+            provided_by: [
+                [VK_VERSION_1_3] => CreatePrivateDataSlot,
+                [VK_EXT_private_data] => CreatePrivateDataSlotEXT,
+            ],
+            hooks: [
+                p_create_info: structs,
+                p_allocator: structs,
+                p_private_data_slot: handles,
+            ],
+            checked: {
+                method_name: create_private_data_slot_checked,
+                args: (
+                    p_create_info: *const PrivateDataSlotCreateInfo,
+                    p_allocator: Option<&AllocationCallbacks>,
+                ),
+                output: p_private_data_slot: PrivateDataSlot,
+                result: success,
+            },
         },
         {
-            name: pfn_debug_marker_set_object_tag_ext,
-            method_name: debug_marker_set_object_tag_ext,
-            ptr: DebugMarkerSetObjectTagEXT,
+            name: pfn_cmd_set_tessellation_domain_origin_ext,
+            method_name: cmd_set_tessellation_domain_origin_ext,
+            try_method_name: try_cmd_set_tessellation_domain_origin_ext,
+            unchecked_method_name: cmd_set_tessellation_domain_origin_ext_unchecked,
+            ptr: CmdSetTessellationDomainOriginEXT,
             signature: (
-                p_tag_info: *const DebugMarkerObjectTagInfoEXT,
-            ) -> Result,
-            takes_handle: true,
+                command_buffer: CommandBuffer,
+                domain_origin: TessellationDomainOrigin,
+            ),
+            takes_handle: false,
+            provided_by: [
+                [VK_EXT_extended_dynamic_state3] => CmdSetTessellationDomainOriginEXT,
+            ],
+            hooks: [
+                command_buffer: handle,
+                domain_origin: value,
+            ],
         },
         {
-            name: pfn_cmd_debug_marker_begin_ext,
-            method_name: cmd_debug_marker_begin_ext,
-            ptr: CmdDebugMarkerBeginEXT,
+            name: pfn_cmd_set_depth_clamp_enable_ext,
+            method_name: cmd_set_depth_clamp_enable_ext,
+            try_method_name: try_cmd_set_depth_clamp_enable_ext,
+            unchecked_method_name: cmd_set_depth_clamp_enable_ext_unchecked,
+            ptr: CmdSetDepthClampEnableEXT,
             signature: (
                 command_buffer: CommandBuffer,
-                p_marker_info: *const DebugMarkerMarkerInfoEXT,
+                depth_clamp_enable: Bool32,
             ),
             takes_handle: false,
+            provided_by: [
+                [VK_EXT_extended_dynamic_state3] => CmdSetDepthClampEnableEXT,
+            ],
+            hooks: [
+                command_buffer: handle,
+                depth_clamp_enable: value,
+            ],
         },
         {
-            name: pfn_cmd_debug_marker_end_ext,
-            method_name: cmd_debug_marker_end_ext,
-            ptr: CmdDebugMarkerEndEXT,
+            name: pfn_cmd_set_polygon_mode_ext,
+            method_name: cmd_set_polygon_mode_ext,
+            try_method_name: try_cmd_set_polygon_mode_ext,
+            unchecked_method_name: cmd_set_polygon_mode_ext_unchecked,
+            ptr: CmdSetPolygonModeEXT,
             signature: (
                 command_buffer: CommandBuffer,
+                polygon_mode: PolygonMode,
             ),
             takes_handle: false,
+            provided_by: [
+                [VK_EXT_extended_dynamic_state3] => CmdSetPolygonModeEXT,
+            ],
+            hooks: [
+                command_buffer: handle,
+                polygon_mode: value,
+            ],
         },
         {
-            name: pfn_cmd_debug_marker_insert_ext,
-            method_name: cmd_debug_marker_insert_ext,
-            ptr: CmdDebugMarkerInsertEXT,
+            name: pfn_cmd_set_rasterization_samples_ext,
+            method_name: cmd_set_rasterization_samples_ext,
+            try_method_name: try_cmd_set_rasterization_samples_ext,
+            unchecked_method_name: cmd_set_rasterization_samples_ext_unchecked,
+            ptr: CmdSetRasterizationSamplesEXT,
             signature: (
                 command_buffer: CommandBuffer,
-                p_marker_info: *const DebugMarkerMarkerInfoEXT,
+                rasterization_samples: SampleCountFlagBits,
             ),
             takes_handle: false,
+            provided_by: [
+                [VK_EXT_extended_dynamic_state3] => CmdSetRasterizationSamplesEXT,
+            ],
+            hooks: [
+                command_buffer: handle,
+                rasterization_samples: value,
+            ],
         },
         {
-            name: pfn_get_memory_win_32_handle_nv,
-            method_name: get_memory_win_32_handle_nv,
-            ptr: GetMemoryWin32HandleNV,
+            name: pfn_cmd_set_sample_mask_ext,
+            method_name: cmd_set_sample_mask_ext,
+            try_method_name: try_cmd_set_sample_mask_ext,
+            unchecked_method_name: cmd_set_sample_mask_ext_unchecked,
+            ptr: CmdSetSampleMaskEXT,
             signature: (
-                memory: DeviceMemory,
-                handle_type: ExternalMemoryHandleTypeFlagsNV,
-                p_handle: *mut HANDLE,
-            ) -> Result,
-            takes_handle: true,
+                command_buffer: CommandBuffer,
+                samples: SampleCountFlagBits,
+                p_sample_mask: *const SampleMask,
+            ),
+            takes_handle: false,
+            provided_by: [
+                [VK_EXT_extended_dynamic_state3] => CmdSetSampleMaskEXT,
+            ],
+            hooks: [
+                command_buffer: handle,
+                samples: value,
+                p_sample_mask: opaque,
+            ],
         },
         {
-            name: pfn_cmd_execute_generated_commands_nv,
-            method_name: cmd_execute_generated_commands_nv,
-            ptr: CmdExecuteGeneratedCommandsNV,
+            name: pfn_cmd_set_alpha_to_coverage_enable_ext,
+            method_name: cmd_set_alpha_to_coverage_enable_ext,
+            try_method_name: try_cmd_set_alpha_to_coverage_enable_ext,
+            unchecked_method_name: cmd_set_alpha_to_coverage_enable_ext_unchecked,
+            ptr: CmdSetAlphaToCoverageEnableEXT,
             signature: (
                 command_buffer: CommandBuffer,
-                is_preprocessed: Bool32,
-                p_generated_commands_info: *const GeneratedCommandsInfoNV,
+                alpha_to_coverage_enable: Bool32,
             ),
             takes_handle: false,
+            provided_by: [
+                [VK_EXT_extended_dynamic_state3] => CmdSetAlphaToCoverageEnableEXT,
+            ],
+            hooks: [
+                command_buffer: handle,
+                alpha_to_coverage_enable: value,
+            ],
         },
         {
-            name: pfn_cmd_preprocess_generated_commands_nv,
-            method_name: cmd_preprocess_generated_commands_nv,
-            ptr: CmdPreprocessGeneratedCommandsNV,
+            name: pfn_cmd_set_alpha_to_one_enable_ext,
+            method_name: cmd_set_alpha_to_one_enable_ext,
+            try_method_name: try_cmd_set_alpha_to_one_enable_ext,
+            unchecked_method_name: cmd_set_alpha_to_one_enable_ext_unchecked,
+            ptr: CmdSetAlphaToOneEnableEXT,
             signature: (
                 command_buffer: CommandBuffer,
-                p_generated_commands_info: *const GeneratedCommandsInfoNV,
+                alpha_to_one_enable: Bool32,
             ),
             takes_handle: false,
+            provided_by: [
+                [VK_EXT_extended_dynamic_state3] => CmdSetAlphaToOneEnableEXT,
+            ],
+            hooks: [
+                command_buffer: handle,
+                alpha_to_one_enable: value,
+            ],
         },
         {
-            name: pfn_cmd_bind_pipeline_shader_group_nv,
-            method_name: cmd_bind_pipeline_shader_group_nv,
-            ptr: CmdBindPipelineShaderGroupNV,
+            name: pfn_cmd_set_logic_op_enable_ext,
+            method_name: cmd_set_logic_op_enable_ext,
+            try_method_name: try_cmd_set_logic_op_enable_ext,
+            unchecked_method_name: cmd_set_logic_op_enable_ext_unchecked,
+            ptr: CmdSetLogicOpEnableEXT,
             signature: (
                 command_buffer: CommandBuffer,
-                pipeline_bind_point: PipelineBindPoint,
-                pipeline: Pipeline,
-                group_index: u32,
+                logic_op_enable: Bool32,
             ),
             takes_handle: false,
+            provided_by: [
+                [VK_EXT_extended_dynamic_state3] => CmdSetLogicOpEnableEXT,
+            ],
+            hooks: [
+                command_buffer: handle,
+                logic_op_enable: value,
+            ],
         },
         {
-            name: pfn_get_generated_commands_memory_requirements_nv,
-            method_name: get_generated_commands_memory_requirements_nv,
-            ptr: GetGeneratedCommandsMemoryRequirementsNV,
+            name: pfn_cmd_set_color_blend_enable_ext,
+            method_name: cmd_set_color_blend_enable_ext,
+            try_method_name: try_cmd_set_color_blend_enable_ext,
+            unchecked_method_name: cmd_set_color_blend_enable_ext_unchecked,
+            ptr: CmdSetColorBlendEnableEXT,
             signature: (
-                p_info: *const GeneratedCommandsMemoryRequirementsInfoNV,
-                p_memory_requirements: *mut MemoryRequirements2,
+                command_buffer: CommandBuffer,
+                first_attachment: u32,
+                attachment_count: u32,
+                p_color_blend_enables: *const Bool32,
             ),
-            takes_handle: true,
+            takes_handle: false,
+            provided_by: [
+                [VK_EXT_extended_dynamic_state3] => CmdSetColorBlendEnableEXT,
+            ],
+            hooks: [
+                command_buffer: handle,
+                first_attachment: value,
+                attachment_count: value,
+                p_color_blend_enables: ptr[attachment_count],
+            ],
         },
         {
-            name: pfn_create_indirect_commands_layout_nv,
-            method_name: create_indirect_commands_layout_nv,
-            ptr: CreateIndirectCommandsLayoutNV,
+            name: pfn_cmd_set_color_blend_equation_ext,
+            method_name: cmd_set_color_blend_equation_ext,
+            try_method_name: try_cmd_set_color_blend_equation_ext,
+            unchecked_method_name: cmd_set_color_blend_equation_ext_unchecked,
+            ptr: CmdSetColorBlendEquationEXT,
             signature: (
-                p_create_info: *const IndirectCommandsLayoutCreateInfoNV,
-                p_allocator: *const AllocationCallbacks,
-                p_indirect_commands_layout: *mut IndirectCommandsLayoutNV,
-            ) -> Result,
-            takes_handle: true,
+                command_buffer: CommandBuffer,
+                first_attachment: u32,
+                attachment_count: u32,
+                p_color_blend_equations: *const ColorBlendEquationEXT,
+            ),
+            takes_handle: false,
+            provided_by: [
+                [VK_EXT_extended_dynamic_state3] => CmdSetColorBlendEquationEXT,
+            ],
+            hooks: [
+                command_buffer: handle,
+                first_attachment: value,
+                attachment_count: value,
+                p_color_blend_equations: ptr[attachment_count],
+            ],
         },
         {
-            name: pfn_destroy_indirect_commands_layout_nv,
-            method_name: destroy_indirect_commands_layout_nv,
-            ptr: DestroyIndirectCommandsLayoutNV,
+            name: pfn_cmd_set_color_write_mask_ext,
+            method_name: cmd_set_color_write_mask_ext,
+            try_method_name: try_cmd_set_color_write_mask_ext,
+            unchecked_method_name: cmd_set_color_write_mask_ext_unchecked,
+            ptr: CmdSetColorWriteMaskEXT,
             signature: (
-                indirect_commands_layout: IndirectCommandsLayoutNV,
-                p_allocator: *const AllocationCallbacks,
+                command_buffer: CommandBuffer,
+                first_attachment: u32,
+                attachment_count: u32,
+                p_color_write_masks: *const ColorComponentFlags,
             ),
-            takes_handle: true,
+            takes_handle: false,
+            provided_by: [
+                [VK_EXT_extended_dynamic_state3] => CmdSetColorWriteMaskEXT,
+            ],
+            hooks: [
+                command_buffer: handle,
+                first_attachment: value,
+                attachment_count: value,
+                p_color_write_masks: ptr[attachment_count],
+            ],
         },
         {
-            name: pfn_cmd_push_descriptor_set_khr,
-            method_name: cmd_push_descriptor_set_khr,
-            ptr: CmdPushDescriptorSetKHR,
+            name: pfn_cmd_set_rasterization_stream_ext,
+            method_name: cmd_set_rasterization_stream_ext,
+            try_method_name: try_cmd_set_rasterization_stream_ext,
+            unchecked_method_name: cmd_set_rasterization_stream_ext_unchecked,
+            ptr: CmdSetRasterizationStreamEXT,
             signature: (
                 command_buffer: CommandBuffer,
-                pipeline_bind_point: PipelineBindPoint,
-                layout: PipelineLayout,
-                set: u32,
-                descriptor_write_count: u32,
-                p_descriptor_writes: *const WriteDescriptorSet,
+                rasterization_stream: u32,
             ),
             takes_handle: false,
+            provided_by: [
+                [VK_EXT_extended_dynamic_state3] => CmdSetRasterizationStreamEXT,
+            ],
+            hooks: [
+                command_buffer: handle,
+                rasterization_stream: value,
+            ],
         },
         {
-            name: pfn_trim_command_pool,
-            method_name: trim_command_pool,
-            ptr: TrimCommandPool,
+            name: pfn_cmd_set_conservative_rasterization_mode_ext,
+            method_name: cmd_set_conservative_rasterization_mode_ext,
+            try_method_name: try_cmd_set_conservative_rasterization_mode_ext,
+            unchecked_method_name: cmd_set_conservative_rasterization_mode_ext_unchecked,
+            ptr: CmdSetConservativeRasterizationModeEXT,
             signature: (
-                command_pool: CommandPool,
-                flags: CommandPoolTrimFlags,
+                command_buffer: CommandBuffer,
+                conservative_rasterization_mode: ConservativeRasterizationModeEXT,
             ),
-            takes_handle: true,
+            takes_handle: false,
+            provided_by: [
+                [VK_EXT_extended_dynamic_state3] => CmdSetConservativeRasterizationModeEXT,
+            ],
+            hooks: [
+                command_buffer: handle,
+                conservative_rasterization_mode: value,
+            ],
         },
         {
-            name: pfn_get_memory_win_32_handle_khr,
-            method_name: get_memory_win_32_handle_khr,
-            ptr: GetMemoryWin32HandleKHR,
+            name: pfn_cmd_set_extra_primitive_overestimation_size_ext,
+            method_name: cmd_set_extra_primitive_overestimation_size_ext,
+            try_method_name: try_cmd_set_extra_primitive_overestimation_size_ext,
+            unchecked_method_name: cmd_set_extra_primitive_overestimation_size_ext_unchecked,
+            ptr: CmdSetExtraPrimitiveOverestimationSizeEXT,
             signature: (
-                p_get_win_32_handle_info: *const MemoryGetWin32HandleInfoKHR,
-                p_handle: *mut HANDLE,
-            ) -> Result,
-            takes_handle: true,
+                command_buffer: CommandBuffer,
+                extra_primitive_overestimation_size: c_float,
+            ),
+            takes_handle: false,
+            provided_by: [
+                [VK_EXT_extended_dynamic_state3] => CmdSetExtraPrimitiveOverestimationSizeEXT,
+            ],
+            hooks: [
+                command_buffer: handle,
+                extra_primitive_overestimation_size: value,
+            ],
         },
         {
-            name: pfn_get_memory_win_32_handle_properties_khr,
-            method_name: get_memory_win_32_handle_properties_khr,
-            ptr: GetMemoryWin32HandlePropertiesKHR,
+            name: pfn_cmd_set_depth_clip_enable_ext,
+            method_name: cmd_set_depth_clip_enable_ext,
+            try_method_name: try_cmd_set_depth_clip_enable_ext,
+            unchecked_method_name: cmd_set_depth_clip_enable_ext_unchecked,
+            ptr: CmdSetDepthClipEnableEXT,
             signature: (
-                handle_type: ExternalMemoryHandleTypeFlagBits,
-                handle: HANDLE,
-                p_memory_win_32_handle_properties: *mut MemoryWin32HandlePropertiesKHR,
-            ) -> Result,
-            takes_handle: true,
+                command_buffer: CommandBuffer,
+                depth_clip_enable: Bool32,
+            ),
+            takes_handle: false,
+            provided_by: [
+                [VK_EXT_extended_dynamic_state3] => CmdSetDepthClipEnableEXT,
+            ],
+            hooks: [
+                command_buffer: handle,
+                depth_clip_enable: value,
+            ],
         },
         {
-            name: pfn_get_memory_fd_khr,
-            method_name: get_memory_fd_khr,
-            ptr: GetMemoryFdKHR,
+            name: pfn_cmd_set_sample_locations_enable_ext,
+            method_name: cmd_set_sample_locations_enable_ext,
+            try_method_name: try_cmd_set_sample_locations_enable_ext,
+            unchecked_method_name: cmd_set_sample_locations_enable_ext_unchecked,
+            ptr: CmdSetSampleLocationsEnableEXT,
             signature: (
-                p_get_fd_info: *const MemoryGetFdInfoKHR,
-                p_fd: *mut c_int,
-            ) -> Result,
-            takes_handle: true,
+                command_buffer: CommandBuffer,
+                sample_locations_enable: Bool32,
+            ),
+            takes_handle: false,
+            provided_by: [
+                [VK_EXT_extended_dynamic_state3] => CmdSetSampleLocationsEnableEXT,
+            ],
+            hooks: [
+                command_buffer: handle,
+                sample_locations_enable: value,
+            ],
         },
         {
-            name: pfn_get_memory_fd_properties_khr,
-            method_name: get_memory_fd_properties_khr,
-            ptr: GetMemoryFdPropertiesKHR,
+            name: pfn_cmd_set_color_blend_advanced_ext,
+            method_name: cmd_set_color_blend_advanced_ext,
+            try_method_name: try_cmd_set_color_blend_advanced_ext,
+            unchecked_method_name: cmd_set_color_blend_advanced_ext_unchecked,
+            ptr: CmdSetColorBlendAdvancedEXT,
             signature: (
-                handle_type: ExternalMemoryHandleTypeFlagBits,
-                fd: c_int,
-                p_memory_fd_properties: *mut MemoryFdPropertiesKHR,
-            ) -> Result,
-            takes_handle: true,
+                command_buffer: CommandBuffer,
+                first_attachment: u32,
+                attachment_count: u32,
+                p_color_blend_advanced: *const ColorBlendAdvancedEXT,
+            ),
+            takes_handle: false,
+            provided_by: [
+                [VK_EXT_extended_dynamic_state3] => CmdSetColorBlendAdvancedEXT,
+            ],
+            hooks: [
+                command_buffer: handle,
+                first_attachment: value,
+                attachment_count: value,
+                p_color_blend_advanced: ptr[attachment_count],
+            ],
         },
         {
-            name: pfn_get_memory_zircon_handle_fuchsia,
-            method_name: get_memory_zircon_handle_fuchsia,
-            ptr: GetMemoryZirconHandleFUCHSIA,
+            name: pfn_cmd_set_provoking_vertex_mode_ext,
+            method_name: cmd_set_provoking_vertex_mode_ext,
+            try_method_name: try_cmd_set_provoking_vertex_mode_ext,
+            unchecked_method_name: cmd_set_provoking_vertex_mode_ext_unchecked,
+            ptr: CmdSetProvokingVertexModeEXT,
             signature: (
-                p_get_zircon_handle_info: *const MemoryGetZirconHandleInfoFUCHSIA,
-                p_zircon_handle: *mut zx_handle_t,
-            ) -> Result,
-            takes_handle: true,
+                command_buffer: CommandBuffer,
+                provoking_vertex_mode: ProvokingVertexModeEXT,
+            ),
+            takes_handle: false,
+            provided_by: [
+                [VK_EXT_extended_dynamic_state3] => CmdSetProvokingVertexModeEXT,
+            ],
+            hooks: [
+                command_buffer: handle,
+                provoking_vertex_mode: value,
+            ],
         },
         {
-            name: pfn_get_memory_zircon_handle_properties_fuchsia,
-            method_name: get_memory_zircon_handle_properties_fuchsia,
-            ptr: GetMemoryZirconHandlePropertiesFUCHSIA,
+            name: pfn_cmd_set_line_rasterization_mode_ext,
+            method_name: cmd_set_line_rasterization_mode_ext,
+            try_method_name: try_cmd_set_line_rasterization_mode_ext,
+            unchecked_method_name: cmd_set_line_rasterization_mode_ext_unchecked,
+            ptr: CmdSetLineRasterizationModeEXT,
             signature: (
-                handle_type: ExternalMemoryHandleTypeFlagBits,
-                zircon_handle: zx_handle_t,
-                p_memory_zircon_handle_properties: *mut MemoryZirconHandlePropertiesFUCHSIA,
-            ) -> Result,
-            takes_handle: true,
+                command_buffer: CommandBuffer,
+                line_rasterization_mode: LineRasterizationModeEXT,
+            ),
+            takes_handle: false,
+            provided_by: [
+                [VK_EXT_extended_dynamic_state3] => CmdSetLineRasterizationModeEXT,
+            ],
+            hooks: [
+                command_buffer: handle,
+                line_rasterization_mode: value,
+            ],
         },
         {
-            name: pfn_get_memory_remote_address_nv,
-            method_name: get_memory_remote_address_nv,
-            ptr: GetMemoryRemoteAddressNV,
+            name: pfn_cmd_set_line_stipple_enable_ext,
+            method_name: cmd_set_line_stipple_enable_ext,
+            try_method_name: try_cmd_set_line_stipple_enable_ext,
+            unchecked_method_name: cmd_set_line_stipple_enable_ext_unchecked,
+            ptr: CmdSetLineStippleEnableEXT,
             signature: (
-                p_memory_get_remote_address_info: *const MemoryGetRemoteAddressInfoNV,
-                p_address: *mut RemoteAddressNV,
-            ) -> Result,
-            takes_handle: true,
+                command_buffer: CommandBuffer,
+                stippled_line_enable: Bool32,
+            ),
+            takes_handle: false,
+            provided_by: [
+                [VK_EXT_extended_dynamic_state3] => CmdSetLineStippleEnableEXT,
+            ],
+            hooks: [
+                command_buffer: handle,
+                stippled_line_enable: value,
+            ],
         },
         {
-            name: pfn_get_semaphore_win_32_handle_khr,
-            method_name: get_semaphore_win_32_handle_khr,
-            ptr: GetSemaphoreWin32HandleKHR,
+            name: pfn_cmd_set_depth_clip_negative_one_to_one_ext,
+            method_name: cmd_set_depth_clip_negative_one_to_one_ext,
+            try_method_name: try_cmd_set_depth_clip_negative_one_to_one_ext,
+            unchecked_method_name: cmd_set_depth_clip_negative_one_to_one_ext_unchecked,
+            ptr: CmdSetDepthClipNegativeOneToOneEXT,
             signature: (
-                p_get_win_32_handle_info: *const SemaphoreGetWin32HandleInfoKHR,
-                p_handle: *mut HANDLE,
-            ) -> Result,
-            takes_handle: true,
+                command_buffer: CommandBuffer,
+                negative_one_to_one: Bool32,
+            ),
+            takes_handle: false,
+            provided_by: [
+                [VK_EXT_extended_dynamic_state3] => CmdSetDepthClipNegativeOneToOneEXT,
+            ],
+            hooks: [
+                command_buffer: handle,
+                negative_one_to_one: value,
+            ],
         },
         {
-            name: pfn_import_semaphore_win_32_handle_khr,
-            method_name: import_semaphore_win_32_handle_khr,
-            ptr: ImportSemaphoreWin32HandleKHR,
+            name: pfn_cmd_set_viewport_w_scaling_enable_nv,
+            method_name: cmd_set_viewport_w_scaling_enable_nv,
+            try_method_name: try_cmd_set_viewport_w_scaling_enable_nv,
+            unchecked_method_name: cmd_set_viewport_w_scaling_enable_nv_unchecked,
+            ptr: CmdSetViewportWScalingEnableNV,
             signature: (
-                p_import_semaphore_win_32_handle_info: *const ImportSemaphoreWin32HandleInfoKHR,
-            ) -> Result,
-            takes_handle: true,
+                command_buffer: CommandBuffer,
+                viewport_w_scaling_enable: Bool32,
+            ),
+            takes_handle: false,
+            provided_by: [
+                [VK_EXT_extended_dynamic_state3] => CmdSetViewportWScalingEnableNV,
+            ],
+            hooks: [
+                command_buffer: handle,
+                viewport_w_scaling_enable: value,
+            ],
         },
         {
-            name: pfn_get_semaphore_fd_khr,
-            method_name: get_semaphore_fd_khr,
-            ptr: GetSemaphoreFdKHR,
+            name: pfn_cmd_set_viewport_swizzle_nv,
+            method_name: cmd_set_viewport_swizzle_nv,
+            try_method_name: try_cmd_set_viewport_swizzle_nv,
+            unchecked_method_name: cmd_set_viewport_swizzle_nv_unchecked,
+            ptr: CmdSetViewportSwizzleNV,
             signature: (
-                p_get_fd_info: *const SemaphoreGetFdInfoKHR,
-                p_fd: *mut c_int,
-            ) -> Result,
-            takes_handle: true,
+                command_buffer: CommandBuffer,
+                first_viewport: u32,
+                viewport_count: u32,
+                p_viewport_swizzles: *const ViewportSwizzleNV,
+            ),
+            takes_handle: false,
+            provided_by: [
+                [VK_EXT_extended_dynamic_state3] => CmdSetViewportSwizzleNV,
+            ],
+            hooks: [
+                command_buffer: handle,
+                first_viewport: value,
+                viewport_count: value,
+                p_viewport_swizzles: ptr[viewport_count],
+            ],
         },
         {
-            name: pfn_import_semaphore_fd_khr,
-            method_name: import_semaphore_fd_khr,
-            ptr: ImportSemaphoreFdKHR,
+            name: pfn_cmd_set_coverage_to_color_enable_nv,
+            method_name: cmd_set_coverage_to_color_enable_nv,
+            try_method_name: try_cmd_set_coverage_to_color_enable_nv,
+            unchecked_method_name: cmd_set_coverage_to_color_enable_nv_unchecked,
+            ptr: CmdSetCoverageToColorEnableNV,
             signature: (
-                p_import_semaphore_fd_info: *const ImportSemaphoreFdInfoKHR,
-            ) -> Result,
-            takes_handle: true,
+                command_buffer: CommandBuffer,
+                coverage_to_color_enable: Bool32,
+            ),
+            takes_handle: false,
+            provided_by: [
+                [VK_EXT_extended_dynamic_state3] => CmdSetCoverageToColorEnableNV,
+            ],
+            hooks: [
+                command_buffer: handle,
+                coverage_to_color_enable: value,
+            ],
         },
         {
-            name: pfn_get_semaphore_zircon_handle_fuchsia,
-            method_name: get_semaphore_zircon_handle_fuchsia,
-            ptr: GetSemaphoreZirconHandleFUCHSIA,
+            name: pfn_cmd_set_coverage_to_color_location_nv,
+            method_name: cmd_set_coverage_to_color_location_nv,
+            try_method_name: try_cmd_set_coverage_to_color_location_nv,
+            unchecked_method_name: cmd_set_coverage_to_color_location_nv_unchecked,
+            ptr: CmdSetCoverageToColorLocationNV,
             signature: (
-                p_get_zircon_handle_info: *const SemaphoreGetZirconHandleInfoFUCHSIA,
-                p_zircon_handle: *mut zx_handle_t,
-            ) -> Result,
-            takes_handle: true,
+                command_buffer: CommandBuffer,
+                coverage_to_color_location: u32,
+            ),
+            takes_handle: false,
+            provided_by: [
+                [VK_EXT_extended_dynamic_state3] => CmdSetCoverageToColorLocationNV,
+            ],
+            hooks: [
+                command_buffer: handle,
+                coverage_to_color_location: value,
+            ],
         },
         {
-            name: pfn_import_semaphore_zircon_handle_fuchsia,
-            method_name: import_semaphore_zircon_handle_fuchsia,
-            ptr: ImportSemaphoreZirconHandleFUCHSIA,
+            name: pfn_cmd_set_coverage_modulation_mode_nv,
+            method_name: cmd_set_coverage_modulation_mode_nv,
+            try_method_name: try_cmd_set_coverage_modulation_mode_nv,
+            unchecked_method_name: cmd_set_coverage_modulation_mode_nv_unchecked,
+            ptr: CmdSetCoverageModulationModeNV,
             signature: (
-                p_import_semaphore_zircon_handle_info: *const ImportSemaphoreZirconHandleInfoFUCHSIA,
-            ) -> Result,
-            takes_handle: true,
+                command_buffer: CommandBuffer,
+                coverage_modulation_mode: CoverageModulationModeNV,
+            ),
+            takes_handle: false,
+            provided_by: [
+                [VK_EXT_extended_dynamic_state3] => CmdSetCoverageModulationModeNV,
+            ],
+            hooks: [
+                command_buffer: handle,
+                coverage_modulation_mode: value,
+            ],
         },
         {
-            name: pfn_get_fence_win_32_handle_khr,
-            method_name: get_fence_win_32_handle_khr,
-            ptr: GetFenceWin32HandleKHR,
+            name: pfn_cmd_set_coverage_modulation_table_enable_nv,
+            method_name: cmd_set_coverage_modulation_table_enable_nv,
+            try_method_name: try_cmd_set_coverage_modulation_table_enable_nv,
+            unchecked_method_name: cmd_set_coverage_modulation_table_enable_nv_unchecked,
+            ptr: CmdSetCoverageModulationTableEnableNV,
             signature: (
-                p_get_win_32_handle_info: *const FenceGetWin32HandleInfoKHR,
-                p_handle: *mut HANDLE,
-            ) -> Result,
-            takes_handle: true,
+                command_buffer: CommandBuffer,
+                coverage_modulation_table_enable: Bool32,
+            ),
+            takes_handle: false,
+            provided_by: [
+                [VK_EXT_extended_dynamic_state3] => CmdSetCoverageModulationTableEnableNV,
+            ],
+            hooks: [
+                command_buffer: handle,
+                coverage_modulation_table_enable: value,
+            ],
         },
         {
-            name: pfn_import_fence_win_32_handle_khr,
-            method_name: import_fence_win_32_handle_khr,
-            ptr: ImportFenceWin32HandleKHR,
+            name: pfn_cmd_set_coverage_modulation_table_nv,
+            method_name: cmd_set_coverage_modulation_table_nv,
+            try_method_name: try_cmd_set_coverage_modulation_table_nv,
+            unchecked_method_name: cmd_set_coverage_modulation_table_nv_unchecked,
+            ptr: CmdSetCoverageModulationTableNV,
             signature: (
-                p_import_fence_win_32_handle_info: *const ImportFenceWin32HandleInfoKHR,
-            ) -> Result,
-            takes_handle: true,
+                command_buffer: CommandBuffer,
+                coverage_modulation_table_count: u32,
+                p_coverage_modulation_table: *const c_float,
+            ),
+            takes_handle: false,
+            provided_by: [
+                [VK_EXT_extended_dynamic_state3] => CmdSetCoverageModulationTableNV,
+            ],
+            hooks: [
+                command_buffer: handle,
+                coverage_modulation_table_count: value,
+                p_coverage_modulation_table: ptr[coverage_modulation_table_count],
+            ],
         },
         {
-            name: pfn_get_fence_fd_khr,
-            method_name: get_fence_fd_khr,
-            ptr: GetFenceFdKHR,
+            name: pfn_cmd_set_shading_rate_image_enable_nv,
+            method_name: cmd_set_shading_rate_image_enable_nv,
+            try_method_name: try_cmd_set_shading_rate_image_enable_nv,
+            unchecked_method_name: cmd_set_shading_rate_image_enable_nv_unchecked,
+            ptr: CmdSetShadingRateImageEnableNV,
             signature: (
-                p_get_fd_info: *const FenceGetFdInfoKHR,
-                p_fd: *mut c_int,
-            ) -> Result,
-            takes_handle: true,
+                command_buffer: CommandBuffer,
+                shading_rate_image_enable: Bool32,
+            ),
+            takes_handle: false,
+            provided_by: [
+                [VK_EXT_extended_dynamic_state3] => CmdSetShadingRateImageEnableNV,
+            ],
+            hooks: [
+                command_buffer: handle,
+                shading_rate_image_enable: value,
+            ],
         },
         {
-            name: pfn_import_fence_fd_khr,
-            method_name: import_fence_fd_khr,
-            ptr: ImportFenceFdKHR,
+            name: pfn_cmd_set_coverage_reduction_mode_nv,
+            method_name: cmd_set_coverage_reduction_mode_nv,
+            try_method_name: try_cmd_set_coverage_reduction_mode_nv,
+            unchecked_method_name: cmd_set_coverage_reduction_mode_nv_unchecked,
+            ptr: CmdSetCoverageReductionModeNV,
             signature: (
-                p_import_fence_fd_info: *const ImportFenceFdInfoKHR,
-            ) -> Result,
-            takes_handle: true,
+                command_buffer: CommandBuffer,
+                coverage_reduction_mode: CoverageReductionModeNV,
+            ),
+            takes_handle: false,
+            provided_by: [
+                [VK_EXT_extended_dynamic_state3] => CmdSetCoverageReductionModeNV,
+            ],
+            hooks: [
+                command_buffer: handle,
+                coverage_reduction_mode: value,
+            ],
         },
         {
-            name: pfn_display_power_control_ext,
-            method_name: display_power_control_ext,
-            ptr: DisplayPowerControlEXT,
+            name: pfn_cmd_set_representative_fragment_test_enable_nv,
+            method_name: cmd_set_representative_fragment_test_enable_nv,
+            try_method_name: try_cmd_set_representative_fragment_test_enable_nv,
+            unchecked_method_name: cmd_set_representative_fragment_test_enable_nv_unchecked,
+            ptr: CmdSetRepresentativeFragmentTestEnableNV,
             signature: (
-                display: DisplayKHR,
-                p_display_power_info: *const DisplayPowerInfoEXT,
-            ) -> Result,
-            takes_handle: true,
+                command_buffer: CommandBuffer,
+                representative_fragment_test_enable: Bool32,
+            ),
+            takes_handle: false,
+            provided_by: [
+                [VK_EXT_extended_dynamic_state3] => CmdSetRepresentativeFragmentTestEnableNV,
+            ],
+            hooks: [
+                command_buffer: handle,
+                representative_fragment_test_enable: value,
+            ],
         },
         {
-            name: pfn_register_device_event_ext,
-            method_name: register_device_event_ext,
-            ptr: RegisterDeviceEventEXT,
+            name: pfn_destroy_private_data_slot,
+            method_name: destroy_private_data_slot,
+            try_method_name: try_destroy_private_data_slot,
+            unchecked_method_name: destroy_private_data_slot_unchecked,
+            ptr: DestroyPrivateDataSlot,
+            aliases: [DestroyPrivateDataSlotEXT],
             signature: (
-                p_device_event_info: *const DeviceEventInfoEXT,
+                private_data_slot: PrivateDataSlot,
                 p_allocator: *const AllocationCallbacks,
-                p_fence: *mut Fence,
-            ) -> Result,
+            ),
             takes_handle: true,
+            provided_by: [
+                [VK_VERSION_1_3] => DestroyPrivateDataSlot,
+                [VK_EXT_private_data] => DestroyPrivateDataSlotEXT,
+            ],
+            hooks: [
+                private_data_slot: handle,
+                p_allocator: structs,
+            ],
         },
         {
-            name: pfn_register_display_event_ext,
-            method_name: register_display_event_ext,
-            ptr: RegisterDisplayEventEXT,
+            name: pfn_set_private_data,
+            method_name: set_private_data,
+            try_method_name: try_set_private_data,
+            unchecked_method_name: set_private_data_unchecked,
+            ptr: SetPrivateData,
+            aliases: [SetPrivateDataEXT],
             signature: (
-                display: DisplayKHR,
-                p_display_event_info: *const DisplayEventInfoEXT,
-                p_allocator: *const AllocationCallbacks,
-                p_fence: *mut Fence,
+                object_type: ObjectType,
+                object_handle: u64,
+                private_data_slot: PrivateDataSlot,
+                data: u64,
             ) -> Result,
             takes_handle: true,
+            provided_by: [
+                [VK_VERSION_1_3] => SetPrivateData,
+                [VK_EXT_private_data] => SetPrivateDataEXT,
+            ],
+            hooks: [
+                object_type: value,
+                object_handle: value,
+                private_data_slot: handle,
+                data: value,
+            ],
         },
         {
-            name: pfn_get_swapchain_counter_ext,
-            method_name: get_swapchain_counter_ext,
-            ptr: GetSwapchainCounterEXT,
+            name: pfn_get_private_data,
+            method_name: get_private_data,
+            try_method_name: try_get_private_data,
+            unchecked_method_name: get_private_data_unchecked,
+            ptr: GetPrivateData,
+            aliases: [GetPrivateDataEXT],
             signature: (
-                swapchain: SwapchainKHR,
-                counter: SurfaceCounterFlagBitsEXT,
-                p_counter_value: *mut u64,
-            ) -> Result,
+                object_type: ObjectType,
+                object_handle: u64,
+                private_data_slot: PrivateDataSlot,
+                p_data: *mut u64,
+            ),
             takes_handle: true,
+            provided_by: [
+                [VK_VERSION_1_3] => GetPrivateData,
+                [VK_EXT_private_data] => GetPrivateDataEXT,
+            ],
+            hooks: [
+                object_type: value,
+                object_handle: value,
+                private_data_slot: handle,
+                p_data: ptr,
+            ],
+            checked: {
+                method_name: get_private_data_checked,
+                args: (
+                    object_type: ObjectType,
+                    object_handle: u64,
+                    private_data_slot: PrivateDataSlot,
+                ),
+                output: p_data: u64,
+                result: void,
+            },
         },
         {
-            name: pfn_get_device_group_peer_memory_features,
-            method_name: get_device_group_peer_memory_features,
-            ptr: GetDeviceGroupPeerMemoryFeatures,
+            name: pfn_cmd_copy_buffer_2,
+            method_name: cmd_copy_buffer_2,
+            try_method_name: try_cmd_copy_buffer_2,
+            unchecked_method_name: cmd_copy_buffer_2_unchecked,
+            ptr: CmdCopyBuffer2,
+            aliases: [CmdCopyBuffer2KHR],
             signature: (
-                heap_index: u32,
-                local_device_index: u32,
-                remote_device_index: u32,
-                p_peer_memory_features: *mut PeerMemoryFeatureFlags,
+                command_buffer: CommandBuffer,
+                p_copy_buffer_info: *const CopyBufferInfo2,
             ),
-            takes_handle: true,
+            takes_handle: false,
+            provided_by: [
+                [VK_VERSION_1_3] => CmdCopyBuffer2,
+                [VK_KHR_copy_commands2] => CmdCopyBuffer2KHR,
+            ],
+            hooks: [
+                command_buffer: handle,
+                p_copy_buffer_info: structs,
+            ],
         },
         {
-            name: pfn_bind_buffer_memory_2,
-            method_name: bind_buffer_memory_2,
-            ptr: BindBufferMemory2,
+            name: pfn_cmd_copy_image_2,
+            method_name: cmd_copy_image_2,
+            try_method_name: try_cmd_copy_image_2,
+            unchecked_method_name: cmd_copy_image_2_unchecked,
+            ptr: CmdCopyImage2,
+            aliases: [CmdCopyImage2KHR],
             signature: (
-                bind_info_count: u32,
-                p_bind_infos: *const BindBufferMemoryInfo,
-            ) -> Result,
-            takes_handle: true,
+                command_buffer: CommandBuffer,
+                p_copy_image_info: *const CopyImageInfo2,
+            ),
+            takes_handle: false,
+            provided_by: [
+                [VK_VERSION_1_3] => CmdCopyImage2,
+                [VK_KHR_copy_commands2] => CmdCopyImage2KHR,
+            ],
+            hooks: [
+                command_buffer: handle,
+                p_copy_image_info: structs,
+            ],
         },
         {
-            name: pfn_bind_image_memory_2,
-            method_name: bind_image_memory_2,
-            ptr: BindImageMemory2,
+            name: pfn_cmd_blit_image_2,
+            method_name: cmd_blit_image_2,
+            try_method_name: try_cmd_blit_image_2,
+            unchecked_method_name: cmd_blit_image_2_unchecked,
+            ptr: CmdBlitImage2,
+            aliases: [CmdBlitImage2KHR],
             signature: (
-                bind_info_count: u32,
-                p_bind_infos: *const BindImageMemoryInfo,
-            ) -> Result,
-            takes_handle: true,
+                command_buffer: CommandBuffer,
+                p_blit_image_info: *const BlitImageInfo2,
+            ),
+            takes_handle: false,
+            provided_by: [
+                [VK_VERSION_1_3] => CmdBlitImage2,
+                [VK_KHR_copy_commands2] => CmdBlitImage2KHR,
+            ],
+            hooks: [
+                command_buffer: handle,
+                p_blit_image_info: structs,
+            ],
         },
         {
-            name: pfn_cmd_set_device_mask,
-            method_name: cmd_set_device_mask,
-            ptr: CmdSetDeviceMask,
+            name: pfn_cmd_copy_buffer_to_image_2,
+            method_name: cmd_copy_buffer_to_image_2,
+            try_method_name: try_cmd_copy_buffer_to_image_2,
+            unchecked_method_name: cmd_copy_buffer_to_image_2_unchecked,
+            ptr: CmdCopyBufferToImage2,
+            aliases: [CmdCopyBufferToImage2KHR],
             signature: (
                 command_buffer: CommandBuffer,
-                device_mask: u32,
+                p_copy_buffer_to_image_info: *const CopyBufferToImageInfo2,
             ),
             takes_handle: false,
+            provided_by: [
+                [VK_VERSION_1_3] => CmdCopyBufferToImage2,
+                [VK_KHR_copy_commands2] => CmdCopyBufferToImage2KHR,
+            ],
+            hooks: [
+                command_buffer: handle,
+                p_copy_buffer_to_image_info: structs,
+            ],
         },
         {
-            name: pfn_get_device_group_present_capabilities_khr,
-            method_name: get_device_group_present_capabilities_khr,
-            ptr: GetDeviceGroupPresentCapabilitiesKHR,
+            name: pfn_cmd_copy_image_to_buffer_2,
+            method_name: cmd_copy_image_to_buffer_2,
+            try_method_name: try_cmd_copy_image_to_buffer_2,
+            unchecked_method_name: cmd_copy_image_to_buffer_2_unchecked,
+            ptr: CmdCopyImageToBuffer2,
+            aliases: [CmdCopyImageToBuffer2KHR],
             signature: (
-                p_device_group_present_capabilities: *mut DeviceGroupPresentCapabilitiesKHR,
-            ) -> Result,
-            takes_handle: true,
+                command_buffer: CommandBuffer,
+                p_copy_image_to_buffer_info: *const CopyImageToBufferInfo2,
+            ),
+            takes_handle: false,
+            provided_by: [
+                [VK_VERSION_1_3] => CmdCopyImageToBuffer2,
+                [VK_KHR_copy_commands2] => CmdCopyImageToBuffer2KHR,
+            ],
+            hooks: [
+                command_buffer: handle,
+                p_copy_image_to_buffer_info: structs,
+            ],
         },
         {
-            name: pfn_get_device_group_surface_present_modes_khr,
-            method_name: get_device_group_surface_present_modes_khr,
-            ptr: GetDeviceGroupSurfacePresentModesKHR,
+            name: pfn_cmd_resolve_image_2,
+            method_name: cmd_resolve_image_2,
+            try_method_name: try_cmd_resolve_image_2,
+            unchecked_method_name: cmd_resolve_image_2_unchecked,
+            ptr: CmdResolveImage2,
+            aliases: [CmdResolveImage2KHR],
             signature: (
-                surface: SurfaceKHR,
-                p_modes: *mut DeviceGroupPresentModeFlagsKHR,
-            ) -> Result,
-            takes_handle: true,
+                command_buffer: CommandBuffer,
+                p_resolve_image_info: *const ResolveImageInfo2,
+            ),
+            takes_handle: false,
+            provided_by: [
+                [VK_VERSION_1_3] => CmdResolveImage2,
+                [VK_KHR_copy_commands2] => CmdResolveImage2KHR,
+            ],
+            hooks: [
+                command_buffer: handle,
+                p_resolve_image_info: structs,
+            ],
         },
         {
-            name: pfn_acquire_next_image_2_khr,
-            method_name: acquire_next_image_2_khr,
-            ptr: AcquireNextImage2KHR,
+            name: pfn_cmd_set_fragment_shading_rate_khr,
+            method_name: cmd_set_fragment_shading_rate_khr,
+            try_method_name: try_cmd_set_fragment_shading_rate_khr,
+            unchecked_method_name: cmd_set_fragment_shading_rate_khr_unchecked,
+            ptr: CmdSetFragmentShadingRateKHR,
             signature: (
-                p_acquire_info: *const AcquireNextImageInfoKHR,
-                p_image_index: *mut u32,
-            ) -> Result,
-            takes_handle: true,
+                command_buffer: CommandBuffer,
+                p_fragment_size: *const Extent2D,
+                combiner_ops: [FragmentShadingRateCombinerOpKHR; 2],
+            ),
+            takes_handle: false,
+            provided_by: [
+                [VK_KHR_fragment_shading_rate] => CmdSetFragmentShadingRateKHR,
+            ],
+            hooks: [
+                command_buffer: handle,
+                p_fragment_size: ptr,
+                combiner_ops: value,
+            ],
         },
         {
-            name: pfn_cmd_dispatch_base,
-            method_name: cmd_dispatch_base,
-            ptr: CmdDispatchBase,
+            name: pfn_cmd_set_fragment_shading_rate_enum_nv,
+            method_name: cmd_set_fragment_shading_rate_enum_nv,
+            try_method_name: try_cmd_set_fragment_shading_rate_enum_nv,
+            unchecked_method_name: cmd_set_fragment_shading_rate_enum_nv_unchecked,
+            ptr: CmdSetFragmentShadingRateEnumNV,
             signature: (
                 command_buffer: CommandBuffer,
-                base_group_x: u32,
-                base_group_y: u32,
-                base_group_z: u32,
-                group_count_x: u32,
-                group_count_y: u32,
-                group_count_z: u32,
+                shading_rate: FragmentShadingRateNV,
+                combiner_ops: [FragmentShadingRateCombinerOpKHR; 2],
             ),
             takes_handle: false,
+            provided_by: [
+                [VK_NV_fragment_shading_rate_enums] => CmdSetFragmentShadingRateEnumNV,
+            ],
+            hooks: [
+                command_buffer: handle,
+                shading_rate: value,
+                combiner_ops: value,
+            ],
         },
         {
-            name: pfn_create_descriptor_update_template,
-            method_name: create_descriptor_update_template,
-            ptr: CreateDescriptorUpdateTemplate,
+            name: pfn_get_acceleration_structure_build_sizes_khr,
+            method_name: get_acceleration_structure_build_sizes_khr,
+            try_method_name: try_get_acceleration_structure_build_sizes_khr,
+            unchecked_method_name: get_acceleration_structure_build_sizes_khr_unchecked,
+            ptr: GetAccelerationStructureBuildSizesKHR,
             signature: (
-                p_create_info: *const DescriptorUpdateTemplateCreateInfo,
-                p_allocator: *const AllocationCallbacks,
-                p_descriptor_update_template: *mut DescriptorUpdateTemplate,
-            ) -> Result,
+                build_type: AccelerationStructureBuildTypeKHR,
+                p_build_info: *const AccelerationStructureBuildGeometryInfoKHR,
+                p_max_primitive_counts: *const u32,
+                p_size_info: *mut AccelerationStructureBuildSizesInfoKHR,
+            ),
             takes_handle: true,
+            provided_by: [
+                [VK_KHR_acceleration_structure] => GetAccelerationStructureBuildSizesKHR,
+            ],
+            hooks: [
+                build_type: value,
+                p_build_info: structs,
+                p_max_primitive_counts: ptr[(*p_build_info).geometry_count],
+                p_size_info: structs,
+            ],
+            checked: {
+                method_name: get_acceleration_structure_build_sizes_khr_checked,
+                args: (
+                    build_type: AccelerationStructureBuildTypeKHR,
+                    p_build_info: *const AccelerationStructureBuildGeometryInfoKHR,
+                    p_max_primitive_counts: *const u32,
+                ),
+                output: p_size_info: AccelerationStructureBuildSizesInfoKHR,
+                result: void,
+            },
         },
         {
-            name: pfn_destroy_descriptor_update_template,
-            method_name: destroy_descriptor_update_template,
-            ptr: DestroyDescriptorUpdateTemplate,
+            name: pfn_cmd_set_vertex_input_ext,
+            method_name: cmd_set_vertex_input_ext,
+            try_method_name: try_cmd_set_vertex_input_ext,
+            unchecked_method_name: cmd_set_vertex_input_ext_unchecked,
+            ptr: CmdSetVertexInputEXT,
             signature: (
-                descriptor_update_template: DescriptorUpdateTemplate,
-                p_allocator: *const AllocationCallbacks,
+                command_buffer: CommandBuffer,
+                vertex_binding_description_count: u32,
+                p_vertex_binding_descriptions: *const VertexInputBindingDescription2EXT,
+                vertex_attribute_description_count: u32,
+                p_vertex_attribute_descriptions: *const VertexInputAttributeDescription2EXT,
             ),
-            takes_handle: true,
+            takes_handle: false,
+            provided_by: [
+                [VK_EXT_vertex_input_dynamic_state] => CmdSetVertexInputEXT,
+            ],
+            hooks: [
+                command_buffer: handle,
+                vertex_binding_description_count: value,
+                p_vertex_binding_descriptions: structs[vertex_binding_description_count],
+                vertex_attribute_description_count: value,
+                p_vertex_attribute_descriptions: structs[vertex_attribute_description_count],
+            ],
         },
         {
-            name: pfn_update_descriptor_set_with_template,
-            method_name: update_descriptor_set_with_template,
-            ptr: UpdateDescriptorSetWithTemplate,
+            name: pfn_cmd_set_color_write_enable_ext,
+            method_name: cmd_set_color_write_enable_ext,
+            try_method_name: try_cmd_set_color_write_enable_ext,
+            unchecked_method_name: cmd_set_color_write_enable_ext_unchecked,
+            ptr: CmdSetColorWriteEnableEXT,
             signature: (
-                descriptor_set: DescriptorSet,
-                descriptor_update_template: DescriptorUpdateTemplate,
-                p_data: *const c_void,
+                command_buffer: CommandBuffer,
+                attachment_count: u32,
+                p_color_write_enables: *const Bool32,
             ),
-            takes_handle: true,
+            takes_handle: false,
+            provided_by: [
+                [VK_EXT_color_write_enable] => CmdSetColorWriteEnableEXT,
+            ],
+            hooks: [
+                command_buffer: handle,
+                attachment_count: value,
+                p_color_write_enables: ptr[attachment_count],
+            ],
         },
         {
-            name: pfn_cmd_push_descriptor_set_with_template_khr,
-            method_name: cmd_push_descriptor_set_with_template_khr,
-            ptr: CmdPushDescriptorSetWithTemplateKHR,
+            name: pfn_cmd_set_event_2,
+            method_name: cmd_set_event_2,
+            try_method_name: try_cmd_set_event_2,
+            unchecked_method_name: cmd_set_event_2_unchecked,
+            ptr: CmdSetEvent2,
+            aliases: [CmdSetEvent2KHR],
             signature: (
                 command_buffer: CommandBuffer,
-                descriptor_update_template: DescriptorUpdateTemplate,
-                layout: PipelineLayout,
-                set: u32,
-                p_data: *const c_void,
+                event: Event,
+                p_dependency_info: *const DependencyInfo,
             ),
             takes_handle: false,
+            provided_by: [
+                [VK_VERSION_1_3] => CmdSetEvent2,
+                [VK_KHR_synchronization2] => CmdSetEvent2KHR,
+            ],
+            hooks: [
+                command_buffer: handle,
+                event: handle,
+                p_dependency_info: structs,
+            ],
         },
         {
-            name: pfn_set_hdr_metadata_ext,
-            method_name: set_hdr_metadata_ext,
-            ptr: SetHdrMetadataEXT,
+            name: pfn_cmd_reset_event_2,
+            method_name: cmd_reset_event_2,
+            try_method_name: try_cmd_reset_event_2,
+            unchecked_method_name: cmd_reset_event_2_unchecked,
+            ptr: CmdResetEvent2,
+            aliases: [CmdResetEvent2KHR],
             signature: (
-                swapchain_count: u32,
-                p_swapchains: *const SwapchainKHR,
-                p_metadata: *const HdrMetadataEXT,
+                command_buffer: CommandBuffer,
+                event: Event,
+                stage_mask: PipelineStageFlags2,
             ),
-            takes_handle: true,
+            takes_handle: false,
+            provided_by: [
+                [VK_VERSION_1_3] => CmdResetEvent2,
+                [VK_KHR_synchronization2] => CmdResetEvent2KHR,
+            ],
+            hooks: [
+                command_buffer: handle,
+                event: handle,
+                stage_mask: value,
+            ],
         },
         {
-            name: pfn_get_swapchain_status_khr,
-            method_name: get_swapchain_status_khr,
-            ptr: GetSwapchainStatusKHR,
+            name: pfn_cmd_wait_events_2,
+            method_name: cmd_wait_events_2,
+            try_method_name: try_cmd_wait_events_2,
+            unchecked_method_name: cmd_wait_events_2_unchecked,
+            ptr: CmdWaitEvents2,
+            aliases: [CmdWaitEvents2KHR],
             signature: (
-                swapchain: SwapchainKHR,
-            ) -> Result,
-            takes_handle: true,
+                command_buffer: CommandBuffer,
+                event_count: u32,
+                p_events: *const Event,
+                p_dependency_infos: *const DependencyInfo,
+            ),
+            takes_handle: false,
+            provided_by: [
+                [VK_VERSION_1_3] => CmdWaitEvents2,
+                [VK_KHR_synchronization2] => CmdWaitEvents2KHR,
+            ],
+            hooks: [
+                command_buffer: handle,
+                event_count: value,
+                p_events: handles[event_count],
+                p_dependency_infos: structs[event_count],
+            ],
         },
         {
-            name: pfn_get_refresh_cycle_duration_google,
-            method_name: get_refresh_cycle_duration_google,
-            ptr: GetRefreshCycleDurationGOOGLE,
+            name: pfn_cmd_pipeline_barrier_2,
+            method_name: cmd_pipeline_barrier_2,
+            try_method_name: try_cmd_pipeline_barrier_2,
+            unchecked_method_name: cmd_pipeline_barrier_2_unchecked,
+            ptr: CmdPipelineBarrier2,
+            aliases: [CmdPipelineBarrier2KHR],
             signature: (
-                swapchain: SwapchainKHR,
-                p_display_timing_properties: *mut RefreshCycleDurationGOOGLE,
-            ) -> Result,
-            takes_handle: true,
+                command_buffer: CommandBuffer,
+                p_dependency_info: *const DependencyInfo,
+            ),
+            takes_handle: false,
+            provided_by: [
+                [VK_VERSION_1_3] => CmdPipelineBarrier2,
+                [VK_KHR_synchronization2] => CmdPipelineBarrier2KHR,
+            ],
+            hooks: [
+                command_buffer: handle,
+                p_dependency_info: structs,
+            ],
         },
         {
-            name: pfn_get_past_presentation_timing_google,
-            method_name: get_past_presentation_timing_google,
-            ptr: GetPastPresentationTimingGOOGLE,
+            name: pfn_queue_submit_2,
+            method_name: queue_submit_2,
+            try_method_name: try_queue_submit_2,
+            unchecked_method_name: queue_submit_2_unchecked,
+            ptr: QueueSubmit2,
+            aliases: [QueueSubmit2KHR],
             signature: (
-                swapchain: SwapchainKHR,
-                p_presentation_timing_count: *mut u32,
-                p_presentation_timings: *mut PastPresentationTimingGOOGLE,
+                queue: Queue,
+                submit_count: u32,
+                p_submits: *const SubmitInfo2,
+                fence: Fence,
             ) -> Result,
-            takes_handle: true,
+            takes_handle: false,
+            provided_by: [
+                [VK_VERSION_1_3] => QueueSubmit2,
+                [VK_KHR_synchronization2] => QueueSubmit2KHR,
+            ],
+            hooks: [
+                queue: handle,
+                submit_count: value,
+                p_submits: structs[submit_count],
+                fence: handle,
+            ],
         },
         {
-            name: pfn_cmd_set_viewport_w_scaling_nv,
-            method_name: cmd_set_viewport_w_scaling_nv,
-            ptr: CmdSetViewportWScalingNV,
+            name: pfn_cmd_write_timestamp_2,
+            method_name: cmd_write_timestamp_2,
+            try_method_name: try_cmd_write_timestamp_2,
+            unchecked_method_name: cmd_write_timestamp_2_unchecked,
+            ptr: CmdWriteTimestamp2,
+            aliases: [CmdWriteTimestamp2KHR],
             signature: (
                 command_buffer: CommandBuffer,
-                first_viewport: u32,
-                viewport_count: u32,
-                p_viewport_w_scalings: *const ViewportWScalingNV,
+                stage: PipelineStageFlags2,
+                query_pool: QueryPool,
+                query: u32,
             ),
             takes_handle: false,
+            provided_by: [
+                [VK_VERSION_1_3] => CmdWriteTimestamp2,
+                [VK_KHR_synchronization2] => CmdWriteTimestamp2KHR,
+            ],
+            hooks: [
+                command_buffer: handle,
+                stage: value,
+                query_pool: handle,
+                query: value,
+            ],
         },
         {
-            name: pfn_cmd_set_discard_rectangle_ext,
-            method_name: cmd_set_discard_rectangle_ext,
-            ptr: CmdSetDiscardRectangleEXT,
+            name: pfn_cmd_write_buffer_marker_2_amd,
+            method_name: cmd_write_buffer_marker_2_amd,
+            try_method_name: try_cmd_write_buffer_marker_2_amd,
+            unchecked_method_name: cmd_write_buffer_marker_2_amd_unchecked,
+            ptr: CmdWriteBufferMarker2AMD,
             signature: (
                 command_buffer: CommandBuffer,
-                first_discard_rectangle: u32,
-                discard_rectangle_count: u32,
-                p_discard_rectangles: *const Rect2D,
+                stage: PipelineStageFlags2,
+                dst_buffer: Buffer,
+                dst_offset: DeviceSize,
+                marker: u32,
             ),
             takes_handle: false,
+            provided_by: [
+                [VK_KHR_synchronization2, VK_AMD_buffer_marker] => CmdWriteBufferMarker2AMD,
+            ],
+            hooks: [
+                command_buffer: handle,
+                stage: value,
+                dst_buffer: handle,
+                dst_offset: value,
+                marker: value,
+            ],
         },
         {
-            name: pfn_cmd_set_sample_locations_ext,
-            method_name: cmd_set_sample_locations_ext,
-            ptr: CmdSetSampleLocationsEXT,
+            name: pfn_get_queue_checkpoint_data_2_nv,
+            method_name: get_queue_checkpoint_data_2_nv,
+            try_method_name: try_get_queue_checkpoint_data_2_nv,
+            unchecked_method_name: get_queue_checkpoint_data_2_nv_unchecked,
+            ptr: GetQueueCheckpointData2NV,
             signature: (
-                command_buffer: CommandBuffer,
-                p_sample_locations_info: *const SampleLocationsInfoEXT,
+                queue: Queue,
+                p_checkpoint_data_count: *mut u32,
+                p_checkpoint_data: *mut CheckpointData2NV,
             ),
             takes_handle: false,
+            provided_by: [
+                [VK_KHR_synchronization2, VK_NV_device_diagnostic_checkpoints] => GetQueueCheckpointData2NV,
+            ],
+            hooks: [
+                queue: handle,
+                p_checkpoint_data_count: ptr,
+                p_checkpoint_data: structs[*p_checkpoint_data_count],
+            ],
+            vec: {
+                method_name: get_queue_checkpoint_data_2_nv_vec,
+                args: (
+                    queue: Queue,
+                ),
+                element: CheckpointData2NV,
+                result: void,
+            },
         },
         {
-            name: pfn_get_buffer_memory_requirements_2,
-            method_name: get_buffer_memory_requirements_2,
-            ptr: GetBufferMemoryRequirements2,
+            name: pfn_create_video_session_khr,
+            method_name: create_video_session_khr,
+            try_method_name: try_create_video_session_khr,
+            unchecked_method_name: create_video_session_khr_unchecked,
+            ptr: CreateVideoSessionKHR,
             signature: (
-                p_info: *const BufferMemoryRequirementsInfo2,
-                p_memory_requirements: *mut MemoryRequirements2,
-            ),
+                p_create_info: *const VideoSessionCreateInfoKHR,
+                p_allocator: *const AllocationCallbacks,
+                p_video_session: *mut VideoSessionKHR,
+            ) -> Result,
             takes_handle: true,
+            provided_by: [
+                [VK_KHR_video_queue] => CreateVideoSessionKHR,
+            ],
+            hooks: [
+                p_create_info: structs,
+                p_allocator: structs,
+                p_video_session: handles,
+            ],
+            checked: {
+                method_name: create_video_session_khr_checked,
+                args: (
+                    p_create_info: *const VideoSessionCreateInfoKHR,
+                    p_allocator: Option<&AllocationCallbacks>,
+                ),
+                output: p_video_session: VideoSessionKHR,
+                result: success,
+            },
         },
         {
-            name: pfn_get_image_memory_requirements_2,
-            method_name: get_image_memory_requirements_2,
-            ptr: GetImageMemoryRequirements2,
+            name: pfn_destroy_video_session_khr,
+            method_name: destroy_video_session_khr,
+            try_method_name: try_destroy_video_session_khr,
+            unchecked_method_name: destroy_video_session_khr_unchecked,
+            ptr: DestroyVideoSessionKHR,
             signature: (
-                p_info: *const ImageMemoryRequirementsInfo2,
-                p_memory_requirements: *mut MemoryRequirements2,
+                video_session: VideoSessionKHR,
+                p_allocator: *const AllocationCallbacks,
             ),
             takes_handle: true,
+            provided_by: [
+                [VK_KHR_video_queue] => DestroyVideoSessionKHR,
+            ],
+            hooks: [
+                video_session: handle,
+                p_allocator: structs,
+            ],
         },
         {
-            name: pfn_get_image_sparse_memory_requirements_2,
-            method_name: get_image_sparse_memory_requirements_2,
-            ptr: GetImageSparseMemoryRequirements2,
+            name: pfn_create_video_session_parameters_khr,
+            method_name: create_video_session_parameters_khr,
+            try_method_name: try_create_video_session_parameters_khr,
+            unchecked_method_name: create_video_session_parameters_khr_unchecked,
+            ptr: CreateVideoSessionParametersKHR,
             signature: (
-                p_info: *const ImageSparseMemoryRequirementsInfo2,
-                p_sparse_memory_requirement_count: *mut u32,
-                p_sparse_memory_requirements: *mut SparseImageMemoryRequirements2,
-            ),
+                p_create_info: *const VideoSessionParametersCreateInfoKHR,
+                p_allocator: *const AllocationCallbacks,
+                p_video_session_parameters: *mut VideoSessionParametersKHR,
+            ) -> Result,
             takes_handle: true,
+            provided_by: [
+                [VK_KHR_video_queue] => CreateVideoSessionParametersKHR,
+            ],
+            hooks: [
+                p_create_info: structs,
+                p_allocator: structs,
+                p_video_session_parameters: handles,
+            ],
+            checked: {
+                method_name: create_video_session_parameters_khr_checked,
+                args: (
+                    p_create_info: *const VideoSessionParametersCreateInfoKHR,
+                    p_allocator: Option<&AllocationCallbacks>,
+                ),
+                output: p_video_session_parameters: VideoSessionParametersKHR,
+                result: success,
+            },
         },
         {
-            name: pfn_get_device_buffer_memory_requirements,
-            method_name: get_device_buffer_memory_requirements,
-            ptr: GetDeviceBufferMemoryRequirements,
+            name: pfn_update_video_session_parameters_khr,
+            method_name: update_video_session_parameters_khr,
+            try_method_name: try_update_video_session_parameters_khr,
+            unchecked_method_name: update_video_session_parameters_khr_unchecked,
+            ptr: UpdateVideoSessionParametersKHR,
             signature: (
-                p_info: *const DeviceBufferMemoryRequirements,
-                p_memory_requirements: *mut MemoryRequirements2,
-            ),
+                video_session_parameters: VideoSessionParametersKHR,
+                p_update_info: *const VideoSessionParametersUpdateInfoKHR,
+            ) -> Result,
             takes_handle: true,
+            provided_by: [
+                [VK_KHR_video_queue] => UpdateVideoSessionParametersKHR,
+            ],
+            hooks: [
+                video_session_parameters: handle,
+                p_update_info: structs,
+            ],
         },
         {
-            name: pfn_get_device_image_memory_requirements,
-            method_name: get_device_image_memory_requirements,
-            ptr: GetDeviceImageMemoryRequirements,
+            name: pfn_destroy_video_session_parameters_khr,
+            method_name: destroy_video_session_parameters_khr,
+            try_method_name: try_destroy_video_session_parameters_khr,
+            unchecked_method_name: destroy_video_session_parameters_khr_unchecked,
+            ptr: DestroyVideoSessionParametersKHR,
             signature: (
-                p_info: *const DeviceImageMemoryRequirements,
-                p_memory_requirements: *mut MemoryRequirements2,
+                video_session_parameters: VideoSessionParametersKHR,
+                p_allocator: *const AllocationCallbacks,
             ),
             takes_handle: true,
+            provided_by: [
+                [VK_KHR_video_queue] => DestroyVideoSessionParametersKHR,
+            ],
+            hooks: [
+                video_session_parameters: handle,
+                p_allocator: structs,
+            ],
         },
         {
-            name: pfn_get_device_image_sparse_memory_requirements,
-            method_name: get_device_image_sparse_memory_requirements,
-            ptr: GetDeviceImageSparseMemoryRequirements,
+            name: pfn_get_video_session_memory_requirements_khr,
+            method_name: get_video_session_memory_requirements_khr,
+            try_method_name: try_get_video_session_memory_requirements_khr,
+            unchecked_method_name: get_video_session_memory_requirements_khr_unchecked,
+            ptr: GetVideoSessionMemoryRequirementsKHR,
             signature: (
-                p_info: *const DeviceImageMemoryRequirements,
-                p_sparse_memory_requirement_count: *mut u32,
-                p_sparse_memory_requirements: *mut SparseImageMemoryRequirements2,
-            ),
+                video_session: VideoSessionKHR,
+                p_memory_requirements_count: *mut u32,
+                p_memory_requirements: *mut VideoSessionMemoryRequirementsKHR,
+            ) -> Result,
             takes_handle: true,
+            provided_by: [
+                [VK_KHR_video_queue] => GetVideoSessionMemoryRequirementsKHR,
+            ],
+            hooks: [
+                video_session: handle,
+                p_memory_requirements_count: ptr,
+                p_memory_requirements: structs[*p_memory_requirements_count],
+            ],
+            vec: {
+                method_name: get_video_session_memory_requirements_khr_vec,
+                args: (
+                    video_session: VideoSessionKHR,
+                ),
+                element: VideoSessionMemoryRequirementsKHR,
+                result: success,
+            },
         },
         {
-            name: pfn_create_sampler_ycbcr_conversion,
-            method_name: create_sampler_ycbcr_conversion,
-            ptr: CreateSamplerYcbcrConversion,
+            name: pfn_bind_video_session_memory_khr,
+            method_name: bind_video_session_memory_khr,
+            try_method_name: try_bind_video_session_memory_khr,
+            unchecked_method_name: bind_video_session_memory_khr_unchecked,
+            ptr: BindVideoSessionMemoryKHR,
             signature: (
-                p_create_info: *const SamplerYcbcrConversionCreateInfo,
-                p_allocator: *const AllocationCallbacks,
-                p_ycbcr_conversion: *mut SamplerYcbcrConversion,
+                video_session: VideoSessionKHR,
+                bind_session_memory_info_count: u32,
+                p_bind_session_memory_infos: *const BindVideoSessionMemoryInfoKHR,
             ) -> Result,
             takes_handle: true,
+            provided_by: [
+                [VK_KHR_video_queue] => BindVideoSessionMemoryKHR,
+            ],
+            hooks: [
+                video_session: handle,
+                bind_session_memory_info_count: value,
+                p_bind_session_memory_infos: structs[bind_session_memory_info_count],
+            ],
+        },
+        {
+            name: pfn_cmd_decode_video_khr,
+            method_name: cmd_decode_video_khr,
+            try_method_name: try_cmd_decode_video_khr,
+            unchecked_method_name: cmd_decode_video_khr_unchecked,
+            ptr: CmdDecodeVideoKHR,
+            signature: (
+                command_buffer: CommandBuffer,
+                p_decode_info: *const VideoDecodeInfoKHR,
+            ),
+            takes_handle: false,
+            provided_by: [
+                [VK_KHR_video_decode_queue] => CmdDecodeVideoKHR,
+            ],
+            hooks: [
+                command_buffer: handle,
+                p_decode_info: structs,
+            ],
         },
         {
-            name: pfn_destroy_sampler_ycbcr_conversion,
-            method_name: destroy_sampler_ycbcr_conversion,
-            ptr: DestroySamplerYcbcrConversion,
+            name: pfn_cmd_begin_video_coding_khr,
+            method_name: cmd_begin_video_coding_khr,
+            try_method_name: try_cmd_begin_video_coding_khr,
+            unchecked_method_name: cmd_begin_video_coding_khr_unchecked,
+            ptr: CmdBeginVideoCodingKHR,
             signature: (
-                ycbcr_conversion: SamplerYcbcrConversion,
-                p_allocator: *const AllocationCallbacks,
+                command_buffer: CommandBuffer,
+                p_begin_info: *const VideoBeginCodingInfoKHR,
             ),
-            takes_handle: true,
+            takes_handle: false,
+            provided_by: [
+                [VK_KHR_video_queue] => CmdBeginVideoCodingKHR,
+            ],
+            hooks: [
+                command_buffer: handle,
+                p_begin_info: structs,
+            ],
         },
         {
-            name: pfn_get_device_queue_2,
-            method_name: get_device_queue_2,
-            ptr: GetDeviceQueue2,
+            name: pfn_cmd_control_video_coding_khr,
+            method_name: cmd_control_video_coding_khr,
+            try_method_name: try_cmd_control_video_coding_khr,
+            unchecked_method_name: cmd_control_video_coding_khr_unchecked,
+            ptr: CmdControlVideoCodingKHR,
             signature: (
-                p_queue_info: *const DeviceQueueInfo2,
-                p_queue: *mut Queue,
+                command_buffer: CommandBuffer,
+                p_coding_control_info: *const VideoCodingControlInfoKHR,
             ),
-            takes_handle: true,
+            takes_handle: false,
+            provided_by: [
+                [VK_KHR_video_queue] => CmdControlVideoCodingKHR,
+            ],
+            hooks: [
+                command_buffer: handle,
+                p_coding_control_info: structs,
+            ],
         },
         {
-            name: pfn_create_validation_cache_ext,
-            method_name: create_validation_cache_ext,
-            ptr: CreateValidationCacheEXT,
+            name: pfn_cmd_end_video_coding_khr,
+            method_name: cmd_end_video_coding_khr,
+            try_method_name: try_cmd_end_video_coding_khr,
+            unchecked_method_name: cmd_end_video_coding_khr_unchecked,
+            ptr: CmdEndVideoCodingKHR,
             signature: (
-                p_create_info: *const ValidationCacheCreateInfoEXT,
-                p_allocator: *const AllocationCallbacks,
-                p_validation_cache: *mut ValidationCacheEXT,
-            ) -> Result,
-            takes_handle: true,
+                command_buffer: CommandBuffer,
+                p_end_coding_info: *const VideoEndCodingInfoKHR,
+            ),
+            takes_handle: false,
+            provided_by: [
+                [VK_KHR_video_queue] => CmdEndVideoCodingKHR,
+            ],
+            hooks: [
+                command_buffer: handle,
+                p_end_coding_info: structs,
+            ],
         },
         {
-            name: pfn_destroy_validation_cache_ext,
-            method_name: destroy_validation_cache_ext,
-            ptr: DestroyValidationCacheEXT,
+            name: pfn_cmd_encode_video_khr,
+            method_name: cmd_encode_video_khr,
+            try_method_name: try_cmd_encode_video_khr,
+            unchecked_method_name: cmd_encode_video_khr_unchecked,
+            ptr: CmdEncodeVideoKHR,
             signature: (
-                validation_cache: ValidationCacheEXT,
-                p_allocator: *const AllocationCallbacks,
+                command_buffer: CommandBuffer,
+                p_encode_info: *const VideoEncodeInfoKHR,
             ),
-            takes_handle: true,
+            takes_handle: false,
+            provided_by: [
+                [VK_KHR_video_encode_queue] => CmdEncodeVideoKHR,
+            ],
+            hooks: [
+                command_buffer: handle,
+                p_encode_info: structs,
+            ],
         },
         {
-            name: pfn_get_validation_cache_data_ext,
-            method_name: get_validation_cache_data_ext,
-            ptr: GetValidationCacheDataEXT,
+            name: pfn_create_cu_module_nvx,
+            method_name: create_cu_module_nvx,
+            try_method_name: try_create_cu_module_nvx,
+            unchecked_method_name: create_cu_module_nvx_unchecked,
+            ptr: CreateCuModuleNVX,
             signature: (
-                validation_cache: ValidationCacheEXT,
-                p_data_size: *mut usize,
-                p_data: *mut c_void,
+                p_create_info: *const CuModuleCreateInfoNVX,
+                p_allocator: *const AllocationCallbacks,
+                p_module: *mut CuModuleNVX,
             ) -> Result,
             takes_handle: true,
+            provided_by: [
+                [VK_NVX_binary_import] => CreateCuModuleNVX,
+            ],
+            hooks: [
+                p_create_info: structs,
+                p_allocator: structs,
+                p_module: handles,
+            ],
+            checked: {
+                method_name: create_cu_module_nvx_checked,
+                args: (
+                    p_create_info: *const CuModuleCreateInfoNVX,
+                    p_allocator: Option<&AllocationCallbacks>,
+                ),
+                output: p_module: CuModuleNVX,
+                result: success,
+            },
         },
         {
-            name: pfn_merge_validation_caches_ext,
-            method_name: merge_validation_caches_ext,
-            ptr: MergeValidationCachesEXT,
+            name: pfn_create_cu_function_nvx,
+            method_name: create_cu_function_nvx,
+            try_method_name: try_create_cu_function_nvx,
+            unchecked_method_name: create_cu_function_nvx_unchecked,
+            ptr: CreateCuFunctionNVX,
             signature: (
-                dst_cache: ValidationCacheEXT,
-                src_cache_count: u32,
-                p_src_caches: *const ValidationCacheEXT,
+                p_create_info: *const CuFunctionCreateInfoNVX,
+                p_allocator: *const AllocationCallbacks,
+                p_function: *mut CuFunctionNVX,
             ) -> Result,
             takes_handle: true,
+            provided_by: [
+                [VK_NVX_binary_import] => CreateCuFunctionNVX,
+            ],
+            hooks: [
+                p_create_info: structs,
+                p_allocator: structs,
+                p_function: handles,
+            ],
+            checked: {
+                method_name: create_cu_function_nvx_checked,
+                args: (
+                    p_create_info: *const CuFunctionCreateInfoNVX,
+                    p_allocator: Option<&AllocationCallbacks>,
+                ),
+                output: p_function: CuFunctionNVX,
+                result: success,
+            },
         },
         {
-            name: pfn_get_descriptor_set_layout_support,
-            method_name: get_descriptor_set_layout_support,
-            ptr: GetDescriptorSetLayoutSupport,
+            name: pfn_destroy_cu_module_nvx,
+            method_name: destroy_cu_module_nvx,
+            try_method_name: try_destroy_cu_module_nvx,
+            unchecked_method_name: destroy_cu_module_nvx_unchecked,
+            ptr: DestroyCuModuleNVX,
             signature: (
-                p_create_info: *const DescriptorSetLayoutCreateInfo,
-                p_support: *mut DescriptorSetLayoutSupport,
+                module: CuModuleNVX,
+                p_allocator: *const AllocationCallbacks,
             ),
             takes_handle: true,
+            provided_by: [
+                [VK_NVX_binary_import] => DestroyCuModuleNVX,
+            ],
+            hooks: [
+                module: handle,
+                p_allocator: structs,
+            ],
         },
         {
-            name: pfn_get_swapchain_gralloc_usage_android,
-            method_name: get_swapchain_gralloc_usage_android,
-            ptr: GetSwapchainGrallocUsageANDROID,
+            name: pfn_destroy_cu_function_nvx,
+            method_name: destroy_cu_function_nvx,
+            try_method_name: try_destroy_cu_function_nvx,
+            unchecked_method_name: destroy_cu_function_nvx_unchecked,
+            ptr: DestroyCuFunctionNVX,
             signature: (
-                format: Format,
-                image_usage: ImageUsageFlags,
-                gralloc_usage: *mut c_int,
-            ) -> Result,
+                function: CuFunctionNVX,
+                p_allocator: *const AllocationCallbacks,
+            ),
             takes_handle: true,
+            provided_by: [
+                [VK_NVX_binary_import] => DestroyCuFunctionNVX,
+            ],
+            hooks: [
+                function: handle,
+                p_allocator: structs,
+            ],
         },
         {
-            name: pfn_get_swapchain_gralloc_usage_2_android,
-            method_name: get_swapchain_gralloc_usage_2_android,
-            ptr: GetSwapchainGrallocUsage2ANDROID,
+            name: pfn_cmd_cu_launch_kernel_nvx,
+            method_name: cmd_cu_launch_kernel_nvx,
+            try_method_name: try_cmd_cu_launch_kernel_nvx,
+            unchecked_method_name: cmd_cu_launch_kernel_nvx_unchecked,
+            ptr: CmdCuLaunchKernelNVX,
             signature: (
-                format: Format,
-                image_usage: ImageUsageFlags,
-                swapchain_image_usage: SwapchainImageUsageFlagsANDROID,
-                gralloc_consumer_usage: *mut u64,
-                gralloc_producer_usage: *mut u64,
-            ) -> Result,
-            takes_handle: true,
+                command_buffer: CommandBuffer,
+                p_launch_info: *const CuLaunchInfoNVX,
+            ),
+            takes_handle: false,
+            provided_by: [
+                [VK_NVX_binary_import] => CmdCuLaunchKernelNVX,
+            ],
+            hooks: [
+                command_buffer: handle,
+                p_launch_info: structs,
+            ],
         },
         {
-            name: pfn_acquire_image_android,
-            method_name: acquire_image_android,
-            ptr: AcquireImageANDROID,
+            name: pfn_set_device_memory_priority_ext,
+            method_name: set_device_memory_priority_ext,
+            try_method_name: try_set_device_memory_priority_ext,
+            unchecked_method_name: set_device_memory_priority_ext_unchecked,
+            ptr: SetDeviceMemoryPriorityEXT,
             signature: (
-                image: Image,
-                native_fence_fd: c_int,
-                semaphore: Semaphore,
-                fence: Fence,
-            ) -> Result,
+                memory: DeviceMemory,
+                priority: c_float,
+            ),
             takes_handle: true,
+            provided_by: [
+                [VK_EXT_pageable_device_local_memory] => SetDeviceMemoryPriorityEXT,
+            ],
+            hooks: [
+                memory: handle,
+                priority: value,
+            ],
         },
         {
-            name: pfn_queue_signal_release_image_android,
-            method_name: queue_signal_release_image_android,
-            ptr: QueueSignalReleaseImageANDROID,
+            name: pfn_wait_for_present_khr,
+            method_name: wait_for_present_khr,
+            try_method_name: try_wait_for_present_khr,
+            unchecked_method_name: wait_for_present_khr_unchecked,
+            ptr: WaitForPresentKHR,
             signature: (
-                queue: Queue,
-                wait_semaphore_count: u32,
-                p_wait_semaphores: *const Semaphore,
-                image: Image,
-                p_native_fence_fd: *mut c_int,
+                swapchain: SwapchainKHR,
+                present_id: u64,
+                timeout: u64,
             ) -> Result,
-            takes_handle: false,
+            takes_handle: true,
+            provided_by: [
+                [VK_KHR_present_wait] => WaitForPresentKHR,
+            ],
+            hooks: [
+                swapchain: handle,
+                present_id: value,
+                timeout: value,
+            ],
         },
         {
-            name: pfn_get_shader_info_amd,
-            method_name: get_shader_info_amd,
-            ptr: GetShaderInfoAMD,
+            name: pfn_create_buffer_collection_fuchsia,
+            method_name: create_buffer_collection_fuchsia,
+            try_method_name: try_create_buffer_collection_fuchsia,
+            unchecked_method_name: create_buffer_collection_fuchsia_unchecked,
+            ptr: CreateBufferCollectionFUCHSIA,
             signature: (
-                pipeline: Pipeline,
-                shader_stage: ShaderStageFlagBits,
-                info_type: ShaderInfoTypeAMD,
-                p_info_size: *mut usize,
-                p_info: *mut c_void,
+                p_create_info: *const BufferCollectionCreateInfoFUCHSIA,
+                p_allocator: *const AllocationCallbacks,
+                p_collection: *mut BufferCollectionFUCHSIA,
             ) -> Result,
             takes_handle: true,
+            provided_by: [
+                [VK_FUCHSIA_buffer_collection] => CreateBufferCollectionFUCHSIA,
+            ],
+            hooks: [
+                p_create_info: structs,
+                p_allocator: structs,
+                p_collection: handles,
+            ],
+            checked: {
+                method_name: create_buffer_collection_fuchsia_checked,
+                args: (
+                    p_create_info: *const BufferCollectionCreateInfoFUCHSIA,
+                    p_allocator: Option<&AllocationCallbacks>,
+                ),
+                output: p_collection: BufferCollectionFUCHSIA,
+                result: success,
+            },
         },
         {
-            name: pfn_set_local_dimming_amd,
-            method_name: set_local_dimming_amd,
-            ptr: SetLocalDimmingAMD,
+            name: pfn_set_buffer_collection_buffer_constraints_fuchsia,
+            method_name: set_buffer_collection_buffer_constraints_fuchsia,
+            try_method_name: try_set_buffer_collection_buffer_constraints_fuchsia,
+            unchecked_method_name: set_buffer_collection_buffer_constraints_fuchsia_unchecked,
+            ptr: SetBufferCollectionBufferConstraintsFUCHSIA,
             signature: (
-                swap_chain: SwapchainKHR,
-                local_dimming_enable: Bool32,
-            ),
+                collection: BufferCollectionFUCHSIA,
+                p_buffer_constraints_info: *const BufferConstraintsInfoFUCHSIA,
+            ) -> Result,
             takes_handle: true,
+            provided_by: [
+                [VK_FUCHSIA_buffer_collection] => SetBufferCollectionBufferConstraintsFUCHSIA,
+            ],
+            hooks: [
+                collection: handle,
+                p_buffer_constraints_info: structs,
+            ],
         },
         {
-            name: pfn_get_calibrated_timestamps_ext,
-            method_name: get_calibrated_timestamps_ext,
-            ptr: GetCalibratedTimestampsEXT,
+            name: pfn_set_buffer_collection_image_constraints_fuchsia,
+            method_name: set_buffer_collection_image_constraints_fuchsia,
+            try_method_name: try_set_buffer_collection_image_constraints_fuchsia,
+            unchecked_method_name: set_buffer_collection_image_constraints_fuchsia_unchecked,
+            ptr: SetBufferCollectionImageConstraintsFUCHSIA,
             signature: (
-                timestamp_count: u32,
-                p_timestamp_infos: *const CalibratedTimestampInfoEXT,
-                p_timestamps: *mut u64,
-                p_max_deviation: *mut u64,
+                collection: BufferCollectionFUCHSIA,
+                p_image_constraints_info: *const ImageConstraintsInfoFUCHSIA,
             ) -> Result,
             takes_handle: true,
+            provided_by: [
+                [VK_FUCHSIA_buffer_collection] => SetBufferCollectionImageConstraintsFUCHSIA,
+            ],
+            hooks: [
+                collection: handle,
+                p_image_constraints_info: structs,
+            ],
         },
         {
-            name: pfn_set_debug_utils_object_name_ext,
-            method_name: set_debug_utils_object_name_ext,
-            ptr: SetDebugUtilsObjectNameEXT,
+            name: pfn_destroy_buffer_collection_fuchsia,
+            method_name: destroy_buffer_collection_fuchsia,
+            try_method_name: try_destroy_buffer_collection_fuchsia,
+            unchecked_method_name: destroy_buffer_collection_fuchsia_unchecked,
+            ptr: DestroyBufferCollectionFUCHSIA,
             signature: (
-                p_name_info: *const DebugUtilsObjectNameInfoEXT,
-            ) -> Result,
+                collection: BufferCollectionFUCHSIA,
+                p_allocator: *const AllocationCallbacks,
+            ),
             takes_handle: true,
+            provided_by: [
+                [VK_FUCHSIA_buffer_collection] => DestroyBufferCollectionFUCHSIA,
+            ],
+            hooks: [
+                collection: handle,
+                p_allocator: structs,
+            ],
         },
         {
-            name: pfn_set_debug_utils_object_tag_ext,
-            method_name: set_debug_utils_object_tag_ext,
-            ptr: SetDebugUtilsObjectTagEXT,
+            name: pfn_get_buffer_collection_properties_fuchsia,
+            method_name: get_buffer_collection_properties_fuchsia,
+            try_method_name: try_get_buffer_collection_properties_fuchsia,
+            unchecked_method_name: get_buffer_collection_properties_fuchsia_unchecked,
+            ptr: GetBufferCollectionPropertiesFUCHSIA,
             signature: (
-                p_tag_info: *const DebugUtilsObjectTagInfoEXT,
+                collection: BufferCollectionFUCHSIA,
+                p_properties: *mut BufferCollectionPropertiesFUCHSIA,
             ) -> Result,
             takes_handle: true,
+            provided_by: [
+                [VK_FUCHSIA_buffer_collection] => GetBufferCollectionPropertiesFUCHSIA,
+            ],
+            hooks: [
+                collection: handle,
+                p_properties: structs,
+            ],
+            checked: {
+                method_name: get_buffer_collection_properties_fuchsia_checked,
+                args: (
+                    collection: BufferCollectionFUCHSIA,
+                ),
+                output: p_properties: BufferCollectionPropertiesFUCHSIA,
+                result: success,
+            },
         },
         {
-            name: pfn_queue_begin_debug_utils_label_ext,
-            method_name: queue_begin_debug_utils_label_ext,
-            ptr: QueueBeginDebugUtilsLabelEXT,
+            name: pfn_cmd_begin_rendering,
+            method_name: cmd_begin_rendering,
+            try_method_name: try_cmd_begin_rendering,
+            unchecked_method_name: cmd_begin_rendering_unchecked,
+            ptr: CmdBeginRendering,
+            aliases: [CmdBeginRenderingKHR],
             signature: (
-                queue: Queue,
-                p_label_info: *const DebugUtilsLabelEXT,
+                command_buffer: CommandBuffer,
+                p_rendering_info: *const RenderingInfo,
             ),
             takes_handle: false,
+            provided_by: [
+                [VK_VERSION_1_3] => CmdBeginRendering,
+                [VK_KHR_dynamic_rendering] => CmdBeginRenderingKHR,
+            ],
+            hooks: [
+                command_buffer: handle,
+                p_rendering_info: structs,
+            ],
         },
         {
-            name: pfn_queue_end_debug_utils_label_ext,
-            method_name: queue_end_debug_utils_label_ext,
-            ptr: QueueEndDebugUtilsLabelEXT,
+            name: pfn_cmd_end_rendering,
+            method_name: cmd_end_rendering,
+            try_method_name: try_cmd_end_rendering,
+            unchecked_method_name: cmd_end_rendering_unchecked,
+            ptr: CmdEndRendering,
+            aliases: [CmdEndRenderingKHR],
             signature: (
-                queue: Queue,
+                command_buffer: CommandBuffer,
             ),
             takes_handle: false,
+            provided_by: [
+                [VK_VERSION_1_3] => CmdEndRendering,
+                [VK_KHR_dynamic_rendering] => CmdEndRenderingKHR,
+            ],
+            hooks: [
+                command_buffer: handle,
+            ],
         },
         {
-            name: pfn_queue_insert_debug_utils_label_ext,
-            method_name: queue_insert_debug_utils_label_ext,
-            ptr: QueueInsertDebugUtilsLabelEXT,
+            name: pfn_get_descriptor_set_layout_host_mapping_info_valve,
+            method_name: get_descriptor_set_layout_host_mapping_info_valve,
+            try_method_name: try_get_descriptor_set_layout_host_mapping_info_valve,
+            unchecked_method_name: get_descriptor_set_layout_host_mapping_info_valve_unchecked,
+            ptr: GetDescriptorSetLayoutHostMappingInfoVALVE,
             signature: (
-                queue: Queue,
-                p_label_info: *const DebugUtilsLabelEXT,
+                p_binding_reference: *const DescriptorSetBindingReferenceVALVE,
+                p_host_mapping: *mut DescriptorSetLayoutHostMappingInfoVALVE,
             ),
-            takes_handle: false,
+            takes_handle: true,
+            provided_by: [
+                [VK_VALVE_descriptor_set_host_mapping] => GetDescriptorSetLayoutHostMappingInfoVALVE,
+            ],
+            hooks: [
+                p_binding_reference: structs,
+                p_host_mapping: structs,
+            ],
+            checked: {
+                method_name: get_descriptor_set_layout_host_mapping_info_valve_checked,
+                args: (
+                    p_binding_reference: *const DescriptorSetBindingReferenceVALVE,
+                ),
+                output: p_host_mapping: DescriptorSetLayoutHostMappingInfoVALVE,
+                result: void,
+            },
         },
         {
-            name: pfn_cmd_begin_debug_utils_label_ext,
-            method_name: cmd_begin_debug_utils_label_ext,
-            ptr: CmdBeginDebugUtilsLabelEXT,
+            name: pfn_get_descriptor_set_host_mapping_valve,
+            method_name: get_descriptor_set_host_mapping_valve,
+            try_method_name: try_get_descriptor_set_host_mapping_valve,
+            unchecked_method_name: get_descriptor_set_host_mapping_valve_unchecked,
+            ptr: GetDescriptorSetHostMappingVALVE,
             signature: (
-                command_buffer: CommandBuffer,
-                p_label_info: *const DebugUtilsLabelEXT,
+                descriptor_set: DescriptorSet,
+                pp_data: *mut *mut c_void,
             ),
-            takes_handle: false,
+            takes_handle: true,
+            provided_by: [
+                [VK_VALVE_descriptor_set_host_mapping] => GetDescriptorSetHostMappingVALVE,
+            ],
+            hooks: [
+                descriptor_set: handle,
+                pp_data: ptr,
+            ],
+        },
+        {
+            name: pfn_create_micromap_ext,
+            method_name: create_micromap_ext,
+            try_method_name: try_create_micromap_ext,
+            unchecked_method_name: create_micromap_ext_unchecked,
+            ptr: CreateMicromapEXT,
+            signature: (
+                p_create_info: *const MicromapCreateInfoEXT,
+                p_allocator: *const AllocationCallbacks,
+                p_micromap: *mut MicromapEXT,
+            ) -> Result,
+            takes_handle: true,
+            provided_by: [
+                [VK_EXT_opacity_micromap] => CreateMicromapEXT,
+            ],
+            hooks: [
+                p_create_info: structs,
+                p_allocator: structs,
+                p_micromap: handles,
+            ],
+            checked: {
+                method_name: create_micromap_ext_checked,
+                args: (
+                    p_create_info: *const MicromapCreateInfoEXT,
+                    p_allocator: Option<&AllocationCallbacks>,
+                ),
+                output: p_micromap: MicromapEXT,
+                result: success,
+            },
         },
         {
-            name: pfn_cmd_end_debug_utils_label_ext,
-            method_name: cmd_end_debug_utils_label_ext,
-            ptr: CmdEndDebugUtilsLabelEXT,
+            name: pfn_cmd_build_micromaps_ext,
+            method_name: cmd_build_micromaps_ext,
+            try_method_name: try_cmd_build_micromaps_ext,
+            unchecked_method_name: cmd_build_micromaps_ext_unchecked,
+            ptr: CmdBuildMicromapsEXT,
             signature: (
                 command_buffer: CommandBuffer,
+                info_count: u32,
+                p_infos: *const MicromapBuildInfoEXT,
             ),
             takes_handle: false,
+            provided_by: [
+                [VK_EXT_opacity_micromap] => CmdBuildMicromapsEXT,
+            ],
+            hooks: [
+                command_buffer: handle,
+                info_count: value,
+                p_infos: structs[info_count],
+            ],
         },
         {
-            name: pfn_cmd_insert_debug_utils_label_ext,
-            method_name: cmd_insert_debug_utils_label_ext,
-            ptr: CmdInsertDebugUtilsLabelEXT,
+            name: pfn_build_micromaps_ext,
+            method_name: build_micromaps_ext,
+            try_method_name: try_build_micromaps_ext,
+            unchecked_method_name: build_micromaps_ext_unchecked,
+            ptr: BuildMicromapsEXT,
             signature: (
-                command_buffer: CommandBuffer,
-                p_label_info: *const DebugUtilsLabelEXT,
-            ),
-            takes_handle: false,
+                deferred_operation: DeferredOperationKHR,
+                info_count: u32,
+                p_infos: *const MicromapBuildInfoEXT,
+            ) -> Result,
+            takes_handle: true,
+            provided_by: [
+                [VK_EXT_opacity_micromap] => BuildMicromapsEXT,
+            ],
+            hooks: [
+                deferred_operation: handle,
+                info_count: value,
+                p_infos: structs[info_count],
+            ],
         },
         {
-            name: pfn_get_memory_host_pointer_properties_ext,
-            method_name: get_memory_host_pointer_properties_ext,
-            ptr: GetMemoryHostPointerPropertiesEXT,
+            name: pfn_destroy_micromap_ext,
+            method_name: destroy_micromap_ext,
+            try_method_name: try_destroy_micromap_ext,
+            unchecked_method_name: destroy_micromap_ext_unchecked,
+            ptr: DestroyMicromapEXT,
             signature: (
-                handle_type: ExternalMemoryHandleTypeFlagBits,
-                p_host_pointer: *const c_void,
-                p_memory_host_pointer_properties: *mut MemoryHostPointerPropertiesEXT,
-            ) -> Result,
+                micromap: MicromapEXT,
+                p_allocator: *const AllocationCallbacks,
+            ),
             takes_handle: true,
+            provided_by: [
+                [VK_EXT_opacity_micromap] => DestroyMicromapEXT,
+            ],
+            hooks: [
+                micromap: handle,
+                p_allocator: structs,
+            ],
         },
         {
-            name: pfn_cmd_write_buffer_marker_amd,
-            method_name: cmd_write_buffer_marker_amd,
-            ptr: CmdWriteBufferMarkerAMD,
+            name: pfn_cmd_copy_micromap_ext,
+            method_name: cmd_copy_micromap_ext,
+            try_method_name: try_cmd_copy_micromap_ext,
+            unchecked_method_name: cmd_copy_micromap_ext_unchecked,
+            ptr: CmdCopyMicromapEXT,
             signature: (
                 command_buffer: CommandBuffer,
-                pipeline_stage: PipelineStageFlagBits,
-                dst_buffer: Buffer,
-                dst_offset: DeviceSize,
-                marker: u32,
+                p_info: *const CopyMicromapInfoEXT,
             ),
             takes_handle: false,
+            provided_by: [
+                [VK_EXT_opacity_micromap] => CmdCopyMicromapEXT,
+            ],
+            hooks: [
+                command_buffer: handle,
+                p_info: structs,
+            ],
         },
         {
-            name: pfn_create_render_pass_2,
-            method_name: create_render_pass_2,
-            ptr: CreateRenderPass2,
+            name: pfn_copy_micromap_ext,
+            method_name: copy_micromap_ext,
+            try_method_name: try_copy_micromap_ext,
+            unchecked_method_name: copy_micromap_ext_unchecked,
+            ptr: CopyMicromapEXT,
             signature: (
-                p_create_info: *const RenderPassCreateInfo2,
-                p_allocator: *const AllocationCallbacks,
-                p_render_pass: *mut RenderPass,
+                deferred_operation: DeferredOperationKHR,
+                p_info: *const CopyMicromapInfoEXT,
             ) -> Result,
             takes_handle: true,
+            provided_by: [
+                [VK_EXT_opacity_micromap] => CopyMicromapEXT,
+            ],
+            hooks: [
+                deferred_operation: handle,
+                p_info: structs,
+            ],
         },
         {
-            name: pfn_cmd_begin_render_pass_2,
-            method_name: cmd_begin_render_pass_2,
-            ptr: CmdBeginRenderPass2,
+            name: pfn_cmd_copy_micromap_to_memory_ext,
+            method_name: cmd_copy_micromap_to_memory_ext,
+            try_method_name: try_cmd_copy_micromap_to_memory_ext,
+            unchecked_method_name: cmd_copy_micromap_to_memory_ext_unchecked,
+            ptr: CmdCopyMicromapToMemoryEXT,
             signature: (
                 command_buffer: CommandBuffer,
-                p_render_pass_begin: *const RenderPassBeginInfo,
-                p_subpass_begin_info: *const SubpassBeginInfo,
+                p_info: *const CopyMicromapToMemoryInfoEXT,
             ),
             takes_handle: false,
+            provided_by: [
+                [VK_EXT_opacity_micromap] => CmdCopyMicromapToMemoryEXT,
+            ],
+            hooks: [
+                command_buffer: handle,
+                p_info: structs,
+            ],
         },
         {
-            name: pfn_cmd_next_subpass_2,
-            method_name: cmd_next_subpass_2,
-            ptr: CmdNextSubpass2,
+            name: pfn_copy_micromap_to_memory_ext,
+            method_name: copy_micromap_to_memory_ext,
+            try_method_name: try_copy_micromap_to_memory_ext,
+            unchecked_method_name: copy_micromap_to_memory_ext_unchecked,
+            ptr: CopyMicromapToMemoryEXT,
             signature: (
-                command_buffer: CommandBuffer,
-                p_subpass_begin_info: *const SubpassBeginInfo,
-                p_subpass_end_info: *const SubpassEndInfo,
-            ),
-            takes_handle: false,
+                deferred_operation: DeferredOperationKHR,
+                p_info: *const CopyMicromapToMemoryInfoEXT,
+            ) -> Result,
+            takes_handle: true,
+            provided_by: [
+                [VK_EXT_opacity_micromap] => CopyMicromapToMemoryEXT,
+            ],
+            hooks: [
+                deferred_operation: handle,
+                p_info: structs,
+            ],
         },
         {
-            name: pfn_cmd_end_render_pass_2,
-            method_name: cmd_end_render_pass_2,
-            ptr: CmdEndRenderPass2,
+            name: pfn_cmd_copy_memory_to_micromap_ext,
+            method_name: cmd_copy_memory_to_micromap_ext,
+            try_method_name: try_cmd_copy_memory_to_micromap_ext,
+            unchecked_method_name: cmd_copy_memory_to_micromap_ext_unchecked,
+            ptr: CmdCopyMemoryToMicromapEXT,
             signature: (
                 command_buffer: CommandBuffer,
-                p_subpass_end_info: *const SubpassEndInfo,
+                p_info: *const CopyMemoryToMicromapInfoEXT,
             ),
             takes_handle: false,
+            provided_by: [
+                [VK_EXT_opacity_micromap] => CmdCopyMemoryToMicromapEXT,
+            ],
+            hooks: [
+                command_buffer: handle,
+                p_info: structs,
+            ],
         },
         {
-            name: pfn_get_semaphore_counter_value,
-            method_name: get_semaphore_counter_value,
-            ptr: GetSemaphoreCounterValue,
+            name: pfn_copy_memory_to_micromap_ext,
+            method_name: copy_memory_to_micromap_ext,
+            try_method_name: try_copy_memory_to_micromap_ext,
+            unchecked_method_name: copy_memory_to_micromap_ext_unchecked,
+            ptr: CopyMemoryToMicromapEXT,
             signature: (
-                semaphore: Semaphore,
-                p_value: *mut u64,
+                deferred_operation: DeferredOperationKHR,
+                p_info: *const CopyMemoryToMicromapInfoEXT,
             ) -> Result,
             takes_handle: true,
+            provided_by: [
+                [VK_EXT_opacity_micromap] => CopyMemoryToMicromapEXT,
+            ],
+            hooks: [
+                deferred_operation: handle,
+                p_info: structs,
+            ],
         },
         {
-            name: pfn_wait_semaphores,
-            method_name: wait_semaphores,
-            ptr: WaitSemaphores,
+            name: pfn_cmd_write_micromaps_properties_ext,
+            method_name: cmd_write_micromaps_properties_ext,
+            try_method_name: try_cmd_write_micromaps_properties_ext,
+            unchecked_method_name: cmd_write_micromaps_properties_ext_unchecked,
+            ptr: CmdWriteMicromapsPropertiesEXT,
             signature: (
-                p_wait_info: *const SemaphoreWaitInfo,
-                timeout: u64,
-            ) -> Result,
-            takes_handle: true,
+                command_buffer: CommandBuffer,
+                micromap_count: u32,
+                p_micromaps: *const MicromapEXT,
+                query_type: QueryType,
+                query_pool: QueryPool,
+                first_query: u32,
+            ),
+            takes_handle: false,
+            provided_by: [
+                [VK_EXT_opacity_micromap] => CmdWriteMicromapsPropertiesEXT,
+            ],
+            hooks: [
+                command_buffer: handle,
+                micromap_count: value,
+                p_micromaps: handles[micromap_count],
+                query_type: value,
+                query_pool: handle,
+                first_query: value,
+            ],
         },
         {
-            name: pfn_signal_semaphore,
-            method_name: signal_semaphore,
-            ptr: SignalSemaphore,
+            name: pfn_write_micromaps_properties_ext,
+            method_name: write_micromaps_properties_ext,
+            try_method_name: try_write_micromaps_properties_ext,
+            unchecked_method_name: write_micromaps_properties_ext_unchecked,
+            ptr: WriteMicromapsPropertiesEXT,
             signature: (
-                p_signal_info: *const SemaphoreSignalInfo,
+                micromap_count: u32,
+                p_micromaps: *const MicromapEXT,
+                query_type: QueryType,
+                data_size: usize,
+                p_data: *mut c_void,
+                stride: usize,
             ) -> Result,
             takes_handle: true,
+            provided_by: [
+                [VK_EXT_opacity_micromap] => WriteMicromapsPropertiesEXT,
+            ],
+            hooks: [
+                micromap_count: value,
+                p_micromaps: handles[micromap_count],
+                query_type: value,
+                data_size: value,
+                p_data: bytes[data_size],
+                stride: value,
+            ],
         },
         {
-            name: pfn_get_android_hardware_buffer_properties_android,
-            method_name: get_android_hardware_buffer_properties_android,
-            ptr: GetAndroidHardwareBufferPropertiesANDROID,
+            name: pfn_get_device_micromap_compatibility_ext,
+            method_name: get_device_micromap_compatibility_ext,
+            try_method_name: try_get_device_micromap_compatibility_ext,
+            unchecked_method_name: get_device_micromap_compatibility_ext_unchecked,
+            ptr: GetDeviceMicromapCompatibilityEXT,
             signature: (
-                buffer: *const AHardwareBuffer,
-                p_properties: *mut AndroidHardwareBufferPropertiesANDROID,
-            ) -> Result,
+                p_version_info: *const MicromapVersionInfoEXT,
+                p_compatibility: *mut AccelerationStructureCompatibilityKHR,
+            ),
             takes_handle: true,
+            provided_by: [
+                [VK_EXT_opacity_micromap] => GetDeviceMicromapCompatibilityEXT,
+            ],
+            hooks: [
+                p_version_info: structs,
+                p_compatibility: ptr,
+            ],
+            checked: {
+                method_name: get_device_micromap_compatibility_ext_checked,
+                args: (
+                    p_version_info: *const MicromapVersionInfoEXT,
+                ),
+                output: p_compatibility: AccelerationStructureCompatibilityKHR,
+                result: void,
+            },
         },
         {
-            name: pfn_get_memory_android_hardware_buffer_android,
-            method_name: get_memory_android_hardware_buffer_android,
-            ptr: GetMemoryAndroidHardwareBufferANDROID,
+            name: pfn_get_micromap_build_sizes_ext,
+            method_name: get_micromap_build_sizes_ext,
+            try_method_name: try_get_micromap_build_sizes_ext,
+            unchecked_method_name: get_micromap_build_sizes_ext_unchecked,
+            ptr: GetMicromapBuildSizesEXT,
             signature: (
-                p_info: *const MemoryGetAndroidHardwareBufferInfoANDROID,
-                p_buffer: *mut *mut AHardwareBuffer,
-            ) -> Result,
+                build_type: AccelerationStructureBuildTypeKHR,
+                p_build_info: *const MicromapBuildInfoEXT,
+                p_size_info: *mut MicromapBuildSizesInfoEXT,
+            ),
             takes_handle: true,
+            provided_by: [
+                [VK_EXT_opacity_micromap] => GetMicromapBuildSizesEXT,
+            ],
+            hooks: [
+                build_type: value,
+                p_build_info: structs,
+                p_size_info: structs,
+            ],
+            checked: {
+                method_name: get_micromap_build_sizes_ext_checked,
+                args: (
+                    build_type: AccelerationStructureBuildTypeKHR,
+                    p_build_info: *const MicromapBuildInfoEXT,
+                ),
+                output: p_size_info: MicromapBuildSizesInfoEXT,
+                result: void,
+            },
         },
         {
-            name: pfn_cmd_draw_indirect_count,
-            method_name: cmd_draw_indirect_count,
-            ptr: CmdDrawIndirectCount,
+            name: pfn_get_shader_module_identifier_ext,
+            method_name: get_shader_module_identifier_ext,
+            try_method_name: try_get_shader_module_identifier_ext,
+            unchecked_method_name: get_shader_module_identifier_ext_unchecked,
+            ptr: GetShaderModuleIdentifierEXT,
             signature: (
-                command_buffer: CommandBuffer,
-                buffer: Buffer,
-                offset: DeviceSize,
-                count_buffer: Buffer,
-                count_buffer_offset: DeviceSize,
-                max_draw_count: u32,
-                stride: u32,
+                shader_module: ShaderModule,
+                p_identifier: *mut ShaderModuleIdentifierEXT,
             ),
-            takes_handle: false,
+            takes_handle: true,
+            provided_by: [
+                [VK_EXT_shader_module_identifier] => GetShaderModuleIdentifierEXT,
+            ],
+            hooks: [
+                shader_module: handle,
+                p_identifier: structs,
+            ],
+            checked: {
+                method_name: get_shader_module_identifier_ext_checked,
+                args: (
+                    shader_module: ShaderModule,
+                ),
+                output: p_identifier: ShaderModuleIdentifierEXT,
+                result: void,
+            },
         },
         {
-            name: pfn_cmd_draw_indexed_indirect_count,
-            method_name: cmd_draw_indexed_indirect_count,
-            ptr: CmdDrawIndexedIndirectCount,
+            name: pfn_get_shader_module_create_info_identifier_ext,
+            method_name: get_shader_module_create_info_identifier_ext,
+            try_method_name: try_get_shader_module_create_info_identifier_ext,
+            unchecked_method_name: get_shader_module_create_info_identifier_ext_unchecked,
+            ptr: GetShaderModuleCreateInfoIdentifierEXT,
             signature: (
-                command_buffer: CommandBuffer,
-                buffer: Buffer,
-                offset: DeviceSize,
-                count_buffer: Buffer,
-                count_buffer_offset: DeviceSize,
-                max_draw_count: u32,
-                stride: u32,
+                p_create_info: *const ShaderModuleCreateInfo,
+                p_identifier: *mut ShaderModuleIdentifierEXT,
             ),
-            takes_handle: false,
+            takes_handle: true,
+            provided_by: [
+                [VK_EXT_shader_module_identifier] => GetShaderModuleCreateInfoIdentifierEXT,
+            ],
+            hooks: [
+                p_create_info: structs,
+                p_identifier: structs,
+            ],
+            checked: {
+                method_name: get_shader_module_create_info_identifier_ext_checked,
+                args: (
+                    p_create_info: *const ShaderModuleCreateInfo,
+                ),
+                output: p_identifier: ShaderModuleIdentifierEXT,
+                result: void,
+            },
         },
         {
-            name: pfn_cmd_set_checkpoint_nv,
-            method_name: cmd_set_checkpoint_nv,
-            ptr: CmdSetCheckpointNV,
+            name: pfn_get_image_subresource_layout_2_ext,
+            method_name: get_image_subresource_layout_2_ext,
+            try_method_name: try_get_image_subresource_layout_2_ext,
+            unchecked_method_name: get_image_subresource_layout_2_ext_unchecked,
+            ptr: GetImageSubresourceLayout2EXT,
             signature: (
-                command_buffer: CommandBuffer,
-                p_checkpoint_marker: *const c_void,
+                image: Image,
+                p_subresource: *const ImageSubresource2EXT,
+                p_layout: *mut SubresourceLayout2EXT,
             ),
-            takes_handle: false,
+            takes_handle: true,
+            provided_by: [
+                [VK_EXT_image_compression_control] => GetImageSubresourceLayout2EXT,
+            ],
+            hooks: [
+                image: handle,
+                p_subresource: structs,
+                p_layout: structs,
+            ],
+            checked: {
+                method_name: get_image_subresource_layout_2_ext_checked,
+                args: (
+                    image: Image,
+                    p_subresource: *const ImageSubresource2EXT,
+                ),
+                output: p_layout: SubresourceLayout2EXT,
+                result: void,
+            },
         },
         {
-            name: pfn_get_queue_checkpoint_data_nv,
-            method_name: get_queue_checkpoint_data_nv,
-            ptr: GetQueueCheckpointDataNV,
+            name: pfn_get_pipeline_properties_ext,
+            method_name: get_pipeline_properties_ext,
+            try_method_name: try_get_pipeline_properties_ext,
+            unchecked_method_name: get_pipeline_properties_ext_unchecked,
+            ptr: GetPipelinePropertiesEXT,
             signature: (
-                queue: Queue,
-                p_checkpoint_data_count: *mut u32,
-                p_checkpoint_data: *mut CheckpointDataNV,
-            ),
-            takes_handle: false,
+                p_pipeline_info: *const PipelineInfoEXT,
+                p_pipeline_properties: *mut BaseOutStructure,
+            ) -> Result,
+            takes_handle: true,
+            provided_by: [
+                [VK_EXT_pipeline_properties] => GetPipelinePropertiesEXT,
+            ],
+            hooks: [
+                p_pipeline_info: structs,
+                p_pipeline_properties: structs,
+            ],
+            checked: {
+                method_name: get_pipeline_properties_ext_checked,
+                args: (
+                    p_pipeline_info: *const PipelineInfoEXT,
+                ),
+                output: p_pipeline_properties: BaseOutStructure,
+                result: success,
+            },
         },
         {
-            name: pfn_cmd_bind_transform_feedback_buffers_ext,
-            method_name: cmd_bind_transform_feedback_buffers_ext,
-            ptr: CmdBindTransformFeedbackBuffersEXT,
+            name: pfn_export_metal_objects_ext,
+            method_name: export_metal_objects_ext,
+            try_method_name: try_export_metal_objects_ext,
+            unchecked_method_name: export_metal_objects_ext_unchecked,
+            ptr: ExportMetalObjectsEXT,
             signature: (
-                command_buffer: CommandBuffer,
-                first_binding: u32,
-                binding_count: u32,
-                p_buffers: *const Buffer,
-                p_offsets: *const DeviceSize,
-                p_sizes: *const DeviceSize,
+                p_metal_objects_info: *mut ExportMetalObjectsInfoEXT,
             ),
-            takes_handle: false,
+            takes_handle: true,
+            provided_by: [
+                [VK_EXT_metal_objects] => ExportMetalObjectsEXT,
+            ],
+            hooks: [
+                p_metal_objects_info: structs,
+            ],
         },
         {
-            name: pfn_cmd_begin_transform_feedback_ext,
-            method_name: cmd_begin_transform_feedback_ext,
-            ptr: CmdBeginTransformFeedbackEXT,
+            name: pfn_get_framebuffer_tile_properties_qcom,
+            method_name: get_framebuffer_tile_properties_qcom,
+            try_method_name: try_get_framebuffer_tile_properties_qcom,
+            unchecked_method_name: get_framebuffer_tile_properties_qcom_unchecked,
+            ptr: GetFramebufferTilePropertiesQCOM,
             signature: (
-                command_buffer: CommandBuffer,
-                first_counter_buffer: u32,
-                counter_buffer_count: u32,
-                p_counter_buffers: *const Buffer,
-                p_counter_buffer_offsets: *const DeviceSize,
-            ),
-            takes_handle: false,
+                framebuffer: Framebuffer,
+                p_properties_count: *mut u32,
+                p_properties: *mut TilePropertiesQCOM,
+            ) -> Result,
+            takes_handle: true,
+            provided_by: [
+                [VK_QCOM_tile_properties] => GetFramebufferTilePropertiesQCOM,
+            ],
+            hooks: [
+                framebuffer: handle,
+                p_properties_count: ptr,
+                p_properties: structs[*p_properties_count],
+            ],
+            vec: {
+                method_name: get_framebuffer_tile_properties_qcom_vec,
+                args: (
+                    framebuffer: Framebuffer,
+                ),
+                element: TilePropertiesQCOM,
+                result: success,
+            },
+        },
+        {
+            name: pfn_get_dynamic_rendering_tile_properties_qcom,
+            method_name: get_dynamic_rendering_tile_properties_qcom,
+            try_method_name: try_get_dynamic_rendering_tile_properties_qcom,
+            unchecked_method_name: get_dynamic_rendering_tile_properties_qcom_unchecked,
+            ptr: GetDynamicRenderingTilePropertiesQCOM,
+            signature: (
+                p_rendering_info: *const RenderingInfo,
+                p_properties: *mut TilePropertiesQCOM,
+            ) -> Result,
+            takes_handle: true,
+            provided_by: [
+                [VK_QCOM_tile_properties] => GetDynamicRenderingTilePropertiesQCOM,
+            ],
+            hooks: [
+                p_rendering_info: structs,
+                p_properties: structs,
+            ],
+            checked: {
+                method_name: get_dynamic_rendering_tile_properties_qcom_checked,
+                args: (
+                    p_rendering_info: *const RenderingInfo,
+                ),
+                output: p_properties: TilePropertiesQCOM,
+                result: success,
+            },
         },
         {
-            name: pfn_cmd_end_transform_feedback_ext,
-            method_name: cmd_end_transform_feedback_ext,
-            ptr: CmdEndTransformFeedbackEXT,
+            name: pfn_create_optical_flow_session_nv,
+            method_name: create_optical_flow_session_nv,
+            try_method_name: try_create_optical_flow_session_nv,
+            unchecked_method_name: create_optical_flow_session_nv_unchecked,
+            ptr: CreateOpticalFlowSessionNV,
             signature: (
-                command_buffer: CommandBuffer,
-                first_counter_buffer: u32,
-                counter_buffer_count: u32,
-                p_counter_buffers: *const Buffer,
-                p_counter_buffer_offsets: *const DeviceSize,
-            ),
-            takes_handle: false,
+                p_create_info: *const OpticalFlowSessionCreateInfoNV,
+                p_allocator: *const AllocationCallbacks,
+                p_session: *mut OpticalFlowSessionNV,
+            ) -> Result,
+            takes_handle: true,
+            provided_by: [
+                [VK_NV_optical_flow] => CreateOpticalFlowSessionNV,
+            ],
+            hooks: [
+                p_create_info: structs,
+                p_allocator: structs,
+                p_session: handles,
+            ],
+            checked: {
+                method_name: create_optical_flow_session_nv_checked,
+                args: (
+                    p_create_info: *const OpticalFlowSessionCreateInfoNV,
+                    p_allocator: Option<&AllocationCallbacks>,
+                ),
+                output: p_session: OpticalFlowSessionNV,
+                result: success,
+            },
         },
         {
-            name: pfn_cmd_begin_query_indexed_ext,
-            method_name: cmd_begin_query_indexed_ext,
-            ptr: CmdBeginQueryIndexedEXT,
+            name: pfn_destroy_optical_flow_session_nv,
+            method_name: destroy_optical_flow_session_nv,
+            try_method_name: try_destroy_optical_flow_session_nv,
+            unchecked_method_name: destroy_optical_flow_session_nv_unchecked,
+            ptr: DestroyOpticalFlowSessionNV,
             signature: (
-                command_buffer: CommandBuffer,
-                query_pool: QueryPool,
-                query: u32,
-                flags: QueryControlFlags,
-                index: u32,
+                session: OpticalFlowSessionNV,
+                p_allocator: *const AllocationCallbacks,
             ),
-            takes_handle: false,
+            takes_handle: true,
+            provided_by: [
+                [VK_NV_optical_flow] => DestroyOpticalFlowSessionNV,
+            ],
+            hooks: [
+                session: handle,
+                p_allocator: structs,
+            ],
         },
         {
-            name: pfn_cmd_end_query_indexed_ext,
-            method_name: cmd_end_query_indexed_ext,
-            ptr: CmdEndQueryIndexedEXT,
+            name: pfn_bind_optical_flow_session_image_nv,
+            method_name: bind_optical_flow_session_image_nv,
+            try_method_name: try_bind_optical_flow_session_image_nv,
+            unchecked_method_name: bind_optical_flow_session_image_nv_unchecked,
+            ptr: BindOpticalFlowSessionImageNV,
             signature: (
-                command_buffer: CommandBuffer,
-                query_pool: QueryPool,
-                query: u32,
-                index: u32,
-            ),
-            takes_handle: false,
+                session: OpticalFlowSessionNV,
+                binding_point: OpticalFlowSessionBindingPointNV,
+                view: ImageView,
+                layout: ImageLayout,
+            ) -> Result,
+            takes_handle: true,
+            provided_by: [
+                [VK_NV_optical_flow] => BindOpticalFlowSessionImageNV,
+            ],
+            hooks: [
+                session: handle,
+                binding_point: value,
+                view: handle,
+                layout: value,
+            ],
         },
         {
-            name: pfn_cmd_draw_indirect_byte_count_ext,
-            method_name: cmd_draw_indirect_byte_count_ext,
-            ptr: CmdDrawIndirectByteCountEXT,
+            name: pfn_cmd_optical_flow_execute_nv,
+            method_name: cmd_optical_flow_execute_nv,
+            try_method_name: try_cmd_optical_flow_execute_nv,
+            unchecked_method_name: cmd_optical_flow_execute_nv_unchecked,
+            ptr: CmdOpticalFlowExecuteNV,
             signature: (
                 command_buffer: CommandBuffer,
-                instance_count: u32,
-                first_instance: u32,
-                counter_buffer: Buffer,
-                counter_buffer_offset: DeviceSize,
-                counter_offset: u32,
-                vertex_stride: u32,
+                session: OpticalFlowSessionNV,
+                p_execute_info: *const OpticalFlowExecuteInfoNV,
             ),
             takes_handle: false,
+            provided_by: [
+                [VK_NV_optical_flow] => CmdOpticalFlowExecuteNV,
+            ],
+            hooks: [
+                command_buffer: handle,
+                session: handle,
+                p_execute_info: structs,
+            ],
         },
         {
-            name: pfn_cmd_set_exclusive_scissor_nv,
-            method_name: cmd_set_exclusive_scissor_nv,
-            ptr: CmdSetExclusiveScissorNV,
+            name: pfn_get_device_fault_info_ext,
+            method_name: get_device_fault_info_ext,
+            try_method_name: try_get_device_fault_info_ext,
+            unchecked_method_name: get_device_fault_info_ext_unchecked,
+            ptr: GetDeviceFaultInfoEXT,
             signature: (
-                command_buffer: CommandBuffer,
-                first_exclusive_scissor: u32,
-                exclusive_scissor_count: u32,
-                p_exclusive_scissors: *const Rect2D,
-            ),
-            takes_handle: false,
+                p_fault_counts: *mut DeviceFaultCountsEXT,
+                p_fault_info: *mut DeviceFaultInfoEXT,
+            ) -> Result,
+            takes_handle: true,
+            provided_by: [
+                [VK_EXT_device_fault] => GetDeviceFaultInfoEXT,
+            ],
+            hooks: [
+                p_fault_counts: structs,
+                p_fault_info: structs,
+            ],
         },
+    ],
+}
+
+impl_view! {
+    name: QueueView,
+    table: DeviceTable,
+    handle: {
+        name: queue,
+        type: Queue,
+    },
+    members: [
         {
-            name: pfn_cmd_bind_shading_rate_image_nv,
-            method_name: cmd_bind_shading_rate_image_nv,
-            ptr: CmdBindShadingRateImageNV,
+            method_name: queue_submit,
+            try_method_name: try_queue_submit,
+            unchecked_method_name: queue_submit_unchecked,
             signature: (
-                command_buffer: CommandBuffer,
-                image_view: ImageView,
-                image_layout: ImageLayout,
-            ),
-            takes_handle: false,
+                submit_count: u32,
+                p_submits: *const SubmitInfo,
+                fence: Fence,
+            ) -> Result,
         },
         {
-            name: pfn_cmd_set_viewport_shading_rate_palette_nv,
-            method_name: cmd_set_viewport_shading_rate_palette_nv,
-            ptr: CmdSetViewportShadingRatePaletteNV,
+            method_name: queue_wait_idle,
+            try_method_name: try_queue_wait_idle,
+            unchecked_method_name: queue_wait_idle_unchecked,
             signature: (
-                command_buffer: CommandBuffer,
-                first_viewport: u32,
-                viewport_count: u32,
-                p_shading_rate_palettes: *const ShadingRatePaletteNV,
-            ),
-            takes_handle: false,
+            ) -> Result,
         },
         {
-            name: pfn_cmd_set_coarse_sample_order_nv,
-            method_name: cmd_set_coarse_sample_order_nv,
-            ptr: CmdSetCoarseSampleOrderNV,
+            method_name: queue_bind_sparse,
+            try_method_name: try_queue_bind_sparse,
+            unchecked_method_name: queue_bind_sparse_unchecked,
             signature: (
-                command_buffer: CommandBuffer,
-                sample_order_type: CoarseSampleOrderTypeNV,
-                custom_sample_order_count: u32,
-                p_custom_sample_orders: *const CoarseSampleOrderCustomNV,
-            ),
-            takes_handle: false,
+                bind_info_count: u32,
+                p_bind_info: *const BindSparseInfo,
+                fence: Fence,
+            ) -> Result,
         },
         {
-            name: pfn_cmd_draw_mesh_tasks_nv,
-            method_name: cmd_draw_mesh_tasks_nv,
-            ptr: CmdDrawMeshTasksNV,
+            method_name: queue_present_khr,
+            try_method_name: try_queue_present_khr,
+            unchecked_method_name: queue_present_khr_unchecked,
             signature: (
-                command_buffer: CommandBuffer,
-                task_count: u32,
-                first_task: u32,
-            ),
-            takes_handle: false,
+                p_present_info: *const PresentInfoKHR,
+            ) -> Result,
         },
         {
-            name: pfn_cmd_draw_mesh_tasks_indirect_nv,
-            method_name: cmd_draw_mesh_tasks_indirect_nv,
-            ptr: CmdDrawMeshTasksIndirectNV,
+            method_name: queue_signal_release_image_android,
+            try_method_name: try_queue_signal_release_image_android,
+            unchecked_method_name: queue_signal_release_image_android_unchecked,
             signature: (
-                command_buffer: CommandBuffer,
-                buffer: Buffer,
-                offset: DeviceSize,
-                draw_count: u32,
-                stride: u32,
-            ),
-            takes_handle: false,
+                wait_semaphore_count: u32,
+                p_wait_semaphores: *const Semaphore,
+                image: Image,
+                p_native_fence_fd: *mut c_int,
+            ) -> Result,
         },
         {
-            name: pfn_cmd_draw_mesh_tasks_indirect_count_nv,
-            method_name: cmd_draw_mesh_tasks_indirect_count_nv,
-            ptr: CmdDrawMeshTasksIndirectCountNV,
+            method_name: queue_begin_debug_utils_label_ext,
+            try_method_name: try_queue_begin_debug_utils_label_ext,
+            unchecked_method_name: queue_begin_debug_utils_label_ext_unchecked,
             signature: (
-                command_buffer: CommandBuffer,
-                buffer: Buffer,
-                offset: DeviceSize,
-                count_buffer: Buffer,
-                count_buffer_offset: DeviceSize,
-                max_draw_count: u32,
-                stride: u32,
+                p_label_info: *const DebugUtilsLabelEXT,
             ),
-            takes_handle: false,
         },
         {
-            name: pfn_cmd_draw_mesh_tasks_ext,
-            method_name: cmd_draw_mesh_tasks_ext,
-            ptr: CmdDrawMeshTasksEXT,
+            method_name: queue_end_debug_utils_label_ext,
+            try_method_name: try_queue_end_debug_utils_label_ext,
+            unchecked_method_name: queue_end_debug_utils_label_ext_unchecked,
             signature: (
-                command_buffer: CommandBuffer,
-                group_count_x: u32,
-                group_count_y: u32,
-                group_count_z: u32,
             ),
-            takes_handle: false,
         },
         {
-            name: pfn_cmd_draw_mesh_tasks_indirect_ext,
-            method_name: cmd_draw_mesh_tasks_indirect_ext,
-            ptr: CmdDrawMeshTasksIndirectEXT,
+            method_name: queue_insert_debug_utils_label_ext,
+            try_method_name: try_queue_insert_debug_utils_label_ext,
+            unchecked_method_name: queue_insert_debug_utils_label_ext_unchecked,
             signature: (
-                command_buffer: CommandBuffer,
-                buffer: Buffer,
-                offset: DeviceSize,
-                draw_count: u32,
-                stride: u32,
+                p_label_info: *const DebugUtilsLabelEXT,
             ),
-            takes_handle: false,
         },
         {
-            name: pfn_cmd_draw_mesh_tasks_indirect_count_ext,
-            method_name: cmd_draw_mesh_tasks_indirect_count_ext,
-            ptr: CmdDrawMeshTasksIndirectCountEXT,
+            method_name: get_queue_checkpoint_data_nv,
+            try_method_name: try_get_queue_checkpoint_data_nv,
+            unchecked_method_name: get_queue_checkpoint_data_nv_unchecked,
             signature: (
-                command_buffer: CommandBuffer,
-                buffer: Buffer,
-                offset: DeviceSize,
-                count_buffer: Buffer,
-                count_buffer_offset: DeviceSize,
-                max_draw_count: u32,
-                stride: u32,
+                p_checkpoint_data_count: *mut u32,
+                p_checkpoint_data: *mut CheckpointDataNV,
             ),
-            takes_handle: false,
+            vec: {
+                method_name: get_queue_checkpoint_data_nv_vec,
+                args: (
+                ),
+                element: CheckpointDataNV,
+                result: void,
+            },
         },
         {
-            name: pfn_compile_deferred_nv,
-            method_name: compile_deferred_nv,
-            ptr: CompileDeferredNV,
+            method_name: queue_set_performance_configuration_intel,
+            try_method_name: try_queue_set_performance_configuration_intel,
+            unchecked_method_name: queue_set_performance_configuration_intel_unchecked,
             signature: (
-                pipeline: Pipeline,
-                shader: u32,
+                configuration: PerformanceConfigurationINTEL,
             ) -> Result,
-            takes_handle: true,
         },
         {
-            name: pfn_create_acceleration_structure_nv,
-            method_name: create_acceleration_structure_nv,
-            ptr: CreateAccelerationStructureNV,
+            method_name: queue_submit_2,
+            try_method_name: try_queue_submit_2,
+            unchecked_method_name: queue_submit_2_unchecked,
             signature: (
-                p_create_info: *const AccelerationStructureCreateInfoNV,
-                p_allocator: *const AllocationCallbacks,
-                p_acceleration_structure: *mut AccelerationStructureNV,
+                submit_count: u32,
+                p_submits: *const SubmitInfo2,
+                fence: Fence,
             ) -> Result,
-            takes_handle: true,
         },
         {
-            name: pfn_cmd_bind_invocation_mask_huawei,
-            method_name: cmd_bind_invocation_mask_huawei,
-            ptr: CmdBindInvocationMaskHUAWEI,
+            method_name: get_queue_checkpoint_data_2_nv,
+            try_method_name: try_get_queue_checkpoint_data_2_nv,
+            unchecked_method_name: get_queue_checkpoint_data_2_nv_unchecked,
             signature: (
-                command_buffer: CommandBuffer,
-                image_view: ImageView,
-                image_layout: ImageLayout,
+                p_checkpoint_data_count: *mut u32,
+                p_checkpoint_data: *mut CheckpointData2NV,
             ),
-            takes_handle: false,
+            vec: {
+                method_name: get_queue_checkpoint_data_2_nv_vec,
+                args: (
+                ),
+                element: CheckpointData2NV,
+                result: void,
+            },
         },
+    ],
+}
+
+impl_view! {
+    name: CommandBufferView,
+    table: DeviceTable,
+    handle: {
+        name: command_buffer,
+        type: CommandBuffer,
+    },
+    members: [
         {
-            name: pfn_destroy_acceleration_structure_khr,
-            method_name: destroy_acceleration_structure_khr,
-            ptr: DestroyAccelerationStructureKHR,
+            method_name: begin_command_buffer,
+            try_method_name: try_begin_command_buffer,
+            unchecked_method_name: begin_command_buffer_unchecked,
             signature: (
-                acceleration_structure: AccelerationStructureKHR,
-                p_allocator: *const AllocationCallbacks,
-            ),
-            takes_handle: true,
+                p_begin_info: *const CommandBufferBeginInfo,
+            ) -> Result,
         },
         {
-            name: pfn_destroy_acceleration_structure_nv,
-            method_name: destroy_acceleration_structure_nv,
-            ptr: DestroyAccelerationStructureNV,
+            method_name: end_command_buffer,
+            try_method_name: try_end_command_buffer,
+            unchecked_method_name: end_command_buffer_unchecked,
+            signature: (
+            ) -> Result,
+        },
+        {
+            method_name: reset_command_buffer,
+            try_method_name: try_reset_command_buffer,
+            unchecked_method_name: reset_command_buffer_unchecked,
             signature: (
-                acceleration_structure: AccelerationStructureNV,
-                p_allocator: *const AllocationCallbacks,
-            ),
-            takes_handle: true,
+                flags: CommandBufferResetFlags,
+            ) -> Result,
         },
         {
-            name: pfn_get_acceleration_structure_memory_requirements_nv,
-            method_name: get_acceleration_structure_memory_requirements_nv,
-            ptr: GetAccelerationStructureMemoryRequirementsNV,
+            method_name: cmd_bind_pipeline,
+            try_method_name: try_cmd_bind_pipeline,
+            unchecked_method_name: cmd_bind_pipeline_unchecked,
             signature: (
-                p_info: *const AccelerationStructureMemoryRequirementsInfoNV,
-                p_memory_requirements: *mut MemoryRequirements2KHR,
+                pipeline_bind_point: PipelineBindPoint,
+                pipeline: Pipeline,
             ),
-            takes_handle: true,
         },
         {
-            name: pfn_bind_acceleration_structure_memory_nv,
-            method_name: bind_acceleration_structure_memory_nv,
-            ptr: BindAccelerationStructureMemoryNV,
+            method_name: cmd_set_viewport,
+            try_method_name: try_cmd_set_viewport,
+            unchecked_method_name: cmd_set_viewport_unchecked,
             signature: (
-                bind_info_count: u32,
-                p_bind_infos: *const BindAccelerationStructureMemoryInfoNV,
-            ) -> Result,
-            takes_handle: true,
+                first_viewport: u32,
+                viewport_count: u32,
+                p_viewports: *const Viewport,
+            ),
         },
         {
-            name: pfn_cmd_copy_acceleration_structure_nv,
-            method_name: cmd_copy_acceleration_structure_nv,
-            ptr: CmdCopyAccelerationStructureNV,
+            method_name: cmd_set_scissor,
+            try_method_name: try_cmd_set_scissor,
+            unchecked_method_name: cmd_set_scissor_unchecked,
             signature: (
-                command_buffer: CommandBuffer,
-                dst: AccelerationStructureNV,
-                src: AccelerationStructureNV,
-                mode: CopyAccelerationStructureModeKHR,
+                first_scissor: u32,
+                scissor_count: u32,
+                p_scissors: *const Rect2D,
             ),
-            takes_handle: false,
         },
         {
-            name: pfn_cmd_copy_acceleration_structure_khr,
-            method_name: cmd_copy_acceleration_structure_khr,
-            ptr: CmdCopyAccelerationStructureKHR,
+            method_name: cmd_set_line_width,
+            try_method_name: try_cmd_set_line_width,
+            unchecked_method_name: cmd_set_line_width_unchecked,
             signature: (
-                command_buffer: CommandBuffer,
-                p_info: *const CopyAccelerationStructureInfoKHR,
+                line_width: c_float,
             ),
-            takes_handle: false,
         },
         {
-            name: pfn_copy_acceleration_structure_khr,
-            method_name: copy_acceleration_structure_khr,
-            ptr: CopyAccelerationStructureKHR,
+            method_name: cmd_set_depth_bias,
+            try_method_name: try_cmd_set_depth_bias,
+            unchecked_method_name: cmd_set_depth_bias_unchecked,
             signature: (
-                deferred_operation: DeferredOperationKHR,
-                p_info: *const CopyAccelerationStructureInfoKHR,
-            ) -> Result,
-            takes_handle: true,
+                depth_bias_constant_factor: c_float,
+                depth_bias_clamp: c_float,
+                depth_bias_slope_factor: c_float,
+            ),
         },
         {
-            name: pfn_cmd_copy_acceleration_structure_to_memory_khr,
-            method_name: cmd_copy_acceleration_structure_to_memory_khr,
-            ptr: CmdCopyAccelerationStructureToMemoryKHR,
+            method_name: cmd_set_blend_constants,
+            try_method_name: try_cmd_set_blend_constants,
+            unchecked_method_name: cmd_set_blend_constants_unchecked,
             signature: (
-                command_buffer: CommandBuffer,
-                p_info: *const CopyAccelerationStructureToMemoryInfoKHR,
+                blend_constants: [c_float; 4],
             ),
-            takes_handle: false,
         },
         {
-            name: pfn_copy_acceleration_structure_to_memory_khr,
-            method_name: copy_acceleration_structure_to_memory_khr,
-            ptr: CopyAccelerationStructureToMemoryKHR,
+            method_name: cmd_set_depth_bounds,
+            try_method_name: try_cmd_set_depth_bounds,
+            unchecked_method_name: cmd_set_depth_bounds_unchecked,
             signature: (
-                deferred_operation: DeferredOperationKHR,
-                p_info: *const CopyAccelerationStructureToMemoryInfoKHR,
-            ) -> Result,
-            takes_handle: true,
+                min_depth_bounds: c_float,
+                max_depth_bounds: c_float,
+            ),
         },
         {
-            name: pfn_cmd_copy_memory_to_acceleration_structure_khr,
-            method_name: cmd_copy_memory_to_acceleration_structure_khr,
-            ptr: CmdCopyMemoryToAccelerationStructureKHR,
+            method_name: cmd_set_stencil_compare_mask,
+            try_method_name: try_cmd_set_stencil_compare_mask,
+            unchecked_method_name: cmd_set_stencil_compare_mask_unchecked,
             signature: (
-                command_buffer: CommandBuffer,
-                p_info: *const CopyMemoryToAccelerationStructureInfoKHR,
+                face_mask: StencilFaceFlags,
+                compare_mask: u32,
             ),
-            takes_handle: false,
         },
         {
-            name: pfn_copy_memory_to_acceleration_structure_khr,
-            method_name: copy_memory_to_acceleration_structure_khr,
-            ptr: CopyMemoryToAccelerationStructureKHR,
+            method_name: cmd_set_stencil_write_mask,
+            try_method_name: try_cmd_set_stencil_write_mask,
+            unchecked_method_name: cmd_set_stencil_write_mask_unchecked,
             signature: (
-                deferred_operation: DeferredOperationKHR,
-                p_info: *const CopyMemoryToAccelerationStructureInfoKHR,
-            ) -> Result,
-            takes_handle: true,
+                face_mask: StencilFaceFlags,
+                write_mask: u32,
+            ),
         },
         {
-            name: pfn_cmd_write_acceleration_structures_properties_khr,
-            method_name: cmd_write_acceleration_structures_properties_khr,
-            ptr: CmdWriteAccelerationStructuresPropertiesKHR,
+            method_name: cmd_set_stencil_reference,
+            try_method_name: try_cmd_set_stencil_reference,
+            unchecked_method_name: cmd_set_stencil_reference_unchecked,
             signature: (
-                command_buffer: CommandBuffer,
-                acceleration_structure_count: u32,
-                p_acceleration_structures: *const AccelerationStructureKHR,
-                query_type: QueryType,
-                query_pool: QueryPool,
-                first_query: u32,
+                face_mask: StencilFaceFlags,
+                reference: u32,
             ),
-            takes_handle: false,
         },
         {
-            name: pfn_cmd_write_acceleration_structures_properties_nv,
-            method_name: cmd_write_acceleration_structures_properties_nv,
-            ptr: CmdWriteAccelerationStructuresPropertiesNV,
+            method_name: cmd_bind_descriptor_sets,
+            try_method_name: try_cmd_bind_descriptor_sets,
+            unchecked_method_name: cmd_bind_descriptor_sets_unchecked,
             signature: (
-                command_buffer: CommandBuffer,
-                acceleration_structure_count: u32,
-                p_acceleration_structures: *const AccelerationStructureNV,
-                query_type: QueryType,
-                query_pool: QueryPool,
-                first_query: u32,
+                pipeline_bind_point: PipelineBindPoint,
+                layout: PipelineLayout,
+                first_set: u32,
+                descriptor_set_count: u32,
+                p_descriptor_sets: *const DescriptorSet,
+                dynamic_offset_count: u32,
+                p_dynamic_offsets: *const u32,
             ),
-            takes_handle: false,
         },
         {
-            name: pfn_cmd_build_acceleration_structure_nv,
-            method_name: cmd_build_acceleration_structure_nv,
-            ptr: CmdBuildAccelerationStructureNV,
+            method_name: cmd_bind_index_buffer,
+            try_method_name: try_cmd_bind_index_buffer,
+            unchecked_method_name: cmd_bind_index_buffer_unchecked,
             signature: (
-                command_buffer: CommandBuffer,
-                p_info: *const AccelerationStructureInfoNV,
-                instance_data: Buffer,
-                instance_offset: DeviceSize,
-                update: Bool32,
-                dst: AccelerationStructureNV,
-                src: AccelerationStructureNV,
-                scratch: Buffer,
-                scratch_offset: DeviceSize,
+                buffer: Buffer,
+                offset: DeviceSize,
+                index_type: IndexType,
             ),
-            takes_handle: false,
         },
         {
-            name: pfn_write_acceleration_structures_properties_khr,
-            method_name: write_acceleration_structures_properties_khr,
-            ptr: WriteAccelerationStructuresPropertiesKHR,
+            method_name: cmd_bind_vertex_buffers,
+            try_method_name: try_cmd_bind_vertex_buffers,
+            unchecked_method_name: cmd_bind_vertex_buffers_unchecked,
             signature: (
-                acceleration_structure_count: u32,
-                p_acceleration_structures: *const AccelerationStructureKHR,
-                query_type: QueryType,
-                data_size: usize,
-                p_data: *mut c_void,
-                stride: usize,
-            ) -> Result,
-            takes_handle: true,
+                first_binding: u32,
+                binding_count: u32,
+                p_buffers: *const Buffer,
+                p_offsets: *const DeviceSize,
+            ),
         },
         {
-            name: pfn_cmd_trace_rays_khr,
-            method_name: cmd_trace_rays_khr,
-            ptr: CmdTraceRaysKHR,
+            method_name: cmd_draw,
+            try_method_name: try_cmd_draw,
+            unchecked_method_name: cmd_draw_unchecked,
             signature: (
-                command_buffer: CommandBuffer,
-                p_raygen_shader_binding_table: *const StridedDeviceAddressRegionKHR,
-                p_miss_shader_binding_table: *const StridedDeviceAddressRegionKHR,
-                p_hit_shader_binding_table: *const StridedDeviceAddressRegionKHR,
-                p_callable_shader_binding_table: *const StridedDeviceAddressRegionKHR,
-                width: u32,
-                height: u32,
-                depth: u32,
+                vertex_count: u32,
+                instance_count: u32,
+                first_vertex: u32,
+                first_instance: u32,
             ),
-            takes_handle: false,
         },
         {
-            name: pfn_cmd_trace_rays_nv,
-            method_name: cmd_trace_rays_nv,
-            ptr: CmdTraceRaysNV,
+            method_name: cmd_draw_indexed,
+            try_method_name: try_cmd_draw_indexed,
+            unchecked_method_name: cmd_draw_indexed_unchecked,
             signature: (
-                command_buffer: CommandBuffer,
-                raygen_shader_binding_table_buffer: Buffer,
-                raygen_shader_binding_offset: DeviceSize,
-                miss_shader_binding_table_buffer: Buffer,
-                miss_shader_binding_offset: DeviceSize,
-                miss_shader_binding_stride: DeviceSize,
-                hit_shader_binding_table_buffer: Buffer,
-                hit_shader_binding_offset: DeviceSize,
-                hit_shader_binding_stride: DeviceSize,
-                callable_shader_binding_table_buffer: Buffer,
-                callable_shader_binding_offset: DeviceSize,
-                callable_shader_binding_stride: DeviceSize,
-                width: u32,
-                height: u32,
-                depth: u32,
+                index_count: u32,
+                instance_count: u32,
+                first_index: u32,
+                vertex_offset: i32,
+                first_instance: u32,
             ),
-            takes_handle: false,
         },
         {
-            name: pfn_get_ray_tracing_shader_group_handles_khr,
-            method_name: get_ray_tracing_shader_group_handles_khr,
-            ptr: GetRayTracingShaderGroupHandlesKHR,
+            method_name: cmd_draw_multi_ext,
+            try_method_name: try_cmd_draw_multi_ext,
+            unchecked_method_name: cmd_draw_multi_ext_unchecked,
             signature: (
-                pipeline: Pipeline,
-                first_group: u32,
-                group_count: u32,
-                data_size: usize,
-                p_data: *mut c_void,
-            ) -> Result,
-            takes_handle: true,
+                draw_count: u32,
+                p_vertex_info: *const MultiDrawInfoEXT,
+                instance_count: u32,
+                first_instance: u32,
+                stride: u32,
+            ),
         },
         {
-            name: pfn_get_ray_tracing_capture_replay_shader_group_handles_khr,
-            method_name: get_ray_tracing_capture_replay_shader_group_handles_khr,
-            ptr: GetRayTracingCaptureReplayShaderGroupHandlesKHR,
+            method_name: cmd_draw_multi_indexed_ext,
+            try_method_name: try_cmd_draw_multi_indexed_ext,
+            unchecked_method_name: cmd_draw_multi_indexed_ext_unchecked,
             signature: (
-                pipeline: Pipeline,
-                first_group: u32,
-                group_count: u32,
-                data_size: usize,
-                p_data: *mut c_void,
-            ) -> Result,
-            takes_handle: true,
+                draw_count: u32,
+                p_index_info: *const MultiDrawIndexedInfoEXT,
+                instance_count: u32,
+                first_instance: u32,
+                stride: u32,
+                p_vertex_offset: *const i32,
+            ),
         },
         {
-            name: pfn_get_acceleration_structure_handle_nv,
-            method_name: get_acceleration_structure_handle_nv,
-            ptr: GetAccelerationStructureHandleNV,
+            method_name: cmd_draw_indirect,
+            try_method_name: try_cmd_draw_indirect,
+            unchecked_method_name: cmd_draw_indirect_unchecked,
             signature: (
-                acceleration_structure: AccelerationStructureNV,
-                data_size: usize,
-                p_data: *mut c_void,
-            ) -> Result,
-            takes_handle: true,
+                buffer: Buffer,
+                offset: DeviceSize,
+                draw_count: u32,
+                stride: u32,
+            ),
         },
         {
-            name: pfn_create_ray_tracing_pipelines_nv,
-            method_name: create_ray_tracing_pipelines_nv,
-            ptr: CreateRayTracingPipelinesNV,
+            method_name: cmd_draw_indexed_indirect,
+            try_method_name: try_cmd_draw_indexed_indirect,
+            unchecked_method_name: cmd_draw_indexed_indirect_unchecked,
             signature: (
-                pipeline_cache: PipelineCache,
-                create_info_count: u32,
-                p_create_infos: *const RayTracingPipelineCreateInfoNV,
-                p_allocator: *const AllocationCallbacks,
-                p_pipelines: *mut Pipeline,
-            ) -> Result,
-            takes_handle: true,
+                buffer: Buffer,
+                offset: DeviceSize,
+                draw_count: u32,
+                stride: u32,
+            ),
         },
         {
-            name: pfn_create_ray_tracing_pipelines_khr,
-            method_name: create_ray_tracing_pipelines_khr,
-            ptr: CreateRayTracingPipelinesKHR,
+            method_name: cmd_dispatch,
+            try_method_name: try_cmd_dispatch,
+            unchecked_method_name: cmd_dispatch_unchecked,
             signature: (
-                deferred_operation: DeferredOperationKHR,
-                pipeline_cache: PipelineCache,
-                create_info_count: u32,
-                p_create_infos: *const RayTracingPipelineCreateInfoKHR,
-                p_allocator: *const AllocationCallbacks,
-                p_pipelines: *mut Pipeline,
-            ) -> Result,
-            takes_handle: true,
+                group_count_x: u32,
+                group_count_y: u32,
+                group_count_z: u32,
+            ),
         },
         {
-            name: pfn_cmd_trace_rays_indirect_khr,
-            method_name: cmd_trace_rays_indirect_khr,
-            ptr: CmdTraceRaysIndirectKHR,
+            method_name: cmd_dispatch_indirect,
+            try_method_name: try_cmd_dispatch_indirect,
+            unchecked_method_name: cmd_dispatch_indirect_unchecked,
             signature: (
-                command_buffer: CommandBuffer,
-                p_raygen_shader_binding_table: *const StridedDeviceAddressRegionKHR,
-                p_miss_shader_binding_table: *const StridedDeviceAddressRegionKHR,
-                p_hit_shader_binding_table: *const StridedDeviceAddressRegionKHR,
-                p_callable_shader_binding_table: *const StridedDeviceAddressRegionKHR,
-                indirect_device_address: DeviceAddress,
+                buffer: Buffer,
+                offset: DeviceSize,
             ),
-            takes_handle: false,
         },
         {
-            name: pfn_cmd_trace_rays_indirect_2_khr,
-            method_name: cmd_trace_rays_indirect_2_khr,
-            ptr: CmdTraceRaysIndirect2KHR,
+            method_name: cmd_subpass_shading_huawei,
+            try_method_name: try_cmd_subpass_shading_huawei,
+            unchecked_method_name: cmd_subpass_shading_huawei_unchecked,
             signature: (
-                command_buffer: CommandBuffer,
-                indirect_device_address: DeviceAddress,
             ),
-            takes_handle: false,
         },
         {
-            name: pfn_get_device_acceleration_structure_compatibility_khr,
-            method_name: get_device_acceleration_structure_compatibility_khr,
-            ptr: GetDeviceAccelerationStructureCompatibilityKHR,
+            method_name: cmd_copy_buffer,
+            try_method_name: try_cmd_copy_buffer,
+            unchecked_method_name: cmd_copy_buffer_unchecked,
             signature: (
-                p_version_info: *const AccelerationStructureVersionInfoKHR,
-                p_compatibility: *mut AccelerationStructureCompatibilityKHR,
+                src_buffer: Buffer,
+                dst_buffer: Buffer,
+                region_count: u32,
+                p_regions: *const BufferCopy,
             ),
-            takes_handle: true,
         },
         {
-            name: pfn_get_ray_tracing_shader_group_stack_size_khr,
-            method_name: get_ray_tracing_shader_group_stack_size_khr,
-            ptr: GetRayTracingShaderGroupStackSizeKHR,
+            method_name: cmd_copy_image,
+            try_method_name: try_cmd_copy_image,
+            unchecked_method_name: cmd_copy_image_unchecked,
             signature: (
-                pipeline: Pipeline,
-                group: u32,
-                group_shader: ShaderGroupShaderKHR,
-            ) -> DeviceSize,
-            takes_handle: true,
+                src_image: Image,
+                src_image_layout: ImageLayout,
+                dst_image: Image,
+                dst_image_layout: ImageLayout,
+                region_count: u32,
+                p_regions: *const ImageCopy,
+            ),
         },
         {
-            name: pfn_cmd_set_ray_tracing_pipeline_stack_size_khr,
-            method_name: cmd_set_ray_tracing_pipeline_stack_size_khr,
-            ptr: CmdSetRayTracingPipelineStackSizeKHR,
+            method_name: cmd_blit_image,
+            try_method_name: try_cmd_blit_image,
+            unchecked_method_name: cmd_blit_image_unchecked,
             signature: (
-                command_buffer: CommandBuffer,
-                pipeline_stack_size: u32,
+                src_image: Image,
+                src_image_layout: ImageLayout,
+                dst_image: Image,
+                dst_image_layout: ImageLayout,
+                region_count: u32,
+                p_regions: *const ImageBlit,
+                filter: Filter,
             ),
-            takes_handle: false,
         },
         {
-            name: pfn_get_image_view_handle_nvx,
-            method_name: get_image_view_handle_nvx,
-            ptr: GetImageViewHandleNVX,
+            method_name: cmd_copy_buffer_to_image,
+            try_method_name: try_cmd_copy_buffer_to_image,
+            unchecked_method_name: cmd_copy_buffer_to_image_unchecked,
             signature: (
-                p_info: *const ImageViewHandleInfoNVX,
-            ) -> u32,
-            takes_handle: true,
+                src_buffer: Buffer,
+                dst_image: Image,
+                dst_image_layout: ImageLayout,
+                region_count: u32,
+                p_regions: *const BufferImageCopy,
+            ),
         },
         {
-            name: pfn_get_image_view_address_nvx,
-            method_name: get_image_view_address_nvx,
-            ptr: GetImageViewAddressNVX,
+            method_name: cmd_copy_image_to_buffer,
+            try_method_name: try_cmd_copy_image_to_buffer,
+            unchecked_method_name: cmd_copy_image_to_buffer_unchecked,
             signature: (
-                image_view: ImageView,
-                p_properties: *mut ImageViewAddressPropertiesNVX,
-            ) -> Result,
-            takes_handle: true,
+                src_image: Image,
+                src_image_layout: ImageLayout,
+                dst_buffer: Buffer,
+                region_count: u32,
+                p_regions: *const BufferImageCopy,
+            ),
         },
         {
-            name: pfn_get_device_group_surface_present_modes_2_ext,
-            method_name: get_device_group_surface_present_modes_2_ext,
-            ptr: GetDeviceGroupSurfacePresentModes2EXT,
+            method_name: cmd_update_buffer,
+            try_method_name: try_cmd_update_buffer,
+            unchecked_method_name: cmd_update_buffer_unchecked,
             signature: (
-                p_surface_info: *const PhysicalDeviceSurfaceInfo2KHR,
-                p_modes: *mut DeviceGroupPresentModeFlagsKHR,
-            ) -> Result,
-            takes_handle: true,
+                dst_buffer: Buffer,
+                dst_offset: DeviceSize,
+                data_size: DeviceSize,
+                p_data: *const c_void,
+            ),
         },
         {
-            name: pfn_acquire_full_screen_exclusive_mode_ext,
-            method_name: acquire_full_screen_exclusive_mode_ext,
-            ptr: AcquireFullScreenExclusiveModeEXT,
+            method_name: cmd_fill_buffer,
+            try_method_name: try_cmd_fill_buffer,
+            unchecked_method_name: cmd_fill_buffer_unchecked,
             signature: (
-                swapchain: SwapchainKHR,
-            ) -> Result,
-            takes_handle: true,
+                dst_buffer: Buffer,
+                dst_offset: DeviceSize,
+                size: DeviceSize,
+                data: u32,
+            ),
         },
         {
-            name: pfn_release_full_screen_exclusive_mode_ext,
-            method_name: release_full_screen_exclusive_mode_ext,
-            ptr: ReleaseFullScreenExclusiveModeEXT,
+            method_name: cmd_clear_color_image,
+            try_method_name: try_cmd_clear_color_image,
+            unchecked_method_name: cmd_clear_color_image_unchecked,
             signature: (
-                swapchain: SwapchainKHR,
-            ) -> Result,
-            takes_handle: true,
+                image: Image,
+                image_layout: ImageLayout,
+                p_color: *const ClearColorValue,
+                range_count: u32,
+                p_ranges: *const ImageSubresourceRange,
+            ),
         },
         {
-            name: pfn_acquire_profiling_lock_khr,
-            method_name: acquire_profiling_lock_khr,
-            ptr: AcquireProfilingLockKHR,
+            method_name: cmd_clear_depth_stencil_image,
+            try_method_name: try_cmd_clear_depth_stencil_image,
+            unchecked_method_name: cmd_clear_depth_stencil_image_unchecked,
             signature: (
-                p_info: *const AcquireProfilingLockInfoKHR,
-            ) -> Result,
-            takes_handle: true,
+                image: Image,
+                image_layout: ImageLayout,
+                p_depth_stencil: *const ClearDepthStencilValue,
+                range_count: u32,
+                p_ranges: *const ImageSubresourceRange,
+            ),
         },
         {
-            name: pfn_release_profiling_lock_khr,
-            method_name: release_profiling_lock_khr,
-            ptr: ReleaseProfilingLockKHR,
+            method_name: cmd_clear_attachments,
+            try_method_name: try_cmd_clear_attachments,
+            unchecked_method_name: cmd_clear_attachments_unchecked,
             signature: (
+                attachment_count: u32,
+                p_attachments: *const ClearAttachment,
+                rect_count: u32,
+                p_rects: *const ClearRect,
             ),
-            takes_handle: true,
         },
         {
-            name: pfn_get_image_drm_format_modifier_properties_ext,
-            method_name: get_image_drm_format_modifier_properties_ext,
-            ptr: GetImageDrmFormatModifierPropertiesEXT,
+            method_name: cmd_resolve_image,
+            try_method_name: try_cmd_resolve_image,
+            unchecked_method_name: cmd_resolve_image_unchecked,
             signature: (
-                image: Image,
-                p_properties: *mut ImageDrmFormatModifierPropertiesEXT,
-            ) -> Result,
-            takes_handle: true,
+                src_image: Image,
+                src_image_layout: ImageLayout,
+                dst_image: Image,
+                dst_image_layout: ImageLayout,
+                region_count: u32,
+                p_regions: *const ImageResolve,
+            ),
         },
         {
-            name: pfn_get_buffer_opaque_capture_address,
-            method_name: get_buffer_opaque_capture_address,
-            ptr: GetBufferOpaqueCaptureAddress,
+            method_name: cmd_set_event,
+            try_method_name: try_cmd_set_event,
+            unchecked_method_name: cmd_set_event_unchecked,
             signature: (
-                p_info: *const BufferDeviceAddressInfo,
-            ) -> u64,
-            takes_handle: true,
+                event: Event,
+                stage_mask: PipelineStageFlags,
+            ),
         },
         {
-            name: pfn_get_buffer_device_address,
-            method_name: get_buffer_device_address,
-            ptr: GetBufferDeviceAddress,
+            method_name: cmd_reset_event,
+            try_method_name: try_cmd_reset_event,
+            unchecked_method_name: cmd_reset_event_unchecked,
             signature: (
-                p_info: *const BufferDeviceAddressInfo,
-            ) -> DeviceAddress,
-            takes_handle: true,
+                event: Event,
+                stage_mask: PipelineStageFlags,
+            ),
         },
         {
-            name: pfn_initialize_performance_api_intel,
-            method_name: initialize_performance_api_intel,
-            ptr: InitializePerformanceApiINTEL,
+            method_name: cmd_wait_events,
+            try_method_name: try_cmd_wait_events,
+            unchecked_method_name: cmd_wait_events_unchecked,
             signature: (
-                p_initialize_info: *const InitializePerformanceApiInfoINTEL,
-            ) -> Result,
-            takes_handle: true,
+                event_count: u32,
+                p_events: *const Event,
+                src_stage_mask: PipelineStageFlags,
+                dst_stage_mask: PipelineStageFlags,
+                memory_barrier_count: u32,
+                p_memory_barriers: *const MemoryBarrier,
+                buffer_memory_barrier_count: u32,
+                p_buffer_memory_barriers: *const BufferMemoryBarrier,
+                image_memory_barrier_count: u32,
+                p_image_memory_barriers: *const ImageMemoryBarrier,
+            ),
         },
         {
-            name: pfn_uninitialize_performance_api_intel,
-            method_name: uninitialize_performance_api_intel,
-            ptr: UninitializePerformanceApiINTEL,
+            method_name: cmd_pipeline_barrier,
+            try_method_name: try_cmd_pipeline_barrier,
+            unchecked_method_name: cmd_pipeline_barrier_unchecked,
             signature: (
+                src_stage_mask: PipelineStageFlags,
+                dst_stage_mask: PipelineStageFlags,
+                dependency_flags: DependencyFlags,
+                memory_barrier_count: u32,
+                p_memory_barriers: *const MemoryBarrier,
+                buffer_memory_barrier_count: u32,
+                p_buffer_memory_barriers: *const BufferMemoryBarrier,
+                image_memory_barrier_count: u32,
+                p_image_memory_barriers: *const ImageMemoryBarrier,
             ),
-            takes_handle: true,
         },
         {
-            name: pfn_cmd_set_performance_marker_intel,
-            method_name: cmd_set_performance_marker_intel,
-            ptr: CmdSetPerformanceMarkerINTEL,
-            signature: (
-                command_buffer: CommandBuffer,
-                p_marker_info: *const PerformanceMarkerInfoINTEL,
-            ) -> Result,
-            takes_handle: false,
+            method_name: cmd_begin_query,
+            try_method_name: try_cmd_begin_query,
+            unchecked_method_name: cmd_begin_query_unchecked,
+            signature: (
+                query_pool: QueryPool,
+                query: u32,
+                flags: QueryControlFlags,
+            ),
         },
         {
-            name: pfn_cmd_set_performance_stream_marker_intel,
-            method_name: cmd_set_performance_stream_marker_intel,
-            ptr: CmdSetPerformanceStreamMarkerINTEL,
+            method_name: cmd_end_query,
+            try_method_name: try_cmd_end_query,
+            unchecked_method_name: cmd_end_query_unchecked,
             signature: (
-                command_buffer: CommandBuffer,
-                p_marker_info: *const PerformanceStreamMarkerInfoINTEL,
-            ) -> Result,
-            takes_handle: false,
+                query_pool: QueryPool,
+                query: u32,
+            ),
         },
         {
-            name: pfn_cmd_set_performance_override_intel,
-            method_name: cmd_set_performance_override_intel,
-            ptr: CmdSetPerformanceOverrideINTEL,
+            method_name: cmd_begin_conditional_rendering_ext,
+            try_method_name: try_cmd_begin_conditional_rendering_ext,
+            unchecked_method_name: cmd_begin_conditional_rendering_ext_unchecked,
             signature: (
-                command_buffer: CommandBuffer,
-                p_override_info: *const PerformanceOverrideInfoINTEL,
-            ) -> Result,
-            takes_handle: false,
+                p_conditional_rendering_begin: *const ConditionalRenderingBeginInfoEXT,
+            ),
         },
         {
-            name: pfn_acquire_performance_configuration_intel,
-            method_name: acquire_performance_configuration_intel,
-            ptr: AcquirePerformanceConfigurationINTEL,
+            method_name: cmd_end_conditional_rendering_ext,
+            try_method_name: try_cmd_end_conditional_rendering_ext,
+            unchecked_method_name: cmd_end_conditional_rendering_ext_unchecked,
             signature: (
-                p_acquire_info: *const PerformanceConfigurationAcquireInfoINTEL,
-                p_configuration: *mut PerformanceConfigurationINTEL,
-            ) -> Result,
-            takes_handle: true,
+            ),
         },
         {
-            name: pfn_release_performance_configuration_intel,
-            method_name: release_performance_configuration_intel,
-            ptr: ReleasePerformanceConfigurationINTEL,
+            method_name: cmd_reset_query_pool,
+            try_method_name: try_cmd_reset_query_pool,
+            unchecked_method_name: cmd_reset_query_pool_unchecked,
             signature: (
-                configuration: PerformanceConfigurationINTEL,
-            ) -> Result,
-            takes_handle: true,
+                query_pool: QueryPool,
+                first_query: u32,
+                query_count: u32,
+            ),
         },
         {
-            name: pfn_queue_set_performance_configuration_intel,
-            method_name: queue_set_performance_configuration_intel,
-            ptr: QueueSetPerformanceConfigurationINTEL,
+            method_name: cmd_write_timestamp,
+            try_method_name: try_cmd_write_timestamp,
+            unchecked_method_name: cmd_write_timestamp_unchecked,
             signature: (
-                queue: Queue,
-                configuration: PerformanceConfigurationINTEL,
-            ) -> Result,
-            takes_handle: false,
+                pipeline_stage: PipelineStageFlagBits,
+                query_pool: QueryPool,
+                query: u32,
+            ),
         },
         {
-            name: pfn_get_performance_parameter_intel,
-            method_name: get_performance_parameter_intel,
-            ptr: GetPerformanceParameterINTEL,
+            method_name: cmd_copy_query_pool_results,
+            try_method_name: try_cmd_copy_query_pool_results,
+            unchecked_method_name: cmd_copy_query_pool_results_unchecked,
             signature: (
-                parameter: PerformanceParameterTypeINTEL,
-                p_value: *mut PerformanceValueINTEL,
-            ) -> Result,
-            takes_handle: true,
+                query_pool: QueryPool,
+                first_query: u32,
+                query_count: u32,
+                dst_buffer: Buffer,
+                dst_offset: DeviceSize,
+                stride: DeviceSize,
+                flags: QueryResultFlags,
+            ),
         },
         {
-            name: pfn_get_device_memory_opaque_capture_address,
-            method_name: get_device_memory_opaque_capture_address,
-            ptr: GetDeviceMemoryOpaqueCaptureAddress,
+            method_name: cmd_push_constants,
+            try_method_name: try_cmd_push_constants,
+            unchecked_method_name: cmd_push_constants_unchecked,
             signature: (
-                p_info: *const DeviceMemoryOpaqueCaptureAddressInfo,
-            ) -> u64,
-            takes_handle: true,
+                layout: PipelineLayout,
+                stage_flags: ShaderStageFlags,
+                offset: u32,
+                size: u32,
+                p_values: *const c_void,
+            ),
         },
         {
-            name: pfn_get_pipeline_executable_properties_khr,
-            method_name: get_pipeline_executable_properties_khr,
-            ptr: GetPipelineExecutablePropertiesKHR,
+            method_name: cmd_begin_render_pass,
+            try_method_name: try_cmd_begin_render_pass,
+            unchecked_method_name: cmd_begin_render_pass_unchecked,
             signature: (
-                p_pipeline_info: *const PipelineInfoKHR,
-                p_executable_count: *mut u32,
-                p_properties: *mut PipelineExecutablePropertiesKHR,
-            ) -> Result,
-            takes_handle: true,
+                p_render_pass_begin: *const RenderPassBeginInfo,
+                contents: SubpassContents,
+            ),
         },
         {
-            name: pfn_get_pipeline_executable_statistics_khr,
-            method_name: get_pipeline_executable_statistics_khr,
-            ptr: GetPipelineExecutableStatisticsKHR,
+            method_name: cmd_next_subpass,
+            try_method_name: try_cmd_next_subpass,
+            unchecked_method_name: cmd_next_subpass_unchecked,
             signature: (
-                p_executable_info: *const PipelineExecutableInfoKHR,
-                p_statistic_count: *mut u32,
-                p_statistics: *mut PipelineExecutableStatisticKHR,
-            ) -> Result,
-            takes_handle: true,
+                contents: SubpassContents,
+            ),
         },
         {
-            name: pfn_get_pipeline_executable_internal_representations_khr,
-            method_name: get_pipeline_executable_internal_representations_khr,
-            ptr: GetPipelineExecutableInternalRepresentationsKHR,
+            method_name: cmd_end_render_pass,
+            try_method_name: try_cmd_end_render_pass,
+            unchecked_method_name: cmd_end_render_pass_unchecked,
             signature: (
-                p_executable_info: *const PipelineExecutableInfoKHR,
-                p_internal_representation_count: *mut u32,
-                p_internal_representations: *mut PipelineExecutableInternalRepresentationKHR,
-            ) -> Result,
-            takes_handle: true,
+            ),
         },
         {
-            name: pfn_cmd_set_line_stipple_ext,
-            method_name: cmd_set_line_stipple_ext,
-            ptr: CmdSetLineStippleEXT,
+            method_name: cmd_execute_commands,
+            try_method_name: try_cmd_execute_commands,
+            unchecked_method_name: cmd_execute_commands_unchecked,
             signature: (
-                command_buffer: CommandBuffer,
-                line_stipple_factor: u32,
-                line_stipple_pattern: u16,
+                command_buffer_count: u32,
+                p_command_buffers: *const CommandBuffer,
             ),
-            takes_handle: false,
         },
         {
-            name: pfn_create_acceleration_structure_khr,
-            method_name: create_acceleration_structure_khr,
-            ptr: CreateAccelerationStructureKHR,
+            method_name: cmd_debug_marker_begin_ext,
+            try_method_name: try_cmd_debug_marker_begin_ext,
+            unchecked_method_name: cmd_debug_marker_begin_ext_unchecked,
             signature: (
-                p_create_info: *const AccelerationStructureCreateInfoKHR,
-                p_allocator: *const AllocationCallbacks,
-                p_acceleration_structure: *mut AccelerationStructureKHR,
-            ) -> Result,
-            takes_handle: true,
+                p_marker_info: *const DebugMarkerMarkerInfoEXT,
+            ),
         },
         {
-            name: pfn_cmd_build_acceleration_structures_khr,
-            method_name: cmd_build_acceleration_structures_khr,
-            ptr: CmdBuildAccelerationStructuresKHR,
+            method_name: cmd_debug_marker_end_ext,
+            try_method_name: try_cmd_debug_marker_end_ext,
+            unchecked_method_name: cmd_debug_marker_end_ext_unchecked,
             signature: (
-                command_buffer: CommandBuffer,
-                info_count: u32,
-                p_infos: *const AccelerationStructureBuildGeometryInfoKHR,
-                pp_build_range_infos: *const *const AccelerationStructureBuildRangeInfoKHR,
             ),
-            takes_handle: false,
         },
         {
-            name: pfn_cmd_build_acceleration_structures_indirect_khr,
-            method_name: cmd_build_acceleration_structures_indirect_khr,
-            ptr: CmdBuildAccelerationStructuresIndirectKHR,
+            method_name: cmd_debug_marker_insert_ext,
+            try_method_name: try_cmd_debug_marker_insert_ext,
+            unchecked_method_name: cmd_debug_marker_insert_ext_unchecked,
             signature: (
-                command_buffer: CommandBuffer,
-                info_count: u32,
-                p_infos: *const AccelerationStructureBuildGeometryInfoKHR,
-                p_indirect_device_addresses: *const DeviceAddress,
-                p_indirect_strides: *const u32,
-                pp_max_primitive_counts: *const *const u32,
+                p_marker_info: *const DebugMarkerMarkerInfoEXT,
             ),
-            takes_handle: false,
         },
         {
-            name: pfn_build_acceleration_structures_khr,
-            method_name: build_acceleration_structures_khr,
-            ptr: BuildAccelerationStructuresKHR,
+            method_name: cmd_execute_generated_commands_nv,
+            try_method_name: try_cmd_execute_generated_commands_nv,
+            unchecked_method_name: cmd_execute_generated_commands_nv_unchecked,
             signature: (
-                deferred_operation: DeferredOperationKHR,
-                info_count: u32,
-                p_infos: *const AccelerationStructureBuildGeometryInfoKHR,
-                pp_build_range_infos: *const *const AccelerationStructureBuildRangeInfoKHR,
-            ) -> Result,
-            takes_handle: true,
+                is_preprocessed: Bool32,
+                p_generated_commands_info: *const GeneratedCommandsInfoNV,
+            ),
         },
         {
-            name: pfn_get_acceleration_structure_device_address_khr,
-            method_name: get_acceleration_structure_device_address_khr,
-            ptr: GetAccelerationStructureDeviceAddressKHR,
+            method_name: cmd_preprocess_generated_commands_nv,
+            try_method_name: try_cmd_preprocess_generated_commands_nv,
+            unchecked_method_name: cmd_preprocess_generated_commands_nv_unchecked,
             signature: (
-                p_info: *const AccelerationStructureDeviceAddressInfoKHR,
-            ) -> DeviceAddress,
-            takes_handle: true,
+                p_generated_commands_info: *const GeneratedCommandsInfoNV,
+            ),
         },
         {
-            name: pfn_create_deferred_operation_khr,
-            method_name: create_deferred_operation_khr,
-            ptr: CreateDeferredOperationKHR,
+            method_name: cmd_bind_pipeline_shader_group_nv,
+            try_method_name: try_cmd_bind_pipeline_shader_group_nv,
+            unchecked_method_name: cmd_bind_pipeline_shader_group_nv_unchecked,
             signature: (
-                p_allocator: *const AllocationCallbacks,
-                p_deferred_operation: *mut DeferredOperationKHR,
-            ) -> Result,
-            takes_handle: true,
+                pipeline_bind_point: PipelineBindPoint,
+                pipeline: Pipeline,
+                group_index: u32,
+            ),
         },
         {
-            name: pfn_destroy_deferred_operation_khr,
-            method_name: destroy_deferred_operation_khr,
-            ptr: DestroyDeferredOperationKHR,
+            method_name: cmd_push_descriptor_set_khr,
+            try_method_name: try_cmd_push_descriptor_set_khr,
+            unchecked_method_name: cmd_push_descriptor_set_khr_unchecked,
             signature: (
-                operation: DeferredOperationKHR,
-                p_allocator: *const AllocationCallbacks,
+                pipeline_bind_point: PipelineBindPoint,
+                layout: PipelineLayout,
+                set: u32,
+                descriptor_write_count: u32,
+                p_descriptor_writes: *const WriteDescriptorSet,
             ),
-            takes_handle: true,
         },
         {
-            name: pfn_get_deferred_operation_max_concurrency_khr,
-            method_name: get_deferred_operation_max_concurrency_khr,
-            ptr: GetDeferredOperationMaxConcurrencyKHR,
+            method_name: cmd_set_device_mask,
+            try_method_name: try_cmd_set_device_mask,
+            unchecked_method_name: cmd_set_device_mask_unchecked,
             signature: (
-                operation: DeferredOperationKHR,
-            ) -> u32,
-            takes_handle: true,
+                device_mask: u32,
+            ),
         },
         {
-            name: pfn_get_deferred_operation_result_khr,
-            method_name: get_deferred_operation_result_khr,
-            ptr: GetDeferredOperationResultKHR,
+            method_name: cmd_dispatch_base,
+            try_method_name: try_cmd_dispatch_base,
+            unchecked_method_name: cmd_dispatch_base_unchecked,
             signature: (
-                operation: DeferredOperationKHR,
-            ) -> Result,
-            takes_handle: true,
+                base_group_x: u32,
+                base_group_y: u32,
+                base_group_z: u32,
+                group_count_x: u32,
+                group_count_y: u32,
+                group_count_z: u32,
+            ),
         },
         {
-            name: pfn_deferred_operation_join_khr,
-            method_name: deferred_operation_join_khr,
-            ptr: DeferredOperationJoinKHR,
+            method_name: cmd_push_descriptor_set_with_template_khr,
+            try_method_name: try_cmd_push_descriptor_set_with_template_khr,
+            unchecked_method_name: cmd_push_descriptor_set_with_template_khr_unchecked,
             signature: (
-                operation: DeferredOperationKHR,
-            ) -> Result,
-            takes_handle: true,
+                descriptor_update_template: DescriptorUpdateTemplate,
+                layout: PipelineLayout,
+                set: u32,
+                p_data: *const c_void,
+            ),
         },
         {
-            name: pfn_cmd_set_cull_mode,
-            method_name: cmd_set_cull_mode,
-            ptr: CmdSetCullMode,
+            method_name: cmd_set_viewport_w_scaling_nv,
+            try_method_name: try_cmd_set_viewport_w_scaling_nv,
+            unchecked_method_name: cmd_set_viewport_w_scaling_nv_unchecked,
             signature: (
-                command_buffer: CommandBuffer,
-                cull_mode: CullModeFlags,
+                first_viewport: u32,
+                viewport_count: u32,
+                p_viewport_w_scalings: *const ViewportWScalingNV,
+            ),
+        },
+        {
+            method_name: cmd_set_discard_rectangle_ext,
+            try_method_name: try_cmd_set_discard_rectangle_ext,
+            unchecked_method_name: cmd_set_discard_rectangle_ext_unchecked,
+            signature: (
+                first_discard_rectangle: u32,
+                discard_rectangle_count: u32,
+                p_discard_rectangles: *const Rect2D,
             ),
-            takes_handle: false,
         },
         {
-            name: pfn_cmd_set_front_face,
-            method_name: cmd_set_front_face,
-            ptr: CmdSetFrontFace,
+            method_name: cmd_set_sample_locations_ext,
+            try_method_name: try_cmd_set_sample_locations_ext,
+            unchecked_method_name: cmd_set_sample_locations_ext_unchecked,
             signature: (
-                command_buffer: CommandBuffer,
-                front_face: FrontFace,
+                p_sample_locations_info: *const SampleLocationsInfoEXT,
             ),
-            takes_handle: false,
         },
         {
-            name: pfn_cmd_set_primitive_topology,
-            method_name: cmd_set_primitive_topology,
-            ptr: CmdSetPrimitiveTopology,
+            method_name: cmd_begin_debug_utils_label_ext,
+            try_method_name: try_cmd_begin_debug_utils_label_ext,
+            unchecked_method_name: cmd_begin_debug_utils_label_ext_unchecked,
             signature: (
-                command_buffer: CommandBuffer,
-                primitive_topology: PrimitiveTopology,
+                p_label_info: *const DebugUtilsLabelEXT,
             ),
-            takes_handle: false,
         },
         {
-            name: pfn_cmd_set_viewport_with_count,
-            method_name: cmd_set_viewport_with_count,
-            ptr: CmdSetViewportWithCount,
+            method_name: cmd_end_debug_utils_label_ext,
+            try_method_name: try_cmd_end_debug_utils_label_ext,
+            unchecked_method_name: cmd_end_debug_utils_label_ext_unchecked,
             signature: (
-                command_buffer: CommandBuffer,
-                viewport_count: u32,
-                p_viewports: *const Viewport,
             ),
-            takes_handle: false,
         },
         {
-            name: pfn_cmd_set_scissor_with_count,
-            method_name: cmd_set_scissor_with_count,
-            ptr: CmdSetScissorWithCount,
+            method_name: cmd_insert_debug_utils_label_ext,
+            try_method_name: try_cmd_insert_debug_utils_label_ext,
+            unchecked_method_name: cmd_insert_debug_utils_label_ext_unchecked,
             signature: (
-                command_buffer: CommandBuffer,
-                scissor_count: u32,
-                p_scissors: *const Rect2D,
+                p_label_info: *const DebugUtilsLabelEXT,
             ),
-            takes_handle: false,
         },
         {
-            name: pfn_cmd_bind_vertex_buffers_2,
-            method_name: cmd_bind_vertex_buffers_2,
-            ptr: CmdBindVertexBuffers2,
+            method_name: cmd_write_buffer_marker_amd,
+            try_method_name: try_cmd_write_buffer_marker_amd,
+            unchecked_method_name: cmd_write_buffer_marker_amd_unchecked,
             signature: (
-                command_buffer: CommandBuffer,
-                first_binding: u32,
-                binding_count: u32,
-                p_buffers: *const Buffer,
-                p_offsets: *const DeviceSize,
-                p_sizes: *const DeviceSize,
-                p_strides: *const DeviceSize,
+                pipeline_stage: PipelineStageFlagBits,
+                dst_buffer: Buffer,
+                dst_offset: DeviceSize,
+                marker: u32,
             ),
-            takes_handle: false,
         },
         {
-            name: pfn_cmd_set_depth_test_enable,
-            method_name: cmd_set_depth_test_enable,
-            ptr: CmdSetDepthTestEnable,
+            method_name: cmd_begin_render_pass_2,
+            try_method_name: try_cmd_begin_render_pass_2,
+            unchecked_method_name: cmd_begin_render_pass_2_unchecked,
             signature: (
-                command_buffer: CommandBuffer,
-                depth_test_enable: Bool32,
+                p_render_pass_begin: *const RenderPassBeginInfo,
+                p_subpass_begin_info: *const SubpassBeginInfo,
             ),
-            takes_handle: false,
         },
         {
-            name: pfn_cmd_set_depth_write_enable,
-            method_name: cmd_set_depth_write_enable,
-            ptr: CmdSetDepthWriteEnable,
+            method_name: cmd_next_subpass_2,
+            try_method_name: try_cmd_next_subpass_2,
+            unchecked_method_name: cmd_next_subpass_2_unchecked,
             signature: (
-                command_buffer: CommandBuffer,
-                depth_write_enable: Bool32,
+                p_subpass_begin_info: *const SubpassBeginInfo,
+                p_subpass_end_info: *const SubpassEndInfo,
             ),
-            takes_handle: false,
         },
         {
-            name: pfn_cmd_set_depth_compare_op,
-            method_name: cmd_set_depth_compare_op,
-            ptr: CmdSetDepthCompareOp,
+            method_name: cmd_end_render_pass_2,
+            try_method_name: try_cmd_end_render_pass_2,
+            unchecked_method_name: cmd_end_render_pass_2_unchecked,
             signature: (
-                command_buffer: CommandBuffer,
-                depth_compare_op: CompareOp,
+                p_subpass_end_info: *const SubpassEndInfo,
             ),
-            takes_handle: false,
         },
         {
-            name: pfn_cmd_set_depth_bounds_test_enable,
-            method_name: cmd_set_depth_bounds_test_enable,
-            ptr: CmdSetDepthBoundsTestEnable,
+            method_name: cmd_draw_indirect_count,
+            try_method_name: try_cmd_draw_indirect_count,
+            unchecked_method_name: cmd_draw_indirect_count_unchecked,
             signature: (
-                command_buffer: CommandBuffer,
-                depth_bounds_test_enable: Bool32,
+                buffer: Buffer,
+                offset: DeviceSize,
+                count_buffer: Buffer,
+                count_buffer_offset: DeviceSize,
+                max_draw_count: u32,
+                stride: u32,
             ),
-            takes_handle: false,
         },
         {
-            name: pfn_cmd_set_stencil_test_enable,
-            method_name: cmd_set_stencil_test_enable,
-            ptr: CmdSetStencilTestEnable,
+            method_name: cmd_draw_indexed_indirect_count,
+            try_method_name: try_cmd_draw_indexed_indirect_count,
+            unchecked_method_name: cmd_draw_indexed_indirect_count_unchecked,
             signature: (
-                command_buffer: CommandBuffer,
-                stencil_test_enable: Bool32,
+                buffer: Buffer,
+                offset: DeviceSize,
+                count_buffer: Buffer,
+                count_buffer_offset: DeviceSize,
+                max_draw_count: u32,
+                stride: u32,
             ),
-            takes_handle: false,
         },
         {
-            name: pfn_cmd_set_stencil_op,
-            method_name: cmd_set_stencil_op,
-            ptr: CmdSetStencilOp,
+            method_name: cmd_set_checkpoint_nv,
+            try_method_name: try_cmd_set_checkpoint_nv,
+            unchecked_method_name: cmd_set_checkpoint_nv_unchecked,
             signature: (
-                command_buffer: CommandBuffer,
-                face_mask: StencilFaceFlags,
-                fail_op: StencilOp,
-                pass_op: StencilOp,
-                depth_fail_op: StencilOp,
-                compare_op: CompareOp,
+                p_checkpoint_marker: *const c_void,
             ),
-            takes_handle: false,
         },
         {
-            name: pfn_cmd_set_patch_control_points_ext,
-            method_name: cmd_set_patch_control_points_ext,
-            ptr: CmdSetPatchControlPointsEXT,
+            method_name: cmd_bind_transform_feedback_buffers_ext,
+            try_method_name: try_cmd_bind_transform_feedback_buffers_ext,
+            unchecked_method_name: cmd_bind_transform_feedback_buffers_ext_unchecked,
             signature: (
-                command_buffer: CommandBuffer,
-                patch_control_points: u32,
+                first_binding: u32,
+                binding_count: u32,
+                p_buffers: *const Buffer,
+                p_offsets: *const DeviceSize,
+                p_sizes: *const DeviceSize,
             ),
-            takes_handle: false,
         },
         {
-            name: pfn_cmd_set_rasterizer_discard_enable,
-            method_name: cmd_set_rasterizer_discard_enable,
-            ptr: CmdSetRasterizerDiscardEnable,
+            method_name: cmd_begin_transform_feedback_ext,
+            try_method_name: try_cmd_begin_transform_feedback_ext,
+            unchecked_method_name: cmd_begin_transform_feedback_ext_unchecked,
             signature: (
-                command_buffer: CommandBuffer,
-                rasterizer_discard_enable: Bool32,
+                first_counter_buffer: u32,
+                counter_buffer_count: u32,
+                p_counter_buffers: *const Buffer,
+                p_counter_buffer_offsets: *const DeviceSize,
             ),
-            takes_handle: false,
         },
         {
-            name: pfn_cmd_set_depth_bias_enable,
-            method_name: cmd_set_depth_bias_enable,
-            ptr: CmdSetDepthBiasEnable,
+            method_name: cmd_end_transform_feedback_ext,
+            try_method_name: try_cmd_end_transform_feedback_ext,
+            unchecked_method_name: cmd_end_transform_feedback_ext_unchecked,
             signature: (
-                command_buffer: CommandBuffer,
-                depth_bias_enable: Bool32,
+                first_counter_buffer: u32,
+                counter_buffer_count: u32,
+                p_counter_buffers: *const Buffer,
+                p_counter_buffer_offsets: *const DeviceSize,
             ),
-            takes_handle: false,
         },
         {
-            name: pfn_cmd_set_logic_op_ext,
-            method_name: cmd_set_logic_op_ext,
-            ptr: CmdSetLogicOpEXT,
+            method_name: cmd_begin_query_indexed_ext,
+            try_method_name: try_cmd_begin_query_indexed_ext,
+            unchecked_method_name: cmd_begin_query_indexed_ext_unchecked,
             signature: (
-                command_buffer: CommandBuffer,
-                logic_op: LogicOp,
+                query_pool: QueryPool,
+                query: u32,
+                flags: QueryControlFlags,
+                index: u32,
             ),
-            takes_handle: false,
         },
         {
-            name: pfn_cmd_set_primitive_restart_enable,
-            method_name: cmd_set_primitive_restart_enable,
-            ptr: CmdSetPrimitiveRestartEnable,
+            method_name: cmd_end_query_indexed_ext,
+            try_method_name: try_cmd_end_query_indexed_ext,
+            unchecked_method_name: cmd_end_query_indexed_ext_unchecked,
             signature: (
-                command_buffer: CommandBuffer,
-                primitive_restart_enable: Bool32,
+                query_pool: QueryPool,
+                query: u32,
+                index: u32,
             ),
-            takes_handle: false,
         },
         {
-            name: pfn_create_private_data_slot,
-            method_name: create_private_data_slot,
-            ptr: CreatePrivateDataSlot,
+            method_name: cmd_draw_indirect_byte_count_ext,
+            try_method_name: try_cmd_draw_indirect_byte_count_ext,
+            unchecked_method_name: cmd_draw_indirect_byte_count_ext_unchecked,
             signature: (
-                p_create_info: *const PrivateDataSlotCreateInfo,
-                p_allocator: *const AllocationCallbacks,
-                p_private_data_slot: *mut PrivateDataSlot,
-            ) -> Result,
-            takes_handle: true,
+                instance_count: u32,
+                first_instance: u32,
+                counter_buffer: Buffer,
+                counter_buffer_offset: DeviceSize,
+                counter_offset: u32,
+                vertex_stride: u32,
+            ),
         },
         {
-            name: pfn_cmd_set_tessellation_domain_origin_ext,
-            method_name: cmd_set_tessellation_domain_origin_ext,
-            ptr: CmdSetTessellationDomainOriginEXT,
+            method_name: cmd_set_exclusive_scissor_nv,
+            try_method_name: try_cmd_set_exclusive_scissor_nv,
+            unchecked_method_name: cmd_set_exclusive_scissor_nv_unchecked,
             signature: (
-                command_buffer: CommandBuffer,
-                domain_origin: TessellationDomainOrigin,
+                first_exclusive_scissor: u32,
+                exclusive_scissor_count: u32,
+                p_exclusive_scissors: *const Rect2D,
             ),
-            takes_handle: false,
         },
         {
-            name: pfn_cmd_set_depth_clamp_enable_ext,
-            method_name: cmd_set_depth_clamp_enable_ext,
-            ptr: CmdSetDepthClampEnableEXT,
+            method_name: cmd_bind_shading_rate_image_nv,
+            try_method_name: try_cmd_bind_shading_rate_image_nv,
+            unchecked_method_name: cmd_bind_shading_rate_image_nv_unchecked,
             signature: (
-                command_buffer: CommandBuffer,
-                depth_clamp_enable: Bool32,
+                image_view: ImageView,
+                image_layout: ImageLayout,
             ),
-            takes_handle: false,
         },
         {
-            name: pfn_cmd_set_polygon_mode_ext,
-            method_name: cmd_set_polygon_mode_ext,
-            ptr: CmdSetPolygonModeEXT,
+            method_name: cmd_set_viewport_shading_rate_palette_nv,
+            try_method_name: try_cmd_set_viewport_shading_rate_palette_nv,
+            unchecked_method_name: cmd_set_viewport_shading_rate_palette_nv_unchecked,
             signature: (
-                command_buffer: CommandBuffer,
-                polygon_mode: PolygonMode,
+                first_viewport: u32,
+                viewport_count: u32,
+                p_shading_rate_palettes: *const ShadingRatePaletteNV,
             ),
-            takes_handle: false,
         },
         {
-            name: pfn_cmd_set_rasterization_samples_ext,
-            method_name: cmd_set_rasterization_samples_ext,
-            ptr: CmdSetRasterizationSamplesEXT,
+            method_name: cmd_set_coarse_sample_order_nv,
+            try_method_name: try_cmd_set_coarse_sample_order_nv,
+            unchecked_method_name: cmd_set_coarse_sample_order_nv_unchecked,
             signature: (
-                command_buffer: CommandBuffer,
-                rasterization_samples: SampleCountFlagBits,
+                sample_order_type: CoarseSampleOrderTypeNV,
+                custom_sample_order_count: u32,
+                p_custom_sample_orders: *const CoarseSampleOrderCustomNV,
             ),
-            takes_handle: false,
         },
         {
-            name: pfn_cmd_set_sample_mask_ext,
-            method_name: cmd_set_sample_mask_ext,
-            ptr: CmdSetSampleMaskEXT,
+            method_name: cmd_draw_mesh_tasks_nv,
+            try_method_name: try_cmd_draw_mesh_tasks_nv,
+            unchecked_method_name: cmd_draw_mesh_tasks_nv_unchecked,
             signature: (
-                command_buffer: CommandBuffer,
-                samples: SampleCountFlagBits,
-                p_sample_mask: *const SampleMask,
+                task_count: u32,
+                first_task: u32,
             ),
-            takes_handle: false,
         },
         {
-            name: pfn_cmd_set_alpha_to_coverage_enable_ext,
-            method_name: cmd_set_alpha_to_coverage_enable_ext,
-            ptr: CmdSetAlphaToCoverageEnableEXT,
+            method_name: cmd_draw_mesh_tasks_indirect_nv,
+            try_method_name: try_cmd_draw_mesh_tasks_indirect_nv,
+            unchecked_method_name: cmd_draw_mesh_tasks_indirect_nv_unchecked,
             signature: (
-                command_buffer: CommandBuffer,
-                alpha_to_coverage_enable: Bool32,
+                buffer: Buffer,
+                offset: DeviceSize,
+                draw_count: u32,
+                stride: u32,
             ),
-            takes_handle: false,
         },
         {
-            name: pfn_cmd_set_alpha_to_one_enable_ext,
-            method_name: cmd_set_alpha_to_one_enable_ext,
-            ptr: CmdSetAlphaToOneEnableEXT,
-            signature: (
-                command_buffer: CommandBuffer,
-                alpha_to_one_enable: Bool32,
+            method_name: cmd_draw_mesh_tasks_indirect_count_nv,
+            try_method_name: try_cmd_draw_mesh_tasks_indirect_count_nv,
+            unchecked_method_name: cmd_draw_mesh_tasks_indirect_count_nv_unchecked,
+            signature: (
+                buffer: Buffer,
+                offset: DeviceSize,
+                count_buffer: Buffer,
+                count_buffer_offset: DeviceSize,
+                max_draw_count: u32,
+                stride: u32,
             ),
-            takes_handle: false,
         },
         {
-            name: pfn_cmd_set_logic_op_enable_ext,
-            method_name: cmd_set_logic_op_enable_ext,
-            ptr: CmdSetLogicOpEnableEXT,
+            method_name: cmd_draw_mesh_tasks_ext,
+            try_method_name: try_cmd_draw_mesh_tasks_ext,
+            unchecked_method_name: cmd_draw_mesh_tasks_ext_unchecked,
             signature: (
-                command_buffer: CommandBuffer,
-                logic_op_enable: Bool32,
+                group_count_x: u32,
+                group_count_y: u32,
+                group_count_z: u32,
             ),
-            takes_handle: false,
         },
         {
-            name: pfn_cmd_set_color_blend_enable_ext,
-            method_name: cmd_set_color_blend_enable_ext,
-            ptr: CmdSetColorBlendEnableEXT,
+            method_name: cmd_draw_mesh_tasks_indirect_ext,
+            try_method_name: try_cmd_draw_mesh_tasks_indirect_ext,
+            unchecked_method_name: cmd_draw_mesh_tasks_indirect_ext_unchecked,
             signature: (
-                command_buffer: CommandBuffer,
-                first_attachment: u32,
-                attachment_count: u32,
-                p_color_blend_enables: *const Bool32,
+                buffer: Buffer,
+                offset: DeviceSize,
+                draw_count: u32,
+                stride: u32,
             ),
-            takes_handle: false,
         },
         {
-            name: pfn_cmd_set_color_blend_equation_ext,
-            method_name: cmd_set_color_blend_equation_ext,
-            ptr: CmdSetColorBlendEquationEXT,
+            method_name: cmd_draw_mesh_tasks_indirect_count_ext,
+            try_method_name: try_cmd_draw_mesh_tasks_indirect_count_ext,
+            unchecked_method_name: cmd_draw_mesh_tasks_indirect_count_ext_unchecked,
             signature: (
-                command_buffer: CommandBuffer,
-                first_attachment: u32,
-                attachment_count: u32,
-                p_color_blend_equations: *const ColorBlendEquationEXT,
+                buffer: Buffer,
+                offset: DeviceSize,
+                count_buffer: Buffer,
+                count_buffer_offset: DeviceSize,
+                max_draw_count: u32,
+                stride: u32,
             ),
-            takes_handle: false,
         },
         {
-            name: pfn_cmd_set_color_write_mask_ext,
-            method_name: cmd_set_color_write_mask_ext,
-            ptr: CmdSetColorWriteMaskEXT,
+            method_name: cmd_bind_invocation_mask_huawei,
+            try_method_name: try_cmd_bind_invocation_mask_huawei,
+            unchecked_method_name: cmd_bind_invocation_mask_huawei_unchecked,
             signature: (
-                command_buffer: CommandBuffer,
-                first_attachment: u32,
-                attachment_count: u32,
-                p_color_write_masks: *const ColorComponentFlags,
+                image_view: ImageView,
+                image_layout: ImageLayout,
             ),
-            takes_handle: false,
         },
         {
-            name: pfn_cmd_set_rasterization_stream_ext,
-            method_name: cmd_set_rasterization_stream_ext,
-            ptr: CmdSetRasterizationStreamEXT,
+            method_name: cmd_copy_acceleration_structure_nv,
+            try_method_name: try_cmd_copy_acceleration_structure_nv,
+            unchecked_method_name: cmd_copy_acceleration_structure_nv_unchecked,
             signature: (
-                command_buffer: CommandBuffer,
-                rasterization_stream: u32,
+                dst: AccelerationStructureNV,
+                src: AccelerationStructureNV,
+                mode: CopyAccelerationStructureModeKHR,
             ),
-            takes_handle: false,
         },
         {
-            name: pfn_cmd_set_conservative_rasterization_mode_ext,
-            method_name: cmd_set_conservative_rasterization_mode_ext,
-            ptr: CmdSetConservativeRasterizationModeEXT,
+            method_name: cmd_copy_acceleration_structure_khr,
+            try_method_name: try_cmd_copy_acceleration_structure_khr,
+            unchecked_method_name: cmd_copy_acceleration_structure_khr_unchecked,
             signature: (
-                command_buffer: CommandBuffer,
-                conservative_rasterization_mode: ConservativeRasterizationModeEXT,
+                p_info: *const CopyAccelerationStructureInfoKHR,
             ),
-            takes_handle: false,
         },
         {
-            name: pfn_cmd_set_extra_primitive_overestimation_size_ext,
-            method_name: cmd_set_extra_primitive_overestimation_size_ext,
-            ptr: CmdSetExtraPrimitiveOverestimationSizeEXT,
+            method_name: cmd_copy_acceleration_structure_to_memory_khr,
+            try_method_name: try_cmd_copy_acceleration_structure_to_memory_khr,
+            unchecked_method_name: cmd_copy_acceleration_structure_to_memory_khr_unchecked,
             signature: (
-                command_buffer: CommandBuffer,
-                extra_primitive_overestimation_size: c_float,
+                p_info: *const CopyAccelerationStructureToMemoryInfoKHR,
             ),
-            takes_handle: false,
         },
         {
-            name: pfn_cmd_set_depth_clip_enable_ext,
-            method_name: cmd_set_depth_clip_enable_ext,
-            ptr: CmdSetDepthClipEnableEXT,
+            method_name: cmd_copy_memory_to_acceleration_structure_khr,
+            try_method_name: try_cmd_copy_memory_to_acceleration_structure_khr,
+            unchecked_method_name: cmd_copy_memory_to_acceleration_structure_khr_unchecked,
             signature: (
-                command_buffer: CommandBuffer,
-                depth_clip_enable: Bool32,
+                p_info: *const CopyMemoryToAccelerationStructureInfoKHR,
             ),
-            takes_handle: false,
         },
         {
-            name: pfn_cmd_set_sample_locations_enable_ext,
-            method_name: cmd_set_sample_locations_enable_ext,
-            ptr: CmdSetSampleLocationsEnableEXT,
+            method_name: cmd_write_acceleration_structures_properties_khr,
+            try_method_name: try_cmd_write_acceleration_structures_properties_khr,
+            unchecked_method_name: cmd_write_acceleration_structures_properties_khr_unchecked,
             signature: (
-                command_buffer: CommandBuffer,
-                sample_locations_enable: Bool32,
+                acceleration_structure_count: u32,
+                p_acceleration_structures: *const AccelerationStructureKHR,
+                query_type: QueryType,
+                query_pool: QueryPool,
+                first_query: u32,
             ),
-            takes_handle: false,
         },
         {
-            name: pfn_cmd_set_color_blend_advanced_ext,
-            method_name: cmd_set_color_blend_advanced_ext,
-            ptr: CmdSetColorBlendAdvancedEXT,
+            method_name: cmd_write_acceleration_structures_properties_nv,
+            try_method_name: try_cmd_write_acceleration_structures_properties_nv,
+            unchecked_method_name: cmd_write_acceleration_structures_properties_nv_unchecked,
             signature: (
-                command_buffer: CommandBuffer,
-                first_attachment: u32,
-                attachment_count: u32,
-                p_color_blend_advanced: *const ColorBlendAdvancedEXT,
+                acceleration_structure_count: u32,
+                p_acceleration_structures: *const AccelerationStructureNV,
+                query_type: QueryType,
+                query_pool: QueryPool,
+                first_query: u32,
             ),
-            takes_handle: false,
         },
         {
-            name: pfn_cmd_set_provoking_vertex_mode_ext,
-            method_name: cmd_set_provoking_vertex_mode_ext,
-            ptr: CmdSetProvokingVertexModeEXT,
+            method_name: cmd_build_acceleration_structure_nv,
+            try_method_name: try_cmd_build_acceleration_structure_nv,
+            unchecked_method_name: cmd_build_acceleration_structure_nv_unchecked,
             signature: (
-                command_buffer: CommandBuffer,
-                provoking_vertex_mode: ProvokingVertexModeEXT,
+                p_info: *const AccelerationStructureInfoNV,
+                instance_data: Buffer,
+                instance_offset: DeviceSize,
+                update: Bool32,
+                dst: AccelerationStructureNV,
+                src: AccelerationStructureNV,
+                scratch: Buffer,
+                scratch_offset: DeviceSize,
             ),
-            takes_handle: false,
         },
         {
-            name: pfn_cmd_set_line_rasterization_mode_ext,
-            method_name: cmd_set_line_rasterization_mode_ext,
-            ptr: CmdSetLineRasterizationModeEXT,
+            method_name: cmd_trace_rays_khr,
+            try_method_name: try_cmd_trace_rays_khr,
+            unchecked_method_name: cmd_trace_rays_khr_unchecked,
             signature: (
-                command_buffer: CommandBuffer,
-                line_rasterization_mode: LineRasterizationModeEXT,
+                p_raygen_shader_binding_table: *const StridedDeviceAddressRegionKHR,
+                p_miss_shader_binding_table: *const StridedDeviceAddressRegionKHR,
+                p_hit_shader_binding_table: *const StridedDeviceAddressRegionKHR,
+                p_callable_shader_binding_table: *const StridedDeviceAddressRegionKHR,
+                width: u32,
+                height: u32,
+                depth: u32,
             ),
-            takes_handle: false,
         },
         {
-            name: pfn_cmd_set_line_stipple_enable_ext,
-            method_name: cmd_set_line_stipple_enable_ext,
-            ptr: CmdSetLineStippleEnableEXT,
+            method_name: cmd_trace_rays_nv,
+            try_method_name: try_cmd_trace_rays_nv,
+            unchecked_method_name: cmd_trace_rays_nv_unchecked,
             signature: (
-                command_buffer: CommandBuffer,
-                stippled_line_enable: Bool32,
+                raygen_shader_binding_table_buffer: Buffer,
+                raygen_shader_binding_offset: DeviceSize,
+                miss_shader_binding_table_buffer: Buffer,
+                miss_shader_binding_offset: DeviceSize,
+                miss_shader_binding_stride: DeviceSize,
+                hit_shader_binding_table_buffer: Buffer,
+                hit_shader_binding_offset: DeviceSize,
+                hit_shader_binding_stride: DeviceSize,
+                callable_shader_binding_table_buffer: Buffer,
+                callable_shader_binding_offset: DeviceSize,
+                callable_shader_binding_stride: DeviceSize,
+                width: u32,
+                height: u32,
+                depth: u32,
             ),
-            takes_handle: false,
         },
         {
-            name: pfn_cmd_set_depth_clip_negative_one_to_one_ext,
-            method_name: cmd_set_depth_clip_negative_one_to_one_ext,
-            ptr: CmdSetDepthClipNegativeOneToOneEXT,
+            method_name: cmd_trace_rays_indirect_khr,
+            try_method_name: try_cmd_trace_rays_indirect_khr,
+            unchecked_method_name: cmd_trace_rays_indirect_khr_unchecked,
             signature: (
-                command_buffer: CommandBuffer,
-                negative_one_to_one: Bool32,
+                p_raygen_shader_binding_table: *const StridedDeviceAddressRegionKHR,
+                p_miss_shader_binding_table: *const StridedDeviceAddressRegionKHR,
+                p_hit_shader_binding_table: *const StridedDeviceAddressRegionKHR,
+                p_callable_shader_binding_table: *const StridedDeviceAddressRegionKHR,
+                indirect_device_address: DeviceAddress,
             ),
-            takes_handle: false,
         },
         {
-            name: pfn_cmd_set_viewport_w_scaling_enable_nv,
-            method_name: cmd_set_viewport_w_scaling_enable_nv,
-            ptr: CmdSetViewportWScalingEnableNV,
+            method_name: cmd_trace_rays_indirect_2_khr,
+            try_method_name: try_cmd_trace_rays_indirect_2_khr,
+            unchecked_method_name: cmd_trace_rays_indirect_2_khr_unchecked,
             signature: (
-                command_buffer: CommandBuffer,
-                viewport_w_scaling_enable: Bool32,
+                indirect_device_address: DeviceAddress,
             ),
-            takes_handle: false,
         },
         {
-            name: pfn_cmd_set_viewport_swizzle_nv,
-            method_name: cmd_set_viewport_swizzle_nv,
-            ptr: CmdSetViewportSwizzleNV,
+            method_name: cmd_set_ray_tracing_pipeline_stack_size_khr,
+            try_method_name: try_cmd_set_ray_tracing_pipeline_stack_size_khr,
+            unchecked_method_name: cmd_set_ray_tracing_pipeline_stack_size_khr_unchecked,
             signature: (
-                command_buffer: CommandBuffer,
-                first_viewport: u32,
-                viewport_count: u32,
-                p_viewport_swizzles: *const ViewportSwizzleNV,
+                pipeline_stack_size: u32,
             ),
-            takes_handle: false,
         },
         {
-            name: pfn_cmd_set_coverage_to_color_enable_nv,
-            method_name: cmd_set_coverage_to_color_enable_nv,
-            ptr: CmdSetCoverageToColorEnableNV,
+            method_name: cmd_set_performance_marker_intel,
+            try_method_name: try_cmd_set_performance_marker_intel,
+            unchecked_method_name: cmd_set_performance_marker_intel_unchecked,
             signature: (
-                command_buffer: CommandBuffer,
-                coverage_to_color_enable: Bool32,
-            ),
-            takes_handle: false,
+                p_marker_info: *const PerformanceMarkerInfoINTEL,
+            ) -> Result,
         },
         {
-            name: pfn_cmd_set_coverage_to_color_location_nv,
-            method_name: cmd_set_coverage_to_color_location_nv,
-            ptr: CmdSetCoverageToColorLocationNV,
+            method_name: cmd_set_performance_stream_marker_intel,
+            try_method_name: try_cmd_set_performance_stream_marker_intel,
+            unchecked_method_name: cmd_set_performance_stream_marker_intel_unchecked,
             signature: (
-                command_buffer: CommandBuffer,
-                coverage_to_color_location: u32,
-            ),
-            takes_handle: false,
+                p_marker_info: *const PerformanceStreamMarkerInfoINTEL,
+            ) -> Result,
         },
         {
-            name: pfn_cmd_set_coverage_modulation_mode_nv,
-            method_name: cmd_set_coverage_modulation_mode_nv,
-            ptr: CmdSetCoverageModulationModeNV,
+            method_name: cmd_set_performance_override_intel,
+            try_method_name: try_cmd_set_performance_override_intel,
+            unchecked_method_name: cmd_set_performance_override_intel_unchecked,
             signature: (
-                command_buffer: CommandBuffer,
-                coverage_modulation_mode: CoverageModulationModeNV,
-            ),
-            takes_handle: false,
+                p_override_info: *const PerformanceOverrideInfoINTEL,
+            ) -> Result,
         },
         {
-            name: pfn_cmd_set_coverage_modulation_table_enable_nv,
-            method_name: cmd_set_coverage_modulation_table_enable_nv,
-            ptr: CmdSetCoverageModulationTableEnableNV,
+            method_name: cmd_set_line_stipple_ext,
+            try_method_name: try_cmd_set_line_stipple_ext,
+            unchecked_method_name: cmd_set_line_stipple_ext_unchecked,
             signature: (
-                command_buffer: CommandBuffer,
-                coverage_modulation_table_enable: Bool32,
+                line_stipple_factor: u32,
+                line_stipple_pattern: u16,
             ),
-            takes_handle: false,
         },
         {
-            name: pfn_cmd_set_coverage_modulation_table_nv,
-            method_name: cmd_set_coverage_modulation_table_nv,
-            ptr: CmdSetCoverageModulationTableNV,
+            method_name: cmd_build_acceleration_structures_khr,
+            try_method_name: try_cmd_build_acceleration_structures_khr,
+            unchecked_method_name: cmd_build_acceleration_structures_khr_unchecked,
             signature: (
-                command_buffer: CommandBuffer,
-                coverage_modulation_table_count: u32,
-                p_coverage_modulation_table: *const c_float,
+                info_count: u32,
+                p_infos: *const AccelerationStructureBuildGeometryInfoKHR,
+                pp_build_range_infos: *const *const AccelerationStructureBuildRangeInfoKHR,
             ),
-            takes_handle: false,
         },
         {
-            name: pfn_cmd_set_shading_rate_image_enable_nv,
-            method_name: cmd_set_shading_rate_image_enable_nv,
-            ptr: CmdSetShadingRateImageEnableNV,
+            method_name: cmd_build_acceleration_structures_indirect_khr,
+            try_method_name: try_cmd_build_acceleration_structures_indirect_khr,
+            unchecked_method_name: cmd_build_acceleration_structures_indirect_khr_unchecked,
             signature: (
-                command_buffer: CommandBuffer,
-                shading_rate_image_enable: Bool32,
+                info_count: u32,
+                p_infos: *const AccelerationStructureBuildGeometryInfoKHR,
+                p_indirect_device_addresses: *const DeviceAddress,
+                p_indirect_strides: *const u32,
+                pp_max_primitive_counts: *const *const u32,
             ),
-            takes_handle: false,
         },
         {
-            name: pfn_cmd_set_coverage_reduction_mode_nv,
-            method_name: cmd_set_coverage_reduction_mode_nv,
-            ptr: CmdSetCoverageReductionModeNV,
+            method_name: cmd_set_cull_mode,
+            try_method_name: try_cmd_set_cull_mode,
+            unchecked_method_name: cmd_set_cull_mode_unchecked,
             signature: (
-                command_buffer: CommandBuffer,
-                coverage_reduction_mode: CoverageReductionModeNV,
+                cull_mode: CullModeFlags,
             ),
-            takes_handle: false,
         },
         {
-            name: pfn_cmd_set_representative_fragment_test_enable_nv,
-            method_name: cmd_set_representative_fragment_test_enable_nv,
-            ptr: CmdSetRepresentativeFragmentTestEnableNV,
+            method_name: cmd_set_front_face,
+            try_method_name: try_cmd_set_front_face,
+            unchecked_method_name: cmd_set_front_face_unchecked,
             signature: (
-                command_buffer: CommandBuffer,
-                representative_fragment_test_enable: Bool32,
+                front_face: FrontFace,
             ),
-            takes_handle: false,
         },
         {
-            name: pfn_destroy_private_data_slot,
-            method_name: destroy_private_data_slot,
-            ptr: DestroyPrivateDataSlot,
+            method_name: cmd_set_primitive_topology,
+            try_method_name: try_cmd_set_primitive_topology,
+            unchecked_method_name: cmd_set_primitive_topology_unchecked,
             signature: (
-                private_data_slot: PrivateDataSlot,
-                p_allocator: *const AllocationCallbacks,
+                primitive_topology: PrimitiveTopology,
             ),
-            takes_handle: true,
         },
         {
-            name: pfn_set_private_data,
-            method_name: set_private_data,
-            ptr: SetPrivateData,
+            method_name: cmd_set_viewport_with_count,
+            try_method_name: try_cmd_set_viewport_with_count,
+            unchecked_method_name: cmd_set_viewport_with_count_unchecked,
             signature: (
-                object_type: ObjectType,
-                object_handle: u64,
-                private_data_slot: PrivateDataSlot,
-                data: u64,
-            ) -> Result,
-            takes_handle: true,
+                viewport_count: u32,
+                p_viewports: *const Viewport,
+            ),
         },
         {
-            name: pfn_get_private_data,
-            method_name: get_private_data,
-            ptr: GetPrivateData,
+            method_name: cmd_set_scissor_with_count,
+            try_method_name: try_cmd_set_scissor_with_count,
+            unchecked_method_name: cmd_set_scissor_with_count_unchecked,
             signature: (
-                object_type: ObjectType,
-                object_handle: u64,
-                private_data_slot: PrivateDataSlot,
-                p_data: *mut u64,
+                scissor_count: u32,
+                p_scissors: *const Rect2D,
             ),
-            takes_handle: true,
         },
         {
-            name: pfn_cmd_copy_buffer_2,
-            method_name: cmd_copy_buffer_2,
-            ptr: CmdCopyBuffer2,
+            method_name: cmd_bind_vertex_buffers_2,
+            try_method_name: try_cmd_bind_vertex_buffers_2,
+            unchecked_method_name: cmd_bind_vertex_buffers_2_unchecked,
             signature: (
-                command_buffer: CommandBuffer,
-                p_copy_buffer_info: *const CopyBufferInfo2,
+                first_binding: u32,
+                binding_count: u32,
+                p_buffers: *const Buffer,
+                p_offsets: *const DeviceSize,
+                p_sizes: *const DeviceSize,
+                p_strides: *const DeviceSize,
             ),
-            takes_handle: false,
         },
         {
-            name: pfn_cmd_copy_image_2,
-            method_name: cmd_copy_image_2,
-            ptr: CmdCopyImage2,
+            method_name: cmd_set_depth_test_enable,
+            try_method_name: try_cmd_set_depth_test_enable,
+            unchecked_method_name: cmd_set_depth_test_enable_unchecked,
             signature: (
-                command_buffer: CommandBuffer,
-                p_copy_image_info: *const CopyImageInfo2,
+                depth_test_enable: Bool32,
             ),
-            takes_handle: false,
         },
         {
-            name: pfn_cmd_blit_image_2,
-            method_name: cmd_blit_image_2,
-            ptr: CmdBlitImage2,
+            method_name: cmd_set_depth_write_enable,
+            try_method_name: try_cmd_set_depth_write_enable,
+            unchecked_method_name: cmd_set_depth_write_enable_unchecked,
             signature: (
-                command_buffer: CommandBuffer,
-                p_blit_image_info: *const BlitImageInfo2,
+                depth_write_enable: Bool32,
             ),
-            takes_handle: false,
         },
         {
-            name: pfn_cmd_copy_buffer_to_image_2,
-            method_name: cmd_copy_buffer_to_image_2,
-            ptr: CmdCopyBufferToImage2,
+            method_name: cmd_set_depth_compare_op,
+            try_method_name: try_cmd_set_depth_compare_op,
+            unchecked_method_name: cmd_set_depth_compare_op_unchecked,
             signature: (
-                command_buffer: CommandBuffer,
-                p_copy_buffer_to_image_info: *const CopyBufferToImageInfo2,
+                depth_compare_op: CompareOp,
             ),
-            takes_handle: false,
         },
         {
-            name: pfn_cmd_copy_image_to_buffer_2,
-            method_name: cmd_copy_image_to_buffer_2,
-            ptr: CmdCopyImageToBuffer2,
+            method_name: cmd_set_depth_bounds_test_enable,
+            try_method_name: try_cmd_set_depth_bounds_test_enable,
+            unchecked_method_name: cmd_set_depth_bounds_test_enable_unchecked,
             signature: (
-                command_buffer: CommandBuffer,
-                p_copy_image_to_buffer_info: *const CopyImageToBufferInfo2,
+                depth_bounds_test_enable: Bool32,
             ),
-            takes_handle: false,
         },
         {
-            name: pfn_cmd_resolve_image_2,
-            method_name: cmd_resolve_image_2,
-            ptr: CmdResolveImage2,
+            method_name: cmd_set_stencil_test_enable,
+            try_method_name: try_cmd_set_stencil_test_enable,
+            unchecked_method_name: cmd_set_stencil_test_enable_unchecked,
             signature: (
-                command_buffer: CommandBuffer,
-                p_resolve_image_info: *const ResolveImageInfo2,
+                stencil_test_enable: Bool32,
             ),
-            takes_handle: false,
         },
         {
-            name: pfn_cmd_set_fragment_shading_rate_khr,
-            method_name: cmd_set_fragment_shading_rate_khr,
-            ptr: CmdSetFragmentShadingRateKHR,
+            method_name: cmd_set_stencil_op,
+            try_method_name: try_cmd_set_stencil_op,
+            unchecked_method_name: cmd_set_stencil_op_unchecked,
             signature: (
-                command_buffer: CommandBuffer,
-                p_fragment_size: *const Extent2D,
-                combiner_ops: [FragmentShadingRateCombinerOpKHR; 2],
+                face_mask: StencilFaceFlags,
+                fail_op: StencilOp,
+                pass_op: StencilOp,
+                depth_fail_op: StencilOp,
+                compare_op: CompareOp,
             ),
-            takes_handle: false,
         },
         {
-            name: pfn_cmd_set_fragment_shading_rate_enum_nv,
-            method_name: cmd_set_fragment_shading_rate_enum_nv,
-            ptr: CmdSetFragmentShadingRateEnumNV,
+            method_name: cmd_set_patch_control_points_ext,
+            try_method_name: try_cmd_set_patch_control_points_ext,
+            unchecked_method_name: cmd_set_patch_control_points_ext_unchecked,
             signature: (
-                command_buffer: CommandBuffer,
-                shading_rate: FragmentShadingRateNV,
-                combiner_ops: [FragmentShadingRateCombinerOpKHR; 2],
+                patch_control_points: u32,
             ),
-            takes_handle: false,
         },
         {
-            name: pfn_get_acceleration_structure_build_sizes_khr,
-            method_name: get_acceleration_structure_build_sizes_khr,
-            ptr: GetAccelerationStructureBuildSizesKHR,
+            method_name: cmd_set_rasterizer_discard_enable,
+            try_method_name: try_cmd_set_rasterizer_discard_enable,
+            unchecked_method_name: cmd_set_rasterizer_discard_enable_unchecked,
             signature: (
-                build_type: AccelerationStructureBuildTypeKHR,
-                p_build_info: *const AccelerationStructureBuildGeometryInfoKHR,
-                p_max_primitive_counts: *const u32,
-                p_size_info: *mut AccelerationStructureBuildSizesInfoKHR,
+                rasterizer_discard_enable: Bool32,
             ),
-            takes_handle: true,
         },
         {
-            name: pfn_cmd_set_vertex_input_ext,
-            method_name: cmd_set_vertex_input_ext,
-            ptr: CmdSetVertexInputEXT,
+            method_name: cmd_set_depth_bias_enable,
+            try_method_name: try_cmd_set_depth_bias_enable,
+            unchecked_method_name: cmd_set_depth_bias_enable_unchecked,
             signature: (
-                command_buffer: CommandBuffer,
-                vertex_binding_description_count: u32,
-                p_vertex_binding_descriptions: *const VertexInputBindingDescription2EXT,
-                vertex_attribute_description_count: u32,
-                p_vertex_attribute_descriptions: *const VertexInputAttributeDescription2EXT,
+                depth_bias_enable: Bool32,
             ),
-            takes_handle: false,
         },
         {
-            name: pfn_cmd_set_color_write_enable_ext,
-            method_name: cmd_set_color_write_enable_ext,
-            ptr: CmdSetColorWriteEnableEXT,
+            method_name: cmd_set_logic_op_ext,
+            try_method_name: try_cmd_set_logic_op_ext,
+            unchecked_method_name: cmd_set_logic_op_ext_unchecked,
             signature: (
-                command_buffer: CommandBuffer,
-                attachment_count: u32,
-                p_color_write_enables: *const Bool32,
+                logic_op: LogicOp,
             ),
-            takes_handle: false,
         },
         {
-            name: pfn_cmd_set_event_2,
-            method_name: cmd_set_event_2,
-            ptr: CmdSetEvent2,
+            method_name: cmd_set_primitive_restart_enable,
+            try_method_name: try_cmd_set_primitive_restart_enable,
+            unchecked_method_name: cmd_set_primitive_restart_enable_unchecked,
             signature: (
-                command_buffer: CommandBuffer,
-                event: Event,
-                p_dependency_info: *const DependencyInfo,
+                primitive_restart_enable: Bool32,
             ),
-            takes_handle: false,
         },
         {
-            name: pfn_cmd_reset_event_2,
-            method_name: cmd_reset_event_2,
-            ptr: CmdResetEvent2,
+            method_name: cmd_set_tessellation_domain_origin_ext,
+            try_method_name: try_cmd_set_tessellation_domain_origin_ext,
+            unchecked_method_name: cmd_set_tessellation_domain_origin_ext_unchecked,
             signature: (
-                command_buffer: CommandBuffer,
-                event: Event,
-                stage_mask: PipelineStageFlags2,
+                domain_origin: TessellationDomainOrigin,
             ),
-            takes_handle: false,
         },
         {
-            name: pfn_cmd_wait_events_2,
-            method_name: cmd_wait_events_2,
-            ptr: CmdWaitEvents2,
+            method_name: cmd_set_depth_clamp_enable_ext,
+            try_method_name: try_cmd_set_depth_clamp_enable_ext,
+            unchecked_method_name: cmd_set_depth_clamp_enable_ext_unchecked,
             signature: (
-                command_buffer: CommandBuffer,
-                event_count: u32,
-                p_events: *const Event,
-                p_dependency_infos: *const DependencyInfo,
+                depth_clamp_enable: Bool32,
             ),
-            takes_handle: false,
         },
         {
-            name: pfn_cmd_pipeline_barrier_2,
-            method_name: cmd_pipeline_barrier_2,
-            ptr: CmdPipelineBarrier2,
+            method_name: cmd_set_polygon_mode_ext,
+            try_method_name: try_cmd_set_polygon_mode_ext,
+            unchecked_method_name: cmd_set_polygon_mode_ext_unchecked,
             signature: (
-                command_buffer: CommandBuffer,
-                p_dependency_info: *const DependencyInfo,
+                polygon_mode: PolygonMode,
             ),
-            takes_handle: false,
         },
         {
-            name: pfn_queue_submit_2,
-            method_name: queue_submit_2,
-            ptr: QueueSubmit2,
+            method_name: cmd_set_rasterization_samples_ext,
+            try_method_name: try_cmd_set_rasterization_samples_ext,
+            unchecked_method_name: cmd_set_rasterization_samples_ext_unchecked,
             signature: (
-                queue: Queue,
-                submit_count: u32,
-                p_submits: *const SubmitInfo2,
-                fence: Fence,
-            ) -> Result,
-            takes_handle: false,
+                rasterization_samples: SampleCountFlagBits,
+            ),
         },
         {
-            name: pfn_cmd_write_timestamp_2,
-            method_name: cmd_write_timestamp_2,
-            ptr: CmdWriteTimestamp2,
+            method_name: cmd_set_sample_mask_ext,
+            try_method_name: try_cmd_set_sample_mask_ext,
+            unchecked_method_name: cmd_set_sample_mask_ext_unchecked,
             signature: (
-                command_buffer: CommandBuffer,
-                stage: PipelineStageFlags2,
-                query_pool: QueryPool,
-                query: u32,
+                samples: SampleCountFlagBits,
+                p_sample_mask: *const SampleMask,
             ),
-            takes_handle: false,
         },
         {
-            name: pfn_cmd_write_buffer_marker_2_amd,
-            method_name: cmd_write_buffer_marker_2_amd,
-            ptr: CmdWriteBufferMarker2AMD,
+            method_name: cmd_set_alpha_to_coverage_enable_ext,
+            try_method_name: try_cmd_set_alpha_to_coverage_enable_ext,
+            unchecked_method_name: cmd_set_alpha_to_coverage_enable_ext_unchecked,
             signature: (
-                command_buffer: CommandBuffer,
-                stage: PipelineStageFlags2,
-                dst_buffer: Buffer,
-                dst_offset: DeviceSize,
-                marker: u32,
+                alpha_to_coverage_enable: Bool32,
             ),
-            takes_handle: false,
         },
         {
-            name: pfn_get_queue_checkpoint_data_2_nv,
-            method_name: get_queue_checkpoint_data_2_nv,
-            ptr: GetQueueCheckpointData2NV,
+            method_name: cmd_set_alpha_to_one_enable_ext,
+            try_method_name: try_cmd_set_alpha_to_one_enable_ext,
+            unchecked_method_name: cmd_set_alpha_to_one_enable_ext_unchecked,
             signature: (
-                queue: Queue,
-                p_checkpoint_data_count: *mut u32,
-                p_checkpoint_data: *mut CheckpointData2NV,
+                alpha_to_one_enable: Bool32,
             ),
-            takes_handle: false,
         },
         {
-            name: pfn_create_video_session_khr,
-            method_name: create_video_session_khr,
-            ptr: CreateVideoSessionKHR,
+            method_name: cmd_set_logic_op_enable_ext,
+            try_method_name: try_cmd_set_logic_op_enable_ext,
+            unchecked_method_name: cmd_set_logic_op_enable_ext_unchecked,
             signature: (
-                p_create_info: *const VideoSessionCreateInfoKHR,
-                p_allocator: *const AllocationCallbacks,
-                p_video_session: *mut VideoSessionKHR,
-            ) -> Result,
-            takes_handle: true,
+                logic_op_enable: Bool32,
+            ),
         },
         {
-            name: pfn_destroy_video_session_khr,
-            method_name: destroy_video_session_khr,
-            ptr: DestroyVideoSessionKHR,
+            method_name: cmd_set_color_blend_enable_ext,
+            try_method_name: try_cmd_set_color_blend_enable_ext,
+            unchecked_method_name: cmd_set_color_blend_enable_ext_unchecked,
             signature: (
-                video_session: VideoSessionKHR,
-                p_allocator: *const AllocationCallbacks,
+                first_attachment: u32,
+                attachment_count: u32,
+                p_color_blend_enables: *const Bool32,
             ),
-            takes_handle: true,
         },
         {
-            name: pfn_create_video_session_parameters_khr,
-            method_name: create_video_session_parameters_khr,
-            ptr: CreateVideoSessionParametersKHR,
+            method_name: cmd_set_color_blend_equation_ext,
+            try_method_name: try_cmd_set_color_blend_equation_ext,
+            unchecked_method_name: cmd_set_color_blend_equation_ext_unchecked,
             signature: (
-                p_create_info: *const VideoSessionParametersCreateInfoKHR,
-                p_allocator: *const AllocationCallbacks,
-                p_video_session_parameters: *mut VideoSessionParametersKHR,
-            ) -> Result,
-            takes_handle: true,
+                first_attachment: u32,
+                attachment_count: u32,
+                p_color_blend_equations: *const ColorBlendEquationEXT,
+            ),
         },
         {
-            name: pfn_update_video_session_parameters_khr,
-            method_name: update_video_session_parameters_khr,
-            ptr: UpdateVideoSessionParametersKHR,
+            method_name: cmd_set_color_write_mask_ext,
+            try_method_name: try_cmd_set_color_write_mask_ext,
+            unchecked_method_name: cmd_set_color_write_mask_ext_unchecked,
             signature: (
-                video_session_parameters: VideoSessionParametersKHR,
-                p_update_info: *const VideoSessionParametersUpdateInfoKHR,
-            ) -> Result,
-            takes_handle: true,
+                first_attachment: u32,
+                attachment_count: u32,
+                p_color_write_masks: *const ColorComponentFlags,
+            ),
         },
         {
-            name: pfn_destroy_video_session_parameters_khr,
-            method_name: destroy_video_session_parameters_khr,
-            ptr: DestroyVideoSessionParametersKHR,
+            method_name: cmd_set_rasterization_stream_ext,
+            try_method_name: try_cmd_set_rasterization_stream_ext,
+            unchecked_method_name: cmd_set_rasterization_stream_ext_unchecked,
             signature: (
-                video_session_parameters: VideoSessionParametersKHR,
-                p_allocator: *const AllocationCallbacks,
+                rasterization_stream: u32,
             ),
-            takes_handle: true,
         },
         {
-            name: pfn_get_video_session_memory_requirements_khr,
-            method_name: get_video_session_memory_requirements_khr,
-            ptr: GetVideoSessionMemoryRequirementsKHR,
+            method_name: cmd_set_conservative_rasterization_mode_ext,
+            try_method_name: try_cmd_set_conservative_rasterization_mode_ext,
+            unchecked_method_name: cmd_set_conservative_rasterization_mode_ext_unchecked,
             signature: (
-                video_session: VideoSessionKHR,
-                p_memory_requirements_count: *mut u32,
-                p_memory_requirements: *mut VideoSessionMemoryRequirementsKHR,
-            ) -> Result,
-            takes_handle: true,
+                conservative_rasterization_mode: ConservativeRasterizationModeEXT,
+            ),
         },
         {
-            name: pfn_bind_video_session_memory_khr,
-            method_name: bind_video_session_memory_khr,
-            ptr: BindVideoSessionMemoryKHR,
+            method_name: cmd_set_extra_primitive_overestimation_size_ext,
+            try_method_name: try_cmd_set_extra_primitive_overestimation_size_ext,
+            unchecked_method_name: cmd_set_extra_primitive_overestimation_size_ext_unchecked,
             signature: (
-                video_session: VideoSessionKHR,
-                bind_session_memory_info_count: u32,
-                p_bind_session_memory_infos: *const BindVideoSessionMemoryInfoKHR,
-            ) -> Result,
-            takes_handle: true,
+                extra_primitive_overestimation_size: c_float,
+            ),
         },
         {
-            name: pfn_cmd_decode_video_khr,
-            method_name: cmd_decode_video_khr,
-            ptr: CmdDecodeVideoKHR,
+            method_name: cmd_set_depth_clip_enable_ext,
+            try_method_name: try_cmd_set_depth_clip_enable_ext,
+            unchecked_method_name: cmd_set_depth_clip_enable_ext_unchecked,
             signature: (
-                command_buffer: CommandBuffer,
-                p_decode_info: *const VideoDecodeInfoKHR,
+                depth_clip_enable: Bool32,
             ),
-            takes_handle: false,
         },
         {
-            name: pfn_cmd_begin_video_coding_khr,
-            method_name: cmd_begin_video_coding_khr,
-            ptr: CmdBeginVideoCodingKHR,
+            method_name: cmd_set_sample_locations_enable_ext,
+            try_method_name: try_cmd_set_sample_locations_enable_ext,
+            unchecked_method_name: cmd_set_sample_locations_enable_ext_unchecked,
             signature: (
-                command_buffer: CommandBuffer,
-                p_begin_info: *const VideoBeginCodingInfoKHR,
+                sample_locations_enable: Bool32,
             ),
-            takes_handle: false,
         },
         {
-            name: pfn_cmd_control_video_coding_khr,
-            method_name: cmd_control_video_coding_khr,
-            ptr: CmdControlVideoCodingKHR,
+            method_name: cmd_set_color_blend_advanced_ext,
+            try_method_name: try_cmd_set_color_blend_advanced_ext,
+            unchecked_method_name: cmd_set_color_blend_advanced_ext_unchecked,
             signature: (
-                command_buffer: CommandBuffer,
-                p_coding_control_info: *const VideoCodingControlInfoKHR,
+                first_attachment: u32,
+                attachment_count: u32,
+                p_color_blend_advanced: *const ColorBlendAdvancedEXT,
             ),
-            takes_handle: false,
         },
         {
-            name: pfn_cmd_end_video_coding_khr,
-            method_name: cmd_end_video_coding_khr,
-            ptr: CmdEndVideoCodingKHR,
+            method_name: cmd_set_provoking_vertex_mode_ext,
+            try_method_name: try_cmd_set_provoking_vertex_mode_ext,
+            unchecked_method_name: cmd_set_provoking_vertex_mode_ext_unchecked,
             signature: (
-                command_buffer: CommandBuffer,
-                p_end_coding_info: *const VideoEndCodingInfoKHR,
+                provoking_vertex_mode: ProvokingVertexModeEXT,
             ),
-            takes_handle: false,
         },
         {
-            name: pfn_cmd_encode_video_khr,
-            method_name: cmd_encode_video_khr,
-            ptr: CmdEncodeVideoKHR,
+            method_name: cmd_set_line_rasterization_mode_ext,
+            try_method_name: try_cmd_set_line_rasterization_mode_ext,
+            unchecked_method_name: cmd_set_line_rasterization_mode_ext_unchecked,
             signature: (
-                command_buffer: CommandBuffer,
-                p_encode_info: *const VideoEncodeInfoKHR,
+                line_rasterization_mode: LineRasterizationModeEXT,
             ),
-            takes_handle: false,
         },
         {
-            name: pfn_create_cu_module_nvx,
-            method_name: create_cu_module_nvx,
-            ptr: CreateCuModuleNVX,
+            method_name: cmd_set_line_stipple_enable_ext,
+            try_method_name: try_cmd_set_line_stipple_enable_ext,
+            unchecked_method_name: cmd_set_line_stipple_enable_ext_unchecked,
             signature: (
-                p_create_info: *const CuModuleCreateInfoNVX,
-                p_allocator: *const AllocationCallbacks,
-                p_module: *mut CuModuleNVX,
-            ) -> Result,
-            takes_handle: true,
+                stippled_line_enable: Bool32,
+            ),
         },
         {
-            name: pfn_create_cu_function_nvx,
-            method_name: create_cu_function_nvx,
-            ptr: CreateCuFunctionNVX,
+            method_name: cmd_set_depth_clip_negative_one_to_one_ext,
+            try_method_name: try_cmd_set_depth_clip_negative_one_to_one_ext,
+            unchecked_method_name: cmd_set_depth_clip_negative_one_to_one_ext_unchecked,
             signature: (
-                p_create_info: *const CuFunctionCreateInfoNVX,
-                p_allocator: *const AllocationCallbacks,
-                p_function: *mut CuFunctionNVX,
-            ) -> Result,
-            takes_handle: true,
+                negative_one_to_one: Bool32,
+            ),
         },
         {
-            name: pfn_destroy_cu_module_nvx,
-            method_name: destroy_cu_module_nvx,
-            ptr: DestroyCuModuleNVX,
+            method_name: cmd_set_viewport_w_scaling_enable_nv,
+            try_method_name: try_cmd_set_viewport_w_scaling_enable_nv,
+            unchecked_method_name: cmd_set_viewport_w_scaling_enable_nv_unchecked,
             signature: (
-                module: CuModuleNVX,
-                p_allocator: *const AllocationCallbacks,
+                viewport_w_scaling_enable: Bool32,
             ),
-            takes_handle: true,
         },
         {
-            name: pfn_destroy_cu_function_nvx,
-            method_name: destroy_cu_function_nvx,
-            ptr: DestroyCuFunctionNVX,
+            method_name: cmd_set_viewport_swizzle_nv,
+            try_method_name: try_cmd_set_viewport_swizzle_nv,
+            unchecked_method_name: cmd_set_viewport_swizzle_nv_unchecked,
             signature: (
-                function: CuFunctionNVX,
-                p_allocator: *const AllocationCallbacks,
+                first_viewport: u32,
+                viewport_count: u32,
+                p_viewport_swizzles: *const ViewportSwizzleNV,
             ),
-            takes_handle: true,
         },
         {
-            name: pfn_cmd_cu_launch_kernel_nvx,
-            method_name: cmd_cu_launch_kernel_nvx,
-            ptr: CmdCuLaunchKernelNVX,
+            method_name: cmd_set_coverage_to_color_enable_nv,
+            try_method_name: try_cmd_set_coverage_to_color_enable_nv,
+            unchecked_method_name: cmd_set_coverage_to_color_enable_nv_unchecked,
             signature: (
-                command_buffer: CommandBuffer,
-                p_launch_info: *const CuLaunchInfoNVX,
+                coverage_to_color_enable: Bool32,
             ),
-            takes_handle: false,
         },
         {
-            name: pfn_set_device_memory_priority_ext,
-            method_name: set_device_memory_priority_ext,
-            ptr: SetDeviceMemoryPriorityEXT,
+            method_name: cmd_set_coverage_to_color_location_nv,
+            try_method_name: try_cmd_set_coverage_to_color_location_nv,
+            unchecked_method_name: cmd_set_coverage_to_color_location_nv_unchecked,
             signature: (
-                memory: DeviceMemory,
-                priority: c_float,
+                coverage_to_color_location: u32,
             ),
-            takes_handle: true,
         },
         {
-            name: pfn_wait_for_present_khr,
-            method_name: wait_for_present_khr,
-            ptr: WaitForPresentKHR,
+            method_name: cmd_set_coverage_modulation_mode_nv,
+            try_method_name: try_cmd_set_coverage_modulation_mode_nv,
+            unchecked_method_name: cmd_set_coverage_modulation_mode_nv_unchecked,
             signature: (
-                swapchain: SwapchainKHR,
-                present_id: u64,
-                timeout: u64,
-            ) -> Result,
-            takes_handle: true,
+                coverage_modulation_mode: CoverageModulationModeNV,
+            ),
         },
         {
-            name: pfn_create_buffer_collection_fuchsia,
-            method_name: create_buffer_collection_fuchsia,
-            ptr: CreateBufferCollectionFUCHSIA,
+            method_name: cmd_set_coverage_modulation_table_enable_nv,
+            try_method_name: try_cmd_set_coverage_modulation_table_enable_nv,
+            unchecked_method_name: cmd_set_coverage_modulation_table_enable_nv_unchecked,
             signature: (
-                p_create_info: *const BufferCollectionCreateInfoFUCHSIA,
-                p_allocator: *const AllocationCallbacks,
-                p_collection: *mut BufferCollectionFUCHSIA,
-            ) -> Result,
-            takes_handle: true,
+                coverage_modulation_table_enable: Bool32,
+            ),
         },
         {
-            name: pfn_set_buffer_collection_buffer_constraints_fuchsia,
-            method_name: set_buffer_collection_buffer_constraints_fuchsia,
-            ptr: SetBufferCollectionBufferConstraintsFUCHSIA,
+            method_name: cmd_set_coverage_modulation_table_nv,
+            try_method_name: try_cmd_set_coverage_modulation_table_nv,
+            unchecked_method_name: cmd_set_coverage_modulation_table_nv_unchecked,
             signature: (
-                collection: BufferCollectionFUCHSIA,
-                p_buffer_constraints_info: *const BufferConstraintsInfoFUCHSIA,
-            ) -> Result,
-            takes_handle: true,
+                coverage_modulation_table_count: u32,
+                p_coverage_modulation_table: *const c_float,
+            ),
         },
         {
-            name: pfn_set_buffer_collection_image_constraints_fuchsia,
-            method_name: set_buffer_collection_image_constraints_fuchsia,
-            ptr: SetBufferCollectionImageConstraintsFUCHSIA,
+            method_name: cmd_set_shading_rate_image_enable_nv,
+            try_method_name: try_cmd_set_shading_rate_image_enable_nv,
+            unchecked_method_name: cmd_set_shading_rate_image_enable_nv_unchecked,
             signature: (
-                collection: BufferCollectionFUCHSIA,
-                p_image_constraints_info: *const ImageConstraintsInfoFUCHSIA,
-            ) -> Result,
-            takes_handle: true,
+                shading_rate_image_enable: Bool32,
+            ),
         },
         {
-            name: pfn_destroy_buffer_collection_fuchsia,
-            method_name: destroy_buffer_collection_fuchsia,
-            ptr: DestroyBufferCollectionFUCHSIA,
+            method_name: cmd_set_coverage_reduction_mode_nv,
+            try_method_name: try_cmd_set_coverage_reduction_mode_nv,
+            unchecked_method_name: cmd_set_coverage_reduction_mode_nv_unchecked,
             signature: (
-                collection: BufferCollectionFUCHSIA,
-                p_allocator: *const AllocationCallbacks,
+                coverage_reduction_mode: CoverageReductionModeNV,
             ),
-            takes_handle: true,
         },
         {
-            name: pfn_get_buffer_collection_properties_fuchsia,
-            method_name: get_buffer_collection_properties_fuchsia,
-            ptr: GetBufferCollectionPropertiesFUCHSIA,
+            method_name: cmd_set_representative_fragment_test_enable_nv,
+            try_method_name: try_cmd_set_representative_fragment_test_enable_nv,
+            unchecked_method_name: cmd_set_representative_fragment_test_enable_nv_unchecked,
             signature: (
-                collection: BufferCollectionFUCHSIA,
-                p_properties: *mut BufferCollectionPropertiesFUCHSIA,
-            ) -> Result,
-            takes_handle: true,
+                representative_fragment_test_enable: Bool32,
+            ),
         },
         {
-            name: pfn_cmd_begin_rendering,
-            method_name: cmd_begin_rendering,
-            ptr: CmdBeginRendering,
+            method_name: cmd_copy_buffer_2,
+            try_method_name: try_cmd_copy_buffer_2,
+            unchecked_method_name: cmd_copy_buffer_2_unchecked,
             signature: (
-                command_buffer: CommandBuffer,
-                p_rendering_info: *const RenderingInfo,
+                p_copy_buffer_info: *const CopyBufferInfo2,
             ),
-            takes_handle: false,
         },
         {
-            name: pfn_cmd_end_rendering,
-            method_name: cmd_end_rendering,
-            ptr: CmdEndRendering,
+            method_name: cmd_copy_image_2,
+            try_method_name: try_cmd_copy_image_2,
+            unchecked_method_name: cmd_copy_image_2_unchecked,
             signature: (
-                command_buffer: CommandBuffer,
+                p_copy_image_info: *const CopyImageInfo2,
             ),
-            takes_handle: false,
         },
         {
-            name: pfn_get_descriptor_set_layout_host_mapping_info_valve,
-            method_name: get_descriptor_set_layout_host_mapping_info_valve,
-            ptr: GetDescriptorSetLayoutHostMappingInfoVALVE,
+            method_name: cmd_blit_image_2,
+            try_method_name: try_cmd_blit_image_2,
+            unchecked_method_name: cmd_blit_image_2_unchecked,
             signature: (
-                p_binding_reference: *const DescriptorSetBindingReferenceVALVE,
-                p_host_mapping: *mut DescriptorSetLayoutHostMappingInfoVALVE,
+                p_blit_image_info: *const BlitImageInfo2,
             ),
-            takes_handle: true,
         },
         {
-            name: pfn_get_descriptor_set_host_mapping_valve,
-            method_name: get_descriptor_set_host_mapping_valve,
-            ptr: GetDescriptorSetHostMappingVALVE,
+            method_name: cmd_copy_buffer_to_image_2,
+            try_method_name: try_cmd_copy_buffer_to_image_2,
+            unchecked_method_name: cmd_copy_buffer_to_image_2_unchecked,
             signature: (
-                descriptor_set: DescriptorSet,
-                pp_data: *mut *mut c_void,
+                p_copy_buffer_to_image_info: *const CopyBufferToImageInfo2,
             ),
-            takes_handle: true,
         },
         {
-            name: pfn_create_micromap_ext,
-            method_name: create_micromap_ext,
-            ptr: CreateMicromapEXT,
+            method_name: cmd_copy_image_to_buffer_2,
+            try_method_name: try_cmd_copy_image_to_buffer_2,
+            unchecked_method_name: cmd_copy_image_to_buffer_2_unchecked,
             signature: (
-                p_create_info: *const MicromapCreateInfoEXT,
-                p_allocator: *const AllocationCallbacks,
-                p_micromap: *mut MicromapEXT,
-            ) -> Result,
-            takes_handle: true,
+                p_copy_image_to_buffer_info: *const CopyImageToBufferInfo2,
+            ),
         },
         {
-            name: pfn_cmd_build_micromaps_ext,
-            method_name: cmd_build_micromaps_ext,
-            ptr: CmdBuildMicromapsEXT,
+            method_name: cmd_resolve_image_2,
+            try_method_name: try_cmd_resolve_image_2,
+            unchecked_method_name: cmd_resolve_image_2_unchecked,
             signature: (
-                command_buffer: CommandBuffer,
-                info_count: u32,
-                p_infos: *const MicromapBuildInfoEXT,
+                p_resolve_image_info: *const ResolveImageInfo2,
             ),
-            takes_handle: false,
         },
         {
-            name: pfn_build_micromaps_ext,
-            method_name: build_micromaps_ext,
-            ptr: BuildMicromapsEXT,
+            method_name: cmd_set_fragment_shading_rate_khr,
+            try_method_name: try_cmd_set_fragment_shading_rate_khr,
+            unchecked_method_name: cmd_set_fragment_shading_rate_khr_unchecked,
             signature: (
-                deferred_operation: DeferredOperationKHR,
-                info_count: u32,
-                p_infos: *const MicromapBuildInfoEXT,
-            ) -> Result,
-            takes_handle: true,
+                p_fragment_size: *const Extent2D,
+                combiner_ops: [FragmentShadingRateCombinerOpKHR; 2],
+            ),
         },
         {
-            name: pfn_destroy_micromap_ext,
-            method_name: destroy_micromap_ext,
-            ptr: DestroyMicromapEXT,
+            method_name: cmd_set_fragment_shading_rate_enum_nv,
+            try_method_name: try_cmd_set_fragment_shading_rate_enum_nv,
+            unchecked_method_name: cmd_set_fragment_shading_rate_enum_nv_unchecked,
             signature: (
-                micromap: MicromapEXT,
-                p_allocator: *const AllocationCallbacks,
+                shading_rate: FragmentShadingRateNV,
+                combiner_ops: [FragmentShadingRateCombinerOpKHR; 2],
             ),
-            takes_handle: true,
         },
         {
-            name: pfn_cmd_copy_micromap_ext,
-            method_name: cmd_copy_micromap_ext,
-            ptr: CmdCopyMicromapEXT,
+            method_name: cmd_set_vertex_input_ext,
+            try_method_name: try_cmd_set_vertex_input_ext,
+            unchecked_method_name: cmd_set_vertex_input_ext_unchecked,
             signature: (
-                command_buffer: CommandBuffer,
-                p_info: *const CopyMicromapInfoEXT,
+                vertex_binding_description_count: u32,
+                p_vertex_binding_descriptions: *const VertexInputBindingDescription2EXT,
+                vertex_attribute_description_count: u32,
+                p_vertex_attribute_descriptions: *const VertexInputAttributeDescription2EXT,
             ),
-            takes_handle: false,
         },
         {
-            name: pfn_copy_micromap_ext,
-            method_name: copy_micromap_ext,
-            ptr: CopyMicromapEXT,
+            method_name: cmd_set_color_write_enable_ext,
+            try_method_name: try_cmd_set_color_write_enable_ext,
+            unchecked_method_name: cmd_set_color_write_enable_ext_unchecked,
             signature: (
-                deferred_operation: DeferredOperationKHR,
-                p_info: *const CopyMicromapInfoEXT,
-            ) -> Result,
-            takes_handle: true,
+                attachment_count: u32,
+                p_color_write_enables: *const Bool32,
+            ),
         },
         {
-            name: pfn_cmd_copy_micromap_to_memory_ext,
-            method_name: cmd_copy_micromap_to_memory_ext,
-            ptr: CmdCopyMicromapToMemoryEXT,
+            method_name: cmd_set_event_2,
+            try_method_name: try_cmd_set_event_2,
+            unchecked_method_name: cmd_set_event_2_unchecked,
             signature: (
-                command_buffer: CommandBuffer,
-                p_info: *const CopyMicromapToMemoryInfoEXT,
+                event: Event,
+                p_dependency_info: *const DependencyInfo,
             ),
-            takes_handle: false,
         },
         {
-            name: pfn_copy_micromap_to_memory_ext,
-            method_name: copy_micromap_to_memory_ext,
-            ptr: CopyMicromapToMemoryEXT,
+            method_name: cmd_reset_event_2,
+            try_method_name: try_cmd_reset_event_2,
+            unchecked_method_name: cmd_reset_event_2_unchecked,
             signature: (
-                deferred_operation: DeferredOperationKHR,
-                p_info: *const CopyMicromapToMemoryInfoEXT,
-            ) -> Result,
-            takes_handle: true,
+                event: Event,
+                stage_mask: PipelineStageFlags2,
+            ),
         },
         {
-            name: pfn_cmd_copy_memory_to_micromap_ext,
-            method_name: cmd_copy_memory_to_micromap_ext,
-            ptr: CmdCopyMemoryToMicromapEXT,
+            method_name: cmd_wait_events_2,
+            try_method_name: try_cmd_wait_events_2,
+            unchecked_method_name: cmd_wait_events_2_unchecked,
             signature: (
-                command_buffer: CommandBuffer,
-                p_info: *const CopyMemoryToMicromapInfoEXT,
+                event_count: u32,
+                p_events: *const Event,
+                p_dependency_infos: *const DependencyInfo,
             ),
-            takes_handle: false,
         },
         {
-            name: pfn_copy_memory_to_micromap_ext,
-            method_name: copy_memory_to_micromap_ext,
-            ptr: CopyMemoryToMicromapEXT,
+            method_name: cmd_pipeline_barrier_2,
+            try_method_name: try_cmd_pipeline_barrier_2,
+            unchecked_method_name: cmd_pipeline_barrier_2_unchecked,
             signature: (
-                deferred_operation: DeferredOperationKHR,
-                p_info: *const CopyMemoryToMicromapInfoEXT,
-            ) -> Result,
-            takes_handle: true,
+                p_dependency_info: *const DependencyInfo,
+            ),
         },
         {
-            name: pfn_cmd_write_micromaps_properties_ext,
-            method_name: cmd_write_micromaps_properties_ext,
-            ptr: CmdWriteMicromapsPropertiesEXT,
+            method_name: cmd_write_timestamp_2,
+            try_method_name: try_cmd_write_timestamp_2,
+            unchecked_method_name: cmd_write_timestamp_2_unchecked,
             signature: (
-                command_buffer: CommandBuffer,
-                micromap_count: u32,
-                p_micromaps: *const MicromapEXT,
-                query_type: QueryType,
+                stage: PipelineStageFlags2,
                 query_pool: QueryPool,
-                first_query: u32,
+                query: u32,
             ),
-            takes_handle: false,
         },
         {
-            name: pfn_write_micromaps_properties_ext,
-            method_name: write_micromaps_properties_ext,
-            ptr: WriteMicromapsPropertiesEXT,
+            method_name: cmd_write_buffer_marker_2_amd,
+            try_method_name: try_cmd_write_buffer_marker_2_amd,
+            unchecked_method_name: cmd_write_buffer_marker_2_amd_unchecked,
             signature: (
-                micromap_count: u32,
-                p_micromaps: *const MicromapEXT,
-                query_type: QueryType,
-                data_size: usize,
-                p_data: *mut c_void,
-                stride: usize,
-            ) -> Result,
-            takes_handle: true,
+                stage: PipelineStageFlags2,
+                dst_buffer: Buffer,
+                dst_offset: DeviceSize,
+                marker: u32,
+            ),
         },
         {
-            name: pfn_get_device_micromap_compatibility_ext,
-            method_name: get_device_micromap_compatibility_ext,
-            ptr: GetDeviceMicromapCompatibilityEXT,
+            method_name: cmd_decode_video_khr,
+            try_method_name: try_cmd_decode_video_khr,
+            unchecked_method_name: cmd_decode_video_khr_unchecked,
             signature: (
-                p_version_info: *const MicromapVersionInfoEXT,
-                p_compatibility: *mut AccelerationStructureCompatibilityKHR,
+                p_decode_info: *const VideoDecodeInfoKHR,
             ),
-            takes_handle: true,
         },
         {
-            name: pfn_get_micromap_build_sizes_ext,
-            method_name: get_micromap_build_sizes_ext,
-            ptr: GetMicromapBuildSizesEXT,
+            method_name: cmd_begin_video_coding_khr,
+            try_method_name: try_cmd_begin_video_coding_khr,
+            unchecked_method_name: cmd_begin_video_coding_khr_unchecked,
             signature: (
-                build_type: AccelerationStructureBuildTypeKHR,
-                p_build_info: *const MicromapBuildInfoEXT,
-                p_size_info: *mut MicromapBuildSizesInfoEXT,
+                p_begin_info: *const VideoBeginCodingInfoKHR,
             ),
-            takes_handle: true,
         },
         {
-            name: pfn_get_shader_module_identifier_ext,
-            method_name: get_shader_module_identifier_ext,
-            ptr: GetShaderModuleIdentifierEXT,
+            method_name: cmd_control_video_coding_khr,
+            try_method_name: try_cmd_control_video_coding_khr,
+            unchecked_method_name: cmd_control_video_coding_khr_unchecked,
             signature: (
-                shader_module: ShaderModule,
-                p_identifier: *mut ShaderModuleIdentifierEXT,
+                p_coding_control_info: *const VideoCodingControlInfoKHR,
             ),
-            takes_handle: true,
         },
         {
-            name: pfn_get_shader_module_create_info_identifier_ext,
-            method_name: get_shader_module_create_info_identifier_ext,
-            ptr: GetShaderModuleCreateInfoIdentifierEXT,
+            method_name: cmd_end_video_coding_khr,
+            try_method_name: try_cmd_end_video_coding_khr,
+            unchecked_method_name: cmd_end_video_coding_khr_unchecked,
             signature: (
-                p_create_info: *const ShaderModuleCreateInfo,
-                p_identifier: *mut ShaderModuleIdentifierEXT,
+                p_end_coding_info: *const VideoEndCodingInfoKHR,
             ),
-            takes_handle: true,
         },
         {
-            name: pfn_get_image_subresource_layout_2_ext,
-            method_name: get_image_subresource_layout_2_ext,
-            ptr: GetImageSubresourceLayout2EXT,
+            method_name: cmd_encode_video_khr,
+            try_method_name: try_cmd_encode_video_khr,
+            unchecked_method_name: cmd_encode_video_khr_unchecked,
             signature: (
-                image: Image,
-                p_subresource: *const ImageSubresource2EXT,
-                p_layout: *mut SubresourceLayout2EXT,
+                p_encode_info: *const VideoEncodeInfoKHR,
             ),
-            takes_handle: true,
         },
         {
-            name: pfn_get_pipeline_properties_ext,
-            method_name: get_pipeline_properties_ext,
-            ptr: GetPipelinePropertiesEXT,
+            method_name: cmd_cu_launch_kernel_nvx,
+            try_method_name: try_cmd_cu_launch_kernel_nvx,
+            unchecked_method_name: cmd_cu_launch_kernel_nvx_unchecked,
             signature: (
-                p_pipeline_info: *const PipelineInfoEXT,
-                p_pipeline_properties: *mut BaseOutStructure,
-            ) -> Result,
-            takes_handle: true,
+                p_launch_info: *const CuLaunchInfoNVX,
+            ),
         },
         {
-            name: pfn_export_metal_objects_ext,
-            method_name: export_metal_objects_ext,
-            ptr: ExportMetalObjectsEXT,
+            method_name: cmd_begin_rendering,
+            try_method_name: try_cmd_begin_rendering,
+            unchecked_method_name: cmd_begin_rendering_unchecked,
             signature: (
-                p_metal_objects_info: *mut ExportMetalObjectsInfoEXT,
+                p_rendering_info: *const RenderingInfo,
             ),
-            takes_handle: true,
         },
         {
-            name: pfn_get_framebuffer_tile_properties_qcom,
-            method_name: get_framebuffer_tile_properties_qcom,
-            ptr: GetFramebufferTilePropertiesQCOM,
+            method_name: cmd_end_rendering,
+            try_method_name: try_cmd_end_rendering,
+            unchecked_method_name: cmd_end_rendering_unchecked,
             signature: (
-                framebuffer: Framebuffer,
-                p_properties_count: *mut u32,
-                p_properties: *mut TilePropertiesQCOM,
-            ) -> Result,
-            takes_handle: true,
+            ),
         },
         {
-            name: pfn_get_dynamic_rendering_tile_properties_qcom,
-            method_name: get_dynamic_rendering_tile_properties_qcom,
-            ptr: GetDynamicRenderingTilePropertiesQCOM,
+            method_name: cmd_build_micromaps_ext,
+            try_method_name: try_cmd_build_micromaps_ext,
+            unchecked_method_name: cmd_build_micromaps_ext_unchecked,
             signature: (
-                p_rendering_info: *const RenderingInfo,
-                p_properties: *mut TilePropertiesQCOM,
-            ) -> Result,
-            takes_handle: true,
+                info_count: u32,
+                p_infos: *const MicromapBuildInfoEXT,
+            ),
         },
         {
-            name: pfn_create_optical_flow_session_nv,
-            method_name: create_optical_flow_session_nv,
-            ptr: CreateOpticalFlowSessionNV,
+            method_name: cmd_copy_micromap_ext,
+            try_method_name: try_cmd_copy_micromap_ext,
+            unchecked_method_name: cmd_copy_micromap_ext_unchecked,
             signature: (
-                p_create_info: *const OpticalFlowSessionCreateInfoNV,
-                p_allocator: *const AllocationCallbacks,
-                p_session: *mut OpticalFlowSessionNV,
-            ) -> Result,
-            takes_handle: true,
+                p_info: *const CopyMicromapInfoEXT,
+            ),
         },
         {
-            name: pfn_destroy_optical_flow_session_nv,
-            method_name: destroy_optical_flow_session_nv,
-            ptr: DestroyOpticalFlowSessionNV,
+            method_name: cmd_copy_micromap_to_memory_ext,
+            try_method_name: try_cmd_copy_micromap_to_memory_ext,
+            unchecked_method_name: cmd_copy_micromap_to_memory_ext_unchecked,
             signature: (
-                session: OpticalFlowSessionNV,
-                p_allocator: *const AllocationCallbacks,
+                p_info: *const CopyMicromapToMemoryInfoEXT,
             ),
-            takes_handle: true,
         },
         {
-            name: pfn_bind_optical_flow_session_image_nv,
-            method_name: bind_optical_flow_session_image_nv,
-            ptr: BindOpticalFlowSessionImageNV,
+            method_name: cmd_copy_memory_to_micromap_ext,
+            try_method_name: try_cmd_copy_memory_to_micromap_ext,
+            unchecked_method_name: cmd_copy_memory_to_micromap_ext_unchecked,
             signature: (
-                session: OpticalFlowSessionNV,
-                binding_point: OpticalFlowSessionBindingPointNV,
-                view: ImageView,
-                layout: ImageLayout,
-            ) -> Result,
-            takes_handle: true,
+                p_info: *const CopyMemoryToMicromapInfoEXT,
+            ),
         },
         {
-            name: pfn_cmd_optical_flow_execute_nv,
-            method_name: cmd_optical_flow_execute_nv,
-            ptr: CmdOpticalFlowExecuteNV,
+            method_name: cmd_write_micromaps_properties_ext,
+            try_method_name: try_cmd_write_micromaps_properties_ext,
+            unchecked_method_name: cmd_write_micromaps_properties_ext_unchecked,
             signature: (
-                command_buffer: CommandBuffer,
-                session: OpticalFlowSessionNV,
-                p_execute_info: *const OpticalFlowExecuteInfoNV,
+                micromap_count: u32,
+                p_micromaps: *const MicromapEXT,
+                query_type: QueryType,
+                query_pool: QueryPool,
+                first_query: u32,
             ),
-            takes_handle: false,
         },
         {
-            name: pfn_get_device_fault_info_ext,
-            method_name: get_device_fault_info_ext,
-            ptr: GetDeviceFaultInfoEXT,
+            method_name: cmd_optical_flow_execute_nv,
+            try_method_name: try_cmd_optical_flow_execute_nv,
+            unchecked_method_name: cmd_optical_flow_execute_nv_unchecked,
             signature: (
-                p_fault_counts: *mut DeviceFaultCountsEXT,
-                p_fault_info: *mut DeviceFaultInfoEXT,
-            ) -> Result,
-            takes_handle: true,
+                session: OpticalFlowSessionNV,
+                p_execute_info: *const OpticalFlowExecuteInfoNV,
+            ),
         },
     ],
 }
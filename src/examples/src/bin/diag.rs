@@ -1,5 +1,3 @@
-#![feature(try_blocks)]
-
 use std::ffi;
 use std::ptr;
 
@@ -25,8 +23,7 @@ unsafe fn unsafe_main() {
     let loader = Loader::load();
     let entry = vkl::Entry::load(loader.get_instance_proc_addr);
 
-    let layers = vk::enumerate2!(entry, enumerate_instance_layer_properties)
-        .unwrap();
+    let layers = entry.enumerate_instance_layer_properties_vec().unwrap();
     println!("layers:");
     for layer in layers.into_iter() {
         println!("  - name: {:?}",
@@ -39,11 +36,8 @@ unsafe fn unsafe_main() {
             ffi::CStr::from_ptr(&layer.description as _));
     }
 
-    let exts = vk::enumerate2!(
-        entry,
-        enumerate_instance_extension_properties,
-        ptr::null(),
-    ).unwrap();
+    let exts = entry.enumerate_instance_extension_properties_vec(ptr::null())
+        .unwrap();
     println!("extensions:");
     for ext in exts.into_iter() {
         println!("  - name: {:?}",
@@ -76,12 +70,11 @@ unsafe fn unsafe_main() {
         vkl::InstanceTable::load(instance, loader.get_instance_proc_addr);
 
     let physical_devices =
-        vk::enumerate2!(instance_table, enumerate_physical_devices).unwrap();
+        instance_table.enumerate_physical_devices_vec().unwrap();
     println!("physical_devices:");
     for &pdev in physical_devices.iter() {
-        let mut props: vk::PhysicalDeviceProperties = Default::default();
-        instance_table.get_physical_device_properties
-            (pdev, &mut props);
+        let pdev = instance_table.physical_device(pdev);
+        let props = pdev.get_physical_device_properties_checked();
         println!("  - name: {:?}",
             ffi::CStr::from_ptr(&props.device_name as _));
         println!("    device_type: {}", match props.device_type {
@@ -99,11 +92,7 @@ unsafe fn unsafe_main() {
         println!("    driver_version: {}",
             Version::from(props.driver_version));
 
-        let qf_props = vk::enumerate2!(
-            @void instance_table,
-            get_physical_device_queue_family_properties,
-            pdev,
-        );
+        let qf_props = pdev.get_physical_device_queue_family_properties_vec();
         println!("    queue_families:");
         for qf in qf_props.into_iter() {
             print!("      - queue_flags: ");
@@ -129,10 +118,7 @@ unsafe fn unsafe_main() {
             );
         }
 
-        let mut mem_props: vk::PhysicalDeviceMemoryProperties =
-            Default::default();
-        instance_table.get_physical_device_memory_properties
-            (pdev, &mut mem_props);
+        let mem_props = pdev.get_physical_device_memory_properties_checked();
 
         let mem_types =
             &mem_props.memory_types[..mem_props.memory_type_count as usize];
@@ -168,12 +154,9 @@ unsafe fn unsafe_main() {
             println!();
         }
 
-        let exts = vk::enumerate2!(
-            instance_table,
-            enumerate_device_extension_properties,
-            pdev,
-            ptr::null(),
-        ).unwrap();
+        let exts = pdev
+            .enumerate_device_extension_properties_vec(ptr::null())
+            .unwrap();
         println!("    extensions:");
         for ext in exts.into_iter() {
             println!("      - name: {:?}",
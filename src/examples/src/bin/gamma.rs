@@ -1,6 +1,5 @@
 // Vulkan compute demonstration which produces a plot of the gamma
 // function in a rectangular region (with Re(z) > 1/2).
-#![feature(try_blocks)]
 
 use std::ffi::c_void;
 use std::io::Write;
@@ -70,9 +69,8 @@ unsafe fn unsafe_main() {
         p_code: from_bytes_le(GAMMA_SPV_BYTES).as_ptr(),
         ..Default::default()
     };
-    let mut shader_mod = vk::null();
-    sys.device.create_shader_module(&create_info, ptr::null(), &mut shader_mod)
-        .check().unwrap();
+    let shader_mod = sys.device.create_shader_module_checked(&create_info, None)
+        .unwrap();
 
     let binding = vk::DescriptorSetLayoutBinding {
         binding: 0,
@@ -86,20 +84,17 @@ unsafe fn unsafe_main() {
         p_bindings: &binding,
         ..Default::default()
     };
-    let mut set_layout = vk::null();
-    sys.device.create_descriptor_set_layout
-        (&create_info, ptr::null(), &mut set_layout)
-        .check().unwrap();
+    let set_layout = sys.device.create_descriptor_set_layout_checked
+        (&create_info, None)
+        .unwrap();
 
     let create_info = vk::PipelineLayoutCreateInfo {
         set_layout_count: 1,
         p_set_layouts: &set_layout,
         ..Default::default()
     };
-    let mut layout = vk::null();
-    sys.device.create_pipeline_layout
-        (&create_info, ptr::null(), &mut layout)
-        .check().unwrap();
+    let layout = sys.device.create_pipeline_layout_checked(&create_info, None)
+        .unwrap();
 
     let stage_create_info = vk::PipelineShaderStageCreateInfo {
         stage: vk::ShaderStageFlags::COMPUTE_BIT,
@@ -123,9 +118,8 @@ unsafe fn unsafe_main() {
     ).check().unwrap();
 
     // Create and bind storage buffer
-    let mut props: vk::PhysicalDeviceMemoryProperties = Default::default();
-    sys.instance.get_physical_device_memory_properties
-        (sys.physical_device, &mut props);
+    let props = sys.instance.get_physical_device_memory_properties_checked
+        (sys.physical_device);
     let mem_type = props.memory_types[..props.memory_type_count as usize]
         .iter()
         .position(|ty| ty.property_flags.intersects(
@@ -141,10 +135,8 @@ unsafe fn unsafe_main() {
         memory_type_index: mem_type,
         ..Default::default()
     };
-    let mut buf_mem = vk::null();
-    sys.device.allocate_memory
-        (&allocate_info, ptr::null(), &mut buf_mem)
-        .check().unwrap();
+    let buf_mem = sys.device.allocate_memory_checked(&allocate_info, None)
+        .unwrap();
 
     let create_info = vk::BufferCreateInfo {
         size: buf_size,
@@ -152,10 +144,8 @@ unsafe fn unsafe_main() {
         sharing_mode: vk::SharingMode::EXCLUSIVE,
         ..Default::default()
     };
-    let mut buffer = vk::null();
-    sys.device.create_buffer
-        (&create_info, ptr::null(), &mut buffer)
-        .check().unwrap();
+    let buffer = sys.device.create_buffer_checked(&create_info, None)
+        .unwrap();
 
     sys.device.bind_buffer_memory(buffer, buf_mem, 0).check().unwrap();
 
@@ -170,10 +160,9 @@ unsafe fn unsafe_main() {
         p_pool_sizes: &pool_size,
         ..Default::default()
     };
-    let mut desc_pool = vk::null();
-    sys.device.create_descriptor_pool
-        (&create_info, ptr::null(), &mut desc_pool)
-        .check().unwrap();
+    let desc_pool = sys.device.create_descriptor_pool_checked
+        (&create_info, None)
+        .unwrap();
 
     let allocate_info = vk::DescriptorSetAllocateInfo {
         descriptor_pool: desc_pool,
@@ -205,10 +194,9 @@ unsafe fn unsafe_main() {
 
     // Create and record to command buffer
     let create_info = Default::default();
-    let mut command_pool = vk::null();
-    sys.device.create_command_pool
-        (&create_info, ptr::null(), &mut command_pool)
-        .check().unwrap();
+    let command_pool = sys.device.create_command_pool_checked
+        (&create_info, None)
+        .unwrap();
 
     let allocate_info = vk::CommandBufferAllocateInfo {
         command_pool,
@@ -225,12 +213,10 @@ unsafe fn unsafe_main() {
         flags: vk::CommandBufferUsageFlags::ONE_TIME_SUBMIT_BIT,
         ..Default::default()
     };
-    sys.device.begin_command_buffer(cmd_buf, &begin_info)
-        .check().unwrap();
-    sys.device.cmd_bind_pipeline
-        (cmd_buf, vk::PipelineBindPoint::COMPUTE, pipeline);
-    sys.device.cmd_bind_descriptor_sets(
-        cmd_buf,
+    let cmds = sys.device.command_buffer(cmd_buf);
+    cmds.begin_command_buffer(&begin_info).check().unwrap();
+    cmds.cmd_bind_pipeline(vk::PipelineBindPoint::COMPUTE, pipeline);
+    cmds.cmd_bind_descriptor_sets(
         vk::PipelineBindPoint::COMPUTE,
         layout,
         0,
@@ -239,8 +225,8 @@ unsafe fn unsafe_main() {
         0,
         ptr::null(),
     );
-    sys.device.cmd_dispatch(cmd_buf, IMAGE_DIMS[0], IMAGE_DIMS[1], 1);
-    sys.device.end_command_buffer(cmd_buf).check().unwrap();
+    cmds.cmd_dispatch(IMAGE_DIMS[0], IMAGE_DIMS[1], 1);
+    cmds.end_command_buffer().check().unwrap();
 
     // Submit
     let submit_info = vk::SubmitInfo {
@@ -248,7 +234,7 @@ unsafe fn unsafe_main() {
         p_command_buffers: &cmd_buf,
         ..Default::default()
     };
-    sys.device.queue_submit(sys.queue, 1, &submit_info, vk::null())
+    sys.device.queue(sys.queue).queue_submit(1, &submit_info, vk::null())
         .check().unwrap();
 
     sys.device.device_wait_idle().check().unwrap();
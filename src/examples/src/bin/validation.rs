@@ -1,5 +1,3 @@
-#![feature(try_blocks)]
-
 use std::ptr;
 
 use examples::*;
@@ -22,12 +20,16 @@ unsafe fn unsafe_main() {
     entry.create_instance(&create_info, ptr::null(), &mut vk_instance)
         .check().unwrap();
 
-    let instance = vkl::InstanceTable::load
-        (vk_instance, loader.get_instance_proc_addr);
+    // Also track object lifetimes on the Rust side
+    let tracker = vkl::lifetime::Tracker::new();
+    let instance = vkl::hooks::Hooked::new(
+        vkl::InstanceTable::load(vk_instance, loader.get_instance_proc_addr),
+        &tracker,
+    );
 
     // Create device
     let physical_devices =
-        vk::enumerate2!(instance, enumerate_physical_devices).unwrap();
+        instance.enumerate_physical_devices_vec().unwrap();
     let physical_device = physical_devices[0];
 
     let queue_create_infos = [vk::DeviceQueueCreateInfo {
@@ -49,8 +51,10 @@ unsafe fn unsafe_main() {
         &mut vk_device,
     ).check().unwrap();
 
-    let device =
-        vkl::DeviceTable::load(vk_device, loader.get_device_proc_addr);
+    let device = vkl::hooks::Hooked::new(
+        vkl::DeviceTable::load(vk_device, loader.get_device_proc_addr),
+        &tracker,
+    );
 
     let mut queue = vk::null();
     device.get_device_queue(0, 0, &mut queue);
@@ -63,9 +67,13 @@ unsafe fn unsafe_main() {
     let mut buffer = vk::null();
     device.create_buffer(&create_info, ptr::null(), &mut buffer);
 
-    // Don't free the buffer, causing a validation error
+    // Don't free the buffer, causing a validation error, which the
+    // tracker reports as a leak too
     device.destroy_device(ptr::null());
     instance.destroy_instance(ptr::null());
+    for report in tracker.take_reports() {
+        println!("{}", report);
+    }
 }
 
 fn main() {
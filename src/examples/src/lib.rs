@@ -1,5 +1,3 @@
-#![feature(try_blocks)]
-
 use std::ffi::CStr;
 use std::ptr;
 
@@ -11,26 +9,23 @@ macro_rules! c_str {
     }
 }
 
-#[cfg(unix)]
-pub const VULKAN_LOADER_PATH: &'static str = "libvulkan.so";
-
 // TODO: fall back on LUNARG if KHRONOS unavailable
 pub const VALIDATION_LAYER: &'static [u8] = b"VK_LAYER_KHRONOS_validation\0";
 
 #[derive(Debug)]
 pub struct Loader {
-    pub lib: lib::Library,
+    pub lib: vkl::Library,
     pub get_instance_proc_addr: vk::pfn::GetInstanceProcAddr,
     pub get_device_proc_addr: vk::pfn::GetDeviceProcAddr,
 }
 
 impl Loader {
     pub unsafe fn load() -> Self {
-        let lib = lib::Library::new(VULKAN_LOADER_PATH).unwrap();
-        let get_instance_proc_addr =
-                *lib.get(b"vkGetInstanceProcAddr\0").unwrap();
+        let lib = vkl::Library::open_default()
+            .unwrap_or_else(|e| panic!("{}", e));
+        let get_instance_proc_addr = lib.get_instance_proc_addr();
         let get_device_proc_addr =
-                *lib.get(b"vkGetDeviceProcAddr\0").unwrap();
+                lib.get(b"vkGetDeviceProcAddr\0").unwrap();
         Loader { lib, get_instance_proc_addr, get_device_proc_addr }
     }
 }
@@ -113,9 +108,7 @@ impl VulkanSys {
         let entry = vkl::Entry::load(loader.get_instance_proc_addr);
 
         // Enable validation if available
-        let layers =
-            vk::enumerate2!(entry, enumerate_instance_layer_properties)
-            .unwrap();
+        let layers = entry.enumerate_instance_layer_properties_vec().unwrap();
         let enable_validation = layers.iter().any(|layer| {
             CStr::from_ptr(&layer.layer_name as *const _ as *const _)
                 == CStr::from_bytes_with_nul_unchecked(VALIDATION_LAYER)
@@ -148,16 +141,15 @@ impl VulkanSys {
             pp_enabled_extension_names: ptr::null(),
             ..Default::default()
         };
-        let mut vk_instance = vk::null();
-        entry.create_instance(&create_info, ptr::null(), &mut vk_instance)
-            .check().unwrap();
+        let vk_instance =
+            entry.create_instance_checked(&create_info, None).unwrap();
 
         let instance = vkl::InstanceTable::load
             (vk_instance, loader.get_instance_proc_addr);
 
         // Create device
         let physical_devices =
-            vk::enumerate2!(instance, enumerate_physical_devices).unwrap();
+            instance.enumerate_physical_devices_vec().unwrap();
         let physical_device = physical_devices[0];
 
         let queue_create_info = vk::DeviceQueueCreateInfo {
@@ -177,19 +169,16 @@ impl VulkanSys {
             p_enabled_features: &features,
             ..Default::default()
         };
-        let mut vk_device = vk::null();
-        instance.create_device(
+        let vk_device = instance.create_device_checked(
             physical_device,
             &create_info,
-            ptr::null(),
-            &mut vk_device,
-        ).check().unwrap();
+            None,
+        ).unwrap();
 
         let device =
             vkl::DeviceTable::load(vk_device, loader.get_device_proc_addr);
 
-        let mut queue = vk::null();
-        device.get_device_queue(0, 0, &mut queue);
+        let queue = device.get_device_queue_checked(0, 0);
 
         VulkanSys {
             loader,
@@ -0,0 +1,178 @@
+//! Walking and editing `pNext` chains.
+//!
+//! Every function here takes the head of a chain, which is any
+//! structure with an `s_type`, and is unsafe because it follows the
+//! `p_next` pointers. The caller must ensure that each pointer is
+//! either null or points to a valid structure of the type its `s_type`
+//! names, which outlives the borrow of the head.
+
+use std::ffi::c_void;
+use std::marker::PhantomData;
+use std::ptr;
+
+use crate::traits::{Extends, TaggedStruct};
+use crate::{BaseInStructure, BaseOutStructure, StructureType};
+
+/// An iterator over a `pNext` chain, yielding the structure type and
+/// address of each structure in turn. As `BaseInStructure` and
+/// `BaseOutStructure` are laid out the same way, it can walk either
+/// kind of chain.
+#[derive(Clone, Debug)]
+pub struct Iter<'a> {
+    next: *const BaseInStructure,
+    marker: PhantomData<&'a BaseInStructure>,
+}
+
+impl<'a> Iter<'a> {
+    /// Iterates over the chain starting at `p_next`, e.g. the `p_next`
+    /// member of a structure passed to a layer.
+    ///
+    /// # Safety
+    ///
+    /// `p_next` must be null or point to a valid chain for `'a`.
+    #[inline]
+    pub unsafe fn new(p_next: *const c_void) -> Self {
+        Iter { next: p_next as _, marker: PhantomData }
+    }
+}
+
+impl<'a> Iterator for Iter<'a> {
+    type Item = (StructureType, *const BaseInStructure);
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        let current = self.next;
+        if current.is_null() {
+            return None;
+        }
+        // Valid by the contract of `Iter::new`
+        let current = unsafe { &*current };
+        self.next = current.p_next;
+        Some((current.s_type, current as *const _))
+    }
+}
+
+impl std::iter::FusedIterator for Iter<'_> {}
+
+// Sound as `TaggedStruct` guarantees the layout
+#[inline]
+fn base<H: TaggedStruct>(head: &H) -> &BaseInStructure {
+    unsafe { &*(head as *const H as *const BaseInStructure) }
+}
+
+#[inline]
+fn base_mut<H: TaggedStruct>(head: &mut H) -> &mut BaseOutStructure {
+    unsafe { &mut *(head as *mut H as *mut BaseOutStructure) }
+}
+
+/// Iterates over the structures chained after `head`, not including
+/// `head` itself.
+///
+/// # Safety
+///
+/// See the module documentation.
+#[inline]
+pub unsafe fn iter<H: TaggedStruct>(head: &H) -> Iter<'_> {
+    Iter::new(base(head).p_next as _)
+}
+
+/// Finds the first structure of type `T` chained after `head`.
+///
+/// # Safety
+///
+/// See the module documentation.
+pub unsafe fn find<T, H>(head: &H) -> Option<&T>
+where
+    T: TaggedStruct,
+    H: TaggedStruct,
+{
+    iter(head)
+        .find(|&(s_type, _)| s_type == T::STRUCTURE_TYPE)
+        .map(|(_, next)| &*(next as *const T))
+}
+
+/// Like `find`, but borrows the structure mutably, e.g. to read the
+/// output of a `*2` query.
+///
+/// # Safety
+///
+/// See the module documentation.
+pub unsafe fn find_mut<T, H>(head: &mut H) -> Option<&mut T>
+where
+    T: TaggedStruct,
+    H: TaggedStruct,
+{
+    iter(head)
+        .find(|&(s_type, _)| s_type == T::STRUCTURE_TYPE)
+        .map(|(_, next)| &mut *(next as *mut T))
+}
+
+// Returns the last `p_next` pointer of the chain starting at `base`.
+unsafe fn tail(
+    mut base: *mut BaseOutStructure,
+) -> *mut *mut BaseOutStructure {
+    while !(*base).p_next.is_null() {
+        base = (*base).p_next;
+    }
+    ptr::addr_of_mut!((*base).p_next)
+}
+
+/// Appends `next` to the end of the chain after `head`. Unlike
+/// `push_next`, this keeps the existing structures first, in order.
+/// `next.p_next` is overwritten.
+///
+/// # Safety
+///
+/// See the module documentation. `next` must also outlive the borrow
+/// of `head`.
+pub unsafe fn insert<H, T>(head: &mut H, next: &mut T)
+where
+    H: TaggedStruct,
+    T: Extends<H>,
+{
+    let next = next as *mut T as *mut BaseOutStructure;
+    (*next).p_next = ptr::null_mut();
+    *tail(base_mut(head)) = next;
+}
+
+/// Inserts the chain starting at `first` right after `head`, keeping
+/// the structures already chained after `head` at the end.
+///
+/// # Safety
+///
+/// See the module documentation. Each structure chained after `first`
+/// must also extend `H`, and all of them must outlive the borrow of
+/// `head`.
+pub unsafe fn splice<H, T>(head: &mut H, first: &mut T)
+where
+    H: TaggedStruct,
+    T: Extends<H>,
+{
+    let head = base_mut(head);
+    let first = first as *mut T as *mut BaseOutStructure;
+    *tail(first) = head.p_next;
+    head.p_next = first;
+}
+
+/// Unlinks the first structure of type `T` from the chain after
+/// `head` and returns it. Its own `p_next` is set to null.
+///
+/// # Safety
+///
+/// See the module documentation.
+pub unsafe fn remove<T, H>(head: &mut H) -> Option<&mut T>
+where
+    T: TaggedStruct,
+    H: TaggedStruct,
+{
+    let mut prev: *mut BaseOutStructure = base_mut(head);
+    while let Some(current) = (*prev).p_next.as_mut() {
+        if current.s_type == T::STRUCTURE_TYPE {
+            (*prev).p_next = current.p_next;
+            current.p_next = ptr::null_mut();
+            return Some(&mut *(current as *mut BaseOutStructure as *mut T));
+        }
+        prev = current;
+    }
+    None
+}
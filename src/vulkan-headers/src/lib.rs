@@ -1,3 +1,26 @@
+//! Bindings to the Vulkan API, generated from the registry.
+//!
+//! Structures with pointer members also have builders, which set each
+//! count along with the array it counts and borrow the array for as
+//! long as the builder lives:
+//!
+//! ```
+//! use vulkan_headers as vk;
+//! let command_buffers = vec![vk::CommandBuffer::default(); 2];
+//! let info = vk::SubmitInfo::builder().command_buffers(&command_buffers);
+//! assert_eq!(info.command_buffer_count, 2);
+//! ```
+//!
+//! So a temporary array is rejected, where it would dangle if it were
+//! assigned to the structure directly:
+//!
+//! ```compile_fail
+//! use vulkan_headers as vk;
+//! let info = vk::SubmitInfo::builder()
+//!     .command_buffers(&vec![vk::CommandBuffer::default(); 2]);
+//! assert_eq!(info.command_buffer_count, 2);
+//! ```
+
 #![feature(extern_types, trait_alias, trivial_bounds)]
 #![allow(non_upper_case_globals, trivial_bounds)]
 
@@ -5,6 +28,7 @@ use std::os::raw::*;
 
 #[macro_use]
 mod macros;
+pub mod chain;
 mod imp;
 #[cfg(test)]
 mod tests;
@@ -313,6 +337,275 @@ macro_rules! aggregate_imports {
     };
 }
 
+macro_rules! impl_tagged {
+    ($($name:ident = $s_type:ident,)*) => {
+        $(
+            unsafe impl crate::traits::TaggedStruct for $name {
+                const STRUCTURE_TYPE: StructureType = StructureType::$s_type;
+            }
+        )*
+    };
+}
+
+macro_rules! impl_extends {
+    ($($base:ident { $($ext:ident,)* })*) => {
+        $(
+            $(unsafe impl crate::traits::Extends<$base> for $ext {})*
+
+            impl $base {
+                /// Inserts `next` into the `pNext` chain right after
+                /// `self`. Only `next` itself is inserted: its own
+                /// `p_next` is overwritten.
+                #[inline]
+                pub fn push_next<T: crate::traits::Extends<Self>>(
+                    &mut self,
+                    next: &mut T,
+                ) -> &mut Self {
+                    // `Extends` guarantees the layout
+                    let next = unsafe {
+                        &mut *(next as *mut T as *mut crate::BaseOutStructure)
+                    };
+                    next.p_next = self.p_next as _;
+                    self.p_next = next as *mut crate::BaseOutStructure as _;
+                    self
+                }
+
+                /// Like `push_next`, but inserts the whole chain
+                /// starting at `next`, followed by the structures
+                /// already chained after `self`.
+                ///
+                /// # Safety
+                ///
+                /// Each structure chained after `next` must be valid,
+                /// extend `Self`, and outlive the borrow of `self`.
+                #[inline]
+                pub unsafe fn push_chain<T: crate::traits::Extends<Self>>(
+                    &mut self,
+                    next: &mut T,
+                ) -> &mut Self {
+                    crate::chain::splice(self, next);
+                    self
+                }
+            }
+        )*
+    };
+}
+
+macro_rules! impl_builders {
+    (
+        $(
+            $name:ident => $builder:ident $(($shared:ident))? {
+                $([$($setter:tt)*])*
+            }
+        )*
+    ) => {
+        mod builders {
+            use std::convert::TryInto;
+            use std::ffi::{c_void, CStr};
+            use std::marker::PhantomData;
+            use std::ops::{Deref, DerefMut};
+            use std::os::raw::*;
+            use crate::*;
+            $(
+                /// Sets each count along with the pointer to the array
+                /// it counts, and borrows what the pointers point to
+                /// for `'a`. Derefs to the structure itself, so
+                /// `&*builder` can be passed to a command.
+                #[derive(Debug, Default)]
+                pub struct $builder<'a> {
+                    inner: $name,
+                    // Which of the slices sharing a count have been set
+                    #[allow(dead_code)]
+                    shared: impl_builders!(@shared $($shared)?),
+                    marker: PhantomData<&'a ()>,
+                }
+
+                impl $name {
+                    #[inline]
+                    pub fn builder<'a>() -> $builder<'a> {
+                        Default::default()
+                    }
+                }
+
+                impl<'a> $builder<'a> {
+                    $(impl_builders!(@setter $name $($setter)*);)*
+
+                    /// Returns the structure, which no longer borrows
+                    /// anything.
+                    ///
+                    /// **The lifetime is lost:** the structure still
+                    /// points to everything passed to the setters, but
+                    /// the borrow checker no longer ensures that it
+                    /// outlives the structure. Prefer passing
+                    /// `&*builder` to commands, and keep the arrays
+                    /// alive yourself if you must call this.
+                    #[inline]
+                    pub fn build(self) -> $name {
+                        self.inner
+                    }
+                }
+
+                impl Deref for $builder<'_> {
+                    type Target = $name;
+                    #[inline]
+                    fn deref(&self) -> &$name {
+                        &self.inner
+                    }
+                }
+
+                impl DerefMut for $builder<'_> {
+                    #[inline]
+                    fn deref_mut(&mut self) -> &mut $name {
+                        &mut self.inner
+                    }
+                }
+            )*
+        }
+    };
+    (@setter $name:ident next) => {
+        #[inline]
+        pub fn push_next<T: traits::Extends<$name>>(
+            mut self,
+            next: &'a mut T,
+        ) -> Self {
+            self.inner.push_next(next);
+            self
+        }
+    };
+    (@setter $name:ident value $member:ident: $ty:ty) => {
+        #[inline]
+        pub fn $member(mut self, $member: $ty) -> Self {
+            self.inner.$member = $member;
+            self
+        }
+    };
+    (@setter $name:ident str $setter:ident = $member:ident) => {
+        #[inline]
+        pub fn $setter(mut self, $setter: &'a CStr) -> Self {
+            self.inner.$member = $setter.as_ptr();
+            self
+        }
+    };
+    (@setter $name:ident ptr $setter:ident = $member:ident: $ty:ty) => {
+        #[inline]
+        pub fn $setter(mut self, $setter: &'a $ty) -> Self {
+            self.inner.$member = $setter;
+            self
+        }
+    };
+    (@setter $name:ident ptr_mut $setter:ident = $member:ident: $ty:ty) => {
+        #[inline]
+        pub fn $setter(mut self, $setter: &'a mut $ty) -> Self {
+            self.inner.$member = $setter;
+            self
+        }
+    };
+    (
+        @setter $name:ident slice $setter:ident = $member:ident,
+        $count:ident $(* $n:literal)?
+        $(shared($bit:literal, $others:literal))?: $ty:ty
+    ) => {
+        #[inline]
+        pub fn $setter(mut self, $setter: &'a [$ty]) -> Self {
+            let len = $setter.len() $(* $n)?;
+            impl_builders!(@count self, $count, len, $($bit, $others)?);
+            self.inner.$member = $setter.as_ptr() as _;
+            self
+        }
+    };
+    (
+        @setter $name:ident slice_mut $setter:ident = $member:ident,
+        $count:ident $(* $n:literal)?
+        $(shared($bit:literal, $others:literal))?: $ty:ty
+    ) => {
+        #[inline]
+        pub fn $setter(mut self, $setter: &'a mut [$ty]) -> Self {
+            let len = $setter.len() $(* $n)?;
+            impl_builders!(@count self, $count, len, $($bit, $others)?);
+            self.inner.$member = $setter.as_mut_ptr() as _;
+            self
+        }
+    };
+    // A count shared by several slices must match each of the others
+    // which have been set. Setting the same slice again replaces its
+    // length.
+    (
+        @count $this:ident, $count:ident, $len:expr,
+        $bit:literal, $others:literal
+    ) => {
+        let len = $len.try_into().expect("slice is too long");
+        assert!(
+            $this.shared & $others == 0 || $this.inner.$count == len,
+            "slice length doesn't match another slice sharing its count",
+        );
+        $this.inner.$count = len;
+        $this.shared |= $bit;
+    };
+    (@count $this:ident, $count:ident, $len:expr,) => {
+        $this.inner.$count = $len.try_into().expect("slice is too long");
+    };
+    (@shared shared) => { u32 };
+    (@shared) => { () };
+}
+
+macro_rules! impl_accessors {
+    ($($name:ident { $([$($member:tt)*])* })*) => {
+        $(
+            impl $name {
+                $(impl_accessors!(@accessor $($member)*);)*
+            }
+        )*
+    };
+    (@accessor str $accessor:ident = $member:ident) => {
+        /// Returns `None` if the pointer is null.
+        ///
+        /// # Safety
+        ///
+        /// The pointer must be null or point to a null-terminated
+        /// string which outlives the borrow of `self`.
+        #[inline]
+        pub unsafe fn $accessor(&self) -> Option<&std::ffi::CStr> {
+            self.$member
+                .as_ref()
+                .map(|ptr| std::ffi::CStr::from_ptr(ptr))
+        }
+    };
+    (
+        @accessor strs $accessor:ident = $member:ident,
+        $count:ident: $ty:ty
+    ) => {
+        /// # Safety
+        ///
+        /// The pointer must be null or point to as many null-terminated
+        /// strings as the count says, which outlive the borrow of
+        /// `self`.
+        #[inline]
+        pub unsafe fn $accessor(
+            &self,
+        ) -> impl ExactSizeIterator<Item = &std::ffi::CStr> + '_ {
+            crate::raw_slice(self.$member, self.$count as usize)
+                .iter()
+                .map(|&ptr| std::ffi::CStr::from_ptr(ptr))
+        }
+    };
+    (
+        @accessor slice $accessor:ident = $member:ident,
+        $count:ident $(* $n:literal)?: $ty:ty
+    ) => {
+        /// Returns an empty slice if the pointer is null.
+        ///
+        /// # Safety
+        ///
+        /// The pointer must be null or point to as many elements as
+        /// the count says, which outlive the borrow of `self`.
+        #[inline]
+        pub unsafe fn $accessor(&self) -> &[$ty] {
+            let len = self.$count as usize $(/ $n)?;
+            crate::raw_slice(self.$member as *const $ty, len)
+        }
+    };
+}
+
 // Function pointers and commands
 
 macro_rules! impl_func_pointers {
@@ -375,6 +668,7 @@ pub mod pfn {
     pub use crate::fn_ptrs::*;
 }
 
+pub use builders::*;
 pub use data::*;
 
 // TODO: Could stand to make Bool32 an enum
@@ -395,6 +689,7 @@ pub const SUBPASS_EXTERNAL: u32 = !0u32;
 pub const API_VERSION_1_0: u32 = crate::make_version!(1, 0, 0);
 pub const API_VERSION_1_1: u32 = crate::make_version!(1, 1, 0);
 pub const API_VERSION_1_2: u32 = crate::make_version!(1, 2, 0);
+pub const API_VERSION_1_3: u32 = crate::make_version!(1, 3, 0);
 
 #[cfg(feature = "reflection")]
 pub mod reflection {
@@ -451,6 +746,47 @@ pub mod traits {
         + BitOrAssign
         + BitXor
         + BitXorAssign;
+
+    /// A structure with an `s_type` member, which always holds
+    /// `STRUCTURE_TYPE`.
+    ///
+    /// # Safety
+    ///
+    /// The implementor must begin with `s_type` and `p_next` members,
+    /// laid out like `BaseOutStructure`, and no other type may use the
+    /// same `STRUCTURE_TYPE`. The `chain` functions and the downcasts
+    /// reinterpret structures based on this.
+    pub unsafe trait TaggedStruct: Sized {
+        const STRUCTURE_TYPE: crate::StructureType;
+    }
+
+    /// Marks a structure which may appear in the `pNext` chain of
+    /// `Base`, as given by the `structextends` attribute of the
+    /// registry. This is what `push_next` accepts:
+    ///
+    /// ```compile_fail
+    /// use vulkan_headers as vk;
+    /// let mut info = vk::BufferCreateInfo::default();
+    /// let mut features = vk::PhysicalDeviceVulkan12Features::default();
+    /// info.push_next(&mut features);
+    /// ```
+    ///
+    /// # Safety
+    ///
+    /// The implementor must begin with `s_type` and `p_next` members,
+    /// laid out like `BaseOutStructure`.
+    pub unsafe trait Extends<Base> {}
+}
+
+// Unlike `slice::from_raw_parts`, returns an empty slice for a null
+// pointer, which Vulkan allows for an empty or optional array.
+#[inline]
+unsafe fn raw_slice<'a, T>(ptr: *const T, len: usize) -> &'a [T] {
+    if ptr.is_null() || len == 0 {
+        &[]
+    } else {
+        std::slice::from_raw_parts(ptr, len)
+    }
 }
 
 /// Returns a null-valued handle.
@@ -458,6 +794,57 @@ pub fn null<T: crate::traits::HandleType>() -> T {
     <T as crate::traits::HandleType>::null()
 }
 
+impl BaseInStructure {
+    /// Borrows `self` as a `T`, if it has `T`'s structure type.
+    ///
+    /// This can't be a safe method: nothing stops safe code from
+    /// creating a lone `BaseInStructure`, or one whose `s_type` doesn't
+    /// match what follows it, and only the caller knows where `self`
+    /// came from.
+    ///
+    /// # Safety
+    ///
+    /// `self` must be the head of a structure of the type its `s_type`
+    /// names, as is the case for every structure reached through a
+    /// valid `pNext` chain. A `BaseInStructure` on its own is only 16
+    /// bytes, so downcasting it reads past its end.
+    #[inline]
+    pub unsafe fn downcast_ref<T: traits::TaggedStruct>(
+        &self,
+    ) -> Option<&T> {
+        (self.s_type == T::STRUCTURE_TYPE)
+            .then(|| &*(self as *const Self as *const T))
+    }
+}
+
+impl BaseOutStructure {
+    /// Borrows `self` as a `T`, if it has `T`'s structure type.
+    ///
+    /// # Safety
+    ///
+    /// See `BaseInStructure::downcast_ref`.
+    #[inline]
+    pub unsafe fn downcast_ref<T: traits::TaggedStruct>(
+        &self,
+    ) -> Option<&T> {
+        (self.s_type == T::STRUCTURE_TYPE)
+            .then(|| &*(self as *const Self as *const T))
+    }
+
+    /// Borrows `self` mutably as a `T`, if it has `T`'s structure type.
+    ///
+    /// # Safety
+    ///
+    /// See `BaseInStructure::downcast_ref`.
+    #[inline]
+    pub unsafe fn downcast_mut<T: traits::TaggedStruct>(
+        &mut self,
+    ) -> Option<&mut T> {
+        (self.s_type == T::STRUCTURE_TYPE)
+            .then(|| &mut *(self as *mut Self as *mut T))
+    }
+}
+
 impl Result {
     /// Checks if the result was a success.
     ///
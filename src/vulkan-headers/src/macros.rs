@@ -20,6 +20,20 @@ macro_rules! version_patch {
     ($version:expr) => { $version & 0xfff }
 }
 
+/// Like `version_major!`, but masks off the variant in the top three
+/// bits of a `VK_MAKE_API_VERSION` version.
+#[macro_export]
+macro_rules! api_version_major {
+    ($version:expr) => { ($version >> 22) & 0x7f }
+}
+
+/// The same as `version_minor!`, for symmetry with
+/// `api_version_major!`.
+#[macro_export]
+macro_rules! api_version_minor {
+    ($version:expr) => { ($version >> 12) & 0x3ff }
+}
+
 /// Handles the boilerplate of making two calls to `VkEnumerate*`: one
 /// to get the number of elements, and another to fill the array. This
 /// macro yields `Result<Vec<_>, VkResult>`, and never returns
@@ -32,6 +46,9 @@ macro_rules! version_patch {
 /// `vkGetPhysicalDeviceProperties`, which return `void` instead of
 /// `VkResult`. In this case, it just returns `Vec<_>`.
 ///
+/// The loader tables in `vulkan-loader` provide `*_vec` methods which
+/// do the same without requiring the `try_blocks` feature.
+///
 /// # Examples
 ///
 /// ```ignore
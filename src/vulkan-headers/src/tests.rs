@@ -36,6 +36,244 @@ fn struct_hash() {
     assert_eq!(set.len(), 2);
 }
 
+#[test]
+fn push_next() {
+    use std::ffi::c_void;
+    use std::mem::size_of;
+    let mut vulkan12 = vk::PhysicalDeviceVulkan12Features {
+        timeline_semaphore: 1,
+        ..Default::default()
+    };
+    let mut vulkan13 = vk::PhysicalDeviceVulkan13Features::default();
+    let mut info = vk::DeviceCreateInfo::default();
+    info.push_next(&mut vulkan12).push_next(&mut vulkan13);
+
+    // Each structure is inserted right after the base
+    let vulkan13_ptr = &vulkan13 as *const _ as *const c_void;
+    let vulkan12_ptr = &vulkan12 as *const _ as *mut c_void;
+    assert_eq!(info.p_next, vulkan13_ptr);
+    assert_eq!(vulkan13.p_next, vulkan12_ptr);
+    assert!(vulkan12.p_next.is_null());
+    assert_eq!(vulkan12.timeline_semaphore, 1);
+
+    // The chain of the pushed structure is dropped, as it isn't
+    // borrowed, and may not even be valid
+    let mut robustness = vk::PhysicalDeviceRobustness2FeaturesEXT {
+        p_next: std::ptr::dangling_mut(),
+        ..Default::default()
+    };
+    info.push_next(&mut robustness);
+    let robustness_ptr = &robustness as *const _ as *const c_void;
+    let vulkan13_ptr = &vulkan13 as *const _ as *mut c_void;
+    assert_eq!(info.p_next, robustness_ptr);
+    assert_eq!(robustness.p_next, vulkan13_ptr);
+
+    // Unless it is pushed with `push_chain`
+    let mut vulkan11 = vk::PhysicalDeviceVulkan11Features::default();
+    let mut maintenance4 = vk::PhysicalDeviceMaintenance4Features {
+        p_next: &mut vulkan11 as *mut _ as *mut c_void,
+        ..Default::default()
+    };
+    unsafe {
+        info.push_chain(&mut maintenance4);
+    }
+    let maintenance4_ptr = &maintenance4 as *const _ as *const c_void;
+    let vulkan11_ptr = &vulkan11 as *const _ as *mut c_void;
+    let robustness_ptr = &robustness as *const _ as *mut c_void;
+    assert_eq!(info.p_next, maintenance4_ptr);
+    assert_eq!(maintenance4.p_next, vulkan11_ptr);
+    assert_eq!(vulkan11.p_next, robustness_ptr);
+
+    assert_eq!(size_of::<vk::DeviceCreateInfo>(), 72);
+    assert_eq!(size_of::<vk::PhysicalDeviceVulkan13Features>(), 80);
+}
+
+#[test]
+fn downcast() {
+    use vk::traits::TaggedStruct;
+    assert_eq!(
+        vk::DeviceCreateInfo::STRUCTURE_TYPE,
+        vk::StructureType::DEVICE_CREATE_INFO,
+    );
+
+    let mut features = vk::PhysicalDeviceVulkan12Features {
+        timeline_semaphore: 1,
+        ..Default::default()
+    };
+    unsafe {
+        let base = &mut features as *mut _ as *mut vk::BaseOutStructure;
+        let base = &mut *base;
+        let other = base.downcast_ref::<vk::PhysicalDeviceVulkan13Features>();
+        assert!(other.is_none());
+        let found = base
+            .downcast_mut::<vk::PhysicalDeviceVulkan12Features>()
+            .unwrap();
+        found.descriptor_indexing = 1;
+    }
+    assert_eq!(features.timeline_semaphore, 1);
+    assert_eq!(features.descriptor_indexing, 1);
+}
+
+#[test]
+fn chain() {
+    use vk::chain;
+    let mut props = vk::PhysicalDeviceProperties2::default();
+    let mut driver = vk::PhysicalDeviceDriverProperties::default();
+    let mut vulkan11 = vk::PhysicalDeviceVulkan11Properties::default();
+    let mut vulkan12 = vk::PhysicalDeviceVulkan12Properties::default();
+    let mut vulkan13 = vk::PhysicalDeviceVulkan13Properties::default();
+    vulkan11.p_next = &mut vulkan12 as *mut _ as _;
+
+    let types = |props: &vk::PhysicalDeviceProperties2| -> Vec<_> {
+        unsafe { chain::iter(props).map(|(s_type, _)| s_type).collect() }
+    };
+    unsafe {
+        chain::insert(&mut props, &mut driver);
+        chain::insert(&mut props, &mut vulkan13);
+        chain::splice(&mut props, &mut vulkan11);
+    }
+    assert_eq!(types(&props), [
+        vk::StructureType::PHYSICAL_DEVICE_VULKAN_1_1_PROPERTIES,
+        vk::StructureType::PHYSICAL_DEVICE_VULKAN_1_2_PROPERTIES,
+        vk::StructureType::PHYSICAL_DEVICE_DRIVER_PROPERTIES,
+        vk::StructureType::PHYSICAL_DEVICE_VULKAN_1_3_PROPERTIES,
+    ]);
+
+    // As a driver would fill it in
+    type Vulkan12 = vk::PhysicalDeviceVulkan12Properties;
+    type Vulkan13 = vk::PhysicalDeviceVulkan13Properties;
+    unsafe {
+        let found = chain::find_mut::<Vulkan13, _>(&mut props).unwrap();
+        found.max_inline_uniform_block_size = 256;
+        let found = chain::find::<Vulkan13, _>(&props).unwrap();
+        assert_eq!(found.max_inline_uniform_block_size, 256);
+
+        let removed = chain::remove::<Vulkan12, _>(&mut props).unwrap();
+        assert!(removed.p_next.is_null());
+        assert!(chain::remove::<Vulkan12, _>(&mut props).is_none());
+    }
+    assert_eq!(types(&props), [
+        vk::StructureType::PHYSICAL_DEVICE_VULKAN_1_1_PROPERTIES,
+        vk::StructureType::PHYSICAL_DEVICE_DRIVER_PROPERTIES,
+        vk::StructureType::PHYSICAL_DEVICE_VULKAN_1_3_PROPERTIES,
+    ]);
+    assert_eq!(vulkan13.max_inline_uniform_block_size, 256);
+}
+
+#[test]
+fn builder() {
+    let bindings = [
+        vk::DescriptorSetLayoutBinding::builder()
+            .binding(0)
+            .descriptor_type(vk::DescriptorType::UNIFORM_BUFFER)
+            .descriptor_count(1)
+            .build(),
+        vk::DescriptorSetLayoutBinding::builder()
+            .binding(1)
+            .descriptor_type(vk::DescriptorType::STORAGE_BUFFER)
+            .descriptor_count(1)
+            .build(),
+    ];
+    let info =
+        vk::DescriptorSetLayoutCreateInfo::builder().bindings(&bindings);
+    assert_eq!(
+        info.s_type,
+        vk::StructureType::DESCRIPTOR_SET_LAYOUT_CREATE_INFO,
+    );
+    assert_eq!(info.binding_count, 2);
+    assert_eq!(info.p_bindings, bindings.as_ptr());
+
+    // The count of `p_code` is in bytes
+    let code = [0x0723_0203u32; 5];
+    let info = vk::ShaderModuleCreateInfo::builder().code(&code);
+    assert_eq!(info.code_size, 20);
+
+    let info: &vk::ShaderModuleCreateInfo = &info;
+    assert_eq!(info.p_code, code.as_ptr());
+    assert_eq!(
+        std::mem::size_of::<vk::ShaderModuleCreateInfoBuilder>(),
+        std::mem::size_of::<vk::ShaderModuleCreateInfo>(),
+    );
+}
+
+#[test]
+fn builder_shared_count() {
+    let semaphores = [vk::Semaphore::default(); 2];
+    let stages = [vk::PipelineStageFlags::TRANSFER_BIT; 2];
+    let info = vk::SubmitInfo::builder()
+        .wait_semaphores(&semaphores)
+        .wait_dst_stage_mask(&stages);
+    assert_eq!(info.wait_semaphore_count, 2);
+}
+
+#[test]
+#[should_panic(expected = "slice length doesn't match")]
+fn builder_shared_count_mismatch() {
+    let semaphores = [vk::Semaphore::default(); 2];
+    let stages = [vk::PipelineStageFlags::TRANSFER_BIT];
+    let _ = vk::SubmitInfo::builder()
+        .wait_semaphores(&semaphores)
+        .wait_dst_stage_mask(&stages);
+}
+
+#[test]
+#[should_panic(expected = "slice length doesn't match")]
+fn builder_shared_count_empty_mismatch() {
+    let stages = [vk::PipelineStageFlags::TRANSFER_BIT; 2];
+    let _ = vk::SubmitInfo::builder()
+        .wait_semaphores(&[])
+        .wait_dst_stage_mask(&stages);
+}
+
+#[test]
+fn builder_shared_count_reset() {
+    let semaphores = [vk::Semaphore::default(); 3];
+    let stages = [vk::PipelineStageFlags::TRANSFER_BIT; 3];
+    let info = vk::SubmitInfo::builder()
+        .wait_semaphores(&semaphores[..2])
+        .wait_semaphores(&semaphores)
+        .wait_dst_stage_mask(&stages);
+    assert_eq!(info.wait_semaphore_count, 3);
+}
+
+#[test]
+fn accessors() {
+    use std::ffi::CStr;
+    let extensions = [
+        b"VK_KHR_surface\0".as_ptr() as *const _,
+        b"VK_KHR_xcb_surface\0".as_ptr() as *const _,
+    ];
+    let info = vk::InstanceCreateInfo {
+        enabled_extension_count: 2,
+        pp_enabled_extension_names: extensions.as_ptr(),
+        ..Default::default()
+    };
+    let code = [0x0723_0203u32; 5];
+    let module = vk::ShaderModuleCreateInfo {
+        code_size: 20,
+        p_code: code.as_ptr(),
+        ..Default::default()
+    };
+    let message = CStr::from_bytes_with_nul(b"oops\0").unwrap();
+    let data = vk::DebugUtilsMessengerCallbackDataEXT {
+        p_message: message.as_ptr(),
+        ..Default::default()
+    };
+    unsafe {
+        let names: Vec<_> = info
+            .enabled_extension_names()
+            .map(|name| name.to_str().unwrap())
+            .collect();
+        assert_eq!(names, ["VK_KHR_surface", "VK_KHR_xcb_surface"]);
+        assert_eq!(info.enabled_layer_names().len(), 0);
+        assert_eq!(module.code(), &code);
+
+        assert_eq!(data.message(), Some(message));
+        assert_eq!(data.message_id_name(), None);
+        assert!(data.objects().is_empty());
+    }
+}
+
 #[cfg(feature = "reflection")]
 mod reflection {
     use std::str::FromStr;
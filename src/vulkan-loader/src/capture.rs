@@ -0,0 +1,542 @@
+use std::collections::HashMap;
+use std::convert::TryInto;
+use std::ffi::{c_void, CStr};
+use std::fmt::{self, Write as _};
+use std::io;
+use std::mem;
+use std::ptr;
+use std::slice;
+use std::sync::{Mutex, PoisonError};
+
+use crate::hooks::{Call, Hooks, Object, Pointer, Value};
+use crate::{json, Command, DeviceTable};
+
+// The memory behind an argument: its bytes, the handles among them,
+// and the pointers, with the memory they point to.
+#[derive(Clone, Debug, Default)]
+struct Blob {
+    bytes: Vec<u8>,
+    handles: Vec<(usize, vk::ObjectType)>,
+    pointers: Vec<PointerSlot>,
+}
+
+#[derive(Clone, Debug)]
+struct PointerSlot {
+    offset: usize,
+    output: bool,
+    // `None` if the pointer is null or couldn't be followed
+    target: Option<Blob>,
+}
+
+fn handle_size(ty: vk::ObjectType) -> usize {
+    match ty {
+        vk::ObjectType::INSTANCE
+        | vk::ObjectType::PHYSICAL_DEVICE
+        | vk::ObjectType::DEVICE
+        | vk::ObjectType::QUEUE
+        | vk::ObjectType::COMMAND_BUFFER => mem::size_of::<usize>(),
+        _ => mem::size_of::<u64>(),
+    }
+}
+
+fn read_handle(bytes: &[u8], offset: usize, ty: vk::ObjectType) -> u64 {
+    let bytes = &bytes[offset..offset + handle_size(ty)];
+    match *bytes {
+        [a, b, c, d] => u32::from_ne_bytes([a, b, c, d]) as u64,
+        _ => u64::from_ne_bytes(bytes.try_into().unwrap()),
+    }
+}
+
+fn write_handle(
+    bytes: &mut [u8],
+    offset: usize,
+    ty: vk::ObjectType,
+    handle: u64,
+) {
+    let size = handle_size(ty);
+    let bytes = &mut bytes[offset..offset + size];
+    match size {
+        4 => bytes.copy_from_slice(&(handle as u32).to_ne_bytes()),
+        _ => bytes.copy_from_slice(&handle.to_ne_bytes()),
+    }
+}
+
+impl Blob {
+    unsafe fn from_raw(ptr: *const u8, len: usize) -> Self {
+        Blob {
+            bytes: slice::from_raw_parts(ptr, len).to_vec(),
+            ..Default::default()
+        }
+    }
+
+    fn pointer(ptr: *const c_void, output: bool, target: Option<Blob>) -> Self {
+        Blob {
+            bytes: (ptr as usize).to_ne_bytes().to_vec(),
+            pointers: vec![PointerSlot { offset: 0, output, target }],
+            ..Default::default()
+        }
+    }
+
+    // Records a value which is stored at `offset` within this blob.
+    fn embed(&mut self, offset: usize, value: Blob) {
+        for (at, ty) in value.handles {
+            self.handles.push((offset + at, ty));
+        }
+        for slot in value.pointers {
+            let offset = offset + slot.offset;
+            self.pointers.push(PointerSlot { offset, ..slot });
+        }
+    }
+
+    // Appends an array element.
+    fn push(&mut self, element: Blob) {
+        let offset = self.bytes.len();
+        self.bytes.extend_from_slice(&element.bytes);
+        self.embed(offset, element);
+    }
+
+    unsafe fn capture(value: &Value) -> Self {
+        match *value {
+            Value::Handle(object) => {
+                let mut bytes = vec![0; handle_size(object.ty)];
+                write_handle(&mut bytes, 0, object.ty, object.handle);
+                Blob { bytes, handles: vec![(0, object.ty)], pointers: vec![] }
+            },
+            Value::Str(s) => {
+                let target = (!s.is_null()).then(|| {
+                    let s = CStr::from_ptr(s).to_bytes_with_nul();
+                    Blob::from_raw(s.as_ptr(), s.len())
+                });
+                Blob::pointer(s as _, false, target)
+            },
+            Value::Pointer(ptr) => Blob::capture_pointer(&ptr),
+            Value::Struct(value) => {
+                let mut blob = Blob::from_raw(
+                    value as *const _ as *const u8,
+                    mem::size_of_val(value),
+                );
+                for member in value.members() {
+                    blob.embed(member.offset, Blob::capture(&member.value));
+                }
+                blob
+            },
+            Value::Opaque(ptr) => Blob::pointer(ptr, false, None),
+            Value::Debug(value) => Blob::from_raw(
+                value as *const _ as *const u8,
+                mem::size_of_val(value),
+            ),
+        }
+    }
+
+    unsafe fn capture_pointer(ptr: &Pointer) -> Self {
+        if ptr.is_null() {
+            return Blob::pointer(ptr.as_ptr(), ptr.is_output(), None);
+        }
+        let mut target = Blob::default();
+        for element in ptr.elements() {
+            if let Value::Opaque(_) = element {
+                return Blob::pointer(ptr.as_ptr(), ptr.is_output(), None);
+            }
+            target.push(Blob::capture(&element));
+        }
+        Blob::pointer(ptr.as_ptr(), ptr.is_output(), Some(target))
+    }
+
+    fn write_json(&self, out: &mut String) {
+        out.push_str("{\"bytes\":\"");
+        for byte in self.bytes.iter() {
+            let _ = write!(out, "{:02x}", byte);
+        }
+        out.push('"');
+        if !self.handles.is_empty() {
+            out.push_str(",\"handles\":[");
+            for (i, &(offset, ty)) in self.handles.iter().enumerate() {
+                if i > 0 {
+                    out.push(',');
+                }
+                let _ = write!(out, "[{},{}]", offset, ty.0);
+            }
+            out.push(']');
+        }
+        if !self.pointers.is_empty() {
+            out.push_str(",\"pointers\":[");
+            for (i, slot) in self.pointers.iter().enumerate() {
+                if i > 0 {
+                    out.push(',');
+                }
+                let _ = write!(out, "[{},{},", slot.offset, slot.output);
+                match slot.target {
+                    Some(ref target) => target.write_json(out),
+                    None => out.push_str("null"),
+                }
+                out.push(']');
+            }
+            out.push(']');
+        }
+        out.push('}');
+    }
+
+    fn parse(value: &json::Value) -> Option<Self> {
+        let hex = value.get("bytes")?.as_str()?;
+        if hex.len() % 2 != 0 {
+            return None;
+        }
+        let bytes = (0..hex.len()).step_by(2)
+            .map(|i| u8::from_str_radix(hex.get(i..i + 2)?, 16).ok())
+            .collect::<Option<Vec<_>>>()?;
+
+        let list = |key| match value.get(key) {
+            Some(list) => list.as_array(),
+            None => Some(&[][..]),
+        };
+
+        let mut handles = Vec::new();
+        for handle in list("handles")? {
+            let [offset, ty] = handle.as_array()? else { return None };
+            let offset = offset.as_f64()? as usize;
+            let ty = vk::ObjectType(ty.as_f64()? as i32);
+            if offset + handle_size(ty) > bytes.len() {
+                return None;
+            }
+            handles.push((offset, ty));
+        }
+
+        let mut pointers = Vec::new();
+        for slot in list("pointers")? {
+            let [offset, output, target] = slot.as_array()? else {
+                return None;
+            };
+            let offset = offset.as_f64()? as usize;
+            if offset + mem::size_of::<usize>() > bytes.len() {
+                return None;
+            }
+            let target = match target {
+                json::Value::Null => None,
+                target => Some(Blob::parse(target)?),
+            };
+            let output = output.as_bool()?;
+            pointers.push(PointerSlot { offset, output, target });
+        }
+
+        Some(Blob { bytes, handles, pointers })
+    }
+}
+
+unsafe fn capture_line(call: &Call, result: Option<vk::Result>) -> String {
+    let failed = result.is_some_and(|result| result.0 < 0);
+    let mut line = String::new();
+    line.push_str("{\"command\":");
+    json::write_string(&mut line, call.command.name());
+    line.push_str(",\"args\":{");
+    for (i, arg) in call.args.iter().enumerate() {
+        if i > 0 {
+            line.push(',');
+        }
+        json::write_string(&mut line, arg.name);
+        line.push(':');
+        let blob = match arg.value {
+            // Failed calls aren't replayed, and may leave their outputs
+            // unwritten
+            Value::Pointer(ptr) if ptr.is_output() && failed => {
+                Blob::pointer(ptr.as_ptr(), true, None)
+            },
+            _ => Blob::capture(&arg.value),
+        };
+        blob.write_json(&mut line);
+    }
+    line.push('}');
+    if let Some(result) = result {
+        let _ = write!(line, ",\"result\":{}", result.0);
+    }
+    line.push_str("}\n");
+    line
+}
+
+#[derive(Debug)]
+struct Sink<W> {
+    writer: W,
+    error: Option<io::Error>,
+}
+
+/// Hooks which write each command to a capture file after it returns,
+/// for `Replayer` to play back later. The file has one JSON object per
+/// line, holding the bytes of each argument and of the structures,
+/// arrays and strings it points to, and where the handles among them
+/// are.
+///
+/// Memory which commands don't see, like writes through mapped
+/// pointers, isn't captured. Nor are pointers of unknown length, such
+/// as `pUserData` and unknown `pNext` structures; these are null when
+/// replayed.
+///
+/// ```ignore
+/// let recorder = Recorder::new(File::create("capture.jsonl")?);
+/// let device_table = Hooked::new(device_table, &recorder);
+/// ```
+#[derive(Debug)]
+pub struct Recorder<W> {
+    sink: Mutex<Sink<W>>,
+}
+
+impl<W: io::Write> Recorder<W> {
+    pub fn new(writer: W) -> Self {
+        Recorder { sink: Mutex::new(Sink { writer, error: None }) }
+    }
+
+    /// Returns the writer, or the first error writing to it. Commands
+    /// are called either way.
+    pub fn into_inner(self) -> io::Result<W> {
+        let sink =
+            self.sink.into_inner().unwrap_or_else(PoisonError::into_inner);
+        match sink.error {
+            Some(error) => Err(error),
+            None => Ok(sink.writer),
+        }
+    }
+}
+
+impl<W: io::Write> Hooks for Recorder<W> {
+    fn call(
+        &self,
+        call: &Call<'_>,
+        next: &mut dyn FnMut() -> Option<vk::Result>,
+    ) -> Option<vk::Result> {
+        // Arguments are read afterwards to capture the handles and
+        // lengths the command writes.
+        let result = next();
+        let line = unsafe { capture_line(call, result) };
+        let mut sink = self.sink.lock().unwrap_or_else(PoisonError::into_inner);
+        if sink.error.is_none() {
+            if let Err(error) = sink.writer.write_all(line.as_bytes()) {
+                sink.error = Some(error);
+            }
+        }
+        result
+    }
+}
+
+/// Why a capture file couldn't be replayed. `line` counts from 1.
+#[derive(Debug)]
+pub enum ReplayError {
+    Io(io::Error),
+    /// The line isn't a captured call, or its arguments don't match
+    /// the command's parameters in number or size.
+    Parse { line: usize },
+    /// The command isn't a device-level command, or the table doesn't
+    /// have it.
+    Unsupported { line: usize, command: String },
+    /// An argument refers to a handle which wasn't created during the
+    /// capture or given to `Replayer::map`.
+    UnknownHandle { line: usize, command: Command, object: Object },
+    /// The command failed, although it succeeded during the capture.
+    Failed { line: usize, command: Command, result: vk::Result },
+}
+
+// Why a table couldn't replay a call.
+pub(crate) enum Unreplayable {
+    // The command isn't in the table, or wasn't loaded
+    Unsupported,
+    // The arguments don't match the command's parameters
+    Malformed,
+}
+
+impl fmt::Display for ReplayError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            ReplayError::Io(error) => write!(f, "{}", error),
+            ReplayError::Parse { line } => {
+                write!(f, "line {}: malformed call", line)
+            },
+            ReplayError::Unsupported { line, command } => {
+                write!(f, "line {}: {} is not supported", line, command)
+            },
+            ReplayError::UnknownHandle { line, command, object } => write!(
+                f,
+                "line {}: {} uses unknown handle {:?}",
+                line,
+                command.name(),
+                object,
+            ),
+            ReplayError::Failed { line, command, result } => write!(
+                f,
+                "line {}: {} failed with {:?}",
+                line,
+                command.name(),
+                result,
+            ),
+        }
+    }
+}
+
+impl std::error::Error for ReplayError {}
+
+impl From<io::Error> for ReplayError {
+    fn from(error: io::Error) -> Self {
+        ReplayError::Io(error)
+    }
+}
+
+// Arguments laid out in memory for a call.
+#[derive(Default)]
+struct Memory<'a> {
+    buffers: Vec<Vec<u64>>,
+    outputs: Vec<(&'a Blob, *const u8)>,
+}
+
+/// Plays a capture file back on a `DeviceTable`, which may belong to
+/// another device or driver. Handles the capture created are replaced
+/// with the ones the replayed commands create, and the captured device
+/// with the table's.
+///
+/// ```ignore
+/// let mut replayer = Replayer::new(&device_table);
+/// replayer.replay(BufReader::new(File::open("capture.jsonl")?))?;
+/// ```
+#[derive(Debug)]
+pub struct Replayer<'a> {
+    table: &'a DeviceTable,
+    handles: HashMap<(vk::ObjectType, u64), u64>,
+}
+
+impl<'a> Replayer<'a> {
+    pub fn new(table: &'a DeviceTable) -> Self {
+        Replayer { table, handles: HashMap::new() }
+    }
+
+    /// Replaces a handle which wasn't created during the capture, such
+    /// as a swapchain image.
+    pub fn map(&mut self, captured: Object, handle: u64) {
+        self.handles.insert((captured.ty, captured.handle), handle);
+    }
+
+    /// Returns the replayed counterpart of a captured handle.
+    pub fn get(&self, captured: Object) -> Option<u64> {
+        if captured.is_null() {
+            Some(0)
+        } else if captured.ty == vk::ObjectType::DEVICE {
+            Some(self.table.device.into())
+        } else {
+            self.handles.get(&(captured.ty, captured.handle)).copied()
+        }
+    }
+
+    /// Replays every call in a capture file, stopping at the first
+    /// error. Calls which failed during the capture are skipped.
+    ///
+    /// # Safety
+    ///
+    /// The calls must be valid on the table's device, as when they
+    /// were captured.
+    pub unsafe fn replay(
+        &mut self,
+        reader: impl io::BufRead,
+    ) -> Result<(), ReplayError> {
+        for (i, line) in reader.lines().enumerate() {
+            let line = line?;
+            if !line.trim().is_empty() {
+                self.replay_line(i + 1, &line)?;
+            }
+        }
+        Ok(())
+    }
+
+    unsafe fn replay_line(
+        &mut self,
+        line: usize,
+        text: &str,
+    ) -> Result<(), ReplayError> {
+        let value = json::parse(text).map_err(|_| ReplayError::Parse { line })?;
+        let parse = || -> Option<_> {
+            let name = value.get("command")?.as_str()?;
+            let args = value.get("args")?.as_object()?.iter()
+                .map(|(_, arg)| Blob::parse(arg))
+                .collect::<Option<Vec<_>>>()?;
+            let result = match value.get("result") {
+                Some(result) => Some(vk::Result(result.as_f64()? as i32)),
+                None => None,
+            };
+            Some((name, args, result))
+        };
+        let (name, args, result) = parse().ok_or(ReplayError::Parse { line })?;
+        let unsupported = || ReplayError::Unsupported {
+            line,
+            command: name.to_owned(),
+        };
+        let command = Command::from_name(name).ok_or_else(unsupported)?;
+        if result.is_some_and(|result| result.0 < 0) {
+            return Ok(());
+        }
+
+        let mut memory = Memory::default();
+        let mut ptrs = Vec::with_capacity(args.len());
+        for arg in args.iter() {
+            let ptr = self.materialize(arg, false, &mut memory).map_err(
+                |object| ReplayError::UnknownHandle { line, command, object },
+            )?;
+            ptrs.push((ptr as *const u8, arg.bytes.len()));
+        }
+        let status = match self.table.replay(command, &ptrs) {
+            Ok(status) => status,
+            Err(Unreplayable::Unsupported) => return Err(unsupported()),
+            Err(Unreplayable::Malformed) => {
+                return Err(ReplayError::Parse { line })
+            },
+        };
+        if let Some(result) = status.filter(|result| result.0 < 0) {
+            return Err(ReplayError::Failed { line, command, result });
+        }
+
+        for &(blob, ptr) in memory.outputs.iter() {
+            let bytes = slice::from_raw_parts(ptr, blob.bytes.len());
+            for &(offset, ty) in blob.handles.iter() {
+                let captured = read_handle(&blob.bytes, offset, ty);
+                if captured != 0 {
+                    let handle = read_handle(bytes, offset, ty);
+                    self.handles.insert((ty, captured), handle);
+                }
+            }
+        }
+        Ok(())
+    }
+
+    // Copies a blob to memory, replacing handles and pointers. Handles
+    // in output memory are left for the command to write.
+    fn materialize<'b>(
+        &self,
+        blob: &'b Blob,
+        output: bool,
+        memory: &mut Memory<'b>,
+    ) -> Result<*mut u8, Object> {
+        let mut buffer = vec![0u64; blob.bytes.len().div_ceil(8)];
+        let bytes = unsafe {
+            let ptr = buffer.as_mut_ptr() as *mut u8;
+            slice::from_raw_parts_mut(ptr, blob.bytes.len())
+        };
+        bytes.copy_from_slice(&blob.bytes);
+        if !output {
+            for &(offset, ty) in blob.handles.iter() {
+                let handle = read_handle(bytes, offset, ty);
+                let object = Object { ty, handle };
+                let handle = self.get(object).ok_or(object)?;
+                write_handle(bytes, offset, ty, handle);
+            }
+        }
+        for slot in blob.pointers.iter() {
+            let ptr = match slot.target {
+                Some(ref target) => {
+                    self.materialize(target, output || slot.output, memory)?
+                },
+                None => ptr::null_mut(),
+            };
+            let at = slot.offset..slot.offset + mem::size_of::<usize>();
+            bytes[at].copy_from_slice(&(ptr as usize).to_ne_bytes());
+        }
+
+        let ptr = bytes.as_mut_ptr();
+        if output {
+            memory.outputs.push((blob, ptr));
+        }
+        memory.buffers.push(buffer);
+        Ok(ptr)
+    }
+}
@@ -0,0 +1,328 @@
+use std::env;
+use std::ffi::OsString;
+use std::io;
+use std::marker::PhantomData;
+use std::path::{Path, PathBuf};
+
+use vk::pfn;
+
+use crate::icd::{self, Manifest};
+use crate::{
+    Entry, InstanceTable, LoadAttempt, LoadError, PhysicalDeviceView,
+    VkResult,
+};
+
+/// Environment variable which, if set, replaces the default search
+/// with a list of manifests or directories of manifests.
+pub const DRIVER_FILES_VAR: &str = "VK_DRIVER_FILES";
+/// The deprecated name of `VK_DRIVER_FILES`.
+pub const ICD_FILENAMES_VAR: &str = "VK_ICD_FILENAMES";
+/// Environment variable listing manifests or directories to load in
+/// addition to the others.
+pub const ADD_DRIVER_FILES_VAR: &str = "VK_ADD_DRIVER_FILES";
+
+/// The loader-driver interface version offered to drivers. Drivers
+/// which negotiate version 3 or newer create surfaces themselves, and
+/// version 5 or newer accept any `apiVersion`.
+pub const LOADER_ICD_INTERFACE_VERSION: u32 = 5;
+
+// The directories searched for manifests when `VK_DRIVER_FILES` isn't
+// set, in the Khronos loader's order. On Windows, drivers are listed
+// in the registry instead, which isn't supported here.
+#[cfg(unix)]
+fn search_dirs() -> Vec<PathBuf> {
+    let home = env::var_os("HOME").map(PathBuf::from);
+    let var = |name| env::var_os(name).filter(|val| !val.is_empty());
+    let split = |val: OsString| env::split_paths(&val).collect::<Vec<_>>();
+
+    let mut dirs = Vec::new();
+    dirs.extend(var("XDG_CONFIG_HOME").map(PathBuf::from)
+        .or_else(|| home.as_ref().map(|home| home.join(".config"))));
+    dirs.extend(split(var("XDG_CONFIG_DIRS").unwrap_or_else(|| "/etc/xdg".into())));
+    dirs.push("/etc".into());
+    dirs.extend(var("XDG_DATA_HOME").map(PathBuf::from)
+        .or_else(|| home.as_ref().map(|home| home.join(".local/share"))));
+    dirs.extend(split(var("XDG_DATA_DIRS")
+        .unwrap_or_else(|| "/usr/local/share:/usr/share".into())));
+    dirs.into_iter().map(|dir| dir.join("vulkan/icd.d")).collect()
+}
+
+#[cfg(not(unix))]
+fn search_dirs() -> Vec<PathBuf> {
+    Vec::new()
+}
+
+// Lists the manifests in a directory in a stable order.
+fn read_manifest_dir(dir: &Path, paths: &mut Vec<PathBuf>) {
+    let entries = match dir.read_dir() {
+        Ok(entries) => entries,
+        Err(_) => return,
+    };
+    let mut manifests: Vec<_> = entries
+        .filter_map(|entry| Some(entry.ok()?.path()))
+        .filter(|path| path.extension().is_some_and(|ext| ext == "json"))
+        .collect();
+    manifests.sort();
+    paths.extend(manifests);
+}
+
+/// Lists the ICD manifests to load, honoring `VK_DRIVER_FILES`,
+/// `VK_ICD_FILENAMES` and `VK_ADD_DRIVER_FILES` as the Khronos loader
+/// does. Files named by the environment are listed even if they don't
+/// exist, so that `load_manifests` reports them.
+pub fn manifest_paths() -> Vec<PathBuf> {
+    let mut explicit = Vec::new();
+    if let Some(files) = env::var_os(ADD_DRIVER_FILES_VAR) {
+        explicit.extend(env::split_paths(&files));
+    }
+    let files = env::var_os(DRIVER_FILES_VAR)
+        .or_else(|| env::var_os(ICD_FILENAMES_VAR));
+    let search = match files {
+        Some(files) => {
+            explicit.extend(env::split_paths(&files));
+            Vec::new()
+        },
+        None => search_dirs(),
+    };
+
+    let mut paths = Vec::new();
+    for path in explicit {
+        if path.is_dir() {
+            read_manifest_dir(&path, &mut paths);
+        } else {
+            paths.push(path);
+        }
+    }
+    for dir in search {
+        read_manifest_dir(&dir, &mut paths);
+    }
+    paths
+}
+
+// Relative library paths are relative to the manifest, while bare
+// file names are left for the dynamic linker to find.
+fn library_path(manifest_path: &Path, library_path: &Path) -> PathBuf {
+    if library_path.is_relative() && library_path.components().count() > 1 {
+        let dir = manifest_path.parent().unwrap_or_else(|| Path::new(""));
+        dir.join(library_path)
+    } else {
+        library_path.to_owned()
+    }
+}
+
+/// A driver library opened through its manifest, without going
+/// through the Khronos loader.
+#[derive(Debug)]
+pub struct Icd {
+    // Kept open while the driver's commands are in use
+    _lib: libloading::Library,
+    manifest_path: PathBuf,
+    manifest: Manifest,
+    interface_version: u32,
+    get_instance_proc_addr: pfn::GetInstanceProcAddr,
+    get_physical_device_proc_addr:
+        Option<icd::pfn::GetPhysicalDeviceProcAddr>,
+}
+
+impl Icd {
+    /// Reads a manifest, opens the driver it names, and negotiates the
+    /// interface version with it.
+    ///
+    /// # Safety
+    ///
+    /// Opening the driver runs its initialization code, and its entry
+    /// points must follow the loader-driver interface.
+    pub unsafe fn load(manifest_path: impl AsRef<Path>) -> io::Result<Self> {
+        let manifest_path = manifest_path.as_ref();
+        let manifest = Manifest::read(manifest_path)?;
+        let lib = libloading::Library::new(
+            library_path(manifest_path, &manifest.library_path),
+        )?;
+
+        let negotiate = lib.get::<icd::pfn::NegotiateLoaderICDInterfaceVersion>(
+            b"vk_icdNegotiateLoaderICDInterfaceVersion\0",
+        );
+        let interface_version = match negotiate {
+            Ok(negotiate) => {
+                let mut version = LOADER_ICD_INTERFACE_VERSION;
+                let res = negotiate(&mut version);
+                if res.is_error() {
+                    return Err(io::Error::other(
+                        format!("interface negotiation failed: {:?}", res),
+                    ));
+                }
+                version
+            },
+            // Predates negotiation
+            Err(_) => 1,
+        };
+        let get_instance_proc_addr = *lib
+            .get::<pfn::GetInstanceProcAddr>(b"vk_icdGetInstanceProcAddr\0")
+            .or_else(|_| lib.get(b"vkGetInstanceProcAddr\0"))?;
+        let get_physical_device_proc_addr = if interface_version
+            >= icd::MIN_PHYS_DEV_EXTENSION_ICD_INTERFACE_VERSION
+        {
+            lib.get(b"vk_icdGetPhysicalDeviceProcAddr\0").ok().map(|f| *f)
+        } else {
+            None
+        };
+
+        Ok(Icd {
+            _lib: lib,
+            manifest_path: manifest_path.to_owned(),
+            manifest,
+            interface_version,
+            get_instance_proc_addr,
+            get_physical_device_proc_addr,
+        })
+    }
+
+    pub fn manifest_path(&self) -> &Path {
+        &self.manifest_path
+    }
+
+    pub fn manifest(&self) -> &Manifest {
+        &self.manifest
+    }
+
+    pub fn interface_version(&self) -> u32 {
+        self.interface_version
+    }
+
+    pub fn get_instance_proc_addr(&self) -> pfn::GetInstanceProcAddr {
+        self.get_instance_proc_addr
+    }
+
+    pub fn get_physical_device_proc_addr(
+        &self,
+    ) -> Option<icd::pfn::GetPhysicalDeviceProcAddr> {
+        self.get_physical_device_proc_addr
+    }
+
+    /// The returned table calls straight into the driver, and is only
+    /// valid while the driver is loaded.
+    ///
+    /// # Safety
+    ///
+    /// The table must not be used after the `Icd` is dropped.
+    pub unsafe fn entry(&self) -> Entry {
+        Entry::load(self.get_instance_proc_addr)
+    }
+}
+
+/// Loads the driver of each manifest. Drivers which fail to load are
+/// skipped, unless none load, in which case the error lists every
+/// manifest that was tried.
+///
+/// # Safety
+///
+/// As for `Icd::load`, for each driver.
+pub unsafe fn load_manifests(
+    paths: impl IntoIterator<Item = impl AsRef<Path>>,
+) -> Result<Vec<Icd>, LoadError> {
+    let mut icds = Vec::new();
+    let mut attempts = Vec::new();
+    for path in paths {
+        let path = path.as_ref();
+        match Icd::load(path) {
+            Ok(icd) => icds.push(icd),
+            Err(error) => attempts.push(LoadAttempt {
+                path: path.as_os_str().to_owned(),
+                error,
+            }),
+        }
+    }
+    if icds.is_empty() {
+        return Err(LoadError { attempts });
+    }
+    Ok(icds)
+}
+
+/// Loads every driver listed by `manifest_paths`.
+///
+/// # Safety
+///
+/// As for `Icd::load`, for each driver.
+pub unsafe fn load_all() -> Result<Vec<Icd>, LoadError> {
+    load_manifests(manifest_paths())
+}
+
+/// An instance created on each of several drivers, whose physical
+/// devices are enumerated together.
+#[derive(Debug)]
+pub struct Instance<'a> {
+    tables: Vec<InstanceTable>,
+    _icds: PhantomData<&'a [Icd]>,
+}
+
+impl<'a> Instance<'a> {
+    /// Creates an instance on each driver. As with the Khronos loader,
+    /// drivers which return `VK_ERROR_INCOMPATIBLE_DRIVER` are left
+    /// out, and creation fails only if all of them do.
+    ///
+    /// # Safety
+    ///
+    /// `create_info` must follow the valid usage rules of
+    /// `vkCreateInstance`.
+    pub unsafe fn create(
+        icds: &'a [Icd],
+        create_info: &vk::InstanceCreateInfo,
+        allocator: Option<&vk::AllocationCallbacks>,
+    ) -> VkResult<Self> {
+        let mut instance = Instance { tables: Vec::new(), _icds: PhantomData };
+        for icd in icds {
+            match icd.entry().create_instance_checked(create_info, allocator) {
+                Ok(handle) => instance.tables.push(
+                    InstanceTable::load(handle, icd.get_instance_proc_addr),
+                ),
+                Err(vk::Result::ERROR_INCOMPATIBLE_DRIVER) => {},
+                Err(res) => {
+                    instance.destroy(allocator);
+                    return Err(res);
+                },
+            }
+        }
+        if instance.tables.is_empty() {
+            return Err(vk::Result::ERROR_INCOMPATIBLE_DRIVER);
+        }
+        Ok(instance)
+    }
+
+    /// The instance created on each driver.
+    pub fn tables(&self) -> &[InstanceTable] {
+        &self.tables
+    }
+
+    /// Lists the physical devices of every driver, each bound to the
+    /// table of the driver it belongs to.
+    ///
+    /// # Safety
+    ///
+    /// The instances must not have been destroyed.
+    pub unsafe fn enumerate_physical_devices(
+        &self,
+    ) -> VkResult<Vec<PhysicalDeviceView<'_>>> {
+        let mut physical_devices = Vec::new();
+        for table in self.tables.iter() {
+            let handles = table.enumerate_physical_devices_vec()?;
+            physical_devices.extend(
+                handles.into_iter().map(|pdev| table.physical_device(pdev)),
+            );
+        }
+        Ok(physical_devices)
+    }
+
+    /// Destroys the instance on each driver.
+    ///
+    /// # Safety
+    ///
+    /// No objects created from the instances may still exist, and
+    /// `allocator` must be compatible with the one they were created
+    /// with.
+    pub unsafe fn destroy(self, allocator: Option<&vk::AllocationCallbacks>) {
+        let allocator = allocator.map_or(std::ptr::null(), |p| p as *const _);
+        for table in self.tables.iter() {
+            table.destroy_instance(allocator);
+        }
+    }
+}
@@ -0,0 +1,196 @@
+use std::backtrace::{Backtrace, BacktraceStatus};
+use std::collections::HashMap;
+use std::fmt;
+use std::panic::Location;
+use std::sync::{Arc, Mutex, MutexGuard, PoisonError};
+use std::thread::{self, ThreadId};
+
+use crate::hooks::{Call, Hooks, Object, Value};
+use crate::Command;
+
+/// A call to a command, on some thread.
+#[derive(Clone, Debug)]
+pub struct Use {
+    pub command: Command,
+    pub thread: ThreadId,
+    pub thread_name: Option<String>,
+    /// Where the `Hooked` table's method was called from.
+    pub location: &'static Location<'static>,
+    /// The full stack, which is only captured if enabled by
+    /// `RUST_BACKTRACE` or `RUST_LIB_BACKTRACE`, as by
+    /// `Backtrace::capture`.
+    pub backtrace: Arc<Backtrace>,
+}
+
+impl Use {
+    fn new(call: &Call<'_>) -> Self {
+        let thread = thread::current();
+        Use {
+            command: call.command,
+            thread: thread.id(),
+            thread_name: thread.name().map(String::from),
+            location: call.location,
+            backtrace: Arc::new(Backtrace::capture()),
+        }
+    }
+}
+
+impl fmt::Display for Use {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{} on ", self.command.name())?;
+        match &self.thread_name {
+            Some(name) => write!(f, "thread '{}'", name)?,
+            None => write!(f, "{:?}", self.thread)?,
+        }
+        write!(f, " at {}", self.location)?;
+        if self.backtrace.status() == BacktraceStatus::Captured {
+            write!(f, ", backtrace:\n{}", self.backtrace)?;
+        }
+        Ok(())
+    }
+}
+
+/// Two threads used an externally synchronized object at once.
+#[derive(Clone, Debug)]
+pub struct Race {
+    pub object: Object,
+    /// The call which was using the object.
+    pub first: Use,
+    pub second: Use,
+}
+
+impl fmt::Display for Race {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "{:?} was used by two threads at once:\nfirst: {}\nsecond: {}",
+            self.object, self.first, self.second,
+        )
+    }
+}
+
+#[derive(Debug, Default)]
+struct State {
+    in_use: HashMap<Object, Arc<Use>>,
+    races: Vec<Race>,
+}
+
+// Finds the handles at `path` within an argument, through any arrays
+// along the way.
+unsafe fn find_handles(
+    value: &Value<'_>,
+    path: &[&str],
+    handles: &mut Vec<Object>,
+) {
+    match value {
+        Value::Handle(object) if path.is_empty() && !object.is_null() => {
+            if !handles.contains(object) {
+                handles.push(*object);
+            }
+        }
+        Value::Pointer(ptr) if !ptr.is_output() => {
+            for element in ptr.elements() {
+                find_handles(&element, path, handles);
+            }
+        }
+        Value::Struct(value) => {
+            let (name, path) = match path.split_first() {
+                Some(split) => split,
+                None => return,
+            };
+            for member in value.members() {
+                if member.name == *name {
+                    find_handles(&member.value, path, handles);
+                }
+            }
+        }
+        _ => {}
+    }
+}
+
+/// Hooks which report when two threads call commands with the same
+/// externally synchronized object at once, such as the `queue` of
+/// `vkQueueSubmit` or the command pool of `vkAllocateCommandBuffers`.
+/// The parameters come from the `externsync` annotations of the
+/// registry; see `Command::externsync_params`.
+///
+/// Each `Race` names both commands, their threads, and where they were
+/// called from, with a backtrace if enabled. The commands are still
+/// called.
+///
+/// ```ignore
+/// let checker = Checker::new();
+/// let device_table = Hooked::new(device_table, &checker);
+/// ...
+/// for race in checker.take_races() {
+///     eprintln!("{}", race);
+/// }
+/// ```
+#[derive(Debug, Default)]
+pub struct Checker {
+    state: Mutex<State>,
+}
+
+impl Checker {
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    /// Returns the races found so far, and clears them.
+    pub fn take_races(&self) -> Vec<Race> {
+        std::mem::take(&mut self.state().races)
+    }
+
+    fn state(&self) -> MutexGuard<'_, State> {
+        self.state.lock().unwrap_or_else(PoisonError::into_inner)
+    }
+}
+
+impl Hooks for Checker {
+    fn call(
+        &self,
+        call: &Call<'_>,
+        next: &mut dyn FnMut() -> Option<vk::Result>,
+    ) -> Option<vk::Result> {
+        let mut handles = Vec::new();
+        for path in call.command.externsync_params() {
+            let path: Vec<_> = path.split('.').collect();
+            if let Some(value) = call.arg(path[0]) {
+                unsafe { find_handles(value, &path[1..], &mut handles) };
+            }
+        }
+        if handles.is_empty() {
+            return next();
+        }
+
+        let current = Arc::new(Use::new(call));
+        let mut acquired = Vec::new();
+        {
+            let mut state = self.state();
+            for object in handles {
+                match state.in_use.get(&object) {
+                    Some(other) if other.thread == current.thread => {}
+                    Some(other) => {
+                        let race = Race {
+                            object,
+                            first: Use::clone(other),
+                            second: Use::clone(&current),
+                        };
+                        state.races.push(race);
+                    }
+                    None => {
+                        state.in_use.insert(object, Arc::clone(&current));
+                        acquired.push(object);
+                    }
+                }
+            }
+        }
+
+        let result = next();
+        let mut state = self.state();
+        for object in acquired {
+            state.in_use.remove(&object);
+        }
+        result
+    }
+}
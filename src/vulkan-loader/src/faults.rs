@@ -0,0 +1,158 @@
+use std::collections::HashMap;
+use std::sync::{Mutex, MutexGuard, PoisonError};
+
+use crate::hooks::{Call, Hooks};
+use crate::Command;
+
+/// Which calls a `Fault` applies to. Calls are counted from 1, per
+/// command, or across all commands for a fault without one.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum When {
+    /// Only the `n`th call.
+    Nth(u64),
+    /// Every call after the first `n`, e.g. to simulate a lost device.
+    After(u64),
+    /// Each call with probability `p`, drawn from the seeded generator.
+    Probability(f64),
+}
+
+/// Makes a command return `result` instead of calling the driver.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Fault {
+    /// If `None`, any command which returns `VkResult` may fail.
+    pub command: Option<Command>,
+    pub when: When,
+    pub result: vk::Result,
+}
+
+impl Fault {
+    pub fn new(command: Command, when: When, result: vk::Result) -> Self {
+        Fault { command: Some(command), when, result }
+    }
+
+    pub fn any(when: When, result: vk::Result) -> Self {
+        Fault { command: None, when, result }
+    }
+}
+
+#[derive(Debug)]
+struct State {
+    faults: Vec<Fault>,
+    rng: u64,
+    calls: HashMap<Command, u64>,
+    total: u64,
+    injected: Vec<(Command, vk::Result)>,
+}
+
+// SplitMix64, which is plenty to pick faults reproducibly.
+fn next_u64(state: &mut u64) -> u64 {
+    *state = state.wrapping_add(0x9e37_79b9_7f4a_7c15);
+    let mut z = *state;
+    z = (z ^ (z >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+    z = (z ^ (z >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
+    z ^ (z >> 31)
+}
+
+fn next_f64(state: &mut u64) -> f64 {
+    (next_u64(state) >> 11) as f64 / (1u64 << 53) as f64
+}
+
+/// Hooks which make commands fail, to exercise error paths such as
+/// `VK_ERROR_DEVICE_LOST`. A failed command isn't called, so its
+/// output parameters are left untouched. Only commands which return
+/// `VkResult` fail; the first matching fault applies.
+///
+/// ```ignore
+/// let faults = Faults::new(seed).with(Fault::new(
+///     Command::AllocateMemory,
+///     When::Nth(2),
+///     vk::Result::ERROR_OUT_OF_DEVICE_MEMORY,
+/// ));
+/// let device_table = Hooked::new(device_table, &faults);
+/// ```
+#[derive(Debug)]
+pub struct Faults {
+    state: Mutex<State>,
+}
+
+impl Faults {
+    /// `seed` determines which calls fail with a `When::Probability`,
+    /// given the same sequence of calls.
+    pub fn new(seed: u64) -> Self {
+        Faults {
+            state: Mutex::new(State {
+                faults: Vec::new(),
+                rng: seed,
+                calls: HashMap::new(),
+                total: 0,
+                injected: Vec::new(),
+            }),
+        }
+    }
+
+    pub fn with(self, fault: Fault) -> Self {
+        self.add(fault);
+        self
+    }
+
+    pub fn add(&self, fault: Fault) {
+        self.state().faults.push(fault);
+    }
+
+    /// Removes every fault, so that commands succeed again. Calls are
+    /// still counted.
+    pub fn clear(&self) {
+        self.state().faults.clear();
+    }
+
+    /// Lists each command that was made to fail, in order.
+    pub fn injected(&self) -> Vec<(Command, vk::Result)> {
+        self.state().injected.clone()
+    }
+
+    fn state(&self) -> MutexGuard<'_, State> {
+        self.state.lock().unwrap_or_else(PoisonError::into_inner)
+    }
+
+    fn inject(&self, command: Command) -> Option<vk::Result> {
+        let mut state = self.state();
+        let state = &mut *state;
+        let calls = state.calls.entry(command).or_insert(0);
+        *calls += 1;
+        let calls = *calls;
+        state.total += 1;
+
+        let rng = &mut state.rng;
+        let total = state.total;
+        let result = state.faults.iter().find_map(|fault| {
+            let count = match fault.command {
+                Some(other) if other != command => return None,
+                Some(_) => calls,
+                None => total,
+            };
+            let fails = match fault.when {
+                When::Nth(n) => count == n,
+                When::After(n) => count > n,
+                When::Probability(p) => next_f64(rng) < p,
+            };
+            fails.then_some(fault.result)
+        })?;
+        state.injected.push((command, result));
+        Some(result)
+    }
+}
+
+impl Hooks for Faults {
+    fn call(
+        &self,
+        call: &Call<'_>,
+        next: &mut dyn FnMut() -> Option<vk::Result>,
+    ) -> Option<vk::Result> {
+        if call.command.returns_result() {
+            if let Some(result) = self.inject(call.command) {
+                return Some(result);
+            }
+        }
+        next()
+    }
+}
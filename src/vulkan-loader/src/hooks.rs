@@ -0,0 +1,411 @@
+use std::ffi::c_void;
+use std::fmt::Debug;
+use std::os::raw::c_char;
+use std::panic::Location;
+use std::sync::Arc;
+
+use crate::Command;
+
+/// A handle type with a `VkObjectType`.
+pub trait Handle: Copy + Into<u64> + 'static {
+    const OBJECT_TYPE: vk::ObjectType;
+}
+
+/// A handle of any type.
+#[derive(Clone, Copy, Eq, Hash, PartialEq)]
+pub struct Object {
+    pub ty: vk::ObjectType,
+    pub handle: u64,
+}
+
+impl Object {
+    pub fn new<T: Handle>(handle: T) -> Self {
+        Object { ty: T::OBJECT_TYPE, handle: handle.into() }
+    }
+
+    pub fn is_null(&self) -> bool {
+        self.handle == 0
+    }
+}
+
+// Formatted like the handle types themselves, e.g. `Buffer(0x1)`.
+impl Debug for Object {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        let name = self.type_name();
+        write!(f, "{}({:#x})", &name[2..], self.handle)
+    }
+}
+
+// The number of elements behind a pointer.
+#[derive(Clone, Copy, Debug)]
+pub(crate) enum Len {
+    One,
+    Count(usize),
+    // Written by the command, e.g. `pPhysicalDeviceCount`
+    Pointer(*const u32),
+}
+
+// Distinguishes input from output pointers.
+pub(crate) trait RawPtr: Copy {
+    type Target;
+    const OUTPUT: bool;
+    fn cast(self) -> *const c_void;
+}
+
+impl<T> RawPtr for *const T {
+    type Target = T;
+    const OUTPUT: bool = false;
+    fn cast(self) -> *const c_void {
+        self as _
+    }
+}
+
+impl<T> RawPtr for *mut T {
+    type Target = T;
+    const OUTPUT: bool = true;
+    fn cast(self) -> *const c_void {
+        self as _
+    }
+}
+
+unsafe fn debug_element<T: Debug + 'static>(
+    ptr: *const c_void,
+    index: usize,
+) -> Value<'static> {
+    Value::Debug(&*(ptr as *const T).add(index))
+}
+
+unsafe fn handle_element<T: Handle>(
+    ptr: *const c_void,
+    index: usize,
+) -> Value<'static> {
+    Value::Handle(Object::new(*(ptr as *const T).add(index)))
+}
+
+pub(crate) unsafe fn struct_element<T: Reflect + 'static>(
+    ptr: *const c_void,
+    index: usize,
+) -> Value<'static> {
+    Value::Struct(&*(ptr as *const T).add(index))
+}
+
+unsafe fn str_element(ptr: *const c_void, index: usize) -> Value<'static> {
+    Value::Str(*(ptr as *const *const c_char).add(index))
+}
+
+/// A pointer argument, to either a single value or an array whose
+/// length is given by another argument.
+#[derive(Clone, Copy, Debug)]
+pub struct Pointer {
+    ptr: *const c_void,
+    len: Len,
+    output: bool,
+    element: unsafe fn(*const c_void, usize) -> Value<'static>,
+}
+
+impl Pointer {
+    pub(crate) fn new<P>(ptr: P, len: Len) -> Self
+    where
+        P: RawPtr,
+        P::Target: Debug + 'static,
+    {
+        Pointer {
+            ptr: ptr.cast(),
+            len,
+            output: P::OUTPUT,
+            element: debug_element::<P::Target>,
+        }
+    }
+
+    pub(crate) fn handles<P>(ptr: P, len: Len) -> Self
+    where
+        P: RawPtr,
+        P::Target: Handle,
+    {
+        Pointer {
+            ptr: ptr.cast(),
+            len,
+            output: P::OUTPUT,
+            element: handle_element::<P::Target>,
+        }
+    }
+
+    pub(crate) fn structs<P>(ptr: P, len: Len) -> Self
+    where
+        P: RawPtr,
+        P::Target: Reflect + 'static,
+    {
+        Pointer {
+            ptr: ptr.cast(),
+            len,
+            output: P::OUTPUT,
+            element: struct_element::<P::Target>,
+        }
+    }
+
+    pub(crate) fn strs<P>(ptr: P, len: Len) -> Self
+    where
+        P: RawPtr<Target = *const c_char>,
+    {
+        Pointer {
+            ptr: ptr.cast(),
+            len,
+            output: P::OUTPUT,
+            element: str_element,
+        }
+    }
+
+    /// `void*` data, `len` bytes long.
+    pub(crate) fn bytes<P>(ptr: P, len: Len) -> Self
+    where
+        P: RawPtr<Target = c_void>,
+    {
+        Pointer {
+            ptr: ptr.cast(),
+            len,
+            output: P::OUTPUT,
+            element: debug_element::<u8>,
+        }
+    }
+
+    /// A `pNext` chain, which is opaque if the structure type of its
+    /// first element isn't known.
+    pub(crate) unsafe fn next<P: RawPtr>(ptr: P) -> Value<'static> {
+        let ptr = ptr.cast();
+        let element = if ptr.is_null() {
+            // Never called, as the pointer has no elements
+            Some(struct_element::<vk::BaseInStructure> as _)
+        } else {
+            Pointer::next_element(*(ptr as *const vk::StructureType))
+        };
+        match element {
+            Some(element) => Value::Pointer(Pointer {
+                ptr,
+                len: Len::One,
+                output: P::OUTPUT,
+                element,
+            }),
+            None => Value::Opaque(ptr),
+        }
+    }
+
+    pub fn as_ptr(&self) -> *const c_void {
+        self.ptr
+    }
+
+    pub fn is_null(&self) -> bool {
+        self.ptr.is_null()
+    }
+
+    /// True if the parameter has a length, even if it is 1.
+    pub fn is_array(&self) -> bool {
+        !matches!(self.len, Len::One)
+    }
+
+    /// True if the pointer is `*mut`, i.e. the command writes to it.
+    pub fn is_output(&self) -> bool {
+        self.output
+    }
+
+    /// Returns the number of elements, which is 0 if the pointer is
+    /// null. Lengths which the command writes, such as
+    /// `*pPhysicalDeviceCount`, are read at the time of the call.
+    ///
+    /// # Safety
+    ///
+    /// The length must be readable, as when calling the command.
+    pub unsafe fn len(&self) -> usize {
+        if self.ptr.is_null() {
+            return 0;
+        }
+        match self.len {
+            Len::One => 1,
+            Len::Count(count) => count,
+            Len::Pointer(ptr) if ptr.is_null() => 0,
+            Len::Pointer(ptr) => *ptr as usize,
+        }
+    }
+
+    /// # Safety
+    ///
+    /// As for `len`.
+    pub unsafe fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// # Safety
+    ///
+    /// `index` must be less than `len()`, and the element must be
+    /// readable. Output elements are only valid after the command.
+    pub unsafe fn get(&self, index: usize) -> Value<'_> {
+        (self.element)(self.ptr, index)
+    }
+
+    /// # Safety
+    ///
+    /// As for `get`.
+    pub unsafe fn elements(&self) -> impl Iterator<Item = Value<'_>> {
+        (0..self.len()).map(move |index| self.get(index))
+    }
+}
+
+/// How `Hooks` see an argument.
+#[derive(Clone, Copy, Debug)]
+pub enum Value<'a> {
+    Handle(Object),
+    /// A null-terminated string, e.g. `pName`.
+    Str(*const c_char),
+    Pointer(Pointer),
+    /// A structure with handles or pointers among its members.
+    Struct(&'a dyn Reflect),
+    /// A pointer which can't be followed, such as `void*` data of
+    /// unknown size or a `pNext` chain of an unknown structure type.
+    Opaque(*const c_void),
+    /// Anything else, through its `Debug` impl. This is plain data,
+    /// without handles or pointers.
+    Debug(&'a dyn Debug),
+}
+
+/// A structure whose members include handles or pointers, which
+/// `members` presents like arguments.
+pub trait Reflect: Debug {
+    /// Lists the members which aren't plain data, in order.
+    ///
+    /// # Safety
+    ///
+    /// The structure must be valid to pass to a command, as the
+    /// lengths of `pNext` chains and arrays are read from it.
+    unsafe fn members(&self) -> Vec<Member<'_>>;
+}
+
+#[derive(Clone, Copy, Debug)]
+pub struct Member<'a> {
+    pub name: &'static str,
+    /// The offset of the member within the structure.
+    pub offset: usize,
+    pub value: Value<'a>,
+}
+
+#[derive(Clone, Copy, Debug)]
+pub struct Arg<'a> {
+    pub name: &'static str,
+    pub value: Value<'a>,
+}
+
+/// A call to a command of a `Hooked` table. The arguments include the
+/// table's own handle, if the command takes it.
+#[derive(Clone, Copy, Debug)]
+pub struct Call<'a> {
+    pub command: Command,
+    pub args: &'a [Arg<'a>],
+    /// Where the table's method was called from.
+    pub location: &'static Location<'static>,
+}
+
+impl<'a> Call<'a> {
+    pub fn arg(&self, name: &str) -> Option<&Value<'a>> {
+        self.args.iter().find(|arg| arg.name == name).map(|arg| &arg.value)
+    }
+}
+
+/// Code which runs around each command called through a `Hooked`
+/// table.
+pub trait Hooks {
+    /// Called in place of the command. `next` calls the command (or
+    /// the next hook) and returns its `VkResult`, if it has one.
+    ///
+    /// If `call` returns `Some`, a command which returns `VkResult`
+    /// returns that instead. A hook may also skip the command by not
+    /// calling `next`, in which case it returns zero (or `VK_SUCCESS`)
+    /// unless overridden this way.
+    fn call(
+        &self,
+        call: &Call<'_>,
+        next: &mut dyn FnMut() -> Option<vk::Result>,
+    ) -> Option<vk::Result>;
+}
+
+impl<H: Hooks + ?Sized> Hooks for &H {
+    fn call(
+        &self,
+        call: &Call<'_>,
+        next: &mut dyn FnMut() -> Option<vk::Result>,
+    ) -> Option<vk::Result> {
+        (**self).call(call, next)
+    }
+}
+
+impl<H: Hooks + ?Sized> Hooks for Box<H> {
+    fn call(
+        &self,
+        call: &Call<'_>,
+        next: &mut dyn FnMut() -> Option<vk::Result>,
+    ) -> Option<vk::Result> {
+        (**self).call(call, next)
+    }
+}
+
+impl<H: Hooks + ?Sized> Hooks for Arc<H> {
+    fn call(
+        &self,
+        call: &Call<'_>,
+        next: &mut dyn FnMut() -> Option<vk::Result>,
+    ) -> Option<vk::Result> {
+        (**self).call(call, next)
+    }
+}
+
+/// Runs the first hook around the second.
+impl<A: Hooks, B: Hooks> Hooks for (A, B) {
+    fn call(
+        &self,
+        call: &Call<'_>,
+        next: &mut dyn FnMut() -> Option<vk::Result>,
+    ) -> Option<vk::Result> {
+        self.0.call(call, &mut || self.1.call(call, next))
+    }
+}
+
+/// A table whose methods call `hooks` around each command.
+///
+/// ```ignore
+/// let device_table = Hooked::new(device_table, hooks);
+/// device_table.create_buffer_checked(&create_info, None)?;
+/// ```
+#[derive(Clone, Debug)]
+pub struct Hooked<T, H> {
+    pub table: T,
+    pub hooks: H,
+}
+
+impl<T, H> Hooked<T, H> {
+    pub fn new(table: T, hooks: H) -> Self {
+        Hooked { table, hooks }
+    }
+}
+
+// The return types of commands.
+pub(crate) trait ReturnValue: Default {
+    fn status(&self) -> Option<vk::Result> {
+        None
+    }
+
+    fn with_status(ret: Option<Self>, _status: Option<vk::Result>) -> Self {
+        ret.unwrap_or_default()
+    }
+}
+
+impl ReturnValue for () {}
+impl ReturnValue for u32 {}
+impl ReturnValue for u64 {}
+impl ReturnValue for vk::pfn::VoidFunction {}
+
+impl ReturnValue for vk::Result {
+    fn status(&self) -> Option<vk::Result> {
+        Some(*self)
+    }
+
+    fn with_status(ret: Option<Self>, status: Option<vk::Result>) -> Self {
+        status.or(ret).unwrap_or_default()
+    }
+}
@@ -0,0 +1,285 @@
+use std::ffi::c_void;
+use std::fmt::Write as _;
+use std::io;
+use std::ops::{Deref, RangeInclusive};
+use std::path::{Path, PathBuf};
+
+use crate::json;
+
+#[doc(hidden)]
+pub use vk;
+
+/// The value the loader expects at the start of every dispatchable
+/// object created by a driver. The loader overwrites it with its own
+/// dispatch pointer once the object has been returned to it.
+pub const ICD_LOADER_MAGIC: usize = 0x01CDC0DE;
+
+/// The newest loader-driver interface version defined by `vk_icd.h`.
+pub const CURRENT_LOADER_ICD_INTERFACE_VERSION: u32 = 7;
+/// The oldest loader-driver interface version.
+pub const MIN_SUPPORTED_LOADER_ICD_INTERFACE_VERSION: u32 = 0;
+/// The first interface version in which the loader queries
+/// `vk_icdGetPhysicalDeviceProcAddr`.
+pub const MIN_PHYS_DEV_EXTENSION_ICD_INTERFACE_VERSION: u32 = 4;
+
+/// Function pointer types of the driver entry points.
+pub mod pfn {
+    use std::os::raw::c_char;
+
+    pub type NegotiateLoaderICDInterfaceVersion =
+        unsafe extern "C" fn(p_supported_version: *mut u32) -> vk::Result;
+    pub type GetPhysicalDeviceProcAddr = unsafe extern "C" fn(
+        instance: vk::Instance,
+        p_name: *const c_char,
+    ) -> vk::pfn::VoidFunction;
+}
+
+/// The first word of a dispatchable object, which belongs to the
+/// loader.
+#[repr(C)]
+#[derive(Clone, Copy)]
+pub union LoaderData {
+    pub loader_magic: usize,
+    pub loader_data: *mut c_void,
+}
+
+impl std::fmt::Debug for LoaderData {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        write!(f, "LoaderData({:#x})", unsafe { self.loader_magic })
+    }
+}
+
+/// A driver's dispatchable object, e.g. the state behind a `VkDevice`,
+/// laid out so that it begins with the loader data.
+///
+/// ```ignore
+/// let device = vk::Device(Dispatchable::new(state).into_handle());
+/// let state: &DeviceState = &Dispatchable::from_handle(device.0);
+/// ```
+#[repr(C)]
+#[derive(Debug)]
+pub struct Dispatchable<T> {
+    loader_data: LoaderData,
+    value: T,
+}
+
+impl<T> Dispatchable<T> {
+    pub fn new(value: T) -> Box<Self> {
+        let loader_data = LoaderData { loader_magic: ICD_LOADER_MAGIC };
+        Box::new(Dispatchable { loader_data, value })
+    }
+
+    /// Returns the object as a raw handle, which must eventually be
+    /// passed to `destroy`.
+    pub fn into_handle(self: Box<Self>) -> *const c_void {
+        Box::into_raw(self) as *const c_void
+    }
+
+    /// Borrows the object behind a handle created by `into_handle`.
+    ///
+    /// # Safety
+    ///
+    /// `handle` must have been created from a `Dispatchable<T>` of the
+    /// same `T` and not yet destroyed.
+    pub unsafe fn from_handle<'a>(handle: *const c_void) -> &'a Self {
+        &*(handle as *const Self)
+    }
+
+    /// Frees the object behind a handle created by `into_handle`.
+    ///
+    /// # Safety
+    ///
+    /// As for `from_handle`, and the handle must not be used again.
+    pub unsafe fn destroy(handle: *const c_void) -> T {
+        Box::from_raw(handle as *mut Self).value
+    }
+
+    pub fn loader_data(&self) -> LoaderData {
+        self.loader_data
+    }
+
+    /// True until the loader has claimed the object.
+    pub fn has_loader_magic(&self) -> bool {
+        unsafe { self.loader_data.loader_magic == ICD_LOADER_MAGIC }
+    }
+}
+
+impl<T> Deref for Dispatchable<T> {
+    type Target = T;
+    fn deref(&self) -> &T {
+        &self.value
+    }
+}
+
+/// Implements `vk_icdNegotiateLoaderICDInterfaceVersion` for a driver
+/// which supports the interface versions in `supported`.
+///
+/// # Safety
+///
+/// `p_supported_version` must be valid for reads and writes.
+pub unsafe fn negotiate_interface_version(
+    p_supported_version: *mut u32,
+    supported: RangeInclusive<u32>,
+) -> vk::Result {
+    let version = (*p_supported_version).min(*supported.end());
+    if version < *supported.start() {
+        return vk::Result::ERROR_INCOMPATIBLE_DRIVER;
+    }
+    *p_supported_version = version;
+    vk::Result::SUCCESS
+}
+
+/// Exports the `vk_icd*` entry points the loader looks up in a driver
+/// library. Invoke it once, at the root of a `cdylib`:
+///
+/// ```ignore
+/// vkl::export_icd! {
+///     interface_version: 2..=5,
+///     get_instance_proc_addr: driver::get_instance_proc_addr,
+///     get_physical_device_proc_addr:
+///         driver::get_physical_device_proc_addr,
+/// }
+/// ```
+#[macro_export]
+macro_rules! export_icd {
+    (
+        interface_version: $versions:expr,
+        get_instance_proc_addr: $get_instance_proc_addr:path,
+        $(get_physical_device_proc_addr: $get_phys_dev_proc_addr:path,)?
+    ) => {
+        /// Called by the loader to agree on an interface version.
+        ///
+        /// # Safety
+        ///
+        /// `p_supported_version` must be valid for reads and writes.
+        #[no_mangle]
+        #[allow(non_snake_case)]
+        pub unsafe extern "C" fn vk_icdNegotiateLoaderICDInterfaceVersion(
+            p_supported_version: *mut u32,
+        ) -> $crate::icd::vk::Result {
+            $crate::icd::negotiate_interface_version(
+                p_supported_version,
+                $versions,
+            )
+        }
+
+        /// Called by the loader to look up the driver's commands.
+        ///
+        /// # Safety
+        ///
+        /// As for the `get_instance_proc_addr` passed to the macro.
+        #[no_mangle]
+        #[allow(non_snake_case)]
+        pub unsafe extern "C" fn vk_icdGetInstanceProcAddr(
+            instance: $crate::icd::vk::Instance,
+            p_name: *const std::os::raw::c_char,
+        ) -> $crate::icd::vk::pfn::VoidFunction {
+            $get_instance_proc_addr(instance, p_name)
+        }
+
+        $(
+            /// Called by the loader to look up physical device
+            /// commands of extensions it doesn't know.
+            ///
+            /// # Safety
+            ///
+            /// As for the `get_physical_device_proc_addr` passed to
+            /// the macro.
+            #[no_mangle]
+            #[allow(non_snake_case)]
+            pub unsafe extern "C" fn vk_icdGetPhysicalDeviceProcAddr(
+                instance: $crate::icd::vk::Instance,
+                p_name: *const std::os::raw::c_char,
+            ) -> $crate::icd::vk::pfn::VoidFunction {
+                $get_phys_dev_proc_addr(instance, p_name)
+            }
+        )?
+    }
+}
+
+/// The JSON manifest which tells the loader where to find a driver.
+#[derive(Clone, Debug)]
+pub struct Manifest {
+    pub library_path: PathBuf,
+    pub api_version: u32,
+    pub is_portability_driver: bool,
+}
+
+impl Manifest {
+    pub fn new(library_path: impl Into<PathBuf>, api_version: u32) -> Self {
+        Manifest {
+            library_path: library_path.into(),
+            api_version,
+            is_portability_driver: false,
+        }
+    }
+
+    pub fn to_json(&self) -> String {
+        let path = self.library_path.to_string_lossy();
+        let mut json = String::new();
+        json.push_str("{\n");
+        json.push_str("    \"file_format_version\": \"1.0.1\",\n");
+        json.push_str("    \"ICD\": {\n");
+        json.push_str("        \"library_path\": ");
+        json::write_string(&mut json, &path);
+        json.push_str(",\n");
+        let _ = writeln!(
+            json,
+            "        \"api_version\": \"{}.{}.{}\",",
+            vk::version_major!(self.api_version),
+            vk::version_minor!(self.api_version),
+            vk::version_patch!(self.api_version),
+        );
+        let _ = writeln!(
+            json,
+            "        \"is_portability_driver\": {}",
+            self.is_portability_driver,
+        );
+        json.push_str("    }\n");
+        json.push_str("}\n");
+        json
+    }
+
+    pub fn write(&self, path: impl AsRef<Path>) -> io::Result<()> {
+        std::fs::write(path, self.to_json())
+    }
+
+    /// Parses a manifest. `library_path` is returned as written, and
+    /// `api_version` defaults to 1.0 if it is missing.
+    pub fn parse(json: &str) -> io::Result<Self> {
+        let invalid = |msg: String| io::Error::new(io::ErrorKind::InvalidData, msg);
+        let root = json::parse(json).map_err(invalid)?;
+        let icd = root.get("ICD")
+            .ok_or_else(|| invalid("missing \"ICD\" object".to_owned()))?;
+        let library_path = icd.get("library_path")
+            .and_then(json::Value::as_str)
+            .ok_or_else(|| invalid("missing \"library_path\"".to_owned()))?;
+        let api_version = match icd.get("api_version") {
+            Some(version) => version.as_str()
+                .and_then(parse_version)
+                .ok_or_else(|| invalid("invalid \"api_version\"".to_owned()))?,
+            None => vk::API_VERSION_1_0,
+        };
+        let is_portability_driver = icd.get("is_portability_driver")
+            .and_then(json::Value::as_bool)
+            .unwrap_or(false);
+        Ok(Manifest {
+            library_path: library_path.into(),
+            api_version,
+            is_portability_driver,
+        })
+    }
+
+    pub fn read(path: impl AsRef<Path>) -> io::Result<Self> {
+        Self::parse(&std::fs::read_to_string(path)?)
+    }
+}
+
+// Parses e.g. "1.3.250".
+fn parse_version(version: &str) -> Option<u32> {
+    let mut parts = version.split('.').map(|part| part.parse::<u32>().ok());
+    let major = parts.next()??;
+    let minor = parts.next().unwrap_or(Some(0))?;
+    let patch = parts.next().unwrap_or(Some(0))?;
+    Some(vk::make_version!(major, minor, patch))
+}
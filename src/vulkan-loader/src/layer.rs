@@ -0,0 +1,390 @@
+use std::collections::BTreeMap;
+use std::ffi::{c_void, CStr};
+use std::os::raw::c_char;
+use std::sync::{Arc, RwLock};
+
+use vk::pfn;
+
+use crate::{DeviceTable, InstanceTable};
+
+/// The newest loader-layer interface version defined by
+/// `vk_layer.h`.
+pub const CURRENT_LOADER_LAYER_INTERFACE_VERSION: u32 = 2;
+/// The oldest version supported by `negotiate_interface_version`,
+/// which is the first to have `vkNegotiateLoaderLayerInterfaceVersion`.
+pub const MIN_SUPPORTED_LOADER_LAYER_INTERFACE_VERSION: u32 = 2;
+
+/// Function pointer types of the layer interface.
+pub mod pfn_layer {
+    use super::*;
+
+    pub type GetPhysicalDeviceProcAddr = unsafe extern "C" fn(
+        instance: vk::Instance,
+        p_name: *const c_char,
+    ) -> pfn::VoidFunction;
+    pub type NegotiateLoaderLayerInterfaceVersion = unsafe extern "C" fn(
+        p_version_struct: *mut NegotiateLayerInterface,
+    ) -> vk::Result;
+    pub type SetInstanceLoaderData = unsafe extern "C" fn(
+        instance: vk::Instance,
+        object: *mut c_void,
+    ) -> vk::Result;
+    pub type SetDeviceLoaderData = unsafe extern "C" fn(
+        device: vk::Device,
+        object: *mut c_void,
+    ) -> vk::Result;
+    pub type LayerCreateDevice = unsafe extern "C" fn(
+        instance: vk::Instance,
+        physical_device: vk::PhysicalDevice,
+        p_create_info: *const vk::DeviceCreateInfo,
+        p_allocator: *const vk::AllocationCallbacks,
+        p_device: *mut vk::Device,
+        layer_gipa: pfn::GetInstanceProcAddr,
+        next_gdpa: *mut Option<pfn::GetDeviceProcAddr>,
+    ) -> vk::Result;
+    pub type LayerDestroyDevice = unsafe extern "C" fn(
+        device: vk::Device,
+        p_allocator: *const vk::AllocationCallbacks,
+        destroy_function: pfn::DestroyDevice,
+    );
+}
+
+/// `VkNegotiateLayerStructType`
+#[repr(transparent)]
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, PartialEq)]
+pub struct NegotiateLayerStructType(pub i32);
+
+impl NegotiateLayerStructType {
+    pub const UNINTIALIZED: Self = NegotiateLayerStructType(0);
+    pub const INTERFACE_STRUCT: Self = NegotiateLayerStructType(1);
+}
+
+/// `VkLayerFunction`, which identifies the contents of the chain info
+/// the loader passes to `vkCreateInstance` and `vkCreateDevice`.
+#[repr(transparent)]
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, PartialEq)]
+pub struct LayerFunction(pub i32);
+
+impl LayerFunction {
+    pub const LAYER_LINK_INFO: Self = LayerFunction(0);
+    pub const LOADER_DATA_CALLBACK: Self = LayerFunction(1);
+    pub const LOADER_LAYER_CREATE_DEVICE_CALLBACK: Self = LayerFunction(2);
+    pub const LOADER_FEATURES: Self = LayerFunction(3);
+}
+
+/// `VkNegotiateLayerInterface`
+#[repr(C)]
+#[derive(Clone, Copy, Debug)]
+pub struct NegotiateLayerInterface {
+    pub s_type: NegotiateLayerStructType,
+    pub p_next: *mut c_void,
+    pub loader_layer_interface_version: u32,
+    pub pfn_get_instance_proc_addr: Option<pfn::GetInstanceProcAddr>,
+    pub pfn_get_device_proc_addr: Option<pfn::GetDeviceProcAddr>,
+    pub pfn_get_physical_device_proc_addr:
+        Option<pfn_layer::GetPhysicalDeviceProcAddr>,
+}
+
+/// `VkLayerInstanceLink`
+#[repr(C)]
+#[derive(Clone, Copy, Debug)]
+pub struct LayerInstanceLink {
+    pub p_next: *mut LayerInstanceLink,
+    pub pfn_next_get_instance_proc_addr: pfn::GetInstanceProcAddr,
+    pub pfn_next_get_physical_device_proc_addr:
+        Option<pfn_layer::GetPhysicalDeviceProcAddr>,
+}
+
+/// `VkLayerDeviceLink`
+#[repr(C)]
+#[derive(Clone, Copy, Debug)]
+pub struct LayerDeviceLink {
+    pub p_next: *mut LayerDeviceLink,
+    pub pfn_next_get_instance_proc_addr: pfn::GetInstanceProcAddr,
+    pub pfn_next_get_device_proc_addr: pfn::GetDeviceProcAddr,
+}
+
+#[repr(C)]
+#[derive(Clone, Copy, Debug)]
+pub struct LayerDevice {
+    pub pfn_layer_create_device: Option<pfn_layer::LayerCreateDevice>,
+    pub pfn_layer_destroy_device: Option<pfn_layer::LayerDestroyDevice>,
+}
+
+#[repr(C)]
+#[derive(Clone, Copy)]
+pub union LayerInstanceCreateInfoU {
+    pub p_layer_info: *mut LayerInstanceLink,
+    pub pfn_set_instance_loader_data:
+        Option<pfn_layer::SetInstanceLoaderData>,
+    pub layer_device: LayerDevice,
+    pub loader_features: u32,
+}
+
+/// `VkLayerInstanceCreateInfo`, which the loader chains onto the
+/// `VkInstanceCreateInfo` passed to each layer.
+#[repr(C)]
+#[derive(Clone, Copy)]
+pub struct LayerInstanceCreateInfo {
+    pub s_type: vk::StructureType,
+    pub p_next: *const c_void,
+    pub function: LayerFunction,
+    pub u: LayerInstanceCreateInfoU,
+}
+
+#[repr(C)]
+#[derive(Clone, Copy)]
+pub union LayerDeviceCreateInfoU {
+    pub p_layer_info: *mut LayerDeviceLink,
+    pub pfn_set_device_loader_data: Option<pfn_layer::SetDeviceLoaderData>,
+}
+
+/// `VkLayerDeviceCreateInfo`, which the loader chains onto the
+/// `VkDeviceCreateInfo` passed to each layer.
+#[repr(C)]
+#[derive(Clone, Copy)]
+pub struct LayerDeviceCreateInfo {
+    pub s_type: vk::StructureType,
+    pub p_next: *const c_void,
+    pub function: LayerFunction,
+    pub u: LayerDeviceCreateInfoU,
+}
+
+/// Implements `vkNegotiateLoaderLayerInterfaceVersion`, handing the
+/// loader the layer's entry points.
+///
+/// # Safety
+///
+/// `p_version_struct` must be valid for reads and writes.
+pub unsafe fn negotiate_interface_version(
+    p_version_struct: *mut NegotiateLayerInterface,
+    get_instance_proc_addr: pfn::GetInstanceProcAddr,
+    get_device_proc_addr: pfn::GetDeviceProcAddr,
+) -> vk::Result {
+    let negotiate = &mut *p_version_struct;
+    if negotiate.s_type != NegotiateLayerStructType::INTERFACE_STRUCT
+        || negotiate.loader_layer_interface_version
+            < MIN_SUPPORTED_LOADER_LAYER_INTERFACE_VERSION
+    {
+        return vk::Result::ERROR_INITIALIZATION_FAILED;
+    }
+    negotiate.loader_layer_interface_version =
+        CURRENT_LOADER_LAYER_INTERFACE_VERSION;
+    negotiate.pfn_get_instance_proc_addr = Some(get_instance_proc_addr);
+    negotiate.pfn_get_device_proc_addr = Some(get_device_proc_addr);
+    negotiate.pfn_get_physical_device_proc_addr = None;
+    vk::Result::SUCCESS
+}
+
+/// Exports `vkNegotiateLoaderLayerInterfaceVersion` from a layer
+/// `cdylib`, handing the loader the layer's own entry points:
+///
+/// ```ignore
+/// vkl::export_layer! {
+///     get_instance_proc_addr: layer::get_instance_proc_addr,
+///     get_device_proc_addr: layer::get_device_proc_addr,
+/// }
+/// ```
+#[macro_export]
+macro_rules! export_layer {
+    (
+        get_instance_proc_addr: $get_instance_proc_addr:path,
+        get_device_proc_addr: $get_device_proc_addr:path,
+    ) => {
+        /// Called by the loader to agree on an interface version and
+        /// find the layer's entry points.
+        ///
+        /// # Safety
+        ///
+        /// `p_version_struct` must be valid for reads and writes.
+        #[no_mangle]
+        #[allow(non_snake_case)]
+        pub unsafe extern "C" fn vkNegotiateLoaderLayerInterfaceVersion(
+            p_version_struct: *mut $crate::layer::NegotiateLayerInterface,
+        ) -> $crate::icd::vk::Result {
+            $crate::layer::negotiate_interface_version(
+                p_version_struct,
+                $get_instance_proc_addr,
+                $get_device_proc_addr,
+            )
+        }
+    }
+}
+
+// Finds the link info the loader chained onto a create info.
+unsafe fn find_link_info<T>(
+    p_next: *const c_void,
+    s_type: vk::StructureType,
+) -> Option<*mut T> {
+    vk::chain::Iter::new(p_next)
+        .filter(|&(ty, _)| ty == s_type)
+        // Both chain info structs share this layout up to `function`.
+        .map(|(_, base)| base as *const LayerDeviceCreateInfo)
+        .find(|&info| (*info).function == LayerFunction::LAYER_LINK_INFO)
+        .map(|info| info as *mut T)
+}
+
+/// Creates the instance through the next layer in the chain and loads
+/// a table of the next layer's commands for it.
+///
+/// This advances the loader's link info, so it must be called exactly
+/// once from the layer's `vkCreateInstance`, which should return any
+/// error as-is.
+///
+/// # Safety
+///
+/// The arguments must be those passed to the layer's
+/// `vkCreateInstance` by the loader.
+pub unsafe fn create_instance(
+    p_create_info: *const vk::InstanceCreateInfo,
+    p_allocator: *const vk::AllocationCallbacks,
+    p_instance: *mut vk::Instance,
+) -> Result<InstanceTable, vk::Result> {
+    let chain_info = find_link_info::<LayerInstanceCreateInfo>(
+        (*p_create_info).p_next,
+        vk::StructureType::LOADER_INSTANCE_CREATE_INFO,
+    ).ok_or(vk::Result::ERROR_INITIALIZATION_FAILED)?;
+    let link = (*chain_info).u.p_layer_info;
+    if link.is_null() {
+        return Err(vk::Result::ERROR_INITIALIZATION_FAILED);
+    }
+    let get_instance_proc_addr = (*link).pfn_next_get_instance_proc_addr;
+    (*chain_info).u.p_layer_info = (*link).p_next;
+
+    let name = b"vkCreateInstance\0".as_ptr() as *const c_char;
+    let create_instance: pfn::CreateInstance =
+        match get_instance_proc_addr(vk::null(), name) {
+            Some(f) => std::mem::transmute::<
+                unsafe extern "C" fn(),
+                pfn::CreateInstance,
+            >(f),
+            None => return Err(vk::Result::ERROR_INITIALIZATION_FAILED),
+        };
+    create_instance(p_create_info, p_allocator, p_instance).check()?;
+    Ok(InstanceTable::load(*p_instance, get_instance_proc_addr))
+}
+
+/// Creates the device through the next layer in the chain and loads a
+/// table of the next layer's commands for it.
+///
+/// As with `create_instance`, this must be called exactly once from
+/// the layer's `vkCreateDevice`. `instance` is passed to the next
+/// layer's `vkGetInstanceProcAddr` and may be null.
+///
+/// # Safety
+///
+/// The other arguments must be those passed to the layer's
+/// `vkCreateDevice` by the loader.
+pub unsafe fn create_device(
+    instance: vk::Instance,
+    physical_device: vk::PhysicalDevice,
+    p_create_info: *const vk::DeviceCreateInfo,
+    p_allocator: *const vk::AllocationCallbacks,
+    p_device: *mut vk::Device,
+) -> Result<DeviceTable, vk::Result> {
+    let chain_info = find_link_info::<LayerDeviceCreateInfo>(
+        (*p_create_info).p_next,
+        vk::StructureType::LOADER_DEVICE_CREATE_INFO,
+    ).ok_or(vk::Result::ERROR_INITIALIZATION_FAILED)?;
+    let link = (*chain_info).u.p_layer_info;
+    if link.is_null() {
+        return Err(vk::Result::ERROR_INITIALIZATION_FAILED);
+    }
+    let get_instance_proc_addr = (*link).pfn_next_get_instance_proc_addr;
+    let get_device_proc_addr = (*link).pfn_next_get_device_proc_addr;
+    (*chain_info).u.p_layer_info = (*link).p_next;
+
+    let name = b"vkCreateDevice\0".as_ptr() as *const c_char;
+    let create_device: pfn::CreateDevice =
+        match get_instance_proc_addr(instance, name) {
+            Some(f) => std::mem::transmute::<
+                unsafe extern "C" fn(),
+                pfn::CreateDevice,
+            >(f),
+            None => return Err(vk::Result::ERROR_INITIALIZATION_FAILED),
+        };
+    create_device(physical_device, p_create_info, p_allocator, p_device)
+        .check()?;
+    Ok(DeviceTable::load(*p_device, get_device_proc_addr))
+}
+
+/// Looks up `p_name` among the commands a layer intercepts, given as
+/// `(name, function)` pairs, e.g. `("vkQueueSubmit", hook)`. Commands
+/// which aren't intercepted should be forwarded to the next layer's
+/// `vkGetInstanceProcAddr` or `vkGetDeviceProcAddr`.
+///
+/// # Safety
+///
+/// `p_name` must be a nul-terminated string.
+pub unsafe fn intercept(
+    p_name: *const c_char,
+    hooks: &[(&str, unsafe extern "C" fn())],
+) -> pfn::VoidFunction {
+    let name = CStr::from_ptr(p_name).to_bytes();
+    hooks.iter()
+        .find(|(hook, _)| hook.as_bytes() == name)
+        .map(|&(_, f)| f)
+}
+
+/// Returns the dispatch key of a dispatchable handle, i.e. the
+/// loader's dispatch table pointer it begins with. Objects created
+/// from the same instance or device share a key.
+///
+/// # Safety
+///
+/// `handle` must be a valid dispatchable handle created by the loader.
+pub unsafe fn dispatch_key(handle: *const c_void) -> usize {
+    *(handle as *const usize)
+}
+
+/// Maps dispatch keys to per-instance or per-device layer state, e.g.
+/// the table of the next layer's commands. It can be used in a
+/// `static`:
+///
+/// ```ignore
+/// static DEVICES: DispatchMap<DeviceTable> = DispatchMap::new();
+/// ```
+#[derive(Debug, Default)]
+pub struct DispatchMap<T> {
+    map: RwLock<BTreeMap<usize, Arc<T>>>,
+}
+
+impl<T> DispatchMap<T> {
+    pub const fn new() -> Self {
+        DispatchMap { map: RwLock::new(BTreeMap::new()) }
+    }
+
+    /// Associates `value` with the dispatch key of `handle`.
+    ///
+    /// # Safety
+    ///
+    /// As for `dispatch_key`.
+    pub unsafe fn insert(&self, handle: *const c_void, value: T) -> Arc<T> {
+        let value = Arc::new(value);
+        let mut map = self.map.write().unwrap_or_else(|e| e.into_inner());
+        map.insert(dispatch_key(handle), Arc::clone(&value));
+        value
+    }
+
+    /// Finds the value associated with any object sharing a dispatch
+    /// key with `handle`.
+    ///
+    /// # Safety
+    ///
+    /// As for `dispatch_key`.
+    pub unsafe fn get(&self, handle: *const c_void) -> Option<Arc<T>> {
+        let map = self.map.read().unwrap_or_else(|e| e.into_inner());
+        map.get(&dispatch_key(handle)).cloned()
+    }
+
+    /// Removes the value associated with `handle`, e.g. when the
+    /// instance or device is destroyed.
+    ///
+    /// # Safety
+    ///
+    /// As for `dispatch_key`.
+    pub unsafe fn remove(&self, handle: *const c_void) -> Option<Arc<T>> {
+        let mut map = self.map.write().unwrap_or_else(|e| e.into_inner());
+        map.remove(&dispatch_key(handle))
+    }
+}
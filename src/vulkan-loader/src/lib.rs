@@ -1,15 +1,58 @@
+//! Tables of Vulkan command pointers, with methods to call them.
+//!
+//! # Safety
+//!
+//! Calling a command through a table is as unsafe as calling it from
+//! C: its arguments must follow the command's valid usage rules, and
+//! the table's handle must still be alive. Loading a table trusts the
+//! `vkGet*ProcAddr` it is given to return pointers with the right
+//! signatures.
+
 #![allow(unused_parens)]
 
 use std::fmt::Debug;
-use std::ffi::c_void;
+use std::ffi::{c_void, CStr};
 use std::os::raw::*;
+use std::sync::atomic::{AtomicUsize, Ordering};
 
 use vk::*;
 
+#[cfg(any(test, feature = "hooks"))]
+pub mod capture;
+#[cfg(feature = "libloading")]
+pub mod drivers;
+#[cfg(any(test, feature = "hooks"))]
+pub mod externsync;
+#[cfg(any(test, feature = "hooks"))]
+pub mod faults;
+#[cfg(any(test, feature = "hooks"))]
+pub mod hooks;
+pub mod icd;
+mod json;
+pub mod layer;
+#[cfg(any(test, feature = "hooks"))]
+pub mod lifetime;
+#[cfg(feature = "libloading")]
+mod library;
+#[cfg(any(test, feature = "mock"))]
+pub mod mock;
+#[cfg(any(test, feature = "hooks"))]
+pub mod profile;
+#[cfg(test)]
+mod tests;
+#[cfg(any(test, feature = "hooks"))]
+pub mod trace;
+
+#[cfg(feature = "libloading")]
+pub use library::*;
+
+macro_rules! vk_name {
+    ($cmd:ident) => { concat!("vk", stringify!($cmd)) }
+}
+
 macro_rules! vk_symbol {
     ($cmd:ident) => {
-        concat!(concat!("vk", stringify!($cmd)), "\0")
-            as *const str as *const c_char
+        concat!(vk_name!($cmd), "\0") as *const str as *const c_char
     }
 }
 
@@ -17,6 +60,291 @@ macro_rules! opt_to_ptr {
     ($opt:expr) => { $opt.map_or(std::ptr::null(), |p| p as *const c_void) }
 }
 
+// Looks up each name in turn, returning the first non-null pointer.
+unsafe fn resolve<H: Copy>(
+    get_proc_addr: unsafe extern "C" fn(H, *const c_char) -> pfn::VoidFunction,
+    handle: H,
+    names: &[&'static str],
+    on_resolve: &mut impl FnMut(&'static str, &'static str),
+) -> pfn::VoidFunction {
+    let strip_nul = |name: &'static str| &name[..name.len() - 1];
+    for &name in names {
+        if let Some(f) = get_proc_addr(handle, name.as_ptr() as _) {
+            on_resolve(strip_nul(names[0]), strip_nul(name));
+            return Some(f);
+        }
+    }
+    None
+}
+
+// The API version and extensions a handle was created with.
+struct Enabled<'a> {
+    api_version: u32,
+    extensions: &'a [*const c_char],
+}
+
+impl Enabled<'_> {
+    // `name` is either a feature, e.g. `VK_VERSION_1_1`, or an
+    // extension.
+    unsafe fn has(&self, name: &str) -> bool {
+        if let Some(version) = name.strip_prefix("VK_VERSION_") {
+            let parse = |version: &str| {
+                let (major, minor) = version.split_once('_')?;
+                Some((major.parse::<u32>().ok()?, minor.parse::<u32>().ok()?))
+            };
+            let enabled = (
+                vk::api_version_major!(self.api_version),
+                vk::api_version_minor!(self.api_version),
+            );
+            return parse(version).is_some_and(|version| enabled >= version);
+        }
+        self.extensions.iter()
+            .any(|&ext| CStr::from_ptr(ext).to_bytes() == name.as_bytes())
+    }
+}
+
+// Looks up the command under the first name whose features and
+// extensions are all enabled.
+unsafe fn resolve_enabled<H: Copy>(
+    get_proc_addr: unsafe extern "C" fn(H, *const c_char) -> pfn::VoidFunction,
+    handle: H,
+    enabled: &Enabled,
+    providers: &[(&[&str], &'static str)],
+) -> pfn::VoidFunction {
+    for &(requires, name) in providers {
+        if !requires.iter().all(|name| enabled.has(name)) {
+            continue;
+        }
+        if let Some(f) = get_proc_addr(handle, name.as_ptr() as _) {
+            return Some(f);
+        }
+    }
+    None
+}
+
+// A function pointer which is looked up on first use.
+struct LazySlot(AtomicUsize);
+
+impl LazySlot {
+    const UNRESOLVED: usize = 0;
+    const MISSING: usize = 1;
+
+    fn new() -> Self {
+        LazySlot(AtomicUsize::new(Self::UNRESOLVED))
+    }
+
+    #[inline(always)]
+    fn get(
+        &self,
+        resolve: impl FnOnce() -> pfn::VoidFunction,
+    ) -> pfn::VoidFunction {
+        match self.0.load(Ordering::Acquire) {
+            Self::UNRESOLVED => self.resolve(resolve),
+            Self::MISSING => None,
+            addr => Some(unsafe {
+                std::mem::transmute::<usize, unsafe extern "C" fn()>(addr)
+            }),
+        }
+    }
+
+    // Racing threads will all find the same pointer, so there is no
+    // harm in resolving it more than once.
+    #[cold]
+    fn resolve(
+        &self,
+        resolve: impl FnOnce() -> pfn::VoidFunction,
+    ) -> pfn::VoidFunction {
+        let f = resolve();
+        let addr = f.map_or(Self::MISSING, |f| f as usize);
+        self.0.store(addr, Ordering::Release);
+        f
+    }
+}
+
+#[cold]
+#[inline(never)]
+#[track_caller]
+fn not_loaded(name: &str) -> ! {
+    panic!("{} was not loaded", name)
+}
+
+// Unwraps a table entry, panicking with the command name if it is null.
+macro_rules! load_pfn {
+    ($opt:expr, $pfn:ident) => {
+        match $opt {
+            Some(f) => f,
+            None => crate::not_loaded(vk_name!($pfn)),
+        }
+    }
+}
+
+/// The return type of `*_checked` methods.
+pub type VkResult<T> = std::result::Result<T, vk::Result>;
+
+// Converts the arguments of `*_checked` methods to their raw types.
+trait IntoRaw<T> {
+    fn into_raw(self) -> T;
+}
+
+impl<T> IntoRaw<T> for T {
+    #[inline(always)]
+    fn into_raw(self) -> T {
+        self
+    }
+}
+
+impl<T> IntoRaw<*const T> for Option<&T> {
+    #[inline(always)]
+    fn into_raw(self) -> *const T {
+        self.map_or(std::ptr::null(), |p| p)
+    }
+}
+
+macro_rules! checked_ret {
+    (void, $output_type:ty) => { $output_type };
+    (success, $output_type:ty) => { VkResult<$output_type> };
+    (status, $output_type:ty) => { VkResult<($output_type, vk::Result)> };
+}
+
+macro_rules! checked_call {
+    (void, $call:expr, $output:ident) => {{
+        $call;
+        $output
+    }};
+    (success, $call:expr, $output:ident) => {
+        $call.check().map(|()| $output)
+    };
+    (status, $call:expr, $output:ident) => {{
+        let res = $call;
+        if res.is_error() { Err(res) } else { Ok(($output, res)) }
+    }};
+}
+
+macro_rules! impl_checked {
+    (
+        $method:ident,
+        method_name: $checked_method:ident,
+        args: ($($arg:ident: $type:ty,)*),
+        output: $output:ident: $output_type:ty,
+        result: $result:ident,
+    ) => {
+        /// Calls the command with a default-initialized output
+        /// parameter and returns it.
+        ///
+        /// # Safety
+        ///
+        /// See the [crate documentation](crate#safety).
+        #[inline]
+        #[track_caller]
+        pub unsafe fn $checked_method(&self, $($arg: $type,)*)
+            -> checked_ret!($result, $output_type)
+        {
+            let mut $output: $output_type = Default::default();
+            checked_call!(
+                $result,
+                self.$method($(IntoRaw::into_raw($arg),)* &mut $output),
+                $output
+            )
+        }
+    };
+    (
+        $method:ident,
+        method_name: $checked_method:ident,
+        args: ($($arg:ident: $type:ty,)*),
+        output: $output:ident: $output_type:ty,
+        len: $len:expr,
+        result: $result:ident,
+    ) => {
+        /// Calls the command with an array of as many
+        /// default-initialized elements as it writes, and returns it.
+        ///
+        /// # Safety
+        ///
+        /// See the [crate documentation](crate#safety).
+        #[inline]
+        #[track_caller]
+        pub unsafe fn $checked_method(&self, $($arg: $type,)*)
+            -> checked_ret!($result, $output_type)
+        {
+            let mut $output: $output_type = Default::default();
+            $output.resize_with($len as usize, Default::default);
+            checked_call!(
+                $result,
+                self.$method(
+                    $(IntoRaw::into_raw($arg),)*
+                    $output.as_mut_ptr(),
+                ),
+                $output
+            )
+        }
+    };
+}
+
+// Calls a two-call enumeration command until the array is complete.
+unsafe fn enumerate_vec<T: Default>(
+    mut f: impl FnMut(*mut u32, *mut T) -> vk::Result,
+) -> VkResult<Vec<T>> {
+    loop {
+        let mut count = 0;
+        f(&mut count, std::ptr::null_mut()).check()?;
+        let mut vec = Vec::new();
+        vec.resize_with(count as usize, Default::default);
+        let res = f(&mut count, vec.as_mut_ptr());
+        if res == vk::Result::INCOMPLETE {
+            continue;
+        } else if res.is_error() {
+            return Err(res);
+        }
+        vec.truncate(count as usize);
+        return Ok(vec);
+    }
+}
+
+unsafe fn enumerate_vec_void<T: Default>(
+    mut f: impl FnMut(*mut u32, *mut T),
+) -> Vec<T> {
+    let mut count = 0;
+    f(&mut count, std::ptr::null_mut());
+    let mut vec = Vec::new();
+    vec.resize_with(count as usize, Default::default);
+    f(&mut count, vec.as_mut_ptr());
+    vec.truncate(count as usize);
+    vec
+}
+
+macro_rules! vec_ret {
+    (void, $element:ty) => { Vec<$element> };
+    (success, $element:ty) => { VkResult<Vec<$element>> };
+}
+
+macro_rules! vec_call {
+    (void, $f:expr) => { enumerate_vec_void($f) };
+    (success, $f:expr) => { enumerate_vec($f) };
+}
+
+macro_rules! impl_vec {
+    (
+        $method:ident,
+        method_name: $vec_method:ident,
+        args: ($($arg:ident: $type:ty,)*),
+        element: $element:ty,
+        result: $result:ident,
+    ) => {
+        /// Calls the command until all elements have been written,
+        /// default-initializing each element beforehand.
+        ///
+        /// # Safety
+        ///
+        /// See the [crate documentation](crate#safety).
+        #[track_caller]
+        pub unsafe fn $vec_method(&self, $($arg: $type,)*)
+            -> vec_ret!($result, $element)
+        {
+            vec_call!($result, |count, data| self.$method($($arg,)* count, data))
+        }
+    }
+}
+
 // Easier to implement this manually than to rig the generator to.
 macro_rules! impl_entry {
     (
@@ -24,14 +352,25 @@ macro_rules! impl_entry {
             {
                 name: $member:ident,
                 method_name: $method:ident,
+                try_method_name: $try_method:ident,
+                unchecked_method_name: $unchecked_method:ident,
                 ptr: $pfn:ident,
                 signature: ($($arg:ident: $type:ty,)*) $(-> $ret:ty)*,
+                $(checked: { $($checked:tt)* },)?
+                $(vec: { $($vec:tt)* },)?
             },
         )*
     ) => {
         #[derive(Clone, Copy)]
         pub struct Entry { $(pub $member: Option<pfn::$pfn>,)* }
         impl Entry {
+            /// Loads the global commands through `get_proc_addr`.
+            ///
+            /// # Safety
+            ///
+            /// `get_proc_addr` must be a valid implementation of
+            /// `vkGetInstanceProcAddr`. See the [crate
+            /// documentation](crate#safety).
             pub unsafe fn load(get_proc_addr: pfn::GetInstanceProcAddr) -> Self
             {
                 Entry {
@@ -53,10 +392,41 @@ macro_rules! impl_entry {
         }
         impl Entry {
             $(
+                /// Panics if the command was not loaded.
+                ///
+                /// # Safety
+                ///
+                /// See the [crate documentation](crate#safety).
+                #[track_caller]
                 pub unsafe fn $method(&self, $($arg: $type,)*) $(-> $ret)* {
-                    std::mem::transmute::<_, pfn::$pfn>(self.$member)
-                        ($($arg,)*)
+                    load_pfn!(self.$member, $pfn)($($arg,)*)
+                }
+
+                /// Returns `None` if the command was not loaded.
+                ///
+                /// # Safety
+                ///
+                /// See the [crate documentation](crate#safety).
+                pub unsafe fn $try_method(&self, $($arg: $type,)*)
+                    -> Option<($($ret)*)>
+                {
+                    Some(self.$member?($($arg,)*))
                 }
+
+                /// Skips the check that the command was loaded.
+                ///
+                /// # Safety
+                ///
+                /// The command must be loaded. See also the [crate
+                /// documentation](crate#safety).
+                pub unsafe fn $unchecked_method(&self, $($arg: $type,)*)
+                    $(-> $ret)*
+                {
+                    self.$member.unwrap_unchecked()($($arg,)*)
+                }
+
+                $(impl_checked!($method, $($checked)*);)?
+                $(impl_vec!($method, $($vec)*);)?
             )*
         }
     }
@@ -66,35 +436,66 @@ impl_entry! {
     {
         name: pfn_enumerate_instance_extension_properties,
         method_name: enumerate_instance_extension_properties,
+        try_method_name: try_enumerate_instance_extension_properties,
+        unchecked_method_name: enumerate_instance_extension_properties_unchecked,
         ptr: EnumerateInstanceExtensionProperties,
         signature: (
             p_layer_name: *const c_char,
             p_property_count: *mut u32,
             p_properties: *mut ExtensionProperties,
         ) -> Result,
+        vec: {
+            method_name: enumerate_instance_extension_properties_vec,
+            args: (
+                p_layer_name: *const c_char,
+            ),
+            element: ExtensionProperties,
+            result: success,
+        },
     },
     {
         name: pfn_enumerate_instance_layer_properties,
         method_name: enumerate_instance_layer_properties,
+        try_method_name: try_enumerate_instance_layer_properties,
+        unchecked_method_name: enumerate_instance_layer_properties_unchecked,
         ptr: EnumerateInstanceLayerProperties,
         signature: (
             p_property_count: *mut u32,
             p_properties: *mut LayerProperties,
         ) -> Result,
+        vec: {
+            method_name: enumerate_instance_layer_properties_vec,
+            args: (),
+            element: LayerProperties,
+            result: success,
+        },
     },
     {
         name: pfn_create_instance,
         method_name: create_instance,
+        try_method_name: try_create_instance,
+        unchecked_method_name: create_instance_unchecked,
         ptr: CreateInstance,
         signature: (
             p_create_info: *const InstanceCreateInfo,
             p_allocator: *const AllocationCallbacks,
             p_instance: *mut Instance,
         ) -> Result,
+        checked: {
+            method_name: create_instance_checked,
+            args: (
+                p_create_info: *const InstanceCreateInfo,
+                p_allocator: Option<&AllocationCallbacks>,
+            ),
+            output: p_instance: Instance,
+            result: success,
+        },
     },
     {
         name: pfn_enumerate_instance_version,
         method_name: enumerate_instance_version,
+        try_method_name: try_enumerate_instance_version,
+        unchecked_method_name: enumerate_instance_version_unchecked,
         ptr: EnumerateInstanceVersion,
         signature: (
             p_api_version: *mut u32,
@@ -102,6 +503,240 @@ impl_entry! {
     },
 }
 
+macro_rules! returns_result {
+    () => { false };
+    (Result) => { true };
+}
+
+macro_rules! impl_commands {
+    ($($cmd:ident $(-> $ret:ident)?,)*) => {
+        /// Identifies a command of `InstanceTable` or `DeviceTable`.
+        #[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+        pub enum Command {
+            $($cmd,)*
+        }
+        impl Command {
+            /// Returns the name of the command, e.g. `"vkCmdDraw"`.
+            pub fn name(self) -> &'static str {
+                match self {
+                    $(Command::$cmd => vk_name!($cmd),)*
+                }
+            }
+
+            /// Returns true if the command returns `VkResult`.
+            pub fn returns_result(self) -> bool {
+                match self {
+                    $(Command::$cmd => returns_result!($($ret)?),)*
+                }
+            }
+
+            /// Looks up a command by name, e.g. `"vkCmdDraw"`.
+            pub fn from_name(name: &str) -> Option<Self> {
+                match name {
+                    $(vk_name!($cmd) => Some(Command::$cmd),)*
+                    _ => None,
+                }
+            }
+        }
+    }
+}
+
+macro_rules! impl_externsync {
+    ($($cmd:ident: $($path:literal),+;)*) => {
+        impl Command {
+            /// Lists the parameters which must be externally
+            /// synchronized, as paths through the arguments a `Hooked`
+            /// table passes to hooks, e.g. `"queue"` or
+            /// `"p_allocate_info.command_pool"`. Arrays are implied, so
+            /// `"p_descriptor_writes.dst_set"` names each element's.
+            pub fn externsync_params(self) -> &'static [&'static str] {
+                match self {
+                    $(Command::$cmd => &[$($path),+],)*
+                    _ => &[],
+                }
+            }
+        }
+    }
+}
+
+#[cfg(any(test, feature = "hooks"))]
+macro_rules! impl_handle_types {
+    ($($name:ident = $object_type:ident $(: $($parent:ident)|+)?,)*) => {
+        $(
+            impl hooks::Handle for vk::$name {
+                const OBJECT_TYPE: ObjectType = ObjectType::$object_type;
+            }
+        )*
+        impl hooks::Object {
+            /// Returns the name of the handle type, e.g. `"VkBuffer"`.
+            pub fn type_name(&self) -> &'static str {
+                match self.ty {
+                    $(ObjectType::$object_type => concat!("Vk", stringify!($name)),)*
+                    _ => "VkUnknown",
+                }
+            }
+
+            /// Returns the handle types the object may be created
+            /// from, e.g. `VkCommandPool` for a `VkCommandBuffer`.
+            pub fn parent_types(&self) -> &'static [ObjectType] {
+                use hooks::Handle;
+                match self.ty {
+                    $(ObjectType::$object_type => &[
+                        $($(vk::$parent::OBJECT_TYPE,)+)?
+                    ],)*
+                    _ => &[],
+                }
+            }
+        }
+    }
+}
+
+#[cfg(not(any(test, feature = "hooks")))]
+macro_rules! impl_handle_types {
+    ($($tt:tt)*) => {}
+}
+
+// `$this` names the structure in the lengths of its members.
+#[cfg(any(test, feature = "hooks"))]
+macro_rules! impl_reflect {
+    (
+        $this:ident;
+        $(
+            $name:ident $(= $s_type:ident)? {
+                $($member:ident: $kind:ident $([$($len:tt)*])?,)*
+            },
+        )*
+    ) => {
+        $(
+            impl hooks::Reflect for vk::$name {
+                // Lengths are C expressions from the registry
+                #[allow(clippy::manual_div_ceil)]
+                unsafe fn members(&self) -> Vec<hooks::Member<'_>> {
+                    let $this = self;
+                    vec![$(hooks::Member {
+                        name: stringify!($member),
+                        offset: std::mem::offset_of!(vk::$name, $member),
+                        value: hook_value!($kind $([$($len)*])?, $this.$member),
+                    },)*]
+                }
+            }
+        )*
+        impl hooks::Pointer {
+            // Finds the structure an extension structure's `sType`
+            // belongs to.
+            pub(crate) fn next_element(
+                s_type: vk::StructureType,
+            ) -> Option<unsafe fn(*const c_void, usize) -> hooks::Value<'static>> {
+                match s_type {
+                    $($(
+                        vk::StructureType::$s_type =>
+                            Some(hooks::struct_element::<vk::$name>),
+                    )?)*
+                    _ => None,
+                }
+            }
+        }
+    }
+}
+
+#[cfg(not(any(test, feature = "hooks")))]
+macro_rules! impl_reflect {
+    ($($tt:tt)*) => {}
+}
+
+#[cfg(feature = "linked")]
+macro_rules! impl_linked {
+    ($(
+        $feature:literal {
+            $($name:ident ($($arg:ident: $type:ty,)*) $(-> $ret:ty)*;)*
+        }
+    )*) => {
+        /// The core commands, as exported by the Vulkan loader library
+        /// the program was linked with.
+        ///
+        /// Only Vulkan 1.0 commands are declared by default, as older
+        /// loaders don't export the rest; the `linked-1-x` features
+        /// declare those of each later version.
+        pub mod linked {
+            use super::*;
+
+            $(
+                #[cfg(feature = $feature)]
+                #[cfg_attr(windows, link(name = "vulkan-1"))]
+                #[cfg_attr(not(windows), link(name = "vulkan"))]
+                extern "C" {
+                    $(
+                        #[link_name = vk_name!($name)]
+                        pub fn $name($($arg: $type,)*) $(-> $ret)*;
+                    )*
+                }
+            )*
+
+            /// Returns the exported symbol for core commands and defers
+            /// to the exported `vkGetInstanceProcAddr` for the rest.
+            ///
+            /// # Safety
+            ///
+            /// `p_name` must be a null-terminated string, and
+            /// `instance` valid as for `vkGetInstanceProcAddr`.
+            pub unsafe extern "C" fn get_instance_proc_addr(
+                instance: vk::Instance,
+                p_name: *const c_char,
+            ) -> pfn::VoidFunction {
+                let name = CStr::from_ptr(p_name).to_bytes();
+                $(
+                    #[cfg(feature = $feature)]
+                    {
+                        $(
+                            if name == vk_name!($name).as_bytes() {
+                                let pfn: pfn::$name = $name;
+                                return Some(std::mem::transmute::<
+                                    pfn::$name,
+                                    unsafe extern "C" fn(),
+                                >(pfn));
+                            }
+                        )*
+                    }
+                )*
+                GetInstanceProcAddr(instance, p_name)
+            }
+        }
+
+        impl Entry {
+            /// Loads the entry table from the exported symbols, without
+            /// opening the library at runtime.
+            ///
+            /// # Safety
+            ///
+            /// The program must be linked to a Vulkan loader
+            /// library that exports the declared commands.
+            pub unsafe fn linked() -> Self {
+                Entry::load(linked::get_instance_proc_addr)
+            }
+        }
+
+        impl InstanceTable {
+            /// Loads core commands from the exported symbols and
+            /// extension commands through the exported
+            /// `vkGetInstanceProcAddr`.
+            ///
+            /// # Safety
+            ///
+            /// `instance` must be a valid instance created through
+            /// the linked loader, which must export the declared
+            /// commands.
+            pub unsafe fn linked(instance: vk::Instance) -> Self {
+                InstanceTable::load(instance, linked::get_instance_proc_addr)
+            }
+        }
+    }
+}
+
+#[cfg(not(feature = "linked"))]
+macro_rules! impl_linked {
+    ($($tt:tt)*) => {}
+}
+
 macro_rules! call_cmd {
     (
         fn: $fn:expr,
@@ -121,9 +756,195 @@ macro_rules! call_cmd {
     };
 }
 
+macro_rules! get_pfn {
+    (field, $table:ident, $member:ident) => { $table.$member };
+    (lazy, $table:ident, $member:ident) => { $table.$member() };
+}
+
+#[cfg(any(test, feature = "hooks"))]
+macro_rules! hook_len {
+    () => { crate::hooks::Len::One };
+    (* $count:ident) => { crate::hooks::Len::Pointer($count) };
+    ($($count:tt)*) => { crate::hooks::Len::Count(($($count)*) as usize) };
+}
+
+// Presents an argument or structure member to hooks as described by
+// the generator.
+#[cfg(any(test, feature = "hooks"))]
+macro_rules! hook_value {
+    (value, $arg:expr) => { crate::hooks::Value::Debug(&$arg) };
+    (handle, $arg:expr) => {
+        crate::hooks::Value::Handle(crate::hooks::Object::new($arg))
+    };
+    (struct, $arg:expr) => { crate::hooks::Value::Struct(&$arg) };
+    (str, $arg:expr) => { crate::hooks::Value::Str($arg) };
+    (opaque, $arg:expr) => {
+        crate::hooks::Value::Opaque($arg as *const c_void)
+    };
+    (next, $arg:expr) => { crate::hooks::Pointer::next($arg) };
+    (ptr $([$($len:tt)*])?, $arg:expr) => {
+        crate::hooks::Value::Pointer(
+            crate::hooks::Pointer::new($arg, hook_len!($($($len)*)?)),
+        )
+    };
+    ($kind:ident $([$($len:tt)*])?, $arg:expr) => {
+        crate::hooks::Value::Pointer(
+            crate::hooks::Pointer::$kind($arg, hook_len!($($($len)*)?)),
+        )
+    };
+}
+
+#[cfg(any(test, feature = "hooks"))]
+macro_rules! hook_args {
+    (
+        handle: $handle:ident = $value:expr,
+        takes_handle: true,
+        args: [$($arg:ident: $kind:ident $([$($len:tt)*])?,)*],
+    ) => {
+        [
+            crate::hooks::Arg {
+                name: stringify!($handle),
+                value: crate::hooks::Value::Handle(
+                    crate::hooks::Object::new($value),
+                ),
+            },
+            $(crate::hooks::Arg {
+                name: stringify!($arg),
+                value: hook_value!($kind $([$($len)*])?, $arg),
+            },)*
+        ]
+    };
+    (
+        handle: $handle:ident = $value:expr,
+        takes_handle: false,
+        args: [$($arg:ident: $kind:ident $([$($len:tt)*])?,)*],
+    ) => {
+        [
+            $(crate::hooks::Arg {
+                name: stringify!($arg),
+                value: hook_value!($kind $([$($len)*])?, $arg),
+            },)*
+        ]
+    };
+}
+
+#[cfg(any(test, feature = "hooks"))]
+macro_rules! skip_handle {
+    ($args:ident, true) => { $args.next(); };
+    ($args:ident, false) => {};
+}
+
+// Calls a table entry of a `Hooked` table through its hooks.
+#[cfg(any(test, feature = "hooks"))]
+macro_rules! hooked_call {
+    (
+        $self:ident,
+        fn: $fn:expr,
+        command: $pfn:ident,
+        args: [$($arg:ident,)*],
+        hooks: [$($hooks:tt)*],
+        handle: $handle:ident,
+        takes_handle: $takes_handle:tt,
+    ) => {{
+        use crate::hooks::ReturnValue;
+        let f = $fn;
+        let args = hook_args! {
+            handle: $handle = $self.table.$handle,
+            takes_handle: $takes_handle,
+            args: [$($hooks)*],
+        };
+        let call = crate::hooks::Call {
+            command: Command::$pfn,
+            args: &args,
+            location: std::panic::Location::caller(),
+        };
+        let mut ret = None;
+        let status = $self.hooks.call(&call, &mut || {
+            let res = call_cmd! {
+                fn: f,
+                args: [$($arg,)*],
+                handle: $self.table.$handle,
+                takes_handle: $takes_handle,
+            };
+            let status = res.status();
+            ret = Some(res);
+            status
+        });
+        ReturnValue::with_status(ret, status)
+    }};
+}
+
+// Defines the methods which call a table entry. Eager tables store
+// each entry in a `field`, whereas `lazy` tables have an accessor.
+macro_rules! impl_methods {
+    (
+        $get:ident,
+        handle: $handle:ident,
+        member: $member:ident,
+        method_name: $method:ident,
+        try_method_name: $try_method:ident,
+        unchecked_method_name: $unchecked_method:ident,
+        ptr: $pfn:ident,
+        signature: ($($arg:ident: $type:ty,)*) $(-> $ret:ty)*,
+        takes_handle: $takes_handle:tt,
+    ) => {
+        /// Panics if the command was not loaded.
+        ///
+        /// # Safety
+        ///
+        /// See the [crate documentation](crate#safety).
+        #[inline(always)]
+        #[track_caller]
+        pub unsafe fn $method(&self, $($arg: $type,)*) $(-> $ret)* {
+            call_cmd! {
+                fn: load_pfn!(get_pfn!($get, self, $member), $pfn),
+                args: [$($arg,)*],
+                handle: self.$handle,
+                takes_handle: $takes_handle,
+            }
+        }
+
+        /// Returns `None` if the command was not loaded.
+        ///
+        /// # Safety
+        ///
+        /// See the [crate documentation](crate#safety).
+        #[inline(always)]
+        pub unsafe fn $try_method(&self, $($arg: $type,)*)
+            -> Option<($($ret)*)>
+        {
+            Some(call_cmd! {
+                fn: get_pfn!($get, self, $member)?,
+                args: [$($arg,)*],
+                handle: self.$handle,
+                takes_handle: $takes_handle,
+            })
+        }
+
+        /// Skips the check that the command was loaded.
+        ///
+        /// # Safety
+        ///
+        /// The command must be loaded. See also the [crate
+        /// documentation](crate#safety).
+        #[inline(always)]
+        pub unsafe fn $unchecked_method(&self, $($arg: $type,)*)
+            $(-> $ret)*
+        {
+            call_cmd! {
+                fn: get_pfn!($get, self, $member).unwrap_unchecked(),
+                args: [$($arg,)*],
+                handle: self.$handle,
+                takes_handle: $takes_handle,
+            }
+        }
+    }
+}
+
 macro_rules! impl_table {
     (
         name: $name:ident,
+        lazy_name: $lazy_name:ident,
         get_proc_addr: $get_proc_addr:ident,
         handle: {
             name: $handle:ident,
@@ -134,9 +955,18 @@ macro_rules! impl_table {
                 {
                     name: $member:ident,
                     method_name: $method:ident,
+                    try_method_name: $try_method:ident,
+                    unchecked_method_name: $unchecked_method:ident,
                     ptr: $pfn:ident,
+                    $(aliases: [$($alias:ident),*],)?
                     signature: ($($arg:ident: $type:ty,)*) $(-> $ret:ty)*,
                     takes_handle: $takes_handle:tt,
+                    provided_by: [
+                        $([$($requires:ident),*] => $provider:ident,)*
+                    ],
+                    hooks: [$($hooks:tt)*],
+                    $(checked: { $($checked:tt)* },)?
+                    $(vec: { $($vec:tt)* },)?
                 },
             )*
         ],
@@ -147,20 +977,109 @@ macro_rules! impl_table {
             $(pub $member: Option<pfn::$pfn>,)*
         }
         impl $name {
+            /// Loads each command through `get_proc_addr`.
+            ///
+            /// # Safety
+            ///
+            /// `get_proc_addr` must be a valid implementation of
+            /// `vkGet*ProcAddr` for the handle. See the [crate
+            /// documentation](crate#safety).
             pub unsafe fn load(
                 $handle: $handle_type,
                 get_proc_addr: pfn::$get_proc_addr,
+            ) -> Self {
+                Self::load_with($handle, get_proc_addr, |_, _| {})
+            }
+
+            /// Loads the table, falling back on alias names (e.g.
+            /// `vkGetPhysicalDeviceFeatures2KHR`) for commands that
+            /// aren't available under their core name. `on_resolve` is
+            /// called with the name of each loaded command and the name
+            /// it was resolved under.
+            ///
+            /// # Safety
+            ///
+            /// `get_proc_addr` must be a valid implementation of
+            /// `vkGet*ProcAddr` for the handle. See the [crate
+            /// documentation](crate#safety).
+            pub unsafe fn load_with(
+                $handle: $handle_type,
+                get_proc_addr: pfn::$get_proc_addr,
+                mut on_resolve: impl FnMut(&'static str, &'static str),
             ) -> Self {
                 $name {
                     $handle,
                     $(
                         $member: {
-                            let symbol = vk_symbol!($pfn);
-                            std::mem::transmute(get_proc_addr($handle, symbol))
+                            let names = [
+                                concat!(vk_name!($pfn), "\0"),
+                                $($(concat!(vk_name!($alias), "\0"),)*)?
+                            ];
+                            let f = resolve(
+                                get_proc_addr,
+                                $handle,
+                                &names,
+                                &mut on_resolve,
+                            );
+                            std::mem::transmute::<
+                                pfn::VoidFunction,
+                                Option<pfn::$pfn>,
+                            >(f)
                         },
                     )*
                 }
             }
+
+            /// Loads only the commands provided by `api_version` and
+            /// `extensions`, which should be the values the handle was
+            /// created with. The remaining entries are left null, even
+            /// if the implementation exposes them.
+            ///
+            /// # Safety
+            ///
+            /// `get_proc_addr` must be a valid implementation of
+            /// `vkGet*ProcAddr` for the handle. See the [crate
+            /// documentation](crate#safety).
+            pub unsafe fn load_for(
+                $handle: $handle_type,
+                get_proc_addr: pfn::$get_proc_addr,
+                api_version: u32,
+                extensions: &[*const c_char],
+            ) -> Self {
+                let enabled = Enabled { api_version, extensions };
+                $name {
+                    $handle,
+                    $(
+                        $member: {
+                            let providers: &[(&[&str], &str)] = &[$((
+                                &[$(stringify!($requires)),*],
+                                concat!(vk_name!($provider), "\0"),
+                            ),)*];
+                            let f = resolve_enabled(
+                                get_proc_addr,
+                                $handle,
+                                &enabled,
+                                providers,
+                            );
+                            std::mem::transmute::<
+                                pfn::VoidFunction,
+                                Option<pfn::$pfn>,
+                            >(f)
+                        },
+                    )*
+                }
+            }
+
+            /// Returns true if the command was loaded. Tables loaded
+            /// with `load_for` only contain commands provided by the
+            /// enabled API version and extensions.
+            pub fn supports(&self, command: Command) -> bool {
+                match command {
+                    $(Command::$pfn => self.$member.is_some(),)*
+                    #[allow(unreachable_patterns)]
+                    _ => false,
+                }
+            }
         }
         impl Debug for $name {
             fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
@@ -172,15 +1091,347 @@ macro_rules! impl_table {
         }
         impl $name {
             $(
+                impl_methods! {
+                    field,
+                    handle: $handle,
+                    member: $member,
+                    method_name: $method,
+                    try_method_name: $try_method,
+                    unchecked_method_name: $unchecked_method,
+                    ptr: $pfn,
+                    signature: ($($arg: $type,)*) $(-> $ret)*,
+                    takes_handle: $takes_handle,
+                }
+                $(impl_checked!($method, $($checked)*);)?
+                $(impl_vec!($method, $($vec)*);)?
+            )*
+        }
+
+        #[doc = concat!(
+            "Like `", stringify!($name), "`, but looks up each command ",
+            "the first time it is called instead of when the table is ",
+            "created.",
+        )]
+        pub struct $lazy_name {
+            pub $handle: $handle_type,
+            get_proc_addr: pfn::$get_proc_addr,
+            $($member: LazySlot,)*
+        }
+        // The handle and function pointers may be used from any thread.
+        unsafe impl Send for $lazy_name {}
+        unsafe impl Sync for $lazy_name {}
+        impl $lazy_name {
+            /// Creates the table without looking up any commands.
+            ///
+            /// # Safety
+            ///
+            /// As for `load`.
+            pub unsafe fn new(
+                $handle: $handle_type,
+                get_proc_addr: pfn::$get_proc_addr,
+            ) -> Self {
+                $lazy_name {
+                    $handle,
+                    get_proc_addr,
+                    $($member: LazySlot::new(),)*
+                }
+            }
+
+            $(
+                /// Returns the command, looking it up if this is the
+                /// first call. Alias names are tried as in `load`.
                 #[inline(always)]
+                pub fn $member(&self) -> Option<pfn::$pfn> {
+                    let f = self.$member.get(|| unsafe {
+                        let names = [
+                            concat!(vk_name!($pfn), "\0"),
+                            $($(concat!(vk_name!($alias), "\0"),)*)?
+                        ];
+                        resolve(
+                            self.get_proc_addr,
+                            self.$handle,
+                            &names,
+                            &mut |_, _| {},
+                        )
+                    });
+                    unsafe {
+                        std::mem::transmute::<
+                            pfn::VoidFunction,
+                            Option<pfn::$pfn>,
+                        >(f)
+                    }
+                }
+            )*
+
+            /// Returns true if the command is available, looking it up
+            /// if necessary.
+            pub fn supports(&self, command: Command) -> bool {
+                match command {
+                    $(Command::$pfn => self.$member().is_some(),)*
+                    #[allow(unreachable_patterns)]
+                    _ => false,
+                }
+            }
+        }
+        impl Debug for $lazy_name {
+            fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+                f.debug_struct(stringify!($lazy_name))
+                    .field(stringify!($handle), &self.$handle.0)
+                    .finish_non_exhaustive()
+            }
+        }
+        impl $lazy_name {
+            $(
+                impl_methods! {
+                    lazy,
+                    handle: $handle,
+                    member: $member,
+                    method_name: $method,
+                    try_method_name: $try_method,
+                    unchecked_method_name: $unchecked_method,
+                    ptr: $pfn,
+                    signature: ($($arg: $type,)*) $(-> $ret)*,
+                    takes_handle: $takes_handle,
+                }
+                $(impl_checked!($method, $($checked)*);)?
+                $(impl_vec!($method, $($vec)*);)?
+            )*
+        }
+
+        #[cfg(any(test, feature = "hooks"))]
+        impl<H: crate::hooks::Hooks> crate::hooks::Hooked<$name, H> {
+            $(
+                /// Panics if the command was not loaded.
+                ///
+                /// # Safety
+                ///
+                /// See the [crate documentation](crate#safety).
+                #[track_caller]
                 pub unsafe fn $method(&self, $($arg: $type,)*) $(-> $ret)* {
-                    call_cmd! {
-                        fn: std::mem::transmute::<_, pfn::$pfn>(self.$member),
+                    hooked_call! {
+                        self,
+                        fn: load_pfn!(self.table.$member, $pfn),
+                        command: $pfn,
                         args: [$($arg,)*],
-                        handle: self.$handle,
+                        hooks: [$($hooks)*],
+                        handle: $handle,
                         takes_handle: $takes_handle,
                     }
                 }
+
+                /// Returns `None` if the command was not loaded.
+                ///
+                /// # Safety
+                ///
+                /// See the [crate documentation](crate#safety).
+                #[track_caller]
+                pub unsafe fn $try_method(&self, $($arg: $type,)*)
+                    -> Option<($($ret)*)>
+                {
+                    Some(hooked_call! {
+                        self,
+                        fn: self.table.$member?,
+                        command: $pfn,
+                        args: [$($arg,)*],
+                        hooks: [$($hooks)*],
+                        handle: $handle,
+                        takes_handle: $takes_handle,
+                    })
+                }
+
+                /// Skips the check that the command was loaded.
+                ///
+                /// # Safety
+                ///
+                /// The command must be loaded. See also the [crate
+                /// documentation](crate#safety).
+                #[track_caller]
+                pub unsafe fn $unchecked_method(&self, $($arg: $type,)*)
+                    $(-> $ret)*
+                {
+                    hooked_call! {
+                        self,
+                        fn: self.table.$member.unwrap_unchecked(),
+                        command: $pfn,
+                        args: [$($arg,)*],
+                        hooks: [$($hooks)*],
+                        handle: $handle,
+                        takes_handle: $takes_handle,
+                    }
+                }
+
+                $(impl_checked!($method, $($checked)*);)?
+                $(impl_vec!($method, $($vec)*);)?
+            )*
+        }
+
+        #[cfg(any(test, feature = "hooks"))]
+        impl $name {
+            // Calls `command` with each argument read from `args`, given
+            // as the address and length of its bytes, which start with
+            // the table's handle if the command takes it.
+            #[allow(dead_code)]
+            pub(crate) unsafe fn replay(
+                &self,
+                command: Command,
+                args: &[(*const u8, usize)],
+            ) -> std::result::Result<
+                Option<vk::Result>,
+                crate::capture::Unreplayable,
+            > {
+                use crate::capture::Unreplayable;
+                use crate::hooks::ReturnValue;
+                match command {
+                    $(Command::$pfn => {
+                        let names: &[&str] = &[$(stringify!($arg),)*];
+                        if args.len() != names.len() + $takes_handle as usize {
+                            return Err(Unreplayable::Malformed);
+                        }
+                        let mut args = args.iter();
+                        skip_handle!(args, $takes_handle);
+                        let ret = self.$try_method($({
+                            let &(ptr, len) = args.next().unwrap();
+                            if len != std::mem::size_of::<$type>() {
+                                return Err(Unreplayable::Malformed);
+                            }
+                            (ptr as *const $type).read_unaligned()
+                        },)*);
+                        Ok(ret.ok_or(Unreplayable::Unsupported)?.status())
+                    },)*
+                    #[allow(unreachable_patterns)]
+                    _ => Err(Unreplayable::Unsupported),
+                }
+            }
+        }
+    }
+}
+
+macro_rules! impl_view {
+    (
+        name: $name:ident,
+        table: $table:ident,
+        handle: {
+            name: $handle:ident,
+            type: $handle_type:ty,
+        },
+        members: [
+            $(
+                {
+                    method_name: $method:ident,
+                    try_method_name: $try_method:ident,
+                    unchecked_method_name: $unchecked_method:ident,
+                    signature: ($($arg:ident: $type:ty,)*) $(-> $ret:ty)*,
+                    $(checked: {
+                        method_name: $checked_method:ident,
+                        args: ($($checked_arg:ident: $checked_type:ty,)*),
+                        output: $output:ident: $output_type:ty,
+                        $(len: $len:expr,)?
+                        result: $result:ident,
+                    },)?
+                    $(vec: {
+                        method_name: $vec_method:ident,
+                        args: ($($vec_arg:ident: $vec_type:ty,)*),
+                        element: $element:ty,
+                        result: $vec_result:ident,
+                    },)?
+                },
+            )*
+        ],
+    ) => {
+        #[doc = concat!(
+            "Binds a `", stringify!($handle_type), "` to a `",
+            stringify!($table), "` so that it is passed to each command ",
+            "automatically.",
+        )]
+        #[derive(Clone, Copy, Debug)]
+        pub struct $name<'a> {
+            pub table: &'a $table,
+            pub $handle: $handle_type,
+        }
+        impl $table {
+            #[inline(always)]
+            pub fn $handle(&self, $handle: $handle_type) -> $name<'_> {
+                $name::new(self, $handle)
+            }
+        }
+        impl<'a> $name<'a> {
+            #[inline(always)]
+            pub fn new(table: &'a $table, $handle: $handle_type) -> Self {
+                $name { table, $handle }
+            }
+
+            $(
+                /// Panics if the command was not loaded.
+                ///
+                /// # Safety
+                ///
+                /// See the [crate documentation](crate#safety).
+                #[inline(always)]
+                #[track_caller]
+                pub unsafe fn $method(&self, $($arg: $type,)*) $(-> $ret)* {
+                    self.table.$method(self.$handle, $($arg,)*)
+                }
+
+                /// Returns `None` if the command was not loaded.
+                ///
+                /// # Safety
+                ///
+                /// See the [crate documentation](crate#safety).
+                #[inline(always)]
+                pub unsafe fn $try_method(&self, $($arg: $type,)*)
+                    -> Option<($($ret)*)>
+                {
+                    self.table.$try_method(self.$handle, $($arg,)*)
+                }
+
+                /// Skips the check that the command was loaded.
+                ///
+                /// # Safety
+                ///
+                /// The command must be loaded. See also the [crate
+                /// documentation](crate#safety).
+                #[inline(always)]
+                pub unsafe fn $unchecked_method(&self, $($arg: $type,)*)
+                    $(-> $ret)*
+                {
+                    self.table.$unchecked_method(self.$handle, $($arg,)*)
+                }
+
+                $(
+                    /// Calls the command with default-initialized
+                    /// output and returns it.
+                    ///
+                    /// # Safety
+                    ///
+                    /// See the [crate documentation](crate#safety).
+                    #[inline]
+                    #[track_caller]
+                    pub unsafe fn $checked_method(
+                        &self,
+                        $($checked_arg: $checked_type,)*
+                    ) -> checked_ret!($result, $output_type) {
+                        self.table.$checked_method(
+                            self.$handle,
+                            $($checked_arg,)*
+                        )
+                    }
+                )?
+
+                $(
+                    /// Calls the command until all elements have been
+                    /// written, default-initializing each element
+                    /// beforehand.
+                    ///
+                    /// # Safety
+                    ///
+                    /// See the [crate documentation](crate#safety).
+                    #[track_caller]
+                    pub unsafe fn $vec_method(&self, $($vec_arg: $vec_type,)*)
+                        -> vec_ret!($vec_result, $element)
+                    {
+                        self.table.$vec_method(self.$handle, $($vec_arg,)*)
+                    }
+                )?
             )*
         }
     }
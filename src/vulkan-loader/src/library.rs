@@ -0,0 +1,146 @@
+use std::ffi::{OsStr, OsString};
+use std::fmt;
+use std::io;
+use std::sync::OnceLock;
+
+use vk::pfn;
+
+use crate::Entry;
+
+/// Environment variable which, if set, names the only library that
+/// `Library::open_default` will try.
+pub const LIBRARY_PATH_VAR: &str = "VULKAN_LIBRARY_PATH";
+
+/// The libraries `Library::open_default` tries, in order.
+#[cfg(all(unix, not(target_os = "macos")))]
+pub const DEFAULT_LIBRARY_PATHS: &[&str] =
+    &["libvulkan.so.1", "libvulkan.so"];
+#[cfg(target_os = "macos")]
+pub const DEFAULT_LIBRARY_PATHS: &[&str] =
+    &["libvulkan.1.dylib", "libvulkan.dylib", "libMoltenVK.dylib"];
+#[cfg(windows)]
+pub const DEFAULT_LIBRARY_PATHS: &[&str] = &["vulkan-1.dll"];
+
+/// A Vulkan loader library opened at runtime.
+#[derive(Debug)]
+pub struct Library {
+    lib: libloading::Library,
+    get_instance_proc_addr: pfn::GetInstanceProcAddr,
+}
+
+/// A library which couldn't be opened, or which didn't export
+/// `vkGetInstanceProcAddr`.
+#[derive(Debug)]
+pub struct LoadAttempt {
+    pub path: OsString,
+    pub error: io::Error,
+}
+
+/// Lists every library that was tried and why it failed.
+#[derive(Debug)]
+pub struct LoadError {
+    pub attempts: Vec<LoadAttempt>,
+}
+
+impl fmt::Display for LoadError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "failed to load the Vulkan library")?;
+        for attempt in self.attempts.iter() {
+            let path = attempt.path.to_string_lossy();
+            write!(f, "\n  {}: {}", path, attempt.error)?;
+        }
+        Ok(())
+    }
+}
+
+impl std::error::Error for LoadError {}
+
+impl Library {
+    /// Opens the library at `path` and looks up `vkGetInstanceProcAddr`.
+    ///
+    /// # Safety
+    ///
+    /// Loading a library runs its initialization code, which must be
+    /// sound to run, and its `vkGetInstanceProcAddr` must follow the
+    /// Vulkan specification.
+    pub unsafe fn open(path: impl AsRef<OsStr>) -> Result<Self, LoadError> {
+        let path = path.as_ref();
+        Self::open_one(path).map_err(|error| LoadError {
+            attempts: vec![LoadAttempt { path: path.to_owned(), error }],
+        })
+    }
+
+    /// Opens the library named by `VULKAN_LIBRARY_PATH` if it is set,
+    /// and otherwise the first of `DEFAULT_LIBRARY_PATHS` that works.
+    ///
+    /// # Safety
+    ///
+    /// As for `open`.
+    pub unsafe fn open_default() -> Result<Self, LoadError> {
+        let paths = match std::env::var_os(LIBRARY_PATH_VAR) {
+            Some(path) => vec![path],
+            None => DEFAULT_LIBRARY_PATHS.iter().map(OsString::from).collect(),
+        };
+        let mut attempts = Vec::new();
+        for path in paths {
+            match Self::open_one(&path) {
+                Ok(lib) => return Ok(lib),
+                Err(error) => attempts.push(LoadAttempt { path, error }),
+            }
+        }
+        Err(LoadError { attempts })
+    }
+
+    unsafe fn open_one(path: &OsStr) -> io::Result<Self> {
+        let lib = libloading::Library::new(path)?;
+        let get_instance_proc_addr = *lib.get(b"vkGetInstanceProcAddr\0")?;
+        Ok(Library { lib, get_instance_proc_addr })
+    }
+
+    pub fn get_instance_proc_addr(&self) -> pfn::GetInstanceProcAddr {
+        self.get_instance_proc_addr
+    }
+
+    /// Looks up any other symbol exported by the library, e.g.
+    /// `vkGetDeviceProcAddr`. `symbol` must be nul-terminated.
+    ///
+    /// # Safety
+    ///
+    /// `T` must be the type of the symbol, e.g. a function pointer
+    /// with the same signature.
+    pub unsafe fn get<T: Copy>(&self, symbol: &[u8]) -> io::Result<T> {
+        Ok(*self.lib.get::<T>(symbol)?)
+    }
+
+    /// The returned table is only valid while the library is open.
+    ///
+    /// # Safety
+    ///
+    /// The table must not be used after the library is dropped.
+    pub unsafe fn entry(&self) -> Entry {
+        Entry::load(self.get_instance_proc_addr)
+    }
+}
+
+// Kept open for the rest of the program once loaded by `load_default`.
+static DEFAULT_LIBRARY: OnceLock<Library> = OnceLock::new();
+
+impl Entry {
+    /// Opens the Vulkan library with `Library::open_default` and loads
+    /// the entry table from it. The library is never closed, so the
+    /// table remains valid for the rest of the program.
+    ///
+    /// # Safety
+    ///
+    /// As for `Library::open`.
+    pub unsafe fn load_default() -> Result<Self, LoadError> {
+        let lib = match DEFAULT_LIBRARY.get() {
+            Some(lib) => lib,
+            None => {
+                let lib = Library::open_default()?;
+                DEFAULT_LIBRARY.get_or_init(|| lib)
+            },
+        };
+        Ok(lib.entry())
+    }
+}
@@ -0,0 +1,369 @@
+use std::backtrace::{Backtrace, BacktraceStatus};
+use std::collections::HashMap;
+use std::fmt;
+use std::panic::Location;
+use std::sync::{Arc, Mutex, MutexGuard, PoisonError};
+
+use crate::hooks::{Call, Hooks, Object, Value};
+use crate::Command;
+
+/// A misuse of an object found by a `Tracker`.
+#[derive(Clone, Debug)]
+pub enum Report {
+    /// A command was called with an object after it was destroyed.
+    UseAfterDestroy {
+        command: Command,
+        object: Object,
+        destroyed_by: Command,
+    },
+    /// An object outlived the device or instance it belongs to.
+    Leak {
+        object: Object,
+        parent: Option<Object>,
+        destroyed_by: Command,
+        /// Where the command which created the object was called from.
+        location: &'static Location<'static>,
+        /// The full stack at that point, which is only captured if
+        /// enabled by `RUST_BACKTRACE` or `RUST_LIB_BACKTRACE`, as by
+        /// `Backtrace::capture`.
+        backtrace: Arc<Backtrace>,
+    },
+}
+
+impl fmt::Display for Report {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Report::UseAfterDestroy { command, object, destroyed_by } => {
+                write!(
+                    f,
+                    "{} uses {:?} after {}",
+                    command.name(),
+                    object,
+                    destroyed_by.name(),
+                )
+            }
+            Report::Leak {
+                object,
+                parent,
+                destroyed_by,
+                location,
+                backtrace,
+            } => {
+                write!(f, "{:?}", object)?;
+                if let Some(parent) = parent {
+                    write!(f, " (from {:?})", parent)?;
+                }
+                write!(
+                    f,
+                    " was never destroyed before {}; created at {}",
+                    destroyed_by.name(),
+                    location,
+                )?;
+                if backtrace.status() == BacktraceStatus::Captured {
+                    write!(f, ", backtrace:\n{}", backtrace)?;
+                }
+                Ok(())
+            }
+        }
+    }
+}
+
+#[derive(Debug)]
+struct Creation {
+    location: &'static Location<'static>,
+    backtrace: Arc<Backtrace>,
+}
+
+#[derive(Debug)]
+struct Entry {
+    parent: Option<Object>,
+    // `Some` if the object was created by `vkCreate*` or `vkAllocate*`,
+    // rather than retrieved like a queue, so it must be destroyed
+    created: Option<Creation>,
+    serial: u64,
+    destroyed_by: Option<Command>,
+}
+
+#[derive(Debug, Default)]
+struct State {
+    objects: HashMap<Object, Entry>,
+    // The objects created from each object, destroyed or not, so that
+    // destroying one only visits its own descendants
+    children: HashMap<Object, Vec<Object>>,
+    serial: u64,
+    reports: Vec<Report>,
+}
+
+impl State {
+    fn check(&mut self, command: Command, object: Object) {
+        let entry = self.objects.get(&object);
+        let destroyed_by = entry.and_then(|entry| entry.destroyed_by);
+        if let Some(destroyed_by) = destroyed_by {
+            self.reports.push(Report::UseAfterDestroy {
+                command,
+                object,
+                destroyed_by,
+            });
+        }
+    }
+
+    // Lists the objects created from `object`, directly or not.
+    fn descendants(&self, object: Object) -> Vec<Object> {
+        let mut descendants = Vec::new();
+        let mut stack = vec![object];
+        while let Some(object) = stack.pop() {
+            if let Some(children) = self.children.get(&object) {
+                descendants.extend_from_slice(children);
+                stack.extend_from_slice(children);
+            }
+        }
+        descendants
+    }
+
+    // Destroys the live descendants of `object`. Those which had to be
+    // destroyed first are leaks, except for the children of pools.
+    fn destroy_children(&mut self, command: Command, object: Object) {
+        let mut children: Vec<_> = self
+            .descendants(object)
+            .into_iter()
+            .filter_map(|child| {
+                let entry = self.objects.get(&child)?;
+                let live = entry.destroyed_by.is_none();
+                live.then_some((entry.serial, child))
+            })
+            .collect();
+        children.sort_unstable_by_key(|&(serial, _)| serial);
+
+        let leaks = matches!(
+            object.ty,
+            vk::ObjectType::INSTANCE | vk::ObjectType::DEVICE,
+        );
+        for (_, child) in children {
+            let entry = self.objects.get_mut(&child).unwrap();
+            entry.destroyed_by = Some(command);
+            if let (true, Some(created)) = (leaks, &entry.created) {
+                self.reports.push(Report::Leak {
+                    object: child,
+                    parent: entry.parent,
+                    destroyed_by: command,
+                    location: created.location,
+                    backtrace: Arc::clone(&created.backtrace),
+                });
+            }
+        }
+    }
+
+    fn destroy(&mut self, command: Command, object: Object) {
+        self.destroy_children(command, object);
+
+        // Only the object itself is remembered, to catch later uses of
+        // it, so that entries don't pile up for the whole run. It is
+        // forgotten in turn when its own parent is destroyed.
+        for child in self.descendants(object) {
+            self.objects.remove(&child);
+            self.children.remove(&child);
+        }
+        self.children.remove(&object);
+
+        let serial = self.serial;
+        let entry = self.objects.entry(object).or_insert(Entry {
+            parent: None,
+            created: None,
+            serial,
+            destroyed_by: None,
+        });
+        entry.destroyed_by = Some(command);
+    }
+
+    fn create(
+        &mut self,
+        object: Object,
+        parent: Option<Object>,
+        created: Option<Creation>,
+    ) {
+        self.serial += 1;
+        let old = self.objects.insert(object, Entry {
+            parent,
+            created,
+            serial: self.serial,
+            destroyed_by: None,
+        });
+        // Handles of destroyed objects are often reused
+        let old_parent = old.and_then(|old| old.parent);
+        if old_parent == parent && old_parent.is_some() {
+            return;
+        }
+        if let Some(old_parent) = old_parent {
+            if let Some(children) = self.children.get_mut(&old_parent) {
+                children.retain(|&child| child != object);
+            }
+        }
+        if let Some(parent) = parent {
+            self.children.entry(parent).or_default().push(object);
+        }
+    }
+}
+
+// Lists the non-null handles a command reads, including those within
+// structures and arrays.
+unsafe fn input_handles(value: &Value<'_>, handles: &mut Vec<Object>) {
+    match value {
+        Value::Handle(object) if !object.is_null() => handles.push(*object),
+        Value::Pointer(ptr) if !ptr.is_output() => {
+            for element in ptr.elements() {
+                input_handles(&element, handles);
+            }
+        }
+        Value::Struct(value) => {
+            for member in value.members() {
+                input_handles(&member.value, handles);
+            }
+        }
+        _ => {}
+    }
+}
+
+// The object a `vkDestroy*` or `vkFree*` command destroys is its last
+// handle argument, or the last array, e.g. `pCommandBuffers`.
+unsafe fn destroyed_objects(call: &Call<'_>) -> Vec<Object> {
+    let mut handles = Vec::new();
+    let arg = call.args.iter().rev().find(|arg| match arg.value {
+        Value::Handle(_) => true,
+        Value::Pointer(ptr) => ptr.is_array() && !ptr.is_output(),
+        _ => false,
+    });
+    if let Some(arg) = arg {
+        input_handles(&arg.value, &mut handles);
+    }
+    handles
+}
+
+/// Hooks which keep track of the objects that commands create, and
+/// report objects which are used after being destroyed or never
+/// destroyed at all.
+///
+/// Every handle returned by a command is recorded with its parent,
+/// such as the `VkCommandPool` of a `VkCommandBuffer`. When a device or
+/// instance is destroyed, each of its objects that was created by
+/// `vkCreate*` or `vkAllocate*` and is still alive is reported as a
+/// leak, with the location it was created at, and a backtrace if
+/// enabled. The commands are still called when a report is made.
+///
+/// Destroyed objects are remembered until their parent is destroyed
+/// too, so uses of an object after its parent is gone are not
+/// reported.
+///
+/// ```ignore
+/// let tracker = Tracker::new();
+/// let instance_table = Hooked::new(instance_table, &tracker);
+/// let device_table = Hooked::new(device_table, &tracker);
+/// ...
+/// for report in tracker.take_reports() {
+///     eprintln!("{}", report);
+/// }
+/// ```
+#[derive(Debug, Default)]
+pub struct Tracker {
+    state: Mutex<State>,
+}
+
+impl Tracker {
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    /// Returns the reports made so far, and clears them.
+    pub fn take_reports(&self) -> Vec<Report> {
+        std::mem::take(&mut self.state().reports)
+    }
+
+    /// Lists the objects which are alive and have yet to be destroyed,
+    /// in the order they were created.
+    pub fn live_objects(&self) -> Vec<Object> {
+        let state = self.state();
+        let mut objects: Vec<_> = state
+            .objects
+            .iter()
+            .filter(|(_, entry)| entry.destroyed_by.is_none())
+            .filter(|(_, entry)| entry.created.is_some())
+            .map(|(&object, entry)| (entry.serial, object))
+            .collect();
+        objects.sort_unstable_by_key(|&(serial, _)| serial);
+        objects.into_iter().map(|(_, object)| object).collect()
+    }
+
+    fn state(&self) -> MutexGuard<'_, State> {
+        self.state.lock().unwrap_or_else(PoisonError::into_inner)
+    }
+}
+
+impl Hooks for Tracker {
+    fn call(
+        &self,
+        call: &Call<'_>,
+        next: &mut dyn FnMut() -> Option<vk::Result>,
+    ) -> Option<vk::Result> {
+        let name = call.command.name();
+        let mut used = Vec::new();
+        unsafe {
+            for arg in call.args {
+                input_handles(&arg.value, &mut used);
+            }
+        }
+
+        // Objects are destroyed before the call, as their handles may
+        // be reused by another thread as soon as it returns.
+        {
+            let mut state = self.state();
+            for &object in &used {
+                state.check(call.command, object);
+            }
+            if name.starts_with("vkDestroy") || name.starts_with("vkFree") {
+                for object in unsafe { destroyed_objects(call) } {
+                    state.destroy(call.command, object);
+                }
+            } else if call.command == Command::ResetDescriptorPool {
+                for &object in &used {
+                    if object.ty == vk::ObjectType::DESCRIPTOR_POOL {
+                        state.destroy_children(call.command, object);
+                    }
+                }
+            }
+        }
+
+        let result = next();
+        if result.is_some_and(|result| result.is_error()) {
+            return result;
+        }
+
+        let owned =
+            name.starts_with("vkCreate") || name.starts_with("vkAllocate");
+        let mut state = self.state();
+        for arg in call.args {
+            let ptr = match arg.value {
+                Value::Pointer(ptr) if ptr.is_output() => ptr,
+                _ => continue,
+            };
+            for element in unsafe { ptr.elements() } {
+                let object = match element {
+                    Value::Handle(object) if !object.is_null() => object,
+                    _ => continue,
+                };
+                // Falls back to the table's own handle
+                let parent_types = object.parent_types();
+                let parent = used
+                    .iter()
+                    .find(|parent| parent_types.contains(&parent.ty))
+                    .or_else(|| used.first())
+                    .filter(|_| !parent_types.is_empty())
+                    .copied();
+                let created = owned.then(|| Creation {
+                    location: call.location,
+                    backtrace: Arc::new(Backtrace::capture()),
+                });
+                state.create(object, parent, created);
+            }
+        }
+        result
+    }
+}
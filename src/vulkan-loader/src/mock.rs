@@ -0,0 +1,798 @@
+use std::cell::RefCell;
+use std::collections::{HashMap, VecDeque};
+use std::ffi::{c_void, CStr};
+use std::os::raw::c_char;
+use std::sync::{Arc, Mutex, MutexGuard, Weak};
+
+use vk::pfn;
+
+use crate::Entry;
+
+/// The API version the mock driver reports.
+pub const API_VERSION: u32 = vk::API_VERSION_1_1;
+/// The number of queues in the mock device's only queue family.
+pub const QUEUE_COUNT: u32 = 4;
+/// The size of the mock device's only memory heap.
+pub const HEAP_SIZE: vk::DeviceSize = 256 << 20;
+/// The alignment and size granularity of buffer memory requirements.
+pub const MEMORY_ALIGNMENT: vk::DeviceSize = 256;
+
+/// A fake Vulkan implementation which runs in-process, for testing
+/// code built on the loader tables without a GPU.
+///
+/// The driver exposes one physical device, with one queue family and
+/// one host-visible memory type, and implements enough commands to
+/// create instances, devices, queues, buffers, and device memory.
+/// Every handle it creates is tracked until it is destroyed, and
+/// misuse (unknown handles, objects outliving their parent) is
+/// recorded in `errors` rather than aborting the test.
+///
+/// ```ignore
+/// let driver = mock::Driver::new();
+/// let entry = driver.entry();
+/// let instance = entry.create_instance_checked(&info, None)?;
+/// let instance_table =
+///     InstanceTable::load(instance, mock::get_instance_proc_addr);
+/// ```
+#[derive(Clone, Debug)]
+pub struct Driver {
+    state: Arc<Mutex<State>>,
+}
+
+#[derive(Debug, Default)]
+struct State {
+    objects: HashMap<u64, Object>,
+    results: HashMap<String, VecDeque<vk::Result>>,
+    calls: HashMap<String, u32>,
+    errors: Vec<String>,
+    next_handle: u64,
+}
+
+#[derive(Debug)]
+struct Object {
+    ty: vk::ObjectType,
+    parent: u64,
+    // Boxed so that dispatchable handles can point to it
+    _dispatch: Option<Box<Dispatch>>,
+    data: Data,
+}
+
+#[derive(Debug)]
+enum Data {
+    None,
+    Queue { family: u32, index: u32 },
+    Buffer { size: vk::DeviceSize },
+    Memory(Box<[u8]>),
+}
+
+// Every dispatchable handle points to one of these, so that each has
+// a unique address. Commands never read through the handle, but look
+// it up in `DRIVERS` instead, so that a stale or foreign handle is
+// reported rather than dereferenced. Objects which are never destroyed
+// keep their driver alive.
+#[derive(Debug)]
+struct Dispatch {
+    _driver: Driver,
+}
+
+// Every driver created so far, which commands search for the owner of
+// a dispatchable handle.
+static DRIVERS: Mutex<Vec<Weak<Mutex<State>>>> = Mutex::new(Vec::new());
+
+thread_local! {
+    // The driver which `vkCreateInstance` and other global commands
+    // are dispatched to on this thread.
+    static CURRENT: RefCell<Option<Driver>> = const { RefCell::new(None) };
+}
+
+fn type_name(ty: vk::ObjectType) -> &'static str {
+    match ty {
+        vk::ObjectType::INSTANCE => "VkInstance",
+        vk::ObjectType::PHYSICAL_DEVICE => "VkPhysicalDevice",
+        vk::ObjectType::DEVICE => "VkDevice",
+        vk::ObjectType::QUEUE => "VkQueue",
+        vk::ObjectType::BUFFER => "VkBuffer",
+        vk::ObjectType::DEVICE_MEMORY => "VkDeviceMemory",
+        _ => "object",
+    }
+}
+
+impl Default for Driver {
+    fn default() -> Self {
+        let state = Arc::default();
+        let mut drivers = DRIVERS.lock().unwrap_or_else(|e| e.into_inner());
+        drivers.retain(|driver| driver.strong_count() > 0);
+        drivers.push(Arc::downgrade(&state));
+        Driver { state }
+    }
+}
+
+impl Driver {
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    /// Makes this the driver that global commands, such as
+    /// `vkCreateInstance`, are dispatched to on the calling thread,
+    /// and loads an entry table from it.
+    pub fn entry(&self) -> Entry {
+        CURRENT.with(|current| *current.borrow_mut() = Some(self.clone()));
+        unsafe { Entry::load(get_instance_proc_addr) }
+    }
+
+    /// Makes the next call to `command`, e.g. `"vkAllocateMemory"`,
+    /// return `result` without any other effect. Results pushed for
+    /// the same command are returned in order.
+    pub fn push_result(&self, command: &str, result: vk::Result) {
+        let mut state = self.state();
+        let results = state.results.entry(command.to_owned()).or_default();
+        results.push_back(result);
+    }
+
+    /// Returns the number of times `command` has been called.
+    pub fn call_count(&self, command: &str) -> u32 {
+        self.state().calls.get(command).copied().unwrap_or(0)
+    }
+
+    /// Returns the number of objects of type `ty` which have been
+    /// created and not yet destroyed.
+    pub fn live_objects(&self, ty: vk::ObjectType) -> usize {
+        self.state().objects.values().filter(|obj| obj.ty == ty).count()
+    }
+
+    /// Returns a description of each misuse detected so far.
+    pub fn errors(&self) -> Vec<String> {
+        self.state().errors.clone()
+    }
+
+    fn state(&self) -> MutexGuard<'_, State> {
+        self.state.lock().unwrap_or_else(|e| e.into_inner())
+    }
+
+    // Records a call and returns the scripted result, if any.
+    fn begin(&self, command: &str) -> Option<vk::Result> {
+        let mut state = self.state();
+        *state.calls.entry(command.to_owned()).or_default() += 1;
+        state.results.get_mut(command)?.pop_front()
+    }
+
+    fn create(&self, ty: vk::ObjectType, parent: u64, data: Data) -> u64 {
+        let mut state = self.state();
+        state.next_handle += 1;
+        let handle = state.next_handle;
+        let obj = Object { ty, parent, _dispatch: None, data };
+        state.objects.insert(handle, obj);
+        handle
+    }
+
+    fn create_dispatchable(
+        &self,
+        ty: vk::ObjectType,
+        parent: u64,
+        data: Data,
+    ) -> *const c_void {
+        let dispatch = Box::new(Dispatch { _driver: self.clone() });
+        let handle = &*dispatch as *const Dispatch as *const c_void;
+        let obj = Object { ty, parent, _dispatch: Some(dispatch), data };
+        self.state().objects.insert(handle as u64, obj);
+        handle
+    }
+
+    // Checks that `handle` is a live object of type `ty`.
+    fn check(&self, command: &str, ty: vk::ObjectType, handle: u64) -> bool {
+        let mut state = self.state();
+        if state.objects.get(&handle).is_some_and(|obj| obj.ty == ty) {
+            return true;
+        }
+        let error =
+            format!("{}: unknown {} {:#x}", command, type_name(ty), handle);
+        state.errors.push(error);
+        false
+    }
+
+    // Destroys an object along with the children it implicitly owns,
+    // i.e. physical devices and queues, and reports any others.
+    fn destroy(&self, command: &str, ty: vk::ObjectType, handle: u64) {
+        if handle == 0 || !self.check(command, ty, handle) {
+            return;
+        }
+        let mut state = self.state();
+        state.objects.remove(&handle);
+        let implicit = |ty| {
+            ty == vk::ObjectType::PHYSICAL_DEVICE
+                || ty == vk::ObjectType::QUEUE
+        };
+        state.objects.retain(|_, obj| obj.parent != handle || !implicit(obj.ty));
+        let mut leaked: Vec<_> = state.objects.iter()
+            .filter(|(_, obj)| obj.parent == handle)
+            .map(|(&child, obj)| (child, obj.ty))
+            .collect();
+        leaked.sort_unstable_by_key(|&(child, _)| child);
+        for (child, child_ty) in leaked {
+            state.errors.push(format!(
+                "{}: {} {:#x} was not destroyed before its parent",
+                command, type_name(child_ty), child,
+            ));
+        }
+    }
+
+    fn error(&self, error: String) {
+        self.state().errors.push(error);
+    }
+}
+
+// Finds the driver which created the dispatchable `handle`, which must
+// be a live object of type `ty`. An unknown handle is reported to the
+// current driver, if any.
+fn driver(
+    command: &str,
+    ty: vk::ObjectType,
+    handle: *const c_void,
+) -> Option<Driver> {
+    let handle = handle as u64;
+    let owner = DRIVERS.lock().unwrap_or_else(|e| e.into_inner()).iter()
+        .filter_map(Weak::upgrade)
+        .map(|state| Driver { state })
+        .find(|driver| driver.state().objects.contains_key(&handle));
+    match owner {
+        Some(driver) if driver.check(command, ty, handle) => Some(driver),
+        Some(_) => None,
+        None => {
+            if let Some(driver) = current() {
+                driver.check(command, ty, handle);
+            }
+            None
+        },
+    }
+}
+
+// Looks up the driver for a dispatchable handle, or returns `$err`
+// from the command if there is none.
+macro_rules! dispatch {
+    ($command:expr, $ty:ident, $handle:expr $(, $err:ident)?) => {
+        match driver($command, vk::ObjectType::$ty, $handle) {
+            Some(driver) => driver,
+            None => return $(vk::Result::$err)?,
+        }
+    }
+}
+
+fn current() -> Option<Driver> {
+    CURRENT.with(|current| current.borrow().clone())
+}
+
+// Implements the two-call idiom over a fixed array.
+unsafe fn enumerate<T: Copy>(
+    items: &[T],
+    p_count: *mut u32,
+    p_items: *mut T,
+) -> vk::Result {
+    if p_items.is_null() {
+        *p_count = items.len() as u32;
+        return vk::Result::SUCCESS;
+    }
+    let count = (*p_count as usize).min(items.len());
+    std::ptr::copy_nonoverlapping(items.as_ptr(), p_items, count);
+    *p_count = count as u32;
+    if count < items.len() {
+        vk::Result::INCOMPLETE
+    } else {
+        vk::Result::SUCCESS
+    }
+}
+
+macro_rules! lookup {
+    ($name:expr, $($cmd:ident: $fn:ident,)*) => {
+        match $name {
+            $(
+                name if name == concat!("vk", stringify!($cmd)).as_bytes() => {
+                    let pfn: pfn::$cmd = $fn;
+                    Some(std::mem::transmute::<
+                        pfn::$cmd,
+                        unsafe extern "C" fn(),
+                    >(pfn))
+                },
+            )*
+            _ => None,
+        }
+    }
+}
+
+unsafe fn lookup_device(name: &[u8]) -> pfn::VoidFunction {
+    lookup!(name,
+        GetDeviceProcAddr: get_device_proc_addr,
+        DestroyDevice: destroy_device,
+        GetDeviceQueue: get_device_queue,
+        QueueSubmit: queue_submit,
+        QueueWaitIdle: queue_wait_idle,
+        DeviceWaitIdle: device_wait_idle,
+        CreateBuffer: create_buffer,
+        DestroyBuffer: destroy_buffer,
+        GetBufferMemoryRequirements: get_buffer_memory_requirements,
+        AllocateMemory: allocate_memory,
+        FreeMemory: free_memory,
+        BindBufferMemory: bind_buffer_memory,
+        MapMemory: map_memory,
+        UnmapMemory: unmap_memory,
+    )
+}
+
+/// The mock driver's `vkGetInstanceProcAddr`.
+///
+/// # Safety
+///
+/// `p_name` must be a nul-terminated string.
+pub unsafe extern "C" fn get_instance_proc_addr(
+    _instance: vk::Instance,
+    p_name: *const c_char,
+) -> pfn::VoidFunction {
+    let name = CStr::from_ptr(p_name).to_bytes();
+    lookup!(name,
+        GetInstanceProcAddr: get_instance_proc_addr,
+        EnumerateInstanceVersion: enumerate_instance_version,
+        EnumerateInstanceExtensionProperties:
+            enumerate_instance_extension_properties,
+        EnumerateInstanceLayerProperties: enumerate_instance_layer_properties,
+        CreateInstance: create_instance,
+        DestroyInstance: destroy_instance,
+        EnumeratePhysicalDevices: enumerate_physical_devices,
+        GetPhysicalDeviceProperties: get_physical_device_properties,
+        GetPhysicalDeviceQueueFamilyProperties:
+            get_physical_device_queue_family_properties,
+        GetPhysicalDeviceMemoryProperties: get_physical_device_memory_properties,
+        EnumerateDeviceExtensionProperties:
+            enumerate_device_extension_properties,
+        CreateDevice: create_device,
+    ).or_else(|| lookup_device(name))
+}
+
+/// The mock driver's `vkGetDeviceProcAddr`.
+///
+/// # Safety
+///
+/// `p_name` must be a nul-terminated string.
+pub unsafe extern "C" fn get_device_proc_addr(
+    _device: vk::Device,
+    p_name: *const c_char,
+) -> pfn::VoidFunction {
+    lookup_device(CStr::from_ptr(p_name).to_bytes())
+}
+
+unsafe extern "C" fn enumerate_instance_version(
+    p_api_version: *mut u32,
+) -> vk::Result {
+    if let Some(res) = current().and_then(|d| d.begin("vkEnumerateInstanceVersion")) {
+        return res;
+    }
+    *p_api_version = API_VERSION;
+    vk::Result::SUCCESS
+}
+
+unsafe extern "C" fn enumerate_instance_extension_properties(
+    _p_layer_name: *const c_char,
+    p_property_count: *mut u32,
+    p_properties: *mut vk::ExtensionProperties,
+) -> vk::Result {
+    let command = "vkEnumerateInstanceExtensionProperties";
+    if let Some(res) = current().and_then(|d| d.begin(command)) {
+        return res;
+    }
+    enumerate(&[], p_property_count, p_properties)
+}
+
+unsafe extern "C" fn enumerate_instance_layer_properties(
+    p_property_count: *mut u32,
+    p_properties: *mut vk::LayerProperties,
+) -> vk::Result {
+    let command = "vkEnumerateInstanceLayerProperties";
+    if let Some(res) = current().and_then(|d| d.begin(command)) {
+        return res;
+    }
+    enumerate(&[], p_property_count, p_properties)
+}
+
+unsafe extern "C" fn create_instance(
+    _p_create_info: *const vk::InstanceCreateInfo,
+    _p_allocator: *const vk::AllocationCallbacks,
+    p_instance: *mut vk::Instance,
+) -> vk::Result {
+    let driver = match current() {
+        Some(driver) => driver,
+        None => return vk::Result::ERROR_INITIALIZATION_FAILED,
+    };
+    if let Some(res) = driver.begin("vkCreateInstance") {
+        return res;
+    }
+    let instance =
+        driver.create_dispatchable(vk::ObjectType::INSTANCE, 0, Data::None);
+    driver.create_dispatchable(
+        vk::ObjectType::PHYSICAL_DEVICE,
+        instance as u64,
+        Data::None,
+    );
+    *p_instance = vk::Instance(instance);
+    vk::Result::SUCCESS
+}
+
+unsafe extern "C" fn destroy_instance(
+    instance: vk::Instance,
+    _p_allocator: *const vk::AllocationCallbacks,
+) {
+    if instance.0.is_null() {
+        return;
+    }
+    let driver = dispatch!("vkDestroyInstance", INSTANCE, instance.0);
+    driver.begin("vkDestroyInstance");
+    driver.destroy(
+        "vkDestroyInstance",
+        vk::ObjectType::INSTANCE,
+        instance.0 as u64,
+    );
+}
+
+unsafe extern "C" fn enumerate_physical_devices(
+    instance: vk::Instance,
+    p_physical_device_count: *mut u32,
+    p_physical_devices: *mut vk::PhysicalDevice,
+) -> vk::Result {
+    let command = "vkEnumeratePhysicalDevices";
+    let driver =
+        dispatch!(command, INSTANCE, instance.0, ERROR_INITIALIZATION_FAILED);
+    if let Some(res) = driver.begin(command) {
+        return res;
+    }
+    let physical_devices: Vec<_> = driver.state().objects.iter()
+        .filter(|(_, obj)| obj.ty == vk::ObjectType::PHYSICAL_DEVICE)
+        .filter(|(_, obj)| obj.parent == instance.0 as u64)
+        .map(|(&handle, _)| vk::PhysicalDevice(handle as _))
+        .collect();
+    enumerate(&physical_devices, p_physical_device_count, p_physical_devices)
+}
+
+unsafe extern "C" fn get_physical_device_properties(
+    physical_device: vk::PhysicalDevice,
+    p_properties: *mut vk::PhysicalDeviceProperties,
+) {
+    let command = "vkGetPhysicalDeviceProperties";
+    dispatch!(command, PHYSICAL_DEVICE, physical_device.0).begin(command);
+    let mut props = vk::PhysicalDeviceProperties {
+        api_version: API_VERSION,
+        device_type: vk::PhysicalDeviceType::CPU,
+        ..Default::default()
+    };
+    let name = b"vulkan-loader mock device";
+    for (dst, &src) in props.device_name.iter_mut().zip(name.iter()) {
+        *dst = src as c_char;
+    }
+    *p_properties = props;
+}
+
+unsafe extern "C" fn get_physical_device_queue_family_properties(
+    physical_device: vk::PhysicalDevice,
+    p_queue_family_property_count: *mut u32,
+    p_queue_family_properties: *mut vk::QueueFamilyProperties,
+) {
+    let command = "vkGetPhysicalDeviceQueueFamilyProperties";
+    dispatch!(command, PHYSICAL_DEVICE, physical_device.0).begin(command);
+    let family = vk::QueueFamilyProperties {
+        queue_flags: vk::QueueFlags::GRAPHICS_BIT
+            | vk::QueueFlags::COMPUTE_BIT
+            | vk::QueueFlags::TRANSFER_BIT,
+        queue_count: QUEUE_COUNT,
+        ..Default::default()
+    };
+    enumerate(
+        &[family],
+        p_queue_family_property_count,
+        p_queue_family_properties,
+    );
+}
+
+unsafe extern "C" fn get_physical_device_memory_properties(
+    physical_device: vk::PhysicalDevice,
+    p_memory_properties: *mut vk::PhysicalDeviceMemoryProperties,
+) {
+    let command = "vkGetPhysicalDeviceMemoryProperties";
+    dispatch!(command, PHYSICAL_DEVICE, physical_device.0).begin(command);
+    let mut props = vk::PhysicalDeviceMemoryProperties {
+        memory_type_count: 1,
+        memory_heap_count: 1,
+        ..Default::default()
+    };
+    props.memory_types[0] = vk::MemoryType {
+        property_flags: vk::MemoryPropertyFlags::DEVICE_LOCAL_BIT
+            | vk::MemoryPropertyFlags::HOST_VISIBLE_BIT
+            | vk::MemoryPropertyFlags::HOST_COHERENT_BIT,
+        heap_index: 0,
+    };
+    props.memory_heaps[0] = vk::MemoryHeap {
+        size: HEAP_SIZE,
+        flags: vk::MemoryHeapFlags::DEVICE_LOCAL_BIT,
+    };
+    *p_memory_properties = props;
+}
+
+unsafe extern "C" fn enumerate_device_extension_properties(
+    physical_device: vk::PhysicalDevice,
+    _p_layer_name: *const c_char,
+    p_property_count: *mut u32,
+    p_properties: *mut vk::ExtensionProperties,
+) -> vk::Result {
+    let command = "vkEnumerateDeviceExtensionProperties";
+    let driver = dispatch!(
+        command,
+        PHYSICAL_DEVICE,
+        physical_device.0,
+        ERROR_INITIALIZATION_FAILED
+    );
+    if let Some(res) = driver.begin(command) {
+        return res;
+    }
+    enumerate(&[], p_property_count, p_properties)
+}
+
+unsafe extern "C" fn create_device(
+    physical_device: vk::PhysicalDevice,
+    p_create_info: *const vk::DeviceCreateInfo,
+    _p_allocator: *const vk::AllocationCallbacks,
+    p_device: *mut vk::Device,
+) -> vk::Result {
+    let driver = dispatch!(
+        "vkCreateDevice",
+        PHYSICAL_DEVICE,
+        physical_device.0,
+        ERROR_INITIALIZATION_FAILED
+    );
+    if let Some(res) = driver.begin("vkCreateDevice") {
+        return res;
+    }
+    let queue_infos = (*p_create_info).queue_create_infos();
+    for queue_info in queue_infos {
+        if queue_info.queue_family_index != 0
+            || queue_info.queue_count > QUEUE_COUNT
+        {
+            driver.error(format!(
+                "vkCreateDevice: invalid queue family {} or count {}",
+                queue_info.queue_family_index, queue_info.queue_count,
+            ));
+            return vk::Result::ERROR_INITIALIZATION_FAILED;
+        }
+    }
+
+    // Devices are owned by the instance so that destroying the
+    // instance reports them.
+    let instance = driver.state().objects[&(physical_device.0 as u64)].parent;
+    let device = driver.create_dispatchable(
+        vk::ObjectType::DEVICE,
+        instance,
+        Data::None,
+    );
+    for queue_info in queue_infos {
+        for index in 0..queue_info.queue_count {
+            let family = queue_info.queue_family_index;
+            driver.create_dispatchable(
+                vk::ObjectType::QUEUE,
+                device as u64,
+                Data::Queue { family, index },
+            );
+        }
+    }
+    *p_device = vk::Device(device);
+    vk::Result::SUCCESS
+}
+
+unsafe extern "C" fn destroy_device(
+    device: vk::Device,
+    _p_allocator: *const vk::AllocationCallbacks,
+) {
+    if device.0.is_null() {
+        return;
+    }
+    let driver = dispatch!("vkDestroyDevice", DEVICE, device.0);
+    driver.begin("vkDestroyDevice");
+    driver.destroy("vkDestroyDevice", vk::ObjectType::DEVICE, device.0 as u64);
+}
+
+unsafe extern "C" fn get_device_queue(
+    device: vk::Device,
+    queue_family_index: u32,
+    queue_index: u32,
+    p_queue: *mut vk::Queue,
+) {
+    let driver = dispatch!("vkGetDeviceQueue", DEVICE, device.0);
+    driver.begin("vkGetDeviceQueue");
+    let queue = driver.state().objects.iter()
+        .find(|(_, obj)| {
+            obj.parent == device.0 as u64 && match obj.data {
+                Data::Queue { family, index } =>
+                    (family, index) == (queue_family_index, queue_index),
+                _ => false,
+            }
+        })
+        .map(|(&handle, _)| handle);
+    *p_queue = match queue {
+        Some(queue) => vk::Queue(queue as _),
+        None => {
+            driver.error(format!(
+                "vkGetDeviceQueue: queue {} of family {} was not created",
+                queue_index, queue_family_index,
+            ));
+            vk::null()
+        },
+    };
+}
+
+unsafe extern "C" fn queue_submit(
+    queue: vk::Queue,
+    _submit_count: u32,
+    _p_submits: *const vk::SubmitInfo,
+    _fence: vk::Fence,
+) -> vk::Result {
+    let command = "vkQueueSubmit";
+    let driver = dispatch!(command, QUEUE, queue.0, ERROR_DEVICE_LOST);
+    driver.begin(command).unwrap_or(vk::Result::SUCCESS)
+}
+
+unsafe extern "C" fn queue_wait_idle(queue: vk::Queue) -> vk::Result {
+    let command = "vkQueueWaitIdle";
+    let driver = dispatch!(command, QUEUE, queue.0, ERROR_DEVICE_LOST);
+    driver.begin(command).unwrap_or(vk::Result::SUCCESS)
+}
+
+unsafe extern "C" fn device_wait_idle(device: vk::Device) -> vk::Result {
+    let command = "vkDeviceWaitIdle";
+    let driver = dispatch!(command, DEVICE, device.0, ERROR_DEVICE_LOST);
+    driver.begin(command).unwrap_or(vk::Result::SUCCESS)
+}
+
+unsafe extern "C" fn create_buffer(
+    device: vk::Device,
+    p_create_info: *const vk::BufferCreateInfo,
+    _p_allocator: *const vk::AllocationCallbacks,
+    p_buffer: *mut vk::Buffer,
+) -> vk::Result {
+    let command = "vkCreateBuffer";
+    let driver = dispatch!(command, DEVICE, device.0, ERROR_OUT_OF_HOST_MEMORY);
+    if let Some(res) = driver.begin(command) {
+        return res;
+    }
+    let size = (*p_create_info).size;
+    let data = Data::Buffer { size };
+    let buffer = driver.create(vk::ObjectType::BUFFER, device.0 as u64, data);
+    *p_buffer = vk::Buffer(buffer);
+    vk::Result::SUCCESS
+}
+
+unsafe extern "C" fn destroy_buffer(
+    device: vk::Device,
+    buffer: vk::Buffer,
+    _p_allocator: *const vk::AllocationCallbacks,
+) {
+    let driver = dispatch!("vkDestroyBuffer", DEVICE, device.0);
+    driver.begin("vkDestroyBuffer");
+    driver.destroy("vkDestroyBuffer", vk::ObjectType::BUFFER, buffer.0);
+}
+
+unsafe extern "C" fn get_buffer_memory_requirements(
+    device: vk::Device,
+    buffer: vk::Buffer,
+    p_memory_requirements: *mut vk::MemoryRequirements,
+) {
+    let command = "vkGetBufferMemoryRequirements";
+    let driver = dispatch!(command, DEVICE, device.0);
+    driver.begin(command);
+    if !driver.check(command, vk::ObjectType::BUFFER, buffer.0) {
+        return;
+    }
+    let size = match driver.state().objects[&buffer.0].data {
+        Data::Buffer { size } => size,
+        _ => unreachable!(),
+    };
+    let align = MEMORY_ALIGNMENT;
+    *p_memory_requirements = vk::MemoryRequirements {
+        size: size.div_ceil(align) * align,
+        alignment: align,
+        memory_type_bits: 1,
+    };
+}
+
+unsafe extern "C" fn allocate_memory(
+    device: vk::Device,
+    p_allocate_info: *const vk::MemoryAllocateInfo,
+    _p_allocator: *const vk::AllocationCallbacks,
+    p_memory: *mut vk::DeviceMemory,
+) -> vk::Result {
+    let command = "vkAllocateMemory";
+    let driver = dispatch!(command, DEVICE, device.0, ERROR_OUT_OF_DEVICE_MEMORY);
+    if let Some(res) = driver.begin(command) {
+        return res;
+    }
+    let info = &*p_allocate_info;
+    if info.memory_type_index != 0 {
+        driver.error(format!(
+            "vkAllocateMemory: invalid memory type {}",
+            info.memory_type_index,
+        ));
+        return vk::Result::ERROR_OUT_OF_DEVICE_MEMORY;
+    }
+    if info.allocation_size > HEAP_SIZE {
+        return vk::Result::ERROR_OUT_OF_DEVICE_MEMORY;
+    }
+    let data = vec![0; info.allocation_size as usize].into_boxed_slice();
+    let memory = driver.create(
+        vk::ObjectType::DEVICE_MEMORY,
+        device.0 as u64,
+        Data::Memory(data),
+    );
+    *p_memory = vk::DeviceMemory(memory);
+    vk::Result::SUCCESS
+}
+
+unsafe extern "C" fn free_memory(
+    device: vk::Device,
+    memory: vk::DeviceMemory,
+    _p_allocator: *const vk::AllocationCallbacks,
+) {
+    let driver = dispatch!("vkFreeMemory", DEVICE, device.0);
+    driver.begin("vkFreeMemory");
+    driver.destroy("vkFreeMemory", vk::ObjectType::DEVICE_MEMORY, memory.0);
+}
+
+unsafe extern "C" fn bind_buffer_memory(
+    device: vk::Device,
+    buffer: vk::Buffer,
+    memory: vk::DeviceMemory,
+    _memory_offset: vk::DeviceSize,
+) -> vk::Result {
+    let command = "vkBindBufferMemory";
+    let driver = dispatch!(command, DEVICE, device.0, ERROR_DEVICE_LOST);
+    if let Some(res) = driver.begin(command) {
+        return res;
+    }
+    driver.check(command, vk::ObjectType::BUFFER, buffer.0);
+    driver.check(command, vk::ObjectType::DEVICE_MEMORY, memory.0);
+    vk::Result::SUCCESS
+}
+
+unsafe extern "C" fn map_memory(
+    device: vk::Device,
+    memory: vk::DeviceMemory,
+    offset: vk::DeviceSize,
+    size: vk::DeviceSize,
+    _flags: vk::MemoryMapFlags,
+    pp_data: *mut *mut c_void,
+) -> vk::Result {
+    let command = "vkMapMemory";
+    let driver = dispatch!(command, DEVICE, device.0, ERROR_MEMORY_MAP_FAILED);
+    if let Some(res) = driver.begin(command) {
+        return res;
+    }
+    if !driver.check(command, vk::ObjectType::DEVICE_MEMORY, memory.0) {
+        return vk::Result::ERROR_MEMORY_MAP_FAILED;
+    }
+    let mut state = driver.state();
+    let data = match state.objects.get_mut(&memory.0).unwrap().data {
+        Data::Memory(ref mut data) => data,
+        _ => unreachable!(),
+    };
+    let len = data.len() as vk::DeviceSize;
+    let end = match size {
+        vk::WHOLE_SIZE => Some(len),
+        size => offset.checked_add(size),
+    };
+    if offset > len || end.is_none_or(|end| end > len) {
+        state.errors.push(format!(
+            "vkMapMemory: range {} + {} is outside VkDeviceMemory {:#x}",
+            offset, size, memory.0,
+        ));
+        return vk::Result::ERROR_MEMORY_MAP_FAILED;
+    }
+    *pp_data = data.as_mut_ptr().add(offset as usize) as *mut c_void;
+    vk::Result::SUCCESS
+}
+
+unsafe extern "C" fn unmap_memory(device: vk::Device, memory: vk::DeviceMemory) {
+    let driver = dispatch!("vkUnmapMemory", DEVICE, device.0);
+    driver.begin("vkUnmapMemory");
+    driver.check("vkUnmapMemory", vk::ObjectType::DEVICE_MEMORY, memory.0);
+}
@@ -0,0 +1,151 @@
+use std::collections::HashMap;
+use std::fmt;
+use std::sync::{Mutex, MutexGuard, PoisonError};
+use std::time::{Duration, Instant};
+
+use crate::hooks::{Call, Hooks};
+use crate::Command;
+
+/// How often a command was called and how long it took altogether.
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub struct Stats {
+    pub calls: u64,
+    pub time: Duration,
+}
+
+impl Stats {
+    /// The mean time per call.
+    pub fn mean(&self) -> Duration {
+        match self.calls {
+            0 => Duration::ZERO,
+            calls => {
+                let nanos = self.time.as_nanos() / calls as u128;
+                Duration::from_nanos(nanos as u64)
+            }
+        }
+    }
+}
+
+/// The statistics of a `Profiler` at some point.
+#[derive(Clone, Debug, Default)]
+pub struct Snapshot {
+    stats: HashMap<Command, Stats>,
+}
+
+impl Snapshot {
+    /// Returns zero for a command which wasn't called.
+    pub fn get(&self, command: Command) -> Stats {
+        self.stats.get(&command).copied().unwrap_or_default()
+    }
+
+    /// Lists the commands which were called, taking the most time
+    /// first.
+    pub fn sorted(&self) -> Vec<(Command, Stats)> {
+        let mut stats: Vec<_> =
+            self.stats.iter().map(|(&cmd, &stats)| (cmd, stats)).collect();
+        stats.sort_unstable_by(|(a_cmd, a), (b_cmd, b)| {
+            (b.time, b.calls, a_cmd.name())
+                .cmp(&(a.time, a.calls, b_cmd.name()))
+        });
+        stats
+    }
+
+    /// Returns the total of all commands.
+    pub fn total(&self) -> Stats {
+        self.stats.values().fold(Stats::default(), |total, stats| Stats {
+            calls: total.calls + stats.calls,
+            time: total.time + stats.time,
+        })
+    }
+}
+
+/// A table of each command's calls, total and mean time, sorted by
+/// total time.
+impl fmt::Display for Snapshot {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let sorted = self.sorted();
+        let width = sorted
+            .iter()
+            .map(|(command, _)| command.name().len())
+            .max()
+            .unwrap_or(0)
+            .max("command".len());
+        writeln!(
+            f,
+            "{:<width$} {:>10} {:>12} {:>12}",
+            "command", "calls", "total", "mean",
+            width = width,
+        )?;
+        for (command, stats) in sorted {
+            writeln!(
+                f,
+                "{:<width$} {:>10} {:>12} {:>12}",
+                command.name(),
+                stats.calls,
+                format!("{:.1?}", stats.time),
+                format!("{:.1?}", stats.mean()),
+                width = width,
+            )?;
+        }
+        Ok(())
+    }
+}
+
+/// Hooks which count the calls to each command and measure the
+/// wall-clock time they take, including any hooks called after this
+/// one.
+///
+/// ```ignore
+/// let profiler = Profiler::new();
+/// let device_table = Hooked::new(device_table, &profiler);
+/// ...
+/// print!("{}", profiler.snapshot());
+/// profiler.reset();
+/// ```
+#[derive(Debug, Default)]
+pub struct Profiler {
+    stats: Mutex<HashMap<Command, Stats>>,
+}
+
+impl Profiler {
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    pub fn snapshot(&self) -> Snapshot {
+        Snapshot { stats: self.stats().clone() }
+    }
+
+    /// Sets every command's statistics to zero.
+    pub fn reset(&self) {
+        self.stats().clear();
+    }
+
+    /// Takes a snapshot and resets the statistics at once, so no calls
+    /// are missed in between, e.g. at the end of each frame.
+    pub fn take(&self) -> Snapshot {
+        Snapshot { stats: std::mem::take(&mut *self.stats()) }
+    }
+
+    fn stats(&self) -> MutexGuard<'_, HashMap<Command, Stats>> {
+        self.stats.lock().unwrap_or_else(PoisonError::into_inner)
+    }
+}
+
+impl Hooks for Profiler {
+    fn call(
+        &self,
+        call: &Call<'_>,
+        next: &mut dyn FnMut() -> Option<vk::Result>,
+    ) -> Option<vk::Result> {
+        let start = Instant::now();
+        let result = next();
+        let time = start.elapsed();
+
+        let mut stats = self.stats();
+        let stats = stats.entry(call.command).or_default();
+        stats.calls += 1;
+        stats.time += time;
+        result
+    }
+}
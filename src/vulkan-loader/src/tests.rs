@@ -0,0 +1,1294 @@
+use std::ffi::{c_void, CStr};
+use std::os::raw::c_char;
+use std::ptr;
+
+use crate::*;
+
+unsafe extern "C" fn get_instance_proc_addr(
+    _instance: vk::Instance,
+    p_name: *const c_char,
+) -> vk::pfn::VoidFunction {
+    let name = CStr::from_ptr(p_name).to_bytes();
+    if name == b"vkEnumerateInstanceVersion" {
+        Some(std::mem::transmute(
+            enumerate_instance_version as vk::pfn::EnumerateInstanceVersion,
+        ))
+    } else {
+        None
+    }
+}
+
+unsafe extern "C" fn enumerate_instance_version(
+    p_api_version: *mut u32,
+) -> vk::Result {
+    *p_api_version = vk::API_VERSION_1_1;
+    vk::Result::SUCCESS
+}
+
+#[test]
+fn call_loaded_command() {
+    unsafe {
+        let entry = Entry::load(get_instance_proc_addr);
+        let mut version = 0;
+        assert_eq!(
+            entry.enumerate_instance_version(&mut version),
+            vk::Result::SUCCESS,
+        );
+        assert_eq!(version, vk::API_VERSION_1_1);
+        assert_eq!(
+            entry.try_enumerate_instance_version(&mut version),
+            Some(vk::Result::SUCCESS),
+        );
+    }
+}
+
+#[test]
+fn try_unloaded_command() {
+    unsafe {
+        let entry = Entry::load(get_instance_proc_addr);
+        let mut count = 0;
+        let res = entry.try_enumerate_instance_layer_properties(
+            &mut count,
+            ptr::null_mut(),
+        );
+        assert_eq!(res, None);
+    }
+}
+
+#[test]
+#[should_panic(expected = "vkEnumerateInstanceLayerProperties was not loaded")]
+fn call_unloaded_command() {
+    unsafe {
+        let entry = Entry::load(get_instance_proc_addr);
+        let mut count = 0;
+        entry.enumerate_instance_layer_properties(&mut count, ptr::null_mut());
+    }
+}
+
+unsafe extern "C" fn create_instance(
+    _p_create_info: *const vk::InstanceCreateInfo,
+    p_allocator: *const vk::AllocationCallbacks,
+    p_instance: *mut vk::Instance,
+) -> vk::Result {
+    if !p_allocator.is_null() {
+        return vk::Result::ERROR_INITIALIZATION_FAILED;
+    }
+    *p_instance = vk::Instance(0x1234 as _);
+    vk::Result::SUCCESS
+}
+
+#[test]
+fn call_checked_command() {
+    unsafe {
+        let mut entry = Entry::load(get_instance_proc_addr);
+        entry.pfn_create_instance = Some(create_instance);
+        let create_info = Default::default();
+        assert_eq!(
+            entry.create_instance_checked(&create_info, None),
+            Ok(vk::Instance(0x1234 as _)),
+        );
+        let allocator = std::mem::zeroed();
+        assert_eq!(
+            entry.create_instance_checked(&create_info, Some(&allocator)),
+            Err(vk::Result::ERROR_INITIALIZATION_FAILED),
+        );
+    }
+}
+
+unsafe extern "C" fn allocate_command_buffers(
+    _device: vk::Device,
+    p_allocate_info: *const vk::CommandBufferAllocateInfo,
+    p_command_buffers: *mut vk::CommandBuffer,
+) -> vk::Result {
+    for i in 0..(*p_allocate_info).command_buffer_count as usize {
+        *p_command_buffers.add(i) = vk::CommandBuffer((0x100 + i) as _);
+    }
+    vk::Result::SUCCESS
+}
+
+unsafe extern "C" fn create_compute_pipelines(
+    _device: vk::Device,
+    _pipeline_cache: vk::PipelineCache,
+    create_info_count: u32,
+    _p_create_infos: *const vk::ComputePipelineCreateInfo,
+    _p_allocator: *const vk::AllocationCallbacks,
+    p_pipelines: *mut vk::Pipeline,
+) -> vk::Result {
+    for i in 0..create_info_count as usize {
+        *p_pipelines.add(i) = vk::Pipeline(0x200 + i as u64);
+    }
+    vk::Result::PIPELINE_COMPILE_REQUIRED
+}
+
+#[test]
+fn call_checked_array_command() {
+    unsafe {
+        let mut table =
+            DeviceTable::load(vk::null(), mock::get_device_proc_addr);
+        table.pfn_allocate_command_buffers = Some(allocate_command_buffers);
+        table.pfn_create_compute_pipelines = Some(create_compute_pipelines);
+
+        let alloc_info = vk::CommandBufferAllocateInfo {
+            command_buffer_count: 2,
+            ..Default::default()
+        };
+        assert_eq!(
+            table.allocate_command_buffers_checked(&alloc_info),
+            Ok(vec![
+                vk::CommandBuffer(0x100 as _),
+                vk::CommandBuffer(0x101 as _),
+            ]),
+        );
+
+        // Non-error results other than success are passed along
+        let create_infos = [Default::default(); 2];
+        assert_eq!(
+            table.create_compute_pipelines_checked(
+                vk::null(),
+                create_infos.len() as u32,
+                create_infos.as_ptr(),
+                None,
+            ),
+            Ok((
+                vec![vk::Pipeline(0x200), vk::Pipeline(0x201)],
+                vk::Result::PIPELINE_COMPILE_REQUIRED,
+            )),
+        );
+    }
+}
+
+static LAYER_COUNT: std::sync::atomic::AtomicU32 =
+    std::sync::atomic::AtomicU32::new(2);
+
+// Simulates a layer being installed between the two calls.
+unsafe extern "C" fn enumerate_instance_layer_properties(
+    p_property_count: *mut u32,
+    p_properties: *mut vk::LayerProperties,
+) -> vk::Result {
+    use std::sync::atomic::Ordering;
+    let count = LAYER_COUNT.fetch_add(1, Ordering::Relaxed).min(3);
+    if p_properties.is_null() {
+        *p_property_count = count;
+        return vk::Result::SUCCESS;
+    }
+    let written = count.min(*p_property_count);
+    for i in 0..written {
+        (*p_properties.add(i as usize)).spec_version = i;
+    }
+    *p_property_count = written;
+    if written < count {
+        vk::Result::INCOMPLETE
+    } else {
+        vk::Result::SUCCESS
+    }
+}
+
+#[test]
+fn enumerate_retries_incomplete() {
+    unsafe {
+        let mut entry = Entry::load(get_instance_proc_addr);
+        entry.pfn_enumerate_instance_layer_properties =
+            Some(enumerate_instance_layer_properties);
+        let layers = entry.enumerate_instance_layer_properties_vec().unwrap();
+        let versions: Vec<_> =
+            layers.iter().map(|layer| layer.spec_version).collect();
+        assert_eq!(versions, [0, 1, 2]);
+    }
+}
+
+unsafe extern "C" fn get_physical_device_features_2(
+    _physical_device: vk::PhysicalDevice,
+    p_features: *mut vk::PhysicalDeviceFeatures2,
+) {
+    (*p_features).features.geometry_shader = vk::TRUE;
+}
+
+unsafe extern "C" fn get_instance_proc_addr_khr(
+    _instance: vk::Instance,
+    p_name: *const c_char,
+) -> vk::pfn::VoidFunction {
+    let name = CStr::from_ptr(p_name).to_bytes();
+    if name == b"vkGetPhysicalDeviceFeatures2KHR" {
+        Some(std::mem::transmute(
+            get_physical_device_features_2
+                as vk::pfn::GetPhysicalDeviceFeatures2,
+        ))
+    } else {
+        None
+    }
+}
+
+#[test]
+fn load_alias() {
+    unsafe {
+        let mut resolved = Vec::new();
+        let table = InstanceTable::load_with(
+            vk::null(),
+            get_instance_proc_addr_khr,
+            |name, alias| resolved.push((name, alias)),
+        );
+        assert_eq!(resolved, [(
+            "vkGetPhysicalDeviceFeatures2",
+            "vkGetPhysicalDeviceFeatures2KHR",
+        )]);
+        let features = table
+            .get_physical_device_features_2_checked(vk::null());
+        assert_eq!(features.features.geometry_shader, vk::TRUE);
+    }
+}
+
+#[test]
+fn load_for_enabled_extensions() {
+    unsafe {
+        let table = InstanceTable::load_for(
+            vk::null(),
+            get_instance_proc_addr_khr,
+            vk::API_VERSION_1_0,
+            &[],
+        );
+        assert!(!table.supports(Command::GetPhysicalDeviceFeatures2));
+
+        let table = InstanceTable::load_for(
+            vk::null(),
+            get_instance_proc_addr_khr,
+            vk::API_VERSION_1_0,
+            &[vk::KHR_GET_PHYSICAL_DEVICE_PROPERTIES2_EXTENSION_NAME],
+        );
+        assert!(table.supports(Command::GetPhysicalDeviceFeatures2));
+        assert!(!table.supports(Command::CmdDrawMeshTasksEXT));
+    }
+}
+
+#[test]
+fn enabled_versions() {
+    unsafe {
+        // The variant bits must not make 1.0 look like a later version
+        let api_version = (1 << 29) | vk::API_VERSION_1_0;
+        let enabled = Enabled { api_version, extensions: &[] };
+        assert!(enabled.has("VK_VERSION_1_0"));
+        assert!(!enabled.has("VK_VERSION_1_1"));
+        assert!(!enabled.has("VK_VERSION_1"));
+        assert!(!enabled.has("VK_VERSION_X_Y"));
+    }
+}
+
+#[test]
+fn physical_device_view() {
+    unsafe {
+        let table = InstanceTable::load(vk::null(), get_instance_proc_addr_khr);
+        let physical_device = table.physical_device(vk::null());
+        let features = physical_device.get_physical_device_features_2_checked();
+        assert_eq!(features.features.geometry_shader, vk::TRUE);
+        assert_eq!(
+            physical_device
+                .try_get_physical_device_properties(ptr::null_mut()),
+            None,
+        );
+    }
+}
+
+static DISPATCHED: std::sync::Mutex<Vec<(vk::CommandBuffer, [u32; 3])>> =
+    std::sync::Mutex::new(Vec::new());
+
+unsafe extern "C" fn cmd_dispatch(
+    command_buffer: vk::CommandBuffer,
+    group_count_x: u32,
+    group_count_y: u32,
+    group_count_z: u32,
+) {
+    let groups = [group_count_x, group_count_y, group_count_z];
+    DISPATCHED.lock().unwrap().push((command_buffer, groups));
+}
+
+unsafe extern "C" fn get_device_proc_addr(
+    _device: vk::Device,
+    p_name: *const c_char,
+) -> vk::pfn::VoidFunction {
+    let name = CStr::from_ptr(p_name).to_bytes();
+    if name == b"vkCmdDispatch" {
+        Some(std::mem::transmute(cmd_dispatch as vk::pfn::CmdDispatch))
+    } else {
+        None
+    }
+}
+
+#[test]
+fn command_buffer_view() {
+    unsafe {
+        let table = DeviceTable::load(vk::null(), get_device_proc_addr);
+        let cmd_buf = vk::CommandBuffer(0x1234 as _);
+        table.command_buffer(cmd_buf).cmd_dispatch(1, 2, 3);
+        let dispatched = DISPATCHED.lock().unwrap();
+        assert!(dispatched.contains(&(cmd_buf, [1, 2, 3])));
+        let queue = table.queue(vk::null());
+        assert_eq!(queue.try_queue_wait_idle(), None);
+    }
+}
+
+static LOOKUPS: std::sync::atomic::AtomicU32 =
+    std::sync::atomic::AtomicU32::new(0);
+
+unsafe extern "C" fn get_device_proc_addr_counted(
+    device: vk::Device,
+    p_name: *const c_char,
+) -> vk::pfn::VoidFunction {
+    LOOKUPS.fetch_add(1, std::sync::atomic::Ordering::Relaxed);
+    get_device_proc_addr(device, p_name)
+}
+
+#[test]
+fn lazy_table() {
+    use std::sync::atomic::Ordering;
+    unsafe {
+        let table = LazyDeviceTable::new(
+            vk::null(),
+            get_device_proc_addr_counted,
+        );
+        assert_eq!(LOOKUPS.load(Ordering::Relaxed), 0);
+        let cmd_buf = vk::CommandBuffer(0x5678 as _);
+        std::thread::scope(|s| {
+            for _ in 0..4 {
+                s.spawn(|| table.cmd_dispatch(cmd_buf, 4, 5, 6));
+            }
+        });
+        assert!(LOOKUPS.load(Ordering::Relaxed) >= 1);
+        let lookups = LOOKUPS.load(Ordering::Relaxed);
+        table.cmd_dispatch(cmd_buf, 4, 5, 6);
+        assert_eq!(table.try_queue_wait_idle(vk::null()), None);
+        assert_eq!(table.try_queue_wait_idle(vk::null()), None);
+        assert_eq!(LOOKUPS.load(Ordering::Relaxed), lookups + 1);
+        let dispatched = DISPATCHED.lock().unwrap();
+        let count = dispatched.iter().filter(|&&(cb, _)| cb == cmd_buf).count();
+        assert_eq!(count, 5);
+    }
+}
+
+#[cfg(feature = "libloading")]
+#[test]
+fn load_error_lists_attempts() {
+    unsafe {
+        let err = Library::open("/nonexistent/libvulkan.so.1").unwrap_err();
+        assert_eq!(err.attempts.len(), 1);
+        assert_eq!(err.attempts[0].path, "/nonexistent/libvulkan.so.1");
+        let message = err.to_string();
+        assert!(message.starts_with("failed to load the Vulkan library\n"));
+        assert!(message.contains("/nonexistent/libvulkan.so.1: "));
+    }
+}
+
+unsafe fn mock_device(
+    driver: &mock::Driver,
+) -> (InstanceTable, DeviceTable) {
+    let (instance_table, device_table) = hooked_mock_device(driver, Unhooked);
+    (instance_table.table, device_table.table)
+}
+
+// Hooks which just call the command
+#[derive(Clone, Copy)]
+struct Unhooked;
+
+impl hooks::Hooks for Unhooked {
+    fn call(
+        &self,
+        _call: &hooks::Call<'_>,
+        next: &mut dyn FnMut() -> Option<vk::Result>,
+    ) -> Option<vk::Result> {
+        next()
+    }
+}
+
+// Like `mock_device`, but the device is created through `hooks`
+unsafe fn hooked_mock_device<H: hooks::Hooks + Clone>(
+    driver: &mock::Driver,
+    hooks: H,
+) -> (hooks::Hooked<InstanceTable, H>, hooks::Hooked<DeviceTable, H>) {
+    let entry = driver.entry();
+    let instance =
+        entry.create_instance_checked(&Default::default(), None).unwrap();
+    let instance_table = hooks::Hooked::new(
+        InstanceTable::load(instance, mock::get_instance_proc_addr),
+        hooks.clone(),
+    );
+    let physical_devices =
+        instance_table.enumerate_physical_devices_vec().unwrap();
+    assert_eq!(physical_devices.len(), 1);
+
+    let priorities = [1.0];
+    let queue_info = vk::DeviceQueueCreateInfo {
+        queue_family_index: 0,
+        queue_count: 1,
+        p_queue_priorities: priorities.as_ptr(),
+        ..Default::default()
+    };
+    let create_info = vk::DeviceCreateInfo {
+        queue_create_info_count: 1,
+        p_queue_create_infos: &queue_info,
+        ..Default::default()
+    };
+    let device = instance_table
+        .create_device_checked(physical_devices[0], &create_info, None)
+        .unwrap();
+    let device_table = hooks::Hooked::new(
+        DeviceTable::load(device, mock::get_device_proc_addr),
+        hooks,
+    );
+    (instance_table, device_table)
+}
+
+// A device on its own mock driver, for the tests of hooks. Both tables
+// call `hooks` once the device is created.
+struct Fixture<H> {
+    driver: mock::Driver,
+    instance_table: hooks::Hooked<InstanceTable, H>,
+    device_table: hooks::Hooked<DeviceTable, H>,
+}
+
+impl<H: hooks::Hooks + Clone> Fixture<H> {
+    unsafe fn new(hooks: H) -> Self {
+        let driver = mock::Driver::new();
+        let (instance_table, device_table) = mock_device(&driver);
+        Fixture {
+            driver,
+            instance_table: hooks::Hooked::new(instance_table, hooks.clone()),
+            device_table: hooks::Hooked::new(device_table, hooks),
+        }
+    }
+
+    // Also calls `hooks` around the commands which create the device
+    unsafe fn hook_creation(hooks: H) -> Self {
+        let driver = mock::Driver::new();
+        let (instance_table, device_table) =
+            hooked_mock_device(&driver, hooks);
+        Fixture { driver, instance_table, device_table }
+    }
+
+    // Destroys the device and instance without calling the hooks
+    unsafe fn destroy(&self) {
+        self.device_table.table.destroy_device(ptr::null());
+        self.instance_table.table.destroy_instance(ptr::null());
+    }
+}
+
+#[test]
+fn mock_driver() {
+    unsafe {
+        let driver = mock::Driver::new();
+        let (instance_table, device_table) = mock_device(&driver);
+
+        let queue = device_table.get_device_queue_checked(0, 0);
+        assert_eq!(
+            device_table.queue(queue).queue_wait_idle(),
+            vk::Result::SUCCESS,
+        );
+
+        let create_info = vk::BufferCreateInfo {
+            size: 100,
+            usage: vk::BufferUsageFlags::TRANSFER_SRC_BIT,
+            ..Default::default()
+        };
+        let buffer =
+            device_table.create_buffer_checked(&create_info, None).unwrap();
+        let reqs = device_table.get_buffer_memory_requirements_checked(buffer);
+        assert_eq!(reqs.size, mock::MEMORY_ALIGNMENT);
+        let alloc_info = vk::MemoryAllocateInfo {
+            allocation_size: reqs.size,
+            memory_type_index: 0,
+            ..Default::default()
+        };
+        let memory =
+            device_table.allocate_memory_checked(&alloc_info, None).unwrap();
+        device_table.bind_buffer_memory(buffer, memory, 0).check().unwrap();
+        let mut data = ptr::null_mut();
+        device_table
+            .map_memory(memory, 0, reqs.size, Default::default(), &mut data)
+            .check()
+            .unwrap();
+        *(data as *mut u32) = 0xdead_beef;
+        device_table.unmap_memory(memory);
+        assert_eq!(driver.live_objects(vk::ObjectType::BUFFER), 1);
+
+        device_table.destroy_buffer(buffer, ptr::null());
+        device_table.free_memory(memory, ptr::null());
+        device_table.destroy_device(ptr::null());
+        instance_table.destroy_instance(ptr::null());
+        assert_eq!(driver.errors(), Vec::<String>::new());
+        assert_eq!(driver.live_objects(vk::ObjectType::DEVICE), 0);
+        assert_eq!(driver.live_objects(vk::ObjectType::QUEUE), 0);
+        assert_eq!(driver.call_count("vkMapMemory"), 1);
+    }
+}
+
+#[test]
+fn mock_driver_scripted_failure() {
+    unsafe {
+        let driver = mock::Driver::new();
+        let (instance_table, device_table) = mock_device(&driver);
+
+        driver.push_result(
+            "vkAllocateMemory",
+            vk::Result::ERROR_OUT_OF_DEVICE_MEMORY,
+        );
+        let alloc_info = vk::MemoryAllocateInfo {
+            allocation_size: 256,
+            ..Default::default()
+        };
+        assert_eq!(
+            device_table.allocate_memory_checked(&alloc_info, None),
+            Err(vk::Result::ERROR_OUT_OF_DEVICE_MEMORY),
+        );
+        assert_eq!(driver.live_objects(vk::ObjectType::DEVICE_MEMORY), 0);
+
+        let create_info = Default::default();
+        let buffer =
+            device_table.create_buffer_checked(&create_info, None).unwrap();
+        device_table.destroy_device(ptr::null());
+        instance_table.destroy_instance(ptr::null());
+        assert_eq!(driver.errors(), [format!(
+            "vkDestroyDevice: VkBuffer {:#x} was not destroyed before its \
+             parent",
+            buffer.0,
+        )]);
+    }
+}
+
+#[test]
+fn mock_driver_invalid_use() {
+    unsafe {
+        let driver = mock::Driver::new();
+        let (instance_table, device_table) = mock_device(&driver);
+
+        let alloc_info = vk::MemoryAllocateInfo {
+            allocation_size: 256,
+            ..Default::default()
+        };
+        let memory =
+            device_table.allocate_memory_checked(&alloc_info, None).unwrap();
+        let mut data = ptr::null_mut();
+        assert_eq!(
+            device_table.map_memory(memory, 128, 256, Default::default(),
+                &mut data),
+            vk::Result::ERROR_MEMORY_MAP_FAILED,
+        );
+        device_table
+            .map_memory(memory, 128, vk::WHOLE_SIZE, Default::default(),
+                &mut data)
+            .check()
+            .unwrap();
+        device_table.unmap_memory(memory);
+        device_table.free_memory(memory, ptr::null());
+
+        // A handle the driver never created is reported, not followed
+        let bogus = vk::Device(&alloc_info as *const _ as _);
+        let bogus_table = DeviceTable::load(bogus, mock::get_device_proc_addr);
+        assert_eq!(
+            bogus_table.device_wait_idle(),
+            vk::Result::ERROR_DEVICE_LOST,
+        );
+
+        device_table.destroy_device(ptr::null());
+        instance_table.destroy_instance(ptr::null());
+        assert_eq!(driver.errors(), [
+            format!(
+                "vkMapMemory: range 128 + 256 is outside VkDeviceMemory {:#x}",
+                memory.0,
+            ),
+            format!("vkDeviceWaitIdle: unknown VkDevice {:#x}", bogus.0 as u64),
+        ]);
+    }
+}
+
+#[test]
+fn create_with_builders() {
+    unsafe {
+        let driver = mock::Driver::new();
+        let entry = driver.entry();
+        let name = CStr::from_bytes_with_nul(b"vkl\0").unwrap();
+        let app_info = vk::ApplicationInfo::builder()
+            .application_name(name)
+            .api_version(vk::API_VERSION_1_1);
+        let create_info =
+            vk::InstanceCreateInfo::builder().application_info(&app_info);
+        let instance =
+            entry.create_instance_checked(&*create_info, None).unwrap();
+        let instance_table =
+            InstanceTable::load(instance, mock::get_instance_proc_addr);
+        let physical_devices =
+            instance_table.enumerate_physical_devices_vec().unwrap();
+
+        let priorities = [1.0];
+        let queue_infos = [vk::DeviceQueueCreateInfo::builder()
+            .queue_family_index(0)
+            .queue_priorities(&priorities)
+            .build()];
+        let mut features = vk::PhysicalDeviceVulkan11Features::default();
+        let create_info = vk::DeviceCreateInfo::builder()
+            .queue_create_infos(&queue_infos)
+            .push_next(&mut features);
+        assert_eq!(create_info.queue_create_info_count, 1);
+        let device = instance_table
+            .create_device_checked(physical_devices[0], &*create_info, None)
+            .unwrap();
+        let device_table =
+            DeviceTable::load(device, mock::get_device_proc_addr);
+
+        let create_info = vk::BufferCreateInfo::builder()
+            .size(64)
+            .usage(vk::BufferUsageFlags::UNIFORM_BUFFER_BIT)
+            .queue_family_indices(&[0]);
+        let buffer =
+            device_table.create_buffer_checked(&*create_info, None).unwrap();
+        assert_eq!(driver.live_objects(vk::ObjectType::BUFFER), 1);
+        device_table.destroy_buffer(buffer, ptr::null());
+        device_table.destroy_device(ptr::null());
+        instance_table.destroy_instance(ptr::null());
+        assert!(driver.errors().is_empty());
+    }
+}
+
+crate::export_icd! {
+    interface_version: 2..=5,
+    get_instance_proc_addr: mock::get_instance_proc_addr,
+}
+
+#[test]
+fn icd_entry_points() {
+    unsafe {
+        let mut version = icd::CURRENT_LOADER_ICD_INTERFACE_VERSION;
+        assert_eq!(
+            vk_icdNegotiateLoaderICDInterfaceVersion(&mut version),
+            vk::Result::SUCCESS,
+        );
+        assert_eq!(version, 5);
+        let mut version = 1;
+        assert_eq!(
+            vk_icdNegotiateLoaderICDInterfaceVersion(&mut version),
+            vk::Result::ERROR_INCOMPATIBLE_DRIVER,
+        );
+
+        let name = b"vkCreateInstance\0".as_ptr() as *const c_char;
+        assert!(vk_icdGetInstanceProcAddr(vk::null(), name).is_some());
+
+        let handle = icd::Dispatchable::new(42u32).into_handle();
+        assert_eq!(*(handle as *const usize), icd::ICD_LOADER_MAGIC);
+        let object = icd::Dispatchable::<u32>::from_handle(handle);
+        assert!(object.has_loader_magic());
+        assert_eq!(**object, 42);
+        assert_eq!(icd::Dispatchable::<u32>::destroy(handle), 42);
+    }
+}
+
+#[test]
+fn icd_manifest() {
+    let mut manifest = icd::Manifest::new(
+        "/opt/driver/lib\"soft\".so",
+        vk::API_VERSION_1_1,
+    );
+    manifest.is_portability_driver = true;
+    assert_eq!(manifest.to_json(), r#"{
+    "file_format_version": "1.0.1",
+    "ICD": {
+        "library_path": "/opt/driver/lib\"soft\".so",
+        "api_version": "1.1.0",
+        "is_portability_driver": true
+    }
+}
+"#);
+}
+
+crate::export_layer! {
+    get_instance_proc_addr: mock::get_instance_proc_addr,
+    get_device_proc_addr: mock::get_device_proc_addr,
+}
+
+#[test]
+fn layer_negotiate() {
+    unsafe {
+        let mut negotiate = layer::NegotiateLayerInterface {
+            s_type: layer::NegotiateLayerStructType::INTERFACE_STRUCT,
+            p_next: ptr::null_mut(),
+            loader_layer_interface_version: 3,
+            pfn_get_instance_proc_addr: None,
+            pfn_get_device_proc_addr: None,
+            pfn_get_physical_device_proc_addr: None,
+        };
+        assert_eq!(
+            vkNegotiateLoaderLayerInterfaceVersion(&mut negotiate),
+            vk::Result::SUCCESS,
+        );
+        assert_eq!(negotiate.loader_layer_interface_version, 2);
+        assert!(negotiate.pfn_get_instance_proc_addr.is_some());
+        assert!(negotiate.pfn_get_device_proc_addr.is_some());
+    }
+}
+
+unsafe extern "C" fn layer_queue_wait_idle(_queue: vk::Queue) -> vk::Result {
+    vk::Result::ERROR_DEVICE_LOST
+}
+
+#[test]
+fn layer_chain() {
+    static INSTANCES: layer::DispatchMap<InstanceTable> =
+        layer::DispatchMap::new();
+    static DEVICES: layer::DispatchMap<DeviceTable> =
+        layer::DispatchMap::new();
+
+    unsafe {
+        // Plays the part of the loader, with the mock driver as the
+        // next layer in the chain.
+        let driver = mock::Driver::new();
+        driver.entry();
+        let mut instance_link = layer::LayerInstanceLink {
+            p_next: ptr::null_mut(),
+            pfn_next_get_instance_proc_addr: mock::get_instance_proc_addr,
+            pfn_next_get_physical_device_proc_addr: None,
+        };
+        let mut instance_chain = layer::LayerInstanceCreateInfo {
+            s_type: vk::StructureType::LOADER_INSTANCE_CREATE_INFO,
+            p_next: ptr::null(),
+            function: layer::LayerFunction::LAYER_LINK_INFO,
+            u: layer::LayerInstanceCreateInfoU {
+                p_layer_info: &mut instance_link,
+            },
+        };
+        let create_info = vk::InstanceCreateInfo {
+            p_next: &mut instance_chain as *mut _ as *const c_void,
+            ..Default::default()
+        };
+        let mut instance = vk::null();
+        let table =
+            layer::create_instance(&create_info, ptr::null(), &mut instance)
+                .unwrap();
+        assert!(instance_chain.u.p_layer_info.is_null());
+        INSTANCES.insert(instance.0, table);
+
+        let table = INSTANCES.get(instance.0).unwrap();
+        let physical_device = table.enumerate_physical_devices_vec().unwrap()[0];
+        let mut device_link = layer::LayerDeviceLink {
+            p_next: ptr::null_mut(),
+            pfn_next_get_instance_proc_addr: mock::get_instance_proc_addr,
+            pfn_next_get_device_proc_addr: mock::get_device_proc_addr,
+        };
+        let mut device_chain = layer::LayerDeviceCreateInfo {
+            s_type: vk::StructureType::LOADER_DEVICE_CREATE_INFO,
+            p_next: ptr::null(),
+            function: layer::LayerFunction::LAYER_LINK_INFO,
+            u: layer::LayerDeviceCreateInfoU {
+                p_layer_info: &mut device_link,
+            },
+        };
+        let create_info = vk::DeviceCreateInfo {
+            p_next: &mut device_chain as *mut _ as *const c_void,
+            ..Default::default()
+        };
+        let mut device = vk::null();
+        let device_table = layer::create_device(
+            instance,
+            physical_device,
+            &create_info,
+            ptr::null(),
+            &mut device,
+        ).unwrap();
+        DEVICES.insert(device.0, device_table);
+
+        let hooks: &[(&str, unsafe extern "C" fn())] = &[(
+            "vkQueueWaitIdle",
+            std::mem::transmute::<vk::pfn::QueueWaitIdle, _>(
+                layer_queue_wait_idle,
+            ),
+        )];
+        let name = b"vkQueueWaitIdle\0".as_ptr() as *const c_char;
+        assert!(layer::intercept(name, hooks).is_some());
+        let name = b"vkQueueSubmit\0".as_ptr() as *const c_char;
+        assert!(layer::intercept(name, hooks).is_none());
+
+        DEVICES.remove(device.0).unwrap().destroy_device(ptr::null());
+        INSTANCES.remove(instance.0).unwrap().destroy_instance(ptr::null());
+        assert!(INSTANCES.get(instance.0).is_none());
+        assert_eq!(driver.errors(), Vec::<String>::new());
+    }
+}
+
+#[test]
+fn icd_manifest_round_trip() {
+    let manifest = icd::Manifest::new(
+        "C:\\drivers\\soft\u{e9}.dll",
+        vk::make_version!(1, 3, 250),
+    );
+    let parsed = icd::Manifest::parse(&manifest.to_json()).unwrap();
+    assert_eq!(parsed.library_path, manifest.library_path);
+    assert_eq!(parsed.api_version, manifest.api_version);
+    assert!(!parsed.is_portability_driver);
+
+    let parsed = icd::Manifest::parse(r#"{
+        "file_format_version": "1.0.0",
+        "ICD": { "library_path": "lib\u0073oft.so", "api_version": "1.1" }
+    }"#).unwrap();
+    assert_eq!(parsed.library_path.to_str(), Some("libsoft.so"));
+    assert_eq!(parsed.api_version, vk::API_VERSION_1_1);
+    assert!(icd::Manifest::parse(r#"{ "ICD": {} }"#).is_err());
+    assert!(icd::Manifest::parse(r#"{ "ICD": "#).is_err());
+}
+
+#[test]
+fn json_surrogates() {
+    let parsed = json::parse(r#""\ud83e\udd80""#).unwrap();
+    assert_eq!(parsed.as_str(), Some("\u{1f980}"));
+    assert_eq!(
+        json::parse(r#""\ud83e""#),
+        Err("unpaired surrogate at offset 7".to_owned()),
+    );
+    assert_eq!(
+        json::parse(r#""\udd80\ud83e""#),
+        Err("unpaired surrogate at offset 7".to_owned()),
+    );
+    assert_eq!(
+        json::parse(r#""\ud83e\u0041""#),
+        Err("invalid low surrogate at offset 13".to_owned()),
+    );
+}
+
+#[test]
+fn trace_text() {
+    unsafe {
+        let tracer = trace::Tracer::new(Vec::new(), trace::Format::Text);
+        let fixture = Fixture::new(&tracer);
+        let device_table = &fixture.device_table;
+
+        let create_info = vk::BufferCreateInfo {
+            size: 64,
+            ..Default::default()
+        };
+        let buffer =
+            device_table.create_buffer_checked(&create_info, None).unwrap();
+        let queue = device_table.get_device_queue_checked(0, 0);
+        let submits = [vk::SubmitInfo::default(); 2];
+        device_table
+            .queue_submit(queue, 2, submits.as_ptr(), vk::null())
+            .check()
+            .unwrap();
+        device_table.destroy_buffer(buffer, ptr::null());
+        fixture.destroy();
+
+        let log = String::from_utf8(tracer.into_inner()).unwrap();
+        let lines: Vec<_> = log.lines().collect();
+        assert_eq!(lines.len(), 4);
+        assert!(lines[0].starts_with("vkCreateBuffer(device: Device(0x"));
+        assert!(lines[0].contains("p_create_info: BufferCreateInfo {"));
+        assert!(lines[0].ends_with(&format!(
+            "p_allocator: null, p_buffer: Buffer({:#x})) -> Result(0)",
+            buffer.0,
+        )));
+        assert!(lines[1].starts_with("vkGetDeviceQueue("));
+        assert!(!lines[1].contains("->"));
+        assert!(lines[2].contains("submit_count: 2, p_submits: [SubmitInfo {"));
+        assert_eq!(lines[2].matches("SubmitInfo {").count(), 2);
+        assert!(lines[2].ends_with("fence: Fence(0x0)) -> Result(0)"));
+        assert!(lines[3].starts_with("vkDestroyBuffer("));
+    }
+}
+
+#[test]
+fn trace_json_lines() {
+    unsafe {
+        let tracer = trace::Tracer::new(Vec::new(), trace::Format::JsonLines);
+        let fixture = Fixture::new(&tracer);
+        let device_table = &fixture.device_table;
+
+        fixture.driver.push_result(
+            "vkAllocateMemory",
+            vk::Result::ERROR_OUT_OF_DEVICE_MEMORY,
+        );
+        let alloc_info = vk::MemoryAllocateInfo {
+            allocation_size: 256,
+            ..Default::default()
+        };
+        assert_eq!(
+            device_table.allocate_memory_checked(&alloc_info, None),
+            Err(vk::Result::ERROR_OUT_OF_DEVICE_MEMORY),
+        );
+        let physical_device = fixture.instance_table.table
+            .enumerate_physical_devices_vec()
+            .unwrap()[0];
+        let extensions = fixture.instance_table
+            .enumerate_device_extension_properties_vec(
+                physical_device,
+                ptr::null(),
+            )
+            .unwrap();
+        fixture.destroy();
+
+        let log = String::from_utf8(tracer.into_inner()).unwrap();
+        let lines: Vec<_> = log.lines()
+            .map(|line| json::parse(line).unwrap())
+            .collect();
+        assert_eq!(lines.len(), 3);
+        let command = |line: &json::Value| {
+            line.get("command").unwrap().as_str().unwrap().to_owned()
+        };
+        let arg = |line: &json::Value, name| {
+            line.get("args").unwrap().get(name).unwrap().clone()
+        };
+
+        assert_eq!(command(&lines[0]), "vkAllocateMemory");
+        assert_eq!(arg(&lines[0], "p_allocator"), json::Value::Null);
+        assert_eq!(arg(&lines[0], "p_memory").as_str(), Some("<unset>"));
+        assert_eq!(
+            lines[0].get("result").unwrap().as_str(),
+            Some("Result(-2)"),
+        );
+
+        // Both calls of the two-call idiom are logged, and the array is
+        // null the first time.
+        assert_eq!(command(&lines[1]), "vkEnumerateDeviceExtensionProperties");
+        assert_eq!(arg(&lines[1], "p_properties"), json::Value::Null);
+        let count = arg(&lines[2], "p_property_count");
+        assert_eq!(count.as_str(), Some(&*extensions.len().to_string()));
+        match arg(&lines[2], "p_properties") {
+            json::Value::Array(props) => {
+                assert_eq!(props.len(), extensions.len())
+            },
+            value => panic!("expected an array: {:?}", value),
+        }
+    }
+}
+
+#[test]
+fn capture_replay() {
+    unsafe {
+        let recorder = capture::Recorder::new(Vec::new());
+        let fixture = Fixture::new(&recorder);
+        let (driver, hooked) = (&fixture.driver, &fixture.device_table);
+
+        let queue_family_indices = [0];
+        let create_info = vk::BufferCreateInfo {
+            size: 100,
+            queue_family_index_count: 1,
+            p_queue_family_indices: queue_family_indices.as_ptr(),
+            ..Default::default()
+        };
+        let buffer = hooked.create_buffer_checked(&create_info, None).unwrap();
+        let reqs = hooked.get_buffer_memory_requirements_checked(buffer);
+        let alloc_info = vk::MemoryAllocateInfo {
+            allocation_size: reqs.size,
+            ..Default::default()
+        };
+        driver.push_result(
+            "vkAllocateMemory",
+            vk::Result::ERROR_OUT_OF_DEVICE_MEMORY,
+        );
+        assert!(hooked.allocate_memory_checked(&alloc_info, None).is_err());
+        let memory = hooked.allocate_memory_checked(&alloc_info, None).unwrap();
+        hooked.bind_buffer_memory(buffer, memory, 0).check().unwrap();
+        let queue = hooked.get_device_queue_checked(0, 0);
+        let submit = vk::SubmitInfo::default();
+        hooked.queue_submit(queue, 1, &submit, vk::null()).check().unwrap();
+        hooked.destroy_buffer(buffer, ptr::null());
+        hooked.free_memory(memory, ptr::null());
+        fixture.destroy();
+        let capture = recorder.into_inner().unwrap();
+
+        // The new device's handles differ, so each must be remapped
+        let (instance_table, device_table) = mock_device(driver);
+        let mut replayer = capture::Replayer::new(&device_table);
+        replayer.replay(&capture[..]).unwrap();
+        let captured = hooks::Object::new(buffer);
+        let replayed = replayer.get(captured).unwrap();
+        assert_ne!(replayed, buffer.0);
+        assert_eq!(driver.call_count("vkCreateBuffer"), 2);
+        // The failed allocation isn't replayed
+        assert_eq!(driver.call_count("vkAllocateMemory"), 3);
+        assert_eq!(driver.call_count("vkQueueSubmit"), 2);
+        assert_eq!(driver.live_objects(vk::ObjectType::BUFFER), 0);
+        assert_eq!(driver.live_objects(vk::ObjectType::DEVICE_MEMORY), 0);
+
+        // Without the line that creates the buffer
+        let text = String::from_utf8(capture).unwrap();
+        let rest: String =
+            text.lines().skip(1).map(|line| format!("{}\n", line)).collect();
+        let mut replayer = capture::Replayer::new(&device_table);
+        match replayer.replay(rest.as_bytes()) {
+            Err(capture::ReplayError::UnknownHandle {
+                line: 1,
+                command: Command::GetBufferMemoryRequirements,
+                object,
+            }) => assert_eq!(object, captured),
+            res => panic!("expected an unknown handle: {:?}", res),
+        }
+
+        // Too few arguments, and one too short for its type
+        let malformed = concat!(
+            r#"{"command":"vkDeviceWaitIdle","args":{}}"#, "\n",
+            r#"{"command":"vkQueueWaitIdle","#,
+            r#""args":{"queue":{"bytes":"00000000"}}}"#, "\n",
+        );
+        for (i, line) in malformed.lines().enumerate() {
+            match replayer.replay(line.as_bytes()) {
+                Err(capture::ReplayError::Parse { line: 1 }) => {},
+                res => panic!("expected a parse error on {}: {:?}", i, res),
+            }
+        }
+
+        device_table.destroy_device(ptr::null());
+        instance_table.destroy_instance(ptr::null());
+        assert_eq!(driver.errors(), Vec::<String>::new());
+    }
+}
+
+#[test]
+fn inject_faults() {
+    unsafe {
+        let faults = faults::Faults::new(7)
+            .with(faults::Fault::new(
+                Command::AllocateMemory,
+                faults::When::Nth(2),
+                vk::Result::ERROR_OUT_OF_DEVICE_MEMORY,
+            ))
+            .with(faults::Fault::new(
+                Command::QueueSubmit,
+                faults::When::After(1),
+                vk::Result::ERROR_DEVICE_LOST,
+            ));
+        let fixture = Fixture::new(&faults);
+        let (driver, hooked) = (&fixture.driver, &fixture.device_table);
+
+        let alloc_info = vk::MemoryAllocateInfo {
+            allocation_size: 256,
+            ..Default::default()
+        };
+        let first = hooked.allocate_memory_checked(&alloc_info, None).unwrap();
+        // The output is left untouched, and the driver isn't called
+        let mut memory = vk::DeviceMemory(0xdead);
+        assert_eq!(
+            hooked.allocate_memory(&alloc_info, ptr::null(), &mut memory),
+            vk::Result::ERROR_OUT_OF_DEVICE_MEMORY,
+        );
+        assert_eq!(memory, vk::DeviceMemory(0xdead));
+        assert_eq!(driver.call_count("vkAllocateMemory"), 1);
+        let third = hooked.allocate_memory_checked(&alloc_info, None).unwrap();
+
+        let queue = hooked.get_device_queue_checked(0, 0);
+        let submit = |hooked: &hooks::Hooked<_, _>| {
+            hooked.queue_submit(queue, 0, ptr::null(), vk::null())
+        };
+        assert_eq!(submit(hooked), vk::Result::SUCCESS);
+        assert_eq!(submit(hooked), vk::Result::ERROR_DEVICE_LOST);
+        assert_eq!(submit(hooked), vk::Result::ERROR_DEVICE_LOST);
+        assert_eq!(faults.injected(), [
+            (Command::AllocateMemory, vk::Result::ERROR_OUT_OF_DEVICE_MEMORY),
+            (Command::QueueSubmit, vk::Result::ERROR_DEVICE_LOST),
+            (Command::QueueSubmit, vk::Result::ERROR_DEVICE_LOST),
+        ]);
+        faults.clear();
+        assert_eq!(submit(hooked), vk::Result::SUCCESS);
+
+        // The same seed fails the same calls
+        let failures = |seed| {
+            let faults = faults::Faults::new(seed).with(faults::Fault::any(
+                faults::When::Probability(0.5),
+                vk::Result::ERROR_OUT_OF_HOST_MEMORY,
+            ));
+            let hooked = hooks::Hooked::new(hooked.table, &faults);
+            (0..64)
+                .map(|_| hooked.device_wait_idle().is_error())
+                .collect::<Vec<_>>()
+        };
+        let failed = failures(42);
+        assert_eq!(failed, failures(42));
+        let count = failed.iter().filter(|&&failed| failed).count();
+        assert!(count > 8 && count < 56, "{} of 64 calls failed", count);
+
+        hooked.free_memory(first, ptr::null());
+        hooked.free_memory(third, ptr::null());
+        fixture.destroy();
+        assert_eq!(driver.errors(), Vec::<String>::new());
+    }
+}
+
+#[test]
+fn track_lifetimes() {
+    unsafe {
+        let tracker = lifetime::Tracker::new();
+        let fixture = Fixture::hook_creation(&tracker);
+        let device_table = &fixture.device_table;
+        let device = device_table.table.device;
+
+        let create_info = vk::BufferCreateInfo {
+            size: 256,
+            ..Default::default()
+        };
+        let buffer = device_table
+            .create_buffer_checked(&create_info, None)
+            .unwrap();
+        let leaked = device_table
+            .create_buffer_checked(&create_info, None)
+            .unwrap();
+        let alloc_info = vk::MemoryAllocateInfo {
+            allocation_size: 256,
+            ..Default::default()
+        };
+        let memory = device_table
+            .allocate_memory_checked(&alloc_info, None)
+            .unwrap();
+        // Retrieved rather than created, so it isn't leaked
+        device_table.get_device_queue_checked(0, 0);
+        assert_eq!(tracker.live_objects(), [
+            hooks::Object::new(device),
+            hooks::Object::new(buffer),
+            hooks::Object::new(leaked),
+            hooks::Object::new(memory),
+        ]);
+        assert!(tracker.take_reports().is_empty());
+
+        device_table.destroy_buffer(buffer, ptr::null());
+        device_table.get_buffer_memory_requirements_checked(buffer);
+        let reports = tracker.take_reports();
+        assert_eq!(reports.len(), 1);
+        assert_eq!(
+            reports[0].to_string(),
+            format!(
+                "vkGetBufferMemoryRequirements uses Buffer({:#x}) after \
+                 vkDestroyBuffer",
+                buffer.0,
+            ),
+        );
+
+        device_table.destroy_device(ptr::null());
+        let reports = tracker.take_reports();
+        let leaks: Vec<_> = reports
+            .iter()
+            .map(|report| match *report {
+                lifetime::Report::Leak {
+                    object,
+                    parent,
+                    destroyed_by: Command::DestroyDevice,
+                    ..
+                } => (object, parent.unwrap()),
+                ref report => panic!("unexpected report: {}", report),
+            })
+            .collect();
+        assert_eq!(leaks, [
+            (hooks::Object::new(leaked), hooks::Object::new(device)),
+            (hooks::Object::new(memory), hooks::Object::new(device)),
+        ]);
+        let created_at = format!("created at {}:", file!());
+        assert!(reports[0].to_string().contains(&created_at));
+
+        fixture.instance_table.destroy_instance(ptr::null());
+        assert!(tracker.take_reports().is_empty());
+        assert!(tracker.live_objects().is_empty());
+    }
+}
+
+#[test]
+fn detect_races() {
+    use std::sync::Barrier;
+    use std::thread;
+
+    // Holds up `vkQueueWaitIdle` until the main thread has used the
+    // queue too
+    #[derive(Clone)]
+    struct Pause<'a>(&'a Barrier);
+
+    impl hooks::Hooks for Pause<'_> {
+        fn call(
+            &self,
+            call: &hooks::Call<'_>,
+            next: &mut dyn FnMut() -> Option<vk::Result>,
+        ) -> Option<vk::Result> {
+            if call.command == Command::QueueWaitIdle {
+                self.0.wait();
+                self.0.wait();
+            }
+            next()
+        }
+    }
+
+    assert_eq!(Command::QueueSubmit.externsync_params(), ["queue", "fence"]);
+    assert_eq!(
+        Command::AllocateCommandBuffers.externsync_params(),
+        ["p_allocate_info.command_pool"],
+    );
+    assert!(Command::CreateBuffer.externsync_params().is_empty());
+
+    unsafe {
+        let barrier = Barrier::new(2);
+        let checker = externsync::Checker::new();
+        let fixture = Fixture::new((&checker, Pause(&barrier)));
+        let hooked = &fixture.device_table;
+        let queue = hooked.get_device_queue_checked(0, 0);
+        let submit_line = line!() + 1;
+        let submit = || hooked.queue_submit(queue, 0, ptr::null(), vk::null());
+
+        thread::scope(|scope| {
+            let waiter = thread::Builder::new()
+                .name("waiter".to_owned())
+                .spawn_scoped(scope, || hooked.queue_wait_idle(queue))
+                .unwrap();
+            barrier.wait();
+            assert_eq!(submit(), vk::Result::SUCCESS);
+            barrier.wait();
+            assert_eq!(waiter.join().unwrap(), vk::Result::SUCCESS);
+        });
+        let races = checker.take_races();
+        assert_eq!(races.len(), 1);
+        let race = &races[0];
+        assert_eq!(race.object, hooks::Object::new(queue));
+        assert_eq!(race.first.command, Command::QueueWaitIdle);
+        assert_eq!(race.first.thread_name.as_deref(), Some("waiter"));
+        assert_eq!(race.second.command, Command::QueueSubmit);
+        assert_eq!(race.second.thread, thread::current().id());
+        assert_eq!(race.first.location.file(), file!());
+        assert_eq!(race.second.location.line(), submit_line);
+
+        // Using the queue from one thread after another is fine
+        thread::scope(|scope| scope.spawn(submit).join().unwrap());
+        assert_eq!(submit(), vk::Result::SUCCESS);
+        assert!(checker.take_races().is_empty());
+
+        fixture.destroy();
+        assert_eq!(fixture.driver.errors(), Vec::<String>::new());
+    }
+}
+
+#[test]
+fn profile_commands() {
+    unsafe {
+        let profiler = profile::Profiler::new();
+        let fixture = Fixture::new(&profiler);
+        let hooked = &fixture.device_table;
+        let queue = hooked.get_device_queue_checked(0, 0);
+        for _ in 0..3 {
+            hooked.queue_submit(queue, 0, ptr::null(), vk::null());
+        }
+        hooked.device_wait_idle();
+
+        let snapshot = profiler.snapshot();
+        assert_eq!(snapshot.get(Command::QueueSubmit).calls, 3);
+        assert_eq!(snapshot.get(Command::DeviceWaitIdle).calls, 1);
+        assert_eq!(snapshot.get(Command::CreateBuffer), Default::default());
+        assert_eq!(snapshot.total().calls, 5);
+        let sorted = snapshot.sorted();
+        assert_eq!(sorted.len(), 3);
+        assert!(sorted.windows(2).all(|w| w[0].1.time >= w[1].1.time));
+        let report = snapshot.to_string();
+        let lines: Vec<_> = report.lines().collect();
+        assert_eq!(lines.len(), 4);
+        assert!(lines[0].starts_with("command "));
+        assert!(lines.iter().any(|line| {
+            line.starts_with("vkQueueSubmit ")
+                && line.split_whitespace().nth(1) == Some("3")
+        }));
+
+        // A snapshot leaves the statistics as they are
+        assert_eq!(profiler.snapshot().total().calls, 5);
+
+        // Taking the statistics resets them
+        assert_eq!(profiler.take().total().calls, 5);
+        assert_eq!(profiler.snapshot().total(), Default::default());
+        hooked.device_wait_idle();
+        profiler.reset();
+        assert_eq!(profiler.snapshot().total().calls, 0);
+
+        fixture.destroy();
+    }
+}
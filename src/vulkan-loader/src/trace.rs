@@ -0,0 +1,189 @@
+use std::ffi::CStr;
+use std::fmt::Write as _;
+use std::io;
+use std::sync::{Mutex, PoisonError};
+
+use crate::hooks::{Call, Hooks, Value};
+use crate::json;
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum Format {
+    /// One line per call, e.g.
+    /// `vkDestroyBuffer(device: Device(0x1), buffer: Buffer(0x2), ...)`.
+    Text,
+    /// One JSON object per line, with the command name, an object of
+    /// arguments, and the result if there is one. Arrays become JSON
+    /// arrays; other values are strings formatted with `Debug`.
+    JsonLines,
+}
+
+/// Hooks which log each command after it returns, with its arguments
+/// and `VkResult`. Pointers with a length are expanded into arrays.
+/// Output parameters of a command which failed are logged as
+/// `<unset>`, as the driver may not have written them.
+///
+/// ```ignore
+/// let tracer = Tracer::new(io::stderr(), Format::Text);
+/// let device_table = Hooked::new(device_table, tracer);
+/// ```
+#[derive(Debug)]
+pub struct Tracer<W> {
+    format: Format,
+    sink: Mutex<W>,
+}
+
+impl<W: io::Write> Tracer<W> {
+    pub fn new(sink: W, format: Format) -> Self {
+        Tracer { format, sink: Mutex::new(sink) }
+    }
+
+    pub fn format(&self) -> Format {
+        self.format
+    }
+
+    pub fn into_inner(self) -> W {
+        self.sink.into_inner().unwrap_or_else(PoisonError::into_inner)
+    }
+}
+
+impl<W: io::Write> Hooks for Tracer<W> {
+    fn call(
+        &self,
+        call: &Call<'_>,
+        next: &mut dyn FnMut() -> Option<vk::Result>,
+    ) -> Option<vk::Result> {
+        let result = next();
+        let line = unsafe {
+            match self.format {
+                Format::Text => text_line(call, result),
+                Format::JsonLines => json_line(call, result),
+            }
+        };
+        // A failing sink shouldn't fail the command
+        let mut sink = self.sink.lock().unwrap_or_else(PoisonError::into_inner);
+        let _ = sink.write_all(line.as_bytes());
+        result
+    }
+}
+
+// Output parameters are only written if the command succeeds.
+fn is_unset(value: &Value, result: Option<vk::Result>) -> bool {
+    matches!(value, Value::Pointer(ptr) if ptr.is_output())
+        && result.is_some_and(|result| !result.is_success())
+}
+
+unsafe fn write_text(out: &mut String, value: &Value) {
+    match *value {
+        Value::Handle(object) => {
+            let _ = write!(out, "{:?}", object);
+        },
+        Value::Str(s) if s.is_null() => out.push_str("null"),
+        Value::Str(s) => {
+            let _ = write!(out, "{:?}", CStr::from_ptr(s).to_string_lossy());
+        },
+        Value::Pointer(ptr) if ptr.is_null() => out.push_str("null"),
+        Value::Pointer(ptr) if ptr.is_array() => {
+            out.push('[');
+            for (i, elem) in ptr.elements().enumerate() {
+                if i > 0 {
+                    out.push_str(", ");
+                }
+                write_text(out, &elem);
+            }
+            out.push(']');
+        },
+        Value::Pointer(ptr) => write_text(out, &ptr.get(0)),
+        Value::Struct(value) => {
+            let _ = write!(out, "{:?}", value);
+        },
+        Value::Opaque(ptr) => {
+            let _ = write!(out, "{:?}", ptr);
+        },
+        Value::Debug(value) => {
+            let _ = write!(out, "{:?}", value);
+        },
+    }
+}
+
+unsafe fn text_line(call: &Call, result: Option<vk::Result>) -> String {
+    let mut line = String::new();
+    line.push_str(call.command.name());
+    line.push('(');
+    for (i, arg) in call.args.iter().enumerate() {
+        if i > 0 {
+            line.push_str(", ");
+        }
+        line.push_str(arg.name);
+        line.push_str(": ");
+        if is_unset(&arg.value, result) {
+            line.push_str("<unset>");
+        } else {
+            write_text(&mut line, &arg.value);
+        }
+    }
+    line.push(')');
+    if let Some(result) = result {
+        let _ = write!(line, " -> {:?}", result);
+    }
+    line.push('\n');
+    line
+}
+
+unsafe fn write_json(out: &mut String, value: &Value) {
+    match *value {
+        Value::Handle(object) => {
+            json::write_string(out, &format!("{:?}", object));
+        },
+        Value::Str(s) if s.is_null() => out.push_str("null"),
+        Value::Str(s) => {
+            json::write_string(out, &CStr::from_ptr(s).to_string_lossy());
+        },
+        Value::Pointer(ptr) if ptr.is_null() => out.push_str("null"),
+        Value::Pointer(ptr) if ptr.is_array() => {
+            out.push('[');
+            for (i, elem) in ptr.elements().enumerate() {
+                if i > 0 {
+                    out.push(',');
+                }
+                write_json(out, &elem);
+            }
+            out.push(']');
+        },
+        Value::Pointer(ptr) => write_json(out, &ptr.get(0)),
+        Value::Struct(value) => {
+            json::write_string(out, &format!("{:?}", value));
+        },
+        Value::Opaque(ptr) => {
+            json::write_string(out, &format!("{:?}", ptr));
+        },
+        Value::Debug(value) => {
+            json::write_string(out, &format!("{:?}", value));
+        },
+    }
+}
+
+unsafe fn json_line(call: &Call, result: Option<vk::Result>) -> String {
+    let mut line = String::new();
+    line.push_str("{\"command\":");
+    json::write_string(&mut line, call.command.name());
+    line.push_str(",\"args\":{");
+    for (i, arg) in call.args.iter().enumerate() {
+        if i > 0 {
+            line.push(',');
+        }
+        json::write_string(&mut line, arg.name);
+        line.push(':');
+        if is_unset(&arg.value, result) {
+            json::write_string(&mut line, "<unset>");
+        } else {
+            write_json(&mut line, &arg.value);
+        }
+    }
+    line.push('}');
+    if let Some(result) = result {
+        line.push_str(",\"result\":");
+        json::write_string(&mut line, &format!("{:?}", result));
+    }
+    line.push_str("}\n");
+    line
+}
@@ -0,0 +1,102 @@
+// A tiny driver with one physical device, which tests/drivers.rs
+// compiles into a shared library. It only depends on std so that it
+// can be built with a bare `rustc` invocation.
+#![allow(non_snake_case)]
+
+use std::ffi::{c_void, CStr};
+use std::os::raw::c_char;
+
+const ICD_LOADER_MAGIC: usize = 0x01CDC0DE;
+const SUCCESS: i32 = 0;
+const INCOMPLETE: i32 = 5;
+
+#[repr(C)]
+struct Object {
+    loader_magic: usize,
+}
+
+static PHYSICAL_DEVICE: Object = Object { loader_magic: ICD_LOADER_MAGIC };
+
+type VoidFunction = Option<unsafe extern "C" fn()>;
+
+#[no_mangle]
+pub unsafe extern "C" fn vk_icdNegotiateLoaderICDInterfaceVersion(
+    p_supported_version: *mut u32,
+) -> i32 {
+    *p_supported_version = (*p_supported_version).min(5);
+    SUCCESS
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn vk_icdGetInstanceProcAddr(
+    _instance: *const c_void,
+    p_name: *const c_char,
+) -> VoidFunction {
+    let f = match CStr::from_ptr(p_name).to_bytes() {
+        b"vkCreateInstance" => create_instance as *const (),
+        b"vkDestroyInstance" => destroy_instance as *const (),
+        b"vkEnumeratePhysicalDevices" => enumerate_physical_devices as *const (),
+        b"vkGetPhysicalDeviceProperties" =>
+            get_physical_device_properties as *const (),
+        _ => return None,
+    };
+    Some(std::mem::transmute::<*const (), unsafe extern "C" fn()>(f))
+}
+
+unsafe extern "C" fn create_instance(
+    _p_create_info: *const c_void,
+    _p_allocator: *const c_void,
+    p_instance: *mut *mut Object,
+) -> i32 {
+    *p_instance = Box::into_raw(Box::new(Object {
+        loader_magic: ICD_LOADER_MAGIC,
+    }));
+    SUCCESS
+}
+
+unsafe extern "C" fn destroy_instance(
+    instance: *mut Object,
+    _p_allocator: *const c_void,
+) {
+    drop(Box::from_raw(instance));
+}
+
+unsafe extern "C" fn enumerate_physical_devices(
+    _instance: *mut Object,
+    p_physical_device_count: *mut u32,
+    p_physical_devices: *mut *const Object,
+) -> i32 {
+    if p_physical_devices.is_null() {
+        *p_physical_device_count = 1;
+        return SUCCESS;
+    }
+    if *p_physical_device_count == 0 {
+        return INCOMPLETE;
+    }
+    *p_physical_devices = &PHYSICAL_DEVICE;
+    *p_physical_device_count = 1;
+    SUCCESS
+}
+
+// Only fills in the leading members of VkPhysicalDeviceProperties.
+#[repr(C)]
+struct PhysicalDeviceProperties {
+    api_version: u32,
+    driver_version: u32,
+    vendor_id: u32,
+    device_id: u32,
+    device_type: i32,
+    device_name: [c_char; 256],
+}
+
+unsafe extern "C" fn get_physical_device_properties(
+    _physical_device: *const Object,
+    p_properties: *mut PhysicalDeviceProperties,
+) {
+    let props = &mut *p_properties;
+    props.api_version = 1 << 22;
+    props.vendor_id = 0x10005;
+    for (dst, &src) in props.device_name.iter_mut().zip(b"stub\0") {
+        *dst = src as c_char;
+    }
+}
@@ -0,0 +1,72 @@
+use std::env::consts::{DLL_PREFIX, DLL_SUFFIX};
+use std::ffi::CStr;
+use std::path::{Path, PathBuf};
+use std::process::Command;
+
+use vulkan_loader::drivers;
+use vulkan_loader::icd::Manifest;
+
+// Builds tests/data/stub_icd.rs into a shared library in `dir`.
+fn build_stub_icd(dir: &Path) -> PathBuf {
+    let src = Path::new(env!("CARGO_MANIFEST_DIR"))
+        .join("tests/data/stub_icd.rs");
+    let lib = dir.join(format!("{}stub_icd{}", DLL_PREFIX, DLL_SUFFIX));
+    let rustc = std::env::var_os("RUSTC").unwrap_or_else(|| "rustc".into());
+    let status = Command::new(rustc)
+        .args(["--crate-type=cdylib", "--edition=2018", "-o"])
+        .arg(&lib)
+        .arg(&src)
+        .status()
+        .unwrap();
+    assert!(status.success());
+    lib
+}
+
+#[test]
+fn load_stub_drivers() {
+    let dir = Path::new(env!("CARGO_TARGET_TMPDIR")).join("drivers");
+    let _ = std::fs::remove_dir_all(&dir);
+    std::fs::create_dir_all(&dir).unwrap();
+    let lib = build_stub_icd(&dir);
+
+    // One manifest names the library relative to itself and the other
+    // by its absolute path.
+    let relative = Path::new(".").join(lib.file_name().unwrap());
+    Manifest::new(relative, vk::API_VERSION_1_0)
+        .write(dir.join("a.json"))
+        .unwrap();
+    Manifest::new(&lib, vk::API_VERSION_1_0)
+        .write(dir.join("b.json"))
+        .unwrap();
+    std::fs::write(dir.join("notes.txt"), "not a manifest").unwrap();
+
+    std::env::remove_var(drivers::ADD_DRIVER_FILES_VAR);
+    std::env::set_var(drivers::DRIVER_FILES_VAR, &dir);
+    let paths = drivers::manifest_paths();
+    assert_eq!(paths, [dir.join("a.json"), dir.join("b.json")]);
+
+    unsafe {
+        let icds = drivers::load_manifests(&paths).unwrap();
+        assert_eq!(icds.len(), 2);
+        assert_eq!(icds[0].interface_version(), 5);
+        assert_eq!(icds[0].manifest().api_version, vk::API_VERSION_1_0);
+
+        let create_info = Default::default();
+        let instance =
+            drivers::Instance::create(&icds, &create_info, None).unwrap();
+        let physical_devices = instance.enumerate_physical_devices().unwrap();
+        assert_eq!(physical_devices.len(), 2);
+        for physical_device in physical_devices {
+            let props = physical_device.get_physical_device_properties_checked();
+            let name = CStr::from_ptr(props.device_name.as_ptr());
+            assert_eq!(name.to_str().unwrap(), "stub");
+            assert_eq!(props.vendor_id, 0x10005);
+        }
+        instance.destroy(None);
+
+        let missing = dir.join("missing.json");
+        let err = drivers::load_manifests([&missing]).unwrap_err();
+        assert_eq!(err.attempts.len(), 1);
+        assert_eq!(err.attempts[0].path, missing.as_os_str());
+    }
+}